@@ -0,0 +1,28 @@
+use clap::Args;
+use serde_json::Value;
+
+use corp_finance_core::capital_budgeting::project_selection::{self, ProjectSelectionInput};
+
+use crate::input;
+
+/// Arguments for capital project selection
+#[derive(Args)]
+pub struct ProjectSelectionArgs {
+    /// Path to JSON input file
+    #[arg(long)]
+    pub input: Option<String>,
+}
+
+pub fn run_project_selection(
+    args: ProjectSelectionArgs,
+) -> Result<Value, Box<dyn std::error::Error>> {
+    let ps_input: ProjectSelectionInput = if let Some(ref path) = args.input {
+        input::file::read_json(path)?
+    } else if let Some(data) = input::stdin::read_stdin()? {
+        serde_json::from_value(data)?
+    } else {
+        return Err("--input <file.json> or stdin required for project-selection".into());
+    };
+    let result = project_selection::select_projects(&ps_input)?;
+    Ok(serde_json::to_value(result)?)
+}
@@ -0,0 +1,26 @@
+use clap::Args;
+use serde_json::Value;
+
+use corp_finance_core::cashflow_ladder::ladder::{self, CashFlowLadderInput};
+
+use crate::input;
+
+/// Arguments for the aggregated cash flow ladder
+#[derive(Args)]
+pub struct CashflowLadderArgs {
+    /// Path to JSON input file ({ "as_of": ..., "positions": [...], "bucketing": ... })
+    #[arg(long)]
+    pub input: Option<String>,
+}
+
+pub fn run_cashflow_ladder(args: CashflowLadderArgs) -> Result<Value, Box<dyn std::error::Error>> {
+    let ladder_input: CashFlowLadderInput = if let Some(ref path) = args.input {
+        input::file::read_json(path)?
+    } else if let Some(data) = input::stdin::read_stdin()? {
+        serde_json::from_value(data)?
+    } else {
+        return Err("--input <file.json> or stdin required for cashflow-ladder".into());
+    };
+    let result = ladder::build_cashflow_ladder(&ladder_input)?;
+    Ok(serde_json::to_value(result)?)
+}
@@ -2,7 +2,10 @@ use clap::Args;
 use serde_json::Value;
 
 use corp_finance_core::clo_analytics::coverage_tests::{self, CoverageTestInput};
-use corp_finance_core::clo_analytics::reinvestment::{self, ReinvestmentInput};
+use corp_finance_core::clo_analytics::portfolio_metrics::{self, PortfolioMetricsInput};
+use corp_finance_core::clo_analytics::reinvestment::{
+    self, ReinvestmentInput, ReinvestmentOptimizationInput,
+};
 use corp_finance_core::clo_analytics::scenario::{self, CloScenarioInput};
 use corp_finance_core::clo_analytics::tranche_analytics::{self, TrancheAnalyticsInput};
 use corp_finance_core::clo_analytics::waterfall::{self, WaterfallInput};
@@ -21,12 +24,24 @@ pub struct CloCoverageArgs {
     pub input: Option<String>,
 }
 
+#[derive(Args)]
+pub struct CloPortfolioMetricsArgs {
+    #[arg(long)]
+    pub input: Option<String>,
+}
+
 #[derive(Args)]
 pub struct CloReinvestmentArgs {
     #[arg(long)]
     pub input: Option<String>,
 }
 
+#[derive(Args)]
+pub struct CloReinvestmentOptimizeArgs {
+    #[arg(long)]
+    pub input: Option<String>,
+}
+
 #[derive(Args)]
 pub struct CloTrancheArgs {
     #[arg(long)]
@@ -63,6 +78,20 @@ pub fn run_clo_coverage(args: CloCoverageArgs) -> Result<Value, Box<dyn std::err
     Ok(serde_json::to_value(result)?)
 }
 
+pub fn run_clo_portfolio_metrics(
+    args: CloPortfolioMetricsArgs,
+) -> Result<Value, Box<dyn std::error::Error>> {
+    let input_data: PortfolioMetricsInput = if let Some(ref path) = args.input {
+        input::file::read_json(path)?
+    } else if let Some(data) = input::stdin::read_stdin()? {
+        serde_json::from_value(data)?
+    } else {
+        return Err("--input <file.json> or stdin required".into());
+    };
+    let result = portfolio_metrics::calculate_portfolio_metrics(&input_data)?;
+    Ok(serde_json::to_value(result)?)
+}
+
 pub fn run_clo_reinvestment(
     args: CloReinvestmentArgs,
 ) -> Result<Value, Box<dyn std::error::Error>> {
@@ -77,6 +106,20 @@ pub fn run_clo_reinvestment(
     Ok(serde_json::to_value(result)?)
 }
 
+pub fn run_clo_reinvestment_optimize(
+    args: CloReinvestmentOptimizeArgs,
+) -> Result<Value, Box<dyn std::error::Error>> {
+    let input_data: ReinvestmentOptimizationInput = if let Some(ref path) = args.input {
+        input::file::read_json(path)?
+    } else if let Some(data) = input::stdin::read_stdin()? {
+        serde_json::from_value(data)?
+    } else {
+        return Err("--input <file.json> or stdin required".into());
+    };
+    let result = reinvestment::optimize_reinvestment(&input_data)?;
+    Ok(serde_json::to_value(result)?)
+}
+
 pub fn run_clo_tranche(args: CloTrancheArgs) -> Result<Value, Box<dyn std::error::Error>> {
     let input_data: TrancheAnalyticsInput = if let Some(ref path) = args.input {
         input::file::read_json(path)?
@@ -0,0 +1,295 @@
+use clap::{Arg, ArgAction, Args, Command, ValueEnum};
+use std::fmt::Write;
+
+#[derive(Debug, Clone, ValueEnum)]
+pub enum Shell {
+    Bash,
+    Zsh,
+    Fish,
+}
+
+#[derive(Args)]
+pub struct CompletionsArgs {
+    /// Shell to generate the completion script for
+    pub shell: Shell,
+}
+
+/// Generate a completion script for `cmd` by walking its subcommands and
+/// arguments.
+pub fn run_completions(args: CompletionsArgs, cmd: &Command) -> String {
+    match args.shell {
+        Shell::Bash => bash(cmd),
+        Shell::Zsh => zsh(cmd),
+        Shell::Fish => fish(cmd),
+    }
+}
+
+fn visible_subcommands(cmd: &Command) -> impl Iterator<Item = &Command> {
+    cmd.get_subcommands().filter(|c| !c.is_hide_set())
+}
+
+fn visible_args(cmd: &Command) -> impl Iterator<Item = &Arg> {
+    cmd.get_arguments().filter(|a| !a.is_hide_set())
+}
+
+fn possible_values(arg: &Arg) -> Vec<String> {
+    arg.get_possible_values()
+        .iter()
+        .filter(|v| !v.is_hide_set())
+        .map(|v| v.get_name().to_string())
+        .collect()
+}
+
+fn takes_value(arg: &Arg) -> bool {
+    !matches!(
+        arg.get_action(),
+        ArgAction::SetTrue | ArgAction::SetFalse | ArgAction::Count
+    )
+}
+
+/// Flags that name an input file.
+fn is_path(arg: &Arg) -> bool {
+    arg.get_long().is_some_and(|l| {
+        l == "input" || l.ends_with("-inputs") || l.ends_with("-file") || l.ends_with("-path")
+    })
+}
+
+/// First line of an argument or command's help text.
+pub(crate) fn short_help(help: Option<&clap::builder::StyledStr>) -> String {
+    help.map(|h| h.to_string())
+        .and_then(|h| h.lines().next().map(str::to_string))
+        .unwrap_or_default()
+}
+
+fn long_flags(cmd: &Command) -> Vec<String> {
+    visible_args(cmd)
+        .filter_map(|a| a.get_long().map(|l| format!("--{l}")))
+        .collect()
+}
+
+fn bash(cmd: &Command) -> String {
+    let name = cmd.get_name();
+    let global = long_flags(cmd).join(" ");
+    let mut s = String::new();
+    let _ = writeln!(s, "_{name}() {{");
+    let _ = writeln!(s, "    local cur prev opts");
+    let _ = writeln!(s, "    COMPREPLY=()");
+    let _ = writeln!(s, "    cur=\"${{COMP_WORDS[COMP_CWORD]}}\"");
+    let _ = writeln!(s, "    prev=\"${{COMP_WORDS[COMP_CWORD-1]}}\"");
+    let _ = writeln!(s);
+
+    // Flag values: enumerated choices or file paths
+    let mut value_cases: Vec<(String, String)> = Vec::new();
+    for c in std::iter::once(cmd).chain(visible_subcommands(cmd)) {
+        for a in visible_args(c) {
+            let Some(long) = a.get_long() else { continue };
+            let flag = format!("--{long}");
+            if value_cases.iter().any(|(f, _)| *f == flag) {
+                continue;
+            }
+            let values = possible_values(a);
+            if !values.is_empty() {
+                value_cases.push((
+                    flag,
+                    format!(
+                        "COMPREPLY=( $(compgen -W \"{}\" -- \"${{cur}}\") )",
+                        values.join(" ")
+                    ),
+                ));
+            } else if is_path(a) {
+                value_cases.push((flag, "COMPREPLY=( $(compgen -f -- \"${cur}\") )".into()));
+            }
+        }
+    }
+    if !value_cases.is_empty() {
+        let _ = writeln!(s, "    case \"${{prev}}\" in");
+        for (flag, reply) in &value_cases {
+            let _ = writeln!(s, "        {flag})");
+            let _ = writeln!(s, "            {reply}");
+            let _ = writeln!(s, "            return 0");
+            let _ = writeln!(s, "            ;;");
+        }
+        let _ = writeln!(s, "    esac");
+        let _ = writeln!(s);
+    }
+
+    let subcommands: Vec<&str> = visible_subcommands(cmd).map(|c| c.get_name()).collect();
+    let _ = writeln!(s, "    if [[ ${{COMP_CWORD}} -eq 1 ]]; then");
+    let _ = writeln!(
+        s,
+        "        COMPREPLY=( $(compgen -W \"{} {global}\" -- \"${{cur}}\") )",
+        subcommands.join(" ")
+    );
+    let _ = writeln!(s, "        return 0");
+    let _ = writeln!(s, "    fi");
+    let _ = writeln!(s);
+    let _ = writeln!(s, "    case \"${{COMP_WORDS[1]}}\" in");
+    for c in visible_subcommands(cmd) {
+        let mut opts = long_flags(c);
+        for a in visible_args(c).filter(|a| a.is_positional()) {
+            opts.extend(possible_values(a));
+        }
+        let _ = writeln!(s, "        {})", c.get_name());
+        let _ = writeln!(s, "            opts=\"{}\"", opts.join(" "));
+        let _ = writeln!(s, "            ;;");
+    }
+    let _ = writeln!(s, "        *)");
+    let _ = writeln!(s, "            opts=\"\"");
+    let _ = writeln!(s, "            ;;");
+    let _ = writeln!(s, "    esac");
+    let _ = writeln!(
+        s,
+        "    COMPREPLY=( $(compgen -W \"${{opts}} {global}\" -- \"${{cur}}\") )"
+    );
+    let _ = writeln!(s, "}}");
+    let _ = writeln!(s);
+    let _ = writeln!(s, "complete -F _{name} -o bashdefault -o default {name}");
+    s
+}
+
+/// Escape text for a single-quoted zsh `_arguments` spec.
+fn zsh_escape(text: &str) -> String {
+    text.replace('\'', "'\\''")
+        .replace('[', "\\[")
+        .replace(']', "\\]")
+        .replace(':', "\\:")
+}
+
+fn zsh_arg_spec(a: &Arg) -> Option<String> {
+    let long = a.get_long()?;
+    let help = zsh_escape(&short_help(a.get_help()));
+    if !takes_value(a) {
+        return Some(format!("'--{long}[{help}]'"));
+    }
+    let values = possible_values(a);
+    let action = if !values.is_empty() {
+        format!("({})", values.join(" "))
+    } else if is_path(a) {
+        "_files".to_string()
+    } else {
+        String::new()
+    };
+    Some(format!("'--{long}[{help}]:{long}:{action}'"))
+}
+
+fn zsh(cmd: &Command) -> String {
+    let name = cmd.get_name();
+    let mut s = String::new();
+    let _ = writeln!(s, "#compdef {name}");
+    let _ = writeln!(s);
+    let _ = writeln!(s, "_{name}() {{");
+    let _ = writeln!(s, "    local line state");
+    let _ = writeln!(s, "    _arguments -C \\");
+    for spec in visible_args(cmd).filter_map(zsh_arg_spec) {
+        let _ = writeln!(s, "        {spec} \\");
+    }
+    let _ = writeln!(s, "        '1: :->cmds' \\");
+    let _ = writeln!(s, "        '*:: :->args'");
+    let _ = writeln!(s);
+    let _ = writeln!(s, "    case $state in");
+    let _ = writeln!(s, "        cmds)");
+    let _ = writeln!(s, "            local -a commands");
+    let _ = writeln!(s, "            commands=(");
+    for c in visible_subcommands(cmd) {
+        let _ = writeln!(
+            s,
+            "                '{}:{}'",
+            c.get_name(),
+            zsh_escape(&short_help(c.get_about()))
+        );
+    }
+    let _ = writeln!(s, "            )");
+    let _ = writeln!(s, "            _describe 'command' commands");
+    let _ = writeln!(s, "            ;;");
+    let _ = writeln!(s, "        args)");
+    let _ = writeln!(s, "            case $line[1] in");
+    for c in visible_subcommands(cmd) {
+        let mut specs: Vec<String> = visible_args(c).filter_map(zsh_arg_spec).collect();
+        for a in visible_args(c).filter(|a| a.is_positional()) {
+            let values = possible_values(a);
+            let id = a.get_id().as_str();
+            specs.push(if values.is_empty() {
+                format!("'1:{id}:'")
+            } else {
+                format!("'1:{id}:({})'", values.join(" "))
+            });
+        }
+        let _ = writeln!(s, "                {})", c.get_name());
+        if specs.is_empty() {
+            let _ = writeln!(s, "                    _message 'no arguments'");
+        } else {
+            let _ = writeln!(s, "                    _arguments \\");
+            let last = specs.len() - 1;
+            for (i, spec) in specs.iter().enumerate() {
+                let cont = if i == last { "" } else { " \\" };
+                let _ = writeln!(s, "                        {spec}{cont}");
+            }
+        }
+        let _ = writeln!(s, "                    ;;");
+    }
+    let _ = writeln!(s, "            esac");
+    let _ = writeln!(s, "            ;;");
+    let _ = writeln!(s, "    esac");
+    let _ = writeln!(s, "}}");
+    let _ = writeln!(s);
+    let _ = writeln!(s, "_{name} \"$@\"");
+    s
+}
+
+fn fish_arg(s: &mut String, name: &str, condition: Option<&str>, a: &Arg) {
+    let Some(long) = a.get_long() else { return };
+    let mut line = format!("complete -c {name}");
+    if let Some(cond) = condition {
+        let _ = write!(line, " -n \"{cond}\"");
+    }
+    let _ = write!(line, " -l {long}");
+    let help = short_help(a.get_help());
+    if !help.is_empty() {
+        let _ = write!(line, " -d '{}'", help.replace('\'', "\\'"));
+    }
+    if takes_value(a) {
+        let values = possible_values(a);
+        if !values.is_empty() {
+            let _ = write!(line, " -xa \"{}\"", values.join(" "));
+        } else if is_path(a) {
+            line.push_str(" -rF");
+        } else {
+            line.push_str(" -r");
+        }
+    }
+    let _ = writeln!(s, "{line}");
+}
+
+fn fish(cmd: &Command) -> String {
+    let name = cmd.get_name();
+    let mut s = String::new();
+    for a in visible_args(cmd) {
+        fish_arg(&mut s, name, None, a);
+    }
+    for c in visible_subcommands(cmd) {
+        let help = short_help(c.get_about()).replace('\'', "\\'");
+        let _ = writeln!(
+            s,
+            "complete -c {name} -n \"__fish_use_subcommand\" -f -a \"{}\" -d '{help}'",
+            c.get_name()
+        );
+    }
+    for c in visible_subcommands(cmd) {
+        let condition = format!("__fish_seen_subcommand_from {}", c.get_name());
+        for a in visible_args(c) {
+            if a.is_positional() {
+                let values = possible_values(a);
+                if !values.is_empty() {
+                    let _ = writeln!(
+                        s,
+                        "complete -c {name} -n \"{condition}\" -f -a \"{}\"",
+                        values.join(" ")
+                    );
+                }
+            } else {
+                fish_arg(&mut s, name, Some(&condition), a);
+            }
+        }
+    }
+    s
+}
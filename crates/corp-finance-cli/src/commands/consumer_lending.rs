@@ -0,0 +1,68 @@
+use clap::Args;
+use serde_json::Value;
+
+use corp_finance_core::consumer_lending::apr::{self, AprInput};
+use corp_finance_core::consumer_lending::loans::{self, RetailLoanInput};
+use corp_finance_core::consumer_lending::refinance::{self, RefinanceInput};
+
+use crate::input;
+
+/// Arguments for a retail loan amortization schedule
+#[derive(Args)]
+pub struct RetailLoanArgs {
+    /// Path to JSON input file
+    #[arg(long)]
+    pub input: Option<String>,
+}
+
+/// Arguments for a Reg Z APR calculation
+#[derive(Args)]
+pub struct LoanAprArgs {
+    /// Path to JSON input file
+    #[arg(long)]
+    pub input: Option<String>,
+}
+
+/// Arguments for refinance breakeven analysis
+#[derive(Args)]
+pub struct RefinanceArgs {
+    /// Path to JSON input file
+    #[arg(long)]
+    pub input: Option<String>,
+}
+
+pub fn run_retail_loan(args: RetailLoanArgs) -> Result<Value, Box<dyn std::error::Error>> {
+    let loan_input: RetailLoanInput = if let Some(ref path) = args.input {
+        input::file::read_json(path)?
+    } else if let Some(data) = input::stdin::read_stdin()? {
+        serde_json::from_value(data)?
+    } else {
+        return Err("--input <file.json> or stdin required for retail-loan".into());
+    };
+    let result = loans::build_retail_loan_schedule(&loan_input)?;
+    Ok(serde_json::to_value(result)?)
+}
+
+pub fn run_loan_apr(args: LoanAprArgs) -> Result<Value, Box<dyn std::error::Error>> {
+    let apr_input: AprInput = if let Some(ref path) = args.input {
+        input::file::read_json(path)?
+    } else if let Some(data) = input::stdin::read_stdin()? {
+        serde_json::from_value(data)?
+    } else {
+        return Err("--input <file.json> or stdin required for loan-apr".into());
+    };
+    let result = apr::calculate_apr(&apr_input)?;
+    Ok(serde_json::to_value(result)?)
+}
+
+pub fn run_refinance(args: RefinanceArgs) -> Result<Value, Box<dyn std::error::Error>> {
+    let refi_input: RefinanceInput = if let Some(ref path) = args.input {
+        input::file::read_json(path)?
+    } else if let Some(data) = input::stdin::read_stdin()? {
+        serde_json::from_value(data)?
+    } else {
+        return Err("--input <file.json> or stdin required for refinance".into());
+    };
+    let result = refinance::analyze_refinance(&refi_input)?;
+    Ok(serde_json::to_value(result)?)
+}
@@ -2,6 +2,7 @@ use clap::Args;
 use serde_json::Value;
 
 use corp_finance_core::convertibles::analysis::{self, ConvertibleAnalysisInput};
+use corp_finance_core::convertibles::lattice::{self, ConvertibleBookInput};
 use corp_finance_core::convertibles::pricing::{self, ConvertibleBondInput};
 
 use crate::input;
@@ -22,6 +23,14 @@ pub struct ConvertibleAnalysisArgs {
     pub input: Option<String>,
 }
 
+/// Arguments for pricing a book of convertibles on one calibrated lattice
+#[derive(Args)]
+pub struct ConvertibleBookArgs {
+    /// Path to JSON input file
+    #[arg(long)]
+    pub input: Option<String>,
+}
+
 pub fn run_convertible_pricing(
     args: ConvertiblePricingArgs,
 ) -> Result<Value, Box<dyn std::error::Error>> {
@@ -49,3 +58,17 @@ pub fn run_convertible_analysis(
     let result = analysis::analyze_convertible(&ca_input)?;
     Ok(serde_json::to_value(result)?)
 }
+
+pub fn run_convertible_book(
+    args: ConvertibleBookArgs,
+) -> Result<Value, Box<dyn std::error::Error>> {
+    let book_input: ConvertibleBookInput = if let Some(ref path) = args.input {
+        input::file::read_json(path)?
+    } else if let Some(data) = input::stdin::read_stdin()? {
+        serde_json::from_value(data)?
+    } else {
+        return Err("--input <file.json> or stdin required for convertible book pricing".into());
+    };
+    let result = lattice::price_convertible_book(&book_input)?;
+    Ok(serde_json::to_value(result)?)
+}
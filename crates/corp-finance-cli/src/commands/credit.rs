@@ -2,7 +2,11 @@ use clap::Args;
 use rust_decimal::Decimal;
 use serde_json::Value;
 
+use corp_finance_core::amortization::{self, AmortizationInput};
 use corp_finance_core::credit::altman::{self, AltmanInput};
+use corp_finance_core::credit::capacity::{self, DebtCapacityInput, DebtMaturityInput};
+use corp_finance_core::credit::counterparty_exposure::{self, CounterpartyExposureInput};
+use corp_finance_core::credit::covenants::{self, CovenantProjectionInput, CovenantTestInput};
 use corp_finance_core::credit::metrics::{self, CreditMetricsInput};
 
 use crate::input;
@@ -100,6 +104,22 @@ pub struct DebtCapacityArgs {
     pub input: Option<String>,
 }
 
+/// Arguments for the debt maturity schedule and refinancing analysis
+#[derive(Args)]
+pub struct DebtMaturityArgs {
+    /// Path to JSON input file
+    #[arg(long)]
+    pub input: Option<String>,
+}
+
+/// Arguments for a loan amortisation schedule
+#[derive(Args)]
+pub struct AmortizationArgs {
+    /// Path to JSON input file
+    #[arg(long)]
+    pub input: Option<String>,
+}
+
 /// Arguments for covenant compliance testing
 #[derive(Args)]
 pub struct CovenantArgs {
@@ -108,6 +128,22 @@ pub struct CovenantArgs {
     pub input: Option<String>,
 }
 
+/// Arguments for projecting formula covenants
+#[derive(Args)]
+pub struct CovenantProjectionArgs {
+    /// Path to JSON input file
+    #[arg(long)]
+    pub input: Option<String>,
+}
+
+/// Arguments for counterparty exposure
+#[derive(Args)]
+pub struct CounterpartyExposureArgs {
+    /// Path to JSON input file
+    #[arg(long)]
+    pub input: Option<String>,
+}
+
 pub fn run_credit_metrics(args: CreditArgs) -> Result<Value, Box<dyn std::error::Error>> {
     let credit_input: CreditMetricsInput = if let Some(ref path) = args.input {
         input::file::read_json(path)?
@@ -168,35 +204,65 @@ pub fn run_credit_metrics(args: CreditArgs) -> Result<Value, Box<dyn std::error:
 }
 
 pub fn run_debt_capacity(args: DebtCapacityArgs) -> Result<Value, Box<dyn std::error::Error>> {
-    let input_data: Value = if let Some(ref path) = args.input {
-        input::file::read_json_value(path)?
+    let capacity_input: DebtCapacityInput = if let Some(ref path) = args.input {
+        input::file::read_json(path)?
     } else if let Some(data) = input::stdin::read_stdin()? {
-        data
+        serde_json::from_value(data)?
     } else {
         return Err("--input file is required for debt capacity analysis".into());
     };
+    let result = capacity::calculate_debt_capacity(&capacity_input)?;
+    Ok(serde_json::to_value(result)?)
+}
+
+pub fn run_debt_maturity(args: DebtMaturityArgs) -> Result<Value, Box<dyn std::error::Error>> {
+    let maturity_input: DebtMaturityInput = if let Some(ref path) = args.input {
+        input::file::read_json(path)?
+    } else if let Some(data) = input::stdin::read_stdin()? {
+        serde_json::from_value(data)?
+    } else {
+        return Err("--input <file.json> or stdin required for debt maturity schedule".into());
+    };
+    let result = capacity::analyze_debt_maturities(&maturity_input)?;
+    Ok(serde_json::to_value(result)?)
+}
 
-    Err(format!(
-        "Debt capacity model not yet available. Input received: {}",
-        serde_json::to_string_pretty(&input_data)?
-    )
-    .into())
+pub fn run_amortization(args: AmortizationArgs) -> Result<Value, Box<dyn std::error::Error>> {
+    let amort_input: AmortizationInput = if let Some(ref path) = args.input {
+        input::file::read_json(path)?
+    } else if let Some(data) = input::stdin::read_stdin()? {
+        serde_json::from_value(data)?
+    } else {
+        return Err("--input <file.json> or stdin required for amortization schedule".into());
+    };
+    let result = amortization::amortization_schedule(&amort_input)?;
+    Ok(serde_json::to_value(result)?)
 }
 
 pub fn run_covenant_test(args: CovenantArgs) -> Result<Value, Box<dyn std::error::Error>> {
-    let input_data: Value = if let Some(ref path) = args.input {
-        input::file::read_json_value(path)?
+    let covenant_input: CovenantTestInput = if let Some(ref path) = args.input {
+        input::file::read_json(path)?
     } else if let Some(data) = input::stdin::read_stdin()? {
-        data
+        serde_json::from_value(data)?
     } else {
         return Err("--input file is required for covenant testing".into());
     };
+    let result = covenants::test_covenants(&covenant_input)?;
+    Ok(serde_json::to_value(result)?)
+}
 
-    Err(format!(
-        "Covenant testing not yet available. Input received: {}",
-        serde_json::to_string_pretty(&input_data)?
-    )
-    .into())
+pub fn run_covenant_projection(
+    args: CovenantProjectionArgs,
+) -> Result<Value, Box<dyn std::error::Error>> {
+    let projection_input: CovenantProjectionInput = if let Some(ref path) = args.input {
+        input::file::read_json(path)?
+    } else if let Some(data) = input::stdin::read_stdin()? {
+        serde_json::from_value(data)?
+    } else {
+        return Err("--input <file.json> or stdin required for covenant projection".into());
+    };
+    let result = covenants::project_covenants(&projection_input)?;
+    Ok(serde_json::to_value(result)?)
 }
 
 /// Arguments for Altman Z-Score calculation
@@ -218,3 +284,17 @@ pub fn run_altman(args: AltmanArgs) -> Result<Value, Box<dyn std::error::Error>>
     let result = altman::calculate_altman_zscore(&altman_input)?;
     Ok(serde_json::to_value(result)?)
 }
+
+pub fn run_counterparty_exposure(
+    args: CounterpartyExposureArgs,
+) -> Result<Value, Box<dyn std::error::Error>> {
+    let input_data: CounterpartyExposureInput = if let Some(ref path) = args.input {
+        input::file::read_json(path)?
+    } else if let Some(data) = input::stdin::read_stdin()? {
+        serde_json::from_value(data)?
+    } else {
+        return Err("--input <file.json> or stdin required for counterparty exposure".into());
+    };
+    let result = counterparty_exposure::calculate_counterparty_exposure(&input_data)?;
+    Ok(serde_json::to_value(result)?)
+}
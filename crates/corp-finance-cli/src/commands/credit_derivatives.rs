@@ -3,6 +3,7 @@ use serde_json::Value;
 
 use corp_finance_core::credit_derivatives::cds::{self, CdsInput};
 use corp_finance_core::credit_derivatives::cva::{self, CvaInput};
+use corp_finance_core::credit_derivatives::xva::{self, XvaInput};
 
 use crate::input;
 
@@ -22,6 +23,14 @@ pub struct CvaArgs {
     pub input: Option<String>,
 }
 
+/// Arguments for XVA calculation
+#[derive(Args)]
+pub struct XvaArgs {
+    /// Path to JSON input file
+    #[arg(long)]
+    pub input: Option<String>,
+}
+
 pub fn run_cds_pricing(args: CdsArgs) -> Result<Value, Box<dyn std::error::Error>> {
     let cds_input: CdsInput = if let Some(ref path) = args.input {
         input::file::read_json(path)?
@@ -45,3 +54,15 @@ pub fn run_cva_calculation(args: CvaArgs) -> Result<Value, Box<dyn std::error::E
     let result = cva::calculate_cva(&cva_input)?;
     Ok(serde_json::to_value(result)?)
 }
+
+pub fn run_xva_calculation(args: XvaArgs) -> Result<Value, Box<dyn std::error::Error>> {
+    let xva_input: XvaInput = if let Some(ref path) = args.input {
+        input::file::read_json(path)?
+    } else if let Some(data) = input::stdin::read_stdin()? {
+        serde_json::from_value(data)?
+    } else {
+        return Err("--input <file.json> or stdin required for XVA calculation".into());
+    };
+    let result = xva::calculate_xva(&xva_input)?;
+    Ok(serde_json::to_value(result)?)
+}
@@ -0,0 +1,26 @@
+use clap::Args;
+use serde_json::Value;
+
+use corp_finance_core::deal_model::orchestration::{self, DealInput};
+
+use crate::input;
+
+/// Arguments for the end-to-end deal model
+#[derive(Args)]
+pub struct DealModelArgs {
+    /// Path to JSON input file ({ "target": {...}, "financing": {...}, "valuation": {...} })
+    #[arg(long)]
+    pub input: Option<String>,
+}
+
+pub fn run_deal_model(args: DealModelArgs) -> Result<Value, Box<dyn std::error::Error>> {
+    let deal_input: DealInput = if let Some(ref path) = args.input {
+        input::file::read_json(path)?
+    } else if let Some(data) = input::stdin::read_stdin()? {
+        serde_json::from_value(data)?
+    } else {
+        return Err("--input <file.json> or stdin required for deal-model".into());
+    };
+    let result = orchestration::run_deal_model(&deal_input)?;
+    Ok(serde_json::to_value(result)?)
+}
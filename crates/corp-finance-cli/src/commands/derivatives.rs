@@ -4,6 +4,7 @@ use serde_json::Value;
 use corp_finance_core::derivatives::forwards::{
     self, BasisAnalysisInput, ForwardInput, ForwardPositionInput,
 };
+use corp_finance_core::derivatives::heston::{self, HestonCalibrationInput, HestonPricingInput};
 use corp_finance_core::derivatives::options::{self, ImpliedVolInput, OptionInput};
 use corp_finance_core::derivatives::strategies::{self, StrategyInput};
 use corp_finance_core::derivatives::swaps::{self, CurrencySwapInput, IrsInput};
@@ -50,6 +51,48 @@ pub fn run_implied_vol(args: ImpliedVolArgs) -> Result<Value, Box<dyn std::error
     Ok(serde_json::to_value(result)?)
 }
 
+/// Arguments for Heston stochastic volatility pricing
+#[derive(Args)]
+pub struct HestonPriceArgs {
+    /// Path to JSON input file
+    #[arg(long)]
+    pub input: Option<String>,
+}
+
+pub fn run_heston_price(args: HestonPriceArgs) -> Result<Value, Box<dyn std::error::Error>> {
+    let heston_input: HestonPricingInput = if let Some(ref path) = args.input {
+        input::file::read_json(path)?
+    } else if let Some(data) = input::stdin::read_stdin()? {
+        serde_json::from_value(data)?
+    } else {
+        return Err("--input <file.json> or stdin required for Heston pricing".into());
+    };
+    let result = heston::price_heston(&heston_input)?;
+    Ok(serde_json::to_value(result)?)
+}
+
+/// Arguments for Heston calibration
+#[derive(Args)]
+pub struct HestonCalibrationArgs {
+    /// Path to JSON input file
+    #[arg(long)]
+    pub input: Option<String>,
+}
+
+pub fn run_heston_calibration(
+    args: HestonCalibrationArgs,
+) -> Result<Value, Box<dyn std::error::Error>> {
+    let cal_input: HestonCalibrationInput = if let Some(ref path) = args.input {
+        input::file::read_json(path)?
+    } else if let Some(data) = input::stdin::read_stdin()? {
+        serde_json::from_value(data)?
+    } else {
+        return Err("--input <file.json> or stdin required for Heston calibration".into());
+    };
+    let result = heston::calibrate_heston(&cal_input)?;
+    Ok(serde_json::to_value(result)?)
+}
+
 /// Arguments for forward pricing
 #[derive(Args)]
 pub struct ForwardPriceArgs {
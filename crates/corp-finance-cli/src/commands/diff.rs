@@ -0,0 +1,101 @@
+use clap::Args;
+use rust_decimal::Decimal;
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use corp_finance_core::diff::{self, MaterialityThreshold, ResultDiffInput};
+
+use crate::input;
+
+/// Arguments for comparing two calculator outputs
+#[derive(Args)]
+pub struct DiffArgs {
+    /// Earlier result (JSON file, or - for stdin)
+    pub old: String,
+    /// Later result (JSON file, or - for stdin)
+    pub new: String,
+    /// Minimum absolute change for a numeric field to be material
+    #[arg(long, value_name = "AMOUNT")]
+    pub abs_threshold: Option<Decimal>,
+    /// Minimum relative change for a numeric field to be material (0.01 = 1%)
+    #[arg(long, value_name = "RATE")]
+    pub pct_threshold: Option<Decimal>,
+    /// Threshold for one field, by name or full path: `irr=0.001` (absolute)
+    /// or `enterprise_value=2%` (relative). Repeatable
+    #[arg(long = "field-threshold", value_name = "FIELD=LIMIT", value_parser = parse_field_threshold)]
+    pub field_thresholds: Vec<(String, MaterialityThreshold)>,
+    /// Path prefix to leave out, or a bare field name to leave out at any
+    /// depth, in addition to `metadata`. Repeatable
+    #[arg(long, value_name = "PATH")]
+    pub ignore: Vec<String>,
+    /// Only list material changes
+    #[arg(long, conflicts_with = "all")]
+    pub material_only: bool,
+    /// Also list fields that did not change
+    #[arg(long)]
+    pub all: bool,
+}
+
+pub fn run_diff(args: DiffArgs) -> Result<Value, Box<dyn std::error::Error>> {
+    if args.old == "-" && args.new == "-" {
+        return Err("only one of the two results can be read from stdin".into());
+    }
+    let mut field_thresholds: BTreeMap<String, MaterialityThreshold> = BTreeMap::new();
+    for (field, threshold) in args.field_thresholds {
+        let entry = field_thresholds.entry(field).or_default();
+        entry.absolute = threshold.absolute.or(entry.absolute);
+        entry.relative = threshold.relative.or(entry.relative);
+    }
+    let mut ignore = vec!["metadata".to_string()];
+    ignore.extend(args.ignore);
+
+    let diff_input = ResultDiffInput {
+        old: input::file::read_json_value(&args.old)?,
+        new: input::file::read_json_value(&args.new)?,
+        threshold: MaterialityThreshold {
+            absolute: args.abs_threshold,
+            relative: args.pct_threshold,
+        },
+        field_thresholds,
+        ignore,
+        include_unchanged: args.all,
+    };
+    let result = diff::diff_results(&diff_input)?;
+    for warning in &result.warnings {
+        eprintln!("warning: {warning}");
+    }
+
+    // One row per field, so table and CSV output read as a change list.
+    let changes: Vec<Value> = result
+        .result
+        .changes
+        .into_iter()
+        .filter(|c| c.material || !args.material_only)
+        .map(serde_json::to_value)
+        .collect::<Result<_, _>>()?;
+    Ok(Value::Array(changes))
+}
+
+/// Parse `field=0.5` (absolute) or `field=2%` (relative).
+fn parse_field_threshold(s: &str) -> Result<(String, MaterialityThreshold), String> {
+    let (field, limit) = s
+        .split_once('=')
+        .ok_or_else(|| format!("invalid field threshold '{s}': expected FIELD=LIMIT"))?;
+    let limit = limit.trim();
+    let threshold = match limit.strip_suffix('%') {
+        Some(pct) => MaterialityThreshold {
+            absolute: None,
+            relative: Some(parse_decimal(pct)? / Decimal::ONE_HUNDRED),
+        },
+        None => MaterialityThreshold {
+            absolute: Some(parse_decimal(limit)?),
+            relative: None,
+        },
+    };
+    Ok((field.trim().to_string(), threshold))
+}
+
+fn parse_decimal(s: &str) -> Result<Decimal, String> {
+    Decimal::from_str(s.trim()).map_err(|_| format!("'{s}' is not a number"))
+}
@@ -2,8 +2,10 @@ use clap::Args;
 use serde_json::Value;
 
 use corp_finance_core::dividend_policy::buyback::{self, BuybackInput};
+use corp_finance_core::dividend_policy::buyback_execution::{self, AsrInput, OpenMarketInput};
 use corp_finance_core::dividend_policy::h_model::{self, HModelInput};
 use corp_finance_core::dividend_policy::multistage_ddm::{self, MultistageDdmInput};
+use corp_finance_core::dividend_policy::payout_optimizer::{self, PayoutOptimizerInput};
 use corp_finance_core::dividend_policy::payout_sustainability::{self, PayoutSustainabilityInput};
 use corp_finance_core::dividend_policy::total_shareholder_return::{
     self, TotalShareholderReturnInput,
@@ -41,6 +43,24 @@ pub struct TotalShareholderReturnArgs {
     pub input: Option<String>,
 }
 
+#[derive(Args)]
+pub struct AsrBuybackArgs {
+    #[arg(long)]
+    pub input: Option<String>,
+}
+
+#[derive(Args)]
+pub struct OpenMarketBuybackArgs {
+    #[arg(long)]
+    pub input: Option<String>,
+}
+
+#[derive(Args)]
+pub struct PayoutOptimizerArgs {
+    #[arg(long)]
+    pub input: Option<String>,
+}
+
 pub fn run_h_model_ddm(args: HModelDdmArgs) -> Result<Value, Box<dyn std::error::Error>> {
     let input_data: HModelInput = if let Some(ref path) = args.input {
         input::file::read_json(path)?
@@ -104,3 +124,45 @@ pub fn run_total_shareholder_return(
     let result = total_shareholder_return::calculate_total_shareholder_return(&input_data)?;
     Ok(serde_json::to_value(result)?)
 }
+
+pub fn run_asr_buyback(args: AsrBuybackArgs) -> Result<Value, Box<dyn std::error::Error>> {
+    let input_data: AsrInput = if let Some(ref path) = args.input {
+        input::file::read_json(path)?
+    } else if let Some(data) = input::stdin::read_stdin()? {
+        serde_json::from_value(data)?
+    } else {
+        return Err("--input <file.json> or stdin required for ASR modelling".into());
+    };
+    let result = buyback_execution::model_asr(&input_data)?;
+    Ok(serde_json::to_value(result)?)
+}
+
+pub fn run_open_market_buyback(
+    args: OpenMarketBuybackArgs,
+) -> Result<Value, Box<dyn std::error::Error>> {
+    let input_data: OpenMarketInput = if let Some(ref path) = args.input {
+        input::file::read_json(path)?
+    } else if let Some(data) = input::stdin::read_stdin()? {
+        serde_json::from_value(data)?
+    } else {
+        return Err(
+            "--input <file.json> or stdin required for open-market buyback simulation".into(),
+        );
+    };
+    let result = buyback_execution::model_open_market_program(&input_data)?;
+    Ok(serde_json::to_value(result)?)
+}
+
+pub fn run_payout_optimizer(
+    args: PayoutOptimizerArgs,
+) -> Result<Value, Box<dyn std::error::Error>> {
+    let input_data: PayoutOptimizerInput = if let Some(ref path) = args.input {
+        input::file::read_json(path)?
+    } else if let Some(data) = input::stdin::read_stdin()? {
+        serde_json::from_value(data)?
+    } else {
+        return Err("--input <file.json> or stdin required for payout policy optimization".into());
+    };
+    let result = payout_optimizer::optimize_payout_policy(&input_data)?;
+    Ok(serde_json::to_value(result)?)
+}
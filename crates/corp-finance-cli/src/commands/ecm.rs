@@ -0,0 +1,47 @@
+use clap::Args;
+use serde_json::Value;
+
+use corp_finance_core::ecm::ipo::{self, IpoInput};
+use corp_finance_core::ecm::rights_issue::{self, RightsIssueInput};
+
+use crate::input;
+
+/// Arguments for IPO valuation and offering structure
+#[derive(Args)]
+pub struct IpoArgs {
+    /// Path to JSON input file
+    #[arg(long)]
+    pub input: Option<String>,
+}
+
+/// Arguments for rights issue analysis
+#[derive(Args)]
+pub struct RightsIssueArgs {
+    /// Path to JSON input file
+    #[arg(long)]
+    pub input: Option<String>,
+}
+
+pub fn run_ipo(args: IpoArgs) -> Result<Value, Box<dyn std::error::Error>> {
+    let input_data: IpoInput = if let Some(ref path) = args.input {
+        input::file::read_json(path)?
+    } else if let Some(data) = input::stdin::read_stdin()? {
+        serde_json::from_value(data)?
+    } else {
+        return Err("--input <file.json> or stdin required for IPO analysis".into());
+    };
+    let result = ipo::analyze_ipo(&input_data)?;
+    Ok(serde_json::to_value(result)?)
+}
+
+pub fn run_rights_issue(args: RightsIssueArgs) -> Result<Value, Box<dyn std::error::Error>> {
+    let input_data: RightsIssueInput = if let Some(ref path) = args.input {
+        input::file::read_json(path)?
+    } else if let Some(data) = input::stdin::read_stdin()? {
+        serde_json::from_value(data)?
+    } else {
+        return Err("--input <file.json> or stdin required for rights issue analysis".into());
+    };
+    let result = rights_issue::analyze_rights_issue(&input_data)?;
+    Ok(serde_json::to_value(result)?)
+}
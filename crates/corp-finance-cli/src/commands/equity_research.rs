@@ -1,8 +1,10 @@
 use clap::Args;
 use serde_json::Value;
 
+use corp_finance_core::equity_research::bank_valuation::{self, BankValuationInput};
+use corp_finance_core::equity_research::reit::{self, ReitInput};
 use corp_finance_core::equity_research::sotp::{self, SotpInput};
-use corp_finance_core::equity_research::target_price::{self, TargetPriceInput};
+use corp_finance_core::equity_research::target_price::{self, ReverseDcfInput, TargetPriceInput};
 
 use crate::input;
 
@@ -22,6 +24,30 @@ pub struct TargetPriceArgs {
     pub input: Option<String>,
 }
 
+/// Arguments for REIT FFO/AFFO and NAV analysis
+#[derive(Args)]
+pub struct ReitArgs {
+    /// Path to JSON input file
+    #[arg(long)]
+    pub input: Option<String>,
+}
+
+/// Arguments for bank equity valuation
+#[derive(Args)]
+pub struct BankValuationArgs {
+    /// Path to JSON input file
+    #[arg(long)]
+    pub input: Option<String>,
+}
+
+/// Arguments for reverse DCF implied expectations
+#[derive(Args)]
+pub struct ReverseDcfArgs {
+    /// Path to JSON input file
+    #[arg(long)]
+    pub input: Option<String>,
+}
+
 pub fn run_sotp(args: SotpArgs) -> Result<Value, Box<dyn std::error::Error>> {
     let sotp_input: SotpInput = if let Some(ref path) = args.input {
         input::file::read_json(path)?
@@ -45,3 +71,39 @@ pub fn run_target_price(args: TargetPriceArgs) -> Result<Value, Box<dyn std::err
     let result = target_price::calculate_target_price(&tp_input)?;
     Ok(serde_json::to_value(result)?)
 }
+
+pub fn run_reit(args: ReitArgs) -> Result<Value, Box<dyn std::error::Error>> {
+    let input_data: ReitInput = if let Some(ref path) = args.input {
+        input::file::read_json(path)?
+    } else if let Some(data) = input::stdin::read_stdin()? {
+        serde_json::from_value(data)?
+    } else {
+        return Err("--input <file.json> or stdin required for REIT analysis".into());
+    };
+    let result = reit::analyze_reit(&input_data)?;
+    Ok(serde_json::to_value(result)?)
+}
+
+pub fn run_bank_valuation(args: BankValuationArgs) -> Result<Value, Box<dyn std::error::Error>> {
+    let input_data: BankValuationInput = if let Some(ref path) = args.input {
+        input::file::read_json(path)?
+    } else if let Some(data) = input::stdin::read_stdin()? {
+        serde_json::from_value(data)?
+    } else {
+        return Err("--input <file.json> or stdin required for bank valuation".into());
+    };
+    let result = bank_valuation::value_bank(&input_data)?;
+    Ok(serde_json::to_value(result)?)
+}
+
+pub fn run_reverse_dcf(args: ReverseDcfArgs) -> Result<Value, Box<dyn std::error::Error>> {
+    let input_data: ReverseDcfInput = if let Some(ref path) = args.input {
+        input::file::read_json(path)?
+    } else if let Some(data) = input::stdin::read_stdin()? {
+        serde_json::from_value(data)?
+    } else {
+        return Err("--input <file.json> or stdin required for reverse DCF".into());
+    };
+    let result = target_price::calculate_reverse_dcf(&input_data)?;
+    Ok(serde_json::to_value(result)?)
+}
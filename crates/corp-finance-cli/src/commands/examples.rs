@@ -0,0 +1,1460 @@
+use clap::builder::PossibleValuesParser;
+use clap::Args;
+use serde_json::Value;
+
+/// A runnable sample input for a calculator command.
+pub struct Example {
+    pub command: &'static str,
+    pub description: &'static str,
+    pub input: &'static str,
+}
+
+/// Sample inputs, taken from the reference fixtures and unit test cases in
+/// corp-finance-core so every one is known to compute.
+pub const EXAMPLES: &[Example] = &[
+    Example {
+        command: "abs-mbs",
+        description: "$1M pool of 1,000 30-year loans at a 6% coupon under a constant CPR",
+        input: include_str!("../../samples/abs-mbs.json"),
+    },
+    Example {
+        command: "accrual-quality",
+        description: "Industrial with $100 net income and $140 operating cash flow",
+        input: include_str!("../../samples/accrual-quality.json"),
+    },
+    Example {
+        command: "acquisition-financing",
+        description:
+            "$2.5B equity purchase funded with three debt tranches and a $900M equity raise",
+        input: include_str!("../../samples/acquisition-financing.json"),
+    },
+    Example {
+        command: "aifmd-reporting",
+        description: "Luxembourg AIFM with $2B AUM across two funds",
+        input: include_str!("../../samples/aifmd-reporting.json"),
+    },
+    Example {
+        command: "alm",
+        description: "Two-asset, two-liability bank balance sheet under parallel rate shocks",
+        input: include_str!("../../samples/alm.json"),
+    },
+    Example {
+        command: "altman-zscore",
+        description: "Strong public manufacturer",
+        input: include_str!("../../samples/altman-zscore.json"),
+    },
+    Example {
+        command: "amortization",
+        description: "$1,000 bullet loan at 10% over five periods",
+        input: include_str!("../../samples/amortization.json"),
+    },
+    Example {
+        command: "annualize",
+        description: "Run-rate annualisation of an interim period",
+        input: include_str!("../../samples/annualize.json"),
+    },
+    Example {
+        command: "apv",
+        description: "APV on five years of unlevered FCF with an amortising debt schedule, distress costs and two financing side effects",
+        input: include_str!("../../samples/apv.json"),
+    },
+    Example {
+        command: "asr-buyback",
+        description: "$1,000 accelerated share repurchase with 80% initial delivery over 60 days",
+        input: include_str!("../../samples/asr-buyback.json"),
+    },
+    Example {
+        command: "asset-based-valuation",
+        description: "Distressed industrial under orderly and forced liquidation",
+        input: include_str!("../../samples/asset-based-valuation.json"),
+    },
+    Example {
+        command: "backlog-revenue",
+        description: "Single contract recognised over four periods",
+        input: include_str!("../../samples/backlog-revenue.json"),
+    },
+    Example {
+        command: "bank-valuation",
+        description: "Regional bank at 14% RoTE against three peers",
+        input: include_str!("../../samples/bank-valuation.json"),
+    },
+    Example {
+        command: "basis-analysis",
+        description: "Spot at 100 against a three-contract futures strip",
+        input: include_str!("../../samples/basis-analysis.json"),
+    },
+    Example {
+        command: "beneish",
+        description:
+            "Company with normal year-on-year movements in receivables, margins and accruals",
+        input: include_str!("../../samples/beneish.json"),
+    },
+    Example {
+        command: "benfords-law",
+        description: "Second-digit test on 208 data points",
+        input: include_str!("../../samples/benfords-law.json"),
+    },
+    Example {
+        command: "beps-compliance",
+        description: "US-parented group with three entities and two intercompany flows",
+        input: include_str!("../../samples/beps-compliance.json"),
+    },
+    Example {
+        command: "best-execution",
+        description: "Single buy trade measured against VWAP",
+        input: include_str!("../../samples/best-execution.json"),
+    },
+    Example {
+        command: "black-litterman",
+        description: "Two-asset equilibrium returns with no views",
+        input: include_str!("../../samples/black-litterman.json"),
+    },
+    Example {
+        command: "black-litterman-portfolio",
+        description: "Three-asset market portfolio with no views",
+        input: include_str!("../../samples/black-litterman-portfolio.json"),
+    },
+    Example {
+        command: "bond-pricing",
+        description: "5-year 5% semi-annual bond priced at a 6% yield",
+        input: include_str!("../../samples/bond-pricing.json"),
+    },
+    Example {
+        command: "bond-yield",
+        description: "10-year 5% semi-annual bond priced at par",
+        input: include_str!("../../samples/bond-yield.json"),
+    },
+    Example {
+        command: "bootstrap",
+        description: "Spot curve from two par instruments",
+        input: include_str!("../../samples/bootstrap.json"),
+    },
+    Example {
+        command: "breakeven",
+        description: "Widget selling at $50 with $30 variable cost and $10,000 fixed costs",
+        input: include_str!("../../samples/breakeven.json"),
+    },
+    Example {
+        command: "bridge-financing",
+        description: "$1B bridge commitment with three takeout scenarios",
+        input: include_str!("../../samples/bridge-financing.json"),
+    },
+    Example {
+        command: "brinson",
+        description: "Three-sector portfolio against its benchmark",
+        input: include_str!("../../samples/brinson.json"),
+    },
+    Example {
+        command: "buyback",
+        description: "$500 cash-funded buyback at $50 a share",
+        input: include_str!("../../samples/buyback.json"),
+    },
+    Example {
+        command: "calendarize",
+        description: "March year-end company calendarised to December 2024",
+        input: include_str!("../../samples/calendarize.json"),
+    },
+    Example {
+        command: "camels-rating",
+        description: "Well-capitalised bank",
+        input: include_str!("../../samples/camels-rating.json"),
+    },
+    Example {
+        command: "capital-account-statements",
+        description:
+            "$100M buyout fund: two LPs and a GP over two quarters with fees, gains and carry",
+        input: include_str!("../../samples/capital-account-statements.json"),
+    },
+    Example {
+        command: "capital-controls",
+        description: "$50M investment in India under moderate capital controls",
+        input: include_str!("../../samples/capital-controls.json"),
+    },
+    Example {
+        command: "carbon-footprint",
+        description: "$500M revenue company with Scope 1-3 emissions and a 42% reduction target",
+        input: include_str!("../../samples/carbon-footprint.json"),
+    },
+    Example {
+        command: "carbon-pricing",
+        description: "Verra renewable energy credit at $50 spot",
+        input: include_str!("../../samples/carbon-pricing.json"),
+    },
+    Example {
+        command: "cash-flow-forecast",
+        description: "One buyout commitment projected 15 years from 2024",
+        input: include_str!("../../samples/cash-flow-forecast.json"),
+    },
+    Example {
+        command: "cash-management",
+        description:
+            "Twelve months of operating cash flows against a $500K buffer and a $2M facility",
+        input: include_str!("../../samples/cash-management.json"),
+    },
+    Example {
+        command: "cashflow-ladder",
+        description: "Bonds and a loan book against a lease, term deposits and a pension annuity",
+        input: include_str!("../../samples/cashflow-ladder.json"),
+    },
+    Example {
+        command: "cayman-fund",
+        description: "$500M Cayman SPC hedge fund with 2/20 fees",
+        input: include_str!("../../samples/cayman-fund.json"),
+    },
+    Example {
+        command: "cbam",
+        description: "Imported steel under the EU CBAM at an $80 ETS price",
+        input: include_str!("../../samples/cbam.json"),
+    },
+    Example {
+        command: "cds-pricing",
+        description: "5-year CDS on Acme Corp at 100bp with 40% recovery",
+        input: include_str!("../../samples/cds-pricing.json"),
+    },
+    Example {
+        command: "cecl-provisioning",
+        description: "Single loan segment under base, adverse and severe scenarios",
+        input: include_str!("../../samples/cecl-provisioning.json"),
+    },
+    Example {
+        command: "clo-coverage",
+        description: "Four-tranche CLO OC and IC tests on a $500M pool",
+        input: include_str!("../../samples/clo-coverage.json"),
+    },
+    Example {
+        command: "clo-portfolio-metrics",
+        description: "Two-loan tape: WARF, WAS and WAL",
+        input: include_str!("../../samples/clo-portfolio-metrics.json"),
+    },
+    Example {
+        command: "clo-reinvestment",
+        description: "Five-asset pool tested against WARF, WAS, WAL and diversity limits",
+        input: include_str!("../../samples/clo-reinvestment.json"),
+    },
+    Example {
+        command: "clo-reinvestment-optimize",
+        description: "Reinvest $12M across three candidate loans to maximise spread",
+        input: include_str!("../../samples/clo-reinvestment-optimize.json"),
+    },
+    Example {
+        command: "clo-scenario",
+        description: "Five-tranche CLO under three default and prepayment scenarios",
+        input: include_str!("../../samples/clo-scenario.json"),
+    },
+    Example {
+        command: "clo-tranche",
+        description: "AAA tranche cash flows with a call at period 8",
+        input: include_str!("../../samples/clo-tranche.json"),
+    },
+    Example {
+        command: "clo-waterfall",
+        description: "Five-tranche CLO on a $900M pool at 2% CDR and 10% CPR",
+        input: include_str!("../../samples/clo-waterfall.json"),
+    },
+    Example {
+        command: "cmbs",
+        description: "Single-loan CMBS stressed by a higher exit cap rate",
+        input: include_str!("../../samples/cmbs.json"),
+    },
+    Example {
+        command: "co-investment",
+        description: "Co-investment alongside a fund, screened against concentration limits",
+        input: include_str!("../../samples/co-investment.json"),
+    },
+    Example {
+        command: "collateral-analytics",
+        description: "Haircut model for a repo collateral pool",
+        input: include_str!("../../samples/collateral-analytics.json"),
+    },
+    Example {
+        command: "combined-ratio",
+        description: "Profitable single-year underwriting result",
+        input: include_str!("../../samples/combined-ratio.json"),
+    },
+    Example {
+        command: "commitment-pacing",
+        description: "Two existing funds and $25M of new commitments a year over five years",
+        input: include_str!("../../samples/commitment-pacing.json"),
+    },
+    Example {
+        command: "commodity-curve",
+        description: "Contango futures curve from a spot of 100",
+        input: include_str!("../../samples/commodity-curve.json"),
+    },
+    Example {
+        command: "commodity-forward",
+        description: "One-year gold forward at a $1,900 spot",
+        input: include_str!("../../samples/commodity-forward.json"),
+    },
+    Example {
+        command: "commodity-spread",
+        description: "3-2-1 crack spread",
+        input: include_str!("../../samples/commodity-spread.json"),
+    },
+    Example {
+        command: "comps",
+        description: "TargetCo against four comparables on four multiples",
+        input: include_str!("../../samples/comps.json"),
+    },
+    Example {
+        command: "concentrated-stock",
+        description: "$1M concentrated position with an $200K cost basis",
+        input: include_str!("../../samples/concentrated-stock.json"),
+    },
+    Example {
+        command: "concession",
+        description: "20-year toll road concession",
+        input: include_str!("../../samples/concession.json"),
+    },
+    Example {
+        command: "convertible-analysis",
+        description: "5-year 4% convertible across six stock price scenarios",
+        input: include_str!("../../samples/convertible-analysis.json"),
+    },
+    Example {
+        command: "convertible-book",
+        description: "Two convertibles and a callable senior bond priced on one 50-step lattice",
+        input: include_str!("../../samples/convertible-book.json"),
+    },
+    Example {
+        command: "convertible-note",
+        description:
+            "$100K note with a 20% discount and $5M cap converting in a qualified financing",
+        input: include_str!("../../samples/convertible-note.json"),
+    },
+    Example {
+        command: "convertible-pricing",
+        description: "5-year 4% convertible priced on a 50-step tree",
+        input: include_str!("../../samples/convertible-pricing.json"),
+    },
+    Example {
+        command: "cost-of-equity",
+        description: "Hamada relevered median beta of three peers",
+        input: include_str!("../../samples/cost-of-equity.json"),
+    },
+    Example {
+        command: "counterparty-exposure",
+        description: "Single FX forward under the supervisory add-on",
+        input: include_str!("../../samples/counterparty-exposure.json"),
+    },
+    Example {
+        command: "country-risk",
+        description: "AAA-rated country (Switzerland)",
+        input: include_str!("../../samples/country-risk.json"),
+    },
+    Example {
+        command: "country-risk-premium",
+        description: "Baa2 sovereign at 250bp with relative equity volatility",
+        input: include_str!("../../samples/country-risk-premium.json"),
+    },
+    Example {
+        command: "covenant-projection",
+        description: "Leverage covenant stepping down after a named quarter",
+        input: include_str!("../../samples/covenant-projection.json"),
+    },
+    Example {
+        command: "covenant-test",
+        description: "Maximum net debt / EBITDA covenant of 3.5x",
+        input: include_str!("../../samples/covenant-test.json"),
+    },
+    Example {
+        command: "credit-metrics",
+        description: "Mid-sized borrower with full leverage, coverage and liquidity inputs",
+        input: include_str!("../../samples/credit-metrics.json"),
+    },
+    Example {
+        command: "credit-migration",
+        description: "Single BBB exposure over a one-year horizon",
+        input: include_str!("../../samples/credit-migration.json"),
+    },
+    Example {
+        command: "credit-scorecard",
+        description: "Three-bin scorecard scaled to 600 points at 50:1 odds",
+        input: include_str!("../../samples/credit-scorecard.json"),
+    },
+    Example {
+        command: "credit-spread",
+        description: "5-year 4% bond at 95 against a benchmark curve",
+        input: include_str!("../../samples/credit-spread.json"),
+    },
+    Example {
+        command: "cross-rate",
+        description: "EUR/JPY cross from USD/EUR and JPY/USD",
+        input: include_str!("../../samples/cross-rate.json"),
+    },
+    Example {
+        command: "currency-swap",
+        description: "5-year fixed-fixed currency swap with domestic and foreign curves",
+        input: include_str!("../../samples/currency-swap.json"),
+    },
+    Example {
+        command: "cva-calculation",
+        description: "5-year interest rate swap against a 2% PD counterparty",
+        input: include_str!("../../samples/cva-calculation.json"),
+    },
+    Example {
+        command: "dcf",
+        description: "Ten-year Gordon growth DCF on $1,000 of base revenue",
+        input: include_str!("../../samples/dcf.json"),
+    },
+    Example {
+        command: "deal-model",
+        description:
+            "8.0x buyout with senior and subordinated debt, a leverage covenant and an IRR grid",
+        input: include_str!("../../samples/deal-model.json"),
+    },
+    Example {
+        command: "debt-capacity",
+        description: "$100K EBITDA borrower sized against leverage, coverage, DSCR and FFO limits",
+        input: include_str!("../../samples/debt-capacity.json"),
+    },
+    Example {
+        command: "debt-maturity",
+        description: "Single facility refinanced against a 5.0x leverage cap",
+        input: include_str!("../../samples/debt-maturity.json"),
+    },
+    Example {
+        command: "decision-tree",
+        description: "Five-node decision tree",
+        input: include_str!("../../samples/decision-tree.json"),
+    },
+    Example {
+        command: "defi-analysis",
+        description: "Yield farm at 12% APR compounded daily",
+        input: include_str!("../../samples/defi-analysis.json"),
+    },
+    Example {
+        command: "deposit-beta",
+        description: "Deposit rates fully passing through benchmark changes",
+        input: include_str!("../../samples/deposit-beta.json"),
+    },
+    Example {
+        command: "dilution",
+        description: "Three funding rounds with option pool top-ups",
+        input: include_str!("../../samples/dilution.json"),
+    },
+    Example {
+        command: "direct-indexing",
+        description: "$1M direct index of seven holdings harvested for losses",
+        input: include_str!("../../samples/direct-indexing.json"),
+    },
+    Example {
+        command: "direct-loan",
+        description: "$100M interest-only senior secured loan at SOFR + 550bp",
+        input: include_str!("../../samples/direct-loan.json"),
+    },
+    Example {
+        command: "distressed-debt",
+        description: "Two-tranche capital structure through a restructuring",
+        input: include_str!("../../samples/distressed-debt.json"),
+    },
+    Example {
+        command: "dupont-analysis",
+        description: "Five-step DuPont decomposition",
+        input: include_str!("../../samples/dupont-analysis.json"),
+    },
+    Example {
+        command: "duration",
+        description: "10-year 5% semi-annual bond at par",
+        input: include_str!("../../samples/duration.json"),
+    },
+    Example {
+        command: "earnings-quality-composite",
+        description: "High-quality earnings profile",
+        input: include_str!("../../samples/earnings-quality-composite.json"),
+    },
+    Example {
+        command: "earnout",
+        description: "Two-year EBITDA earn-out with binary and linear payoffs",
+        input: include_str!("../../samples/earnout.json"),
+    },
+    Example {
+        command: "economic-capital",
+        description: "Simulated loss distribution at 99.9% with three stress scenarios",
+        input: include_str!("../../samples/economic-capital.json"),
+    },
+    Example {
+        command: "economic-profit",
+        description: "EVA valuation of the same ten-year DCF with $650 of opening invested capital",
+        input: include_str!("../../samples/economic-profit.json"),
+    },
+    Example {
+        command: "economic-substance",
+        description: "Cayman holding company",
+        input: include_str!("../../samples/economic-substance.json"),
+    },
+    Example {
+        command: "em-bond-analysis",
+        description: "Local versus hard-currency sovereign bond",
+        input: include_str!("../../samples/em-bond-analysis.json"),
+    },
+    Example {
+        command: "em-equity-premium",
+        description: "Emerging market equity premium from sovereign spread and relative volatility",
+        input: include_str!("../../samples/em-equity-premium.json"),
+    },
+    Example {
+        command: "entity-classification",
+        description: "Cayman holding company classified for FATCA and CRS",
+        input: include_str!("../../samples/entity-classification.json"),
+    },
+    Example {
+        command: "esg-score",
+        description: "High-scoring technology company",
+        input: include_str!("../../samples/esg-score.json"),
+    },
+    Example {
+        command: "estate-plan",
+        description: "$25M estate with two annual gifting programmes",
+        input: include_str!("../../samples/estate-plan.json"),
+    },
+    Example {
+        command: "ets-compliance",
+        description: "100,000 t of verified emissions against 80,000 allowances held",
+        input: include_str!("../../samples/ets-compliance.json"),
+    },
+    Example {
+        command: "euler-allocation",
+        description: "Two business units sharing a portfolio VaR of 150",
+        input: include_str!("../../samples/euler-allocation.json"),
+    },
+    Example {
+        command: "event-study",
+        description: "Three events under the market model with a [-1, +1] window",
+        input: include_str!("../../samples/event-study.json"),
+    },
+    Example {
+        command: "exotic-product",
+        description: "Down-and-out call with a distant barrier",
+        input: include_str!("../../samples/exotic-product.json"),
+    },
+    Example {
+        command: "factor-attribution",
+        description: "Three-factor attribution of 4% active return",
+        input: include_str!("../../samples/factor-attribution.json"),
+    },
+    Example {
+        command: "factor-model",
+        description: "CAPM regression over twelve months",
+        input: include_str!("../../samples/factor-model.json"),
+    },
+    Example {
+        command: "factor-risk-budget",
+        description: "Two assets on a single factor",
+        input: include_str!("../../samples/factor-risk-budget.json"),
+    },
+    Example {
+        command: "family-governance",
+        description: "Three-generation family with a full governance framework",
+        input: include_str!("../../samples/family-governance.json"),
+    },
+    Example {
+        command: "fatca-crs-reporting",
+        description: "German bank reporting 500 accounts under a Model 1 IGA",
+        input: include_str!("../../samples/fatca-crs-reporting.json"),
+    },
+    Example {
+        command: "fof-portfolio",
+        description: "Five-fund programme tested against strategy, vintage and geography limits",
+        input: include_str!("../../samples/fof-portfolio.json"),
+    },
+    Example {
+        command: "forward-position",
+        description: "Long forward marked after the spot rises from 100 to 110",
+        input: include_str!("../../samples/forward-position.json"),
+    },
+    Example {
+        command: "forward-price",
+        description: "One-year equity forward with no dividends",
+        input: include_str!("../../samples/forward-price.json"),
+    },
+    Example {
+        command: "franchise",
+        description: "QSR roll-out of 70 units: 6% royalty, 2% marketing fund, 10-year term",
+        input: include_str!("../../samples/franchise.json"),
+    },
+    Example {
+        command: "fund-fees",
+        description: "$500M European-waterfall fund with 2/20 and an 8% hurdle",
+        input: include_str!("../../samples/fund-fees.json"),
+    },
+    Example {
+        command: "fund-finance",
+        description: "$250M NAV facility with $150M drawn",
+        input: include_str!("../../samples/fund-finance.json"),
+    },
+    Example {
+        command: "funding-round",
+        description: "$1M Series A at a $5M pre-money valuation",
+        input: include_str!("../../samples/funding-round.json"),
+    },
+    Example {
+        command: "fx-forward",
+        description: "One-year USD/EUR deliverable forward",
+        input: include_str!("../../samples/fx-forward.json"),
+    },
+    Example {
+        command: "gaap-ifrs",
+        description: "US GAAP financials restated under IFRS",
+        input: include_str!("../../samples/gaap-ifrs.json"),
+    },
+    Example {
+        command: "gips-report",
+        description: "Single-period GIPS composite report",
+        input: include_str!("../../samples/gips-report.json"),
+    },
+    Example {
+        command: "gp-economics",
+        description: "$500M fund with 2/20 and an 8% hurdle",
+        input: include_str!("../../samples/gp-economics.json"),
+    },
+    Example {
+        command: "green-bond",
+        description: "$500M 10-year green bond with four use-of-proceeds projects",
+        input: include_str!("../../samples/green-bond.json"),
+    },
+    Example {
+        command: "h-model-ddm",
+        description: "Dividend growth fading from 15% to 4% over a five-year half-life",
+        input: include_str!("../../samples/h-model-ddm.json"),
+    },
+    Example {
+        command: "hedge-effectiveness",
+        description: "$10M EUR cash flow hedge with a forward over six periods",
+        input: include_str!("../../samples/hedge-effectiveness.json"),
+    },
+    Example {
+        command: "heston-calibration",
+        description: "Heston calibration to a 15-point call surface",
+        input: include_str!("../../samples/heston-calibration.json"),
+    },
+    Example {
+        command: "heston-price",
+        description: "Four options under Heston with low vol-of-vol",
+        input: include_str!("../../samples/heston-price.json"),
+    },
+    Example {
+        command: "hrp",
+        description: "Hierarchical risk parity across four assets in two blocks",
+        input: include_str!("../../samples/hrp.json"),
+    },
+    Example {
+        command: "implied-vol",
+        description: "At-the-money one-year call implied volatility",
+        input: include_str!("../../samples/implied-vol.json"),
+    },
+    Example {
+        command: "implied-vol-surface",
+        description: "15 quotes across five strikes and three expiries",
+        input: include_str!("../../samples/implied-vol-surface.json"),
+    },
+    Example {
+        command: "index-rebalancing",
+        description: "Quarterly rebalance of a five-stock index",
+        input: include_str!("../../samples/index-rebalancing.json"),
+    },
+    Example {
+        command: "index-reconstitution",
+        description: "Five members and three candidates screened at reconstitution",
+        input: include_str!("../../samples/index-reconstitution.json"),
+    },
+    Example {
+        command: "index-weighting",
+        description: "Market-cap weights for three stocks capped at 40%",
+        input: include_str!("../../samples/index-weighting.json"),
+    },
+    Example {
+        command: "inflation-derivative",
+        description: "Zero-coupon inflation swap",
+        input: include_str!("../../samples/inflation-derivative.json"),
+    },
+    Example {
+        command: "intensity-model",
+        description: "Hazard rates bootstrapped from a five-point CDS curve",
+        input: include_str!("../../samples/intensity-model.json"),
+    },
+    Example {
+        command: "intercompany",
+        description: "TNMM test of a distribution agreement against seven comparables",
+        input: include_str!("../../samples/intercompany.json"),
+    },
+    Example {
+        command: "interim-nav",
+        description: "Roll a June NAV forward to September with public comps",
+        input: include_str!("../../samples/interim-nav.json"),
+    },
+    Example {
+        command: "international",
+        description: "USD/EUR parity conditions",
+        input: include_str!("../../samples/international.json"),
+    },
+    Example {
+        command: "inventory-optimization",
+        description: "Safety stock and reorder point for one SKU",
+        input: include_str!("../../samples/inventory-optimization.json"),
+    },
+    Example {
+        command: "investor-net-returns",
+        description: "15% gross return net of 2/20 fees over five years",
+        input: include_str!("../../samples/investor-net-returns.json"),
+    },
+    Example {
+        command: "ipo",
+        description: "IPO priced off comps with a 15% IPO discount",
+        input: include_str!("../../samples/ipo.json"),
+    },
+    Example {
+        command: "irs",
+        description: "Two-year pay-fixed interest rate swap",
+        input: include_str!("../../samples/irs.json"),
+    },
+    Example {
+        command: "j-curve",
+        description: "$100M fund J-curve over ten years",
+        input: include_str!("../../samples/j-curve.json"),
+    },
+    Example {
+        command: "jurisdiction-substance-test",
+        description: "Holding company substance tested in one jurisdiction",
+        input: include_str!("../../samples/jurisdiction-substance-test.json"),
+    },
+    Example {
+        command: "kelly",
+        description: "60% win rate at even odds sized at half-Kelly",
+        input: include_str!("../../samples/kelly.json"),
+    },
+    Example {
+        command: "kyc-risk",
+        description: "UK individual customer risk score",
+        input: include_str!("../../samples/kyc-risk.json"),
+    },
+    Example {
+        command: "lbo",
+        description: "5-year LBO at 5.0x entry with a single amortising term loan",
+        input: include_str!("../../samples/lbo.json"),
+    },
+    Example {
+        command: "lcr",
+        description: "Liquidity coverage ratio for a simple bank",
+        input: include_str!("../../samples/lcr.json"),
+    },
+    Example {
+        command: "ldi-strategy",
+        description: "Pension plan with 12-year liability duration hedged by LDI",
+        input: include_str!("../../samples/ldi-strategy.json"),
+    },
+    Example {
+        command: "lease-classification",
+        description: "Five-year office lease under ASC 842",
+        input: include_str!("../../samples/lease-classification.json"),
+    },
+    Example {
+        command: "letter-of-credit",
+        description: "$1M 180-day commercial letter of credit",
+        input: include_str!("../../samples/letter-of-credit.json"),
+    },
+    Example {
+        command: "limit-management",
+        description: "Five risk limits with warning thresholds",
+        input: include_str!("../../samples/limit-management.json"),
+    },
+    Example {
+        command: "loan-apr",
+        description: "$200K 30-year mortgage with one point",
+        input: include_str!("../../samples/loan-apr.json"),
+    },
+    Example {
+        command: "loan-book",
+        description: "Five-loan book diversified by sector and geography",
+        input: include_str!("../../samples/loan-book.json"),
+    },
+    Example {
+        command: "loan-pricing",
+        description: "$500M B-rated term loan B priced off a clearing grid",
+        input: include_str!("../../samples/loan-pricing.json"),
+    },
+    Example {
+        command: "ltm",
+        description: "LTM build from annual and interim periods",
+        input: include_str!("../../samples/ltm.json"),
+    },
+    Example {
+        command: "lux-fund",
+        description: "$500M Luxembourg SICAV-SIF",
+        input: include_str!("../../samples/lux-fund.json"),
+    },
+    Example {
+        command: "manager-selection",
+        description: "Four-fund manager track record against benchmark quartiles",
+        input: include_str!("../../samples/manager-selection.json"),
+    },
+    Example {
+        command: "mbs-analytics",
+        description: "Agency pass-through cash flows",
+        input: include_str!("../../samples/mbs-analytics.json"),
+    },
+    Example {
+        command: "mc-dcf",
+        description: "Monte Carlo DCF with normal revenue growth, margin, WACC and terminal growth",
+        input: include_str!("../../samples/mc-dcf.json"),
+    },
+    Example {
+        command: "mean-variance-opt",
+        description: "Unconstrained two-asset efficient frontier",
+        input: include_str!("../../samples/mean-variance-opt.json"),
+    },
+    Example {
+        command: "merger",
+        description: "All-cash acquisition at a 25% premium",
+        input: include_str!("../../samples/merger.json"),
+    },
+    Example {
+        command: "merger-arb",
+        description: "Cash deal at $50 with the target trading at $48",
+        input: include_str!("../../samples/merger-arb.json"),
+    },
+    Example {
+        command: "merton-pd",
+        description: "Merton default probability for equity of 100 and debt of 80",
+        input: include_str!("../../samples/merton-pd.json"),
+    },
+    Example {
+        command: "model-to-dcf",
+        description: "Three-year three-statement model valued with a Gordon growth DCF",
+        input: include_str!("../../samples/model-to-dcf.json"),
+    },
+    Example {
+        command: "momentum",
+        description: "Twelve-one momentum across ten assets, top three held",
+        input: include_str!("../../samples/momentum.json"),
+    },
+    Example {
+        command: "monetary-policy",
+        description: "US Taylor rule with inflation at 3.5%",
+        input: include_str!("../../samples/monetary-policy.json"),
+    },
+    Example {
+        command: "monte-carlo",
+        description: "10,000 simulations of one variable with variance reduction",
+        input: include_str!("../../samples/monte-carlo.json"),
+    },
+    Example {
+        command: "multi-target-merger",
+        description: "Acquisition programme of two stock-funded targets with synergies",
+        input: include_str!("../../samples/multi-target-merger.json"),
+    },
+    Example {
+        command: "multistage-ddm",
+        description: "Single-stage dividend growth before a 3% terminal rate",
+        input: include_str!("../../samples/multistage-ddm.json"),
+    },
+    Example {
+        command: "muni-analysis",
+        description: "General obligation credit for the City of Springfield",
+        input: include_str!("../../samples/muni-analysis.json"),
+    },
+    Example {
+        command: "muni-bond",
+        description: "10-year 5% tax-exempt GO bond",
+        input: include_str!("../../samples/muni-bond.json"),
+    },
+    Example {
+        command: "nav",
+        description: "Single share class NAV with a 10% gross return",
+        input: include_str!("../../samples/nav.json"),
+    },
+    Example {
+        command: "nelson-siegel",
+        description: "Nelson-Siegel fit to a flat curve",
+        input: include_str!("../../samples/nelson-siegel.json"),
+    },
+    Example {
+        command: "nim-analysis",
+        description: "Net interest margin and its drivers for a $1B balance sheet",
+        input: include_str!("../../samples/nim-analysis.json"),
+    },
+    Example {
+        command: "nsfr",
+        description: "Net stable funding ratio for a simple bank",
+        input: include_str!("../../samples/nsfr.json"),
+    },
+    Example {
+        command: "offset-valuation",
+        description: "Verra renewable energy offset with co-benefits",
+        input: include_str!("../../samples/offset-valuation.json"),
+    },
+    Example {
+        command: "open-market-buyback",
+        description: "$1,000 open market programme capped at 25% of volume",
+        input: include_str!("../../samples/open-market-buyback.json"),
+    },
+    Example {
+        command: "optimal-execution",
+        description: "TWAP execution of a 10,000 share buy order",
+        input: include_str!("../../samples/optimal-execution.json"),
+    },
+    Example {
+        command: "option-price",
+        description: "At-the-money one-year European call",
+        input: include_str!("../../samples/option-price.json"),
+    },
+    Example {
+        command: "pacing",
+        description: "Existing buyout commitment and two planned vintages",
+        input: include_str!("../../samples/pacing.json"),
+    },
+    Example {
+        command: "pairs-trading",
+        description: "AAPL/MSFT pairs trade over 60 days",
+        input: include_str!("../../samples/pairs-trading.json"),
+    },
+    Example {
+        command: "payout-optimizer",
+        description: "Dividend versus buyback mix within a leverage limit",
+        input: include_str!("../../samples/payout-optimizer.json"),
+    },
+    Example {
+        command: "payout-sustainability",
+        description: "Dividend covered by earnings and free cash flow",
+        input: include_str!("../../samples/payout-sustainability.json"),
+    },
+    Example {
+        command: "pd-calibration",
+        description: "Through-the-cycle 2% PD converted to point-in-time",
+        input: include_str!("../../samples/pd-calibration.json"),
+    },
+    Example {
+        command: "peer-benchmarking",
+        description: "Sector leader against four peers",
+        input: include_str!("../../samples/peer-benchmarking.json"),
+    },
+    Example {
+        command: "peer-screen",
+        description: "Target screened against a five-company universe",
+        input: include_str!("../../samples/peer-screen.json"),
+    },
+    Example {
+        command: "pension-funding",
+        description: "Projected benefit obligation funding status",
+        input: include_str!("../../samples/pension-funding.json"),
+    },
+    Example {
+        command: "philanthropic-vehicles",
+        description: "$500K donation of appreciated stock across giving vehicles",
+        input: include_str!("../../samples/philanthropic-vehicles.json"),
+    },
+    Example {
+        command: "piotroski",
+        description: "Strong Piotroski F-score",
+        input: include_str!("../../samples/piotroski.json"),
+    },
+    Example {
+        command: "political-risk",
+        description: "Brazil political risk score",
+        input: include_str!("../../samples/political-risk.json"),
+    },
+    Example {
+        command: "portfolio-credit-risk",
+        description: "Single exposure at 99% confidence",
+        input: include_str!("../../samples/portfolio-credit-risk.json"),
+    },
+    Example {
+        command: "portfolio-valuation",
+        description:
+            "Bond, shares, short calls, payer swap, convertible and a EUR note on one market set",
+        input: include_str!("../../samples/portfolio-valuation.json"),
+    },
+    Example {
+        command: "ppa",
+        description: "$1,000 purchase price allocated with step-ups and goodwill",
+        input: include_str!("../../samples/ppa.json"),
+    },
+    Example {
+        command: "ppp-model",
+        description: "30-year availability-payment highway PPP",
+        input: include_str!("../../samples/ppp-model.json"),
+    },
+    Example {
+        command: "premium-pricing",
+        description: "Motor insurance premium from frequency and severity",
+        input: include_str!("../../samples/premium-pricing.json"),
+    },
+    Example {
+        command: "prepayment",
+        description: "100% PSA prepayment schedule",
+        input: include_str!("../../samples/prepayment.json"),
+    },
+    Example {
+        command: "project-finance",
+        description: "$100M solar farm with a two-year build and 20 years of operation",
+        input: include_str!("../../samples/project-finance.json"),
+    },
+    Example {
+        command: "project-selection",
+        description: "Capital rationing where the exact optimum beats greedy ranking",
+        input: include_str!("../../samples/project-selection.json"),
+    },
+    Example {
+        command: "property-valuation",
+        description: "Apartment building valued by direct cap, DCF and comparables",
+        input: include_str!("../../samples/property-valuation.json"),
+    },
+    Example {
+        command: "prospect-theory",
+        description: "Three-outcome prospect with a 2.25 loss aversion",
+        input: include_str!("../../samples/prospect-theory.json"),
+    },
+    Example {
+        command: "purchase-price",
+        description: "Locked box versus completion accounts on a $500 deal",
+        input: include_str!("../../samples/purchase-price.json"),
+    },
+    Example {
+        command: "raroc",
+        description: "RAROC against a 12% hurdle",
+        input: include_str!("../../samples/raroc.json"),
+    },
+    Example {
+        command: "rate-consistency",
+        description: "USD nominal WACC applied to a EUR forecast with 3.5% embedded inflation",
+        input: include_str!("../../samples/rate-consistency.json"),
+    },
+    Example {
+        command: "real-option",
+        description: "Option to defer a project with 30% volatility",
+        input: include_str!("../../samples/real-option.json"),
+    },
+    Example {
+        command: "recovery",
+        description: "Two-claim going-concern recovery waterfall",
+        input: include_str!("../../samples/recovery.json"),
+    },
+    Example {
+        command: "red-flag-scoring",
+        description: "Clean forensic red flag profile",
+        input: include_str!("../../samples/red-flag-scoring.json"),
+    },
+    Example {
+        command: "redemption-stress",
+        description: "$500M credit fund: three redeeming investors through fund and investor gates, a soft lock-up and a side pocket",
+        input: include_str!("../../samples/redemption-stress.json"),
+    },
+    Example {
+        command: "refinance",
+        description: "Refinancing a $250K mortgage from 7.5% to 6%",
+        input: include_str!("../../samples/refinance.json"),
+    },
+    Example {
+        command: "regulatory-capital",
+        description: "Single corporate exposure at a 100% risk weight",
+        input: include_str!("../../samples/regulatory-capital.json"),
+    },
+    Example {
+        command: "reit",
+        description: "Office REIT FFO and AFFO",
+        input: include_str!("../../samples/reit.json"),
+    },
+    Example {
+        command: "repo-analytics",
+        description: "Repo rate model",
+        input: include_str!("../../samples/repo-analytics.json"),
+    },
+    Example {
+        command: "reserving",
+        description: "Chain-ladder reserves on an auto liability triangle",
+        input: include_str!("../../samples/reserving.json"),
+    },
+    Example {
+        command: "retail-loan",
+        description:
+            "5/1 ARM mortgage with monthly overpayments and a lump sum against the baseline",
+        input: include_str!("../../samples/retail-loan.json"),
+    },
+    Example {
+        command: "retirement",
+        description: "35-year-old saving $20K a year towards retirement at 65",
+        input: include_str!("../../samples/retirement.json"),
+    },
+    Example {
+        command: "returns",
+        description: "5-year LBO with dividend recaps (LBO reference fixture)",
+        input: include_str!("../../samples/returns.json"),
+    },
+    Example {
+        command: "revaluation-var",
+        description: "Linear book under 41 historical scenarios",
+        input: include_str!("../../samples/revaluation-var.json"),
+    },
+    Example {
+        command: "revenue-quality",
+        description: "Receivables and deferred revenue moving with revenue",
+        input: include_str!("../../samples/revenue-quality.json"),
+    },
+    Example {
+        command: "reverse-dcf",
+        description: "Growth implied by a $50 share price",
+        input: include_str!("../../samples/reverse-dcf.json"),
+    },
+    Example {
+        command: "rights-issue",
+        description: "1-for-4 rights issue at $4 against a $6 cum-rights price",
+        input: include_str!("../../samples/rights-issue.json"),
+    },
+    Example {
+        command: "risk",
+        description: "VaR and CVaR of twelve monthly returns",
+        input: include_str!("../../samples/risk.json"),
+    },
+    Example {
+        command: "risk-parity",
+        description: "Inverse-volatility weights for two assets",
+        input: include_str!("../../samples/risk-parity.json"),
+    },
+    Example {
+        command: "rolling-forecast",
+        description: "Three-period rolling forecast from four historical periods",
+        input: include_str!("../../samples/rolling-forecast.json"),
+    },
+    Example {
+        command: "saas-metrics",
+        description: "Three quarters of ARR movements",
+        input: include_str!("../../samples/saas-metrics.json"),
+    },
+    Example {
+        command: "sabr-calibration",
+        description: "SABR fit to nine strikes with beta 0.5",
+        input: include_str!("../../samples/sabr-calibration.json"),
+    },
+    Example {
+        command: "saccr",
+        description: "SA-CCR EAD of a single 10-year receiver swap",
+        input: include_str!("../../samples/saccr.json"),
+    },
+    Example {
+        command: "safe",
+        description: "$500K pre-money SAFE with a $5M cap and 20% discount",
+        input: include_str!("../../samples/safe.json"),
+    },
+    Example {
+        command: "sale-leaseback",
+        description: "Office building sale-leaseback under ASC 842",
+        input: include_str!("../../samples/sale-leaseback.json"),
+    },
+    Example {
+        command: "sanctions-screening",
+        description: "Onboarding screen of one entity against four lists",
+        input: include_str!("../../samples/sanctions-screening.json"),
+    },
+    Example {
+        command: "scoring-validation",
+        description: "Discrimination and calibration of 20 predictions",
+        input: include_str!("../../samples/scoring-validation.json"),
+    },
+    Example {
+        command: "scr",
+        description: "Solvency II SCR from premium, reserve, catastrophe and market risk",
+        input: include_str!("../../samples/scr.json"),
+    },
+    Example {
+        command: "sec-cftc-reporting",
+        description: "Form PF and CPO-PQR data for a $2B adviser",
+        input: include_str!("../../samples/sec-cftc-reporting.json"),
+    },
+    Example {
+        command: "secondaries-pricing",
+        description: "$50M fund NAV with $10M unfunded priced at a 12% discount rate",
+        input: include_str!("../../samples/secondaries-pricing.json"),
+    },
+    Example {
+        command: "semi-liquid-stress",
+        description: "$2B open-end property fund: two redemption waves met from cash, facility and fire sales under a 5% gate",
+        input: include_str!("../../samples/semi-liquid-stress.json"),
+    },
+    Example {
+        command: "sentiment",
+        description: "S&P 500 sentiment indicators",
+        input: include_str!("../../samples/sentiment.json"),
+    },
+    Example {
+        command: "shadow-carbon",
+        description: "One project appraised with a $50 shadow carbon price",
+        input: include_str!("../../samples/shadow-carbon.json"),
+    },
+    Example {
+        command: "shapley-allocation",
+        description: "Shapley capital allocation across three units",
+        input: include_str!("../../samples/shapley-allocation.json"),
+    },
+    Example {
+        command: "sharpe",
+        description: "Sharpe ratio of twelve monthly returns",
+        input: include_str!("../../samples/sharpe.json"),
+    },
+    Example {
+        command: "shift-market-data",
+        description: "Risk-off scenario applied consistently to a market data set",
+        input: include_str!("../../samples/shift-market-data.json"),
+    },
+    Example {
+        command: "short-rate",
+        description: "Vasicek short-rate model",
+        input: include_str!("../../samples/short-rate.json"),
+    },
+    Example {
+        command: "sll",
+        description: "$200M sustainability-linked loan with three KPIs",
+        input: include_str!("../../samples/sll.json"),
+    },
+    Example {
+        command: "small-business",
+        description: "HVAC services business valued on seller's discretionary earnings",
+        input: include_str!("../../samples/small-business.json"),
+    },
+    Example {
+        command: "smart-beta",
+        description: "Five-stock multi-factor tilt",
+        input: include_str!("../../samples/smart-beta.json"),
+    },
+    Example {
+        command: "sotp",
+        description: "Three-segment technology company",
+        input: include_str!("../../samples/sotp.json"),
+    },
+    Example {
+        command: "sovereign-bond",
+        description: "10-year 5% Brazil USD sovereign bond",
+        input: include_str!("../../samples/sovereign-bond.json"),
+    },
+    Example {
+        command: "spread-analysis",
+        description: "Quoted spread from five trades and quotes",
+        input: include_str!("../../samples/spread-analysis.json"),
+    },
+    Example {
+        command: "storage-economics",
+        description: "Contango storage trade",
+        input: include_str!("../../samples/storage-economics.json"),
+    },
+    Example {
+        command: "strategy",
+        description: "Long call payoff",
+        input: include_str!("../../samples/strategy.json"),
+    },
+    Example {
+        command: "stress-test",
+        description: "Single-asset equity crash scenario",
+        input: include_str!("../../samples/stress-test.json"),
+    },
+    Example {
+        command: "structured-note",
+        description: "3-year fully capital-protected note",
+        input: include_str!("../../samples/structured-note.json"),
+    },
+    Example {
+        command: "style-analysis",
+        description: "48 months of fund returns against three style indices",
+        input: include_str!("../../samples/style-analysis.json"),
+    },
+    Example {
+        command: "supply-chain-finance",
+        description: "$1M reverse factoring on 90-day terms",
+        input: include_str!("../../samples/supply-chain-finance.json"),
+    },
+    Example {
+        command: "syndication",
+        description: "$500M facility with a $100M arranger hold and three members",
+        input: include_str!("../../samples/syndication.json"),
+    },
+    Example {
+        command: "tail-risk",
+        description: "Two-asset normal VaR and CVaR at 95%",
+        input: include_str!("../../samples/tail-risk.json"),
+    },
+    Example {
+        command: "target-price",
+        description: "Growth stock target price across methods",
+        input: include_str!("../../samples/target-price.json"),
+    },
+    Example {
+        command: "term-structure-fit",
+        description: "Nelson-Siegel term structure fit",
+        input: include_str!("../../samples/term-structure-fit.json"),
+    },
+    Example {
+        command: "three-statement",
+        description: "Three-year integrated income statement, balance sheet and cash flow",
+        input: include_str!("../../samples/three-statement.json"),
+    },
+    Example {
+        command: "tips-analytics",
+        description: "TIPS real yield and inflation-adjusted pricing",
+        input: include_str!("../../samples/tips-analytics.json"),
+    },
+    Example {
+        command: "tlh",
+        description: "$1M portfolio with four positions harvested for losses",
+        input: include_str!("../../samples/tlh.json"),
+    },
+    Example {
+        command: "token-valuation",
+        description: "Protocol token valued on network and revenue multiples",
+        input: include_str!("../../samples/token-valuation.json"),
+    },
+    Example {
+        command: "total-shareholder-return",
+        description: "One-year TSR with dividends and buybacks",
+        input: include_str!("../../samples/total-shareholder-return.json"),
+    },
+    Example {
+        command: "tracking-error",
+        description: "Twelve months of tracking error against a five-stock benchmark",
+        input: include_str!("../../samples/tracking-error.json"),
+    },
+    Example {
+        command: "tranching",
+        description: "Two-tranche securitisation of 1,000 of collateral",
+        input: include_str!("../../samples/tranching.json"),
+    },
+    Example {
+        command: "treaty-network",
+        description: "US to UK income flows through five intermediary jurisdictions",
+        input: include_str!("../../samples/treaty-network.json"),
+    },
+    Example {
+        command: "treaty-optimization",
+        description: "Two operating companies choosing a holding jurisdiction",
+        input: include_str!("../../samples/treaty-optimization.json"),
+    },
+    Example {
+        command: "ubti-screening",
+        description: "Tax-exempt investor in a leveraged operating business",
+        input: include_str!("../../samples/ubti-screening.json"),
+    },
+    Example {
+        command: "uk-eu-fund",
+        description: "$200M UK limited partnership PE fund",
+        input: include_str!("../../samples/uk-eu-fund.json"),
+    },
+    Example {
+        command: "unit-economics",
+        description: "One cohort with CAC, retention and ARPU",
+        input: include_str!("../../samples/unit-economics.json"),
+    },
+    Example {
+        command: "unitranche",
+        description: "$100M unitranche split 60/40 into first-out and last-out",
+        input: include_str!("../../samples/unitranche.json"),
+    },
+    Example {
+        command: "us-fund",
+        description: "$100M Delaware LP with 2/20 and an 8% preferred return",
+        input: include_str!("../../samples/us-fund.json"),
+    },
+    Example {
+        command: "valuation-adjustments",
+        description: "Marketable minority EV bridged to the equity value of a 100% interest",
+        input: include_str!("../../samples/valuation-adjustments.json"),
+    },
+    Example {
+        command: "variance",
+        description: "Q1 2024 budget versus actual revenue and cost variances",
+        input: include_str!("../../samples/variance.json"),
+    },
+    Example {
+        command: "venture-fund",
+        description: "$100M venture fund with three investments",
+        input: include_str!("../../samples/venture-fund.json"),
+    },
+    Example {
+        command: "wacc",
+        description: "Apple, January 2024 (Damodaran reference fixture)",
+        input: include_str!("../../samples/wacc.json"),
+    },
+    Example {
+        command: "waterfall",
+        description: "European waterfall: 8% preferred, 100% catch-up, 80/20 carry",
+        input: include_str!("../../samples/waterfall.json"),
+    },
+    Example {
+        command: "wealth-transfer",
+        description: "$50M estate transferred over ten years",
+        input: include_str!("../../samples/wealth-transfer.json"),
+    },
+    Example {
+        command: "wht",
+        description: "US dividend paid to a UK investor",
+        input: include_str!("../../samples/wht.json"),
+    },
+    Example {
+        command: "workflow-audit",
+        description: "Audit trail for an initiating coverage workflow run",
+        input: include_str!("../../samples/workflow-audit.json"),
+    },
+    Example {
+        command: "workflow-describe",
+        description: "Describe the initiating coverage workflow",
+        input: include_str!("../../samples/workflow-describe.json"),
+    },
+    Example {
+        command: "workflow-list",
+        description: "Equity research workflows",
+        input: include_str!("../../samples/workflow-list.json"),
+    },
+    Example {
+        command: "workflow-quality-check",
+        description: "Quality gates for a completed initiating coverage report",
+        input: include_str!("../../samples/workflow-quality-check.json"),
+    },
+    Example {
+        command: "workflow-validate",
+        description: "Check the inputs supplied for initiating coverage",
+        input: include_str!("../../samples/workflow-validate.json"),
+    },
+    Example {
+        command: "working-capital",
+        description: "Working capital days and cash tied up",
+        input: include_str!("../../samples/working-capital.json"),
+    },
+    Example {
+        command: "xva-calculation",
+        description: "CVA, DVA and FVA on a 5Y interest rate swap book",
+        input: include_str!("../../samples/xva-calculation.json"),
+    },
+    Example {
+        command: "zscore-models",
+        description: "Healthy company across Z-score variants",
+        input: include_str!("../../samples/zscore-models.json"),
+    },
+];
+
+#[derive(Args)]
+pub struct ExamplesArgs {
+    /// Command to print a sample input for (omit to list all examples)
+    #[arg(value_parser = PossibleValuesParser::new(EXAMPLES.iter().map(|e| e.command)))]
+    pub command: Option<String>,
+}
+
+/// Sample input JSON for `command`, ready to pass back via `--input`.
+pub fn sample_input(command: &str) -> Option<&'static str> {
+    EXAMPLES
+        .iter()
+        .find(|e| e.command == command)
+        .map(|e| e.input)
+}
+
+pub fn run_examples_list() -> Result<Value, Box<dyn std::error::Error>> {
+    let examples: Vec<Value> = EXAMPLES
+        .iter()
+        .map(|e| {
+            serde_json::json!({
+                "command": e.command,
+                "description": e.description,
+                "usage": format!(
+                    "cfa examples {0} > {0}.json && cfa {0} --input {0}.json",
+                    e.command
+                ),
+            })
+        })
+        .collect();
+    Ok(Value::Array(examples))
+}
@@ -1,8 +1,13 @@
 use clap::Args;
 use serde_json::Value;
 
+use corp_finance_core::fpa::backlog::{self, BacklogRevenueInput};
+use corp_finance_core::fpa::saas_metrics::{self, SaasMetricsInput};
+use corp_finance_core::fpa::unit_economics::{self, UnitEconomicsInput};
 use corp_finance_core::fpa::variance::{self, BreakevenInput, VarianceInput};
-use corp_finance_core::fpa::working_capital::{self, RollingForecastInput, WorkingCapitalInput};
+use corp_finance_core::fpa::working_capital::{
+    self, InventoryOptimizationInput, RollingForecastInput, WorkingCapitalInput,
+};
 
 use crate::input;
 
@@ -30,6 +35,14 @@ pub struct WorkingCapitalArgs {
     pub input: Option<String>,
 }
 
+/// Arguments for EOQ, safety stock and inventory financing optimisation
+#[derive(Args)]
+pub struct InventoryOptimizationArgs {
+    /// Path to JSON input file
+    #[arg(long)]
+    pub input: Option<String>,
+}
+
 /// Arguments for rolling forecast
 #[derive(Args)]
 pub struct RollingForecastArgs {
@@ -38,6 +51,30 @@ pub struct RollingForecastArgs {
     pub input: Option<String>,
 }
 
+/// Arguments for backlog and pipeline revenue recognition
+#[derive(Args)]
+pub struct BacklogRevenueArgs {
+    /// Path to JSON input file
+    #[arg(long)]
+    pub input: Option<String>,
+}
+
+/// Arguments for SaaS metrics analysis
+#[derive(Args)]
+pub struct SaasMetricsArgs {
+    /// Path to JSON input file
+    #[arg(long)]
+    pub input: Option<String>,
+}
+
+/// Arguments for cohort unit economics
+#[derive(Args)]
+pub struct UnitEconomicsArgs {
+    /// Path to JSON input file
+    #[arg(long)]
+    pub input: Option<String>,
+}
+
 pub fn run_variance(args: VarianceArgs) -> Result<Value, Box<dyn std::error::Error>> {
     let var_input: VarianceInput = if let Some(ref path) = args.input {
         input::file::read_json(path)?
@@ -74,6 +111,20 @@ pub fn run_working_capital(args: WorkingCapitalArgs) -> Result<Value, Box<dyn st
     Ok(serde_json::to_value(result)?)
 }
 
+pub fn run_inventory_optimization(
+    args: InventoryOptimizationArgs,
+) -> Result<Value, Box<dyn std::error::Error>> {
+    let inv_input: InventoryOptimizationInput = if let Some(ref path) = args.input {
+        input::file::read_json(path)?
+    } else if let Some(data) = input::stdin::read_stdin()? {
+        serde_json::from_value(data)?
+    } else {
+        return Err("--input <file.json> or stdin required for inventory optimization".into());
+    };
+    let result = working_capital::optimize_inventory(&inv_input)?;
+    Ok(serde_json::to_value(result)?)
+}
+
 pub fn run_rolling_forecast(
     args: RollingForecastArgs,
 ) -> Result<Value, Box<dyn std::error::Error>> {
@@ -87,3 +138,39 @@ pub fn run_rolling_forecast(
     let result = working_capital::build_rolling_forecast(&rf_input)?;
     Ok(serde_json::to_value(result)?)
 }
+
+pub fn run_backlog_revenue(args: BacklogRevenueArgs) -> Result<Value, Box<dyn std::error::Error>> {
+    let bl_input: BacklogRevenueInput = if let Some(ref path) = args.input {
+        input::file::read_json(path)?
+    } else if let Some(data) = input::stdin::read_stdin()? {
+        serde_json::from_value(data)?
+    } else {
+        return Err("--input <file.json> or stdin required for backlog revenue".into());
+    };
+    let result = backlog::forecast_backlog_revenue(&bl_input)?;
+    Ok(serde_json::to_value(result)?)
+}
+
+pub fn run_saas_metrics(args: SaasMetricsArgs) -> Result<Value, Box<dyn std::error::Error>> {
+    let input_data: SaasMetricsInput = if let Some(ref path) = args.input {
+        input::file::read_json(path)?
+    } else if let Some(data) = input::stdin::read_stdin()? {
+        serde_json::from_value(data)?
+    } else {
+        return Err("--input <file.json> or stdin required for SaaS metrics analysis".into());
+    };
+    let result = saas_metrics::analyze_saas_metrics(&input_data)?;
+    Ok(serde_json::to_value(result)?)
+}
+
+pub fn run_unit_economics(args: UnitEconomicsArgs) -> Result<Value, Box<dyn std::error::Error>> {
+    let input_data: UnitEconomicsInput = if let Some(ref path) = args.input {
+        input::file::read_json(path)?
+    } else if let Some(data) = input::stdin::read_stdin()? {
+        serde_json::from_value(data)?
+    } else {
+        return Err("--input <file.json> or stdin required for unit economics analysis".into());
+    };
+    let result = unit_economics::analyze_unit_economics(&input_data)?;
+    Ok(serde_json::to_value(result)?)
+}
@@ -0,0 +1,26 @@
+use clap::Args;
+use serde_json::Value;
+
+use corp_finance_core::franchise::economics::{self, FranchiseInput};
+
+use crate::input;
+
+/// Arguments for franchise economics
+#[derive(Args)]
+pub struct FranchiseArgs {
+    /// Path to JSON input file
+    #[arg(long)]
+    pub input: Option<String>,
+}
+
+pub fn run_franchise(args: FranchiseArgs) -> Result<Value, Box<dyn std::error::Error>> {
+    let franchise_input: FranchiseInput = if let Some(ref path) = args.input {
+        input::file::read_json(path)?
+    } else if let Some(data) = input::stdin::read_stdin()? {
+        serde_json::from_value(data)?
+    } else {
+        return Err("--input <file.json> or stdin required for franchise".into());
+    };
+    let result = economics::analyze_franchise(&franchise_input)?;
+    Ok(serde_json::to_value(result)?)
+}
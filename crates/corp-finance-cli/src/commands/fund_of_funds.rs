@@ -1,11 +1,13 @@
 use clap::Args;
 use serde_json::Value;
 
+use corp_finance_core::fund_of_funds::cash_flow_forecast::{self, CashFlowForecastInput};
 use corp_finance_core::fund_of_funds::commitment_pacing::{self, CommitmentPacingInput};
 use corp_finance_core::fund_of_funds::j_curve::{self, JCurveInput};
 use corp_finance_core::fund_of_funds::manager_selection::{self, ManagerSelectionInput};
 use corp_finance_core::fund_of_funds::portfolio_construction::{self, FofPortfolioInput};
 use corp_finance_core::fund_of_funds::secondaries::{self, SecondariesPricingInput};
+use corp_finance_core::fund_of_funds::style_analysis::{self, StyleAnalysisInput};
 
 use crate::input;
 
@@ -39,6 +41,18 @@ pub struct FofPortfolioArgs {
     pub input: Option<String>,
 }
 
+#[derive(Args)]
+pub struct StyleAnalysisArgs {
+    #[arg(long)]
+    pub input: Option<String>,
+}
+
+#[derive(Args)]
+pub struct CashFlowForecastArgs {
+    #[arg(long)]
+    pub input: Option<String>,
+}
+
 pub fn run_j_curve(args: JCurveArgs) -> Result<Value, Box<dyn std::error::Error>> {
     let input_data: JCurveInput = if let Some(ref path) = args.input {
         input::file::read_json(path)?
@@ -104,3 +118,29 @@ pub fn run_fof_portfolio(args: FofPortfolioArgs) -> Result<Value, Box<dyn std::e
     let result = portfolio_construction::analyze_fof_portfolio(&input_data)?;
     Ok(serde_json::to_value(result)?)
 }
+
+pub fn run_style_analysis(args: StyleAnalysisArgs) -> Result<Value, Box<dyn std::error::Error>> {
+    let input_data: StyleAnalysisInput = if let Some(ref path) = args.input {
+        input::file::read_json(path)?
+    } else if let Some(data) = input::stdin::read_stdin()? {
+        serde_json::from_value(data)?
+    } else {
+        return Err("--input <file.json> or stdin required".into());
+    };
+    let result = style_analysis::analyze_style(&input_data)?;
+    Ok(serde_json::to_value(result)?)
+}
+
+pub fn run_cash_flow_forecast(
+    args: CashFlowForecastArgs,
+) -> Result<Value, Box<dyn std::error::Error>> {
+    let input_data: CashFlowForecastInput = if let Some(ref path) = args.input {
+        input::file::read_json(path)?
+    } else if let Some(data) = input::stdin::read_stdin()? {
+        serde_json::from_value(data)?
+    } else {
+        return Err("--input <file.json> or stdin required".into());
+    };
+    let result = cash_flow_forecast::forecast_cash_flows(&input_data)?;
+    Ok(serde_json::to_value(result)?)
+}
@@ -0,0 +1,51 @@
+use clap::Args;
+use serde_json::Value;
+
+use corp_finance_core::fund_structuring::liquidity_terms::{self, LiquidityTermsInput};
+use corp_finance_core::fund_structuring::semi_liquid_stress::{self, SemiLiquidStressInput};
+
+use crate::input;
+
+/// Arguments for redemption stress
+#[derive(Args)]
+pub struct RedemptionStressArgs {
+    /// Path to JSON input file
+    #[arg(long)]
+    pub input: Option<String>,
+}
+
+/// Arguments for semi-liquid fund stress
+#[derive(Args)]
+pub struct SemiLiquidStressArgs {
+    /// Path to JSON input file
+    #[arg(long)]
+    pub input: Option<String>,
+}
+
+pub fn run_redemption_stress(
+    args: RedemptionStressArgs,
+) -> Result<Value, Box<dyn std::error::Error>> {
+    let input_data: LiquidityTermsInput = if let Some(ref path) = args.input {
+        input::file::read_json(path)?
+    } else if let Some(data) = input::stdin::read_stdin()? {
+        serde_json::from_value(data)?
+    } else {
+        return Err("--input <file.json> or stdin required for redemption stress".into());
+    };
+    let result = liquidity_terms::simulate_redemption_stress(&input_data)?;
+    Ok(serde_json::to_value(result)?)
+}
+
+pub fn run_semi_liquid_stress(
+    args: SemiLiquidStressArgs,
+) -> Result<Value, Box<dyn std::error::Error>> {
+    let input_data: SemiLiquidStressInput = if let Some(ref path) = args.input {
+        input::file::read_json(path)?
+    } else if let Some(data) = input::stdin::read_stdin()? {
+        serde_json::from_value(data)?
+    } else {
+        return Err("--input <file.json> or stdin required for semi-liquid fund stress".into());
+    };
+    let result = semi_liquid_stress::simulate_semi_liquid_stress(&input_data)?;
+    Ok(serde_json::to_value(result)?)
+}
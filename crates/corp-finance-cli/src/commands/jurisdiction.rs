@@ -3,7 +3,9 @@ use serde_json::Value;
 
 use corp_finance_core::jurisdiction::fund_fees::{self, FundFeeInput};
 use corp_finance_core::jurisdiction::gp_economics::{self, GpEconomicsInput};
-use corp_finance_core::jurisdiction::investor_returns::{self, InvestorNetReturnsInput};
+use corp_finance_core::jurisdiction::investor_returns::{
+    self, CapitalAccountInput, InvestorNetReturnsInput,
+};
 use corp_finance_core::jurisdiction::nav::{self, NavInput};
 use corp_finance_core::jurisdiction::reconciliation::{self, ReconciliationInput};
 use corp_finance_core::jurisdiction::ubti::{self, UbtiScreeningInput};
@@ -19,6 +21,14 @@ pub struct FundFeesArgs {
     pub input: Option<String>,
 }
 
+/// Arguments for capital account statements
+#[derive(Args)]
+pub struct CapitalAccountStatementsArgs {
+    /// Path to JSON input file
+    #[arg(long)]
+    pub input: Option<String>,
+}
+
 pub fn run_fund_fees(args: FundFeesArgs) -> Result<Value, Box<dyn std::error::Error>> {
     let fee_input: FundFeeInput = if let Some(ref path) = args.input {
         input::file::read_json(path)?
@@ -152,3 +162,17 @@ pub fn run_ubti_screening(args: UbtiScreeningArgs) -> Result<Value, Box<dyn std:
     let result = ubti::screen_ubti_eci(&ubti_input)?;
     Ok(serde_json::to_value(result)?)
 }
+
+pub fn run_capital_account_statements(
+    args: CapitalAccountStatementsArgs,
+) -> Result<Value, Box<dyn std::error::Error>> {
+    let input_data: CapitalAccountInput = if let Some(ref path) = args.input {
+        input::file::read_json(path)?
+    } else if let Some(data) = input::stdin::read_stdin()? {
+        serde_json::from_value(data)?
+    } else {
+        return Err("--input <file.json> or stdin required for capital account statements".into());
+    };
+    let result = investor_returns::build_capital_account_statements(&input_data)?;
+    Ok(serde_json::to_value(result)?)
+}
@@ -1,7 +1,12 @@
 use clap::Args;
 use serde_json::Value;
 
-use corp_finance_core::ma::merger_model::{self, MergerInput};
+use corp_finance_core::ma::earnout::{self, EarnoutInput};
+use corp_finance_core::ma::financing::{self, FinancingInput};
+use corp_finance_core::ma::merger_arb::{self, MergerArbInput};
+use corp_finance_core::ma::merger_model::{self, MergerInput, MultiTargetMergerInput};
+use corp_finance_core::ma::ppa::{self, PpaInput};
+use corp_finance_core::ma::purchase_price::{self, PurchasePriceInput};
 
 use crate::input;
 
@@ -13,6 +18,38 @@ pub struct MergerArgs {
     pub input: Option<String>,
 }
 
+/// Arguments for merger arbitrage analysis
+#[derive(Args)]
+pub struct MergerArbArgs {
+    /// Path to JSON input file
+    #[arg(long)]
+    pub input: Option<String>,
+}
+
+/// Arguments for acquisition financing analysis
+#[derive(Args)]
+pub struct AcquisitionFinancingArgs {
+    /// Path to JSON input file
+    #[arg(long)]
+    pub input: Option<String>,
+}
+
+/// Arguments for purchase price mechanism comparison
+#[derive(Args)]
+pub struct PurchasePriceArgs {
+    /// Path to JSON input file
+    #[arg(long)]
+    pub input: Option<String>,
+}
+
+/// Arguments for earn-out valuation
+#[derive(Args)]
+pub struct EarnoutArgs {
+    /// Path to JSON input file
+    #[arg(long)]
+    pub input: Option<String>,
+}
+
 pub fn run_merger(args: MergerArgs) -> Result<Value, Box<dyn std::error::Error>> {
     let merger_input: MergerInput = if let Some(ref path) = args.input {
         input::file::read_json(path)?
@@ -24,3 +61,99 @@ pub fn run_merger(args: MergerArgs) -> Result<Value, Box<dyn std::error::Error>>
     let result = merger_model::analyze_merger(&merger_input)?;
     Ok(serde_json::to_value(result)?)
 }
+
+/// Arguments for staged, multi-target accretion/dilution analysis
+#[derive(Args)]
+pub struct MultiTargetMergerArgs {
+    /// Path to JSON input file
+    #[arg(long)]
+    pub input: Option<String>,
+}
+
+pub fn run_multi_target_merger(
+    args: MultiTargetMergerArgs,
+) -> Result<Value, Box<dyn std::error::Error>> {
+    let merger_input: MultiTargetMergerInput = if let Some(ref path) = args.input {
+        input::file::read_json(path)?
+    } else if let Some(data) = input::stdin::read_stdin()? {
+        serde_json::from_value(data)?
+    } else {
+        return Err("--input <file.json> or stdin required for multi-target merger".into());
+    };
+    let result = merger_model::analyze_multi_target_merger(&merger_input)?;
+    Ok(serde_json::to_value(result)?)
+}
+
+/// Arguments for purchase price allocation
+#[derive(Args)]
+pub struct PpaArgs {
+    /// Path to JSON input file
+    #[arg(long)]
+    pub input: Option<String>,
+}
+
+pub fn run_ppa(args: PpaArgs) -> Result<Value, Box<dyn std::error::Error>> {
+    let ppa_input: PpaInput = if let Some(ref path) = args.input {
+        input::file::read_json(path)?
+    } else if let Some(data) = input::stdin::read_stdin()? {
+        serde_json::from_value(data)?
+    } else {
+        return Err("--input <file.json> or stdin required for purchase price allocation".into());
+    };
+    let result = ppa::allocate_purchase_price(&ppa_input)?;
+    Ok(serde_json::to_value(result)?)
+}
+
+pub fn run_merger_arb(args: MergerArbArgs) -> Result<Value, Box<dyn std::error::Error>> {
+    let input_data: MergerArbInput = if let Some(ref path) = args.input {
+        input::file::read_json(path)?
+    } else if let Some(data) = input::stdin::read_stdin()? {
+        serde_json::from_value(data)?
+    } else {
+        return Err("--input <file.json> or stdin required for merger arbitrage analysis".into());
+    };
+    let result = merger_arb::analyze_merger_arb(&input_data)?;
+    Ok(serde_json::to_value(result)?)
+}
+
+pub fn run_acquisition_financing(
+    args: AcquisitionFinancingArgs,
+) -> Result<Value, Box<dyn std::error::Error>> {
+    let input_data: FinancingInput = if let Some(ref path) = args.input {
+        input::file::read_json(path)?
+    } else if let Some(data) = input::stdin::read_stdin()? {
+        serde_json::from_value(data)?
+    } else {
+        return Err(
+            "--input <file.json> or stdin required for acquisition financing analysis".into(),
+        );
+    };
+    let result = financing::analyze_financing(&input_data)?;
+    Ok(serde_json::to_value(result)?)
+}
+
+pub fn run_purchase_price(args: PurchasePriceArgs) -> Result<Value, Box<dyn std::error::Error>> {
+    let input_data: PurchasePriceInput = if let Some(ref path) = args.input {
+        input::file::read_json(path)?
+    } else if let Some(data) = input::stdin::read_stdin()? {
+        serde_json::from_value(data)?
+    } else {
+        return Err(
+            "--input <file.json> or stdin required for purchase price mechanism comparison".into(),
+        );
+    };
+    let result = purchase_price::analyze_purchase_price_mechanism(&input_data)?;
+    Ok(serde_json::to_value(result)?)
+}
+
+pub fn run_earnout(args: EarnoutArgs) -> Result<Value, Box<dyn std::error::Error>> {
+    let input_data: EarnoutInput = if let Some(ref path) = args.input {
+        input::file::read_json(path)?
+    } else if let Some(data) = input::stdin::read_stdin()? {
+        serde_json::from_value(data)?
+    } else {
+        return Err("--input <file.json> or stdin required for earn-out valuation".into());
+    };
+    let result = earnout::value_earnout(&input_data)?;
+    Ok(serde_json::to_value(result)?)
+}
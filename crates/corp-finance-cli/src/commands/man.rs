@@ -0,0 +1,201 @@
+use clap::{Arg, Args, Command};
+use std::fmt::Write;
+
+use super::completions::short_help;
+use super::examples;
+
+#[derive(Args)]
+pub struct ManArgs {
+    /// Command to document (omit for the top-level `cfa` page)
+    pub command: Option<String>,
+}
+
+/// Render a roff man page for `cfa`, or for one of its commands.
+pub fn run_man(args: ManArgs, cmd: &Command) -> Result<String, Box<dyn std::error::Error>> {
+    match args.command {
+        None => Ok(root_page(cmd)),
+        Some(ref name) => {
+            let sub = cmd
+                .find_subcommand(name)
+                .ok_or_else(|| format!("Unknown command '{name}'"))?;
+            Ok(command_page(cmd, sub))
+        }
+    }
+}
+
+/// Escape text for roff.
+fn roff(text: &str) -> String {
+    let escaped = text.replace('\\', "\\e").replace('-', "\\-");
+    if escaped.starts_with('.') || escaped.starts_with('\'') {
+        format!("\\&{escaped}")
+    } else {
+        escaped
+    }
+}
+
+fn header(s: &mut String, title: &str, version: &str) {
+    let _ = writeln!(
+        s,
+        ".TH {} 1 \"\" \"cfa {version}\" \"User Commands\"",
+        title.to_uppercase()
+    );
+}
+
+fn option_entry(s: &mut String, a: &Arg) {
+    let Some(long) = a.get_long() else { return };
+    let values: Vec<String> = a
+        .get_possible_values()
+        .iter()
+        .filter(|v| !v.is_hide_set())
+        .map(|v| v.get_name().to_string())
+        .collect();
+    let _ = writeln!(s, ".TP");
+    if values.is_empty() {
+        let _ = writeln!(s, "\\fB\\-\\-{}\\fR", roff(long));
+    } else {
+        let _ = writeln!(
+            s,
+            "\\fB\\-\\-{}\\fR <{}>",
+            roff(long),
+            roff(&values.join("|"))
+        );
+    }
+    let _ = writeln!(s, "{}", roff(&short_help(a.get_help())));
+}
+
+fn root_page(cmd: &Command) -> String {
+    let name = cmd.get_name();
+    let version = cmd.get_version().unwrap_or_default();
+    let mut s = String::new();
+    header(&mut s, name, version);
+    let _ = writeln!(s, ".SH NAME");
+    let _ = writeln!(s, "{name} \\- {}", roff(&short_help(cmd.get_about())));
+    let _ = writeln!(s, ".SH SYNOPSIS");
+    let _ = writeln!(
+        s,
+        "\\fB{name}\\fR [\\fB\\-\\-output\\fR \\fIFORMAT\\fR] \\fICOMMAND\\fR [\\fIOPTIONS\\fR]"
+    );
+    let _ = writeln!(s, ".SH DESCRIPTION");
+    let description = cmd.get_long_about().or(cmd.get_about());
+    let _ = writeln!(
+        s,
+        "{}",
+        roff(&description.map(|d| d.to_string()).unwrap_or_default())
+    );
+    let _ = writeln!(
+        s,
+        "Most commands read a JSON input from \\fB\\-\\-input\\fR \\fIfile.json\\fR, \\fB\\-\\-input \\-\\fR or piped stdin."
+    );
+    let _ = writeln!(s, ".SH OPTIONS");
+    for a in cmd.get_arguments().filter(|a| !a.is_hide_set()) {
+        option_entry(&mut s, a);
+    }
+    let _ = writeln!(s, ".SH COMMANDS");
+    for c in cmd.get_subcommands().filter(|c| !c.is_hide_set()) {
+        let _ = writeln!(s, ".TP");
+        let _ = writeln!(s, "\\fB{}\\fR", roff(c.get_name()));
+        let _ = writeln!(s, "{}", roff(&short_help(c.get_about())));
+    }
+    let _ = writeln!(s, ".SH EXAMPLES");
+    let _ = writeln!(s, "List the commands that ship with a sample input:");
+    let _ = writeln!(s, ".PP");
+    let _ = writeln!(s, ".nf");
+    let _ = writeln!(s, "    {name} examples \\-\\-output table");
+    let _ = writeln!(s, ".fi");
+    let _ = writeln!(s, ".PP");
+    let _ = writeln!(s, "Run a calculator on its sample input:");
+    let _ = writeln!(s, ".PP");
+    let _ = writeln!(s, ".nf");
+    let _ = writeln!(s, "    {name} examples lbo > lbo.json");
+    let _ = writeln!(s, "    {name} lbo \\-\\-input lbo.json \\-\\-output table");
+    let _ = writeln!(s, ".fi");
+    let _ = writeln!(s, ".PP");
+    let _ = writeln!(
+        s,
+        "Pipe a sample through a calculator, overriding one assumption:"
+    );
+    let _ = writeln!(s, ".PP");
+    let _ = writeln!(s, ".nf");
+    let _ = writeln!(
+        s,
+        "    {name} examples wacc | {name} wacc \\-\\-input \\- \\-\\-set beta=1.5"
+    );
+    let _ = writeln!(s, ".fi");
+    let _ = writeln!(s, ".PP");
+    let _ = writeln!(
+        s,
+        "Keep a transaction's analyses and scenarios in a project:"
+    );
+    let _ = writeln!(s, ".PP");
+    let _ = writeln!(s, ".nf");
+    let _ = writeln!(
+        s,
+        "    {name} project\\-init \\-\\-project falcon && cd falcon"
+    );
+    let _ = writeln!(
+        s,
+        "    {name} project\\-add \\-\\-name lbo \\-\\-command lbo"
+    );
+    let _ = writeln!(
+        s,
+        "    {name} project\\-scenario \\-\\-name downside \\-\\-override lbo.exit_multiple=5"
+    );
+    let _ = writeln!(
+        s,
+        "    {name} project\\-run && {name} project\\-report \\-\\-output table"
+    );
+    let _ = writeln!(s, ".fi");
+    let _ = writeln!(s, ".PP");
+    let _ = writeln!(s, "Show what moved between two versions of a model:");
+    let _ = writeln!(s, ".PP");
+    let _ = writeln!(s, ".nf");
+    let _ = writeln!(
+        s,
+        "    {name} diff old.json new.json \\-\\-pct\\-threshold 0.01 \\-\\-material\\-only"
+    );
+    let _ = writeln!(s, ".fi");
+    let _ = writeln!(s, ".SH SEE ALSO");
+    let _ = writeln!(
+        s,
+        "\\fB{name} man\\fR \\fICOMMAND\\fR for per-command pages."
+    );
+    s
+}
+
+fn command_page(root: &Command, cmd: &Command) -> String {
+    let root_name = root.get_name();
+    let name = cmd.get_name();
+    let version = root.get_version().unwrap_or_default();
+    let mut s = String::new();
+    header(&mut s, &format!("{root_name}-{name}"), version);
+    let _ = writeln!(s, ".SH NAME");
+    let _ = writeln!(
+        s,
+        "{root_name}\\-{} \\- {}",
+        roff(name),
+        roff(&short_help(cmd.get_about()))
+    );
+    let _ = writeln!(s, ".SH SYNOPSIS");
+    let _ = writeln!(s, "\\fB{root_name} {}\\fR [\\fIOPTIONS\\fR]", roff(name));
+    let options: Vec<&Arg> = cmd
+        .get_arguments()
+        .chain(root.get_arguments())
+        .filter(|a| !a.is_hide_set() && !a.is_positional())
+        .collect();
+    if !options.is_empty() {
+        let _ = writeln!(s, ".SH OPTIONS");
+        for a in options {
+            option_entry(&mut s, a);
+        }
+    }
+    if examples::sample_input(name).is_some() {
+        let _ = writeln!(s, ".SH EXAMPLES");
+        let _ = writeln!(s, ".nf");
+        let _ = writeln!(s, "    {root_name} examples {0} > {0}.json", roff(name));
+        let _ = writeln!(s, "    {root_name} {0} \\-\\-input {0}.json", roff(name));
+        let _ = writeln!(s, ".fi");
+    }
+    let _ = writeln!(s, ".SH SEE ALSO");
+    let _ = writeln!(s, "\\fB{root_name}\\fR(1)");
+    s
+}
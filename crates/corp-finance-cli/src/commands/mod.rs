@@ -2,27 +2,37 @@ pub mod aml_compliance;
 pub mod bank_analytics;
 pub mod behavioral;
 pub mod capital_allocation;
+pub mod capital_budgeting;
 pub mod carbon_markets;
+pub mod cashflow_ladder;
 pub mod clo_analytics;
 pub mod commodity_trading;
+pub mod completions;
 pub mod compliance;
+pub mod consumer_lending;
 pub mod convertibles;
 pub mod credit;
 pub mod credit_derivatives;
 pub mod credit_portfolio;
 pub mod credit_scoring;
 pub mod crypto;
+pub mod deal_model;
 pub mod derivatives;
+pub mod diff;
 pub mod dividend_policy;
 pub mod earnings_quality;
+pub mod ecm;
 pub mod emerging_markets;
 pub mod equity_research;
 pub mod esg;
+pub mod examples;
 pub mod fatca_crs;
 pub mod financial_forensics;
 pub mod fixed_income;
 pub mod fpa;
+pub mod franchise;
 pub mod fund_of_funds;
+pub mod fund_structuring;
 pub mod fx_commodities;
 pub mod index_construction;
 pub mod inflation_linked;
@@ -33,6 +43,7 @@ pub mod jurisdiction;
 pub mod lease_accounting;
 pub mod ma;
 pub mod macro_economics;
+pub mod man;
 pub mod market_microstructure;
 pub mod monte_carlo;
 pub mod mortgage_analytics;
@@ -46,6 +57,7 @@ pub mod portfolio;
 pub mod portfolio_optimization;
 pub mod private_credit;
 pub mod private_wealth;
+pub mod project;
 pub mod quant_risk;
 pub mod quant_strategies;
 pub mod real_assets;
@@ -2,7 +2,10 @@ use clap::Args;
 use rust_decimal::Decimal;
 use serde_json::Value;
 
+use corp_finance_core::pe::co_investment::{self, CoInvestmentInput};
+use corp_finance_core::pe::interim_nav::{self, InterimNavInput};
 use corp_finance_core::pe::lbo::{self, LboInput};
+use corp_finance_core::pe::pacing::{self, PacingInput};
 use corp_finance_core::pe::returns::{self, ReturnsInput};
 use corp_finance_core::pe::waterfall::{self, WaterfallInput};
 
@@ -54,6 +57,7 @@ pub fn run_returns(args: ReturnsArgs) -> Result<Value, Box<dyn std::error::Error
             exit_equity: exit,
             holding_period_years: args.holding_years,
             dates: None,
+            subscription_line: None,
         }
     };
 
@@ -100,3 +104,63 @@ pub fn run_waterfall(args: WaterfallArgs) -> Result<Value, Box<dyn std::error::E
     let result = waterfall::calculate_waterfall(&wf_input)?;
     Ok(serde_json::to_value(result)?)
 }
+
+/// Arguments for interim NAV estimation
+#[derive(Args)]
+pub struct InterimNavArgs {
+    /// Path to JSON input file
+    #[arg(long)]
+    pub input: Option<String>,
+}
+
+pub fn run_interim_nav(args: InterimNavArgs) -> Result<Value, Box<dyn std::error::Error>> {
+    let nav_input: InterimNavInput = if let Some(ref path) = args.input {
+        input::file::read_json(path)?
+    } else if let Some(data) = input::stdin::read_stdin()? {
+        serde_json::from_value(data)?
+    } else {
+        return Err("--input <file.json> or stdin required for interim NAV".into());
+    };
+    let result = interim_nav::estimate_interim_nav(&nav_input)?;
+    Ok(serde_json::to_value(result)?)
+}
+
+/// Arguments for co-investment evaluation
+#[derive(Args)]
+pub struct CoInvestmentArgs {
+    /// Path to JSON input file
+    #[arg(long)]
+    pub input: Option<String>,
+}
+
+pub fn run_co_investment(args: CoInvestmentArgs) -> Result<Value, Box<dyn std::error::Error>> {
+    let ci_input: CoInvestmentInput = if let Some(ref path) = args.input {
+        input::file::read_json(path)?
+    } else if let Some(data) = input::stdin::read_stdin()? {
+        serde_json::from_value(data)?
+    } else {
+        return Err("--input <file.json> or stdin required for co-investment evaluation".into());
+    };
+    let result = co_investment::evaluate_co_investment(&ci_input)?;
+    Ok(serde_json::to_value(result)?)
+}
+
+/// Arguments for a commitment pacing plan
+#[derive(Args)]
+pub struct PacingArgs {
+    /// Path to JSON input file
+    #[arg(long)]
+    pub input: Option<String>,
+}
+
+pub fn run_pacing(args: PacingArgs) -> Result<Value, Box<dyn std::error::Error>> {
+    let pacing_input: PacingInput = if let Some(ref path) = args.input {
+        input::file::read_json(path)?
+    } else if let Some(data) = input::stdin::read_stdin()? {
+        serde_json::from_value(data)?
+    } else {
+        return Err("--input <file.json> or stdin required for pacing projection".into());
+    };
+    let result = pacing::project_pacing(&pacing_input)?;
+    Ok(serde_json::to_value(result)?)
+}
@@ -4,6 +4,9 @@ use rust_decimal_macros::dec;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
+use corp_finance_core::portfolio::revaluation_var::{self, RevaluationVarInput};
+use corp_finance_core::portfolio::valuation::{self, PortfolioValuationInput};
+
 use crate::input;
 
 /// Arguments for Sharpe ratio calculation
@@ -46,20 +49,40 @@ pub struct RiskArgs {
     pub portfolio_value: Option<Decimal>,
 }
 
+/// Arguments for position-level portfolio valuation
+#[derive(Args)]
+pub struct PortfolioValuationArgs {
+    /// Path to JSON input file
+    #[arg(long)]
+    pub input: Option<String>,
+}
+
+/// Arguments for full-revaluation historical / Monte Carlo VaR
+#[derive(Args)]
+pub struct RevaluationVarArgs {
+    /// Path to JSON input file
+    #[arg(long)]
+    pub input: Option<String>,
+}
+
 /// Arguments for Kelly criterion position sizing
 #[derive(Args)]
 pub struct KellyArgs {
+    /// Path to JSON input file (win_prob, win_loss_ratio, fraction, portfolio_value)
+    #[arg(long)]
+    pub input: Option<String>,
+
     /// Probability of a winning trade (0 to 1)
     #[arg(long)]
-    pub win_prob: Decimal,
+    pub win_prob: Option<Decimal>,
 
     /// Win/loss ratio (average win / average loss)
     #[arg(long)]
-    pub win_loss_ratio: Decimal,
+    pub win_loss_ratio: Option<Decimal>,
 
     /// Kelly fraction (0 to 1, typically 0.5 for half-Kelly)
-    #[arg(long, default_value = "0.5")]
-    pub fraction: Decimal,
+    #[arg(long)]
+    pub fraction: Option<Decimal>,
 
     /// Portfolio value for monetary sizing
     #[arg(long)]
@@ -88,6 +111,20 @@ struct RiskOutput {
     num_periods: usize,
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+struct KellyInput {
+    win_prob: Decimal,
+    win_loss_ratio: Decimal,
+    #[serde(default = "default_kelly_fraction")]
+    fraction: Decimal,
+    #[serde(default)]
+    portfolio_value: Option<Decimal>,
+}
+
+fn default_kelly_fraction() -> Decimal {
+    dec!(0.5)
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 struct KellyOutput {
     full_kelly: Decimal,
@@ -296,9 +333,25 @@ pub fn run_risk(args: RiskArgs) -> Result<Value, Box<dyn std::error::Error>> {
 }
 
 pub fn run_kelly(args: KellyArgs) -> Result<Value, Box<dyn std::error::Error>> {
-    let p = args.win_prob;
-    let wl = args.win_loss_ratio;
-    let frac = args.fraction;
+    let kelly: KellyInput = if let Some(ref path) = args.input {
+        input::file::read_json(path)?
+    } else if let (Some(win_prob), Some(win_loss_ratio)) = (args.win_prob, args.win_loss_ratio) {
+        KellyInput {
+            win_prob,
+            win_loss_ratio,
+            fraction: args.fraction.unwrap_or_else(default_kelly_fraction),
+            portfolio_value: args.portfolio_value,
+        }
+    } else if let Some(data) = input::stdin::read_stdin()? {
+        serde_json::from_value(data)?
+    } else {
+        return Err(
+            "Provide --win-prob and --win-loss-ratio, or --input <file.json> or stdin".into(),
+        );
+    };
+    let p = kelly.win_prob;
+    let wl = kelly.win_loss_ratio;
+    let frac = kelly.fraction;
 
     if p <= Decimal::ZERO || p >= Decimal::ONE {
         return Err("--win-prob must be between 0 and 1 (exclusive)".into());
@@ -322,7 +375,7 @@ pub fn run_kelly(args: KellyArgs) -> Result<Value, Box<dyn std::error::Error>> {
         full_kelly,
         fractional_kelly,
         fraction_used: frac,
-        position_size: args.portfolio_value.map(|pv| {
+        position_size: kelly.portfolio_value.map(|pv| {
             if fractional_kelly > Decimal::ZERO {
                 fractional_kelly * pv
             } else {
@@ -334,3 +387,29 @@ pub fn run_kelly(args: KellyArgs) -> Result<Value, Box<dyn std::error::Error>> {
 
     Ok(serde_json::to_value(output)?)
 }
+
+pub fn run_portfolio_valuation(
+    args: PortfolioValuationArgs,
+) -> Result<Value, Box<dyn std::error::Error>> {
+    let pv_input: PortfolioValuationInput = if let Some(ref path) = args.input {
+        input::file::read_json(path)?
+    } else if let Some(data) = input::stdin::read_stdin()? {
+        serde_json::from_value(data)?
+    } else {
+        return Err("--input <file.json> or stdin required for portfolio valuation".into());
+    };
+    let result = valuation::value_portfolio(&pv_input)?;
+    Ok(serde_json::to_value(result)?)
+}
+
+pub fn run_revaluation_var(args: RevaluationVarArgs) -> Result<Value, Box<dyn std::error::Error>> {
+    let var_input: RevaluationVarInput = if let Some(ref path) = args.input {
+        input::file::read_json(path)?
+    } else if let Some(data) = input::stdin::read_stdin()? {
+        serde_json::from_value(data)?
+    } else {
+        return Err("--input <file.json> or stdin required for revaluation VaR".into());
+    };
+    let result = revaluation_var::calculate_revaluation_var(&var_input)?;
+    Ok(serde_json::to_value(result)?)
+}
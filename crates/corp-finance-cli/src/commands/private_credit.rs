@@ -1,7 +1,10 @@
 use clap::Args;
 use serde_json::Value;
 
+use corp_finance_core::private_credit::bridge_financing::{self, BridgeFinancingInput};
 use corp_finance_core::private_credit::direct_lending::{self, DirectLoanInput, SyndicationInput};
+use corp_finance_core::private_credit::fund_finance::{self, FundFinanceInput};
+use corp_finance_core::private_credit::loan_pricing::{self, LoanPricingInput};
 use corp_finance_core::private_credit::unitranche::{self, UnitrancheInput};
 
 use crate::input;
@@ -30,6 +33,30 @@ pub struct SyndicationArgs {
     pub input: Option<String>,
 }
 
+/// Arguments for leveraged loan pricing
+#[derive(Args)]
+pub struct LoanPricingArgs {
+    /// Path to JSON input file
+    #[arg(long)]
+    pub input: Option<String>,
+}
+
+/// Arguments for bridge financing analysis
+#[derive(Args)]
+pub struct BridgeFinancingArgs {
+    /// Path to JSON input file
+    #[arg(long)]
+    pub input: Option<String>,
+}
+
+/// Arguments for fund finance analysis
+#[derive(Args)]
+pub struct FundFinanceArgs {
+    /// Path to JSON input file
+    #[arg(long)]
+    pub input: Option<String>,
+}
+
 pub fn run_unitranche(args: UnitrancheArgs) -> Result<Value, Box<dyn std::error::Error>> {
     let ut_input: UnitrancheInput = if let Some(ref path) = args.input {
         input::file::read_json(path)?
@@ -65,3 +92,41 @@ pub fn run_syndication(args: SyndicationArgs) -> Result<Value, Box<dyn std::erro
     let result = direct_lending::analyze_syndication(&synd_input)?;
     Ok(serde_json::to_value(result)?)
 }
+
+pub fn run_loan_pricing(args: LoanPricingArgs) -> Result<Value, Box<dyn std::error::Error>> {
+    let input_data: LoanPricingInput = if let Some(ref path) = args.input {
+        input::file::read_json(path)?
+    } else if let Some(data) = input::stdin::read_stdin()? {
+        serde_json::from_value(data)?
+    } else {
+        return Err("--input <file.json> or stdin required for leveraged loan pricing".into());
+    };
+    let result = loan_pricing::price_leveraged_loan(&input_data)?;
+    Ok(serde_json::to_value(result)?)
+}
+
+pub fn run_bridge_financing(
+    args: BridgeFinancingArgs,
+) -> Result<Value, Box<dyn std::error::Error>> {
+    let input_data: BridgeFinancingInput = if let Some(ref path) = args.input {
+        input::file::read_json(path)?
+    } else if let Some(data) = input::stdin::read_stdin()? {
+        serde_json::from_value(data)?
+    } else {
+        return Err("--input <file.json> or stdin required for bridge financing analysis".into());
+    };
+    let result = bridge_financing::analyze_bridge_financing(&input_data)?;
+    Ok(serde_json::to_value(result)?)
+}
+
+pub fn run_fund_finance(args: FundFinanceArgs) -> Result<Value, Box<dyn std::error::Error>> {
+    let input_data: FundFinanceInput = if let Some(ref path) = args.input {
+        input::file::read_json(path)?
+    } else if let Some(data) = input::stdin::read_stdin()? {
+        serde_json::from_value(data)?
+    } else {
+        return Err("--input <file.json> or stdin required for fund finance analysis".into());
+    };
+    let result = fund_finance::analyze_fund_finance(&input_data)?;
+    Ok(serde_json::to_value(result)?)
+}
@@ -0,0 +1,582 @@
+use clap::{Args, Command};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::examples;
+use crate::input;
+use crate::input::overrides::Override;
+
+/// Manifest file at the root of a project directory.
+const MANIFEST: &str = "cfa-project.json";
+/// Scenario that runs every analysis on its stored input, unmodified.
+const BASE_SCENARIO: &str = "base";
+
+/// Runs a calculator command on a JSON input file, as `cfa <command> --input <path>`.
+pub type Runner = fn(&str, &Path) -> Result<Value, Box<dyn std::error::Error>>;
+
+/// A directory-based workspace: named analyses with their inputs, scenario
+/// overrides, and cached results under `results/<scenario>/<analysis>.json`.
+#[derive(Debug, Serialize, Deserialize)]
+struct Manifest {
+    name: String,
+    #[serde(default)]
+    analyses: Vec<Analysis>,
+    #[serde(default)]
+    scenarios: Vec<Scenario>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Analysis {
+    name: String,
+    /// Calculator command, e.g. `lbo` or `wacc`.
+    command: String,
+    /// Input file, relative to the project directory.
+    input: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Scenario {
+    name: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    description: String,
+    /// Field overrides by analysis name, then dotted field path.
+    #[serde(default)]
+    overrides: BTreeMap<String, BTreeMap<String, Value>>,
+}
+
+/// A cached calculator run, keyed by the exact command and resolved input.
+#[derive(Debug, Serialize, Deserialize)]
+struct CachedResult {
+    command: String,
+    input: Value,
+    output: Value,
+}
+
+#[derive(Args)]
+pub struct ProjectInitArgs {
+    /// Project directory (created if missing)
+    #[arg(long, default_value = ".")]
+    pub project: String,
+
+    /// Project name (defaults to the directory name)
+    #[arg(long)]
+    pub name: Option<String>,
+}
+
+#[derive(Args)]
+pub struct ProjectAddArgs {
+    /// Project directory
+    #[arg(long, default_value = ".")]
+    pub project: String,
+
+    /// Analysis name (letters, digits, '-' and '_')
+    #[arg(long)]
+    pub name: String,
+
+    /// Calculator command to run, e.g. lbo, wacc, merger
+    #[arg(long)]
+    pub command: String,
+
+    /// Path to JSON input file (`-` reads stdin); defaults to the command's sample input
+    #[arg(long)]
+    pub input: Option<String>,
+}
+
+#[derive(Args)]
+pub struct ProjectScenarioArgs {
+    /// Project directory
+    #[arg(long, default_value = ".")]
+    pub project: String,
+
+    /// Scenario name (letters, digits, '-' and '_')
+    #[arg(long)]
+    pub name: String,
+
+    /// Scenario description
+    #[arg(long)]
+    pub description: Option<String>,
+
+    /// Field override as analysis.field.path=value (repeatable)
+    #[arg(
+        long = "override",
+        value_name = "ANALYSIS.PATH=VALUE",
+        value_parser = input::overrides::parse
+    )]
+    pub overrides: Vec<Override>,
+}
+
+#[derive(Args)]
+pub struct ProjectRunArgs {
+    /// Project directory
+    #[arg(long, default_value = ".")]
+    pub project: String,
+
+    /// Run a single scenario (default: base and every scenario)
+    #[arg(long)]
+    pub scenario: Option<String>,
+
+    /// Run a single analysis (default: all)
+    #[arg(long)]
+    pub analysis: Option<String>,
+
+    /// Re-run analyses whose cached result is still fresh
+    #[arg(long)]
+    pub force: bool,
+}
+
+#[derive(Args)]
+pub struct ProjectStatusArgs {
+    /// Project directory
+    #[arg(long, default_value = ".")]
+    pub project: String,
+}
+
+#[derive(Args)]
+pub struct ProjectReportArgs {
+    /// Project directory
+    #[arg(long, default_value = ".")]
+    pub project: String,
+
+    /// Report a single scenario (default: base and every scenario)
+    #[arg(long)]
+    pub scenario: Option<String>,
+}
+
+pub fn run_project_init(args: ProjectInitArgs) -> Result<Value, Box<dyn std::error::Error>> {
+    let dir = PathBuf::from(&args.project);
+    let manifest_path = dir.join(MANIFEST);
+    if manifest_path.exists() {
+        return Err(format!("'{}' already exists", manifest_path.display()).into());
+    }
+    fs::create_dir_all(dir.join("inputs"))?;
+    fs::create_dir_all(dir.join("results"))?;
+
+    let name = match args.name {
+        Some(name) => name,
+        None => fs::canonicalize(&dir)?
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_else(|| "project".into()),
+    };
+    let manifest = Manifest {
+        name,
+        analyses: Vec::new(),
+        scenarios: Vec::new(),
+    };
+    save_manifest(&dir, &manifest)?;
+
+    Ok(json!({
+        "project": manifest.name,
+        "manifest": manifest_path.display().to_string(),
+    }))
+}
+
+pub fn run_project_add(
+    args: ProjectAddArgs,
+    cli: &Command,
+) -> Result<Value, Box<dyn std::error::Error>> {
+    let dir = PathBuf::from(&args.project);
+    let mut manifest = load_manifest(&dir)?;
+    validate_name("analysis", &args.name)?;
+
+    let accepts_input = cli
+        .find_subcommand(&args.command)
+        .is_some_and(|c| c.get_arguments().any(|a| a.get_id() == "input"));
+    if !accepts_input || args.command.starts_with("project-") {
+        return Err(format!(
+            "'{}' is not a calculator command that takes --input",
+            args.command
+        )
+        .into());
+    }
+
+    let data: Value = match args.input {
+        Some(ref path) => input::file::read_json_value(path)?,
+        None => {
+            let sample = examples::sample_input(&args.command).ok_or_else(|| {
+                format!(
+                    "No sample input for '{}'; pass --input <file.json>",
+                    args.command
+                )
+            })?;
+            let mut data: Value = serde_json::from_str(sample)?;
+            input::overrides::apply(&mut data)?;
+            data
+        }
+    };
+
+    let relative = format!("inputs/{}.json", args.name);
+    write_json(&dir.join(&relative), &data)?;
+
+    let analysis = Analysis {
+        name: args.name.clone(),
+        command: args.command,
+        input: relative,
+    };
+    let result = json!({
+        "analysis": analysis.name,
+        "command": analysis.command,
+        "input": analysis.input,
+    });
+    match manifest.analyses.iter_mut().find(|a| a.name == args.name) {
+        Some(existing) => *existing = analysis,
+        None => manifest.analyses.push(analysis),
+    }
+    save_manifest(&dir, &manifest)?;
+    Ok(result)
+}
+
+pub fn run_project_scenario(
+    args: ProjectScenarioArgs,
+) -> Result<Value, Box<dyn std::error::Error>> {
+    let dir = PathBuf::from(&args.project);
+    let mut manifest = load_manifest(&dir)?;
+    validate_name("scenario", &args.name)?;
+    if [BASE_SCENARIO, "analysis", "metric"].contains(&args.name.as_str()) {
+        return Err(format!("Scenario name '{}' is reserved", args.name).into());
+    }
+
+    let mut overrides: Vec<(String, String, Value)> = Vec::new();
+    for o in &args.overrides {
+        let (analysis, field) = o
+            .path()
+            .split_first()
+            .filter(|(_, rest)| !rest.is_empty())
+            .ok_or("--override expects analysis.field.path=value")?;
+        if !manifest.analyses.iter().any(|a| a.name == *analysis) {
+            return Err(format!("Unknown analysis '{analysis}' in --override").into());
+        }
+        overrides.push((analysis.clone(), field.join("."), o.value().clone()));
+    }
+
+    let index = match manifest.scenarios.iter().position(|s| s.name == args.name) {
+        Some(i) => i,
+        None => {
+            manifest.scenarios.push(Scenario {
+                name: args.name.clone(),
+                description: String::new(),
+                overrides: BTreeMap::new(),
+            });
+            manifest.scenarios.len() - 1
+        }
+    };
+    let scenario = &mut manifest.scenarios[index];
+    if let Some(description) = args.description {
+        scenario.description = description;
+    }
+    for (analysis, field, value) in overrides {
+        scenario
+            .overrides
+            .entry(analysis)
+            .or_default()
+            .insert(field, value);
+    }
+    let result = serde_json::to_value(&*scenario)?;
+    save_manifest(&dir, &manifest)?;
+    Ok(result)
+}
+
+pub fn run_project_run(
+    args: ProjectRunArgs,
+    runner: Runner,
+) -> Result<Value, Box<dyn std::error::Error>> {
+    if input::overrides::any() {
+        return Err(
+            "--set is not applied to project runs; record overrides with `cfa project-scenario \
+             --override` instead"
+                .into(),
+        );
+    }
+    let dir = PathBuf::from(&args.project);
+    let manifest = load_manifest(&dir)?;
+    let scenarios = select_scenarios(&manifest, args.scenario.as_deref())?;
+    let analyses = select_analyses(&manifest, args.analysis.as_deref())?;
+
+    let mut runs = Vec::new();
+    let mut failures = Vec::new();
+    for scenario in &scenarios {
+        let scenario_name = scenario.map_or(BASE_SCENARIO, |s| s.name.as_str());
+        for analysis in &analyses {
+            let resolved = resolve_input(&dir, analysis, *scenario)?;
+            let cache_path = result_path(&dir, scenario_name, &analysis.name);
+            let cached = read_cached(&cache_path);
+            let fresh = cached
+                .as_ref()
+                .is_some_and(|c| c.command == analysis.command && c.input == resolved);
+
+            let status = if fresh && !args.force {
+                "cached".to_string()
+            } else {
+                match run_one(runner, &cache_path, analysis, &resolved) {
+                    Ok(output) => {
+                        let cached = CachedResult {
+                            command: analysis.command.clone(),
+                            input: resolved,
+                            output,
+                        };
+                        write_json(&cache_path, &serde_json::to_value(cached)?)?;
+                        "ran".to_string()
+                    }
+                    Err(e) => {
+                        failures.push(format!("{scenario_name}/{}: {e}", analysis.name));
+                        "failed".to_string()
+                    }
+                }
+            };
+            runs.push(json!({
+                "scenario": scenario_name,
+                "analysis": analysis.name,
+                "command": analysis.command,
+                "status": status,
+            }));
+        }
+    }
+
+    if !failures.is_empty() {
+        return Err(format!(
+            "{} of {} analyses failed:\n  {}",
+            failures.len(),
+            runs.len(),
+            failures.join("\n  ")
+        )
+        .into());
+    }
+    Ok(Value::Array(runs))
+}
+
+pub fn run_project_status(args: ProjectStatusArgs) -> Result<Value, Box<dyn std::error::Error>> {
+    let dir = PathBuf::from(&args.project);
+    let manifest = load_manifest(&dir)?;
+    let scenarios = select_scenarios(&manifest, None)?;
+
+    let mut rows = Vec::new();
+    for scenario in &scenarios {
+        let scenario_name = scenario.map_or(BASE_SCENARIO, |s| s.name.as_str());
+        for analysis in &manifest.analyses {
+            let state = match resolve_input(&dir, analysis, *scenario) {
+                Ok(resolved) => cache_state(&dir, scenario_name, analysis, &resolved).to_string(),
+                Err(e) => format!("invalid input: {e}"),
+            };
+            rows.push(json!({
+                "scenario": scenario_name,
+                "analysis": analysis.name,
+                "command": analysis.command,
+                "status": state,
+            }));
+        }
+    }
+    Ok(Value::Array(rows))
+}
+
+/// Combine cached results into one scenario comparison: a row per analysis
+/// metric (cache status, methodology, warnings, then every scalar result
+/// field) with a column per scenario.
+pub fn run_project_report(args: ProjectReportArgs) -> Result<Value, Box<dyn std::error::Error>> {
+    let dir = PathBuf::from(&args.project);
+    let manifest = load_manifest(&dir)?;
+    let scenarios = select_scenarios(&manifest, args.scenario.as_deref())?;
+
+    let mut rows = Vec::new();
+    for analysis in &manifest.analyses {
+        let mut status = Map::new();
+        let mut methodology = Map::new();
+        let mut warnings = Map::new();
+        let mut metrics: BTreeMap<String, Map<String, Value>> = BTreeMap::new();
+
+        for scenario in &scenarios {
+            let scenario_name = scenario.map_or(BASE_SCENARIO, |s| s.name.as_str());
+            let resolved = resolve_input(&dir, analysis, *scenario)?;
+            let state = cache_state(&dir, scenario_name, analysis, &resolved);
+            status.insert(scenario_name.into(), json!(state));
+
+            let Some(cached) = read_cached(&result_path(&dir, scenario_name, &analysis.name))
+            else {
+                continue;
+            };
+            let output = cached.output;
+            if let Some(m) = output.get("methodology") {
+                methodology.insert(scenario_name.into(), m.clone());
+            }
+            let notes: Vec<&str> = output
+                .get("warnings")
+                .and_then(Value::as_array)
+                .map(|w| w.iter().filter_map(Value::as_str).collect())
+                .unwrap_or_default();
+            warnings.insert(scenario_name.into(), json!(notes.join("; ")));
+
+            let result = output.get("result").unwrap_or(&output);
+            if let Value::Object(fields) = result {
+                for (field, value) in fields {
+                    if matches!(value, Value::Number(_) | Value::String(_) | Value::Bool(_)) {
+                        metrics
+                            .entry(field.clone())
+                            .or_default()
+                            .insert(scenario_name.into(), value.clone());
+                    }
+                }
+            }
+        }
+
+        let sections = [
+            ("status".to_string(), status),
+            ("methodology".to_string(), methodology),
+            ("warnings".to_string(), warnings),
+        ];
+        for (metric, values) in sections.into_iter().chain(metrics) {
+            let mut row = Map::new();
+            row.insert("analysis".into(), json!(analysis.name));
+            row.insert("metric".into(), json!(metric));
+            for scenario in &scenarios {
+                let name = scenario.map_or(BASE_SCENARIO, |s| s.name.as_str());
+                row.insert(
+                    name.into(),
+                    values.get(name).cloned().unwrap_or(Value::Null),
+                );
+            }
+            rows.push(Value::Object(row));
+        }
+    }
+    Ok(Value::Array(rows))
+}
+
+fn load_manifest(dir: &Path) -> Result<Manifest, Box<dyn std::error::Error>> {
+    let path = dir.join(MANIFEST);
+    if !path.exists() {
+        return Err(format!(
+            "No {MANIFEST} in '{}'; run `cfa project-init` first",
+            dir.display()
+        )
+        .into());
+    }
+    let contents = fs::read_to_string(&path)
+        .map_err(|e| format!("Failed to read '{}': {}", path.display(), e))?;
+    let manifest = serde_json::from_str(&contents)
+        .map_err(|e| format!("Failed to parse '{}': {}", path.display(), e))?;
+    Ok(manifest)
+}
+
+fn save_manifest(dir: &Path, manifest: &Manifest) -> Result<(), Box<dyn std::error::Error>> {
+    write_json(&dir.join(MANIFEST), &serde_json::to_value(manifest)?)
+}
+
+/// Pretty-print JSON with a trailing newline, so files diff cleanly under
+/// version control.
+fn write_json(path: &Path, value: &Value) -> Result<(), Box<dyn std::error::Error>> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let mut contents = serde_json::to_string_pretty(value)?;
+    contents.push('\n');
+    fs::write(path, contents)
+        .map_err(|e| format!("Failed to write '{}': {}", path.display(), e))?;
+    Ok(())
+}
+
+/// Names become file names, so keep them to a portable character set.
+fn validate_name(kind: &str, name: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let valid = !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    if !valid {
+        return Err(
+            format!("Invalid {kind} name '{name}': use letters, digits, '-' and '_'").into(),
+        );
+    }
+    Ok(())
+}
+
+/// Scenarios to process; `None` stands for the base scenario.
+fn select_scenarios<'a>(
+    manifest: &'a Manifest,
+    only: Option<&str>,
+) -> Result<Vec<Option<&'a Scenario>>, Box<dyn std::error::Error>> {
+    match only {
+        None => Ok(std::iter::once(None)
+            .chain(manifest.scenarios.iter().map(Some))
+            .collect()),
+        Some(BASE_SCENARIO) => Ok(vec![None]),
+        Some(name) => manifest
+            .scenarios
+            .iter()
+            .find(|s| s.name == name)
+            .map(|s| vec![Some(s)])
+            .ok_or_else(|| format!("Unknown scenario '{name}'").into()),
+    }
+}
+
+fn select_analyses<'a>(
+    manifest: &'a Manifest,
+    only: Option<&str>,
+) -> Result<Vec<&'a Analysis>, Box<dyn std::error::Error>> {
+    match only {
+        None if manifest.analyses.is_empty() => {
+            Err("Project has no analyses; add one with `cfa project-add`".into())
+        }
+        None => Ok(manifest.analyses.iter().collect()),
+        Some(name) => manifest
+            .analyses
+            .iter()
+            .find(|a| a.name == name)
+            .map(|a| vec![a])
+            .ok_or_else(|| format!("Unknown analysis '{name}'").into()),
+    }
+}
+
+/// The analysis input with the scenario's overrides applied.
+fn resolve_input(
+    dir: &Path,
+    analysis: &Analysis,
+    scenario: Option<&Scenario>,
+) -> Result<Value, Box<dyn std::error::Error>> {
+    let path = dir.join(&analysis.input);
+    let contents = fs::read_to_string(&path)
+        .map_err(|e| format!("Failed to read '{}': {}", path.display(), e))?;
+    let mut data: Value = serde_json::from_str(&contents)
+        .map_err(|e| format!("Failed to parse '{}': {}", path.display(), e))?;
+    if let Some(fields) = scenario.and_then(|s| s.overrides.get(&analysis.name)) {
+        for (field, value) in fields {
+            input::overrides::set(&mut data, field, value.clone())?;
+        }
+    }
+    Ok(data)
+}
+
+fn result_path(dir: &Path, scenario: &str, analysis: &str) -> PathBuf {
+    dir.join("results")
+        .join(scenario)
+        .join(format!("{analysis}.json"))
+}
+
+fn read_cached(path: &Path) -> Option<CachedResult> {
+    let contents = fs::read_to_string(path).ok()?;
+    serde_json::from_str(&contents).ok()
+}
+
+/// `fresh` when the cached result was computed from the current command and
+/// resolved input, `stale` when either has changed, `missing` otherwise.
+fn cache_state(dir: &Path, scenario: &str, analysis: &Analysis, resolved: &Value) -> &'static str {
+    match read_cached(&result_path(dir, scenario, &analysis.name)) {
+        Some(c) if c.command == analysis.command && c.input == *resolved => "fresh",
+        Some(_) => "stale",
+        None => "missing",
+    }
+}
+
+/// Run one analysis through the calculator, via a resolved input file
+/// written next to its cached result.
+fn run_one(
+    runner: Runner,
+    cache_path: &Path,
+    analysis: &Analysis,
+    resolved: &Value,
+) -> Result<Value, Box<dyn std::error::Error>> {
+    let input_path = cache_path.with_extension("input.json");
+    write_json(&input_path, resolved)?;
+    let output = runner(&analysis.command, &input_path);
+    let _ = fs::remove_file(&input_path);
+    output
+}
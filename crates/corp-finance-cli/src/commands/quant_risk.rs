@@ -3,6 +3,7 @@ use serde_json::Value;
 
 use corp_finance_core::quant_risk::black_litterman::{self, BlackLittermanInput};
 use corp_finance_core::quant_risk::factor_models::{self, FactorModelInput};
+use corp_finance_core::quant_risk::hrp::{self, HrpInput};
 use corp_finance_core::quant_risk::risk_parity::{self, RiskParityInput};
 use corp_finance_core::quant_risk::stress_testing::{self, StressTestInput};
 
@@ -32,6 +33,14 @@ pub struct RiskParityArgs {
     pub input: Option<String>,
 }
 
+/// Arguments for hierarchical risk parity portfolio construction
+#[derive(Args)]
+pub struct HrpArgs {
+    /// Path to JSON input file
+    #[arg(long)]
+    pub input: Option<String>,
+}
+
 /// Arguments for portfolio stress testing
 #[derive(Args)]
 pub struct StressTestArgs {
@@ -76,6 +85,18 @@ pub fn run_risk_parity(args: RiskParityArgs) -> Result<Value, Box<dyn std::error
     Ok(serde_json::to_value(result)?)
 }
 
+pub fn run_hrp(args: HrpArgs) -> Result<Value, Box<dyn std::error::Error>> {
+    let hrp_input: HrpInput = if let Some(ref path) = args.input {
+        input::file::read_json(path)?
+    } else if let Some(data) = input::stdin::read_stdin()? {
+        serde_json::from_value(data)?
+    } else {
+        return Err("--input <file.json> or stdin required for hierarchical risk parity".into());
+    };
+    let result = hrp::calculate_hrp(&hrp_input)?;
+    Ok(serde_json::to_value(result)?)
+}
+
 pub fn run_stress_test(args: StressTestArgs) -> Result<Value, Box<dyn std::error::Error>> {
     let st_input: StressTestInput = if let Some(ref path) = args.input {
         input::file::read_json(path)?
@@ -1,6 +1,7 @@
 use clap::Args;
 use serde_json::Value;
 
+use corp_finance_core::quant_strategies::event_study::{self, EventStudyInput};
 use corp_finance_core::quant_strategies::momentum::{self, MomentumInput};
 use corp_finance_core::quant_strategies::pairs_trading::{self, PairsTradingInput};
 
@@ -22,6 +23,14 @@ pub struct MomentumArgs {
     pub input: Option<String>,
 }
 
+/// Arguments for event study
+#[derive(Args)]
+pub struct EventStudyArgs {
+    /// Path to JSON input file
+    #[arg(long)]
+    pub input: Option<String>,
+}
+
 pub fn run_pairs_trading(args: PairsTradingArgs) -> Result<Value, Box<dyn std::error::Error>> {
     let pairs_input: PairsTradingInput = if let Some(ref path) = args.input {
         input::file::read_json(path)?
@@ -45,3 +54,15 @@ pub fn run_momentum(args: MomentumArgs) -> Result<Value, Box<dyn std::error::Err
     let result = momentum::analyze_momentum(&momentum_input)?;
     Ok(serde_json::to_value(result)?)
 }
+
+pub fn run_event_study(args: EventStudyArgs) -> Result<Value, Box<dyn std::error::Error>> {
+    let input_data: EventStudyInput = if let Some(ref path) = args.input {
+        input::file::read_json(path)?
+    } else if let Some(data) = input::stdin::read_stdin()? {
+        serde_json::from_value(data)?
+    } else {
+        return Err("--input <file.json> or stdin required for event study".into());
+    };
+    let result = event_study::run_event_study(&input_data)?;
+    Ok(serde_json::to_value(result)?)
+}
@@ -4,6 +4,7 @@ use serde_json::Value;
 use corp_finance_core::regulatory::alm::{self, AlmInput};
 use corp_finance_core::regulatory::capital::{self, RegulatoryCapitalInput};
 use corp_finance_core::regulatory::liquidity::{self, LcrInput, NsfrInput};
+use corp_finance_core::regulatory::saccr::{self, SaccrInput};
 
 use crate::input;
 
@@ -39,6 +40,14 @@ pub struct AlmArgs {
     pub input: Option<String>,
 }
 
+/// Arguments for SA-CCR exposure calculation
+#[derive(Args)]
+pub struct SaccrArgs {
+    /// Path to JSON input file
+    #[arg(long)]
+    pub input: Option<String>,
+}
+
 pub fn run_regulatory_capital(
     args: RegulatoryCapitalArgs,
 ) -> Result<Value, Box<dyn std::error::Error>> {
@@ -88,3 +97,15 @@ pub fn run_alm(args: AlmArgs) -> Result<Value, Box<dyn std::error::Error>> {
     let result = alm::analyze_alm(&alm_input)?;
     Ok(serde_json::to_value(result)?)
 }
+
+pub fn run_saccr(args: SaccrArgs) -> Result<Value, Box<dyn std::error::Error>> {
+    let saccr_input: SaccrInput = if let Some(ref path) = args.input {
+        input::file::read_json(path)?
+    } else if let Some(data) = input::stdin::read_stdin()? {
+        serde_json::from_value(data)?
+    } else {
+        return Err("--input <file.json> or stdin required for SA-CCR".into());
+    };
+    let result = saccr::calculate_saccr(&saccr_input)?;
+    Ok(serde_json::to_value(result)?)
+}
@@ -4,6 +4,7 @@ use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
 use corp_finance_core::credit::metrics::{self, CreditMetricsInput};
+use corp_finance_core::scenarios::market_shift::{self, MarketShiftInput};
 use corp_finance_core::valuation::wacc::{self, WaccInput};
 
 use crate::input;
@@ -24,11 +25,19 @@ pub struct SensitivityArgs {
     #[arg(long)]
     pub var2: Option<String>,
 
-    /// Path to JSON file with base case inputs
-    #[arg(long)]
+    /// Path to JSON file with base case inputs (`-` reads stdin)
+    #[arg(long, visible_alias = "input")]
     pub base_inputs: String,
 }
 
+/// Arguments for scenario-consistent market data shifting
+#[derive(Args)]
+pub struct ShiftMarketDataArgs {
+    /// Path to JSON input file
+    #[arg(long)]
+    pub input: Option<String>,
+}
+
 #[derive(Debug, Clone)]
 struct SensVar {
     name: String,
@@ -179,3 +188,17 @@ pub fn run_sensitivity(args: SensitivityArgs) -> Result<Value, Box<dyn std::erro
 
     Ok(serde_json::to_value(output)?)
 }
+
+pub fn run_shift_market_data(
+    args: ShiftMarketDataArgs,
+) -> Result<Value, Box<dyn std::error::Error>> {
+    let shift_input: MarketShiftInput = if let Some(ref path) = args.input {
+        input::file::read_json(path)?
+    } else if let Some(data) = input::stdin::read_stdin()? {
+        serde_json::from_value(data)?
+    } else {
+        return Err("--input <file.json> or stdin required for market data shifting".into());
+    };
+    let result = market_shift::shift_market_data(&shift_input)?;
+    Ok(serde_json::to_value(result)?)
+}
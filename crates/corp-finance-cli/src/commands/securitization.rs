@@ -2,6 +2,7 @@ use clap::Args;
 use serde_json::Value;
 
 use corp_finance_core::securitization::abs_mbs::{self, AbsMbsInput};
+use corp_finance_core::securitization::cmbs::{self, CmbsInput};
 use corp_finance_core::securitization::tranching::{self, TranchingInput};
 
 use crate::input;
@@ -14,6 +15,14 @@ pub struct AbsMbsArgs {
     pub input: Option<String>,
 }
 
+/// Arguments for CMBS loan-level cash flow modelling
+#[derive(Args)]
+pub struct CmbsArgs {
+    /// Path to JSON input file
+    #[arg(long)]
+    pub input: Option<String>,
+}
+
 /// Arguments for CDO/CLO tranching analysis
 #[derive(Args)]
 pub struct TranchingArgs {
@@ -34,6 +43,18 @@ pub fn run_abs_mbs(args: AbsMbsArgs) -> Result<Value, Box<dyn std::error::Error>
     Ok(serde_json::to_value(result)?)
 }
 
+pub fn run_cmbs(args: CmbsArgs) -> Result<Value, Box<dyn std::error::Error>> {
+    let cmbs_input: CmbsInput = if let Some(ref path) = args.input {
+        input::file::read_json(path)?
+    } else if let Some(data) = input::stdin::read_stdin()? {
+        serde_json::from_value(data)?
+    } else {
+        return Err("--input <file.json> or stdin required for CMBS modelling".into());
+    };
+    let result = cmbs::model_cmbs_cashflows(&cmbs_input)?;
+    Ok(serde_json::to_value(result)?)
+}
+
 pub fn run_tranching(args: TranchingArgs) -> Result<Value, Box<dyn std::error::Error>> {
     let tr_input: TranchingInput = if let Some(ref path) = args.input {
         input::file::read_json(path)?
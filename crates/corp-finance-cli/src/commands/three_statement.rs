@@ -1,6 +1,8 @@
 use clap::Args;
 use serde_json::Value;
 
+use corp_finance_core::three_statement::dcf_bridge::{self, ModelToDcfInput};
+use corp_finance_core::three_statement::ltm::{self, AnnualizeInput, CalendarizeInput, LtmInput};
 use corp_finance_core::three_statement::model::{self, ThreeStatementInput};
 
 use crate::input;
@@ -13,6 +15,30 @@ pub struct ThreeStatementArgs {
     pub input: Option<String>,
 }
 
+/// Arguments for LTM build-up
+#[derive(Args)]
+pub struct LtmArgs {
+    /// Path to JSON input file
+    #[arg(long)]
+    pub input: Option<String>,
+}
+
+/// Arguments for calendarization
+#[derive(Args)]
+pub struct CalendarizeArgs {
+    /// Path to JSON input file
+    #[arg(long)]
+    pub input: Option<String>,
+}
+
+/// Arguments for partial-period annualization
+#[derive(Args)]
+pub struct AnnualizeArgs {
+    /// Path to JSON input file
+    #[arg(long)]
+    pub input: Option<String>,
+}
+
 pub fn run_three_statement(args: ThreeStatementArgs) -> Result<Value, Box<dyn std::error::Error>> {
     let ts_input: ThreeStatementInput = if let Some(ref path) = args.input {
         input::file::read_json(path)?
@@ -24,3 +50,59 @@ pub fn run_three_statement(args: ThreeStatementArgs) -> Result<Value, Box<dyn st
     let result = model::build_three_statement_model(&ts_input)?;
     Ok(serde_json::to_value(result)?)
 }
+
+/// Arguments for the three-statement model to DCF pipeline
+#[derive(Args)]
+pub struct ModelToDcfArgs {
+    /// Path to JSON input file ({ "model": {...}, "valuation": {...} })
+    #[arg(long)]
+    pub input: Option<String>,
+}
+
+pub fn run_model_to_dcf(args: ModelToDcfArgs) -> Result<Value, Box<dyn std::error::Error>> {
+    let bridge_input: ModelToDcfInput = if let Some(ref path) = args.input {
+        input::file::read_json(path)?
+    } else if let Some(data) = input::stdin::read_stdin()? {
+        serde_json::from_value(data)?
+    } else {
+        return Err("--input <file.json> or stdin required for model-to-dcf".into());
+    };
+    let result = dcf_bridge::model_to_dcf(&bridge_input)?;
+    Ok(serde_json::to_value(result)?)
+}
+
+pub fn run_ltm(args: LtmArgs) -> Result<Value, Box<dyn std::error::Error>> {
+    let input_data: LtmInput = if let Some(ref path) = args.input {
+        input::file::read_json(path)?
+    } else if let Some(data) = input::stdin::read_stdin()? {
+        serde_json::from_value(data)?
+    } else {
+        return Err("--input <file.json> or stdin required for LTM build-up".into());
+    };
+    let result = ltm::build_ltm(&input_data)?;
+    Ok(serde_json::to_value(result)?)
+}
+
+pub fn run_calendarize(args: CalendarizeArgs) -> Result<Value, Box<dyn std::error::Error>> {
+    let input_data: CalendarizeInput = if let Some(ref path) = args.input {
+        input::file::read_json(path)?
+    } else if let Some(data) = input::stdin::read_stdin()? {
+        serde_json::from_value(data)?
+    } else {
+        return Err("--input <file.json> or stdin required for calendarization".into());
+    };
+    let result = ltm::calendarize(&input_data)?;
+    Ok(serde_json::to_value(result)?)
+}
+
+pub fn run_annualize(args: AnnualizeArgs) -> Result<Value, Box<dyn std::error::Error>> {
+    let input_data: AnnualizeInput = if let Some(ref path) = args.input {
+        input::file::read_json(path)?
+    } else if let Some(data) = input::stdin::read_stdin()? {
+        serde_json::from_value(data)?
+    } else {
+        return Err("--input <file.json> or stdin required for annualization".into());
+    };
+    let result = ltm::annualize_period(&input_data)?;
+    Ok(serde_json::to_value(result)?)
+}
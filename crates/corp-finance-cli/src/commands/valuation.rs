@@ -3,6 +3,16 @@ use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
 use serde_json::Value;
 
+use corp_finance_core::valuation::adjustments::{self, ValuationAdjustmentsInput};
+use corp_finance_core::valuation::apv::{self, ApvInput};
+use corp_finance_core::valuation::asset_based::{self, AssetBasedInput};
+use corp_finance_core::valuation::comps::{self, CompsInput};
+use corp_finance_core::valuation::cost_of_equity::{self, CostOfEquityInput};
+use corp_finance_core::valuation::dcf::{self, DcfInput};
+use corp_finance_core::valuation::economic_profit::{self, EconomicProfitInput};
+use corp_finance_core::valuation::peer_screen::{self, PeerScreenInput};
+use corp_finance_core::valuation::rate_consistency::{self, RateConsistencyInput};
+use corp_finance_core::valuation::small_business::{self, SmallBusinessInput};
 use corp_finance_core::valuation::wacc::{self, WaccInput};
 
 use crate::input;
@@ -70,36 +80,76 @@ pub struct DcfArgs {
     /// Path to JSON input file with DCF parameters
     #[arg(long)]
     pub input: Option<String>,
+}
 
-    /// Base revenue for projection
+/// Arguments for comparable company analysis
+#[derive(Args)]
+pub struct CompsArgs {
+    /// Path to JSON input file with comparable company data
     #[arg(long)]
-    pub base_revenue: Option<Decimal>,
+    pub input: Option<String>,
+}
 
-    /// Revenue growth rate
+/// Arguments for small business valuation
+#[derive(Args)]
+pub struct SmallBusinessArgs {
+    /// Path to JSON input file with historical earnings, owner add-backs and build-up rate
     #[arg(long)]
-    pub growth_rate: Option<Decimal>,
+    pub input: Option<String>,
+}
 
-    /// EBITDA margin
+/// Arguments for peer screening
+#[derive(Args)]
+pub struct PeerScreenArgs {
+    /// Path to JSON input file
     #[arg(long)]
-    pub ebitda_margin: Option<Decimal>,
+    pub input: Option<String>,
+}
 
-    /// Discount rate (WACC)
+/// Arguments for asset-based valuation
+#[derive(Args)]
+pub struct AssetBasedValuationArgs {
+    /// Path to JSON input file
     #[arg(long)]
-    pub discount_rate: Option<Decimal>,
+    pub input: Option<String>,
+}
 
-    /// Terminal growth rate
+/// Arguments for cost of equity build-up
+#[derive(Args)]
+pub struct CostOfEquityArgs {
+    /// Path to JSON input file
     #[arg(long)]
-    pub terminal_growth: Option<Decimal>,
+    pub input: Option<String>,
+}
 
-    /// Projection years
-    #[arg(long, default_value = "5")]
-    pub years: u32,
+/// Arguments for valuation adjustments
+#[derive(Args)]
+pub struct ValuationAdjustmentsArgs {
+    /// Path to JSON input file
+    #[arg(long)]
+    pub input: Option<String>,
 }
 
-/// Arguments for comparable company analysis
+/// Arguments for economic profit valuation
 #[derive(Args)]
-pub struct CompsArgs {
-    /// Path to JSON input file with comparable company data
+pub struct EconomicProfitArgs {
+    /// Path to JSON input file
+    #[arg(long)]
+    pub input: Option<String>,
+}
+
+/// Arguments for adjusted present value
+#[derive(Args)]
+pub struct ApvArgs {
+    /// Path to JSON input file
+    #[arg(long)]
+    pub input: Option<String>,
+}
+
+/// Arguments for rate consistency check
+#[derive(Args)]
+pub struct RateConsistencyArgs {
+    /// Path to JSON input file
     #[arg(long)]
     pub input: Option<String>,
 }
@@ -143,43 +193,127 @@ pub fn run_wacc(args: WaccArgs) -> Result<Value, Box<dyn std::error::Error>> {
 }
 
 pub fn run_dcf(args: DcfArgs) -> Result<Value, Box<dyn std::error::Error>> {
-    let input_data: Value = if let Some(ref path) = args.input {
-        input::file::read_json_value(path)?
+    let dcf_input: DcfInput = if let Some(ref path) = args.input {
+        input::file::read_json(path)?
     } else if let Some(data) = input::stdin::read_stdin()? {
-        data
+        serde_json::from_value(data)?
     } else {
-        // Build from CLI args
-        serde_json::json!({
-            "base_revenue": args.base_revenue.map(|v| v.to_string()),
-            "growth_rate": args.growth_rate.map(|v| v.to_string()),
-            "ebitda_margin": args.ebitda_margin.map(|v| v.to_string()),
-            "discount_rate": args.discount_rate.map(|v| v.to_string()),
-            "terminal_growth": args.terminal_growth.map(|v| v.to_string()),
-            "years": args.years,
-        })
+        return Err("--input <file.json> or stdin required for DCF valuation".into());
     };
-
-    // DCF module may not be fully implemented yet; pass through as structured data
-    Err(format!(
-        "DCF model not yet available. Input received: {}",
-        serde_json::to_string_pretty(&input_data)?
-    )
-    .into())
+    let result = dcf::calculate_dcf(&dcf_input)?;
+    Ok(serde_json::to_value(result)?)
 }
 
 pub fn run_comps(args: CompsArgs) -> Result<Value, Box<dyn std::error::Error>> {
-    let input_data: Value = if let Some(ref path) = args.input {
-        input::file::read_json_value(path)?
+    let comps_input: CompsInput = if let Some(ref path) = args.input {
+        input::file::read_json(path)?
     } else if let Some(data) = input::stdin::read_stdin()? {
-        data
+        serde_json::from_value(data)?
     } else {
         return Err("--input file is required for comps analysis".into());
     };
+    let result = comps::calculate_comps(&comps_input)?;
+    Ok(serde_json::to_value(result)?)
+}
 
-    // Comps module may not be fully implemented yet
-    Err(format!(
-        "Comps analysis not yet available. Input received: {}",
-        serde_json::to_string_pretty(&input_data)?
-    )
-    .into())
+pub fn run_small_business(args: SmallBusinessArgs) -> Result<Value, Box<dyn std::error::Error>> {
+    let sb_input: SmallBusinessInput = if let Some(ref path) = args.input {
+        input::file::read_json(path)?
+    } else if let Some(data) = input::stdin::read_stdin()? {
+        serde_json::from_value(data)?
+    } else {
+        return Err("--input <file.json> or stdin required for small-business".into());
+    };
+    let result = small_business::value_small_business(&sb_input)?;
+    Ok(serde_json::to_value(result)?)
+}
+
+pub fn run_peer_screen(args: PeerScreenArgs) -> Result<Value, Box<dyn std::error::Error>> {
+    let input_data: PeerScreenInput = if let Some(ref path) = args.input {
+        input::file::read_json(path)?
+    } else if let Some(data) = input::stdin::read_stdin()? {
+        serde_json::from_value(data)?
+    } else {
+        return Err("--input <file.json> or stdin required for peer screening".into());
+    };
+    let result = peer_screen::peer_screen(&input_data)?;
+    Ok(serde_json::to_value(result)?)
+}
+
+pub fn run_asset_based_valuation(
+    args: AssetBasedValuationArgs,
+) -> Result<Value, Box<dyn std::error::Error>> {
+    let input_data: AssetBasedInput = if let Some(ref path) = args.input {
+        input::file::read_json(path)?
+    } else if let Some(data) = input::stdin::read_stdin()? {
+        serde_json::from_value(data)?
+    } else {
+        return Err("--input <file.json> or stdin required for asset-based valuation".into());
+    };
+    let result = asset_based::asset_based_valuation(&input_data)?;
+    Ok(serde_json::to_value(result)?)
+}
+
+pub fn run_cost_of_equity(args: CostOfEquityArgs) -> Result<Value, Box<dyn std::error::Error>> {
+    let input_data: CostOfEquityInput = if let Some(ref path) = args.input {
+        input::file::read_json(path)?
+    } else if let Some(data) = input::stdin::read_stdin()? {
+        serde_json::from_value(data)?
+    } else {
+        return Err("--input <file.json> or stdin required for cost of equity build-up".into());
+    };
+    let result = cost_of_equity::build_cost_of_equity(&input_data)?;
+    Ok(serde_json::to_value(result)?)
+}
+
+pub fn run_valuation_adjustments(
+    args: ValuationAdjustmentsArgs,
+) -> Result<Value, Box<dyn std::error::Error>> {
+    let input_data: ValuationAdjustmentsInput = if let Some(ref path) = args.input {
+        input::file::read_json(path)?
+    } else if let Some(data) = input::stdin::read_stdin()? {
+        serde_json::from_value(data)?
+    } else {
+        return Err("--input <file.json> or stdin required for valuation adjustments".into());
+    };
+    let result = adjustments::apply_valuation_adjustments(&input_data)?;
+    Ok(serde_json::to_value(result)?)
+}
+
+pub fn run_economic_profit(args: EconomicProfitArgs) -> Result<Value, Box<dyn std::error::Error>> {
+    let input_data: EconomicProfitInput = if let Some(ref path) = args.input {
+        input::file::read_json(path)?
+    } else if let Some(data) = input::stdin::read_stdin()? {
+        serde_json::from_value(data)?
+    } else {
+        return Err("--input <file.json> or stdin required for economic profit valuation".into());
+    };
+    let result = economic_profit::economic_profit_valuation(&input_data)?;
+    Ok(serde_json::to_value(result)?)
+}
+
+pub fn run_apv(args: ApvArgs) -> Result<Value, Box<dyn std::error::Error>> {
+    let input_data: ApvInput = if let Some(ref path) = args.input {
+        input::file::read_json(path)?
+    } else if let Some(data) = input::stdin::read_stdin()? {
+        serde_json::from_value(data)?
+    } else {
+        return Err("--input <file.json> or stdin required for adjusted present value".into());
+    };
+    let result = apv::calculate_apv(&input_data)?;
+    Ok(serde_json::to_value(result)?)
+}
+
+pub fn run_rate_consistency(
+    args: RateConsistencyArgs,
+) -> Result<Value, Box<dyn std::error::Error>> {
+    let input_data: RateConsistencyInput = if let Some(ref path) = args.input {
+        input::file::read_json(path)?
+    } else if let Some(data) = input::stdin::read_stdin()? {
+        serde_json::from_value(data)?
+    } else {
+        return Err("--input <file.json> or stdin required for rate consistency check".into());
+    };
+    let result = rate_consistency::check_rate_consistency(&input_data)?;
+    Ok(serde_json::to_value(result)?)
 }
@@ -8,6 +8,9 @@ use crate::input;
 
 #[derive(Args)]
 pub struct WorkflowListArgs {
+    #[arg(long)]
+    pub input: Option<String>,
+
     #[arg(long)]
     pub domain: Option<String>,
 }
@@ -15,7 +18,10 @@ pub struct WorkflowListArgs {
 #[derive(Args)]
 pub struct WorkflowDescribeArgs {
     #[arg(long)]
-    pub workflow_id: String,
+    pub input: Option<String>,
+
+    #[arg(long)]
+    pub workflow_id: Option<String>,
 }
 
 #[derive(Args)]
@@ -37,8 +43,16 @@ pub struct WorkflowAuditArgs {
 }
 
 pub fn run_workflow_list(args: WorkflowListArgs) -> Result<Value, Box<dyn std::error::Error>> {
-    let input_data = types::WorkflowListInput {
-        domain: args.domain,
+    let input_data: types::WorkflowListInput = if let Some(ref path) = args.input {
+        input::file::read_json(path)?
+    } else if args.domain.is_some() {
+        types::WorkflowListInput {
+            domain: args.domain,
+        }
+    } else if let Some(data) = input::stdin::read_stdin()? {
+        serde_json::from_value(data)?
+    } else {
+        types::WorkflowListInput { domain: None }
     };
     let result = types::list_workflows(&input_data)?;
     Ok(serde_json::to_value(result)?)
@@ -47,8 +61,14 @@ pub fn run_workflow_list(args: WorkflowListArgs) -> Result<Value, Box<dyn std::e
 pub fn run_workflow_describe(
     args: WorkflowDescribeArgs,
 ) -> Result<Value, Box<dyn std::error::Error>> {
-    let input_data = types::WorkflowDescribeInput {
-        workflow_id: args.workflow_id,
+    let input_data: types::WorkflowDescribeInput = if let Some(ref path) = args.input {
+        input::file::read_json(path)?
+    } else if let Some(workflow_id) = args.workflow_id {
+        types::WorkflowDescribeInput { workflow_id }
+    } else if let Some(data) = input::stdin::read_stdin()? {
+        serde_json::from_value(data)?
+    } else {
+        return Err("Provide --workflow-id, or --input <file.json> or stdin".into());
     };
     let result = types::describe_workflow(&input_data)?;
     Ok(serde_json::to_value(result)?)
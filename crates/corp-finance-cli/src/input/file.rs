@@ -3,26 +3,43 @@ use serde_json::Value;
 use std::fs;
 use std::path::Path;
 
-/// Read a JSON file and deserialise into a typed struct.
+use super::{market_data, overrides, stdin, units};
+
+/// Read a JSON file (or stdin when `path` is `-`), apply any `--set`
+/// overrides and deserialise into a typed struct.
 pub fn read_json<T: DeserializeOwned>(path: &str) -> Result<T, Box<dyn std::error::Error>> {
-    let canonical = resolve_path(path)?;
-    let contents = fs::read_to_string(&canonical)
-        .map_err(|e| format!("Failed to read '{}': {}", canonical.display(), e))?;
-    let value: T = serde_json::from_str(&contents)
-        .map_err(|e| format!("Failed to parse '{}': {}", canonical.display(), e))?;
-    Ok(value)
+    let value = read_json_value(path)?;
+    let typed: T = serde_json::from_value(value)
+        .map_err(|e| format!("Failed to parse '{}': {}", display_name(path), e))?;
+    Ok(typed)
 }
 
-/// Read a JSON file as a generic serde_json::Value.
+/// Read a JSON file (or stdin when `path` is `-`) as a generic
+/// serde_json::Value, with any `--set` overrides applied, rates
+/// normalised to decimals and market data references resolved.
 pub fn read_json_value(path: &str) -> Result<Value, Box<dyn std::error::Error>> {
+    if path == "-" {
+        return stdin::read_stdin_required();
+    }
     let canonical = resolve_path(path)?;
     let contents = fs::read_to_string(&canonical)
         .map_err(|e| format!("Failed to read '{}': {}", canonical.display(), e))?;
-    let value: Value = serde_json::from_str(&contents)
+    let mut value: Value = serde_json::from_str(&contents)
         .map_err(|e| format!("Failed to parse '{}': {}", canonical.display(), e))?;
+    overrides::apply(&mut value)?;
+    units::apply(&mut value)?;
+    market_data::apply(&mut value)?;
     Ok(value)
 }
 
+fn display_name(path: &str) -> &str {
+    if path == "-" {
+        "stdin"
+    } else {
+        path
+    }
+}
+
 /// Resolve and validate the path, preventing directory traversal.
 fn resolve_path(path: &str) -> Result<std::path::PathBuf, Box<dyn std::error::Error>> {
     let p = Path::new(path);
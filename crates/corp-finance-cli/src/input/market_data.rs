@@ -0,0 +1,50 @@
+use serde_json::Value;
+use std::fs;
+use std::path::Path;
+use std::sync::OnceLock;
+
+use corp_finance_core::market_data::{self, MarketData};
+
+static DATA: OnceLock<MarketData> = OnceLock::new();
+
+/// Load the `--market-data` files: CSV when the extension is `.csv`,
+/// `.tsv` or `.txt`, JSON otherwise. Later files replace series of the
+/// same identifier from earlier ones.
+pub fn init(paths: &[String]) -> Result<(), Box<dyn std::error::Error>> {
+    let mut data = MarketData::default();
+    for path in paths {
+        let contents =
+            fs::read_to_string(path).map_err(|e| format!("Failed to read '{path}': {e}"))?;
+        let extension = Path::new(path)
+            .extension()
+            .and_then(|e| e.to_str())
+            .unwrap_or_default()
+            .to_lowercase();
+        let loaded = if matches!(extension.as_str(), "csv" | "tsv" | "txt") {
+            MarketData::from_csv(&contents)
+        } else {
+            let value: Value = serde_json::from_str(&contents)
+                .map_err(|e| format!("Failed to parse '{path}': {e}"))?;
+            MarketData::from_json(&value)
+        }
+        .map_err(|e| format!("Failed to load market data '{path}': {e}"))?;
+        data.merge(loaded);
+    }
+    let _ = DATA.set(data);
+    Ok(())
+}
+
+/// Replace `$series` / `$date_index` references in a JSON input document
+/// with values from the loaded market data.
+pub fn apply(doc: &mut Value) -> Result<(), Box<dyn std::error::Error>> {
+    let empty = MarketData::default();
+    let data = DATA.get().unwrap_or(&empty);
+    market_data::resolve_references(doc, data).map_err(|e| {
+        if data.series.is_empty() {
+            format!("{e} (load series with --market-data <file>)")
+        } else {
+            e.to_string()
+        }
+    })?;
+    Ok(())
+}
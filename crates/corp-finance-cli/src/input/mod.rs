@@ -1,2 +1,5 @@
 pub mod file;
+pub mod market_data;
+pub mod overrides;
 pub mod stdin;
+pub mod units;
@@ -0,0 +1,131 @@
+use serde_json::{Map, Value};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::OnceLock;
+
+/// A `--set field.path=value` override applied on top of the JSON input.
+#[derive(Debug, Clone)]
+pub struct Override {
+    raw_path: String,
+    path: Vec<String>,
+    value: Value,
+}
+
+static OVERRIDES: OnceLock<Vec<Override>> = OnceLock::new();
+static APPLIED: AtomicBool = AtomicBool::new(false);
+
+/// Parse `field.path=value`. Path segments are separated by `.`, and array
+/// elements are addressed by index (`tranches.0.amount` or
+/// `tranches[0].amount`). The value is read as JSON when it parses
+/// (numbers, booleans, null, arrays, objects, quoted strings) and as a plain
+/// string otherwise.
+pub fn parse(s: &str) -> Result<Override, String> {
+    let (raw_path, raw_value) = s
+        .split_once('=')
+        .ok_or_else(|| format!("invalid override '{s}': expected field.path=value"))?;
+    let path = parse_path(raw_path)?;
+    let value = serde_json::from_str(raw_value.trim())
+        .unwrap_or_else(|_| Value::String(raw_value.to_string()));
+    Ok(Override {
+        raw_path: raw_path.to_string(),
+        path,
+        value,
+    })
+}
+
+impl Override {
+    /// Path segments, e.g. `["tranches", "0", "amount"]`.
+    pub fn path(&self) -> &[String] {
+        &self.path
+    }
+
+    pub fn value(&self) -> &Value {
+        &self.value
+    }
+}
+
+fn parse_path(raw_path: &str) -> Result<Vec<String>, String> {
+    let path: Vec<String> = raw_path
+        .replace('[', ".")
+        .replace(']', "")
+        .split('.')
+        .map(str::trim)
+        .map(String::from)
+        .collect();
+    if path.iter().any(String::is_empty) {
+        return Err(format!("invalid override path '{raw_path}'"));
+    }
+    Ok(path)
+}
+
+/// Register the overrides given on the command line.
+pub fn init(overrides: Vec<Override>) {
+    let _ = OVERRIDES.set(overrides);
+}
+
+/// True when `--set` overrides were given on the command line.
+pub fn any() -> bool {
+    OVERRIDES.get().is_some_and(|o| !o.is_empty())
+}
+
+/// True when overrides were given but no JSON input was read to apply them to.
+pub fn unused() -> bool {
+    any() && !APPLIED.load(Ordering::Relaxed)
+}
+
+/// Apply the registered overrides to a JSON input document.
+pub fn apply(doc: &mut Value) -> Result<(), Box<dyn std::error::Error>> {
+    let Some(overrides) = OVERRIDES.get() else {
+        return Ok(());
+    };
+    for o in overrides {
+        set_path(doc, &o.path, o.value.clone())
+            .map_err(|e| format!("--set {}: {e}", o.raw_path))?;
+    }
+    APPLIED.store(true, Ordering::Relaxed);
+    Ok(())
+}
+
+/// Set `value` at a dotted field path (`tranches.0.amount` or
+/// `tranches[0].amount`) of a JSON document.
+pub fn set(doc: &mut Value, path: &str, value: Value) -> Result<(), String> {
+    set_path(doc, &parse_path(path)?, value).map_err(|e| format!("{path}: {e}"))
+}
+
+/// Set `value` at `path`, creating missing object fields along the way.
+/// Array indices must exist, or equal the length to append.
+fn set_path(doc: &mut Value, path: &[String], value: Value) -> Result<(), String> {
+    let Some((key, rest)) = path.split_first() else {
+        *doc = value;
+        return Ok(());
+    };
+    match doc {
+        Value::Object(map) => {
+            let child = map.entry(key.clone()).or_insert_with(|| {
+                if rest.is_empty() {
+                    Value::Null
+                } else {
+                    Value::Object(Map::new())
+                }
+            });
+            set_path(child, rest, value)
+        }
+        Value::Array(items) => {
+            let idx: usize = key
+                .parse()
+                .map_err(|_| format!("'{key}' is not an array index"))?;
+            if idx == items.len() {
+                items.push(Value::Null);
+            }
+            let len = items.len();
+            let child = items
+                .get_mut(idx)
+                .ok_or_else(|| format!("index {idx} out of range (length {len})"))?;
+            set_path(child, rest, value)
+        }
+        Value::Null => {
+            *doc = Value::Object(Map::new());
+            set_path(doc, path, value)
+        }
+        _ => Err(format!("cannot set field '{key}' on a non-object value")),
+    }
+}
@@ -1,6 +1,8 @@
 use serde_json::Value;
 use std::io::{self, Read};
 
+use super::{market_data, overrides, units};
+
 /// Attempt to read JSON from stdin if data is being piped.
 /// Returns None if stdin is a TTY (interactive).
 pub fn read_stdin() -> Result<Option<Value>, Box<dyn std::error::Error>> {
@@ -16,6 +18,22 @@ pub fn read_stdin() -> Result<Option<Value>, Box<dyn std::error::Error>> {
         return Ok(None);
     }
 
-    let value: Value = serde_json::from_str(trimmed)?;
+    let mut value: Value = serde_json::from_str(trimmed)?;
+    overrides::apply(&mut value)?;
+    units::apply(&mut value)?;
+    market_data::apply(&mut value)?;
     Ok(Some(value))
 }
+
+/// Read JSON from stdin for `--input -`, waiting for input even when stdin
+/// is a TTY.
+pub fn read_stdin_required() -> Result<Value, Box<dyn std::error::Error>> {
+    let mut buffer = String::new();
+    io::stdin().read_to_string(&mut buffer)?;
+    let mut value: Value =
+        serde_json::from_str(buffer.trim()).map_err(|e| format!("Failed to parse stdin: {e}"))?;
+    overrides::apply(&mut value)?;
+    units::apply(&mut value)?;
+    market_data::apply(&mut value)?;
+    Ok(value)
+}
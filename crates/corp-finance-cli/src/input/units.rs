@@ -0,0 +1,22 @@
+use serde_json::Value;
+use std::sync::OnceLock;
+
+use corp_finance_core::units::{self, UnitConvention};
+
+static CONVENTION: OnceLock<UnitConvention> = OnceLock::new();
+
+/// Register the rate convention given on the command line. Inputs may still
+/// declare their own with `rate_convention` / `strict_units`.
+pub fn init(convention: UnitConvention) {
+    let _ = CONVENTION.set(convention);
+}
+
+/// Convert the rate-type fields of a JSON input document to decimals,
+/// printing a warning for each magnitude that contradicts the convention.
+pub fn apply(doc: &mut Value) -> Result<(), Box<dyn std::error::Error>> {
+    let default = CONVENTION.get().copied().unwrap_or_default();
+    for warning in units::normalize_input(doc, default)? {
+        eprintln!("warning: {warning}");
+    }
+    Ok(())
+}
@@ -2,9 +2,14 @@ mod commands;
 mod input;
 mod output;
 
-use clap::{Parser, Subcommand, ValueEnum};
+use clap::{CommandFactory, FromArgMatches, Parser, Subcommand, ValueEnum};
 use colored::Colorize;
+use corp_finance_core::precision::{self, PrecisionPolicy, RoundingMode};
+use corp_finance_core::units::{RateConvention, UnitConvention};
+use std::ffi::OsStr;
+use std::path::Path;
 use std::process;
+use std::time::Instant;
 
 use commands::aml_compliance::{KycRiskArgs, SanctionsScreeningArgs};
 use commands::bank_analytics::{
@@ -14,40 +19,56 @@ use commands::behavioral::{ProspectTheoryArgs, SentimentArgs};
 use commands::capital_allocation::{
     EconomicCapitalArgs, EulerAllocationArgs, LimitManagementArgs, RarocArgs, ShapleyAllocationArgs,
 };
+use commands::capital_budgeting::ProjectSelectionArgs;
 use commands::carbon_markets::{
     CarbonPricingArgs, CbamArgs, EtsComplianceArgs, OffsetValuationArgs, ShadowCarbonArgs,
 };
+use commands::cashflow_ladder::CashflowLadderArgs;
 use commands::clo_analytics::{
-    CloCoverageArgs, CloReinvestmentArgs, CloScenarioArgs, CloTrancheArgs, CloWaterfallArgs,
+    CloCoverageArgs, CloPortfolioMetricsArgs, CloReinvestmentArgs, CloReinvestmentOptimizeArgs,
+    CloScenarioArgs, CloTrancheArgs, CloWaterfallArgs,
 };
 use commands::commodity_trading::{CommoditySpreadArgs, StorageEconomicsArgs};
+use commands::completions::CompletionsArgs;
 use commands::compliance::{BestExecutionArgs, GipsReportArgs};
-use commands::convertibles::{ConvertibleAnalysisArgs, ConvertiblePricingArgs};
-use commands::credit::{AltmanArgs, CovenantArgs, CreditArgs, DebtCapacityArgs};
-use commands::credit_derivatives::{CdsArgs, CvaArgs};
+use commands::consumer_lending::{LoanAprArgs, RefinanceArgs, RetailLoanArgs};
+use commands::convertibles::{
+    ConvertibleAnalysisArgs, ConvertibleBookArgs, ConvertiblePricingArgs,
+};
+use commands::credit::{
+    AltmanArgs, AmortizationArgs, CounterpartyExposureArgs, CovenantArgs, CovenantProjectionArgs,
+    CreditArgs, DebtCapacityArgs, DebtMaturityArgs,
+};
+use commands::credit_derivatives::{CdsArgs, CvaArgs, XvaArgs};
 use commands::credit_portfolio::{MigrationArgs, PortfolioCreditRiskArgs};
 use commands::credit_scoring::{
     CreditScorecardArgs, IntensityModelArgs, MertonPdArgs, PdCalibrationArgs, ScoringValidationArgs,
 };
 use commands::crypto::{DefiAnalysisArgs, TokenValuationArgs};
+use commands::deal_model::DealModelArgs;
 use commands::derivatives::{
-    BasisAnalysisArgs, CurrencySwapArgs, ForwardPositionArgs, ForwardPriceArgs, ImpliedVolArgs,
-    IrsArgs, OptionPriceArgs, StrategyArgs,
+    BasisAnalysisArgs, CurrencySwapArgs, ForwardPositionArgs, ForwardPriceArgs,
+    HestonCalibrationArgs, HestonPriceArgs, ImpliedVolArgs, IrsArgs, OptionPriceArgs, StrategyArgs,
 };
+use commands::diff::DiffArgs;
 use commands::dividend_policy::{
-    BuybackArgs, HModelDdmArgs, MultistageDdmArgs, PayoutSustainabilityArgs,
-    TotalShareholderReturnArgs,
+    AsrBuybackArgs, BuybackArgs, HModelDdmArgs, MultistageDdmArgs, OpenMarketBuybackArgs,
+    PayoutOptimizerArgs, PayoutSustainabilityArgs, TotalShareholderReturnArgs,
 };
 use commands::earnings_quality::{
     AccrualQualityArgs, BeneishArgs, EarningsQualityCompositeArgs, PiotroskiArgs,
     RevenueQualityArgs,
 };
+use commands::ecm::{IpoArgs, RightsIssueArgs};
 use commands::emerging_markets::{
     CapitalControlsArgs, CountryRiskPremiumArgs, EmBondAnalysisArgs, EmEquityPremiumArgs,
     PoliticalRiskArgs,
 };
-use commands::equity_research::{SotpArgs, TargetPriceArgs};
+use commands::equity_research::{
+    BankValuationArgs, ReitArgs, ReverseDcfArgs, SotpArgs, TargetPriceArgs,
+};
 use commands::esg::{CarbonFootprintArgs, EsgScoreArgs, GreenBondArgs, SllArgs};
+use commands::examples::ExamplesArgs;
 use commands::fatca_crs::{EntityClassificationArgs, FatcaCrsReportingArgs};
 use commands::financial_forensics::{
     BenfordsLawArgs, DupontAnalysisArgs, PeerBenchmarkingArgs, RedFlagScoringArgs, ZscoreModelsArgs,
@@ -55,11 +76,16 @@ use commands::financial_forensics::{
 use commands::fixed_income::{
     BondPricingArgs, BondYieldArgs, BootstrapArgs, CreditSpreadArgs, DurationArgs, NelsonSiegelArgs,
 };
-use commands::fpa::{BreakevenArgs, RollingForecastArgs, VarianceArgs, WorkingCapitalArgs};
+use commands::fpa::{
+    BacklogRevenueArgs, BreakevenArgs, InventoryOptimizationArgs, RollingForecastArgs,
+    SaasMetricsArgs, UnitEconomicsArgs, VarianceArgs, WorkingCapitalArgs,
+};
+use commands::franchise::FranchiseArgs;
 use commands::fund_of_funds::{
-    CommitmentPacingArgs, FofPortfolioArgs, JCurveArgs, ManagerSelectionArgs,
-    SecondariesPricingArgs,
+    CashFlowForecastArgs, CommitmentPacingArgs, FofPortfolioArgs, JCurveArgs, ManagerSelectionArgs,
+    SecondariesPricingArgs, StyleAnalysisArgs,
 };
+use commands::fund_structuring::{RedemptionStressArgs, SemiLiquidStressArgs};
 use commands::fx_commodities::{
     CommodityCurveArgs, CommodityForwardArgs, CrossRateArgs, FxForwardArgs,
 };
@@ -72,48 +98,70 @@ use commands::infrastructure::{ConcessionArgs, PppModelArgs};
 use commands::insurance::{CombinedRatioArgs, PremiumPricingArgs, ReservingArgs, ScrArgs};
 use commands::interest_rate_models::{ShortRateArgs, TermStructureFitArgs};
 use commands::jurisdiction::{
-    FundFeesArgs, GaapIfrsArgs, GpEconomicsArgs, InvestorNetReturnsArgs, NavArgs,
-    UbtiScreeningArgs, WhtArgs,
+    CapitalAccountStatementsArgs, FundFeesArgs, GaapIfrsArgs, GpEconomicsArgs,
+    InvestorNetReturnsArgs, NavArgs, UbtiScreeningArgs, WhtArgs,
 };
 use commands::lease_accounting::{LeaseClassificationArgs, SaleLeasebackArgs};
-use commands::ma::MergerArgs;
+use commands::ma::{
+    AcquisitionFinancingArgs, EarnoutArgs, MergerArbArgs, MergerArgs, MultiTargetMergerArgs,
+    PpaArgs, PurchasePriceArgs,
+};
 use commands::macro_economics::{InternationalArgs, MonetaryPolicyArgs};
+use commands::man::ManArgs;
 use commands::market_microstructure::{OptimalExecutionArgs, SpreadAnalysisArgs};
 use commands::monte_carlo::{McDcfArgs, MonteCarloArgs};
 use commands::mortgage_analytics::{MbsAnalyticsArgs, PrepaymentArgs};
 use commands::municipal::{MuniAnalysisArgs, MuniBondArgs};
 use commands::offshore_structures::{CaymanFundArgs, LuxFundArgs};
 use commands::onshore_structures::{UkEuFundArgs, UsFundArgs};
-use commands::pe::{LboArgs, ReturnsArgs, WaterfallArgs};
+use commands::pe::{
+    CoInvestmentArgs, InterimNavArgs, LboArgs, PacingArgs, ReturnsArgs, WaterfallArgs,
+};
 use commands::pension::{LdiStrategyArgs, PensionFundingArgs};
 use commands::performance_attribution::{BrinsonArgs, FactorAttributionArgs};
-use commands::portfolio::{KellyArgs, RiskArgs, SharpeArgs};
+use commands::portfolio::{
+    KellyArgs, PortfolioValuationArgs, RevaluationVarArgs, RiskArgs, SharpeArgs,
+};
 use commands::portfolio_optimization::{BlackLittermanPortfolioArgs, MeanVarianceArgs};
-use commands::private_credit::{DirectLoanArgs, SyndicationArgs, UnitrancheArgs};
+use commands::private_credit::{
+    BridgeFinancingArgs, DirectLoanArgs, FundFinanceArgs, LoanPricingArgs, SyndicationArgs,
+    UnitrancheArgs,
+};
 use commands::private_wealth::{
     ConcentratedStockArgs, DirectIndexingArgs, FamilyGovernanceArgs, PhilanthropicVehiclesArgs,
     WealthTransferArgs,
 };
-use commands::quant_risk::{BlackLittermanArgs, FactorModelArgs, RiskParityArgs, StressTestArgs};
-use commands::quant_strategies::{MomentumArgs, PairsTradingArgs};
+use commands::project::{
+    ProjectAddArgs, ProjectInitArgs, ProjectReportArgs, ProjectRunArgs, ProjectScenarioArgs,
+    ProjectStatusArgs,
+};
+use commands::quant_risk::{
+    BlackLittermanArgs, FactorModelArgs, HrpArgs, RiskParityArgs, StressTestArgs,
+};
+use commands::quant_strategies::{EventStudyArgs, MomentumArgs, PairsTradingArgs};
 use commands::real_assets::{ProjectFinanceArgs, PropertyValuationArgs};
 use commands::real_options::{DecisionTreeArgs, RealOptionArgs};
-use commands::regulatory::{AlmArgs, LcrArgs, NsfrArgs, RegulatoryCapitalArgs};
+use commands::regulatory::{AlmArgs, LcrArgs, NsfrArgs, RegulatoryCapitalArgs, SaccrArgs};
 use commands::regulatory_reporting::{AifmdReportingArgs, SecCftcReportingArgs};
 use commands::repo_financing::{CollateralArgs, RepoAnalyticsArgs};
 use commands::restructuring::{DistressedDebtArgs, RecoveryArgs};
 use commands::risk_budgeting::{FactorRiskBudgetArgs, TailRiskArgs};
-use commands::scenarios::SensitivityArgs;
-use commands::securitization::{AbsMbsArgs, TranchingArgs};
+use commands::scenarios::{SensitivityArgs, ShiftMarketDataArgs};
+use commands::securitization::{AbsMbsArgs, CmbsArgs, TranchingArgs};
 use commands::sovereign::{CountryRiskArgs, SovereignBondArgs};
 use commands::structured_products::{ExoticProductArgs, StructuredNoteArgs};
 use commands::substance_requirements::{EconomicSubstanceArgs, JurisdictionSubstanceTestArgs};
 use commands::tax_treaty::{TreatyNetworkArgs, TreatyOptArgs};
-use commands::three_statement::ThreeStatementArgs;
+use commands::three_statement::{
+    AnnualizeArgs, CalendarizeArgs, LtmArgs, ModelToDcfArgs, ThreeStatementArgs,
+};
 use commands::trade_finance::{LetterOfCreditArgs, SupplyChainFinanceArgs};
 use commands::transfer_pricing::{BepsArgs, IntercompanyArgs};
 use commands::treasury::{CashManagementArgs, HedgingArgs};
-use commands::valuation::{CompsArgs, DcfArgs, WaccArgs};
+use commands::valuation::{
+    ApvArgs, AssetBasedValuationArgs, CompsArgs, CostOfEquityArgs, DcfArgs, EconomicProfitArgs,
+    PeerScreenArgs, RateConsistencyArgs, SmallBusinessArgs, ValuationAdjustmentsArgs, WaccArgs,
+};
 use commands::venture::{
     ConvertibleNoteArgs, DilutionArgs, FundingRoundArgs, SafeArgs, VentureFundArgs,
 };
@@ -132,7 +180,10 @@ use commands::workflows::{
     about = "Institutional-grade corporate finance calculations",
     long_about = "A CLI for performing institutional-grade corporate finance calculations \
                   with decimal precision. Supports WACC, DCF, comps, credit metrics, \
-                  PE returns, portfolio analytics, and sensitivity analysis."
+                  PE returns, portfolio analytics, and sensitivity analysis.\n\n\
+                  Calculators take a JSON document via --input <file.json>, --input - or \
+                  piped stdin. Repeat --set field.path=value to override single fields \
+                  of that document, e.g. --set tranches.0.amount=250."
 )]
 struct Cli {
     #[command(subcommand)]
@@ -141,6 +192,47 @@ struct Cli {
     /// Output format
     #[arg(long, default_value = "json", global = true)]
     output: OutputFormat,
+
+    /// Override a field of the JSON input (repeatable), e.g. --set beta=1.3
+    #[arg(
+        long = "set",
+        global = true,
+        value_name = "PATH=VALUE",
+        value_parser = input::overrides::parse
+    )]
+    set: Vec<input::overrides::Override>,
+
+    /// Decimal places for money in the result (overrides CFA_MONEY_DP)
+    #[arg(long, global = true, value_name = "DP")]
+    money_dp: Option<u32>,
+
+    /// Decimal places for rates in the result (overrides CFA_RATE_DP)
+    #[arg(long, global = true, value_name = "DP")]
+    rate_dp: Option<u32>,
+
+    /// Decimal places for ratios and multiples in the result (overrides CFA_RATIO_DP)
+    #[arg(long, global = true, value_name = "DP")]
+    ratio_dp: Option<u32>,
+
+    /// Rounding mode: half_up, half_down, half_even, down, up, floor, ceiling
+    #[arg(long, global = true, value_name = "MODE", value_parser = str::parse::<RoundingMode>)]
+    rounding: Option<RoundingMode>,
+
+    /// How rate inputs are written: decimal (0.05 = 5%) or percent (5 = 5%)
+    #[arg(long, global = true, value_name = "CONVENTION", value_parser = str::parse::<RateConvention>)]
+    rate_convention: Option<RateConvention>,
+
+    /// Number formatting for table and CSV output, e.g. en-GB, de-DE, fr-FR
+    #[arg(long, global = true, value_name = "LOCALE", value_parser = str::parse::<output::locale::Locale>)]
+    locale: Option<output::locale::Locale>,
+
+    /// Reject rate inputs whose magnitude contradicts the rate convention
+    #[arg(long, global = true)]
+    strict_units: bool,
+
+    /// Time series file (CSV or JSON) for `$series` references in the input (repeatable)
+    #[arg(long = "market-data", global = true, value_name = "FILE")]
+    market_data: Vec<String>,
 }
 
 #[derive(Subcommand)]
@@ -151,28 +243,84 @@ enum Commands {
     Dcf(DcfArgs),
     /// Comparable company analysis
     Comps(CompsArgs),
+    /// Small business valuation: SDE normalization, build-up rate and size-banded multiples
+    SmallBusiness(SmallBusinessArgs),
+    /// Screen and rank comparable companies by similarity
+    PeerScreen(PeerScreenArgs),
+    /// Adjusted NAV and orderly/forced liquidation value
+    AssetBasedValuation(AssetBasedValuationArgs),
+    /// Cost of equity build-up from peer betas and risk premia
+    CostOfEquity(CostOfEquityArgs),
+    /// Control premium, minority discount and DLOM bridge from EV to interest value
+    ValuationAdjustments(ValuationAdjustmentsArgs),
+    /// Economic profit (EVA) valuation reconciled to the DCF
+    EconomicProfit(EconomicProfitArgs),
+    /// Adjusted present value: unlevered value plus financing side effects
+    Apv(ApvArgs),
+    /// Check currency and nominal/real consistency of a discount rate with its cash flows
+    RateConsistency(RateConsistencyArgs),
+    /// Franchise economics: franchisee unit returns, franchisor system returns, royalty sensitivity
+    Franchise(FranchiseArgs),
+    /// Capital project selection under multi-year budgets with shadow values
+    ProjectSelection(ProjectSelectionArgs),
     /// Calculate credit metrics from financial statements
     CreditMetrics(CreditArgs),
     /// Estimate debt capacity
     DebtCapacity(DebtCapacityArgs),
+    /// Project debt maturities, refinancing and the maturity wall
+    DebtMaturity(DebtMaturityArgs),
+    /// Generate a period-by-period loan amortisation schedule
+    Amortization(AmortizationArgs),
     /// Run covenant compliance tests
     CovenantTest(CovenantArgs),
+    /// Project formula covenants with step-downs and headroom waterfalls
+    CovenantProjection(CovenantProjectionArgs),
+    /// Counterparty PFE profile and limit utilisation for OTC derivatives
+    CounterpartyExposure(CounterpartyExposureArgs),
     /// Calculate PE fund returns (IRR, MOIC, Cash-on-Cash)
     Returns(ReturnsArgs),
     /// Run sensitivity analysis on any model
     Sensitivity(SensitivityArgs),
+    /// Shift curves, vol surfaces, prices and FX rates under market scenarios
+    ShiftMarketData(ShiftMarketDataArgs),
     /// Calculate Sharpe ratio
     Sharpe(SharpeArgs),
     /// Portfolio risk metrics (VaR, CVaR)
     Risk(RiskArgs),
     /// Kelly criterion position sizing
     Kelly(KellyArgs),
+    /// Value a mixed book against shared market data with Greeks and bumped risk
+    PortfolioValuation(PortfolioValuationArgs),
+    /// Historical or Monte Carlo VaR by full revaluation of typed positions
+    RevaluationVar(RevaluationVarArgs),
     /// Build a full LBO model with multi-tranche debt
     Lbo(LboArgs),
     /// Calculate GP/LP distribution waterfall
     Waterfall(WaterfallArgs),
+    /// Roll private marks forward to an interim NAV with a contribution bridge
+    InterimNav(InterimNavArgs),
+    /// Evaluate a co-investment against fund economics and LP policy limits
+    CoInvestment(CoInvestmentArgs),
+    /// Project capital calls, distributions and NAV by vintage for a pacing plan
+    Pacing(PacingArgs),
     /// Merger accretion/dilution analysis
     Merger(MergerArgs),
+    /// Staged multi-target accretion/dilution with collars and CVRs
+    MultiTargetMerger(MultiTargetMergerArgs),
+    /// Purchase price allocation: goodwill, deferred tax and incremental D&A
+    Ppa(PpaArgs),
+    /// Merger arbitrage spread, implied probability and Kelly sizing
+    MergerArb(MergerArbArgs),
+    /// Acquisition financing bridge and pro-forma leverage profile
+    AcquisitionFinancing(AcquisitionFinancingArgs),
+    /// Locked box vs completion accounts purchase price comparison
+    PurchasePrice(PurchasePriceArgs),
+    /// Earn-out (contingent consideration) valuation
+    Earnout(EarnoutArgs),
+    /// IPO valuation, offering structure and lock-up schedule
+    Ipo(IpoArgs),
+    /// Rights issue TERP, right value and underwriting economics
+    RightsIssue(RightsIssueArgs),
     /// Altman Z-Score bankruptcy prediction
     AltmanZscore(AltmanArgs),
     /// Fund fee modelling (management + performance fees)
@@ -187,6 +335,8 @@ enum Commands {
     GpEconomics(GpEconomicsArgs),
     /// Investor net returns calculator
     InvestorNetReturns(InvestorNetReturnsArgs),
+    /// Per-partner capital account statements with fee, gain and carry allocation
+    CapitalAccountStatements(CapitalAccountStatementsArgs),
     /// UBTI/ECI screening
     UbtiScreening(UbtiScreeningArgs),
     /// Bond pricing (clean/dirty, accrued interest)
@@ -205,6 +355,10 @@ enum Commands {
     OptionPrice(OptionPriceArgs),
     /// Implied volatility solver
     ImpliedVol(ImpliedVolArgs),
+    /// Heston stochastic volatility option pricing
+    HestonPrice(HestonPriceArgs),
+    /// Calibrate Heston parameters to an implied vol surface
+    HestonCalibration(HestonCalibrationArgs),
     /// Forward/futures pricing
     ForwardPrice(ForwardPriceArgs),
     /// Forward position valuation
@@ -219,6 +373,16 @@ enum Commands {
     Strategy(StrategyArgs),
     /// Build a linked three-statement financial model (IS, BS, CF)
     ThreeStatement(ThreeStatementArgs),
+    /// Build a three-statement model and value its unlevered FCF with a DCF
+    ModelToDcf(ModelToDcfArgs),
+    /// Last-twelve-months build-up from annual and interim periods
+    Ltm(LtmArgs),
+    /// Calendarize fiscal periods onto a common twelve-month window
+    Calendarize(CalendarizeArgs),
+    /// Annualize a partial period by run rate or seasonality
+    Annualize(AnnualizeArgs),
+    /// Run a deal end to end: target model, DCF, LBO, pro forma credit metrics and covenants
+    DealModel(DealModelArgs),
     /// Run a generic Monte Carlo simulation
     MonteCarlo(MonteCarloArgs),
     /// Monte Carlo DCF valuation
@@ -229,6 +393,8 @@ enum Commands {
     BlackLitterman(BlackLittermanArgs),
     /// Risk-parity portfolio construction
     RiskParity(RiskParityArgs),
+    /// Hierarchical risk parity portfolio construction (correlation clustering)
+    Hrp(HrpArgs),
     /// Portfolio stress testing across multiple scenarios
     StressTest(StressTestArgs),
     /// Restructuring recovery analysis (APR waterfall)
@@ -249,6 +415,8 @@ enum Commands {
     CommodityCurve(CommodityCurveArgs),
     /// ABS/MBS cash flow modelling (CPR/PSA/CDR/SDA)
     AbsMbs(AbsMbsArgs),
+    /// CMBS loan-level cash flows (balloon defaults, ARA/ASER, advancing)
+    Cmbs(CmbsArgs),
     /// CDO/CLO tranching and waterfall analysis
     Tranching(TranchingArgs),
     /// VC funding round modelling with option pool shuffle
@@ -277,12 +445,22 @@ enum Commands {
     Nsfr(NsfrArgs),
     /// Asset-Liability Management (ALM / IRRBB)
     Alm(AlmArgs),
+    /// Aggregate projected cash flows of bonds, loans, leases, ABS, project finance and annuities into an asset/liability ladder
+    CashflowLadder(CashflowLadderArgs),
+    /// SA-CCR exposure at default for derivative netting sets
+    Saccr(SaccrArgs),
     /// Unitranche pricing (first-out / last-out split)
     Unitranche(UnitrancheArgs),
     /// Direct lending loan model (cash/PIK, delayed draw)
     DirectLoan(DirectLoanArgs),
     /// Loan syndication analysis
     Syndication(SyndicationArgs),
+    /// Leveraged loan pricing off a clearing grid with flex economics
+    LoanPricing(LoanPricingArgs),
+    /// Bridge commitment economics for the underwriter
+    BridgeFinancing(BridgeFinancingArgs),
+    /// NAV facility and subscription line borrowing base and economics
+    FundFinance(FundFinanceArgs),
     /// Insurance loss reserve estimation (Chain-Ladder / Bornhuetter-Ferguson)
     Reserving(ReservingArgs),
     /// Insurance premium pricing (frequency x severity)
@@ -297,14 +475,28 @@ enum Commands {
     Breakeven(BreakevenArgs),
     /// Working capital analysis (DSO, DIO, DPO, CCC)
     WorkingCapital(WorkingCapitalArgs),
+    /// Inventory economics (EOQ, safety stock, revolver sizing)
+    InventoryOptimization(InventoryOptimizationArgs),
     /// Rolling financial forecast
     RollingForecast(RollingForecastArgs),
+    /// Backlog and pipeline revenue recognition with coverage by period
+    BacklogRevenue(BacklogRevenueArgs),
+    /// SaaS metrics: ARR bridge, NRR/GRR, CAC payback, LTV/CAC, Rule of 40
+    SaasMetrics(SaasMetricsArgs),
+    /// Cohort unit economics: retention, discounted LTV and payback
+    UnitEconomics(UnitEconomicsArgs),
     /// Retirement planning projection
     Retirement(RetirementArgs),
     /// Tax-loss harvesting simulation
     Tlh(TlhArgs),
     /// Estate planning (gift tax, GST, trust analysis)
     EstatePlan(EstatePlanArgs),
+    /// Retail loan amortization (mortgage, auto, student) with prepayments and ARM resets
+    RetailLoan(RetailLoanArgs),
+    /// Regulation Z APR, finance charge and points breakeven
+    LoanApr(LoanAprArgs),
+    /// Refinance breakeven, NPV and lifetime interest comparison
+    Refinance(RefinanceArgs),
     /// Token/protocol valuation using on-chain metrics
     TokenValuation(TokenValuationArgs),
     /// DeFi yield, impermanent loss, staking & LP analysis
@@ -325,10 +517,14 @@ enum Commands {
     CdsPricing(CdsArgs),
     /// Credit Valuation Adjustment (CVA/DVA)
     CvaCalculation(CvaArgs),
+    /// Full XVA stack (CVA, DVA, FVA, KVA, MVA) across netting sets
+    XvaCalculation(XvaArgs),
     /// Price a convertible bond (CRR binomial tree)
     ConvertiblePricing(ConvertiblePricingArgs),
     /// Convertible bond scenario analysis
     ConvertibleAnalysis(ConvertibleAnalysisArgs),
+    /// Price a book of convertible or callable bonds on one calibrated lattice
+    ConvertibleBook(ConvertibleBookArgs),
     /// ASC 842 / IFRS 16 lease classification and measurement
     LeaseClassification(LeaseClassificationArgs),
     /// Sale-leaseback transaction analysis
@@ -349,6 +545,12 @@ enum Commands {
     Sotp(SotpArgs),
     /// Equity research target price calculation
     TargetPrice(TargetPriceArgs),
+    /// REIT FFO/AFFO, payout and cap-rate NAV analysis
+    Reit(ReitArgs),
+    /// Bank valuation: warranted P/TBV, excess-capital DDM and peer regression
+    BankValuation(BankValuationArgs),
+    /// Reverse DCF: market-implied growth and margin vs estimates
+    ReverseDcf(ReverseDcfArgs),
     /// Commodity spread analysis (calendar, crack, crush, spark)
     CommoditySpread(CommoditySpreadArgs),
     /// Storage economics analysis (carry trade, injection/withdrawal)
@@ -357,6 +559,8 @@ enum Commands {
     PairsTrading(PairsTradingArgs),
     /// Momentum factor analysis and portfolio construction
     Momentum(MomentumArgs),
+    /// Event study abnormal returns with Patell and BMP tests
+    EventStudy(EventStudyArgs),
     /// Corporate cash management and liquidity analysis
     CashManagement(CashManagementArgs),
     /// Hedge effectiveness analysis (FX, IR hedging)
@@ -393,6 +597,10 @@ enum Commands {
     CaymanFund(CaymanFundArgs),
     /// Luxembourg/Ireland fund structure analysis (SICAV-SIF, RAIF, SCSp, ICAV, QIAIF)
     LuxFund(LuxFundArgs),
+    /// Redemption stress test through gates, lock-ups and side pockets
+    RedemptionStress(RedemptionStressArgs),
+    /// Semi-liquid fund redemption stress with fire sales, credit facility and gate
+    SemiLiquidStress(SemiLiquidStressArgs),
     /// OECD BEPS compliance analysis (CbCR, Pillar Two, functional analysis)
     BepsCompliance(BepsArgs),
     /// Intercompany transfer pricing analysis (CUP, TNMM, Profit Split, CFC)
@@ -473,8 +681,12 @@ enum Commands {
     CloWaterfall(CloWaterfallArgs),
     /// CLO OC/IC coverage tests
     CloCoverage(CloCoverageArgs),
+    /// CLO portfolio quality metrics: WARF, WAS, WAL and Moody's diversity score
+    CloPortfolioMetrics(CloPortfolioMetricsArgs),
     /// CLO reinvestment period analytics
     CloReinvestment(CloReinvestmentArgs),
+    /// CLO reinvestment optimiser: what to buy within WARF, WALS, WAL and diversity limits
+    CloReinvestmentOptimize(CloReinvestmentOptimizeArgs),
     /// CLO tranche analytics (yield, WAL, breakeven CDR)
     CloTranche(CloTrancheArgs),
     /// CLO scenario analysis (stress testing)
@@ -489,6 +701,10 @@ enum Commands {
     SecondariesPricing(SecondariesPricingArgs),
     /// Fund of funds portfolio analytics
     FofPortfolio(FofPortfolioArgs),
+    /// Returns-based style analysis and style drift
+    StyleAnalysis(StyleAnalysisArgs),
+    /// Takahashi-Alexander cash flow forecast for private market commitments
+    CashFlowForecast(CashFlowForecastArgs),
     /// Beneish M-Score earnings manipulation detection
     Beneish(BeneishArgs),
     /// Piotroski F-Score fundamental strength
@@ -519,6 +735,12 @@ enum Commands {
     PayoutSustainability(PayoutSustainabilityArgs),
     /// Total shareholder return decomposition
     TotalShareholderReturn(TotalShareholderReturnArgs),
+    /// Accelerated share repurchase VWAP settlement simulation
+    AsrBuyback(AsrBuybackArgs),
+    /// Open-market 10b5-1 buyback program execution simulation
+    OpenMarketBuyback(OpenMarketBuybackArgs),
+    /// Constrained payout policy optimizer (dividends vs buybacks)
+    PayoutOptimizer(PayoutOptimizerArgs),
     /// Carbon pricing analysis (EU ETS, CBAM, internal)
     CarbonPricing(CarbonPricingArgs),
     /// ETS compliance analysis (allowance, hedging, auction)
@@ -579,6 +801,26 @@ enum Commands {
     WorkflowQualityCheck(WorkflowQualityCheckArgs),
     /// Generate audit trail for workflow execution
     WorkflowAudit(WorkflowAuditArgs),
+    /// Create a project workspace for a transaction's analyses
+    ProjectInit(ProjectInitArgs),
+    /// Add or replace a named analysis in a project
+    ProjectAdd(ProjectAddArgs),
+    /// Define or update a project scenario's field overrides
+    ProjectScenario(ProjectScenarioArgs),
+    /// Run every project analysis under every scenario, reusing fresh cached results
+    ProjectRun(ProjectRunArgs),
+    /// Show which cached project results are fresh, stale or missing
+    ProjectStatus(ProjectStatusArgs),
+    /// Combine cached project results into a scenario comparison report
+    ProjectReport(ProjectReportArgs),
+    /// Compare two calculator outputs field by field
+    Diff(DiffArgs),
+    /// Print a runnable sample input for a calculator
+    Examples(ExamplesArgs),
+    /// Generate a shell completion script
+    Completions(CompletionsArgs),
+    /// Generate a roff man page
+    Man(ManArgs),
     /// Print version information
     Version,
 }
@@ -592,23 +834,198 @@ pub enum OutputFormat {
 }
 
 fn main() {
-    let cli = Cli::parse();
+    let matches = Cli::command().get_matches();
+    let cli = Cli::from_arg_matches(&matches).unwrap_or_else(|e| e.exit());
+    let command_name = matches.subcommand_name().unwrap_or_default().to_string();
+    init_tracing();
+    input::overrides::init(cli.set);
+    input::units::init(UnitConvention {
+        rates: cli.rate_convention.unwrap_or_default(),
+        strict: cli.strict_units,
+    });
+    if let Err(e) = input::market_data::init(&cli.market_data) {
+        eprintln!("{}: {}", "error".red().bold(), e);
+        process::exit(1);
+    }
+    match corp_finance_core::limits::ComputeLimits::from_env() {
+        Ok(limits) => corp_finance_core::limits::configure(limits),
+        Err(e) => {
+            eprintln!("{}: {}", "error".red().bold(), e);
+            process::exit(1);
+        }
+    }
+    match PrecisionPolicy::from_env() {
+        Ok(env_policy) => precision::configure(
+            PrecisionPolicy {
+                money_dp: cli.money_dp,
+                rate_dp: cli.rate_dp,
+                ratio_dp: cli.ratio_dp,
+                mode: cli.rounding.unwrap_or(env_policy.mode),
+            }
+            .or(env_policy),
+        ),
+        Err(e) => {
+            eprintln!("{}: {}", "error".red().bold(), e);
+            process::exit(1);
+        }
+    }
 
     let result: Result<serde_json::Value, Box<dyn std::error::Error>> = match cli.command {
+        Commands::ProjectInit(args) => commands::project::run_project_init(args),
+        Commands::ProjectAdd(args) => commands::project::run_project_add(args, &Cli::command()),
+        Commands::ProjectScenario(args) => commands::project::run_project_scenario(args),
+        Commands::ProjectRun(args) => commands::project::run_project_run(args, run_calculator),
+        Commands::ProjectStatus(args) => commands::project::run_project_status(args),
+        Commands::ProjectReport(args) => commands::project::run_project_report(args),
+        Commands::Diff(args) => commands::diff::run_diff(args),
+        Commands::Examples(args) => match args.command {
+            Some(ref name) => {
+                print!(
+                    "{}",
+                    commands::examples::sample_input(name).unwrap_or_default()
+                );
+                return;
+            }
+            None => commands::examples::run_examples_list(),
+        },
+        Commands::Completions(args) => {
+            print!(
+                "{}",
+                commands::completions::run_completions(args, &Cli::command())
+            );
+            return;
+        }
+        Commands::Man(args) => match commands::man::run_man(args, &Cli::command()) {
+            Ok(page) => {
+                print!("{page}");
+                return;
+            }
+            Err(e) => Err(e),
+        },
+        Commands::Version => {
+            println!("cfa {}", env!("CARGO_PKG_VERSION"));
+            return;
+        }
+        command => run_traced(&command_name, command),
+    };
+
+    let result = result.and_then(|value| {
+        if input::overrides::unused() {
+            Err(
+                "--set overrides require a JSON input (--input <file.json>, --input - or stdin)"
+                    .into(),
+            )
+        } else {
+            Ok(value)
+        }
+    });
+
+    match result {
+        Ok(value) => {
+            output::format_output(&cli.output, &value, cli.locale);
+            process::exit(0);
+        }
+        Err(e) => {
+            eprintln!("{}: {}", "error".red().bold(), e);
+            process::exit(1);
+        }
+    }
+}
+
+/// Run a calculator command on a JSON input file, as `cfa <command> --input <path>`.
+fn run_calculator(
+    command: &str,
+    input: &Path,
+) -> Result<serde_json::Value, Box<dyn std::error::Error>> {
+    let args: [&OsStr; 4] = [
+        "cfa".as_ref(),
+        command.as_ref(),
+        "--input".as_ref(),
+        input.as_os_str(),
+    ];
+    let cli = Cli::try_parse_from(args)?;
+    run_traced(command, cli.command)
+}
+
+/// Log spans and events to stderr when `CFA_LOG` is set, e.g. `CFA_LOG=debug`
+/// or `CFA_LOG=corp_finance_core=trace`. Closed spans report their duration.
+fn init_tracing() {
+    let Ok(filter) = tracing_subscriber::EnvFilter::try_from_env("CFA_LOG") else {
+        return;
+    };
+    tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_span_events(tracing_subscriber::fmt::format::FmtSpan::CLOSE)
+        .with_writer(std::io::stderr)
+        .with_ansi(atty::is(atty::Stream::Stderr))
+        .init();
+}
+
+/// Run a calculator inside a span named after its subcommand, logging the
+/// duration and outcome.
+fn run_traced(
+    name: &str,
+    command: Commands,
+) -> Result<serde_json::Value, Box<dyn std::error::Error>> {
+    let _span = tracing::info_span!("command", name).entered();
+    let start = Instant::now();
+    let result = run_command(command);
+    let elapsed_us = start.elapsed().as_micros() as u64;
+    match &result {
+        Ok(_) => tracing::info!(elapsed_us, "command finished"),
+        Err(e) => tracing::error!(elapsed_us, error = %e, "command failed"),
+    }
+    result
+}
+
+fn run_command(command: Commands) -> Result<serde_json::Value, Box<dyn std::error::Error>> {
+    match command {
         Commands::Wacc(args) => commands::valuation::run_wacc(args),
         Commands::Dcf(args) => commands::valuation::run_dcf(args),
+        Commands::SmallBusiness(args) => commands::valuation::run_small_business(args),
+        Commands::PeerScreen(args) => commands::valuation::run_peer_screen(args),
+        Commands::AssetBasedValuation(args) => commands::valuation::run_asset_based_valuation(args),
+        Commands::CostOfEquity(args) => commands::valuation::run_cost_of_equity(args),
+        Commands::ValuationAdjustments(args) => {
+            commands::valuation::run_valuation_adjustments(args)
+        }
+        Commands::EconomicProfit(args) => commands::valuation::run_economic_profit(args),
+        Commands::Apv(args) => commands::valuation::run_apv(args),
+        Commands::RateConsistency(args) => commands::valuation::run_rate_consistency(args),
+        Commands::Franchise(args) => commands::franchise::run_franchise(args),
+        Commands::ProjectSelection(args) => {
+            commands::capital_budgeting::run_project_selection(args)
+        }
         Commands::Comps(args) => commands::valuation::run_comps(args),
         Commands::CreditMetrics(args) => commands::credit::run_credit_metrics(args),
         Commands::DebtCapacity(args) => commands::credit::run_debt_capacity(args),
+        Commands::DebtMaturity(args) => commands::credit::run_debt_maturity(args),
+        Commands::Amortization(args) => commands::credit::run_amortization(args),
         Commands::CovenantTest(args) => commands::credit::run_covenant_test(args),
+        Commands::CovenantProjection(args) => commands::credit::run_covenant_projection(args),
+        Commands::CounterpartyExposure(args) => commands::credit::run_counterparty_exposure(args),
         Commands::Returns(args) => commands::pe::run_returns(args),
         Commands::Sensitivity(args) => commands::scenarios::run_sensitivity(args),
+        Commands::ShiftMarketData(args) => commands::scenarios::run_shift_market_data(args),
         Commands::Sharpe(args) => commands::portfolio::run_sharpe(args),
         Commands::Risk(args) => commands::portfolio::run_risk(args),
         Commands::Kelly(args) => commands::portfolio::run_kelly(args),
+        Commands::PortfolioValuation(args) => commands::portfolio::run_portfolio_valuation(args),
+        Commands::RevaluationVar(args) => commands::portfolio::run_revaluation_var(args),
         Commands::Lbo(args) => commands::pe::run_lbo(args),
         Commands::Waterfall(args) => commands::pe::run_waterfall(args),
+        Commands::InterimNav(args) => commands::pe::run_interim_nav(args),
+        Commands::CoInvestment(args) => commands::pe::run_co_investment(args),
+        Commands::Pacing(args) => commands::pe::run_pacing(args),
         Commands::Merger(args) => commands::ma::run_merger(args),
+        Commands::MultiTargetMerger(args) => commands::ma::run_multi_target_merger(args),
+        Commands::Ppa(args) => commands::ma::run_ppa(args),
+        Commands::MergerArb(args) => commands::ma::run_merger_arb(args),
+        Commands::AcquisitionFinancing(args) => commands::ma::run_acquisition_financing(args),
+        Commands::PurchasePrice(args) => commands::ma::run_purchase_price(args),
+        Commands::Earnout(args) => commands::ma::run_earnout(args),
+        Commands::Ipo(args) => commands::ecm::run_ipo(args),
+        Commands::RightsIssue(args) => commands::ecm::run_rights_issue(args),
         Commands::AltmanZscore(args) => commands::credit::run_altman(args),
         Commands::FundFees(args) => commands::jurisdiction::run_fund_fees(args),
         Commands::GaapIfrs(args) => commands::jurisdiction::run_gaap_ifrs(args),
@@ -618,6 +1035,9 @@ fn main() {
         Commands::InvestorNetReturns(args) => {
             commands::jurisdiction::run_investor_net_returns(args)
         }
+        Commands::CapitalAccountStatements(args) => {
+            commands::jurisdiction::run_capital_account_statements(args)
+        }
         Commands::UbtiScreening(args) => commands::jurisdiction::run_ubti_screening(args),
         Commands::BondPricing(args) => commands::fixed_income::run_bond_pricing(args),
         Commands::BondYield(args) => commands::fixed_income::run_bond_yield(args),
@@ -627,6 +1047,8 @@ fn main() {
         Commands::CreditSpread(args) => commands::fixed_income::run_credit_spreads(args),
         Commands::OptionPrice(args) => commands::derivatives::run_option_price(args),
         Commands::ImpliedVol(args) => commands::derivatives::run_implied_vol(args),
+        Commands::HestonPrice(args) => commands::derivatives::run_heston_price(args),
+        Commands::HestonCalibration(args) => commands::derivatives::run_heston_calibration(args),
         Commands::ForwardPrice(args) => commands::derivatives::run_forward_price(args),
         Commands::ForwardPosition(args) => commands::derivatives::run_forward_position(args),
         Commands::BasisAnalysis(args) => commands::derivatives::run_basis_analysis(args),
@@ -634,11 +1056,17 @@ fn main() {
         Commands::CurrencySwap(args) => commands::derivatives::run_currency_swap(args),
         Commands::Strategy(args) => commands::derivatives::run_strategy(args),
         Commands::ThreeStatement(args) => commands::three_statement::run_three_statement(args),
+        Commands::ModelToDcf(args) => commands::three_statement::run_model_to_dcf(args),
+        Commands::Ltm(args) => commands::three_statement::run_ltm(args),
+        Commands::Calendarize(args) => commands::three_statement::run_calendarize(args),
+        Commands::Annualize(args) => commands::three_statement::run_annualize(args),
+        Commands::DealModel(args) => commands::deal_model::run_deal_model(args),
         Commands::MonteCarlo(args) => commands::monte_carlo::run_monte_carlo(args),
         Commands::McDcf(args) => commands::monte_carlo::run_mc_dcf(args),
         Commands::FactorModel(args) => commands::quant_risk::run_factor_model(args),
         Commands::BlackLitterman(args) => commands::quant_risk::run_black_litterman(args),
         Commands::RiskParity(args) => commands::quant_risk::run_risk_parity(args),
+        Commands::Hrp(args) => commands::quant_risk::run_hrp(args),
         Commands::StressTest(args) => commands::quant_risk::run_stress_test(args),
         Commands::Recovery(args) => commands::restructuring::run_recovery(args),
         Commands::DistressedDebt(args) => commands::restructuring::run_distressed_debt(args),
@@ -649,6 +1077,7 @@ fn main() {
         Commands::CommodityForward(args) => commands::fx_commodities::run_commodity_forward(args),
         Commands::CommodityCurve(args) => commands::fx_commodities::run_commodity_curve(args),
         Commands::AbsMbs(args) => commands::securitization::run_abs_mbs(args),
+        Commands::Cmbs(args) => commands::securitization::run_cmbs(args),
         Commands::Tranching(args) => commands::securitization::run_tranching(args),
         Commands::FundingRound(args) => commands::venture::run_funding_round(args),
         Commands::Dilution(args) => commands::venture::run_dilution(args),
@@ -663,9 +1092,14 @@ fn main() {
         Commands::Lcr(args) => commands::regulatory::run_lcr(args),
         Commands::Nsfr(args) => commands::regulatory::run_nsfr(args),
         Commands::Alm(args) => commands::regulatory::run_alm(args),
+        Commands::CashflowLadder(args) => commands::cashflow_ladder::run_cashflow_ladder(args),
+        Commands::Saccr(args) => commands::regulatory::run_saccr(args),
         Commands::Unitranche(args) => commands::private_credit::run_unitranche(args),
         Commands::DirectLoan(args) => commands::private_credit::run_direct_loan(args),
         Commands::Syndication(args) => commands::private_credit::run_syndication(args),
+        Commands::LoanPricing(args) => commands::private_credit::run_loan_pricing(args),
+        Commands::BridgeFinancing(args) => commands::private_credit::run_bridge_financing(args),
+        Commands::FundFinance(args) => commands::private_credit::run_fund_finance(args),
         Commands::Reserving(args) => commands::insurance::run_reserving(args),
         Commands::PremiumPricing(args) => commands::insurance::run_premium_pricing(args),
         Commands::CombinedRatio(args) => commands::insurance::run_combined_ratio(args),
@@ -673,10 +1107,17 @@ fn main() {
         Commands::Variance(args) => commands::fpa::run_variance(args),
         Commands::Breakeven(args) => commands::fpa::run_breakeven(args),
         Commands::WorkingCapital(args) => commands::fpa::run_working_capital(args),
+        Commands::InventoryOptimization(args) => commands::fpa::run_inventory_optimization(args),
         Commands::RollingForecast(args) => commands::fpa::run_rolling_forecast(args),
+        Commands::BacklogRevenue(args) => commands::fpa::run_backlog_revenue(args),
+        Commands::SaasMetrics(args) => commands::fpa::run_saas_metrics(args),
+        Commands::UnitEconomics(args) => commands::fpa::run_unit_economics(args),
         Commands::Retirement(args) => commands::wealth::run_retirement(args),
         Commands::Tlh(args) => commands::wealth::run_tlh(args),
         Commands::EstatePlan(args) => commands::wealth::run_estate_plan(args),
+        Commands::RetailLoan(args) => commands::consumer_lending::run_retail_loan(args),
+        Commands::LoanApr(args) => commands::consumer_lending::run_loan_apr(args),
+        Commands::Refinance(args) => commands::consumer_lending::run_refinance(args),
         Commands::TokenValuation(args) => commands::crypto::run_token_valuation(args),
         Commands::DefiAnalysis(args) => commands::crypto::run_defi_analysis(args),
         Commands::MuniBond(args) => commands::municipal::run_muni_bond(args),
@@ -689,10 +1130,12 @@ fn main() {
         }
         Commands::CdsPricing(args) => commands::credit_derivatives::run_cds_pricing(args),
         Commands::CvaCalculation(args) => commands::credit_derivatives::run_cva_calculation(args),
+        Commands::XvaCalculation(args) => commands::credit_derivatives::run_xva_calculation(args),
         Commands::ConvertiblePricing(args) => commands::convertibles::run_convertible_pricing(args),
         Commands::ConvertibleAnalysis(args) => {
             commands::convertibles::run_convertible_analysis(args)
         }
+        Commands::ConvertibleBook(args) => commands::convertibles::run_convertible_book(args),
         Commands::LeaseClassification(args) => {
             commands::lease_accounting::run_lease_classification(args)
         }
@@ -705,12 +1148,16 @@ fn main() {
         Commands::DecisionTree(args) => commands::real_options::run_decision_tree(args),
         Commands::Sotp(args) => commands::equity_research::run_sotp(args),
         Commands::TargetPrice(args) => commands::equity_research::run_target_price(args),
+        Commands::Reit(args) => commands::equity_research::run_reit(args),
+        Commands::BankValuation(args) => commands::equity_research::run_bank_valuation(args),
+        Commands::ReverseDcf(args) => commands::equity_research::run_reverse_dcf(args),
         Commands::CommoditySpread(args) => commands::commodity_trading::run_commodity_spread(args),
         Commands::StorageEconomics(args) => {
             commands::commodity_trading::run_storage_economics(args)
         }
         Commands::PairsTrading(args) => commands::quant_strategies::run_pairs_trading(args),
         Commands::Momentum(args) => commands::quant_strategies::run_momentum(args),
+        Commands::EventStudy(args) => commands::quant_strategies::run_event_study(args),
         Commands::CashManagement(args) => commands::treasury::run_cash_management(args),
         Commands::HedgeEffectiveness(args) => commands::treasury::run_hedging(args),
         Commands::PppModel(args) => commands::infrastructure::run_ppp_model(args),
@@ -733,6 +1180,10 @@ fn main() {
         Commands::UkEuFund(args) => commands::onshore_structures::run_uk_eu_fund(args),
         Commands::CaymanFund(args) => commands::offshore_structures::run_cayman_fund(args),
         Commands::LuxFund(args) => commands::offshore_structures::run_lux_fund(args),
+        Commands::RedemptionStress(args) => commands::fund_structuring::run_redemption_stress(args),
+        Commands::SemiLiquidStress(args) => {
+            commands::fund_structuring::run_semi_liquid_stress(args)
+        }
         Commands::BepsCompliance(args) => commands::transfer_pricing::run_beps(args),
         Commands::Intercompany(args) => commands::transfer_pricing::run_intercompany(args),
         Commands::TreatyNetwork(args) => commands::tax_treaty::run_treaty_network(args),
@@ -801,7 +1252,13 @@ fn main() {
         Commands::LimitManagement(args) => commands::capital_allocation::run_limit_management(args),
         Commands::CloWaterfall(args) => commands::clo_analytics::run_clo_waterfall(args),
         Commands::CloCoverage(args) => commands::clo_analytics::run_clo_coverage(args),
+        Commands::CloPortfolioMetrics(args) => {
+            commands::clo_analytics::run_clo_portfolio_metrics(args)
+        }
         Commands::CloReinvestment(args) => commands::clo_analytics::run_clo_reinvestment(args),
+        Commands::CloReinvestmentOptimize(args) => {
+            commands::clo_analytics::run_clo_reinvestment_optimize(args)
+        }
         Commands::CloTranche(args) => commands::clo_analytics::run_clo_tranche(args),
         Commands::CloScenario(args) => commands::clo_analytics::run_clo_scenario(args),
         Commands::JCurve(args) => commands::fund_of_funds::run_j_curve(args),
@@ -811,6 +1268,8 @@ fn main() {
             commands::fund_of_funds::run_secondaries_pricing(args)
         }
         Commands::FofPortfolio(args) => commands::fund_of_funds::run_fof_portfolio(args),
+        Commands::StyleAnalysis(args) => commands::fund_of_funds::run_style_analysis(args),
+        Commands::CashFlowForecast(args) => commands::fund_of_funds::run_cash_flow_forecast(args),
         Commands::Beneish(args) => commands::earnings_quality::run_beneish(args),
         Commands::Piotroski(args) => commands::earnings_quality::run_piotroski(args),
         Commands::AccrualQuality(args) => commands::earnings_quality::run_accrual_quality(args),
@@ -832,6 +1291,11 @@ fn main() {
         Commands::TotalShareholderReturn(args) => {
             commands::dividend_policy::run_total_shareholder_return(args)
         }
+        Commands::AsrBuyback(args) => commands::dividend_policy::run_asr_buyback(args),
+        Commands::OpenMarketBuyback(args) => {
+            commands::dividend_policy::run_open_market_buyback(args)
+        }
+        Commands::PayoutOptimizer(args) => commands::dividend_policy::run_payout_optimizer(args),
         Commands::CarbonPricing(args) => commands::carbon_markets::run_carbon_pricing(args),
         Commands::EtsCompliance(args) => commands::carbon_markets::run_ets_compliance(args),
         Commands::Cbam(args) => commands::carbon_markets::run_cbam(args),
@@ -874,20 +1338,49 @@ fn main() {
             commands::workflows::run_workflow_quality_check(args)
         }
         Commands::WorkflowAudit(args) => commands::workflows::run_workflow_audit(args),
-        Commands::Version => {
-            println!("cfa {}", env!("CARGO_PKG_VERSION"));
-            return;
-        }
-    };
+        _ => Err("not a calculator command".into()),
+    }
+}
 
-    match result {
-        Ok(value) => {
-            output::format_output(&cli.output, &value);
-            process::exit(0);
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Calculators that take `--input` but cannot run from a standalone
+    /// sample: `sensitivity` needs `--model`/`--var1` and flexes another
+    /// calculator's input, and `project-add` copies an input into a project.
+    const NO_SAMPLE: &[&str] = &["sensitivity", "project-add"];
+
+    #[test]
+    fn every_calculator_sample_runs() {
+        let cli = Cli::command();
+        let dir = std::env::temp_dir().join(format!("cfa-examples-{}", process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let mut failures = Vec::new();
+        for sub in cli.get_subcommands() {
+            let name = sub.get_name();
+            if NO_SAMPLE.contains(&name) || !sub.get_arguments().any(|a| a.get_id() == "input") {
+                continue;
+            }
+            let Some(sample) = commands::examples::sample_input(name) else {
+                failures.push(format!("{name}: no sample input"));
+                continue;
+            };
+            let path = dir.join(format!("{name}.json"));
+            std::fs::write(&path, sample).unwrap();
+            if let Err(e) = run_calculator(name, &path) {
+                failures.push(format!("{name}: {e}"));
+            }
         }
-        Err(e) => {
-            eprintln!("{}: {}", "error".red().bold(), e);
-            process::exit(1);
+        std::fs::remove_dir_all(&dir).ok();
+        for example in commands::examples::EXAMPLES {
+            if cli.find_subcommand(example.command).is_none() {
+                failures.push(format!(
+                    "{}: sample for an unknown command",
+                    example.command
+                ));
+            }
         }
+        assert!(failures.is_empty(), "{}", failures.join("\n"));
     }
 }
@@ -1,10 +1,24 @@
 use serde_json::Value;
 use std::io;
 
-/// Write output as CSV to stdout.
-pub fn print_csv(value: &Value) {
+use super::locale::Locale;
+
+/// Write output as CSV to stdout. With a locale, numbers follow its
+/// conventions and the delimiter is `;` where the comma is the decimal
+/// separator.
+pub fn print_csv(value: &Value, locale: Option<Locale>) {
+    let localized;
+    let value = match locale {
+        Some(locale) => {
+            localized = locale.localize(value);
+            &localized
+        }
+        None => value,
+    };
     let stdout = io::stdout();
-    let mut wtr = csv::Writer::from_writer(stdout.lock());
+    let mut wtr = csv::WriterBuilder::new()
+        .delimiter(locale.map_or(b',', Locale::csv_delimiter))
+        .from_writer(stdout.lock());
 
     match value {
         Value::Object(map) => {
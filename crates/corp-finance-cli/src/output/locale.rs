@@ -0,0 +1,313 @@
+use serde_json::{Map, Value};
+use std::fmt;
+use std::str::FromStr;
+
+use corp_finance_core::precision::{classify, ValueKind};
+
+/// Words marking a field as a currency amount when they end its name.
+/// Names the precision classifier cannot place default to money, so the
+/// currency symbol is only shown for these (`equity_value` but not
+/// `z_score` or `cash_conversion`).
+const MONEY_WORDS: &[&str] = &[
+    "value",
+    "price",
+    "amount",
+    "cost",
+    "costs",
+    "debt",
+    "equity",
+    "cash",
+    "revenue",
+    "revenues",
+    "sales",
+    "ebitda",
+    "ebit",
+    "income",
+    "earnings",
+    "profit",
+    "loss",
+    "proceeds",
+    "fee",
+    "fees",
+    "nav",
+    "capital",
+    "balance",
+    "principal",
+    "notional",
+    "payment",
+    "payments",
+    "interest",
+    "tax",
+    "assets",
+    "liabilities",
+    "pv",
+    "npv",
+    "fcf",
+    "fcff",
+    "fcfe",
+    "exposure",
+    "expense",
+    "expenses",
+    "flow",
+    "flows",
+    "capex",
+    "opex",
+    "gain",
+    "gains",
+    "reserve",
+    "reserves",
+    "receivable",
+    "receivables",
+    "payable",
+    "payables",
+    "synergies",
+    "savings",
+    "dividend",
+    "dividends",
+    "distribution",
+    "distributions",
+    "commitment",
+    "contribution",
+    "investment",
+    "invested",
+    "returned",
+    "premium",
+    "cva",
+    "dva",
+    "fva",
+    "kva",
+    "mva",
+];
+
+/// Number formatting conventions for table and CSV output (`--locale`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Locale {
+    EnUs,
+    EnGb,
+    DeDe,
+    DeCh,
+    FrFr,
+    EsEs,
+    ItIt,
+    NlNl,
+    JaJp,
+}
+
+impl FromStr for Locale {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().replace('_', "-").as_str() {
+            "en-us" | "us" => Ok(Locale::EnUs),
+            "en-gb" | "gb" | "uk" => Ok(Locale::EnGb),
+            "de-de" | "de" => Ok(Locale::DeDe),
+            "de-ch" | "ch" => Ok(Locale::DeCh),
+            "fr-fr" | "fr" => Ok(Locale::FrFr),
+            "es-es" | "es" => Ok(Locale::EsEs),
+            "it-it" | "it" => Ok(Locale::ItIt),
+            "nl-nl" | "nl" => Ok(Locale::NlNl),
+            "ja-jp" | "ja" | "jp" => Ok(Locale::JaJp),
+            _ => Err(format!(
+                "unknown locale '{s}': expected en-US, en-GB, de-DE, de-CH, fr-FR, es-ES, it-IT, nl-NL or ja-JP"
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Locale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let tag = match self {
+            Locale::EnUs => "en-US",
+            Locale::EnGb => "en-GB",
+            Locale::DeDe => "de-DE",
+            Locale::DeCh => "de-CH",
+            Locale::FrFr => "fr-FR",
+            Locale::EsEs => "es-ES",
+            Locale::ItIt => "it-IT",
+            Locale::NlNl => "nl-NL",
+            Locale::JaJp => "ja-JP",
+        };
+        f.write_str(tag)
+    }
+}
+
+impl Locale {
+    pub fn decimal_separator(self) -> char {
+        match self {
+            Locale::EnUs | Locale::EnGb | Locale::DeCh | Locale::JaJp => '.',
+            Locale::DeDe | Locale::FrFr | Locale::EsEs | Locale::ItIt | Locale::NlNl => ',',
+        }
+    }
+
+    pub fn group_separator(self) -> &'static str {
+        match self {
+            Locale::EnUs | Locale::EnGb | Locale::JaJp => ",",
+            Locale::DeDe | Locale::EsEs | Locale::ItIt | Locale::NlNl => ".",
+            Locale::DeCh => "'",
+            Locale::FrFr => "\u{202f}",
+        }
+    }
+
+    /// CSV field delimiter: `;` where the comma is the decimal separator,
+    /// as spreadsheets in those locales expect.
+    pub fn csv_delimiter(self) -> u8 {
+        if self.decimal_separator() == ',' {
+            b';'
+        } else {
+            b','
+        }
+    }
+
+    /// Separator for lists of values printed in a single table cell.
+    pub fn list_separator(self) -> &'static str {
+        if self.decimal_separator() == ',' {
+            "; "
+        } else {
+            ", "
+        }
+    }
+
+    /// Currency assumed when the output does not name one.
+    fn default_currency(self) -> &'static str {
+        match self {
+            Locale::EnUs => "USD",
+            Locale::EnGb => "GBP",
+            Locale::DeCh => "CHF",
+            Locale::JaJp => "JPY",
+            Locale::DeDe | Locale::FrFr | Locale::EsEs | Locale::ItIt | Locale::NlNl => "EUR",
+        }
+    }
+
+    fn symbol_after(self) -> bool {
+        matches!(
+            self,
+            Locale::DeDe | Locale::FrFr | Locale::EsEs | Locale::ItIt
+        )
+    }
+
+    /// Rewrite the numbers of an output document as localised strings.
+    /// Money fields get thousands separators, a currency symbol and
+    /// parentheses when negative; rates and ratios get the locale's
+    /// separators; counts such as years are left alone.
+    pub fn localize(self, value: &Value) -> Value {
+        let currency = find_currency(value).unwrap_or_else(|| self.default_currency().into());
+        let symbol = currency_symbol(&currency);
+        self.walk(value, None, &symbol)
+    }
+
+    fn walk(self, value: &Value, key: Option<&str>, symbol: &str) -> Value {
+        match value {
+            Value::Object(map) => Value::Object(
+                map.iter()
+                    .map(|(k, v)| (k.clone(), self.walk(v, Some(k), symbol)))
+                    .collect::<Map<_, _>>(),
+            ),
+            Value::Array(items) => {
+                Value::Array(items.iter().map(|v| self.walk(v, key, symbol)).collect())
+            }
+            Value::Number(n) => self
+                .format_field(&n.to_string(), key, symbol)
+                .map(Value::String)
+                .unwrap_or_else(|| value.clone()),
+            Value::String(s) => self
+                .format_field(s, key, symbol)
+                .map(Value::String)
+                .unwrap_or_else(|| value.clone()),
+            _ => value.clone(),
+        }
+    }
+
+    /// Format a plain decimal number held in `key`; `None` when the text is
+    /// not one (labels, dates, exponents) or the field is a count.
+    fn format_field(self, text: &str, key: Option<&str>, symbol: &str) -> Option<String> {
+        let kind = key.map(classify).unwrap_or(ValueKind::Ratio);
+        if kind == ValueKind::Count {
+            return None;
+        }
+        let (negative, digits) = match text.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, text),
+        };
+        let (int_part, frac_part) = match digits.split_once('.') {
+            Some((i, f)) => (i, Some(f)),
+            None => (digits, None),
+        };
+        let is_digits = |s: &str| !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit());
+        if !is_digits(int_part) || !frac_part.is_none_or(is_digits) {
+            return None;
+        }
+
+        let mut number = group(int_part, self.group_separator());
+        if let Some(frac) = frac_part {
+            number.push(self.decimal_separator());
+            number.push_str(frac);
+        }
+
+        let is_money = kind == ValueKind::Money && key.is_some_and(has_money_word);
+        if !is_money {
+            return Some(if negative {
+                format!("-{number}")
+            } else {
+                number
+            });
+        }
+        let amount = if self.symbol_after() {
+            format!("{number}\u{a0}{symbol}")
+        } else if symbol.chars().all(char::is_alphabetic) {
+            format!("{symbol}\u{a0}{number}")
+        } else {
+            format!("{symbol}{number}")
+        };
+        Some(if negative {
+            format!("({amount})")
+        } else {
+            amount
+        })
+    }
+}
+
+fn has_money_word(key: &str) -> bool {
+    key.to_lowercase()
+        .rsplit(|c: char| !c.is_ascii_alphanumeric())
+        .next()
+        .is_some_and(|w| MONEY_WORDS.contains(&w))
+}
+
+/// Insert a separator every three digits from the right.
+fn group(digits: &str, separator: &str) -> String {
+    let mut out = String::with_capacity(digits.len() + digits.len() / 3 * separator.len());
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            out.push_str(separator);
+        }
+        out.push(c);
+    }
+    out
+}
+
+/// Currency code named by the output: a `currency` field of the result or
+/// the envelope.
+fn find_currency(value: &Value) -> Option<String> {
+    let root = value.as_object()?;
+    let result = root.get("result").and_then(Value::as_object);
+    [result, Some(root)]
+        .into_iter()
+        .flatten()
+        .find_map(|map| map.get("currency").and_then(Value::as_str))
+        .map(str::to_string)
+}
+
+fn currency_symbol(code: &str) -> String {
+    match code {
+        "USD" => "$",
+        "GBP" => "£",
+        "EUR" => "€",
+        "JPY" => "¥",
+        "CAD" => "C$",
+        "AUD" => "A$",
+        "HKD" => "HK$",
+        "SGD" => "S$",
+        other => other,
+    }
+    .to_string()
+}
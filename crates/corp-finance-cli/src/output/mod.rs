@@ -1,17 +1,20 @@
 pub mod csv_out;
 pub mod json;
+pub mod locale;
 pub mod minimal;
 pub mod table;
 
 use crate::OutputFormat;
+use locale::Locale;
 use serde_json::Value;
 
-/// Dispatch output to the appropriate formatter.
-pub fn format_output(format: &OutputFormat, value: &Value) {
+/// Dispatch output to the appropriate formatter. The locale applies to
+/// table and CSV output only; JSON and minimal output stay machine-readable.
+pub fn format_output(format: &OutputFormat, value: &Value, locale: Option<Locale>) {
     match format {
         OutputFormat::Json => json::print_json(value),
-        OutputFormat::Table => table::print_table(value),
-        OutputFormat::Csv => csv_out::print_csv(value),
+        OutputFormat::Table => table::print_table(value, locale),
+        OutputFormat::Csv => csv_out::print_csv(value, locale),
         OutputFormat::Minimal => minimal::print_minimal(value),
     }
 }
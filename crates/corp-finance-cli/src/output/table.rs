@@ -1,19 +1,31 @@
 use serde_json::Value;
 use tabled::{builder::Builder, Table};
 
-/// Format output as a table using the tabled crate.
-pub fn print_table(value: &Value) {
+use super::locale::Locale;
+
+/// Format output as a table using the tabled crate, with numbers in the
+/// conventions of `locale` when given.
+pub fn print_table(value: &Value, locale: Option<Locale>) {
+    let localized;
+    let value = match locale {
+        Some(locale) => {
+            localized = locale.localize(value);
+            &localized
+        }
+        None => value,
+    };
+    let sep = locale.map_or(", ", Locale::list_separator);
     match value {
         Value::Object(map) => {
             // Check if "result" key holds the primary data
             if let Some(result) = map.get("result") {
-                print_result_table(result, map);
+                print_result_table(result, map, sep);
             } else {
-                print_flat_object(value);
+                print_flat_object(value, sep);
             }
         }
         Value::Array(arr) => {
-            print_array_table(arr);
+            print_array_table(arr, sep);
         }
         _ => {
             println!("{}", value);
@@ -21,18 +33,18 @@ pub fn print_table(value: &Value) {
     }
 }
 
-fn print_result_table(result: &Value, envelope: &serde_json::Map<String, Value>) {
+fn print_result_table(result: &Value, envelope: &serde_json::Map<String, Value>, sep: &str) {
     // Print the result section
     if let Value::Object(res_map) = result {
         let mut builder = Builder::default();
         builder.push_record(["Field", "Value"]);
         for (key, val) in res_map {
-            builder.push_record([key.as_str(), &format_value(val)]);
+            builder.push_record([key.as_str(), &format_value(val, sep)]);
         }
         let table = Table::from(builder);
         println!("{}", table);
     } else {
-        print_flat_object(&Value::Object(envelope.clone()));
+        print_flat_object(&Value::Object(envelope.clone()), sep);
     }
 
     // Print warnings if any
@@ -53,19 +65,19 @@ fn print_result_table(result: &Value, envelope: &serde_json::Map<String, Value>)
     }
 }
 
-fn print_flat_object(value: &Value) {
+fn print_flat_object(value: &Value, sep: &str) {
     if let Value::Object(map) = value {
         let mut builder = Builder::default();
         builder.push_record(["Field", "Value"]);
         for (key, val) in map {
-            builder.push_record([key.as_str(), &format_value(val)]);
+            builder.push_record([key.as_str(), &format_value(val, sep)]);
         }
         let table = Table::from(builder);
         println!("{}", table);
     }
 }
 
-fn print_array_table(arr: &[Value]) {
+fn print_array_table(arr: &[Value], sep: &str) {
     if arr.is_empty() {
         println!("(empty)");
         return;
@@ -81,7 +93,11 @@ fn print_array_table(arr: &[Value]) {
             if let Value::Object(map) = item {
                 let row: Vec<String> = headers
                     .iter()
-                    .map(|h| map.get(h.as_str()).map(format_value).unwrap_or_default())
+                    .map(|h| {
+                        map.get(h.as_str())
+                            .map(|v| format_value(v, sep))
+                            .unwrap_or_default()
+                    })
                     .collect();
                 builder.push_record(row);
             }
@@ -92,20 +108,20 @@ fn print_array_table(arr: &[Value]) {
     } else {
         // Simple array of values
         for item in arr {
-            println!("{}", format_value(item));
+            println!("{}", format_value(item, sep));
         }
     }
 }
 
-fn format_value(value: &Value) -> String {
+fn format_value(value: &Value, sep: &str) -> String {
     match value {
         Value::String(s) => s.clone(),
         Value::Number(n) => n.to_string(),
         Value::Bool(b) => b.to_string(),
         Value::Null => "null".to_string(),
         Value::Array(arr) => {
-            let items: Vec<String> = arr.iter().map(format_value).collect();
-            items.join(", ")
+            let items: Vec<String> = arr.iter().map(|v| format_value(v, sep)).collect();
+            items.join(sep)
         }
         Value::Object(_) => serde_json::to_string(value).unwrap_or_default(),
     }
@@ -0,0 +1,415 @@
+//! Period-by-period loan amortisation shared by every debt model.
+//!
+//! Interest accrues on the opening balance. PIK interest is capitalised
+//! before principal is paid, and the final period settles whatever is left,
+//! including capitalised PIK. Sculpted schedules are the exception: principal
+//! is capped by the cash flow available at the target DSCR, so a shortfall
+//! stays outstanding as a tail balance.
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::time::Instant;
+
+use crate::error::CorpFinanceError;
+use crate::types::{with_metadata, ComputationOutput, Money, Rate};
+use crate::CorpFinanceResult;
+
+// ---------------------------------------------------------------------------
+// Input / Output types
+// ---------------------------------------------------------------------------
+
+/// How principal is repaid over the term.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum AmortizationMethod {
+    /// Constant debt service (annuity), re-solved each period over the
+    /// remaining term so capitalised PIK is spread too
+    LevelPayment,
+    /// Fixed share of the original principal each period (1 / periods for
+    /// equal instalments)
+    StraightLine(Rate),
+    /// Interest only for the given number of periods, then level payment
+    InterestOnly(u32),
+    /// All principal at maturity
+    Bullet,
+    /// Principal per period; periods beyond the vector pay none
+    Custom(Vec<Money>),
+    /// Principal sized so each period's cash flow covers debt service at the
+    /// target DSCR
+    Sculpted {
+        cash_flows: Vec<Money>,
+        target_dscr: Decimal,
+    },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AmortizationInput {
+    pub principal: Money,
+    /// Cash interest rate per period
+    pub rate: Rate,
+    /// Interest capitalised onto the balance each period
+    #[serde(default)]
+    pub pik_rate: Rate,
+    /// Number of periods to maturity
+    pub periods: u32,
+    pub method: AmortizationMethod,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AmortizationPeriod {
+    /// 1-based period number
+    pub period: u32,
+    pub opening_balance: Money,
+    pub interest: Money,
+    pub pik_interest: Money,
+    pub principal: Money,
+    /// Cash interest plus principal
+    pub debt_service: Money,
+    pub closing_balance: Money,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AmortizationSchedule {
+    pub periods: Vec<AmortizationPeriod>,
+    pub total_interest: Money,
+    pub total_pik_interest: Money,
+    pub total_principal: Money,
+    pub total_debt_service: Money,
+    /// Balance left after the final period (non-zero only when sculpting
+    /// cannot retire the debt)
+    pub residual_balance: Money,
+    /// Principal-weighted average repayment period
+    pub weighted_average_life: Decimal,
+}
+
+// ---------------------------------------------------------------------------
+// Public API
+// ---------------------------------------------------------------------------
+
+/// Generate an amortisation schedule with methodology metadata.
+pub fn amortization_schedule(
+    input: &AmortizationInput,
+) -> CorpFinanceResult<ComputationOutput<AmortizationSchedule>> {
+    let start = Instant::now();
+    let mut warnings: Vec<String> = Vec::new();
+
+    let schedule = build_schedule(input)?;
+
+    if schedule.residual_balance > Decimal::ZERO {
+        warnings.push(format!(
+            "Sculpted repayment leaves {} outstanding after period {}",
+            schedule.residual_balance.round_dp(2),
+            input.periods
+        ));
+    }
+    if let Some(p) = schedule
+        .periods
+        .iter()
+        .find(|p| p.closing_balance > p.opening_balance)
+    {
+        warnings.push(format!(
+            "Negative amortisation: balance grows in period {}",
+            p.period
+        ));
+    }
+
+    let elapsed = start.elapsed().as_micros() as u64;
+    Ok(with_metadata(
+        "Loan Amortisation Schedule",
+        &serde_json::json!({
+            "method": method_name(&input.method),
+            "interest_basis": "opening balance",
+            "pik": "capitalised before principal is paid",
+            "maturity": "final period settles the remaining balance (except sculpted)",
+        }),
+        warnings,
+        elapsed,
+        schedule,
+    ))
+}
+
+/// Build the schedule without metadata, for models that embed it.
+pub fn build_schedule(input: &AmortizationInput) -> CorpFinanceResult<AmortizationSchedule> {
+    validate_input(input)?;
+
+    let n = input.periods;
+    let mut periods = Vec::with_capacity(n as usize);
+    let mut balance = input.principal;
+
+    for period in 1..=n {
+        let opening = balance;
+        let interest = opening * input.rate;
+        let pik_interest = opening * input.pik_rate;
+        balance += pik_interest;
+
+        let remaining = n - period + 1;
+        let principal =
+            if period == n && !matches!(input.method, AmortizationMethod::Sculpted { .. }) {
+                balance
+            } else {
+                scheduled_principal(input, period, remaining, balance, interest)
+            }
+            .min(balance)
+            .max(Decimal::ZERO);
+        balance -= principal;
+
+        periods.push(AmortizationPeriod {
+            period,
+            opening_balance: opening,
+            interest,
+            pik_interest,
+            principal,
+            debt_service: interest + principal,
+            closing_balance: balance,
+        });
+    }
+
+    let total_interest: Money = periods.iter().map(|p| p.interest).sum();
+    let total_pik_interest: Money = periods.iter().map(|p| p.pik_interest).sum();
+    let total_principal: Money = periods.iter().map(|p| p.principal).sum();
+    let weighted_average_life = if total_principal > Decimal::ZERO {
+        periods
+            .iter()
+            .map(|p| Decimal::from(p.period) * p.principal)
+            .sum::<Money>()
+            / total_principal
+    } else {
+        Decimal::ZERO
+    };
+
+    Ok(AmortizationSchedule {
+        total_interest,
+        total_pik_interest,
+        total_principal,
+        total_debt_service: total_interest + total_principal,
+        residual_balance: balance,
+        weighted_average_life,
+        periods,
+    })
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Principal due before the maturity settlement, uncapped.
+fn scheduled_principal(
+    input: &AmortizationInput,
+    period: u32,
+    remaining: u32,
+    balance: Money,
+    interest: Money,
+) -> Money {
+    match &input.method {
+        AmortizationMethod::LevelPayment => level_principal(balance, input.rate, remaining),
+        AmortizationMethod::StraightLine(pct) => input.principal * pct,
+        AmortizationMethod::InterestOnly(io_periods) => {
+            if period <= *io_periods {
+                Decimal::ZERO
+            } else {
+                level_principal(balance, input.rate, remaining)
+            }
+        }
+        AmortizationMethod::Bullet => Decimal::ZERO,
+        AmortizationMethod::Custom(schedule) => schedule
+            .get((period - 1) as usize)
+            .copied()
+            .unwrap_or(Decimal::ZERO),
+        AmortizationMethod::Sculpted {
+            cash_flows,
+            target_dscr,
+        } => {
+            let cash_flow = cash_flows
+                .get((period - 1) as usize)
+                .copied()
+                .unwrap_or(Decimal::ZERO);
+            cash_flow / target_dscr - interest
+        }
+    }
+}
+
+/// Principal portion of an annuity payment retiring `balance` over
+/// `remaining` periods at `rate`.
+fn level_principal(balance: Money, rate: Rate, remaining: u32) -> Money {
+    if rate.is_zero() {
+        return balance / Decimal::from(remaining);
+    }
+    let mut growth = Decimal::ONE;
+    for _ in 0..remaining {
+        growth *= Decimal::ONE + rate;
+    }
+    let payment = balance * rate * growth / (growth - Decimal::ONE);
+    payment - balance * rate
+}
+
+fn method_name(method: &AmortizationMethod) -> &'static str {
+    match method {
+        AmortizationMethod::LevelPayment => "level payment",
+        AmortizationMethod::StraightLine(_) => "straight-line",
+        AmortizationMethod::InterestOnly(_) => "interest-only then level payment",
+        AmortizationMethod::Bullet => "bullet",
+        AmortizationMethod::Custom(_) => "custom",
+        AmortizationMethod::Sculpted { .. } => "DSCR sculpted",
+    }
+}
+
+fn validate_input(input: &AmortizationInput) -> CorpFinanceResult<()> {
+    if input.principal < Decimal::ZERO {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "principal".into(),
+            reason: "Principal cannot be negative".into(),
+        });
+    }
+    if input.periods == 0 {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "periods".into(),
+            reason: "At least one period is required".into(),
+        });
+    }
+    crate::limits::check_schedule_periods("periods", input.periods.into())?;
+    if input.rate <= Decimal::NEGATIVE_ONE {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "rate".into(),
+            reason: "Rate must be greater than -100%".into(),
+        });
+    }
+    if input.pik_rate < Decimal::ZERO {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "pik_rate".into(),
+            reason: "PIK rate cannot be negative".into(),
+        });
+    }
+    match &input.method {
+        AmortizationMethod::StraightLine(pct) if *pct < Decimal::ZERO => {
+            Err(CorpFinanceError::InvalidInput {
+                field: "method".into(),
+                reason: "Straight-line share cannot be negative".into(),
+            })
+        }
+        AmortizationMethod::Custom(schedule) if schedule.iter().any(|p| *p < Decimal::ZERO) => {
+            Err(CorpFinanceError::InvalidInput {
+                field: "method".into(),
+                reason: "Custom principal payments cannot be negative".into(),
+            })
+        }
+        AmortizationMethod::Sculpted { target_dscr, .. } if *target_dscr <= Decimal::ZERO => {
+            Err(CorpFinanceError::InvalidInput {
+                field: "target_dscr".into(),
+                reason: "Target DSCR must be positive".into(),
+            })
+        }
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn input(method: AmortizationMethod) -> AmortizationInput {
+        AmortizationInput {
+            principal: dec!(1000),
+            rate: dec!(0.10),
+            pik_rate: Decimal::ZERO,
+            periods: 5,
+            method,
+        }
+    }
+
+    #[test]
+    fn test_level_payment_constant_debt_service() {
+        let s = build_schedule(&input(AmortizationMethod::LevelPayment)).unwrap();
+        // 1000 x 0.1 / (1 - 1.1^-5) = 263.797...
+        let first = s.periods[0].debt_service;
+        assert!((first - dec!(263.7975)).abs() < dec!(0.001));
+        for p in &s.periods {
+            assert!((p.debt_service - first).abs() < dec!(0.000001));
+        }
+        assert_eq!(s.residual_balance, Decimal::ZERO);
+        assert_eq!(s.total_principal, dec!(1000));
+    }
+
+    #[test]
+    fn test_straight_line_settles_at_maturity() {
+        let s = build_schedule(&input(AmortizationMethod::StraightLine(dec!(0.10)))).unwrap();
+        assert_eq!(s.periods[0].principal, dec!(100));
+        assert_eq!(s.periods[0].interest, dec!(100));
+        assert_eq!(s.periods[4].principal, dec!(600));
+        assert_eq!(s.periods[4].closing_balance, Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_interest_only_then_amortising() {
+        let s = build_schedule(&input(AmortizationMethod::InterestOnly(2))).unwrap();
+        assert_eq!(s.periods[0].principal, Decimal::ZERO);
+        assert_eq!(s.periods[1].principal, Decimal::ZERO);
+        assert!(s.periods[2].principal > Decimal::ZERO);
+        let ds = s.periods[2].debt_service;
+        assert!((s.periods[4].debt_service - ds).abs() < dec!(0.000001));
+        assert_eq!(s.residual_balance, Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_bullet_with_pik_repays_capitalised_interest() {
+        let mut i = input(AmortizationMethod::Bullet);
+        i.pik_rate = dec!(0.02);
+        let s = build_schedule(&i).unwrap();
+        assert_eq!(s.periods[0].closing_balance, dec!(1020));
+        let last = &s.periods[4];
+        assert_eq!(last.principal, last.opening_balance + last.pik_interest);
+        assert_eq!(s.total_principal, dec!(1000) + s.total_pik_interest);
+        assert_eq!(s.weighted_average_life, dec!(5));
+    }
+
+    #[test]
+    fn test_custom_caps_at_balance() {
+        let s = build_schedule(&input(AmortizationMethod::Custom(vec![
+            dec!(600),
+            dec!(600),
+        ])))
+        .unwrap();
+        assert_eq!(s.periods[0].principal, dec!(600));
+        assert_eq!(s.periods[1].principal, dec!(400));
+        assert_eq!(s.periods[2].opening_balance, Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_sculpted_holds_target_dscr_and_reports_tail() {
+        let s = build_schedule(&input(AmortizationMethod::Sculpted {
+            cash_flows: vec![dec!(260); 5],
+            target_dscr: dec!(1.3),
+        }))
+        .unwrap();
+        for p in &s.periods {
+            assert_eq!(p.debt_service, dec!(200));
+        }
+        // 200 of debt service a year cannot retire 1000 at 10% in 5 years
+        assert!(s.residual_balance > Decimal::ZERO);
+        let out = amortization_schedule(&input(AmortizationMethod::Sculpted {
+            cash_flows: vec![dec!(260); 5],
+            target_dscr: dec!(1.3),
+        }))
+        .unwrap();
+        assert!(out.warnings.iter().any(|w| w.contains("outstanding")));
+    }
+
+    #[test]
+    fn test_zero_rate_level_payment() {
+        let mut i = input(AmortizationMethod::LevelPayment);
+        i.rate = Decimal::ZERO;
+        let s = build_schedule(&i).unwrap();
+        assert!(s.periods.iter().all(|p| p.principal == dec!(200)));
+    }
+
+    #[test]
+    fn test_invalid_inputs_rejected() {
+        let mut i = input(AmortizationMethod::Bullet);
+        i.periods = 0;
+        assert!(build_schedule(&i).is_err());
+        assert!(build_schedule(&input(AmortizationMethod::Sculpted {
+            cash_flows: vec![],
+            target_dscr: Decimal::ZERO,
+        }))
+        .is_err());
+    }
+}
@@ -0,0 +1 @@
+pub mod project_selection;
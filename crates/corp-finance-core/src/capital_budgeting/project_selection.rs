@@ -0,0 +1,989 @@
+//! Capital project portfolio selection under multi-year budget constraints.
+//!
+//! Chooses the set of candidate projects with the highest total NPV whose
+//! capex, phased by year, fits within each year's budget. Projects can be
+//! mandatory, depend on other projects, or belong to a mutually exclusive
+//! group (at most one chosen). Small problems are solved exactly by
+//! branch and bound, bounding each node by the tightest single-year
+//! fractional knapsack; larger ones use a greedy ranking on NPV per unit of
+//! scarce budget followed by add and swap local search. The shadow value of
+//! each year's budget is the NPV gained per unit of budget when that year is
+//! relaxed and the portfolio re-solved; with whole projects it is lumpy, so
+//! it reads as "what the next tranche of budget is worth", not a marginal
+//! LP dual.
+
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::time::Instant;
+
+use crate::error::CorpFinanceError;
+use crate::time_value::{irr, npv};
+use crate::types::{with_metadata, ComputationOutput, Money, Rate};
+use crate::CorpFinanceResult;
+
+/// Largest candidate set solved exactly under `SelectionMethod::Auto`.
+const MAX_AUTO_EXACT_PROJECTS: usize = 20;
+/// Node budget for branch and bound before falling back to the heuristic.
+const MAX_NODES: u64 = 2_000_000;
+
+// ---------------------------------------------------------------------------
+// Types
+// ---------------------------------------------------------------------------
+
+/// How the portfolio is solved.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SelectionMethod {
+    /// Exact for up to 20 candidates, heuristic above
+    #[default]
+    Auto,
+    /// Branch and bound (falls back to the heuristic past the node limit)
+    Exact,
+    /// Greedy ranking plus add / swap local search
+    Heuristic,
+}
+
+/// A candidate capital project.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CandidateProject {
+    pub name: String,
+    /// Project NPV. Computed from `cash_flows` at `discount_rate` when
+    /// omitted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub npv: Option<Money>,
+    /// Project IRR, for reporting. Computed from `cash_flows` when omitted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub irr: Option<Rate>,
+    /// Net cash flows from year 0, used when `npv` is omitted.
+    #[serde(default)]
+    pub cash_flows: Vec<Money>,
+    /// Capex drawn against each budget year, year 1 first.
+    pub capex: Vec<Money>,
+    /// Must be in the portfolio (regulatory, maintenance, committed).
+    #[serde(default)]
+    pub mandatory: bool,
+    /// Projects that must also be selected for this one to go ahead.
+    #[serde(default)]
+    pub requires: Vec<String>,
+    /// At most one project per group is selected.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exclusive_group: Option<String>,
+}
+
+/// Input for capital project selection.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectSelectionInput {
+    pub projects: Vec<CandidateProject>,
+    /// Capex budget for each year, year 1 first.
+    pub budgets: Vec<Money>,
+    /// Discount rate for projects given as cash flows.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub discount_rate: Option<Rate>,
+    #[serde(default)]
+    pub method: SelectionMethod,
+    /// Fractional budget increase used for shadow values (default 10%).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub budget_relaxation: Option<Rate>,
+}
+
+/// A project in the chosen portfolio.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SelectedProject {
+    pub name: String,
+    pub npv: Money,
+    pub irr: Option<Rate>,
+    pub total_capex: Money,
+    /// NPV per unit of undiscounted capex.
+    pub npv_to_capex: Option<Decimal>,
+    pub mandatory: bool,
+}
+
+/// A candidate left out, with the reason.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RejectedProject {
+    pub name: String,
+    pub npv: Money,
+    pub reason: String,
+}
+
+/// Budget use and shadow value for one year.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BudgetYear {
+    pub year: u32,
+    pub budget: Money,
+    pub capex: Money,
+    pub slack: Money,
+    pub utilization: Rate,
+    /// NPV gained per unit of extra budget in this year alone.
+    pub shadow_value: Decimal,
+    /// True when the relaxation adds NPV.
+    pub binding: bool,
+}
+
+/// Output of capital project selection.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectSelectionOutput {
+    pub selected: Vec<SelectedProject>,
+    pub rejected: Vec<RejectedProject>,
+    pub total_npv: Money,
+    pub total_capex: Money,
+    pub budget_years: Vec<BudgetYear>,
+    /// NPV gained per unit of extra budget when every year is relaxed.
+    pub shadow_value_all_years: Decimal,
+    /// NPV of all positive-NPV candidates, ignoring budgets and constraints.
+    pub unconstrained_npv: Money,
+    /// Share of the unconstrained NPV captured.
+    pub npv_capture: Option<Rate>,
+    pub method_used: SelectionMethod,
+    /// True when branch and bound completed.
+    pub proven_optimal: bool,
+}
+
+// ---------------------------------------------------------------------------
+// Problem
+// ---------------------------------------------------------------------------
+
+/// Resolved project data, indexed by position.
+struct Problem {
+    npv: Vec<Money>,
+    capex: Vec<Vec<Money>>,
+    mandatory: Vec<bool>,
+    requires: Vec<Vec<usize>>,
+    group: Vec<Option<usize>>,
+}
+
+impl Problem {
+    fn len(&self) -> usize {
+        self.npv.len()
+    }
+
+    fn feasible(&self, selected: &[bool], budgets: &[Money]) -> bool {
+        for (t, budget) in budgets.iter().enumerate() {
+            let used: Money = (0..self.len())
+                .filter(|&i| selected[i])
+                .map(|i| self.capex[i][t])
+                .sum();
+            if used > *budget {
+                return false;
+            }
+        }
+        let mut groups = HashSet::new();
+        for i in 0..self.len() {
+            if self.mandatory[i] && !selected[i] {
+                return false;
+            }
+            if !selected[i] {
+                continue;
+            }
+            if self.requires[i].iter().any(|&j| !selected[j]) {
+                return false;
+            }
+            if let Some(g) = self.group[i] {
+                if !groups.insert(g) {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    fn value(&self, selected: &[bool]) -> Money {
+        (0..self.len())
+            .filter(|&i| selected[i])
+            .map(|i| self.npv[i])
+            .sum()
+    }
+
+    /// `i` plus everything it transitively requires.
+    fn closure(&self, i: usize) -> Vec<usize> {
+        let mut out = vec![i];
+        let mut k = 0;
+        while k < out.len() {
+            for &j in &self.requires[out[k]] {
+                if !out.contains(&j) {
+                    out.push(j);
+                }
+            }
+            k += 1;
+        }
+        out
+    }
+
+    /// `i` plus everything that transitively requires it.
+    fn dependents(&self, i: usize) -> Vec<usize> {
+        let mut out = vec![i];
+        let mut k = 0;
+        while k < out.len() {
+            let target = out[k];
+            for j in 0..self.len() {
+                if self.requires[j].contains(&target) && !out.contains(&j) {
+                    out.push(j);
+                }
+            }
+            k += 1;
+        }
+        out
+    }
+
+    /// Rank by NPV per unit of budget, each year's capex scaled by that
+    /// year's budget so the scarce years weigh most.
+    fn ranking(&self, budgets: &[Money]) -> Vec<usize> {
+        let score = |i: usize| -> Decimal {
+            let used: Decimal = budgets
+                .iter()
+                .enumerate()
+                .filter(|(_, b)| **b > Decimal::ZERO)
+                .map(|(t, b)| self.capex[i][t] / b)
+                .sum();
+            if used > Decimal::ZERO {
+                self.npv[i] / used
+            } else {
+                self.npv[i] * dec!(1000000)
+            }
+        };
+        let scores: Vec<Decimal> = (0..self.len()).map(score).collect();
+        let mut order: Vec<usize> = (0..self.len()).collect();
+        order.sort_by(|&a, &b| scores[b].cmp(&scores[a]));
+        order
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Exact: branch and bound
+// ---------------------------------------------------------------------------
+
+struct Search<'a> {
+    problem: &'a Problem,
+    budgets: &'a [Money],
+    order: Vec<usize>,
+    selected: Vec<bool>,
+    used: Vec<Money>,
+    groups: Vec<bool>,
+    best: Option<(Money, Vec<bool>)>,
+    nodes: u64,
+}
+
+impl Search<'_> {
+    /// Upper bound on NPV still available from positions `depth..`: the
+    /// tightest single-year fractional knapsack over positive-NPV projects.
+    fn bound(&self, depth: usize) -> Money {
+        let remaining: Vec<usize> = self.order[depth..]
+            .iter()
+            .copied()
+            .filter(|&i| self.problem.npv[i] > Decimal::ZERO)
+            .collect();
+        let mut best = remaining
+            .iter()
+            .map(|&i| self.problem.npv[i])
+            .sum::<Money>();
+        for (t, budget) in self.budgets.iter().enumerate() {
+            let mut room = *budget - self.used[t];
+            let mut items: Vec<usize> = remaining.clone();
+            items.sort_by(|&a, &b| {
+                let ra = self.problem.capex[a][t];
+                let rb = self.problem.capex[b][t];
+                // Higher NPV per unit of this year's capex first; free items first.
+                (self.problem.npv[b] * ra).cmp(&(self.problem.npv[a] * rb))
+            });
+            let mut value = Decimal::ZERO;
+            for i in items {
+                let c = self.problem.capex[i][t];
+                if c <= room {
+                    value += self.problem.npv[i];
+                    room -= c;
+                } else {
+                    if room > Decimal::ZERO {
+                        value += self.problem.npv[i] * room / c;
+                    }
+                    break;
+                }
+            }
+            best = best.min(value);
+        }
+        best
+    }
+
+    fn run(&mut self, depth: usize, value: Money) -> bool {
+        self.nodes += 1;
+        if self.nodes > MAX_NODES {
+            return false;
+        }
+        if depth == self.order.len() {
+            if self.problem.feasible(&self.selected, self.budgets)
+                && self.best.as_ref().is_none_or(|(b, _)| value > *b)
+            {
+                self.best = Some((value, self.selected.clone()));
+            }
+            return true;
+        }
+        if let Some((b, _)) = &self.best {
+            if value + self.bound(depth) <= *b {
+                return true;
+            }
+        }
+        let i = self.order[depth];
+        let p = self.problem;
+
+        // Include
+        let fits = (0..self.budgets.len()).all(|t| self.used[t] + p.capex[i][t] <= self.budgets[t]);
+        let group_free = p.group[i].is_none_or(|g| !self.groups[g]);
+        if fits && group_free {
+            self.selected[i] = true;
+            for t in 0..self.budgets.len() {
+                self.used[t] += p.capex[i][t];
+            }
+            if let Some(g) = p.group[i] {
+                self.groups[g] = true;
+            }
+            let complete = self.run(depth + 1, value + p.npv[i]);
+            self.selected[i] = false;
+            for t in 0..self.budgets.len() {
+                self.used[t] -= p.capex[i][t];
+            }
+            if let Some(g) = p.group[i] {
+                self.groups[g] = false;
+            }
+            if !complete {
+                return false;
+            }
+        }
+
+        // Exclude
+        if !p.mandatory[i] {
+            return self.run(depth + 1, value);
+        }
+        true
+    }
+}
+
+/// Returns the best selection and whether the search completed.
+fn branch_and_bound(
+    problem: &Problem,
+    budgets: &[Money],
+    groups: usize,
+) -> (Option<Vec<bool>>, bool) {
+    let mut search = Search {
+        problem,
+        budgets,
+        order: problem.ranking(budgets),
+        selected: vec![false; problem.len()],
+        used: vec![Decimal::ZERO; budgets.len()],
+        groups: vec![false; groups],
+        best: None,
+        nodes: 0,
+    };
+    let complete = search.run(0, Decimal::ZERO);
+    (search.best.map(|(_, s)| s), complete)
+}
+
+// ---------------------------------------------------------------------------
+// Heuristic: greedy plus local search
+// ---------------------------------------------------------------------------
+
+fn heuristic(problem: &Problem, budgets: &[Money]) -> Option<Vec<bool>> {
+    let n = problem.len();
+    let mut selected = vec![false; n];
+    for i in 0..n {
+        if problem.mandatory[i] {
+            for j in problem.closure(i) {
+                selected[j] = true;
+            }
+        }
+    }
+    if !problem.feasible(&selected, budgets) {
+        return None;
+    }
+
+    // Try to add `i` with whatever it requires; keep it if NPV rises.
+    let try_add = |selected: &mut Vec<bool>, i: usize| -> bool {
+        if selected[i] {
+            return false;
+        }
+        let block: Vec<usize> = problem
+            .closure(i)
+            .into_iter()
+            .filter(|&j| !selected[j])
+            .collect();
+        let gain: Money = block.iter().map(|&j| problem.npv[j]).sum();
+        if gain <= Decimal::ZERO {
+            return false;
+        }
+        for &j in &block {
+            selected[j] = true;
+        }
+        if problem.feasible(selected, budgets) {
+            true
+        } else {
+            for &j in &block {
+                selected[j] = false;
+            }
+            false
+        }
+    };
+
+    let order = problem.ranking(budgets);
+    for &i in &order {
+        try_add(&mut selected, i);
+    }
+
+    // Local search: drop one project (and its dependents) and add another.
+    let mut improved = true;
+    let mut rounds = 0;
+    while improved && rounds < n * n {
+        improved = false;
+        rounds += 1;
+        let current = problem.value(&selected);
+        'outer: for out in 0..n {
+            if !selected[out] || problem.mandatory[out] {
+                continue;
+            }
+            let removed = problem.dependents(out);
+            if removed.iter().any(|&j| problem.mandatory[j] && selected[j]) {
+                continue;
+            }
+            let mut trial = selected.clone();
+            for &j in &removed {
+                trial[j] = false;
+            }
+            for &add in &order {
+                if removed.contains(&add) {
+                    continue;
+                }
+                let mut candidate = trial.clone();
+                if try_add(&mut candidate, add) {
+                    for &k in &order {
+                        try_add(&mut candidate, k);
+                    }
+                    if problem.value(&candidate) > current {
+                        selected = candidate;
+                        improved = true;
+                        break 'outer;
+                    }
+                }
+            }
+        }
+    }
+    Some(selected)
+}
+
+// ---------------------------------------------------------------------------
+// Public API
+// ---------------------------------------------------------------------------
+
+/// Select the NPV-maximising project portfolio within yearly capex budgets.
+pub fn select_projects(
+    input: &ProjectSelectionInput,
+) -> CorpFinanceResult<ComputationOutput<ProjectSelectionOutput>> {
+    let start = Instant::now();
+    let mut warnings: Vec<String> = Vec::new();
+
+    validate_input(input)?;
+
+    let years = input.budgets.len();
+    let index: HashMap<&str, usize> = input
+        .projects
+        .iter()
+        .enumerate()
+        .map(|(i, p)| (p.name.as_str(), i))
+        .collect();
+    let mut group_index: HashMap<&str, usize> = HashMap::new();
+    let mut npvs = Vec::with_capacity(input.projects.len());
+    let mut irrs = Vec::with_capacity(input.projects.len());
+    for p in &input.projects {
+        let value = match (p.npv, input.discount_rate) {
+            (Some(v), _) => v,
+            (None, Some(r)) => npv(r, &p.cash_flows)?,
+            (None, None) => {
+                return Err(CorpFinanceError::InvalidInput {
+                    field: "discount_rate".into(),
+                    reason: format!("Needed to value '{}' from its cash flows", p.name),
+                })
+            }
+        };
+        npvs.push(value);
+        irrs.push(p.irr.or_else(|| {
+            let has_sign_change = p.cash_flows.iter().any(|c| *c < Decimal::ZERO)
+                && p.cash_flows.iter().any(|c| *c > Decimal::ZERO);
+            if has_sign_change {
+                irr(&p.cash_flows, dec!(0.10)).ok()
+            } else {
+                None
+            }
+        }));
+    }
+    let problem = Problem {
+        npv: npvs,
+        capex: input
+            .projects
+            .iter()
+            .map(|p| {
+                let mut c = p.capex.clone();
+                c.resize(years, Decimal::ZERO);
+                c
+            })
+            .collect(),
+        mandatory: input.projects.iter().map(|p| p.mandatory).collect(),
+        requires: input
+            .projects
+            .iter()
+            .map(|p| p.requires.iter().map(|r| index[r.as_str()]).collect())
+            .collect(),
+        group: input
+            .projects
+            .iter()
+            .map(|p| {
+                p.exclusive_group.as_deref().map(|g| {
+                    let next = group_index.len();
+                    *group_index.entry(g).or_insert(next)
+                })
+            })
+            .collect(),
+    };
+    let groups = group_index.len();
+
+    let solve = |budgets: &[Money]| -> (Option<Vec<bool>>, SelectionMethod, bool) {
+        let exact = match input.method {
+            SelectionMethod::Exact => true,
+            SelectionMethod::Heuristic => false,
+            SelectionMethod::Auto => problem.len() <= MAX_AUTO_EXACT_PROJECTS,
+        };
+        if exact {
+            let (best, complete) = branch_and_bound(&problem, budgets, groups);
+            if complete {
+                return (best, SelectionMethod::Exact, true);
+            }
+            let fallback = heuristic(&problem, budgets);
+            let pick = match (best, fallback) {
+                (Some(a), Some(b)) if problem.value(&b) > problem.value(&a) => Some(b),
+                (Some(a), _) => Some(a),
+                (None, b) => b,
+            };
+            return (pick, SelectionMethod::Heuristic, false);
+        }
+        (
+            heuristic(&problem, budgets),
+            SelectionMethod::Heuristic,
+            false,
+        )
+    };
+
+    let (best, method_used, proven_optimal) = solve(&input.budgets);
+    let Some(selected) = best else {
+        return Err(CorpFinanceError::FinancialImpossibility(
+            "No feasible portfolio: mandatory projects and their requirements exceed the budget or conflict".into(),
+        ));
+    };
+    if input.method == SelectionMethod::Exact && !proven_optimal {
+        warnings.push(format!(
+            "Branch and bound stopped at {MAX_NODES} nodes; best of search and heuristic returned"
+        ));
+    }
+    let total_npv = problem.value(&selected);
+
+    // Shadow values by finite difference.
+    let relaxation = input.budget_relaxation.unwrap_or(dec!(0.10));
+    let mut budget_years = Vec::with_capacity(years);
+    for t in 0..years {
+        let capex: Money = (0..problem.len())
+            .filter(|&i| selected[i])
+            .map(|i| problem.capex[i][t])
+            .sum();
+        let budget = input.budgets[t];
+        let extra = budget * relaxation;
+        let shadow_value = if extra > Decimal::ZERO {
+            let mut relaxed = input.budgets.clone();
+            relaxed[t] += extra;
+            let gain = solve(&relaxed)
+                .0
+                .map(|s| problem.value(&s) - total_npv)
+                .unwrap_or(Decimal::ZERO);
+            gain.max(Decimal::ZERO) / extra
+        } else {
+            Decimal::ZERO
+        };
+        budget_years.push(BudgetYear {
+            year: t as u32 + 1,
+            budget,
+            capex,
+            slack: budget - capex,
+            utilization: if budget > Decimal::ZERO {
+                capex / budget
+            } else {
+                Decimal::ZERO
+            },
+            shadow_value,
+            binding: shadow_value > Decimal::ZERO,
+        });
+    }
+    let extra_all: Money = input.budgets.iter().map(|b| *b * relaxation).sum();
+    let shadow_value_all_years = if extra_all > Decimal::ZERO {
+        let relaxed: Vec<Money> = input
+            .budgets
+            .iter()
+            .map(|b| *b * (Decimal::ONE + relaxation))
+            .collect();
+        let gain = solve(&relaxed)
+            .0
+            .map(|s| problem.value(&s) - total_npv)
+            .unwrap_or(Decimal::ZERO);
+        gain.max(Decimal::ZERO) / extra_all
+    } else {
+        Decimal::ZERO
+    };
+
+    // Reasons for rejection, checked in order of precedence.
+    let mut rejected = Vec::new();
+    let mut chosen = Vec::new();
+    for (i, p) in input.projects.iter().enumerate() {
+        let total_capex: Money = problem.capex[i].iter().sum();
+        if selected[i] {
+            chosen.push(SelectedProject {
+                name: p.name.clone(),
+                npv: problem.npv[i],
+                irr: irrs[i],
+                total_capex,
+                npv_to_capex: if total_capex > Decimal::ZERO {
+                    Some(problem.npv[i] / total_capex)
+                } else {
+                    None
+                },
+                mandatory: p.mandatory,
+            });
+            continue;
+        }
+        let reason = if problem.npv[i] <= Decimal::ZERO
+            && !(0..problem.len())
+                .any(|j| problem.requires[j].contains(&i) && problem.npv[j] > Decimal::ZERO)
+        {
+            "Non-positive NPV".to_string()
+        } else if let Some(winner) = problem.group[i]
+            .and_then(|g| (0..problem.len()).find(|&j| selected[j] && problem.group[j] == Some(g)))
+        {
+            format!("Mutually exclusive with {}", input.projects[winner].name)
+        } else if (0..years).any(|t| problem.capex[i][t] > budget_years[t].slack) {
+            let year = (0..years)
+                .find(|&t| problem.capex[i][t] > budget_years[t].slack)
+                .unwrap_or(0);
+            format!("Budget in year {}", year + 1)
+        } else if let Some(&j) = problem.requires[i].iter().find(|&&j| !selected[j]) {
+            format!("Requires {}", input.projects[j].name)
+        } else {
+            "Lower value than the selected alternatives".to_string()
+        };
+        rejected.push(RejectedProject {
+            name: p.name.clone(),
+            npv: problem.npv[i],
+            reason,
+        });
+    }
+
+    let unconstrained_npv: Money = problem.npv.iter().filter(|v| **v > Decimal::ZERO).sum();
+    let npv_capture = if unconstrained_npv > Decimal::ZERO {
+        Some(total_npv / unconstrained_npv)
+    } else {
+        None
+    };
+    let total_capex = chosen.iter().map(|s| s.total_capex).sum();
+
+    if let Some(p) = chosen.iter().find(|s| s.mandatory && s.npv < Decimal::ZERO) {
+        warnings.push(format!("Mandatory project {} has negative NPV", p.name));
+    }
+    if !proven_optimal && input.method != SelectionMethod::Exact {
+        warnings.push("Heuristic solution: good and feasible, not proven optimal".into());
+    }
+
+    let output = ProjectSelectionOutput {
+        selected: chosen,
+        rejected,
+        total_npv,
+        total_capex,
+        budget_years,
+        shadow_value_all_years,
+        unconstrained_npv,
+        npv_capture,
+        method_used,
+        proven_optimal,
+    };
+
+    let elapsed = start.elapsed().as_micros() as u64;
+    let assumptions = serde_json::json!({
+        "objective": "maximise total NPV",
+        "projects": "whole projects only (0/1)",
+        "budget_relaxation": relaxation.to_string(),
+        "shadow_value": "NPV gain per unit of relaxed budget, re-solved",
+        "exact_limit": MAX_AUTO_EXACT_PROJECTS,
+    });
+
+    Ok(with_metadata(
+        "Capital project selection under multi-year budget constraints",
+        &assumptions,
+        warnings,
+        elapsed,
+        output,
+    ))
+}
+
+// ---------------------------------------------------------------------------
+// Validation
+// ---------------------------------------------------------------------------
+
+fn validate_input(input: &ProjectSelectionInput) -> CorpFinanceResult<()> {
+    if input.projects.is_empty() {
+        return Err(CorpFinanceError::InsufficientData(
+            "At least one candidate project is required".into(),
+        ));
+    }
+    if input.budgets.is_empty() {
+        return Err(CorpFinanceError::InsufficientData(
+            "At least one budget year is required".into(),
+        ));
+    }
+    crate::limits::check_schedule_periods("budgets", input.budgets.len() as u64)?;
+    if input.budgets.iter().any(|b| *b < Decimal::ZERO) {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "budgets".into(),
+            reason: "Budgets cannot be negative".into(),
+        });
+    }
+    if input.budget_relaxation.is_some_and(|r| r <= Decimal::ZERO) {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "budget_relaxation".into(),
+            reason: "Relaxation must be positive".into(),
+        });
+    }
+    let mut names = HashSet::new();
+    for p in &input.projects {
+        if !names.insert(p.name.as_str()) {
+            return Err(CorpFinanceError::InvalidInput {
+                field: "projects".into(),
+                reason: format!("Duplicate project name '{}'", p.name),
+            });
+        }
+    }
+    for p in &input.projects {
+        if p.capex.len() > input.budgets.len() {
+            return Err(CorpFinanceError::InvalidInput {
+                field: format!("{}.capex", p.name),
+                reason: "Capex extends beyond the budget years".into(),
+            });
+        }
+        if p.capex.iter().any(|c| *c < Decimal::ZERO) {
+            return Err(CorpFinanceError::InvalidInput {
+                field: format!("{}.capex", p.name),
+                reason: "Capex cannot be negative".into(),
+            });
+        }
+        if p.npv.is_none() && p.cash_flows.is_empty() {
+            return Err(CorpFinanceError::InvalidInput {
+                field: format!("{}.npv", p.name),
+                reason: "Provide an NPV or cash flows".into(),
+            });
+        }
+        if let Some(r) = p.requires.iter().find(|r| !names.contains(r.as_str())) {
+            return Err(CorpFinanceError::InvalidInput {
+                field: format!("{}.requires", p.name),
+                reason: format!("Unknown project '{r}'"),
+            });
+        }
+        if p.requires.contains(&p.name) {
+            return Err(CorpFinanceError::InvalidInput {
+                field: format!("{}.requires", p.name),
+                reason: "A project cannot require itself".into(),
+            });
+        }
+    }
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn project(name: &str, npv: Decimal, capex: Vec<Decimal>) -> CandidateProject {
+        CandidateProject {
+            name: name.into(),
+            npv: Some(npv),
+            irr: None,
+            cash_flows: vec![],
+            capex,
+            mandatory: false,
+            requires: vec![],
+            exclusive_group: None,
+        }
+    }
+
+    fn input(projects: Vec<CandidateProject>, budgets: Vec<Decimal>) -> ProjectSelectionInput {
+        ProjectSelectionInput {
+            projects,
+            budgets,
+            discount_rate: None,
+            method: SelectionMethod::Auto,
+            budget_relaxation: None,
+        }
+    }
+
+    fn names(out: &ProjectSelectionOutput) -> Vec<String> {
+        out.selected.iter().map(|s| s.name.clone()).collect()
+    }
+
+    #[test]
+    fn test_exact_beats_greedy_ranking() {
+        // Greedy by NPV/capex takes A (ratio 1.5) and leaves 40 unused;
+        // B + C use the full budget for more NPV.
+        let inp = input(
+            vec![
+                project("A", dec!(90), vec![dec!(60)]),
+                project("B", dec!(70), vec![dec!(50)]),
+                project("C", dec!(70), vec![dec!(50)]),
+            ],
+            vec![dec!(100)],
+        );
+        let out = select_projects(&inp).unwrap().result;
+        assert_eq!(names(&out), vec!["B", "C"]);
+        assert_eq!(out.total_npv, dec!(140));
+        assert!(out.proven_optimal);
+        assert_eq!(out.method_used, SelectionMethod::Exact);
+        assert_eq!(out.rejected[0].reason, "Budget in year 1");
+    }
+
+    #[test]
+    fn test_multi_year_phasing() {
+        // Year 2 is the binding year for the pair X + Y.
+        let inp = input(
+            vec![
+                project("X", dec!(50), vec![dec!(20), dec!(60)]),
+                project("Y", dec!(45), vec![dec!(20), dec!(50)]),
+                project("Z", dec!(30), vec![dec!(50), dec!(0)]),
+            ],
+            vec![dec!(100), dec!(100)],
+        );
+        let out = select_projects(&inp).unwrap().result;
+        assert_eq!(names(&out), vec!["X", "Z"]);
+        assert_eq!(out.budget_years[0].capex, dec!(70));
+        assert_eq!(out.budget_years[1].capex, dec!(60));
+        assert_eq!(out.budget_years[1].slack, dec!(40));
+        assert_eq!(out.unconstrained_npv, dec!(125));
+    }
+
+    #[test]
+    fn test_mutual_exclusivity_and_dependencies() {
+        let mut big = project("Plant large", dec!(100), vec![dec!(80)]);
+        big.exclusive_group = Some("site".into());
+        let mut small = project("Plant small", dec!(60), vec![dec!(40)]);
+        small.exclusive_group = Some("site".into());
+        let road = project("Access road", dec!(-10), vec![dec!(10)]);
+        let mut warehouse = project("Warehouse", dec!(50), vec![dec!(30)]);
+        warehouse.requires = vec!["Access road".into()];
+        let inp = input(vec![big, small, road, warehouse], vec![dec!(200)]);
+        let out = select_projects(&inp).unwrap().result;
+        // Road is negative NPV but enables the warehouse
+        assert_eq!(names(&out), vec!["Plant large", "Access road", "Warehouse"]);
+        assert_eq!(out.total_npv, dec!(140));
+        assert_eq!(
+            out.rejected[0].reason,
+            "Mutually exclusive with Plant large"
+        );
+    }
+
+    #[test]
+    fn test_mandatory_projects_and_infeasibility() {
+        let mut compliance = project("Compliance", dec!(-20), vec![dec!(50)]);
+        compliance.mandatory = true;
+        let inp = input(
+            vec![
+                compliance.clone(),
+                project("Growth", dec!(80), vec![dec!(60)]),
+            ],
+            vec![dec!(100)],
+        );
+        let out = select_projects(&inp).unwrap();
+        assert_eq!(names(&out.result), vec!["Compliance"]);
+        assert!(out.warnings.iter().any(|w| w.contains("negative NPV")));
+
+        let inp = input(vec![compliance], vec![dec!(40)]);
+        assert!(matches!(
+            select_projects(&inp),
+            Err(CorpFinanceError::FinancialImpossibility(_))
+        ));
+    }
+
+    #[test]
+    fn test_shadow_value_of_binding_budget() {
+        let inp = input(
+            vec![
+                project("A", dec!(90), vec![dec!(60), dec!(0)]),
+                project("B", dec!(40), vec![dec!(50), dec!(0)]),
+                project("C", dec!(20), vec![dec!(0), dec!(10)]),
+            ],
+            vec![dec!(100), dec!(100)],
+        );
+        let out = select_projects(&inp).unwrap().result;
+        assert_eq!(out.total_npv, dec!(110));
+        // Ten more in year 1 lets B in: +40 NPV for +10 budget
+        assert_eq!(out.budget_years[0].shadow_value, dec!(4));
+        assert!(out.budget_years[0].binding);
+        assert_eq!(out.budget_years[1].shadow_value, Decimal::ZERO);
+        assert_eq!(out.shadow_value_all_years, dec!(40) / dec!(20));
+    }
+
+    #[test]
+    fn test_npv_and_irr_from_cash_flows() {
+        let mut p = project("CF", Decimal::ZERO, vec![dec!(100)]);
+        p.npv = None;
+        p.cash_flows = vec![dec!(-100), dec!(60), dec!(60)];
+        let mut inp = input(vec![p], vec![dec!(100)]);
+        inp.discount_rate = Some(dec!(0.10));
+        let out = select_projects(&inp).unwrap().result;
+        let s = &out.selected[0];
+        let expected = dec!(-100) + dec!(60) / dec!(1.1) + dec!(60) / dec!(1.21);
+        assert!((s.npv - expected).abs() < dec!(0.0001));
+        assert!((s.irr.unwrap() - dec!(0.1306623863)).abs() < dec!(0.0001));
+    }
+
+    #[test]
+    fn test_heuristic_matches_exact_on_mixed_problem() {
+        let mut projects = Vec::new();
+        for k in 0..12u32 {
+            let k_dec = Decimal::from(k);
+            let mut p = project(
+                &format!("P{k}"),
+                dec!(10) + k_dec * dec!(3),
+                vec![dec!(8) + k_dec * dec!(2), dec!(20) - k_dec],
+            );
+            if k % 4 == 0 {
+                p.exclusive_group = Some("g".into());
+            }
+            projects.push(p);
+        }
+        let exact = select_projects(&input(projects.clone(), vec![dec!(80), dec!(70)]))
+            .unwrap()
+            .result;
+        let mut h = input(projects, vec![dec!(80), dec!(70)]);
+        h.method = SelectionMethod::Heuristic;
+        let heur = select_projects(&h).unwrap();
+        assert!(!heur.result.proven_optimal);
+        assert!(heur.result.total_npv <= exact.total_npv);
+        assert!(heur.result.total_npv >= exact.total_npv * dec!(0.95));
+        for y in &heur.result.budget_years {
+            assert!(y.capex <= y.budget);
+        }
+    }
+
+    #[test]
+    fn test_validation() {
+        let inp = input(vec![], vec![dec!(100)]);
+        assert!(select_projects(&inp).is_err());
+
+        let mut p = project("A", dec!(10), vec![dec!(10)]);
+        p.requires = vec!["Missing".into()];
+        assert!(select_projects(&input(vec![p], vec![dec!(100)])).is_err());
+
+        let p = project("A", dec!(10), vec![dec!(10), dec!(10)]);
+        assert!(select_projects(&input(vec![p], vec![dec!(100)])).is_err());
+    }
+}
@@ -0,0 +1,1202 @@
+//! Cash flow ladders for mixed books.
+//!
+//! Bonds, amortising loans, leases, ABS pools and tranches, project finance
+//! debt or equity, annuities and hand-entered schedules are run through
+//! their own models, and the projected flows are dated, split into
+//! interest, principal and other, and summed into tenor or calendar
+//! buckets for assets and liabilities. Every flow is stated from the
+//! holder's side as a receipt; a liability position turns its receipts
+//! into payments, so the net ladder is assets less liabilities.
+
+use std::time::Instant;
+
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::amortization::{build_schedule, AmortizationInput};
+use crate::curves::Curve;
+use crate::error::CorpFinanceError;
+use crate::fixed_income::bonds::{price_bond, BondPricingInput};
+use crate::fixed_income::daycount::add_months;
+use crate::lease_accounting::classification::{classify_lease, LeaseInput};
+use crate::real_assets::project_finance::{model_project_finance, ProjectFinanceInput};
+use crate::securitization::abs_mbs::{model_abs_cashflows, AbsMbsInput};
+use crate::securitization::tranching::{analyze_tranching, TranchingInput};
+use crate::types::{with_metadata, ComputationOutput, Money, Rate, Years};
+use crate::CorpFinanceResult;
+
+/// Upper edges in months of the default tenor buckets; a final open
+/// bucket catches everything beyond ten years.
+const STANDARD_TENOR_EDGES: [u32; 8] = [1, 3, 6, 12, 24, 36, 60, 120];
+
+// ---------------------------------------------------------------------------
+// Input types
+// ---------------------------------------------------------------------------
+
+/// Whether a position's flows are received or paid.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LadderSide {
+    #[default]
+    Asset,
+    Liability,
+}
+
+/// Which project finance flows the position holds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProjectComponent {
+    /// Senior lender: drawdowns during construction (negative principal),
+    /// then interest and repayments
+    SeniorDebt,
+    /// Sponsor: distributions from the waterfall
+    Equity,
+}
+
+/// One hand-entered cash flow.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScheduledFlow {
+    pub date: NaiveDate,
+    #[serde(default)]
+    pub interest: Money,
+    #[serde(default)]
+    pub principal: Money,
+    #[serde(default)]
+    pub other: Money,
+}
+
+/// The model a position's flows are projected from, tagged by `type`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum CashFlowSource {
+    /// Coupons and redemption of one bond after settlement
+    Bond { bond: BondPricingInput },
+    /// Cash interest and principal of an amortising loan
+    Loan {
+        loan: AmortizationInput,
+        /// Date of the first payment
+        first_payment_date: NaiveDate,
+        /// Months between payments
+        period_months: u32,
+    },
+    /// Monthly lease payments in arrears, split into interest and principal
+    Lease {
+        lease: LeaseInput,
+        commencement_date: NaiveDate,
+    },
+    /// All pool cash flows (principal, interest net of servicing, recoveries)
+    AbsPool {
+        pool: AbsMbsInput,
+        /// Date of month 1 when the pool has no `first_payment_date`
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        first_payment_date: Option<NaiveDate>,
+    },
+    /// Interest and principal paid to one tranche of a waterfall
+    AbsTranche {
+        deal: TranchingInput,
+        tranche_name: String,
+        first_payment_date: NaiveDate,
+        period_months: u32,
+    },
+    /// Senior debt or equity flows of a project, at each year end
+    ProjectFinance {
+        project: ProjectFinanceInput,
+        start_date: NaiveDate,
+        component: ProjectComponent,
+    },
+    /// A level or growing annuity certain
+    Annuity {
+        payment: Money,
+        periods: u32,
+        period_months: u32,
+        first_payment_date: NaiveDate,
+        /// Growth of the payment each period (0.005 = +0.5%)
+        #[serde(default)]
+        growth_rate: Rate,
+    },
+    /// Flows from any other model, entered directly
+    Scheduled { flows: Vec<ScheduledFlow> },
+}
+
+impl CashFlowSource {
+    fn type_name(&self) -> &'static str {
+        match self {
+            CashFlowSource::Bond { .. } => "bond",
+            CashFlowSource::Loan { .. } => "loan",
+            CashFlowSource::Lease { .. } => "lease",
+            CashFlowSource::AbsPool { .. } => "abs_pool",
+            CashFlowSource::AbsTranche { .. } => "abs_tranche",
+            CashFlowSource::ProjectFinance { .. } => "project_finance",
+            CashFlowSource::Annuity { .. } => "annuity",
+            CashFlowSource::Scheduled { .. } => "scheduled",
+        }
+    }
+}
+
+/// One position in the book.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LadderPosition {
+    pub id: String,
+    #[serde(default)]
+    pub side: LadderSide,
+    /// Multiplier on the model's flows: number of bonds, share of a
+    /// tranche or loan held (default 1)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub quantity: Option<Decimal>,
+    pub source: CashFlowSource,
+}
+
+/// How flows are grouped.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LadderBucketing {
+    /// Tenor buckets ending the given number of months after `as_of`, plus
+    /// an open bucket for later flows
+    Tenor(Vec<u32>),
+    /// Consecutive periods of the given number of months, out to the last
+    /// flow
+    Periodic(u32),
+}
+
+impl Default for LadderBucketing {
+    fn default() -> Self {
+        LadderBucketing::Tenor(STANDARD_TENOR_EDGES.to_vec())
+    }
+}
+
+/// Input for building an aggregated cash flow ladder.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CashFlowLadderInput {
+    /// Ladder date; flows on or before it are left out
+    pub as_of: NaiveDate,
+    pub positions: Vec<LadderPosition>,
+    /// Bucket definition (default tenor buckets 1M, 3M, 6M, 1Y, 2Y, 3Y, 5Y,
+    /// 10Y and over)
+    #[serde(default)]
+    pub bucketing: LadderBucketing,
+    /// Curve for present values and durations (ACT/365 from `as_of`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub discount_curve: Option<Curve>,
+    /// Return every dated flow as well as the buckets (default true)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub include_flows: Option<bool>,
+}
+
+// ---------------------------------------------------------------------------
+// Output types
+// ---------------------------------------------------------------------------
+
+/// Interest, principal and other cash in one total.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct FlowComponents {
+    pub interest: Money,
+    pub principal: Money,
+    pub other: Money,
+    pub total: Money,
+}
+
+impl FlowComponents {
+    fn add(&mut self, other: &FlowComponents) {
+        self.interest += other.interest;
+        self.principal += other.principal;
+        self.other += other.other;
+        self.total += other.total;
+    }
+
+    fn scaled(&self, factor: Decimal) -> FlowComponents {
+        FlowComponents {
+            interest: self.interest * factor,
+            principal: self.principal * factor,
+            other: self.other * factor,
+            total: self.total * factor,
+        }
+    }
+}
+
+/// One dated flow of one position, from the holder's side.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LadderFlow {
+    pub position_id: String,
+    pub side: LadderSide,
+    pub date: NaiveDate,
+    pub bucket: String,
+    pub amounts: FlowComponents,
+}
+
+/// Asset and liability flows falling in one bucket.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LadderBucket {
+    pub label: String,
+    /// Flows after this date...
+    pub start_date: NaiveDate,
+    /// ...and on or before this one (open-ended when absent)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_date: Option<NaiveDate>,
+    pub assets: FlowComponents,
+    pub liabilities: FlowComponents,
+    /// Asset less liability flows
+    pub net: Money,
+    pub cumulative_net: Money,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pv_assets: Option<Money>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pv_liabilities: Option<Money>,
+}
+
+/// One position's flows in total and by bucket.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PositionLadder {
+    pub id: String,
+    pub side: LadderSide,
+    pub source_type: String,
+    pub num_flows: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_date: Option<NaiveDate>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_date: Option<NaiveDate>,
+    pub totals: FlowComponents,
+    /// Total flow in each bucket, in bucket order
+    pub by_bucket: Vec<Money>,
+    /// Principal-weighted average life in years
+    pub weighted_average_life: Years,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub present_value: Option<Money>,
+}
+
+/// Book-level totals.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LadderSummary {
+    pub total_assets: FlowComponents,
+    pub total_liabilities: FlowComponents,
+    pub net: Money,
+    /// Most negative cumulative net position across buckets (zero if never
+    /// negative)
+    pub max_cumulative_shortfall: Money,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_shortfall_bucket: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pv_assets: Option<Money>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pv_liabilities: Option<Money>,
+    /// PV-weighted average time of asset flows, in years
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub asset_duration: Option<Years>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub liability_duration: Option<Years>,
+}
+
+/// Output of cash flow ladder aggregation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CashFlowLadderOutput {
+    pub as_of: NaiveDate,
+    pub buckets: Vec<LadderBucket>,
+    pub positions: Vec<PositionLadder>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub flows: Vec<LadderFlow>,
+    pub summary: LadderSummary,
+}
+
+// ---------------------------------------------------------------------------
+// Extraction
+// ---------------------------------------------------------------------------
+
+/// A projected flow before scaling and bucketing.
+struct RawFlow {
+    date: NaiveDate,
+    amounts: FlowComponents,
+}
+
+fn flow(date: NaiveDate, interest: Money, principal: Money, other: Money) -> RawFlow {
+    RawFlow {
+        date,
+        amounts: FlowComponents {
+            interest,
+            principal,
+            other,
+            total: interest + principal + other,
+        },
+    }
+}
+
+fn months(n: u32) -> i32 {
+    i32::try_from(n).unwrap_or(i32::MAX)
+}
+
+fn check_period_months(field: &str, period_months: u32) -> CorpFinanceResult<()> {
+    if period_months == 0 {
+        return Err(CorpFinanceError::InvalidInput {
+            field: field.into(),
+            reason: "Months between payments must be positive".into(),
+        });
+    }
+    Ok(())
+}
+
+/// Run the source's model and date its flows.
+fn extract(
+    source: &CashFlowSource,
+    as_of: NaiveDate,
+    field: &str,
+    warnings: &mut Vec<String>,
+) -> CorpFinanceResult<Vec<RawFlow>> {
+    match source {
+        CashFlowSource::Bond { bond } => {
+            let out = price_bond(bond)?;
+            warnings.extend(out.warnings);
+            let coupon = out.result.coupon_amount;
+            Ok(out
+                .result
+                .total_cashflows
+                .iter()
+                .map(|cf| {
+                    let interest = cf.amount.min(coupon);
+                    flow(cf.date, interest, cf.amount - interest, Decimal::ZERO)
+                })
+                .collect())
+        }
+        CashFlowSource::Loan {
+            loan,
+            first_payment_date,
+            period_months,
+        } => {
+            check_period_months(&format!("{field}.period_months"), *period_months)?;
+            let schedule = build_schedule(loan)?;
+            Ok(schedule
+                .periods
+                .iter()
+                .map(|p| {
+                    let date =
+                        add_months(*first_payment_date, months((p.period - 1) * period_months));
+                    flow(date, p.interest, p.principal, Decimal::ZERO)
+                })
+                .collect())
+        }
+        CashFlowSource::Lease {
+            lease,
+            commencement_date,
+        } => {
+            let out = classify_lease(lease)?;
+            Ok(out
+                .amortization_schedule
+                .iter()
+                .map(|row| {
+                    flow(
+                        add_months(*commencement_date, months(row.month)),
+                        row.interest_expense,
+                        row.payment - row.interest_expense,
+                        Decimal::ZERO,
+                    )
+                })
+                .collect())
+        }
+        CashFlowSource::AbsPool {
+            pool,
+            first_payment_date,
+        } => {
+            let out = model_abs_cashflows(pool)?;
+            warnings.extend(out.warnings);
+            let first = first_payment_date.unwrap_or_else(|| add_months(as_of, 1));
+            Ok(out
+                .result
+                .periods
+                .iter()
+                .map(|p| {
+                    let date = p
+                        .payment_date
+                        .unwrap_or_else(|| add_months(first, months(p.month - 1)));
+                    let principal = p.total_principal;
+                    flow(date, p.total_cashflow - principal, principal, Decimal::ZERO)
+                })
+                .collect())
+        }
+        CashFlowSource::AbsTranche {
+            deal,
+            tranche_name,
+            first_payment_date,
+            period_months,
+        } => {
+            check_period_months(&format!("{field}.period_months"), *period_months)?;
+            if !deal.tranches.iter().any(|t| &t.name == tranche_name) {
+                return Err(CorpFinanceError::InvalidInput {
+                    field: format!("{field}.tranche_name"),
+                    reason: format!("No tranche '{tranche_name}' in deal '{}'", deal.deal_name),
+                });
+            }
+            let out = analyze_tranching(deal)?;
+            warnings.extend(out.warnings);
+            Ok(out
+                .result
+                .waterfall_periods
+                .iter()
+                .filter_map(|p| {
+                    let payment = p
+                        .tranche_payments
+                        .iter()
+                        .find(|t| &t.tranche_name == tranche_name)?;
+                    let date =
+                        add_months(*first_payment_date, months((p.period - 1) * period_months));
+                    Some(flow(
+                        date,
+                        payment.interest_paid,
+                        payment.principal_paid,
+                        Decimal::ZERO,
+                    ))
+                })
+                .collect())
+        }
+        CashFlowSource::ProjectFinance {
+            project,
+            start_date,
+            component,
+        } => {
+            let out = model_project_finance(project)?;
+            warnings.extend(out.warnings);
+            let year_end = |year: i32| add_months(*start_date, 12 * year);
+            Ok(match component {
+                ProjectComponent::SeniorDebt => {
+                    // The model draws project cost during construction;
+                    // the lender funds its senior share of each draw, as
+                    // negative principal. Interest capitalised meanwhile is
+                    // repaid through the larger opening balance, recovered
+                    // from service = opening x (1 + r) - closing.
+                    let rate = project.debt_assumptions.senior_rate;
+                    let senior_share = if project.total_project_cost.is_zero() {
+                        Decimal::ZERO
+                    } else {
+                        project.debt_assumptions.senior_debt / project.total_project_cost
+                    };
+                    let mut drawn = Decimal::ZERO;
+                    out.result
+                        .projections
+                        .iter()
+                        .map(|y| {
+                            let date = year_end(y.year);
+                            if y.year <= months(project.construction_period_years) {
+                                let draw = (y.outstanding_debt - drawn) * senior_share;
+                                drawn = y.outstanding_debt;
+                                return flow(date, Decimal::ZERO, -draw, Decimal::ZERO);
+                            }
+                            let opening = (y.senior_debt_service + y.outstanding_debt)
+                                / (Decimal::ONE + rate);
+                            let interest = opening * rate;
+                            flow(
+                                date,
+                                interest,
+                                y.senior_debt_service - interest,
+                                Decimal::ZERO,
+                            )
+                        })
+                        .collect()
+                }
+                ProjectComponent::Equity => out
+                    .result
+                    .distribution_waterfall
+                    .iter()
+                    .map(|y| {
+                        flow(
+                            year_end(y.year),
+                            Decimal::ZERO,
+                            Decimal::ZERO,
+                            y.equity_distribution,
+                        )
+                    })
+                    .collect(),
+            })
+        }
+        CashFlowSource::Annuity {
+            payment,
+            periods,
+            period_months,
+            first_payment_date,
+            growth_rate,
+        } => {
+            check_period_months(&format!("{field}.period_months"), *period_months)?;
+            crate::limits::check_schedule_periods(&format!("{field}.periods"), (*periods).into())?;
+            let mut amount = *payment;
+            Ok((0..*periods)
+                .map(|k| {
+                    let date = add_months(*first_payment_date, months(k * period_months));
+                    let f = flow(date, Decimal::ZERO, Decimal::ZERO, amount);
+                    amount *= Decimal::ONE + growth_rate;
+                    f
+                })
+                .collect())
+        }
+        CashFlowSource::Scheduled { flows } => Ok(flows
+            .iter()
+            .map(|f| flow(f.date, f.interest, f.principal, f.other))
+            .collect()),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Bucketing
+// ---------------------------------------------------------------------------
+
+/// Bucket boundaries as (label, start, end).
+fn bucket_bounds(
+    bucketing: &LadderBucketing,
+    as_of: NaiveDate,
+    last_flow: Option<NaiveDate>,
+) -> CorpFinanceResult<Vec<(String, NaiveDate, Option<NaiveDate>)>> {
+    let tenor_label = |m: u32| {
+        if m.is_multiple_of(12) {
+            format!("{}Y", m / 12)
+        } else {
+            format!("{m}M")
+        }
+    };
+    match bucketing {
+        LadderBucketing::Tenor(edges) => {
+            if edges.is_empty() || edges[0] == 0 || edges.windows(2).any(|w| w[1] <= w[0]) {
+                return Err(CorpFinanceError::InvalidInput {
+                    field: "bucketing.tenor".into(),
+                    reason: "Tenor edges must be positive and strictly increasing".into(),
+                });
+            }
+            let mut bounds = Vec::with_capacity(edges.len() + 1);
+            let mut start = as_of;
+            let mut prev = 0;
+            for &edge in edges {
+                let end = add_months(as_of, months(edge));
+                bounds.push((
+                    format!(
+                        "{}-{}",
+                        if prev == 0 {
+                            "0".to_string()
+                        } else {
+                            tenor_label(prev)
+                        },
+                        tenor_label(edge)
+                    ),
+                    start,
+                    Some(end),
+                ));
+                start = end;
+                prev = edge;
+            }
+            bounds.push((format!("{}+", tenor_label(prev)), start, None));
+            Ok(bounds)
+        }
+        LadderBucketing::Periodic(period_months) => {
+            check_period_months("bucketing.periodic", *period_months)?;
+            let mut bounds = Vec::new();
+            let mut start = as_of;
+            let last = last_flow.unwrap_or(as_of);
+            let mut k = 1;
+            loop {
+                let end = add_months(as_of, months(k * period_months));
+                bounds.push((format!("P{k}"), start, Some(end)));
+                if end >= last {
+                    break;
+                }
+                crate::limits::check_schedule_periods("bucketing.periodic", k.into())?;
+                start = end;
+                k += 1;
+            }
+            Ok(bounds)
+        }
+    }
+}
+
+fn bucket_index(bounds: &[(String, NaiveDate, Option<NaiveDate>)], date: NaiveDate) -> usize {
+    bounds
+        .iter()
+        .position(|(_, _, end)| end.is_none_or(|end| date <= end))
+        .unwrap_or(bounds.len() - 1)
+}
+
+fn years_between(from: NaiveDate, to: NaiveDate) -> Years {
+    Decimal::from((to - from).num_days()) / Decimal::from(365)
+}
+
+// ---------------------------------------------------------------------------
+// Validation
+// ---------------------------------------------------------------------------
+
+fn validate_input(input: &CashFlowLadderInput) -> CorpFinanceResult<()> {
+    if input.positions.is_empty() {
+        return Err(CorpFinanceError::InsufficientData(
+            "At least one position required".into(),
+        ));
+    }
+    for (i, p) in input.positions.iter().enumerate() {
+        if input.positions[..i].iter().any(|q| q.id == p.id) {
+            return Err(CorpFinanceError::InvalidInput {
+                field: format!("positions[{i}].id"),
+                reason: format!("Duplicate position id '{}'", p.id),
+            });
+        }
+    }
+    if let Some(curve) = &input.discount_curve {
+        curve.validate("discount_curve")?;
+    }
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Public API
+// ---------------------------------------------------------------------------
+
+/// Project every position's cash flows through its own model and aggregate
+/// them into an asset/liability ladder.
+pub fn build_cashflow_ladder(
+    input: &CashFlowLadderInput,
+) -> CorpFinanceResult<ComputationOutput<CashFlowLadderOutput>> {
+    let start = Instant::now();
+    let mut warnings: Vec<String> = Vec::new();
+
+    validate_input(input)?;
+    let as_of = input.as_of;
+
+    // --- Extract and scale ---
+    let mut extracted: Vec<Vec<RawFlow>> = Vec::with_capacity(input.positions.len());
+    for (i, p) in input.positions.iter().enumerate() {
+        let mut model_warnings = Vec::new();
+        let raw = extract(
+            &p.source,
+            as_of,
+            &format!("positions[{i}].source"),
+            &mut model_warnings,
+        )?;
+        warnings.extend(
+            model_warnings
+                .into_iter()
+                .map(|w| format!("Position '{}': {w}", p.id)),
+        );
+        let quantity = p.quantity.unwrap_or(Decimal::ONE);
+        let before = raw.len();
+        let kept: Vec<RawFlow> = raw
+            .into_iter()
+            .filter(|f| f.date > as_of)
+            .map(|f| RawFlow {
+                date: f.date,
+                amounts: f.amounts.scaled(quantity),
+            })
+            .collect();
+        if kept.len() < before {
+            warnings.push(format!(
+                "Position '{}': {} flows on or before {as_of} left out",
+                p.id,
+                before - kept.len()
+            ));
+        }
+        if kept.is_empty() {
+            warnings.push(format!("Position '{}' has no flows after {as_of}", p.id));
+        }
+        extracted.push(kept);
+    }
+
+    // --- Buckets ---
+    let last_flow = extracted.iter().flatten().map(|f| f.date).max();
+    let bounds = bucket_bounds(&input.bucketing, as_of, last_flow)?;
+    let discount = |date: NaiveDate| {
+        input
+            .discount_curve
+            .as_ref()
+            .map(|c| c.discount_factor(years_between(as_of, date)))
+    };
+
+    let mut buckets: Vec<LadderBucket> = bounds
+        .iter()
+        .map(|(label, start, end)| LadderBucket {
+            label: label.clone(),
+            start_date: *start,
+            end_date: *end,
+            assets: FlowComponents::default(),
+            liabilities: FlowComponents::default(),
+            net: Decimal::ZERO,
+            cumulative_net: Decimal::ZERO,
+            pv_assets: input.discount_curve.as_ref().map(|_| Decimal::ZERO),
+            pv_liabilities: input.discount_curve.as_ref().map(|_| Decimal::ZERO),
+        })
+        .collect();
+
+    let include_flows = input.include_flows.unwrap_or(true);
+    let mut flows: Vec<LadderFlow> = Vec::new();
+    let mut positions: Vec<PositionLadder> = Vec::with_capacity(input.positions.len());
+    // (sum of PV, sum of PV x t) per side for durations
+    let mut pv_time = [(Decimal::ZERO, Decimal::ZERO); 2];
+
+    for (p, raw) in input.positions.iter().zip(&extracted) {
+        let mut totals = FlowComponents::default();
+        let mut by_bucket = vec![Decimal::ZERO; buckets.len()];
+        let mut wal_numerator = Decimal::ZERO;
+        let mut present_value = input.discount_curve.as_ref().map(|_| Decimal::ZERO);
+        let side_idx = usize::from(p.side == LadderSide::Liability);
+
+        for f in raw {
+            let idx = bucket_index(&bounds, f.date);
+            let bucket = &mut buckets[idx];
+            let t = years_between(as_of, f.date);
+            match p.side {
+                LadderSide::Asset => bucket.assets.add(&f.amounts),
+                LadderSide::Liability => bucket.liabilities.add(&f.amounts),
+            }
+            if let Some(df) = discount(f.date) {
+                let pv = f.amounts.total * df;
+                let slot = match p.side {
+                    LadderSide::Asset => &mut bucket.pv_assets,
+                    LadderSide::Liability => &mut bucket.pv_liabilities,
+                };
+                *slot = slot.map(|v| v + pv);
+                present_value = present_value.map(|v| v + pv);
+                pv_time[side_idx].0 += pv;
+                pv_time[side_idx].1 += pv * t;
+            }
+            totals.add(&f.amounts);
+            by_bucket[idx] += f.amounts.total;
+            wal_numerator += f.amounts.principal * t;
+
+            if include_flows {
+                flows.push(LadderFlow {
+                    position_id: p.id.clone(),
+                    side: p.side,
+                    date: f.date,
+                    bucket: bucket.label.clone(),
+                    amounts: f.amounts.clone(),
+                });
+            }
+        }
+
+        positions.push(PositionLadder {
+            id: p.id.clone(),
+            side: p.side,
+            source_type: p.source.type_name().to_string(),
+            num_flows: raw.len(),
+            first_date: raw.iter().map(|f| f.date).min(),
+            last_date: raw.iter().map(|f| f.date).max(),
+            weighted_average_life: if totals.principal.is_zero() {
+                Decimal::ZERO
+            } else {
+                wal_numerator / totals.principal
+            },
+            totals,
+            by_bucket,
+            present_value,
+        });
+    }
+    flows.sort_by_key(|f| f.date);
+
+    // --- Net and cumulative positions ---
+    let mut cumulative = Decimal::ZERO;
+    let mut max_shortfall = Decimal::ZERO;
+    let mut first_shortfall_bucket = None;
+    for bucket in &mut buckets {
+        bucket.net = bucket.assets.total - bucket.liabilities.total;
+        cumulative += bucket.net;
+        bucket.cumulative_net = cumulative;
+        if cumulative < Decimal::ZERO {
+            first_shortfall_bucket.get_or_insert_with(|| bucket.label.clone());
+            max_shortfall = max_shortfall.min(cumulative);
+        }
+    }
+
+    let mut total_assets = FlowComponents::default();
+    let mut total_liabilities = FlowComponents::default();
+    for bucket in &buckets {
+        total_assets.add(&bucket.assets);
+        total_liabilities.add(&bucket.liabilities);
+    }
+    let has_curve = input.discount_curve.is_some();
+    let duration = |(pv, pv_t): (Decimal, Decimal)| (has_curve && !pv.is_zero()).then(|| pv_t / pv);
+    let summary = LadderSummary {
+        net: total_assets.total - total_liabilities.total,
+        total_assets,
+        total_liabilities,
+        max_cumulative_shortfall: max_shortfall,
+        first_shortfall_bucket,
+        pv_assets: has_curve.then_some(pv_time[0].0),
+        pv_liabilities: has_curve.then_some(pv_time[1].0),
+        asset_duration: duration(pv_time[0]),
+        liability_duration: duration(pv_time[1]),
+    };
+
+    let output = CashFlowLadderOutput {
+        as_of,
+        buckets,
+        positions,
+        flows,
+        summary,
+    };
+
+    let elapsed = start.elapsed().as_micros() as u64;
+    let assumptions = serde_json::json!({
+        "as_of": as_of.to_string(),
+        "num_positions": input.positions.len(),
+        "bucketing": input.bucketing,
+        "sign_convention": "flows are receipts to the holder; liabilities are paid",
+        "discounting": if has_curve { "discount curve, ACT/365 from as_of" } else { "none" },
+        "bond_split": "coupon as interest, remainder as principal",
+        "project_finance_dates": "year ends from start_date",
+    });
+
+    Ok(with_metadata(
+        "Aggregated cash flow ladder across instrument models",
+        &assumptions,
+        warnings,
+        elapsed,
+        output,
+    ))
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::amortization::AmortizationMethod;
+    use crate::fixed_income::daycount::DayCountConvention;
+    use crate::lease_accounting::classification::LeaseStandard;
+    use crate::real_assets::project_finance::{
+        DebtSculpting, OpExAssumptions, ProjectDebt, RevenueAssumptions,
+    };
+    use crate::types::Currency;
+    use rust_decimal_macros::dec;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn bond_source() -> CashFlowSource {
+        CashFlowSource::Bond {
+            bond: BondPricingInput {
+                face_value: dec!(1000),
+                coupon_rate: dec!(0.05),
+                coupon_frequency: 2,
+                ytm: dec!(0.05),
+                settlement_date: date(2026, 1, 1),
+                maturity_date: date(2029, 1, 1),
+                day_count: DayCountConvention::Thirty360,
+                call_price: None,
+                call_date: None,
+                currency: Currency::USD,
+                presentation_currency: None,
+                fx_rates: Vec::new(),
+                payment_adjustment: None,
+            },
+        }
+    }
+
+    fn loan_source() -> CashFlowSource {
+        CashFlowSource::Loan {
+            loan: AmortizationInput {
+                principal: dec!(1_200_000),
+                rate: dec!(0.005),
+                pik_rate: Decimal::ZERO,
+                periods: 24,
+                method: AmortizationMethod::StraightLine(dec!(1) / dec!(24)),
+            },
+            first_payment_date: date(2026, 2, 1),
+            period_months: 1,
+        }
+    }
+
+    fn annuity_source() -> CashFlowSource {
+        CashFlowSource::Annuity {
+            payment: dec!(10_000),
+            periods: 12,
+            period_months: 3,
+            first_payment_date: date(2026, 4, 1),
+            growth_rate: Decimal::ZERO,
+        }
+    }
+
+    fn position(id: &str, side: LadderSide, source: CashFlowSource) -> LadderPosition {
+        LadderPosition {
+            id: id.into(),
+            side,
+            quantity: None,
+            source,
+        }
+    }
+
+    fn input(positions: Vec<LadderPosition>) -> CashFlowLadderInput {
+        CashFlowLadderInput {
+            as_of: date(2026, 1, 1),
+            positions,
+            bucketing: LadderBucketing::default(),
+            discount_curve: None,
+            include_flows: None,
+        }
+    }
+
+    #[test]
+    fn test_bond_flows_split_into_coupon_and_redemption() {
+        let mut pos = position("bond", LadderSide::Asset, bond_source());
+        pos.quantity = Some(dec!(10));
+        let out = build_cashflow_ladder(&input(vec![pos])).unwrap().result;
+        let p = &out.positions[0];
+        assert_eq!(p.num_flows, 6);
+        assert_eq!(p.totals.interest, dec!(25) * dec!(6) * dec!(10));
+        assert_eq!(p.totals.principal, dec!(10_000));
+        // Three-year bond redeems in the 2Y-3Y bucket
+        let redemption = out.buckets.iter().find(|b| b.label == "2Y-3Y").unwrap();
+        assert_eq!(redemption.assets.principal, dec!(10_000));
+        assert!((p.weighted_average_life - dec!(3)).abs() < dec!(0.01));
+    }
+
+    #[test]
+    fn test_loan_principal_sums_to_balance_across_buckets() {
+        let out = build_cashflow_ladder(&input(vec![position(
+            "loan",
+            LadderSide::Asset,
+            loan_source(),
+        )]))
+        .unwrap()
+        .result;
+        let principal: Money = out.buckets.iter().map(|b| b.assets.principal).sum();
+        assert!((principal - dec!(1_200_000)).abs() < dec!(0.01));
+        // Straight-line: one month of principal in the first bucket
+        assert!((out.buckets[0].assets.principal - dec!(50_000)).abs() < dec!(0.01));
+        let by_bucket: Money = out.positions[0].by_bucket.iter().sum();
+        assert!((by_bucket - out.positions[0].totals.total).abs() < dec!(0.000001));
+    }
+
+    #[test]
+    fn test_liabilities_net_against_assets() {
+        let out = build_cashflow_ladder(&input(vec![
+            position("loan", LadderSide::Asset, loan_source()),
+            position("annuity", LadderSide::Liability, annuity_source()),
+        ]))
+        .unwrap()
+        .result;
+        for b in &out.buckets {
+            assert_eq!(b.net, b.assets.total - b.liabilities.total);
+        }
+        assert_eq!(out.summary.total_liabilities.total, dec!(120_000));
+        assert_eq!(
+            out.summary.net,
+            out.summary.total_assets.total - out.summary.total_liabilities.total
+        );
+        let last = out.buckets.last().unwrap();
+        assert_eq!(last.cumulative_net, out.summary.net);
+    }
+
+    #[test]
+    fn test_shortfall_reported_when_liabilities_come_first() {
+        let out = build_cashflow_ladder(&input(vec![
+            position("bond", LadderSide::Asset, bond_source()),
+            position("annuity", LadderSide::Liability, annuity_source()),
+        ]))
+        .unwrap()
+        .result;
+        assert!(out.summary.max_cumulative_shortfall < Decimal::ZERO);
+        assert!(out.summary.first_shortfall_bucket.is_some());
+    }
+
+    #[test]
+    fn test_periodic_buckets_cover_every_flow() {
+        let mut inp = input(vec![position("loan", LadderSide::Asset, loan_source())]);
+        inp.bucketing = LadderBucketing::Periodic(12);
+        let out = build_cashflow_ladder(&inp).unwrap().result;
+        assert_eq!(out.buckets.len(), 2);
+        assert_eq!(out.buckets[0].label, "P1");
+        let total: Money = out.buckets.iter().map(|b| b.assets.total).sum();
+        assert!((total - out.positions[0].totals.total).abs() < dec!(0.000001));
+    }
+
+    #[test]
+    fn test_growing_annuity() {
+        let out = build_cashflow_ladder(&input(vec![position(
+            "annuity",
+            LadderSide::Asset,
+            CashFlowSource::Annuity {
+                payment: dec!(100),
+                periods: 3,
+                period_months: 12,
+                first_payment_date: date(2027, 1, 1),
+                growth_rate: dec!(0.10),
+            },
+        )]))
+        .unwrap()
+        .result;
+        let amounts: Vec<Money> = out.flows.iter().map(|f| f.amounts.other).collect();
+        assert_eq!(amounts, vec![dec!(100), dec!(110), dec!(121)]);
+    }
+
+    #[test]
+    fn test_discounting_gives_pv_and_duration() {
+        let mut inp = input(vec![position("bond", LadderSide::Asset, bond_source())]);
+        inp.discount_curve = Some(Curve::from_zero_rates([(dec!(1), dec!(0.05))]));
+        let out = build_cashflow_ladder(&inp).unwrap().result;
+        let pv = out.summary.pv_assets.unwrap();
+        assert!(pv < dec!(1150) && pv > dec!(950));
+        let d = out.summary.asset_duration.unwrap();
+        assert!(d > dec!(2.5) && d < dec!(3));
+        assert!(out.summary.liability_duration.is_none());
+        let bucket_pv: Money = out.buckets.iter().filter_map(|b| b.pv_assets).sum();
+        assert!((bucket_pv - pv).abs() < dec!(0.000001));
+    }
+
+    #[test]
+    fn test_scheduled_flows_before_as_of_are_dropped() {
+        let out = build_cashflow_ladder(&input(vec![position(
+            "deposits",
+            LadderSide::Liability,
+            CashFlowSource::Scheduled {
+                flows: vec![
+                    ScheduledFlow {
+                        date: date(2025, 12, 1),
+                        interest: Decimal::ZERO,
+                        principal: dec!(500),
+                        other: Decimal::ZERO,
+                    },
+                    ScheduledFlow {
+                        date: date(2026, 1, 15),
+                        interest: dec!(5),
+                        principal: dec!(1000),
+                        other: Decimal::ZERO,
+                    },
+                ],
+            },
+        )]))
+        .unwrap();
+        assert_eq!(out.result.positions[0].num_flows, 1);
+        assert_eq!(out.result.buckets[0].liabilities.total, dec!(1005));
+        assert!(out.warnings.iter().any(|w| w.contains("left out")));
+    }
+
+    #[test]
+    fn test_tenor_labels() {
+        let out = build_cashflow_ladder(&input(vec![position(
+            "annuity",
+            LadderSide::Asset,
+            annuity_source(),
+        )]))
+        .unwrap()
+        .result;
+        let labels: Vec<&str> = out.buckets.iter().map(|b| b.label.as_str()).collect();
+        assert_eq!(
+            labels,
+            vec!["0-1M", "1M-3M", "3M-6M", "6M-1Y", "1Y-2Y", "2Y-3Y", "3Y-5Y", "5Y-10Y", "10Y+"]
+        );
+        assert!(out.buckets.last().unwrap().end_date.is_none());
+    }
+
+    #[test]
+    fn test_lease_payments_split_into_interest_and_liability() {
+        let lease = LeaseInput {
+            lease_description: "Office".into(),
+            standard: LeaseStandard::Asc842,
+            lease_term_months: 60,
+            monthly_payment: dec!(10000),
+            annual_escalation: None,
+            incremental_borrowing_rate: dec!(0.06),
+            implicit_rate: None,
+            fair_value_of_asset: dec!(1000000),
+            useful_life_months: 240,
+            residual_value_guaranteed: None,
+            residual_value_unguaranteed: None,
+            purchase_option_price: None,
+            purchase_option_reasonably_certain: None,
+            termination_penalty: None,
+            initial_direct_costs: None,
+            lease_incentives_received: None,
+            prepaid_lease_payments: None,
+            transfer_of_ownership: false,
+            specialized_asset: false,
+        };
+        let liability = classify_lease(&lease).unwrap().initial_lease_liability;
+        let out = build_cashflow_ladder(&input(vec![position(
+            "office",
+            LadderSide::Liability,
+            CashFlowSource::Lease {
+                lease,
+                commencement_date: date(2026, 1, 1),
+            },
+        )]))
+        .unwrap()
+        .result;
+        let p = &out.positions[0];
+        assert_eq!(p.num_flows, 60);
+        assert_eq!(p.first_date, Some(date(2026, 2, 1)));
+        assert_eq!(p.totals.total, dec!(600000));
+        assert!((p.totals.principal - liability).abs() < dec!(1));
+        assert_eq!(out.summary.net, dec!(-600000));
+    }
+
+    #[test]
+    fn test_project_senior_debt_draws_then_repays() {
+        let project = ProjectFinanceInput {
+            project_name: "Solar".into(),
+            total_project_cost: dec!(100_000_000),
+            construction_period_years: 2,
+            operating_period_years: 20,
+            revenue_assumptions: RevenueAssumptions {
+                base_revenue: dec!(15_000_000),
+                revenue_growth: dec!(0.02),
+                capacity_factor: Some(dec!(0.85)),
+                offtake_pct: dec!(0.90),
+            },
+            operating_assumptions: OpExAssumptions {
+                fixed_opex: dec!(2_000_000),
+                variable_opex_pct: dec!(0.05),
+                opex_escalation: dec!(0.02),
+                major_maintenance_reserve: dec!(500_000),
+            },
+            debt_assumptions: ProjectDebt {
+                senior_debt: dec!(70_000_000),
+                senior_rate: dec!(0.05),
+                senior_tenor_years: 15,
+                sculpting: DebtSculpting::LevelRepayment,
+                target_dscr: dec!(1.3),
+                dsra_months: 6,
+                subordinated_debt: None,
+                sub_rate: None,
+            },
+            equity_contribution: dec!(30_000_000),
+            discount_rate: dec!(0.08),
+            tax_rate: dec!(0.25),
+            depreciation_years: 20,
+        };
+        let model = model_project_finance(&project).unwrap().result;
+        let out = build_cashflow_ladder(&input(vec![position(
+            "solar_senior",
+            LadderSide::Asset,
+            CashFlowSource::ProjectFinance {
+                project,
+                start_date: date(2026, 1, 1),
+                component: ProjectComponent::SeniorDebt,
+            },
+        )]))
+        .unwrap()
+        .result;
+        let draws: Money = out
+            .flows
+            .iter()
+            .filter(|f| f.amounts.principal < Decimal::ZERO)
+            .map(|f| f.amounts.principal)
+            .sum();
+        assert!((draws + dec!(70_000_000)).abs() < dec!(1));
+        // Operating years: interest plus principal is the model's debt service
+        for (f, y) in out.flows.iter().zip(&model.projections).skip(2) {
+            assert!((f.amounts.total - y.senior_debt_service).abs() < dec!(0.01));
+            assert!(f.amounts.interest >= Decimal::ZERO);
+        }
+        // First operating year interest accrues on the balance grown by
+        // capitalised construction interest
+        assert!(out.flows[2].amounts.interest > dec!(70_000_000) * dec!(0.05));
+    }
+
+    #[test]
+    fn test_invalid_inputs_rejected() {
+        let mut inp = input(vec![position("a", LadderSide::Asset, annuity_source())]);
+        inp.bucketing = LadderBucketing::Tenor(vec![12, 6]);
+        assert!(build_cashflow_ladder(&inp).is_err());
+
+        let inp = input(vec![
+            position("a", LadderSide::Asset, annuity_source()),
+            position("a", LadderSide::Asset, loan_source()),
+        ]);
+        assert!(build_cashflow_ladder(&inp).is_err());
+
+        assert!(matches!(
+            build_cashflow_ladder(&input(vec![])),
+            Err(CorpFinanceError::InsufficientData(_))
+        ));
+    }
+}
@@ -11,6 +11,7 @@
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
+use super::portfolio_metrics::{tape_metrics, CollateralLoan, PortfolioMetricsOutput};
 use crate::error::CorpFinanceError;
 use crate::CorpFinanceResult;
 
@@ -48,6 +49,10 @@ pub struct CoverageTestInput {
     pub senior_fees: Decimal,
     /// Reference rate (SOFR/LIBOR, decimal).
     pub reference_rate: Decimal,
+    /// Loan tape; when given, the output includes WARF, WAS, WAL and the
+    /// diversity score.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub loan_tape: Option<Vec<CollateralLoan>>,
 }
 
 /// Result for a single tranche.
@@ -82,6 +87,9 @@ pub struct CoverageTestOutput {
     pub any_ic_breach: bool,
     /// Total amount to be diverted to cure breaches.
     pub total_diversion: Decimal,
+    /// Portfolio quality metrics from the loan tape.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub portfolio_metrics: Option<PortfolioMetricsOutput>,
 }
 
 // ---------------------------------------------------------------------------
@@ -167,11 +175,14 @@ pub fn calculate_coverage_tests(
         });
     }
 
+    let portfolio_metrics = input.loan_tape.as_deref().map(tape_metrics).transpose()?;
+
     Ok(CoverageTestOutput {
         tranche_results,
         any_oc_breach,
         any_ic_breach,
         total_diversion,
+        portfolio_metrics,
     })
 }
 
@@ -286,6 +297,7 @@ mod tests {
             interest_income: dec!(10_000_000),
             senior_fees: dec!(500_000),
             reference_rate: dec!(0.05),
+            loan_tape: None,
         }
     }
 
@@ -358,6 +370,7 @@ mod tests {
             interest_income: dec!(50_000_000),
             senior_fees: Decimal::ZERO,
             reference_rate: dec!(0.05),
+            loan_tape: None,
         };
         let out = calculate_coverage_tests(&input).unwrap();
         assert!(out.tranche_results[0].oc_pass);
@@ -382,6 +395,7 @@ mod tests {
             interest_income: dec!(10_000_000),
             senior_fees: Decimal::ZERO,
             reference_rate: dec!(0.05),
+            loan_tape: None,
         };
         let out = calculate_coverage_tests(&input).unwrap();
         // adjusted = 110M, OC = 110/100 = 1.10 < 1.50
@@ -405,6 +419,7 @@ mod tests {
             interest_income: dec!(10_000_000),
             senior_fees: Decimal::ZERO,
             reference_rate: dec!(0.05),
+            loan_tape: None,
         };
         let out = calculate_coverage_tests(&input).unwrap();
         // adjusted = 110M, OC = 110/100 = 1.10 < 1.20
@@ -435,6 +450,7 @@ mod tests {
             interest_income: dec!(5_000_000),
             senior_fees: dec!(1_000_000),
             reference_rate: dec!(0.05),
+            loan_tape: None,
         };
         let out = calculate_coverage_tests(&input).unwrap();
         // net_interest = 4M, interest_due = 100M * 0.063 = 6.3M
@@ -505,6 +521,7 @@ mod tests {
             interest_income: dec!(50_000_000),
             senior_fees: Decimal::ZERO,
             reference_rate: dec!(0.05),
+            loan_tape: None,
         };
         let out = calculate_coverage_tests(&input).unwrap();
         let sum: Decimal = out.tranche_results.iter().map(|r| r.diversion_amount).sum();
@@ -583,10 +600,37 @@ mod tests {
             interest_income: dec!(10_000_000),
             senior_fees: Decimal::ZERO,
             reference_rate: dec!(0.05),
+            loan_tape: None,
         };
         let out = calculate_coverage_tests(&input).unwrap();
         // OC = 100/80 = 1.25 >= 1.25 => pass
         assert!(out.tranche_results[0].oc_pass);
         assert_eq!(out.tranche_results[0].diversion_amount, Decimal::ZERO);
     }
+
+    #[test]
+    fn test_loan_tape_adds_portfolio_metrics() {
+        let mut input = sample_input();
+        assert!(calculate_coverage_tests(&input)
+            .unwrap()
+            .portfolio_metrics
+            .is_none());
+
+        input.loan_tape = serde_json::from_value(serde_json::json!([
+            { "name": "A", "par": 60, "rating": "B2", "spread": 0.04,
+              "remaining_life": 5, "industry": "Healthcare" },
+            { "name": "B", "par": 40, "rating": "Ba3", "spread": 0.03,
+              "remaining_life": 4, "industry": "Retail" },
+            { "name": "C", "par": 10, "rating": "Ca", "spread": 0.05,
+              "remaining_life": 2, "industry": "Retail", "defaulted": true }
+        ]))
+        .unwrap();
+        let metrics = calculate_coverage_tests(&input)
+            .unwrap()
+            .portfolio_metrics
+            .unwrap();
+        assert_eq!(metrics.defaulted_par, dec!(10));
+        assert_eq!(metrics.was, dec!(0.036));
+        assert_eq!(metrics.obligor_count, 2);
+    }
 }
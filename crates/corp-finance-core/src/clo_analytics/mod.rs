@@ -1,4 +1,5 @@
 pub mod coverage_tests;
+pub mod portfolio_metrics;
 pub mod reinvestment;
 pub mod scenario;
 pub mod tranche_analytics;
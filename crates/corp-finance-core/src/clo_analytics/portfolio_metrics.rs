@@ -0,0 +1,411 @@
+//! CLO Portfolio Quality Metrics.
+//!
+//! Computes the collateral quality measures from a loan tape:
+//! - WARF (Weighted Average Rating Factor, Moody's factors)
+//! - WAS (Weighted Average Spread)
+//! - WAL (Weighted Average Life)
+//! - Moody's Diversity Score
+//!
+//! Defaulted obligations are excluded from every measure, as in a CLO
+//! indenture. The diversity score follows the Moody's method: par is
+//! aggregated by obligor, each obligor counts for at most one unit of the
+//! average obligor par, units are summed by industry and each industry's
+//! total is mapped through the industry diversity table.
+//!
+//! All arithmetic uses `rust_decimal::Decimal`. No `f64`.
+
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::error::CorpFinanceError;
+use crate::ratings::{self, Rating};
+use crate::CorpFinanceResult;
+
+/// Moody's industry diversity table as (aggregate equivalent units, score)
+/// knots, interpolated linearly between them.
+const INDUSTRY_DIVERSITY_TABLE: [(Decimal, Decimal); 12] = [
+    (dec!(0), dec!(0)),
+    (dec!(1), dec!(1)),
+    (dec!(2), dec!(1.5)),
+    (dec!(3), dec!(2)),
+    (dec!(4), dec!(2.3333)),
+    (dec!(5), dec!(2.6667)),
+    (dec!(6), dec!(3)),
+    (dec!(7), dec!(3.25)),
+    (dec!(8), dec!(3.5)),
+    (dec!(9), dec!(3.75)),
+    (dec!(10), dec!(4)),
+    (dec!(20), dec!(5)),
+];
+
+// ---------------------------------------------------------------------------
+// Input / Output types
+// ---------------------------------------------------------------------------
+
+/// A loan on the collateral tape.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CollateralLoan {
+    /// Loan or facility identifier.
+    pub name: String,
+    /// Borrower; loans to the same obligor are aggregated for diversity.
+    /// Defaults to the loan name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub obligor: Option<String>,
+    /// Outstanding par.
+    pub par: Decimal,
+    /// Rating in any agency notation (e.g. "B2", "B").
+    pub rating: Rating,
+    /// Spread over the reference rate (decimal: 0.0350 = 350bp).
+    pub spread: Decimal,
+    /// Remaining life in years.
+    pub remaining_life: Decimal,
+    /// Moody's industry classification.
+    pub industry: String,
+    /// Defaulted obligation, excluded from the quality measures.
+    #[serde(default)]
+    pub defaulted: bool,
+}
+
+/// Input for portfolio quality metrics.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PortfolioMetricsInput {
+    /// Loan tape.
+    pub loans: Vec<CollateralLoan>,
+}
+
+/// Diversity contribution of one industry.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IndustryDiversity {
+    /// Industry classification.
+    pub industry: String,
+    /// Performing par in the industry.
+    pub par: Decimal,
+    /// Share of performing par.
+    pub par_pct: Decimal,
+    /// Number of obligors.
+    pub obligor_count: u32,
+    /// Aggregate industry equivalent units.
+    pub equivalent_units: Decimal,
+    /// Industry diversity score from the Moody's table.
+    pub diversity_score: Decimal,
+}
+
+/// Portfolio quality metrics.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PortfolioMetricsOutput {
+    /// Par of all loans on the tape.
+    pub total_par: Decimal,
+    /// Par excluding defaulted obligations.
+    pub performing_par: Decimal,
+    /// Par of defaulted obligations.
+    pub defaulted_par: Decimal,
+    /// Number of performing obligors.
+    pub obligor_count: u32,
+    /// Performing par divided by the number of obligors.
+    pub average_obligor_par: Decimal,
+    /// Weighted average rating factor.
+    pub warf: Decimal,
+    /// Rating implied by the WARF.
+    pub warf_rating: Rating,
+    /// Weighted average spread (decimal).
+    pub was: Decimal,
+    /// Weighted average life (years).
+    pub wal: Decimal,
+    /// Moody's diversity score.
+    pub diversity_score: Decimal,
+    /// Industry breakdown, largest par first.
+    pub industries: Vec<IndustryDiversity>,
+}
+
+// ---------------------------------------------------------------------------
+// Diversity score
+// ---------------------------------------------------------------------------
+
+/// Map aggregate industry equivalent units to the industry diversity score.
+fn industry_diversity_score(units: Decimal) -> Decimal {
+    let last = INDUSTRY_DIVERSITY_TABLE[INDUSTRY_DIVERSITY_TABLE.len() - 1];
+    if units >= last.0 {
+        return last.1;
+    }
+    for w in INDUSTRY_DIVERSITY_TABLE.windows(2) {
+        let ((x0, y0), (x1, y1)) = (w[0], w[1]);
+        if units <= x1 {
+            return y0 + (y1 - y0) * (units - x0) / (x1 - x0);
+        }
+    }
+    last.1
+}
+
+/// Moody's diversity score of `(obligor, industry, par)` holdings, with the
+/// per-industry breakdown sorted by par (largest first).
+fn moodys_diversity(holdings: &[(&str, &str, Decimal)]) -> (Decimal, Vec<IndustryDiversity>) {
+    // Aggregate by obligor; an obligor keeps the industry of its first loan.
+    let mut obligors: Vec<(&str, &str, Decimal)> = Vec::new();
+    let mut index: HashMap<&str, usize> = HashMap::new();
+    for &(obligor, industry, par) in holdings {
+        match index.get(obligor) {
+            Some(&i) => obligors[i].2 += par,
+            None => {
+                index.insert(obligor, obligors.len());
+                obligors.push((obligor, industry, par));
+            }
+        }
+    }
+    obligors.retain(|o| o.2 > Decimal::ZERO);
+
+    let total: Decimal = obligors.iter().map(|o| o.2).sum();
+    if obligors.is_empty() || total.is_zero() {
+        return (Decimal::ZERO, Vec::new());
+    }
+    let average = total / Decimal::from(obligors.len() as u64);
+
+    let mut industries: Vec<IndustryDiversity> = Vec::new();
+    for (_, industry, par) in &obligors {
+        let unit = (*par / average).min(Decimal::ONE);
+        match industries.iter_mut().find(|d| d.industry == *industry) {
+            Some(d) => {
+                d.par += *par;
+                d.obligor_count += 1;
+                d.equivalent_units += unit;
+            }
+            None => industries.push(IndustryDiversity {
+                industry: industry.to_string(),
+                par: *par,
+                par_pct: Decimal::ZERO,
+                obligor_count: 1,
+                equivalent_units: unit,
+                diversity_score: Decimal::ZERO,
+            }),
+        }
+    }
+    for d in &mut industries {
+        d.par_pct = d.par / total;
+        d.diversity_score = industry_diversity_score(d.equivalent_units);
+    }
+    industries.sort_by_key(|d| std::cmp::Reverse(d.par));
+
+    let score = industries.iter().map(|d| d.diversity_score).sum();
+    (score, industries)
+}
+
+// ---------------------------------------------------------------------------
+// Engine
+// ---------------------------------------------------------------------------
+
+/// Compute WARF, WAS, WAL and the Moody's diversity score from a loan tape.
+pub fn calculate_portfolio_metrics(
+    input: &PortfolioMetricsInput,
+) -> CorpFinanceResult<PortfolioMetricsOutput> {
+    tape_metrics(&input.loans)
+}
+
+/// Portfolio metrics for a loan tape; shared by the coverage and scenario
+/// engines when a tape is supplied.
+pub(crate) fn tape_metrics(loans: &[CollateralLoan]) -> CorpFinanceResult<PortfolioMetricsOutput> {
+    validate_loans(loans)?;
+
+    let performing: Vec<&CollateralLoan> = loans.iter().filter(|l| !l.defaulted).collect();
+    let total_par: Decimal = loans.iter().map(|l| l.par).sum();
+    let performing_par: Decimal = performing.iter().map(|l| l.par).sum();
+    if performing_par.is_zero() {
+        return Err(CorpFinanceError::InsufficientData(
+            "Loan tape has no performing par".into(),
+        ));
+    }
+
+    let holdings: Vec<(Rating, Decimal)> = performing.iter().map(|l| (l.rating, l.par)).collect();
+    let warf = ratings::warf(&holdings)?;
+    let weighted = |f: &dyn Fn(&CollateralLoan) -> Decimal| {
+        performing.iter().map(|l| l.par * f(l)).sum::<Decimal>() / performing_par
+    };
+
+    let tape: Vec<(&str, &str, Decimal)> = performing
+        .iter()
+        .map(|l| {
+            (
+                l.obligor.as_deref().unwrap_or(&l.name),
+                l.industry.as_str(),
+                l.par,
+            )
+        })
+        .collect();
+    let (diversity_score, industries) = moodys_diversity(&tape);
+    let obligor_count: u32 = industries.iter().map(|d| d.obligor_count).sum();
+
+    Ok(PortfolioMetricsOutput {
+        total_par,
+        performing_par,
+        defaulted_par: total_par - performing_par,
+        obligor_count,
+        average_obligor_par: performing_par / Decimal::from(obligor_count.max(1)),
+        warf,
+        warf_rating: Rating::from_rating_factor(warf),
+        was: weighted(&|l| l.spread),
+        wal: weighted(&|l| l.remaining_life),
+        diversity_score,
+        industries,
+    })
+}
+
+// ---------------------------------------------------------------------------
+// Validation
+// ---------------------------------------------------------------------------
+
+fn validate_loans(loans: &[CollateralLoan]) -> CorpFinanceResult<()> {
+    if loans.is_empty() {
+        return Err(CorpFinanceError::InsufficientData(
+            "Loan tape must contain at least one loan".into(),
+        ));
+    }
+    for loan in loans {
+        if loan.par < Decimal::ZERO {
+            return Err(CorpFinanceError::InvalidInput {
+                field: "par".into(),
+                reason: format!("Loan '{}' has negative par", loan.name),
+            });
+        }
+        if loan.remaining_life < Decimal::ZERO {
+            return Err(CorpFinanceError::InvalidInput {
+                field: "remaining_life".into(),
+                reason: format!("Loan '{}' has negative remaining life", loan.name),
+            });
+        }
+    }
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn loan(name: &str, par: Decimal, rating: &str, industry: &str) -> CollateralLoan {
+        CollateralLoan {
+            name: name.into(),
+            obligor: None,
+            par,
+            rating: Rating::parse(rating).unwrap(),
+            spread: dec!(0.035),
+            remaining_life: dec!(5),
+            industry: industry.into(),
+            defaulted: false,
+        }
+    }
+
+    fn approx_eq(a: Decimal, b: Decimal, eps: Decimal) -> bool {
+        (a - b).abs() < eps
+    }
+
+    #[test]
+    fn test_warf_was_wal() {
+        let mut a = loan("A", dec!(60), "B2", "Healthcare");
+        a.spread = dec!(0.04);
+        a.remaining_life = dec!(6);
+        let mut b = loan("B", dec!(40), "Ba3", "Retail");
+        b.spread = dec!(0.03);
+        b.remaining_life = dec!(4);
+        let out =
+            calculate_portfolio_metrics(&PortfolioMetricsInput { loans: vec![a, b] }).unwrap();
+        // B2 = 2720, Ba3 = 1766
+        assert_eq!(out.warf, dec!(0.6) * dec!(2720) + dec!(0.4) * dec!(1766));
+        assert_eq!(out.was, dec!(0.036));
+        assert_eq!(out.wal, dec!(5.2));
+        assert_eq!(out.warf_rating, Rating::parse("B1").unwrap());
+    }
+
+    #[test]
+    fn test_defaulted_loans_excluded() {
+        let mut d = loan("D", dec!(50), "Caa3", "Retail");
+        d.defaulted = true;
+        let loans = vec![loan("A", dec!(100), "B2", "Healthcare"), d];
+        let out = calculate_portfolio_metrics(&PortfolioMetricsInput { loans }).unwrap();
+        assert_eq!(out.total_par, dec!(150));
+        assert_eq!(out.performing_par, dec!(100));
+        assert_eq!(out.defaulted_par, dec!(50));
+        assert_eq!(out.warf, dec!(2720));
+        assert_eq!(out.obligor_count, 1);
+        assert_eq!(out.diversity_score, Decimal::ONE);
+    }
+
+    #[test]
+    fn test_equal_obligors_in_distinct_industries() {
+        let loans = (0..5)
+            .map(|i| loan(&format!("L{i}"), dec!(10), "B2", &format!("Industry {i}")))
+            .collect();
+        let out = calculate_portfolio_metrics(&PortfolioMetricsInput { loans }).unwrap();
+        assert_eq!(out.diversity_score, dec!(5));
+        assert_eq!(out.industries.len(), 5);
+    }
+
+    #[test]
+    fn test_same_industry_follows_table() {
+        let loans: Vec<CollateralLoan> = (0..4)
+            .map(|i| loan(&format!("L{i}"), dec!(10), "B2", "Retail"))
+            .collect();
+        let out = calculate_portfolio_metrics(&PortfolioMetricsInput { loans }).unwrap();
+        assert_eq!(out.diversity_score, dec!(2.3333));
+        assert_eq!(out.industries[0].equivalent_units, dec!(4));
+    }
+
+    #[test]
+    fn test_obligor_aggregation_and_small_obligors() {
+        // Two facilities to one borrower count as one obligor of 40
+        let mut a1 = loan("A TLB", dec!(20), "B2", "Retail");
+        a1.obligor = Some("A".into());
+        let mut a2 = loan("A TLC", dec!(20), "B2", "Retail");
+        a2.obligor = Some("A".into());
+        let b = loan("B", dec!(10), "B2", "Retail");
+        let c = loan("C", dec!(30), "B2", "Healthcare");
+        let out = calculate_portfolio_metrics(&PortfolioMetricsInput {
+            loans: vec![a1, a2, b, c],
+        })
+        .unwrap();
+        assert_eq!(out.obligor_count, 3);
+        // Average obligor par 80/3; B contributes 10 / 26.67 = 0.375 units
+        let retail = out
+            .industries
+            .iter()
+            .find(|d| d.industry == "Retail")
+            .unwrap();
+        assert_eq!(retail.obligor_count, 2);
+        assert!(approx_eq(
+            retail.equivalent_units,
+            dec!(1.375),
+            dec!(0.0001)
+        ));
+        assert!(approx_eq(
+            retail.diversity_score,
+            dec!(1.1875),
+            dec!(0.0001)
+        ));
+        assert!(approx_eq(out.diversity_score, dec!(2.1875), dec!(0.0001)));
+        // Largest industry first
+        assert_eq!(out.industries[0].industry, "Retail");
+    }
+
+    #[test]
+    fn test_industry_table_interpolation() {
+        assert_eq!(industry_diversity_score(dec!(0.5)), dec!(0.5));
+        assert_eq!(industry_diversity_score(dec!(2.5)), dec!(1.75));
+        assert_eq!(industry_diversity_score(dec!(10)), dec!(4));
+        assert_eq!(industry_diversity_score(dec!(15)), dec!(4.5));
+        assert_eq!(industry_diversity_score(dec!(40)), dec!(5));
+    }
+
+    #[test]
+    fn test_invalid_tapes_rejected() {
+        assert!(calculate_portfolio_metrics(&PortfolioMetricsInput { loans: vec![] }).is_err());
+
+        let loans = vec![loan("A", dec!(-1), "B2", "Retail")];
+        assert!(calculate_portfolio_metrics(&PortfolioMetricsInput { loans }).is_err());
+
+        let mut d = loan("D", dec!(10), "C", "Retail");
+        d.defaulted = true;
+        assert!(calculate_portfolio_metrics(&PortfolioMetricsInput { loans: vec![d] }).is_err());
+    }
+}
@@ -8,6 +8,7 @@
 //! - Diversity Score (Moody's methodology)
 //! - Par Build Test
 //! - Reinvestment Criteria Compliance
+//! - Reinvestment Optimisation (what to buy with principal proceeds)
 //!
 //! All arithmetic uses `rust_decimal::Decimal`. No `f64`.
 
@@ -17,6 +18,7 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 use crate::error::CorpFinanceError;
+use crate::ratings::Rating;
 use crate::CorpFinanceResult;
 
 // ---------------------------------------------------------------------------
@@ -30,7 +32,7 @@ pub struct PoolAsset {
     pub name: String,
     /// Current notional balance.
     pub notional: Decimal,
-    /// Credit rating (AAA, AA, A, BBB, BB, B, CCC).
+    /// Credit rating in S&P/Fitch or Moody's notation (e.g. "BB-", "Ba3").
     pub rating: String,
     /// Loan spread (decimal: 0.0350 = 350bp).
     pub spread: Decimal,
@@ -91,22 +93,125 @@ pub struct ReinvestmentOutput {
     pub all_criteria_met: bool,
 }
 
+/// A loan available to buy during the reinvestment period.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReinvestmentCandidate {
+    /// Asset identifier/name; buying a name already held adds to it.
+    pub name: String,
+    /// Maximum par available to buy.
+    pub max_notional: Decimal,
+    /// Purchase price as a fraction of par (default 1.0).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub price: Option<Decimal>,
+    /// Credit rating in S&P/Fitch or Moody's notation.
+    pub rating: String,
+    /// Loan spread (decimal).
+    pub spread: Decimal,
+    /// Remaining life in years (decimal).
+    pub remaining_life: Decimal,
+    /// Industry classification for diversity score.
+    pub industry: String,
+}
+
+/// What the optimiser tries to get out of each unit of par bought.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ReinvestmentObjective {
+    /// Highest spread first.
+    #[default]
+    MaxSpread,
+    /// Lowest rating factor first.
+    MinWarf,
+    /// Most par per unit of cash first (deepest discount).
+    MaxPar,
+}
+
+/// Input for reinvestment optimisation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReinvestmentOptimizationInput {
+    /// Current pool, par target and collateral quality test limits.
+    pub pool: ReinvestmentInput,
+    /// Loans on offer.
+    pub candidates: Vec<ReinvestmentCandidate>,
+    /// Principal proceeds available to reinvest.
+    pub available_proceeds: Decimal,
+    /// Par bought per step (default 1% of proceeds).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lot_size: Option<Decimal>,
+    /// Purchase ranking (default max spread).
+    #[serde(default)]
+    pub objective: ReinvestmentObjective,
+}
+
+/// Par bought of one candidate.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReinvestmentPurchase {
+    pub name: String,
+    /// Par bought.
+    pub notional: Decimal,
+    /// Cash paid.
+    pub cost: Decimal,
+    pub rating: String,
+    pub spread: Decimal,
+    pub remaining_life: Decimal,
+    pub industry: String,
+}
+
+/// Headroom on one test after the trades.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConstraintSlack {
+    /// Metric name.
+    pub metric: String,
+    /// Value after the trades.
+    pub value: Decimal,
+    /// Limit (max or min depending on metric).
+    pub limit: Decimal,
+    /// Distance to the limit in the passing direction; negative when failing.
+    pub slack: Decimal,
+    /// Whether the test stopped further purchases of at least one candidate.
+    pub binding: bool,
+}
+
+/// A candidate not bought in full, and why.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UnfilledCandidate {
+    pub name: String,
+    /// Par still available.
+    pub remaining_notional: Decimal,
+    /// Test the next lot would breach or worsen, or "Proceeds".
+    pub reason: String,
+}
+
+/// Output of reinvestment optimisation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReinvestmentOptimizationOutput {
+    /// Loans bought, in order of first purchase.
+    pub purchases: Vec<ReinvestmentPurchase>,
+    /// Par bought.
+    pub par_purchased: Decimal,
+    /// Cash spent.
+    pub proceeds_used: Decimal,
+    /// Cash left uninvested.
+    pub proceeds_remaining: Decimal,
+    /// Pool analytics before the trades.
+    pub pre_trade: ReinvestmentOutput,
+    /// Pool analytics after the trades.
+    pub post_trade: ReinvestmentOutput,
+    /// Headroom on each test after the trades.
+    pub constraint_slack: Vec<ConstraintSlack>,
+    /// Candidates with par left over.
+    pub unfilled: Vec<UnfilledCandidate>,
+}
+
 // ---------------------------------------------------------------------------
 // Rating factor mapping (Moody's methodology)
 // ---------------------------------------------------------------------------
 
-/// Map a rating string to its Moody's rating factor.
+/// Map a rating string (any agency notation) to its Moody's rating factor.
+/// Unrecognised ratings are treated as Caa2 (6500).
 fn rating_factor(rating: &str) -> Decimal {
-    match rating.to_uppercase().as_str() {
-        "AAA" => dec!(1),
-        "AA+" | "AA" | "AA-" => dec!(10),
-        "A+" | "A" | "A-" => dec!(120),
-        "BBB+" | "BBB" | "BBB-" => dec!(360),
-        "BB+" | "BB" | "BB-" => dec!(1350),
-        "B+" | "B" | "B-" => dec!(2720),
-        "CCC+" | "CCC" | "CCC-" | "CC" | "C" => dec!(6500),
-        _ => dec!(6500), // Default to worst
-    }
+    Rating::parse(rating)
+        .map(Rating::rating_factor)
+        .unwrap_or(dec!(6500))
 }
 
 // ---------------------------------------------------------------------------
@@ -183,45 +288,15 @@ fn compute_diversity_score(assets: &[PoolAsset]) -> Decimal {
 pub fn calculate_reinvestment(input: &ReinvestmentInput) -> CorpFinanceResult<ReinvestmentOutput> {
     validate_reinvestment_input(input)?;
 
-    let total_notional: Decimal = input.assets.iter().map(|a| a.notional).sum();
-
-    // WARF = sum(notional_i * rating_factor_i) / total_notional
-    let warf = if total_notional.is_zero() {
-        Decimal::ZERO
-    } else {
-        let weighted_rf: Decimal = input
-            .assets
-            .iter()
-            .map(|a| a.notional * rating_factor(&a.rating))
-            .sum();
-        weighted_rf / total_notional
-    };
-
-    // WAL = sum(notional_i * remaining_life_i) / total_notional
-    let wal = if total_notional.is_zero() {
-        Decimal::ZERO
-    } else {
-        let weighted_life: Decimal = input
-            .assets
-            .iter()
-            .map(|a| a.notional * a.remaining_life)
-            .sum();
-        weighted_life / total_notional
-    };
-
-    // WALS = sum(notional_i * spread_i) / total_notional
-    let wals = if total_notional.is_zero() {
-        Decimal::ZERO
-    } else {
-        let weighted_spread: Decimal = input.assets.iter().map(|a| a.notional * a.spread).sum();
-        weighted_spread / total_notional
-    };
-
-    // Diversity Score
-    let diversity_score = compute_diversity_score(&input.assets);
+    let PoolMetrics {
+        warf,
+        wal,
+        wals,
+        diversity_score,
+        par_amount,
+    } = pool_metrics(&input.assets);
 
     // Par Build Test
-    let par_amount = total_notional;
     let par_build_needed = par_amount < input.target_par;
 
     // Criteria checks
@@ -278,6 +353,247 @@ pub fn calculate_reinvestment(input: &ReinvestmentInput) -> CorpFinanceResult<Re
     })
 }
 
+/// Par-weighted pool metrics.
+struct PoolMetrics {
+    warf: Decimal,
+    wal: Decimal,
+    wals: Decimal,
+    diversity_score: Decimal,
+    par_amount: Decimal,
+}
+
+fn pool_metrics(assets: &[PoolAsset]) -> PoolMetrics {
+    let total_notional: Decimal = assets.iter().map(|a| a.notional).sum();
+    let weighted = |f: &dyn Fn(&PoolAsset) -> Decimal| {
+        if total_notional.is_zero() {
+            Decimal::ZERO
+        } else {
+            assets.iter().map(|a| a.notional * f(a)).sum::<Decimal>() / total_notional
+        }
+    };
+
+    PoolMetrics {
+        // WARF = sum(notional_i * rating_factor_i) / total_notional
+        warf: weighted(&|a| rating_factor(&a.rating)),
+        // WAL = sum(notional_i * remaining_life_i) / total_notional
+        wal: weighted(&|a| a.remaining_life),
+        // WALS = sum(notional_i * spread_i) / total_notional
+        wals: weighted(&|a| a.spread),
+        diversity_score: compute_diversity_score(assets),
+        par_amount: total_notional,
+    }
+}
+
+/// The four collateral quality tests as (metric, value, limit, is_max).
+fn quality_tests(
+    m: &PoolMetrics,
+    limits: &ReinvestmentInput,
+) -> [(&'static str, Decimal, Decimal, bool); 4] {
+    [
+        ("WARF", m.warf, limits.max_warf, true),
+        ("WALS", m.wals, limits.min_wals, false),
+        ("WAL", m.wal, limits.max_wal, true),
+        (
+            "Diversity Score",
+            m.diversity_score,
+            limits.min_diversity_score,
+            false,
+        ),
+    ]
+}
+
+/// Distance to the limit in the passing direction.
+fn slack(value: Decimal, limit: Decimal, is_max: bool) -> Decimal {
+    if is_max {
+        limit - value
+    } else {
+        value - limit
+    }
+}
+
+/// First test the trade breaches, applying the indenture "maintain or
+/// improve" rule: a test already failing may not get worse.
+fn breached_test(
+    before: &PoolMetrics,
+    after: &PoolMetrics,
+    limits: &ReinvestmentInput,
+) -> Option<&'static str> {
+    quality_tests(before, limits)
+        .iter()
+        .zip(quality_tests(after, limits).iter())
+        .find(|((_, b, limit, is_max), (_, a, _, _))| {
+            let after_slack = slack(*a, *limit, *is_max);
+            after_slack < Decimal::ZERO && after_slack < slack(*b, *limit, *is_max)
+        })
+        .map(|((name, ..), _)| *name)
+}
+
+/// Choose what to buy with principal proceeds during the reinvestment
+/// period.
+///
+/// Buys one lot at a time, always taking the best-ranked candidate under
+/// the objective whose lot keeps every collateral quality test (WARF, WALS,
+/// WAL, diversity) passing, or, for a test already failing, does not make
+/// it worse. Stops when the proceeds run out or no lot qualifies. Greedy by
+/// lot, so the result is a good feasible portfolio rather than a proven
+/// optimum; smaller lots get closer to the constraint boundaries.
+pub fn optimize_reinvestment(
+    input: &ReinvestmentOptimizationInput,
+) -> CorpFinanceResult<ReinvestmentOptimizationOutput> {
+    validate_reinvestment_input(&input.pool)?;
+    validate_optimization_input(input)?;
+
+    let limits = &input.pool;
+    let lot_size = input
+        .lot_size
+        .unwrap_or(input.available_proceeds / dec!(100));
+    let price = |c: &ReinvestmentCandidate| c.price.unwrap_or(Decimal::ONE);
+
+    // Rank candidates once: the objective is linear in par bought.
+    let mut order: Vec<usize> = (0..input.candidates.len()).collect();
+    order.sort_by(|&i, &j| {
+        let (a, b) = (&input.candidates[i], &input.candidates[j]);
+        let key = |c: &ReinvestmentCandidate| match input.objective {
+            ReinvestmentObjective::MaxSpread => (c.spread, -rating_factor(&c.rating)),
+            ReinvestmentObjective::MinWarf => (-rating_factor(&c.rating), c.spread),
+            ReinvestmentObjective::MaxPar => (Decimal::ONE / price(c), c.spread),
+        };
+        key(b).cmp(&key(a))
+    });
+
+    // Working pool, with a slot per candidate (new names start at zero).
+    let mut pool = limits.assets.clone();
+    let slots: Vec<usize> = input
+        .candidates
+        .iter()
+        .map(|c| match pool.iter().position(|a| a.name == c.name) {
+            Some(idx) => idx,
+            None => {
+                pool.push(PoolAsset {
+                    name: c.name.clone(),
+                    notional: Decimal::ZERO,
+                    rating: c.rating.clone(),
+                    spread: c.spread,
+                    remaining_life: c.remaining_life,
+                    industry: c.industry.clone(),
+                });
+                pool.len() - 1
+            }
+        })
+        .collect();
+    // Zero-par slots do not count towards diversity until bought.
+    let live = |pool: &[PoolAsset]| -> Vec<PoolAsset> {
+        pool.iter()
+            .filter(|a| a.notional > Decimal::ZERO)
+            .cloned()
+            .collect()
+    };
+
+    let mut bought = vec![Decimal::ZERO; input.candidates.len()];
+    let mut first_bought: Vec<usize> = Vec::new();
+    let mut cash = input.available_proceeds;
+    let mut current = pool_metrics(&live(&pool));
+
+    loop {
+        let mut chosen = None;
+        for &i in &order {
+            let c = &input.candidates[i];
+            let capacity = c.max_notional - bought[i];
+            if capacity <= Decimal::ZERO {
+                continue;
+            }
+            let par = lot_size.min(capacity).min(cash / price(c));
+            if par <= Decimal::ZERO {
+                continue;
+            }
+            pool[slots[i]].notional += par;
+            let trial = pool_metrics(&live(&pool));
+            pool[slots[i]].notional -= par;
+            if breached_test(&current, &trial, limits).is_none() {
+                chosen = Some((i, par, trial));
+                break;
+            }
+        }
+        let Some((i, par, trial)) = chosen else {
+            break;
+        };
+        pool[slots[i]].notional += par;
+        if bought[i].is_zero() {
+            first_bought.push(i);
+        }
+        bought[i] += par;
+        cash -= par * price(&input.candidates[i]);
+        current = trial;
+        if cash <= Decimal::ZERO {
+            break;
+        }
+    }
+
+    let purchases: Vec<ReinvestmentPurchase> = first_bought
+        .iter()
+        .map(|&i| {
+            let c = &input.candidates[i];
+            ReinvestmentPurchase {
+                name: c.name.clone(),
+                notional: bought[i],
+                cost: bought[i] * price(c),
+                rating: c.rating.clone(),
+                spread: c.spread,
+                remaining_life: c.remaining_life,
+                industry: c.industry.clone(),
+            }
+        })
+        .collect();
+
+    // What would stop one more lot of each unfilled candidate, cash aside.
+    let unfilled: Vec<UnfilledCandidate> = input
+        .candidates
+        .iter()
+        .enumerate()
+        .filter(|(i, c)| bought[*i] < c.max_notional)
+        .map(|(i, c)| {
+            let remaining = c.max_notional - bought[i];
+            pool[slots[i]].notional += lot_size.min(remaining);
+            let trial = pool_metrics(&live(&pool));
+            pool[slots[i]].notional -= lot_size.min(remaining);
+            UnfilledCandidate {
+                name: c.name.clone(),
+                remaining_notional: remaining,
+                reason: breached_test(&current, &trial, limits)
+                    .unwrap_or("Proceeds")
+                    .to_string(),
+            }
+        })
+        .collect();
+
+    let constraint_slack = quality_tests(&current, limits)
+        .iter()
+        .map(|(metric, value, limit, is_max)| ConstraintSlack {
+            metric: metric.to_string(),
+            value: *value,
+            limit: *limit,
+            slack: slack(*value, *limit, *is_max),
+            binding: unfilled.iter().any(|u| u.reason == *metric),
+        })
+        .collect();
+
+    let post_input = ReinvestmentInput {
+        assets: live(&pool),
+        ..limits.clone()
+    };
+
+    Ok(ReinvestmentOptimizationOutput {
+        par_purchased: bought.iter().copied().sum(),
+        proceeds_used: input.available_proceeds - cash,
+        proceeds_remaining: cash,
+        pre_trade: calculate_reinvestment(limits)?,
+        post_trade: calculate_reinvestment(&post_input)?,
+        purchases,
+        constraint_slack,
+        unfilled,
+    })
+}
+
 // ---------------------------------------------------------------------------
 // Validation
 // ---------------------------------------------------------------------------
@@ -329,6 +645,49 @@ fn validate_reinvestment_input(input: &ReinvestmentInput) -> CorpFinanceResult<(
     Ok(())
 }
 
+fn validate_optimization_input(input: &ReinvestmentOptimizationInput) -> CorpFinanceResult<()> {
+    if input.candidates.is_empty() {
+        return Err(CorpFinanceError::InsufficientData(
+            "At least one reinvestment candidate is required.".into(),
+        ));
+    }
+    if input.available_proceeds < Decimal::ZERO {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "available_proceeds".into(),
+            reason: "Available proceeds cannot be negative.".into(),
+        });
+    }
+    if let Some(lot) = input.lot_size {
+        if lot <= Decimal::ZERO {
+            return Err(CorpFinanceError::InvalidInput {
+                field: "lot_size".into(),
+                reason: "Lot size must be positive.".into(),
+            });
+        }
+    }
+    for c in &input.candidates {
+        if c.max_notional < Decimal::ZERO {
+            return Err(CorpFinanceError::InvalidInput {
+                field: format!("candidate.{}.max_notional", c.name),
+                reason: "Available par cannot be negative.".into(),
+            });
+        }
+        if c.price.is_some_and(|p| p <= Decimal::ZERO) {
+            return Err(CorpFinanceError::InvalidInput {
+                field: format!("candidate.{}.price", c.name),
+                reason: "Price must be positive.".into(),
+            });
+        }
+        if c.remaining_life < Decimal::ZERO || c.spread < Decimal::ZERO {
+            return Err(CorpFinanceError::InvalidInput {
+                field: format!("candidate.{}", c.name),
+                reason: "Spread and remaining life cannot be negative.".into(),
+            });
+        }
+    }
+    Ok(())
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -678,4 +1037,136 @@ mod tests {
         let json = serde_json::to_string(&out).unwrap();
         let _: ReinvestmentOutput = serde_json::from_str(&json).unwrap();
     }
+
+    fn candidate(
+        name: &str,
+        rating: &str,
+        spread: Decimal,
+        life: Decimal,
+        industry: &str,
+    ) -> ReinvestmentCandidate {
+        ReinvestmentCandidate {
+            name: name.into(),
+            max_notional: dec!(10_000_000),
+            price: None,
+            rating: rating.into(),
+            spread,
+            remaining_life: life,
+            industry: industry.into(),
+        }
+    }
+
+    fn optimization_input() -> ReinvestmentOptimizationInput {
+        ReinvestmentOptimizationInput {
+            pool: sample_input(),
+            candidates: vec![
+                candidate("CCC Loan", "CCC", dec!(0.0900), dec!(5), "Media"),
+                candidate("B Loan", "B", dec!(0.0500), dec!(5), "Chemicals"),
+                candidate("BB Loan", "BB", dec!(0.0375), dec!(4), "Telecom"),
+            ],
+            available_proceeds: dec!(12_000_000),
+            lot_size: Some(dec!(250_000)),
+            objective: ReinvestmentObjective::MaxSpread,
+        }
+    }
+
+    #[test]
+    fn test_optimizer_spends_proceeds_within_limits() {
+        let mut input = optimization_input();
+        input.pool.max_warf = dec!(2000);
+        let out = optimize_reinvestment(&input).unwrap();
+        assert_eq!(out.proceeds_used + out.proceeds_remaining, dec!(12_000_000));
+        assert_eq!(out.par_purchased, dec!(12_000_000));
+        assert!(out.post_trade.criteria_checks[..4].iter().all(|c| c.passes));
+        assert!(out
+            .constraint_slack
+            .iter()
+            .all(|c| c.slack >= Decimal::ZERO));
+        // WARF (limit 2000) caps the CCC loan before proceeds run out
+        let ccc = out.purchases.iter().find(|p| p.name == "CCC Loan").unwrap();
+        assert!(ccc.notional < dec!(10_000_000));
+        let warf = out
+            .constraint_slack
+            .iter()
+            .find(|c| c.metric == "WARF")
+            .unwrap();
+        assert!(warf.binding);
+        assert!(warf.slack < dec!(100));
+        assert!(out.post_trade.wals > out.pre_trade.wals);
+    }
+
+    #[test]
+    fn test_optimizer_min_warf_buys_best_credit_first() {
+        let mut input = optimization_input();
+        input.objective = ReinvestmentObjective::MinWarf;
+        input.available_proceeds = dec!(5_000_000);
+        let out = optimize_reinvestment(&input).unwrap();
+        assert_eq!(out.purchases.len(), 1);
+        assert_eq!(out.purchases[0].name, "BB Loan");
+        assert!(out.post_trade.warf < out.pre_trade.warf);
+    }
+
+    #[test]
+    fn test_optimizer_max_par_prefers_discount() {
+        let mut input = optimization_input();
+        input.objective = ReinvestmentObjective::MaxPar;
+        input.candidates[1].price = Some(dec!(0.95));
+        input.available_proceeds = dec!(4_750_000);
+        let out = optimize_reinvestment(&input).unwrap();
+        assert_eq!(out.purchases[0].name, "B Loan");
+        assert_eq!(out.par_purchased, dec!(5_000_000));
+        assert_eq!(out.proceeds_remaining, Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_optimizer_maintain_or_improve_when_failing() {
+        let mut input = optimization_input();
+        // WARF already failing: only trades that lower it are allowed
+        input.pool.max_warf = dec!(1000);
+        let out = optimize_reinvestment(&input).unwrap();
+        assert!(out.purchases.iter().all(|p| p.name == "BB Loan"));
+        assert!(out.post_trade.warf < out.pre_trade.warf);
+        let ccc = out.unfilled.iter().find(|u| u.name == "CCC Loan").unwrap();
+        assert_eq!(ccc.reason, "WARF");
+    }
+
+    #[test]
+    fn test_optimizer_new_industry_lifts_diversity() {
+        let mut input = optimization_input();
+        input.pool.min_diversity_score = dec!(5.5);
+        let out = optimize_reinvestment(&input).unwrap();
+        assert!(out.post_trade.diversity_score > out.pre_trade.diversity_score);
+        assert!(out.post_trade.diversity_score >= dec!(5.5));
+    }
+
+    #[test]
+    fn test_optimizer_adds_to_existing_holding() {
+        let mut input = optimization_input();
+        input.candidates = vec![candidate(
+            "Loan B",
+            "BB",
+            dec!(0.0450),
+            dec!(4),
+            "Healthcare",
+        )];
+        input.available_proceeds = dec!(2_000_000);
+        let out = optimize_reinvestment(&input).unwrap();
+        assert_eq!(out.post_trade.par_amount, dec!(62_000_000));
+        assert_eq!(out.purchases[0].notional, dec!(2_000_000));
+        // Adding to an existing name does not add an issuer
+        assert!(out.post_trade.diversity_score <= out.pre_trade.diversity_score);
+    }
+
+    #[test]
+    fn test_optimizer_rejects_bad_candidates() {
+        let mut input = optimization_input();
+        input.candidates[0].price = Some(Decimal::ZERO);
+        assert!(optimize_reinvestment(&input).is_err());
+        let mut input = optimization_input();
+        input.candidates.clear();
+        assert!(optimize_reinvestment(&input).is_err());
+        let mut input = optimization_input();
+        input.lot_size = Some(Decimal::ZERO);
+        assert!(optimize_reinvestment(&input).is_err());
+    }
 }
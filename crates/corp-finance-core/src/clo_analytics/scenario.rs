@@ -11,6 +11,7 @@ use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
 use serde::{Deserialize, Serialize};
 
+use super::portfolio_metrics::{tape_metrics, CollateralLoan, PortfolioMetricsOutput};
 use crate::error::CorpFinanceError;
 use crate::CorpFinanceResult;
 
@@ -63,6 +64,10 @@ pub struct CloScenarioInput {
     pub scenarios: Vec<ScenarioDefinition>,
     /// Number of projection periods.
     pub num_periods: u32,
+    /// Loan tape; when given, the output includes WARF, WAS, WAL and the
+    /// diversity score.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub loan_tape: Option<Vec<CollateralLoan>>,
 }
 
 /// Loss result for a single tranche in a scenario.
@@ -96,6 +101,9 @@ pub struct CloScenarioOutput {
     pub attachment_points: Vec<(String, Decimal)>,
     /// Detachment points by tranche (percentage of pool).
     pub detachment_points: Vec<(String, Decimal)>,
+    /// Portfolio quality metrics from the loan tape.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub portfolio_metrics: Option<PortfolioMetricsOutput>,
 }
 
 // ---------------------------------------------------------------------------
@@ -256,11 +264,14 @@ pub fn calculate_clo_scenario(input: &CloScenarioInput) -> CorpFinanceResult<Clo
         .map(|(i, t)| (t.name.clone(), expected_loss_accum[i]))
         .collect();
 
+    let portfolio_metrics = input.loan_tape.as_deref().map(tape_metrics).transpose()?;
+
     Ok(CloScenarioOutput {
         scenario_results,
         expected_loss_by_tranche,
         attachment_points,
         detachment_points,
+        portfolio_metrics,
     })
 }
 
@@ -415,6 +426,7 @@ mod tests {
             reference_rate: dec!(0.05),
             scenarios: sample_scenarios(),
             num_periods: 20,
+            loan_tape: None,
         }
     }
 
@@ -675,4 +687,22 @@ mod tests {
         let json = serde_json::to_string(&out).unwrap();
         let _: CloScenarioOutput = serde_json::from_str(&json).unwrap();
     }
+
+    #[test]
+    fn test_loan_tape_adds_portfolio_metrics() {
+        let mut input = sample_input();
+        input.loan_tape = serde_json::from_value(serde_json::json!([
+            { "name": "A", "par": 50, "rating": "B2", "spread": 0.035,
+              "remaining_life": 5, "industry": "Healthcare" },
+            { "name": "B", "par": 50, "rating": "B2", "spread": 0.035,
+              "remaining_life": 5, "industry": "Retail" }
+        ]))
+        .unwrap();
+        let metrics = calculate_clo_scenario(&input)
+            .unwrap()
+            .portfolio_metrics
+            .unwrap();
+        assert_eq!(metrics.warf, dec!(2720));
+        assert_eq!(metrics.diversity_score, dec!(2));
+    }
 }
@@ -0,0 +1,347 @@
+//! Annual percentage rate under Regulation Z.
+//!
+//! Appendix J actuarial method with a monthly unit period: the APR is twelve
+//! times the monthly rate that discounts the payment stream to the amount
+//! financed (loan amount less prepaid finance charges such as points and
+//! lender fees). Adjustable rate loans use a composite stream with the index
+//! at consummation held flat, subject to the loan's caps. Optionally
+//! compares the note rate against a par rate to give the breakeven on
+//! discount points.
+
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+use std::time::Instant;
+
+use super::loans::{level_payment, simulate, ArmTerms, RetailLoanInput, RetailLoanType};
+use crate::error::CorpFinanceError;
+use crate::types::{with_metadata, ComputationOutput, Money, Rate};
+use crate::CorpFinanceResult;
+
+/// Reg Z tolerance for a regular transaction (1/8 of a percentage point).
+const REGULAR_TOLERANCE: Decimal = dec!(0.00125);
+/// Reg Z tolerance for an irregular transaction (1/4 of a percentage point).
+const IRREGULAR_TOLERANCE: Decimal = dec!(0.0025);
+
+const MAX_APR_ITERATIONS: u32 = 100;
+
+// ---------------------------------------------------------------------------
+// Types
+// ---------------------------------------------------------------------------
+
+/// Input for an APR disclosure calculation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AprInput {
+    pub loan_amount: Money,
+    /// Annual note rate.
+    pub note_rate: Rate,
+    pub term_months: u32,
+    /// Discount and origination points as a fraction of the loan (0.01 = 1 point).
+    #[serde(default)]
+    pub points: Rate,
+    /// Other prepaid finance charges: lender fees, prepaid interest,
+    /// upfront mortgage insurance.
+    #[serde(default)]
+    pub prepaid_finance_charges: Money,
+    /// Adjustable rate terms; only the first index rate (at consummation)
+    /// is used.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub arm: Option<ArmTerms>,
+    /// Rate available with no discount points, for the points breakeven.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub par_rate: Option<Rate>,
+}
+
+/// Output of the APR calculation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AprOutput {
+    /// First scheduled payment.
+    pub monthly_payment: Money,
+    pub points_cost: Money,
+    /// Loan amount less prepaid finance charges.
+    pub amount_financed: Money,
+    /// Total of payments less the amount financed.
+    pub finance_charge: Money,
+    pub total_of_payments: Money,
+    pub apr: Rate,
+    /// APR less the note rate.
+    pub apr_spread: Rate,
+    /// Whether the payment stream is level (regular transaction).
+    pub regular_transaction: bool,
+    /// Disclosure tolerance applying to the APR.
+    pub apr_tolerance: Rate,
+    /// Payment saved by paying points to get the note rate instead of the
+    /// par rate.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub points_monthly_savings: Option<Money>,
+    /// Months for the payment saving to repay the points.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub points_breakeven_months: Option<u32>,
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Monthly rate discounting `payments` (months 1..n) to `amount_financed`,
+/// by Newton-Raphson.
+fn solve_unit_rate(
+    payments: &[Money],
+    amount_financed: Money,
+    guess: Rate,
+) -> CorpFinanceResult<Rate> {
+    let mut i = guess.max(dec!(0.0001));
+    let mut delta = Decimal::ZERO;
+    for _ in 0..MAX_APR_ITERATIONS {
+        let v = Decimal::ONE / (Decimal::ONE + i);
+        let mut disc = Decimal::ONE;
+        let mut f = -amount_financed;
+        let mut df = Decimal::ZERO;
+        for (k, p) in payments.iter().enumerate() {
+            disc *= v;
+            f += *p * disc;
+            df -= Decimal::from(k as u64 + 1) * *p * disc * v;
+        }
+        if df.is_zero() {
+            break;
+        }
+        delta = f / df;
+        i -= delta;
+        if delta.abs() < dec!(0.000000000001) {
+            return Ok(i);
+        }
+    }
+    Err(CorpFinanceError::ConvergenceFailure {
+        function: "APR".into(),
+        iterations: MAX_APR_ITERATIONS,
+        last_delta: delta,
+    })
+}
+
+// ---------------------------------------------------------------------------
+// Public API
+// ---------------------------------------------------------------------------
+
+/// Compute the Reg Z APR, finance charge and amount financed for a closed-end
+/// consumer loan.
+pub fn calculate_apr(input: &AprInput) -> CorpFinanceResult<ComputationOutput<AprOutput>> {
+    let start = Instant::now();
+    let mut warnings: Vec<String> = Vec::new();
+
+    validate_input(input)?;
+
+    let arm = input.arm.clone().map(|mut arm| {
+        arm.index_rates.truncate(1);
+        arm
+    });
+    let loan = RetailLoanInput {
+        loan_type: RetailLoanType::Mortgage,
+        principal: input.loan_amount,
+        annual_rate: input.note_rate,
+        term_months: input.term_months,
+        arm,
+        extra_payments: None,
+        student: None,
+    };
+    let (schedule, resets, summary) = simulate(&loan, None)?;
+    let payments: Vec<Money> = schedule.iter().map(|p| p.payment).collect();
+
+    let points_cost = input.points * input.loan_amount;
+    let amount_financed = input.loan_amount - points_cost - input.prepaid_finance_charges;
+    let total_of_payments = summary.total_payments;
+    let finance_charge = total_of_payments - amount_financed;
+
+    let unit_rate = solve_unit_rate(&payments, amount_financed, input.note_rate / dec!(12))?;
+    let apr = unit_rate * dec!(12);
+
+    // Regular: level payments apart from the final-payment cent adjustment.
+    let regular_transaction = resets.is_empty();
+    let apr_tolerance = if regular_transaction {
+        REGULAR_TOLERANCE
+    } else {
+        IRREGULAR_TOLERANCE
+    };
+
+    let (points_monthly_savings, points_breakeven_months) = match input.par_rate {
+        Some(par_rate) => {
+            let savings = level_payment(input.loan_amount, par_rate, input.term_months)?
+                - summary.initial_payment;
+            if savings <= Decimal::ZERO {
+                warnings.push("Par rate payment is no higher than the note rate payment; points do not pay back".into());
+                (Some(savings), None)
+            } else {
+                (Some(savings), (points_cost / savings).ceil().to_u32())
+            }
+        }
+        None => (None, None),
+    };
+
+    if apr - input.note_rate > dec!(0.015) {
+        warnings.push(format!(
+            "APR exceeds the note rate by {} percentage points; check fees against HOEPA thresholds",
+            ((apr - input.note_rate) * dec!(100)).round_dp(3)
+        ));
+    }
+
+    let output = AprOutput {
+        monthly_payment: summary.initial_payment,
+        points_cost,
+        amount_financed,
+        finance_charge,
+        total_of_payments,
+        apr,
+        apr_spread: apr - input.note_rate,
+        regular_transaction,
+        apr_tolerance,
+        points_monthly_savings,
+        points_breakeven_months,
+    };
+
+    let elapsed = start.elapsed().as_micros() as u64;
+    let assumptions = serde_json::json!({
+        "method": "Reg Z Appendix J actuarial method, monthly unit period",
+        "amount_financed": "loan amount less points and prepaid finance charges",
+        "payments": "rounded to the cent; final payment clears the balance",
+        "variable_rate": "composite APR with the index at consummation held flat, caps applied",
+    });
+
+    Ok(with_metadata(
+        "Regulation Z APR (actuarial method)",
+        &assumptions,
+        warnings,
+        elapsed,
+        output,
+    ))
+}
+
+// ---------------------------------------------------------------------------
+// Validation
+// ---------------------------------------------------------------------------
+
+fn validate_input(input: &AprInput) -> CorpFinanceResult<()> {
+    if input.loan_amount <= Decimal::ZERO {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "loan_amount".into(),
+            reason: "Loan amount must be positive".into(),
+        });
+    }
+    if input.points < Decimal::ZERO || input.prepaid_finance_charges < Decimal::ZERO {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "points".into(),
+            reason: "Points and prepaid finance charges cannot be negative".into(),
+        });
+    }
+    if input.points * input.loan_amount + input.prepaid_finance_charges >= input.loan_amount {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "prepaid_finance_charges".into(),
+            reason: "Prepaid finance charges must be less than the loan amount".into(),
+        });
+    }
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mortgage() -> AprInput {
+        AprInput {
+            loan_amount: dec!(200_000),
+            note_rate: dec!(0.06),
+            term_months: 360,
+            points: dec!(0.01),
+            prepaid_finance_charges: dec!(1_500),
+            arm: None,
+            par_rate: None,
+        }
+    }
+
+    #[test]
+    fn test_no_fees_apr_equals_note_rate() {
+        let mut input = mortgage();
+        input.points = Decimal::ZERO;
+        input.prepaid_finance_charges = Decimal::ZERO;
+        let out = calculate_apr(&input).unwrap().result;
+        assert!((out.apr - dec!(0.06)).abs() < dec!(0.00001));
+        assert_eq!(out.amount_financed, dec!(200_000));
+    }
+
+    #[test]
+    fn test_fees_raise_apr() {
+        let out = calculate_apr(&mortgage()).unwrap().result;
+        assert_eq!(out.monthly_payment, dec!(1199.10));
+        assert_eq!(out.points_cost, dec!(2000));
+        assert_eq!(out.amount_financed, dec!(196_500));
+        // 3,500 of fees on a 30-year loan adds roughly 16bp
+        assert!(
+            out.apr > dec!(0.0614) && out.apr < dec!(0.0620),
+            "APR {}",
+            out.apr
+        );
+        assert_eq!(
+            out.finance_charge,
+            out.total_of_payments - out.amount_financed
+        );
+        assert!(out.regular_transaction);
+        assert_eq!(out.apr_tolerance, dec!(0.00125));
+    }
+
+    #[test]
+    fn test_shorter_term_spreads_fees_over_fewer_payments() {
+        let long = calculate_apr(&mortgage()).unwrap().result;
+        let mut input = mortgage();
+        input.term_months = 180;
+        let short = calculate_apr(&input).unwrap().result;
+        assert!(short.apr_spread > long.apr_spread);
+    }
+
+    #[test]
+    fn test_points_breakeven() {
+        let mut input = mortgage();
+        input.par_rate = Some(dec!(0.0625));
+        input.prepaid_finance_charges = Decimal::ZERO;
+        let out = calculate_apr(&input).unwrap().result;
+        let savings = out.points_monthly_savings.unwrap();
+        // 6.25% payment 1,231.43 less 6% payment 1,199.10
+        assert_eq!(savings, dec!(32.33));
+        assert_eq!(out.points_breakeven_months, Some(62));
+    }
+
+    #[test]
+    fn test_arm_composite_apr_is_irregular() {
+        let mut input = mortgage();
+        input.note_rate = dec!(0.05);
+        input.arm = Some(ArmTerms {
+            initial_fixed_months: 60,
+            reset_frequency_months: 12,
+            margin: dec!(0.0275),
+            index_rates: vec![dec!(0.045), dec!(0.09)],
+            initial_adjustment_cap: dec!(0.02),
+            periodic_cap: dec!(0.02),
+            lifetime_cap: dec!(0.05),
+            floor: None,
+            rounding: None,
+        });
+        let out = calculate_apr(&input).unwrap().result;
+        assert!(!out.regular_transaction);
+        assert_eq!(out.apr_tolerance, dec!(0.0025));
+        // Rate steps from 5% towards a 7.25% fully indexed rate
+        assert!(out.apr > dec!(0.06) && out.apr < dec!(0.0725));
+    }
+
+    #[test]
+    fn test_invalid_inputs_rejected() {
+        let mut input = mortgage();
+        input.loan_amount = Decimal::ZERO;
+        assert!(calculate_apr(&input).is_err());
+
+        let mut input = mortgage();
+        input.prepaid_finance_charges = dec!(250_000);
+        assert!(calculate_apr(&input).is_err());
+    }
+}
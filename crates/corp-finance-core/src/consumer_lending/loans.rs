@@ -0,0 +1,642 @@
+//! Retail loan amortisation: mortgages, auto and student loans.
+//!
+//! Monthly schedules with payments rounded to the cent, recurring and lump
+//! sum prepayments (compared against the contractual schedule), adjustable
+//! rate resets with initial, periodic and lifetime caps, and student loan
+//! deferment with interest capitalised on unsubsidised loans.
+
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+use std::time::Instant;
+
+use crate::error::CorpFinanceError;
+use crate::time_value::pmt;
+use crate::types::{with_metadata, ComputationOutput, Money, Rate};
+use crate::CorpFinanceResult;
+
+// ---------------------------------------------------------------------------
+// Types
+// ---------------------------------------------------------------------------
+
+/// Kind of consumer loan.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RetailLoanType {
+    Mortgage,
+    Auto,
+    Student,
+}
+
+/// Adjustable rate terms (e.g. a 5/1 ARM: 60 fixed months, then yearly).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArmTerms {
+    /// Months at the initial rate.
+    pub initial_fixed_months: u32,
+    /// Months between resets after the fixed period.
+    pub reset_frequency_months: u32,
+    /// Margin added to the index.
+    pub margin: Rate,
+    /// Index rate at each reset in turn; the last value holds thereafter.
+    pub index_rates: Vec<Rate>,
+    /// Maximum change at the first reset (e.g. 0.02).
+    pub initial_adjustment_cap: Rate,
+    /// Maximum change at later resets.
+    pub periodic_cap: Rate,
+    /// Maximum rise over the initial rate for the life of the loan.
+    pub lifetime_cap: Rate,
+    /// Minimum rate (default the margin).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub floor: Option<Rate>,
+    /// Fully indexed rate rounded to the nearest multiple (default 0.00125).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rounding: Option<Rate>,
+}
+
+/// A one-off prepayment.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LumpSumPayment {
+    /// Loan month in which it is paid.
+    pub month: u32,
+    pub amount: Money,
+}
+
+/// Prepayments on top of the scheduled payment.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ExtraPayments {
+    /// Extra principal paid every month.
+    #[serde(default)]
+    pub monthly: Money,
+    /// First month of the recurring extra (default 1).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_month: Option<u32>,
+    #[serde(default)]
+    pub lump_sums: Vec<LumpSumPayment>,
+}
+
+/// In-school or grace deferment before repayment starts.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StudentLoanTerms {
+    /// Months with no payments due.
+    pub deferment_months: u32,
+    /// Subsidised loans accrue no interest during deferment; unsubsidised
+    /// interest is capitalised when repayment starts.
+    pub subsidized: bool,
+}
+
+/// Input for a retail loan schedule.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RetailLoanInput {
+    pub loan_type: RetailLoanType,
+    pub principal: Money,
+    /// Annual note rate (initial rate for an ARM).
+    pub annual_rate: Rate,
+    /// Repayment term in months, after any deferment.
+    pub term_months: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub arm: Option<ArmTerms>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extra_payments: Option<ExtraPayments>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub student: Option<StudentLoanTerms>,
+}
+
+/// One month of the schedule.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RetailLoanPeriod {
+    pub month: u32,
+    /// Annual rate charged this month.
+    pub rate: Rate,
+    pub opening_balance: Money,
+    /// Scheduled payment (zero during deferment).
+    pub payment: Money,
+    pub extra_payment: Money,
+    pub interest: Money,
+    pub principal: Money,
+    /// Deferment interest added to the balance this month.
+    pub capitalized_interest: Money,
+    pub closing_balance: Money,
+}
+
+/// An ARM rate reset.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RateReset {
+    pub month: u32,
+    pub index_rate: Rate,
+    /// Index plus margin, rounded.
+    pub fully_indexed_rate: Rate,
+    pub new_rate: Rate,
+    pub new_payment: Money,
+    /// Whether a cap or floor held the rate away from fully indexed.
+    pub capped: bool,
+}
+
+/// Totals for one payment path.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LoanSummary {
+    /// First scheduled payment.
+    pub initial_payment: Money,
+    /// Largest scheduled payment (ARM payment shock).
+    pub max_payment: Money,
+    pub total_payments: Money,
+    /// Total paid less the amount borrowed.
+    pub total_interest: Money,
+    pub capitalized_interest: Money,
+    /// Month of the final payment.
+    pub payoff_month: u32,
+}
+
+/// Output of the retail loan schedule.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RetailLoanOutput {
+    pub loan_type: RetailLoanType,
+    pub schedule: Vec<RetailLoanPeriod>,
+    pub rate_resets: Vec<RateReset>,
+    pub summary: LoanSummary,
+    /// Contractual schedule without prepayments (when any are given).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub baseline: Option<LoanSummary>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interest_saved: Option<Money>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub months_saved: Option<u32>,
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Level monthly payment rounded to the cent.
+pub(crate) fn level_payment(
+    balance: Money,
+    annual_rate: Rate,
+    months: u32,
+) -> CorpFinanceResult<Money> {
+    Ok((-pmt(annual_rate / dec!(12), months, balance, Decimal::ZERO)?).round_dp(2))
+}
+
+/// Next ARM rate from the index, caps, floor and rounding.
+fn reset_rate(
+    arm: &ArmTerms,
+    initial_rate: Rate,
+    current: Rate,
+    reset_no: usize,
+) -> (Rate, Rate, Rate, bool) {
+    let index = arm.index_rates[reset_no.min(arm.index_rates.len() - 1)];
+    let step = arm.rounding.unwrap_or(dec!(0.00125));
+    let raw = index + arm.margin;
+    let fully_indexed = if step.is_zero() {
+        raw
+    } else {
+        (raw / step).round() * step
+    };
+    let cap = if reset_no == 0 {
+        arm.initial_adjustment_cap
+    } else {
+        arm.periodic_cap
+    };
+    let floor = arm.floor.unwrap_or(arm.margin);
+    let ceiling = initial_rate + arm.lifetime_cap;
+    let new_rate = fully_indexed
+        .clamp(current - cap, current + cap)
+        .min(ceiling)
+        .max(floor);
+    (index, fully_indexed, new_rate, new_rate != fully_indexed)
+}
+
+/// Run the loan month by month, with or without prepayments.
+pub(crate) fn simulate(
+    input: &RetailLoanInput,
+    extras: Option<&ExtraPayments>,
+) -> CorpFinanceResult<(Vec<RetailLoanPeriod>, Vec<RateReset>, LoanSummary)> {
+    let (deferment, subsidized) = input
+        .student
+        .as_ref()
+        .map_or((0, true), |s| (s.deferment_months, s.subsidized));
+    let mut schedule = Vec::with_capacity((deferment + input.term_months) as usize);
+    let mut resets = Vec::new();
+    let mut balance = input.principal;
+    let mut rate = input.annual_rate;
+    let mut payment = Decimal::ZERO;
+    let mut accrued = Decimal::ZERO;
+    let mut capitalized_total = Decimal::ZERO;
+
+    for month in 1..=deferment + input.term_months {
+        let opening = balance;
+        let monthly_rate = rate / dec!(12);
+
+        // --- Deferment ---
+        if month <= deferment {
+            let interest = if subsidized {
+                Decimal::ZERO
+            } else {
+                opening * monthly_rate
+            };
+            accrued += interest;
+            let capitalized = if month == deferment {
+                accrued
+            } else {
+                Decimal::ZERO
+            };
+            balance += capitalized;
+            capitalized_total += capitalized;
+            schedule.push(RetailLoanPeriod {
+                month,
+                rate,
+                opening_balance: opening,
+                payment: Decimal::ZERO,
+                extra_payment: Decimal::ZERO,
+                interest,
+                principal: Decimal::ZERO,
+                capitalized_interest: capitalized,
+                closing_balance: balance,
+            });
+            continue;
+        }
+
+        // --- Repayment ---
+        let k = month - deferment;
+        let remaining = input.term_months - k + 1;
+        if k == 1 {
+            payment = level_payment(balance, rate, input.term_months)?;
+        }
+        if let Some(arm) = &input.arm {
+            if k > arm.initial_fixed_months
+                && (k - arm.initial_fixed_months - 1) % arm.reset_frequency_months == 0
+            {
+                let reset_no =
+                    ((k - arm.initial_fixed_months - 1) / arm.reset_frequency_months) as usize;
+                let (index_rate, fully_indexed_rate, new_rate, capped) =
+                    reset_rate(arm, input.annual_rate, rate, reset_no);
+                rate = new_rate;
+                payment = level_payment(balance, rate, remaining)?;
+                resets.push(RateReset {
+                    month,
+                    index_rate,
+                    fully_indexed_rate,
+                    new_rate,
+                    new_payment: payment,
+                    capped,
+                });
+            }
+        }
+
+        let interest = opening * rate / dec!(12);
+        let scheduled = if remaining == 1 || payment >= opening + interest {
+            opening + interest
+        } else {
+            payment
+        };
+        let principal = scheduled - interest;
+        let extra = extras.map_or(Decimal::ZERO, |e| {
+            let recurring = if month >= e.start_month.unwrap_or(1) {
+                e.monthly
+            } else {
+                Decimal::ZERO
+            };
+            let lump: Money = e
+                .lump_sums
+                .iter()
+                .filter(|l| l.month == month)
+                .map(|l| l.amount)
+                .sum();
+            (recurring + lump)
+                .min(opening - principal)
+                .max(Decimal::ZERO)
+        });
+        balance = opening - principal - extra;
+
+        schedule.push(RetailLoanPeriod {
+            month,
+            rate,
+            opening_balance: opening,
+            payment: scheduled,
+            extra_payment: extra,
+            interest,
+            principal,
+            capitalized_interest: Decimal::ZERO,
+            closing_balance: balance,
+        });
+        if balance <= Decimal::ZERO {
+            break;
+        }
+    }
+
+    let total_payments: Money = schedule.iter().map(|p| p.payment + p.extra_payment).sum();
+    let summary = LoanSummary {
+        initial_payment: schedule
+            .iter()
+            .find(|p| p.payment > Decimal::ZERO)
+            .map_or(Decimal::ZERO, |p| p.payment),
+        max_payment: schedule.iter().map(|p| p.payment).max().unwrap_or_default(),
+        total_payments,
+        total_interest: total_payments - input.principal,
+        capitalized_interest: capitalized_total,
+        payoff_month: schedule.last().map_or(0, |p| p.month),
+    };
+    Ok((schedule, resets, summary))
+}
+
+// ---------------------------------------------------------------------------
+// Public API
+// ---------------------------------------------------------------------------
+
+/// Build a monthly retail loan schedule, with prepayment savings against
+/// the contractual schedule when extra payments are given.
+pub fn build_retail_loan_schedule(
+    input: &RetailLoanInput,
+) -> CorpFinanceResult<ComputationOutput<RetailLoanOutput>> {
+    let start = Instant::now();
+    let mut warnings: Vec<String> = Vec::new();
+
+    validate_input(input)?;
+
+    let (schedule, rate_resets, summary) = simulate(input, input.extra_payments.as_ref())?;
+
+    let (baseline, interest_saved, months_saved) = match &input.extra_payments {
+        Some(_) => {
+            let (_, _, base) = simulate(input, None)?;
+            let saved = base.total_interest - summary.total_interest;
+            let months = base.payoff_month.saturating_sub(summary.payoff_month);
+            (Some(base), Some(saved), Some(months))
+        }
+        None => (None, None, None),
+    };
+
+    if summary.max_payment > summary.initial_payment * dec!(1.25) {
+        warnings.push(format!(
+            "Payment shock: scheduled payment rises from {} to {}",
+            summary.initial_payment, summary.max_payment
+        ));
+    }
+    if input.loan_type == RetailLoanType::Student && input.arm.is_some() {
+        warnings.push("Federal student loans carry fixed rates; ARM terms applied as given".into());
+    }
+
+    let output = RetailLoanOutput {
+        loan_type: input.loan_type,
+        schedule,
+        rate_resets,
+        summary,
+        baseline,
+        interest_saved,
+        months_saved,
+    };
+
+    let elapsed = start.elapsed().as_micros() as u64;
+    let assumptions = serde_json::json!({
+        "loan_type": input.loan_type,
+        "payment_rounding": "cent; final payment clears the balance",
+        "interest": "annual rate / 12 on the opening balance",
+        "prepayments": "applied to principal after the scheduled payment; payment unchanged, term shortens",
+        "arm_recast": "payment re-amortised over the remaining term at each reset",
+        "deferment": "unsubsidised interest capitalised at the end of deferment",
+    });
+
+    Ok(with_metadata(
+        "Retail loan amortisation with prepayments, ARM resets and deferment",
+        &assumptions,
+        warnings,
+        elapsed,
+        output,
+    ))
+}
+
+// ---------------------------------------------------------------------------
+// Validation
+// ---------------------------------------------------------------------------
+
+fn validate_input(input: &RetailLoanInput) -> CorpFinanceResult<()> {
+    if input.principal <= Decimal::ZERO {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "principal".into(),
+            reason: "Principal must be positive".into(),
+        });
+    }
+    if input.annual_rate < Decimal::ZERO {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "annual_rate".into(),
+            reason: "Rate cannot be negative".into(),
+        });
+    }
+    if input.term_months == 0 {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "term_months".into(),
+            reason: "Term must be at least one month".into(),
+        });
+    }
+    let deferment = input.student.as_ref().map_or(0, |s| s.deferment_months);
+    crate::limits::check_schedule_periods("term_months", u64::from(input.term_months + deferment))?;
+    if let Some(arm) = &input.arm {
+        if arm.reset_frequency_months == 0 {
+            return Err(CorpFinanceError::InvalidInput {
+                field: "arm.reset_frequency_months".into(),
+                reason: "Reset frequency must be at least one month".into(),
+            });
+        }
+        if arm.index_rates.is_empty() {
+            return Err(CorpFinanceError::InvalidInput {
+                field: "arm.index_rates".into(),
+                reason: "At least one index rate is required".into(),
+            });
+        }
+        if arm.initial_adjustment_cap < Decimal::ZERO
+            || arm.periodic_cap < Decimal::ZERO
+            || arm.lifetime_cap < Decimal::ZERO
+        {
+            return Err(CorpFinanceError::InvalidInput {
+                field: "arm".into(),
+                reason: "Rate caps cannot be negative".into(),
+            });
+        }
+    }
+    if let Some(extra) = &input.extra_payments {
+        if extra.monthly < Decimal::ZERO || extra.lump_sums.iter().any(|l| l.amount < Decimal::ZERO)
+        {
+            return Err(CorpFinanceError::InvalidInput {
+                field: "extra_payments".into(),
+                reason: "Extra payments cannot be negative".into(),
+            });
+        }
+    }
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mortgage() -> RetailLoanInput {
+        RetailLoanInput {
+            loan_type: RetailLoanType::Mortgage,
+            principal: dec!(300_000),
+            annual_rate: dec!(0.06),
+            term_months: 360,
+            arm: None,
+            extra_payments: None,
+            student: None,
+        }
+    }
+
+    #[test]
+    fn test_fixed_mortgage_payment_and_payoff() {
+        let out = build_retail_loan_schedule(&mortgage()).unwrap().result;
+        // Standard 30-year 6% payment on 300k
+        assert_eq!(out.summary.initial_payment, dec!(1798.65));
+        assert_eq!(out.summary.payoff_month, 360);
+        assert_eq!(out.schedule.last().unwrap().closing_balance, Decimal::ZERO);
+        assert!((out.summary.total_interest - dec!(347_514)).abs() < dec!(5));
+        assert!(out.baseline.is_none());
+    }
+
+    #[test]
+    fn test_extra_payments_shorten_term_and_save_interest() {
+        let mut input = mortgage();
+        input.extra_payments = Some(ExtraPayments {
+            monthly: dec!(200),
+            start_month: None,
+            lump_sums: vec![LumpSumPayment {
+                month: 12,
+                amount: dec!(10_000),
+            }],
+        });
+        let out = build_retail_loan_schedule(&input).unwrap().result;
+        assert!(out.summary.payoff_month < 300);
+        assert_eq!(out.months_saved, Some(360 - out.summary.payoff_month));
+        let saved = out.interest_saved.unwrap();
+        assert!(saved > dec!(80_000));
+        assert_eq!(
+            saved,
+            out.baseline.unwrap().total_interest - out.summary.total_interest
+        );
+        assert_eq!(out.schedule[11].extra_payment, dec!(10_200));
+        assert_eq!(out.schedule.last().unwrap().closing_balance, Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_arm_resets_respect_caps() {
+        let mut input = mortgage();
+        input.annual_rate = dec!(0.05);
+        input.arm = Some(ArmTerms {
+            initial_fixed_months: 60,
+            reset_frequency_months: 12,
+            margin: dec!(0.0275),
+            index_rates: vec![dec!(0.05), dec!(0.06), dec!(0.03)],
+            initial_adjustment_cap: dec!(0.02),
+            periodic_cap: dec!(0.02),
+            lifetime_cap: dec!(0.05),
+            floor: None,
+            rounding: None,
+        });
+        let out = build_retail_loan_schedule(&input).unwrap();
+        let resets = &out.result.rate_resets;
+        assert_eq!(resets[0].month, 61);
+        // 5.00% + 2.75% = 7.75% capped at 5% + 2% = 7%
+        assert_eq!(resets[0].fully_indexed_rate, dec!(0.0775));
+        assert_eq!(resets[0].new_rate, dec!(0.07));
+        assert!(resets[0].capped);
+        // 6% + 2.75% = 8.75% reached within the 2% periodic cap
+        assert_eq!(resets[1].new_rate, dec!(0.0875));
+        assert!(!resets[1].capped);
+        // Index falls: 5.75% within 8.75% - 2%
+        assert_eq!(resets[2].new_rate, dec!(0.0675));
+        assert!(out.result.summary.max_payment > out.result.summary.initial_payment);
+        assert_eq!(out.result.schedule.last().unwrap().month, 360);
+        assert!(out.warnings.iter().any(|w| w.contains("Payment shock")));
+    }
+
+    #[test]
+    fn test_arm_lifetime_cap() {
+        let mut input = mortgage();
+        input.annual_rate = dec!(0.03);
+        input.arm = Some(ArmTerms {
+            initial_fixed_months: 12,
+            reset_frequency_months: 12,
+            margin: dec!(0.03),
+            index_rates: vec![dec!(0.08)],
+            initial_adjustment_cap: dec!(0.02),
+            periodic_cap: dec!(0.02),
+            lifetime_cap: dec!(0.05),
+            floor: None,
+            rounding: None,
+        });
+        let out = build_retail_loan_schedule(&input).unwrap().result;
+        assert!(out.rate_resets.iter().all(|r| r.new_rate <= dec!(0.08)));
+        assert_eq!(out.rate_resets.last().unwrap().new_rate, dec!(0.08));
+    }
+
+    #[test]
+    fn test_unsubsidized_student_loan_capitalizes_interest() {
+        let input = RetailLoanInput {
+            loan_type: RetailLoanType::Student,
+            principal: dec!(30_000),
+            annual_rate: dec!(0.055),
+            term_months: 120,
+            arm: None,
+            extra_payments: None,
+            student: Some(StudentLoanTerms {
+                deferment_months: 48,
+                subsidized: false,
+            }),
+        };
+        let out = build_retail_loan_schedule(&input).unwrap().result;
+        // Simple interest over four years: 30,000 x 5.5% x 4 = 6,600
+        assert!((out.summary.capitalized_interest - dec!(6600)).abs() < dec!(0.01));
+        assert!((out.schedule[47].closing_balance - dec!(36_600)).abs() < dec!(0.01));
+        assert_eq!(out.schedule[47].payment, Decimal::ZERO);
+        assert_eq!(out.summary.payoff_month, 168);
+
+        let mut subsidized = input.clone();
+        subsidized.student = Some(StudentLoanTerms {
+            deferment_months: 48,
+            subsidized: true,
+        });
+        let sub = build_retail_loan_schedule(&subsidized).unwrap().result;
+        assert_eq!(sub.summary.capitalized_interest, Decimal::ZERO);
+        assert!(sub.summary.initial_payment < out.summary.initial_payment);
+    }
+
+    #[test]
+    fn test_auto_loan_zero_rate() {
+        let input = RetailLoanInput {
+            loan_type: RetailLoanType::Auto,
+            principal: dec!(36_000),
+            annual_rate: Decimal::ZERO,
+            term_months: 60,
+            arm: None,
+            extra_payments: None,
+            student: None,
+        };
+        let out = build_retail_loan_schedule(&input).unwrap().result;
+        assert_eq!(out.summary.initial_payment, dec!(600));
+        assert_eq!(out.summary.total_interest, Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_invalid_inputs_rejected() {
+        let mut input = mortgage();
+        input.principal = Decimal::ZERO;
+        assert!(build_retail_loan_schedule(&input).is_err());
+
+        let mut input = mortgage();
+        input.term_months = 0;
+        assert!(build_retail_loan_schedule(&input).is_err());
+
+        let mut input = mortgage();
+        input.arm = Some(ArmTerms {
+            initial_fixed_months: 60,
+            reset_frequency_months: 12,
+            margin: dec!(0.0275),
+            index_rates: vec![],
+            initial_adjustment_cap: dec!(0.02),
+            periodic_cap: dec!(0.02),
+            lifetime_cap: dec!(0.05),
+            floor: None,
+            rounding: None,
+        });
+        assert!(build_retail_loan_schedule(&input).is_err());
+    }
+}
@@ -0,0 +1,3 @@
+pub mod apr;
+pub mod loans;
+pub mod refinance;
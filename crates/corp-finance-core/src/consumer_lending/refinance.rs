@@ -0,0 +1,390 @@
+//! Refinance breakeven analysis.
+//!
+//! Compares keeping the current loan with replacing it: payment change,
+//! simple breakeven (costs over monthly saving), a fuller breakeven that
+//! also credits the difference in balances outstanding (so a longer new term
+//! does not look better than it is), the NPV of refinancing over the
+//! holding period, and lifetime interest on both paths. Closing costs and
+//! points can be paid in cash or rolled into the new loan, with optional
+//! cash out.
+
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+use std::time::Instant;
+
+use super::loans::level_payment;
+use crate::error::CorpFinanceError;
+use crate::types::{with_metadata, ComputationOutput, Money, Rate};
+use crate::CorpFinanceResult;
+
+// ---------------------------------------------------------------------------
+// Types
+// ---------------------------------------------------------------------------
+
+/// Input for a refinance decision.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RefinanceInput {
+    pub current_balance: Money,
+    /// Annual rate on the current loan.
+    pub current_rate: Rate,
+    pub remaining_months: u32,
+    /// Annual rate on the new loan.
+    pub new_rate: Rate,
+    pub new_term_months: u32,
+    /// Lender and third-party closing costs.
+    #[serde(default)]
+    pub closing_costs: Money,
+    /// Points on the new loan as a fraction of its amount.
+    #[serde(default)]
+    pub points: Rate,
+    /// Cash taken out on top of the payoff.
+    #[serde(default)]
+    pub cash_out: Money,
+    /// Roll closing costs and points into the new loan instead of paying cash.
+    #[serde(default)]
+    pub finance_costs: bool,
+    /// Annual rate for discounting (default the current loan rate).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub discount_rate: Option<Rate>,
+    /// Months the borrower expects to keep the loan (default the current
+    /// remaining term).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub holding_period_months: Option<u32>,
+}
+
+/// Output of the refinance analysis.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RefinanceOutput {
+    pub current_payment: Money,
+    pub new_loan_amount: Money,
+    pub new_payment: Money,
+    /// Current payment less new payment.
+    pub monthly_savings: Money,
+    /// Closing costs plus points.
+    pub upfront_costs: Money,
+    /// Costs paid in cash at closing (zero when financed).
+    pub cash_due_at_closing: Money,
+    /// Upfront costs over the monthly saving, rounded up.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub simple_breakeven_months: Option<u32>,
+    /// First month in which cumulative payment savings plus the lower
+    /// balance outstanding recover the upfront costs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub breakeven_month: Option<u32>,
+    pub horizon_months: u32,
+    /// Balance difference (current less new) at the horizon.
+    pub balance_difference_at_horizon: Money,
+    /// Present value of refinancing to the horizon, net of costs.
+    pub npv: Money,
+    /// Interest still to pay on the current loan.
+    pub current_loan_interest: Money,
+    /// Interest over the life of the new loan, including financed costs.
+    pub new_loan_interest: Money,
+    pub refinance_beneficial: bool,
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Payment and closing balance per month, zero after payoff.
+fn amortize(
+    balance: Money,
+    annual_rate: Rate,
+    months: u32,
+    payment: Money,
+    horizon: u32,
+) -> Vec<(Money, Money)> {
+    let mut out = Vec::with_capacity(horizon as usize);
+    let mut b = balance;
+    for m in 1..=horizon {
+        if b <= Decimal::ZERO || m > months {
+            out.push((Decimal::ZERO, Decimal::ZERO));
+            continue;
+        }
+        let interest = b * annual_rate / dec!(12);
+        let paid = if m == months || payment >= b + interest {
+            b + interest
+        } else {
+            payment
+        };
+        b = b + interest - paid;
+        out.push((paid, b));
+    }
+    out
+}
+
+// ---------------------------------------------------------------------------
+// Public API
+// ---------------------------------------------------------------------------
+
+/// Analyse whether refinancing pays back within the holding period.
+pub fn analyze_refinance(
+    input: &RefinanceInput,
+) -> CorpFinanceResult<ComputationOutput<RefinanceOutput>> {
+    let start = Instant::now();
+    let mut warnings: Vec<String> = Vec::new();
+
+    validate_input(input)?;
+
+    let payoff = input.current_balance + input.cash_out;
+    let (new_loan_amount, cash_due_at_closing) = if input.finance_costs {
+        (
+            (payoff + input.closing_costs) / (Decimal::ONE - input.points),
+            Decimal::ZERO,
+        )
+    } else {
+        (payoff, input.closing_costs + input.points * payoff)
+    };
+    let upfront_costs = input.closing_costs + input.points * new_loan_amount;
+
+    let current_payment = level_payment(
+        input.current_balance,
+        input.current_rate,
+        input.remaining_months,
+    )?;
+    let new_payment = level_payment(new_loan_amount, input.new_rate, input.new_term_months)?;
+    let monthly_savings = current_payment - new_payment;
+
+    let horizon = input
+        .holding_period_months
+        .unwrap_or(input.remaining_months);
+    let full = input
+        .remaining_months
+        .max(input.new_term_months)
+        .max(horizon);
+    let old_path = amortize(
+        input.current_balance,
+        input.current_rate,
+        input.remaining_months,
+        current_payment,
+        full,
+    );
+    let new_path = amortize(
+        new_loan_amount,
+        input.new_rate,
+        input.new_term_months,
+        new_payment,
+        full,
+    );
+
+    // Wealth from refinancing after t months: payments saved, plus the
+    // balance difference, less cash costs, plus cash out received.
+    let v = Decimal::ONE
+        / (Decimal::ONE + input.discount_rate.unwrap_or(input.current_rate) / dec!(12));
+    let mut cumulative = input.cash_out - cash_due_at_closing;
+    let mut pv = cumulative;
+    let mut disc = Decimal::ONE;
+    let mut breakeven_month = None;
+    let mut npv = -upfront_costs;
+    let mut balance_difference_at_horizon = input.current_balance - new_loan_amount;
+    for (t, ((old_pmt, old_bal), (new_pmt, new_bal))) in old_path.iter().zip(&new_path).enumerate()
+    {
+        let month = t as u32 + 1;
+        disc *= v;
+        cumulative += *old_pmt - *new_pmt;
+        pv += (*old_pmt - *new_pmt) * disc;
+        if breakeven_month.is_none() && cumulative + *old_bal - *new_bal >= Decimal::ZERO {
+            breakeven_month = Some(month);
+        }
+        if month == horizon {
+            npv = pv + (*old_bal - *new_bal) * disc;
+            balance_difference_at_horizon = *old_bal - *new_bal;
+        }
+    }
+
+    let simple_breakeven_months = if monthly_savings > Decimal::ZERO {
+        (upfront_costs / monthly_savings).ceil().to_u32()
+    } else {
+        None
+    };
+
+    let current_loan_interest: Money =
+        old_path.iter().map(|(p, _)| *p).sum::<Money>() - input.current_balance;
+    let new_loan_interest: Money = new_path.iter().map(|(p, _)| *p).sum::<Money>() - payoff;
+
+    if input.new_term_months > input.remaining_months {
+        warnings.push(format!(
+            "New term of {} months extends repayment beyond the {} months remaining",
+            input.new_term_months, input.remaining_months
+        ));
+    }
+    if breakeven_month.is_some_and(|m| m > horizon) {
+        warnings.push("Breakeven falls after the holding period".into());
+    }
+
+    let output = RefinanceOutput {
+        current_payment,
+        new_loan_amount,
+        new_payment,
+        monthly_savings,
+        upfront_costs,
+        cash_due_at_closing,
+        simple_breakeven_months,
+        breakeven_month,
+        horizon_months: horizon,
+        balance_difference_at_horizon,
+        npv,
+        current_loan_interest,
+        new_loan_interest,
+        refinance_beneficial: npv > Decimal::ZERO,
+    };
+
+    let elapsed = start.elapsed().as_micros() as u64;
+    let assumptions = serde_json::json!({
+        "payments": "level, rounded to the cent",
+        "breakeven": "cumulative payment savings plus balance difference cover upfront costs",
+        "npv": "monthly discounting of payment savings plus balance difference at the horizon",
+        "discount_rate": input.discount_rate.unwrap_or(input.current_rate).to_string(),
+        "financed_costs": input.finance_costs,
+    });
+
+    Ok(with_metadata(
+        "Refinance breakeven and NPV",
+        &assumptions,
+        warnings,
+        elapsed,
+        output,
+    ))
+}
+
+// ---------------------------------------------------------------------------
+// Validation
+// ---------------------------------------------------------------------------
+
+fn validate_input(input: &RefinanceInput) -> CorpFinanceResult<()> {
+    if input.current_balance <= Decimal::ZERO {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "current_balance".into(),
+            reason: "Balance must be positive".into(),
+        });
+    }
+    if input.remaining_months == 0 || input.new_term_months == 0 {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "new_term_months".into(),
+            reason: "Terms must be at least one month".into(),
+        });
+    }
+    crate::limits::check_schedule_periods(
+        "new_term_months",
+        u64::from(input.remaining_months.max(input.new_term_months)),
+    )?;
+    if input.current_rate < Decimal::ZERO || input.new_rate < Decimal::ZERO {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "new_rate".into(),
+            reason: "Rates cannot be negative".into(),
+        });
+    }
+    if input.points < Decimal::ZERO || input.points >= Decimal::ONE {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "points".into(),
+            reason: "Points must be between 0 and 1".into(),
+        });
+    }
+    if input.closing_costs < Decimal::ZERO || input.cash_out < Decimal::ZERO {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "closing_costs".into(),
+            reason: "Closing costs and cash out cannot be negative".into(),
+        });
+    }
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn refi() -> RefinanceInput {
+        RefinanceInput {
+            current_balance: dec!(250_000),
+            current_rate: dec!(0.075),
+            remaining_months: 300,
+            new_rate: dec!(0.06),
+            new_term_months: 300,
+            closing_costs: dec!(4_000),
+            points: dec!(0.005),
+            cash_out: Decimal::ZERO,
+            finance_costs: false,
+            discount_rate: None,
+            holding_period_months: Some(84),
+        }
+    }
+
+    #[test]
+    fn test_rate_cut_same_term_breaks_even() {
+        let out = analyze_refinance(&refi()).unwrap().result;
+        assert_eq!(out.upfront_costs, dec!(5_250));
+        assert_eq!(out.cash_due_at_closing, dec!(5_250));
+        assert!(out.monthly_savings > dec!(230) && out.monthly_savings < dec!(240));
+        assert_eq!(out.simple_breakeven_months, Some(23));
+        // Same term at a lower rate also pays down faster, so the full
+        // breakeven comes no later than the simple one
+        assert!(out.breakeven_month.unwrap() <= 23);
+        assert!(out.npv > Decimal::ZERO);
+        assert!(out.refinance_beneficial);
+        assert!(out.new_loan_interest < out.current_loan_interest);
+    }
+
+    #[test]
+    fn test_short_holding_period_not_beneficial() {
+        let mut input = refi();
+        input.holding_period_months = Some(12);
+        let out = analyze_refinance(&input).unwrap();
+        assert!(!out.result.refinance_beneficial);
+        assert!(out
+            .warnings
+            .iter()
+            .any(|w| w.contains("after the holding period")));
+    }
+
+    #[test]
+    fn test_term_extension_flatters_simple_breakeven() {
+        let mut input = refi();
+        input.remaining_months = 180;
+        input.new_rate = dec!(0.07);
+        input.new_term_months = 360;
+        input.holding_period_months = None;
+        let out = analyze_refinance(&input).unwrap();
+        let r = &out.result;
+        // Payment falls sharply, but the balance falls far more slowly
+        assert!(r.simple_breakeven_months.unwrap() < 12);
+        assert!(r
+            .breakeven_month
+            .is_none_or(|m| m > r.simple_breakeven_months.unwrap()));
+        assert!(r.new_loan_interest > r.current_loan_interest);
+        assert!(out.warnings.iter().any(|w| w.contains("extends repayment")));
+    }
+
+    #[test]
+    fn test_financed_costs_and_cash_out() {
+        let mut input = refi();
+        input.finance_costs = true;
+        input.cash_out = dec!(20_000);
+        let out = analyze_refinance(&input).unwrap().result;
+        assert_eq!(out.cash_due_at_closing, Decimal::ZERO);
+        // Points are charged on the grossed-up loan
+        let expected = dec!(274_000) / dec!(0.995);
+        assert_eq!(out.new_loan_amount, expected);
+        assert!(
+            (out.upfront_costs - (dec!(4_000) + expected * dec!(0.005))).abs() < dec!(0.000001)
+        );
+        assert!(out.new_payment > out.current_payment * dec!(0.9));
+    }
+
+    #[test]
+    fn test_invalid_inputs_rejected() {
+        let mut input = refi();
+        input.current_balance = Decimal::ZERO;
+        assert!(analyze_refinance(&input).is_err());
+
+        let mut input = refi();
+        input.points = dec!(1);
+        assert!(analyze_refinance(&input).is_err());
+    }
+}
@@ -3,6 +3,7 @@ use rust_decimal_macros::dec;
 use serde::{Deserialize, Serialize};
 use std::time::Instant;
 
+use crate::dilution::{diluted_shares_at_price, DilutiveSecurity, SecurityKind};
 use crate::error::CorpFinanceError;
 use crate::types::*;
 use crate::CorpFinanceResult;
@@ -28,6 +29,19 @@ pub struct ConvertibleAnalysisInput {
     pub stock_scenarios: Vec<Decimal>,
     pub vol_scenarios: Option<Vec<Rate>>,
     pub spread_scenarios: Option<Vec<Rate>>,
+    /// Issuer share count for the dilution analysis
+    #[serde(default)]
+    pub dilution: Option<ConvertibleDilutionInput>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConvertibleDilutionInput {
+    pub basic_shares: Decimal,
+    /// Number of bonds of `face_value` outstanding
+    pub bonds_outstanding: Decimal,
+    /// Options, warrants and RSUs diluting alongside the convertible
+    #[serde(default)]
+    pub other_securities: Vec<DilutiveSecurity>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -43,6 +57,7 @@ pub struct ConvertibleAnalysisOutput {
     pub forced_conversion_analysis: Option<ForcedConversion>,
     pub income_advantage: IncomeAdvantage,
     pub risk_return_profile: RiskReturnProfile,
+    pub dilution: Option<ConvertibleDilution>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -70,6 +85,23 @@ pub struct IncomeAdvantage {
     pub breakeven_years: Decimal,
 }
 
+/// Share dilution at the current stock price, using the crate-wide
+/// dilution rules.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConvertibleDilution {
+    pub conversion_price: Money,
+    /// Underlying shares if every bond converts
+    pub underlying_shares: Decimal,
+    /// New shares under physical settlement (if-converted)
+    pub if_converted_new_shares: Decimal,
+    /// New shares under net share settlement
+    pub net_share_new_shares: Decimal,
+    pub if_converted_dilution_pct: Rate,
+    pub net_share_dilution_pct: Rate,
+    /// Fully diluted shares including other securities (if-converted)
+    pub fully_diluted_shares: Decimal,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RiskReturnProfile {
     pub upside_participation: Rate,
@@ -299,6 +331,45 @@ fn validate_input(input: &ConvertibleAnalysisInput) -> CorpFinanceResult<()> {
     Ok(())
 }
 
+/// Dilution from the convertible under physical and net share settlement.
+fn convertible_dilution(
+    input: &ConvertibleAnalysisInput,
+    d: &ConvertibleDilutionInput,
+) -> CorpFinanceResult<ConvertibleDilution> {
+    let conversion_price = input.face_value / input.conversion_ratio;
+    let bond = |kind| DilutiveSecurity {
+        name: input.bond_name.clone(),
+        kind,
+        count: d.bonds_outstanding,
+        strike: conversion_price,
+        shares_per_instrument: input.conversion_ratio,
+        time_to_expiry: Some(input.maturity_years),
+    };
+    let physical = diluted_shares_at_price(
+        d.basic_shares,
+        &[bond(SecurityKind::Convertible)],
+        input.stock_price,
+    )?;
+    let net_share = diluted_shares_at_price(
+        d.basic_shares,
+        &[bond(SecurityKind::NetShareConvertible)],
+        input.stock_price,
+    )?;
+    let mut all = d.other_securities.clone();
+    all.push(bond(SecurityKind::Convertible));
+    let fully_diluted = diluted_shares_at_price(d.basic_shares, &all, input.stock_price)?;
+
+    Ok(ConvertibleDilution {
+        conversion_price,
+        underlying_shares: d.bonds_outstanding * input.conversion_ratio,
+        if_converted_new_shares: physical.diluted_shares - d.basic_shares,
+        net_share_new_shares: net_share.diluted_shares - d.basic_shares,
+        if_converted_dilution_pct: physical.dilution_pct,
+        net_share_dilution_pct: net_share.dilution_pct,
+        fully_diluted_shares: fully_diluted.diluted_shares,
+    })
+}
+
 // ---------------------------------------------------------------------------
 // Public API
 // ---------------------------------------------------------------------------
@@ -573,6 +644,11 @@ pub fn analyze_convertible(
         asymmetry_ratio,
     };
 
+    let dilution = match &input.dilution {
+        Some(d) => Some(convertible_dilution(input, d)?),
+        None => None,
+    };
+
     let output = ConvertibleAnalysisOutput {
         bond_name: input.bond_name.clone(),
         current_conversion_value,
@@ -585,6 +661,7 @@ pub fn analyze_convertible(
         forced_conversion_analysis,
         income_advantage,
         risk_return_profile,
+        dilution,
     };
 
     let elapsed = start.elapsed().as_micros() as u64;
@@ -638,6 +715,7 @@ mod tests {
             stock_scenarios: vec![dec!(30), dec!(35), dec!(40), dec!(45), dec!(50), dec!(55)],
             vol_scenarios: None,
             spread_scenarios: None,
+            dilution: None,
         }
     }
 
@@ -919,4 +997,52 @@ mod tests {
         assert!(!result.metadata.version.is_empty());
         assert_eq!(result.metadata.precision, "rust_decimal_128bit");
     }
+
+    #[test]
+    fn test_dilution_physical_vs_net_share() {
+        let input = ConvertibleAnalysisInput {
+            stock_price: dec!(50),
+            dilution: Some(ConvertibleDilutionInput {
+                basic_shares: dec!(1000),
+                bonds_outstanding: dec!(4),
+                other_securities: vec![DilutiveSecurity {
+                    name: "ESOP".into(),
+                    kind: SecurityKind::Option,
+                    count: dec!(10),
+                    strike: dec!(25),
+                    shares_per_instrument: dec!(1),
+                    time_to_expiry: None,
+                }],
+            }),
+            ..default_analysis_input()
+        };
+        let result = analyze_convertible(&input).unwrap();
+        let d = result.result.dilution.unwrap();
+        // Conversion price 1000 / 25 = 40; 4 bonds x 25 = 100 shares
+        assert_eq!(d.conversion_price, dec!(40));
+        assert_eq!(d.if_converted_new_shares, dec!(100));
+        // Net share: 100 x (50 - 40) / 50 = 20
+        assert_eq!(d.net_share_new_shares, dec!(20));
+        assert_eq!(d.if_converted_dilution_pct, dec!(0.1));
+        // Options add 10 - 250 / 50 = 5
+        assert_eq!(d.fully_diluted_shares, dec!(1105));
+    }
+
+    #[test]
+    fn test_dilution_out_of_the_money() {
+        let input = ConvertibleAnalysisInput {
+            stock_price: dec!(30),
+            dilution: Some(ConvertibleDilutionInput {
+                basic_shares: dec!(1000),
+                bonds_outstanding: dec!(4),
+                other_securities: vec![],
+            }),
+            ..default_analysis_input()
+        };
+        let result = analyze_convertible(&input).unwrap();
+        let d = result.result.dilution.unwrap();
+        assert_eq!(d.if_converted_new_shares, Decimal::ZERO);
+        assert_eq!(d.net_share_new_shares, Decimal::ZERO);
+        assert_eq!(d.fully_diluted_shares, dec!(1000));
+    }
 }
@@ -0,0 +1,629 @@
+//! Calibrated CRR lattice that can be reused across related pricings.
+//!
+//! Building the tree (up/down factors, risk-neutral probability, discount
+//! factor and the stock price at every node) depends only on the market
+//! assumptions, not on the bond. A [`CalibratedLattice`] is built once for a
+//! curve, spread and vol and then prices any number of convertibles or
+//! callable/puttable bonds maturing within its horizon. Shorter maturities
+//! roll back from the nearest step on the same grid.
+//!
+//! A bond with a zero conversion ratio is a straight callable/puttable bond
+//! discounted at the risky rate.
+
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+use std::time::Instant;
+
+use super::pricing::{
+    compute_bond_floor, decimal_to_u32, exp_dec, is_coupon_period, pow_dec, sqrt_dec,
+};
+use crate::error::CorpFinanceError;
+use crate::types::*;
+use crate::CorpFinanceResult;
+
+// ---------------------------------------------------------------------------
+// Public types
+// ---------------------------------------------------------------------------
+
+/// Market assumptions a lattice is calibrated to.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LatticeMarketInput {
+    pub risk_free_rate: Rate,
+    /// Issuer credit spread; hold values discount at risk-free + spread
+    pub credit_spread: Rate,
+    pub stock_price: Money,
+    pub stock_volatility: Rate,
+    #[serde(default)]
+    pub dividend_yield: Option<Rate>,
+    /// Longest maturity the lattice can price
+    pub horizon_years: Decimal,
+    /// Steps to the horizon (default 100)
+    pub tree_steps: Option<u32>,
+}
+
+/// Bond-specific terms priced on a calibrated lattice.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LatticeBondTerms {
+    pub bond_name: String,
+    pub face_value: Money,
+    pub coupon_rate: Rate,
+    pub coupon_frequency: u32,
+    /// Must not exceed the lattice horizon; snapped to the nearest step
+    pub maturity_years: Decimal,
+    /// Shares per bond; zero prices a straight callable/puttable bond
+    pub conversion_ratio: Decimal,
+    pub call_price: Option<Money>,
+    pub call_protection_years: Option<Decimal>,
+    pub put_price: Option<Money>,
+    pub put_date_years: Option<Decimal>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LatticeBondPrice {
+    pub bond_name: String,
+    /// Lattice steps used to reach this bond's maturity
+    pub steps_used: u32,
+    pub model_price: Money,
+    pub bond_floor: Money,
+    pub conversion_value: Money,
+    pub conversion_premium_pct: Rate,
+    pub embedded_option_value: Money,
+    /// Read off the first-step nodes, so no tree is rebuilt
+    pub delta: Decimal,
+    /// Read off the second-step nodes
+    pub gamma: Decimal,
+}
+
+/// A book of bonds priced off one set of market assumptions.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConvertibleBookInput {
+    pub market: LatticeMarketInput,
+    pub bonds: Vec<LatticeBondTerms>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConvertibleBookOutput {
+    pub tree_steps: u32,
+    pub horizon_years: Decimal,
+    pub time_step_years: Decimal,
+    pub bonds: Vec<LatticeBondPrice>,
+}
+
+/// A CRR tree calibrated to one set of market assumptions.
+///
+/// Not serialisable: hosts hold it as an opaque handle and pass it back to
+/// [`CalibratedLattice::price`] for each bond.
+#[derive(Debug, Clone)]
+pub struct CalibratedLattice {
+    steps: u32,
+    horizon: Decimal,
+    dt: Decimal,
+    risky_rate: Rate,
+    p_up: Decimal,
+    disc: Decimal,
+    /// `stock_grid[k][i]` is the stock price after `k` steps with `i` up moves
+    stock_grid: Vec<Vec<Money>>,
+}
+
+/// Bond terms resolved for the roll-back.
+pub(crate) struct LatticeTerms {
+    pub face: Money,
+    pub coupon_rate: Rate,
+    pub freq: u32,
+    pub ratio: Decimal,
+    pub call_price: Option<Money>,
+    pub call_protection: Decimal,
+    pub put_price: Option<Money>,
+    pub put_date: Decimal,
+}
+
+/// Node values kept from a roll-back.
+pub(crate) struct RollBack {
+    pub root: Decimal,
+    pub step_one: Vec<Decimal>,
+    pub step_two: Vec<Decimal>,
+}
+
+// ---------------------------------------------------------------------------
+// Lattice
+// ---------------------------------------------------------------------------
+
+impl CalibratedLattice {
+    /// Validate the market assumptions and build the tree.
+    pub fn calibrate(input: &LatticeMarketInput) -> CorpFinanceResult<Self> {
+        if input.stock_price <= Decimal::ZERO {
+            return Err(CorpFinanceError::InvalidInput {
+                field: "stock_price".into(),
+                reason: "must be positive".into(),
+            });
+        }
+        if input.stock_volatility <= Decimal::ZERO {
+            return Err(CorpFinanceError::InvalidInput {
+                field: "stock_volatility".into(),
+                reason: "must be positive".into(),
+            });
+        }
+        if input.horizon_years <= Decimal::ZERO {
+            return Err(CorpFinanceError::InvalidInput {
+                field: "horizon_years".into(),
+                reason: "must be positive".into(),
+            });
+        }
+        let steps = input.tree_steps.unwrap_or(100);
+        if steps == 0 {
+            return Err(CorpFinanceError::InvalidInput {
+                field: "tree_steps".into(),
+                reason: "must be at least 1".into(),
+            });
+        }
+        let nodes = (u64::from(steps) + 1) * (u64::from(steps) + 2) / 2;
+        crate::limits::check_grid_cells("tree_steps", nodes)?;
+
+        Ok(Self::build(
+            input.stock_price,
+            input.stock_volatility,
+            input.risk_free_rate,
+            input.dividend_yield.unwrap_or(Decimal::ZERO),
+            input.credit_spread,
+            input.horizon_years,
+            steps,
+        ))
+    }
+
+    pub(crate) fn build(
+        stock: Money,
+        vol: Rate,
+        risk_free: Rate,
+        div_yield: Rate,
+        credit_spread: Rate,
+        horizon: Decimal,
+        steps: u32,
+    ) -> Self {
+        let dt = horizon / Decimal::from(steps);
+        let u = exp_dec(vol * sqrt_dec(dt));
+        let d = Decimal::ONE / u;
+        let r_dt = exp_dec((risk_free - div_yield) * dt);
+        let p_up = (r_dt - d) / (u - d);
+
+        let risky_rate = risk_free + credit_spread;
+        let disc = exp_dec(-risky_rate * dt);
+
+        let stock_grid = (0..=steps)
+            .map(|k| {
+                (0..=k)
+                    .map(|i| stock * pow_dec(u, i) * pow_dec(d, k - i))
+                    .collect()
+            })
+            .collect();
+
+        CalibratedLattice {
+            steps,
+            horizon,
+            dt,
+            risky_rate,
+            p_up,
+            disc,
+            stock_grid,
+        }
+    }
+
+    pub fn steps(&self) -> u32 {
+        self.steps
+    }
+
+    pub fn horizon_years(&self) -> Decimal {
+        self.horizon
+    }
+
+    pub fn time_step_years(&self) -> Decimal {
+        self.dt
+    }
+
+    /// Price one bond on the calibrated tree.
+    pub fn price(&self, terms: &LatticeBondTerms) -> CorpFinanceResult<LatticeBondPrice> {
+        validate_terms(terms)?;
+        if terms.maturity_years > self.horizon + self.dt / dec!(2) {
+            return Err(CorpFinanceError::InvalidInput {
+                field: "maturity_years".into(),
+                reason: format!(
+                    "{} exceeds the lattice horizon of {} years",
+                    terms.maturity_years, self.horizon
+                ),
+            });
+        }
+
+        let steps_used = decimal_to_u32(terms.maturity_years / self.dt).clamp(1, self.steps);
+        let maturity = Decimal::from(steps_used) * self.dt;
+        let resolved = LatticeTerms {
+            face: terms.face_value,
+            coupon_rate: terms.coupon_rate,
+            freq: terms.coupon_frequency,
+            ratio: terms.conversion_ratio,
+            call_price: terms.call_price,
+            call_protection: terms.call_protection_years.unwrap_or(Decimal::ZERO),
+            put_price: terms.put_price,
+            put_date: terms.put_date_years.unwrap_or(maturity),
+        };
+        let rolled = self.roll_back(&resolved, steps_used);
+        let model_price = rolled.root;
+
+        let bond_floor = compute_bond_floor(
+            terms.face_value,
+            terms.coupon_rate,
+            terms.coupon_frequency,
+            maturity,
+            self.risky_rate,
+        );
+        let conversion_value = self.stock_grid[0][0] * terms.conversion_ratio;
+        let conversion_premium_pct = if conversion_value > Decimal::ZERO {
+            (model_price - conversion_value) / conversion_value
+        } else {
+            Decimal::ZERO
+        };
+
+        let s1 = &self.stock_grid[1];
+        let delta = (rolled.step_one[1] - rolled.step_one[0]) / (s1[1] - s1[0]);
+        let gamma = if steps_used >= 2 {
+            let s2 = &self.stock_grid[2];
+            let v2 = &rolled.step_two;
+            let delta_up = (v2[2] - v2[1]) / (s2[2] - s2[1]);
+            let delta_down = (v2[1] - v2[0]) / (s2[1] - s2[0]);
+            (delta_up - delta_down) / ((s2[2] - s2[0]) / dec!(2))
+        } else {
+            Decimal::ZERO
+        };
+
+        Ok(LatticeBondPrice {
+            bond_name: terms.bond_name.clone(),
+            steps_used,
+            model_price,
+            bond_floor,
+            conversion_value,
+            conversion_premium_pct,
+            embedded_option_value: model_price - bond_floor,
+            delta,
+            gamma,
+        })
+    }
+
+    /// Backward induction from `steps` (at most the calibrated step count).
+    pub(crate) fn roll_back(&self, terms: &LatticeTerms, steps: u32) -> RollBack {
+        let n = steps;
+        let dt = self.dt;
+        let p_up = self.p_up;
+        let p_down = Decimal::ONE - p_up;
+        let coupon_per_period = terms.face * terms.coupon_rate / Decimal::from(terms.freq);
+        let coupon_interval = Decimal::ONE / Decimal::from(terms.freq);
+
+        // Terminal values: max(conversion_value, face + final_coupon)
+        let mut values: Vec<Decimal> = self.stock_grid[n as usize]
+            .iter()
+            .map(|s| (s * terms.ratio).max(terms.face + coupon_per_period))
+            .collect();
+        let mut step_one = Vec::new();
+        let mut step_two = Vec::new();
+
+        for step in (0..n).rev() {
+            let t_at_step = Decimal::from(step) * dt;
+            let coupon_at_step = is_coupon_period(t_at_step, dt, coupon_interval);
+            let grid = &self.stock_grid[step as usize];
+
+            for i in 0..=step as usize {
+                let hold = self.disc * (p_up * values[i + 1] + p_down * values[i]);
+                let hold_with_coupon = if coupon_at_step {
+                    hold + coupon_per_period
+                } else {
+                    hold
+                };
+                let conversion_val = grid[i] * terms.ratio;
+
+                let mut cb_val = hold_with_coupon.max(conversion_val);
+
+                // Holder puts at put_price once past the put date
+                if let Some(pp) = terms.put_price {
+                    if t_at_step >= terms.put_date {
+                        cb_val = cb_val.max(pp);
+                    }
+                }
+
+                // Issuer calls above call_price once protection ends; the
+                // holder can still convert
+                if let Some(cp) = terms.call_price {
+                    if t_at_step >= terms.call_protection && cb_val > cp {
+                        cb_val = cp.max(conversion_val);
+                    }
+                }
+
+                values[i] = cb_val;
+            }
+
+            match step {
+                2 => step_two = values[..3].to_vec(),
+                1 => step_one = values[..2].to_vec(),
+                _ => {}
+            }
+        }
+
+        RollBack {
+            root: values[0],
+            step_one,
+            step_two,
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Validation
+// ---------------------------------------------------------------------------
+
+fn validate_terms(terms: &LatticeBondTerms) -> CorpFinanceResult<()> {
+    if terms.face_value <= Decimal::ZERO {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "face_value".into(),
+            reason: "must be positive".into(),
+        });
+    }
+    if terms.conversion_ratio < Decimal::ZERO {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "conversion_ratio".into(),
+            reason: "cannot be negative".into(),
+        });
+    }
+    if terms.maturity_years <= Decimal::ZERO {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "maturity_years".into(),
+            reason: "must be positive".into(),
+        });
+    }
+    if terms.coupon_frequency != 1 && terms.coupon_frequency != 2 && terms.coupon_frequency != 4 {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "coupon_frequency".into(),
+            reason: "must be 1 (annual), 2 (semi-annual), or 4 (quarterly)".into(),
+        });
+    }
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Public API
+// ---------------------------------------------------------------------------
+
+/// Calibrate one lattice to the book's market assumptions and price every
+/// bond on it.
+pub fn price_convertible_book(
+    input: &ConvertibleBookInput,
+) -> CorpFinanceResult<ComputationOutput<ConvertibleBookOutput>> {
+    let start = Instant::now();
+    if input.bonds.is_empty() {
+        return Err(CorpFinanceError::InsufficientData(
+            "at least one bond is required".into(),
+        ));
+    }
+    let lattice = CalibratedLattice::calibrate(&input.market)?;
+
+    let mut warnings = Vec::new();
+    let mut bonds = Vec::with_capacity(input.bonds.len());
+    for terms in &input.bonds {
+        let priced = lattice.price(terms)?;
+        let snapped = Decimal::from(priced.steps_used) * lattice.dt;
+        if (snapped - terms.maturity_years).abs() > dec!(0.000001) {
+            warnings.push(format!(
+                "{}: maturity {} years snapped to {} years on the lattice grid",
+                terms.bond_name,
+                terms.maturity_years,
+                snapped.round_dp(6)
+            ));
+        }
+        bonds.push(priced);
+    }
+
+    let output = ConvertibleBookOutput {
+        tree_steps: lattice.steps,
+        horizon_years: lattice.horizon,
+        time_step_years: lattice.dt,
+        bonds,
+    };
+
+    let elapsed = start.elapsed().as_micros() as u64;
+    let assumptions = serde_json::json!({
+        "model": "CRR Binomial Tree, calibrated once per book",
+        "tree_steps": lattice.steps,
+        "horizon_years": lattice.horizon.to_string(),
+        "risk_free_rate": input.market.risk_free_rate.to_string(),
+        "credit_spread": input.market.credit_spread.to_string(),
+        "stock_volatility": input.market.stock_volatility.to_string(),
+        "bonds": input.bonds.len(),
+    });
+
+    Ok(with_metadata(
+        "CRR Binomial Tree for Convertible Bond Book",
+        &assumptions,
+        warnings,
+        elapsed,
+        output,
+    ))
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::convertibles::pricing::{price_convertible, ConvertibleBondInput};
+
+    fn market() -> LatticeMarketInput {
+        LatticeMarketInput {
+            risk_free_rate: dec!(0.05),
+            credit_spread: dec!(0.03),
+            stock_price: dec!(40),
+            stock_volatility: dec!(0.30),
+            dividend_yield: None,
+            horizon_years: dec!(5),
+            tree_steps: Some(50),
+        }
+    }
+
+    fn terms(name: &str, maturity: Decimal) -> LatticeBondTerms {
+        LatticeBondTerms {
+            bond_name: name.into(),
+            face_value: dec!(1000),
+            coupon_rate: dec!(0.04),
+            coupon_frequency: 2,
+            maturity_years: maturity,
+            conversion_ratio: dec!(25),
+            call_price: None,
+            call_protection_years: None,
+            put_price: None,
+            put_date_years: None,
+        }
+    }
+
+    fn standalone(t: &LatticeBondTerms) -> ConvertibleBondInput {
+        let m = market();
+        ConvertibleBondInput {
+            bond_name: t.bond_name.clone(),
+            face_value: t.face_value,
+            coupon_rate: t.coupon_rate,
+            coupon_frequency: t.coupon_frequency,
+            maturity_years: t.maturity_years,
+            credit_spread: m.credit_spread,
+            risk_free_rate: m.risk_free_rate,
+            stock_price: m.stock_price,
+            conversion_ratio: t.conversion_ratio,
+            stock_volatility: m.stock_volatility,
+            dividend_yield: m.dividend_yield,
+            call_price: t.call_price,
+            call_protection_years: t.call_protection_years,
+            put_price: t.put_price,
+            put_date_years: t.put_date_years,
+            tree_steps: m.tree_steps,
+        }
+    }
+
+    #[test]
+    fn test_matches_standalone_pricing_at_horizon() {
+        let lattice = CalibratedLattice::calibrate(&market()).unwrap();
+        let mut t = terms("CB-5Y", dec!(5));
+        t.call_price = Some(dec!(1100));
+        t.call_protection_years = Some(dec!(2));
+        let on_lattice = lattice.price(&t).unwrap();
+        let direct = price_convertible(&standalone(&t)).unwrap().result;
+
+        assert_eq!(on_lattice.steps_used, 50);
+        assert_eq!(on_lattice.model_price, direct.model_price);
+        assert_eq!(on_lattice.bond_floor, direct.bond_floor);
+    }
+
+    #[test]
+    fn test_shorter_maturity_reuses_grid() {
+        let lattice = CalibratedLattice::calibrate(&market()).unwrap();
+        let short = lattice.price(&terms("CB-3Y", dec!(3))).unwrap();
+        assert_eq!(short.steps_used, 30);
+
+        // Same dt as a 30-step, 3-year standalone tree
+        let mut direct_input = standalone(&terms("CB-3Y", dec!(3)));
+        direct_input.tree_steps = Some(30);
+        let direct = price_convertible(&direct_input).unwrap().result;
+        assert_eq!(short.model_price, direct.model_price);
+    }
+
+    #[test]
+    fn test_repeated_pricing_is_stable() {
+        let lattice = CalibratedLattice::calibrate(&market()).unwrap();
+        let a = lattice.price(&terms("A", dec!(4))).unwrap();
+        let _ = lattice.price(&terms("B", dec!(2))).unwrap();
+        let a_again = lattice.price(&terms("A", dec!(4))).unwrap();
+        assert_eq!(a.model_price, a_again.model_price);
+    }
+
+    #[test]
+    fn test_tree_greeks_close_to_bumped_greeks() {
+        let lattice = CalibratedLattice::calibrate(&market()).unwrap();
+        let t = terms("CB-5Y", dec!(5));
+        let on_lattice = lattice.price(&t).unwrap();
+        let direct = price_convertible(&standalone(&t)).unwrap().result;
+        assert!(
+            (on_lattice.delta - direct.delta).abs() < dec!(2),
+            "tree delta {} vs bumped delta {}",
+            on_lattice.delta,
+            direct.delta
+        );
+        assert!(on_lattice.delta > Decimal::ZERO && on_lattice.delta <= dec!(25));
+        assert!(on_lattice.gamma >= Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_callable_straight_bond() {
+        let lattice = CalibratedLattice::calibrate(&market()).unwrap();
+        let mut straight = terms("CALLABLE", dec!(5));
+        straight.conversion_ratio = Decimal::ZERO;
+        straight.coupon_rate = dec!(0.10);
+        let non_call = lattice.price(&straight).unwrap();
+
+        straight.call_price = Some(dec!(1000));
+        straight.call_protection_years = Some(dec!(1));
+        let callable = lattice.price(&straight).unwrap();
+
+        assert_eq!(non_call.conversion_value, Decimal::ZERO);
+        assert_eq!(callable.delta, Decimal::ZERO);
+        assert!(
+            callable.model_price < non_call.model_price,
+            "call should cap value: {} vs {}",
+            callable.model_price,
+            non_call.model_price
+        );
+        assert!(callable.model_price <= dec!(1000) + dec!(50));
+    }
+
+    #[test]
+    fn test_maturity_beyond_horizon_rejected() {
+        let lattice = CalibratedLattice::calibrate(&market()).unwrap();
+        let err = lattice.price(&terms("LONG", dec!(7))).unwrap_err();
+        match err {
+            CorpFinanceError::InvalidInput { field, .. } => assert_eq!(field, "maturity_years"),
+            other => panic!("unexpected error {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_invalid_market_rejected() {
+        let mut m = market();
+        m.tree_steps = Some(0);
+        assert!(CalibratedLattice::calibrate(&m).is_err());
+        let mut m = market();
+        m.stock_volatility = Decimal::ZERO;
+        assert!(CalibratedLattice::calibrate(&m).is_err());
+    }
+
+    #[test]
+    fn test_book_pricing_and_snap_warning() {
+        let input = ConvertibleBookInput {
+            market: market(),
+            bonds: vec![
+                terms("CB-5Y", dec!(5)),
+                terms("CB-3Y", dec!(3)),
+                terms("CB-ODD", dec!(2.33)),
+            ],
+        };
+        let result = price_convertible_book(&input).unwrap();
+        let out = &result.result;
+        assert_eq!(out.bonds.len(), 3);
+        assert_eq!(out.time_step_years, dec!(0.1));
+        assert_eq!(out.bonds[2].steps_used, 23);
+        assert_eq!(result.warnings.len(), 1);
+        assert!(result.warnings[0].contains("CB-ODD"));
+        assert!(out.bonds[0].model_price > out.bonds[0].bond_floor);
+    }
+
+    #[test]
+    fn test_empty_book_rejected() {
+        let input = ConvertibleBookInput {
+            market: market(),
+            bonds: vec![],
+        };
+        assert!(price_convertible_book(&input).is_err());
+    }
+}
@@ -3,6 +3,7 @@ use rust_decimal_macros::dec;
 use serde::{Deserialize, Serialize};
 use std::time::Instant;
 
+use super::lattice::{CalibratedLattice, LatticeTerms};
 use crate::error::CorpFinanceError;
 use crate::types::*;
 use crate::CorpFinanceResult;
@@ -56,7 +57,7 @@ pub struct ConvertibleBondOutput {
 // ---------------------------------------------------------------------------
 
 /// Taylor series exp(x) with range reduction for |x| > 2.
-fn exp_dec(x: Decimal) -> Decimal {
+pub(crate) fn exp_dec(x: Decimal) -> Decimal {
     let two = dec!(2);
     if x > two || x < -two {
         let half = exp_dec(x / two);
@@ -72,7 +73,7 @@ fn exp_dec(x: Decimal) -> Decimal {
 }
 
 /// Newton's method sqrt, 25 iterations.
-fn sqrt_dec(x: Decimal) -> Decimal {
+pub(crate) fn sqrt_dec(x: Decimal) -> Decimal {
     if x <= Decimal::ZERO {
         return Decimal::ZERO;
     }
@@ -94,7 +95,7 @@ fn sqrt_dec(x: Decimal) -> Decimal {
 }
 
 /// Integer power by squaring (avoids powd precision drift).
-fn pow_dec(base: Decimal, exp: u32) -> Decimal {
+pub(crate) fn pow_dec(base: Decimal, exp: u32) -> Decimal {
     if exp == 0 {
         return Decimal::ONE;
     }
@@ -159,7 +160,7 @@ fn validate_input(input: &ConvertibleBondInput) -> CorpFinanceResult<()> {
 // Bond floor: PV of coupons + principal at risky rate
 // ---------------------------------------------------------------------------
 
-fn compute_bond_floor(
+pub(crate) fn compute_bond_floor(
     face: Decimal,
     coupon_rate: Rate,
     freq: u32,
@@ -187,7 +188,7 @@ fn compute_bond_floor(
 }
 
 /// Convert a Decimal to u32 (rounded).
-fn decimal_to_u32(d: Decimal) -> u32 {
+pub(crate) fn decimal_to_u32(d: Decimal) -> u32 {
     let rounded = d.round();
     // Extract the integer part
     let s = rounded.to_string();
@@ -216,86 +217,30 @@ fn binomial_cb_price(
     put_date: Decimal,
     steps: u32,
 ) -> Decimal {
-    let n = steps;
-    let dt = maturity / Decimal::from(n);
-    let u = exp_dec(vol * sqrt_dec(dt));
-    let d = Decimal::ONE / u;
-    let r_dt = exp_dec((risk_free - div_yield) * dt);
-    let p_up = (r_dt - d) / (u - d);
-    let p_down = Decimal::ONE - p_up;
-
-    // Discount factor per step at risky rate (for hold value discounting)
-    let risky_rate = risk_free + credit_spread;
-    let disc = exp_dec(-risky_rate * dt);
-
-    // Coupon per period
-    let coupon_per_period = face * coupon_rate / Decimal::from(freq);
-    // Time between coupon payments in years
-    let coupon_interval = Decimal::ONE / Decimal::from(freq);
-
-    let size = (n + 1) as usize;
-
-    // Terminal values: max(conversion_value, face + final_coupon)
-    let mut values = Vec::with_capacity(size);
-    for i in 0..size {
-        let stock_at_node = stock * pow_dec(u, i as u32) * pow_dec(d, n - i as u32);
-        let conversion_val = stock_at_node * ratio;
-        let bond_val = face + coupon_per_period; // final coupon + par
-        values.push(conversion_val.max(bond_val));
-    }
-
-    // Backward induction
-    for step in (0..n).rev() {
-        let t_at_step = Decimal::from(step) * dt;
-        let step_size = (step + 1) as usize;
-
-        // Check if a coupon is paid in this period
-        // A coupon is paid if there is a coupon date in (t_at_step, t_at_step + dt]
-        let coupon_at_step = is_coupon_period(t_at_step, dt, coupon_interval);
-
-        for i in 0..step_size {
-            // Hold value: discounted expected value
-            let hold = disc * (p_up * values[i + 1] + p_down * values[i]);
-            // Add accrued coupon if this period has a payment
-            let hold_with_coupon = if coupon_at_step {
-                hold + coupon_per_period
-            } else {
-                hold
-            };
-
-            // Conversion value at this node
-            let stock_at_node = stock * pow_dec(u, i as u32) * pow_dec(d, step - i as u32);
-            let conversion_val = stock_at_node * ratio;
-
-            // CB value = max(conversion, hold)
-            let mut cb_val = hold_with_coupon.max(conversion_val);
-
-            // Put provision: holder can put at put_price if past put_date
-            if let Some(pp) = put_price {
-                if t_at_step >= put_date {
-                    cb_val = cb_val.max(pp);
-                }
-            }
-
-            // Call provision: issuer can call at call_price if past call protection
-            if let Some(cp) = call_price {
-                if t_at_step >= call_protection {
-                    // Issuer calls only if CB > call_price, but holder can still convert
-                    if cb_val > cp {
-                        cb_val = cp.max(conversion_val);
-                    }
-                }
-            }
-
-            values[i] = cb_val;
-        }
-    }
-
-    values[0]
+    let lattice = CalibratedLattice::build(
+        stock,
+        vol,
+        risk_free,
+        div_yield,
+        credit_spread,
+        maturity,
+        steps,
+    );
+    let terms = LatticeTerms {
+        face,
+        coupon_rate,
+        freq,
+        ratio,
+        call_price,
+        call_protection,
+        put_price,
+        put_date,
+    };
+    lattice.roll_back(&terms, steps).root
 }
 
 /// Check if a coupon payment falls within the period (t, t + dt].
-fn is_coupon_period(t: Decimal, dt: Decimal, interval: Decimal) -> bool {
+pub(crate) fn is_coupon_period(t: Decimal, dt: Decimal, interval: Decimal) -> bool {
     if interval <= Decimal::ZERO {
         return false;
     }
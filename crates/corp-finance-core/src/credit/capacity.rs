@@ -50,6 +50,134 @@ pub struct DebtCapacityOutput {
     pub implied_coverage_at_max: Multiple,
 }
 
+/// A debt facility in the maturity schedule.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DebtFacility {
+    pub name: String,
+    pub principal: Money,
+    pub interest_rate: Rate,
+    /// Projection year in which the facility matures (1 = end of year 1).
+    pub maturity_year: u32,
+    /// Scheduled annual amortisation before maturity.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub annual_amortisation: Option<Money>,
+}
+
+/// How maturing debt is refinanced.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RefinancingAssumptions {
+    /// All-in coupon on refinancing debt.
+    pub interest_rate: Rate,
+    /// Tenor of refinancing debt in years.
+    pub tenor_years: u32,
+    /// Share of each maturity the borrower seeks to refinance (default 1);
+    /// the rest is repaid from cash.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refinance_pct: Option<Rate>,
+    /// Upfront fees as a share of the amount refinanced, paid from cash.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fee_pct: Option<Rate>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DebtMaturityInput {
+    pub facilities: Vec<DebtFacility>,
+    /// Year 1 EBITDA.
+    pub ebitda: Money,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ebitda_growth: Option<Rate>,
+    /// Share of EBITDA available for debt service after tax, capex and
+    /// working capital.
+    pub cash_flow_conversion: Rate,
+    #[serde(default)]
+    pub opening_cash: Money,
+    /// Cash kept on balance sheet and not used to repay maturities.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub minimum_cash: Option<Money>,
+    pub refinancing: RefinancingAssumptions,
+    /// Maximum tolerable debt / EBITDA multiple.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_leverage: Option<Multiple>,
+    /// Minimum acceptable interest-coverage ratio.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_interest_coverage: Option<Multiple>,
+    /// Minimum acceptable debt-service coverage ratio.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_dscr: Option<Multiple>,
+    pub projection_years: u32,
+}
+
+/// Debt, cash flow and capacity for one projection year.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DebtMaturityYear {
+    pub year: u32,
+    pub ebitda: Money,
+    pub opening_debt: Money,
+    pub interest_expense: Money,
+    pub scheduled_amortisation: Money,
+    /// Principal falling due at maturity.
+    pub maturities: Money,
+    pub refinanced: Money,
+    pub repaid_from_cash: Money,
+    /// Maturities that could be neither refinanced nor repaid from cash.
+    pub funding_gap: Money,
+    pub closing_debt: Money,
+    pub closing_cash: Money,
+    /// Debt the constraints support this year, at the refinancing rate for
+    /// new money.
+    pub debt_capacity: Money,
+    pub binding_constraint: String,
+    /// Debt capacity less closing debt (negative when capacity binds).
+    pub headroom: Money,
+    pub leverage: Multiple,
+    pub interest_coverage: Option<Multiple>,
+    pub dscr: Option<Multiple>,
+    /// `true` when refinancing was cut back or closing debt exceeds capacity.
+    pub capacity_binds: bool,
+}
+
+/// Contractual principal falling due in one year.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MaturityBucket {
+    pub year: u32,
+    pub principal: Money,
+    pub pct_of_total: Rate,
+    pub cumulative_pct: Rate,
+    pub facilities: Vec<String>,
+}
+
+/// Outcome of one facility reaching maturity.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RefinancingEvent {
+    pub year: u32,
+    pub facility: String,
+    pub maturing: Money,
+    pub refinanced: Money,
+    pub repaid_from_cash: Money,
+    pub funding_gap: Money,
+    /// Maturity year of the replacement facility.
+    pub new_maturity_year: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DebtMaturityOutput {
+    pub years: Vec<DebtMaturityYear>,
+    /// Contractual maturity profile of the opening facilities, including
+    /// maturities beyond the projection.
+    pub maturity_wall: Vec<MaturityBucket>,
+    pub refinancings: Vec<RefinancingEvent>,
+    pub opening_debt: Money,
+    /// Principal-weighted average years to maturity of the opening debt.
+    pub weighted_average_maturity: Years,
+    pub weighted_average_rate: Rate,
+    /// Year with the largest contractual maturity.
+    pub peak_maturity_year: Option<u32>,
+    pub peak_maturity_pct: Rate,
+    /// First year in which capacity binds.
+    pub first_binding_year: Option<u32>,
+    pub total_funding_gap: Money,
+}
+
 // ---------------------------------------------------------------------------
 // Public API
 // ---------------------------------------------------------------------------
@@ -193,10 +321,374 @@ pub fn calculate_debt_capacity(
     ))
 }
 
+/// Project a debt stack year by year: scheduled amortisation, maturities,
+/// refinancing within the capacity constraints, and repayment of whatever
+/// cannot be refinanced from cash flow. Reports the contractual maturity wall
+/// and the years in which capacity binds.
+///
+/// Capacity for refinancing is sized at the margin: the new money that keeps
+/// leverage, interest coverage and DSCR within limits given the debt left in
+/// place and its interest and amortisation. Maturities that can be neither
+/// refinanced nor repaid leave a funding gap; the debt is removed from the
+/// schedule so later years show the position if the gap were filled with
+/// equity.
+#[tracing::instrument(
+    level = "debug",
+    name = "debt_maturities",
+    skip_all,
+    fields(facilities = input.facilities.len(), years = input.projection_years)
+)]
+pub fn analyze_debt_maturities(
+    input: &DebtMaturityInput,
+) -> CorpFinanceResult<ComputationOutput<DebtMaturityOutput>> {
+    let start = Instant::now();
+    let mut warnings: Vec<String> = Vec::new();
+
+    validate_maturity_input(input)?;
+
+    let zero = Decimal::ZERO;
+    let one = dec!(1);
+    let refi = &input.refinancing;
+    let refinance_pct = refi.refinance_pct.unwrap_or(one);
+    let fee_pct = refi.fee_pct.unwrap_or(zero);
+    let minimum_cash = input.minimum_cash.unwrap_or(zero);
+
+    // -- Maturity wall ----------------------------------------------------------
+    let opening_debt: Money = input.facilities.iter().map(|f| f.principal).sum();
+    let maturity_wall = build_maturity_wall(&input.facilities, opening_debt);
+    let (weighted_average_maturity, weighted_average_rate) = if opening_debt > zero {
+        (
+            input
+                .facilities
+                .iter()
+                .map(|f| f.principal * Decimal::from(f.maturity_year))
+                .sum::<Decimal>()
+                / opening_debt,
+            input
+                .facilities
+                .iter()
+                .map(|f| f.principal * f.interest_rate)
+                .sum::<Decimal>()
+                / opening_debt,
+        )
+    } else {
+        (zero, zero)
+    };
+    let peak = maturity_wall
+        .iter()
+        .max_by(|a, b| a.principal.cmp(&b.principal).then(b.year.cmp(&a.year)));
+    let peak_maturity_year = peak.map(|b| b.year);
+    let peak_maturity_pct = peak.map(|b| b.pct_of_total).unwrap_or(zero);
+    if peak_maturity_pct > dec!(0.5) && input.facilities.len() > 1 {
+        warnings.push(format!(
+            "{:.0}% of debt matures in year {}; maturity wall concentration",
+            peak_maturity_pct * dec!(100),
+            peak_maturity_year.unwrap_or_default()
+        ));
+    }
+
+    // -- Year-by-year schedule ------------------------------------------------
+    let mut tranches: Vec<DebtFacility> = input.facilities.clone();
+    let mut cash = input.opening_cash;
+    let mut ebitda = input.ebitda;
+    let mut years = Vec::with_capacity(input.projection_years as usize);
+    let mut refinancings = Vec::new();
+
+    for year in 1..=input.projection_years {
+        if year > 1 {
+            ebitda *= one + input.ebitda_growth.unwrap_or(zero);
+        }
+        let opening: Money = tranches.iter().map(|t| t.principal).sum();
+        let interest: Money = tranches.iter().map(|t| t.principal * t.interest_rate).sum();
+
+        // Scheduled amortisation ahead of maturity
+        let mut scheduled = zero;
+        for t in tranches.iter_mut().filter(|t| t.maturity_year > year) {
+            let amort = t.annual_amortisation.unwrap_or(zero).min(t.principal);
+            t.principal -= amort;
+            scheduled += amort;
+        }
+
+        cash += ebitda * input.cash_flow_conversion - interest - scheduled;
+
+        // Maturities
+        let (maturing, remaining): (Vec<DebtFacility>, Vec<DebtFacility>) = tranches
+            .into_iter()
+            .filter(|t| t.principal > zero)
+            .partition(|t| t.maturity_year <= year);
+        tranches = remaining;
+        let maturities: Money = maturing.iter().map(|t| t.principal).sum();
+
+        let other_debt: Money = tranches.iter().map(|t| t.principal).sum();
+        let other_interest: Money = tranches.iter().map(|t| t.principal * t.interest_rate).sum();
+        let other_amortisation: Money = tranches
+            .iter()
+            .filter(|t| t.maturity_year > year + 1)
+            .map(|t| t.annual_amortisation.unwrap_or(zero).min(t.principal))
+            .sum();
+        let (new_money_capacity, binding_constraint) = marginal_capacity(
+            input,
+            ebitda,
+            other_debt,
+            other_interest,
+            other_amortisation,
+        );
+
+        let sought = maturities * refinance_pct;
+        let refinanced = sought.min(new_money_capacity.max(zero));
+        let to_repay = maturities - refinanced;
+        let fees = refinanced * fee_pct;
+        cash -= fees;
+        let repaid_from_cash = to_repay.min((cash - minimum_cash).max(zero));
+        cash -= repaid_from_cash;
+        let funding_gap = to_repay - repaid_from_cash;
+        let refinancing_cut_back = refinanced < sought;
+
+        let new_maturity_year = year + refi.tenor_years;
+        if refinanced > zero {
+            tranches.push(DebtFacility {
+                name: format!("Refinancing {year}"),
+                principal: refinanced,
+                interest_rate: refi.interest_rate,
+                maturity_year: new_maturity_year,
+                annual_amortisation: None,
+            });
+        }
+        for t in &maturing {
+            let share = if maturities > zero {
+                t.principal / maturities
+            } else {
+                zero
+            };
+            refinancings.push(RefinancingEvent {
+                year,
+                facility: t.name.clone(),
+                maturing: t.principal,
+                refinanced: refinanced * share,
+                repaid_from_cash: repaid_from_cash * share,
+                funding_gap: funding_gap * share,
+                new_maturity_year: (refinanced > zero).then_some(new_maturity_year),
+            });
+        }
+        if funding_gap > zero {
+            warnings.push(format!(
+                "Year {year}: {funding_gap:.0} of maturities can be neither refinanced within \
+                 the {binding_constraint} constraint nor repaid from cash"
+            ));
+        }
+
+        let closing_debt = other_debt + refinanced;
+        let debt_capacity = other_debt + new_money_capacity;
+        let headroom = debt_capacity - closing_debt;
+        let debt_service = interest + scheduled;
+        years.push(DebtMaturityYear {
+            year,
+            ebitda,
+            opening_debt: opening,
+            interest_expense: interest,
+            scheduled_amortisation: scheduled,
+            maturities,
+            refinanced,
+            repaid_from_cash,
+            funding_gap,
+            closing_debt,
+            closing_cash: cash,
+            debt_capacity,
+            binding_constraint,
+            headroom,
+            leverage: closing_debt / ebitda,
+            interest_coverage: (interest > zero).then(|| ebitda / interest),
+            dscr: (debt_service > zero).then(|| ebitda / debt_service),
+            capacity_binds: refinancing_cut_back || headroom < zero,
+        });
+    }
+
+    if cash < zero {
+        warnings
+            .push("Cash turns negative: debt service exceeds cash flow before maturities".into());
+    }
+
+    let first_binding_year = years.iter().find(|y| y.capacity_binds).map(|y| y.year);
+    let total_funding_gap = years.iter().map(|y| y.funding_gap).sum();
+
+    let output = DebtMaturityOutput {
+        years,
+        maturity_wall,
+        refinancings,
+        opening_debt,
+        weighted_average_maturity,
+        weighted_average_rate,
+        peak_maturity_year,
+        peak_maturity_pct,
+        first_binding_year,
+        total_funding_gap,
+    };
+
+    let elapsed = start.elapsed().as_micros() as u64;
+    let assumptions = serde_json::json!({
+        "refinancing_rate": refi.interest_rate.to_string(),
+        "refinancing_tenor_years": refi.tenor_years,
+        "refinance_pct": refinance_pct.to_string(),
+        "interest": "on opening balances",
+        "funding_gap": "removed from the schedule as if equity-funded",
+    });
+
+    Ok(with_metadata(
+        "Debt Maturity Schedule with Refinancing Capacity",
+        &assumptions,
+        warnings,
+        elapsed,
+        output,
+    ))
+}
+
 // ---------------------------------------------------------------------------
 // Internal helpers
 // ---------------------------------------------------------------------------
 
+/// New debt at the refinancing rate that keeps every constraint satisfied,
+/// given the debt already in place, and the constraint that limits it.
+fn marginal_capacity(
+    input: &DebtMaturityInput,
+    ebitda: Money,
+    other_debt: Money,
+    other_interest: Money,
+    other_amortisation: Money,
+) -> (Money, String) {
+    let rate = input.refinancing.interest_rate;
+    let mut candidates: Vec<(&str, Money)> = Vec::new();
+    if let Some(ml) = input.max_leverage {
+        candidates.push(("max_leverage", ebitda * ml - other_debt));
+    }
+    if !rate.is_zero() {
+        if let Some(mc) = input.min_interest_coverage {
+            candidates.push((
+                "min_interest_coverage",
+                (ebitda / mc - other_interest) / rate,
+            ));
+        }
+        if let Some(md) = input.min_dscr {
+            candidates.push((
+                "min_dscr",
+                (ebitda / md - other_interest - other_amortisation) / rate,
+            ));
+        }
+    }
+    match candidates.into_iter().min_by_key(|(_, v)| *v) {
+        Some((name, v)) => (v, name.to_string()),
+        // Only coverage constraints at a zero refinancing rate: unconstrained
+        None => (Decimal::MAX / dec!(2), "none".to_string()),
+    }
+}
+
+fn build_maturity_wall(facilities: &[DebtFacility], total: Money) -> Vec<MaturityBucket> {
+    let mut by_year: std::collections::BTreeMap<u32, (Money, Vec<String>)> =
+        std::collections::BTreeMap::new();
+    for f in facilities {
+        let entry = by_year.entry(f.maturity_year).or_default();
+        entry.0 += f.principal;
+        entry.1.push(f.name.clone());
+    }
+    let mut cumulative = Decimal::ZERO;
+    by_year
+        .into_iter()
+        .map(|(year, (principal, facilities))| {
+            let pct_of_total = if total > Decimal::ZERO {
+                principal / total
+            } else {
+                Decimal::ZERO
+            };
+            cumulative += pct_of_total;
+            MaturityBucket {
+                year,
+                principal,
+                pct_of_total,
+                cumulative_pct: cumulative,
+                facilities,
+            }
+        })
+        .collect()
+}
+
+fn validate_maturity_input(input: &DebtMaturityInput) -> CorpFinanceResult<()> {
+    if input.ebitda <= Decimal::ZERO {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "ebitda".into(),
+            reason: "EBITDA must be positive for debt capacity sizing.".into(),
+        });
+    }
+    if input.projection_years == 0 {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "projection_years".into(),
+            reason: "Projection must cover at least one year.".into(),
+        });
+    }
+    crate::limits::check_schedule_periods("projection_years", input.projection_years.into())?;
+    if input.facilities.is_empty() {
+        return Err(CorpFinanceError::InsufficientData(
+            "At least one debt facility is required.".into(),
+        ));
+    }
+    if input.max_leverage.is_none()
+        && input.min_interest_coverage.is_none()
+        && input.min_dscr.is_none()
+    {
+        return Err(CorpFinanceError::InsufficientData(
+            "At least one capacity constraint must be provided.".into(),
+        ));
+    }
+    for (field, value) in [
+        ("max_leverage", input.max_leverage),
+        ("min_interest_coverage", input.min_interest_coverage),
+        ("min_dscr", input.min_dscr),
+    ] {
+        if value.is_some_and(|v| v <= Decimal::ZERO) {
+            return Err(CorpFinanceError::InvalidInput {
+                field: field.into(),
+                reason: "Constraint thresholds must be positive.".into(),
+            });
+        }
+    }
+    for f in &input.facilities {
+        if f.principal < Decimal::ZERO
+            || f.interest_rate < Decimal::ZERO
+            || f.annual_amortisation.is_some_and(|a| a < Decimal::ZERO)
+        {
+            return Err(CorpFinanceError::InvalidInput {
+                field: "facilities".into(),
+                reason: format!(
+                    "Facility '{}' has a negative principal, rate or amortisation.",
+                    f.name
+                ),
+            });
+        }
+        if f.maturity_year == 0 {
+            return Err(CorpFinanceError::InvalidInput {
+                field: "facilities.maturity_year".into(),
+                reason: format!("Facility '{}' must mature in year 1 or later.", f.name),
+            });
+        }
+    }
+    let refi = &input.refinancing;
+    if refi.interest_rate < Decimal::ZERO || refi.tenor_years == 0 {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "refinancing".into(),
+            reason: "Refinancing needs a non-negative rate and a tenor of at least one year."
+                .into(),
+        });
+    }
+    if refi
+        .refinance_pct
+        .is_some_and(|p| p < Decimal::ZERO || p > dec!(1))
+    {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "refinancing.refinance_pct".into(),
+            reason: "Refinance percentage must be between 0 and 1.".into(),
+        });
+    }
+    Ok(())
+}
+
 fn validate_input(input: &DebtCapacityInput) -> CorpFinanceResult<()> {
     if input.ebitda <= Decimal::ZERO {
         return Err(CorpFinanceError::InvalidInput {
@@ -418,4 +910,183 @@ mod tests {
         assert!(!result.methodology.is_empty());
         assert_eq!(result.metadata.precision, "rust_decimal_128bit");
     }
+
+    // -----------------------------------------------------------------------
+    // Debt maturity schedule
+    // -----------------------------------------------------------------------
+
+    fn facility(name: &str, principal: Money, rate: Rate, maturity_year: u32) -> DebtFacility {
+        DebtFacility {
+            name: name.into(),
+            principal,
+            interest_rate: rate,
+            maturity_year,
+            annual_amortisation: None,
+        }
+    }
+
+    fn maturity_input() -> DebtMaturityInput {
+        DebtMaturityInput {
+            facilities: vec![facility("Term Loan", dec!(300), dec!(0.05), 2)],
+            ebitda: dec!(100),
+            ebitda_growth: None,
+            cash_flow_conversion: dec!(0.5),
+            opening_cash: Decimal::ZERO,
+            minimum_cash: None,
+            refinancing: RefinancingAssumptions {
+                interest_rate: dec!(0.07),
+                tenor_years: 5,
+                refinance_pct: None,
+                fee_pct: None,
+            },
+            max_leverage: Some(dec!(5)),
+            min_interest_coverage: None,
+            min_dscr: None,
+            projection_years: 4,
+        }
+    }
+
+    #[test]
+    fn test_maturity_refinanced_within_capacity() {
+        let out = analyze_debt_maturities(&maturity_input()).unwrap().result;
+        let y2 = &out.years[1];
+        assert_eq!(y2.maturities, dec!(300));
+        assert_eq!(y2.refinanced, dec!(300));
+        assert_eq!(y2.funding_gap, Decimal::ZERO);
+        assert_eq!(y2.debt_capacity, dec!(500));
+        assert_eq!(y2.headroom, dec!(200));
+        assert!(!y2.capacity_binds);
+        assert_eq!(out.first_binding_year, None);
+        assert_eq!(out.refinancings.len(), 1);
+        assert_eq!(out.refinancings[0].new_maturity_year, Some(7));
+        // Year 3 interest is on the refinancing tranche at 7%
+        assert_eq!(out.years[2].interest_expense, dec!(21));
+    }
+
+    #[test]
+    fn test_capacity_binds_and_funding_gap() {
+        let mut input = maturity_input();
+        input.max_leverage = Some(dec!(2));
+        let out = analyze_debt_maturities(&input).unwrap();
+        let r = &out.result;
+        // Cash: year 1 = 50 - 15 = 35; year 2 = 35 + 50 - 15 = 70
+        let y2 = &r.years[1];
+        assert_eq!(y2.refinanced, dec!(200));
+        assert_eq!(y2.repaid_from_cash, dec!(70));
+        assert_eq!(y2.funding_gap, dec!(30));
+        assert_eq!(y2.closing_cash, Decimal::ZERO);
+        assert!(y2.capacity_binds);
+        assert_eq!(y2.binding_constraint, "max_leverage");
+        // Year 1 already carries more than 2x leverage
+        assert_eq!(r.first_binding_year, Some(1));
+        assert_eq!(r.total_funding_gap, dec!(30));
+        assert!(out
+            .warnings
+            .iter()
+            .any(|w| w.contains("neither refinanced")));
+    }
+
+    #[test]
+    fn test_maturity_wall_profile() {
+        let mut input = maturity_input();
+        input.facilities = vec![
+            facility("RCF", dec!(100), dec!(0.04), 1),
+            facility("Term Loan B", dec!(600), dec!(0.06), 3),
+            facility("Notes A", dec!(200), dec!(0.05), 3),
+            facility("Notes B", dec!(100), dec!(0.055), 8),
+        ];
+        input.max_leverage = Some(dec!(12));
+        let out = analyze_debt_maturities(&input).unwrap();
+        let r = &out.result;
+        assert_eq!(r.opening_debt, dec!(1000));
+        assert_eq!(r.maturity_wall.len(), 3);
+        assert_eq!(r.maturity_wall[1].principal, dec!(800));
+        assert_eq!(r.maturity_wall[1].facilities.len(), 2);
+        assert_eq!(r.maturity_wall[2].cumulative_pct, dec!(1));
+        assert_eq!(r.peak_maturity_year, Some(3));
+        assert_eq!(r.peak_maturity_pct, dec!(0.8));
+        // WAM = (100*1 + 800*3 + 100*8) / 1000 = 3.3
+        assert_eq!(r.weighted_average_maturity, dec!(3.3));
+        // Beyond-horizon maturities are in the wall but not refinanced
+        assert!(r.refinancings.iter().all(|e| e.facility != "Notes B"));
+        assert!(out.warnings.iter().any(|w| w.contains("concentration")));
+    }
+
+    #[test]
+    fn test_scheduled_amortisation_before_maturity() {
+        let mut input = maturity_input();
+        input.facilities[0].annual_amortisation = Some(dec!(50));
+        let out = analyze_debt_maturities(&input).unwrap().result;
+        assert_eq!(out.years[0].scheduled_amortisation, dec!(50));
+        assert_eq!(out.years[0].closing_debt, dec!(250));
+        // No amortisation in the maturity year: the balance matures instead
+        assert_eq!(out.years[1].scheduled_amortisation, Decimal::ZERO);
+        assert_eq!(out.years[1].maturities, dec!(250));
+    }
+
+    #[test]
+    fn test_partial_refinancing_and_fees() {
+        let mut input = maturity_input();
+        input.opening_cash = dec!(200);
+        input.refinancing.refinance_pct = Some(dec!(0.5));
+        input.refinancing.fee_pct = Some(dec!(0.02));
+        let out = analyze_debt_maturities(&input).unwrap().result;
+        let y2 = &out.years[1];
+        assert_eq!(y2.refinanced, dec!(150));
+        assert_eq!(y2.repaid_from_cash, dec!(150));
+        // 200 + 2 * (50 - 15) - 3 fees - 150 repaid
+        assert_eq!(y2.closing_cash, dec!(117));
+        assert!(!y2.capacity_binds);
+    }
+
+    #[test]
+    fn test_minimum_cash_protected() {
+        let mut input = maturity_input();
+        input.refinancing.refinance_pct = Some(Decimal::ZERO);
+        input.minimum_cash = Some(dec!(20));
+        let out = analyze_debt_maturities(&input).unwrap().result;
+        let y2 = &out.years[1];
+        assert_eq!(y2.repaid_from_cash, dec!(50));
+        assert_eq!(y2.closing_cash, dec!(20));
+        assert_eq!(y2.funding_gap, dec!(250));
+    }
+
+    #[test]
+    fn test_coverage_constraint_binds_at_margin() {
+        let mut input = maturity_input();
+        input
+            .facilities
+            .push(facility("Notes", dec!(200), dec!(0.06), 6));
+        input.max_leverage = None;
+        input.min_interest_coverage = Some(dec!(4));
+        let out = analyze_debt_maturities(&input).unwrap().result;
+        // (100 / 4 - 12) / 0.07 = 185.71...
+        let y2 = &out.years[1];
+        assert_eq!(y2.binding_constraint, "min_interest_coverage");
+        assert!((y2.refinanced - dec!(185.714)).abs() < dec!(0.001));
+        assert!(y2.capacity_binds);
+    }
+
+    #[test]
+    fn test_maturity_validation() {
+        let mut input = maturity_input();
+        input.max_leverage = None;
+        assert!(matches!(
+            analyze_debt_maturities(&input).unwrap_err(),
+            CorpFinanceError::InsufficientData(_)
+        ));
+
+        let mut input = maturity_input();
+        input.facilities[0].maturity_year = 0;
+        match analyze_debt_maturities(&input).unwrap_err() {
+            CorpFinanceError::InvalidInput { field, .. } => {
+                assert_eq!(field, "facilities.maturity_year")
+            }
+            other => panic!("Expected InvalidInput, got {other:?}"),
+        }
+
+        let mut input = maturity_input();
+        input.refinancing.refinance_pct = Some(dec!(1.5));
+        assert!(analyze_debt_maturities(&input).is_err());
+    }
 }
@@ -0,0 +1,1065 @@
+use rust_decimal::prelude::*;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::time::Instant;
+
+use crate::sampling::{percentile, Lcg};
+use crate::{types::*, CorpFinanceError, CorpFinanceResult};
+
+// ---------------------------------------------------------------------------
+// Input / Output types
+// ---------------------------------------------------------------------------
+
+/// Product types supported for potential future exposure.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ProductType {
+    FxForward,
+    InterestRateSwap,
+    FxOption,
+    EquityOption,
+}
+
+/// A derivative trade facing a counterparty.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExposureTrade {
+    pub trade_id: String,
+    pub counterparty: String,
+    /// Trades sharing a netting set are netted; trades without one stand alone.
+    #[serde(default)]
+    pub netting_set: Option<String>,
+    pub product: ProductType,
+    /// Signed notional: positive is long the risk factor (buy foreign
+    /// currency, pay fixed, bought option), negative is short.
+    pub notional: Money,
+    /// Current mark-to-market from our side.
+    pub mark_to_market: Money,
+    pub maturity_years: Years,
+}
+
+/// How potential future exposure is estimated.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub enum PfeMethod {
+    /// Supervisory add-on factors by asset class and residual maturity,
+    /// with the net-to-gross ratio applied within netting sets.
+    #[default]
+    AddOn,
+    /// Monte Carlo simulation of one driving factor per asset class.
+    Simulation {
+        /// Annualised FX spot volatility
+        fx_volatility: Rate,
+        /// Annualised absolute volatility of swap rates (e.g. 0.01 = 100bp)
+        rate_volatility: Rate,
+        /// Annualised equity volatility
+        equity_volatility: Rate,
+        #[serde(default = "default_paths")]
+        num_paths: u32,
+        /// PFE quantile (e.g. 0.95)
+        #[serde(default = "default_confidence")]
+        confidence: Rate,
+        #[serde(default)]
+        seed: Option<u64>,
+    },
+}
+
+fn default_paths() -> u32 {
+    2000
+}
+
+fn default_confidence() -> Rate {
+    dec!(0.95)
+}
+
+fn default_warning_threshold() -> Rate {
+    dec!(0.8)
+}
+
+/// Exposure limit for a tenor bucket.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TenorLimit {
+    /// Upper end of the bucket; the bucket starts where the previous one ends.
+    pub up_to_years: Years,
+    pub limit: Money,
+}
+
+/// Limit structure for one counterparty.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CounterpartyLimit {
+    pub counterparty: String,
+    /// Limit on peak PFE across all tenors.
+    pub pfe_limit: Money,
+    #[serde(default)]
+    pub tenor_limits: Vec<TenorLimit>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CounterpartyExposureInput {
+    pub trades: Vec<ExposureTrade>,
+    #[serde(default)]
+    pub method: PfeMethod,
+    /// Profile time points in years; defaults to a standard grid out to the
+    /// longest maturity.
+    #[serde(default)]
+    pub time_grid: Vec<Years>,
+    #[serde(default)]
+    pub limits: Vec<CounterpartyLimit>,
+    /// Utilisation at or above which a limit is flagged as a warning.
+    #[serde(default = "default_warning_threshold")]
+    pub warning_threshold: Rate,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LimitStatus {
+    Within,
+    Warning,
+    Breach,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExposurePoint {
+    pub time: Years,
+    pub pfe: Money,
+    /// Mean positive exposure (simulation only).
+    pub expected_exposure: Option<Money>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TenorUtilization {
+    pub up_to_years: Years,
+    pub limit: Money,
+    pub peak_pfe: Money,
+    pub utilization: Rate,
+    pub status: LimitStatus,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CounterpartyExposure {
+    pub counterparty: String,
+    pub trade_count: usize,
+    pub netting_sets: usize,
+    /// Current exposure after netting: sum over netting sets of max(net MtM, 0)
+    pub current_exposure: Money,
+    pub peak_pfe: Money,
+    pub peak_pfe_time: Years,
+    /// Peak PFE if no trades were netted
+    pub gross_peak_pfe: Money,
+    pub netting_benefit: Money,
+    pub profile: Vec<ExposurePoint>,
+    pub limit: Option<Money>,
+    pub utilization: Option<Rate>,
+    pub headroom: Option<Money>,
+    pub tenor_utilization: Vec<TenorUtilization>,
+    /// Worst status across the overall and tenor limits
+    pub status: Option<LimitStatus>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CounterpartyExposureOutput {
+    pub counterparties: Vec<CounterpartyExposure>,
+    pub total_current_exposure: Money,
+    pub total_peak_pfe: Money,
+    pub breaches: Vec<String>,
+}
+
+// ---------------------------------------------------------------------------
+// Public API
+// ---------------------------------------------------------------------------
+
+/// Compute PFE profiles by counterparty with netting, and report utilisation
+/// against the limit structure.
+pub fn calculate_counterparty_exposure(
+    input: &CounterpartyExposureInput,
+) -> CorpFinanceResult<ComputationOutput<CounterpartyExposureOutput>> {
+    let start = Instant::now();
+    let mut warnings: Vec<String> = Vec::new();
+
+    validate_input(input)?;
+
+    let grid = time_grid(input);
+
+    // Group trades by counterparty, then by netting set
+    let mut by_cpty: BTreeMap<&str, Vec<&ExposureTrade>> = BTreeMap::new();
+    for t in &input.trades {
+        by_cpty.entry(t.counterparty.as_str()).or_default().push(t);
+    }
+
+    let simulated = match &input.method {
+        PfeMethod::AddOn => None,
+        PfeMethod::Simulation {
+            fx_volatility,
+            rate_volatility,
+            equity_volatility,
+            num_paths,
+            seed,
+            ..
+        } => Some(simulate_factors(
+            &grid,
+            [*fx_volatility, *rate_volatility, *equity_volatility],
+            *num_paths,
+            seed.unwrap_or(42),
+        )),
+    };
+
+    let mut counterparties = Vec::with_capacity(by_cpty.len());
+    for (cpty, trades) in &by_cpty {
+        let sets = netting_sets(trades);
+        let current_exposure: Decimal = sets
+            .iter()
+            .map(|s| s.iter().map(|t| t.mark_to_market).sum::<Decimal>())
+            .map(|v| v.max(Decimal::ZERO))
+            .sum();
+        let standalone: Vec<Vec<&ExposureTrade>> = trades.iter().map(|t| vec![*t]).collect();
+
+        let (profile, gross_profile) = match (&input.method, &simulated) {
+            (PfeMethod::Simulation { confidence, .. }, Some(factors)) => (
+                simulated_profile(&sets, &grid, factors, *confidence),
+                simulated_profile(&standalone, &grid, factors, *confidence),
+            ),
+            _ => (
+                add_on_profile(&sets, &grid),
+                add_on_profile(&standalone, &grid),
+            ),
+        };
+
+        let (peak_pfe, peak_pfe_time) =
+            profile
+                .iter()
+                .fold((Decimal::ZERO, Decimal::ZERO), |(best, at), p| {
+                    if p.pfe > best {
+                        (p.pfe, p.time)
+                    } else {
+                        (best, at)
+                    }
+                });
+        let gross_peak_pfe = gross_profile
+            .iter()
+            .map(|p| p.pfe)
+            .max()
+            .unwrap_or(Decimal::ZERO);
+
+        let limit = input.limits.iter().find(|l| l.counterparty == *cpty);
+        let mut exposure = CounterpartyExposure {
+            counterparty: cpty.to_string(),
+            trade_count: trades.len(),
+            netting_sets: sets.len(),
+            current_exposure,
+            peak_pfe,
+            peak_pfe_time,
+            gross_peak_pfe,
+            netting_benefit: gross_peak_pfe - peak_pfe,
+            profile,
+            limit: None,
+            utilization: None,
+            headroom: None,
+            tenor_utilization: Vec::new(),
+            status: None,
+        };
+        if let Some(l) = limit {
+            apply_limit(&mut exposure, l, input.warning_threshold);
+        }
+        counterparties.push(exposure);
+    }
+
+    for l in &input.limits {
+        if !by_cpty.contains_key(l.counterparty.as_str()) {
+            warnings.push(format!(
+                "Limit for '{}' has no trades; ignored.",
+                l.counterparty
+            ));
+        }
+    }
+
+    let mut breaches = Vec::new();
+    for c in &counterparties {
+        match c.status {
+            Some(LimitStatus::Breach) => breaches.push(c.counterparty.clone()),
+            Some(LimitStatus::Warning) => warnings.push(format!(
+                "Counterparty '{}' is above {}% of a limit.",
+                c.counterparty,
+                (input.warning_threshold * dec!(100)).normalize()
+            )),
+            _ => {}
+        }
+    }
+
+    let output = CounterpartyExposureOutput {
+        total_current_exposure: counterparties.iter().map(|c| c.current_exposure).sum(),
+        total_peak_pfe: counterparties.iter().map(|c| c.peak_pfe).sum(),
+        counterparties,
+        breaches,
+    };
+
+    let elapsed = start.elapsed().as_micros() as u64;
+    let method = match &input.method {
+        PfeMethod::AddOn => "Counterparty PFE (supervisory add-on, NGR netting)",
+        PfeMethod::Simulation { .. } => "Counterparty PFE (Monte Carlo simulation)",
+    };
+    let assumptions = serde_json::json!({
+        "trade_count": input.trades.len(),
+        "counterparty_count": output.counterparties.len(),
+        "time_grid": grid.iter().map(|t| t.to_string()).collect::<Vec<_>>(),
+        "warning_threshold": input.warning_threshold.to_string(),
+    });
+
+    Ok(with_metadata(
+        method,
+        &assumptions,
+        warnings,
+        elapsed,
+        output,
+    ))
+}
+
+// ---------------------------------------------------------------------------
+// Internal helpers
+// ---------------------------------------------------------------------------
+
+/// Supervisory add-on factor by asset class and residual maturity
+/// (<= 1y, 1-5y, > 5y).
+fn add_on_factor(product: ProductType, residual: Years) -> Rate {
+    let bucket = if residual <= Decimal::ONE {
+        0
+    } else if residual <= dec!(5) {
+        1
+    } else {
+        2
+    };
+    let factors = match product {
+        ProductType::InterestRateSwap => [dec!(0), dec!(0.005), dec!(0.015)],
+        ProductType::FxForward | ProductType::FxOption => [dec!(0.01), dec!(0.05), dec!(0.075)],
+        ProductType::EquityOption => [dec!(0.06), dec!(0.08), dec!(0.10)],
+    };
+    factors[bucket]
+}
+
+fn is_option(product: ProductType) -> bool {
+    matches!(product, ProductType::FxOption | ProductType::EquityOption)
+}
+
+/// Trade add-on at a horizon; sold options carry no potential exposure.
+fn trade_add_on(t: &ExposureTrade, time: Years) -> Money {
+    if is_option(t.product) && t.notional < Decimal::ZERO {
+        return Decimal::ZERO;
+    }
+    t.notional.abs() * add_on_factor(t.product, t.maturity_years - time)
+}
+
+fn time_grid(input: &CounterpartyExposureInput) -> Vec<Years> {
+    if !input.time_grid.is_empty() {
+        return input.time_grid.clone();
+    }
+    let max_maturity = input
+        .trades
+        .iter()
+        .map(|t| t.maturity_years)
+        .max()
+        .unwrap_or(Decimal::ONE);
+    let mut grid: Vec<Years> = [
+        dec!(0),
+        dec!(0.25),
+        dec!(0.5),
+        dec!(1),
+        dec!(2),
+        dec!(3),
+        dec!(5),
+        dec!(7),
+        dec!(10),
+        dec!(15),
+        dec!(20),
+        dec!(30),
+    ]
+    .into_iter()
+    .filter(|t| *t < max_maturity)
+    .collect();
+    grid.push(max_maturity);
+    grid
+}
+
+fn netting_sets<'a>(trades: &[&'a ExposureTrade]) -> Vec<Vec<&'a ExposureTrade>> {
+    let mut named: BTreeMap<&str, Vec<&ExposureTrade>> = BTreeMap::new();
+    let mut sets = Vec::new();
+    for t in trades {
+        match &t.netting_set {
+            Some(name) => named.entry(name.as_str()).or_default().push(*t),
+            None => sets.push(vec![*t]),
+        }
+    }
+    sets.extend(named.into_values());
+    sets
+}
+
+/// Add-on profile: at each horizon, current exposure of the live trades plus
+/// the net add-on A_net = A_gross x (0.4 + 0.6 x NGR) per netting set.
+fn add_on_profile(sets: &[Vec<&ExposureTrade>], grid: &[Years]) -> Vec<ExposurePoint> {
+    grid.iter()
+        .map(|&time| {
+            let pfe = sets
+                .iter()
+                .map(|set| {
+                    let live: Vec<&&ExposureTrade> =
+                        set.iter().filter(|t| t.maturity_years > time).collect();
+                    let net_mtm: Decimal = live.iter().map(|t| t.mark_to_market).sum();
+                    let gross_mtm: Decimal = live
+                        .iter()
+                        .map(|t| t.mark_to_market.max(Decimal::ZERO))
+                        .sum();
+                    let net_ce = net_mtm.max(Decimal::ZERO);
+                    let ngr = if gross_mtm > Decimal::ZERO {
+                        net_ce / gross_mtm
+                    } else {
+                        Decimal::ZERO
+                    };
+                    let gross_add_on: Decimal = live.iter().map(|t| trade_add_on(t, time)).sum();
+                    let add_on = if live.len() > 1 {
+                        gross_add_on * (dec!(0.4) + dec!(0.6) * ngr)
+                    } else {
+                        gross_add_on
+                    };
+                    net_ce + add_on
+                })
+                .sum();
+            ExposurePoint {
+                time,
+                pfe,
+                expected_exposure: None,
+            }
+        })
+        .collect()
+}
+
+/// Simulated driving factor paths: factors[path][time][asset class], each a
+/// Brownian motion scaled by its volatility.
+fn simulate_factors(grid: &[Years], vols: [Rate; 3], paths: u32, seed: u64) -> Vec<Vec<[f64; 3]>> {
+    let vols = vols.map(|v| v.to_f64().unwrap_or(0.0));
+    let times: Vec<f64> = grid.iter().map(|t| t.to_f64().unwrap_or(0.0)).collect();
+    let mut rng = Lcg::new(seed);
+    (0..paths)
+        .map(|_| {
+            let mut w = [0.0f64; 3];
+            let mut prev = 0.0f64;
+            times
+                .iter()
+                .map(|&t| {
+                    let dt = (t - prev).max(0.0);
+                    prev = t;
+                    for (k, wk) in w.iter_mut().enumerate() {
+                        *wk += vols[k] * dt.sqrt() * rng.next_normal_f64();
+                    }
+                    w
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Trade value at a horizon given the factor moves.
+fn simulated_value(t: &ExposureTrade, time: f64, factors: &[f64; 3]) -> f64 {
+    let notional = t.notional.to_f64().unwrap_or(0.0);
+    let mtm = t.mark_to_market.to_f64().unwrap_or(0.0);
+    let maturity = t.maturity_years.to_f64().unwrap_or(0.0);
+    match t.product {
+        ProductType::FxForward => mtm + notional * factors[0],
+        // Annuity approximation: value moves by notional x dr x remaining life
+        ProductType::InterestRateSwap => mtm + notional * factors[1] * (maturity - time),
+        // At-the-money call on the factor with delta 0.5; long options are
+        // worth at least zero, sold options at most zero.
+        ProductType::FxOption | ProductType::EquityOption => {
+            let dx = if t.product == ProductType::FxOption {
+                factors[0]
+            } else {
+                factors[2]
+            };
+            let value = mtm.abs() + notional.abs() * 0.5 * dx;
+            if notional >= 0.0 {
+                value.max(0.0)
+            } else {
+                -value.max(0.0)
+            }
+        }
+    }
+}
+
+fn simulated_profile(
+    sets: &[Vec<&ExposureTrade>],
+    grid: &[Years],
+    factors: &[Vec<[f64; 3]>],
+    confidence: Rate,
+) -> Vec<ExposurePoint> {
+    let n = factors.len().max(1);
+    grid.iter()
+        .enumerate()
+        .map(|(k, &time)| {
+            let tf = time.to_f64().unwrap_or(0.0);
+            let mut exposures: Vec<f64> = factors
+                .iter()
+                .map(|path| {
+                    sets.iter()
+                        .map(|set| {
+                            set.iter()
+                                .filter(|t| t.maturity_years > time)
+                                .map(|t| simulated_value(t, tf, &path[k]))
+                                .sum::<f64>()
+                                .max(0.0)
+                        })
+                        .sum()
+                })
+                .collect();
+            let ee = exposures.iter().sum::<f64>() / n as f64;
+            exposures.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+            ExposurePoint {
+                time,
+                pfe: to_money(percentile(&exposures, confidence)),
+                expected_exposure: Some(to_money(ee)),
+            }
+        })
+        .collect()
+}
+
+fn apply_limit(exposure: &mut CounterpartyExposure, limit: &CounterpartyLimit, warning: Rate) {
+    let status = |utilization: Rate| {
+        if utilization > Decimal::ONE {
+            LimitStatus::Breach
+        } else if utilization >= warning {
+            LimitStatus::Warning
+        } else {
+            LimitStatus::Within
+        }
+    };
+
+    let utilization = exposure.peak_pfe / limit.pfe_limit;
+    let mut worst = status(utilization);
+    exposure.limit = Some(limit.pfe_limit);
+    exposure.utilization = Some(utilization);
+    exposure.headroom = Some(limit.pfe_limit - exposure.peak_pfe);
+
+    let mut tenors = limit.tenor_limits.clone();
+    tenors.sort_by_key(|tl| tl.up_to_years);
+    let mut from = None;
+    for tl in &tenors {
+        let peak_pfe = exposure
+            .profile
+            .iter()
+            .filter(|p| from.is_none_or(|f| p.time > f) && p.time <= tl.up_to_years)
+            .map(|p| p.pfe)
+            .max()
+            .unwrap_or(Decimal::ZERO);
+        let utilization = peak_pfe / tl.limit;
+        let s = status(utilization);
+        worst = worst.max(s);
+        exposure.tenor_utilization.push(TenorUtilization {
+            up_to_years: tl.up_to_years,
+            limit: tl.limit,
+            peak_pfe,
+            utilization,
+            status: s,
+        });
+        from = Some(tl.up_to_years);
+    }
+    exposure.status = Some(worst);
+}
+
+fn to_money(v: f64) -> Money {
+    Decimal::from_f64(v).unwrap_or(Decimal::ZERO).round_dp(2)
+}
+
+fn validate_input(input: &CounterpartyExposureInput) -> CorpFinanceResult<()> {
+    if input.trades.is_empty() {
+        return Err(CorpFinanceError::InsufficientData(
+            "At least one trade must be provided.".into(),
+        ));
+    }
+    for t in &input.trades {
+        if t.maturity_years <= Decimal::ZERO {
+            return Err(CorpFinanceError::InvalidInput {
+                field: format!("trades[{}].maturity_years", t.trade_id),
+                reason: "Maturity must be positive".into(),
+            });
+        }
+    }
+    if input.time_grid.iter().any(|t| *t < Decimal::ZERO)
+        || input.time_grid.windows(2).any(|w| w[1] <= w[0])
+    {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "time_grid".into(),
+            reason: "Time grid must be non-negative and strictly increasing".into(),
+        });
+    }
+    for l in &input.limits {
+        if l.pfe_limit <= Decimal::ZERO || l.tenor_limits.iter().any(|t| t.limit <= Decimal::ZERO) {
+            return Err(CorpFinanceError::InvalidInput {
+                field: format!("limits[{}]", l.counterparty),
+                reason: "Limits must be positive".into(),
+            });
+        }
+    }
+    if let PfeMethod::Simulation {
+        fx_volatility,
+        rate_volatility,
+        equity_volatility,
+        num_paths,
+        confidence,
+        ..
+    } = &input.method
+    {
+        if [fx_volatility, rate_volatility, equity_volatility]
+            .iter()
+            .any(|v| **v < Decimal::ZERO)
+        {
+            return Err(CorpFinanceError::InvalidInput {
+                field: "method".into(),
+                reason: "Volatilities cannot be negative".into(),
+            });
+        }
+        if *num_paths == 0 {
+            return Err(CorpFinanceError::InvalidInput {
+                field: "method.num_paths".into(),
+                reason: "At least one path is required".into(),
+            });
+        }
+        crate::limits::check_simulation_paths("method.num_paths", (*num_paths).into())?;
+        if *confidence <= Decimal::ZERO || *confidence >= Decimal::ONE {
+            return Err(CorpFinanceError::InvalidInput {
+                field: "method.confidence".into(),
+                reason: "Confidence must be in (0, 1)".into(),
+            });
+        }
+    }
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn trade(
+        id: &str,
+        cpty: &str,
+        set: Option<&str>,
+        product: ProductType,
+        notional: Decimal,
+        mtm: Decimal,
+        maturity: Decimal,
+    ) -> ExposureTrade {
+        ExposureTrade {
+            trade_id: id.into(),
+            counterparty: cpty.into(),
+            netting_set: set.map(|s| s.into()),
+            product,
+            notional,
+            mark_to_market: mtm,
+            maturity_years: maturity,
+        }
+    }
+
+    fn input(trades: Vec<ExposureTrade>) -> CounterpartyExposureInput {
+        CounterpartyExposureInput {
+            trades,
+            method: PfeMethod::AddOn,
+            time_grid: vec![],
+            limits: vec![],
+            warning_threshold: dec!(0.8),
+        }
+    }
+
+    fn simulation() -> PfeMethod {
+        PfeMethod::Simulation {
+            fx_volatility: dec!(0.10),
+            rate_volatility: dec!(0.01),
+            equity_volatility: dec!(0.25),
+            num_paths: 1000,
+            confidence: dec!(0.95),
+            seed: Some(7),
+        }
+    }
+
+    #[test]
+    fn test_add_on_factors() {
+        assert_eq!(
+            add_on_factor(ProductType::InterestRateSwap, dec!(0.5)),
+            dec!(0)
+        );
+        assert_eq!(
+            add_on_factor(ProductType::InterestRateSwap, dec!(3)),
+            dec!(0.005)
+        );
+        assert_eq!(add_on_factor(ProductType::FxForward, dec!(1)), dec!(0.01));
+        assert_eq!(add_on_factor(ProductType::FxOption, dec!(7)), dec!(0.075));
+        assert_eq!(
+            add_on_factor(ProductType::EquityOption, dec!(2)),
+            dec!(0.08)
+        );
+    }
+
+    #[test]
+    fn test_single_fx_forward_add_on() {
+        let inp = input(vec![trade(
+            "T1",
+            "Bank A",
+            None,
+            ProductType::FxForward,
+            dec!(10_000_000),
+            dec!(200_000),
+            dec!(2),
+        )]);
+        let out = calculate_counterparty_exposure(&inp).unwrap().result;
+        let c = &out.counterparties[0];
+        assert_eq!(c.current_exposure, dec!(200_000));
+        // 200k MtM + 5% x 10m at t=0
+        assert_eq!(c.peak_pfe, dec!(700_000));
+        assert_eq!(c.peak_pfe_time, dec!(0));
+        assert_eq!(c.netting_benefit, Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_default_grid_ends_at_maturity() {
+        let inp = input(vec![trade(
+            "T1",
+            "Bank A",
+            None,
+            ProductType::InterestRateSwap,
+            dec!(1_000_000),
+            dec!(0),
+            dec!(4),
+        )]);
+        let grid = time_grid(&inp);
+        assert_eq!(grid.first(), Some(&dec!(0)));
+        assert_eq!(grid.last(), Some(&dec!(4)));
+        assert!(grid.windows(2).all(|w| w[1] > w[0]));
+    }
+
+    #[test]
+    fn test_add_on_profile_rolls_down() {
+        let inp = input(vec![trade(
+            "S1",
+            "Bank A",
+            None,
+            ProductType::InterestRateSwap,
+            dec!(100_000_000),
+            dec!(0),
+            dec!(7),
+        )]);
+        let out = calculate_counterparty_exposure(&inp).unwrap().result;
+        let p = &out.counterparties[0].profile;
+        // 7y residual -> 1.5%, 5y at t=2 -> 0.5%, <1y -> 0%
+        assert_eq!(p[0].pfe, dec!(1_500_000));
+        let at_2 = p.iter().find(|x| x.time == dec!(2)).unwrap();
+        assert_eq!(at_2.pfe, dec!(500_000));
+        assert_eq!(p.last().unwrap().pfe, Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_ngr_netting() {
+        let inp = input(vec![
+            trade(
+                "T1",
+                "Bank A",
+                Some("ISDA-1"),
+                ProductType::FxForward,
+                dec!(10_000_000),
+                dec!(300_000),
+                dec!(0.5),
+            ),
+            trade(
+                "T2",
+                "Bank A",
+                Some("ISDA-1"),
+                ProductType::FxForward,
+                dec!(-10_000_000),
+                dec!(-200_000),
+                dec!(0.5),
+            ),
+        ]);
+        let out = calculate_counterparty_exposure(&inp).unwrap().result;
+        let c = &out.counterparties[0];
+        assert_eq!(c.netting_sets, 1);
+        assert_eq!(c.current_exposure, dec!(100_000));
+        // NGR = 100k / 300k; gross add-on 200k -> 200k x (0.4 + 0.6/3) = 120k
+        assert_eq!(c.peak_pfe.round_dp(6), dec!(220_000));
+        // Without netting: 300k + 100k + 100k
+        assert_eq!(c.gross_peak_pfe, dec!(500_000));
+        assert_eq!(c.netting_benefit.round_dp(6), dec!(280_000));
+    }
+
+    #[test]
+    fn test_trades_without_netting_set_are_standalone() {
+        let inp = input(vec![
+            trade(
+                "T1",
+                "Bank A",
+                None,
+                ProductType::FxForward,
+                dec!(10_000_000),
+                dec!(300_000),
+                dec!(0.5),
+            ),
+            trade(
+                "T2",
+                "Bank A",
+                None,
+                ProductType::FxForward,
+                dec!(-10_000_000),
+                dec!(-200_000),
+                dec!(0.5),
+            ),
+        ]);
+        let out = calculate_counterparty_exposure(&inp).unwrap().result;
+        let c = &out.counterparties[0];
+        assert_eq!(c.netting_sets, 2);
+        assert_eq!(c.current_exposure, dec!(300_000));
+        assert_eq!(c.netting_benefit, Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_sold_option_has_no_add_on() {
+        let inp = input(vec![trade(
+            "O1",
+            "Bank A",
+            None,
+            ProductType::EquityOption,
+            dec!(-5_000_000),
+            dec!(-150_000),
+            dec!(1),
+        )]);
+        let out = calculate_counterparty_exposure(&inp).unwrap().result;
+        assert_eq!(out.counterparties[0].peak_pfe, Decimal::ZERO);
+        assert_eq!(out.counterparties[0].current_exposure, Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_aggregates_by_counterparty() {
+        let inp = input(vec![
+            trade(
+                "T1",
+                "Bank A",
+                None,
+                ProductType::FxForward,
+                dec!(1_000_000),
+                dec!(10_000),
+                dec!(1),
+            ),
+            trade(
+                "T2",
+                "Bank B",
+                None,
+                ProductType::FxForward,
+                dec!(2_000_000),
+                dec!(20_000),
+                dec!(1),
+            ),
+        ]);
+        let out = calculate_counterparty_exposure(&inp).unwrap().result;
+        assert_eq!(out.counterparties.len(), 2);
+        assert_eq!(out.total_current_exposure, dec!(30_000));
+        assert_eq!(out.total_peak_pfe, dec!(20_000) + dec!(40_000));
+    }
+
+    #[test]
+    fn test_limit_utilization_and_breach() {
+        let mut inp = input(vec![trade(
+            "T1",
+            "Bank A",
+            None,
+            ProductType::FxForward,
+            dec!(10_000_000),
+            dec!(200_000),
+            dec!(2),
+        )]);
+        inp.limits = vec![CounterpartyLimit {
+            counterparty: "Bank A".into(),
+            pfe_limit: dec!(500_000),
+            tenor_limits: vec![],
+        }];
+        let out = calculate_counterparty_exposure(&inp).unwrap().result;
+        let c = &out.counterparties[0];
+        assert_eq!(c.utilization, Some(dec!(1.4)));
+        assert_eq!(c.headroom, Some(dec!(-200_000)));
+        assert_eq!(c.status, Some(LimitStatus::Breach));
+        assert_eq!(out.breaches, vec!["Bank A".to_string()]);
+    }
+
+    #[test]
+    fn test_warning_threshold() {
+        let mut inp = input(vec![trade(
+            "T1",
+            "Bank A",
+            None,
+            ProductType::FxForward,
+            dec!(10_000_000),
+            dec!(200_000),
+            dec!(2),
+        )]);
+        inp.limits = vec![CounterpartyLimit {
+            counterparty: "Bank A".into(),
+            pfe_limit: dec!(800_000),
+            tenor_limits: vec![],
+        }];
+        let out = calculate_counterparty_exposure(&inp).unwrap();
+        assert_eq!(
+            out.result.counterparties[0].status,
+            Some(LimitStatus::Warning)
+        );
+        assert!(out.result.breaches.is_empty());
+        assert!(out.warnings.iter().any(|w| w.contains("Bank A")));
+    }
+
+    #[test]
+    fn test_tenor_limits() {
+        let mut inp = input(vec![trade(
+            "S1",
+            "Bank A",
+            None,
+            ProductType::InterestRateSwap,
+            dec!(100_000_000),
+            dec!(0),
+            dec!(7),
+        )]);
+        inp.limits = vec![CounterpartyLimit {
+            counterparty: "Bank A".into(),
+            pfe_limit: dec!(5_000_000),
+            tenor_limits: vec![
+                TenorLimit {
+                    up_to_years: dec!(10),
+                    limit: dec!(1_000_000),
+                },
+                TenorLimit {
+                    up_to_years: dec!(1),
+                    limit: dec!(1_000_000),
+                },
+            ],
+        }];
+        let out = calculate_counterparty_exposure(&inp).unwrap().result;
+        let c = &out.counterparties[0];
+        assert_eq!(c.tenor_utilization.len(), 2);
+        // Sorted: 0-1y bucket holds the 1.5m peak
+        assert_eq!(c.tenor_utilization[0].up_to_years, dec!(1));
+        assert_eq!(c.tenor_utilization[0].status, LimitStatus::Breach);
+        // 1-10y bucket peaks at 0.5m
+        assert_eq!(c.tenor_utilization[1].peak_pfe, dec!(500_000));
+        assert_eq!(c.tenor_utilization[1].status, LimitStatus::Within);
+        assert_eq!(c.status, Some(LimitStatus::Breach));
+    }
+
+    #[test]
+    fn test_simulation_fx_forward_grows_with_time() {
+        let mut inp = input(vec![trade(
+            "T1",
+            "Bank A",
+            None,
+            ProductType::FxForward,
+            dec!(10_000_000),
+            dec!(0),
+            dec!(2),
+        )]);
+        inp.method = simulation();
+        let out = calculate_counterparty_exposure(&inp).unwrap().result;
+        let p = &out.counterparties[0].profile;
+        assert_eq!(p[0].pfe, Decimal::ZERO);
+        // PFE grows with sqrt(t): at 1y roughly 1.645 x 10% x 10m
+        let at_1 = p.iter().find(|x| x.time == dec!(1)).unwrap();
+        assert!(at_1.pfe > dec!(1_400_000) && at_1.pfe < dec!(1_900_000));
+        assert!(at_1.expected_exposure.unwrap() < at_1.pfe);
+    }
+
+    #[test]
+    fn test_simulation_swap_profile_humps() {
+        let mut inp = input(vec![trade(
+            "S1",
+            "Bank A",
+            None,
+            ProductType::InterestRateSwap,
+            dec!(100_000_000),
+            dec!(0),
+            dec!(10),
+        )]);
+        inp.method = simulation();
+        let out = calculate_counterparty_exposure(&inp).unwrap().result;
+        let c = &out.counterparties[0];
+        // Diffusion vs amortisation: peak lies strictly inside the swap life
+        assert!(c.peak_pfe_time > Decimal::ZERO && c.peak_pfe_time < dec!(10));
+    }
+
+    #[test]
+    fn test_simulation_netting_offsets() {
+        let mut inp = input(vec![
+            trade(
+                "T1",
+                "Bank A",
+                Some("ISDA-1"),
+                ProductType::FxForward,
+                dec!(10_000_000),
+                dec!(0),
+                dec!(1),
+            ),
+            trade(
+                "T2",
+                "Bank A",
+                Some("ISDA-1"),
+                ProductType::FxForward,
+                dec!(-10_000_000),
+                dec!(0),
+                dec!(1),
+            ),
+        ]);
+        inp.method = simulation();
+        let out = calculate_counterparty_exposure(&inp).unwrap().result;
+        let c = &out.counterparties[0];
+        // Perfect hedge within a netting set
+        assert_eq!(c.peak_pfe, Decimal::ZERO);
+        assert!(c.gross_peak_pfe > Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_simulation_is_reproducible() {
+        let mut inp = input(vec![trade(
+            "O1",
+            "Bank A",
+            None,
+            ProductType::EquityOption,
+            dec!(1_000_000),
+            dec!(50_000),
+            dec!(1),
+        )]);
+        inp.method = simulation();
+        let a = calculate_counterparty_exposure(&inp).unwrap().result;
+        let b = calculate_counterparty_exposure(&inp).unwrap().result;
+        assert_eq!(a.total_peak_pfe, b.total_peak_pfe);
+        // Long option exposure is never below its current value floor of zero
+        assert!(a.counterparties[0]
+            .profile
+            .iter()
+            .all(|p| p.pfe >= Decimal::ZERO));
+    }
+
+    #[test]
+    fn test_invalid_inputs() {
+        assert!(calculate_counterparty_exposure(&input(vec![])).is_err());
+
+        let mut inp = input(vec![trade(
+            "T1",
+            "Bank A",
+            None,
+            ProductType::FxForward,
+            dec!(1),
+            dec!(0),
+            dec!(0),
+        )]);
+        assert!(calculate_counterparty_exposure(&inp).is_err());
+
+        inp.trades[0].maturity_years = dec!(1);
+        inp.time_grid = vec![dec!(1), dec!(0.5)];
+        assert!(calculate_counterparty_exposure(&inp).is_err());
+
+        inp.time_grid = vec![];
+        inp.limits = vec![CounterpartyLimit {
+            counterparty: "Bank A".into(),
+            pfe_limit: dec!(0),
+            tenor_limits: vec![],
+        }];
+        assert!(calculate_counterparty_exposure(&inp).is_err());
+    }
+}
@@ -0,0 +1,782 @@
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{CorpFinanceError, CorpFinanceResult};
+
+// ---------------------------------------------------------------------------
+// Syntax tree
+// ---------------------------------------------------------------------------
+
+/// Arithmetic expression over named financial variables.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expr {
+    Number(Decimal),
+    Variable(String),
+    Neg(Box<Expr>),
+    Binary {
+        op: BinaryOp,
+        lhs: Box<Expr>,
+        rhs: Box<Expr>,
+    },
+    Call {
+        function: Function,
+        args: Vec<Expr>,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BinaryOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Function {
+    Min,
+    Max,
+    Abs,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Comparator {
+    /// `<=`: the metric must not exceed the threshold.
+    AtMost,
+    /// `<`: the metric must stay below the threshold.
+    Below,
+    /// `>=`: the metric must not fall below the threshold.
+    AtLeast,
+    /// `>`: the metric must stay above the threshold.
+    Above,
+}
+
+/// Test period referenced by a threshold step, as written in the formula.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PeriodRef {
+    /// `Q8`: the eighth quarter.
+    Quarter(u32),
+    /// `Y2` or `FY2`: the end of the second year.
+    Year(u32),
+    /// A bare number: the nth test period.
+    Period(u32),
+}
+
+/// A change in threshold from a given test period.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ThresholdStep {
+    pub threshold: Decimal,
+    pub period: PeriodRef,
+    /// `after Q8` starts the step in the period following Q8; `from Q8`
+    /// starts it in Q8 itself.
+    pub after: bool,
+}
+
+/// A parsed covenant such as
+/// `net_debt / ebitda <= 4.5x stepping to 4.0x after Q8`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CovenantFormula {
+    pub metric: Expr,
+    pub comparator: Comparator,
+    pub threshold: Decimal,
+    pub steps: Vec<ThresholdStep>,
+}
+
+// ---------------------------------------------------------------------------
+// Public API
+// ---------------------------------------------------------------------------
+
+/// Parse a covenant: a metric expression, a comparator, an opening threshold
+/// and any step-downs.
+///
+/// Thresholds accept an `x` suffix for multiples and `%` for percentages.
+/// Step-downs are written `stepping to 4.0 after Q8` (or `steps to`, `then`),
+/// optionally separated by commas or `and`; `after` starts the new level in
+/// the following period and `from` in the named one.
+pub fn parse_formula(src: &str) -> CorpFinanceResult<CovenantFormula> {
+    let mut parser = Parser::new(src)?;
+    let metric = parser.parse_sum()?;
+    let comparator = match parser.next() {
+        Some(Token::Le) => Comparator::AtMost,
+        Some(Token::Lt) => Comparator::Below,
+        Some(Token::Ge) => Comparator::AtLeast,
+        Some(Token::Gt) => Comparator::Above,
+        _ => {
+            parser.pos -= 1;
+            return Err(parser.error("expected a comparator (<=, <, >=, >)"));
+        }
+    };
+    let threshold = parser.parse_threshold()?;
+
+    let mut steps = Vec::new();
+    while parser.peek().is_some() {
+        if parser.peek() == Some(&Token::Comma) {
+            parser.next();
+        }
+        parser.eat_keyword("and");
+        if parser.eat_keyword("stepping") || parser.eat_keyword("steps") {
+            if !parser.eat_keyword("to") {
+                return Err(parser.error("expected 'to'"));
+            }
+        } else if !parser.eat_keyword("then") {
+            return Err(parser.error("expected 'stepping to' or 'then'"));
+        }
+        let level = parser.parse_threshold()?;
+        let after = if parser.eat_keyword("after") {
+            true
+        } else if parser.eat_keyword("from") {
+            false
+        } else {
+            return Err(parser.error("expected 'after' or 'from'"));
+        };
+        let period = parser.parse_period()?;
+        steps.push(ThresholdStep {
+            threshold: level,
+            period,
+            after,
+        });
+    }
+
+    Ok(CovenantFormula {
+        metric,
+        comparator,
+        threshold,
+        steps,
+    })
+}
+
+/// Parse a bare metric expression with no comparator, e.g.
+/// `(total_debt - cash) / ebitda`.
+pub fn parse_expression(src: &str) -> CorpFinanceResult<Expr> {
+    let mut parser = Parser::new(src)?;
+    let expr = parser.parse_sum()?;
+    if parser.peek().is_some() {
+        return Err(parser.error("unexpected input after expression"));
+    }
+    Ok(expr)
+}
+
+impl Expr {
+    /// Evaluate against a variable lookup.
+    pub fn evaluate<F>(&self, lookup: &F) -> CorpFinanceResult<Decimal>
+    where
+        F: Fn(&str) -> CorpFinanceResult<Decimal>,
+    {
+        match self {
+            Expr::Number(n) => Ok(*n),
+            Expr::Variable(name) => lookup(name),
+            Expr::Neg(inner) => Ok(-inner.evaluate(lookup)?),
+            Expr::Binary { op, lhs, rhs } => {
+                let l = lhs.evaluate(lookup)?;
+                let r = rhs.evaluate(lookup)?;
+                match op {
+                    BinaryOp::Add => Ok(l + r),
+                    BinaryOp::Sub => Ok(l - r),
+                    BinaryOp::Mul => Ok(l * r),
+                    BinaryOp::Div => {
+                        if r.is_zero() {
+                            return Err(CorpFinanceError::DivisionByZero {
+                                context: format!("covenant expression '{self}'"),
+                            });
+                        }
+                        Ok(l / r)
+                    }
+                }
+            }
+            Expr::Call { function, args } => {
+                let values = args
+                    .iter()
+                    .map(|a| a.evaluate(lookup))
+                    .collect::<CorpFinanceResult<Vec<_>>>()?;
+                Ok(match function {
+                    Function::Min => values.into_iter().min().unwrap_or_default(),
+                    Function::Max => values.into_iter().max().unwrap_or_default(),
+                    Function::Abs => values[0].abs(),
+                })
+            }
+        }
+    }
+
+    /// Variable names in order of first appearance.
+    pub fn variables(&self) -> Vec<String> {
+        let mut out = Vec::new();
+        self.collect_variables(&mut out);
+        out
+    }
+
+    fn collect_variables(&self, out: &mut Vec<String>) {
+        match self {
+            Expr::Number(_) => {}
+            Expr::Variable(name) => {
+                if !out.contains(name) {
+                    out.push(name.clone());
+                }
+            }
+            Expr::Neg(inner) => inner.collect_variables(out),
+            Expr::Binary { lhs, rhs, .. } => {
+                lhs.collect_variables(out);
+                rhs.collect_variables(out);
+            }
+            Expr::Call { args, .. } => args.iter().for_each(|a| a.collect_variables(out)),
+        }
+    }
+
+    fn precedence(&self) -> u8 {
+        match self {
+            Expr::Binary {
+                op: BinaryOp::Add | BinaryOp::Sub,
+                ..
+            } => 1,
+            Expr::Binary { .. } => 2,
+            _ => 3,
+        }
+    }
+}
+
+impl Comparator {
+    /// Whether the metric passes against the threshold.
+    pub fn passes(self, actual: Decimal, threshold: Decimal) -> bool {
+        match self {
+            Comparator::AtMost => actual <= threshold,
+            Comparator::Below => actual < threshold,
+            Comparator::AtLeast => actual >= threshold,
+            Comparator::Above => actual > threshold,
+        }
+    }
+
+    /// `true` for maximum (leverage-style) covenants.
+    pub fn is_maximum(self) -> bool {
+        matches!(self, Comparator::AtMost | Comparator::Below)
+    }
+}
+
+impl PeriodRef {
+    /// Resolve to a 1-based test period index.
+    pub fn resolve(self, periods_per_year: u32) -> CorpFinanceResult<u32> {
+        match self {
+            PeriodRef::Period(n) => Ok(n),
+            PeriodRef::Year(n) => Ok(n * periods_per_year),
+            PeriodRef::Quarter(n) if periods_per_year == 4 => Ok(n),
+            PeriodRef::Quarter(n) if n % 4 == 0 => Ok(n / 4 * periods_per_year),
+            PeriodRef::Quarter(n) => Err(CorpFinanceError::InvalidInput {
+                field: "formula".into(),
+                reason: format!("Q{n} does not fall on an annual test date"),
+            }),
+        }
+    }
+}
+
+impl CovenantFormula {
+    /// Threshold schedule as `(first period, threshold)` pairs, starting with
+    /// the opening threshold in period 1.
+    pub fn schedule(&self, periods_per_year: u32) -> CorpFinanceResult<Vec<(u32, Decimal)>> {
+        let mut out = vec![(1, self.threshold)];
+        for step in &self.steps {
+            let start = step.period.resolve(periods_per_year)? + u32::from(step.after);
+            if start <= out.last().map(|(p, _)| *p).unwrap_or(0) {
+                return Err(CorpFinanceError::InvalidInput {
+                    field: "formula".into(),
+                    reason: "threshold steps must be in chronological order".into(),
+                });
+            }
+            out.push((start, step.threshold));
+        }
+        Ok(out)
+    }
+}
+
+/// Threshold in force in `period` under a resolved schedule.
+pub fn threshold_at(schedule: &[(u32, Decimal)], period: u32) -> Decimal {
+    schedule
+        .iter()
+        .rev()
+        .find(|(start, _)| *start <= period)
+        .or(schedule.first())
+        .map(|(_, t)| *t)
+        .unwrap_or_default()
+}
+
+impl fmt::Display for Expr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Expr::Number(n) => write!(f, "{n}"),
+            Expr::Variable(name) => write!(f, "{name}"),
+            Expr::Neg(inner) if inner.precedence() < 3 => write!(f, "-({inner})"),
+            Expr::Neg(inner) => write!(f, "-{inner}"),
+            Expr::Binary { op, lhs, rhs } => {
+                let prec = self.precedence();
+                let symbol = match op {
+                    BinaryOp::Add => "+",
+                    BinaryOp::Sub => "-",
+                    BinaryOp::Mul => "*",
+                    BinaryOp::Div => "/",
+                };
+                if lhs.precedence() < prec {
+                    write!(f, "({lhs})")?;
+                } else {
+                    write!(f, "{lhs}")?;
+                }
+                write!(f, " {symbol} ")?;
+                // Right operands bind tighter: a - (b - c), a / (b * c)
+                if rhs.precedence() <= prec {
+                    write!(f, "({rhs})")
+                } else {
+                    write!(f, "{rhs}")
+                }
+            }
+            Expr::Call { function, args } => {
+                let name = match function {
+                    Function::Min => "min",
+                    Function::Max => "max",
+                    Function::Abs => "abs",
+                };
+                write!(f, "{name}(")?;
+                for (i, a) in args.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{a}")?;
+                }
+                write!(f, ")")
+            }
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tokeniser and parser
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Number(Decimal),
+    Ident(String),
+    Plus,
+    Minus,
+    Star,
+    Slash,
+    LParen,
+    RParen,
+    Comma,
+    Le,
+    Lt,
+    Ge,
+    Gt,
+}
+
+fn tokenise(src: &str) -> CorpFinanceResult<Vec<(Token, usize)>> {
+    let chars: Vec<(usize, char)> = src.char_indices().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    let err = |pos: usize, msg: String| CorpFinanceError::InvalidInput {
+        field: "formula".into(),
+        reason: format!("{msg} at position {pos} in '{src}'"),
+    };
+
+    while i < chars.len() {
+        let (pos, c) = chars[i];
+        if c.is_whitespace() {
+            i += 1;
+            continue;
+        }
+        if c.is_ascii_digit()
+            || (c == '.' && chars.get(i + 1).is_some_and(|(_, d)| d.is_ascii_digit()))
+        {
+            let mut text = String::new();
+            while let Some(&(_, d)) = chars.get(i) {
+                if d.is_ascii_digit() || d == '.' {
+                    text.push(d);
+                } else if d != '_' {
+                    break;
+                }
+                i += 1;
+            }
+            let mut value = Decimal::from_str(&text)
+                .map_err(|_| err(pos, format!("invalid number '{text}'")))?;
+            match chars.get(i).map(|(_, d)| *d) {
+                // 4.5x: a multiple, same value
+                Some('x' | 'X')
+                    if !chars
+                        .get(i + 1)
+                        .is_some_and(|(_, d)| d.is_alphanumeric() || *d == '_') =>
+                {
+                    i += 1;
+                }
+                Some('%') => {
+                    value /= Decimal::ONE_HUNDRED;
+                    i += 1;
+                }
+                _ => {}
+            }
+            tokens.push((Token::Number(value), pos));
+            continue;
+        }
+        if c.is_alphabetic() || c == '_' {
+            let mut text = String::new();
+            while let Some(&(_, d)) = chars.get(i) {
+                if d.is_alphanumeric() || d == '_' {
+                    text.push(d);
+                    i += 1;
+                } else {
+                    break;
+                }
+            }
+            tokens.push((Token::Ident(text), pos));
+            continue;
+        }
+        let next = chars.get(i + 1).map(|(_, d)| *d);
+        let (token, width) = match (c, next) {
+            ('<', Some('=')) => (Token::Le, 2),
+            ('>', Some('=')) => (Token::Ge, 2),
+            ('<', _) => (Token::Lt, 1),
+            ('>', _) => (Token::Gt, 1),
+            ('≤', _) => (Token::Le, 1),
+            ('≥', _) => (Token::Ge, 1),
+            ('+', _) => (Token::Plus, 1),
+            ('-', _) => (Token::Minus, 1),
+            ('*', _) => (Token::Star, 1),
+            ('/', _) => (Token::Slash, 1),
+            ('(', _) => (Token::LParen, 1),
+            (')', _) => (Token::RParen, 1),
+            (',', _) => (Token::Comma, 1),
+            _ => return Err(err(pos, format!("unexpected character '{c}'"))),
+        };
+        tokens.push((token, pos));
+        i += width;
+    }
+    Ok(tokens)
+}
+
+struct Parser<'a> {
+    src: &'a str,
+    tokens: Vec<(Token, usize)>,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(src: &'a str) -> CorpFinanceResult<Self> {
+        let tokens = tokenise(src)?;
+        if tokens.is_empty() {
+            return Err(CorpFinanceError::InvalidInput {
+                field: "formula".into(),
+                reason: "formula is empty".into(),
+            });
+        }
+        Ok(Parser {
+            src,
+            tokens,
+            pos: 0,
+        })
+    }
+
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos).map(|(t, _)| t)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let t = self.tokens.get(self.pos).map(|(t, _)| t.clone());
+        self.pos += 1;
+        t
+    }
+
+    fn error(&self, msg: &str) -> CorpFinanceError {
+        let reason = match self.tokens.get(self.pos.min(self.tokens.len())) {
+            Some((_, at)) => format!("{msg} at position {at} in '{}'", self.src),
+            None => format!("{msg} at end of '{}'", self.src),
+        };
+        CorpFinanceError::InvalidInput {
+            field: "formula".into(),
+            reason,
+        }
+    }
+
+    fn eat_keyword(&mut self, keyword: &str) -> bool {
+        match self.peek() {
+            Some(Token::Ident(id)) if id.eq_ignore_ascii_case(keyword) => {
+                self.pos += 1;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn parse_sum(&mut self) -> CorpFinanceResult<Expr> {
+        let mut lhs = self.parse_product()?;
+        loop {
+            let op = match self.peek() {
+                Some(Token::Plus) => BinaryOp::Add,
+                Some(Token::Minus) => BinaryOp::Sub,
+                _ => return Ok(lhs),
+            };
+            self.pos += 1;
+            let rhs = self.parse_product()?;
+            lhs = Expr::Binary {
+                op,
+                lhs: Box::new(lhs),
+                rhs: Box::new(rhs),
+            };
+        }
+    }
+
+    fn parse_product(&mut self) -> CorpFinanceResult<Expr> {
+        let mut lhs = self.parse_unary()?;
+        loop {
+            let op = match self.peek() {
+                Some(Token::Star) => BinaryOp::Mul,
+                Some(Token::Slash) => BinaryOp::Div,
+                _ => return Ok(lhs),
+            };
+            self.pos += 1;
+            let rhs = self.parse_unary()?;
+            lhs = Expr::Binary {
+                op,
+                lhs: Box::new(lhs),
+                rhs: Box::new(rhs),
+            };
+        }
+    }
+
+    fn parse_unary(&mut self) -> CorpFinanceResult<Expr> {
+        if self.peek() == Some(&Token::Minus) {
+            self.pos += 1;
+            return Ok(Expr::Neg(Box::new(self.parse_unary()?)));
+        }
+        self.parse_primary()
+    }
+
+    fn parse_primary(&mut self) -> CorpFinanceResult<Expr> {
+        match self.next() {
+            Some(Token::Number(n)) => Ok(Expr::Number(n)),
+            Some(Token::LParen) => {
+                let inner = self.parse_sum()?;
+                if self.next() != Some(Token::RParen) {
+                    self.pos -= 1;
+                    return Err(self.error("expected ')'"));
+                }
+                Ok(inner)
+            }
+            Some(Token::Ident(name)) => {
+                if self.peek() != Some(&Token::LParen) {
+                    return Ok(Expr::Variable(name));
+                }
+                let function = match name.to_ascii_lowercase().as_str() {
+                    "min" => Function::Min,
+                    "max" => Function::Max,
+                    "abs" => Function::Abs,
+                    _ => {
+                        self.pos -= 1;
+                        return Err(self.error(&format!("unknown function '{name}'")));
+                    }
+                };
+                self.pos += 1;
+                let mut args = vec![self.parse_sum()?];
+                while self.peek() == Some(&Token::Comma) {
+                    self.pos += 1;
+                    args.push(self.parse_sum()?);
+                }
+                if self.next() != Some(Token::RParen) {
+                    self.pos -= 1;
+                    return Err(self.error("expected ')'"));
+                }
+                if function == Function::Abs && args.len() != 1 {
+                    return Err(self.error("abs() takes one argument"));
+                }
+                Ok(Expr::Call { function, args })
+            }
+            _ => {
+                self.pos = self.pos.saturating_sub(1);
+                Err(self.error("expected a number, variable or '('"))
+            }
+        }
+    }
+
+    fn parse_threshold(&mut self) -> CorpFinanceResult<Decimal> {
+        let negative = self.peek() == Some(&Token::Minus);
+        if negative {
+            self.pos += 1;
+        }
+        match self.next() {
+            Some(Token::Number(n)) => Ok(if negative { -n } else { n }),
+            _ => {
+                self.pos -= 1;
+                Err(self.error("expected a threshold"))
+            }
+        }
+    }
+
+    fn parse_period(&mut self) -> CorpFinanceResult<PeriodRef> {
+        let period = match self.next() {
+            Some(Token::Number(n)) => n.to_string().parse().ok().map(PeriodRef::Period),
+            Some(Token::Ident(id)) => {
+                let upper = id.to_ascii_uppercase();
+                if let Some(n) = upper.strip_prefix("FY") {
+                    n.parse().ok().map(PeriodRef::Year)
+                } else if let Some(n) = upper.strip_prefix('Y') {
+                    n.parse().ok().map(PeriodRef::Year)
+                } else if let Some(n) = upper.strip_prefix('Q') {
+                    n.parse().ok().map(PeriodRef::Quarter)
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        };
+        match period {
+            Some(p)
+                if !matches!(
+                    p,
+                    PeriodRef::Period(0) | PeriodRef::Year(0) | PeriodRef::Quarter(0)
+                ) =>
+            {
+                Ok(p)
+            }
+            _ => {
+                self.pos -= 1;
+                Err(self.error("expected a test period such as Q8, Y2 or 8"))
+            }
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+    use std::collections::HashMap;
+
+    fn eval(src: &str, vars: &[(&str, Decimal)]) -> CorpFinanceResult<Decimal> {
+        let map: HashMap<&str, Decimal> = vars.iter().copied().collect();
+        parse_expression(src)?.evaluate(&|name: &str| {
+            map.get(name)
+                .copied()
+                .ok_or_else(|| CorpFinanceError::InsufficientData(name.to_string()))
+        })
+    }
+
+    #[test]
+    fn test_arithmetic_precedence() {
+        assert_eq!(eval("1 + 2 * 3", &[]).unwrap(), dec!(7));
+        assert_eq!(eval("(1 + 2) * 3", &[]).unwrap(), dec!(9));
+        assert_eq!(eval("10 - 4 - 3", &[]).unwrap(), dec!(3));
+        assert_eq!(eval("-2 * -3", &[]).unwrap(), dec!(6));
+    }
+
+    #[test]
+    fn test_variables_and_functions() {
+        let vars = [
+            ("total_debt", dec!(500)),
+            ("cash", dec!(100)),
+            ("ebitda", dec!(100)),
+        ];
+        assert_eq!(
+            eval("(total_debt - cash) / ebitda", &vars).unwrap(),
+            dec!(4)
+        );
+        assert_eq!(
+            eval("max(cash, 150) - min(1, 2)", &vars).unwrap(),
+            dec!(149)
+        );
+        assert_eq!(eval("abs(cash - total_debt)", &vars).unwrap(), dec!(400));
+    }
+
+    #[test]
+    fn test_division_by_zero() {
+        let err = eval(
+            "ebitda / interest",
+            &[("ebitda", dec!(1)), ("interest", dec!(0))],
+        );
+        assert!(matches!(err, Err(CorpFinanceError::DivisionByZero { .. })));
+    }
+
+    #[test]
+    fn test_parse_step_down_formula() {
+        let f = parse_formula("net_debt/ebitda <= 4.5x stepping to 4.0x after Q8").unwrap();
+        assert_eq!(f.comparator, Comparator::AtMost);
+        assert_eq!(f.threshold, dec!(4.5));
+        assert_eq!(f.steps.len(), 1);
+        assert_eq!(f.steps[0].threshold, dec!(4.0));
+        assert_eq!(f.steps[0].period, PeriodRef::Quarter(8));
+        assert!(f.steps[0].after);
+        assert_eq!(f.metric.variables(), vec!["net_debt", "ebitda"]);
+
+        let schedule = f.schedule(4).unwrap();
+        assert_eq!(threshold_at(&schedule, 8), dec!(4.5));
+        assert_eq!(threshold_at(&schedule, 9), dec!(4.0));
+    }
+
+    #[test]
+    fn test_multiple_steps_and_keywords() {
+        let f = parse_formula(
+            "ebitda / interest >= 2.0 stepping to 2.25 from Y2, and then 2.5 after FY3",
+        )
+        .unwrap();
+        assert_eq!(f.comparator, Comparator::AtLeast);
+        let schedule = f.schedule(4).unwrap();
+        assert_eq!(
+            schedule,
+            vec![(1, dec!(2.0)), (8, dec!(2.25)), (13, dec!(2.5))]
+        );
+        // Annual testing
+        let annual = f.schedule(1).unwrap();
+        assert_eq!(threshold_at(&annual, 1), dec!(2.0));
+        assert_eq!(threshold_at(&annual, 2), dec!(2.25));
+        assert_eq!(threshold_at(&annual, 4), dec!(2.5));
+    }
+
+    #[test]
+    fn test_percent_threshold() {
+        let f = parse_formula("capex / revenue < 12.5%").unwrap();
+        assert_eq!(f.threshold, dec!(0.125));
+        assert!(f.comparator.passes(dec!(0.12), f.threshold));
+        assert!(!f.comparator.passes(dec!(0.125), f.threshold));
+    }
+
+    #[test]
+    fn test_steps_out_of_order_rejected() {
+        let f = parse_formula("lev <= 4 stepping to 3.5 after Q8 stepping to 3 after Q4").unwrap();
+        assert!(f.schedule(4).is_err());
+    }
+
+    #[test]
+    fn test_quarter_on_annual_schedule() {
+        let f = parse_formula("lev <= 4 stepping to 3.5 after Q6").unwrap();
+        assert!(f.schedule(1).is_err());
+        let f = parse_formula("lev <= 4 stepping to 3.5 after Q8").unwrap();
+        assert_eq!(f.schedule(1).unwrap()[1], (3, dec!(3.5)));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        for bad in [
+            "",
+            "net_debt / ebitda",
+            "net_debt / <= 4",
+            "(a + b <= 4",
+            "a <= 4 stepping 3 after Q8",
+            "a <= 4 stepping to 3 after soon",
+            "foo(a) <= 1",
+            "a <= 4 #",
+        ] {
+            assert!(parse_formula(bad).is_err(), "{bad} should not parse");
+        }
+    }
+
+    #[test]
+    fn test_display_round_trip() {
+        for src in [
+            "(total_debt - cash) / ebitda",
+            "a - (b - c)",
+            "-(a + b) * max(c, 2)",
+        ] {
+            let expr = parse_expression(src).unwrap();
+            assert_eq!(expr.to_string(), src);
+            assert_eq!(parse_expression(&expr.to_string()).unwrap(), expr);
+        }
+    }
+}
@@ -1,7 +1,9 @@
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::time::Instant;
 
+use super::covenant_formula::{self, Comparator, Expr};
 use super::metrics::CreditMetricsOutput;
 use crate::{types::*, CorpFinanceError, CorpFinanceResult};
 
@@ -31,6 +33,8 @@ pub enum CovenantMetric {
     DebtToEquity,
     MinCash,
     MaxCapex,
+    /// Expression over the fields of `CreditMetricsOutput`, e.g.
+    /// `total_debt_to_ebitda - net_debt_to_ebitda`.
     Custom(String),
 }
 
@@ -67,6 +71,95 @@ pub struct CovenantHeadroom {
     pub passing: bool,
 }
 
+/// Covenant defined by a formula such as
+/// `net_debt / ebitda <= 4.5x stepping to 4.0x after Q8`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FormulaCovenant {
+    pub name: String,
+    pub formula: String,
+}
+
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub enum TestFrequency {
+    #[default]
+    Quarterly,
+    Annual,
+}
+
+/// Projected financials for one test date.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CovenantPeriod {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    pub values: BTreeMap<String, Decimal>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CovenantProjectionInput {
+    pub covenants: Vec<FormulaCovenant>,
+    /// Test dates in order; period numbers in formulas count from 1.
+    pub periods: Vec<CovenantPeriod>,
+    #[serde(default)]
+    pub frequency: TestFrequency,
+    /// Defined terms as expressions, e.g. `"net_debt": "total_debt - cash"`.
+    /// A value supplied directly in a period takes precedence.
+    #[serde(default)]
+    pub definitions: BTreeMap<String, String>,
+}
+
+/// Contribution of one driver to the change in headroom since the previous
+/// test date.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HeadroomDriver {
+    /// `threshold` for a step-down, otherwise an input variable.
+    pub driver: String,
+    pub impact: Decimal,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CovenantPeriodResult {
+    pub period: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    pub actual: Decimal,
+    pub threshold: Decimal,
+    pub passing: bool,
+    pub headroom: Decimal,
+    pub headroom_pct: Rate,
+    /// Headroom bridge from the previous period; empty for the first.
+    pub waterfall: Vec<HeadroomDriver>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CovenantProjection {
+    pub covenant: String,
+    /// The metric expression as parsed.
+    pub metric: String,
+    pub comparator: Comparator,
+    pub periods: Vec<CovenantPeriodResult>,
+    pub minimum_headroom_pct: Rate,
+    pub tightest_period: u32,
+    pub first_breach_period: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CovenantBreach {
+    pub covenant: String,
+    pub period: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    pub actual: Decimal,
+    pub threshold: Decimal,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CovenantProjectionOutput {
+    pub covenants: Vec<CovenantProjection>,
+    pub all_passing: bool,
+    /// Earliest breach across all covenants.
+    pub first_breach: Option<CovenantBreach>,
+}
+
 // ---------------------------------------------------------------------------
 // Public API
 // ---------------------------------------------------------------------------
@@ -89,34 +182,21 @@ pub fn test_covenants(
 
     for cov in &input.covenants {
         let actual = match extract_metric(&input.actuals, &cov.metric) {
-            Some(v) => v,
-            None => {
+            Ok(v) => v,
+            Err(e) => {
                 warnings.push(format!(
-                    "Covenant '{}': metric {:?} not available in actuals; skipped.",
+                    "Covenant '{}': metric {:?} not available in actuals ({e}); skipped.",
                     cov.name, cov.metric
                 ));
                 continue;
             }
         };
 
-        let (passing, headroom) = match cov.direction {
-            CovenantDirection::MaxOf => {
-                let pass = actual <= cov.threshold;
-                let hr = cov.threshold - actual;
-                (pass, hr)
-            }
-            CovenantDirection::MinOf => {
-                let pass = actual >= cov.threshold;
-                let hr = actual - cov.threshold;
-                (pass, hr)
-            }
-        };
-
-        let headroom_pct = if cov.threshold.is_zero() {
-            Decimal::ZERO
-        } else {
-            headroom / cov.threshold
+        let comparator = match cov.direction {
+            CovenantDirection::MaxOf => Comparator::AtMost,
+            CovenantDirection::MinOf => Comparator::AtLeast,
         };
+        let (passing, headroom, headroom_pct) = assess(comparator, actual, cov.threshold);
 
         results.push(CovenantResult {
             covenant: cov.name.clone(),
@@ -160,22 +240,309 @@ pub fn test_covenants(
     ))
 }
 
+/// Test formula covenants over a projection, with the threshold schedule in
+/// force at each test date and a headroom waterfall between dates.
+///
+/// Formulas are parsed by [`covenant_formula::parse_formula`]. Variables are
+/// looked up in each period's values, then in `definitions`. The waterfall
+/// moves from the previous period's headroom to the current one by first
+/// applying any threshold step and then updating each input variable in the
+/// order it appears in the formula, so the impacts sum to the change in
+/// headroom.
+#[tracing::instrument(
+    level = "debug",
+    name = "covenant_projection",
+    skip_all,
+    fields(covenants = input.covenants.len(), periods = input.periods.len())
+)]
+pub fn project_covenants(
+    input: &CovenantProjectionInput,
+) -> CorpFinanceResult<ComputationOutput<CovenantProjectionOutput>> {
+    let start = Instant::now();
+    let mut warnings: Vec<String> = Vec::new();
+
+    if input.covenants.is_empty() {
+        return Err(CorpFinanceError::InsufficientData(
+            "At least one covenant must be provided.".into(),
+        ));
+    }
+    if input.periods.is_empty() {
+        return Err(CorpFinanceError::InsufficientData(
+            "At least one projection period must be provided.".into(),
+        ));
+    }
+    crate::limits::check_schedule_periods("periods", input.periods.len() as u64)?;
+
+    let definitions = input
+        .definitions
+        .iter()
+        .map(|(name, src)| {
+            covenant_formula::parse_expression(src)
+                .map(|e| (name.clone(), e))
+                .map_err(|e| CorpFinanceError::InvalidInput {
+                    field: format!("definitions.{name}"),
+                    reason: e.to_string(),
+                })
+        })
+        .collect::<CorpFinanceResult<BTreeMap<String, Expr>>>()?;
+
+    let periods_per_year = match input.frequency {
+        TestFrequency::Quarterly => 4,
+        TestFrequency::Annual => 1,
+    };
+    let period_count = input.periods.len() as u32;
+
+    let mut projections = Vec::with_capacity(input.covenants.len());
+    for cov in &input.covenants {
+        let invalid = |e: CorpFinanceError| CorpFinanceError::InvalidInput {
+            field: format!("covenants.{}", cov.name),
+            reason: e.to_string(),
+        };
+        let formula = covenant_formula::parse_formula(&cov.formula).map_err(invalid)?;
+        let schedule = formula.schedule(periods_per_year).map_err(invalid)?;
+        if schedule.iter().any(|(p, _)| *p > period_count) {
+            warnings.push(format!(
+                "Covenant '{}': threshold steps fall beyond the {period_count}-period projection.",
+                cov.name
+            ));
+        }
+        let mut drivers = input_drivers(&formula.metric, &definitions);
+        // Defined terms only move headroom when a period overrides them
+        drivers.retain(|d| {
+            !definitions.contains_key(d) || input.periods.iter().any(|p| p.values.contains_key(d))
+        });
+
+        let evaluate = |values: &BTreeMap<String, Decimal>, period: u32| {
+            evaluate_metric(&formula.metric, values, &definitions).map_err(|e| {
+                CorpFinanceError::InvalidInput {
+                    field: format!("covenants.{}", cov.name),
+                    reason: format!("period {period}: {e}"),
+                }
+            })
+        };
+
+        let mut results: Vec<CovenantPeriodResult> = Vec::with_capacity(input.periods.len());
+        for (idx, period) in input.periods.iter().enumerate() {
+            let number = idx as u32 + 1;
+            let threshold = covenant_formula::threshold_at(&schedule, number);
+            let actual = evaluate(&period.values, number)?;
+            let (passing, headroom, headroom_pct) = assess(formula.comparator, actual, threshold);
+
+            let mut waterfall = Vec::new();
+            if let Some(prev) = results.last() {
+                let mut values = input.periods[idx - 1].values.clone();
+                let mut running = assess(formula.comparator, prev.actual, threshold).1;
+                waterfall.push(HeadroomDriver {
+                    driver: "threshold".into(),
+                    impact: running - prev.headroom,
+                });
+                for name in &drivers {
+                    match period.values.get(name) {
+                        Some(v) => values.insert(name.clone(), *v),
+                        None => values.remove(name),
+                    };
+                    let stepped =
+                        assess(formula.comparator, evaluate(&values, number)?, threshold).1;
+                    waterfall.push(HeadroomDriver {
+                        driver: name.clone(),
+                        impact: stepped - running,
+                    });
+                    running = stepped;
+                }
+            }
+
+            results.push(CovenantPeriodResult {
+                period: number,
+                label: period.label.clone(),
+                actual,
+                threshold,
+                passing,
+                headroom,
+                headroom_pct,
+                waterfall,
+            });
+        }
+
+        let tightest = results
+            .iter()
+            .min_by(|a, b| a.headroom_pct.cmp(&b.headroom_pct))
+            .expect("at least one period");
+        let first_breach_period = results.iter().find(|r| !r.passing).map(|r| r.period);
+        if let Some(p) = first_breach_period {
+            warnings.push(format!("Covenant '{}' breached in period {p}.", cov.name));
+        }
+        projections.push(CovenantProjection {
+            covenant: cov.name.clone(),
+            metric: formula.metric.to_string(),
+            comparator: formula.comparator,
+            minimum_headroom_pct: tightest.headroom_pct,
+            tightest_period: tightest.period,
+            first_breach_period,
+            periods: results,
+        });
+    }
+
+    let first_breach = projections
+        .iter()
+        .filter_map(|p| {
+            p.first_breach_period.map(|period| {
+                let r = &p.periods[period as usize - 1];
+                CovenantBreach {
+                    covenant: p.covenant.clone(),
+                    period,
+                    label: r.label.clone(),
+                    actual: r.actual,
+                    threshold: r.threshold,
+                }
+            })
+        })
+        .min_by_key(|b| b.period);
+
+    let output = CovenantProjectionOutput {
+        all_passing: first_breach.is_none(),
+        first_breach,
+        covenants: projections,
+    };
+
+    let elapsed = start.elapsed().as_micros() as u64;
+    let assumptions = serde_json::json!({
+        "covenant_count": input.covenants.len(),
+        "period_count": input.periods.len(),
+        "frequency": input.frequency,
+        "definitions": input.definitions,
+    });
+
+    Ok(with_metadata(
+        "Formula Covenant Projection with Headroom Waterfall",
+        &assumptions,
+        warnings,
+        elapsed,
+        output,
+    ))
+}
+
 // ---------------------------------------------------------------------------
 // Internal helpers
 // ---------------------------------------------------------------------------
 
+/// Pass/fail, headroom (positive when passing) and headroom as a share of the
+/// threshold.
+fn assess(comparator: Comparator, actual: Decimal, threshold: Decimal) -> (bool, Decimal, Rate) {
+    let headroom = if comparator.is_maximum() {
+        threshold - actual
+    } else {
+        actual - threshold
+    };
+    let headroom_pct = if threshold.is_zero() {
+        Decimal::ZERO
+    } else {
+        headroom / threshold.abs()
+    };
+    (comparator.passes(actual, threshold), headroom, headroom_pct)
+}
+
+/// Evaluate a metric, resolving variables from the period's values and then
+/// from the defined terms.
+fn evaluate_metric(
+    expr: &Expr,
+    values: &BTreeMap<String, Decimal>,
+    definitions: &BTreeMap<String, Expr>,
+) -> CorpFinanceResult<Decimal> {
+    fn resolve(
+        name: &str,
+        values: &BTreeMap<String, Decimal>,
+        definitions: &BTreeMap<String, Expr>,
+        depth: usize,
+    ) -> CorpFinanceResult<Decimal> {
+        if let Some(v) = values.get(name) {
+            return Ok(*v);
+        }
+        let def = definitions
+            .get(name)
+            .ok_or_else(|| CorpFinanceError::InsufficientData(format!("no value for '{name}'")))?;
+        if depth > definitions.len() {
+            return Err(CorpFinanceError::InvalidInput {
+                field: format!("definitions.{name}"),
+                reason: "circular definition".into(),
+            });
+        }
+        def.evaluate(&|n: &str| resolve(n, values, definitions, depth + 1))
+    }
+    expr.evaluate(&|n: &str| resolve(n, values, definitions, 0))
+}
+
+/// Input variables a metric depends on, expanding defined terms, in order of
+/// first appearance.
+fn input_drivers(expr: &Expr, definitions: &BTreeMap<String, Expr>) -> Vec<String> {
+    fn expand(
+        expr: &Expr,
+        definitions: &BTreeMap<String, Expr>,
+        seen: &mut Vec<String>,
+        out: &mut Vec<String>,
+    ) {
+        for name in expr.variables() {
+            if seen.contains(&name) {
+                continue;
+            }
+            seen.push(name.clone());
+            // A defined term's inputs and the term itself, in case a period
+            // supplies it directly
+            if let Some(def) = definitions.get(&name) {
+                expand(def, definitions, seen, out);
+            }
+            out.push(name);
+        }
+    }
+    let mut out = Vec::new();
+    expand(expr, definitions, &mut Vec::new(), &mut out);
+    out
+}
+
 /// Map a CovenantMetric enum variant to the corresponding value from
-/// CreditMetricsOutput. Returns None for Custom metrics or metrics that
-/// are not directly available.
-fn extract_metric(actuals: &CreditMetricsOutput, metric: &CovenantMetric) -> Option<Decimal> {
+/// CreditMetricsOutput. Custom metrics are evaluated as expressions over the
+/// numeric fields.
+fn extract_metric(
+    actuals: &CreditMetricsOutput,
+    metric: &CovenantMetric,
+) -> CorpFinanceResult<Decimal> {
     match metric {
-        CovenantMetric::NetDebtToEbitda => Some(actuals.net_debt_to_ebitda),
-        CovenantMetric::InterestCoverage => Some(actuals.interest_coverage),
-        CovenantMetric::Dscr => Some(actuals.dscr),
-        CovenantMetric::DebtToEquity => Some(actuals.debt_to_equity),
-        CovenantMetric::MinCash => Some(actuals.cash_to_debt), // proxy: cash/debt ratio
-        CovenantMetric::MaxCapex => Some(actuals.fcf),         // proxy: FCF as capex indicator
-        CovenantMetric::Custom(_) => None, // Custom metrics require external resolution
+        CovenantMetric::NetDebtToEbitda => Ok(actuals.net_debt_to_ebitda),
+        CovenantMetric::InterestCoverage => Ok(actuals.interest_coverage),
+        CovenantMetric::Dscr => Ok(actuals.dscr),
+        CovenantMetric::DebtToEquity => Ok(actuals.debt_to_equity),
+        CovenantMetric::MinCash => Ok(actuals.cash_to_debt), // proxy: cash/debt ratio
+        CovenantMetric::MaxCapex => Ok(actuals.fcf),         // proxy: FCF as capex indicator
+        CovenantMetric::Custom(src) => {
+            covenant_formula::parse_expression(src)?.evaluate(&|name: &str| {
+                actuals_field(actuals, name).ok_or_else(|| {
+                    CorpFinanceError::InsufficientData(format!("no value for '{name}'"))
+                })
+            })
+        }
+    }
+}
+
+fn actuals_field(a: &CreditMetricsOutput, name: &str) -> Option<Decimal> {
+    match name {
+        "net_debt" => Some(a.net_debt),
+        "net_debt_to_ebitda" => Some(a.net_debt_to_ebitda),
+        "total_debt_to_ebitda" => Some(a.total_debt_to_ebitda),
+        "debt_to_equity" => Some(a.debt_to_equity),
+        "debt_to_assets" => Some(a.debt_to_assets),
+        "net_debt_to_ev" => a.net_debt_to_ev,
+        "interest_coverage" => Some(a.interest_coverage),
+        "ebit_coverage" => Some(a.ebit_coverage),
+        "fixed_charge_coverage" => a.fixed_charge_coverage,
+        "dscr" => Some(a.dscr),
+        "ffo_to_debt" => a.ffo_to_debt,
+        "ocf_to_debt" => Some(a.ocf_to_debt),
+        "fcf_to_debt" => Some(a.fcf_to_debt),
+        "fcf" => Some(a.fcf),
+        "cash_conversion" => Some(a.cash_conversion),
+        "current_ratio" => Some(a.current_ratio),
+        "quick_ratio" => Some(a.quick_ratio),
+        "cash_to_debt" => Some(a.cash_to_debt),
+        _ => None,
     }
 }
 
@@ -470,4 +837,166 @@ mod tests {
         assert!(!result.methodology.is_empty());
         assert_eq!(result.metadata.precision, "rust_decimal_128bit");
     }
+
+    #[test]
+    fn test_custom_metric_expression() {
+        let input = CovenantTestInput {
+            covenants: vec![Covenant {
+                name: "Gross vs net leverage gap".into(),
+                metric: CovenantMetric::Custom("total_debt_to_ebitda - net_debt_to_ebitda".into()),
+                threshold: dec!(0.5),
+                direction: CovenantDirection::MaxOf,
+            }],
+            actuals: sample_actuals(),
+        };
+        let result = test_covenants(&input).unwrap();
+        let r = &result.result.results[0];
+        // 2.5 - 2.1 = 0.4
+        assert_eq!(r.actual, dec!(0.4));
+        assert!(r.passing);
+        assert!(result.warnings.is_empty());
+    }
+
+    // -----------------------------------------------------------------------
+    // Formula covenants over a projection
+    // -----------------------------------------------------------------------
+
+    fn period(values: &[(&str, Decimal)]) -> CovenantPeriod {
+        CovenantPeriod {
+            label: None,
+            values: values.iter().map(|(k, v)| (k.to_string(), *v)).collect(),
+        }
+    }
+
+    fn projection_input(formula: &str, periods: Vec<CovenantPeriod>) -> CovenantProjectionInput {
+        CovenantProjectionInput {
+            covenants: vec![FormulaCovenant {
+                name: "Leverage".into(),
+                formula: formula.into(),
+            }],
+            periods,
+            frequency: TestFrequency::Quarterly,
+            definitions: BTreeMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_step_down_applies_after_named_quarter() {
+        let periods = (0..10)
+            .map(|_| period(&[("net_debt", dec!(420)), ("ebitda", dec!(100))]))
+            .collect();
+        let input = projection_input("net_debt/ebitda <= 4.5 stepping to 4.0 after Q8", periods);
+        let out = project_covenants(&input).unwrap();
+        let cov = &out.result.covenants[0];
+        assert_eq!(cov.periods[7].threshold, dec!(4.5));
+        assert!(cov.periods[7].passing);
+        assert_eq!(cov.periods[8].threshold, dec!(4.0));
+        assert!(!cov.periods[8].passing);
+        assert_eq!(cov.first_breach_period, Some(9));
+        let breach = out.result.first_breach.as_ref().unwrap();
+        assert_eq!(breach.period, 9);
+        assert_eq!(breach.actual, dec!(4.2));
+        assert!(!out.result.all_passing);
+        assert!(out
+            .warnings
+            .iter()
+            .any(|w| w.contains("breached in period 9")));
+    }
+
+    #[test]
+    fn test_headroom_waterfall_sums_to_change() {
+        let periods = vec![
+            period(&[("net_debt", dec!(400)), ("ebitda", dec!(100))]),
+            period(&[("net_debt", dec!(360)), ("ebitda", dec!(90))]),
+        ];
+        let input = projection_input("net_debt / ebitda <= 5 then 4.5 from 2", periods);
+        let out = project_covenants(&input).unwrap();
+        let p = &out.result.covenants[0].periods;
+        assert_eq!(p[0].headroom, dec!(1));
+        assert_eq!(p[1].headroom, dec!(0.5));
+        let wf = &p[1].waterfall;
+        let drivers: Vec<&str> = wf.iter().map(|d| d.driver.as_str()).collect();
+        assert_eq!(drivers, vec!["threshold", "net_debt", "ebitda"]);
+        // Step-down costs 0.5x, lower debt adds 0.4x, lower EBITDA costs 0.4x
+        assert_eq!(wf[0].impact, dec!(-0.5));
+        assert_eq!(wf[1].impact, dec!(0.4));
+        assert_eq!(wf[2].impact, dec!(-0.4));
+        let total: Decimal = wf.iter().map(|d| d.impact).sum();
+        assert_eq!(total, p[1].headroom - p[0].headroom);
+    }
+
+    #[test]
+    fn test_definitions_and_minimum_covenant() {
+        let mut input = projection_input(
+            "ebitda / cash_interest >= 3.0x",
+            vec![
+                period(&[
+                    ("ebitda", dec!(120)),
+                    ("interest", dec!(30)),
+                    ("pik", dec!(5)),
+                ]),
+                period(&[
+                    ("ebitda", dec!(100)),
+                    ("interest", dec!(36)),
+                    ("pik", dec!(6)),
+                ]),
+            ],
+        );
+        input
+            .definitions
+            .insert("cash_interest".into(), "interest - pik".into());
+        let out = project_covenants(&input).unwrap();
+        let cov = &out.result.covenants[0];
+        // 120 / 25 = 4.8x; 100 / 30 = 3.33x
+        assert_eq!(cov.periods[0].actual, dec!(4.8));
+        assert_eq!(cov.tightest_period, 2);
+        assert!(out.result.all_passing);
+        // Drivers are the inputs behind the defined term
+        let drivers: Vec<&str> = cov.periods[1]
+            .waterfall
+            .iter()
+            .map(|d| d.driver.as_str())
+            .collect();
+        assert_eq!(drivers, vec!["threshold", "ebitda", "interest", "pik"]);
+    }
+
+    #[test]
+    fn test_annual_frequency_year_steps() {
+        let periods = (0..3).map(|_| period(&[("lev", dec!(3.8))])).collect();
+        let mut input = projection_input("lev <= 4.0 stepping to 3.75 after Y2", periods);
+        input.frequency = TestFrequency::Annual;
+        let out = project_covenants(&input).unwrap();
+        let cov = &out.result.covenants[0];
+        assert!(cov.periods[1].passing);
+        assert!(!cov.periods[2].passing);
+    }
+
+    #[test]
+    fn test_projection_errors() {
+        let input = projection_input(
+            "net_debt / ebitda <= 4.5",
+            vec![period(&[("ebitda", dec!(1))])],
+        );
+        match project_covenants(&input).unwrap_err() {
+            CorpFinanceError::InvalidInput { field, reason } => {
+                assert_eq!(field, "covenants.Leverage");
+                assert!(reason.contains("net_debt"));
+            }
+            other => panic!("Expected InvalidInput, got {other:?}"),
+        }
+
+        let input = projection_input("net_debt / ebitda 4.5", vec![period(&[])]);
+        assert!(project_covenants(&input).is_err());
+
+        let mut input = projection_input("a <= 1", vec![period(&[])]);
+        input.definitions.insert("a".into(), "b + 1".into());
+        input.definitions.insert("b".into(), "a - 1".into());
+        assert!(project_covenants(&input).is_err());
+
+        let input = projection_input("a <= 1", vec![]);
+        assert!(matches!(
+            project_covenants(&input).unwrap_err(),
+            CorpFinanceError::InsufficientData(_)
+        ));
+    }
 }
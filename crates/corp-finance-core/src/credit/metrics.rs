@@ -339,6 +339,56 @@ fn safe_divide(
     Ok(numerator / denominator)
 }
 
+/// Primary rating grid keyed on interest coverage and net-debt/EBITDA, with
+/// the reason for the zone. Shared with the merger model's pro-forma credit
+/// metrics.
+pub(crate) fn coverage_leverage_rating(
+    coverage: Multiple,
+    leverage: Multiple,
+) -> (CreditRating, String) {
+    match (coverage, leverage) {
+        (c, l) if c > dec!(8.0) && l < dec!(1.0) => (
+            CreditRating::AAA,
+            format!("Coverage {c}x > 8.0 and leverage {l}x < 1.0 => AAA zone"),
+        ),
+        (c, l) if c > dec!(6.0) && l < dec!(2.0) => (
+            CreditRating::AA,
+            format!("Coverage {c}x > 6.0 and leverage {l}x < 2.0 => AA zone"),
+        ),
+        (c, l) if c > dec!(5.0) && l < dec!(2.5) => (
+            CreditRating::A,
+            format!("Coverage {c}x > 5.0 and leverage {l}x < 2.5 => A zone"),
+        ),
+        (c, l) if c > dec!(4.0) && l < dec!(3.5) => (
+            CreditRating::BBB,
+            format!("Coverage {c}x > 4.0 and leverage {l}x < 3.5 => BBB zone"),
+        ),
+        (c, l) if c > dec!(3.0) && l < dec!(4.5) => (
+            CreditRating::BB,
+            format!("Coverage {c}x > 3.0 and leverage {l}x < 4.5 => BB zone"),
+        ),
+        (c, l) if c > dec!(2.0) && l < dec!(5.5) => (
+            CreditRating::B,
+            format!("Coverage {c}x > 2.0 and leverage {l}x < 5.5 => B zone"),
+        ),
+        (c, l) if c > dec!(1.0) && l < dec!(7.0) => (
+            CreditRating::CCC,
+            format!("Coverage {c}x > 1.0 and leverage {l}x < 7.0 => CCC zone"),
+        ),
+        (c, _) if c > dec!(0.5) => (
+            CreditRating::CC,
+            format!("Coverage {c}x > 0.5 but high leverage => CC zone"),
+        ),
+        (c, _) if c > Decimal::ZERO => {
+            (CreditRating::C, format!("Marginal coverage {c}x => C zone"))
+        }
+        _ => (
+            CreditRating::D,
+            "Zero or negative coverage => D (default)".into(),
+        ),
+    }
+}
+
 /// Derive a synthetic credit rating from key ratios.
 ///
 /// The mapping follows a simplified Damodaran-style grid keyed primarily on
@@ -354,62 +404,8 @@ fn derive_synthetic_rating(
     let mut rationale = Vec::new();
 
     // Primary grid: coverage x leverage
-    let base_rating = match (coverage, leverage) {
-        (c, l) if c > dec!(8.0) && l < dec!(1.0) => {
-            rationale.push(format!(
-                "Coverage {c}x > 8.0 and leverage {l}x < 1.0 => AAA zone"
-            ));
-            CreditRating::AAA
-        }
-        (c, l) if c > dec!(6.0) && l < dec!(2.0) => {
-            rationale.push(format!(
-                "Coverage {c}x > 6.0 and leverage {l}x < 2.0 => AA zone"
-            ));
-            CreditRating::AA
-        }
-        (c, l) if c > dec!(5.0) && l < dec!(2.5) => {
-            rationale.push(format!(
-                "Coverage {c}x > 5.0 and leverage {l}x < 2.5 => A zone"
-            ));
-            CreditRating::A
-        }
-        (c, l) if c > dec!(4.0) && l < dec!(3.5) => {
-            rationale.push(format!(
-                "Coverage {c}x > 4.0 and leverage {l}x < 3.5 => BBB zone"
-            ));
-            CreditRating::BBB
-        }
-        (c, l) if c > dec!(3.0) && l < dec!(4.5) => {
-            rationale.push(format!(
-                "Coverage {c}x > 3.0 and leverage {l}x < 4.5 => BB zone"
-            ));
-            CreditRating::BB
-        }
-        (c, l) if c > dec!(2.0) && l < dec!(5.5) => {
-            rationale.push(format!(
-                "Coverage {c}x > 2.0 and leverage {l}x < 5.5 => B zone"
-            ));
-            CreditRating::B
-        }
-        (c, l) if c > dec!(1.0) && l < dec!(7.0) => {
-            rationale.push(format!(
-                "Coverage {c}x > 1.0 and leverage {l}x < 7.0 => CCC zone"
-            ));
-            CreditRating::CCC
-        }
-        (c, _) if c > dec!(0.5) => {
-            rationale.push(format!("Coverage {c}x > 0.5 but high leverage => CC zone"));
-            CreditRating::CC
-        }
-        (c, _) if c > Decimal::ZERO => {
-            rationale.push(format!("Marginal coverage {c}x => C zone"));
-            CreditRating::C
-        }
-        _ => {
-            rationale.push("Zero or negative coverage => D (default)".into());
-            CreditRating::D
-        }
-    };
+    let (base_rating, reason) = coverage_leverage_rating(coverage, leverage);
+    rationale.push(reason);
 
     // Secondary modifiers (informational; do not notch for simplicity)
     if debt_to_equity > dec!(3.0) {
@@ -1,4 +1,6 @@
 pub mod altman;
 pub mod capacity;
+pub mod counterparty_exposure;
+pub mod covenant_formula;
 pub mod covenants;
 pub mod metrics;
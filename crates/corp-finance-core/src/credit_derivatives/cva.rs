@@ -1,7 +1,9 @@
 use rust_decimal::Decimal;
+use rust_decimal::MathematicalOps;
 use rust_decimal_macros::dec;
 use serde::{Deserialize, Serialize};
 
+use crate::stats::{norm_cdf, norm_pdf};
 use crate::types::*;
 use crate::{CorpFinanceError, CorpFinanceResult};
 
@@ -40,6 +42,12 @@ pub struct CvaInput {
     /// Collateral posting threshold (exposure above this is collateralised)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub collateral_threshold: Option<Money>,
+    /// Correlation between exposure and counterparty default in a Gaussian
+    /// copula. Positive values model wrong-way risk (exposure rises as the
+    /// counterparty weakens, e.g. commodity producers or EM sovereigns),
+    /// negative values right-way risk.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wrong_way_correlation: Option<Decimal>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -60,6 +68,27 @@ pub struct CvaRiskMetrics {
     pub exposure_reduction_pct: Rate,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConditionalExposure {
+    pub time_years: Decimal,
+    /// Exposure after netting and collateral, independent of default
+    pub expected_exposure: Money,
+    /// Expected exposure conditional on counterparty default at this time
+    pub exposure_given_default: Money,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WrongWayRisk {
+    pub correlation: Decimal,
+    /// CVA with exposure independent of default
+    pub independent_cva: Money,
+    /// unilateral_cva - independent_cva
+    pub wrong_way_adjustment: Money,
+    /// unilateral_cva / independent_cva
+    pub cva_multiplier: Decimal,
+    pub conditional_exposure_profile: Vec<ConditionalExposure>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CvaOutput {
     pub trade_description: String,
@@ -78,6 +107,9 @@ pub struct CvaOutput {
     /// Exposure after netting and collateral
     pub adjusted_exposure_profile: Vec<AdjustedExposure>,
     pub risk_metrics: CvaRiskMetrics,
+    /// Dependency between exposure and default, when a correlation is given
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wrong_way_risk: Option<WrongWayRisk>,
 }
 
 // ---------------------------------------------------------------------------
@@ -87,7 +119,10 @@ pub struct CvaOutput {
 /// Calculate Credit Valuation Adjustment (CVA) and Debit Valuation Adjustment (DVA).
 ///
 /// Computes unilateral and bilateral CVA using a discrete marginal-default-probability
-/// framework, with optional netting and collateral adjustments.
+/// framework, with optional netting and collateral adjustments. With a
+/// `wrong_way_correlation`, each bucket's exposure is replaced by its
+/// expectation conditional on default at that time (Pykhtin-Rosen Gaussian
+/// copula with normally distributed exposure).
 pub fn calculate_cva(input: &CvaInput) -> CorpFinanceResult<CvaOutput> {
     validate_cva_input(input)?;
 
@@ -132,7 +167,9 @@ pub fn calculate_cva(input: &CvaInput) -> CorpFinanceResult<CvaOutput> {
     // Survival prob S(t) = (1 - PD)^t via iterative multiplication
     // We need to handle arbitrary time points, so compute S(t) for each point.
     let mut unilateral_cva = Decimal::ZERO;
+    let mut independent_cva = Decimal::ZERO;
     let mut dva = Decimal::ZERO;
+    let mut conditional_profile: Vec<ConditionalExposure> = Vec::new();
 
     let mut exposure_time_sum = Decimal::ZERO;
     let mut exposure_discount_sum = Decimal::ZERO;
@@ -168,8 +205,21 @@ pub fn calculate_cva(input: &CvaInput) -> CorpFinanceResult<CvaOutput> {
         // Discount factor: 1/(1+r)^t
         let df = discount_factor_at(input.risk_free_rate, t);
 
-        // CVA contribution: LGD_c * marginal_PD_c * D(t) * EPE(t)
-        unilateral_cva += c_lgd * marginal_c_pd * df * epe;
+        // CVA contribution: LGD_c * marginal_PD_c * D(t) * EPE(t | default at t)
+        independent_cva += c_lgd * marginal_c_pd * df * epe;
+        match input.wrong_way_correlation {
+            Some(rho) => {
+                let epe_given_default =
+                    exposure_given_default(epe, Decimal::ONE - c_survival_t, rho);
+                unilateral_cva += c_lgd * marginal_c_pd * df * epe_given_default;
+                conditional_profile.push(ConditionalExposure {
+                    time_years: t,
+                    expected_exposure: epe,
+                    exposure_given_default: epe_given_default,
+                });
+            }
+            None => unilateral_cva += c_lgd * marginal_c_pd * df * epe,
+        }
 
         // DVA contribution (if own PD provided)
         if let Some(own_pd) = input.own_default_probability {
@@ -191,6 +241,18 @@ pub fn calculate_cva(input: &CvaInput) -> CorpFinanceResult<CvaOutput> {
 
     let bilateral_cva = unilateral_cva - dva;
 
+    let wrong_way_risk = input.wrong_way_correlation.map(|rho| WrongWayRisk {
+        correlation: rho,
+        independent_cva,
+        wrong_way_adjustment: unilateral_cva - independent_cva,
+        cva_multiplier: if independent_cva.is_zero() {
+            Decimal::ONE
+        } else {
+            unilateral_cva / independent_cva
+        },
+        conditional_exposure_profile: conditional_profile,
+    });
+
     // Expected loss = CVA (it is the expected credit loss)
     let expected_loss = unilateral_cva;
 
@@ -247,6 +309,7 @@ pub fn calculate_cva(input: &CvaInput) -> CorpFinanceResult<CvaOutput> {
         expected_loss,
         adjusted_exposure_profile: adjusted_exposures,
         risk_metrics,
+        wrong_way_risk,
     })
 }
 
@@ -307,6 +370,14 @@ fn validate_cva_input(input: &CvaInput) -> CorpFinanceResult<()> {
             });
         }
     }
+    if let Some(rho) = input.wrong_way_correlation {
+        if rho <= dec!(-1) || rho >= Decimal::ONE {
+            return Err(CorpFinanceError::InvalidInput {
+                field: "wrong_way_correlation".into(),
+                reason: "Wrong-way correlation must be in (-1, 1).".into(),
+            });
+        }
+    }
     for (i, ep) in input.expected_exposure_profile.iter().enumerate() {
         if ep.expected_exposure < Decimal::ZERO {
             return Err(CorpFinanceError::InvalidInput {
@@ -328,11 +399,53 @@ fn validate_cva_input(input: &CvaInput) -> CorpFinanceResult<()> {
 // Math helpers
 // ---------------------------------------------------------------------------
 
+/// Expected positive exposure conditional on counterparty default at a time
+/// with cumulative default probability `cumulative_pd`.
+///
+/// Exposure is taken as V = sigma * Z with sigma = EE * sqrt(2*pi), so that
+/// E[max(V, 0)] = EE, and default as Y <= N^-1(PD) with corr(Z, -Y) = rho.
+/// Given default, V is normal with mean mu = -rho * sigma * N^-1(PD) and
+/// standard deviation s = sigma * sqrt(1 - rho^2), giving
+/// E[max(V, 0) | default] = mu * N(mu / s) + s * n(mu / s).
+fn exposure_given_default(expected_exposure: Money, cumulative_pd: Rate, rho: Decimal) -> Money {
+    if expected_exposure.is_zero()
+        || rho.is_zero()
+        || cumulative_pd <= Decimal::ZERO
+        || cumulative_pd >= Decimal::ONE
+    {
+        return expected_exposure;
+    }
+    let sigma = expected_exposure * dec!(2.5066282746310002);
+    let mu = -rho * sigma * norm_inv(cumulative_pd);
+    let s = sigma * (Decimal::ONE - rho * rho).sqrt().unwrap_or(Decimal::ZERO);
+    if s.is_zero() {
+        return mu.max(Decimal::ZERO);
+    }
+    let z = mu / s;
+    mu * norm_cdf(z) + s * norm_pdf(z)
+}
+
+/// Inverse standard normal CDF (Abramowitz & Stegun 26.2.23).
+fn norm_inv(p: Decimal) -> Decimal {
+    if p == dec!(0.5) {
+        return Decimal::ZERO;
+    }
+    let (tail, sign) = if p < dec!(0.5) {
+        (p, dec!(-1))
+    } else {
+        (Decimal::ONE - p, Decimal::ONE)
+    };
+    let t = (dec!(-2) * tail.ln()).sqrt().unwrap_or(Decimal::ZERO);
+    let numer = dec!(2.515517) + t * (dec!(0.802853) + t * dec!(0.010328));
+    let denom = Decimal::ONE + t * (dec!(1.432788) + t * (dec!(0.189269) + t * dec!(0.001308)));
+    sign * (t - numer / denom)
+}
+
 /// Compute discount factor at time t: D(t) = 1 / (1+r)^t.
 ///
 /// For integer years, use iterative multiplication. For fractional years,
 /// decompose into integer part (iterative) and fractional part (nth root).
-fn discount_factor_at(rate: Rate, t: Decimal) -> Decimal {
+pub(crate) fn discount_factor_at(rate: Rate, t: Decimal) -> Decimal {
     if t.is_zero() {
         return Decimal::ONE;
     }
@@ -421,6 +534,7 @@ mod tests {
             risk_free_rate: dec!(0.05),
             netting_benefit: None,
             collateral_threshold: None,
+            wrong_way_correlation: None,
         }
     }
 
@@ -647,6 +761,7 @@ mod tests {
             risk_free_rate: dec!(0.03),
             netting_benefit: None,
             collateral_threshold: None,
+            wrong_way_correlation: None,
         };
 
         let result = calculate_cva(&input).unwrap();
@@ -814,4 +929,111 @@ mod tests {
         let diff2 = (df2 - expected2).abs();
         assert!(diff2 < dec!(0.0001), "DF(2) should be ~0.9070, got {}", df2);
     }
+
+    // -- Wrong-way risk --
+
+    #[test]
+    fn test_zero_correlation_matches_independent_cva() {
+        let mut input = basic_cva_input();
+        input.wrong_way_correlation = Some(Decimal::ZERO);
+        let result = calculate_cva(&input).unwrap();
+        let independent = calculate_cva(&basic_cva_input()).unwrap();
+
+        assert_eq!(result.unilateral_cva, independent.unilateral_cva);
+        let wwr = result.wrong_way_risk.unwrap();
+        assert_eq!(wwr.cva_multiplier, Decimal::ONE);
+        assert!(wwr.wrong_way_adjustment.is_zero());
+        assert!(independent.wrong_way_risk.is_none());
+    }
+
+    #[test]
+    fn test_wrong_way_risk_increases_cva() {
+        let mut input = basic_cva_input();
+        input.wrong_way_correlation = Some(dec!(0.3));
+        let result = calculate_cva(&input).unwrap();
+        let independent = calculate_cva(&basic_cva_input()).unwrap();
+
+        let wwr = result.wrong_way_risk.as_ref().unwrap();
+        assert_eq!(wwr.independent_cva, independent.unilateral_cva);
+        assert!(result.unilateral_cva > independent.unilateral_cva);
+        assert!(wwr.cva_multiplier > Decimal::ONE);
+        assert_eq!(
+            wwr.wrong_way_adjustment,
+            result.unilateral_cva - independent.unilateral_cva
+        );
+        for point in &wwr.conditional_exposure_profile {
+            assert!(point.exposure_given_default > point.expected_exposure);
+        }
+        // DVA does not depend on counterparty wrong-way risk
+        assert_eq!(result.dva, independent.dva);
+    }
+
+    #[test]
+    fn test_right_way_risk_decreases_cva() {
+        let mut input = basic_cva_input();
+        input.wrong_way_correlation = Some(dec!(-0.3));
+        let result = calculate_cva(&input).unwrap();
+        let independent = calculate_cva(&basic_cva_input()).unwrap();
+
+        assert!(result.unilateral_cva < independent.unilateral_cva);
+        assert!(result.wrong_way_risk.unwrap().cva_multiplier < Decimal::ONE);
+    }
+
+    #[test]
+    fn test_cva_increases_with_correlation() {
+        let cva_at = |rho| {
+            let mut input = basic_cva_input();
+            input.wrong_way_correlation = Some(rho);
+            calculate_cva(&input).unwrap().unilateral_cva
+        };
+        assert!(cva_at(dec!(0.1)) < cva_at(dec!(0.3)));
+        assert!(cva_at(dec!(0.3)) < cva_at(dec!(0.6)));
+    }
+
+    #[test]
+    fn test_exposure_given_default_closed_form() {
+        // rho = 0.5, PD = 2%: E[max(V,0) | default] = 2.6994 * EE
+        let ee = exposure_given_default(dec!(1_000_000), dec!(0.02), dec!(0.5));
+        assert!(
+            (ee - dec!(2_699_387)).abs() < dec!(2_000),
+            "Expected ~2,699,387, got {}",
+            ee
+        );
+        assert_eq!(
+            exposure_given_default(dec!(1_000_000), Decimal::ZERO, dec!(0.5)),
+            dec!(1_000_000)
+        );
+    }
+
+    #[test]
+    fn test_high_correlation_low_pd_does_not_underflow() {
+        // rho = 0.99, PD = 0.01%: z ~ 26, so the conditional exposure is
+        // essentially its mean mu = rho * sqrt(2 pi) * EE * 3.719
+        let ee = exposure_given_default(dec!(1_000_000), dec!(0.0001), dec!(0.99));
+        assert!(
+            (ee - dec!(9_230_000)).abs() < dec!(10_000),
+            "Expected ~9,230,000, got {}",
+            ee
+        );
+        assert!(exposure_given_default(dec!(1_000_000), dec!(0.0001), dec!(-0.99)) < dec!(1));
+
+        let mut input = basic_cva_input();
+        input.counterparty_default_probability = dec!(0.0001);
+        input.wrong_way_correlation = Some(dec!(0.99));
+        let result = calculate_cva(&input).unwrap();
+        assert!(result.wrong_way_risk.unwrap().cva_multiplier > Decimal::ONE);
+    }
+
+    #[test]
+    fn test_invalid_wrong_way_correlation() {
+        let mut input = basic_cva_input();
+        input.wrong_way_correlation = Some(Decimal::ONE);
+        let err = calculate_cva(&input).unwrap_err();
+        match err {
+            CorpFinanceError::InvalidInput { field, .. } => {
+                assert_eq!(field, "wrong_way_correlation")
+            }
+            other => panic!("Expected InvalidInput, got {other:?}"),
+        }
+    }
 }
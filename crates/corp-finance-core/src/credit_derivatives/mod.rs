@@ -1,2 +1,3 @@
 pub mod cds;
 pub mod cva;
+pub mod xva;
@@ -5,6 +5,7 @@ use std::collections::HashMap;
 use std::time::Instant;
 
 use crate::error::CorpFinanceError;
+use crate::ratings::{self, Rating};
 use crate::types::{with_metadata, ComputationOutput};
 use crate::CorpFinanceResult;
 
@@ -81,6 +82,10 @@ pub struct PortfolioRiskOutput {
     pub portfolio_pd: Decimal,
     /// Exposure-weighted average LGD
     pub portfolio_lgd: Decimal,
+    /// Weighted Average Rating Factor (Moody's factors) of rated exposures
+    pub warf: Option<Decimal>,
+    /// Rating implied by the WARF
+    pub weighted_average_rating: Option<Rating>,
     /// 1 - (portfolio UL / sum of standalone ULs)
     pub diversification_benefit: Decimal,
     pub methodology: String,
@@ -500,6 +505,21 @@ pub fn calculate_portfolio_risk(
         Decimal::ZERO
     };
 
+    // Exposures with unrecognised ratings are left out of the rating summary.
+    let rated: Vec<(Rating, Decimal)> = input
+        .exposures
+        .iter()
+        .filter_map(|e| Rating::parse(&e.rating).ok().map(|r| (r, e.exposure)))
+        .collect();
+    if rated.len() < input.exposures.len() {
+        warnings.push(format!(
+            "{} exposure(s) with unrecognised ratings excluded from WARF",
+            input.exposures.len() - rated.len()
+        ));
+    }
+    let warf = ratings::warf(&rated).ok();
+    let weighted_average_rating = warf.map(Rating::from_rating_factor);
+
     let mut assumptions = HashMap::new();
     assumptions.insert(
         "model".into(),
@@ -538,6 +558,8 @@ pub fn calculate_portfolio_risk(
         },
         portfolio_pd,
         portfolio_lgd,
+        warf,
+        weighted_average_rating,
         diversification_benefit,
         methodology: "Vasicek single-factor / Gaussian copula".into(),
         assumptions,
@@ -1463,4 +1485,33 @@ mod tests {
             expected_pct
         );
     }
+
+    // -----------------------------------------------------------------------
+    // Rating summary
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_warf_and_weighted_average_rating() {
+        let input = five_exposure_input();
+        let result = calculate_portfolio_risk(&input).unwrap();
+        // (120 + 360 + 1350 + 360 + 1350) / 5 = 708 => Baa3 (610)
+        assert_eq!(result.result.warf, Some(dec!(708)));
+        assert_eq!(
+            result.result.weighted_average_rating,
+            Some(Rating::BBBMinus)
+        );
+    }
+
+    #[test]
+    fn test_unrecognised_rating_excluded_from_warf() {
+        let mut input = five_exposure_input();
+        input.exposures[0].rating = "NR".into();
+        let result = calculate_portfolio_risk(&input).unwrap();
+        // (360 + 1350 + 360 + 1350) / 4 = 855
+        assert_eq!(result.result.warf, Some(dec!(855)));
+        assert!(result
+            .warnings
+            .iter()
+            .any(|w| w.contains("unrecognised ratings")));
+    }
 }
@@ -0,0 +1,926 @@
+//! Versioned JSON formats for yield curves and volatility surfaces.
+//!
+//! A curve or surface carries its nodes together with the context needed to
+//! read between them (compounding, interpolation, extrapolation, day count
+//! and calibration date), so a curve bootstrapped in one module prices
+//! consistently in another. Curves also accept the older bare
+//! `[{"maturity": .., "rate": ..}]` arrays; conventions those leave unstated
+//! fall back to the consuming module's (see [`Curve::with_conventions`]).
+
+use chrono::NaiveDate;
+use rust_decimal::prelude::MathematicalOps;
+use rust_decimal::Decimal;
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+
+use crate::types::{DayCountConvention, Rate, Years};
+use crate::{CorpFinanceError, CorpFinanceResult};
+
+/// Current version of the curve format.
+pub const CURVE_FORMAT_VERSION: u32 = 1;
+/// Current version of the volatility surface format.
+pub const VOL_SURFACE_FORMAT_VERSION: u32 = 1;
+
+// ---------------------------------------------------------------------------
+// Conventions
+// ---------------------------------------------------------------------------
+
+/// How the zero rates of a curve are compounded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Compounding {
+    Continuous,
+    Annual,
+    SemiAnnual,
+    Quarterly,
+    Monthly,
+    /// Simple interest, money-market style
+    Simple,
+}
+
+impl Compounding {
+    fn periods(self) -> Option<Decimal> {
+        match self {
+            Compounding::Annual => Some(Decimal::ONE),
+            Compounding::SemiAnnual => Some(Decimal::TWO),
+            Compounding::Quarterly => Some(Decimal::from(4)),
+            Compounding::Monthly => Some(Decimal::from(12)),
+            Compounding::Continuous | Compounding::Simple => None,
+        }
+    }
+
+    /// Discount factor to `t` years for a zero rate quoted this way.
+    pub fn discount_factor(self, rate: Rate, t: Years) -> Decimal {
+        match (self, self.periods()) {
+            (Compounding::Continuous, _) => (-rate * t).exp(),
+            (Compounding::Simple, _) => Decimal::ONE / (Decimal::ONE + rate * t),
+            (_, Some(m)) => (Decimal::ONE + rate / m).powd(-m * t),
+            (_, None) => Decimal::ONE,
+        }
+    }
+
+    /// Zero rate quoted this way that gives discount factor `df` at `t`.
+    pub fn rate_from_discount_factor(self, df: Decimal, t: Years) -> Rate {
+        if t <= Decimal::ZERO || df <= Decimal::ZERO {
+            return Decimal::ZERO;
+        }
+        match (self, self.periods()) {
+            (Compounding::Continuous, _) => -df.ln() / t,
+            (Compounding::Simple, _) => (Decimal::ONE / df - Decimal::ONE) / t,
+            (_, Some(m)) => m * (df.powd(-Decimal::ONE / (m * t)) - Decimal::ONE),
+            (_, None) => Decimal::ZERO,
+        }
+    }
+}
+
+/// How a curve is read between its nodes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CurveInterpolation {
+    /// Linear in zero rates
+    Linear,
+    /// Linear in log discount factors (piecewise flat forwards)
+    LogLinearDiscount,
+    /// Natural cubic spline through the zero rates
+    CubicSpline,
+}
+
+/// How a curve or smile is read beyond its first and last nodes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Extrapolation {
+    /// Hold the nearest node's value
+    #[default]
+    Flat,
+    /// Extend the line through the two nearest nodes
+    Linear,
+}
+
+/// What the node values of a curve are.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CurveValue {
+    #[default]
+    ZeroRate,
+    DiscountFactor,
+}
+
+/// Conventions a consuming module assumes for curves that do not state
+/// their own.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CurveConventions {
+    pub compounding: Compounding,
+    pub interpolation: CurveInterpolation,
+    pub extrapolation: Extrapolation,
+}
+
+// ---------------------------------------------------------------------------
+// Curve
+// ---------------------------------------------------------------------------
+
+/// One tenor of a curve.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CurveNode {
+    /// Years from the calibration date
+    pub tenor: Years,
+    /// Zero rate or discount factor, per the curve's `value_type`
+    pub value: Decimal,
+    /// Market label such as "3M" or "10Y"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+}
+
+/// A yield or discount curve in the versioned format:
+///
+/// ```json
+/// { "version": 1, "id": "USD-SOFR", "currency": "USD",
+///   "calibration_date": "2024-06-28", "day_count": "Actual360",
+///   "compounding": "continuous", "interpolation": "log_linear_discount",
+///   "extrapolation": "flat", "value_type": "zero_rate",
+///   "nodes": [{ "tenor": 0.25, "value": 0.0531, "label": "3M" }] }
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Curve {
+    pub version: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub calibration_date: Option<NaiveDate>,
+    /// Convention that turned dates into the node tenors
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub day_count: Option<DayCountConvention>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compounding: Option<Compounding>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interpolation: Option<CurveInterpolation>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extrapolation: Option<Extrapolation>,
+    pub value_type: CurveValue,
+    pub nodes: Vec<CurveNode>,
+}
+
+impl Default for Curve {
+    fn default() -> Self {
+        Curve {
+            version: CURVE_FORMAT_VERSION,
+            id: None,
+            currency: None,
+            calibration_date: None,
+            day_count: None,
+            compounding: None,
+            interpolation: None,
+            extrapolation: None,
+            value_type: CurveValue::ZeroRate,
+            nodes: Vec::new(),
+        }
+    }
+}
+
+/// The fields of the versioned format, before the version is checked.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct CurveDocument {
+    version: u32,
+    #[serde(default)]
+    id: Option<String>,
+    #[serde(default)]
+    currency: Option<String>,
+    #[serde(default)]
+    calibration_date: Option<NaiveDate>,
+    #[serde(default)]
+    day_count: Option<DayCountConvention>,
+    #[serde(default)]
+    compounding: Option<Compounding>,
+    #[serde(default)]
+    interpolation: Option<CurveInterpolation>,
+    #[serde(default)]
+    extrapolation: Option<Extrapolation>,
+    #[serde(default)]
+    value_type: CurveValue,
+    nodes: Vec<CurveNode>,
+}
+
+/// A point of the bare arrays curves were passed as before the format.
+#[derive(Deserialize)]
+struct LegacyPoint {
+    maturity: Years,
+    rate: Rate,
+}
+
+impl<'de> Deserialize<'de> for Curve {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match Value::deserialize(deserializer)? {
+            Value::Array(points) => {
+                let points: Vec<LegacyPoint> =
+                    serde_json::from_value(Value::Array(points)).map_err(D::Error::custom)?;
+                Ok(Curve::from_zero_rates(
+                    points.into_iter().map(|p| (p.maturity, p.rate)),
+                ))
+            }
+            value @ Value::Object(_) => {
+                let doc: CurveDocument = serde_json::from_value(value).map_err(D::Error::custom)?;
+                if doc.version != CURVE_FORMAT_VERSION {
+                    return Err(D::Error::custom(format!(
+                        "unsupported curve format version {} (expected {CURVE_FORMAT_VERSION})",
+                        doc.version
+                    )));
+                }
+                Ok(Curve {
+                    version: doc.version,
+                    id: doc.id,
+                    currency: doc.currency,
+                    calibration_date: doc.calibration_date,
+                    day_count: doc.day_count,
+                    compounding: doc.compounding,
+                    interpolation: doc.interpolation,
+                    extrapolation: doc.extrapolation,
+                    value_type: doc.value_type,
+                    nodes: doc.nodes,
+                })
+            }
+            other => Err(D::Error::custom(format!(
+                "expected a curve object or an array of {{maturity, rate}} points, got {other}"
+            ))),
+        }
+    }
+}
+
+impl Curve {
+    /// A zero-rate curve with no stated conventions.
+    pub fn from_zero_rates(points: impl IntoIterator<Item = (Years, Rate)>) -> Curve {
+        Curve {
+            nodes: points
+                .into_iter()
+                .map(|(tenor, value)| CurveNode {
+                    tenor,
+                    value,
+                    label: None,
+                })
+                .collect(),
+            ..Curve::default()
+        }
+    }
+
+    /// Fill the conventions the curve leaves unstated with a module's own.
+    pub fn with_conventions(&self, defaults: CurveConventions) -> Curve {
+        Curve {
+            compounding: self.compounding.or(Some(defaults.compounding)),
+            interpolation: self.interpolation.or(Some(defaults.interpolation)),
+            extrapolation: self.extrapolation.or(Some(defaults.extrapolation)),
+            ..self.clone()
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    pub fn tenors(&self) -> Vec<Years> {
+        self.nodes.iter().map(|n| n.tenor).collect()
+    }
+
+    fn compounding(&self) -> Compounding {
+        self.compounding.unwrap_or(Compounding::Continuous)
+    }
+
+    /// Check the version, that tenors are non-negative and strictly
+    /// ascending, and that discount factors are positive. `field` names the
+    /// curve in errors.
+    pub fn validate(&self, field: &str) -> CorpFinanceResult<()> {
+        if self.version != CURVE_FORMAT_VERSION {
+            return Err(CorpFinanceError::InvalidInput {
+                field: format!("{field}.version"),
+                reason: format!(
+                    "unsupported curve format version {} (expected {CURVE_FORMAT_VERSION})",
+                    self.version
+                ),
+            });
+        }
+        for (i, node) in self.nodes.iter().enumerate() {
+            if node.tenor < Decimal::ZERO {
+                return Err(CorpFinanceError::InvalidInput {
+                    field: format!("{field}.nodes[{i}].tenor"),
+                    reason: "Tenor cannot be negative".into(),
+                });
+            }
+            if self.value_type == CurveValue::DiscountFactor && node.value <= Decimal::ZERO {
+                return Err(CorpFinanceError::InvalidInput {
+                    field: format!("{field}.nodes[{i}].value"),
+                    reason: "Discount factors must be positive".into(),
+                });
+            }
+        }
+        if self.nodes.windows(2).any(|w| w[1].tenor <= w[0].tenor) {
+            return Err(CorpFinanceError::InvalidInput {
+                field: field.into(),
+                reason: "Curve nodes must be sorted by ascending tenor".into(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Nodes as (tenor, zero rate) in the curve's compounding. A discount
+    /// factor at tenor zero carries no rate and is skipped.
+    fn zero_nodes(&self) -> Vec<(Years, Rate)> {
+        match self.value_type {
+            CurveValue::ZeroRate => self.nodes.iter().map(|n| (n.tenor, n.value)).collect(),
+            CurveValue::DiscountFactor => self
+                .nodes
+                .iter()
+                .filter(|n| n.tenor > Decimal::ZERO)
+                .map(|n| {
+                    (
+                        n.tenor,
+                        self.compounding()
+                            .rate_from_discount_factor(n.value, n.tenor),
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    /// Zero rate to `t` in the curve's own compounding (continuous when
+    /// unstated).
+    pub fn zero_rate(&self, t: Years) -> Rate {
+        let nodes = self.zero_nodes();
+        let interpolation = self.interpolation.unwrap_or(CurveInterpolation::Linear);
+        let extrapolation = self.extrapolation.unwrap_or_default();
+        let (xs, ys): (Vec<Decimal>, Vec<Decimal>) = nodes.iter().copied().unzip();
+        let inside =
+            xs.first().is_some_and(|first| t >= *first) && xs.last().is_some_and(|last| t <= *last);
+        if interpolation != CurveInterpolation::LogLinearDiscount || !inside || xs.contains(&t) {
+            let method = match interpolation {
+                CurveInterpolation::CubicSpline => Interpolation::CubicSpline,
+                _ => Interpolation::Linear,
+            };
+            return interpolate(&xs, &ys, t, method, extrapolation);
+        }
+
+        // Linear in ln DF between the bracketing nodes
+        let compounding = self.compounding();
+        let idx = xs.partition_point(|x| *x < t);
+        let (t0, t1) = (xs[idx - 1], xs[idx]);
+        let ln_df = |i: usize| compounding.discount_factor(ys[i], xs[i]).ln();
+        let weight = (t - t0) / (t1 - t0);
+        let ln_df_t = ln_df(idx - 1) + weight * (ln_df(idx) - ln_df(idx - 1));
+        compounding.rate_from_discount_factor(ln_df_t.exp(), t)
+    }
+
+    /// Zero rate to `t` expressed with `compounding`.
+    pub fn zero_rate_as(&self, t: Years, compounding: Compounding) -> Rate {
+        let rate = self.zero_rate(t);
+        if compounding == self.compounding() || t <= Decimal::ZERO {
+            return rate;
+        }
+        compounding.rate_from_discount_factor(self.compounding().discount_factor(rate, t), t)
+    }
+
+    pub fn discount_factor(&self, t: Years) -> Decimal {
+        if t <= Decimal::ZERO {
+            return Decimal::ONE;
+        }
+        self.compounding().discount_factor(self.zero_rate(t), t)
+    }
+
+    /// Forward rate between `t1` and `t2` expressed with `compounding`.
+    pub fn forward_rate(&self, t1: Years, t2: Years, compounding: Compounding) -> Rate {
+        if t2 <= t1 {
+            return self.zero_rate_as(t1, compounding);
+        }
+        let df1 = self.discount_factor(t1);
+        let df2 = self.discount_factor(t2);
+        compounding.rate_from_discount_factor(df2 / df1, t2 - t1)
+    }
+
+    /// The curve with every zero rate moved by `shift` (a parallel shift;
+    /// 0.0001 is one basis point).
+    pub fn shifted(&self, shift: Rate) -> Curve {
+        self.shifted_by(|_| shift)
+    }
+
+    /// The curve with the zero rate at each node moved by `shift(tenor)`,
+    /// for twists and key-rate shifts.
+    pub fn shifted_by(&self, shift: impl Fn(Years) -> Rate) -> Curve {
+        let zero_nodes = self.zero_nodes();
+        let labels = self
+            .nodes
+            .iter()
+            .filter(|n| self.value_type == CurveValue::ZeroRate || n.tenor > Decimal::ZERO);
+        Curve {
+            value_type: CurveValue::ZeroRate,
+            nodes: zero_nodes
+                .into_iter()
+                .zip(labels)
+                .map(|((tenor, rate), node)| CurveNode {
+                    tenor,
+                    value: rate + shift(tenor),
+                    label: node.label.clone(),
+                })
+                .collect(),
+            ..self.clone()
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Volatility surface
+// ---------------------------------------------------------------------------
+
+/// What the strikes of a volatility surface are measured in.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StrikeAxis {
+    #[default]
+    Absolute,
+    /// Strike / spot
+    Moneyness,
+}
+
+/// How a smile is read between strikes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SmileInterpolation {
+    #[default]
+    Linear,
+    CubicSpline,
+}
+
+/// How a surface is read between expiries.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TimeInterpolation {
+    /// Linear in total variance (vol^2 * T), free of calendar arbitrage
+    /// when the slices are
+    #[default]
+    TotalVariance,
+    /// Linear in volatility
+    Linear,
+}
+
+/// One quoted point of a volatility surface.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VolNode {
+    /// Years to expiry
+    pub expiry: Years,
+    pub strike: Decimal,
+    pub vol: Rate,
+}
+
+/// An implied volatility surface in the versioned format:
+///
+/// ```json
+/// { "version": 1, "underlying": "SPX", "calibration_date": "2024-06-28",
+///   "spot": 5460, "strike_axis": "moneyness",
+///   "smile_interpolation": "cubic_spline", "time_interpolation": "total_variance",
+///   "extrapolation": "flat",
+///   "nodes": [{ "expiry": 0.25, "strike": 1.0, "vol": 0.14 }] }
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VolSurface {
+    pub version: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub underlying: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub calibration_date: Option<NaiveDate>,
+    /// Convention that turned expiry dates into year fractions
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub day_count: Option<DayCountConvention>,
+    /// Spot at calibration; required for a moneyness strike axis
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub spot: Option<Decimal>,
+    #[serde(default)]
+    pub strike_axis: StrikeAxis,
+    #[serde(default)]
+    pub smile_interpolation: SmileInterpolation,
+    #[serde(default)]
+    pub time_interpolation: TimeInterpolation,
+    /// Applies across strikes; beyond the first and last expiries the
+    /// nearest slice's volatility is held
+    #[serde(default)]
+    pub extrapolation: Extrapolation,
+    pub nodes: Vec<VolNode>,
+}
+
+impl VolSurface {
+    /// Check the version and that every node and the spot are usable.
+    pub fn validate(&self, field: &str) -> CorpFinanceResult<()> {
+        if self.version != VOL_SURFACE_FORMAT_VERSION {
+            return Err(CorpFinanceError::InvalidInput {
+                field: format!("{field}.version"),
+                reason: format!(
+                    "unsupported volatility surface format version {} (expected {VOL_SURFACE_FORMAT_VERSION})",
+                    self.version
+                ),
+            });
+        }
+        if self.nodes.is_empty() {
+            return Err(CorpFinanceError::InsufficientData(format!(
+                "{field} has no nodes"
+            )));
+        }
+        for (i, node) in self.nodes.iter().enumerate() {
+            if node.expiry <= Decimal::ZERO
+                || node.strike <= Decimal::ZERO
+                || node.vol < Decimal::ZERO
+            {
+                return Err(CorpFinanceError::InvalidInput {
+                    field: format!("{field}.nodes[{i}]"),
+                    reason: "Expiry and strike must be positive and vol non-negative".into(),
+                });
+            }
+        }
+        if self.strike_axis == StrikeAxis::Moneyness && self.spot.is_none_or(|s| s <= Decimal::ZERO)
+        {
+            return Err(CorpFinanceError::InvalidInput {
+                field: format!("{field}.spot"),
+                reason: "A positive spot is required for a moneyness strike axis".into(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Implied volatility for an absolute `strike` at `expiry` years.
+    pub fn vol(&self, expiry: Years, strike: Decimal) -> CorpFinanceResult<Rate> {
+        self.validate("volatility_surface")?;
+        let x = match (self.strike_axis, self.spot) {
+            (StrikeAxis::Moneyness, Some(spot)) => strike / spot,
+            _ => strike,
+        };
+        let mut expiries: Vec<Years> = self.nodes.iter().map(|n| n.expiry).collect();
+        expiries.sort();
+        expiries.dedup();
+        let method = match self.smile_interpolation {
+            SmileInterpolation::Linear => Interpolation::Linear,
+            SmileInterpolation::CubicSpline => Interpolation::CubicSpline,
+        };
+        let slice_vol = |t: Years| {
+            let mut smile: Vec<(Decimal, Decimal)> = self
+                .nodes
+                .iter()
+                .filter(|n| n.expiry == t)
+                .map(|n| (n.strike, n.vol))
+                .collect();
+            smile.sort_by_key(|a| a.0);
+            let (ks, vs): (Vec<Decimal>, Vec<Decimal>) = smile.into_iter().unzip();
+            interpolate(&ks, &vs, x, method, self.extrapolation).max(Decimal::ZERO)
+        };
+
+        let first = expiries[0];
+        let last = expiries[expiries.len() - 1];
+        if expiry <= first {
+            return Ok(slice_vol(first));
+        }
+        if expiry >= last {
+            return Ok(slice_vol(last));
+        }
+        let idx = expiries.partition_point(|t| *t < expiry);
+        let (t0, t1) = (expiries[idx - 1], expiries[idx]);
+        let (v0, v1) = (slice_vol(t0), slice_vol(t1));
+        let weight = (expiry - t0) / (t1 - t0);
+        Ok(match self.time_interpolation {
+            TimeInterpolation::Linear => v0 + weight * (v1 - v0),
+            TimeInterpolation::TotalVariance => {
+                let w0 = v0 * v0 * t0;
+                let w1 = v1 * v1 * t1;
+                let w = (w0 + weight * (w1 - w0)).max(Decimal::ZERO);
+                (w / expiry).sqrt().unwrap_or(Decimal::ZERO)
+            }
+        })
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Interpolation
+// ---------------------------------------------------------------------------
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Interpolation {
+    Linear,
+    CubicSpline,
+}
+
+/// Read `ys` at `x` from points at ascending `xs`.
+fn interpolate(
+    xs: &[Decimal],
+    ys: &[Decimal],
+    x: Decimal,
+    method: Interpolation,
+    extrapolation: Extrapolation,
+) -> Decimal {
+    let n = xs.len();
+    if n == 0 {
+        return Decimal::ZERO;
+    }
+    if n == 1 {
+        return ys[0];
+    }
+    let line = |i: usize| {
+        let span = xs[i + 1] - xs[i];
+        if span.is_zero() {
+            ys[i]
+        } else {
+            ys[i] + (x - xs[i]) * (ys[i + 1] - ys[i]) / span
+        }
+    };
+    if x <= xs[0] || x >= xs[n - 1] {
+        let (edge, segment) = if x <= xs[0] { (0, 0) } else { (n - 1, n - 2) };
+        return match extrapolation {
+            Extrapolation::Flat => ys[edge],
+            Extrapolation::Linear => line(segment),
+        };
+    }
+    let idx = xs.partition_point(|v| *v < x);
+    if xs[idx] == x {
+        return ys[idx];
+    }
+    match method {
+        Interpolation::Linear => line(idx - 1),
+        Interpolation::CubicSpline => {
+            let m = spline_second_derivatives(xs, ys);
+            let (i, j) = (idx - 1, idx);
+            let h = xs[j] - xs[i];
+            let a = (xs[j] - x) / h;
+            let b = (x - xs[i]) / h;
+            a * ys[i]
+                + b * ys[j]
+                + ((a * a * a - a) * m[i] + (b * b * b - b) * m[j]) * h * h / Decimal::from(6)
+        }
+    }
+}
+
+/// Second derivatives of the natural cubic spline through the points
+/// (zero at both ends), by the tridiagonal (Thomas) algorithm.
+fn spline_second_derivatives(xs: &[Decimal], ys: &[Decimal]) -> Vec<Decimal> {
+    let n = xs.len();
+    let mut m = vec![Decimal::ZERO; n];
+    if n < 3 {
+        return m;
+    }
+    let mut c_prime = vec![Decimal::ZERO; n];
+    let mut d_prime = vec![Decimal::ZERO; n];
+    for i in 1..n - 1 {
+        let h0 = xs[i] - xs[i - 1];
+        let h1 = xs[i + 1] - xs[i];
+        let rhs = Decimal::from(6) * ((ys[i + 1] - ys[i]) / h1 - (ys[i] - ys[i - 1]) / h0);
+        let diag = Decimal::TWO * (h0 + h1) - h0 * c_prime[i - 1];
+        c_prime[i] = h1 / diag;
+        d_prime[i] = (rhs - h0 * d_prime[i - 1]) / diag;
+    }
+    for i in (1..n - 1).rev() {
+        m[i] = d_prime[i] - c_prime[i] * m[i + 1];
+    }
+    m
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+    use serde_json::json;
+
+    fn close(a: Decimal, b: Decimal, tol: Decimal) -> bool {
+        (a - b).abs() < tol
+    }
+
+    fn sample() -> Curve {
+        Curve::from_zero_rates([
+            (dec!(1), dec!(0.03)),
+            (dec!(2), dec!(0.035)),
+            (dec!(5), dec!(0.04)),
+            (dec!(10), dec!(0.045)),
+        ])
+    }
+
+    #[test]
+    fn test_legacy_array_and_versioned_format() {
+        let legacy: Curve = serde_json::from_value(json!([
+            { "maturity": 1, "rate": 0.03 },
+            { "maturity": 2, "rate": 0.035 },
+        ]))
+        .unwrap();
+        assert_eq!(legacy.version, CURVE_FORMAT_VERSION);
+        assert_eq!(
+            legacy.compounding, None,
+            "legacy arrays state no conventions"
+        );
+
+        let versioned = json!({
+            "version": 1,
+            "id": "USD-SOFR",
+            "calibration_date": "2024-06-28",
+            "day_count": "Actual360",
+            "compounding": "annual",
+            "interpolation": "cubic_spline",
+            "nodes": [{ "tenor": 1, "value": 0.03, "label": "1Y" }, { "tenor": 2, "value": 0.035 }],
+        });
+        let curve: Curve = serde_json::from_value(versioned).unwrap();
+        assert_eq!(curve.compounding, Some(Compounding::Annual));
+        assert_eq!(curve.nodes[0].label.as_deref(), Some("1Y"));
+
+        // Serialises to the versioned format and reads back unchanged
+        let round_trip: Curve =
+            serde_json::from_value(serde_json::to_value(&curve).unwrap()).unwrap();
+        assert_eq!(round_trip, curve);
+
+        let future = json!({ "version": 2, "nodes": [] });
+        let err = serde_json::from_value::<Curve>(future).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("unsupported curve format version 2"));
+    }
+
+    #[test]
+    fn test_module_defaults_fill_unstated_conventions() {
+        let defaults = CurveConventions {
+            compounding: Compounding::Annual,
+            interpolation: CurveInterpolation::Linear,
+            extrapolation: Extrapolation::Linear,
+        };
+        let curve = sample().with_conventions(defaults);
+        assert_eq!(curve.compounding, Some(Compounding::Annual));
+        assert_eq!(curve.zero_rate(dec!(1.5)), dec!(0.0325));
+        assert_eq!(
+            curve.zero_rate(dec!(0.5)),
+            dec!(0.0275),
+            "linear extrapolation"
+        );
+
+        let stated = Curve {
+            extrapolation: Some(Extrapolation::Flat),
+            ..sample()
+        }
+        .with_conventions(defaults);
+        assert_eq!(
+            stated.zero_rate(dec!(0.5)),
+            dec!(0.03),
+            "curve's own setting wins"
+        );
+    }
+
+    #[test]
+    fn test_compounding_conversion() {
+        let curve = Curve {
+            compounding: Some(Compounding::Continuous),
+            ..sample()
+        };
+        let annual = curve.zero_rate_as(dec!(2), Compounding::Annual);
+        // e^0.035 - 1
+        assert!(close(annual, dec!(0.0356197), dec!(0.0000001)), "{annual}");
+        let df = curve.discount_factor(dec!(2));
+        assert!(close(
+            df,
+            Compounding::Annual.discount_factor(annual, dec!(2)),
+            dec!(0.0000000001)
+        ));
+        let simple = Compounding::Simple.rate_from_discount_factor(dec!(0.95), dec!(0.5));
+        assert!(close(simple, dec!(0.1052632), dec!(0.0000001)));
+    }
+
+    #[test]
+    fn test_log_linear_gives_flat_forwards() {
+        let curve = Curve {
+            compounding: Some(Compounding::Continuous),
+            interpolation: Some(CurveInterpolation::LogLinearDiscount),
+            ..sample()
+        };
+        let f1 = curve.forward_rate(dec!(2), dec!(3), Compounding::Continuous);
+        let f2 = curve.forward_rate(dec!(3.5), dec!(5), Compounding::Continuous);
+        // (0.04 * 5 - 0.035 * 2) / 3
+        assert!(close(f1, dec!(0.0433333), dec!(0.000001)), "{f1}");
+        assert!(close(f1, f2, dec!(0.000001)));
+        assert_eq!(curve.zero_rate(dec!(5)), dec!(0.04));
+    }
+
+    #[test]
+    fn test_cubic_spline_through_nodes() {
+        let curve = Curve {
+            interpolation: Some(CurveInterpolation::CubicSpline),
+            ..sample()
+        };
+        for node in &curve.nodes {
+            assert_eq!(curve.zero_rate(node.tenor), node.value);
+        }
+        let mid = curve.zero_rate(dec!(3.5));
+        assert!(mid > dec!(0.035) && mid < dec!(0.04));
+        assert_ne!(
+            mid,
+            sample().zero_rate(dec!(3.5)),
+            "spline differs from linear"
+        );
+    }
+
+    #[test]
+    fn test_discount_factor_nodes_and_shift() {
+        let curve = Curve {
+            compounding: Some(Compounding::Continuous),
+            value_type: CurveValue::DiscountFactor,
+            nodes: vec![
+                CurveNode {
+                    tenor: dec!(0),
+                    value: dec!(1),
+                    label: None,
+                },
+                CurveNode {
+                    tenor: dec!(1),
+                    value: dec!(0.97),
+                    label: Some("1Y".into()),
+                },
+                CurveNode {
+                    tenor: dec!(2),
+                    value: dec!(0.93),
+                    label: Some("2Y".into()),
+                },
+            ],
+            ..Curve::default()
+        };
+        curve.validate("curve").unwrap();
+        assert!(close(
+            curve.discount_factor(dec!(1)),
+            dec!(0.97),
+            dec!(0.0000001)
+        ));
+
+        let bumped = curve.shifted(dec!(0.0001));
+        assert_eq!(bumped.value_type, CurveValue::ZeroRate);
+        assert_eq!(bumped.len(), 2);
+        assert_eq!(bumped.nodes[1].label.as_deref(), Some("2Y"));
+        let diff = bumped.zero_rate(dec!(1.5)) - curve.zero_rate(dec!(1.5));
+        assert!(close(diff, dec!(0.0001), dec!(0.0000001)));
+
+        let unsorted = Curve::from_zero_rates([(dec!(2), dec!(0.03)), (dec!(1), dec!(0.03))]);
+        assert!(unsorted.validate("discount_curve").is_err());
+    }
+
+    fn surface() -> VolSurface {
+        serde_json::from_value(json!({
+            "version": 1,
+            "underlying": "SPX",
+            "spot": 100,
+            "strike_axis": "moneyness",
+            "nodes": [
+                { "expiry": 0.5, "strike": 0.9, "vol": 0.25 },
+                { "expiry": 0.5, "strike": 1.0, "vol": 0.20 },
+                { "expiry": 0.5, "strike": 1.1, "vol": 0.18 },
+                { "expiry": 1.0, "strike": 0.9, "vol": 0.24 },
+                { "expiry": 1.0, "strike": 1.0, "vol": 0.22 },
+                { "expiry": 1.0, "strike": 1.1, "vol": 0.21 },
+            ],
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_vol_surface_interpolation() {
+        let s = surface();
+        assert_eq!(s.vol(dec!(0.5), dec!(100)).unwrap(), dec!(0.20));
+        assert_eq!(s.vol(dec!(0.5), dec!(95)).unwrap(), dec!(0.225));
+        assert_eq!(
+            s.vol(dec!(0.25), dec!(150)).unwrap(),
+            dec!(0.18),
+            "flat beyond nodes"
+        );
+
+        // Total variance: 0.5 * (0.02 + 0.0484) = 0.0342 at T = 0.75
+        let v = s.vol(dec!(0.75), dec!(100)).unwrap();
+        assert!(
+            close(v * v * dec!(0.75), dec!(0.0342), dec!(0.0000001)),
+            "{v}"
+        );
+
+        let linear = VolSurface {
+            time_interpolation: TimeInterpolation::Linear,
+            ..s.clone()
+        };
+        assert_eq!(linear.vol(dec!(0.75), dec!(100)).unwrap(), dec!(0.21));
+    }
+
+    #[test]
+    fn test_vol_surface_validation() {
+        let no_spot = VolSurface {
+            spot: None,
+            ..surface()
+        };
+        assert!(no_spot.vol(dec!(1), dec!(100)).is_err());
+        let future = VolSurface {
+            version: 2,
+            ..surface()
+        };
+        match future.validate("surface").unwrap_err() {
+            CorpFinanceError::InvalidInput { field, .. } => assert_eq!(field, "surface.version"),
+            other => panic!("Expected InvalidInput, got {other:?}"),
+        }
+    }
+}
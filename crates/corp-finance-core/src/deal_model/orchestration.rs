@@ -0,0 +1,732 @@
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::time::Instant;
+
+use crate::credit::covenants::{self, Covenant, CovenantTestInput, CovenantTestOutput};
+use crate::credit::metrics::{self, CreditMetricsInput, CreditMetricsOutput};
+use crate::pe::debt_schedule::DebtTrancheInput;
+use crate::pe::lbo::{self, LboInput, LboOutput};
+use crate::scenarios::sensitivity::{self, SensitivityInput, SensitivityOutput};
+use crate::three_statement::dcf_bridge::{
+    self, DcfValuationParams, ModelToDcfInput, ModelToDcfOutput,
+};
+use crate::three_statement::model::{
+    build_three_statement_model, ThreeStatementInput, ThreeStatementOutput,
+};
+use crate::types::*;
+use crate::{CorpFinanceError, CorpFinanceResult};
+
+/// Sensitivity drivers accepted by `DealSensitivity` variables.
+const SENSITIVITY_DRIVERS: [&str; 5] = [
+    "wacc",
+    "terminal_growth_rate",
+    "terminal_exit_multiple",
+    "exit_multiple",
+    "entry_ev",
+];
+
+// ---------------------------------------------------------------------------
+// Input
+// ---------------------------------------------------------------------------
+
+/// Acquisition financing and exit assumptions for the sponsor case.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DealFinancing {
+    /// Purchase enterprise value
+    pub entry_ev: Money,
+    /// Acquisition debt tranches in seniority order
+    pub tranches: Vec<DebtTrancheInput>,
+    /// Sponsor equity contribution
+    pub equity_contribution: Money,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub management_rollover: Option<Money>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub transaction_fees: Option<Money>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub financing_fees: Option<Money>,
+    /// Share of excess cash flow swept to repay acquisition debt
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cash_sweep_pct: Option<Rate>,
+    /// Exit EV/EBITDA multiple
+    pub exit_multiple: Multiple,
+    /// Exit year (default: last projected year)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exit_year: Option<u32>,
+}
+
+/// Deal output measured in the sensitivity grid.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum DealMetric {
+    DcfEnterpriseValue,
+    DcfEquityValue,
+    Irr,
+    Moic,
+}
+
+/// Two-way sensitivity of one deal output. Variable names must be one of
+/// `wacc`, `terminal_growth_rate`, `terminal_exit_multiple`,
+/// `exit_multiple` or `entry_ev`; a change in `entry_ev` is funded with
+/// sponsor equity.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DealSensitivity {
+    pub variable_1: SensitivityVariable,
+    pub variable_2: SensitivityVariable,
+    pub output_metric: DealMetric,
+}
+
+/// A complete deal: the target's financials and operating assumptions, how
+/// the acquisition is financed, and how the business is valued.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DealInput {
+    /// Target base-year financials and projection drivers
+    pub target: ThreeStatementInput,
+    pub financing: DealFinancing,
+    /// Standalone DCF settings
+    pub valuation: DcfValuationParams,
+    /// Maintenance covenants tested against each pro forma year
+    #[serde(default)]
+    pub covenants: Vec<Covenant>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sensitivity: Option<DealSensitivity>,
+}
+
+// ---------------------------------------------------------------------------
+// Output
+// ---------------------------------------------------------------------------
+
+/// Credit metrics and covenant compliance for one pro forma year.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DealCreditYear {
+    pub year: i32,
+    pub metrics: CreditMetricsOutput,
+    /// Omitted when the deal has no covenants
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub covenants: Option<CovenantTestOutput>,
+}
+
+/// Headline figures drawn from every stage of the deal model.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DealSummary {
+    pub entry_ev: Money,
+    pub entry_ebitda: Money,
+    pub entry_multiple: Multiple,
+    pub dcf_enterprise_value: Money,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dcf_equity_value: Option<Money>,
+    /// Entry EV over standalone DCF enterprise value, less one
+    pub premium_to_dcf: Rate,
+    pub irr: Rate,
+    pub moic: Multiple,
+    /// Acquisition debt / entry EBITDA
+    pub opening_leverage: Multiple,
+    /// Highest pro forma total debt / EBITDA
+    pub peak_leverage: Multiple,
+    /// Lowest pro forma EBITDA / interest
+    pub min_interest_coverage: Multiple,
+    pub covenants_all_passing: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_covenant_breach_year: Option<i32>,
+}
+
+/// Consolidated results of every module the deal fans out to.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DealOutput {
+    pub summary: DealSummary,
+    /// Target model as is, with its unlevered cash flows valued by DCF
+    pub standalone: ModelToDcfOutput,
+    /// Target model carrying the acquisition debt and equity
+    pub pro_forma: ThreeStatementOutput,
+    pub lbo: LboOutput,
+    pub credit: Vec<DealCreditYear>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sensitivity: Option<SensitivityOutput>,
+}
+
+// ---------------------------------------------------------------------------
+// Public API
+// ---------------------------------------------------------------------------
+
+/// Run a deal end to end from one set of inputs.
+///
+/// 1. The target's three-statement model is built and its unlevered cash
+///    flows valued by DCF (standalone view).
+/// 2. The LBO runs on the same revenue path and EBITDA margins, with the
+///    acquisition tranches, fees and exit assumptions.
+/// 3. A pro forma three-statement model replaces the target's debt with the
+///    acquisition debt at its weighted cash rate and its equity with the
+///    sponsor equity; any excess of the purchase over book net assets is
+///    carried in long-lived assets as goodwill and step-up.
+/// 4. Credit metrics are computed from each pro forma year and the
+///    covenants tested against them.
+/// 5. An optional two-way sensitivity reruns the affected stages per cell.
+pub fn run_deal_model(input: &DealInput) -> CorpFinanceResult<ComputationOutput<DealOutput>> {
+    let start = Instant::now();
+    let mut warnings: Vec<String> = Vec::new();
+
+    let f = &input.financing;
+    if f.tranches.is_empty() {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "financing.tranches".into(),
+            reason: "At least one debt tranche is required".into(),
+        });
+    }
+    if let Some(s) = &input.sensitivity {
+        validate_driver("sensitivity.variable_1", &s.variable_1.name)?;
+        validate_driver("sensitivity.variable_2", &s.variable_2.name)?;
+    }
+
+    // -- Standalone model and DCF ------------------------------------------
+    let standalone = run_standalone(input)?;
+    warnings.extend(standalone.warnings);
+    let standalone = standalone.result;
+
+    // -- LBO -----------------------------------------------------------------
+    let lbo_out = lbo::build_lbo(&lbo_input(input, &standalone.model))?;
+    warnings.extend(lbo_out.warnings.iter().map(|w| format!("[LBO] {w}")));
+    let lbo = lbo_out.result;
+
+    // -- Pro forma model -------------------------------------------------------
+    let (pf_input, step_up) = pro_forma_input(input);
+    let pf_out = build_three_statement_model(&pf_input)?;
+    warnings.extend(pf_out.warnings.iter().map(|w| format!("[Pro forma] {w}")));
+    let pro_forma = pf_out.result;
+
+    // -- Credit metrics and covenants ---------------------------------------
+    let mut credit: Vec<DealCreditYear> = Vec::with_capacity(pro_forma.income_statements.len());
+    for ((is, bs), cf) in pro_forma
+        .income_statements
+        .iter()
+        .zip(&pro_forma.balance_sheets)
+        .zip(&pro_forma.cash_flow_statements)
+    {
+        let metrics_out = metrics::calculate_credit_metrics(&CreditMetricsInput {
+            revenue: is.revenue,
+            ebitda: is.ebitda,
+            ebit: is.ebit,
+            interest_expense: is.interest_expense,
+            depreciation_amortisation: is.depreciation,
+            total_debt: bs.total_debt,
+            cash: bs.cash,
+            total_assets: bs.total_assets,
+            current_assets: bs.total_current_assets,
+            current_liabilities: bs.total_current_liabilities,
+            total_equity: bs.shareholders_equity,
+            retained_earnings: bs.retained_earnings_cumulative,
+            working_capital: bs.total_current_assets - bs.total_current_liabilities,
+            operating_cash_flow: cf.cash_from_operations,
+            capex: cf.capex,
+            funds_from_operations: Some(is.net_income + is.depreciation),
+            lease_payments: None,
+            preferred_dividends: None,
+            market_cap: None,
+        })?;
+        warnings.extend(
+            metrics_out
+                .warnings
+                .iter()
+                .map(|w| format!("[Credit Y{}] {w}", is.year)),
+        );
+
+        let covenants = if input.covenants.is_empty() {
+            None
+        } else {
+            let cov_out = covenants::test_covenants(&CovenantTestInput {
+                covenants: input.covenants.clone(),
+                actuals: metrics_out.result.clone(),
+            })?;
+            warnings.extend(
+                cov_out
+                    .warnings
+                    .iter()
+                    .map(|w| format!("[Covenants Y{}] {w}", is.year)),
+            );
+            Some(cov_out.result)
+        };
+
+        credit.push(DealCreditYear {
+            year: is.year,
+            metrics: metrics_out.result,
+            covenants,
+        });
+    }
+
+    // -- Sensitivity -----------------------------------------------------------
+    let sensitivity = match &input.sensitivity {
+        Some(s) => {
+            let sens_input = SensitivityInput {
+                base_inputs: serde_json::Value::Null,
+                variable_1: s.variable_1.clone(),
+                variable_2: s.variable_2.clone(),
+                output_metric: format!("{:?}", s.output_metric),
+                compute_fn: "deal_model".into(),
+            };
+            let sens_out = sensitivity::evaluate_sensitivity(&sens_input, |v1, v2| {
+                let mut case = input.clone();
+                case.sensitivity = None;
+                apply_driver(&mut case, &s.variable_1.name, v1);
+                apply_driver(&mut case, &s.variable_2.name, v2);
+                metric_value(&case, s.output_metric)
+            })?;
+            warnings.extend(
+                sens_out
+                    .warnings
+                    .iter()
+                    .map(|w| format!("[Sensitivity] {w}")),
+            );
+            Some(sens_out.result)
+        }
+        None => None,
+    };
+
+    // -- Summary ---------------------------------------------------------------
+    let entry_ebitda = entry_ebitda(&input.target);
+    let acquisition_debt: Money = f.tranches.iter().map(|t| t.amount).sum();
+    let dcf_ev = standalone.dcf.enterprise_value;
+    let first_covenant_breach_year = credit
+        .iter()
+        .find(|c| c.covenants.as_ref().is_some_and(|t| !t.all_passing))
+        .map(|c| c.year);
+
+    let summary = DealSummary {
+        entry_ev: f.entry_ev,
+        entry_ebitda,
+        entry_multiple: f.entry_ev / entry_ebitda,
+        dcf_enterprise_value: dcf_ev,
+        dcf_equity_value: standalone.dcf.equity_value,
+        premium_to_dcf: if dcf_ev > Decimal::ZERO {
+            f.entry_ev / dcf_ev - Decimal::ONE
+        } else {
+            Decimal::ZERO
+        },
+        irr: lbo.irr,
+        moic: lbo.moic,
+        opening_leverage: acquisition_debt / entry_ebitda,
+        peak_leverage: credit
+            .iter()
+            .map(|c| c.metrics.total_debt_to_ebitda)
+            .max()
+            .unwrap_or(Decimal::ZERO),
+        min_interest_coverage: credit
+            .iter()
+            .map(|c| c.metrics.interest_coverage)
+            .min()
+            .unwrap_or(Decimal::ZERO),
+        covenants_all_passing: first_covenant_breach_year.is_none(),
+        first_covenant_breach_year,
+    };
+
+    if summary.premium_to_dcf > Decimal::ZERO {
+        warnings.push(format!(
+            "Entry EV exceeds the standalone DCF value by {:.1}%",
+            summary.premium_to_dcf * Decimal::from(100)
+        ));
+    }
+
+    let output = DealOutput {
+        summary,
+        standalone,
+        pro_forma,
+        lbo,
+        credit,
+        sensitivity,
+    };
+
+    let elapsed = start.elapsed().as_micros() as u64;
+    Ok(with_metadata(
+        "End-to-End Deal Model",
+        &serde_json::json!({
+            "stages": ["three_statement", "dcf", "lbo", "pro_forma", "credit_metrics", "covenants", "sensitivity"],
+            "acquisition_debt": acquisition_debt,
+            "pro_forma_interest_rate": pf_input.interest_rate,
+            "goodwill_and_step_up": step_up,
+            "exit_year": output.lbo.projections.len(),
+        }),
+        warnings,
+        elapsed,
+        output,
+    ))
+}
+
+// ---------------------------------------------------------------------------
+// Stage wiring
+// ---------------------------------------------------------------------------
+
+fn run_standalone(input: &DealInput) -> CorpFinanceResult<ComputationOutput<ModelToDcfOutput>> {
+    dcf_bridge::model_to_dcf(&ModelToDcfInput {
+        model: input.target.clone(),
+        valuation: input.valuation.clone(),
+    })
+}
+
+/// Base-year EBITDA at the base cost ratios.
+fn entry_ebitda(target: &ThreeStatementInput) -> Money {
+    target.base_revenue * (Decimal::ONE - target.cogs_pct - target.sga_pct - target.rnd_pct)
+}
+
+/// LBO on the target model's revenue path and margins.
+fn lbo_input(input: &DealInput, model: &ThreeStatementOutput) -> LboInput {
+    let t = &input.target;
+    let f = &input.financing;
+    let nwc_as_pct_revenue = if t.base_revenue > Decimal::ZERO {
+        (t.base_receivables + t.base_inventory - t.base_payables) / t.base_revenue
+    } else {
+        Decimal::ZERO
+    };
+
+    LboInput {
+        entry_ev: f.entry_ev,
+        entry_ebitda: entry_ebitda(t),
+        revenue_growth: t.revenue_growth_rates.clone(),
+        ebitda_margin: model
+            .income_statements
+            .iter()
+            .map(|is| is.ebitda_margin)
+            .collect(),
+        capex_as_pct_revenue: t.capex_pct,
+        nwc_as_pct_revenue,
+        tax_rate: t.tax_rate,
+        da_as_pct_revenue: t.da_pct,
+        base_revenue: t.base_revenue,
+        tranches: f.tranches.clone(),
+        equity_contribution: f.equity_contribution,
+        cash_sweep_pct: f.cash_sweep_pct,
+        exit_year: f.exit_year.unwrap_or(t.revenue_growth_rates.len() as u32),
+        exit_multiple: f.exit_multiple,
+        transaction_fees: f.transaction_fees,
+        financing_fees: f.financing_fees,
+        management_rollover: f.management_rollover,
+        currency: Some(input.valuation.currency.clone()),
+        minimum_cash: Some(t.min_cash_balance),
+        revolver: None,
+        sweep_tiers: vec![],
+        optional_prepayment_pct: None,
+        pik_toggles: vec![],
+    }
+}
+
+/// Target model recapitalised with the acquisition financing, and the
+/// goodwill and asset step-up needed to balance the opening balance sheet.
+fn pro_forma_input(input: &DealInput) -> (ThreeStatementInput, Money) {
+    let t = &input.target;
+    let f = &input.financing;
+
+    let debt: Money = f.tranches.iter().map(|tr| tr.amount).sum();
+    let interest_rate = if debt > Decimal::ZERO {
+        f.tranches
+            .iter()
+            .map(|tr| tr.amount * lbo::tranche_cash_rate(tr))
+            .sum::<Money>()
+            / debt
+    } else {
+        t.interest_rate
+    };
+    let sponsor_equity = f.equity_contribution + f.management_rollover.unwrap_or(Decimal::ZERO);
+    let book_assets = t.base_cash + t.base_receivables + t.base_inventory + t.base_ppe;
+    let step_up = debt + sponsor_equity + t.base_payables - book_assets;
+
+    let mut pf = t.clone();
+    pf.base_debt = debt;
+    pf.interest_rate = interest_rate;
+    if step_up >= Decimal::ZERO {
+        pf.base_ppe += step_up;
+        pf.base_equity = sponsor_equity;
+    } else {
+        // Bought below book: the discount stays in equity
+        pf.base_equity = book_assets - t.base_payables - debt;
+    }
+    (pf, step_up.max(Decimal::ZERO))
+}
+
+fn validate_driver(field: &str, name: &str) -> CorpFinanceResult<()> {
+    if SENSITIVITY_DRIVERS.contains(&name) {
+        Ok(())
+    } else {
+        Err(CorpFinanceError::InvalidInput {
+            field: field.into(),
+            reason: format!(
+                "Unknown driver '{name}'; expected one of {}",
+                SENSITIVITY_DRIVERS.join(", ")
+            ),
+        })
+    }
+}
+
+fn apply_driver(case: &mut DealInput, name: &str, value: Decimal) {
+    match name {
+        "wacc" => {
+            case.valuation.wacc = value;
+            case.valuation.wacc_input = None;
+        }
+        "terminal_growth_rate" => case.valuation.terminal_growth_rate = Some(value),
+        "terminal_exit_multiple" => case.valuation.terminal_exit_multiple = Some(value),
+        "exit_multiple" => case.financing.exit_multiple = value,
+        // Debt is held fixed, so sponsor equity funds the price change
+        "entry_ev" => {
+            case.financing.equity_contribution += value - case.financing.entry_ev;
+            case.financing.entry_ev = value;
+        }
+        _ => {}
+    }
+}
+
+/// Rerun only the stages the metric depends on.
+fn metric_value(case: &DealInput, metric: DealMetric) -> CorpFinanceResult<Decimal> {
+    match metric {
+        DealMetric::DcfEnterpriseValue => Ok(run_standalone(case)?.result.dcf.enterprise_value),
+        DealMetric::DcfEquityValue => run_standalone(case)?
+            .result
+            .dcf
+            .equity_value
+            .ok_or_else(|| CorpFinanceError::InsufficientData("DCF equity value".into())),
+        DealMetric::Irr | DealMetric::Moic => {
+            let model = build_three_statement_model(&case.target)?.result;
+            let lbo = lbo::build_lbo(&lbo_input(case, &model))?.result;
+            Ok(if metric == DealMetric::Irr {
+                lbo.irr
+            } else {
+                lbo.moic
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::credit::covenants::{CovenantDirection, CovenantMetric};
+    use crate::pe::debt_schedule::AmortisationType;
+    use crate::valuation::dcf::TerminalMethod;
+    use rust_decimal_macros::dec;
+
+    fn tranche(
+        name: &str,
+        amount: Money,
+        rate: Rate,
+        amortisation: AmortisationType,
+    ) -> DebtTrancheInput {
+        DebtTrancheInput {
+            name: name.into(),
+            amount,
+            interest_rate: rate,
+            is_floating: false,
+            base_rate: None,
+            spread: None,
+            amortisation,
+            maturity_years: 7,
+            pik_rate: None,
+            seniority: 1,
+            commitment_fee: None,
+            is_revolver: false,
+        }
+    }
+
+    fn sample_input() -> DealInput {
+        DealInput {
+            target: ThreeStatementInput {
+                base_revenue: dec!(1000),
+                revenue_growth_rates: vec![dec!(0.10), dec!(0.08), dec!(0.06)],
+                cogs_pct: dec!(0.60),
+                sga_pct: dec!(0.10),
+                rnd_pct: dec!(0.05),
+                da_pct: dec!(0.10),
+                interest_rate: dec!(0.05),
+                tax_rate: dec!(0.25),
+                base_cash: dec!(100),
+                base_receivables: dec!(80),
+                base_inventory: dec!(60),
+                base_payables: dec!(50),
+                base_ppe: dec!(500),
+                base_debt: dec!(400),
+                base_equity: dec!(290),
+                dso_days: dec!(30),
+                dio_days: dec!(40),
+                dpo_days: dec!(35),
+                capex_pct: dec!(0.08),
+                debt_repayment_pct: dec!(0.05),
+                dividend_payout_ratio: dec!(0),
+                min_cash_balance: dec!(50),
+                drivers: None,
+            },
+            financing: DealFinancing {
+                entry_ev: dec!(2000),
+                tranches: vec![
+                    tranche(
+                        "Senior",
+                        dec!(1000),
+                        dec!(0.06),
+                        AmortisationType::StraightLine(dec!(0.05)),
+                    ),
+                    tranche(
+                        "Subordinated",
+                        dec!(250),
+                        dec!(0.09),
+                        AmortisationType::Bullet,
+                    ),
+                ],
+                equity_contribution: dec!(800),
+                management_rollover: None,
+                transaction_fees: Some(dec!(30)),
+                financing_fees: Some(dec!(20)),
+                cash_sweep_pct: Some(dec!(0.50)),
+                exit_multiple: dec!(8),
+                exit_year: None,
+            },
+            valuation: DcfValuationParams {
+                wacc: dec!(0.09),
+                wacc_input: None,
+                terminal_method: TerminalMethod::GordonGrowth,
+                terminal_growth_rate: Some(dec!(0.02)),
+                terminal_exit_multiple: None,
+                terminal_ronic: None,
+                currency: Currency::USD,
+                mid_year_convention: Some(false),
+                net_debt: None,
+                minority_interest: None,
+                shares_outstanding: Some(dec!(100)),
+            },
+            covenants: vec![Covenant {
+                name: "Max Net Leverage".into(),
+                metric: CovenantMetric::NetDebtToEbitda,
+                threshold: dec!(6.0),
+                direction: CovenantDirection::MaxOf,
+            }],
+            sensitivity: None,
+        }
+    }
+
+    #[test]
+    fn test_deal_fans_out_to_every_stage() {
+        let out = run_deal_model(&sample_input()).unwrap().result;
+        assert_eq!(out.standalone.dcf.projections.len(), 3);
+        assert_eq!(out.pro_forma.income_statements.len(), 3);
+        assert_eq!(out.lbo.projections.len(), 3);
+        assert_eq!(out.credit.len(), 3);
+        assert!(out.credit.iter().all(|c| c.covenants.is_some()));
+        assert!(out.summary.covenants_all_passing);
+        assert!(out.sensitivity.is_none());
+    }
+
+    #[test]
+    fn test_standalone_matches_dcf_bridge() {
+        let input = sample_input();
+        let out = run_deal_model(&input).unwrap().result;
+        let direct = dcf_bridge::model_to_dcf(&ModelToDcfInput {
+            model: input.target.clone(),
+            valuation: input.valuation.clone(),
+        })
+        .unwrap()
+        .result;
+        assert_eq!(
+            out.summary.dcf_enterprise_value,
+            direct.dcf.enterprise_value
+        );
+        assert_eq!(
+            out.summary.premium_to_dcf,
+            dec!(2000) / direct.dcf.enterprise_value - Decimal::ONE
+        );
+    }
+
+    #[test]
+    fn test_lbo_follows_target_operations() {
+        let out = run_deal_model(&sample_input()).unwrap().result;
+        for (p, is) in out
+            .lbo
+            .projections
+            .iter()
+            .zip(&out.standalone.model.income_statements)
+        {
+            assert_eq!(p.revenue, is.revenue);
+            assert_eq!(p.ebitda, is.ebitda);
+        }
+        assert_eq!(out.summary.entry_ebitda, dec!(250));
+        assert_eq!(out.summary.entry_multiple, dec!(8));
+    }
+
+    #[test]
+    fn test_pro_forma_carries_acquisition_financing() {
+        let input = sample_input();
+        let (pf, step_up) = pro_forma_input(&input);
+        assert_eq!(pf.base_debt, dec!(1250));
+        // (1000 x 6% + 250 x 9%) / 1250
+        assert_eq!(pf.interest_rate, dec!(0.066));
+        // 1250 debt + 800 equity + 50 payables - 740 book assets
+        assert_eq!(step_up, dec!(1360));
+        assert_eq!(pf.base_ppe, dec!(1860));
+        assert_eq!(pf.base_equity, dec!(800));
+
+        let out = run_deal_model(&input).unwrap().result;
+        assert!(out.pro_forma.summary.balance_sheet_balances);
+        assert_eq!(out.summary.opening_leverage, dec!(5));
+        assert!(
+            out.pro_forma.income_statements[0].interest_expense
+                > out.standalone.model.income_statements[0].interest_expense
+        );
+    }
+
+    #[test]
+    fn test_covenant_breach_reported() {
+        let mut input = sample_input();
+        input.covenants[0].threshold = dec!(3.0);
+        let out = run_deal_model(&input).unwrap().result;
+        assert!(!out.summary.covenants_all_passing);
+        assert_eq!(
+            out.summary.first_covenant_breach_year,
+            Some(out.credit[0].year)
+        );
+    }
+
+    #[test]
+    fn test_sensitivity_base_case_matches_lbo() {
+        let mut input = sample_input();
+        input.sensitivity = Some(DealSensitivity {
+            variable_1: SensitivityVariable {
+                name: "exit_multiple".into(),
+                min: dec!(7),
+                max: dec!(9),
+                step: dec!(1),
+            },
+            variable_2: SensitivityVariable {
+                name: "entry_ev".into(),
+                min: dec!(1900),
+                max: dec!(2100),
+                step: dec!(100),
+            },
+            output_metric: DealMetric::Irr,
+        });
+        let out = run_deal_model(&input).unwrap().result;
+        let grid = out.sensitivity.unwrap();
+        assert_eq!(grid.base_case_value, out.summary.irr);
+        // Higher exit multiple lifts returns; a higher price, funded with
+        // equity, dilutes them
+        assert!(grid.matrix[2][1] > grid.matrix[0][1]);
+        assert!(grid.matrix[1][0] > grid.matrix[1][2]);
+    }
+
+    #[test]
+    fn test_unknown_sensitivity_driver_rejected() {
+        let mut input = sample_input();
+        input.sensitivity = Some(DealSensitivity {
+            variable_1: SensitivityVariable {
+                name: "beta".into(),
+                min: dec!(1),
+                max: dec!(2),
+                step: dec!(1),
+            },
+            variable_2: SensitivityVariable {
+                name: "wacc".into(),
+                min: dec!(0.08),
+                max: dec!(0.10),
+                step: dec!(0.01),
+            },
+            output_metric: DealMetric::DcfEnterpriseValue,
+        });
+        assert!(run_deal_model(&input).is_err());
+    }
+
+    #[test]
+    fn test_missing_tranches_rejected() {
+        let mut input = sample_input();
+        input.financing.tranches.clear();
+        assert!(run_deal_model(&input).is_err());
+    }
+}
@@ -0,0 +1,1035 @@
+use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::ops::{Add, Div, Mul, Neg, Sub};
+use std::time::Instant;
+
+use crate::derivatives::options::OptionType;
+use crate::error::CorpFinanceError;
+use crate::types::*;
+use crate::volatility_surface::implied_vol_surface::SurfacePoint;
+use crate::CorpFinanceResult;
+
+// ---------------------------------------------------------------------------
+// Public types
+// ---------------------------------------------------------------------------
+
+/// Heston stochastic volatility parameters.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HestonParams {
+    /// Initial variance
+    pub v0: Decimal,
+    /// Mean-reversion speed of variance
+    pub kappa: Decimal,
+    /// Long-run variance
+    pub theta: Decimal,
+    /// Volatility of variance
+    pub vol_of_vol: Decimal,
+    /// Spot-variance correlation
+    pub rho: Decimal,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HestonOption {
+    pub strike: Money,
+    pub expiry: Decimal,
+    pub option_type: OptionType,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HestonPricingInput {
+    pub spot_price: Money,
+    pub risk_free_rate: Rate,
+    #[serde(default)]
+    pub dividend_yield: Rate,
+    pub params: HestonParams,
+    pub options: Vec<HestonOption>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HestonOptionPrice {
+    pub strike: Money,
+    pub expiry: Decimal,
+    pub option_type: OptionType,
+    pub price: Money,
+    /// Black-Scholes implied volatility of the Heston price
+    pub implied_vol: Option<Rate>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HestonPricingOutput {
+    pub prices: Vec<HestonOptionPrice>,
+    /// 2 * kappa * theta / vol_of_vol^2; variance stays positive when >= 1
+    pub feller_ratio: Decimal,
+    pub feller_satisfied: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HestonCalibrationInput {
+    pub spot_price: Money,
+    pub risk_free_rate: Rate,
+    #[serde(default)]
+    pub dividend_yield: Rate,
+    /// Surface points to fit, e.g. `surface_points` from `build_implied_vol_surface`
+    pub surface_points: Vec<SurfacePoint>,
+    pub initial_params: Option<HestonParams>,
+    pub max_iterations: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HestonFitPoint {
+    pub strike: Money,
+    pub expiry: Decimal,
+    pub market_vol: Rate,
+    pub model_vol: Option<Rate>,
+    pub vol_error: Option<Rate>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HestonCalibrationOutput {
+    pub params: HestonParams,
+    /// Root-mean-square implied vol error across fitted points
+    pub rmse_vol_error: Rate,
+    pub max_abs_vol_error: Rate,
+    pub fit: Vec<HestonFitPoint>,
+    pub feller_ratio: Decimal,
+    pub feller_satisfied: bool,
+    pub iterations: u32,
+}
+
+// ---------------------------------------------------------------------------
+// Complex arithmetic (f64)
+// ---------------------------------------------------------------------------
+// The Heston characteristic function needs complex exp, log and sqrt, which
+// Decimal does not provide; the Fourier integral runs in f64 and results are
+// converted back at the boundary.
+
+#[derive(Debug, Clone, Copy)]
+struct Complex {
+    re: f64,
+    im: f64,
+}
+
+impl Complex {
+    const fn new(re: f64, im: f64) -> Self {
+        Complex { re, im }
+    }
+
+    fn exp(self) -> Self {
+        let m = self.re.exp();
+        Complex::new(m * self.im.cos(), m * self.im.sin())
+    }
+
+    /// Principal branch logarithm
+    fn ln(self) -> Self {
+        Complex::new(self.re.hypot(self.im).ln(), self.im.atan2(self.re))
+    }
+
+    /// Principal branch square root
+    fn sqrt(self) -> Self {
+        let r = self.re.hypot(self.im);
+        let re = ((r + self.re) / 2.0).sqrt();
+        let im = ((r - self.re) / 2.0).sqrt();
+        Complex::new(re, if self.im < 0.0 { -im } else { im })
+    }
+}
+
+impl Add for Complex {
+    type Output = Complex;
+    fn add(self, o: Complex) -> Complex {
+        Complex::new(self.re + o.re, self.im + o.im)
+    }
+}
+
+impl Sub for Complex {
+    type Output = Complex;
+    fn sub(self, o: Complex) -> Complex {
+        Complex::new(self.re - o.re, self.im - o.im)
+    }
+}
+
+impl Mul for Complex {
+    type Output = Complex;
+    fn mul(self, o: Complex) -> Complex {
+        Complex::new(
+            self.re * o.re - self.im * o.im,
+            self.re * o.im + self.im * o.re,
+        )
+    }
+}
+
+impl Mul<f64> for Complex {
+    type Output = Complex;
+    fn mul(self, k: f64) -> Complex {
+        Complex::new(self.re * k, self.im * k)
+    }
+}
+
+impl Div for Complex {
+    type Output = Complex;
+    fn div(self, o: Complex) -> Complex {
+        let den = o.re * o.re + o.im * o.im;
+        Complex::new(
+            (self.re * o.re + self.im * o.im) / den,
+            (self.im * o.re - self.re * o.im) / den,
+        )
+    }
+}
+
+impl Neg for Complex {
+    type Output = Complex;
+    fn neg(self) -> Complex {
+        Complex::new(-self.re, -self.im)
+    }
+}
+
+const ONE: Complex = Complex::new(1.0, 0.0);
+const I: Complex = Complex::new(0.0, 1.0);
+
+// ---------------------------------------------------------------------------
+// Semi-analytic pricing
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Copy)]
+struct Params {
+    v0: f64,
+    kappa: f64,
+    theta: f64,
+    sigma: f64,
+    rho: f64,
+}
+
+impl Params {
+    fn from_decimal(p: &HestonParams) -> Self {
+        Params {
+            v0: p.v0.to_f64().unwrap_or(0.0),
+            kappa: p.kappa.to_f64().unwrap_or(0.0),
+            theta: p.theta.to_f64().unwrap_or(0.0),
+            sigma: p.vol_of_vol.to_f64().unwrap_or(0.0),
+            rho: p.rho.to_f64().unwrap_or(0.0),
+        }
+    }
+
+    fn to_decimal(self) -> HestonParams {
+        HestonParams {
+            v0: to_decimal(self.v0),
+            kappa: to_decimal(self.kappa),
+            theta: to_decimal(self.theta),
+            vol_of_vol: to_decimal(self.sigma),
+            rho: to_decimal(self.rho),
+        }
+    }
+
+    fn as_array(self) -> [f64; 5] {
+        [self.v0, self.kappa, self.theta, self.sigma, self.rho]
+    }
+
+    fn from_array(a: [f64; 5]) -> Self {
+        Params {
+            v0: a[0].clamp(1e-4, 4.0),
+            kappa: a[1].clamp(1e-3, 20.0),
+            theta: a[2].clamp(1e-4, 4.0),
+            sigma: a[3].clamp(1e-3, 5.0),
+            rho: a[4].clamp(-0.999, 0.999),
+        }
+    }
+
+    fn feller_ratio(self) -> f64 {
+        2.0 * self.kappa * self.theta / (self.sigma * self.sigma)
+    }
+}
+
+/// Characteristic function of ln(S_T / S_0) in the "little Heston trap"
+/// form of Albrecher et al., which avoids the branch-cut discontinuity.
+///
+/// beta - d is O(vol_of_vol^2) and every term divides it by vol_of_vol^2, so
+/// it is carried as (beta - d) / sigma^2 = -(iu + u^2) / (beta + d) and the
+/// log term as ln(1 + h) / h. Taken directly, the cancellation wrecks f64
+/// prices below a vol of vol of about 1e-2; this form tends smoothly to the
+/// deterministic-variance (Black-Scholes) limit.
+fn characteristic_fn(u: Complex, t: f64, drift: f64, p: &Params) -> Complex {
+    let iu = I * u;
+    let sigma2 = p.sigma * p.sigma;
+    let beta = Complex::new(p.kappa, 0.0) - iu * (p.rho * p.sigma);
+    let a = iu + u * u;
+    let d = (beta * beta + a * sigma2).sqrt();
+    let b = -a / (beta + d);
+    let g = b * sigma2 / (beta + d);
+    let e = (-d * t).exp();
+    // ln((1 - g e) / (1 - g)) = ln(1 + h), h = g (1 - e) / (1 - g)
+    let h_over_sigma2 = b / (beta + d) * ((ONE - e) / (ONE - g));
+    let log_term = h_over_sigma2 * ln1p_ratio(h_over_sigma2 * sigma2);
+    let c = iu * (drift * t) + (b * t - log_term * 2.0) * (p.kappa * p.theta);
+    let dv = b * ((ONE - e) / (ONE - g * e));
+    (c + dv * p.v0).exp()
+}
+
+/// ln(1 + z) / z, by series near zero where the direct form cancels.
+fn ln1p_ratio(z: Complex) -> Complex {
+    if z.re.hypot(z.im) < 1e-4 {
+        ONE - z * 0.5 + z * z * (1.0 / 3.0)
+    } else {
+        (ONE + z).ln() / z
+    }
+}
+
+/// 8-point Gauss-Legendre nodes and weights on [-1, 1].
+const GL_NODES: [f64; 8] = [
+    -0.960_289_856_497_536_3,
+    -0.796_666_477_413_626_7,
+    -0.525_532_409_916_329,
+    -0.183_434_642_495_649_8,
+    0.183_434_642_495_649_8,
+    0.525_532_409_916_329,
+    0.796_666_477_413_626_7,
+    0.960_289_856_497_536_3,
+];
+const GL_WEIGHTS: [f64; 8] = [
+    0.101_228_536_290_376_3,
+    0.222_381_034_453_374_5,
+    0.313_706_645_877_887_3,
+    0.362_683_783_378_362,
+    0.362_683_783_378_362,
+    0.313_706_645_877_887_3,
+    0.222_381_034_453_374_5,
+    0.101_228_536_290_376_3,
+];
+const INTEGRATION_LIMIT: f64 = 200.0;
+const PANELS: usize = 100;
+
+/// Heston call prices for several strikes at one expiry. The characteristic
+/// function does not depend on strike, so it is evaluated once per node.
+fn heston_calls(s: f64, r: f64, q: f64, t: f64, p: &Params, strikes: &[f64]) -> Vec<f64> {
+    let drift = r - q;
+    let forward_growth = (drift * t).exp();
+    let width = INTEGRATION_LIMIT / PANELS as f64;
+
+    // (u, weight, phi(u), phi(u - i) / phi(-i))
+    let mut nodes = Vec::with_capacity(PANELS * GL_NODES.len());
+    for panel in 0..PANELS {
+        let mid = (panel as f64 + 0.5) * width;
+        for (x, w) in GL_NODES.iter().zip(&GL_WEIGHTS) {
+            let u = mid + x * width / 2.0;
+            let phi2 = characteristic_fn(Complex::new(u, 0.0), t, drift, p);
+            let phi1 =
+                characteristic_fn(Complex::new(u, -1.0), t, drift, p) * (1.0 / forward_growth);
+            nodes.push((u, w * width / 2.0, phi2, phi1));
+        }
+    }
+
+    strikes
+        .iter()
+        .map(|&k| {
+            let log_moneyness = (k / s).ln();
+            let (mut p1, mut p2) = (0.0, 0.0);
+            for &(u, w, phi2, phi1) in &nodes {
+                let kernel = Complex::new(0.0, -u * log_moneyness).exp() / (I * u);
+                p1 += w * (kernel * phi1).re;
+                p2 += w * (kernel * phi2).re;
+            }
+            let p1 = 0.5 + p1 / std::f64::consts::PI;
+            let p2 = 0.5 + p2 / std::f64::consts::PI;
+            let call = s * (-q * t).exp() * p1 - k * (-r * t).exp() * p2;
+            // Quadrature noise can push deep out-of-the-money values just
+            // outside the no-arbitrage bounds
+            let lower = (s * (-q * t).exp() - k * (-r * t).exp()).max(0.0);
+            call.clamp(lower, s * (-q * t).exp())
+        })
+        .collect()
+}
+
+fn call_to_put(call: f64, s: f64, k: f64, r: f64, q: f64, t: f64) -> f64 {
+    call - s * (-q * t).exp() + k * (-r * t).exp()
+}
+
+// ---------------------------------------------------------------------------
+// Black-Scholes helpers (f64)
+// ---------------------------------------------------------------------------
+
+/// Standard normal CDF (Abramowitz & Stegun 26.2.17).
+fn norm_cdf(x: f64) -> f64 {
+    let t = 1.0 / (1.0 + 0.231_641_9 * x.abs());
+    let poly = t
+        * (0.319_381_530
+            + t * (-0.356_563_782
+                + t * (1.781_477_937 + t * (-1.821_255_978 + t * 1.330_274_429))));
+    let pdf = (-0.5 * x * x).exp() / (2.0 * std::f64::consts::PI).sqrt();
+    let upper = pdf * poly;
+    if x >= 0.0 {
+        1.0 - upper
+    } else {
+        upper
+    }
+}
+
+fn bs_call(s: f64, k: f64, r: f64, q: f64, t: f64, vol: f64) -> f64 {
+    let sqrt_t = t.sqrt();
+    let d1 = ((s / k).ln() + (r - q + 0.5 * vol * vol) * t) / (vol * sqrt_t);
+    let d2 = d1 - vol * sqrt_t;
+    s * (-q * t).exp() * norm_cdf(d1) - k * (-r * t).exp() * norm_cdf(d2)
+}
+
+fn bs_vega(s: f64, k: f64, r: f64, q: f64, t: f64, vol: f64) -> f64 {
+    let sqrt_t = t.sqrt();
+    let d1 = ((s / k).ln() + (r - q + 0.5 * vol * vol) * t) / (vol * sqrt_t);
+    s * (-q * t).exp() * (-0.5 * d1 * d1).exp() / (2.0 * std::f64::consts::PI).sqrt() * sqrt_t
+}
+
+/// Black-Scholes implied vol of a call price by bisection; None outside the
+/// price range spanned by vols in [0.1%, 500%].
+fn implied_vol_from_call(price: f64, s: f64, k: f64, r: f64, q: f64, t: f64) -> Option<f64> {
+    let (mut lo, mut hi) = (0.001, 5.0);
+    if price < bs_call(s, k, r, q, t, lo) || price > bs_call(s, k, r, q, t, hi) {
+        return None;
+    }
+    for _ in 0..100 {
+        let mid = 0.5 * (lo + hi);
+        if bs_call(s, k, r, q, t, mid) < price {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+        if hi - lo < 1e-10 {
+            break;
+        }
+    }
+    Some(0.5 * (lo + hi))
+}
+
+fn to_decimal(v: f64) -> Decimal {
+    Decimal::from_f64(v).unwrap_or(Decimal::ZERO).round_dp(8)
+}
+
+// ---------------------------------------------------------------------------
+// Validation
+// ---------------------------------------------------------------------------
+
+fn validate_market(spot: Decimal) -> CorpFinanceResult<()> {
+    if spot <= Decimal::ZERO {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "spot_price".into(),
+            reason: "must be positive".into(),
+        });
+    }
+    Ok(())
+}
+
+fn validate_params(p: &HestonParams, field: &str) -> CorpFinanceResult<()> {
+    let positive = [
+        ("v0", p.v0),
+        ("kappa", p.kappa),
+        ("theta", p.theta),
+        ("vol_of_vol", p.vol_of_vol),
+    ];
+    if let Some((name, _)) = positive.iter().find(|(_, v)| *v <= Decimal::ZERO) {
+        return Err(CorpFinanceError::InvalidInput {
+            field: format!("{field}.{name}"),
+            reason: "must be positive".into(),
+        });
+    }
+    if p.rho <= -Decimal::ONE || p.rho >= Decimal::ONE {
+        return Err(CorpFinanceError::InvalidInput {
+            field: format!("{field}.rho"),
+            reason: "must lie in (-1, 1)".into(),
+        });
+    }
+    Ok(())
+}
+
+fn validate_strike_expiry(
+    strike: Decimal,
+    expiry: Decimal,
+    field: String,
+) -> CorpFinanceResult<()> {
+    if strike <= Decimal::ZERO || expiry <= Decimal::ZERO {
+        return Err(CorpFinanceError::InvalidInput {
+            field,
+            reason: "strike and expiry must be positive".into(),
+        });
+    }
+    Ok(())
+}
+
+/// Expiries in ascending order with the indices of the items at each.
+fn group_by_expiry(expiries: &[Decimal]) -> Vec<(Decimal, Vec<usize>)> {
+    let mut groups: Vec<(Decimal, Vec<usize>)> = Vec::new();
+    for (i, t) in expiries.iter().enumerate() {
+        match groups.iter_mut().find(|(e, _)| e == t) {
+            Some((_, idx)) => idx.push(i),
+            None => groups.push((*t, vec![i])),
+        }
+    }
+    groups.sort_by_key(|g| g.0);
+    groups
+}
+
+// ---------------------------------------------------------------------------
+// Public API: price_heston
+// ---------------------------------------------------------------------------
+
+/// Price European options under Heston by Fourier inversion of the
+/// characteristic function.
+pub fn price_heston(
+    input: &HestonPricingInput,
+) -> CorpFinanceResult<ComputationOutput<HestonPricingOutput>> {
+    let start = Instant::now();
+    validate_market(input.spot_price)?;
+    validate_params(&input.params, "params")?;
+    if input.options.is_empty() {
+        return Err(CorpFinanceError::InsufficientData(
+            "At least one option is required".into(),
+        ));
+    }
+    for (i, o) in input.options.iter().enumerate() {
+        validate_strike_expiry(o.strike, o.expiry, format!("options[{i}]"))?;
+    }
+
+    let s = input.spot_price.to_f64().unwrap_or(0.0);
+    let r = input.risk_free_rate.to_f64().unwrap_or(0.0);
+    let q = input.dividend_yield.to_f64().unwrap_or(0.0);
+    let p = Params::from_decimal(&input.params);
+
+    let expiries: Vec<Decimal> = input.options.iter().map(|o| o.expiry).collect();
+    let mut prices: Vec<Option<HestonOptionPrice>> = vec![None; input.options.len()];
+    for (expiry, idx) in group_by_expiry(&expiries) {
+        let t = expiry.to_f64().unwrap_or(0.0);
+        let strikes: Vec<f64> = idx
+            .iter()
+            .map(|&i| input.options[i].strike.to_f64().unwrap_or(0.0))
+            .collect();
+        let calls = heston_calls(s, r, q, t, &p, &strikes);
+        for ((&i, &k), call) in idx.iter().zip(&strikes).zip(calls) {
+            let o = &input.options[i];
+            let price = match o.option_type {
+                OptionType::Call => call,
+                OptionType::Put => call_to_put(call, s, k, r, q, t),
+            };
+            prices[i] = Some(HestonOptionPrice {
+                strike: o.strike,
+                expiry: o.expiry,
+                option_type: o.option_type,
+                price: to_decimal(price).round_dp(6),
+                implied_vol: implied_vol_from_call(call, s, k, r, q, t)
+                    .map(|v| to_decimal(v).round_dp(6)),
+            });
+        }
+    }
+
+    let feller = p.feller_ratio();
+    let mut warnings = Vec::new();
+    if feller < 1.0 {
+        warnings.push(format!(
+            "Feller condition violated (2*kappa*theta / vol_of_vol^2 = {feller:.3}); variance can touch zero"
+        ));
+    }
+
+    let output = HestonPricingOutput {
+        prices: prices.into_iter().flatten().collect(),
+        feller_ratio: to_decimal(feller).round_dp(6),
+        feller_satisfied: feller >= 1.0,
+    };
+
+    let assumptions = serde_json::json!({
+        "model": "Heston (1993) stochastic volatility",
+        "characteristic_function": "Albrecher et al. little Heston trap",
+        "integration": format!("Gauss-Legendre, {PANELS} x 8 nodes on [0, {INTEGRATION_LIMIT}]"),
+        "risk_free_rate": input.risk_free_rate.to_string(),
+        "dividend_yield": input.dividend_yield.to_string(),
+    });
+
+    let elapsed = start.elapsed().as_micros() as u64;
+    Ok(with_metadata(
+        "Heston semi-analytic Fourier pricing",
+        &assumptions,
+        warnings,
+        elapsed,
+        output,
+    ))
+}
+
+// ---------------------------------------------------------------------------
+// Public API: calibrate_heston
+// ---------------------------------------------------------------------------
+
+struct MarketQuote {
+    strike: f64,
+    market_vol: f64,
+    market_price: f64,
+    vega: f64,
+}
+
+/// Vega-weighted price residuals, which approximate implied vol errors
+/// without inverting Black-Scholes inside the optimiser.
+fn calibration_residuals(
+    s: f64,
+    r: f64,
+    q: f64,
+    groups: &[(f64, Vec<MarketQuote>)],
+    p: &Params,
+) -> Vec<f64> {
+    groups
+        .iter()
+        .flat_map(|(t, quotes)| {
+            let strikes: Vec<f64> = quotes.iter().map(|m| m.strike).collect();
+            let calls = heston_calls(s, r, q, *t, p, &strikes);
+            quotes
+                .iter()
+                .zip(calls)
+                .map(|(m, c)| (c - m.market_price) / m.vega)
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+/// Solve a 5x5 linear system by Gaussian elimination with partial pivoting.
+fn solve_5x5(mut a: [[f64; 5]; 5], mut b: [f64; 5]) -> Option<[f64; 5]> {
+    for col in 0..5 {
+        let pivot = (col..5).max_by(|&i, &j| a[i][col].abs().total_cmp(&a[j][col].abs()))?;
+        if a[pivot][col].abs() < 1e-14 {
+            return None;
+        }
+        a.swap(col, pivot);
+        b.swap(col, pivot);
+        for row in col + 1..5 {
+            let pivot_row = a[col];
+            let f = a[row][col] / pivot_row[col];
+            for (cell, &p) in a[row].iter_mut().zip(&pivot_row).skip(col) {
+                *cell -= f * p;
+            }
+            b[row] -= f * b[col];
+        }
+    }
+    let mut x = [0.0f64; 5];
+    for row in (0..5).rev() {
+        let tail: f64 = (row + 1..5).map(|c| a[row][c] * x[c]).sum();
+        x[row] = (b[row] - tail) / a[row][row];
+    }
+    Some(x)
+}
+
+/// Calibrate Heston parameters to an implied volatility surface by
+/// Levenberg-Marquardt on vega-weighted price errors.
+pub fn calibrate_heston(
+    input: &HestonCalibrationInput,
+) -> CorpFinanceResult<ComputationOutput<HestonCalibrationOutput>> {
+    let start = Instant::now();
+    validate_market(input.spot_price)?;
+    if input.surface_points.len() < 5 {
+        return Err(CorpFinanceError::InsufficientData(
+            "At least 5 surface points are needed to fit 5 Heston parameters".into(),
+        ));
+    }
+    for (i, sp) in input.surface_points.iter().enumerate() {
+        validate_strike_expiry(sp.strike, sp.expiry, format!("surface_points[{i}]"))?;
+        if sp.implied_vol <= Decimal::ZERO {
+            return Err(CorpFinanceError::InvalidInput {
+                field: format!("surface_points[{i}].implied_vol"),
+                reason: "must be positive".into(),
+            });
+        }
+    }
+    if let Some(ref p) = input.initial_params {
+        validate_params(p, "initial_params")?;
+    }
+
+    let s = input.spot_price.to_f64().unwrap_or(0.0);
+    let r = input.risk_free_rate.to_f64().unwrap_or(0.0);
+    let q = input.dividend_yield.to_f64().unwrap_or(0.0);
+
+    let expiries: Vec<Decimal> = input.surface_points.iter().map(|p| p.expiry).collect();
+    let groups: Vec<(f64, Vec<MarketQuote>)> = group_by_expiry(&expiries)
+        .into_iter()
+        .map(|(expiry, idx)| {
+            let t = expiry.to_f64().unwrap_or(0.0);
+            let quotes = idx
+                .iter()
+                .map(|&i| {
+                    let sp = &input.surface_points[i];
+                    let k = sp.strike.to_f64().unwrap_or(0.0);
+                    let vol = sp.implied_vol.to_f64().unwrap_or(0.0);
+                    MarketQuote {
+                        strike: k,
+                        market_vol: vol,
+                        market_price: bs_call(s, k, r, q, t, vol),
+                        // Floor keeps far wings from dominating the fit
+                        vega: bs_vega(s, k, r, q, t, vol).max(1e-4 * s),
+                    }
+                })
+                .collect();
+            (t, quotes)
+        })
+        .collect();
+
+    let mean_var = groups
+        .iter()
+        .flat_map(|(_, qs)| qs.iter().map(|m| m.market_vol * m.market_vol))
+        .sum::<f64>()
+        / input.surface_points.len() as f64;
+    let mut params = match input.initial_params {
+        Some(ref p) => Params::from_decimal(p),
+        None => Params {
+            v0: mean_var,
+            kappa: 1.5,
+            theta: mean_var,
+            sigma: 0.5,
+            rho: -0.5,
+        },
+    };
+
+    let max_iter = input.max_iterations.unwrap_or(100);
+    let mut residuals = calibration_residuals(s, r, q, &groups, &params);
+    let mut sse: f64 = residuals.iter().map(|e| e * e).sum();
+    let mut lambda = 1e-3;
+    let mut iterations = 0;
+
+    for iter in 0..max_iter {
+        iterations = iter + 1;
+        let base = params.as_array();
+
+        // Forward-difference Jacobian
+        let mut jacobian = vec![[0.0f64; 5]; residuals.len()];
+        for j in 0..5 {
+            let h = 1e-5 * base[j].abs().max(1e-2);
+            let mut bumped = base;
+            bumped[j] += h;
+            let res_up = calibration_residuals(s, r, q, &groups, &Params::from_array(bumped));
+            for (row, (up, e)) in jacobian.iter_mut().zip(res_up.iter().zip(&residuals)) {
+                row[j] = (up - e) / h;
+            }
+        }
+
+        let mut jtj = [[0.0f64; 5]; 5];
+        let mut jte = [0.0f64; 5];
+        for (row, e) in jacobian.iter().zip(&residuals) {
+            for a in 0..5 {
+                jte[a] -= row[a] * e;
+                for b in 0..5 {
+                    jtj[a][b] += row[a] * row[b];
+                }
+            }
+        }
+
+        // Retry with heavier damping until the step improves the fit
+        let mut improved = false;
+        while lambda < 1e10 {
+            let mut damped = jtj;
+            for (a, row) in damped.iter_mut().enumerate() {
+                row[a] += lambda * jtj[a][a].max(1e-12);
+            }
+            let Some(step) = solve_5x5(damped, jte) else {
+                lambda *= 10.0;
+                continue;
+            };
+            let mut trial = base;
+            for (t, d) in trial.iter_mut().zip(step) {
+                *t += d;
+            }
+            let candidate = Params::from_array(trial);
+            let trial_res = calibration_residuals(s, r, q, &groups, &candidate);
+            let trial_sse: f64 = trial_res.iter().map(|e| e * e).sum();
+            if trial_sse < sse {
+                let gain = (sse - trial_sse) / sse.max(1e-300);
+                params = candidate;
+                residuals = trial_res;
+                sse = trial_sse;
+                lambda = (lambda / 3.0).max(1e-12);
+                improved = gain > 1e-10;
+                break;
+            }
+            lambda *= 10.0;
+        }
+        if !improved {
+            break;
+        }
+    }
+
+    // Report errors in true implied vol terms
+    let mut fit = Vec::with_capacity(input.surface_points.len());
+    let mut sq_sum = 0.0;
+    let mut max_abs: f64 = 0.0;
+    let mut fitted = 0usize;
+    for (t, quotes) in &groups {
+        let strikes: Vec<f64> = quotes.iter().map(|m| m.strike).collect();
+        let calls = heston_calls(s, r, q, *t, &params, &strikes);
+        for (m, c) in quotes.iter().zip(calls) {
+            let model_vol = implied_vol_from_call(c, s, m.strike, r, q, *t);
+            let err = model_vol.map(|v| v - m.market_vol);
+            if let Some(e) = err {
+                sq_sum += e * e;
+                max_abs = max_abs.max(e.abs());
+                fitted += 1;
+            }
+            fit.push(HestonFitPoint {
+                strike: to_decimal(m.strike),
+                expiry: to_decimal(*t),
+                market_vol: to_decimal(m.market_vol).round_dp(6),
+                model_vol: model_vol.map(|v| to_decimal(v).round_dp(6)),
+                vol_error: err.map(|e| to_decimal(e).round_dp(6)),
+            });
+        }
+    }
+    let rmse = if fitted > 0 {
+        (sq_sum / fitted as f64).sqrt()
+    } else {
+        0.0
+    };
+
+    let feller = params.feller_ratio();
+    let mut warnings = Vec::new();
+    if feller < 1.0 {
+        warnings.push(format!(
+            "Calibrated parameters violate the Feller condition (ratio {feller:.3})"
+        ));
+    }
+    if fitted < fit.len() {
+        warnings.push(format!(
+            "{} model prices fall outside the implied vol search range",
+            fit.len() - fitted
+        ));
+    }
+    if iterations == max_iter {
+        warnings.push(format!(
+            "Calibration stopped at the {max_iter} iteration limit"
+        ));
+    }
+
+    let output = HestonCalibrationOutput {
+        params: params.to_decimal(),
+        rmse_vol_error: to_decimal(rmse).round_dp(6),
+        max_abs_vol_error: to_decimal(max_abs).round_dp(6),
+        fit,
+        feller_ratio: to_decimal(feller).round_dp(6),
+        feller_satisfied: feller >= 1.0,
+        iterations,
+    };
+
+    let assumptions = serde_json::json!({
+        "model": "Heston (1993) stochastic volatility",
+        "objective": "vega-weighted call price errors (approximate vol errors)",
+        "optimiser": "Levenberg-Marquardt with forward-difference Jacobian",
+        "surface_points": input.surface_points.len(),
+        "max_iterations": max_iter,
+        "risk_free_rate": input.risk_free_rate.to_string(),
+        "dividend_yield": input.dividend_yield.to_string(),
+    });
+
+    let elapsed = start.elapsed().as_micros() as u64;
+    Ok(with_metadata(
+        "Heston calibration to implied volatility surface",
+        &assumptions,
+        warnings,
+        elapsed,
+        output,
+    ))
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn approx_eq(a: Decimal, b: Decimal, tol: Decimal) -> bool {
+        (a - b).abs() < tol
+    }
+
+    fn equity_params() -> HestonParams {
+        HestonParams {
+            v0: dec!(0.04),
+            kappa: dec!(2.0),
+            theta: dec!(0.05),
+            vol_of_vol: dec!(0.4),
+            rho: dec!(-0.7),
+        }
+    }
+
+    fn option(strike: Decimal, expiry: Decimal, option_type: OptionType) -> HestonOption {
+        HestonOption {
+            strike,
+            expiry,
+            option_type,
+        }
+    }
+
+    fn pricing_input(params: HestonParams, options: Vec<HestonOption>) -> HestonPricingInput {
+        HestonPricingInput {
+            spot_price: dec!(100),
+            risk_free_rate: dec!(0.03),
+            dividend_yield: dec!(0.01),
+            params,
+            options,
+        }
+    }
+
+    #[test]
+    fn test_small_vol_of_vol_matches_black_scholes() {
+        // Constant variance 0.04 -> Black-Scholes with 20% vol, down to vols
+        // of vol where the direct characteristic function cancels away
+        for vol_of_vol in [dec!(0.01), dec!(0.001), dec!(0.0001)] {
+            let params = HestonParams {
+                v0: dec!(0.04),
+                kappa: dec!(1.0),
+                theta: dec!(0.04),
+                vol_of_vol,
+                rho: dec!(0),
+            };
+            let input = pricing_input(
+                params,
+                vec![
+                    option(dec!(90), dec!(1), OptionType::Call),
+                    option(dec!(100), dec!(1), OptionType::Call),
+                    option(dec!(110), dec!(0.5), OptionType::Call),
+                    option(dec!(100), dec!(1), OptionType::Put),
+                ],
+            );
+            let out = price_heston(&input).unwrap().result;
+            for p in &out.prices {
+                let (k, t) = (p.strike.to_f64().unwrap(), p.expiry.to_f64().unwrap());
+                let call = bs_call(100.0, k, 0.03, 0.01, t, 0.2);
+                let bs = match p.option_type {
+                    OptionType::Call => call,
+                    OptionType::Put => call_to_put(call, 100.0, k, 0.03, 0.01, t),
+                };
+                assert!(
+                    approx_eq(p.price, to_decimal(bs), dec!(0.002)),
+                    "Heston {} vs BS {bs} at K={}, vol of vol {vol_of_vol}",
+                    p.price,
+                    p.strike
+                );
+                assert!(approx_eq(p.implied_vol.unwrap(), dec!(0.2), dec!(0.0005)));
+            }
+        }
+    }
+
+    #[test]
+    fn test_put_call_parity() {
+        let input = pricing_input(
+            equity_params(),
+            vec![
+                option(dec!(95), dec!(2), OptionType::Call),
+                option(dec!(95), dec!(2), OptionType::Put),
+            ],
+        );
+        let out = price_heston(&input).unwrap().result;
+        let (c, p) = (out.prices[0].price, out.prices[1].price);
+        let parity = 100.0 * (-0.01f64 * 2.0).exp() - 95.0 * (-0.03f64 * 2.0).exp();
+        assert!(approx_eq(c - p, to_decimal(parity), dec!(0.00001)));
+        // Both sides imply the same volatility
+        assert_eq!(out.prices[0].implied_vol, out.prices[1].implied_vol);
+    }
+
+    #[test]
+    fn test_negative_correlation_produces_skew() {
+        let input = pricing_input(
+            equity_params(),
+            vec![
+                option(dec!(80), dec!(1), OptionType::Put),
+                option(dec!(100), dec!(1), OptionType::Call),
+                option(dec!(120), dec!(1), OptionType::Call),
+            ],
+        );
+        let out = price_heston(&input).unwrap().result;
+        let vols: Vec<Decimal> = out.prices.iter().map(|p| p.implied_vol.unwrap()).collect();
+        assert!(vols[0] > vols[1] && vols[1] > vols[2], "vols {vols:?}");
+        // Feller: 2 * 2 * 0.05 / 0.16 = 1.25
+        assert!(out.feller_satisfied);
+        assert_eq!(out.feller_ratio, dec!(1.25));
+    }
+
+    #[test]
+    fn test_output_order_follows_input() {
+        let input = pricing_input(
+            equity_params(),
+            vec![
+                option(dec!(100), dec!(2), OptionType::Call),
+                option(dec!(100), dec!(0.5), OptionType::Call),
+                option(dec!(110), dec!(2), OptionType::Call),
+            ],
+        );
+        let out = price_heston(&input).unwrap().result;
+        assert_eq!(out.prices[0].expiry, dec!(2));
+        assert_eq!(out.prices[1].expiry, dec!(0.5));
+        assert!(out.prices[0].price > out.prices[1].price);
+        assert!(out.prices[0].price > out.prices[2].price);
+    }
+
+    #[test]
+    fn test_calibration_recovers_parameters() {
+        let truth = equity_params();
+        let strikes = [dec!(80), dec!(90), dec!(100), dec!(110), dec!(120)];
+        let expiries = [dec!(0.5), dec!(1), dec!(2)];
+        let options: Vec<HestonOption> = expiries
+            .iter()
+            .flat_map(|t| {
+                strikes
+                    .iter()
+                    .map(move |k| option(*k, *t, OptionType::Call))
+            })
+            .collect();
+        let priced = price_heston(&pricing_input(truth.clone(), options))
+            .unwrap()
+            .result;
+        let surface_points: Vec<SurfacePoint> = priced
+            .prices
+            .iter()
+            .map(|p| SurfacePoint {
+                strike: p.strike,
+                expiry: p.expiry,
+                implied_vol: p.implied_vol.unwrap(),
+                moneyness: p.strike / dec!(100),
+                log_moneyness: Decimal::ZERO,
+                delta: Decimal::ZERO,
+            })
+            .collect();
+
+        let res = calibrate_heston(&HestonCalibrationInput {
+            spot_price: dec!(100),
+            risk_free_rate: dec!(0.03),
+            dividend_yield: dec!(0.01),
+            surface_points,
+            initial_params: None,
+            max_iterations: None,
+        })
+        .unwrap();
+        let out = &res.result;
+        assert!(
+            out.rmse_vol_error < dec!(0.0005),
+            "rmse {} after {} iterations",
+            out.rmse_vol_error,
+            out.iterations
+        );
+        assert!(approx_eq(out.params.v0, truth.v0, dec!(0.005)));
+        assert!(approx_eq(out.params.rho, truth.rho, dec!(0.1)));
+        assert_eq!(out.fit.len(), 15);
+    }
+
+    #[test]
+    fn test_invalid_inputs() {
+        let mut bad = equity_params();
+        bad.rho = dec!(1);
+        let input = pricing_input(bad, vec![option(dec!(100), dec!(1), OptionType::Call)]);
+        match price_heston(&input).unwrap_err() {
+            CorpFinanceError::InvalidInput { field, .. } => assert_eq!(field, "params.rho"),
+            other => panic!("Expected InvalidInput, got {other:?}"),
+        }
+
+        let no_options = pricing_input(equity_params(), vec![]);
+        assert!(price_heston(&no_options).is_err());
+
+        let too_few = HestonCalibrationInput {
+            spot_price: dec!(100),
+            risk_free_rate: dec!(0.03),
+            dividend_yield: dec!(0),
+            surface_points: vec![],
+            initial_params: None,
+            max_iterations: None,
+        };
+        assert!(matches!(
+            calibrate_heston(&too_few).unwrap_err(),
+            CorpFinanceError::InsufficientData(_)
+        ));
+    }
+}
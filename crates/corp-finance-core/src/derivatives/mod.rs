@@ -1,4 +1,5 @@
 pub mod forwards;
+pub mod heston;
 pub mod options;
 pub mod strategies;
 pub mod swaps;
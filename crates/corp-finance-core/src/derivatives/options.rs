@@ -1,9 +1,11 @@
+use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
 use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
 use serde::{Deserialize, Serialize};
 use std::time::Instant;
 
 use crate::error::CorpFinanceError;
+use crate::sampling::Lcg;
 use crate::types::*;
 use crate::CorpFinanceResult;
 
@@ -21,6 +23,72 @@ pub enum OptionType {
 pub enum ExerciseStyle {
     European,
     American,
+    /// Exercisable only on the dates in `OptionInput::exercise_times` and at expiry.
+    Bermudan,
+}
+
+/// How Greeks are computed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GreeksMethod {
+    /// Closed-form Black-Scholes sensitivities (European exercise)
+    Analytic,
+    /// Central finite differences, repricing with the selected model
+    BumpAndReprice,
+}
+
+/// Finite-difference bump sizes for bump-and-reprice Greeks.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GreekBumps {
+    /// Relative spot bump (0.01 = 1% of spot)
+    #[serde(default = "default_spot_bump")]
+    pub spot_bump_pct: Decimal,
+    /// Absolute volatility bump (0.01 = 1 vol point)
+    #[serde(default = "default_vol_bump")]
+    pub vol_bump: Decimal,
+    /// Absolute rate bump (0.0001 = 1bp)
+    #[serde(default = "default_rate_bump")]
+    pub rate_bump: Decimal,
+    /// Time decay step in calendar days
+    #[serde(default = "default_time_bump_days")]
+    pub time_bump_days: Decimal,
+}
+
+fn default_spot_bump() -> Decimal {
+    dec!(0.01)
+}
+
+fn default_vol_bump() -> Decimal {
+    dec!(0.01)
+}
+
+fn default_rate_bump() -> Decimal {
+    dec!(0.0001)
+}
+
+fn default_time_bump_days() -> Decimal {
+    dec!(1)
+}
+
+impl Default for GreekBumps {
+    fn default() -> Self {
+        GreekBumps {
+            spot_bump_pct: default_spot_bump(),
+            vol_bump: default_vol_bump(),
+            rate_bump: default_rate_bump(),
+            time_bump_days: default_time_bump_days(),
+        }
+    }
+}
+
+/// Numerical method used for options with early exercise.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EarlyExerciseModel {
+    /// Cox-Ross-Rubinstein binomial tree.
+    Binomial,
+    /// Recombining trinomial tree in log-price space.
+    Trinomial,
+    /// Longstaff-Schwartz least-squares Monte Carlo.
+    LongstaffSchwartz,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -34,7 +102,28 @@ pub struct OptionInput {
     pub dividend_yield: Rate,
     pub option_type: OptionType,
     pub exercise_style: ExerciseStyle,
+    /// Lattice steps (default 100); also the exercise grid for American
+    /// options priced by Longstaff-Schwartz.
     pub binomial_steps: Option<u32>,
+    /// Pricing method for American and Bermudan exercise (default Binomial).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub early_exercise_model: Option<EarlyExerciseModel>,
+    /// Bermudan exercise dates as year fractions from valuation; expiry is
+    /// always exercisable.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exercise_times: Option<Vec<Decimal>>,
+    /// Longstaff-Schwartz path count (default 10,000, antithetic pairs).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub simulation_paths: Option<u32>,
+    /// Seed for reproducible Longstaff-Schwartz paths (default 42).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub seed: Option<u64>,
+    /// Greeks calculation (default Analytic).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub greeks_method: Option<GreeksMethod>,
+    /// Bump sizes for `GreeksMethod::BumpAndReprice`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub greek_bumps: Option<GreekBumps>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -47,15 +136,28 @@ pub struct OptionOutput {
     pub put_call_parity_price: Option<Money>,
     pub moneyness: String,
     pub breakeven: Money,
+    /// Early-exercise price less the Black-Scholes European value.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub early_exercise_premium: Option<Money>,
+    /// Standard error of the Longstaff-Schwartz estimate.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub monte_carlo_std_error: Option<Money>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OptionGreeks {
     pub delta: Decimal,
     pub gamma: Decimal,
+    /// Per calendar day
     pub theta: Decimal,
+    /// Per 1 vol point
     pub vega: Decimal,
+    /// Per 1% rate move
     pub rho: Decimal,
+    /// Change in delta per 1 vol point
+    pub vanna: Decimal,
+    /// Change in vega per 1 vol point
+    pub volga: Decimal,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -231,6 +333,68 @@ fn validate_pricing_input(input: &OptionInput) -> CorpFinanceResult<()> {
             reason: "must be positive".into(),
         });
     }
+    if input.binomial_steps == Some(0) {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "binomial_steps".into(),
+            reason: "must be at least 1".into(),
+        });
+    }
+    if input.simulation_paths.is_some_and(|n| n < 2) {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "simulation_paths".into(),
+            reason: "must be at least 2".into(),
+        });
+    }
+    if input.early_exercise_model == Some(EarlyExerciseModel::LongstaffSchwartz) {
+        crate::limits::check_simulation_paths(
+            "simulation_paths",
+            input.simulation_paths.unwrap_or(10_000).into(),
+        )?;
+    }
+    if let Some(ref b) = input.greek_bumps {
+        if b.spot_bump_pct <= Decimal::ZERO || b.spot_bump_pct >= Decimal::ONE {
+            return Err(CorpFinanceError::InvalidInput {
+                field: "greek_bumps.spot_bump_pct".into(),
+                reason: "must be in (0, 1)".into(),
+            });
+        }
+        if b.vol_bump <= Decimal::ZERO || b.vol_bump >= input.volatility {
+            return Err(CorpFinanceError::InvalidInput {
+                field: "greek_bumps.vol_bump".into(),
+                reason: "must be positive and below volatility".into(),
+            });
+        }
+        if b.rate_bump <= Decimal::ZERO {
+            return Err(CorpFinanceError::InvalidInput {
+                field: "greek_bumps.rate_bump".into(),
+                reason: "must be positive".into(),
+            });
+        }
+        if b.time_bump_days <= Decimal::ZERO {
+            return Err(CorpFinanceError::InvalidInput {
+                field: "greek_bumps.time_bump_days".into(),
+                reason: "must be positive".into(),
+            });
+        }
+    }
+    if input.exercise_style == ExerciseStyle::Bermudan {
+        let times = input.exercise_times.as_deref().unwrap_or_default();
+        if times.is_empty() {
+            return Err(CorpFinanceError::InvalidInput {
+                field: "exercise_times".into(),
+                reason: "required for Bermudan exercise".into(),
+            });
+        }
+        if times
+            .iter()
+            .any(|t| *t <= Decimal::ZERO || *t > input.time_to_expiry)
+        {
+            return Err(CorpFinanceError::InvalidInput {
+                field: "exercise_times".into(),
+                reason: "must lie in (0, time_to_expiry]".into(),
+            });
+        }
+    }
     Ok(())
 }
 
@@ -365,12 +529,141 @@ fn compute_greeks(
         OptionType::Put => -k * t * params.exp_neg_rt * norm_cdf(-params.d2) / dec!(100),
     };
 
+    // Vanna: d(delta)/d(sigma) = -e^(-qT) * n(d1) * d2 / sigma, per 1% vol move
+    // Volga: d(vega)/d(sigma) = vega * d1 * d2 / sigma, per 1% vol move
+    let sigma = if t > Decimal::ZERO {
+        sigma_sqrt_t / params.sqrt_t
+    } else {
+        Decimal::ZERO
+    };
+    let (vanna, volga) = if sigma > Decimal::ZERO {
+        (
+            -params.exp_neg_qt * nd1 * params.d2 / sigma / dec!(100),
+            vega * params.d1 * params.d2 / sigma / dec!(100),
+        )
+    } else {
+        (Decimal::ZERO, Decimal::ZERO)
+    };
+
     OptionGreeks {
         delta,
         gamma,
         theta,
         vega,
         rho,
+        vanna,
+        volga,
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Bump-and-reprice Greeks
+// ---------------------------------------------------------------------------
+
+/// Price under the input's exercise style and model, with the Monte Carlo
+/// standard error when Longstaff-Schwartz is used.
+fn model_price(input: &OptionInput) -> (Decimal, Option<Decimal>) {
+    let s = input.spot_price;
+    let k = input.strike_price;
+    let t = input.time_to_expiry;
+    let r = input.risk_free_rate;
+    let q = input.dividend_yield;
+    let sigma = input.volatility;
+    let steps = input.binomial_steps.unwrap_or(100);
+    let option_type = input.option_type;
+
+    if input.exercise_style == ExerciseStyle::European {
+        let params = compute_bs_params(s, k, t, r, q, sigma);
+        return (bs_price(s, k, r, &params, option_type), None);
+    }
+    match input
+        .early_exercise_model
+        .unwrap_or(EarlyExerciseModel::Binomial)
+    {
+        EarlyExerciseModel::Binomial => {
+            let exercisable = lattice_exercise_steps(input, steps);
+            let price = binomial_price(s, k, t, r, q, sigma, steps, option_type, &exercisable);
+            (price, None)
+        }
+        EarlyExerciseModel::Trinomial => {
+            let exercisable = lattice_exercise_steps(input, steps);
+            let price = trinomial_price(s, k, t, r, q, sigma, steps, option_type, &exercisable);
+            (price, None)
+        }
+        EarlyExerciseModel::LongstaffSchwartz => {
+            let times = simulation_exercise_times(input, steps);
+            let (price, se) = longstaff_schwartz_price(
+                s,
+                k,
+                r,
+                q,
+                sigma,
+                option_type,
+                &times,
+                input.simulation_paths.unwrap_or(10_000),
+                input.seed.unwrap_or(42),
+            );
+            (price, Some(se))
+        }
+    }
+}
+
+/// Central-difference Greeks from full repricing. Monte Carlo reprices reuse
+/// the seed, so bumps share random numbers.
+fn bump_and_reprice_greeks(input: &OptionInput, base: Decimal, bumps: &GreekBumps) -> OptionGreeks {
+    let reprice = |ds: Decimal, dv: Decimal, dr: Decimal, dt: Decimal| {
+        let mut bumped = input.clone();
+        bumped.spot_price += ds;
+        bumped.volatility += dv;
+        bumped.risk_free_rate += dr;
+        bumped.time_to_expiry -= dt;
+        // Exercise dates are fixed in calendar time, so they roll with valuation
+        bumped.exercise_times = input.exercise_times.as_ref().map(|ts| {
+            ts.iter()
+                .map(|e| *e - dt)
+                .filter(|e| *e > Decimal::ZERO)
+                .collect()
+        });
+        model_price(&bumped).0
+    };
+    let zero = Decimal::ZERO;
+
+    let h = input.spot_price * bumps.spot_bump_pct;
+    let up = reprice(h, zero, zero, zero);
+    let down = reprice(-h, zero, zero, zero);
+    let delta = (up - down) / (dec!(2) * h);
+    let gamma = (up - dec!(2) * base + down) / (h * h);
+
+    let dv = bumps.vol_bump;
+    let vol_up = reprice(zero, dv, zero, zero);
+    let vol_down = reprice(zero, -dv, zero, zero);
+    let vega = (vol_up - vol_down) / (dec!(2) * dv) / dec!(100);
+    let volga = (vol_up - dec!(2) * base + vol_down) / (dv * dv) / dec!(10000);
+    let vanna =
+        (reprice(h, dv, zero, zero) - reprice(h, -dv, zero, zero) - reprice(-h, dv, zero, zero)
+            + reprice(-h, -dv, zero, zero))
+            / (dec!(4) * h * dv)
+            / dec!(100);
+
+    let dr = bumps.rate_bump;
+    let rho = (reprice(zero, zero, dr, zero) - reprice(zero, zero, -dr, zero))
+        / (dec!(2) * dr)
+        / dec!(100);
+
+    // Forward difference in time, capped at half the remaining life
+    let days = bumps
+        .time_bump_days
+        .min(input.time_to_expiry * dec!(365) / dec!(2));
+    let theta = (reprice(zero, zero, zero, days / dec!(365)) - base) / days;
+
+    OptionGreeks {
+        delta,
+        gamma,
+        theta,
+        vega,
+        rho,
+        vanna,
+        volga,
     }
 }
 
@@ -378,6 +671,9 @@ fn compute_greeks(
 // Binomial tree (CRR model for American options)
 // ---------------------------------------------------------------------------
 
+/// CRR tree where `exercisable[step]` flags the steps before expiry at which
+/// early exercise is allowed (all for American, the schedule for Bermudan,
+/// empty for European).
 #[allow(clippy::too_many_arguments)]
 fn binomial_price(
     s: Decimal,
@@ -388,7 +684,7 @@ fn binomial_price(
     sigma: Decimal,
     steps: u32,
     option_type: OptionType,
-    early_exercise: bool,
+    exercisable: &[bool],
 ) -> Decimal {
     let n = steps;
     let dt = t / Decimal::from(n);
@@ -409,27 +705,20 @@ fn binomial_price(
         let downs = n - ups;
         // Price at node: S * u^ups * d^downs
         let price = s * pow_decimal(u, ups) * pow_decimal(d, downs);
-        let payoff = match option_type {
-            OptionType::Call => (price - k).max(Decimal::ZERO),
-            OptionType::Put => (k - price).max(Decimal::ZERO),
-        };
-        option_values.push(payoff);
+        option_values.push(intrinsic_value(price, k, option_type));
     }
 
     // Backward induction
     for step in (0..n).rev() {
         let step_size = (step + 1) as usize;
+        let early_exercise = exercisable.get(step as usize).copied().unwrap_or(false);
         for i in 0..step_size {
             let hold = disc * (p_up * option_values[i + 1] + p_down * option_values[i]);
             if early_exercise {
                 let ups = i as u32;
                 let downs = step - ups;
                 let price = s * pow_decimal(u, ups) * pow_decimal(d, downs);
-                let exercise = match option_type {
-                    OptionType::Call => (price - k).max(Decimal::ZERO),
-                    OptionType::Put => (k - price).max(Decimal::ZERO),
-                };
-                option_values[i] = hold.max(exercise);
+                option_values[i] = hold.max(intrinsic_value(price, k, option_type));
             } else {
                 option_values[i] = hold;
             }
@@ -439,6 +728,216 @@ fn binomial_price(
     option_values[0]
 }
 
+// ---------------------------------------------------------------------------
+// Trinomial tree (log-price lattice, Hull parameterisation)
+// ---------------------------------------------------------------------------
+
+#[allow(clippy::too_many_arguments)]
+fn trinomial_price(
+    s: Decimal,
+    k: Decimal,
+    t: Decimal,
+    r: Decimal,
+    q: Decimal,
+    sigma: Decimal,
+    steps: u32,
+    option_type: OptionType,
+    exercisable: &[bool],
+) -> Decimal {
+    let n = steps as usize;
+    let dt = t / Decimal::from(steps);
+    // dx = sigma * sqrt(3 dt) keeps the middle probability near 2/3
+    let dx = sigma * sqrt_decimal(dec!(3) * dt);
+    let nu = r - q - sigma * sigma / dec!(2);
+    let var_term = (sigma * sigma * dt + nu * nu * dt * dt) / (dx * dx);
+    let drift_term = nu * dt / dx;
+    let p_up = (var_term + drift_term) / dec!(2);
+    let p_down = (var_term - drift_term) / dec!(2);
+    let p_mid = Decimal::ONE - p_up - p_down;
+    let disc = exp_decimal(-r * dt);
+
+    // Node j (offset by n) carries S * e^(j dx) at every step it exists
+    let u = exp_decimal(dx);
+    let d = Decimal::ONE / u;
+    let prices: Vec<Decimal> = (0..=2 * n)
+        .map(|idx| {
+            if idx >= n {
+                s * pow_decimal(u, (idx - n) as u32)
+            } else {
+                s * pow_decimal(d, (n - idx) as u32)
+            }
+        })
+        .collect();
+
+    let mut values: Vec<Decimal> = prices
+        .iter()
+        .map(|&p| intrinsic_value(p, k, option_type))
+        .collect();
+
+    for step in (0..n).rev() {
+        let early_exercise = exercisable.get(step).copied().unwrap_or(false);
+        // Nodes at `step` span offsets n-step ..= n+step
+        let next: Vec<Decimal> = (n - step..=n + step)
+            .map(|idx| {
+                let hold = disc
+                    * (p_up * values[idx + 1] + p_mid * values[idx] + p_down * values[idx - 1]);
+                if early_exercise {
+                    hold.max(intrinsic_value(prices[idx], k, option_type))
+                } else {
+                    hold
+                }
+            })
+            .collect();
+        values[n - step..=n + step].copy_from_slice(&next);
+    }
+
+    values[n]
+}
+
+// ---------------------------------------------------------------------------
+// Longstaff-Schwartz least-squares Monte Carlo
+// ---------------------------------------------------------------------------
+// Path simulation runs in f64: a Decimal exp per path step is too slow for
+// tens of thousands of paths, and the estimate carries sampling error far
+// larger than f64 rounding.
+
+/// Least-squares fit of y on the basis {1, x, x^2}; None if singular.
+fn regress_quadratic(xs: &[f64], ys: &[f64]) -> Option<[f64; 3]> {
+    let mut a = [[0.0f64; 4]; 3];
+    for (&x, &y) in xs.iter().zip(ys) {
+        let basis = [1.0, x, x * x];
+        for (row, &bi) in a.iter_mut().zip(&basis) {
+            for (cell, &bj) in row.iter_mut().zip(&basis) {
+                *cell += bi * bj;
+            }
+            row[3] += bi * y;
+        }
+    }
+    // Gaussian elimination with partial pivoting on the normal equations
+    for col in 0..3 {
+        let pivot = (col..3).max_by(|&i, &j| a[i][col].abs().total_cmp(&a[j][col].abs()))?;
+        if a[pivot][col].abs() < 1e-12 {
+            return None;
+        }
+        a.swap(col, pivot);
+        for row in col + 1..3 {
+            let pivot_row = a[col];
+            let f = a[row][col] / pivot_row[col];
+            for (cell, &p) in a[row].iter_mut().zip(&pivot_row).skip(col) {
+                *cell -= f * p;
+            }
+        }
+    }
+    let mut beta = [0.0f64; 3];
+    for row in (0..3).rev() {
+        let tail: f64 = (row + 1..3).map(|c| a[row][c] * beta[c]).sum();
+        beta[row] = (a[row][3] - tail) / a[row][row];
+    }
+    Some(beta)
+}
+
+/// Returns (price, standard error) for exercise on `exercise_times`, which
+/// must be ascending and end at expiry.
+#[allow(clippy::too_many_arguments)]
+fn longstaff_schwartz_price(
+    s: Decimal,
+    k: Decimal,
+    r: Decimal,
+    q: Decimal,
+    sigma: Decimal,
+    option_type: OptionType,
+    exercise_times: &[Decimal],
+    paths: u32,
+    seed: u64,
+) -> (Decimal, Decimal) {
+    let s0 = s.to_f64().unwrap_or(0.0);
+    let strike = k.to_f64().unwrap_or(0.0);
+    let r = r.to_f64().unwrap_or(0.0);
+    let q = q.to_f64().unwrap_or(0.0);
+    let sigma = sigma.to_f64().unwrap_or(0.0);
+    let times: Vec<f64> = exercise_times
+        .iter()
+        .map(|t| t.to_f64().unwrap_or(0.0))
+        .collect();
+    let payoff = |spot: f64| match option_type {
+        OptionType::Call => (spot - strike).max(0.0),
+        OptionType::Put => (strike - spot).max(0.0),
+    };
+
+    // Antithetic pairs: path 2i uses +Z, path 2i+1 uses -Z
+    let pairs = (paths as usize).div_ceil(2);
+    let n_paths = pairs * 2;
+    let mut rng = Lcg::new(seed);
+    let mut spots = vec![vec![0.0f64; times.len()]; n_paths];
+    for pair in 0..pairs {
+        let (mut up, mut down) = (s0, s0);
+        let mut prev = 0.0;
+        for (j, &tj) in times.iter().enumerate() {
+            let dt = tj - prev;
+            prev = tj;
+            let drift = (r - q - 0.5 * sigma * sigma) * dt;
+            let shock = sigma * dt.sqrt() * rng.next_normal_f64();
+            up *= (drift + shock).exp();
+            down *= (drift - shock).exp();
+            spots[2 * pair][j] = up;
+            spots[2 * pair + 1][j] = down;
+        }
+    }
+
+    // Realised cashflow and its exercise time per path, starting at expiry
+    let last = times.len() - 1;
+    let mut cash: Vec<f64> = spots.iter().map(|p| payoff(p[last])).collect();
+    let mut cash_time = vec![times[last]; n_paths];
+
+    for j in (0..last).rev() {
+        let tj = times[j];
+        let itm: Vec<usize> = (0..n_paths)
+            .filter(|&p| payoff(spots[p][j]) > 0.0)
+            .collect();
+        if itm.len() < 3 {
+            continue;
+        }
+        let xs: Vec<f64> = itm.iter().map(|&p| spots[p][j] / strike).collect();
+        let ys: Vec<f64> = itm
+            .iter()
+            .map(|&p| cash[p] * (-r * (cash_time[p] - tj)).exp())
+            .collect();
+        let Some(beta) = regress_quadratic(&xs, &ys) else {
+            continue;
+        };
+        for (&p, &x) in itm.iter().zip(&xs) {
+            let continuation = beta[0] + beta[1] * x + beta[2] * x * x;
+            let exercise = payoff(spots[p][j]);
+            if exercise > continuation {
+                cash[p] = exercise;
+                cash_time[p] = tj;
+            }
+        }
+    }
+
+    // Average each antithetic pair so the standard error reflects the pairing
+    let pair_values: Vec<f64> = (0..pairs)
+        .map(|i| {
+            let a = cash[2 * i] * (-r * cash_time[2 * i]).exp();
+            let b = cash[2 * i + 1] * (-r * cash_time[2 * i + 1]).exp();
+            0.5 * (a + b)
+        })
+        .collect();
+    let m = pairs as f64;
+    let mean = pair_values.iter().sum::<f64>() / m;
+    let std_error = if pairs > 1 {
+        let var = pair_values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (m - 1.0);
+        (var / m).sqrt()
+    } else {
+        0.0
+    };
+
+    (
+        Decimal::from_f64(mean).unwrap_or(Decimal::ZERO),
+        Decimal::from_f64(std_error).unwrap_or(Decimal::ZERO),
+    )
+}
+
 /// Integer power of a Decimal via iterative multiplication (avoids powd precision drift).
 fn pow_decimal(base: Decimal, exp: u32) -> Decimal {
     if exp == 0 {
@@ -503,6 +1002,54 @@ fn breakeven(k: Decimal, premium: Decimal, option_type: OptionType) -> Decimal {
     }
 }
 
+// ---------------------------------------------------------------------------
+// Exercise schedules
+// ---------------------------------------------------------------------------
+
+/// Lattice steps before expiry at which early exercise is allowed; Bermudan
+/// dates snap to the nearest step.
+fn lattice_exercise_steps(input: &OptionInput, steps: u32) -> Vec<bool> {
+    let n = steps as usize;
+    match input.exercise_style {
+        ExerciseStyle::European => vec![false; n],
+        ExerciseStyle::American => vec![true; n],
+        ExerciseStyle::Bermudan => {
+            let mut flags = vec![false; n];
+            let per_year = Decimal::from(steps) / input.time_to_expiry;
+            for t in input.exercise_times.as_deref().unwrap_or_default() {
+                let step = (*t * per_year).round().to_usize().unwrap_or(n);
+                if step >= 1 && step < n {
+                    flags[step] = true;
+                }
+            }
+            flags
+        }
+    }
+}
+
+/// Ascending exercise dates for Longstaff-Schwartz, always ending at expiry.
+/// American exercise is approximated on the `steps` grid.
+fn simulation_exercise_times(input: &OptionInput, steps: u32) -> Vec<Decimal> {
+    let t = input.time_to_expiry;
+    let mut times: Vec<Decimal> = match input.exercise_style {
+        ExerciseStyle::Bermudan => input
+            .exercise_times
+            .as_deref()
+            .unwrap_or_default()
+            .iter()
+            .copied()
+            .filter(|e| *e < t)
+            .collect(),
+        _ => (1..steps)
+            .map(|i| t * Decimal::from(i) / Decimal::from(steps))
+            .collect(),
+    };
+    times.sort();
+    times.dedup();
+    times.push(t);
+    times
+}
+
 // ---------------------------------------------------------------------------
 // Public API: price_option
 // ---------------------------------------------------------------------------
@@ -522,16 +1069,27 @@ pub fn price_option(input: &OptionInput) -> CorpFinanceResult<ComputationOutput<
     // Black-Scholes parameters and price (European baseline)
     let params = compute_bs_params(s, k, t, r, q, sigma);
     let bs = bs_price(s, k, r, &params, input.option_type);
-    let greeks = compute_greeks(s, k, r, t, q, &params, input.option_type);
-
     // Determine final price and binomial price
-    let (price, binom) = match input.exercise_style {
-        ExerciseStyle::European => (bs, None),
-        ExerciseStyle::American => {
-            let am = binomial_price(s, k, t, r, q, sigma, steps, input.option_type, true);
-            (am, Some(am))
+    let model = input
+        .early_exercise_model
+        .unwrap_or(EarlyExerciseModel::Binomial);
+    let (price, std_error) = model_price(input);
+    let binom = match (input.exercise_style, model) {
+        (ExerciseStyle::European, _) => None,
+        (_, EarlyExerciseModel::Binomial) => Some(price),
+        _ => None,
+    };
+    let greeks_method = input.greeks_method.unwrap_or(GreeksMethod::Analytic);
+    let greeks = match greeks_method {
+        GreeksMethod::Analytic => compute_greeks(s, k, r, t, q, &params, input.option_type),
+        GreeksMethod::BumpAndReprice => {
+            bump_and_reprice_greeks(input, price, &input.greek_bumps.clone().unwrap_or_default())
         }
     };
+    let early_exercise_premium = match input.exercise_style {
+        ExerciseStyle::European => None,
+        _ => Some(price - bs),
+    };
 
     // Put-call parity cross-check (European only)
     let parity_price = if input.exercise_style == ExerciseStyle::European {
@@ -561,14 +1119,43 @@ pub fn price_option(input: &OptionInput) -> CorpFinanceResult<ComputationOutput<
         put_call_parity_price: parity_price,
         moneyness,
         breakeven: be,
+        early_exercise_premium,
+        monte_carlo_std_error: std_error,
     };
 
-    let methodology = match input.exercise_style {
-        ExerciseStyle::European => "Black-Scholes (closed-form)",
-        ExerciseStyle::American => "CRR Binomial Tree with early exercise",
+    let methodology = match (input.exercise_style, model) {
+        (ExerciseStyle::European, _) => "Black-Scholes (closed-form)",
+        (ExerciseStyle::American, EarlyExerciseModel::Binomial) => {
+            "CRR Binomial Tree with early exercise"
+        }
+        (ExerciseStyle::Bermudan, EarlyExerciseModel::Binomial) => {
+            "CRR Binomial Tree with Bermudan exercise schedule"
+        }
+        (_, EarlyExerciseModel::Trinomial) => "Trinomial Tree with early exercise",
+        (_, EarlyExerciseModel::LongstaffSchwartz) => {
+            "Longstaff-Schwartz least-squares Monte Carlo"
+        }
     };
 
-    let warnings = Vec::new();
+    let mut warnings = Vec::new();
+    if input.exercise_style != ExerciseStyle::European && greeks_method == GreeksMethod::Analytic {
+        warnings.push(
+            "Greeks are Black-Scholes European sensitivities and ignore early exercise".into(),
+        );
+    }
+    if input.exercise_style == ExerciseStyle::Bermudan
+        && model != EarlyExerciseModel::LongstaffSchwartz
+    {
+        let mut snapped = lattice_exercise_steps(input, steps);
+        snapped.retain(|e| *e);
+        // Distinct dates before expiry
+        let distinct = simulation_exercise_times(input, steps).len() - 1;
+        if snapped.len() < distinct {
+            warnings.push(format!(
+                "{steps} lattice steps merge some Bermudan exercise dates; increase binomial_steps"
+            ));
+        }
+    }
     let assumptions = serde_json::json!({
         "model": methodology,
         "risk_free_rate": r.to_string(),
@@ -576,6 +1163,15 @@ pub fn price_option(input: &OptionInput) -> CorpFinanceResult<ComputationOutput<
         "dividend_yield": q.to_string(),
         "exercise_style": format!("{:?}", input.exercise_style),
         "binomial_steps": steps,
+        "early_exercise_model": format!("{model:?}"),
+        "exercise_times": input.exercise_times,
+        "simulation_paths": input.simulation_paths.unwrap_or(10_000),
+        "seed": input.seed.unwrap_or(42),
+        "greeks_method": format!("{greeks_method:?}"),
+        "greek_bumps": match greeks_method {
+            GreeksMethod::BumpAndReprice => Some(input.greek_bumps.clone().unwrap_or_default()),
+            GreeksMethod::Analytic => None,
+        },
     });
 
     let elapsed = start.elapsed().as_micros() as u64;
@@ -704,6 +1300,12 @@ mod tests {
             option_type: OptionType::Call,
             exercise_style: ExerciseStyle::European,
             binomial_steps: None,
+            early_exercise_model: None,
+            exercise_times: None,
+            simulation_paths: None,
+            seed: None,
+            greeks_method: None,
+            greek_bumps: None,
         }
     }
 
@@ -965,7 +1567,7 @@ mod tests {
             dec!(0.20),
             500,
             OptionType::Call,
-            false, // European
+            &[], // European: no early exercise steps
         );
 
         assert!(
@@ -1056,6 +1658,245 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_trinomial_american_put_matches_binomial() {
+        // Reference American put S=K=100, T=1, r=5%, vol=20% ~ 6.09
+        let binomial = OptionInput {
+            exercise_style: ExerciseStyle::American,
+            binomial_steps: Some(200),
+            ..default_european_put()
+        };
+        let trinomial = OptionInput {
+            early_exercise_model: Some(EarlyExerciseModel::Trinomial),
+            ..binomial.clone()
+        };
+        let bin = price_option(&binomial).unwrap().result;
+        let tri = price_option(&trinomial).unwrap().result;
+        assert!(
+            approx_eq(tri.price, dec!(6.09), dec!(0.05)),
+            "Trinomial American put {} not near 6.09",
+            tri.price
+        );
+        assert!(approx_eq(tri.price, bin.price, dec!(0.03)));
+        assert!(tri.binomial_price.is_none());
+        assert!(tri.early_exercise_premium.unwrap() > dec!(0.4));
+    }
+
+    #[test]
+    fn test_trinomial_european_converges_to_bs() {
+        let bs = price_option(&default_european_call()).unwrap().result.price;
+        let tri = trinomial_price(
+            dec!(100),
+            dec!(100),
+            dec!(1),
+            dec!(0.05),
+            dec!(0),
+            dec!(0.20),
+            200,
+            OptionType::Call,
+            &[],
+        );
+        assert!(
+            approx_eq(tri, bs, dec!(0.05)),
+            "Trinomial European {tri} should converge to BS {bs}"
+        );
+    }
+
+    #[test]
+    fn test_bermudan_put_between_european_and_american() {
+        let european = price_option(&default_european_put()).unwrap().result.price;
+        let american = price_option(&OptionInput {
+            exercise_style: ExerciseStyle::American,
+            binomial_steps: Some(200),
+            ..default_european_put()
+        })
+        .unwrap()
+        .result
+        .price;
+        let bermudan = price_option(&OptionInput {
+            exercise_style: ExerciseStyle::Bermudan,
+            binomial_steps: Some(200),
+            exercise_times: Some(vec![dec!(0.25), dec!(0.5), dec!(0.75)]),
+            ..default_european_put()
+        })
+        .unwrap()
+        .result;
+        assert!(bermudan.price > european + dec!(0.1));
+        assert!(bermudan.price < american);
+        assert_eq!(
+            bermudan.binomial_price,
+            Some(bermudan.price),
+            "Binomial is the default early exercise model"
+        );
+    }
+
+    #[test]
+    fn test_bermudan_exercisable_only_at_expiry_is_european() {
+        let input = OptionInput {
+            exercise_style: ExerciseStyle::Bermudan,
+            binomial_steps: Some(400),
+            exercise_times: Some(vec![dec!(1)]),
+            ..default_european_put()
+        };
+        let res = price_option(&input).unwrap().result;
+        let bs = price_option(&default_european_put()).unwrap().result.price;
+        assert!(
+            approx_eq(res.price, bs, dec!(0.03)),
+            "Expiry-only Bermudan {} should equal European {bs}",
+            res.price
+        );
+    }
+
+    #[test]
+    fn test_longstaff_schwartz_american_put() {
+        let input = OptionInput {
+            exercise_style: ExerciseStyle::American,
+            binomial_steps: Some(50),
+            early_exercise_model: Some(EarlyExerciseModel::LongstaffSchwartz),
+            simulation_paths: Some(20_000),
+            ..default_european_put()
+        };
+        let res = price_option(&input).unwrap();
+        let out = &res.result;
+        let se = out.monte_carlo_std_error.unwrap();
+        assert!(se > Decimal::ZERO && se < dec!(0.1));
+        // LSM is biased slightly low against the ~6.09 lattice value
+        assert!(
+            approx_eq(out.price, dec!(6.05), dec!(0.2)),
+            "LSM American put {} not near 6.05",
+            out.price
+        );
+        assert!(res.methodology.contains("Longstaff-Schwartz"));
+    }
+
+    #[test]
+    fn test_longstaff_schwartz_bermudan_matches_lattice() {
+        let lattice = OptionInput {
+            exercise_style: ExerciseStyle::Bermudan,
+            binomial_steps: Some(240),
+            exercise_times: Some(vec![dec!(0.25), dec!(0.5), dec!(0.75)]),
+            ..default_european_put()
+        };
+        let lsm = OptionInput {
+            early_exercise_model: Some(EarlyExerciseModel::LongstaffSchwartz),
+            simulation_paths: Some(20_000),
+            seed: Some(7),
+            ..lattice.clone()
+        };
+        let tree = price_option(&lattice).unwrap().result.price;
+        let mc = price_option(&lsm).unwrap().result;
+        assert!(
+            approx_eq(mc.price, tree, dec!(0.15)),
+            "LSM Bermudan {} should be near lattice {tree}",
+            mc.price
+        );
+        // Same seed reproduces the same estimate
+        let again = price_option(&lsm).unwrap().result;
+        assert_eq!(mc.price, again.price);
+    }
+
+    #[test]
+    fn test_bermudan_requires_exercise_times() {
+        let missing = OptionInput {
+            exercise_style: ExerciseStyle::Bermudan,
+            ..default_european_put()
+        };
+        match price_option(&missing).unwrap_err() {
+            CorpFinanceError::InvalidInput { field, .. } => assert_eq!(field, "exercise_times"),
+            other => panic!("Expected InvalidInput, got {other:?}"),
+        }
+        let beyond_expiry = OptionInput {
+            exercise_style: ExerciseStyle::Bermudan,
+            exercise_times: Some(vec![dec!(0.5), dec!(1.5)]),
+            ..default_european_put()
+        };
+        assert!(price_option(&beyond_expiry).is_err());
+    }
+
+    #[test]
+    fn test_analytic_vanna_volga() {
+        // ATM call: d1 = 0.35, d2 = 0.15, so vanna < 0 and volga > 0
+        let greeks = price_option(&default_european_call())
+            .unwrap()
+            .result
+            .greeks;
+        let expected_vanna = -norm_pdf(dec!(0.35)) * dec!(0.15) / dec!(0.20) / dec!(100);
+        assert!(
+            approx_eq(greeks.vanna, expected_vanna, dec!(0.00001)),
+            "vanna {} vs {expected_vanna}",
+            greeks.vanna
+        );
+        let expected_volga = greeks.vega * dec!(0.35) * dec!(0.15) / dec!(0.20) / dec!(100);
+        assert!(approx_eq(greeks.volga, expected_volga, dec!(0.00001)));
+        assert!(greeks.volga > Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_bump_and_reprice_matches_analytic_for_european() {
+        let analytic = price_option(&default_european_call())
+            .unwrap()
+            .result
+            .greeks;
+        let bumped = price_option(&OptionInput {
+            greeks_method: Some(GreeksMethod::BumpAndReprice),
+            ..default_european_call()
+        })
+        .unwrap();
+        let g = &bumped.result.greeks;
+        assert!(approx_eq(g.delta, analytic.delta, dec!(0.001)));
+        assert!(approx_eq(g.gamma, analytic.gamma, dec!(0.0005)));
+        assert!(approx_eq(g.vega, analytic.vega, dec!(0.001)));
+        assert!(approx_eq(g.rho, analytic.rho, dec!(0.001)));
+        assert!(approx_eq(g.theta, analytic.theta, dec!(0.0005)));
+        assert!(approx_eq(g.vanna, analytic.vanna, dec!(0.0005)));
+        assert!(approx_eq(g.volga, analytic.volga, dec!(0.0005)));
+        assert_eq!(bumped.assumptions["greeks_method"], "BumpAndReprice");
+    }
+
+    #[test]
+    fn test_bump_and_reprice_american_put() {
+        let european = price_option(&default_european_put()).unwrap().result.greeks;
+        let american = price_option(&OptionInput {
+            exercise_style: ExerciseStyle::American,
+            binomial_steps: Some(200),
+            greeks_method: Some(GreeksMethod::BumpAndReprice),
+            greek_bumps: Some(GreekBumps {
+                spot_bump_pct: dec!(0.02),
+                ..GreekBumps::default()
+            }),
+            ..default_european_put()
+        })
+        .unwrap();
+        let g = &american.result.greeks;
+        // Early exercise makes the put more sensitive to spot
+        assert!(g.delta < european.delta && g.delta > -Decimal::ONE);
+        assert!(g.gamma > Decimal::ZERO);
+        assert!(g.vega > Decimal::ZERO);
+        assert!(g.rho < Decimal::ZERO);
+        assert!(!american
+            .warnings
+            .iter()
+            .any(|w| w.contains("ignore early exercise")));
+    }
+
+    #[test]
+    fn test_invalid_greek_bumps() {
+        let input = OptionInput {
+            greeks_method: Some(GreeksMethod::BumpAndReprice),
+            greek_bumps: Some(GreekBumps {
+                vol_bump: dec!(0.25),
+                ..GreekBumps::default()
+            }),
+            ..default_european_call()
+        };
+        match price_option(&input).unwrap_err() {
+            CorpFinanceError::InvalidInput { field, .. } => {
+                assert_eq!(field, "greek_bumps.vol_bump")
+            }
+            other => panic!("Expected InvalidInput, got {other:?}"),
+        }
+    }
+
     #[test]
     fn test_metadata_populated() {
         let input = default_european_call();
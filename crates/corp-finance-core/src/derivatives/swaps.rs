@@ -1,22 +1,28 @@
+use chrono::NaiveDate;
 use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
 use serde::{Deserialize, Serialize};
 use std::time::Instant;
 
+use crate::curves::{Compounding, Curve, CurveConventions, CurveInterpolation, Extrapolation};
 use crate::error::CorpFinanceError;
 use crate::types::*;
 use crate::CorpFinanceResult;
 
+use crate::fixed_income::calendars::DateAdjustment;
+use crate::fixed_income::daycount::{add_months, year_fraction, DayCountConvention};
+
 // ---------------------------------------------------------------------------
 // Types
 // ---------------------------------------------------------------------------
 
-/// A point on the spot/discount curve: maturity in year fractions, rate as decimal.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct DiscountPoint {
-    pub maturity: Decimal,
-    pub rate: Rate,
-}
+/// Conventions for swap curves that do not state their own: annually
+/// compounded spot rates, linear between nodes, flat beyond them.
+const SWAP_CURVE: CurveConventions = CurveConventions {
+    compounding: Compounding::Annual,
+    interpolation: CurveInterpolation::Linear,
+    extrapolation: Extrapolation::Flat,
+};
 
 /// A point on the forward rate curve.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -32,10 +38,29 @@ pub struct IrsInput {
     pub fixed_rate: Rate,
     pub payment_frequency: u8,
     pub remaining_years: Decimal,
-    pub discount_curve: Vec<DiscountPoint>,
+    /// Discount curve; the OIS curve (SOFR, ESTR) for collateralised swaps
+    pub discount_curve: Curve,
+    /// Projection curve for floating-rate forwards. When omitted, forwards
+    /// are implied from the discount curve (single-curve valuation).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub projection_curve: Option<Curve>,
     pub forward_rates: Option<Vec<ForwardRatePoint>>,
     pub is_pay_fixed: bool,
     pub last_floating_reset: Option<Rate>,
+    /// Effective date of the remaining schedule. When set, payment dates are
+    /// generated from it and leg accruals follow the day count conventions.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub effective_date: Option<NaiveDate>,
+    /// Fixed leg day count (default 30/360; requires `effective_date`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fixed_day_count: Option<DayCountConvention>,
+    /// Floating leg day count (default ACT/360; requires `effective_date`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub floating_day_count: Option<DayCountConvention>,
+    /// Business-day adjustment of period end dates (requires
+    /// `effective_date`). Accruals run between adjusted dates.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub payment_adjustment: Option<DateAdjustment>,
 }
 
 /// Single payment in the swap schedule.
@@ -43,6 +68,12 @@ pub struct IrsInput {
 pub struct SwapPayment {
     pub period: u32,
     pub payment_date_years: Decimal,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_date: Option<NaiveDate>,
+    /// Fixed leg accrual fraction for the period
+    pub fixed_accrual: Decimal,
+    /// Floating leg accrual fraction for the period
+    pub floating_accrual: Decimal,
     pub fixed_payment: Money,
     pub floating_payment: Money,
     pub net_payment: Money,
@@ -59,6 +90,10 @@ pub struct IrsOutput {
     pub dv01: Money,
     pub payment_schedule: Vec<SwapPayment>,
     pub annuity_factor: Decimal,
+    /// Net value discounting on the projection curve, as a single-curve
+    /// model would (only with a separate projection curve)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub single_curve_net_value: Option<Money>,
 }
 
 /// Input for cross-currency swap valuation.
@@ -70,8 +105,8 @@ pub struct CurrencySwapInput {
     pub foreign_fixed_rate: Rate,
     pub payment_frequency: u8,
     pub remaining_years: Decimal,
-    pub domestic_discount_curve: Vec<DiscountPoint>,
-    pub foreign_discount_curve: Vec<DiscountPoint>,
+    pub domestic_discount_curve: Curve,
+    pub foreign_discount_curve: Curve,
     pub spot_fx_rate: Decimal,
     pub is_pay_domestic: bool,
 }
@@ -246,37 +281,15 @@ fn discount_factor(spot_rate: Decimal, t: Decimal) -> Decimal {
     Decimal::ONE / denom
 }
 
-/// Interpolate a rate for time `t` from a sorted curve. Uses linear interpolation.
-fn interpolate_rate(curve: &[DiscountPoint], t: Decimal) -> Decimal {
-    if curve.is_empty() {
-        return Decimal::ZERO;
-    }
-    if curve.len() == 1 || t <= curve[0].maturity {
-        return curve[0].rate;
-    }
-    if t >= curve[curve.len() - 1].maturity {
-        return curve[curve.len() - 1].rate;
-    }
-    // Find bracketing points
-    for i in 0..curve.len() - 1 {
-        if t >= curve[i].maturity && t <= curve[i + 1].maturity {
-            let t1 = curve[i].maturity;
-            let t2 = curve[i + 1].maturity;
-            let r1 = curve[i].rate;
-            let r2 = curve[i + 1].rate;
-            let span = t2 - t1;
-            if span.is_zero() {
-                return r1;
-            }
-            return r1 + (r2 - r1) * (t - t1) / span;
-        }
-    }
-    curve[curve.len() - 1].rate
+/// Annually compounded spot rate for time `t`, read with the curve's own
+/// interpolation (linear unless it states otherwise).
+fn interpolate_rate(curve: &Curve, t: Decimal) -> Decimal {
+    curve.zero_rate_as(t, Compounding::Annual)
 }
 
 /// Derive a forward rate f(t1, t2) from the spot curve.
 /// f(t1,t2) = ((1+s2)^t2 / (1+s1)^t1)^(1/(t2-t1)) - 1
-fn implied_forward_rate(curve: &[DiscountPoint], t1: Decimal, t2: Decimal) -> Decimal {
+fn implied_forward_rate(curve: &Curve, t1: Decimal, t2: Decimal) -> Decimal {
     let s1 = interpolate_rate(curve, t1);
     let s2 = interpolate_rate(curve, t2);
     let num = compound_factor(s2, t2);
@@ -324,6 +337,15 @@ fn validate_irs_input(input: &IrsInput) -> CorpFinanceResult<()> {
             "Discount curve must have at least one point".into(),
         ));
     }
+    input.discount_curve.validate("discount_curve")?;
+    if let Some(curve) = &input.projection_curve {
+        if curve.is_empty() {
+            return Err(CorpFinanceError::InsufficientData(
+                "Projection curve must have at least one point".into(),
+            ));
+        }
+        curve.validate("projection_curve")?;
+    }
     Ok(())
 }
 
@@ -362,6 +384,12 @@ fn validate_currency_swap_input(input: &CurrencySwapInput) -> CorpFinanceResult<
             "Foreign discount curve must have at least one point".into(),
         ));
     }
+    input
+        .domestic_discount_curve
+        .validate("domestic_discount_curve")?;
+    input
+        .foreign_discount_curve
+        .validate("foreign_discount_curve")?;
     if input.spot_fx_rate <= Decimal::ZERO {
         return Err(CorpFinanceError::InvalidInput {
             field: "spot_fx_rate".into(),
@@ -386,7 +414,10 @@ struct IrsLegsResult {
 }
 
 /// Compute swap legs given an explicit discount curve (may be bumped for DV01).
-fn compute_irs_legs(input: &IrsInput, curve: &[DiscountPoint]) -> IrsLegsResult {
+/// Forwards come from the input's projection curve when set, otherwise from
+/// the discount curve.
+fn compute_irs_legs(input: &IrsInput, curve: &Curve) -> IrsLegsResult {
+    let projection = input.projection_curve.as_ref().unwrap_or(curve);
     let freq = Decimal::from(input.payment_frequency);
     let period_length = Decimal::ONE / freq;
     let num_periods_dec = input.remaining_years * freq;
@@ -402,16 +433,51 @@ fn compute_irs_legs(input: &IrsInput, curve: &[DiscountPoint]) -> IrsLegsResult
     let mut annuity_sum = Decimal::ZERO;
     let mut schedule = Vec::with_capacity(num_periods as usize);
 
+    let months_per_period = 12 / input.payment_frequency as i32;
+    let fixed_dc = input
+        .fixed_day_count
+        .unwrap_or(DayCountConvention::Thirty360);
+    let floating_dc = input
+        .floating_day_count
+        .unwrap_or(DayCountConvention::Actual360);
+
     for i in 1..=num_periods {
-        let t = Decimal::from(i) * period_length;
-        let t_prev = Decimal::from(i - 1) * period_length;
+        // Payment time, and accrual fractions per leg. Without an effective
+        // date every period accrues exactly 1/freq.
+        let (t, t_prev, payment_date, fixed_accrual, floating_accrual) = match input.effective_date
+        {
+            Some(eff) => {
+                let roll = |date: NaiveDate| match &input.payment_adjustment {
+                    Some(adj) => adj.adjust(date),
+                    None => date,
+                };
+                let d_prev = roll(add_months(eff, months_per_period * (i as i32 - 1)));
+                let d = roll(add_months(eff, months_per_period * i as i32));
+                let t = year_fraction(DayCountConvention::Actual365, eff, d, 1);
+                let t_prev = year_fraction(DayCountConvention::Actual365, eff, d_prev, 1);
+                (
+                    t,
+                    t_prev,
+                    Some(d),
+                    year_fraction(fixed_dc, d_prev, d, input.payment_frequency),
+                    year_fraction(floating_dc, d_prev, d, input.payment_frequency),
+                )
+            }
+            None => (
+                Decimal::from(i) * period_length,
+                Decimal::from(i - 1) * period_length,
+                None,
+                period_length,
+                period_length,
+            ),
+        };
 
         // Discount factor at payment date
         let spot = interpolate_rate(curve, t);
         let df = discount_factor(spot, t);
 
         // Fixed payment for this period
-        let fixed_pmt = input.notional * input.fixed_rate / freq;
+        let fixed_pmt = input.notional * input.fixed_rate * fixed_accrual;
 
         // Floating rate for this period
         let fwd = if i == 1 {
@@ -420,15 +486,15 @@ fn compute_irs_legs(input: &IrsInput, curve: &[DiscountPoint]) -> IrsLegsResult
             } else if let Some(ref fwds) = input.forward_rates {
                 interpolate_rate_from_forwards(fwds, t)
             } else {
-                implied_forward_rate(curve, t_prev, t)
+                implied_forward_rate(projection, t_prev, t)
             }
         } else if let Some(ref fwds) = input.forward_rates {
             interpolate_rate_from_forwards(fwds, t)
         } else {
-            implied_forward_rate(curve, t_prev, t)
+            implied_forward_rate(projection, t_prev, t)
         };
 
-        let float_pmt = input.notional * fwd / freq;
+        let float_pmt = input.notional * fwd * floating_accrual;
 
         // Net payment from perspective of pay-fixed party
         let net = if input.is_pay_fixed {
@@ -439,11 +505,14 @@ fn compute_irs_legs(input: &IrsInput, curve: &[DiscountPoint]) -> IrsLegsResult
 
         fixed_leg_pv += fixed_pmt * df;
         floating_leg_pv += float_pmt * df;
-        annuity_sum += df;
+        annuity_sum += fixed_accrual * df;
 
         schedule.push(SwapPayment {
             period: i,
             payment_date_years: t,
+            payment_date,
+            fixed_accrual,
+            floating_accrual,
             fixed_payment: fixed_pmt,
             floating_payment: float_pmt,
             net_payment: net,
@@ -451,22 +520,14 @@ fn compute_irs_legs(input: &IrsInput, curve: &[DiscountPoint]) -> IrsLegsResult
         });
     }
 
-    let annuity_factor = annuity_sum / freq;
+    // Annuity: sum of fixed-leg accrual-weighted discount factors
+    let annuity_factor = annuity_sum;
 
-    // Par swap rate: R_par = floating_leg_PV / (annuity_sum * notional / freq)
-    // R_par = sum(f_i * DF_i) / sum(DF_i)  (already scaled by freq on both sides)
-    let par_swap_rate = if annuity_sum.is_zero() {
+    // Par swap rate: R_par = floating_leg_PV / (notional * annuity)
+    let par_swap_rate = if annuity_sum.is_zero() || input.notional.is_zero() {
         Decimal::ZERO
     } else {
-        // Sum of forward_i * DF_i over sum of DF_i
-        // floating_leg_pv = sum( notional * f_i / freq * DF_i )
-        // So sum(f_i * DF_i) = floating_leg_pv * freq / notional
-        // par_rate = (floating_leg_pv * freq / notional) / annuity_sum
-        if input.notional.is_zero() {
-            Decimal::ZERO
-        } else {
-            floating_leg_pv * freq / (input.notional * annuity_sum)
-        }
+        floating_leg_pv / (input.notional * annuity_sum)
     };
 
     let net_value = if input.is_pay_fixed {
@@ -512,17 +573,6 @@ fn interpolate_rate_from_forwards(fwds: &[ForwardRatePoint], t: Decimal) -> Deci
     fwds[fwds.len() - 1].rate
 }
 
-/// Bump all rates in a discount curve by a given amount (in decimal, e.g. 0.0001 for 1bp).
-fn bump_curve(curve: &[DiscountPoint], bump: Decimal) -> Vec<DiscountPoint> {
-    curve
-        .iter()
-        .map(|p| DiscountPoint {
-            maturity: p.maturity,
-            rate: p.rate + bump,
-        })
-        .collect()
-}
-
 // ---------------------------------------------------------------------------
 // Public API: Interest Rate Swap
 // ---------------------------------------------------------------------------
@@ -536,16 +586,44 @@ pub fn value_interest_rate_swap(
 ) -> CorpFinanceResult<ComputationOutput<IrsOutput>> {
     let start = Instant::now();
     validate_irs_input(input)?;
+    let mut warnings: Vec<String> = Vec::new();
+    let input = &IrsInput {
+        discount_curve: input.discount_curve.with_conventions(SWAP_CURVE),
+        projection_curve: input
+            .projection_curve
+            .as_ref()
+            .map(|curve| curve.with_conventions(SWAP_CURVE)),
+        ..input.clone()
+    };
+
+    if input.effective_date.is_none()
+        && (input.fixed_day_count.is_some() || input.floating_day_count.is_some())
+    {
+        warnings.push(
+            "Day count conventions ignored without effective_date; periods accrue 1/frequency"
+                .into(),
+        );
+    }
+    if input.effective_date.is_none() && input.payment_adjustment.is_some() {
+        warnings.push("Payment adjustment ignored without effective_date".into());
+    }
+    if input.projection_curve.is_some() && input.forward_rates.is_some() {
+        warnings.push("Explicit forward rates take precedence over the projection curve".into());
+    }
 
     // Base case
     let base = compute_irs_legs(input, &input.discount_curve);
 
     // DV01: bump all discount rates by +1bp, recompute, take difference
     let one_bp = dec!(0.0001);
-    let bumped_curve = bump_curve(&input.discount_curve, one_bp);
-    // Also bump forward rates if provided
+    let bumped_curve = input.discount_curve.shifted(one_bp);
+    // Also bump the projection curve and forward rates if provided
     let bumped_input = IrsInput {
         discount_curve: bumped_curve.clone(),
+        projection_curve: input
+            .projection_curve
+            .as_ref()
+            .map(|curve| curve.shifted(one_bp)),
         forward_rates: input.forward_rates.as_ref().map(|fwds| {
             fwds.iter()
                 .map(|f| ForwardRatePoint {
@@ -555,11 +633,20 @@ pub fn value_interest_rate_swap(
                 .collect()
         }),
         last_floating_reset: input.last_floating_reset,
-        ..*input
+        ..input.clone()
     };
     let bumped = compute_irs_legs(&bumped_input, &bumped_curve);
     let dv01 = (bumped.net_value - base.net_value).abs();
 
+    // Same swap with the projection curve also used for discounting
+    let single_curve_net_value = input.projection_curve.as_ref().map(|curve| {
+        let single = IrsInput {
+            projection_curve: None,
+            ..input.clone()
+        };
+        compute_irs_legs(&single, curve).net_value
+    });
+
     let output = IrsOutput {
         fixed_leg_pv: base.fixed_leg_pv,
         floating_leg_pv: base.floating_leg_pv,
@@ -568,6 +655,7 @@ pub fn value_interest_rate_swap(
         dv01,
         payment_schedule: base.schedule,
         annuity_factor: base.annuity_factor,
+        single_curve_net_value,
     };
 
     let elapsed = start.elapsed().as_micros() as u64;
@@ -576,14 +664,32 @@ pub fn value_interest_rate_swap(
         "payment_frequency": input.payment_frequency,
         "is_pay_fixed": input.is_pay_fixed,
         "curve_points": input.discount_curve.len(),
+        "curve_setup": if input.projection_curve.is_some() {
+            "dual_curve"
+        } else {
+            "single_curve"
+        },
         "forward_rates_provided": input.forward_rates.is_some(),
         "last_floating_reset_provided": input.last_floating_reset.is_some(),
+        "accrual_basis": match input.effective_date {
+            Some(_) => format!(
+                "fixed {} / floating {}",
+                input.fixed_day_count.unwrap_or(DayCountConvention::Thirty360).label(),
+                input.floating_day_count.unwrap_or(DayCountConvention::Actual360).label()
+            ),
+            None => "1/frequency".to_string(),
+        },
+        "business_day_convention": input
+            .payment_adjustment
+            .as_ref()
+            .filter(|_| input.effective_date.is_some())
+            .map(|adj| adj.convention),
     });
 
     Ok(with_metadata(
         "Interest Rate Swap Valuation (DCF, spot-curve discounting)",
         &assumptions,
-        Vec::new(),
+        warnings,
         elapsed,
         output,
     ))
@@ -602,6 +708,11 @@ pub fn value_currency_swap(
 ) -> CorpFinanceResult<ComputationOutput<CurrencySwapOutput>> {
     let start = Instant::now();
     validate_currency_swap_input(input)?;
+    let input = &CurrencySwapInput {
+        domestic_discount_curve: input.domestic_discount_curve.with_conventions(SWAP_CURVE),
+        foreign_discount_curve: input.foreign_discount_curve.with_conventions(SWAP_CURVE),
+        ..input.clone()
+    };
 
     let freq = Decimal::from(input.payment_frequency);
     let period_length = Decimal::ONE / freq;
@@ -695,25 +806,15 @@ mod tests {
     use rust_decimal_macros::dec;
 
     /// Build a flat discount curve at the given rate, with points at each year.
-    fn flat_curve(rate: Decimal, max_years: u32) -> Vec<DiscountPoint> {
-        (1..=max_years)
-            .map(|y| DiscountPoint {
-                maturity: Decimal::from(y),
-                rate,
-            })
-            .collect()
+    fn flat_curve(rate: Decimal, max_years: u32) -> Curve {
+        Curve::from_zero_rates((1..=max_years).map(|y| (Decimal::from(y), rate)))
     }
 
     /// Build a flat curve including half-year points for semi-annual / quarterly.
-    fn flat_curve_fine(rate: Decimal, max_years: u32, freq: u8) -> Vec<DiscountPoint> {
+    fn flat_curve_fine(rate: Decimal, max_years: u32, freq: u8) -> Curve {
         let freq_d = Decimal::from(freq);
         let n = max_years * freq as u32;
-        (1..=n)
-            .map(|i| DiscountPoint {
-                maturity: Decimal::from(i) / freq_d,
-                rate,
-            })
-            .collect()
+        Curve::from_zero_rates((1..=n).map(|i| (Decimal::from(i) / freq_d, rate)))
     }
 
     /// Helper: absolute difference within tolerance.
@@ -734,9 +835,14 @@ mod tests {
             payment_frequency: 1,
             remaining_years: dec!(5),
             discount_curve: curve.clone(),
+            projection_curve: None,
             forward_rates: None,
             is_pay_fixed: true,
             last_floating_reset: None,
+            effective_date: None,
+            fixed_day_count: None,
+            floating_day_count: None,
+            payment_adjustment: None,
         };
         let result = value_interest_rate_swap(&input_for_par).unwrap();
         let par_rate = result.result.par_swap_rate;
@@ -767,9 +873,14 @@ mod tests {
             payment_frequency: 1,
             remaining_years: dec!(5),
             discount_curve: curve,
+            projection_curve: None,
             forward_rates: None,
             is_pay_fixed: true,
             last_floating_reset: None,
+            effective_date: None,
+            fixed_day_count: None,
+            floating_day_count: None,
+            payment_adjustment: None,
         };
         let result = value_interest_rate_swap(&input).unwrap();
         assert!(
@@ -792,9 +903,14 @@ mod tests {
             payment_frequency: 1,
             remaining_years: dec!(5),
             discount_curve: curve,
+            projection_curve: None,
             forward_rates: None,
             is_pay_fixed: true,
             last_floating_reset: None,
+            effective_date: None,
+            fixed_day_count: None,
+            floating_day_count: None,
+            payment_adjustment: None,
         };
         let result = value_interest_rate_swap(&input).unwrap();
         assert!(
@@ -817,9 +933,14 @@ mod tests {
             payment_frequency: 1,
             remaining_years: dec!(10),
             discount_curve: curve,
+            projection_curve: None,
             forward_rates: None,
             is_pay_fixed: true,
             last_floating_reset: None,
+            effective_date: None,
+            fixed_day_count: None,
+            floating_day_count: None,
+            payment_adjustment: None,
         };
         let result = value_interest_rate_swap(&input).unwrap();
         assert!(
@@ -845,9 +966,14 @@ mod tests {
             payment_frequency: 1,
             remaining_years: dec!(3),
             discount_curve: curve,
+            projection_curve: None,
             forward_rates: None,
             is_pay_fixed: true,
             last_floating_reset: None,
+            effective_date: None,
+            fixed_day_count: None,
+            floating_day_count: None,
+            payment_adjustment: None,
         };
         let result = value_interest_rate_swap(&input).unwrap();
 
@@ -882,9 +1008,14 @@ mod tests {
             payment_frequency: 1,
             remaining_years: dec!(5),
             discount_curve: curve,
+            projection_curve: None,
             forward_rates: None,
             is_pay_fixed: true,
             last_floating_reset: None,
+            effective_date: None,
+            fixed_day_count: None,
+            floating_day_count: None,
+            payment_adjustment: None,
         };
         let result = value_interest_rate_swap(&input).unwrap();
         assert!(
@@ -911,9 +1042,14 @@ mod tests {
             payment_frequency: 1,
             remaining_years: dec!(5),
             discount_curve: curve,
+            projection_curve: None,
             forward_rates: None,
             is_pay_fixed: true,
             last_floating_reset: None,
+            effective_date: None,
+            fixed_day_count: None,
+            floating_day_count: None,
+            payment_adjustment: None,
         };
         let result = value_interest_rate_swap(&input).unwrap();
         assert!(
@@ -936,9 +1072,14 @@ mod tests {
             payment_frequency: 1,
             remaining_years: dec!(3),
             discount_curve: curve,
+            projection_curve: None,
             forward_rates: None,
             is_pay_fixed: true,
             last_floating_reset: None,
+            effective_date: None,
+            fixed_day_count: None,
+            floating_day_count: None,
+            payment_adjustment: None,
         };
         let result = value_interest_rate_swap(&input).unwrap();
 
@@ -968,9 +1109,14 @@ mod tests {
             payment_frequency: 1,
             remaining_years: dec!(5),
             discount_curve: curve,
+            projection_curve: None,
             forward_rates: None,
             is_pay_fixed: true,
             last_floating_reset: None,
+            effective_date: None,
+            fixed_day_count: None,
+            floating_day_count: None,
+            payment_adjustment: None,
         };
         let result = value_interest_rate_swap(&input).unwrap();
         assert_eq!(
@@ -992,9 +1138,14 @@ mod tests {
             payment_frequency: 1,
             remaining_years: dec!(5),
             discount_curve: curve,
+            projection_curve: None,
             forward_rates: None,
             is_pay_fixed: true,
             last_floating_reset: None,
+            effective_date: None,
+            fixed_day_count: None,
+            floating_day_count: None,
+            payment_adjustment: None,
         };
         let result = value_interest_rate_swap(&input).unwrap();
 
@@ -1026,9 +1177,14 @@ mod tests {
             payment_frequency: 2,
             remaining_years: dec!(3),
             discount_curve: curve,
+            projection_curve: None,
             forward_rates: None,
             is_pay_fixed: true,
             last_floating_reset: None,
+            effective_date: None,
+            fixed_day_count: None,
+            floating_day_count: None,
+            payment_adjustment: None,
         };
         let result = value_interest_rate_swap(&input).unwrap();
         assert_eq!(
@@ -1062,9 +1218,14 @@ mod tests {
             payment_frequency: 4,
             remaining_years: dec!(2),
             discount_curve: curve,
+            projection_curve: None,
             forward_rates: None,
             is_pay_fixed: true,
             last_floating_reset: None,
+            effective_date: None,
+            fixed_day_count: None,
+            floating_day_count: None,
+            payment_adjustment: None,
         };
         let result = value_interest_rate_swap(&input).unwrap();
         assert_eq!(
@@ -1202,9 +1363,14 @@ mod tests {
             payment_frequency: 1,
             remaining_years: dec!(5),
             discount_curve: curve,
+            projection_curve: None,
             forward_rates: None,
             is_pay_fixed: true,
             last_floating_reset: None,
+            effective_date: None,
+            fixed_day_count: None,
+            floating_day_count: None,
+            payment_adjustment: None,
         };
         let result = value_interest_rate_swap(&input);
         assert!(result.is_err(), "Negative notional should return an error");
@@ -1228,9 +1394,14 @@ mod tests {
             payment_frequency: 1,
             remaining_years: dec!(5),
             discount_curve: curve,
+            projection_curve: None,
             forward_rates: None,
             is_pay_fixed: true,
             last_floating_reset: None,
+            effective_date: None,
+            fixed_day_count: None,
+            floating_day_count: None,
+            payment_adjustment: None,
         };
         let result = value_interest_rate_swap(&input).unwrap();
         assert!(
@@ -1259,9 +1430,14 @@ mod tests {
             payment_frequency: 3, // invalid
             remaining_years: dec!(5),
             discount_curve: curve,
+            projection_curve: None,
             forward_rates: None,
             is_pay_fixed: true,
             last_floating_reset: None,
+            effective_date: None,
+            fixed_day_count: None,
+            floating_day_count: None,
+            payment_adjustment: None,
         };
         let result = value_interest_rate_swap(&input);
         assert!(result.is_err());
@@ -1283,10 +1459,15 @@ mod tests {
             fixed_rate: dec!(0.05),
             payment_frequency: 1,
             remaining_years: dec!(5),
-            discount_curve: vec![],
+            discount_curve: Curve::default(),
+            projection_curve: None,
             forward_rates: None,
             is_pay_fixed: true,
             last_floating_reset: None,
+            effective_date: None,
+            fixed_day_count: None,
+            floating_day_count: None,
+            payment_adjustment: None,
         };
         let result = value_interest_rate_swap(&input);
         assert!(result.is_err());
@@ -1384,9 +1565,14 @@ mod tests {
             payment_frequency: 1,
             remaining_years: dec!(3),
             discount_curve: curve.clone(),
+            projection_curve: None,
             forward_rates: Some(fwds),
             is_pay_fixed: true,
             last_floating_reset: None,
+            effective_date: None,
+            fixed_day_count: None,
+            floating_day_count: None,
+            payment_adjustment: None,
         };
         let result = value_interest_rate_swap(&input).unwrap();
         // Forward rates are above fixed rate, so floating_leg_pv > fixed_leg_pv
@@ -1413,9 +1599,14 @@ mod tests {
             payment_frequency: 1,
             remaining_years: dec!(3),
             discount_curve: curve.clone(),
+            projection_curve: None,
             forward_rates: None,
             is_pay_fixed: true,
             last_floating_reset: None,
+            effective_date: None,
+            fixed_day_count: None,
+            floating_day_count: None,
+            payment_adjustment: None,
         };
         let input_with_reset = IrsInput {
             last_floating_reset: Some(dec!(0.08)), // much higher reset
@@ -1431,4 +1622,246 @@ mod tests {
             "Higher reset rate should increase floating leg PV"
         );
     }
+
+    // -----------------------------------------------------------------------
+    // Day count accruals on a dated schedule
+    // -----------------------------------------------------------------------
+
+    fn dated_irs() -> IrsInput {
+        IrsInput {
+            notional: dec!(1_000_000),
+            fixed_rate: dec!(0.04),
+            payment_frequency: 2,
+            remaining_years: dec!(2),
+            discount_curve: flat_curve_fine(dec!(0.04), 3, 2),
+            projection_curve: None,
+            forward_rates: None,
+            is_pay_fixed: true,
+            last_floating_reset: None,
+            effective_date: NaiveDate::from_ymd_opt(2024, 1, 15),
+            fixed_day_count: None,
+            floating_day_count: None,
+            payment_adjustment: None,
+        }
+    }
+
+    #[test]
+    fn test_dated_schedule_uses_leg_day_counts() {
+        let result = value_interest_rate_swap(&dated_irs()).unwrap();
+        let first = &result.result.payment_schedule[0];
+
+        // 2024-01-15 -> 2024-07-15: 30/360 = 0.5, ACT/360 = 182/360
+        assert_eq!(first.payment_date, NaiveDate::from_ymd_opt(2024, 7, 15));
+        assert_eq!(first.fixed_accrual, dec!(0.5));
+        assert_eq!(first.floating_accrual, dec!(182) / dec!(360));
+        assert_eq!(first.fixed_payment, dec!(20_000));
+    }
+
+    #[test]
+    fn test_dated_par_rate_reprices_to_zero() {
+        let input = dated_irs();
+        let par = value_interest_rate_swap(&input)
+            .unwrap()
+            .result
+            .par_swap_rate;
+        let repriced = value_interest_rate_swap(&IrsInput {
+            fixed_rate: par,
+            ..input
+        })
+        .unwrap();
+        assert!(
+            approx_eq(repriced.result.net_value, Decimal::ZERO, dec!(0.01)),
+            "NPV at par should be ~0, got {}",
+            repriced.result.net_value
+        );
+    }
+
+    #[test]
+    fn test_act_360_floating_leg_exceeds_30_360() {
+        let act = value_interest_rate_swap(&dated_irs()).unwrap();
+        let thirty = value_interest_rate_swap(&IrsInput {
+            floating_day_count: Some(DayCountConvention::Thirty360),
+            ..dated_irs()
+        })
+        .unwrap();
+        assert!(act.result.floating_leg_pv > thirty.result.floating_leg_pv);
+    }
+
+    #[test]
+    fn test_day_count_without_effective_date_warns() {
+        let input = IrsInput {
+            effective_date: None,
+            fixed_day_count: Some(DayCountConvention::Actual365),
+            ..dated_irs()
+        };
+        let result = value_interest_rate_swap(&input).unwrap();
+        assert!(!result.warnings.is_empty());
+        assert_eq!(result.result.payment_schedule[0].fixed_accrual, dec!(0.5));
+    }
+
+    #[test]
+    fn test_payment_adjustment_rolls_dates_and_accruals() {
+        use crate::fixed_income::calendars::{BusinessDayConvention, HolidayCalendar};
+
+        let input = IrsInput {
+            effective_date: NaiveDate::from_ymd_opt(2024, 3, 15),
+            payment_adjustment: Some(DateAdjustment {
+                calendar: HolidayCalendar::WeekendsOnly,
+                convention: BusinessDayConvention::ModifiedFollowing,
+            }),
+            ..dated_irs()
+        };
+        let result = value_interest_rate_swap(&input).unwrap();
+        let schedule = &result.result.payment_schedule;
+
+        // 2024-09-15 is a Sunday and 2025-03-15 a Saturday
+        assert_eq!(
+            schedule[0].payment_date,
+            NaiveDate::from_ymd_opt(2024, 9, 16)
+        );
+        assert_eq!(
+            schedule[1].payment_date,
+            NaiveDate::from_ymd_opt(2025, 3, 17)
+        );
+        assert_eq!(schedule[0].floating_accrual, dec!(185) / dec!(360));
+        assert_eq!(schedule[1].floating_accrual, dec!(182) / dec!(360));
+    }
+
+    #[test]
+    fn test_payment_adjustment_without_effective_date_warns() {
+        let input = IrsInput {
+            effective_date: None,
+            payment_adjustment: Some(DateAdjustment {
+                calendar: crate::fixed_income::calendars::HolidayCalendar::Target,
+                convention: Default::default(),
+            }),
+            ..dated_irs()
+        };
+        let result = value_interest_rate_swap(&input).unwrap();
+        assert!(result
+            .warnings
+            .iter()
+            .any(|w| w.contains("Payment adjustment ignored")));
+    }
+
+    // -----------------------------------------------------------------------
+    // Dual-curve (OIS discounting)
+    // -----------------------------------------------------------------------
+
+    fn dual_curve_irs() -> IrsInput {
+        IrsInput {
+            projection_curve: Some(flat_curve_fine(dec!(0.045), 6, 4)),
+            discount_curve: flat_curve_fine(dec!(0.04), 6, 4),
+            fixed_rate: dec!(0.045),
+            payment_frequency: 4,
+            remaining_years: dec!(5),
+            effective_date: None,
+            ..dated_irs()
+        }
+    }
+
+    #[test]
+    fn test_projection_curve_equal_to_discount_matches_single_curve() {
+        let single = IrsInput {
+            projection_curve: None,
+            ..dual_curve_irs()
+        };
+        let same = IrsInput {
+            projection_curve: Some(single.discount_curve.clone()),
+            ..single.clone()
+        };
+        let a = value_interest_rate_swap(&single).unwrap().result;
+        let b = value_interest_rate_swap(&same).unwrap().result;
+        assert_eq!(a.net_value, b.net_value);
+        assert_eq!(a.par_swap_rate, b.par_swap_rate);
+        assert!(a.single_curve_net_value.is_none());
+        assert_eq!(b.single_curve_net_value, Some(b.net_value));
+    }
+
+    #[test]
+    fn test_dual_curve_forwards_from_projection_curve() {
+        let result = value_interest_rate_swap(&dual_curve_irs()).unwrap();
+        let out = result.result;
+
+        // Par rate is driven by projection forwards, not the lower OIS curve
+        assert!(
+            out.par_swap_rate > dec!(0.044),
+            "par rate {} should sit near the 4.5% projection curve",
+            out.par_swap_rate
+        );
+        let single_curve = value_interest_rate_swap(&IrsInput {
+            projection_curve: None,
+            ..dual_curve_irs()
+        })
+        .unwrap()
+        .result;
+        assert!(single_curve.par_swap_rate < dec!(0.041));
+
+        // OIS discounting moves the value away from the single-curve figure
+        let legacy = out.single_curve_net_value.unwrap();
+        assert_ne!(out.net_value, legacy);
+        assert_eq!(result.assumptions["curve_setup"], "dual_curve");
+    }
+
+    #[test]
+    fn test_dual_curve_dv01_bumps_both_curves() {
+        let out = value_interest_rate_swap(&dual_curve_irs()).unwrap().result;
+        let discount_only = IrsInput {
+            discount_curve: dual_curve_irs().discount_curve.shifted(dec!(0.0001)),
+            ..dual_curve_irs()
+        };
+        let bumped_discount = value_interest_rate_swap(&discount_only).unwrap().result;
+        let discount_dv01 = (bumped_discount.net_value - out.net_value).abs();
+        // Projection sensitivity dominates for a pay-fixed swap near par
+        assert!(out.dv01 > discount_dv01);
+    }
+
+    #[test]
+    fn test_empty_projection_curve_rejected() {
+        let input = IrsInput {
+            projection_curve: Some(Curve::default()),
+            ..dual_curve_irs()
+        };
+        assert!(value_interest_rate_swap(&input).is_err());
+    }
+
+    #[test]
+    fn test_versioned_curve_converted_from_continuous() {
+        // ln(1.04): the continuously compounded twin of a flat 4% annual curve
+        let continuous: Curve = serde_json::from_value(serde_json::json!({
+            "version": 1,
+            "compounding": "continuous",
+            "interpolation": "linear",
+            "nodes": (1..=24)
+                .map(|q| serde_json::json!({ "tenor": q as f64 / 4.0, "value": "0.0392207131532813" }))
+                .collect::<Vec<_>>(),
+        }))
+        .unwrap();
+        let annual = value_interest_rate_swap(&IrsInput {
+            projection_curve: None,
+            ..dual_curve_irs()
+        })
+        .unwrap()
+        .result;
+        let converted = value_interest_rate_swap(&IrsInput {
+            projection_curve: None,
+            discount_curve: continuous,
+            ..dual_curve_irs()
+        })
+        .unwrap()
+        .result;
+        assert!((annual.par_swap_rate - converted.par_swap_rate).abs() < dec!(0.000001));
+        assert!((annual.net_value - converted.net_value).abs() < dec!(1));
+    }
+
+    #[test]
+    fn test_legacy_curve_array_still_accepted() {
+        let curve: Curve = serde_json::from_value(serde_json::json!([
+            { "maturity": "1", "rate": "0.04" },
+            { "maturity": "5", "rate": "0.045" }
+        ]))
+        .unwrap();
+        assert_eq!(curve.len(), 2);
+        assert!(curve.compounding.is_none());
+    }
 }
@@ -0,0 +1,583 @@
+//! Field-by-field comparison of two calculator outputs.
+//!
+//! Used to review what moved between two versions of a model:
+//! - Numeric fields (JSON numbers or decimal strings such as `"50.00"`) are
+//!   compared by value, with absolute and percentage change
+//! - Materiality thresholds, globally or per field, separate the changes a
+//!   reviewer should look at from rounding noise
+//! - Arrays of named objects (tranches, step-ups, holdings) are matched by
+//!   `name`, so inserting an element does not shift every later row; other
+//!   arrays are compared by index
+//! - Fields that only exist on one side, or change type, are always material
+//!
+//! Paths use dots for fields and brackets for array elements:
+//! `result.schedule[2].goodwill`, `result.tranches[name=Senior].amount`.
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::{BTreeMap, BTreeSet};
+use std::str::FromStr;
+use std::time::Instant;
+
+use crate::error::CorpFinanceError;
+use crate::types::*;
+use crate::CorpFinanceResult;
+
+// ---------------------------------------------------------------------------
+// Types
+// ---------------------------------------------------------------------------
+
+/// When a numeric change counts as material. With neither limit set, every
+/// change is material.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MaterialityThreshold {
+    /// Minimum absolute change.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub absolute: Option<Decimal>,
+    /// Minimum change relative to the old value (0.01 = 1%).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub relative: Option<Rate>,
+}
+
+/// Two result documents and the comparison settings.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResultDiffInput {
+    pub old: Value,
+    pub new: Value,
+    /// Threshold applied to every numeric field without its own.
+    #[serde(default)]
+    pub threshold: MaterialityThreshold,
+    /// Per-field thresholds, keyed by full path (`result.irr`) or by field
+    /// name (`irr`); the full path wins.
+    #[serde(default)]
+    pub field_thresholds: BTreeMap<String, MaterialityThreshold>,
+    /// Paths left out of the comparison. A dotted path (`result.exit_year`)
+    /// is a prefix from the root; a bare field name (`metadata`) is skipped
+    /// at any depth, so the run timings in `metadata` are ignored even in an
+    /// envelope nested inside a cached project result. Defaults to `metadata`.
+    #[serde(default = "default_ignore")]
+    pub ignore: Vec<String>,
+    /// Also list fields that did not change.
+    #[serde(default)]
+    pub include_unchanged: bool,
+}
+
+fn default_ignore() -> Vec<String> {
+    vec!["metadata".into()]
+}
+
+/// How a field differs between the two documents.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ChangeKind {
+    Unchanged,
+    Changed,
+    /// Present only in the new document.
+    Added,
+    /// Present only in the old document.
+    Removed,
+    /// Present in both with different JSON types (e.g. number vs object).
+    TypeChanged,
+}
+
+/// Comparison of one field.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FieldChange {
+    pub path: String,
+    pub kind: ChangeKind,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub old: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new: Option<Value>,
+    /// New less old, for numeric fields.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub absolute_change: Option<Decimal>,
+    /// Change relative to the magnitude of the old value; absent when the old
+    /// value is zero.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub percent_change: Option<Rate>,
+    pub material: bool,
+}
+
+/// Results of a result-set comparison.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResultDiffOutput {
+    /// Leaf fields compared (added or removed subtrees count once).
+    pub fields_compared: usize,
+    pub fields_changed: usize,
+    pub material_changes: usize,
+    /// `true` when no compared field changed.
+    pub identical: bool,
+    /// Changed fields in document order (all fields with `include_unchanged`).
+    pub changes: Vec<FieldChange>,
+}
+
+// ---------------------------------------------------------------------------
+// Public API
+// ---------------------------------------------------------------------------
+
+/// Compare two calculator outputs field by field.
+#[tracing::instrument(level = "debug", name = "diff", skip_all)]
+pub fn diff_results(
+    input: &ResultDiffInput,
+) -> CorpFinanceResult<ComputationOutput<ResultDiffOutput>> {
+    let start = Instant::now();
+    let mut warnings: Vec<String> = Vec::new();
+
+    validate_threshold("threshold", &input.threshold)?;
+    for (field, threshold) in &input.field_thresholds {
+        validate_threshold(&format!("field_thresholds.{field}"), threshold)?;
+    }
+
+    let old_methodology = input.old.get("methodology").and_then(Value::as_str);
+    let new_methodology = input.new.get("methodology").and_then(Value::as_str);
+    if let (Some(old), Some(new)) = (old_methodology, new_methodology) {
+        if old != new {
+            warnings.push(format!(
+                "Comparing outputs of different calculations ('{old}' vs '{new}')"
+            ));
+        }
+    }
+
+    let mut differ = Differ {
+        input,
+        fields_compared: 0,
+        changes: Vec::new(),
+    };
+    differ.compare(String::new(), Some(&input.old), Some(&input.new));
+
+    let fields_changed = differ
+        .changes
+        .iter()
+        .filter(|c| c.kind != ChangeKind::Unchanged)
+        .count();
+    let material_changes = differ.changes.iter().filter(|c| c.material).count();
+    let output = ResultDiffOutput {
+        fields_compared: differ.fields_compared,
+        fields_changed,
+        material_changes,
+        identical: fields_changed == 0,
+        changes: differ.changes,
+    };
+
+    let elapsed = start.elapsed().as_micros() as u64;
+    Ok(with_metadata(
+        "Field-by-field result comparison",
+        &serde_json::json!({
+            "threshold": input.threshold,
+            "field_thresholds": input.field_thresholds,
+            "ignore": input.ignore,
+            "array_matching": "by name where every element has a unique name, else by index",
+        }),
+        warnings,
+        elapsed,
+        output,
+    ))
+}
+
+// ---------------------------------------------------------------------------
+// Internal helpers
+// ---------------------------------------------------------------------------
+
+struct Differ<'a> {
+    input: &'a ResultDiffInput,
+    fields_compared: usize,
+    changes: Vec<FieldChange>,
+}
+
+impl Differ<'_> {
+    fn compare(&mut self, path: String, old: Option<&Value>, new: Option<&Value>) {
+        if self.is_ignored(&path) {
+            return;
+        }
+        match (old, new) {
+            (Some(Value::Object(a)), Some(Value::Object(b))) => {
+                let keys: BTreeSet<&String> = a.keys().chain(b.keys()).collect();
+                for key in keys {
+                    let child = if path.is_empty() {
+                        key.clone()
+                    } else {
+                        format!("{path}.{key}")
+                    };
+                    self.compare(child, a.get(key), b.get(key));
+                }
+            }
+            (Some(Value::Array(a)), Some(Value::Array(b))) => match (names(a), names(b)) {
+                (Some(na), Some(nb)) => {
+                    let by_name: BTreeMap<&str, &Value> = nb.iter().copied().zip(b).collect();
+                    for (name, item) in na.iter().zip(a) {
+                        self.compare(
+                            format!("{path}[name={name}]"),
+                            Some(item),
+                            by_name.get(name).copied(),
+                        );
+                    }
+                    for (name, item) in nb.iter().zip(b) {
+                        if !na.contains(name) {
+                            self.compare(format!("{path}[name={name}]"), None, Some(item));
+                        }
+                    }
+                }
+                _ => {
+                    for i in 0..a.len().max(b.len()) {
+                        self.compare(format!("{path}[{i}]"), a.get(i), b.get(i));
+                    }
+                }
+            },
+            (Some(a), Some(b)) => {
+                self.fields_compared += 1;
+                self.compare_leaf(path, a, b);
+            }
+            (Some(a), None) => {
+                self.fields_compared += 1;
+                self.push(path, ChangeKind::Removed, Some(a), None);
+            }
+            (None, Some(b)) => {
+                self.fields_compared += 1;
+                self.push(path, ChangeKind::Added, None, Some(b));
+            }
+            (None, None) => {}
+        }
+    }
+
+    fn compare_leaf(&mut self, path: String, old: &Value, new: &Value) {
+        if let (Some(a), Some(b)) = (as_decimal(old), as_decimal(new)) {
+            let absolute = b - a;
+            if absolute.is_zero() {
+                if self.input.include_unchanged {
+                    self.push(path, ChangeKind::Unchanged, Some(old), Some(new));
+                }
+                return;
+            }
+            let percent = if a.is_zero() {
+                None
+            } else {
+                Some(absolute / a.abs())
+            };
+            let material = self.is_material(&path, absolute, percent);
+            self.changes.push(FieldChange {
+                path,
+                kind: ChangeKind::Changed,
+                old: Some(old.clone()),
+                new: Some(new.clone()),
+                absolute_change: Some(absolute),
+                percent_change: percent,
+                material,
+            });
+            return;
+        }
+
+        let kind = if old == new {
+            ChangeKind::Unchanged
+        } else if same_type(old, new) {
+            ChangeKind::Changed
+        } else {
+            ChangeKind::TypeChanged
+        };
+        if kind != ChangeKind::Unchanged || self.input.include_unchanged {
+            self.push(path, kind, Some(old), Some(new));
+        }
+    }
+
+    fn push(&mut self, path: String, kind: ChangeKind, old: Option<&Value>, new: Option<&Value>) {
+        self.changes.push(FieldChange {
+            path,
+            kind,
+            old: old.cloned(),
+            new: new.cloned(),
+            absolute_change: None,
+            percent_change: None,
+            material: kind != ChangeKind::Unchanged,
+        });
+    }
+
+    /// Descendants are never visited once a node is ignored, so a bare field
+    /// name only has to match the last segment of the path.
+    fn is_ignored(&self, path: &str) -> bool {
+        self.input.ignore.iter().any(|prefix| {
+            path == prefix
+                || if prefix.contains(['.', '[']) {
+                    path.strip_prefix(prefix.as_str())
+                        .is_some_and(|rest| rest.starts_with('.') || rest.starts_with('['))
+                } else {
+                    path.strip_suffix(prefix.as_str())
+                        .is_some_and(|head| head.ends_with('.'))
+                }
+        })
+    }
+
+    fn is_material(&self, path: &str, absolute: Decimal, percent: Option<Rate>) -> bool {
+        let threshold = self.threshold_for(path);
+        match (threshold.absolute, threshold.relative) {
+            (None, None) => true,
+            (abs_limit, rel_limit) => {
+                let abs_hit = abs_limit.is_some_and(|limit| absolute.abs() >= limit);
+                // A move away from zero has no relative size; treat it as
+                // breaching any relative limit.
+                let rel_hit = rel_limit.is_some_and(|limit| match percent {
+                    Some(p) => p.abs() >= limit,
+                    None => true,
+                });
+                abs_hit || rel_hit
+            }
+        }
+    }
+
+    fn threshold_for(&self, path: &str) -> &MaterialityThreshold {
+        let field_name = path
+            .rsplit('.')
+            .next()
+            .map(|leaf| leaf.split('[').next().unwrap_or(leaf))
+            .unwrap_or(path);
+        self.input
+            .field_thresholds
+            .get(path)
+            .or_else(|| self.input.field_thresholds.get(field_name))
+            .unwrap_or(&self.input.threshold)
+    }
+}
+
+/// Names of the elements when every element is an object with a unique
+/// string `name`.
+fn names(items: &[Value]) -> Option<Vec<&str>> {
+    if items.is_empty() {
+        return None;
+    }
+    let names: Vec<&str> = items
+        .iter()
+        .map(|item| item.as_object().and_then(name_of))
+        .collect::<Option<_>>()?;
+    let unique: BTreeSet<&&str> = names.iter().collect();
+    (unique.len() == names.len()).then_some(names)
+}
+
+fn name_of(map: &Map<String, Value>) -> Option<&str> {
+    map.get("name").and_then(Value::as_str)
+}
+
+/// Numeric value of a JSON number or a decimal string.
+fn as_decimal(value: &Value) -> Option<Decimal> {
+    let text = match value {
+        Value::Number(n) => n.to_string(),
+        Value::String(s) => s.trim().to_string(),
+        _ => return None,
+    };
+    Decimal::from_str(&text)
+        .or_else(|_| Decimal::from_scientific(&text))
+        .ok()
+}
+
+fn same_type(a: &Value, b: &Value) -> bool {
+    std::mem::discriminant(a) == std::mem::discriminant(b)
+}
+
+fn validate_threshold(field: &str, threshold: &MaterialityThreshold) -> CorpFinanceResult<()> {
+    if threshold.absolute.is_some_and(|v| v < Decimal::ZERO)
+        || threshold.relative.is_some_and(|v| v < Decimal::ZERO)
+    {
+        return Err(CorpFinanceError::InvalidInput {
+            field: field.into(),
+            reason: "Materiality thresholds cannot be negative".into(),
+        });
+    }
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+    use serde_json::json;
+
+    fn diff_input(old: Value, new: Value) -> ResultDiffInput {
+        ResultDiffInput {
+            old,
+            new,
+            threshold: MaterialityThreshold::default(),
+            field_thresholds: BTreeMap::new(),
+            ignore: default_ignore(),
+            include_unchanged: false,
+        }
+    }
+
+    fn run(input: &ResultDiffInput) -> ResultDiffOutput {
+        diff_results(input).unwrap().result
+    }
+
+    fn change<'a>(out: &'a ResultDiffOutput, path: &str) -> &'a FieldChange {
+        out.changes
+            .iter()
+            .find(|c| c.path == path)
+            .unwrap_or_else(|| panic!("no change at {path}"))
+    }
+
+    fn envelope(irr: &str, moic: &str) -> Value {
+        json!({
+            "methodology": "LBO",
+            "result": { "irr": irr, "moic": moic, "exit_year": 5 },
+            "warnings": [],
+            "metadata": { "computation_time_us": 120 }
+        })
+    }
+
+    #[test]
+    fn test_identical_results() {
+        let mut new = envelope("0.2", "2.5");
+        new["metadata"]["computation_time_us"] = json!(95);
+        let out = run(&diff_input(envelope("0.2", "2.5"), new));
+        assert!(out.identical);
+        assert!(out.changes.is_empty());
+        // irr, moic, exit_year and methodology; metadata is ignored
+        assert_eq!(out.fields_compared, 4);
+    }
+
+    #[test]
+    fn test_nested_metadata_ignored_in_cached_runs() {
+        // Project results cache the envelope under `output`
+        let cached = |irr: &str, elapsed: u64| {
+            let mut output = envelope(irr, "2.5");
+            output["metadata"]["computation_time_us"] = json!(elapsed);
+            json!({ "command": "returns", "input": { "exit_year": 5 }, "output": output })
+        };
+        let out = run(&diff_input(cached("0.2", 120), cached("0.2", 95)));
+        assert!(out.identical);
+        // command, input.exit_year and the four envelope fields
+        assert_eq!(out.fields_compared, 6);
+
+        let out = run(&diff_input(cached("0.2", 120), cached("0.25", 95)));
+        assert_eq!(out.fields_changed, 1);
+        assert_eq!(change(&out, "output.result.irr").kind, ChangeKind::Changed);
+    }
+
+    #[test]
+    fn test_numeric_change_absolute_and_percent() {
+        let out = run(&diff_input(
+            envelope("0.20", "2.5"),
+            envelope("0.22", "2.50"),
+        ));
+        assert_eq!(out.fields_changed, 1);
+        let irr = change(&out, "result.irr");
+        assert_eq!(irr.kind, ChangeKind::Changed);
+        assert_eq!(irr.absolute_change, Some(dec!(0.02)));
+        assert_eq!(irr.percent_change, Some(dec!(0.1)));
+        assert!(irr.material);
+    }
+
+    #[test]
+    fn test_thresholds_and_field_overrides() {
+        let mut input = diff_input(envelope("0.200", "2.50"), envelope("0.201", "2.60"));
+        input.threshold = MaterialityThreshold {
+            absolute: None,
+            relative: Some(dec!(0.01)),
+        };
+        let out = run(&input);
+        // irr moved 0.5%, moic 4%
+        assert!(!change(&out, "result.irr").material);
+        assert!(change(&out, "result.moic").material);
+        assert_eq!(out.material_changes, 1);
+
+        // A tighter absolute limit on irr by field name makes it material
+        input.field_thresholds.insert(
+            "irr".into(),
+            MaterialityThreshold {
+                absolute: Some(dec!(0.0005)),
+                relative: None,
+            },
+        );
+        let out = run(&input);
+        assert!(change(&out, "result.irr").material);
+        assert_eq!(out.material_changes, 2);
+    }
+
+    #[test]
+    fn test_named_arrays_matched_by_name() {
+        let old = json!({ "tranches": [
+            { "name": "Senior", "amount": 400 },
+            { "name": "Mezz", "amount": 100 }
+        ]});
+        let new = json!({ "tranches": [
+            { "name": "Senior", "amount": 400 },
+            { "name": "Second lien", "amount": 50 },
+            { "name": "Mezz", "amount": 120 }
+        ]});
+        let out = run(&diff_input(old, new));
+        assert_eq!(out.fields_changed, 2);
+        assert_eq!(
+            change(&out, "tranches[name=Mezz].amount").absolute_change,
+            Some(dec!(20))
+        );
+        assert_eq!(
+            change(&out, "tranches[name=Second lien]").kind,
+            ChangeKind::Added
+        );
+    }
+
+    #[test]
+    fn test_unnamed_arrays_by_index() {
+        let old = json!({ "schedule": [{ "year": 1, "fcf": 10 }, { "year": 2, "fcf": 12 }] });
+        let new = json!({ "schedule": [{ "year": 1, "fcf": 11 }] });
+        let out = run(&diff_input(old, new));
+        assert_eq!(
+            change(&out, "schedule[0].fcf").absolute_change,
+            Some(dec!(1))
+        );
+        assert_eq!(change(&out, "schedule[1]").kind, ChangeKind::Removed);
+    }
+
+    #[test]
+    fn test_text_and_type_changes_always_material() {
+        let mut input = diff_input(
+            json!({ "rating": "BBB", "is_accretive": true, "exchange_ratio": null }),
+            json!({ "rating": "BB+", "is_accretive": false, "exchange_ratio": "0.5" }),
+        );
+        input.threshold.absolute = Some(dec!(1000));
+        let out = run(&input);
+        assert_eq!(change(&out, "rating").kind, ChangeKind::Changed);
+        assert_eq!(change(&out, "exchange_ratio").kind, ChangeKind::TypeChanged);
+        assert_eq!(out.material_changes, 3);
+    }
+
+    #[test]
+    fn test_move_from_zero_has_no_percent() {
+        let mut input = diff_input(json!({ "fees": 0 }), json!({ "fees": 5 }));
+        input.threshold.relative = Some(dec!(0.5));
+        let out = run(&input);
+        let fees = change(&out, "fees");
+        assert_eq!(fees.percent_change, None);
+        assert!(fees.material);
+    }
+
+    #[test]
+    fn test_include_unchanged_and_custom_ignore() {
+        let mut input = diff_input(envelope("0.2", "2.5"), envelope("0.2", "3.0"));
+        input.include_unchanged = true;
+        input.ignore = vec!["metadata".into(), "result.exit_year".into()];
+        let out = run(&input);
+        assert_eq!(out.changes.len(), 3);
+        assert_eq!(change(&out, "result.irr").kind, ChangeKind::Unchanged);
+        assert!(!change(&out, "result.irr").material);
+        assert!(out.changes.iter().all(|c| c.path != "result.exit_year"));
+    }
+
+    #[test]
+    fn test_different_methodologies_warn() {
+        let mut new = envelope("0.2", "2.5");
+        new["methodology"] = json!("DCF");
+        let result = diff_results(&diff_input(envelope("0.2", "2.5"), new)).unwrap();
+        assert!(result
+            .warnings
+            .iter()
+            .any(|w| w.contains("different calculations")));
+    }
+
+    #[test]
+    fn test_negative_threshold_rejected() {
+        let mut input = diff_input(json!({}), json!({}));
+        input.threshold.relative = Some(dec!(-0.1));
+        assert!(diff_results(&input).is_err());
+    }
+}
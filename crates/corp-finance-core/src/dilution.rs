@@ -0,0 +1,655 @@
+//! Share count dilution from options, warrants, RSUs and convertibles.
+//!
+//! One place for diluted share counts so that comps, target prices and
+//! convertible analysis treat dilutive securities the same way:
+//! - Treasury stock method (TSM) for options and warrants: in-the-money
+//!   instruments are exercised and the proceeds buy back shares
+//! - If-converted method for convertibles: shares are issued and the
+//!   principal leaves net debt; net-share-settled convertibles issue only
+//!   the in-the-money value in shares
+//! - Option-value method: options and warrants are deducted from equity at
+//!   Black-Scholes value instead of adding shares
+//!
+//! Implied value per share is circular (moneyness depends on the price being
+//! solved for); [`diluted_value_per_share`] solves it by bisection.
+
+use rust_decimal::Decimal;
+use rust_decimal::MathematicalOps;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+
+use crate::error::CorpFinanceError;
+use crate::stats::norm_cdf;
+use crate::types::{Money, Rate, Years};
+use crate::CorpFinanceResult;
+
+// ---------------------------------------------------------------------------
+// Types
+// ---------------------------------------------------------------------------
+
+/// Kind of dilutive security.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SecurityKind {
+    /// Employee stock option
+    Option,
+    Warrant,
+    /// RSUs / restricted stock: no exercise price, always dilutive
+    RestrictedStock,
+    /// Convertible bond, physically settled: if converted, all underlying
+    /// shares are issued and the principal is extinguished
+    Convertible,
+    /// Convertible with principal settled in cash and only the excess
+    /// conversion value delivered in shares
+    NetShareConvertible,
+}
+
+/// A tranche of dilutive securities.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DilutiveSecurity {
+    pub name: String,
+    pub kind: SecurityKind,
+    /// Number of instruments
+    pub count: Decimal,
+    /// Exercise or conversion price per underlying share (zero for RSUs)
+    #[serde(default)]
+    pub strike: Money,
+    /// Underlying shares per instrument (the conversion ratio for
+    /// convertibles)
+    #[serde(default = "default_shares_per_instrument")]
+    pub shares_per_instrument: Decimal,
+    /// Remaining life, required for options and warrants under the
+    /// option-value method
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time_to_expiry: Option<Years>,
+}
+
+fn default_shares_per_instrument() -> Decimal {
+    Decimal::ONE
+}
+
+impl DilutiveSecurity {
+    /// Total underlying shares.
+    pub fn underlying_shares(&self) -> Decimal {
+        self.count * self.shares_per_instrument
+    }
+}
+
+/// How options and warrants enter the share count.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub enum DilutionMethod {
+    #[default]
+    TreasuryStock,
+    /// Deduct Black-Scholes value of options and warrants from equity value.
+    OptionValue {
+        volatility: Rate,
+        risk_free_rate: Rate,
+        #[serde(default)]
+        dividend_yield: Rate,
+    },
+}
+
+/// Dilution from one security tranche at a given share price.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SecurityDilution {
+    pub name: String,
+    pub kind: SecurityKind,
+    pub underlying_shares: Decimal,
+    pub in_the_money: bool,
+    /// New shares added to the diluted count
+    pub net_new_shares: Decimal,
+    /// Shares bought back with exercise proceeds (TSM)
+    pub shares_repurchased: Decimal,
+    /// Claim on pre-dilution equity: intrinsic value under TSM and
+    /// if-converted, Black-Scholes value under the option-value method
+    pub value: Money,
+    /// Principal added back to equity value on conversion
+    pub equity_adjustment: Money,
+}
+
+/// Diluted share count at an observed share price.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DilutedShares {
+    pub share_price: Money,
+    pub basic_shares: Decimal,
+    pub diluted_shares: Decimal,
+    /// Diluted shares x price
+    pub diluted_market_cap: Money,
+    /// Principal of in-the-money physically settled convertibles, to be
+    /// removed from net debt in the equity bridge
+    pub convertible_debt_converted: Money,
+    /// diluted / basic - 1
+    pub dilution_pct: Rate,
+    pub securities: Vec<SecurityDilution>,
+}
+
+/// Value per share implied by an equity value, net of dilution.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DilutedEquityValue {
+    /// Equity value before dilution (convertibles still treated as debt)
+    pub equity_value: Money,
+    pub value_per_share: Money,
+    /// Equity value / basic shares, ignoring dilution
+    pub basic_value_per_share: Money,
+    /// Share count consistent with `value_per_share`
+    pub diluted_shares: Decimal,
+    pub securities: Vec<SecurityDilution>,
+    pub iterations: u32,
+}
+
+// ---------------------------------------------------------------------------
+// Diluted share count at a price
+// ---------------------------------------------------------------------------
+
+/// Diluted shares at an observed price: TSM for options, warrants and
+/// net-share convertibles, if-converted for physically settled convertibles.
+pub fn diluted_shares_at_price(
+    basic_shares: Decimal,
+    securities: &[DilutiveSecurity],
+    share_price: Money,
+) -> CorpFinanceResult<DilutedShares> {
+    validate(basic_shares, securities)?;
+    if share_price <= Decimal::ZERO {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "share_price".into(),
+            reason: "Share price must be positive".into(),
+        });
+    }
+
+    let rows: Vec<SecurityDilution> = securities
+        .iter()
+        .map(|s| tsm_dilution(s, share_price))
+        .collect();
+    let new_shares: Decimal = rows.iter().map(|r| r.net_new_shares).sum();
+    let diluted_shares = basic_shares + new_shares;
+
+    Ok(DilutedShares {
+        share_price,
+        basic_shares,
+        diluted_shares,
+        diluted_market_cap: diluted_shares * share_price,
+        convertible_debt_converted: rows.iter().map(|r| r.equity_adjustment).sum(),
+        dilution_pct: new_shares / basic_shares,
+        securities: rows,
+    })
+}
+
+// ---------------------------------------------------------------------------
+// Implied value per share
+// ---------------------------------------------------------------------------
+
+/// Solve for the value per share `P` consistent with an equity value:
+///
+/// `P x basic + sum of claims(P) = equity value`
+///
+/// Each claim is `N x max(P - K, 0)` under TSM (for a physically settled
+/// convertible, shares issued less the principal extinguished), the
+/// Black-Scholes value under the option-value method, and `N x P` for RSUs.
+/// `equity_value` treats convertibles as debt.
+pub fn diluted_value_per_share(
+    equity_value: Money,
+    basic_shares: Decimal,
+    securities: &[DilutiveSecurity],
+    method: &DilutionMethod,
+) -> CorpFinanceResult<DilutedEquityValue> {
+    validate(basic_shares, securities)?;
+    if let DilutionMethod::OptionValue { volatility, .. } = method {
+        if *volatility <= Decimal::ZERO {
+            return Err(CorpFinanceError::InvalidInput {
+                field: "method.volatility".into(),
+                reason: "Volatility must be positive".into(),
+            });
+        }
+        if let Some(s) = securities.iter().find(|s| {
+            matches!(s.kind, SecurityKind::Option | SecurityKind::Warrant)
+                && s.time_to_expiry.is_none()
+        }) {
+            return Err(CorpFinanceError::InvalidInput {
+                field: format!("securities.{}.time_to_expiry", s.name),
+                reason: "Required under the option-value method".into(),
+            });
+        }
+    }
+
+    let basic_value_per_share = equity_value / basic_shares;
+    let rsu_shares: Decimal = securities
+        .iter()
+        .filter(|s| s.kind == SecurityKind::RestrictedStock)
+        .map(|s| s.underlying_shares())
+        .sum();
+
+    // Non-positive equity: every exercisable claim is out of the money.
+    if equity_value <= Decimal::ZERO {
+        let value_per_share = equity_value / (basic_shares + rsu_shares);
+        return Ok(DilutedEquityValue {
+            equity_value,
+            value_per_share,
+            basic_value_per_share,
+            diluted_shares: basic_shares + rsu_shares,
+            securities: securities
+                .iter()
+                .map(|s| claim_dilution(s, Decimal::ZERO, method))
+                .collect(),
+            iterations: 0,
+        });
+    }
+
+    // f(P) = P x basic + claims(P) - equity, continuous and increasing in P
+    let excess = |p: Decimal| -> Decimal {
+        let claims: Decimal = securities
+            .iter()
+            .map(|s| claim_dilution(s, p, method).value)
+            .sum();
+        p * basic_shares + claims - equity_value
+    };
+
+    let mut lo = Decimal::ZERO;
+    let mut hi = basic_value_per_share;
+    let mut iterations = 0u32;
+    for _ in 0..100 {
+        iterations += 1;
+        let mid = (lo + hi) / dec!(2);
+        if excess(mid) > Decimal::ZERO {
+            hi = mid;
+        } else {
+            lo = mid;
+        }
+        if hi - lo < dec!(0.0000000001) {
+            break;
+        }
+    }
+    let value_per_share = (lo + hi) / dec!(2);
+
+    let rows: Vec<SecurityDilution> = securities
+        .iter()
+        .map(|s| claim_dilution(s, value_per_share, method))
+        .collect();
+    let converted: Decimal = rows.iter().map(|r| r.equity_adjustment).sum();
+    let diluted_shares = if value_per_share > Decimal::ZERO {
+        (equity_value + converted) / value_per_share
+    } else {
+        basic_shares
+    };
+
+    Ok(DilutedEquityValue {
+        equity_value,
+        value_per_share,
+        basic_value_per_share,
+        diluted_shares,
+        securities: rows,
+        iterations,
+    })
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// TSM / if-converted dilution of one tranche at price `p`.
+fn tsm_dilution(s: &DilutiveSecurity, p: Decimal) -> SecurityDilution {
+    let n = s.underlying_shares();
+    let itm = s.kind == SecurityKind::RestrictedStock || p > s.strike;
+    let (net_new_shares, shares_repurchased, equity_adjustment) = if !itm {
+        (Decimal::ZERO, Decimal::ZERO, Decimal::ZERO)
+    } else {
+        match s.kind {
+            SecurityKind::RestrictedStock => (n, Decimal::ZERO, Decimal::ZERO),
+            SecurityKind::Convertible => (n, Decimal::ZERO, n * s.strike),
+            SecurityKind::Option | SecurityKind::Warrant | SecurityKind::NetShareConvertible => {
+                let repurchased = if p > Decimal::ZERO {
+                    n * s.strike / p
+                } else {
+                    Decimal::ZERO
+                };
+                (n - repurchased, repurchased, Decimal::ZERO)
+            }
+        }
+    };
+    SecurityDilution {
+        name: s.name.clone(),
+        kind: s.kind,
+        underlying_shares: n,
+        in_the_money: itm,
+        net_new_shares,
+        shares_repurchased,
+        value: match s.kind {
+            SecurityKind::RestrictedStock => n * p,
+            _ => n * (p - s.strike).max(Decimal::ZERO),
+        },
+        equity_adjustment,
+    }
+}
+
+/// Dilution of one tranche at price `p` under the chosen method. Under the
+/// option-value method options and warrants add no shares and carry their
+/// Black-Scholes value instead.
+fn claim_dilution(s: &DilutiveSecurity, p: Decimal, method: &DilutionMethod) -> SecurityDilution {
+    let tsm = tsm_dilution(s, p);
+    match (method, s.kind) {
+        (
+            DilutionMethod::OptionValue {
+                volatility,
+                risk_free_rate,
+                dividend_yield,
+            },
+            SecurityKind::Option | SecurityKind::Warrant,
+        ) => {
+            let t = s.time_to_expiry.unwrap_or(Decimal::ZERO);
+            let value = tsm.underlying_shares
+                * bs_call(
+                    p,
+                    s.strike,
+                    t,
+                    *volatility,
+                    *risk_free_rate,
+                    *dividend_yield,
+                );
+            SecurityDilution {
+                net_new_shares: Decimal::ZERO,
+                shares_repurchased: Decimal::ZERO,
+                value,
+                ..tsm
+            }
+        }
+        _ => tsm,
+    }
+}
+
+/// Black-Scholes-Merton call value per underlying share.
+fn bs_call(s: Decimal, k: Decimal, t: Decimal, sigma: Decimal, r: Decimal, q: Decimal) -> Decimal {
+    if s <= Decimal::ZERO {
+        return Decimal::ZERO;
+    }
+    if k <= Decimal::ZERO {
+        return s * (-q * t).exp();
+    }
+    if t <= Decimal::ZERO {
+        return (s - k).max(Decimal::ZERO);
+    }
+    let sd = sigma * t.sqrt().unwrap_or(Decimal::ZERO);
+    let d1 = ((s / k).ln() + (r - q + sigma * sigma / dec!(2)) * t) / sd;
+    let d2 = d1 - sd;
+    s * (-q * t).exp() * norm_cdf(d1) - k * (-r * t).exp() * norm_cdf(d2)
+}
+
+fn validate(basic_shares: Decimal, securities: &[DilutiveSecurity]) -> CorpFinanceResult<()> {
+    if basic_shares <= Decimal::ZERO {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "basic_shares".into(),
+            reason: "Basic shares must be positive".into(),
+        });
+    }
+    for s in securities {
+        if s.count < Decimal::ZERO || s.shares_per_instrument < Decimal::ZERO {
+            return Err(CorpFinanceError::InvalidInput {
+                field: format!("securities.{}.count", s.name),
+                reason: "Counts cannot be negative".into(),
+            });
+        }
+        if s.strike < Decimal::ZERO {
+            return Err(CorpFinanceError::InvalidInput {
+                field: format!("securities.{}.strike", s.name),
+                reason: "Strike cannot be negative".into(),
+            });
+        }
+    }
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx_eq(a: Decimal, b: Decimal, eps: Decimal) -> bool {
+        (a - b).abs() < eps
+    }
+
+    fn option(name: &str, count: Decimal, strike: Decimal) -> DilutiveSecurity {
+        DilutiveSecurity {
+            name: name.into(),
+            kind: SecurityKind::Option,
+            count,
+            strike,
+            shares_per_instrument: Decimal::ONE,
+            time_to_expiry: Some(dec!(3)),
+        }
+    }
+
+    #[test]
+    fn test_tsm_at_price() {
+        let secs = vec![option("ESOP", dec!(10), dec!(5))];
+        let d = diluted_shares_at_price(dec!(100), &secs, dec!(10)).unwrap();
+        // 10 options exercised, proceeds 50 buy back 5 shares
+        assert_eq!(d.diluted_shares, dec!(105));
+        assert_eq!(d.securities[0].shares_repurchased, dec!(5));
+        assert_eq!(d.dilution_pct, dec!(0.05));
+        assert_eq!(d.diluted_market_cap, dec!(1050));
+    }
+
+    #[test]
+    fn test_out_of_the_money_excluded() {
+        let secs = vec![option("Underwater", dec!(10), dec!(15))];
+        let d = diluted_shares_at_price(dec!(100), &secs, dec!(10)).unwrap();
+        assert_eq!(d.diluted_shares, dec!(100));
+        assert!(!d.securities[0].in_the_money);
+    }
+
+    #[test]
+    fn test_rsus_always_dilutive() {
+        let secs = vec![DilutiveSecurity {
+            name: "RSU".into(),
+            kind: SecurityKind::RestrictedStock,
+            count: dec!(4),
+            strike: Decimal::ZERO,
+            shares_per_instrument: Decimal::ONE,
+            time_to_expiry: None,
+        }];
+        let d = diluted_shares_at_price(dec!(100), &secs, dec!(1)).unwrap();
+        assert_eq!(d.diluted_shares, dec!(104));
+    }
+
+    #[test]
+    fn test_convertible_if_converted_vs_net_share() {
+        let mut conv = DilutiveSecurity {
+            name: "CB".into(),
+            kind: SecurityKind::Convertible,
+            count: dec!(1),
+            strike: dec!(40),
+            shares_per_instrument: dec!(25),
+            time_to_expiry: None,
+        };
+        let d = diluted_shares_at_price(dec!(100), std::slice::from_ref(&conv), dec!(50)).unwrap();
+        assert_eq!(d.diluted_shares, dec!(125));
+        assert_eq!(d.convertible_debt_converted, dec!(1000));
+
+        conv.kind = SecurityKind::NetShareConvertible;
+        let d = diluted_shares_at_price(dec!(100), &[conv], dec!(50)).unwrap();
+        // Excess value 250 / 50 = 5 shares
+        assert_eq!(d.diluted_shares, dec!(105));
+        assert_eq!(d.convertible_debt_converted, Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_value_per_share_no_securities() {
+        let v = diluted_value_per_share(dec!(1000), dec!(100), &[], &DilutionMethod::TreasuryStock)
+            .unwrap();
+        assert!(approx_eq(v.value_per_share, dec!(10), dec!(0.000001)));
+        assert_eq!(v.basic_value_per_share, dec!(10));
+    }
+
+    #[test]
+    fn test_value_per_share_tsm_solves_circularity() {
+        // P x 100 + 10 x (P - 5) = 1000 => P = 1050 / 110
+        let secs = vec![option("ESOP", dec!(10), dec!(5))];
+        let v =
+            diluted_value_per_share(dec!(1000), dec!(100), &secs, &DilutionMethod::TreasuryStock)
+                .unwrap();
+        let expected = dec!(1050) / dec!(110);
+        assert!(approx_eq(v.value_per_share, expected, dec!(0.000001)));
+        // Consistent with the TSM share count at that price
+        let d = diluted_shares_at_price(dec!(100), &secs, v.value_per_share).unwrap();
+        assert!(approx_eq(d.diluted_market_cap, dec!(1000), dec!(0.0001)));
+    }
+
+    #[test]
+    fn test_value_per_share_excludes_options_struck_above_solution() {
+        // Strike 9.9 is in the money on a basic basis (10) but not after
+        // dilution from the cheaper tranche.
+        let secs = vec![
+            option("Cheap", dec!(20), dec!(1)),
+            option("Marginal", dec!(10), dec!(9.9)),
+        ];
+        let v =
+            diluted_value_per_share(dec!(1000), dec!(100), &secs, &DilutionMethod::TreasuryStock)
+                .unwrap();
+        // P = (1000 + 20) / 120 = 8.5
+        assert!(approx_eq(v.value_per_share, dec!(8.5), dec!(0.000001)));
+        assert!(!v.securities[1].in_the_money);
+    }
+
+    #[test]
+    fn test_value_per_share_with_convertible() {
+        // CB principal 1000 deducted as debt; equity 4000, 100 basic shares.
+        // Converted: P = (4000 + 1000) / 125 = 40 <= strike 40, so it stays
+        // debt and P = 40.
+        let conv = DilutiveSecurity {
+            name: "CB".into(),
+            kind: SecurityKind::Convertible,
+            count: dec!(1),
+            strike: dec!(40),
+            shares_per_instrument: dec!(25),
+            time_to_expiry: None,
+        };
+        let v = diluted_value_per_share(
+            dec!(4000),
+            dec!(100),
+            std::slice::from_ref(&conv),
+            &DilutionMethod::TreasuryStock,
+        )
+        .unwrap();
+        assert!(approx_eq(v.value_per_share, dec!(40), dec!(0.000001)));
+
+        // Equity 6000: converted P = 7000 / 125 = 56
+        let v = diluted_value_per_share(
+            dec!(6000),
+            dec!(100),
+            &[conv],
+            &DilutionMethod::TreasuryStock,
+        )
+        .unwrap();
+        assert!(approx_eq(v.value_per_share, dec!(56), dec!(0.000001)));
+        assert!(approx_eq(v.diluted_shares, dec!(125), dec!(0.0001)));
+    }
+
+    #[test]
+    fn test_option_value_method_lower_than_tsm() {
+        let secs = vec![option("ESOP", dec!(10), dec!(8))];
+        let tsm =
+            diluted_value_per_share(dec!(1000), dec!(100), &secs, &DilutionMethod::TreasuryStock)
+                .unwrap();
+        let ovm = diluted_value_per_share(
+            dec!(1000),
+            dec!(100),
+            &secs,
+            &DilutionMethod::OptionValue {
+                volatility: dec!(0.3),
+                risk_free_rate: dec!(0.04),
+                dividend_yield: Decimal::ZERO,
+            },
+        )
+        .unwrap();
+        // Time value makes the option claim larger than its intrinsic value
+        assert!(ovm.value_per_share < tsm.value_per_share);
+        assert!(ovm.securities[0].value > Decimal::ZERO);
+        assert_eq!(ovm.securities[0].net_new_shares, Decimal::ZERO);
+        // Identity: P x basic + option value = equity
+        let lhs = ovm.value_per_share * dec!(100) + ovm.securities[0].value;
+        assert!(approx_eq(lhs, dec!(1000), dec!(0.0001)));
+    }
+
+    #[test]
+    fn test_option_value_requires_expiry() {
+        let mut secs = vec![option("ESOP", dec!(10), dec!(8))];
+        secs[0].time_to_expiry = None;
+        let method = DilutionMethod::OptionValue {
+            volatility: dec!(0.3),
+            risk_free_rate: dec!(0.04),
+            dividend_yield: Decimal::ZERO,
+        };
+        assert!(diluted_value_per_share(dec!(1000), dec!(100), &secs, &method).is_err());
+    }
+
+    #[test]
+    fn test_negative_equity() {
+        let secs = vec![option("ESOP", dec!(10), dec!(5))];
+        let v =
+            diluted_value_per_share(dec!(-100), dec!(100), &secs, &DilutionMethod::TreasuryStock)
+                .unwrap();
+        assert_eq!(v.value_per_share, dec!(-1));
+        assert!(!v.securities[0].in_the_money);
+    }
+
+    #[test]
+    fn test_bs_call_reference() {
+        // S = K = 100, T = 1, sigma = 20%, r = 5%: 10.45
+        let c = bs_call(
+            dec!(100),
+            dec!(100),
+            dec!(1),
+            dec!(0.2),
+            dec!(0.05),
+            Decimal::ZERO,
+        );
+        assert!(approx_eq(c, dec!(10.45), dec!(0.01)));
+    }
+
+    #[test]
+    fn test_deep_in_the_money_penny_strike() {
+        // d1 ~ 15.5: worth S - K e^(-rT) rather than an exp underflow
+        let c = bs_call(
+            dec!(100),
+            dec!(0.5),
+            Decimal::ONE,
+            dec!(0.3),
+            dec!(0.04),
+            Decimal::ZERO,
+        );
+        assert!(approx_eq(c, dec!(99.5196), dec!(0.001)));
+
+        let mut secs = vec![option("Penny warrants", dec!(10), dec!(0.5))];
+        secs[0].time_to_expiry = Some(Decimal::ONE);
+        let v = diluted_value_per_share(
+            dec!(10_000),
+            dec!(100),
+            &secs,
+            &DilutionMethod::OptionValue {
+                volatility: dec!(0.3),
+                risk_free_rate: dec!(0.04),
+                dividend_yield: Decimal::ZERO,
+            },
+        )
+        .unwrap();
+        let lhs = v.value_per_share * dec!(100) + v.securities[0].value;
+        assert!(approx_eq(lhs, dec!(10_000), dec!(0.0001)));
+    }
+
+    #[test]
+    fn test_invalid_inputs() {
+        assert!(diluted_shares_at_price(Decimal::ZERO, &[], dec!(10)).is_err());
+        assert!(diluted_shares_at_price(dec!(100), &[], Decimal::ZERO).is_err());
+        let secs = vec![option("Bad", dec!(-1), dec!(5))];
+        assert!(diluted_shares_at_price(dec!(100), &secs, dec!(10)).is_err());
+    }
+
+    #[test]
+    fn test_serde_defaults() {
+        let s: DilutiveSecurity =
+            serde_json::from_str(r#"{"name":"W","kind":"Warrant","count":"5","strike":"12"}"#)
+                .unwrap();
+        assert_eq!(s.shares_per_instrument, Decimal::ONE);
+        assert!(s.time_to_expiry.is_none());
+    }
+}
@@ -0,0 +1,784 @@
+//! Share Buyback Execution Modeling.
+//!
+//! Covers:
+//! 1. **Accelerated share repurchase (ASR)** — upfront initial delivery at
+//!    spot, with a final true-up against the average VWAP over the contract
+//!    term less the dealer discount.
+//! 2. **10b5-1 open-market programs** — daily purchases capped by a share of
+//!    average daily volume (Rule 10b-18 style), an optional daily spend budget
+//!    and an optional limit price above which the plan does not buy.
+//! 3. **Path simulation** — daily prices follow a discretised geometric
+//!    Brownian motion driven by a seeded LCG, so results are reproducible.
+//!    Completion timelines, average prices and true-up shares are reported as
+//!    distributions (mean, P5, P50, P95, min, max).
+//!
+//! All arithmetic uses `rust_decimal::Decimal`. No `f64`.
+
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+
+use crate::error::CorpFinanceError;
+use crate::sampling::{percentile, Lcg};
+use crate::CorpFinanceResult;
+
+const TRADING_DAYS_PER_YEAR: Decimal = dec!(252);
+const DEFAULT_SIMULATIONS: u32 = 500;
+const MAX_SIMULATIONS: u32 = 20_000;
+const DEFAULT_SEED: u64 = 42;
+/// Floor applied to simulated prices so a path can never go non-positive.
+const PRICE_FLOOR: Decimal = dec!(0.01);
+
+// ---------------------------------------------------------------------------
+// Input / Output
+// ---------------------------------------------------------------------------
+
+/// Market assumptions shared by both execution models.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PricePathAssumptions {
+    /// Current share price.
+    pub current_price: Decimal,
+    /// Annualised volatility of the share price (e.g. 0.30).
+    pub annual_volatility: Decimal,
+    /// Annualised price drift (e.g. 0.05). Defaults to zero.
+    #[serde(default)]
+    pub annual_drift: Decimal,
+    /// Number of simulated price paths (default 500).
+    #[serde(default)]
+    pub num_simulations: Option<u32>,
+    /// Seed for the deterministic random number generator (default 42).
+    #[serde(default)]
+    pub seed: Option<u64>,
+}
+
+/// Input for an accelerated share repurchase.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AsrInput {
+    /// Notional paid to the dealer at inception.
+    pub notional: Decimal,
+    /// Fraction of notional delivered upfront at spot (e.g. 0.80).
+    pub initial_delivery_pct: Decimal,
+    /// Contract term in trading days over which VWAP is averaged.
+    pub term_days: u32,
+    /// Discount to average VWAP granted by the dealer (e.g. 0.01).
+    pub vwap_discount: Decimal,
+    /// Shares outstanding before the ASR.
+    pub shares_outstanding: Decimal,
+    /// Price path assumptions.
+    pub market: PricePathAssumptions,
+}
+
+/// Input for a 10b5-1 open-market repurchase program.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OpenMarketInput {
+    /// Total dollar amount authorised for the program.
+    pub program_amount: Decimal,
+    /// Shares outstanding before the program.
+    pub shares_outstanding: Decimal,
+    /// Average daily trading volume (shares).
+    pub average_daily_volume: Decimal,
+    /// Maximum participation as a fraction of ADV (e.g. 0.25).
+    pub max_pct_of_volume: Decimal,
+    /// Optional cap on dollars spent per day.
+    #[serde(default)]
+    pub daily_budget: Option<Decimal>,
+    /// Optional limit price; no purchases on days the price exceeds it.
+    #[serde(default)]
+    pub limit_price: Option<Decimal>,
+    /// Simulation horizon in trading days.
+    pub max_days: u32,
+    /// Price path assumptions.
+    pub market: PricePathAssumptions,
+}
+
+/// Summary statistics of a simulated distribution.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DistributionSummary {
+    pub mean: Decimal,
+    pub p5: Decimal,
+    pub p50: Decimal,
+    pub p95: Decimal,
+    pub min: Decimal,
+    pub max: Decimal,
+}
+
+/// Output of the ASR execution model.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AsrOutput {
+    /// Shares delivered at inception: notional * initial_delivery_pct / spot.
+    pub initial_shares: Decimal,
+    /// Final shares if the price stays flat at spot for the whole term.
+    pub flat_price_final_shares: Decimal,
+    /// Average VWAP over the term across paths.
+    pub average_vwap: DistributionSummary,
+    /// Settlement price: average VWAP * (1 - discount).
+    pub settlement_price: DistributionSummary,
+    /// Total shares ultimately retired: notional / settlement price.
+    pub final_shares: DistributionSummary,
+    /// True-up shares delivered at settlement (negative = owed by company).
+    pub true_up_shares: DistributionSummary,
+    /// Probability that the company owes shares (or cash) back at settlement.
+    pub prob_company_owes: Decimal,
+    /// Expected final shares as a fraction of shares outstanding.
+    pub expected_pct_retired: Decimal,
+    /// Number of simulated paths.
+    pub simulations: u32,
+}
+
+/// Output of the open-market program execution model.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OpenMarketOutput {
+    /// Daily share cap from the volume constraint: ADV * max_pct_of_volume.
+    pub daily_share_cap: Decimal,
+    /// Maximum daily spend at spot given the volume and budget constraints.
+    pub max_daily_spend_at_spot: Decimal,
+    /// Days to complete if the price stays flat at spot (None if the limit
+    /// price is below spot and the plan never trades).
+    pub flat_price_days_to_complete: Option<u32>,
+    /// Fraction of paths that fully deploy the program within `max_days`.
+    pub completion_probability: Decimal,
+    /// Completion day distribution over paths that completed.
+    pub completion_days: Option<DistributionSummary>,
+    /// Volume-weighted average price paid per path.
+    pub average_price_paid: DistributionSummary,
+    /// Shares repurchased per path.
+    pub shares_repurchased: DistributionSummary,
+    /// Dollars deployed per path.
+    pub amount_deployed: DistributionSummary,
+    /// Mean of (average price paid / average market price over the active
+    /// window - 1). Negative values mean the plan bought below the market.
+    pub execution_vs_market: Decimal,
+    /// Expected shares repurchased as a fraction of shares outstanding.
+    pub expected_pct_retired: Decimal,
+    /// Number of simulated paths.
+    pub simulations: u32,
+}
+
+// ---------------------------------------------------------------------------
+// Core functions
+// ---------------------------------------------------------------------------
+
+/// Model an accelerated share repurchase by simulating VWAP over the term.
+pub fn model_asr(input: &AsrInput) -> CorpFinanceResult<AsrOutput> {
+    validate_asr(input)?;
+    let market = &input.market;
+    let spot = market.current_price;
+
+    let initial_shares = input.notional * input.initial_delivery_pct / spot;
+    let one_minus_discount = Decimal::ONE - input.vwap_discount;
+    let flat_price_final_shares = input.notional / (spot * one_minus_discount);
+
+    let mut sim = PathSimulator::new(market);
+    let n = sim.simulations;
+    let mut vwaps = Vec::with_capacity(n as usize);
+    let mut settlement_prices = Vec::with_capacity(n as usize);
+    let mut finals = Vec::with_capacity(n as usize);
+    let mut true_ups = Vec::with_capacity(n as usize);
+    let mut owes = 0u32;
+
+    for _ in 0..n {
+        let mut price = spot;
+        let mut sum = Decimal::ZERO;
+        for _ in 0..input.term_days {
+            price = sim.step(price);
+            sum += price;
+        }
+        let avg_vwap = sum / Decimal::from(input.term_days);
+        let settlement = avg_vwap * one_minus_discount;
+        let final_shares = input.notional / settlement;
+        let true_up = final_shares - initial_shares;
+        if true_up < Decimal::ZERO {
+            owes += 1;
+        }
+        vwaps.push(avg_vwap);
+        settlement_prices.push(settlement);
+        finals.push(final_shares);
+        true_ups.push(true_up);
+    }
+
+    let final_shares = summarize(finals);
+    let expected_pct_retired = final_shares.mean / input.shares_outstanding;
+
+    Ok(AsrOutput {
+        initial_shares,
+        flat_price_final_shares,
+        average_vwap: summarize(vwaps),
+        settlement_price: summarize(settlement_prices),
+        final_shares,
+        true_up_shares: summarize(true_ups),
+        prob_company_owes: Decimal::from(owes) / Decimal::from(n),
+        expected_pct_retired,
+        simulations: n,
+    })
+}
+
+/// Model a 10b5-1 open-market program subject to daily volume, budget and
+/// limit-price constraints.
+pub fn model_open_market_program(input: &OpenMarketInput) -> CorpFinanceResult<OpenMarketOutput> {
+    validate_open_market(input)?;
+    let market = &input.market;
+    let spot = market.current_price;
+
+    let daily_share_cap = input.average_daily_volume * input.max_pct_of_volume;
+    let max_daily_spend_at_spot = daily_spend_cap(daily_share_cap, spot, input.daily_budget);
+
+    let trades_at_spot = input.limit_price.is_none_or(|limit| spot <= limit);
+    let flat_price_days_to_complete = if trades_at_spot {
+        let days = (input.program_amount / max_daily_spend_at_spot).ceil();
+        days.to_string().parse::<u32>().ok()
+    } else {
+        None
+    };
+
+    let mut sim = PathSimulator::new(market);
+    let n = sim.simulations;
+    let mut completion_days = Vec::new();
+    let mut avg_prices = Vec::with_capacity(n as usize);
+    let mut shares_bought = Vec::with_capacity(n as usize);
+    let mut deployed = Vec::with_capacity(n as usize);
+    let mut relative_sum = Decimal::ZERO;
+    let mut relative_count = 0u32;
+
+    for _ in 0..n {
+        let mut price = spot;
+        let mut remaining = input.program_amount;
+        let mut shares = Decimal::ZERO;
+        let mut market_sum = Decimal::ZERO;
+        let mut completed_on: Option<u32> = None;
+
+        for day in 1..=input.max_days {
+            price = sim.step(price);
+            market_sum += price;
+            if input.limit_price.is_some_and(|limit| price > limit) {
+                continue;
+            }
+            let spend = daily_spend_cap(daily_share_cap, price, input.daily_budget).min(remaining);
+            shares += spend / price;
+            remaining -= spend;
+            if remaining <= Decimal::ZERO {
+                completed_on = Some(day);
+                break;
+            }
+        }
+
+        let spent = input.program_amount - remaining;
+        let avg_paid = if shares > Decimal::ZERO {
+            spent / shares
+        } else {
+            Decimal::ZERO
+        };
+        if shares > Decimal::ZERO {
+            let active_days = completed_on.unwrap_or(input.max_days);
+            let avg_market = market_sum / Decimal::from(active_days);
+            relative_sum += avg_paid / avg_market - Decimal::ONE;
+            relative_count += 1;
+        }
+        if let Some(day) = completed_on {
+            completion_days.push(Decimal::from(day));
+        }
+        avg_prices.push(avg_paid);
+        shares_bought.push(shares);
+        deployed.push(spent);
+    }
+
+    let completion_probability = Decimal::from(completion_days.len() as u32) / Decimal::from(n);
+    let completion_days = if completion_days.is_empty() {
+        None
+    } else {
+        Some(summarize(completion_days))
+    };
+    let execution_vs_market = if relative_count > 0 {
+        relative_sum / Decimal::from(relative_count)
+    } else {
+        Decimal::ZERO
+    };
+    let shares_repurchased = summarize(shares_bought);
+    let expected_pct_retired = shares_repurchased.mean / input.shares_outstanding;
+
+    Ok(OpenMarketOutput {
+        daily_share_cap,
+        max_daily_spend_at_spot,
+        flat_price_days_to_complete,
+        completion_probability,
+        completion_days,
+        average_price_paid: summarize(avg_prices),
+        shares_repurchased,
+        amount_deployed: summarize(deployed),
+        execution_vs_market,
+        expected_pct_retired,
+        simulations: n,
+    })
+}
+
+// ---------------------------------------------------------------------------
+// Simulation helpers
+// ---------------------------------------------------------------------------
+
+/// Seeded daily price-path generator (Euler-discretised GBM).
+struct PathSimulator {
+    rng: Lcg,
+    daily_drift: Decimal,
+    daily_vol: Decimal,
+    simulations: u32,
+}
+
+impl PathSimulator {
+    fn new(market: &PricePathAssumptions) -> Self {
+        Self {
+            rng: Lcg::new(market.seed.unwrap_or(DEFAULT_SEED)),
+            daily_drift: market.annual_drift / TRADING_DAYS_PER_YEAR,
+            daily_vol: market.annual_volatility / sqrt_decimal(TRADING_DAYS_PER_YEAR),
+            simulations: market.num_simulations.unwrap_or(DEFAULT_SIMULATIONS),
+        }
+    }
+
+    fn step(&mut self, price: Decimal) -> Decimal {
+        let z = self.rng.next_normal();
+        (price * (Decimal::ONE + self.daily_drift + self.daily_vol * z)).max(PRICE_FLOOR)
+    }
+}
+
+fn daily_spend_cap(daily_share_cap: Decimal, price: Decimal, budget: Option<Decimal>) -> Decimal {
+    let volume_cap = daily_share_cap * price;
+    match budget {
+        Some(b) => volume_cap.min(b),
+        None => volume_cap,
+    }
+}
+
+fn summarize(mut values: Vec<Decimal>) -> DistributionSummary {
+    values.sort();
+    let n = values.len();
+    let mean = values.iter().copied().sum::<Decimal>() / Decimal::from(n as u32);
+    DistributionSummary {
+        mean,
+        p5: percentile(&values, dec!(0.05)),
+        p50: percentile(&values, dec!(0.50)),
+        p95: percentile(&values, dec!(0.95)),
+        min: values[0],
+        max: values[n - 1],
+    }
+}
+
+/// Newton-Raphson square root.
+fn sqrt_decimal(x: Decimal) -> Decimal {
+    if x <= Decimal::ZERO {
+        return Decimal::ZERO;
+    }
+    let mut guess = x / dec!(2);
+    for _ in 0..30 {
+        let next = (guess + x / guess) / dec!(2);
+        if (next - guess).abs() < dec!(0.0000000001) {
+            return next;
+        }
+        guess = next;
+    }
+    guess
+}
+
+// ---------------------------------------------------------------------------
+// Validation
+// ---------------------------------------------------------------------------
+
+fn validate_market(market: &PricePathAssumptions) -> CorpFinanceResult<()> {
+    if market.current_price <= Decimal::ZERO {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "current_price".into(),
+            reason: "Share price must be positive.".into(),
+        });
+    }
+    if market.annual_volatility < Decimal::ZERO {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "annual_volatility".into(),
+            reason: "Volatility must be non-negative.".into(),
+        });
+    }
+    if let Some(n) = market.num_simulations {
+        if n == 0 || n > MAX_SIMULATIONS {
+            return Err(CorpFinanceError::InvalidInput {
+                field: "num_simulations".into(),
+                reason: format!("Number of simulations must be between 1 and {MAX_SIMULATIONS}."),
+            });
+        }
+    }
+    crate::limits::check_simulation_paths(
+        "num_simulations",
+        market.num_simulations.unwrap_or(DEFAULT_SIMULATIONS).into(),
+    )?;
+    Ok(())
+}
+
+fn validate_asr(input: &AsrInput) -> CorpFinanceResult<()> {
+    validate_market(&input.market)?;
+    if input.notional <= Decimal::ZERO {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "notional".into(),
+            reason: "ASR notional must be positive.".into(),
+        });
+    }
+    if input.initial_delivery_pct <= Decimal::ZERO || input.initial_delivery_pct > Decimal::ONE {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "initial_delivery_pct".into(),
+            reason: "Initial delivery must be in (0, 1].".into(),
+        });
+    }
+    if input.term_days == 0 {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "term_days".into(),
+            reason: "ASR term must be at least one trading day.".into(),
+        });
+    }
+    if input.vwap_discount < Decimal::ZERO || input.vwap_discount >= Decimal::ONE {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "vwap_discount".into(),
+            reason: "VWAP discount must be in [0, 1).".into(),
+        });
+    }
+    if input.shares_outstanding <= Decimal::ZERO {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "shares_outstanding".into(),
+            reason: "Shares outstanding must be positive.".into(),
+        });
+    }
+    Ok(())
+}
+
+fn validate_open_market(input: &OpenMarketInput) -> CorpFinanceResult<()> {
+    validate_market(&input.market)?;
+    if input.program_amount <= Decimal::ZERO {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "program_amount".into(),
+            reason: "Program amount must be positive.".into(),
+        });
+    }
+    if input.shares_outstanding <= Decimal::ZERO {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "shares_outstanding".into(),
+            reason: "Shares outstanding must be positive.".into(),
+        });
+    }
+    if input.average_daily_volume <= Decimal::ZERO {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "average_daily_volume".into(),
+            reason: "Average daily volume must be positive.".into(),
+        });
+    }
+    if input.max_pct_of_volume <= Decimal::ZERO || input.max_pct_of_volume > Decimal::ONE {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "max_pct_of_volume".into(),
+            reason: "Volume participation must be in (0, 1].".into(),
+        });
+    }
+    if let Some(b) = input.daily_budget {
+        if b <= Decimal::ZERO {
+            return Err(CorpFinanceError::InvalidInput {
+                field: "daily_budget".into(),
+                reason: "Daily budget must be positive.".into(),
+            });
+        }
+    }
+    if let Some(l) = input.limit_price {
+        if l <= Decimal::ZERO {
+            return Err(CorpFinanceError::InvalidInput {
+                field: "limit_price".into(),
+                reason: "Limit price must be positive.".into(),
+            });
+        }
+    }
+    if input.max_days == 0 {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "max_days".into(),
+            reason: "Simulation horizon must be at least one trading day.".into(),
+        });
+    }
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn approx_eq(a: Decimal, b: Decimal, eps: Decimal) -> bool {
+        (a - b).abs() < eps
+    }
+
+    fn market() -> PricePathAssumptions {
+        PricePathAssumptions {
+            current_price: dec!(50),
+            annual_volatility: dec!(0.30),
+            annual_drift: Decimal::ZERO,
+            num_simulations: Some(200),
+            seed: None,
+        }
+    }
+
+    fn asr_input() -> AsrInput {
+        AsrInput {
+            notional: dec!(1000),
+            initial_delivery_pct: dec!(0.80),
+            term_days: 60,
+            vwap_discount: dec!(0.01),
+            shares_outstanding: dec!(500),
+            market: market(),
+        }
+    }
+
+    fn open_market_input() -> OpenMarketInput {
+        OpenMarketInput {
+            program_amount: dec!(1000),
+            shares_outstanding: dec!(500),
+            average_daily_volume: dec!(4),
+            max_pct_of_volume: dec!(0.25),
+            daily_budget: None,
+            limit_price: None,
+            max_days: 250,
+            market: market(),
+        }
+    }
+
+    fn zero_vol(mut m: PricePathAssumptions) -> PricePathAssumptions {
+        m.annual_volatility = Decimal::ZERO;
+        m
+    }
+
+    #[test]
+    fn test_asr_initial_delivery() {
+        let out = model_asr(&asr_input()).unwrap();
+        // 1000 * 0.8 / 50 = 16
+        assert_eq!(out.initial_shares, dec!(16));
+    }
+
+    #[test]
+    fn test_asr_flat_price_final_shares() {
+        let out = model_asr(&asr_input()).unwrap();
+        // 1000 / (50 * 0.99) = 20.2020...
+        assert!(approx_eq(
+            out.flat_price_final_shares,
+            dec!(20.20202),
+            dec!(0.0001)
+        ));
+    }
+
+    #[test]
+    fn test_asr_zero_vol_matches_flat_price() {
+        let mut input = asr_input();
+        input.market = zero_vol(input.market);
+        let out = model_asr(&input).unwrap();
+        assert_eq!(out.average_vwap.mean, dec!(50));
+        assert!(approx_eq(
+            out.final_shares.mean,
+            out.flat_price_final_shares,
+            dec!(0.000001)
+        ));
+        assert_eq!(out.final_shares.p5, out.final_shares.p95);
+        assert_eq!(out.prob_company_owes, Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_asr_true_up_is_final_less_initial() {
+        let out = model_asr(&asr_input()).unwrap();
+        assert!(approx_eq(
+            out.true_up_shares.mean,
+            out.final_shares.mean - out.initial_shares,
+            dec!(0.000001)
+        ));
+    }
+
+    #[test]
+    fn test_asr_settlement_applies_discount() {
+        let out = model_asr(&asr_input()).unwrap();
+        assert!(approx_eq(
+            out.settlement_price.mean,
+            out.average_vwap.mean * dec!(0.99),
+            dec!(0.000001)
+        ));
+    }
+
+    #[test]
+    fn test_asr_distribution_ordering() {
+        let out = model_asr(&asr_input()).unwrap();
+        let d = &out.final_shares;
+        assert!(d.min <= d.p5 && d.p5 <= d.p50 && d.p50 <= d.p95 && d.p95 <= d.max);
+        assert!(d.p95 > d.p5, "volatile paths should spread the outcome");
+    }
+
+    #[test]
+    fn test_asr_deterministic_with_seed() {
+        let a = model_asr(&asr_input()).unwrap();
+        let b = model_asr(&asr_input()).unwrap();
+        assert_eq!(a.final_shares.mean, b.final_shares.mean);
+
+        let mut other = asr_input();
+        other.market.seed = Some(7);
+        let c = model_asr(&other).unwrap();
+        assert_ne!(a.final_shares.mean, c.final_shares.mean);
+    }
+
+    #[test]
+    fn test_asr_full_upfront_delivery_company_may_owe() {
+        let mut input = asr_input();
+        input.initial_delivery_pct = Decimal::ONE;
+        input.vwap_discount = Decimal::ZERO;
+        let out = model_asr(&input).unwrap();
+        // Delivering 100% upfront leaves the company owing shares on
+        // roughly the half of paths where the price rises.
+        assert!(out.prob_company_owes > dec!(0.2));
+        assert!(out.prob_company_owes < dec!(0.8));
+    }
+
+    #[test]
+    fn test_asr_positive_drift_reduces_shares() {
+        let base = model_asr(&asr_input()).unwrap();
+        let mut input = asr_input();
+        input.market.annual_drift = dec!(0.50);
+        let out = model_asr(&input).unwrap();
+        assert!(out.final_shares.mean < base.final_shares.mean);
+    }
+
+    #[test]
+    fn test_asr_pct_retired() {
+        let mut input = asr_input();
+        input.market = zero_vol(input.market);
+        let out = model_asr(&input).unwrap();
+        assert!(approx_eq(
+            out.expected_pct_retired,
+            out.flat_price_final_shares / dec!(500),
+            dec!(0.000001)
+        ));
+    }
+
+    #[test]
+    fn test_asr_invalid_inputs() {
+        let mut input = asr_input();
+        input.initial_delivery_pct = dec!(1.2);
+        assert!(model_asr(&input).is_err());
+
+        let mut input = asr_input();
+        input.term_days = 0;
+        assert!(model_asr(&input).is_err());
+
+        let mut input = asr_input();
+        input.vwap_discount = Decimal::ONE;
+        assert!(model_asr(&input).is_err());
+
+        let mut input = asr_input();
+        input.market.num_simulations = Some(0);
+        assert!(model_asr(&input).is_err());
+    }
+
+    #[test]
+    fn test_open_market_flat_price_timeline() {
+        let out = model_open_market_program(&open_market_input()).unwrap();
+        // Cap = 4 * 0.25 = 1 share/day = $50/day at spot -> 20 days
+        assert_eq!(out.daily_share_cap, Decimal::ONE);
+        assert_eq!(out.max_daily_spend_at_spot, dec!(50));
+        assert_eq!(out.flat_price_days_to_complete, Some(20));
+    }
+
+    #[test]
+    fn test_open_market_zero_vol_completes_on_schedule() {
+        let mut input = open_market_input();
+        input.market = zero_vol(input.market);
+        let out = model_open_market_program(&input).unwrap();
+        assert_eq!(out.completion_probability, Decimal::ONE);
+        let days = out.completion_days.unwrap();
+        assert_eq!(days.mean, dec!(20));
+        assert_eq!(out.average_price_paid.mean, dec!(50));
+        assert_eq!(out.shares_repurchased.mean, dec!(20));
+        assert_eq!(out.execution_vs_market, Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_open_market_daily_budget_binds() {
+        let mut input = open_market_input();
+        input.daily_budget = Some(dec!(25));
+        let out = model_open_market_program(&input).unwrap();
+        assert_eq!(out.max_daily_spend_at_spot, dec!(25));
+        assert_eq!(out.flat_price_days_to_complete, Some(40));
+    }
+
+    #[test]
+    fn test_open_market_horizon_too_short() {
+        let mut input = open_market_input();
+        input.market = zero_vol(input.market);
+        input.max_days = 10;
+        let out = model_open_market_program(&input).unwrap();
+        assert_eq!(out.completion_probability, Decimal::ZERO);
+        assert!(out.completion_days.is_none());
+        assert_eq!(out.amount_deployed.mean, dec!(500));
+    }
+
+    #[test]
+    fn test_open_market_limit_below_spot_never_trades_flat() {
+        let mut input = open_market_input();
+        input.market = zero_vol(input.market);
+        input.limit_price = Some(dec!(45));
+        let out = model_open_market_program(&input).unwrap();
+        assert!(out.flat_price_days_to_complete.is_none());
+        assert_eq!(out.amount_deployed.max, Decimal::ZERO);
+        assert_eq!(out.average_price_paid.mean, Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_open_market_limit_caps_price_paid() {
+        let mut input = open_market_input();
+        input.limit_price = Some(dec!(52));
+        let out = model_open_market_program(&input).unwrap();
+        assert!(out.average_price_paid.max <= dec!(52));
+        // Skipping high-price days should buy below the market on average.
+        assert!(out.execution_vs_market <= Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_open_market_limit_slows_completion() {
+        let base = model_open_market_program(&open_market_input()).unwrap();
+        let mut input = open_market_input();
+        input.limit_price = Some(dec!(50));
+        let out = model_open_market_program(&input).unwrap();
+        let base_days = base.completion_days.unwrap().mean;
+        let limited_days = out.completion_days.unwrap().mean;
+        assert!(limited_days > base_days);
+    }
+
+    #[test]
+    fn test_open_market_pct_retired() {
+        let mut input = open_market_input();
+        input.market = zero_vol(input.market);
+        let out = model_open_market_program(&input).unwrap();
+        // 20 shares of 500
+        assert_eq!(out.expected_pct_retired, dec!(0.04));
+    }
+
+    #[test]
+    fn test_open_market_invalid_inputs() {
+        let mut input = open_market_input();
+        input.max_pct_of_volume = Decimal::ZERO;
+        assert!(model_open_market_program(&input).is_err());
+
+        let mut input = open_market_input();
+        input.daily_budget = Some(dec!(-1));
+        assert!(model_open_market_program(&input).is_err());
+
+        let mut input = open_market_input();
+        input.max_days = 0;
+        assert!(model_open_market_program(&input).is_err());
+
+        let mut input = open_market_input();
+        input.market.current_price = Decimal::ZERO;
+        assert!(model_open_market_program(&input).is_err());
+    }
+
+    #[test]
+    fn test_sqrt_decimal() {
+        assert!(approx_eq(
+            sqrt_decimal(dec!(252)),
+            dec!(15.874508),
+            dec!(0.00001)
+        ));
+    }
+}
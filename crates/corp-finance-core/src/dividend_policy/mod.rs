@@ -1,5 +1,7 @@
 pub mod buyback;
+pub mod buyback_execution;
 pub mod h_model;
 pub mod multistage_ddm;
+pub mod payout_optimizer;
 pub mod payout_sustainability;
 pub mod total_shareholder_return;
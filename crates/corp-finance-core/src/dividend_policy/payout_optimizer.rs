@@ -0,0 +1,726 @@
+//! Dividend Policy Optimizer under Capital Constraints.
+//!
+//! Projects a multi-year payout path for each candidate policy and checks it
+//! against:
+//! 1. **Capital requirements** — retained earnings needed for reinvestment
+//!    or regulatory capital build (supplied per year, e.g. from the
+//!    regulatory or credit modules).
+//! 2. **Leverage target** — closing Net Debt / EBITDA must not exceed the
+//!    maximum; unused headroom may fund distributions.
+//! 3. **Signaling constraint** — optionally, DPS may never be cut.
+//!
+//! Each alternative is a target payout ratio split between dividends and
+//! buybacks. Buybacks retire shares at a constant P/E, so the value to a
+//! continuing shareholder is the after-tax PV of dividends plus the
+//! after-tax PV of the terminal share price. The module also solves the
+//! highest flat DPS sustainable across the whole horizon.
+//!
+//! All arithmetic uses `rust_decimal::Decimal`. No `f64`.
+
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+
+use crate::error::CorpFinanceError;
+use crate::CorpFinanceResult;
+
+/// Tolerance used when testing leverage against the maximum.
+const LEVERAGE_TOLERANCE: Decimal = dec!(0.0001);
+const BISECTION_ITERATIONS: u32 = 60;
+
+// ---------------------------------------------------------------------------
+// Input / Output
+// ---------------------------------------------------------------------------
+
+/// One forecast year.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PayoutYearInput {
+    /// Forecast net income.
+    pub net_income: Decimal,
+    /// Forecast EBITDA (leverage denominator).
+    pub ebitda: Decimal,
+    /// Earnings that must be retained (reinvestment or regulatory capital).
+    pub capital_requirement: Decimal,
+}
+
+/// A candidate payout policy.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PayoutAlternative {
+    /// Label, e.g. "Dividend-heavy".
+    pub name: String,
+    /// Total payout (dividends + buybacks) as a fraction of net income.
+    pub target_payout_ratio: Decimal,
+    /// Fraction of the total payout paid as dividends (rest is buybacks).
+    pub dividend_mix: Decimal,
+}
+
+/// Input for the payout optimizer.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PayoutOptimizerInput {
+    /// Shares outstanding at the start of the horizon.
+    pub shares_outstanding: Decimal,
+    /// Current share price.
+    pub share_price: Decimal,
+    /// Current (last paid) dividend per share.
+    pub current_dps: Decimal,
+    /// P/E multiple used to price buybacks and the terminal share.
+    pub pe_multiple: Decimal,
+    /// Cost of equity used to discount shareholder cash flows.
+    pub cost_of_equity: Decimal,
+    /// Personal tax rate on dividends.
+    pub dividend_tax_rate: Decimal,
+    /// Personal tax rate on capital gains.
+    pub capital_gains_tax_rate: Decimal,
+    /// Net debt at the start of the horizon.
+    pub opening_net_debt: Decimal,
+    /// Maximum Net Debt / EBITDA.
+    pub max_net_debt_to_ebitda: Decimal,
+    /// Whether DPS may never fall below the prior year's level.
+    pub no_dividend_cuts: bool,
+    /// Forecast years.
+    pub years: Vec<PayoutYearInput>,
+    /// Candidate payout policies.
+    pub alternatives: Vec<PayoutAlternative>,
+}
+
+/// Projection of one year under a payout alternative.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PayoutYear {
+    pub year: u32,
+    pub net_income: Decimal,
+    /// Distributable capacity: (NI - capital requirement) + leverage headroom.
+    pub distributable_capacity: Decimal,
+    pub dividends: Decimal,
+    pub dps: Decimal,
+    pub buybacks: Decimal,
+    pub shares_repurchased: Decimal,
+    pub closing_shares: Decimal,
+    pub eps: Decimal,
+    pub share_price: Decimal,
+    pub closing_net_debt: Decimal,
+    pub net_debt_to_ebitda: Decimal,
+    /// "target", "capacity" or "no_cut_floor".
+    pub binding_constraint: String,
+}
+
+/// Result for one payout alternative.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PayoutAlternativeResult {
+    pub name: String,
+    pub years: Vec<PayoutYear>,
+    /// Whether every constraint held in every year.
+    pub feasible: bool,
+    /// Description of each constraint breach.
+    pub violations: Vec<String>,
+    pub total_dividends: Decimal,
+    pub total_buybacks: Decimal,
+    /// PV of after-tax dividends per share held throughout.
+    pub pv_dividends_per_share: Decimal,
+    /// Share price at the end of the horizon.
+    pub terminal_share_price: Decimal,
+    /// PV of the after-tax terminal share price.
+    pub pv_terminal_per_share: Decimal,
+    /// Total after-tax value per share to a continuing shareholder.
+    pub value_per_share: Decimal,
+    /// Value per share relative to the current share price.
+    pub value_vs_current_price: Decimal,
+}
+
+/// Output of the payout optimizer.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PayoutOptimizerOutput {
+    pub alternatives: Vec<PayoutAlternativeResult>,
+    /// Feasible alternative with the highest value per share.
+    pub best_alternative: Option<String>,
+    /// Highest flat DPS payable every year without buybacks while staying
+    /// within capacity.
+    pub max_sustainable_dps: Decimal,
+}
+
+// ---------------------------------------------------------------------------
+// Core function
+// ---------------------------------------------------------------------------
+
+/// Project and compare payout alternatives under capital, leverage and
+/// signaling constraints.
+pub fn optimize_payout_policy(
+    input: &PayoutOptimizerInput,
+) -> CorpFinanceResult<PayoutOptimizerOutput> {
+    validate_input(input)?;
+
+    let alternatives: Vec<PayoutAlternativeResult> = input
+        .alternatives
+        .iter()
+        .map(|alt| project_alternative(input, alt))
+        .collect();
+
+    let best_alternative = alternatives
+        .iter()
+        .filter(|a| a.feasible)
+        .max_by(|a, b| a.value_per_share.cmp(&b.value_per_share))
+        .map(|a| a.name.clone());
+
+    Ok(PayoutOptimizerOutput {
+        alternatives,
+        best_alternative,
+        max_sustainable_dps: solve_max_flat_dps(input),
+    })
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+fn project_alternative(
+    input: &PayoutOptimizerInput,
+    alt: &PayoutAlternative,
+) -> PayoutAlternativeResult {
+    let mut shares = input.shares_outstanding;
+    let mut net_debt = input.opening_net_debt;
+    let mut prior_dps = input.current_dps;
+    let mut years = Vec::with_capacity(input.years.len());
+    let mut violations = Vec::new();
+    let mut total_dividends = Decimal::ZERO;
+    let mut total_buybacks = Decimal::ZERO;
+    let mut pv_dividends_per_share = Decimal::ZERO;
+    let mut discount = Decimal::ONE;
+    let one_plus_ke = Decimal::ONE + input.cost_of_equity;
+    let mut terminal_share_price = input.share_price;
+
+    for (i, y) in input.years.iter().enumerate() {
+        let year = (i + 1) as u32;
+        let fcf = y.net_income - y.capital_requirement;
+        let headroom = input.max_net_debt_to_ebitda * y.ebitda - net_debt;
+        let capacity = (fcf + headroom).max(Decimal::ZERO);
+
+        let desired = (alt.target_payout_ratio * y.net_income).max(Decimal::ZERO);
+        let floor = if input.no_dividend_cuts {
+            prior_dps * shares
+        } else {
+            Decimal::ZERO
+        };
+        let mut dividends = (desired * alt.dividend_mix).max(floor);
+        let mut buybacks = (desired - dividends).max(Decimal::ZERO);
+        let mut binding = if dividends > desired * alt.dividend_mix {
+            "no_cut_floor"
+        } else {
+            "target"
+        };
+
+        if dividends + buybacks > capacity {
+            buybacks = (capacity - dividends).max(Decimal::ZERO);
+            if dividends > capacity {
+                if input.no_dividend_cuts && dividends <= floor {
+                    binding = "no_cut_floor";
+                    violations.push(format!(
+                        "Year {year}: maintaining DPS requires {} against capacity of {}",
+                        dividends.round_dp(2),
+                        capacity.round_dp(2)
+                    ));
+                } else {
+                    dividends = capacity.max(floor);
+                    binding = "capacity";
+                }
+            } else {
+                binding = "capacity";
+            }
+        }
+
+        let eps = y.net_income / shares;
+        let price = input.pe_multiple * eps;
+        let shares_repurchased = if buybacks > Decimal::ZERO {
+            buybacks / price
+        } else {
+            Decimal::ZERO
+        };
+        let dps = dividends / shares;
+        let closing_shares = shares - shares_repurchased;
+
+        net_debt = net_debt - fcf + dividends + buybacks;
+        let net_debt_to_ebitda = if y.ebitda > Decimal::ZERO {
+            net_debt / y.ebitda
+        } else {
+            Decimal::ZERO
+        };
+        if y.ebitda > Decimal::ZERO
+            && net_debt_to_ebitda > input.max_net_debt_to_ebitda + LEVERAGE_TOLERANCE
+        {
+            violations.push(format!(
+                "Year {year}: Net Debt / EBITDA {} exceeds maximum {}",
+                net_debt_to_ebitda.round_dp(2),
+                input.max_net_debt_to_ebitda
+            ));
+        }
+
+        discount *= one_plus_ke;
+        pv_dividends_per_share += dps * (Decimal::ONE - input.dividend_tax_rate) / discount;
+        total_dividends += dividends;
+        total_buybacks += buybacks;
+        terminal_share_price =
+            (input.pe_multiple * y.net_income / closing_shares).max(Decimal::ZERO);
+
+        years.push(PayoutYear {
+            year,
+            net_income: y.net_income,
+            distributable_capacity: capacity,
+            dividends,
+            dps,
+            buybacks,
+            shares_repurchased,
+            closing_shares,
+            eps,
+            share_price: price,
+            closing_net_debt: net_debt,
+            net_debt_to_ebitda,
+            binding_constraint: binding.to_string(),
+        });
+
+        prior_dps = dps;
+        shares = closing_shares;
+    }
+
+    let gain = (terminal_share_price - input.share_price).max(Decimal::ZERO);
+    let after_tax_terminal = terminal_share_price - gain * input.capital_gains_tax_rate;
+    let pv_terminal_per_share = after_tax_terminal / discount;
+    let value_per_share = pv_dividends_per_share + pv_terminal_per_share;
+
+    PayoutAlternativeResult {
+        name: alt.name.clone(),
+        years,
+        feasible: violations.is_empty(),
+        violations,
+        total_dividends,
+        total_buybacks,
+        pv_dividends_per_share,
+        terminal_share_price,
+        pv_terminal_per_share,
+        value_per_share,
+        value_vs_current_price: value_per_share / input.share_price - Decimal::ONE,
+    }
+}
+
+/// Whether a flat DPS with no buybacks stays within capacity every year.
+fn flat_dps_feasible(input: &PayoutOptimizerInput, dps: Decimal) -> bool {
+    let dividends = dps * input.shares_outstanding;
+    let mut net_debt = input.opening_net_debt;
+    for y in &input.years {
+        let fcf = y.net_income - y.capital_requirement;
+        let headroom = input.max_net_debt_to_ebitda * y.ebitda - net_debt;
+        if dividends > fcf + headroom {
+            return false;
+        }
+        net_debt = net_debt - fcf + dividends;
+    }
+    true
+}
+
+/// Bisection on the flat DPS level.
+fn solve_max_flat_dps(input: &PayoutOptimizerInput) -> Decimal {
+    if !flat_dps_feasible(input, Decimal::ZERO) {
+        return Decimal::ZERO;
+    }
+    let positive_income: Decimal = input
+        .years
+        .iter()
+        .map(|y| y.net_income.max(Decimal::ZERO))
+        .sum();
+    let first_headroom = input
+        .years
+        .first()
+        .map(|y| input.max_net_debt_to_ebitda * y.ebitda - input.opening_net_debt)
+        .unwrap_or(Decimal::ZERO)
+        .max(Decimal::ZERO);
+    let mut lo = Decimal::ZERO;
+    let mut hi = (positive_income + first_headroom) / input.shares_outstanding;
+    if flat_dps_feasible(input, hi) {
+        return hi.round_dp(4);
+    }
+    for _ in 0..BISECTION_ITERATIONS {
+        let mid = (lo + hi) / dec!(2);
+        if flat_dps_feasible(input, mid) {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    lo.round_dp(4)
+}
+
+// ---------------------------------------------------------------------------
+// Validation
+// ---------------------------------------------------------------------------
+
+fn validate_input(input: &PayoutOptimizerInput) -> CorpFinanceResult<()> {
+    if input.shares_outstanding <= Decimal::ZERO {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "shares_outstanding".into(),
+            reason: "Shares outstanding must be positive.".into(),
+        });
+    }
+    if input.share_price <= Decimal::ZERO {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "share_price".into(),
+            reason: "Share price must be positive.".into(),
+        });
+    }
+    if input.current_dps < Decimal::ZERO {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "current_dps".into(),
+            reason: "Current DPS must be non-negative.".into(),
+        });
+    }
+    if input.pe_multiple <= Decimal::ZERO {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "pe_multiple".into(),
+            reason: "P/E multiple must be positive.".into(),
+        });
+    }
+    if input.cost_of_equity <= Decimal::ZERO {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "cost_of_equity".into(),
+            reason: "Cost of equity must be positive.".into(),
+        });
+    }
+    if input.dividend_tax_rate < Decimal::ZERO || input.dividend_tax_rate > Decimal::ONE {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "dividend_tax_rate".into(),
+            reason: "Dividend tax rate must be between 0 and 1.".into(),
+        });
+    }
+    if input.capital_gains_tax_rate < Decimal::ZERO || input.capital_gains_tax_rate > Decimal::ONE {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "capital_gains_tax_rate".into(),
+            reason: "Capital gains tax rate must be between 0 and 1.".into(),
+        });
+    }
+    if input.max_net_debt_to_ebitda < Decimal::ZERO {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "max_net_debt_to_ebitda".into(),
+            reason: "Leverage target must be non-negative.".into(),
+        });
+    }
+    if input.years.is_empty() {
+        return Err(CorpFinanceError::InsufficientData(
+            "At least one forecast year is required.".into(),
+        ));
+    }
+    if input.alternatives.is_empty() {
+        return Err(CorpFinanceError::InsufficientData(
+            "At least one payout alternative is required.".into(),
+        ));
+    }
+    for alt in &input.alternatives {
+        if alt.target_payout_ratio < Decimal::ZERO {
+            return Err(CorpFinanceError::InvalidInput {
+                field: "target_payout_ratio".into(),
+                reason: format!("Payout ratio for '{}' must be non-negative.", alt.name),
+            });
+        }
+        if alt.dividend_mix < Decimal::ZERO || alt.dividend_mix > Decimal::ONE {
+            return Err(CorpFinanceError::InvalidInput {
+                field: "dividend_mix".into(),
+                reason: format!("Dividend mix for '{}' must be between 0 and 1.", alt.name),
+            });
+        }
+    }
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn approx_eq(a: Decimal, b: Decimal, eps: Decimal) -> bool {
+        (a - b).abs() < eps
+    }
+
+    fn year(net_income: Decimal) -> PayoutYearInput {
+        PayoutYearInput {
+            net_income,
+            ebitda: dec!(200),
+            capital_requirement: dec!(40),
+        }
+    }
+
+    fn alt(name: &str, ratio: Decimal, mix: Decimal) -> PayoutAlternative {
+        PayoutAlternative {
+            name: name.into(),
+            target_payout_ratio: ratio,
+            dividend_mix: mix,
+        }
+    }
+
+    fn base_input() -> PayoutOptimizerInput {
+        PayoutOptimizerInput {
+            shares_outstanding: dec!(100),
+            share_price: dec!(15),
+            current_dps: dec!(0.40),
+            pe_multiple: dec!(15),
+            cost_of_equity: dec!(0.10),
+            dividend_tax_rate: dec!(0.30),
+            capital_gains_tax_rate: dec!(0.15),
+            opening_net_debt: dec!(300),
+            max_net_debt_to_ebitda: dec!(2.0),
+            no_dividend_cuts: true,
+            years: vec![year(dec!(100)), year(dec!(110)), year(dec!(120))],
+            alternatives: vec![
+                alt("All dividend", dec!(0.50), Decimal::ONE),
+                alt("All buyback", dec!(0.50), Decimal::ZERO),
+                alt("Balanced", dec!(0.50), dec!(0.50)),
+            ],
+        }
+    }
+
+    fn find<'a>(out: &'a PayoutOptimizerOutput, name: &str) -> &'a PayoutAlternativeResult {
+        out.alternatives.iter().find(|a| a.name == name).unwrap()
+    }
+
+    #[test]
+    fn test_all_dividend_first_year() {
+        let out = optimize_payout_policy(&base_input()).unwrap();
+        let y1 = &find(&out, "All dividend").years[0];
+        // 50% of 100 = 50 -> DPS 0.50
+        assert_eq!(y1.dividends, dec!(50));
+        assert_eq!(y1.dps, dec!(0.5));
+        assert_eq!(y1.buybacks, Decimal::ZERO);
+        assert_eq!(y1.binding_constraint, "target");
+    }
+
+    #[test]
+    fn test_capacity_first_year() {
+        let out = optimize_payout_policy(&base_input()).unwrap();
+        let y1 = &find(&out, "All dividend").years[0];
+        // FCF 60 + headroom (2 * 200 - 300 = 100) = 160
+        assert_eq!(y1.distributable_capacity, dec!(160));
+    }
+
+    #[test]
+    fn test_net_debt_roll_forward() {
+        let out = optimize_payout_policy(&base_input()).unwrap();
+        let y1 = &find(&out, "All dividend").years[0];
+        // 300 - 60 + 50 = 290
+        assert_eq!(y1.closing_net_debt, dec!(290));
+        assert_eq!(y1.net_debt_to_ebitda, dec!(1.45));
+    }
+
+    #[test]
+    fn test_buyback_retires_shares_at_pe() {
+        let out = optimize_payout_policy(&base_input()).unwrap();
+        let y1 = &find(&out, "All buyback").years[0];
+        // No-cut floor forces DPS 0.40 -> dividends 40; buybacks 10.
+        assert_eq!(y1.dividends, dec!(40));
+        assert_eq!(y1.buybacks, dec!(10));
+        assert_eq!(y1.binding_constraint, "no_cut_floor");
+        // Price = 15 * 1.00 = 15; 10 / 15 shares
+        assert!(approx_eq(
+            y1.shares_repurchased,
+            dec!(0.666667),
+            dec!(0.0001)
+        ));
+    }
+
+    #[test]
+    fn test_buybacks_without_no_cut_constraint() {
+        let mut input = base_input();
+        input.no_dividend_cuts = false;
+        let out = optimize_payout_policy(&input).unwrap();
+        let y1 = &find(&out, "All buyback").years[0];
+        assert_eq!(y1.dividends, Decimal::ZERO);
+        assert_eq!(y1.buybacks, dec!(50));
+    }
+
+    #[test]
+    fn test_buybacks_raise_eps() {
+        let mut input = base_input();
+        input.no_dividend_cuts = false;
+        let out = optimize_payout_policy(&input).unwrap();
+        let div = find(&out, "All dividend");
+        let bb = find(&out, "All buyback");
+        assert!(bb.years[2].eps > div.years[2].eps);
+        assert!(bb.terminal_share_price > div.terminal_share_price);
+    }
+
+    #[test]
+    fn test_tax_differential_favours_buybacks() {
+        let mut input = base_input();
+        input.no_dividend_cuts = false;
+        let out = optimize_payout_policy(&input).unwrap();
+        assert_eq!(out.best_alternative.as_deref(), Some("All buyback"));
+    }
+
+    #[test]
+    fn test_no_tax_differential_ranks_consistently() {
+        let mut input = base_input();
+        input.no_dividend_cuts = false;
+        input.dividend_tax_rate = Decimal::ZERO;
+        input.capital_gains_tax_rate = Decimal::ZERO;
+        let out = optimize_payout_policy(&input).unwrap();
+        for a in &out.alternatives {
+            assert!(a.value_per_share > Decimal::ZERO);
+            assert!(a.feasible);
+        }
+    }
+
+    #[test]
+    fn test_capacity_caps_payout() {
+        let mut input = base_input();
+        input.alternatives = vec![alt("Aggressive", dec!(3.0), Decimal::ZERO)];
+        input.no_dividend_cuts = false;
+        let out = optimize_payout_policy(&input).unwrap();
+        let y1 = &out.alternatives[0].years[0];
+        assert_eq!(y1.buybacks, dec!(160));
+        assert_eq!(y1.binding_constraint, "capacity");
+        // Capacity fully used -> leverage exactly at max
+        assert_eq!(y1.net_debt_to_ebitda, dec!(2));
+        assert!(out.alternatives[0].feasible);
+    }
+
+    #[test]
+    fn test_no_cut_violation_when_earnings_collapse() {
+        let mut input = base_input();
+        input.current_dps = dec!(2.00);
+        input.opening_net_debt = dec!(400);
+        input.years = vec![year(dec!(50))];
+        input.alternatives = vec![alt("Hold", dec!(0.5), Decimal::ONE)];
+        let out = optimize_payout_policy(&input).unwrap();
+        let res = &out.alternatives[0];
+        // Floor 200 vs capacity (10 + 0) = 10
+        assert_eq!(res.years[0].dividends, dec!(200));
+        assert_eq!(res.years[0].binding_constraint, "no_cut_floor");
+        assert!(!res.feasible);
+        assert!(res.violations.len() >= 2);
+        assert!(out.best_alternative.is_none());
+    }
+
+    #[test]
+    fn test_dividend_cut_allowed_without_signaling_constraint() {
+        let mut input = base_input();
+        input.no_dividend_cuts = false;
+        input.current_dps = dec!(2.00);
+        input.opening_net_debt = dec!(400);
+        input.years = vec![year(dec!(50))];
+        input.alternatives = vec![alt("Hold", dec!(0.5), Decimal::ONE)];
+        let out = optimize_payout_policy(&input).unwrap();
+        let y1 = &out.alternatives[0].years[0];
+        assert_eq!(y1.dividends, dec!(10));
+        assert_eq!(y1.binding_constraint, "capacity");
+        assert!(out.alternatives[0].feasible);
+    }
+
+    #[test]
+    fn test_dps_never_falls_with_no_cuts() {
+        let mut input = base_input();
+        input.years = vec![year(dec!(120)), year(dec!(80)), year(dec!(100))];
+        let out = optimize_payout_policy(&input).unwrap();
+        let res = find(&out, "All dividend");
+        assert!(res.years[1].dps >= res.years[0].dps);
+        assert!(res.years[2].dps >= res.years[1].dps);
+    }
+
+    #[test]
+    fn test_no_payout_on_losses() {
+        let mut input = base_input();
+        input.no_dividend_cuts = false;
+        input.current_dps = Decimal::ZERO;
+        input.years = vec![PayoutYearInput {
+            net_income: dec!(-20),
+            ebitda: dec!(200),
+            capital_requirement: Decimal::ZERO,
+        }];
+        input.alternatives = vec![alt("Buyback", dec!(0.5), Decimal::ZERO)];
+        let out = optimize_payout_policy(&input).unwrap();
+        let y1 = &out.alternatives[0].years[0];
+        assert_eq!(y1.buybacks, Decimal::ZERO);
+        assert_eq!(y1.shares_repurchased, Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_pv_dividends_per_share() {
+        let mut input = base_input();
+        input.years = vec![year(dec!(100))];
+        input.alternatives = vec![alt("Div", dec!(0.5), Decimal::ONE)];
+        let out = optimize_payout_policy(&input).unwrap();
+        // 0.50 * 0.70 / 1.10
+        assert!(approx_eq(
+            out.alternatives[0].pv_dividends_per_share,
+            dec!(0.318182),
+            dec!(0.0001)
+        ));
+    }
+
+    #[test]
+    fn test_terminal_value_after_capital_gains_tax() {
+        let mut input = base_input();
+        input.years = vec![year(dec!(110))];
+        input.alternatives = vec![alt("Div", dec!(0.5), Decimal::ONE)];
+        let out = optimize_payout_policy(&input).unwrap();
+        let res = &out.alternatives[0];
+        // Terminal 15 * 1.10 = 16.5; gain 1.5 taxed at 15% -> 16.275 / 1.1
+        assert_eq!(res.terminal_share_price, dec!(16.5));
+        assert!(approx_eq(
+            res.pv_terminal_per_share,
+            dec!(14.795455),
+            dec!(0.0001)
+        ));
+    }
+
+    #[test]
+    fn test_max_sustainable_dps() {
+        let mut input = base_input();
+        input.years = vec![year(dec!(100))];
+        // Capacity = 60 + 100 = 160 -> DPS 1.60
+        let out = optimize_payout_policy(&input).unwrap();
+        assert!(approx_eq(out.max_sustainable_dps, dec!(1.60), dec!(0.0001)));
+    }
+
+    #[test]
+    fn test_max_sustainable_dps_multi_year() {
+        let input = base_input();
+        let out = optimize_payout_policy(&input).unwrap();
+        let dps = out.max_sustainable_dps;
+        assert!(flat_dps_feasible(&input, dps));
+        assert!(!flat_dps_feasible(&input, dps + dec!(0.01)));
+    }
+
+    #[test]
+    fn test_max_sustainable_dps_zero_when_overlevered() {
+        let mut input = base_input();
+        input.opening_net_debt = dec!(1000);
+        let out = optimize_payout_policy(&input).unwrap();
+        assert_eq!(out.max_sustainable_dps, Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_totals_sum_years() {
+        let out = optimize_payout_policy(&base_input()).unwrap();
+        let res = find(&out, "Balanced");
+        let div: Decimal = res.years.iter().map(|y| y.dividends).sum();
+        let bb: Decimal = res.years.iter().map(|y| y.buybacks).sum();
+        assert_eq!(res.total_dividends, div);
+        assert_eq!(res.total_buybacks, bb);
+    }
+
+    #[test]
+    fn test_invalid_inputs() {
+        let mut input = base_input();
+        input.years.clear();
+        assert!(optimize_payout_policy(&input).is_err());
+
+        let mut input = base_input();
+        input.alternatives.clear();
+        assert!(optimize_payout_policy(&input).is_err());
+
+        let mut input = base_input();
+        input.alternatives[0].dividend_mix = dec!(1.5);
+        assert!(optimize_payout_policy(&input).is_err());
+
+        let mut input = base_input();
+        input.pe_multiple = Decimal::ZERO;
+        assert!(optimize_payout_policy(&input).is_err());
+    }
+}
@@ -0,0 +1,909 @@
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::time::Instant;
+
+use crate::error::CorpFinanceError;
+use crate::types::*;
+use crate::valuation::comps::{calculate_comps, CompsInput, MultipleType};
+use crate::valuation::dcf::{calculate_dcf, DcfInput};
+use crate::venture::valuation::CapTableEntry;
+use crate::CorpFinanceResult;
+
+// ---------------------------------------------------------------------------
+// Types — Input
+// ---------------------------------------------------------------------------
+
+/// Existing holder selling shares in the offering.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SellingShareholder {
+    /// Must match a name in the pre-IPO cap table.
+    pub name: String,
+    pub shares_sold: Decimal,
+}
+
+/// Lock-up period agreed by a pre-IPO holder.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LockupTerm {
+    /// Must match a name in the pre-IPO cap table.
+    pub name: String,
+    pub lockup_days: u32,
+}
+
+/// A planned use of the company's net primary proceeds.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UseOfProceeds {
+    pub name: String,
+    pub amount: Money,
+}
+
+/// Inputs for IPO valuation and offering structure.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IpoInput {
+    pub company_name: String,
+
+    // --- Valuation triangulation ---
+    /// Trading comparables for the issuer; median implied values are used.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comps: Option<CompsInput>,
+    /// DCF inputs for the issuer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dcf: Option<DcfInput>,
+    /// Weight on the comps value (0..=1); the DCF receives the remainder.
+    /// Defaults to an equal weighting of the methods supplied.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comps_weight: Option<Rate>,
+    /// Pre-IPO net debt, used to bridge EV-based values to equity.
+    pub net_debt: Money,
+    /// Discount to fair value offered to IPO investors (e.g. 0.15).
+    pub ipo_discount: Rate,
+    /// Half-width of the marketing price range around the midpoint (e.g. 0.05).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price_range_pct: Option<Rate>,
+
+    // --- Cap table ---
+    /// Fully diluted pre-IPO cap table (e.g. from the venture module).
+    pub pre_ipo_cap_table: Vec<CapTableEntry>,
+
+    // --- Offering structure ---
+    /// Gross primary proceeds the company targets in the base deal.
+    pub primary_proceeds: Money,
+    #[serde(default)]
+    pub selling_shareholders: Vec<SellingShareholder>,
+    /// Greenshoe as a fraction of base deal shares, issued as primary on exercise.
+    pub greenshoe_pct: Rate,
+    /// Whether the greenshoe is assumed exercised in the post-IPO figures.
+    pub greenshoe_exercised: bool,
+    /// Underwriting gross spread as a fraction of gross proceeds.
+    pub gross_spread_pct: Rate,
+    /// Legal, listing and other offering expenses borne by the company.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub other_expenses: Option<Money>,
+    #[serde(default)]
+    pub use_of_proceeds: Vec<UseOfProceeds>,
+
+    // --- Lock-ups ---
+    #[serde(default)]
+    pub lockups: Vec<LockupTerm>,
+    /// Lock-up applied to holders without a specific term (e.g. 180).
+    pub default_lockup_days: u32,
+}
+
+// ---------------------------------------------------------------------------
+// Types — Output
+// ---------------------------------------------------------------------------
+
+/// Equity value indicated by a single valuation method.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MethodValue {
+    pub method: String,
+    pub equity_value: Money,
+    pub weight: Rate,
+}
+
+/// Fair value triangulation and IPO pricing.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IpoValuation {
+    pub methods: Vec<MethodValue>,
+    /// Weighted fair equity value (pre-money).
+    pub fair_equity_value: Money,
+    /// Pre-money equity value at the IPO price after the discount.
+    pub offer_equity_value: Money,
+    pub fair_value_per_share: Money,
+    pub offer_price: Money,
+    pub price_range_low: Option<Money>,
+    pub price_range_high: Option<Money>,
+}
+
+/// Share and proceeds structure of the offering.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OfferingStructure {
+    pub pre_ipo_shares: Decimal,
+    pub primary_shares: Decimal,
+    pub secondary_shares: Decimal,
+    pub base_deal_shares: Decimal,
+    pub greenshoe_shares: Decimal,
+    /// Shares sold to the public including the greenshoe if exercised.
+    pub total_shares_offered: Decimal,
+    pub post_ipo_shares: Decimal,
+    pub primary_pct_of_deal: Rate,
+    pub free_float_pct: Rate,
+    pub gross_primary_proceeds: Money,
+    pub gross_secondary_proceeds: Money,
+    pub underwriting_fees: Money,
+    pub net_primary_proceeds: Money,
+    pub net_secondary_proceeds: Money,
+    pub post_money_market_cap: Money,
+    /// Post-money market cap plus pre-IPO net debt less net primary proceeds.
+    pub post_money_enterprise_value: Money,
+    /// Ownership dilution of pre-IPO holders from new primary shares.
+    pub dilution_pct: Rate,
+}
+
+/// Allocation of net primary proceeds.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProceedsAllocation {
+    pub name: String,
+    pub amount: Money,
+    pub pct_of_net_proceeds: Rate,
+}
+
+/// One holder's position before and after the IPO.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OwnershipRow {
+    pub name: String,
+    pub pre_ipo_shares: Decimal,
+    pub pre_ipo_pct: Rate,
+    pub shares_sold: Decimal,
+    pub post_ipo_shares: Decimal,
+    pub post_ipo_pct: Rate,
+    pub value_at_offer: Money,
+    /// `None` for the public float, which is freely tradable.
+    pub lockup_days: Option<u32>,
+}
+
+/// Shares released when a lock-up cohort expires.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LockupRelease {
+    pub days_after_ipo: u32,
+    pub shares_released: Decimal,
+    pub cumulative_tradable_shares: Decimal,
+    pub cumulative_float_pct: Rate,
+}
+
+/// Results of the IPO analysis.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IpoOutput {
+    pub valuation: IpoValuation,
+    pub offering: OfferingStructure,
+    pub use_of_proceeds: Vec<ProceedsAllocation>,
+    pub ownership: Vec<OwnershipRow>,
+    pub lockup_schedule: Vec<LockupRelease>,
+}
+
+// ---------------------------------------------------------------------------
+// Core calculation
+// ---------------------------------------------------------------------------
+
+/// Price an IPO from comps and DCF, size the primary / secondary offering and
+/// greenshoe, and build the post-IPO ownership and lock-up tables.
+pub fn analyze_ipo(input: &IpoInput) -> CorpFinanceResult<ComputationOutput<IpoOutput>> {
+    let start = Instant::now();
+    let mut warnings: Vec<String> = Vec::new();
+
+    validate_input(input)?;
+
+    // ------------------------------------------------------------------
+    // 1. Valuation triangulation
+    // ------------------------------------------------------------------
+    let mut methods: Vec<MethodValue> = Vec::new();
+    if let Some(comps) = &input.comps {
+        let out = calculate_comps(comps)?;
+        warnings.extend(out.warnings.into_iter().map(|w| format!("Comps: {w}")));
+        let equity_values: Vec<Money> = out
+            .result
+            .implied_valuations
+            .iter()
+            .map(|iv| match iv.multiple_type {
+                MultipleType::EvEbitda | MultipleType::EvRevenue | MultipleType::EvEbit => {
+                    iv.implied_at_median - input.net_debt
+                }
+                MultipleType::PriceEarnings | MultipleType::PriceBook | MultipleType::Peg => {
+                    iv.implied_at_median
+                }
+            })
+            .collect();
+        if equity_values.is_empty() {
+            warnings.push("Comps produced no implied valuations for the issuer".into());
+        } else {
+            let avg = equity_values.iter().copied().sum::<Decimal>()
+                / Decimal::from(equity_values.len() as u64);
+            methods.push(MethodValue {
+                method: "Trading comparables (median)".into(),
+                equity_value: avg,
+                weight: Decimal::ZERO,
+            });
+        }
+    }
+    if let Some(dcf) = &input.dcf {
+        let out = calculate_dcf(dcf)?;
+        warnings.extend(out.warnings.into_iter().map(|w| format!("DCF: {w}")));
+        let equity = out
+            .result
+            .equity_value
+            .unwrap_or(out.result.enterprise_value - input.net_debt);
+        methods.push(MethodValue {
+            method: "Discounted cash flow".into(),
+            equity_value: equity,
+            weight: Decimal::ZERO,
+        });
+    }
+    if methods.is_empty() {
+        return Err(CorpFinanceError::InsufficientData(
+            "At least one valuation method (comps or DCF) must produce a value".into(),
+        ));
+    }
+
+    match (methods.len(), input.comps_weight) {
+        (2, Some(w)) => {
+            methods[0].weight = w;
+            methods[1].weight = Decimal::ONE - w;
+        }
+        (n, _) => {
+            let w = Decimal::ONE / Decimal::from(n as u64);
+            for m in methods.iter_mut() {
+                m.weight = w;
+            }
+        }
+    }
+
+    let fair_equity_value: Money = methods.iter().map(|m| m.equity_value * m.weight).sum();
+    if fair_equity_value <= Decimal::ZERO {
+        return Err(CorpFinanceError::FinancialImpossibility(
+            "Triangulated equity value is not positive".into(),
+        ));
+    }
+    if methods.len() == 2 {
+        let (a, b) = (methods[0].equity_value, methods[1].equity_value);
+        if a > Decimal::ZERO && b > Decimal::ZERO && (a - b).abs() / a.min(b) > dec!(0.30) {
+            warnings.push("Comps and DCF values differ by more than 30%".into());
+        }
+    }
+
+    let pre_ipo_shares: Decimal = input
+        .pre_ipo_cap_table
+        .iter()
+        .map(|e| Decimal::from(e.shares))
+        .sum();
+    let offer_equity_value = fair_equity_value * (Decimal::ONE - input.ipo_discount);
+    let fair_value_per_share = fair_equity_value / pre_ipo_shares;
+    let offer_price = offer_equity_value / pre_ipo_shares;
+    let (price_range_low, price_range_high) = match input.price_range_pct {
+        Some(pct) => (
+            Some(offer_price * (Decimal::ONE - pct)),
+            Some(offer_price * (Decimal::ONE + pct)),
+        ),
+        None => (None, None),
+    };
+
+    // ------------------------------------------------------------------
+    // 2. Offering structure
+    // ------------------------------------------------------------------
+    let primary_shares = input.primary_proceeds / offer_price;
+    let secondary_shares: Decimal = input
+        .selling_shareholders
+        .iter()
+        .map(|s| s.shares_sold)
+        .sum();
+    let base_deal_shares = primary_shares + secondary_shares;
+    let greenshoe_shares = base_deal_shares * input.greenshoe_pct;
+    let shoe_issued = if input.greenshoe_exercised {
+        greenshoe_shares
+    } else {
+        Decimal::ZERO
+    };
+    let total_shares_offered = base_deal_shares + shoe_issued;
+    let post_ipo_shares = pre_ipo_shares + primary_shares + shoe_issued;
+
+    let gross_primary_proceeds = (primary_shares + shoe_issued) * offer_price;
+    let gross_secondary_proceeds = secondary_shares * offer_price;
+    let underwriting_fees =
+        (gross_primary_proceeds + gross_secondary_proceeds) * input.gross_spread_pct;
+    let other_expenses = input.other_expenses.unwrap_or(Decimal::ZERO);
+    let net_primary_proceeds =
+        gross_primary_proceeds * (Decimal::ONE - input.gross_spread_pct) - other_expenses;
+    let net_secondary_proceeds = gross_secondary_proceeds * (Decimal::ONE - input.gross_spread_pct);
+
+    let post_money_market_cap = post_ipo_shares * offer_price;
+    let post_money_enterprise_value = post_money_market_cap + input.net_debt - net_primary_proceeds;
+    let primary_pct_of_deal = if total_shares_offered > Decimal::ZERO {
+        (primary_shares + shoe_issued) / total_shares_offered
+    } else {
+        Decimal::ZERO
+    };
+    let free_float_pct = total_shares_offered / post_ipo_shares;
+    let dilution_pct = Decimal::ONE - pre_ipo_shares / post_ipo_shares;
+
+    if free_float_pct < dec!(0.10) {
+        warnings.push(format!(
+            "Free float of {:.1}% is below typical 10% listing thresholds",
+            free_float_pct * dec!(100)
+        ));
+    }
+    if net_primary_proceeds < Decimal::ZERO {
+        warnings.push("Offering expenses exceed primary proceeds".into());
+    }
+
+    // ------------------------------------------------------------------
+    // 3. Use of proceeds
+    // ------------------------------------------------------------------
+    let mut use_of_proceeds: Vec<ProceedsAllocation> = Vec::new();
+    let mut allocated = Decimal::ZERO;
+    for u in &input.use_of_proceeds {
+        allocated += u.amount;
+        use_of_proceeds.push(ProceedsAllocation {
+            name: u.name.clone(),
+            amount: u.amount,
+            pct_of_net_proceeds: Decimal::ZERO,
+        });
+    }
+    let remainder = net_primary_proceeds - allocated;
+    if remainder > Decimal::ZERO {
+        use_of_proceeds.push(ProceedsAllocation {
+            name: "General corporate purposes".into(),
+            amount: remainder,
+            pct_of_net_proceeds: Decimal::ZERO,
+        });
+    } else if remainder < Decimal::ZERO {
+        warnings.push(format!(
+            "Planned uses exceed net primary proceeds by {}",
+            -remainder
+        ));
+    }
+    if net_primary_proceeds > Decimal::ZERO {
+        for p in use_of_proceeds.iter_mut() {
+            p.pct_of_net_proceeds = p.amount / net_primary_proceeds;
+        }
+    }
+
+    // ------------------------------------------------------------------
+    // 4. Ownership table
+    // ------------------------------------------------------------------
+    let mut ownership: Vec<OwnershipRow> = Vec::new();
+    for entry in &input.pre_ipo_cap_table {
+        let pre = Decimal::from(entry.shares);
+        let sold: Decimal = input
+            .selling_shareholders
+            .iter()
+            .filter(|s| s.name == entry.name)
+            .map(|s| s.shares_sold)
+            .sum();
+        let post = pre - sold;
+        let lockup = input
+            .lockups
+            .iter()
+            .find(|l| l.name == entry.name)
+            .map(|l| l.lockup_days)
+            .unwrap_or(input.default_lockup_days);
+        ownership.push(OwnershipRow {
+            name: entry.name.clone(),
+            pre_ipo_shares: pre,
+            pre_ipo_pct: pre / pre_ipo_shares,
+            shares_sold: sold,
+            post_ipo_shares: post,
+            post_ipo_pct: post / post_ipo_shares,
+            value_at_offer: post * offer_price,
+            lockup_days: Some(lockup),
+        });
+    }
+    ownership.push(OwnershipRow {
+        name: "Public investors".into(),
+        pre_ipo_shares: Decimal::ZERO,
+        pre_ipo_pct: Decimal::ZERO,
+        shares_sold: Decimal::ZERO,
+        post_ipo_shares: total_shares_offered,
+        post_ipo_pct: free_float_pct,
+        value_at_offer: total_shares_offered * offer_price,
+        lockup_days: None,
+    });
+
+    // ------------------------------------------------------------------
+    // 5. Lock-up expiry schedule
+    // ------------------------------------------------------------------
+    let mut cohorts: BTreeMap<u32, Decimal> = BTreeMap::new();
+    for row in ownership.iter() {
+        if let Some(days) = row.lockup_days {
+            *cohorts.entry(days).or_insert(Decimal::ZERO) += row.post_ipo_shares;
+        }
+    }
+    let mut cumulative = total_shares_offered;
+    let lockup_schedule: Vec<LockupRelease> = cohorts
+        .into_iter()
+        .map(|(days, shares)| {
+            cumulative += shares;
+            LockupRelease {
+                days_after_ipo: days,
+                shares_released: shares,
+                cumulative_tradable_shares: cumulative,
+                cumulative_float_pct: cumulative / post_ipo_shares,
+            }
+        })
+        .collect();
+
+    let output = IpoOutput {
+        valuation: IpoValuation {
+            methods,
+            fair_equity_value,
+            offer_equity_value,
+            fair_value_per_share,
+            offer_price,
+            price_range_low,
+            price_range_high,
+        },
+        offering: OfferingStructure {
+            pre_ipo_shares,
+            primary_shares,
+            secondary_shares,
+            base_deal_shares,
+            greenshoe_shares,
+            total_shares_offered,
+            post_ipo_shares,
+            primary_pct_of_deal,
+            free_float_pct,
+            gross_primary_proceeds,
+            gross_secondary_proceeds,
+            underwriting_fees,
+            net_primary_proceeds,
+            net_secondary_proceeds,
+            post_money_market_cap,
+            post_money_enterprise_value,
+            dilution_pct,
+        },
+        use_of_proceeds,
+        ownership,
+        lockup_schedule,
+    };
+
+    let elapsed = start.elapsed().as_micros() as u64;
+    Ok(with_metadata(
+        "IPO Valuation Triangulation & Offering Structure",
+        &serde_json::json!({
+            "company": input.company_name,
+            "ipo_discount": input.ipo_discount.to_string(),
+            "greenshoe_pct": input.greenshoe_pct.to_string(),
+            "greenshoe_exercised": input.greenshoe_exercised,
+            "gross_spread_pct": input.gross_spread_pct.to_string(),
+        }),
+        warnings,
+        elapsed,
+        output,
+    ))
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+fn validate_input(input: &IpoInput) -> CorpFinanceResult<()> {
+    if input.pre_ipo_cap_table.is_empty() {
+        return Err(CorpFinanceError::InsufficientData(
+            "Pre-IPO cap table is required".into(),
+        ));
+    }
+    if input.pre_ipo_cap_table.iter().all(|e| e.shares == 0) {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "pre_ipo_cap_table".into(),
+            reason: "Pre-IPO shares must be positive".into(),
+        });
+    }
+    if input.ipo_discount < Decimal::ZERO || input.ipo_discount >= Decimal::ONE {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "ipo_discount".into(),
+            reason: "IPO discount must be in [0, 1)".into(),
+        });
+    }
+    if let Some(w) = input.comps_weight {
+        if w < Decimal::ZERO || w > Decimal::ONE {
+            return Err(CorpFinanceError::InvalidInput {
+                field: "comps_weight".into(),
+                reason: "Comps weight must be between 0 and 1".into(),
+            });
+        }
+    }
+    if input.primary_proceeds < Decimal::ZERO {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "primary_proceeds".into(),
+            reason: "Primary proceeds cannot be negative".into(),
+        });
+    }
+    if input.greenshoe_pct < Decimal::ZERO || input.greenshoe_pct > dec!(0.15) {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "greenshoe_pct".into(),
+            reason: "Greenshoe must be between 0 and 15% of the base deal".into(),
+        });
+    }
+    if input.gross_spread_pct < Decimal::ZERO || input.gross_spread_pct >= Decimal::ONE {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "gross_spread_pct".into(),
+            reason: "Gross spread must be in [0, 1)".into(),
+        });
+    }
+    for seller in &input.selling_shareholders {
+        let holder = input
+            .pre_ipo_cap_table
+            .iter()
+            .find(|e| e.name == seller.name)
+            .ok_or_else(|| CorpFinanceError::InvalidInput {
+                field: "selling_shareholders".into(),
+                reason: format!("Seller '{}' is not in the pre-IPO cap table", seller.name),
+            })?;
+        if seller.shares_sold < Decimal::ZERO || seller.shares_sold > Decimal::from(holder.shares) {
+            return Err(CorpFinanceError::InvalidInput {
+                field: "selling_shareholders".into(),
+                reason: format!("Seller '{}' cannot sell more shares than held", seller.name),
+            });
+        }
+    }
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::valuation::comps::{CompanyMetrics, ComparableCompany};
+    use crate::valuation::dcf::TerminalMethod;
+
+    fn holder(name: &str, shares: u64) -> CapTableEntry {
+        CapTableEntry {
+            name: name.into(),
+            shares,
+            ownership_pct: Decimal::ZERO,
+            value_at_post_money: Decimal::ZERO,
+        }
+    }
+
+    fn empty_metrics() -> CompanyMetrics {
+        CompanyMetrics {
+            enterprise_value: None,
+            market_cap: None,
+            revenue: None,
+            ebitda: None,
+            ebit: None,
+            net_income: None,
+            book_value: None,
+            eps: None,
+            eps_growth_rate: None,
+            share_price: None,
+            basic_shares: None,
+            dilutive_securities: vec![],
+            kpis: Default::default(),
+        }
+    }
+
+    fn peer(name: &str, ev: Decimal, ebitda: Decimal) -> ComparableCompany {
+        let mut m = empty_metrics();
+        m.enterprise_value = Some(ev);
+        m.ebitda = Some(ebitda);
+        ComparableCompany {
+            name: name.into(),
+            metrics: m,
+            include: true,
+        }
+    }
+
+    fn comps() -> CompsInput {
+        let mut target = empty_metrics();
+        target.ebitda = Some(dec!(100));
+        CompsInput {
+            target_name: "IssuerCo".into(),
+            target_metrics: target,
+            comparables: vec![
+                peer("A", dec!(1000), dec!(100)),
+                peer("B", dec!(1200), dec!(100)),
+                peer("C", dec!(1400), dec!(100)),
+            ],
+            multiples: vec![MultipleType::EvEbitda],
+            currency: Currency::USD,
+            kpi_multiples: vec![],
+            kpi_regression: None,
+        }
+    }
+
+    fn base_input() -> IpoInput {
+        IpoInput {
+            company_name: "IssuerCo".into(),
+            comps: Some(comps()),
+            dcf: None,
+            comps_weight: None,
+            net_debt: dec!(200),
+            ipo_discount: dec!(0.15),
+            price_range_pct: Some(dec!(0.05)),
+            pre_ipo_cap_table: vec![
+                holder("Founders", 40),
+                holder("VC Fund", 50),
+                holder("ESOP", 10),
+            ],
+            primary_proceeds: dec!(170),
+            selling_shareholders: vec![SellingShareholder {
+                name: "VC Fund".into(),
+                shares_sold: dec!(10),
+            }],
+            greenshoe_pct: dec!(0.15),
+            greenshoe_exercised: false,
+            gross_spread_pct: dec!(0.07),
+            other_expenses: Some(dec!(5)),
+            use_of_proceeds: vec![UseOfProceeds {
+                name: "Debt repayment".into(),
+                amount: dec!(100),
+            }],
+            lockups: vec![LockupTerm {
+                name: "ESOP".into(),
+                lockup_days: 90,
+            }],
+            default_lockup_days: 180,
+        }
+    }
+
+    fn approx(a: Decimal, b: Decimal, tol: Decimal) -> bool {
+        (a - b).abs() <= tol
+    }
+
+    #[test]
+    fn test_comps_valuation_bridges_to_equity() {
+        let out = analyze_ipo(&base_input()).unwrap().result;
+        // Median EV/EBITDA 12x * 100 = 1200 EV less 200 net debt = 1000
+        assert_eq!(out.valuation.methods.len(), 1);
+        assert_eq!(out.valuation.methods[0].equity_value, dec!(1000));
+        assert_eq!(out.valuation.fair_equity_value, dec!(1000));
+    }
+
+    #[test]
+    fn test_ipo_discount_sets_offer_price() {
+        let out = analyze_ipo(&base_input()).unwrap().result;
+        assert_eq!(out.valuation.offer_equity_value, dec!(850));
+        assert_eq!(out.valuation.fair_value_per_share, dec!(10));
+        assert_eq!(out.valuation.offer_price, dec!(8.5));
+    }
+
+    #[test]
+    fn test_price_range() {
+        let out = analyze_ipo(&base_input()).unwrap().result;
+        assert_eq!(out.valuation.price_range_low, Some(dec!(8.075)));
+        assert_eq!(out.valuation.price_range_high, Some(dec!(8.925)));
+    }
+
+    #[test]
+    fn test_primary_secondary_split() {
+        let out = analyze_ipo(&base_input()).unwrap().result;
+        let o = &out.offering;
+        assert_eq!(o.primary_shares, dec!(20));
+        assert_eq!(o.secondary_shares, dec!(10));
+        assert_eq!(o.base_deal_shares, dec!(30));
+        assert_eq!(o.greenshoe_shares, dec!(4.5));
+        assert_eq!(o.total_shares_offered, dec!(30));
+        assert_eq!(o.post_ipo_shares, dec!(120));
+    }
+
+    #[test]
+    fn test_greenshoe_exercised_adds_primary() {
+        let mut input = base_input();
+        input.greenshoe_exercised = true;
+        let out = analyze_ipo(&input).unwrap().result;
+        let o = &out.offering;
+        assert_eq!(o.total_shares_offered, dec!(34.5));
+        assert_eq!(o.post_ipo_shares, dec!(124.5));
+        assert_eq!(o.gross_primary_proceeds, dec!(24.5) * dec!(8.5));
+    }
+
+    #[test]
+    fn test_proceeds_and_fees() {
+        let out = analyze_ipo(&base_input()).unwrap().result;
+        let o = &out.offering;
+        assert_eq!(o.gross_primary_proceeds, dec!(170));
+        assert_eq!(o.gross_secondary_proceeds, dec!(85));
+        assert_eq!(o.underwriting_fees, dec!(17.85));
+        // 170 * 0.93 - 5 = 153.1
+        assert_eq!(o.net_primary_proceeds, dec!(153.1));
+        assert_eq!(o.net_secondary_proceeds, dec!(79.05));
+    }
+
+    #[test]
+    fn test_post_money_and_dilution() {
+        let out = analyze_ipo(&base_input()).unwrap().result;
+        let o = &out.offering;
+        assert_eq!(o.post_money_market_cap, dec!(1020));
+        assert_eq!(
+            o.post_money_enterprise_value,
+            dec!(1020) + dec!(200) - dec!(153.1)
+        );
+        assert!(approx(
+            o.dilution_pct,
+            dec!(20) / dec!(120),
+            dec!(0.0000001)
+        ));
+        assert!(approx(o.free_float_pct, dec!(0.25), dec!(0.0000001)));
+        assert!(approx(
+            o.primary_pct_of_deal,
+            dec!(20) / dec!(30),
+            dec!(0.0000001)
+        ));
+    }
+
+    #[test]
+    fn test_use_of_proceeds_remainder() {
+        let out = analyze_ipo(&base_input()).unwrap().result;
+        assert_eq!(out.use_of_proceeds.len(), 2);
+        assert_eq!(out.use_of_proceeds[1].name, "General corporate purposes");
+        assert_eq!(out.use_of_proceeds[1].amount, dec!(53.1));
+        let total: Decimal = out
+            .use_of_proceeds
+            .iter()
+            .map(|p| p.pct_of_net_proceeds)
+            .sum();
+        assert!(approx(total, Decimal::ONE, dec!(0.0000001)));
+    }
+
+    #[test]
+    fn test_overallocated_proceeds_warns() {
+        let mut input = base_input();
+        input.use_of_proceeds[0].amount = dec!(200);
+        let result = analyze_ipo(&input).unwrap();
+        assert!(result
+            .warnings
+            .iter()
+            .any(|w| w.contains("exceed net primary")));
+        assert_eq!(result.result.use_of_proceeds.len(), 1);
+    }
+
+    #[test]
+    fn test_ownership_table() {
+        let out = analyze_ipo(&base_input()).unwrap().result;
+        assert_eq!(out.ownership.len(), 4);
+        let vc = &out.ownership[1];
+        assert_eq!(vc.pre_ipo_pct, dec!(0.5));
+        assert_eq!(vc.shares_sold, dec!(10));
+        assert_eq!(vc.post_ipo_shares, dec!(40));
+        assert!(approx(
+            vc.post_ipo_pct,
+            dec!(40) / dec!(120),
+            dec!(0.0000001)
+        ));
+        assert_eq!(vc.value_at_offer, dec!(340));
+        let public = out.ownership.last().unwrap();
+        assert_eq!(public.post_ipo_shares, dec!(30));
+        assert!(public.lockup_days.is_none());
+        let pct: Decimal = out.ownership.iter().map(|r| r.post_ipo_pct).sum();
+        assert!(approx(pct, Decimal::ONE, dec!(0.0000001)));
+    }
+
+    #[test]
+    fn test_lockup_schedule() {
+        let out = analyze_ipo(&base_input()).unwrap().result;
+        assert_eq!(out.lockup_schedule.len(), 2);
+        let first = &out.lockup_schedule[0];
+        assert_eq!(first.days_after_ipo, 90);
+        assert_eq!(first.shares_released, dec!(10));
+        assert_eq!(first.cumulative_tradable_shares, dec!(40));
+        let last = &out.lockup_schedule[1];
+        assert_eq!(last.days_after_ipo, 180);
+        assert_eq!(last.shares_released, dec!(80));
+        assert_eq!(last.cumulative_tradable_shares, dec!(120));
+        assert_eq!(last.cumulative_float_pct, Decimal::ONE);
+    }
+
+    fn dcf_input() -> DcfInput {
+        DcfInput {
+            base_revenue: dec!(500),
+            revenue_growth_rates: vec![dec!(0.10); 5],
+            ebitda_margin: dec!(0.20),
+            ebit_margin: None,
+            da_as_pct_revenue: Some(dec!(0.03)),
+            capex_as_pct_revenue: dec!(0.04),
+            nwc_as_pct_revenue: dec!(0.01),
+            tax_rate: dec!(0.25),
+            wacc: dec!(0.09),
+            wacc_input: None,
+            terminal_method: TerminalMethod::GordonGrowth,
+            terminal_growth_rate: Some(dec!(0.03)),
+            terminal_exit_multiple: None,
+            terminal_ronic: None,
+            currency: Currency::USD,
+            forecast_years: None,
+            mid_year_convention: Some(false),
+            net_debt: Some(dec!(200)),
+            minority_interest: None,
+            shares_outstanding: None,
+            presentation_currency: None,
+            fx_rates: vec![],
+        }
+    }
+
+    #[test]
+    fn test_dcf_and_comps_weighting() {
+        let mut input = base_input();
+        input.dcf = Some(dcf_input());
+        input.comps_weight = Some(dec!(0.75));
+        let out = analyze_ipo(&input).unwrap().result;
+        assert_eq!(out.valuation.methods.len(), 2);
+        let dcf_value = out.valuation.methods[1].equity_value;
+        let expected = dec!(1000) * dec!(0.75) + dcf_value * dec!(0.25);
+        assert!(approx(
+            out.valuation.fair_equity_value,
+            expected,
+            dec!(0.000001)
+        ));
+    }
+
+    #[test]
+    fn test_equal_weighting_by_default() {
+        let mut input = base_input();
+        input.dcf = Some(dcf_input());
+        let out = analyze_ipo(&input).unwrap().result;
+        assert_eq!(out.valuation.methods[0].weight, dec!(0.5));
+        assert_eq!(out.valuation.methods[1].weight, dec!(0.5));
+    }
+
+    #[test]
+    fn test_dcf_only() {
+        let mut input = base_input();
+        input.comps = None;
+        input.dcf = Some(dcf_input());
+        let out = analyze_ipo(&input).unwrap().result;
+        assert_eq!(out.valuation.methods.len(), 1);
+        assert_eq!(out.valuation.methods[0].weight, Decimal::ONE);
+    }
+
+    #[test]
+    fn test_no_valuation_method_rejected() {
+        let mut input = base_input();
+        input.comps = None;
+        assert!(matches!(
+            analyze_ipo(&input),
+            Err(CorpFinanceError::InsufficientData(_))
+        ));
+    }
+
+    #[test]
+    fn test_unknown_seller_rejected() {
+        let mut input = base_input();
+        input.selling_shareholders[0].name = "Unknown".into();
+        assert!(analyze_ipo(&input).is_err());
+    }
+
+    #[test]
+    fn test_seller_oversell_rejected() {
+        let mut input = base_input();
+        input.selling_shareholders[0].shares_sold = dec!(60);
+        assert!(analyze_ipo(&input).is_err());
+    }
+
+    #[test]
+    fn test_invalid_discount_and_greenshoe() {
+        let mut input = base_input();
+        input.ipo_discount = Decimal::ONE;
+        assert!(analyze_ipo(&input).is_err());
+        let mut input = base_input();
+        input.greenshoe_pct = dec!(0.20);
+        assert!(analyze_ipo(&input).is_err());
+    }
+
+    #[test]
+    fn test_low_float_warns() {
+        let mut input = base_input();
+        input.primary_proceeds = dec!(17);
+        input.selling_shareholders.clear();
+        let result = analyze_ipo(&input).unwrap();
+        assert!(result.warnings.iter().any(|w| w.contains("Free float")));
+    }
+
+    #[test]
+    fn test_methodology() {
+        let result = analyze_ipo(&base_input()).unwrap();
+        assert!(result.methodology.contains("IPO"));
+    }
+}
@@ -0,0 +1,2 @@
+pub mod ipo;
+pub mod rights_issue;
@@ -0,0 +1,545 @@
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+use std::time::Instant;
+
+use crate::error::CorpFinanceError;
+use crate::types::*;
+use crate::CorpFinanceResult;
+
+// ---------------------------------------------------------------------------
+// Types — Input
+// ---------------------------------------------------------------------------
+
+/// Inputs for a rights offering.
+///
+/// The subscription ratio is expressed as `new_shares` for every
+/// `existing_shares` held (e.g. 1 for 4).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RightsIssueInput {
+    pub company_name: String,
+    pub shares_outstanding: Decimal,
+    /// Share price cum-rights (before the shares go ex-rights).
+    pub cum_rights_price: Money,
+    pub subscription_price: Money,
+    pub new_shares: Decimal,
+    pub existing_shares: Decimal,
+    /// Underwriting commission as a fraction of gross proceeds.
+    pub underwriting_fee_pct: Rate,
+    /// Legal, listing and other expenses borne by the company.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub other_expenses: Option<Money>,
+    /// Expected fraction of rights taken up by shareholders; the underwriters
+    /// take up the rest. Defaults to 1.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expected_take_up: Option<Rate>,
+    /// Shares held by an illustrative shareholder for the dilution analysis.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shareholder_shares: Option<Decimal>,
+    /// Alternative subscription prices raising the same gross proceeds.
+    #[serde(default)]
+    pub alternative_prices: Vec<Money>,
+}
+
+// ---------------------------------------------------------------------------
+// Types — Output
+// ---------------------------------------------------------------------------
+
+/// Position of one shareholder under each response to the offer.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShareholderOutcome {
+    pub shares_held: Decimal,
+    pub rights_received: Decimal,
+    pub ownership_before: Rate,
+    /// Ownership if the shareholder does not take up the rights.
+    pub ownership_if_not_participating: Rate,
+    /// Ownership dilution from not participating.
+    pub dilution_pct: Rate,
+    /// Holding value cum-rights.
+    pub wealth_before: Money,
+    /// Cash paid to subscribe in full.
+    pub subscription_cost: Money,
+    /// Net wealth change when taking up all rights.
+    pub wealth_change_take_up: Money,
+    /// Net wealth change when selling all rights nil-paid.
+    pub wealth_change_sell_rights: Money,
+    /// Net wealth change when letting the rights lapse uncompensated.
+    pub wealth_change_lapse: Money,
+}
+
+/// Underwriting economics of the offer.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UnderwritingEconomics {
+    pub underwriting_fee: Money,
+    pub other_expenses: Money,
+    pub net_proceeds: Money,
+    /// Total costs as a fraction of gross proceeds.
+    pub total_cost_pct: Rate,
+    /// Shares the underwriters take up at the expected take-up.
+    pub shortfall_shares: Decimal,
+    /// Capital the underwriters commit to the shortfall.
+    pub underwriter_commitment: Money,
+    /// Fee as a fraction of the capital actually committed.
+    pub fee_on_commitment: Option<Rate>,
+    /// Fall in the share price from TERP before the underwriters lose money
+    /// on shortfall shares (ignoring fees).
+    pub price_cushion_pct: Rate,
+}
+
+/// Alternative pricing raising the same gross proceeds.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SubscriptionScenario {
+    pub subscription_price: Money,
+    pub new_shares: Decimal,
+    /// New shares per existing share.
+    pub ratio: Decimal,
+    pub terp: Money,
+    pub discount_to_terp: Rate,
+    pub value_per_right: Money,
+    pub dilution_if_not_participating: Rate,
+}
+
+/// Results of the rights issue analysis.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RightsIssueOutput {
+    pub new_shares_issued: Decimal,
+    pub post_issue_shares: Decimal,
+    pub gross_proceeds: Money,
+    /// Theoretical ex-rights price.
+    pub terp: Money,
+    /// Value of one nil-paid right (entitlement to one new share): TERP - subscription price.
+    pub value_per_right: Money,
+    /// Value of the rights attached to one existing share: cum price - TERP.
+    pub value_per_existing_share: Money,
+    pub discount_to_cum_price: Rate,
+    pub discount_to_terp: Rate,
+    /// TERP / cum-rights price; applied to restate historical per-share data.
+    pub adjustment_factor: Decimal,
+    /// Ownership dilution for a shareholder who does not participate.
+    pub dilution_if_not_participating: Rate,
+    pub underwriting: UnderwritingEconomics,
+    pub shareholder: Option<ShareholderOutcome>,
+    pub scenarios: Vec<SubscriptionScenario>,
+}
+
+// ---------------------------------------------------------------------------
+// Core calculation
+// ---------------------------------------------------------------------------
+
+/// Compute TERP, right values, non-participation dilution and underwriting
+/// economics for a rights offering.
+pub fn analyze_rights_issue(
+    input: &RightsIssueInput,
+) -> CorpFinanceResult<ComputationOutput<RightsIssueOutput>> {
+    let start = Instant::now();
+    let mut warnings: Vec<String> = Vec::new();
+
+    validate_input(input)?;
+
+    let ratio = input.new_shares / input.existing_shares;
+    let new_shares_issued = input.shares_outstanding * ratio;
+    let post_issue_shares = input.shares_outstanding + new_shares_issued;
+    let gross_proceeds = new_shares_issued * input.subscription_price;
+
+    // ------------------------------------------------------------------
+    // 1. TERP and right values
+    // ------------------------------------------------------------------
+    let terp = terp_value(input.cum_rights_price, input.subscription_price, ratio);
+    let value_per_right = terp - input.subscription_price;
+    let value_per_existing_share = input.cum_rights_price - terp;
+    let discount_to_cum_price = Decimal::ONE - input.subscription_price / input.cum_rights_price;
+    let discount_to_terp = Decimal::ONE - input.subscription_price / terp;
+    let adjustment_factor = terp / input.cum_rights_price;
+    let dilution_if_not_participating = new_shares_issued / post_issue_shares;
+
+    if input.subscription_price >= input.cum_rights_price {
+        warnings.push(
+            "Subscription price is at or above the market price; rights have no value and \
+             take-up is unlikely"
+                .into(),
+        );
+    } else if discount_to_terp > dec!(0.50) {
+        warnings.push("Deep discount rights issue (more than 50% to TERP)".into());
+    }
+
+    // ------------------------------------------------------------------
+    // 2. Underwriting economics
+    // ------------------------------------------------------------------
+    let take_up = input.expected_take_up.unwrap_or(Decimal::ONE);
+    let underwriting_fee = gross_proceeds * input.underwriting_fee_pct;
+    let other_expenses = input.other_expenses.unwrap_or(Decimal::ZERO);
+    let net_proceeds = gross_proceeds - underwriting_fee - other_expenses;
+    let total_cost_pct = if gross_proceeds > Decimal::ZERO {
+        (underwriting_fee + other_expenses) / gross_proceeds
+    } else {
+        Decimal::ZERO
+    };
+    let shortfall_shares = new_shares_issued * (Decimal::ONE - take_up);
+    let underwriter_commitment = shortfall_shares * input.subscription_price;
+    let fee_on_commitment = if underwriter_commitment > Decimal::ZERO {
+        Some(underwriting_fee / underwriter_commitment)
+    } else {
+        None
+    };
+    let price_cushion_pct = discount_to_terp;
+
+    // ------------------------------------------------------------------
+    // 3. Illustrative shareholder
+    // ------------------------------------------------------------------
+    let shareholder = input.shareholder_shares.map(|held| {
+        let rights_received = held * ratio;
+        let wealth_before = held * input.cum_rights_price;
+        let subscription_cost = rights_received * input.subscription_price;
+        let rights_value = rights_received * value_per_right;
+        ShareholderOutcome {
+            shares_held: held,
+            rights_received,
+            ownership_before: held / input.shares_outstanding,
+            ownership_if_not_participating: held / post_issue_shares,
+            dilution_pct: dilution_if_not_participating,
+            wealth_before,
+            subscription_cost,
+            wealth_change_take_up: (held + rights_received) * terp
+                - subscription_cost
+                - wealth_before,
+            wealth_change_sell_rights: held * terp + rights_value - wealth_before,
+            wealth_change_lapse: held * terp - wealth_before,
+        }
+    });
+
+    // ------------------------------------------------------------------
+    // 4. Alternative subscription prices at constant proceeds
+    // ------------------------------------------------------------------
+    let mut scenarios: Vec<SubscriptionScenario> = Vec::new();
+    for &price in &input.alternative_prices {
+        if price <= Decimal::ZERO {
+            warnings.push(format!(
+                "Alternative price {price} ignored: must be positive"
+            ));
+            continue;
+        }
+        let alt_new = gross_proceeds / price;
+        let alt_ratio = alt_new / input.shares_outstanding;
+        let alt_terp = terp_value(input.cum_rights_price, price, alt_ratio);
+        scenarios.push(SubscriptionScenario {
+            subscription_price: price,
+            new_shares: alt_new,
+            ratio: alt_ratio,
+            terp: alt_terp,
+            discount_to_terp: Decimal::ONE - price / alt_terp,
+            value_per_right: alt_terp - price,
+            dilution_if_not_participating: alt_new / (input.shares_outstanding + alt_new),
+        });
+    }
+
+    let output = RightsIssueOutput {
+        new_shares_issued,
+        post_issue_shares,
+        gross_proceeds,
+        terp,
+        value_per_right,
+        value_per_existing_share,
+        discount_to_cum_price,
+        discount_to_terp,
+        adjustment_factor,
+        dilution_if_not_participating,
+        underwriting: UnderwritingEconomics {
+            underwriting_fee,
+            other_expenses,
+            net_proceeds,
+            total_cost_pct,
+            shortfall_shares,
+            underwriter_commitment,
+            fee_on_commitment,
+            price_cushion_pct,
+        },
+        shareholder,
+        scenarios,
+    };
+
+    let elapsed = start.elapsed().as_micros() as u64;
+    Ok(with_metadata(
+        "Rights Issue — TERP, Right Value and Underwriting Economics",
+        &serde_json::json!({
+            "company": input.company_name,
+            "ratio": format!("{} for {}", input.new_shares, input.existing_shares),
+            "subscription_price": input.subscription_price.to_string(),
+            "expected_take_up": take_up.to_string(),
+        }),
+        warnings,
+        elapsed,
+        output,
+    ))
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// TERP = (P_cum + ratio * S) / (1 + ratio), with ratio = new shares per existing share.
+fn terp_value(cum_price: Money, subscription_price: Money, ratio: Decimal) -> Money {
+    (cum_price + ratio * subscription_price) / (Decimal::ONE + ratio)
+}
+
+fn validate_input(input: &RightsIssueInput) -> CorpFinanceResult<()> {
+    if input.shares_outstanding <= Decimal::ZERO {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "shares_outstanding".into(),
+            reason: "Shares outstanding must be positive".into(),
+        });
+    }
+    if input.cum_rights_price <= Decimal::ZERO {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "cum_rights_price".into(),
+            reason: "Cum-rights price must be positive".into(),
+        });
+    }
+    if input.subscription_price <= Decimal::ZERO {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "subscription_price".into(),
+            reason: "Subscription price must be positive".into(),
+        });
+    }
+    if input.new_shares <= Decimal::ZERO || input.existing_shares <= Decimal::ZERO {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "new_shares".into(),
+            reason: "Subscription ratio terms must be positive".into(),
+        });
+    }
+    if input.underwriting_fee_pct < Decimal::ZERO || input.underwriting_fee_pct >= Decimal::ONE {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "underwriting_fee_pct".into(),
+            reason: "Underwriting fee must be in [0, 1)".into(),
+        });
+    }
+    if let Some(t) = input.expected_take_up {
+        if t < Decimal::ZERO || t > Decimal::ONE {
+            return Err(CorpFinanceError::InvalidInput {
+                field: "expected_take_up".into(),
+                reason: "Take-up must be between 0 and 1".into(),
+            });
+        }
+    }
+    if let Some(h) = input.shareholder_shares {
+        if h < Decimal::ZERO || h > input.shares_outstanding {
+            return Err(CorpFinanceError::InvalidInput {
+                field: "shareholder_shares".into(),
+                reason: "Shareholder holding must be between 0 and shares outstanding".into(),
+            });
+        }
+    }
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 1-for-4 at 4.00 with the shares at 6.00 cum-rights.
+    fn base_input() -> RightsIssueInput {
+        RightsIssueInput {
+            company_name: "RightsCo".into(),
+            shares_outstanding: dec!(100_000_000),
+            cum_rights_price: dec!(6),
+            subscription_price: dec!(4),
+            new_shares: dec!(1),
+            existing_shares: dec!(4),
+            underwriting_fee_pct: dec!(0.025),
+            other_expenses: Some(dec!(1_000_000)),
+            expected_take_up: Some(dec!(0.90)),
+            shareholder_shares: Some(dec!(1_000)),
+            alternative_prices: vec![],
+        }
+    }
+
+    fn approx(a: Decimal, b: Decimal, tol: Decimal) -> bool {
+        (a - b).abs() <= tol
+    }
+
+    #[test]
+    fn test_new_shares_and_proceeds() {
+        let out = analyze_rights_issue(&base_input()).unwrap().result;
+        assert_eq!(out.new_shares_issued, dec!(25_000_000));
+        assert_eq!(out.post_issue_shares, dec!(125_000_000));
+        assert_eq!(out.gross_proceeds, dec!(100_000_000));
+    }
+
+    #[test]
+    fn test_terp() {
+        let out = analyze_rights_issue(&base_input()).unwrap().result;
+        // (4 * 6 + 1 * 4) / 5 = 5.60
+        assert_eq!(out.terp, dec!(5.6));
+    }
+
+    #[test]
+    fn test_right_values() {
+        let out = analyze_rights_issue(&base_input()).unwrap().result;
+        assert_eq!(out.value_per_right, dec!(1.6));
+        assert_eq!(out.value_per_existing_share, dec!(0.4));
+        // Rights per existing share (0.25) times right value equals cum - TERP
+        assert_eq!(
+            out.value_per_right * dec!(0.25),
+            out.value_per_existing_share
+        );
+    }
+
+    #[test]
+    fn test_discounts_and_adjustment_factor() {
+        let out = analyze_rights_issue(&base_input()).unwrap().result;
+        assert!(approx(
+            out.discount_to_cum_price,
+            dec!(1) / dec!(3),
+            dec!(0.0000001)
+        ));
+        assert!(approx(
+            out.discount_to_terp,
+            dec!(1.6) / dec!(5.6),
+            dec!(0.0000001)
+        ));
+        assert!(approx(
+            out.adjustment_factor,
+            dec!(5.6) / dec!(6),
+            dec!(0.0000001)
+        ));
+    }
+
+    #[test]
+    fn test_non_participation_dilution() {
+        let out = analyze_rights_issue(&base_input()).unwrap().result;
+        assert_eq!(out.dilution_if_not_participating, dec!(0.2));
+        let sh = out.shareholder.unwrap();
+        assert_eq!(sh.rights_received, dec!(250));
+        assert_eq!(sh.ownership_before, dec!(0.00001));
+        assert_eq!(sh.ownership_if_not_participating, dec!(0.000008));
+    }
+
+    #[test]
+    fn test_take_up_and_sell_rights_preserve_wealth() {
+        let sh = analyze_rights_issue(&base_input())
+            .unwrap()
+            .result
+            .shareholder
+            .unwrap();
+        assert_eq!(sh.wealth_before, dec!(6000));
+        assert_eq!(sh.subscription_cost, dec!(1000));
+        assert_eq!(sh.wealth_change_take_up, Decimal::ZERO);
+        assert_eq!(sh.wealth_change_sell_rights, Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_lapse_loses_right_value() {
+        let sh = analyze_rights_issue(&base_input())
+            .unwrap()
+            .result
+            .shareholder
+            .unwrap();
+        // 1000 * (5.6 - 6) = -400 = 250 rights * 1.6
+        assert_eq!(sh.wealth_change_lapse, dec!(-400));
+    }
+
+    #[test]
+    fn test_underwriting_economics() {
+        let out = analyze_rights_issue(&base_input()).unwrap().result;
+        let u = &out.underwriting;
+        assert_eq!(u.underwriting_fee, dec!(2_500_000));
+        assert_eq!(u.net_proceeds, dec!(96_500_000));
+        assert_eq!(u.total_cost_pct, dec!(0.035));
+        assert_eq!(u.shortfall_shares, dec!(2_500_000));
+        assert_eq!(u.underwriter_commitment, dec!(10_000_000));
+        assert_eq!(u.fee_on_commitment, Some(dec!(0.25)));
+        assert_eq!(u.price_cushion_pct, out.discount_to_terp);
+    }
+
+    #[test]
+    fn test_full_take_up_no_commitment() {
+        let mut input = base_input();
+        input.expected_take_up = None;
+        let out = analyze_rights_issue(&input).unwrap().result;
+        assert_eq!(out.underwriting.shortfall_shares, Decimal::ZERO);
+        assert!(out.underwriting.fee_on_commitment.is_none());
+    }
+
+    #[test]
+    fn test_alternative_prices_same_proceeds() {
+        let mut input = base_input();
+        input.alternative_prices = vec![dec!(2), dec!(5)];
+        let out = analyze_rights_issue(&input).unwrap().result;
+        assert_eq!(out.scenarios.len(), 2);
+        let deep = &out.scenarios[0];
+        assert_eq!(deep.new_shares, dec!(50_000_000));
+        assert_eq!(deep.ratio, dec!(0.5));
+        // (6 + 0.5 * 2) / 1.5
+        assert!(approx(deep.terp, dec!(7) / dec!(1.5), dec!(0.0000001)));
+        let shallow = &out.scenarios[1];
+        assert!(shallow.dilution_if_not_participating < deep.dilution_if_not_participating);
+        assert!(shallow.discount_to_terp < deep.discount_to_terp);
+    }
+
+    #[test]
+    fn test_scenarios_preserve_market_value() {
+        let mut input = base_input();
+        input.alternative_prices = vec![dec!(3)];
+        let out = analyze_rights_issue(&input).unwrap().result;
+        let s = &out.scenarios[0];
+        // Post-issue market cap = pre market cap + proceeds regardless of price
+        let cap = (dec!(100_000_000) + s.new_shares) * s.terp;
+        assert!(approx(cap, dec!(700_000_000), dec!(0.01)));
+    }
+
+    #[test]
+    fn test_invalid_alternative_price_warns() {
+        let mut input = base_input();
+        input.alternative_prices = vec![Decimal::ZERO];
+        let result = analyze_rights_issue(&input).unwrap();
+        assert!(result.result.scenarios.is_empty());
+        assert!(result.warnings.iter().any(|w| w.contains("ignored")));
+    }
+
+    #[test]
+    fn test_premium_subscription_warns() {
+        let mut input = base_input();
+        input.subscription_price = dec!(6.5);
+        let result = analyze_rights_issue(&input).unwrap();
+        assert!(result.result.value_per_right < Decimal::ZERO);
+        assert!(result.warnings.iter().any(|w| w.contains("no value")));
+    }
+
+    #[test]
+    fn test_deep_discount_warns() {
+        let mut input = base_input();
+        input.subscription_price = dec!(1);
+        input.new_shares = dec!(2);
+        input.existing_shares = dec!(1);
+        let result = analyze_rights_issue(&input).unwrap();
+        assert!(result.warnings.iter().any(|w| w.contains("Deep discount")));
+    }
+
+    #[test]
+    fn test_invalid_inputs() {
+        let mut input = base_input();
+        input.subscription_price = Decimal::ZERO;
+        assert!(analyze_rights_issue(&input).is_err());
+
+        let mut input = base_input();
+        input.existing_shares = Decimal::ZERO;
+        assert!(analyze_rights_issue(&input).is_err());
+
+        let mut input = base_input();
+        input.expected_take_up = Some(dec!(1.2));
+        assert!(analyze_rights_issue(&input).is_err());
+
+        let mut input = base_input();
+        input.shareholder_shares = Some(dec!(200_000_000));
+        assert!(analyze_rights_issue(&input).is_err());
+    }
+
+    #[test]
+    fn test_methodology() {
+        let result = analyze_rights_issue(&base_input()).unwrap();
+        assert!(result.methodology.contains("TERP"));
+    }
+}
@@ -0,0 +1,799 @@
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+use std::time::Instant;
+
+use crate::bank_analytics::cecl_provisioning::CeclProvisioningOutput;
+use crate::error::CorpFinanceError;
+use crate::types::{with_metadata, ComputationOutput};
+use crate::CorpFinanceResult;
+
+// ---------------------------------------------------------------------------
+// Types — Bank Valuation
+// ---------------------------------------------------------------------------
+
+/// Projection assumptions for a single year of the excess-capital DDM.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BankProjectionYear {
+    /// Return on tangible equity earned on opening tangible equity
+    pub rote: Decimal,
+    /// Growth in risk-weighted assets over the year
+    pub rwa_growth: Decimal,
+}
+
+/// A peer bank for the P/TBV vs ROTE regression.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BankPeer {
+    pub name: String,
+    /// Price / tangible book value per share
+    pub price_to_tbv: Decimal,
+    /// Forward return on tangible equity
+    pub rote: Decimal,
+}
+
+/// Input for bank valuation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BankValuationInput {
+    /// Bank name
+    pub company_name: String,
+    /// Current share price
+    pub share_price: Decimal,
+    /// Diluted shares outstanding
+    pub shares_outstanding: Decimal,
+    /// Tangible common equity (book equity less goodwill and intangibles)
+    pub tangible_book_value: Decimal,
+    /// Common equity tier 1 capital
+    pub cet1_capital: Decimal,
+    /// Risk-weighted assets
+    pub risk_weighted_assets: Decimal,
+    /// Sustainable forward return on tangible equity
+    pub rote: Decimal,
+    /// Cost of equity
+    pub cost_of_equity: Decimal,
+    /// Long-term growth rate of book value and dividends
+    pub long_term_growth: Decimal,
+    /// Management / regulatory CET1 target ratio (e.g. 0.12 = 12%)
+    pub target_cet1_ratio: Decimal,
+    /// Explicit projection years for the multi-stage DDM
+    pub projection: Vec<BankProjectionYear>,
+    /// Cap on distributions as a fraction of net income (None = unconstrained)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_payout_ratio: Option<Decimal>,
+    /// ROTE in the terminal steady state (defaults to the last projected year)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub terminal_rote: Option<Decimal>,
+    /// Peer banks for the P/TBV regression
+    pub peers: Vec<BankPeer>,
+    /// CECL / IFRS 9 output from bank_analytics used to test reserve adequacy
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cecl: Option<CeclProvisioningOutput>,
+    /// Allowance for credit losses currently on balance sheet
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub existing_allowance: Option<Decimal>,
+    /// Tax rate applied to any reserve shortfall (default 21%)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_rate: Option<Decimal>,
+}
+
+// ---------------------------------------------------------------------------
+// Output types
+// ---------------------------------------------------------------------------
+
+/// Warranted equity: Gordon growth expressed as P/TBV = (ROTE - g) / (COE - g).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WarrantedEquity {
+    pub warranted_price_to_tbv: Decimal,
+    pub value_per_share: Decimal,
+    pub upside_downside: Decimal,
+}
+
+/// One year of the capital-constrained dividend projection.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BankDdmYear {
+    pub year: u32,
+    pub risk_weighted_assets: Decimal,
+    pub net_income: Decimal,
+    pub required_cet1: Decimal,
+    /// Capital distributed (dividends and buybacks)
+    pub distributions: Decimal,
+    pub payout_ratio: Decimal,
+    /// Closing CET1 capital
+    pub cet1_capital: Decimal,
+    pub cet1_ratio: Decimal,
+    pub tangible_book_value: Decimal,
+    /// True if the payout cap, not the capital target, bound distributions
+    pub payout_capped: bool,
+    pub discount_factor: Decimal,
+    pub present_value: Decimal,
+}
+
+/// Multi-stage excess capital dividend discount model.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExcessCapitalDdm {
+    pub years: Vec<BankDdmYear>,
+    /// Excess (shortfall) of opening CET1 over the target
+    pub opening_excess_capital: Decimal,
+    pub pv_distributions: Decimal,
+    pub terminal_distribution: Decimal,
+    pub terminal_value: Decimal,
+    pub pv_terminal_value: Decimal,
+    pub equity_value: Decimal,
+    pub value_per_share: Decimal,
+    pub implied_price_to_tbv: Decimal,
+    pub upside_downside: Decimal,
+    /// Share of value from the terminal period
+    pub terminal_value_pct: Decimal,
+}
+
+/// Cross-sectional P/TBV vs ROTE regression.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PtbvRegression {
+    pub peer_count: usize,
+    pub intercept: Decimal,
+    pub slope: Decimal,
+    pub r_squared: Decimal,
+    pub implied_price_to_tbv: Decimal,
+    pub value_per_share: Decimal,
+    pub current_price_to_tbv: Decimal,
+    /// Current P/TBV minus regression-implied P/TBV
+    pub residual: Decimal,
+    pub upside_downside: Decimal,
+}
+
+/// Reserve adequacy using the bank_analytics CECL output.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReserveAdjustment {
+    pub modelled_ecl: Decimal,
+    pub existing_allowance: Decimal,
+    /// Positive when modelled losses exceed reserves
+    pub shortfall: Decimal,
+    /// After-tax shortfall deducted from TBV and CET1
+    pub after_tax_adjustment: Decimal,
+}
+
+/// Full bank valuation output.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BankValuationOutput {
+    pub tangible_book_value_per_share: Decimal,
+    pub current_price_to_tbv: Decimal,
+    pub opening_cet1_ratio: Decimal,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reserve_adjustment: Option<ReserveAdjustment>,
+    pub warranted_equity: WarrantedEquity,
+    pub excess_capital_ddm: ExcessCapitalDdm,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ptbv_regression: Option<PtbvRegression>,
+}
+
+// ---------------------------------------------------------------------------
+// Core calculation
+// ---------------------------------------------------------------------------
+
+/// Value a bank using warranted P/TBV, a capital-constrained multi-stage
+/// DDM and a peer P/TBV vs ROTE regression.
+pub fn value_bank(
+    input: &BankValuationInput,
+) -> CorpFinanceResult<ComputationOutput<BankValuationOutput>> {
+    let start = Instant::now();
+    let mut warnings: Vec<String> = Vec::new();
+
+    validate_input(input)?;
+
+    // --- Reserve adequacy adjustment ---
+    let reserve_adjustment = build_reserve_adjustment(input, &mut warnings);
+    let adjustment = reserve_adjustment
+        .as_ref()
+        .map(|r| r.after_tax_adjustment)
+        .unwrap_or(dec!(0));
+    let tangible_book = input.tangible_book_value - adjustment;
+    let opening_cet1 = input.cet1_capital - adjustment;
+    if tangible_book <= dec!(0) {
+        return Err(CorpFinanceError::FinancialImpossibility(
+            "Tangible book value is non-positive after reserve adjustment".into(),
+        ));
+    }
+
+    let tbvps = tangible_book / input.shares_outstanding;
+    let current_price_to_tbv = input.share_price / tbvps;
+    let opening_cet1_ratio = opening_cet1 / input.risk_weighted_assets;
+    if opening_cet1_ratio < input.target_cet1_ratio {
+        warnings.push(format!(
+            "Opening CET1 ratio {:.2}% is below the {:.2}% target; distributions suspended until rebuilt",
+            opening_cet1_ratio * dec!(100),
+            input.target_cet1_ratio * dec!(100)
+        ));
+    }
+
+    // --- Warranted equity ---
+    let g = input.long_term_growth;
+    let coe = input.cost_of_equity;
+    let warranted_price_to_tbv = (input.rote - g) / (coe - g);
+    if input.rote < coe {
+        warnings.push("ROTE below cost of equity; warranted P/TBV is below 1.0x".into());
+    }
+    let warranted_value = warranted_price_to_tbv * tbvps;
+    let warranted_equity = WarrantedEquity {
+        warranted_price_to_tbv,
+        value_per_share: warranted_value,
+        upside_downside: warranted_value / input.share_price - dec!(1),
+    };
+
+    // --- Excess capital DDM ---
+    let excess_capital_ddm = build_ddm(input, tangible_book, opening_cet1, &mut warnings);
+
+    // --- P/TBV regression ---
+    let ptbv_regression = build_regression(input, tbvps, current_price_to_tbv, &mut warnings);
+
+    let output = BankValuationOutput {
+        tangible_book_value_per_share: tbvps,
+        current_price_to_tbv,
+        opening_cet1_ratio,
+        reserve_adjustment,
+        warranted_equity,
+        excess_capital_ddm,
+        ptbv_regression,
+    };
+
+    let elapsed = start.elapsed().as_micros() as u64;
+    Ok(with_metadata(
+        "Bank Valuation (warranted P/TBV, excess capital DDM, P/TBV-ROTE regression)",
+        &serde_json::json!({
+            "company_name": input.company_name,
+            "cost_of_equity": coe.to_string(),
+            "long_term_growth": g.to_string(),
+            "target_cet1_ratio": input.target_cet1_ratio.to_string(),
+            "projection_years": input.projection.len(),
+            "peers": input.peers.len(),
+        }),
+        warnings,
+        elapsed,
+        output,
+    ))
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+fn validate_input(input: &BankValuationInput) -> CorpFinanceResult<()> {
+    if input.share_price <= dec!(0) {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "share_price".into(),
+            reason: "must be positive".into(),
+        });
+    }
+    if input.shares_outstanding <= dec!(0) {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "shares_outstanding".into(),
+            reason: "must be positive".into(),
+        });
+    }
+    if input.risk_weighted_assets <= dec!(0) {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "risk_weighted_assets".into(),
+            reason: "must be positive".into(),
+        });
+    }
+    if input.target_cet1_ratio <= dec!(0) || input.target_cet1_ratio >= dec!(1) {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "target_cet1_ratio".into(),
+            reason: "must be between 0 and 1 (exclusive)".into(),
+        });
+    }
+    if input.cost_of_equity <= input.long_term_growth {
+        return Err(CorpFinanceError::FinancialImpossibility(format!(
+            "Cost of equity ({}) must exceed long-term growth ({})",
+            input.cost_of_equity, input.long_term_growth
+        )));
+    }
+    if input.projection.is_empty() {
+        return Err(CorpFinanceError::InsufficientData(
+            "At least one projection year is required".into(),
+        ));
+    }
+    if let Some(cap) = input.max_payout_ratio {
+        if cap < dec!(0) {
+            return Err(CorpFinanceError::InvalidInput {
+                field: "max_payout_ratio".into(),
+                reason: "cannot be negative".into(),
+            });
+        }
+    }
+    Ok(())
+}
+
+fn build_reserve_adjustment(
+    input: &BankValuationInput,
+    warnings: &mut Vec<String>,
+) -> Option<ReserveAdjustment> {
+    let cecl = input.cecl.as_ref()?;
+    let existing = match input.existing_allowance {
+        Some(a) => a,
+        None => {
+            warnings.push(
+                "CECL output supplied without existing_allowance; reserve adjustment skipped"
+                    .into(),
+            );
+            return None;
+        }
+    };
+    let tax_rate = input.tax_rate.unwrap_or(dec!(0.21));
+    let shortfall = cecl.total_ecl - existing;
+    let after_tax_adjustment = if shortfall > dec!(0) {
+        warnings.push(format!(
+            "Modelled ECL exceeds existing allowance by {:.0}; after-tax shortfall deducted from TBV and CET1",
+            shortfall
+        ));
+        shortfall * (dec!(1) - tax_rate)
+    } else {
+        dec!(0)
+    };
+    Some(ReserveAdjustment {
+        modelled_ecl: cecl.total_ecl,
+        existing_allowance: existing,
+        shortfall,
+        after_tax_adjustment,
+    })
+}
+
+fn build_ddm(
+    input: &BankValuationInput,
+    tangible_book: Decimal,
+    opening_cet1: Decimal,
+    warnings: &mut Vec<String>,
+) -> ExcessCapitalDdm {
+    let coe = input.cost_of_equity;
+    let g = input.long_term_growth;
+    let target = input.target_cet1_ratio;
+
+    let opening_excess_capital = opening_cet1 - target * input.risk_weighted_assets;
+
+    let mut rwa = input.risk_weighted_assets;
+    let mut cet1 = opening_cet1;
+    let mut tbv = tangible_book;
+    let mut discount_factor = dec!(1);
+    let mut pv_distributions = dec!(0);
+    let mut years: Vec<BankDdmYear> = Vec::new();
+
+    for (i, proj) in input.projection.iter().enumerate() {
+        rwa *= dec!(1) + proj.rwa_growth;
+        let net_income = proj.rote * tbv;
+        let required_cet1 = target * rwa;
+        let capacity = (cet1 + net_income - required_cet1).max(dec!(0));
+
+        let (distributions, payout_capped) = match input.max_payout_ratio {
+            Some(cap) => {
+                let limit = (net_income * cap).max(dec!(0));
+                if capacity > limit {
+                    (limit, true)
+                } else {
+                    (capacity, false)
+                }
+            }
+            None => (capacity, false),
+        };
+
+        cet1 += net_income - distributions;
+        tbv += net_income - distributions;
+        discount_factor /= dec!(1) + coe;
+        let present_value = distributions * discount_factor;
+        pv_distributions += present_value;
+
+        let payout_ratio = if net_income > dec!(0) {
+            distributions / net_income
+        } else {
+            dec!(0)
+        };
+
+        years.push(BankDdmYear {
+            year: (i + 1) as u32,
+            risk_weighted_assets: rwa,
+            net_income,
+            required_cet1,
+            distributions,
+            payout_ratio,
+            cet1_capital: cet1,
+            cet1_ratio: cet1 / rwa,
+            tangible_book_value: tbv,
+            payout_capped,
+            discount_factor,
+            present_value,
+        });
+    }
+
+    // Steady state: capital grows with RWA at g, so retention = g x CET1.
+    let terminal_rote = input
+        .terminal_rote
+        .unwrap_or_else(|| input.projection[input.projection.len() - 1].rote);
+    let terminal_net_income = terminal_rote * tbv;
+    let terminal_retention = g * cet1.max(target * rwa);
+    let terminal_distribution = (terminal_net_income - terminal_retention).max(dec!(0));
+    if terminal_net_income < terminal_retention {
+        warnings.push(
+            "Terminal earnings do not fund capital growth; terminal distribution floored at zero"
+                .into(),
+        );
+    }
+    let terminal_value = terminal_distribution / (coe - g);
+    let pv_terminal_value = terminal_value * discount_factor;
+
+    let equity_value = pv_distributions + pv_terminal_value;
+    let value_per_share = equity_value / input.shares_outstanding;
+    let tbvps = tangible_book / input.shares_outstanding;
+    let terminal_value_pct = if equity_value > dec!(0) {
+        pv_terminal_value / equity_value
+    } else {
+        dec!(0)
+    };
+
+    ExcessCapitalDdm {
+        years,
+        opening_excess_capital,
+        pv_distributions,
+        terminal_distribution,
+        terminal_value,
+        pv_terminal_value,
+        equity_value,
+        value_per_share,
+        implied_price_to_tbv: value_per_share / tbvps,
+        upside_downside: value_per_share / input.share_price - dec!(1),
+        terminal_value_pct,
+    }
+}
+
+fn build_regression(
+    input: &BankValuationInput,
+    tbvps: Decimal,
+    current_price_to_tbv: Decimal,
+    warnings: &mut Vec<String>,
+) -> Option<PtbvRegression> {
+    let n = input.peers.len();
+    if n < 3 {
+        if n > 0 {
+            warnings.push("P/TBV regression requires at least 3 peers; skipped".into());
+        }
+        return None;
+    }
+    let n_dec = Decimal::from(n as u64);
+    let mean_x: Decimal = input.peers.iter().map(|p| p.rote).sum::<Decimal>() / n_dec;
+    let mean_y: Decimal = input.peers.iter().map(|p| p.price_to_tbv).sum::<Decimal>() / n_dec;
+
+    let mut sxx = dec!(0);
+    let mut sxy = dec!(0);
+    let mut syy = dec!(0);
+    for p in &input.peers {
+        let dx = p.rote - mean_x;
+        let dy = p.price_to_tbv - mean_y;
+        sxx += dx * dx;
+        sxy += dx * dy;
+        syy += dy * dy;
+    }
+    if sxx == dec!(0) {
+        warnings.push("Peer ROTE has no dispersion; P/TBV regression skipped".into());
+        return None;
+    }
+
+    let slope = sxy / sxx;
+    let intercept = mean_y - slope * mean_x;
+    let r_squared = if syy > dec!(0) {
+        (sxy * sxy) / (sxx * syy)
+    } else {
+        dec!(0)
+    };
+    if r_squared < dec!(0.5) {
+        warnings.push(format!(
+            "P/TBV regression R-squared {:.2} is weak; treat implied value with caution",
+            r_squared
+        ));
+    }
+
+    let implied_price_to_tbv = intercept + slope * input.rote;
+    let value_per_share = implied_price_to_tbv * tbvps;
+
+    Some(PtbvRegression {
+        peer_count: n,
+        intercept,
+        slope,
+        r_squared,
+        implied_price_to_tbv,
+        value_per_share,
+        current_price_to_tbv,
+        residual: current_price_to_tbv - implied_price_to_tbv,
+        upside_downside: value_per_share / input.share_price - dec!(1),
+    })
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bank_analytics::cecl_provisioning::ScenarioBreakdown;
+    use rust_decimal_macros::dec;
+
+    fn regional_bank() -> BankValuationInput {
+        BankValuationInput {
+            company_name: "Regional Bank".into(),
+            share_price: dec!(15),
+            shares_outstanding: dec!(100),
+            tangible_book_value: dec!(1000),
+            cet1_capital: dec!(1000),
+            risk_weighted_assets: dec!(8000),
+            rote: dec!(0.14),
+            cost_of_equity: dec!(0.10),
+            long_term_growth: dec!(0.04),
+            target_cet1_ratio: dec!(0.11),
+            projection: vec![
+                BankProjectionYear {
+                    rote: dec!(0.14),
+                    rwa_growth: dec!(0.05),
+                },
+                BankProjectionYear {
+                    rote: dec!(0.14),
+                    rwa_growth: dec!(0.05),
+                },
+                BankProjectionYear {
+                    rote: dec!(0.14),
+                    rwa_growth: dec!(0.04),
+                },
+            ],
+            max_payout_ratio: None,
+            terminal_rote: None,
+            peers: vec![
+                BankPeer {
+                    name: "A".into(),
+                    price_to_tbv: dec!(1.0),
+                    rote: dec!(0.10),
+                },
+                BankPeer {
+                    name: "B".into(),
+                    price_to_tbv: dec!(1.5),
+                    rote: dec!(0.14),
+                },
+                BankPeer {
+                    name: "C".into(),
+                    price_to_tbv: dec!(2.0),
+                    rote: dec!(0.18),
+                },
+            ],
+            cecl: None,
+            existing_allowance: None,
+            tax_rate: None,
+        }
+    }
+
+    fn cecl_output(total_ecl: Decimal) -> CeclProvisioningOutput {
+        CeclProvisioningOutput {
+            total_ecl,
+            total_exposure: dec!(10000),
+            ecl_coverage_ratio: total_ecl / dec!(10000),
+            segment_results: vec![],
+            scenario_breakdown: ScenarioBreakdown {
+                base_ecl: total_ecl,
+                adverse_ecl: total_ecl,
+                severe_ecl: total_ecl,
+            },
+        }
+    }
+
+    #[test]
+    fn test_tbv_per_share_and_multiple() {
+        let result = value_bank(&regional_bank()).unwrap();
+        let out = &result.result;
+        assert_eq!(out.tangible_book_value_per_share, dec!(10));
+        assert_eq!(out.current_price_to_tbv, dec!(1.5));
+        assert_eq!(out.opening_cet1_ratio, dec!(0.125));
+    }
+
+    #[test]
+    fn test_warranted_price_to_tbv() {
+        let result = value_bank(&regional_bank()).unwrap();
+        let w = &result.result.warranted_equity;
+        // (0.14 - 0.04) / (0.10 - 0.04) = 1.6667
+        assert_eq!(w.warranted_price_to_tbv, dec!(0.10) / dec!(0.06));
+        assert_eq!(w.value_per_share, dec!(0.10) / dec!(0.06) * dec!(10));
+    }
+
+    #[test]
+    fn test_warranted_below_book_when_rote_below_coe() {
+        let mut input = regional_bank();
+        input.rote = dec!(0.08);
+        let result = value_bank(&input).unwrap();
+        assert!(result.result.warranted_equity.warranted_price_to_tbv < dec!(1));
+        assert!(result
+            .warnings
+            .iter()
+            .any(|w| w.contains("below cost of equity")));
+    }
+
+    #[test]
+    fn test_ddm_first_year_releases_excess_capital() {
+        let result = value_bank(&regional_bank()).unwrap();
+        let ddm = &result.result.excess_capital_ddm;
+        let y1 = &ddm.years[0];
+        // RWA 8400, NI 140, required 924, capacity = 1000 + 140 - 924 = 216
+        assert_eq!(y1.risk_weighted_assets, dec!(8400));
+        assert_eq!(y1.net_income, dec!(140));
+        assert_eq!(y1.required_cet1, dec!(924));
+        assert_eq!(y1.distributions, dec!(216));
+        assert_eq!(y1.cet1_ratio, dec!(0.11));
+        assert!(y1.payout_ratio > dec!(1));
+        assert_eq!(ddm.opening_excess_capital, dec!(120));
+    }
+
+    #[test]
+    fn test_ddm_cet1_held_at_target() {
+        let result = value_bank(&regional_bank()).unwrap();
+        for y in &result.result.excess_capital_ddm.years {
+            assert_eq!(y.cet1_ratio, dec!(0.11));
+        }
+    }
+
+    #[test]
+    fn test_ddm_payout_cap_binds() {
+        let mut input = regional_bank();
+        input.max_payout_ratio = Some(dec!(0.60));
+        let result = value_bank(&input).unwrap();
+        let y1 = &result.result.excess_capital_ddm.years[0];
+        assert!(y1.payout_capped);
+        assert_eq!(y1.distributions, dec!(84));
+        assert!(y1.cet1_ratio > dec!(0.11));
+    }
+
+    #[test]
+    fn test_ddm_capital_shortfall_suspends_distributions() {
+        let mut input = regional_bank();
+        input.cet1_capital = dec!(700);
+        let result = value_bank(&input).unwrap();
+        let y1 = &result.result.excess_capital_ddm.years[0];
+        // capacity = 700 + 140 - 924 < 0
+        assert_eq!(y1.distributions, dec!(0));
+        assert!(result.warnings.iter().any(|w| w.contains("below the")));
+    }
+
+    #[test]
+    fn test_ddm_value_components_sum() {
+        let result = value_bank(&regional_bank()).unwrap();
+        let ddm = &result.result.excess_capital_ddm;
+        let pv_sum: Decimal = ddm.years.iter().map(|y| y.present_value).sum();
+        assert_eq!(pv_sum, ddm.pv_distributions);
+        assert_eq!(
+            ddm.equity_value,
+            ddm.pv_distributions + ddm.pv_terminal_value
+        );
+        assert_eq!(ddm.value_per_share, ddm.equity_value / dec!(100));
+        assert!(ddm.terminal_value_pct > dec!(0) && ddm.terminal_value_pct < dec!(1));
+    }
+
+    #[test]
+    fn test_ddm_terminal_distribution() {
+        let result = value_bank(&regional_bank()).unwrap();
+        let ddm = &result.result.excess_capital_ddm;
+        let last = ddm.years.last().unwrap();
+        let expected = dec!(0.14) * last.tangible_book_value - dec!(0.04) * last.cet1_capital;
+        assert_eq!(ddm.terminal_distribution, expected);
+        assert_eq!(ddm.terminal_value, expected / dec!(0.06));
+    }
+
+    #[test]
+    fn test_ddm_terminal_rote_override() {
+        let mut input = regional_bank();
+        input.terminal_rote = Some(dec!(0.10));
+        let base = value_bank(&regional_bank()).unwrap();
+        let lower = value_bank(&input).unwrap();
+        assert!(
+            lower.result.excess_capital_ddm.terminal_value
+                < base.result.excess_capital_ddm.terminal_value
+        );
+    }
+
+    #[test]
+    fn test_regression_perfect_fit() {
+        let result = value_bank(&regional_bank()).unwrap();
+        let reg = result.result.ptbv_regression.as_ref().unwrap();
+        // Peers lie on P/TBV = -0.25 + 12.5 x ROTE
+        assert_eq!(reg.slope, dec!(12.5));
+        assert_eq!(reg.intercept, dec!(-0.25));
+        assert_eq!(reg.r_squared, dec!(1));
+        assert_eq!(reg.implied_price_to_tbv, dec!(1.5));
+        assert_eq!(reg.value_per_share, dec!(15));
+        assert_eq!(reg.residual, dec!(0));
+        assert_eq!(reg.peer_count, 3);
+    }
+
+    #[test]
+    fn test_regression_requires_three_peers() {
+        let mut input = regional_bank();
+        input.peers.truncate(2);
+        let result = value_bank(&input).unwrap();
+        assert!(result.result.ptbv_regression.is_none());
+        assert!(result
+            .warnings
+            .iter()
+            .any(|w| w.contains("at least 3 peers")));
+    }
+
+    #[test]
+    fn test_regression_no_dispersion() {
+        let mut input = regional_bank();
+        for p in &mut input.peers {
+            p.rote = dec!(0.12);
+        }
+        let result = value_bank(&input).unwrap();
+        assert!(result.result.ptbv_regression.is_none());
+    }
+
+    #[test]
+    fn test_cecl_shortfall_reduces_book() {
+        let mut input = regional_bank();
+        input.cecl = Some(cecl_output(dec!(150)));
+        input.existing_allowance = Some(dec!(100));
+        let result = value_bank(&input).unwrap();
+        let adj = result.result.reserve_adjustment.as_ref().unwrap();
+        assert_eq!(adj.shortfall, dec!(50));
+        assert_eq!(adj.after_tax_adjustment, dec!(39.50));
+        assert_eq!(
+            result.result.tangible_book_value_per_share,
+            dec!(960.50) / dec!(100)
+        );
+    }
+
+    #[test]
+    fn test_cecl_surplus_no_adjustment() {
+        let mut input = regional_bank();
+        input.cecl = Some(cecl_output(dec!(80)));
+        input.existing_allowance = Some(dec!(100));
+        let result = value_bank(&input).unwrap();
+        let adj = result.result.reserve_adjustment.as_ref().unwrap();
+        assert_eq!(adj.after_tax_adjustment, dec!(0));
+        assert_eq!(result.result.tangible_book_value_per_share, dec!(10));
+    }
+
+    #[test]
+    fn test_cecl_without_allowance_warns() {
+        let mut input = regional_bank();
+        input.cecl = Some(cecl_output(dec!(150)));
+        let result = value_bank(&input).unwrap();
+        assert!(result.result.reserve_adjustment.is_none());
+        assert!(result
+            .warnings
+            .iter()
+            .any(|w| w.contains("existing_allowance")));
+    }
+
+    #[test]
+    fn test_coe_below_growth_error() {
+        let mut input = regional_bank();
+        input.long_term_growth = dec!(0.10);
+        assert!(value_bank(&input).is_err());
+    }
+
+    #[test]
+    fn test_empty_projection_error() {
+        let mut input = regional_bank();
+        input.projection.clear();
+        assert!(value_bank(&input).is_err());
+    }
+
+    #[test]
+    fn test_invalid_target_ratio() {
+        let mut input = regional_bank();
+        input.target_cet1_ratio = dec!(0);
+        assert!(value_bank(&input).is_err());
+    }
+
+    #[test]
+    fn test_invalid_share_price() {
+        let mut input = regional_bank();
+        input.share_price = dec!(0);
+        assert!(value_bank(&input).is_err());
+    }
+
+    #[test]
+    fn test_methodology() {
+        let result = value_bank(&regional_bank()).unwrap();
+        assert!(result.methodology.contains("Bank Valuation"));
+    }
+}
@@ -1,2 +1,4 @@
+pub mod bank_valuation;
+pub mod reit;
 pub mod sotp;
 pub mod target_price;
@@ -0,0 +1,717 @@
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+use std::time::Instant;
+
+use crate::error::CorpFinanceError;
+use crate::types::{with_metadata, ComputationOutput};
+use crate::CorpFinanceResult;
+
+// ---------------------------------------------------------------------------
+// Types — REIT Analysis (FFO / AFFO / NAV)
+// ---------------------------------------------------------------------------
+
+/// GAAP-to-FFO/AFFO reconciliation inputs and balance sheet items for a REIT.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReitInput {
+    /// REIT name
+    pub company_name: String,
+
+    // --- GAAP to FFO (NAREIT definition) ---
+    /// GAAP net income attributable to common shareholders
+    pub net_income: Decimal,
+    /// Depreciation and amortisation of real estate assets
+    pub real_estate_depreciation: Decimal,
+    /// Gains (positive) or losses (negative) on sale of depreciable property
+    pub gains_on_property_sales: Decimal,
+    /// Impairment write-downs of depreciable real estate
+    pub impairment_charges: Decimal,
+    /// Share of FFO adjustments from unconsolidated joint ventures
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unconsolidated_jv_adjustments: Option<Decimal>,
+    /// FFO adjustments attributable to non-controlling interests (subtracted)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub noncontrolling_interest_adjustments: Option<Decimal>,
+
+    // --- FFO to AFFO ---
+    /// Recurring (maintenance) capital expenditures
+    pub recurring_capex: Decimal,
+    /// Tenant improvements and leasing commissions on second-generation space
+    pub leasing_costs: Decimal,
+    /// Straight-line rent adjustment (GAAP rent in excess of cash rent)
+    pub straight_line_rent: Decimal,
+    /// Non-cash stock-based compensation (added back)
+    pub stock_based_compensation: Decimal,
+    /// Net amortisation of above/below-market lease intangibles (subtracted)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub above_below_market_lease_amortization: Option<Decimal>,
+    /// Non-cash interest such as deferred financing cost amortisation (added back)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub non_cash_interest: Option<Decimal>,
+
+    // --- Per-share and distribution data ---
+    /// Diluted shares and OP units outstanding
+    pub shares_outstanding: Decimal,
+    /// Annual dividend per share
+    pub dividend_per_share: Decimal,
+    /// Current share price
+    pub share_price: Decimal,
+
+    // --- NAV ---
+    /// Forward 12-month cash net operating income of the stabilised portfolio
+    pub forward_noi: Decimal,
+    /// Market capitalisation rate applied to NOI (e.g. 0.055 = 5.5%)
+    pub cap_rate: Decimal,
+    /// Cash and equivalents
+    pub cash: Decimal,
+    /// Non-income-producing assets at book (land, CIP, development pipeline)
+    pub other_assets: Decimal,
+    /// Total debt at face value
+    pub total_debt: Decimal,
+    /// Preferred equity at liquidation preference
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preferred_equity: Option<Decimal>,
+    /// Other liabilities (accounts payable, accrued, security deposits)
+    pub other_liabilities: Decimal,
+    /// Cap-rate shifts for the NAV sensitivity (default: -50, -25, 0, +25, +50 bps)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cap_rate_shifts: Option<Vec<Decimal>>,
+}
+
+// ---------------------------------------------------------------------------
+// Output types
+// ---------------------------------------------------------------------------
+
+/// NAREIT FFO and AFFO reconciliation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FfoReconciliation {
+    pub net_income: Decimal,
+    pub plus_real_estate_depreciation: Decimal,
+    pub less_gains_on_sales: Decimal,
+    pub plus_impairments: Decimal,
+    pub plus_jv_adjustments: Decimal,
+    pub less_nci_adjustments: Decimal,
+    pub ffo: Decimal,
+    pub less_recurring_capex: Decimal,
+    pub less_leasing_costs: Decimal,
+    pub less_straight_line_rent: Decimal,
+    pub plus_stock_based_compensation: Decimal,
+    pub less_market_lease_amortization: Decimal,
+    pub plus_non_cash_interest: Decimal,
+    pub affo: Decimal,
+}
+
+/// Dividend coverage and payout sustainability metrics.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PayoutAnalysis {
+    /// Dividends / FFO
+    pub ffo_payout_ratio: Decimal,
+    /// Dividends / AFFO
+    pub affo_payout_ratio: Decimal,
+    /// AFFO retained after dividends (total, not per share)
+    pub retained_affo: Decimal,
+    /// AFFO / dividends
+    pub dividend_coverage: Decimal,
+    /// "Conservative", "Sustainable", "Stretched" or "Unsustainable"
+    pub sustainability: String,
+}
+
+/// Cap-rate based net asset value.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NavAnalysis {
+    /// Forward NOI / cap rate
+    pub real_estate_value: Decimal,
+    /// Real estate value plus cash and other assets
+    pub gross_asset_value: Decimal,
+    /// Debt, preferred and other liabilities
+    pub total_claims: Decimal,
+    pub nav: Decimal,
+    pub nav_per_share: Decimal,
+    /// Share price / NAV per share - 1
+    pub premium_discount_to_nav: Decimal,
+    /// Net debt / gross asset value
+    pub loan_to_value: Decimal,
+}
+
+/// One row of the NAV cap-rate sensitivity.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NavSensitivityPoint {
+    pub cap_rate: Decimal,
+    pub nav_per_share: Decimal,
+    pub premium_discount_to_nav: Decimal,
+}
+
+/// Market-implied valuation from the current share price.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImpliedValuation {
+    pub market_capitalisation: Decimal,
+    /// Market cap plus claims, less cash and non-income-producing assets
+    pub implied_real_estate_value: Decimal,
+    /// Forward NOI / implied real estate value
+    pub implied_cap_rate: Decimal,
+    /// Implied cap rate minus the applied cap rate
+    pub cap_rate_spread: Decimal,
+    pub price_to_ffo: Decimal,
+    pub price_to_affo: Decimal,
+    /// AFFO per share / share price
+    pub affo_yield: Decimal,
+    pub dividend_yield: Decimal,
+}
+
+/// Full REIT analysis output.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReitOutput {
+    pub reconciliation: FfoReconciliation,
+    pub ffo_per_share: Decimal,
+    pub affo_per_share: Decimal,
+    pub payout: PayoutAnalysis,
+    pub nav: NavAnalysis,
+    pub nav_sensitivity: Vec<NavSensitivityPoint>,
+    pub implied: ImpliedValuation,
+}
+
+// ---------------------------------------------------------------------------
+// Core calculation
+// ---------------------------------------------------------------------------
+
+/// Analyse a REIT: FFO/AFFO reconciliation, payout sustainability,
+/// cap-rate NAV and the cap rate implied by the current share price.
+pub fn analyze_reit(input: &ReitInput) -> CorpFinanceResult<ComputationOutput<ReitOutput>> {
+    let start = Instant::now();
+    let mut warnings: Vec<String> = Vec::new();
+
+    validate_input(input)?;
+
+    // --- FFO / AFFO ---
+    let reconciliation = build_reconciliation(input);
+    let ffo = reconciliation.ffo;
+    let affo = reconciliation.affo;
+    let ffo_per_share = ffo / input.shares_outstanding;
+    let affo_per_share = affo / input.shares_outstanding;
+
+    if affo <= dec!(0) {
+        warnings
+            .push("AFFO is non-positive; dividend is not covered by recurring cash flow".into());
+    }
+    if reconciliation.less_recurring_capex == dec!(0) {
+        warnings
+            .push("Recurring capex is zero; AFFO likely overstates distributable cash flow".into());
+    }
+
+    // --- Payout ---
+    let total_dividends = input.dividend_per_share * input.shares_outstanding;
+    let payout = build_payout(total_dividends, ffo, affo);
+    if payout.sustainability == "Unsustainable" {
+        warnings.push(format!(
+            "AFFO payout ratio {:.1}% exceeds 100%; dividend funded from capital or debt",
+            payout.affo_payout_ratio * dec!(100)
+        ));
+    }
+
+    // --- NAV ---
+    let nav = build_nav(input, input.cap_rate)?;
+    if nav.nav <= dec!(0) {
+        warnings.push("NAV is non-positive at the applied cap rate".into());
+    }
+    if nav.loan_to_value > dec!(0.50) {
+        warnings.push(format!(
+            "Loan-to-value {:.1}% is above the typical 50% REIT ceiling",
+            nav.loan_to_value * dec!(100)
+        ));
+    }
+
+    let shifts = input.cap_rate_shifts.clone().unwrap_or_else(|| {
+        vec![
+            dec!(-0.005),
+            dec!(-0.0025),
+            dec!(0),
+            dec!(0.0025),
+            dec!(0.005),
+        ]
+    });
+    let mut nav_sensitivity: Vec<NavSensitivityPoint> = Vec::new();
+    for shift in shifts {
+        let cap = input.cap_rate + shift;
+        if cap <= dec!(0) {
+            warnings.push(format!(
+                "Cap-rate shift {} produces a non-positive cap rate; skipped",
+                shift
+            ));
+            continue;
+        }
+        let point = build_nav(input, cap)?;
+        nav_sensitivity.push(NavSensitivityPoint {
+            cap_rate: cap,
+            nav_per_share: point.nav_per_share,
+            premium_discount_to_nav: point.premium_discount_to_nav,
+        });
+    }
+
+    // --- Market-implied valuation ---
+    let implied = build_implied(input, ffo_per_share, affo_per_share, &mut warnings);
+
+    let output = ReitOutput {
+        reconciliation,
+        ffo_per_share,
+        affo_per_share,
+        payout,
+        nav,
+        nav_sensitivity,
+        implied,
+    };
+
+    let elapsed = start.elapsed().as_micros() as u64;
+    Ok(with_metadata(
+        "REIT Analysis (NAREIT FFO, AFFO, cap-rate NAV)",
+        &serde_json::json!({
+            "company_name": input.company_name,
+            "cap_rate": input.cap_rate.to_string(),
+            "shares_outstanding": input.shares_outstanding.to_string(),
+            "ffo_definition": "NAREIT",
+        }),
+        warnings,
+        elapsed,
+        output,
+    ))
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+fn validate_input(input: &ReitInput) -> CorpFinanceResult<()> {
+    if input.shares_outstanding <= dec!(0) {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "shares_outstanding".into(),
+            reason: "must be positive".into(),
+        });
+    }
+    if input.share_price <= dec!(0) {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "share_price".into(),
+            reason: "must be positive".into(),
+        });
+    }
+    if input.cap_rate <= dec!(0) || input.cap_rate >= dec!(1) {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "cap_rate".into(),
+            reason: "must be between 0 and 1 (exclusive)".into(),
+        });
+    }
+    if input.forward_noi < dec!(0) {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "forward_noi".into(),
+            reason: "cannot be negative".into(),
+        });
+    }
+    if input.dividend_per_share < dec!(0) {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "dividend_per_share".into(),
+            reason: "cannot be negative".into(),
+        });
+    }
+    if input.real_estate_depreciation < dec!(0) {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "real_estate_depreciation".into(),
+            reason: "cannot be negative".into(),
+        });
+    }
+    Ok(())
+}
+
+fn build_reconciliation(input: &ReitInput) -> FfoReconciliation {
+    let jv = input.unconsolidated_jv_adjustments.unwrap_or(dec!(0));
+    let nci = input.noncontrolling_interest_adjustments.unwrap_or(dec!(0));
+    let ffo = input.net_income + input.real_estate_depreciation - input.gains_on_property_sales
+        + input.impairment_charges
+        + jv
+        - nci;
+
+    let market_lease = input
+        .above_below_market_lease_amortization
+        .unwrap_or(dec!(0));
+    let non_cash_interest = input.non_cash_interest.unwrap_or(dec!(0));
+    let affo = ffo - input.recurring_capex - input.leasing_costs - input.straight_line_rent
+        + input.stock_based_compensation
+        - market_lease
+        + non_cash_interest;
+
+    FfoReconciliation {
+        net_income: input.net_income,
+        plus_real_estate_depreciation: input.real_estate_depreciation,
+        less_gains_on_sales: input.gains_on_property_sales,
+        plus_impairments: input.impairment_charges,
+        plus_jv_adjustments: jv,
+        less_nci_adjustments: nci,
+        ffo,
+        less_recurring_capex: input.recurring_capex,
+        less_leasing_costs: input.leasing_costs,
+        less_straight_line_rent: input.straight_line_rent,
+        plus_stock_based_compensation: input.stock_based_compensation,
+        less_market_lease_amortization: market_lease,
+        plus_non_cash_interest: non_cash_interest,
+        affo,
+    }
+}
+
+fn build_payout(total_dividends: Decimal, ffo: Decimal, affo: Decimal) -> PayoutAnalysis {
+    let ffo_payout_ratio = if ffo > dec!(0) {
+        total_dividends / ffo
+    } else {
+        dec!(0)
+    };
+    let affo_payout_ratio = if affo > dec!(0) {
+        total_dividends / affo
+    } else {
+        dec!(0)
+    };
+    let dividend_coverage = if total_dividends > dec!(0) {
+        affo / total_dividends
+    } else {
+        dec!(0)
+    };
+
+    let sustainability = if affo <= dec!(0) && total_dividends > dec!(0) {
+        "Unsustainable"
+    } else if affo_payout_ratio <= dec!(0.75) {
+        "Conservative"
+    } else if affo_payout_ratio <= dec!(0.90) {
+        "Sustainable"
+    } else if affo_payout_ratio <= dec!(1) {
+        "Stretched"
+    } else {
+        "Unsustainable"
+    };
+
+    PayoutAnalysis {
+        ffo_payout_ratio,
+        affo_payout_ratio,
+        retained_affo: affo - total_dividends,
+        dividend_coverage,
+        sustainability: sustainability.into(),
+    }
+}
+
+fn total_claims(input: &ReitInput) -> Decimal {
+    input.total_debt + input.preferred_equity.unwrap_or(dec!(0)) + input.other_liabilities
+}
+
+fn build_nav(input: &ReitInput, cap_rate: Decimal) -> CorpFinanceResult<NavAnalysis> {
+    if cap_rate <= dec!(0) {
+        return Err(CorpFinanceError::DivisionByZero {
+            context: "NAV cap rate".into(),
+        });
+    }
+    let real_estate_value = input.forward_noi / cap_rate;
+    let gross_asset_value = real_estate_value + input.cash + input.other_assets;
+    let claims = total_claims(input);
+    let nav = gross_asset_value - claims;
+    let nav_per_share = nav / input.shares_outstanding;
+    let premium_discount_to_nav = if nav_per_share > dec!(0) {
+        input.share_price / nav_per_share - dec!(1)
+    } else {
+        dec!(0)
+    };
+    let loan_to_value = if gross_asset_value > dec!(0) {
+        (input.total_debt - input.cash) / gross_asset_value
+    } else {
+        dec!(0)
+    };
+
+    Ok(NavAnalysis {
+        real_estate_value,
+        gross_asset_value,
+        total_claims: claims,
+        nav,
+        nav_per_share,
+        premium_discount_to_nav,
+        loan_to_value,
+    })
+}
+
+fn build_implied(
+    input: &ReitInput,
+    ffo_per_share: Decimal,
+    affo_per_share: Decimal,
+    warnings: &mut Vec<String>,
+) -> ImpliedValuation {
+    let market_capitalisation = input.share_price * input.shares_outstanding;
+    let implied_real_estate_value =
+        market_capitalisation + total_claims(input) - input.cash - input.other_assets;
+
+    let implied_cap_rate = if implied_real_estate_value > dec!(0) {
+        input.forward_noi / implied_real_estate_value
+    } else {
+        warnings.push(
+            "Implied real estate value is non-positive; implied cap rate not meaningful".into(),
+        );
+        dec!(0)
+    };
+
+    let price_to_ffo = if ffo_per_share > dec!(0) {
+        input.share_price / ffo_per_share
+    } else {
+        warnings.push("FFO per share is non-positive; P/FFO not meaningful".into());
+        dec!(0)
+    };
+    let price_to_affo = if affo_per_share > dec!(0) {
+        input.share_price / affo_per_share
+    } else {
+        dec!(0)
+    };
+
+    ImpliedValuation {
+        market_capitalisation,
+        implied_real_estate_value,
+        implied_cap_rate,
+        cap_rate_spread: implied_cap_rate - input.cap_rate,
+        price_to_ffo,
+        price_to_affo,
+        affo_yield: affo_per_share / input.share_price,
+        dividend_yield: input.dividend_per_share / input.share_price,
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn office_reit() -> ReitInput {
+        ReitInput {
+            company_name: "Office REIT".into(),
+            net_income: dec!(200),
+            real_estate_depreciation: dec!(300),
+            gains_on_property_sales: dec!(50),
+            impairment_charges: dec!(20),
+            unconsolidated_jv_adjustments: Some(dec!(10)),
+            noncontrolling_interest_adjustments: Some(dec!(5)),
+            recurring_capex: dec!(60),
+            leasing_costs: dec!(40),
+            straight_line_rent: dec!(15),
+            stock_based_compensation: dec!(10),
+            above_below_market_lease_amortization: Some(dec!(5)),
+            non_cash_interest: Some(dec!(5)),
+            shares_outstanding: dec!(100),
+            dividend_per_share: dec!(3.50),
+            share_price: dec!(60),
+            forward_noi: dec!(660),
+            cap_rate: dec!(0.06),
+            cash: dec!(200),
+            other_assets: dec!(300),
+            total_debt: dec!(4000),
+            preferred_equity: Some(dec!(500)),
+            other_liabilities: dec!(200),
+            cap_rate_shifts: None,
+        }
+    }
+
+    #[test]
+    fn test_ffo_reconciliation() {
+        let result = analyze_reit(&office_reit()).unwrap();
+        let r = &result.result.reconciliation;
+        // 200 + 300 - 50 + 20 + 10 - 5 = 475
+        assert_eq!(r.ffo, dec!(475));
+    }
+
+    #[test]
+    fn test_affo_reconciliation() {
+        let result = analyze_reit(&office_reit()).unwrap();
+        let r = &result.result.reconciliation;
+        // 475 - 60 - 40 - 15 + 10 - 5 + 5 = 370
+        assert_eq!(r.affo, dec!(370));
+    }
+
+    #[test]
+    fn test_per_share_metrics() {
+        let result = analyze_reit(&office_reit()).unwrap();
+        let out = &result.result;
+        assert_eq!(out.ffo_per_share, dec!(4.75));
+        assert_eq!(out.affo_per_share, dec!(3.70));
+    }
+
+    #[test]
+    fn test_payout_ratios() {
+        let result = analyze_reit(&office_reit()).unwrap();
+        let p = &result.result.payout;
+        // Dividends = 350
+        assert_eq!(p.ffo_payout_ratio, dec!(350) / dec!(475));
+        assert_eq!(p.affo_payout_ratio, dec!(350) / dec!(370));
+        assert_eq!(p.retained_affo, dec!(20));
+        assert_eq!(p.sustainability, "Stretched");
+    }
+
+    #[test]
+    fn test_payout_conservative() {
+        let mut input = office_reit();
+        input.dividend_per_share = dec!(2.50);
+        let result = analyze_reit(&input).unwrap();
+        assert_eq!(result.result.payout.sustainability, "Conservative");
+    }
+
+    #[test]
+    fn test_payout_unsustainable_warns() {
+        let mut input = office_reit();
+        input.dividend_per_share = dec!(4.50);
+        let result = analyze_reit(&input).unwrap();
+        assert_eq!(result.result.payout.sustainability, "Unsustainable");
+        assert!(result.warnings.iter().any(|w| w.contains("exceeds 100%")));
+    }
+
+    #[test]
+    fn test_nav_calculation() {
+        let result = analyze_reit(&office_reit()).unwrap();
+        let nav = &result.result.nav;
+        // RE value = 660 / 0.06 = 11000; GAV = 11000 + 200 + 300 = 11500
+        // Claims = 4000 + 500 + 200 = 4700; NAV = 6800; per share = 68
+        assert_eq!(nav.real_estate_value, dec!(11000));
+        assert_eq!(nav.gross_asset_value, dec!(11500));
+        assert_eq!(nav.total_claims, dec!(4700));
+        assert_eq!(nav.nav, dec!(6800));
+        assert_eq!(nav.nav_per_share, dec!(68));
+    }
+
+    #[test]
+    fn test_nav_discount() {
+        let result = analyze_reit(&office_reit()).unwrap();
+        let nav = &result.result.nav;
+        // 60 / 68 - 1 < 0
+        assert_eq!(nav.premium_discount_to_nav, dec!(60) / dec!(68) - dec!(1));
+        assert!(nav.premium_discount_to_nav < dec!(0));
+    }
+
+    #[test]
+    fn test_loan_to_value() {
+        let result = analyze_reit(&office_reit()).unwrap();
+        // (4000 - 200) / 11500
+        assert_eq!(result.result.nav.loan_to_value, dec!(3800) / dec!(11500));
+    }
+
+    #[test]
+    fn test_implied_cap_rate() {
+        let result = analyze_reit(&office_reit()).unwrap();
+        let imp = &result.result.implied;
+        // Market cap 6000; implied RE = 6000 + 4700 - 200 - 300 = 10200
+        assert_eq!(imp.market_capitalisation, dec!(6000));
+        assert_eq!(imp.implied_real_estate_value, dec!(10200));
+        assert_eq!(imp.implied_cap_rate, dec!(660) / dec!(10200));
+        // Trading at a discount to NAV => implied cap rate above applied cap rate
+        assert!(imp.cap_rate_spread > dec!(0));
+    }
+
+    #[test]
+    fn test_implied_cap_rate_equals_applied_at_nav() {
+        let mut input = office_reit();
+        input.share_price = dec!(68);
+        let result = analyze_reit(&input).unwrap();
+        let imp = &result.result.implied;
+        assert_eq!(imp.implied_cap_rate, dec!(0.06));
+        assert_eq!(imp.cap_rate_spread, dec!(0));
+        assert_eq!(result.result.nav.premium_discount_to_nav, dec!(0));
+    }
+
+    #[test]
+    fn test_price_multiples_and_yields() {
+        let result = analyze_reit(&office_reit()).unwrap();
+        let imp = &result.result.implied;
+        assert_eq!(imp.price_to_ffo, dec!(60) / dec!(4.75));
+        assert_eq!(imp.price_to_affo, dec!(60) / dec!(3.70));
+        assert_eq!(imp.dividend_yield, dec!(3.50) / dec!(60));
+        assert_eq!(imp.affo_yield, dec!(3.70) / dec!(60));
+    }
+
+    #[test]
+    fn test_nav_sensitivity_default_shifts() {
+        let result = analyze_reit(&office_reit()).unwrap();
+        let sens = &result.result.nav_sensitivity;
+        assert_eq!(sens.len(), 5);
+        assert_eq!(sens[2].cap_rate, dec!(0.06));
+        assert_eq!(sens[2].nav_per_share, dec!(68));
+        // NAV falls as cap rate rises
+        for w in sens.windows(2) {
+            assert!(w[0].nav_per_share > w[1].nav_per_share);
+        }
+    }
+
+    #[test]
+    fn test_nav_sensitivity_skips_non_positive_cap_rate() {
+        let mut input = office_reit();
+        input.cap_rate_shifts = Some(vec![dec!(-0.10), dec!(0.01)]);
+        let result = analyze_reit(&input).unwrap();
+        assert_eq!(result.result.nav_sensitivity.len(), 1);
+        assert!(result.warnings.iter().any(|w| w.contains("skipped")));
+    }
+
+    #[test]
+    fn test_optional_adjustments_default_to_zero() {
+        let mut input = office_reit();
+        input.unconsolidated_jv_adjustments = None;
+        input.noncontrolling_interest_adjustments = None;
+        input.above_below_market_lease_amortization = None;
+        input.non_cash_interest = None;
+        input.preferred_equity = None;
+        let result = analyze_reit(&input).unwrap();
+        let r = &result.result.reconciliation;
+        // 200 + 300 - 50 + 20 = 470; AFFO = 470 - 60 - 40 - 15 + 10 = 365
+        assert_eq!(r.ffo, dec!(470));
+        assert_eq!(r.affo, dec!(365));
+        assert_eq!(result.result.nav.total_claims, dec!(4200));
+    }
+
+    #[test]
+    fn test_zero_recurring_capex_warns() {
+        let mut input = office_reit();
+        input.recurring_capex = dec!(0);
+        let result = analyze_reit(&input).unwrap();
+        assert!(result
+            .warnings
+            .iter()
+            .any(|w| w.contains("Recurring capex")));
+    }
+
+    #[test]
+    fn test_negative_affo_unsustainable() {
+        let mut input = office_reit();
+        input.net_income = dec!(-500);
+        let result = analyze_reit(&input).unwrap();
+        assert!(result.result.reconciliation.affo < dec!(0));
+        assert_eq!(result.result.payout.sustainability, "Unsustainable");
+        assert_eq!(result.result.implied.price_to_affo, dec!(0));
+    }
+
+    #[test]
+    fn test_invalid_shares() {
+        let mut input = office_reit();
+        input.shares_outstanding = dec!(0);
+        assert!(analyze_reit(&input).is_err());
+    }
+
+    #[test]
+    fn test_invalid_cap_rate() {
+        let mut input = office_reit();
+        input.cap_rate = dec!(0);
+        assert!(analyze_reit(&input).is_err());
+        input.cap_rate = dec!(1.2);
+        assert!(analyze_reit(&input).is_err());
+    }
+
+    #[test]
+    fn test_invalid_share_price() {
+        let mut input = office_reit();
+        input.share_price = dec!(-1);
+        assert!(analyze_reit(&input).is_err());
+    }
+
+    #[test]
+    fn test_methodology() {
+        let result = analyze_reit(&office_reit()).unwrap();
+        assert_eq!(
+            result.methodology,
+            "REIT Analysis (NAREIT FFO, AFFO, cap-rate NAV)"
+        );
+    }
+}
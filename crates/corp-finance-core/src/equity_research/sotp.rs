@@ -80,6 +80,67 @@ pub struct SotpInput {
     /// Value of unconsolidated equity stakes to add
     #[serde(skip_serializing_if = "Option::is_none")]
     pub unconsolidated_investments: Option<Decimal>,
+    /// Listed cross-holdings valued at market
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cross_holdings: Option<Vec<CrossHolding>>,
+    /// Allocation of net debt to individual segments (remainder stays at holdco)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub segment_net_debt: Option<Vec<SegmentDebtAllocation>>,
+    /// Inputs for estimating the holding-company discount when none is given
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub holdco_discount_estimation: Option<HoldcoDiscountEstimation>,
+}
+
+/// A listed stake in another company, valued at market.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CrossHolding {
+    /// Investee name
+    pub name: String,
+    /// Number of investee shares held
+    pub shares_held: Decimal,
+    /// Investee share price
+    pub market_price: Decimal,
+    /// Discount for selling a large block (e.g. 0.05 = 5%)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blockage_discount: Option<Decimal>,
+    /// Discount for limited trading liquidity
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub liquidity_discount: Option<Decimal>,
+    /// Investee average daily trading volume in shares
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub average_daily_volume: Option<Decimal>,
+    /// Tax base of the stake, for latent capital gains tax
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_basis: Option<Decimal>,
+    /// Tax rate on disposal gains
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub capital_gains_tax_rate: Option<Decimal>,
+    /// Economic ownership of the investee (decimal)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ownership_pct: Option<Decimal>,
+    /// Investee net debt, for look-through leverage
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub investee_net_debt: Option<Decimal>,
+}
+
+/// Net debt attributed to a named segment.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SegmentDebtAllocation {
+    /// Segment name (must match a `SegmentInput::name`)
+    pub segment: String,
+    pub net_debt: Decimal,
+}
+
+/// Drivers of the estimated holding-company discount.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HoldcoDiscountEstimation {
+    /// Annual unallocated head-office costs
+    pub annual_holdco_costs: Decimal,
+    /// Rate used to capitalise head-office costs
+    pub cost_capitalisation_rate: Decimal,
+    /// Observed discounts of comparable holding companies
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub peer_discounts: Option<Vec<Decimal>>,
 }
 
 // ---------------------------------------------------------------------------
@@ -124,6 +185,60 @@ pub struct FootballField {
     pub segment_ranges: Vec<SegmentRange>,
 }
 
+/// Valuation of a single listed cross-holding.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CrossHoldingValuation {
+    pub name: String,
+    pub market_value: Decimal,
+    pub blockage_discount: Decimal,
+    pub liquidity_discount: Decimal,
+    pub latent_tax: Decimal,
+    /// Market value net of discounts and latent tax
+    pub net_value: Decimal,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub days_to_liquidate: Option<Decimal>,
+}
+
+/// Segment equity value after allocated net debt.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SegmentEquityValue {
+    pub name: String,
+    pub enterprise_value: Decimal,
+    pub allocated_net_debt: Decimal,
+    pub equity_value: Decimal,
+    pub equity_value_per_share: Decimal,
+}
+
+/// Estimated holding-company discount.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HoldcoDiscountEstimate {
+    pub capitalised_holdco_costs: Decimal,
+    /// Capitalised costs / gross asset value
+    pub cost_based_discount: Decimal,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub peer_median_discount: Option<Decimal>,
+    pub estimated_discount: Decimal,
+    /// True if the estimate was used because no explicit discount was given
+    pub applied: bool,
+}
+
+/// Holdco and look-through leverage metrics.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LookThroughLeverage {
+    /// Segment EV plus net cross-holding value
+    pub gross_asset_value: Decimal,
+    pub holdco_net_debt: Decimal,
+    /// Net debt not allocated to any segment
+    pub unallocated_net_debt: Decimal,
+    /// Ownership-weighted net debt of cross-holdings
+    pub proportionate_investee_net_debt: Decimal,
+    pub look_through_net_debt: Decimal,
+    /// Holdco net debt / gross asset value
+    pub holdco_ltv: Decimal,
+    /// Look-through net debt / (gross asset value + proportionate investee net debt)
+    pub look_through_ltv: Decimal,
+}
+
 /// Full SOTP output.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SotpOutput {
@@ -135,6 +250,15 @@ pub struct SotpOutput {
     pub football_field: FootballField,
     pub conglomerate_discount_applied: Decimal,
     pub sum_check: Decimal,
+    pub cross_holdings: Vec<CrossHoldingValuation>,
+    pub segment_equity_values: Vec<SegmentEquityValue>,
+    /// SOTP per share before the holding-company discount
+    pub gross_sotp_per_share: Decimal,
+    /// SOTP per share after the holding-company discount
+    pub discounted_sotp_per_share: Decimal,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub holdco_discount_estimate: Option<HoldcoDiscountEstimate>,
+    pub leverage: LookThroughLeverage,
 }
 
 // ---------------------------------------------------------------------------
@@ -223,8 +347,34 @@ pub fn calculate_sotp(input: &SotpInput) -> CorpFinanceResult<ComputationOutput<
         }
     }
 
+    // --- Listed cross-holdings ---
+    let mut cross_holdings: Vec<CrossHoldingValuation> = Vec::new();
+    let mut total_cross = dec!(0);
+    let mut proportionate_investee_net_debt = dec!(0);
+    for ch in input.cross_holdings.as_deref().unwrap_or(&[]) {
+        let valuation = value_cross_holding(ch, &mut warnings)?;
+        total_cross += valuation.net_value;
+        if let (Some(own), Some(nd)) = (ch.ownership_pct, ch.investee_net_debt) {
+            proportionate_investee_net_debt += own * nd;
+        }
+        cross_holdings.push(valuation);
+    }
+    let gross_asset_value = total_ev + total_cross;
+
     // --- Apply holding-company discount ---
-    let discount_rate = input.holding_company_discount.unwrap_or(dec!(0));
+    let holdco_discount_estimate = match &input.holdco_discount_estimation {
+        Some(est) => Some(estimate_holdco_discount(
+            est,
+            gross_asset_value,
+            input.holding_company_discount.is_none(),
+        )?),
+        None => None,
+    };
+    let discount_rate = match (input.holding_company_discount, &holdco_discount_estimate) {
+        (Some(d), _) => d,
+        (None, Some(est)) => est.estimated_discount,
+        (None, None) => dec!(0),
+    };
     if discount_rate < dec!(0) || discount_rate >= dec!(1) {
         return Err(CorpFinanceError::InvalidInput {
             field: "holding_company_discount".into(),
@@ -233,19 +383,56 @@ pub fn calculate_sotp(input: &SotpInput) -> CorpFinanceResult<ComputationOutput<
     }
     let discount_factor = dec!(1) - discount_rate;
     let discounted_ev = total_ev * discount_factor;
+    let discounted_cross = total_cross * discount_factor;
     let discounted_low = total_low * discount_factor;
     let discounted_high = total_high * discount_factor;
-    let conglomerate_discount_applied = total_ev - discounted_ev;
+    let conglomerate_discount_applied = gross_asset_value - discounted_ev - discounted_cross;
 
     // --- Bridge to equity value ---
     let minority = input.minority_interests.unwrap_or(dec!(0));
     let unconsol = input.unconsolidated_investments.unwrap_or(dec!(0));
 
-    let equity_value = discounted_ev - input.net_debt + unconsol - minority;
+    let equity_value = discounted_ev + discounted_cross - input.net_debt + unconsol - minority;
     let equity_value_per_share = equity_value / input.shares_outstanding;
+    let gross_equity_value = gross_asset_value - input.net_debt + unconsol - minority;
+    let gross_sotp_per_share = gross_equity_value / input.shares_outstanding;
+
+    let low_equity = discounted_low + discounted_cross - input.net_debt + unconsol - minority;
+    let high_equity = discounted_high + discounted_cross - input.net_debt + unconsol - minority;
+
+    // --- Net debt allocation by segment ---
+    let (segment_equity_values, unallocated_net_debt) =
+        allocate_segment_net_debt(input, &segment_vals, &mut warnings)?;
 
-    let low_equity = discounted_low - input.net_debt + unconsol - minority;
-    let high_equity = discounted_high - input.net_debt + unconsol - minority;
+    // --- Holdco and look-through leverage ---
+    let look_through_net_debt = input.net_debt + proportionate_investee_net_debt;
+    let holdco_ltv = if gross_asset_value > dec!(0) {
+        input.net_debt / gross_asset_value
+    } else {
+        dec!(0)
+    };
+    let look_through_gav = gross_asset_value + proportionate_investee_net_debt;
+    let look_through_ltv = if look_through_gav > dec!(0) {
+        look_through_net_debt / look_through_gav
+    } else {
+        dec!(0)
+    };
+    if look_through_ltv > holdco_ltv + dec!(0.10) {
+        warnings.push(format!(
+            "Look-through LTV {:.1}% materially exceeds holdco LTV {:.1}%",
+            look_through_ltv * dec!(100),
+            holdco_ltv * dec!(100)
+        ));
+    }
+    let leverage = LookThroughLeverage {
+        gross_asset_value,
+        holdco_net_debt: input.net_debt,
+        unallocated_net_debt,
+        proportionate_investee_net_debt,
+        look_through_net_debt,
+        holdco_ltv,
+        look_through_ltv,
+    };
     let low_per_share = low_equity / input.shares_outstanding;
     let high_per_share = high_equity / input.shares_outstanding;
 
@@ -289,6 +476,7 @@ pub fn calculate_sotp(input: &SotpInput) -> CorpFinanceResult<ComputationOutput<
         segment_ranges,
     };
 
+    let cross_holdings_count = cross_holdings.len();
     let output = SotpOutput {
         total_enterprise_value: discounted_ev,
         equity_value,
@@ -298,6 +486,12 @@ pub fn calculate_sotp(input: &SotpInput) -> CorpFinanceResult<ComputationOutput<
         football_field,
         conglomerate_discount_applied,
         sum_check: discounted_ev,
+        cross_holdings,
+        segment_equity_values,
+        gross_sotp_per_share,
+        discounted_sotp_per_share: equity_value_per_share,
+        holdco_discount_estimate,
+        leverage,
     };
 
     let elapsed = start.elapsed().as_micros() as u64;
@@ -307,6 +501,7 @@ pub fn calculate_sotp(input: &SotpInput) -> CorpFinanceResult<ComputationOutput<
             "segments": input.segments.len(),
             "holding_company_discount": discount_rate,
             "net_debt": input.net_debt.to_string(),
+            "cross_holdings": cross_holdings_count,
         }),
         warnings,
         elapsed,
@@ -397,6 +592,170 @@ fn compute_segment_range(
     }
 }
 
+/// Value a listed cross-holding at market, net of blockage and liquidity
+/// discounts and latent capital gains tax.
+fn value_cross_holding(
+    ch: &CrossHolding,
+    warnings: &mut Vec<String>,
+) -> CorpFinanceResult<CrossHoldingValuation> {
+    if ch.shares_held < dec!(0) || ch.market_price < dec!(0) {
+        return Err(CorpFinanceError::InvalidInput {
+            field: format!("cross_holdings[{}]", ch.name),
+            reason: "shares_held and market_price cannot be negative".into(),
+        });
+    }
+    let blockage = ch.blockage_discount.unwrap_or(dec!(0));
+    let liquidity = ch.liquidity_discount.unwrap_or(dec!(0));
+    for (field, d) in [
+        ("blockage_discount", blockage),
+        ("liquidity_discount", liquidity),
+    ] {
+        if d < dec!(0) || d >= dec!(1) {
+            return Err(CorpFinanceError::InvalidInput {
+                field: format!("cross_holdings[{}].{}", ch.name, field),
+                reason: "must be between 0 and 1 (exclusive)".into(),
+            });
+        }
+    }
+
+    let market_value = ch.shares_held * ch.market_price;
+    let after_blockage = market_value * (dec!(1) - blockage);
+    let blockage_amount = market_value - after_blockage;
+    let after_liquidity = after_blockage * (dec!(1) - liquidity);
+    let liquidity_amount = after_blockage - after_liquidity;
+
+    let latent_tax = match (ch.tax_basis, ch.capital_gains_tax_rate) {
+        (Some(basis), Some(rate)) => ((after_liquidity - basis) * rate).max(dec!(0)),
+        _ => dec!(0),
+    };
+
+    // Days to exit at 20% of average daily volume
+    let days_to_liquidate = match ch.average_daily_volume {
+        Some(adv) if adv > dec!(0) => {
+            let days = ch.shares_held / (adv * dec!(0.20));
+            if days > dec!(20) && ch.blockage_discount.is_none() {
+                warnings.push(format!(
+                    "Cross-holding '{}' takes {:.0} days to exit but no blockage discount is applied",
+                    ch.name, days
+                ));
+            }
+            Some(days)
+        }
+        _ => None,
+    };
+
+    Ok(CrossHoldingValuation {
+        name: ch.name.clone(),
+        market_value,
+        blockage_discount: blockage_amount,
+        liquidity_discount: liquidity_amount,
+        latent_tax,
+        net_value: after_liquidity - latent_tax,
+        days_to_liquidate,
+    })
+}
+
+/// Estimate the holding-company discount from capitalised head-office costs,
+/// blended with the peer median where peers are supplied.
+fn estimate_holdco_discount(
+    est: &HoldcoDiscountEstimation,
+    gross_asset_value: Decimal,
+    applied: bool,
+) -> CorpFinanceResult<HoldcoDiscountEstimate> {
+    if est.cost_capitalisation_rate <= dec!(0) {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "holdco_discount_estimation.cost_capitalisation_rate".into(),
+            reason: "must be positive".into(),
+        });
+    }
+    if gross_asset_value <= dec!(0) {
+        return Err(CorpFinanceError::FinancialImpossibility(
+            "Gross asset value must be positive to estimate a holdco discount".into(),
+        ));
+    }
+    let capitalised_holdco_costs = est.annual_holdco_costs / est.cost_capitalisation_rate;
+    let cost_based_discount = capitalised_holdco_costs / gross_asset_value;
+    let peer_median_discount = match &est.peer_discounts {
+        Some(peers) if !peers.is_empty() => {
+            let mut sorted = peers.clone();
+            sorted.sort();
+            let n = sorted.len();
+            Some(if n % 2 == 1 {
+                sorted[n / 2]
+            } else {
+                (sorted[n / 2 - 1] + sorted[n / 2]) / dec!(2)
+            })
+        }
+        _ => None,
+    };
+    let estimated_discount = match peer_median_discount {
+        Some(peer) => (cost_based_discount + peer) / dec!(2),
+        None => cost_based_discount,
+    }
+    .max(dec!(0))
+    .min(dec!(0.99));
+
+    Ok(HoldcoDiscountEstimate {
+        capitalised_holdco_costs,
+        cost_based_discount,
+        peer_median_discount,
+        estimated_discount,
+        applied,
+    })
+}
+
+/// Allocate net debt to segments and return segment equity values plus the
+/// net debt left at the holding company.
+fn allocate_segment_net_debt(
+    input: &SotpInput,
+    segment_vals: &[SegmentValuation],
+    warnings: &mut Vec<String>,
+) -> CorpFinanceResult<(Vec<SegmentEquityValue>, Decimal)> {
+    let allocations = input.segment_net_debt.as_deref().unwrap_or(&[]);
+    for alloc in allocations {
+        if !segment_vals.iter().any(|s| s.name == alloc.segment) {
+            return Err(CorpFinanceError::InvalidInput {
+                field: "segment_net_debt".into(),
+                reason: format!("unknown segment '{}'", alloc.segment),
+            });
+        }
+    }
+
+    let mut allocated_total = dec!(0);
+    let mut values: Vec<SegmentEquityValue> = Vec::new();
+    for sv in segment_vals {
+        let allocated: Decimal = allocations
+            .iter()
+            .filter(|a| a.segment == sv.name)
+            .map(|a| a.net_debt)
+            .sum();
+        allocated_total += allocated;
+        let equity_value = sv.enterprise_value - allocated;
+        if equity_value < dec!(0) {
+            warnings.push(format!(
+                "Segment '{}' allocated net debt exceeds its enterprise value",
+                sv.name
+            ));
+        }
+        values.push(SegmentEquityValue {
+            name: sv.name.clone(),
+            enterprise_value: sv.enterprise_value,
+            allocated_net_debt: allocated,
+            equity_value,
+            equity_value_per_share: equity_value / input.shares_outstanding,
+        });
+    }
+
+    let unallocated = input.net_debt - allocated_total;
+    if !allocations.is_empty() && unallocated < dec!(0) {
+        warnings.push(
+            "Segment net debt allocations exceed consolidated net debt; holdco shows net cash"
+                .into(),
+        );
+    }
+    Ok((values, unallocated))
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -455,6 +814,9 @@ mod tests {
             holding_company_discount: None,
             minority_interests: None,
             unconsolidated_investments: None,
+            cross_holdings: None,
+            segment_net_debt: None,
+            holdco_discount_estimation: None,
         }
     }
 
@@ -539,6 +901,9 @@ mod tests {
             holding_company_discount: None,
             minority_interests: None,
             unconsolidated_investments: None,
+            cross_holdings: None,
+            segment_net_debt: None,
+            holdco_discount_estimation: None,
         };
         let result = calculate_sotp(&input).unwrap();
         let out = &result.result;
@@ -571,6 +936,9 @@ mod tests {
             holding_company_discount: None,
             minority_interests: None,
             unconsolidated_investments: None,
+            cross_holdings: None,
+            segment_net_debt: None,
+            holdco_discount_estimation: None,
         };
         let result = calculate_sotp(&input).unwrap();
         let out = &result.result;
@@ -600,6 +968,9 @@ mod tests {
             holding_company_discount: None,
             minority_interests: None,
             unconsolidated_investments: None,
+            cross_holdings: None,
+            segment_net_debt: None,
+            holdco_discount_estimation: None,
         };
         let result = calculate_sotp(&input);
         assert!(result.is_err());
@@ -627,6 +998,9 @@ mod tests {
             holding_company_discount: None,
             minority_interests: None,
             unconsolidated_investments: None,
+            cross_holdings: None,
+            segment_net_debt: None,
+            holdco_discount_estimation: None,
         };
         let result = calculate_sotp(&input);
         assert!(result.is_err());
@@ -654,6 +1028,9 @@ mod tests {
             holding_company_discount: None,
             minority_interests: None,
             unconsolidated_investments: None,
+            cross_holdings: None,
+            segment_net_debt: None,
+            holdco_discount_estimation: None,
         };
         let result = calculate_sotp(&input).unwrap();
         let out = &result.result;
@@ -684,6 +1061,9 @@ mod tests {
             holding_company_discount: None,
             minority_interests: None,
             unconsolidated_investments: None,
+            cross_holdings: None,
+            segment_net_debt: None,
+            holdco_discount_estimation: None,
         };
         let result = calculate_sotp(&input);
         assert!(result.is_err());
@@ -711,6 +1091,9 @@ mod tests {
             holding_company_discount: None,
             minority_interests: None,
             unconsolidated_investments: None,
+            cross_holdings: None,
+            segment_net_debt: None,
+            holdco_discount_estimation: None,
         };
         let result = calculate_sotp(&input).unwrap();
         let out = &result.result;
@@ -741,6 +1124,9 @@ mod tests {
             holding_company_discount: None,
             minority_interests: None,
             unconsolidated_investments: None,
+            cross_holdings: None,
+            segment_net_debt: None,
+            holdco_discount_estimation: None,
         };
         let result = calculate_sotp(&input);
         assert!(result.is_err());
@@ -768,6 +1154,9 @@ mod tests {
             holding_company_discount: None,
             minority_interests: None,
             unconsolidated_investments: None,
+            cross_holdings: None,
+            segment_net_debt: None,
+            holdco_discount_estimation: None,
         };
         let result = calculate_sotp(&input).unwrap();
         let out = &result.result;
@@ -852,6 +1241,9 @@ mod tests {
             holding_company_discount: None,
             minority_interests: None,
             unconsolidated_investments: None,
+            cross_holdings: None,
+            segment_net_debt: None,
+            holdco_discount_estimation: None,
         };
         let result = calculate_sotp(&input).unwrap();
         let out = &result.result;
@@ -884,6 +1276,9 @@ mod tests {
             holding_company_discount: None,
             minority_interests: None,
             unconsolidated_investments: None,
+            cross_holdings: None,
+            segment_net_debt: None,
+            holdco_discount_estimation: None,
         };
         let result = calculate_sotp(&input).unwrap();
         let ff = &result.result.football_field;
@@ -918,6 +1313,9 @@ mod tests {
             holding_company_discount: None,
             minority_interests: None,
             unconsolidated_investments: None,
+            cross_holdings: None,
+            segment_net_debt: None,
+            holdco_discount_estimation: None,
         };
         let result = calculate_sotp(&input).unwrap();
         let ff = &result.result.football_field;
@@ -976,6 +1374,9 @@ mod tests {
             holding_company_discount: None,
             minority_interests: None,
             unconsolidated_investments: None,
+            cross_holdings: None,
+            segment_net_debt: None,
+            holdco_discount_estimation: None,
         };
         let result = calculate_sotp(&input);
         assert!(result.is_err());
@@ -1080,6 +1481,9 @@ mod tests {
             holding_company_discount: None,
             minority_interests: None,
             unconsolidated_investments: None,
+            cross_holdings: None,
+            segment_net_debt: None,
+            holdco_discount_estimation: None,
         };
         let result = calculate_sotp(&input).unwrap();
         let im = &result.result.implied_multiple;
@@ -1110,8 +1514,203 @@ mod tests {
             holding_company_discount: None,
             minority_interests: None,
             unconsolidated_investments: None,
+            cross_holdings: None,
+            segment_net_debt: None,
+            holdco_discount_estimation: None,
         };
         let result = calculate_sotp(&input).unwrap();
         assert!(result.result.implied_multiple.pe_ratio.is_none());
     }
+
+    fn listed_stake() -> CrossHolding {
+        CrossHolding {
+            name: "ListedCo".into(),
+            shares_held: dec!(100),
+            market_price: dec!(50),
+            blockage_discount: Some(dec!(0.10)),
+            liquidity_discount: None,
+            average_daily_volume: None,
+            tax_basis: None,
+            capital_gains_tax_rate: None,
+            ownership_pct: Some(dec!(0.30)),
+            investee_net_debt: Some(dec!(10000)),
+        }
+    }
+
+    #[test]
+    fn test_sotp_cross_holding_at_market_with_blockage() {
+        let mut input = tech_company_input();
+        input.cross_holdings = Some(vec![listed_stake()]);
+        let result = calculate_sotp(&input).unwrap();
+        let out = &result.result;
+
+        let ch = &out.cross_holdings[0];
+        assert_eq!(ch.market_value, dec!(5000));
+        assert_eq!(ch.blockage_discount, dec!(500));
+        assert_eq!(ch.net_value, dec!(4500));
+        // Segment EV 49500 + 4500 - net debt 5000
+        assert_eq!(out.equity_value, dec!(49000));
+        assert_eq!(out.total_enterprise_value, dec!(49500));
+    }
+
+    #[test]
+    fn test_sotp_cross_holding_latent_tax_and_liquidity() {
+        let mut stake = listed_stake();
+        stake.blockage_discount = None;
+        stake.liquidity_discount = Some(dec!(0.20));
+        stake.tax_basis = Some(dec!(1000));
+        stake.capital_gains_tax_rate = Some(dec!(0.25));
+        let mut input = tech_company_input();
+        input.cross_holdings = Some(vec![stake]);
+        let result = calculate_sotp(&input).unwrap();
+        let ch = &result.result.cross_holdings[0];
+        // 5000 * 0.8 = 4000; tax = (4000 - 1000) * 0.25 = 750
+        assert_eq!(ch.liquidity_discount, dec!(1000));
+        assert_eq!(ch.latent_tax, dec!(750));
+        assert_eq!(ch.net_value, dec!(3250));
+    }
+
+    #[test]
+    fn test_sotp_cross_holding_days_to_liquidate_warning() {
+        let mut stake = listed_stake();
+        stake.blockage_discount = None;
+        stake.average_daily_volume = Some(dec!(10));
+        let mut input = tech_company_input();
+        input.cross_holdings = Some(vec![stake]);
+        let result = calculate_sotp(&input).unwrap();
+        // 100 / (10 * 0.2) = 50 days
+        assert_eq!(
+            result.result.cross_holdings[0].days_to_liquidate,
+            Some(dec!(50))
+        );
+        assert!(result.warnings.iter().any(|w| w.contains("days to exit")));
+    }
+
+    #[test]
+    fn test_sotp_cross_holding_invalid_discount() {
+        let mut stake = listed_stake();
+        stake.blockage_discount = Some(dec!(1.5));
+        let mut input = tech_company_input();
+        input.cross_holdings = Some(vec![stake]);
+        assert!(calculate_sotp(&input).is_err());
+    }
+
+    #[test]
+    fn test_sotp_gross_and_discounted_per_share() {
+        let mut input = tech_company_input();
+        input.cross_holdings = Some(vec![listed_stake()]);
+        input.holding_company_discount = Some(dec!(0.10));
+        let result = calculate_sotp(&input).unwrap();
+        let out = &result.result;
+        // Gross: (49500 + 4500 - 5000) / 100 = 490
+        assert_eq!(out.gross_sotp_per_share, dec!(490));
+        // Discounted: (54000 * 0.9 - 5000) / 100 = 436
+        assert_eq!(out.discounted_sotp_per_share, dec!(436));
+        assert_eq!(out.discounted_sotp_per_share, out.equity_value_per_share);
+        assert_eq!(out.conglomerate_discount_applied, dec!(5400));
+    }
+
+    #[test]
+    fn test_sotp_gross_equals_discounted_without_discount() {
+        let result = calculate_sotp(&tech_company_input()).unwrap();
+        let out = &result.result;
+        assert_eq!(out.gross_sotp_per_share, out.discounted_sotp_per_share);
+        assert!(out.holdco_discount_estimate.is_none());
+    }
+
+    #[test]
+    fn test_sotp_segment_net_debt_allocation() {
+        let mut input = tech_company_input();
+        input.segment_net_debt = Some(vec![
+            SegmentDebtAllocation {
+                segment: "Cloud Services".into(),
+                net_debt: dec!(2000),
+            },
+            SegmentDebtAllocation {
+                segment: "Hardware".into(),
+                net_debt: dec!(1000),
+            },
+        ]);
+        let result = calculate_sotp(&input).unwrap();
+        let out = &result.result;
+        let cloud = &out.segment_equity_values[0];
+        assert_eq!(cloud.allocated_net_debt, dec!(2000));
+        assert_eq!(cloud.equity_value, dec!(20500));
+        assert_eq!(cloud.equity_value_per_share, dec!(205));
+        assert_eq!(out.segment_equity_values[1].allocated_net_debt, dec!(0));
+        assert_eq!(out.leverage.unallocated_net_debt, dec!(2000));
+        // Allocation does not change the consolidated equity value
+        assert_eq!(out.equity_value, dec!(44500));
+    }
+
+    #[test]
+    fn test_sotp_segment_net_debt_unknown_segment() {
+        let mut input = tech_company_input();
+        input.segment_net_debt = Some(vec![SegmentDebtAllocation {
+            segment: "Unknown".into(),
+            net_debt: dec!(100),
+        }]);
+        assert!(calculate_sotp(&input).is_err());
+    }
+
+    #[test]
+    fn test_sotp_holdco_discount_estimated_from_costs() {
+        let mut input = tech_company_input();
+        input.holdco_discount_estimation = Some(HoldcoDiscountEstimation {
+            annual_holdco_costs: dec!(495),
+            cost_capitalisation_rate: dec!(0.10),
+            peer_discounts: None,
+        });
+        let result = calculate_sotp(&input).unwrap();
+        let out = &result.result;
+        let est = out.holdco_discount_estimate.as_ref().unwrap();
+        // 495 / 0.10 = 4950; 4950 / 49500 = 10%
+        assert_eq!(est.capitalised_holdco_costs, dec!(4950));
+        assert_eq!(est.estimated_discount, dec!(0.10));
+        assert!(est.applied);
+        assert_eq!(out.total_enterprise_value, dec!(44550));
+    }
+
+    #[test]
+    fn test_sotp_holdco_discount_blended_with_peers() {
+        let mut input = tech_company_input();
+        input.holdco_discount_estimation = Some(HoldcoDiscountEstimation {
+            annual_holdco_costs: dec!(495),
+            cost_capitalisation_rate: dec!(0.10),
+            peer_discounts: Some(vec![dec!(0.15), dec!(0.30), dec!(0.20)]),
+        });
+        let result = calculate_sotp(&input).unwrap();
+        let est = result.result.holdco_discount_estimate.as_ref().unwrap();
+        assert_eq!(est.peer_median_discount, Some(dec!(0.20)));
+        assert_eq!(est.estimated_discount, dec!(0.15));
+    }
+
+    #[test]
+    fn test_sotp_explicit_discount_overrides_estimate() {
+        let mut input = tech_company_input();
+        input.holding_company_discount = Some(dec!(0.05));
+        input.holdco_discount_estimation = Some(HoldcoDiscountEstimation {
+            annual_holdco_costs: dec!(495),
+            cost_capitalisation_rate: dec!(0.10),
+            peer_discounts: None,
+        });
+        let result = calculate_sotp(&input).unwrap();
+        let out = &result.result;
+        assert!(!out.holdco_discount_estimate.as_ref().unwrap().applied);
+        assert_eq!(out.total_enterprise_value, dec!(49500) * dec!(0.95));
+    }
+
+    #[test]
+    fn test_sotp_look_through_leverage() {
+        let mut input = tech_company_input();
+        input.cross_holdings = Some(vec![listed_stake()]);
+        let result = calculate_sotp(&input).unwrap();
+        let lev = &result.result.leverage;
+        assert_eq!(lev.gross_asset_value, dec!(54000));
+        assert_eq!(lev.proportionate_investee_net_debt, dec!(3000));
+        assert_eq!(lev.look_through_net_debt, dec!(8000));
+        assert_eq!(lev.holdco_ltv, dec!(5000) / dec!(54000));
+        assert_eq!(lev.look_through_ltv, dec!(8000) / dec!(57000));
+        assert!(lev.look_through_ltv > lev.holdco_ltv);
+    }
 }
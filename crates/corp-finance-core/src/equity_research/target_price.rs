@@ -3,6 +3,9 @@ use rust_decimal_macros::dec;
 use serde::{Deserialize, Serialize};
 use std::time::Instant;
 
+use crate::dilution::{
+    diluted_shares_at_price, diluted_value_per_share, DilutionMethod, DilutiveSecurity,
+};
 use crate::error::CorpFinanceError;
 use crate::types::{with_metadata, ComputationOutput};
 use crate::CorpFinanceResult;
@@ -521,6 +524,361 @@ pub fn calculate_target_price(
     ))
 }
 
+// ---------------------------------------------------------------------------
+// Reverse DCF — Implied Expectations
+// ---------------------------------------------------------------------------
+
+/// User-entered (or consensus) operating assumptions to compare against
+/// what the market price implies.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExpectationEstimates {
+    /// Annual revenue growth over the explicit forecast (decimal)
+    pub revenue_growth: Decimal,
+    /// Operating (EBIT) margin over the explicit forecast (decimal)
+    pub operating_margin: Decimal,
+}
+
+/// Input for the reverse-DCF / expectations investing analysis.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReverseDcfInput {
+    /// Current market price per share
+    pub current_price: Decimal,
+    /// Diluted shares outstanding, or basic shares when
+    /// `dilutive_securities` is supplied
+    pub shares_outstanding: Decimal,
+    /// Net debt (debt less cash) deducted from enterprise value
+    pub net_debt: Decimal,
+    /// Options, warrants, RSUs and convertibles; diluted by TSM at the
+    /// current price and solved for circularly at DCF values
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub dilutive_securities: Vec<DilutiveSecurity>,
+    /// Last twelve months revenue
+    pub base_revenue: Decimal,
+    /// Cash tax rate on operating profit
+    pub tax_rate: Decimal,
+    /// Incremental revenue generated per unit of reinvested capital
+    pub sales_to_capital: Decimal,
+    /// Weighted average cost of capital
+    pub wacc: Decimal,
+    /// Perpetual growth after the explicit forecast
+    pub terminal_growth: Decimal,
+    /// Length of the explicit forecast in years
+    pub projection_years: u32,
+    /// Analyst estimates to compare against market-implied values
+    pub estimates: ExpectationEstimates,
+}
+
+/// Comparison of one assumption: analyst view versus market-implied.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AssumptionComparison {
+    pub assumption: String,
+    pub user_estimate: Decimal,
+    /// None if no value in the search range reproduces the market price
+    pub market_implied: Option<Decimal>,
+    /// Market-implied minus user estimate
+    pub difference: Option<Decimal>,
+    pub interpretation: String,
+}
+
+/// One row of the value-per-share grid over growth and margin.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExpectationsGridRow {
+    pub revenue_growth: Decimal,
+    /// (operating_margin, value_per_share)
+    pub values: Vec<(Decimal, Decimal)>,
+}
+
+/// Reverse-DCF output.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReverseDcfOutput {
+    /// Enterprise value implied by the current price
+    pub market_enterprise_value: Decimal,
+    /// DCF value per share at the user estimates
+    pub value_at_estimates: Decimal,
+    /// (value_at_estimates - price) / price
+    pub upside_downside_pct: Decimal,
+    /// Growth that reproduces the price, holding margin at the estimate
+    pub implied_revenue_growth: Option<Decimal>,
+    /// Margin that reproduces the price, holding growth at the estimate
+    pub implied_operating_margin: Option<Decimal>,
+    /// After-tax return on incremental capital at the estimated margin
+    pub incremental_roic: Decimal,
+    /// Share of market EV explained by the terminal value at the estimates
+    pub terminal_value_pct: Decimal,
+    pub comparisons: Vec<AssumptionComparison>,
+    pub expectations_grid: Vec<ExpectationsGridRow>,
+}
+
+/// Solve for the revenue growth and operating margin implied by the current
+/// share price and compare them with user-entered estimates.
+pub fn calculate_reverse_dcf(
+    input: &ReverseDcfInput,
+) -> CorpFinanceResult<ComputationOutput<ReverseDcfOutput>> {
+    let start = Instant::now();
+    let mut warnings: Vec<String> = Vec::new();
+
+    validate_reverse_dcf(input)?;
+
+    let est = &input.estimates;
+    let market_enterprise_value = if input.dilutive_securities.is_empty() {
+        input.current_price * input.shares_outstanding + input.net_debt
+    } else {
+        let diluted = diluted_shares_at_price(
+            input.shares_outstanding,
+            &input.dilutive_securities,
+            input.current_price,
+        )?;
+        diluted.diluted_market_cap + input.net_debt - diluted.convertible_debt_converted
+    };
+
+    let (value_at_estimates, terminal_value_pct) =
+        dcf_value_per_share(input, est.revenue_growth, est.operating_margin);
+    let upside_downside_pct = (value_at_estimates - input.current_price) / input.current_price;
+
+    let incremental_roic =
+        est.operating_margin * (dec!(1) - input.tax_rate) * input.sales_to_capital;
+    if incremental_roic < input.wacc {
+        warnings.push(format!(
+            "Incremental ROIC {:.1}% is below WACC; growth destroys value and implied growth may be ambiguous",
+            incremental_roic * dec!(100)
+        ));
+    }
+
+    let implied_revenue_growth = solve_implied(
+        |g| dcf_value_per_share(input, g, est.operating_margin).0,
+        input.current_price,
+        dec!(-0.50),
+        input.wacc.max(dec!(1)),
+    );
+    let implied_operating_margin = solve_implied(
+        |m| dcf_value_per_share(input, est.revenue_growth, m).0,
+        input.current_price,
+        dec!(-0.50),
+        dec!(0.95),
+    );
+    if implied_revenue_growth.is_none() {
+        warnings.push(
+            "No revenue growth in the search range reproduces the market price at the estimated margin"
+                .into(),
+        );
+    }
+    if implied_operating_margin.is_none() {
+        warnings.push(
+            "No operating margin in the search range reproduces the market price at the estimated growth"
+                .into(),
+        );
+    }
+
+    let comparisons = vec![
+        compare_assumption("Revenue growth", est.revenue_growth, implied_revenue_growth),
+        compare_assumption(
+            "Operating margin",
+            est.operating_margin,
+            implied_operating_margin,
+        ),
+    ];
+
+    // Grid: estimates +/- 2 and 4 percentage points on each axis
+    let steps = [dec!(-0.04), dec!(-0.02), dec!(0), dec!(0.02), dec!(0.04)];
+    let expectations_grid = steps
+        .iter()
+        .map(|dg| {
+            let g = est.revenue_growth + dg;
+            let values = steps
+                .iter()
+                .map(|dm| {
+                    let m = est.operating_margin + dm;
+                    (m, dcf_value_per_share(input, g, m).0)
+                })
+                .collect();
+            ExpectationsGridRow {
+                revenue_growth: g,
+                values,
+            }
+        })
+        .collect();
+
+    let output = ReverseDcfOutput {
+        market_enterprise_value,
+        value_at_estimates,
+        upside_downside_pct,
+        implied_revenue_growth,
+        implied_operating_margin,
+        incremental_roic,
+        terminal_value_pct,
+        comparisons,
+        expectations_grid,
+    };
+
+    let elapsed = start.elapsed().as_micros() as u64;
+    Ok(with_metadata(
+        "Reverse DCF (Market-Implied Expectations)",
+        &serde_json::json!({
+            "wacc": input.wacc.to_string(),
+            "terminal_growth": input.terminal_growth.to_string(),
+            "projection_years": input.projection_years,
+            "sales_to_capital": input.sales_to_capital.to_string(),
+        }),
+        warnings,
+        elapsed,
+        output,
+    ))
+}
+
+fn validate_reverse_dcf(input: &ReverseDcfInput) -> CorpFinanceResult<()> {
+    if input.current_price <= dec!(0) {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "current_price".into(),
+            reason: "must be positive".into(),
+        });
+    }
+    if input.shares_outstanding <= dec!(0) {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "shares_outstanding".into(),
+            reason: "must be positive".into(),
+        });
+    }
+    if input.base_revenue <= dec!(0) {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "base_revenue".into(),
+            reason: "must be positive".into(),
+        });
+    }
+    if input.sales_to_capital <= dec!(0) {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "sales_to_capital".into(),
+            reason: "must be positive".into(),
+        });
+    }
+    if input.projection_years == 0 {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "projection_years".into(),
+            reason: "must be at least 1".into(),
+        });
+    }
+    if input.wacc <= input.terminal_growth {
+        return Err(CorpFinanceError::FinancialImpossibility(format!(
+            "WACC ({}) must exceed terminal growth ({})",
+            input.wacc, input.terminal_growth
+        )));
+    }
+    Ok(())
+}
+
+/// FCFF DCF value per share and the terminal value share of enterprise value.
+/// FCFF = revenue x margin x (1 - tax) - change in revenue / sales-to-capital.
+fn dcf_value_per_share(
+    input: &ReverseDcfInput,
+    growth: Decimal,
+    margin: Decimal,
+) -> (Decimal, Decimal) {
+    let after_tax = dec!(1) - input.tax_rate;
+    let mut revenue = input.base_revenue;
+    let mut discount = dec!(1);
+    let mut pv_fcff = dec!(0);
+    for _ in 0..input.projection_years {
+        let next = revenue * (dec!(1) + growth);
+        let fcff = next * margin * after_tax - (next - revenue) / input.sales_to_capital;
+        discount /= dec!(1) + input.wacc;
+        pv_fcff += fcff * discount;
+        revenue = next;
+    }
+    let g_t = input.terminal_growth;
+    let terminal_revenue = revenue * (dec!(1) + g_t);
+    let terminal_fcff = terminal_revenue * margin * after_tax
+        - (terminal_revenue - revenue) / input.sales_to_capital;
+    let pv_terminal = terminal_fcff / (input.wacc - g_t) * discount;
+    let ev = pv_fcff + pv_terminal;
+    let tv_pct = if ev != dec!(0) {
+        pv_terminal / ev
+    } else {
+        dec!(0)
+    };
+    let equity = ev - input.net_debt;
+    let per_share = if input.dilutive_securities.is_empty() {
+        equity / input.shares_outstanding
+    } else {
+        diluted_value_per_share(
+            equity,
+            input.shares_outstanding,
+            &input.dilutive_securities,
+            &DilutionMethod::TreasuryStock,
+        )
+        .map(|v| v.value_per_share)
+        .unwrap_or(equity / input.shares_outstanding)
+    };
+    (per_share, tv_pct)
+}
+
+/// Bisection search for the assumption value at which `f(x) == target`.
+/// Returns None if the target is not bracketed by `[lo, hi]`.
+fn solve_implied<F: Fn(Decimal) -> Decimal>(
+    f: F,
+    target: Decimal,
+    mut lo: Decimal,
+    mut hi: Decimal,
+) -> Option<Decimal> {
+    let mut f_lo = f(lo) - target;
+    let f_hi = f(hi) - target;
+    if f_lo == dec!(0) {
+        return Some(lo);
+    }
+    if f_hi == dec!(0) {
+        return Some(hi);
+    }
+    if (f_lo > dec!(0)) == (f_hi > dec!(0)) {
+        return None;
+    }
+    for _ in 0..100 {
+        let mid = (lo + hi) / dec!(2);
+        let f_mid = f(mid) - target;
+        if f_mid.abs() < dec!(0.0000001) || (hi - lo) < dec!(0.00000001) {
+            return Some(mid);
+        }
+        if (f_mid > dec!(0)) == (f_lo > dec!(0)) {
+            lo = mid;
+            f_lo = f_mid;
+        } else {
+            hi = mid;
+        }
+    }
+    Some((lo + hi) / dec!(2))
+}
+
+fn compare_assumption(
+    name: &str,
+    user_estimate: Decimal,
+    market_implied: Option<Decimal>,
+) -> AssumptionComparison {
+    let difference = market_implied.map(|m| m - user_estimate);
+    let interpretation = match difference {
+        None => "Market price not reachable by varying this assumption alone".to_string(),
+        Some(d) if d.abs() <= dec!(0.005) => {
+            format!(
+                "Market is pricing {} in line with the estimate",
+                name.to_lowercase()
+            )
+        }
+        Some(d) if d > dec!(0) => format!(
+            "Market is pricing {} {:.1}pp above the estimate",
+            name.to_lowercase(),
+            d * dec!(100)
+        ),
+        Some(d) => format!(
+            "Market is pricing {} {:.1}pp below the estimate",
+            name.to_lowercase(),
+            d.abs() * dec!(100)
+        ),
+    };
+    AssumptionComparison {
+        assumption: name.into(),
+        user_estimate,
+        market_implied,
+        difference,
+        interpretation,
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Helpers
 // ---------------------------------------------------------------------------
@@ -1214,4 +1572,171 @@ mod tests {
         let result = compound(dec!(100), dec!(0.10), 3);
         assert_eq!(result, dec!(100) * dec!(1.1) * dec!(1.1) * dec!(1.1));
     }
+
+    // --- Reverse DCF ---
+
+    fn reverse_dcf_input() -> ReverseDcfInput {
+        ReverseDcfInput {
+            current_price: dec!(50),
+            shares_outstanding: dec!(100),
+            net_debt: dec!(1000),
+            dilutive_securities: vec![],
+            base_revenue: dec!(2000),
+            tax_rate: dec!(0.25),
+            sales_to_capital: dec!(2),
+            wacc: dec!(0.09),
+            terminal_growth: dec!(0.03),
+            projection_years: 5,
+            estimates: ExpectationEstimates {
+                revenue_growth: dec!(0.08),
+                operating_margin: dec!(0.20),
+            },
+        }
+    }
+
+    #[test]
+    fn test_reverse_dcf_market_ev() {
+        let result = calculate_reverse_dcf(&reverse_dcf_input()).unwrap();
+        assert_eq!(result.result.market_enterprise_value, dec!(6000));
+    }
+
+    #[test]
+    fn test_reverse_dcf_with_dilutive_securities() {
+        let base = calculate_reverse_dcf(&reverse_dcf_input()).unwrap();
+        let mut input = reverse_dcf_input();
+        input.dilutive_securities = vec![DilutiveSecurity {
+            name: "ESOP".into(),
+            kind: crate::dilution::SecurityKind::Option,
+            count: dec!(10),
+            strike: dec!(30),
+            shares_per_instrument: dec!(1),
+            time_to_expiry: None,
+        }];
+        let result = calculate_reverse_dcf(&input).unwrap();
+        let out = &result.result;
+        // TSM at 50: 10 - 300 / 50 = 4 new shares; 104 x 50 + 1000
+        assert_eq!(out.market_enterprise_value, dec!(6200));
+        assert!(out.value_at_estimates < base.result.value_at_estimates);
+        let g = out.implied_revenue_growth.unwrap();
+        let (v_g, _) = dcf_value_per_share(&input, g, input.estimates.operating_margin);
+        assert!((v_g - dec!(50)).abs() < dec!(0.001));
+    }
+
+    #[test]
+    fn test_reverse_dcf_implied_values_reprice_to_market() {
+        let input = reverse_dcf_input();
+        let result = calculate_reverse_dcf(&input).unwrap();
+        let out = &result.result;
+        let g = out.implied_revenue_growth.unwrap();
+        let m = out.implied_operating_margin.unwrap();
+        let (v_g, _) = dcf_value_per_share(&input, g, input.estimates.operating_margin);
+        let (v_m, _) = dcf_value_per_share(&input, input.estimates.revenue_growth, m);
+        assert!((v_g - dec!(50)).abs() < dec!(0.001));
+        assert!((v_m - dec!(50)).abs() < dec!(0.001));
+    }
+
+    #[test]
+    fn test_reverse_dcf_value_at_estimates() {
+        let input = reverse_dcf_input();
+        let result = calculate_reverse_dcf(&input).unwrap();
+        let out = &result.result;
+        let (v, _) = dcf_value_per_share(&input, dec!(0.08), dec!(0.20));
+        assert_eq!(out.value_at_estimates, v);
+        assert_eq!(out.upside_downside_pct, (v - dec!(50)) / dec!(50));
+    }
+
+    #[test]
+    fn test_reverse_dcf_cheap_stock_implies_lower_assumptions() {
+        let mut input = reverse_dcf_input();
+        let (v, _) = dcf_value_per_share(&input, dec!(0.08), dec!(0.20));
+        input.current_price = v * dec!(0.7);
+        let result = calculate_reverse_dcf(&input).unwrap();
+        let out = &result.result;
+        assert!(out.implied_revenue_growth.unwrap() < dec!(0.08));
+        assert!(out.implied_operating_margin.unwrap() < dec!(0.20));
+        assert!(out.comparisons[1].interpretation.contains("below"));
+        assert!(out.upside_downside_pct > dec!(0));
+    }
+
+    #[test]
+    fn test_reverse_dcf_price_at_value_in_line() {
+        let mut input = reverse_dcf_input();
+        let (v, _) = dcf_value_per_share(&input, dec!(0.08), dec!(0.20));
+        input.current_price = v;
+        let result = calculate_reverse_dcf(&input).unwrap();
+        let out = &result.result;
+        assert!((out.implied_operating_margin.unwrap() - dec!(0.20)).abs() < dec!(0.0001));
+        assert!(out.comparisons[1].interpretation.contains("in line"));
+    }
+
+    #[test]
+    fn test_reverse_dcf_margin_unreachable() {
+        let mut input = reverse_dcf_input();
+        input.current_price = dec!(100000);
+        let result = calculate_reverse_dcf(&input).unwrap();
+        assert!(result.result.implied_operating_margin.is_none());
+        assert!(result.result.comparisons[1].difference.is_none());
+        assert!(result
+            .warnings
+            .iter()
+            .any(|w| w.contains("operating margin")));
+    }
+
+    #[test]
+    fn test_reverse_dcf_incremental_roic() {
+        let result = calculate_reverse_dcf(&reverse_dcf_input()).unwrap();
+        // 0.20 * 0.75 * 2 = 0.30
+        assert_eq!(result.result.incremental_roic, dec!(0.30));
+    }
+
+    #[test]
+    fn test_reverse_dcf_value_destroying_growth_warning() {
+        let mut input = reverse_dcf_input();
+        input.estimates.operating_margin = dec!(0.04);
+        let result = calculate_reverse_dcf(&input).unwrap();
+        assert!(result.warnings.iter().any(|w| w.contains("destroys value")));
+    }
+
+    #[test]
+    fn test_reverse_dcf_grid_shape_and_monotonic_margin() {
+        let result = calculate_reverse_dcf(&reverse_dcf_input()).unwrap();
+        let grid = &result.result.expectations_grid;
+        assert_eq!(grid.len(), 5);
+        for row in grid {
+            assert_eq!(row.values.len(), 5);
+            for w in row.values.windows(2) {
+                assert!(w[1].1 > w[0].1);
+            }
+        }
+        assert_eq!(grid[2].values[2].1, result.result.value_at_estimates);
+    }
+
+    #[test]
+    fn test_reverse_dcf_terminal_value_pct() {
+        let result = calculate_reverse_dcf(&reverse_dcf_input()).unwrap();
+        let pct = result.result.terminal_value_pct;
+        assert!(pct > dec!(0.5) && pct < dec!(1));
+    }
+
+    #[test]
+    fn test_reverse_dcf_wacc_below_growth_error() {
+        let mut input = reverse_dcf_input();
+        input.terminal_growth = dec!(0.10);
+        assert!(calculate_reverse_dcf(&input).is_err());
+    }
+
+    #[test]
+    fn test_reverse_dcf_invalid_inputs() {
+        let mut input = reverse_dcf_input();
+        input.current_price = dec!(0);
+        assert!(calculate_reverse_dcf(&input).is_err());
+
+        let mut input = reverse_dcf_input();
+        input.sales_to_capital = dec!(0);
+        assert!(calculate_reverse_dcf(&input).is_err());
+
+        let mut input = reverse_dcf_input();
+        input.projection_years = 0;
+        assert!(calculate_reverse_dcf(&input).is_err());
+    }
 }
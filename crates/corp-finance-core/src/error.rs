@@ -27,6 +27,9 @@ pub enum CorpFinanceError {
 
     #[error("Serialization error: {0}")]
     SerializationError(String),
+
+    #[error("Limit exceeded: {limit} — {detail}")]
+    LimitExceeded { limit: String, detail: String },
 }
 
 impl From<serde_json::Error> for CorpFinanceError {
@@ -3,16 +3,22 @@
 //! Supports multiple day count conventions, accrued interest, clean/dirty pricing,
 //! callable bond yield-to-call (YTC) via Newton's method, and yield-to-worst (YTW).
 
-use chrono::{Datelike, NaiveDate};
+use chrono::NaiveDate;
 use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
 use serde::{Deserialize, Serialize};
 use std::time::Instant;
 
 use crate::error::CorpFinanceError;
-use crate::types::{with_metadata, ComputationOutput, Money, Rate};
+use crate::types::{
+    fx_conversion_rate, with_metadata, ComputationOutput, Currency, FxRate, Money, Rate,
+};
 use crate::CorpFinanceResult;
 
+use super::calendars::DateAdjustment;
+pub use super::daycount::DayCountConvention;
+use super::daycount::{add_months, period_fraction, year_fraction};
+
 // ---------------------------------------------------------------------------
 // Constants
 // ---------------------------------------------------------------------------
@@ -24,19 +30,6 @@ const NEWTON_EPSILON: Decimal = dec!(0.0000001);
 // Types
 // ---------------------------------------------------------------------------
 
-/// Day count convention for computing accrued interest and period fractions.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
-pub enum DayCountConvention {
-    /// 30/360 US corporate convention
-    Thirty360,
-    /// ACT/360 money market convention
-    Actual360,
-    /// ACT/365 fixed (UK gilts)
-    Actual365,
-    /// ACT/ACT (US Treasury)
-    ActualActual,
-}
-
 /// A single bond cashflow entry (coupon, principal, or both).
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BondCashflow {
@@ -68,11 +61,37 @@ pub struct BondPricingInput {
     /// Call date (for callable bonds)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub call_date: Option<NaiveDate>,
+    /// Currency the bond is denominated in
+    #[serde(default)]
+    pub currency: Currency,
+    /// Currency to translate prices into (defaults to `currency`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub presentation_currency: Option<Currency>,
+    /// Spot FX rates used for the presentation translation
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub fx_rates: Vec<FxRate>,
+    /// Business-day adjustment of payment dates. Accrual and discounting
+    /// stay on the unadjusted coupon schedule.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub payment_adjustment: Option<DateAdjustment>,
+}
+
+/// Bond prices translated into the presentation currency.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BondPresentation {
+    pub currency: Currency,
+    /// Units of presentation currency per unit of bond currency
+    pub fx_rate: Decimal,
+    pub clean_price: Money,
+    pub dirty_price: Money,
+    pub accrued_interest: Money,
 }
 
 /// Output of bond pricing computation.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BondPricingOutput {
+    /// Currency of all amounts below
+    pub currency: Currency,
     /// Clean price (excludes accrued interest)
     pub clean_price: Money,
     /// Dirty price (= clean + accrued interest)
@@ -95,6 +114,9 @@ pub struct BondPricingOutput {
     /// Yield to worst = min(YTM, YTC) if callable
     #[serde(skip_serializing_if = "Option::is_none")]
     pub ytw: Option<Rate>,
+    /// Prices in the presentation currency (if it differs)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub presentation: Option<BondPresentation>,
 }
 
 // ---------------------------------------------------------------------------
@@ -124,7 +146,13 @@ pub fn price_bond(
     );
     let num_remaining_coupons = coupon_dates.len() as u32;
 
-    let total_cashflows = build_cashflow_schedule(&coupon_dates, coupon_amount, input.face_value);
+    let mut total_cashflows =
+        build_cashflow_schedule(&coupon_dates, coupon_amount, input.face_value);
+    if let Some(adjustment) = &input.payment_adjustment {
+        for cf in total_cashflows.iter_mut() {
+            cf.date = adjustment.adjust(cf.date);
+        }
+    }
 
     // --- Accrued interest ---
     let accrued_interest = compute_accrued_interest(
@@ -158,8 +186,12 @@ pub fn price_bond(
     };
 
     // --- Years to maturity ---
-    let days_to_maturity = (input.maturity_date - input.settlement_date).num_days();
-    let years_to_maturity = Decimal::from(days_to_maturity) / dec!(365.25);
+    let years_to_maturity = year_fraction(
+        input.day_count,
+        input.settlement_date,
+        input.maturity_date,
+        input.coupon_frequency,
+    );
 
     // --- YTC / YTW for callable bonds ---
     let (ytc, ytw) =
@@ -190,7 +222,22 @@ pub fn price_bond(
             (None, None)
         };
 
+    let presentation = match &input.presentation_currency {
+        Some(ccy) if *ccy != input.currency => {
+            let fx_rate = fx_conversion_rate(&input.currency, ccy, &input.fx_rates)?;
+            Some(BondPresentation {
+                currency: ccy.clone(),
+                fx_rate,
+                clean_price: clean_price * fx_rate,
+                dirty_price: dirty_price * fx_rate,
+                accrued_interest: accrued_interest * fx_rate,
+            })
+        }
+        _ => None,
+    };
+
     let output = BondPricingOutput {
+        currency: input.currency.clone(),
         clean_price,
         dirty_price,
         accrued_interest,
@@ -201,6 +248,7 @@ pub fn price_bond(
         total_cashflows,
         ytc,
         ytw,
+        presentation,
     };
 
     let elapsed = start.elapsed().as_micros() as u64;
@@ -266,10 +314,6 @@ fn validate_input(input: &BondPricingInput) -> CorpFinanceResult<()> {
     Ok(())
 }
 
-// ---------------------------------------------------------------------------
-// Day count fraction helpers
-// ---------------------------------------------------------------------------
-
 // ---------------------------------------------------------------------------
 // Coupon schedule generation
 // ---------------------------------------------------------------------------
@@ -284,76 +328,42 @@ fn generate_coupon_dates(
     let months_per_period = 12 / frequency as i32;
     let mut dates = Vec::new();
 
-    // Walk backwards from maturity
+    // Walk backwards from maturity, rolling from maturity itself so that
+    // month-end clamping does not drift the schedule
     let mut date = maturity;
-    loop {
-        if date > settlement {
-            dates.push(date);
-        } else {
-            break;
-        }
-        date = subtract_months(date, months_per_period);
+    let mut k = 0;
+    while date > settlement {
+        dates.push(date);
+        k += 1;
+        date = add_months(maturity, -months_per_period * k);
     }
 
     dates.sort();
     dates
 }
 
-/// Find the last coupon date on or before the settlement date.
-fn last_coupon_date_before(settlement: NaiveDate, maturity: NaiveDate, frequency: u8) -> NaiveDate {
+/// Find the coupon period containing the settlement date: the last coupon
+/// date on or before settlement and the following coupon date.
+fn coupon_period_around(
+    settlement: NaiveDate,
+    maturity: NaiveDate,
+    frequency: u8,
+) -> (NaiveDate, NaiveDate) {
     let months_per_period = 12 / frequency as i32;
-    let mut date = maturity;
+    let mut next = maturity;
+    let mut k = 1;
 
     // Walk backwards from maturity until we find a date <= settlement
     loop {
-        let prev = subtract_months(date, months_per_period);
+        let prev = add_months(maturity, -months_per_period * k);
         if prev <= settlement {
-            return prev;
+            return (prev, next);
         }
-        date = prev;
+        next = prev;
+        k += 1;
     }
 }
 
-/// Subtract a number of months from a date, clamping the day to the month's max.
-fn subtract_months(date: NaiveDate, months: i32) -> NaiveDate {
-    let total_months = date.year() * 12 + date.month() as i32 - 1 - months;
-    let new_year = total_months.div_euclid(12);
-    let new_month = (total_months.rem_euclid(12) + 1) as u32;
-    let max_day = days_in_month(new_year, new_month);
-    let day = date.day().min(max_day);
-    NaiveDate::from_ymd_opt(new_year, new_month, day).unwrap_or(date)
-}
-
-/// Add a number of months to a date, clamping the day to the month's max.
-fn add_months(date: NaiveDate, months: i32) -> NaiveDate {
-    let total_months = date.year() * 12 + date.month() as i32 - 1 + months;
-    let new_year = total_months.div_euclid(12);
-    let new_month = (total_months.rem_euclid(12) + 1) as u32;
-    let max_day = days_in_month(new_year, new_month);
-    let day = date.day().min(max_day);
-    NaiveDate::from_ymd_opt(new_year, new_month, day).unwrap_or(date)
-}
-
-/// Number of days in a given month/year.
-fn days_in_month(year: i32, month: u32) -> u32 {
-    match month {
-        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
-        4 | 6 | 9 | 11 => 30,
-        2 => {
-            if is_leap_year(year) {
-                29
-            } else {
-                28
-            }
-        }
-        _ => 30,
-    }
-}
-
-fn is_leap_year(year: i32) -> bool {
-    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
-}
-
 // ---------------------------------------------------------------------------
 // Cashflow schedule
 // ---------------------------------------------------------------------------
@@ -396,64 +406,21 @@ fn compute_accrued_interest(
     coupon_amount: Money,
     day_count: DayCountConvention,
 ) -> Money {
-    let last_coupon = last_coupon_date_before(settlement, maturity, frequency);
-    let months_per_period = 12 / frequency as i32;
-    let next_coupon = add_months(last_coupon, months_per_period);
+    let (last_coupon, next_coupon) = coupon_period_around(settlement, maturity, frequency);
 
     // Fraction of the current coupon period that has elapsed
-    let accrued_fraction = match day_count {
-        DayCountConvention::Thirty360 => {
-            let num = thirty_360_days(last_coupon, settlement);
-            let den = thirty_360_days(last_coupon, next_coupon);
-            if den == 0 {
-                Decimal::ZERO
-            } else {
-                Decimal::from(num) / Decimal::from(den)
-            }
-        }
-        DayCountConvention::Actual360 => {
-            let actual_days = (settlement - last_coupon).num_days();
-            let period_days = dec!(360) / Decimal::from(frequency);
-            Decimal::from(actual_days) / period_days
-        }
-        DayCountConvention::Actual365 => {
-            let actual_days = (settlement - last_coupon).num_days();
-            let period_days = dec!(365) / Decimal::from(frequency);
-            Decimal::from(actual_days) / period_days
-        }
-        DayCountConvention::ActualActual => {
-            let actual_days = (settlement - last_coupon).num_days();
-            let period_days = (next_coupon - last_coupon).num_days();
-            if period_days == 0 {
-                Decimal::ZERO
-            } else {
-                Decimal::from(actual_days) / Decimal::from(period_days)
-            }
-        }
-    };
+    let accrued_fraction = period_fraction(
+        day_count,
+        last_coupon,
+        settlement,
+        last_coupon,
+        next_coupon,
+        frequency,
+    );
 
     coupon_amount * accrued_fraction
 }
 
-/// Compute 30/360 day count (raw days, not fraction).
-fn thirty_360_days(start: NaiveDate, end: NaiveDate) -> i32 {
-    let mut d1 = start.day() as i32;
-    let mut d2 = end.day() as i32;
-    let m1 = start.month() as i32;
-    let m2 = end.month() as i32;
-    let y1 = start.year();
-    let y2 = end.year();
-
-    if d1 == 31 {
-        d1 = 30;
-    }
-    if d2 == 31 && d1 >= 30 {
-        d2 = 30;
-    }
-
-    (y2 - y1) * 360 + (m2 - m1) * 30 + (d2 - d1)
-}
-
 // ---------------------------------------------------------------------------
 // Clean price (PV of future cashflows)
 // ---------------------------------------------------------------------------
@@ -481,41 +448,17 @@ fn compute_clean_price(
 
     // Fractional first period: fraction of the current period remaining
     // (from settlement to the next coupon date)
-    let last_coupon =
-        last_coupon_date_before(settlement, coupon_dates[coupon_dates.len() - 1], frequency);
-    let months_per_period = 12 / frequency as i32;
-    let next_coupon_from_last = add_months(last_coupon, months_per_period);
-
-    let fraction_remaining = match day_count {
-        DayCountConvention::Thirty360 => {
-            let num = thirty_360_days(settlement, next_coupon_from_last);
-            let den = thirty_360_days(last_coupon, next_coupon_from_last);
-            if den == 0 {
-                Decimal::ONE
-            } else {
-                Decimal::from(num) / Decimal::from(den)
-            }
-        }
-        DayCountConvention::Actual360 => {
-            let num = (next_coupon_from_last - settlement).num_days();
-            let den_days = dec!(360) / Decimal::from(frequency);
-            Decimal::from(num) / den_days
-        }
-        DayCountConvention::Actual365 => {
-            let num = (next_coupon_from_last - settlement).num_days();
-            let den_days = dec!(365) / Decimal::from(frequency);
-            Decimal::from(num) / den_days
-        }
-        DayCountConvention::ActualActual => {
-            let num = (next_coupon_from_last - settlement).num_days();
-            let den = (next_coupon_from_last - last_coupon).num_days();
-            if den == 0 {
-                Decimal::ONE
-            } else {
-                Decimal::from(num) / Decimal::from(den)
-            }
-        }
-    };
+    let (last_coupon, next_coupon_from_last) =
+        coupon_period_around(settlement, coupon_dates[coupon_dates.len() - 1], frequency);
+
+    let fraction_remaining = period_fraction(
+        day_count,
+        settlement,
+        next_coupon_from_last,
+        last_coupon,
+        next_coupon_from_last,
+        frequency,
+    );
 
     // Discount factor for the fractional first period
     // df_0 = 1 / (1 + periodic_yield)^fraction_remaining
@@ -639,15 +582,15 @@ fn compute_ytc(
     }
 
     // Fractional first period
-    let last_coupon = last_coupon_date_before(settlement, call_date, frequency);
-    let months_per_period = 12 / frequency as i32;
-    let next_coupon_from_last = add_months(last_coupon, months_per_period);
-    let fraction_remaining = compute_fraction_remaining(
+    let (last_coupon, next_coupon_from_last) =
+        coupon_period_around(settlement, call_date, frequency);
+    let fraction_remaining = period_fraction(
+        day_count,
         settlement,
+        next_coupon_from_last,
         last_coupon,
         next_coupon_from_last,
         frequency,
-        day_count,
     );
 
     let freq = Decimal::from(frequency);
@@ -739,46 +682,6 @@ fn price_and_derivative_to_call(
     (price, dprice)
 }
 
-/// Helper: compute fraction of period remaining from settlement to next coupon.
-fn compute_fraction_remaining(
-    settlement: NaiveDate,
-    last_coupon: NaiveDate,
-    next_coupon: NaiveDate,
-    frequency: u8,
-    day_count: DayCountConvention,
-) -> Decimal {
-    match day_count {
-        DayCountConvention::Thirty360 => {
-            let num = thirty_360_days(settlement, next_coupon);
-            let den = thirty_360_days(last_coupon, next_coupon);
-            if den == 0 {
-                Decimal::ONE
-            } else {
-                Decimal::from(num) / Decimal::from(den)
-            }
-        }
-        DayCountConvention::Actual360 => {
-            let num = (next_coupon - settlement).num_days();
-            let den_days = dec!(360) / Decimal::from(frequency);
-            Decimal::from(num) / den_days
-        }
-        DayCountConvention::Actual365 => {
-            let num = (next_coupon - settlement).num_days();
-            let den_days = dec!(365) / Decimal::from(frequency);
-            Decimal::from(num) / den_days
-        }
-        DayCountConvention::ActualActual => {
-            let num = (next_coupon - settlement).num_days();
-            let den = (next_coupon - last_coupon).num_days();
-            if den == 0 {
-                Decimal::ONE
-            } else {
-                Decimal::from(num) / Decimal::from(den)
-            }
-        }
-    }
-}
-
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -801,6 +704,10 @@ mod tests {
             day_count: DayCountConvention::Thirty360,
             call_price: None,
             call_date: None,
+            currency: Currency::USD,
+            presentation_currency: None,
+            fx_rates: vec![],
+            payment_adjustment: None,
         }
     }
 
@@ -869,6 +776,10 @@ mod tests {
             day_count: DayCountConvention::Thirty360,
             call_price: None,
             call_date: None,
+            currency: Currency::USD,
+            presentation_currency: None,
+            fx_rates: vec![],
+            payment_adjustment: None,
         };
         let result = price_bond(&input).unwrap();
         let out = &result.result;
@@ -908,6 +819,10 @@ mod tests {
             day_count: DayCountConvention::Thirty360,
             call_price: None,
             call_date: None,
+            currency: Currency::USD,
+            presentation_currency: None,
+            fx_rates: vec![],
+            payment_adjustment: None,
         };
 
         let result = price_bond(&input).unwrap();
@@ -943,6 +858,10 @@ mod tests {
             day_count: DayCountConvention::ActualActual,
             call_price: None,
             call_date: None,
+            currency: Currency::USD,
+            presentation_currency: None,
+            fx_rates: vec![],
+            payment_adjustment: None,
         };
 
         let result = price_bond(&input).unwrap();
@@ -975,6 +894,10 @@ mod tests {
             day_count: DayCountConvention::Thirty360,
             call_price: None,
             call_date: None,
+            currency: Currency::USD,
+            presentation_currency: None,
+            fx_rates: vec![],
+            payment_adjustment: None,
         };
 
         let result = price_bond(&input).unwrap();
@@ -1025,6 +948,10 @@ mod tests {
             day_count: DayCountConvention::Thirty360,
             call_price: Some(dec!(1020)),
             call_date: Some(NaiveDate::from_ymd_opt(2029, 1, 15).unwrap()),
+            currency: Currency::USD,
+            presentation_currency: None,
+            fx_rates: vec![],
+            payment_adjustment: None,
         };
 
         let result = price_bond(&input).unwrap();
@@ -1058,6 +985,10 @@ mod tests {
             day_count: DayCountConvention::Thirty360,
             call_price: Some(dec!(1020)),
             call_date: Some(NaiveDate::from_ymd_opt(2029, 1, 15).unwrap()),
+            currency: Currency::USD,
+            presentation_currency: None,
+            fx_rates: vec![],
+            payment_adjustment: None,
         };
 
         let result = price_bond(&input).unwrap();
@@ -1109,6 +1040,10 @@ mod tests {
             day_count: DayCountConvention::Thirty360,
             call_price: None,
             call_date: None,
+            currency: Currency::USD,
+            presentation_currency: None,
+            fx_rates: vec![],
+            payment_adjustment: None,
         };
         let result = price_bond(&input).unwrap();
         let out = &result.result;
@@ -1145,6 +1080,10 @@ mod tests {
             day_count: DayCountConvention::Thirty360,
             call_price: None,
             call_date: None,
+            currency: Currency::USD,
+            presentation_currency: None,
+            fx_rates: vec![],
+            payment_adjustment: None,
         };
         let result = price_bond(&input).unwrap();
         let out = &result.result;
@@ -1281,6 +1220,10 @@ mod tests {
             day_count: DayCountConvention::Actual365,
             call_price: None,
             call_date: None,
+            currency: Currency::USD,
+            presentation_currency: None,
+            fx_rates: vec![],
+            payment_adjustment: None,
         };
         let result = price_bond(&input).unwrap();
         let out = &result.result;
@@ -1315,4 +1258,109 @@ mod tests {
             out.years_to_maturity
         );
     }
+
+    // -----------------------------------------------------------------------
+    // 21. Presentation currency translation
+    // -----------------------------------------------------------------------
+    #[test]
+    fn test_presentation_currency_translation() {
+        let mut input = semi_annual_bond(dec!(0.05), dec!(0.05));
+        input.currency = Currency::EUR;
+        input.presentation_currency = Some(Currency::USD);
+        input.fx_rates = vec![FxRate {
+            base: Currency::EUR,
+            quote: Currency::USD,
+            rate: dec!(1.10),
+        }];
+
+        let out = price_bond(&input).unwrap().result;
+        let pres = out.presentation.unwrap();
+        assert_eq!(out.currency, Currency::EUR);
+        assert_eq!(pres.currency, Currency::USD);
+        assert_eq!(pres.clean_price, out.clean_price * dec!(1.10));
+        assert_eq!(pres.dirty_price, out.dirty_price * dec!(1.10));
+        assert_eq!(pres.accrued_interest, out.accrued_interest * dec!(1.10));
+    }
+
+    // -----------------------------------------------------------------------
+    // 22. Currency defaults to USD when omitted from JSON
+    // -----------------------------------------------------------------------
+    #[test]
+    fn test_currency_defaults_when_omitted() {
+        let json = serde_json::json!({
+            "face_value": "1000",
+            "coupon_rate": "0.05",
+            "coupon_frequency": 2,
+            "ytm": "0.05",
+            "settlement_date": "2024-01-15",
+            "maturity_date": "2029-01-15",
+            "day_count": "Thirty360"
+        });
+        let input: BondPricingInput = serde_json::from_value(json).unwrap();
+        assert_eq!(input.currency, Currency::USD);
+        assert!(input.fx_rates.is_empty());
+
+        let out = price_bond(&input).unwrap().result;
+        assert!(out.presentation.is_none());
+    }
+
+    // -----------------------------------------------------------------------
+    // 23. Accrued interest depends on the day count convention
+    // -----------------------------------------------------------------------
+    #[test]
+    fn test_accrued_interest_by_day_count() {
+        let mut input = semi_annual_bond(dec!(0.05), dec!(0.05));
+        input.settlement_date = NaiveDate::from_ymd_opt(2024, 4, 15).unwrap();
+
+        input.day_count = DayCountConvention::ActualActual;
+        let icma = price_bond(&input).unwrap().result.accrued_interest;
+        input.day_count = DayCountConvention::Actual360;
+        let act360 = price_bond(&input).unwrap().result.accrued_interest;
+
+        // 91 of 182 days under ICMA; 91 / 180 under ACT/360
+        assert_eq!(icma, dec!(12.5));
+        assert!((act360 - dec!(25) * dec!(91) / dec!(180)).abs() < dec!(0.000001));
+    }
+
+    // -----------------------------------------------------------------------
+    // 24. Month-end schedules do not drift when rolled back from maturity
+    // -----------------------------------------------------------------------
+    #[test]
+    fn test_month_end_schedule_no_drift() {
+        let mut input = semi_annual_bond(dec!(0.05), dec!(0.05));
+        input.settlement_date = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+        input.maturity_date = NaiveDate::from_ymd_opt(2026, 8, 31).unwrap();
+
+        let out = price_bond(&input).unwrap().result;
+        let dates: Vec<NaiveDate> = out.total_cashflows.iter().map(|c| c.date).collect();
+        assert_eq!(dates[0], NaiveDate::from_ymd_opt(2024, 8, 31).unwrap());
+        assert_eq!(dates[1], NaiveDate::from_ymd_opt(2025, 2, 28).unwrap());
+        assert_eq!(dates[2], NaiveDate::from_ymd_opt(2025, 8, 31).unwrap());
+    }
+
+    // -----------------------------------------------------------------------
+    // 25. Payment dates roll onto business days; pricing is unchanged
+    // -----------------------------------------------------------------------
+    #[test]
+    fn test_payment_dates_business_day_adjusted() {
+        use crate::fixed_income::calendars::{BusinessDayConvention, HolidayCalendar};
+
+        let mut input = semi_annual_bond(dec!(0.05), dec!(0.05));
+        input.settlement_date = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+        input.maturity_date = NaiveDate::from_ymd_opt(2026, 8, 31).unwrap();
+        let unadjusted = price_bond(&input).unwrap().result;
+
+        input.payment_adjustment = Some(DateAdjustment {
+            calendar: HolidayCalendar::WeekendsOnly,
+            convention: BusinessDayConvention::ModifiedFollowing,
+        });
+        let out = price_bond(&input).unwrap().result;
+        let dates: Vec<NaiveDate> = out.total_cashflows.iter().map(|c| c.date).collect();
+        // 2024-08-31 is a Saturday and 2025-08-31 a Sunday: roll back within month
+        assert_eq!(dates[0], NaiveDate::from_ymd_opt(2024, 8, 30).unwrap());
+        assert_eq!(dates[1], NaiveDate::from_ymd_opt(2025, 2, 28).unwrap());
+        assert_eq!(dates[2], NaiveDate::from_ymd_opt(2025, 8, 29).unwrap());
+        assert_eq!(out.clean_price, unadjusted.clean_price);
+        assert_eq!(out.accrued_interest, unadjusted.accrued_interest);
+    }
 }
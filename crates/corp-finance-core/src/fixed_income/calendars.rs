@@ -0,0 +1,428 @@
+//! Holiday calendars and business-day roll conventions.
+//!
+//! Rule-based calendars for TARGET2, US (Federal Reserve / Fedwire) and UK
+//! (London) settlement, plus weekend-only and user-supplied calendars.
+//! Schedules built elsewhere (bond coupons, swap payments, securitisation
+//! distribution dates) are generated unadjusted and rolled onto business days
+//! here.
+
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+use serde::{Deserialize, Serialize};
+
+use super::daycount::days_in_month;
+
+// ---------------------------------------------------------------------------
+// Types
+// ---------------------------------------------------------------------------
+
+/// Holiday calendar determining which dates are business days.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum HolidayCalendar {
+    /// Saturdays and Sundays only
+    WeekendsOnly,
+    /// TARGET2 (EUR settlement)
+    Target,
+    /// US Federal Reserve / Fedwire (USD settlement)
+    UnitedStates,
+    /// England and Wales bank holidays (GBP settlement)
+    UnitedKingdom,
+    /// Weekends plus an explicit list of holidays
+    Custom { holidays: Vec<NaiveDate> },
+    /// A date is a business day only if it is one in every calendar
+    Joint(Vec<HolidayCalendar>),
+}
+
+/// Rule for rolling a date that falls on a non-business day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum BusinessDayConvention {
+    /// Leave the date as is
+    Unadjusted,
+    /// Next business day
+    Following,
+    /// Next business day unless that crosses into the next month, in which
+    /// case the previous business day
+    #[default]
+    ModifiedFollowing,
+    /// Previous business day
+    Preceding,
+}
+
+/// Calendar plus roll convention applied to unadjusted schedule dates.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DateAdjustment {
+    pub calendar: HolidayCalendar,
+    #[serde(default)]
+    pub convention: BusinessDayConvention,
+}
+
+impl DateAdjustment {
+    /// Roll `date` onto a business day under this adjustment.
+    pub fn adjust(&self, date: NaiveDate) -> NaiveDate {
+        adjust_date(date, self.convention, &self.calendar)
+    }
+}
+
+impl HolidayCalendar {
+    /// True if `date` is a weekend day or a holiday in this calendar.
+    pub fn is_holiday(&self, date: NaiveDate) -> bool {
+        if is_weekend(date) {
+            return true;
+        }
+        match self {
+            HolidayCalendar::WeekendsOnly => false,
+            HolidayCalendar::Target => is_target_holiday(date),
+            HolidayCalendar::UnitedStates => is_us_holiday(date),
+            HolidayCalendar::UnitedKingdom => is_uk_holiday(date),
+            HolidayCalendar::Custom { holidays } => holidays.contains(&date),
+            HolidayCalendar::Joint(calendars) => calendars.iter().any(|c| c.is_holiday(date)),
+        }
+    }
+
+    pub fn is_business_day(&self, date: NaiveDate) -> bool {
+        !self.is_holiday(date)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Public API
+// ---------------------------------------------------------------------------
+
+/// Roll `date` onto a business day of `calendar` using `convention`.
+pub fn adjust_date(
+    date: NaiveDate,
+    convention: BusinessDayConvention,
+    calendar: &HolidayCalendar,
+) -> NaiveDate {
+    match convention {
+        BusinessDayConvention::Unadjusted => date,
+        BusinessDayConvention::Following => roll(date, 1, calendar),
+        BusinessDayConvention::Preceding => roll(date, -1, calendar),
+        BusinessDayConvention::ModifiedFollowing => {
+            let following = roll(date, 1, calendar);
+            if following.month() != date.month() {
+                roll(date, -1, calendar)
+            } else {
+                following
+            }
+        }
+    }
+}
+
+/// Move `n` business days forward (or backward if negative) from `date`.
+pub fn add_business_days(date: NaiveDate, n: i32, calendar: &HolidayCalendar) -> NaiveDate {
+    let step = if n >= 0 { 1 } else { -1 };
+    let mut remaining = n.abs();
+    let mut current = date;
+    while remaining > 0 {
+        current += Duration::days(step);
+        if calendar.is_business_day(current) {
+            remaining -= 1;
+        }
+    }
+    current
+}
+
+/// Business days in `(start, end]`.
+pub fn business_days_between(start: NaiveDate, end: NaiveDate, calendar: &HolidayCalendar) -> i64 {
+    if end <= start {
+        return 0;
+    }
+    let mut count = 0;
+    let mut current = start;
+    while current < end {
+        current += Duration::days(1);
+        if calendar.is_business_day(current) {
+            count += 1;
+        }
+    }
+    count
+}
+
+// ---------------------------------------------------------------------------
+// Calendar rules
+// ---------------------------------------------------------------------------
+
+fn roll(date: NaiveDate, step: i64, calendar: &HolidayCalendar) -> NaiveDate {
+    let mut current = date;
+    while calendar.is_holiday(current) {
+        current += Duration::days(step);
+    }
+    current
+}
+
+fn is_weekend(date: NaiveDate) -> bool {
+    matches!(date.weekday(), Weekday::Sat | Weekday::Sun)
+}
+
+fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).expect("valid calendar date")
+}
+
+/// Easter Sunday (anonymous Gregorian algorithm).
+fn easter_sunday(year: i32) -> NaiveDate {
+    let a = year % 19;
+    let b = year / 100;
+    let c = year % 100;
+    let d = b / 4;
+    let e = b % 4;
+    let f = (b + 8) / 25;
+    let g = (b - f + 1) / 3;
+    let h = (19 * a + b - d - g + 15) % 30;
+    let i = c / 4;
+    let k = c % 4;
+    let l = (32 + 2 * e + 2 * i - h - k) % 7;
+    let m = (a + 11 * h + 22 * l) / 451;
+    let month = (h + l - 7 * m + 114) / 31;
+    let day = (h + l - 7 * m + 114) % 31 + 1;
+    ymd(year, month as u32, day as u32)
+}
+
+/// The `n`-th `weekday` of a month (1-based).
+fn nth_weekday(year: i32, month: u32, weekday: Weekday, n: u32) -> NaiveDate {
+    let first = ymd(year, month, 1);
+    let offset = (7 + weekday.num_days_from_monday() as i64
+        - first.weekday().num_days_from_monday() as i64)
+        % 7;
+    first + Duration::days(offset + 7 * (n as i64 - 1))
+}
+
+/// The last `weekday` of a month.
+fn last_weekday(year: i32, month: u32, weekday: Weekday) -> NaiveDate {
+    let last = ymd(year, month, days_in_month(year, month));
+    let offset = (7 + last.weekday().num_days_from_monday() as i64
+        - weekday.num_days_from_monday() as i64)
+        % 7;
+    last - Duration::days(offset)
+}
+
+fn is_target_holiday(date: NaiveDate) -> bool {
+    let easter = easter_sunday(date.year());
+    let (m, d) = (date.month(), date.day());
+    (m == 1 && d == 1)
+        || date == easter - Duration::days(2)
+        || date == easter + Duration::days(1)
+        || (m == 5 && d == 1)
+        || (m == 12 && (d == 25 || d == 26))
+}
+
+/// Federal Reserve holidays. A Sunday holiday is observed on Monday; a
+/// Saturday holiday is not moved.
+fn is_us_holiday(date: NaiveDate) -> bool {
+    let y = date.year();
+    let observed = |month: u32, day: u32| {
+        let h = ymd(y, month, day);
+        if h.weekday() == Weekday::Sun {
+            h + Duration::days(1)
+        } else {
+            h
+        }
+    };
+
+    date == observed(1, 1)
+        || date == nth_weekday(y, 1, Weekday::Mon, 3)
+        || date == nth_weekday(y, 2, Weekday::Mon, 3)
+        || date == last_weekday(y, 5, Weekday::Mon)
+        || (y >= 2022 && date == observed(6, 19))
+        || date == observed(7, 4)
+        || date == nth_weekday(y, 9, Weekday::Mon, 1)
+        || date == nth_weekday(y, 10, Weekday::Mon, 2)
+        || date == observed(11, 11)
+        || date == nth_weekday(y, 11, Weekday::Thu, 4)
+        || date == observed(12, 25)
+}
+
+/// England and Wales bank holidays with weekend substitution. One-off
+/// holidays (jubilees, state funerals) must be added via a custom calendar.
+fn is_uk_holiday(date: NaiveDate) -> bool {
+    let y = date.year();
+    let easter = easter_sunday(y);
+
+    let new_year = match ymd(y, 1, 1).weekday() {
+        Weekday::Sat => ymd(y, 1, 3),
+        Weekday::Sun => ymd(y, 1, 2),
+        _ => ymd(y, 1, 1),
+    };
+    // Christmas and Boxing Day substitutes fall on the next free weekdays
+    let (christmas, boxing) = match ymd(y, 12, 25).weekday() {
+        Weekday::Fri => (ymd(y, 12, 25), ymd(y, 12, 28)),
+        Weekday::Sat => (ymd(y, 12, 27), ymd(y, 12, 28)),
+        Weekday::Sun => (ymd(y, 12, 27), ymd(y, 12, 26)),
+        _ => (ymd(y, 12, 25), ymd(y, 12, 26)),
+    };
+
+    date == new_year
+        || date == easter - Duration::days(2)
+        || date == easter + Duration::days(1)
+        || date == nth_weekday(y, 5, Weekday::Mon, 1)
+        || date == last_weekday(y, 5, Weekday::Mon)
+        || date == last_weekday(y, 8, Weekday::Mon)
+        || date == christmas
+        || date == boxing
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn d(y: i32, m: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, day).unwrap()
+    }
+
+    #[test]
+    fn test_easter_dates() {
+        assert_eq!(easter_sunday(2024), d(2024, 3, 31));
+        assert_eq!(easter_sunday(2025), d(2025, 4, 20));
+        assert_eq!(easter_sunday(2026), d(2026, 4, 5));
+    }
+
+    #[test]
+    fn test_weekends_are_holidays() {
+        let cal = HolidayCalendar::WeekendsOnly;
+        assert!(cal.is_holiday(d(2024, 6, 15))); // Saturday
+        assert!(cal.is_holiday(d(2024, 6, 16))); // Sunday
+        assert!(cal.is_business_day(d(2024, 6, 17)));
+    }
+
+    #[test]
+    fn test_target_holidays() {
+        let cal = HolidayCalendar::Target;
+        assert!(cal.is_holiday(d(2024, 3, 29))); // Good Friday
+        assert!(cal.is_holiday(d(2024, 4, 1))); // Easter Monday
+        assert!(cal.is_holiday(d(2024, 5, 1)));
+        assert!(cal.is_holiday(d(2024, 12, 26)));
+        assert!(cal.is_business_day(d(2024, 7, 4)));
+    }
+
+    #[test]
+    fn test_us_holidays() {
+        let cal = HolidayCalendar::UnitedStates;
+        assert!(cal.is_holiday(d(2024, 1, 15))); // MLK Day
+        assert!(cal.is_holiday(d(2024, 5, 27))); // Memorial Day
+        assert!(cal.is_holiday(d(2024, 11, 28))); // Thanksgiving
+        assert!(cal.is_holiday(d(2023, 6, 19))); // Juneteenth
+        assert!(cal.is_business_day(d(2021, 6, 18)));
+        // Christmas 2022 fell on Sunday, observed Monday 26th
+        assert!(cal.is_holiday(d(2022, 12, 26)));
+        assert!(cal.is_business_day(d(2024, 3, 29))); // Good Friday is not a Fed holiday
+    }
+
+    #[test]
+    fn test_uk_holidays_with_substitution() {
+        let cal = HolidayCalendar::UnitedKingdom;
+        assert!(cal.is_holiday(d(2024, 5, 6))); // Early May
+        assert!(cal.is_holiday(d(2024, 8, 26))); // Summer
+                                                 // 2021: Christmas Saturday, Boxing Sunday -> Mon 27 and Tue 28
+        assert!(cal.is_holiday(d(2021, 12, 27)));
+        assert!(cal.is_holiday(d(2021, 12, 28)));
+        // 2022: Christmas Sunday -> Boxing Mon 26, substitute Tue 27
+        assert!(cal.is_holiday(d(2022, 12, 26)));
+        assert!(cal.is_holiday(d(2022, 12, 27)));
+        // 2022: New Year Saturday -> Monday 3rd
+        assert!(cal.is_holiday(d(2022, 1, 3)));
+    }
+
+    #[test]
+    fn test_custom_and_joint_calendars() {
+        let custom = HolidayCalendar::Custom {
+            holidays: vec![d(2024, 7, 10)],
+        };
+        assert!(custom.is_holiday(d(2024, 7, 10)));
+
+        let joint = HolidayCalendar::Joint(vec![
+            HolidayCalendar::Target,
+            HolidayCalendar::UnitedKingdom,
+        ]);
+        assert!(joint.is_holiday(d(2024, 8, 26))); // UK only
+        assert!(joint.is_holiday(d(2024, 5, 1))); // TARGET only
+        assert!(joint.is_business_day(d(2024, 8, 27)));
+    }
+
+    #[test]
+    fn test_following_and_preceding() {
+        let cal = HolidayCalendar::WeekendsOnly;
+        let saturday = d(2024, 6, 15);
+        assert_eq!(
+            adjust_date(saturday, BusinessDayConvention::Following, &cal),
+            d(2024, 6, 17)
+        );
+        assert_eq!(
+            adjust_date(saturday, BusinessDayConvention::Preceding, &cal),
+            d(2024, 6, 14)
+        );
+        assert_eq!(
+            adjust_date(saturday, BusinessDayConvention::Unadjusted, &cal),
+            saturday
+        );
+    }
+
+    #[test]
+    fn test_modified_following_stays_in_month() {
+        let cal = HolidayCalendar::WeekendsOnly;
+        // 2024-08-31 is a Saturday; following would cross into September
+        assert_eq!(
+            adjust_date(
+                d(2024, 8, 31),
+                BusinessDayConvention::ModifiedFollowing,
+                &cal
+            ),
+            d(2024, 8, 30)
+        );
+        // Mid-month behaves like following
+        assert_eq!(
+            adjust_date(
+                d(2024, 6, 15),
+                BusinessDayConvention::ModifiedFollowing,
+                &cal
+            ),
+            d(2024, 6, 17)
+        );
+    }
+
+    #[test]
+    fn test_business_day_on_business_day_is_unchanged() {
+        let cal = HolidayCalendar::Target;
+        let date = d(2024, 6, 12);
+        for conv in [
+            BusinessDayConvention::Following,
+            BusinessDayConvention::ModifiedFollowing,
+            BusinessDayConvention::Preceding,
+        ] {
+            assert_eq!(adjust_date(date, conv, &cal), date);
+        }
+    }
+
+    #[test]
+    fn test_add_business_days_skips_holidays() {
+        let cal = HolidayCalendar::Target;
+        // Thu 2024-03-28 + 1 business day skips Good Friday and Easter Monday
+        assert_eq!(add_business_days(d(2024, 3, 28), 1, &cal), d(2024, 4, 2));
+        assert_eq!(add_business_days(d(2024, 4, 2), -1, &cal), d(2024, 3, 28));
+        assert_eq!(add_business_days(d(2024, 4, 2), 0, &cal), d(2024, 4, 2));
+    }
+
+    #[test]
+    fn test_business_days_between() {
+        let cal = HolidayCalendar::WeekendsOnly;
+        // Mon 2024-06-10 to Mon 2024-06-17: Tue..Fri + Mon = 5
+        assert_eq!(
+            business_days_between(d(2024, 6, 10), d(2024, 6, 17), &cal),
+            5
+        );
+        assert_eq!(
+            business_days_between(d(2024, 6, 17), d(2024, 6, 10), &cal),
+            0
+        );
+    }
+
+    #[test]
+    fn test_date_adjustment_default_convention() {
+        let adj: DateAdjustment = serde_json::from_str(r#"{"calendar":"Target"}"#).unwrap();
+        assert_eq!(adj.convention, BusinessDayConvention::ModifiedFollowing);
+        // Good Friday 2024: following lands in April, so roll back to Thursday
+        assert_eq!(adj.adjust(d(2024, 3, 29)), d(2024, 3, 28));
+    }
+}
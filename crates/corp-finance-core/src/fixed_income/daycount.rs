@@ -0,0 +1,387 @@
+//! Day count conventions shared by the fixed income and swap analytics.
+//!
+//! Supports ACT/360, ACT/365 Fixed, 30/360 (US bond basis) and ACT/ACT ICMA.
+//! Coupon-period fractions drive accrued interest and the fractional first
+//! discount period; year fractions turn settlement/maturity dates into the
+//! tenors used by yield, duration and swap calculations.
+
+use chrono::{Datelike, NaiveDate};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+
+use crate::error::CorpFinanceError;
+use crate::CorpFinanceResult;
+
+// ---------------------------------------------------------------------------
+// Types
+// ---------------------------------------------------------------------------
+
+pub use crate::types::DayCountConvention;
+
+/// Settlement and maturity dates for analytics that otherwise take a tenor in
+/// years. When supplied, the tenor is derived with the given convention.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DatedTenor {
+    pub settlement_date: NaiveDate,
+    pub maturity_date: NaiveDate,
+    pub day_count: DayCountConvention,
+}
+
+impl DatedTenor {
+    /// Years from settlement to maturity under the tenor's convention.
+    pub fn years_to_maturity(&self, frequency: u8) -> CorpFinanceResult<Decimal> {
+        if self.maturity_date <= self.settlement_date {
+            return Err(CorpFinanceError::InvalidInput {
+                field: "maturity_date".into(),
+                reason: "Maturity date must be after settlement date".into(),
+            });
+        }
+        Ok(year_fraction(
+            self.day_count,
+            self.settlement_date,
+            self.maturity_date,
+            frequency,
+        ))
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Public API
+// ---------------------------------------------------------------------------
+
+/// Days between `start` and `end` under the convention: 30/360 adjusted days
+/// for `Thirty360`, actual calendar days otherwise.
+pub fn day_count_days(convention: DayCountConvention, start: NaiveDate, end: NaiveDate) -> i64 {
+    match convention {
+        DayCountConvention::Thirty360 => thirty_360_days(start, end) as i64,
+        _ => (end - start).num_days(),
+    }
+}
+
+/// Fraction of the coupon period `[period_start, period_end]` covered by
+/// `[start, end]`. For ACT/360 and ACT/365 the period length is the nominal
+/// 360/freq or 365/freq days; 30/360 and ACT/ACT ICMA use the period's own
+/// day count.
+pub fn period_fraction(
+    convention: DayCountConvention,
+    start: NaiveDate,
+    end: NaiveDate,
+    period_start: NaiveDate,
+    period_end: NaiveDate,
+    frequency: u8,
+) -> Decimal {
+    let freq = Decimal::from(frequency.max(1));
+    let days = Decimal::from(day_count_days(convention, start, end));
+
+    let period_days = match convention {
+        DayCountConvention::Actual360 => dec!(360) / freq,
+        DayCountConvention::Actual365 => dec!(365) / freq,
+        DayCountConvention::Thirty360 | DayCountConvention::ActualActual => {
+            Decimal::from(day_count_days(convention, period_start, period_end))
+        }
+    };
+
+    if period_days.is_zero() {
+        Decimal::ZERO
+    } else {
+        days / period_days
+    }
+}
+
+/// Year fraction between `start` and `end`.
+///
+/// ACT/ACT ICMA rolls quasi-coupon periods back from `end` at the given
+/// frequency: each full period counts 1/freq and the stub counts its
+/// actual-day share of the enclosing period.
+pub fn year_fraction(
+    convention: DayCountConvention,
+    start: NaiveDate,
+    end: NaiveDate,
+    frequency: u8,
+) -> Decimal {
+    if end < start {
+        return -year_fraction(convention, end, start, frequency);
+    }
+
+    match convention {
+        DayCountConvention::Thirty360 => Decimal::from(thirty_360_days(start, end)) / dec!(360),
+        DayCountConvention::Actual360 => Decimal::from((end - start).num_days()) / dec!(360),
+        DayCountConvention::Actual365 => Decimal::from((end - start).num_days()) / dec!(365),
+        DayCountConvention::ActualActual => icma_year_fraction(start, end, frequency),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Date helpers
+// ---------------------------------------------------------------------------
+
+/// Add (or, if negative, subtract) months, clamping the day to the month's max.
+pub(crate) fn add_months(date: NaiveDate, months: i32) -> NaiveDate {
+    let total_months = date.year() * 12 + date.month() as i32 - 1 + months;
+    let new_year = total_months.div_euclid(12);
+    let new_month = (total_months.rem_euclid(12) + 1) as u32;
+    let max_day = days_in_month(new_year, new_month);
+    let day = date.day().min(max_day);
+    NaiveDate::from_ymd_opt(new_year, new_month, day).unwrap_or(date)
+}
+
+/// Number of days in a given month/year.
+pub(crate) fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 => {
+            if is_leap_year(year) {
+                29
+            } else {
+                28
+            }
+        }
+        _ => 30,
+    }
+}
+
+fn is_leap_year(year: i32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+/// Compute 30/360 US day count (raw days, not fraction).
+fn thirty_360_days(start: NaiveDate, end: NaiveDate) -> i32 {
+    let mut d1 = start.day() as i32;
+    let mut d2 = end.day() as i32;
+    let m1 = start.month() as i32;
+    let m2 = end.month() as i32;
+    let y1 = start.year();
+    let y2 = end.year();
+
+    if d1 == 31 {
+        d1 = 30;
+    }
+    if d2 == 31 && d1 >= 30 {
+        d2 = 30;
+    }
+
+    (y2 - y1) * 360 + (m2 - m1) * 30 + (d2 - d1)
+}
+
+/// ACT/ACT ICMA year fraction using quasi-coupon periods ending at `end`.
+fn icma_year_fraction(start: NaiveDate, end: NaiveDate, frequency: u8) -> Decimal {
+    let frequency = if frequency == 0 || 12 % frequency != 0 {
+        1
+    } else {
+        frequency
+    };
+    let months_per_period = 12 / frequency as i32;
+    let freq = Decimal::from(frequency);
+
+    let mut fraction = Decimal::ZERO;
+    let mut period_end = end;
+    let mut k = 1;
+    loop {
+        // Roll from `end` each time so month-end clamping does not drift
+        let period_start = add_months(end, -months_per_period * k);
+        if period_start <= start {
+            let stub_days = (period_end - start).num_days();
+            let period_days = (period_end - period_start).num_days();
+            if period_days > 0 {
+                fraction += Decimal::from(stub_days) / Decimal::from(period_days) / freq;
+            }
+            return fraction;
+        }
+        fraction += Decimal::ONE / freq;
+        period_end = period_start;
+        k += 1;
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn d(y: i32, m: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, day).unwrap()
+    }
+
+    #[test]
+    fn test_thirty_360_days_end_of_month() {
+        // 31st is treated as the 30th on both legs when d1 >= 30
+        assert_eq!(
+            day_count_days(
+                DayCountConvention::Thirty360,
+                d(2024, 1, 31),
+                d(2024, 3, 31)
+            ),
+            60
+        );
+        assert_eq!(
+            day_count_days(
+                DayCountConvention::Thirty360,
+                d(2024, 1, 15),
+                d(2024, 7, 15)
+            ),
+            180
+        );
+    }
+
+    #[test]
+    fn test_actual_days() {
+        assert_eq!(
+            day_count_days(DayCountConvention::Actual360, d(2024, 1, 1), d(2024, 3, 1)),
+            60
+        );
+    }
+
+    #[test]
+    fn test_year_fraction_act_360_and_365() {
+        let start = d(2024, 1, 1);
+        let end = d(2025, 1, 1); // 366 days (leap year)
+        assert_eq!(
+            year_fraction(DayCountConvention::Actual360, start, end, 1),
+            dec!(366) / dec!(360)
+        );
+        assert_eq!(
+            year_fraction(DayCountConvention::Actual365, start, end, 1),
+            dec!(366) / dec!(365)
+        );
+    }
+
+    #[test]
+    fn test_year_fraction_thirty_360() {
+        assert_eq!(
+            year_fraction(
+                DayCountConvention::Thirty360,
+                d(2024, 2, 15),
+                d(2024, 8, 15),
+                2
+            ),
+            dec!(0.5)
+        );
+    }
+
+    #[test]
+    fn test_icma_full_periods_are_exact() {
+        // Whole coupon periods count exactly 1/freq regardless of leap days
+        assert_eq!(
+            year_fraction(
+                DayCountConvention::ActualActual,
+                d(2024, 1, 15),
+                d(2029, 1, 15),
+                2
+            ),
+            dec!(5)
+        );
+        assert_eq!(
+            year_fraction(
+                DayCountConvention::ActualActual,
+                d(2023, 6, 30),
+                d(2024, 6, 30),
+                1
+            ),
+            Decimal::ONE
+        );
+    }
+
+    #[test]
+    fn test_icma_stub_period() {
+        // 2024-03-15 to 2024-07-15 inside the semi-annual period Jan 15 - Jul 15 (182 days)
+        let yf = year_fraction(
+            DayCountConvention::ActualActual,
+            d(2024, 3, 15),
+            d(2024, 7, 15),
+            2,
+        );
+        assert_eq!(yf, dec!(122) / dec!(182) / dec!(2));
+    }
+
+    #[test]
+    fn test_year_fraction_reversed_is_negative() {
+        let yf = year_fraction(
+            DayCountConvention::Actual365,
+            d(2025, 1, 1),
+            d(2024, 1, 1),
+            1,
+        );
+        assert_eq!(yf, -(dec!(366) / dec!(365)));
+    }
+
+    #[test]
+    fn test_period_fraction_icma_accrued() {
+        // Settlement 2024-04-15 in period Jan 15 - Jul 15: 91 of 182 actual days
+        let frac = period_fraction(
+            DayCountConvention::ActualActual,
+            d(2024, 1, 15),
+            d(2024, 4, 15),
+            d(2024, 1, 15),
+            d(2024, 7, 15),
+            2,
+        );
+        assert_eq!(frac, dec!(0.5));
+    }
+
+    #[test]
+    fn test_period_fraction_act_360_uses_nominal_period() {
+        let frac = period_fraction(
+            DayCountConvention::Actual360,
+            d(2024, 1, 15),
+            d(2024, 4, 15),
+            d(2024, 1, 15),
+            d(2024, 7, 15),
+            2,
+        );
+        assert_eq!(frac, dec!(91) / dec!(180));
+    }
+
+    #[test]
+    fn test_conventions_disagree_on_same_dates() {
+        let (s, e, ps, pe) = (
+            d(2024, 1, 15),
+            d(2024, 4, 15),
+            d(2024, 1, 15),
+            d(2024, 7, 15),
+        );
+        let act360 = period_fraction(DayCountConvention::Actual360, s, e, ps, pe, 2);
+        let act365 = period_fraction(DayCountConvention::Actual365, s, e, ps, pe, 2);
+        let thirty = period_fraction(DayCountConvention::Thirty360, s, e, ps, pe, 2);
+        assert!(act360 > act365);
+        assert_eq!(thirty, dec!(0.5));
+    }
+
+    #[test]
+    fn test_add_months_clamps_day() {
+        assert_eq!(add_months(d(2024, 1, 31), 1), d(2024, 2, 29));
+        assert_eq!(add_months(d(2024, 3, 31), -1), d(2024, 2, 29));
+        assert_eq!(add_months(d(2024, 11, 30), 3), d(2025, 2, 28));
+    }
+
+    #[test]
+    fn test_dated_tenor_years() {
+        let tenor = DatedTenor {
+            settlement_date: d(2024, 1, 15),
+            maturity_date: d(2027, 1, 15),
+            day_count: DayCountConvention::ActualActual,
+        };
+        assert_eq!(tenor.years_to_maturity(2).unwrap(), dec!(3));
+    }
+
+    #[test]
+    fn test_dated_tenor_rejects_inverted_dates() {
+        let tenor = DatedTenor {
+            settlement_date: d(2027, 1, 15),
+            maturity_date: d(2024, 1, 15),
+            day_count: DayCountConvention::Actual365,
+        };
+        assert!(tenor.years_to_maturity(2).is_err());
+    }
+
+    #[test]
+    fn test_icma_serde_alias() {
+        let conv: DayCountConvention = serde_json::from_str("\"ActualActualIcma\"").unwrap();
+        assert_eq!(conv, DayCountConvention::ActualActual);
+        assert_eq!(conv.label(), "ACT/ACT ICMA");
+    }
+}
@@ -7,6 +7,8 @@ use crate::error::CorpFinanceError;
 use crate::types::{with_metadata, ComputationOutput, Money, Rate};
 use crate::CorpFinanceResult;
 
+use super::daycount::DatedTenor;
+
 // ---------------------------------------------------------------------------
 // Types
 // ---------------------------------------------------------------------------
@@ -23,7 +25,12 @@ pub struct DurationInput {
     /// Yield to maturity as a decimal
     pub ytm: Rate,
     /// Years remaining until maturity
+    #[serde(default)]
     pub years_to_maturity: Decimal,
+    /// Settlement/maturity dates; when set, `years_to_maturity` is derived
+    /// from them using the given day count convention
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dates: Option<DatedTenor>,
     /// Yield shift in basis points for effective duration (default 10 bps)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub yield_shift_bps: Option<Decimal>,
@@ -84,6 +91,19 @@ pub fn calculate_duration(
     let start = Instant::now();
     let warnings: Vec<String> = Vec::new();
 
+    // Resolve tenor from dates if supplied
+    let resolved;
+    let input = match &input.dates {
+        Some(dates) => {
+            resolved = DurationInput {
+                years_to_maturity: dates.years_to_maturity(input.coupon_frequency)?,
+                ..input.clone()
+            };
+            &resolved
+        }
+        None => input,
+    };
+
     validate_input(input)?;
 
     let freq = Decimal::from(input.coupon_frequency);
@@ -183,7 +203,11 @@ pub fn calculate_duration(
         "coupon_frequency": input.coupon_frequency,
         "yield_shift_bps": input.yield_shift_bps.unwrap_or(dec!(10)).to_string(),
         "settlement": "assumed on coupon date (no accrued interest)",
-        "day_count": "30/360 (period-based)",
+        "day_count": input
+            .dates
+            .as_ref()
+            .map_or("30/360 (period-based)", |d| d.day_count.label()),
+        "years_to_maturity": input.years_to_maturity.to_string(),
         "price_change_estimate_shift": "100 bps"
     });
 
@@ -521,6 +545,7 @@ mod tests {
             coupon_frequency: 2,
             ytm: dec!(0.05),
             years_to_maturity: dec!(10),
+            dates: None,
             yield_shift_bps: None,
             key_rate_tenors: None,
         }
@@ -534,6 +559,7 @@ mod tests {
             coupon_frequency: 2,
             ytm: dec!(0.05),
             years_to_maturity: dec!(10),
+            dates: None,
             yield_shift_bps: None,
             key_rate_tenors: None,
         }
@@ -655,10 +681,12 @@ mod tests {
     fn test_longer_maturity_higher_duration() {
         let short = DurationInput {
             years_to_maturity: dec!(5),
+            dates: None,
             ..par_bond_input()
         };
         let long = DurationInput {
             years_to_maturity: dec!(30),
+            dates: None,
             ..par_bond_input()
         };
 
@@ -961,4 +989,37 @@ mod tests {
             out.price
         );
     }
+
+    #[test]
+    fn test_years_derived_from_dates() {
+        use super::super::daycount::DayCountConvention;
+        use chrono::NaiveDate;
+
+        let mut input = par_bond_input();
+        input.years_to_maturity = Decimal::ZERO;
+        input.dates = Some(DatedTenor {
+            settlement_date: NaiveDate::from_ymd_opt(2024, 5, 15).unwrap(),
+            maturity_date: NaiveDate::from_ymd_opt(2034, 5, 15).unwrap(),
+            day_count: DayCountConvention::ActualActual,
+        });
+
+        let dated = calculate_duration(&input).unwrap().result;
+        let plain = calculate_duration(&par_bond_input()).unwrap().result;
+        assert_eq!(dated.macaulay_duration, plain.macaulay_duration);
+        assert_eq!(dated.price, plain.price);
+    }
+
+    #[test]
+    fn test_inverted_dates_rejected() {
+        use super::super::daycount::DayCountConvention;
+        use chrono::NaiveDate;
+
+        let mut input = par_bond_input();
+        input.dates = Some(DatedTenor {
+            settlement_date: NaiveDate::from_ymd_opt(2034, 5, 15).unwrap(),
+            maturity_date: NaiveDate::from_ymd_opt(2024, 5, 15).unwrap(),
+            day_count: DayCountConvention::Actual365,
+        });
+        assert!(calculate_duration(&input).is_err());
+    }
 }
@@ -1,4 +1,6 @@
 pub mod bonds;
+pub mod calendars;
+pub mod daycount;
 pub mod duration;
 pub mod spreads;
 pub mod yields;
@@ -3,6 +3,7 @@ use rust_decimal_macros::dec;
 use serde::{Deserialize, Serialize};
 use std::time::Instant;
 
+use crate::curves::{Compounding, Curve, CurveConventions, CurveInterpolation, Extrapolation};
 use crate::error::CorpFinanceError;
 use crate::types::{with_metadata, ComputationOutput, Money, Rate};
 use crate::CorpFinanceResult;
@@ -29,19 +30,19 @@ const HY_CEILING: Decimal = dec!(0.1000);
 /// Default recovery rate for CDS calculation.
 const DEFAULT_RECOVERY: Decimal = dec!(0.40);
 
+/// Conventions for benchmark curves that do not state their own: annually
+/// compounded spot rates, linear between nodes and extended along the
+/// nearest segment beyond them.
+const BENCHMARK_CURVE: CurveConventions = CurveConventions {
+    compounding: Compounding::Annual,
+    interpolation: CurveInterpolation::Linear,
+    extrapolation: Extrapolation::Linear,
+};
+
 // ---------------------------------------------------------------------------
 // Input / Output types
 // ---------------------------------------------------------------------------
 
-/// A single point on the risk-free benchmark spot curve.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct BenchmarkPoint {
-    /// Time to maturity in years.
-    pub maturity: Decimal,
-    /// Spot rate (annualised, as a decimal, e.g. 0.05 = 5%).
-    pub rate: Rate,
-}
-
 /// Input for credit-spread calculations.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CreditSpreadInput {
@@ -56,8 +57,9 @@ pub struct CreditSpreadInput {
     /// Years remaining until maturity.
     pub years_to_maturity: Decimal,
     /// Risk-free benchmark spot-rate curve (at least 2 points, sorted ascending
-    /// by maturity).
-    pub benchmark_curve: Vec<BenchmarkPoint>,
+    /// by maturity). Either a versioned curve document or the legacy array of
+    /// `{maturity, rate}` points.
+    pub benchmark_curve: Curve,
     /// Recovery rate for CDS spread estimate (default 0.40 if omitted).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub recovery_rate: Option<Rate>,
@@ -111,6 +113,10 @@ pub fn calculate_credit_spreads(
 
     // -- Validation ----------------------------------------------------------
     validate_input(input)?;
+    let input = &CreditSpreadInput {
+        benchmark_curve: input.benchmark_curve.with_conventions(BENCHMARK_CURVE),
+        ..input.clone()
+    };
 
     // -- Derived quantities --------------------------------------------------
     let coupon_per_period =
@@ -210,14 +216,7 @@ fn validate_input(input: &CreditSpreadInput) -> CorpFinanceResult<()> {
     }
 
     // Verify benchmark curve is sorted by maturity
-    for w in input.benchmark_curve.windows(2) {
-        if w[1].maturity <= w[0].maturity {
-            return Err(CorpFinanceError::InvalidInput {
-                field: "benchmark_curve".into(),
-                reason: "Benchmark curve must be sorted ascending by maturity".into(),
-            });
-        }
-    }
+    input.benchmark_curve.validate("benchmark_curve")?;
 
     if let Some(rr) = input.recovery_rate {
         if rr < Decimal::ZERO || rr > Decimal::ONE {
@@ -401,37 +400,18 @@ fn solve_ytm(
 // Benchmark interpolation
 // ---------------------------------------------------------------------------
 
-/// Linearly interpolate a rate from the benchmark curve for a given maturity.
+/// Annually compounded spot rate from the benchmark curve for a given
+/// maturity, linear unless the curve states otherwise.
 ///
 /// If `t` is below the first point or above the last, we extrapolate from the
 /// nearest two points.
-fn interpolate_rate(curve: &[BenchmarkPoint], t: Decimal) -> CorpFinanceResult<Rate> {
+fn interpolate_rate(curve: &Curve, t: Decimal) -> CorpFinanceResult<Rate> {
     if curve.len() < 2 {
         return Err(CorpFinanceError::InsufficientData(
             "Need at least 2 benchmark points for interpolation".into(),
         ));
     }
-
-    // Find the two bracketing points.
-    let idx = curve.partition_point(|p| p.maturity < t);
-
-    let (left, right) = if idx == 0 {
-        // t is at or before first point -- extrapolate from first two.
-        (&curve[0], &curve[1])
-    } else if idx >= curve.len() {
-        // t is beyond last point -- extrapolate from last two.
-        (&curve[curve.len() - 2], &curve[curve.len() - 1])
-    } else {
-        (&curve[idx - 1], &curve[idx])
-    };
-
-    let span = right.maturity - left.maturity;
-    if span.is_zero() {
-        return Ok(left.rate);
-    }
-
-    let weight = (t - left.maturity) / span;
-    Ok(left.rate + weight * (right.rate - left.rate))
+    Ok(curve.zero_rate_as(t, Compounding::Annual))
 }
 
 // ---------------------------------------------------------------------------
@@ -445,7 +425,7 @@ fn interpolate_rate(curve: &[BenchmarkPoint], t: Decimal) -> CorpFinanceResult<R
 /// Formula: P = sum[ CF_i / (1 + s_i + z)^t_i ]
 fn price_with_z_spread(
     cashflows: &[(Decimal, Decimal)],
-    curve: &[BenchmarkPoint],
+    curve: &Curve,
     z: Decimal,
 ) -> CorpFinanceResult<Decimal> {
     let mut price = Decimal::ZERO;
@@ -501,7 +481,7 @@ fn iterative_discount(annual_rate: Decimal, t: Decimal) -> Decimal {
 /// Approximated using the same iterative discount approach.
 fn z_spread_price_derivative(
     cashflows: &[(Decimal, Decimal)],
-    curve: &[BenchmarkPoint],
+    curve: &Curve,
     z: Decimal,
 ) -> CorpFinanceResult<Decimal> {
     let mut deriv = Decimal::ZERO;
@@ -634,51 +614,19 @@ mod tests {
     use rust_decimal_macros::dec;
 
     /// Build a flat benchmark curve at a given rate.
-    fn flat_curve(rate: Rate) -> Vec<BenchmarkPoint> {
-        vec![
-            BenchmarkPoint {
-                maturity: dec!(1),
-                rate,
-            },
-            BenchmarkPoint {
-                maturity: dec!(5),
-                rate,
-            },
-            BenchmarkPoint {
-                maturity: dec!(10),
-                rate,
-            },
-            BenchmarkPoint {
-                maturity: dec!(30),
-                rate,
-            },
-        ]
+    fn flat_curve(rate: Rate) -> Curve {
+        Curve::from_zero_rates([dec!(1), dec!(5), dec!(10), dec!(30)].map(|t| (t, rate)))
     }
 
     /// Build a sample upward-sloping benchmark curve.
-    fn sample_curve() -> Vec<BenchmarkPoint> {
-        vec![
-            BenchmarkPoint {
-                maturity: dec!(1),
-                rate: dec!(0.03),
-            },
-            BenchmarkPoint {
-                maturity: dec!(2),
-                rate: dec!(0.035),
-            },
-            BenchmarkPoint {
-                maturity: dec!(5),
-                rate: dec!(0.04),
-            },
-            BenchmarkPoint {
-                maturity: dec!(10),
-                rate: dec!(0.045),
-            },
-            BenchmarkPoint {
-                maturity: dec!(30),
-                rate: dec!(0.05),
-            },
-        ]
+    fn sample_curve() -> Curve {
+        Curve::from_zero_rates([
+            (dec!(1), dec!(0.03)),
+            (dec!(2), dec!(0.035)),
+            (dec!(5), dec!(0.04)),
+            (dec!(10), dec!(0.045)),
+            (dec!(30), dec!(0.05)),
+        ])
     }
 
     /// A par bond on a flat curve should have near-zero spread.
@@ -967,7 +915,7 @@ mod tests {
     // -----------------------------------------------------------------------
     #[test]
     fn test_benchmark_interpolation() {
-        let curve = sample_curve();
+        let curve = sample_curve().with_conventions(BENCHMARK_CURVE);
 
         // Interpolate at t=3, which is between (2, 0.035) and (5, 0.04).
         let rate = interpolate_rate(&curve, dec!(3)).unwrap();
@@ -1068,10 +1016,7 @@ mod tests {
             coupon_frequency: 2,
             market_price: dec!(1000),
             years_to_maturity: dec!(5),
-            benchmark_curve: vec![BenchmarkPoint {
-                maturity: dec!(5),
-                rate: dec!(0.04),
-            }],
+            benchmark_curve: Curve::from_zero_rates([(dec!(5), dec!(0.04))]),
             recovery_rate: None,
             default_probability: None,
         };
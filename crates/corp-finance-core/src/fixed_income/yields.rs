@@ -7,6 +7,8 @@ use crate::error::CorpFinanceError;
 use crate::types::{with_metadata, ComputationOutput, Money, Rate};
 use crate::CorpFinanceResult;
 
+use super::daycount::DatedTenor;
+
 // ---------------------------------------------------------------------------
 // Constants
 // ---------------------------------------------------------------------------
@@ -36,7 +38,12 @@ pub struct BondYieldInput {
     /// Market (dirty) price of the bond.
     pub market_price: Money,
     /// Years remaining until maturity.
+    #[serde(default)]
     pub years_to_maturity: Decimal,
+    /// Settlement/maturity dates; when set, `years_to_maturity` is derived
+    /// from them using the given day count convention.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dates: Option<DatedTenor>,
     /// If true, only compute current yield and skip the Newton-Raphson YTM solve.
     #[serde(default)]
     pub current_yield_only: bool,
@@ -168,6 +175,19 @@ pub fn calculate_bond_yield(
     let start = Instant::now();
     let mut warnings: Vec<String> = Vec::new();
 
+    // -- Resolve tenor from dates if supplied --
+    let resolved;
+    let input = match &input.dates {
+        Some(dates) => {
+            resolved = BondYieldInput {
+                years_to_maturity: dates.years_to_maturity(input.coupon_frequency)?,
+                ..input.clone()
+            };
+            &resolved
+        }
+        None => input,
+    };
+
     // -- Validation --
     validate_bond_yield_input(input)?;
 
@@ -235,7 +255,12 @@ pub fn calculate_bond_yield(
         "ytm_method": "Newton-Raphson",
         "max_iterations": MAX_YTM_ITERATIONS,
         "convergence_eps": "1e-7",
-        "price_type": "dirty"
+        "price_type": "dirty",
+        "day_count": input
+            .dates
+            .as_ref()
+            .map_or("period-based", |d| d.day_count.label()),
+        "years_to_maturity": input.years_to_maturity.to_string()
     });
 
     Ok(with_metadata(
@@ -1048,6 +1073,7 @@ mod tests {
             coupon_frequency: 2,
             market_price: dec!(1000),
             years_to_maturity: dec!(10),
+            dates: None,
             current_yield_only: false,
         }
     }
@@ -1659,4 +1685,19 @@ mod tests {
         let result = bootstrap_spot_curve(&input);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_years_derived_from_dates() {
+        let mut input = par_bond_input();
+        input.years_to_maturity = Decimal::ZERO;
+        input.dates = Some(DatedTenor {
+            settlement_date: chrono::NaiveDate::from_ymd_opt(2024, 3, 1).unwrap(),
+            maturity_date: chrono::NaiveDate::from_ymd_opt(2034, 3, 1).unwrap(),
+            day_count: super::super::daycount::DayCountConvention::ActualActual,
+        });
+
+        let dated = calculate_bond_yield(&input).unwrap();
+        let plain = calculate_bond_yield(&par_bond_input()).unwrap();
+        assert_eq!(dated.result.ytm, plain.result.ytm);
+    }
 }
@@ -0,0 +1,613 @@
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+use std::time::Instant;
+
+use crate::error::CorpFinanceError;
+use crate::types::{with_metadata, ComputationOutput, Money, Rate};
+use crate::CorpFinanceResult;
+
+// ---------------------------------------------------------------------------
+// Types — Backlog Revenue
+// ---------------------------------------------------------------------------
+
+/// How a contract's revenue is recognised over its delivery periods.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RecognitionPolicy {
+    /// Evenly over the delivery periods (subscriptions, maintenance)
+    #[default]
+    Ratable,
+    /// In proportion to costs incurred (cost-to-cost input method)
+    PercentageOfCompletion,
+    /// In full in the final delivery period
+    PointInTime,
+}
+
+/// A signed contract with unrecognised value.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BacklogContract {
+    pub name: String,
+    /// Total contract value
+    pub total_value: Money,
+    /// Revenue already recognised before the forecast
+    #[serde(default)]
+    pub recognized_to_date: Money,
+    #[serde(default)]
+    pub policy: RecognitionPolicy,
+    /// Forecast period in which remaining delivery starts (0 = first period)
+    #[serde(default)]
+    pub start_period: u32,
+    /// Periods over which the remaining value is delivered
+    pub duration_periods: u32,
+    /// Remaining cost by delivery period, for percentage of completion
+    /// (weights; default even)
+    #[serde(default)]
+    pub cost_profile: Vec<Decimal>,
+}
+
+/// An unsigned opportunity, recognised at its probability-weighted value.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PipelineOpportunity {
+    pub name: String,
+    /// Contract value if won
+    pub value: Money,
+    /// Probability of winning
+    pub win_rate: Rate,
+    /// Forecast period in which the contract is expected to be signed
+    pub expected_close_period: u32,
+    /// Periods between signing and the start of delivery
+    #[serde(default)]
+    pub start_lag_periods: u32,
+    #[serde(default)]
+    pub policy: RecognitionPolicy,
+    pub duration_periods: u32,
+    #[serde(default)]
+    pub cost_profile: Vec<Decimal>,
+}
+
+/// Input for backlog and pipeline revenue forecasting.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BacklogRevenueInput {
+    pub company_name: String,
+    /// Number of forecast periods
+    pub periods: u32,
+    /// Optional labels, one per period
+    #[serde(default)]
+    pub period_labels: Vec<String>,
+    #[serde(default)]
+    pub contracts: Vec<BacklogContract>,
+    #[serde(default)]
+    pub pipeline: Vec<PipelineOpportunity>,
+    /// Revenue targets per period; coverage is measured against these when
+    /// given, otherwise against the forecast itself
+    #[serde(default)]
+    pub revenue_targets: Vec<Money>,
+    /// Revenue of the period before the forecast, for growth rates that can
+    /// be passed to the three-statement model
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base_revenue: Option<Money>,
+}
+
+// ---------------------------------------------------------------------------
+// Output types — Backlog Revenue
+// ---------------------------------------------------------------------------
+
+/// Recognition schedule for one contract or opportunity.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RevenueSchedule {
+    pub name: String,
+    /// True for signed backlog, false for weighted pipeline
+    pub contracted: bool,
+    pub policy: RecognitionPolicy,
+    /// Revenue in each forecast period
+    pub revenue: Vec<Money>,
+    /// Revenue falling after the last forecast period
+    pub beyond_horizon: Money,
+}
+
+/// Forecast for one period.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BacklogPeriod {
+    pub label: String,
+    /// Unrecognised contracted value at the start of the period
+    pub opening_backlog: Money,
+    /// Probability-weighted value of pipeline expected to sign this period
+    pub weighted_bookings: Money,
+    pub contracted_revenue: Money,
+    pub pipeline_revenue: Money,
+    pub total_revenue: Money,
+    /// Unrecognised contracted value at the end of the period, including
+    /// weighted pipeline signed to date
+    pub closing_backlog: Money,
+    pub revenue_target: Option<Money>,
+    /// Contracted revenue / target (or / total forecast when no target)
+    pub backlog_coverage: Option<Rate>,
+    /// Revenue from all sources / target
+    pub target_attainment: Option<Rate>,
+}
+
+/// Output of the backlog revenue forecast.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BacklogRevenueOutput {
+    pub periods: Vec<BacklogPeriod>,
+    pub schedules: Vec<RevenueSchedule>,
+    /// Unrecognised value of signed contracts at the start
+    pub total_backlog: Money,
+    /// Probability-weighted value of the pipeline
+    pub weighted_pipeline: Money,
+    pub total_forecast_revenue: Money,
+    /// Contracted share of forecast revenue over the horizon
+    pub contracted_share: Rate,
+    /// Backlog and pipeline revenue falling after the horizon
+    pub revenue_beyond_horizon: Money,
+    /// Period-over-period revenue growth from base_revenue; with annual
+    /// periods these feed `revenue_growth_rates` in the three-statement model
+    pub revenue_growth_rates: Vec<Rate>,
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Spread `amount` from `start` over `duration` periods under `policy`.
+/// Returns revenue within the horizon and the amount falling after it.
+fn schedule(
+    amount: Money,
+    policy: RecognitionPolicy,
+    start: u32,
+    duration: u32,
+    cost_profile: &[Decimal],
+    horizon: u32,
+) -> (Vec<Money>, Money) {
+    let weights: Vec<Decimal> = match policy {
+        RecognitionPolicy::Ratable => vec![Decimal::ONE; duration as usize],
+        RecognitionPolicy::PercentageOfCompletion if !cost_profile.is_empty() => {
+            cost_profile.to_vec()
+        }
+        RecognitionPolicy::PercentageOfCompletion => vec![Decimal::ONE; duration as usize],
+        RecognitionPolicy::PointInTime => {
+            let mut w = vec![Decimal::ZERO; duration as usize];
+            w[duration as usize - 1] = Decimal::ONE;
+            w
+        }
+    };
+    let total_weight: Decimal = weights.iter().sum();
+
+    let mut revenue = vec![Decimal::ZERO; horizon as usize];
+    let mut beyond = Decimal::ZERO;
+    let mut allocated = Decimal::ZERO;
+    for (i, w) in weights.iter().enumerate() {
+        // Last slice takes the remainder so the schedule sums exactly
+        let slice = if i + 1 == weights.len() {
+            amount - allocated
+        } else {
+            amount * w / total_weight
+        };
+        allocated += slice;
+        let period = start as usize + i;
+        match revenue.get_mut(period) {
+            Some(r) => *r += slice,
+            None => beyond += slice,
+        }
+    }
+    (revenue, beyond)
+}
+
+// ---------------------------------------------------------------------------
+// Public API — Backlog Revenue
+// ---------------------------------------------------------------------------
+
+/// Convert contracted backlog and weighted pipeline into a recognised
+/// revenue forecast with backlog coverage by period.
+pub fn forecast_backlog_revenue(
+    input: &BacklogRevenueInput,
+) -> CorpFinanceResult<ComputationOutput<BacklogRevenueOutput>> {
+    let start = Instant::now();
+    let mut warnings: Vec<String> = Vec::new();
+
+    validate_input(input)?;
+
+    let horizon = input.periods;
+    let n = horizon as usize;
+    let mut schedules = Vec::with_capacity(input.contracts.len() + input.pipeline.len());
+
+    for c in &input.contracts {
+        let remaining = c.total_value - c.recognized_to_date;
+        let (revenue, beyond_horizon) = schedule(
+            remaining,
+            c.policy,
+            c.start_period,
+            c.duration_periods,
+            &c.cost_profile,
+            horizon,
+        );
+        schedules.push(RevenueSchedule {
+            name: c.name.clone(),
+            contracted: true,
+            policy: c.policy,
+            revenue,
+            beyond_horizon,
+        });
+    }
+
+    let mut weighted_bookings = vec![Decimal::ZERO; n];
+    for p in &input.pipeline {
+        let weighted = p.value * p.win_rate;
+        if let Some(b) = weighted_bookings.get_mut(p.expected_close_period as usize) {
+            *b += weighted;
+        }
+        let (revenue, beyond_horizon) = schedule(
+            weighted,
+            p.policy,
+            p.expected_close_period + p.start_lag_periods,
+            p.duration_periods,
+            &p.cost_profile,
+            horizon,
+        );
+        schedules.push(RevenueSchedule {
+            name: p.name.clone(),
+            contracted: false,
+            policy: p.policy,
+            revenue,
+            beyond_horizon,
+        });
+    }
+
+    let total_backlog: Money = input
+        .contracts
+        .iter()
+        .map(|c| c.total_value - c.recognized_to_date)
+        .sum();
+    let weighted_pipeline: Money = input.pipeline.iter().map(|p| p.value * p.win_rate).sum();
+
+    let mut periods = Vec::with_capacity(n);
+    let mut backlog = total_backlog;
+    let mut low_coverage = Vec::new();
+    for (t, &bookings) in weighted_bookings.iter().enumerate() {
+        let contracted_revenue: Money = schedules
+            .iter()
+            .filter(|s| s.contracted)
+            .map(|s| s.revenue[t])
+            .sum();
+        let pipeline_revenue: Money = schedules
+            .iter()
+            .filter(|s| !s.contracted)
+            .map(|s| s.revenue[t])
+            .sum();
+        let total_revenue = contracted_revenue + pipeline_revenue;
+        let opening_backlog = backlog;
+        backlog += bookings - total_revenue;
+
+        let label = input
+            .period_labels
+            .get(t)
+            .cloned()
+            .unwrap_or_else(|| format!("Period {}", t + 1));
+        let revenue_target = input.revenue_targets.get(t).copied();
+        let coverage_base = revenue_target.unwrap_or(total_revenue);
+        let backlog_coverage = if coverage_base > Decimal::ZERO {
+            Some(contracted_revenue / coverage_base)
+        } else {
+            None
+        };
+        let target_attainment = revenue_target
+            .filter(|r| *r > Decimal::ZERO)
+            .map(|r| total_revenue / r);
+        if backlog_coverage.is_some_and(|c| c < dec!(0.5)) {
+            low_coverage.push(label.clone());
+        }
+
+        periods.push(BacklogPeriod {
+            label,
+            opening_backlog,
+            weighted_bookings: bookings,
+            contracted_revenue,
+            pipeline_revenue,
+            total_revenue,
+            closing_backlog: backlog,
+            revenue_target,
+            backlog_coverage,
+            target_attainment,
+        });
+    }
+
+    let total_forecast_revenue: Money = periods.iter().map(|p| p.total_revenue).sum();
+    let contracted_total: Money = periods.iter().map(|p| p.contracted_revenue).sum();
+    let contracted_share = if total_forecast_revenue > Decimal::ZERO {
+        contracted_total / total_forecast_revenue
+    } else {
+        Decimal::ZERO
+    };
+    let revenue_beyond_horizon: Money = schedules.iter().map(|s| s.beyond_horizon).sum();
+
+    let mut revenue_growth_rates = Vec::new();
+    if let Some(base) = input.base_revenue {
+        let mut prior = base;
+        for p in &periods {
+            if prior.is_zero() {
+                warnings.push("Zero revenue in a prior period: growth rates stop there".into());
+                break;
+            }
+            revenue_growth_rates.push(p.total_revenue / prior - Decimal::ONE);
+            prior = p.total_revenue;
+        }
+    }
+
+    if !low_coverage.is_empty() {
+        warnings.push(format!(
+            "Backlog covers under 50% of revenue in: {}",
+            low_coverage.join(", ")
+        ));
+    }
+    let late_signings = input
+        .pipeline
+        .iter()
+        .filter(|p| p.expected_close_period >= horizon)
+        .count();
+    if late_signings > 0 {
+        warnings.push(format!(
+            "{late_signings} pipeline opportunities close after the horizon"
+        ));
+    }
+
+    let output = BacklogRevenueOutput {
+        periods,
+        schedules,
+        total_backlog,
+        weighted_pipeline,
+        total_forecast_revenue,
+        contracted_share,
+        revenue_beyond_horizon,
+        revenue_growth_rates,
+    };
+
+    let elapsed = start.elapsed().as_micros() as u64;
+    let assumptions = serde_json::json!({
+        "ratable": "remaining value spread evenly over delivery periods",
+        "percentage_of_completion": "remaining value recognised in proportion to remaining cost profile",
+        "point_in_time": "remaining value recognised in the final delivery period",
+        "pipeline": "probability-weighted value, delivery starts after close plus lag",
+        "coverage_basis": if input.revenue_targets.is_empty() { "forecast revenue" } else { "revenue targets" },
+    });
+
+    Ok(with_metadata(
+        "Backlog and pipeline revenue recognition forecast",
+        &assumptions,
+        warnings,
+        elapsed,
+        output,
+    ))
+}
+
+fn validate_schedule(
+    field: &str,
+    duration_periods: u32,
+    policy: RecognitionPolicy,
+    cost_profile: &[Decimal],
+) -> CorpFinanceResult<()> {
+    if duration_periods == 0 {
+        return Err(CorpFinanceError::InvalidInput {
+            field: format!("{field}.duration_periods"),
+            reason: "Duration must be at least one period".into(),
+        });
+    }
+    crate::limits::check_schedule_periods(
+        &format!("{field}.duration_periods"),
+        u64::from(duration_periods),
+    )?;
+    if policy == RecognitionPolicy::PercentageOfCompletion && !cost_profile.is_empty() {
+        if cost_profile.len() != duration_periods as usize {
+            return Err(CorpFinanceError::InvalidInput {
+                field: format!("{field}.cost_profile"),
+                reason: "Cost profile needs one weight per delivery period".into(),
+            });
+        }
+        if cost_profile.iter().any(|w| *w < Decimal::ZERO)
+            || cost_profile.iter().sum::<Decimal>() <= Decimal::ZERO
+        {
+            return Err(CorpFinanceError::InvalidInput {
+                field: format!("{field}.cost_profile"),
+                reason: "Cost weights must be non-negative with a positive total".into(),
+            });
+        }
+    }
+    Ok(())
+}
+
+fn validate_input(input: &BacklogRevenueInput) -> CorpFinanceResult<()> {
+    if input.periods == 0 {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "periods".into(),
+            reason: "Forecast needs at least one period".into(),
+        });
+    }
+    crate::limits::check_schedule_periods("periods", u64::from(input.periods))?;
+    if input.contracts.is_empty() && input.pipeline.is_empty() {
+        return Err(CorpFinanceError::InsufficientData(
+            "At least one contract or pipeline opportunity is required".into(),
+        ));
+    }
+    if !input.revenue_targets.is_empty() && input.revenue_targets.len() != input.periods as usize {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "revenue_targets".into(),
+            reason: "Provide one target per period or none".into(),
+        });
+    }
+    for (i, c) in input.contracts.iter().enumerate() {
+        let field = format!("contracts[{i}]");
+        if c.total_value < Decimal::ZERO
+            || c.recognized_to_date < Decimal::ZERO
+            || c.recognized_to_date > c.total_value
+        {
+            return Err(CorpFinanceError::InvalidInput {
+                field: format!("{field}.recognized_to_date"),
+                reason: "Recognised revenue must be between zero and the contract value".into(),
+            });
+        }
+        validate_schedule(&field, c.duration_periods, c.policy, &c.cost_profile)?;
+    }
+    for (i, p) in input.pipeline.iter().enumerate() {
+        let field = format!("pipeline[{i}]");
+        if p.value < Decimal::ZERO {
+            return Err(CorpFinanceError::InvalidInput {
+                field: format!("{field}.value"),
+                reason: "Opportunity value cannot be negative".into(),
+            });
+        }
+        if p.win_rate < Decimal::ZERO || p.win_rate > Decimal::ONE {
+            return Err(CorpFinanceError::InvalidInput {
+                field: format!("{field}.win_rate"),
+                reason: "Win rate must be between 0 and 1".into(),
+            });
+        }
+        validate_schedule(&field, p.duration_periods, p.policy, &p.cost_profile)?;
+    }
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn contract(policy: RecognitionPolicy) -> BacklogContract {
+        BacklogContract {
+            name: "Contract".into(),
+            total_value: dec!(1200),
+            recognized_to_date: Decimal::ZERO,
+            policy,
+            start_period: 0,
+            duration_periods: 4,
+            cost_profile: vec![],
+        }
+    }
+
+    fn base_input() -> BacklogRevenueInput {
+        BacklogRevenueInput {
+            company_name: "Test Co".into(),
+            periods: 4,
+            period_labels: vec![],
+            contracts: vec![contract(RecognitionPolicy::Ratable)],
+            pipeline: vec![],
+            revenue_targets: vec![],
+            base_revenue: None,
+        }
+    }
+
+    #[test]
+    fn test_ratable_contract_spreads_remaining_value() {
+        let mut input = base_input();
+        input.contracts[0].recognized_to_date = dec!(400);
+        let out = forecast_backlog_revenue(&input).unwrap().result;
+        assert_eq!(out.total_backlog, dec!(800));
+        for p in &out.periods {
+            assert_eq!(p.contracted_revenue, dec!(200));
+            assert_eq!(p.backlog_coverage, Some(Decimal::ONE));
+        }
+        assert_eq!(out.periods[0].opening_backlog, dec!(800));
+        assert_eq!(out.periods[3].closing_backlog, Decimal::ZERO);
+        assert_eq!(out.periods[0].label, "Period 1");
+    }
+
+    #[test]
+    fn test_percentage_of_completion_follows_cost_profile() {
+        let mut input = base_input();
+        input.contracts[0].policy = RecognitionPolicy::PercentageOfCompletion;
+        input.contracts[0].cost_profile = vec![dec!(10), dec!(40), dec!(40), dec!(10)];
+        let out = forecast_backlog_revenue(&input).unwrap().result;
+        let revenue: Vec<Money> = out.periods.iter().map(|p| p.total_revenue).collect();
+        assert_eq!(revenue, vec![dec!(120), dec!(480), dec!(480), dec!(120)]);
+    }
+
+    #[test]
+    fn test_point_in_time_and_beyond_horizon() {
+        let mut input = base_input();
+        input.contracts = vec![
+            BacklogContract {
+                start_period: 1,
+                duration_periods: 2,
+                ..contract(RecognitionPolicy::PointInTime)
+            },
+            BacklogContract {
+                name: "Long".into(),
+                start_period: 2,
+                ..contract(RecognitionPolicy::Ratable)
+            },
+        ];
+        let out = forecast_backlog_revenue(&input).unwrap().result;
+        assert_eq!(
+            out.schedules[0].revenue,
+            vec![dec!(0), dec!(0), dec!(1200), dec!(0)]
+        );
+        assert_eq!(out.schedules[1].beyond_horizon, dec!(600));
+        assert_eq!(out.revenue_beyond_horizon, dec!(600));
+        assert_eq!(out.total_forecast_revenue, dec!(1800));
+        assert_eq!(out.periods[3].closing_backlog, dec!(600));
+    }
+
+    #[test]
+    fn test_pipeline_weighted_with_start_lag() {
+        let mut input = base_input();
+        input.pipeline = vec![PipelineOpportunity {
+            name: "Deal".into(),
+            value: dec!(1000),
+            win_rate: dec!(0.4),
+            expected_close_period: 0,
+            start_lag_periods: 2,
+            policy: RecognitionPolicy::Ratable,
+            duration_periods: 2,
+            cost_profile: vec![],
+        }];
+        let out = forecast_backlog_revenue(&input).unwrap().result;
+        assert_eq!(out.weighted_pipeline, dec!(400));
+        assert_eq!(out.periods[0].weighted_bookings, dec!(400));
+        assert_eq!(out.periods[1].pipeline_revenue, Decimal::ZERO);
+        assert_eq!(out.periods[2].pipeline_revenue, dec!(200));
+        assert_eq!(out.periods[2].total_revenue, dec!(500));
+        // Contracted 300 of 500 in period 3
+        assert_eq!(out.periods[2].backlog_coverage, Some(dec!(0.6)));
+        assert_eq!(out.contracted_share, dec!(1200) / dec!(1600));
+        assert_eq!(out.periods[3].closing_backlog, Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_coverage_against_targets_and_growth_rates() {
+        let mut input = base_input();
+        input.revenue_targets = vec![dec!(300), dec!(500), dec!(1000), dec!(300)];
+        input.base_revenue = Some(dec!(250));
+        let out = forecast_backlog_revenue(&input);
+        let out = out.unwrap();
+        let p = &out.result.periods;
+        assert_eq!(p[0].backlog_coverage, Some(Decimal::ONE));
+        assert_eq!(p[1].backlog_coverage, Some(dec!(0.6)));
+        assert_eq!(p[2].target_attainment, Some(dec!(0.3)));
+        assert!(out.warnings.iter().any(|w| w.contains("Period 3")));
+        assert_eq!(
+            out.result.revenue_growth_rates,
+            vec![dec!(0.2), Decimal::ZERO, Decimal::ZERO, Decimal::ZERO]
+        );
+    }
+
+    #[test]
+    fn test_validation() {
+        let mut input = base_input();
+        input.contracts[0].recognized_to_date = dec!(1500);
+        assert!(forecast_backlog_revenue(&input).is_err());
+
+        let mut input = base_input();
+        input.contracts[0].policy = RecognitionPolicy::PercentageOfCompletion;
+        input.contracts[0].cost_profile = vec![Decimal::ONE];
+        assert!(forecast_backlog_revenue(&input).is_err());
+
+        let mut input = base_input();
+        input.contracts.clear();
+        assert!(forecast_backlog_revenue(&input).is_err());
+
+        let mut input = base_input();
+        input.revenue_targets = vec![dec!(100)];
+        assert!(forecast_backlog_revenue(&input).is_err());
+    }
+}
@@ -1,2 +1,5 @@
+pub mod backlog;
+pub mod saas_metrics;
+pub mod unit_economics;
 pub mod variance;
 pub mod working_capital;
@@ -0,0 +1,666 @@
+use rust_decimal::Decimal;
+use rust_decimal::MathematicalOps;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+use std::time::Instant;
+
+use crate::error::CorpFinanceError;
+use crate::types::{with_metadata, ComputationOutput, Money, Rate};
+use crate::CorpFinanceResult;
+
+// ---------------------------------------------------------------------------
+// Types — SaaS Metrics
+// ---------------------------------------------------------------------------
+
+/// ARR bridge and P&L data for one reporting period.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SaasPeriod {
+    /// Period label, e.g. "Q1 2025"
+    pub label: String,
+    /// ARR at the start of the period
+    pub beginning_arr: Money,
+    /// ARR from new customers
+    pub new_arr: Money,
+    /// Upsell / cross-sell ARR from existing customers
+    pub expansion_arr: Money,
+    /// Downgrade ARR from existing customers (positive number)
+    pub contraction_arr: Money,
+    /// ARR lost from churned customers (positive number)
+    pub churned_arr: Money,
+    /// Recognised subscription revenue for the period
+    pub revenue: Money,
+    /// Subscription gross margin
+    pub gross_margin: Rate,
+    /// Sales & marketing expense for the period
+    pub sales_marketing_expense: Money,
+    /// Profitability margin used for Rule of 40 (FCF or EBITDA margin)
+    pub profit_margin: Rate,
+    /// Customers at the start of the period
+    pub beginning_customers: Decimal,
+    /// Customers acquired in the period
+    pub new_customers: Decimal,
+    /// Customers lost in the period
+    pub churned_customers: Decimal,
+}
+
+/// ARR of a customer cohort at inception and twelve months later.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArrCohort {
+    /// Cohort label, e.g. "2024 Q1"
+    pub label: String,
+    /// Cohort ARR at the start
+    pub starting_arr: Money,
+    /// Cohort ARR twelve months later, including expansion
+    pub arr_after_12m: Money,
+    /// Cohort ARR twelve months later, excluding expansion
+    pub gross_retained_arr_after_12m: Money,
+}
+
+/// Input for SaaS operating metrics.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SaasMetricsInput {
+    pub company_name: String,
+    /// Periods in chronological order
+    pub periods: Vec<SaasPeriod>,
+    /// Periods per year (4 = quarterly, 12 = monthly, 1 = annual)
+    pub periods_per_year: u32,
+    /// Optional cohort data for trailing-twelve-month NRR/GRR
+    #[serde(default)]
+    pub cohorts: Vec<ArrCohort>,
+}
+
+// ---------------------------------------------------------------------------
+// Output types — SaaS Metrics
+// ---------------------------------------------------------------------------
+
+/// ARR bridge for one period.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArrBridge {
+    pub beginning_arr: Money,
+    pub new_arr: Money,
+    pub expansion_arr: Money,
+    pub contraction_arr: Money,
+    pub churned_arr: Money,
+    /// new + expansion - contraction - churn
+    pub net_new_arr: Money,
+    pub ending_arr: Money,
+}
+
+/// Metrics for one period.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SaasPeriodMetrics {
+    pub label: String,
+    pub arr_bridge: ArrBridge,
+    /// ending / beginning ARR - 1 for the period
+    pub arr_growth: Rate,
+    /// Annual growth used for Rule of 40: YoY revenue growth when a full year
+    /// of history exists, otherwise compounded period ARR growth
+    pub annualized_growth: Rate,
+    /// (beginning + expansion - contraction - churn) / beginning
+    pub net_revenue_retention: Option<Rate>,
+    /// (beginning - contraction - churn) / beginning
+    pub gross_revenue_retention: Option<Rate>,
+    /// (new + expansion) / (contraction + churn)
+    pub quick_ratio: Option<Decimal>,
+    /// S&M / new customers
+    pub cac: Option<Money>,
+    /// Months of gross-margin-adjusted net new ARR to recover S&M
+    pub cac_payback_months: Option<Decimal>,
+    /// Ending ARR / ending customers
+    pub arr_per_customer: Option<Money>,
+    /// ARR per customer x gross margin / annualized customer churn rate
+    pub ltv: Option<Money>,
+    pub ltv_to_cac: Option<Decimal>,
+    /// annualized_growth + profit_margin
+    pub rule_of_40: Rate,
+    pub passes_rule_of_40: bool,
+    /// Annualized revenue increase / prior-period S&M
+    pub magic_number: Option<Decimal>,
+}
+
+/// Trailing-twelve-month retention of one cohort.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CohortRetention {
+    pub label: String,
+    pub net_revenue_retention: Rate,
+    pub gross_revenue_retention: Rate,
+}
+
+/// Period-over-period trend of a headline metric.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetricTrend {
+    pub metric: String,
+    /// Value per period (None where not computable)
+    pub values: Vec<Option<Decimal>>,
+    /// Last minus first available value
+    pub change: Option<Decimal>,
+    /// Whether the change is in the favourable direction
+    pub improving: Option<bool>,
+}
+
+/// Output of SaaS metrics analysis.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SaasMetricsOutput {
+    pub company_name: String,
+    pub periods: Vec<SaasPeriodMetrics>,
+    pub cohorts: Vec<CohortRetention>,
+    /// Starting-ARR-weighted cohort NRR
+    pub weighted_cohort_nrr: Option<Rate>,
+    /// Starting-ARR-weighted cohort GRR
+    pub weighted_cohort_grr: Option<Rate>,
+    pub trends: Vec<MetricTrend>,
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+fn ratio(numerator: Decimal, denominator: Decimal) -> Option<Decimal> {
+    if denominator.is_zero() {
+        None
+    } else {
+        Some(numerator / denominator)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Function: analyze_saas_metrics
+// ---------------------------------------------------------------------------
+
+/// Compute ARR bridges, retention, unit economics, Rule of 40 and magic
+/// number per period, with cohort retention and period-over-period trends.
+pub fn analyze_saas_metrics(
+    input: &SaasMetricsInput,
+) -> CorpFinanceResult<ComputationOutput<SaasMetricsOutput>> {
+    let start = Instant::now();
+    let mut warnings: Vec<String> = Vec::new();
+
+    validate_input(input)?;
+
+    let ppy = Decimal::from(input.periods_per_year);
+    let mut periods: Vec<SaasPeriodMetrics> = Vec::with_capacity(input.periods.len());
+    let mut prior_ending_arr: Option<Money> = None;
+
+    for (i, p) in input.periods.iter().enumerate() {
+        if let Some(prev) = prior_ending_arr {
+            if prev != p.beginning_arr {
+                warnings.push(format!(
+                    "{}: beginning ARR {} does not match prior ending ARR {}",
+                    p.label, p.beginning_arr, prev
+                ));
+            }
+        }
+
+        let net_new_arr = p.new_arr + p.expansion_arr - p.contraction_arr - p.churned_arr;
+        let ending_arr = p.beginning_arr + net_new_arr;
+        prior_ending_arr = Some(ending_arr);
+
+        let arr_growth = ratio(net_new_arr, p.beginning_arr).unwrap_or(Decimal::ZERO);
+        let annualized_growth = if i >= input.periods_per_year as usize {
+            let year_ago = &input.periods[i - input.periods_per_year as usize];
+            ratio(p.revenue - year_ago.revenue, year_ago.revenue).unwrap_or(Decimal::ZERO)
+        } else {
+            (Decimal::ONE + arr_growth).powu(input.periods_per_year as u64) - Decimal::ONE
+        };
+
+        let existing = p.beginning_arr - p.contraction_arr - p.churned_arr;
+        let gross_revenue_retention = ratio(existing, p.beginning_arr);
+        let net_revenue_retention = ratio(existing + p.expansion_arr, p.beginning_arr);
+        let quick_ratio = ratio(
+            p.new_arr + p.expansion_arr,
+            p.contraction_arr + p.churned_arr,
+        );
+
+        let cac = ratio(p.sales_marketing_expense, p.new_customers);
+        let cac_payback_months = if net_new_arr > Decimal::ZERO && p.gross_margin > Decimal::ZERO {
+            // Net new ARR is an annual figure; convert S&M spend to months.
+            Some(p.sales_marketing_expense / (net_new_arr * p.gross_margin) * dec!(12))
+        } else {
+            None
+        };
+
+        let ending_customers = p.beginning_customers + p.new_customers - p.churned_customers;
+        let arr_per_customer = ratio(ending_arr, ending_customers);
+        let annual_customer_churn =
+            ratio(p.churned_customers, p.beginning_customers).map(|c| c * ppy);
+        let ltv = match (arr_per_customer, annual_customer_churn) {
+            (Some(arpc), Some(churn)) if churn > Decimal::ZERO => {
+                Some(arpc * p.gross_margin / churn)
+            }
+            _ => None,
+        };
+        let ltv_to_cac = match (ltv, cac) {
+            (Some(l), Some(c)) if c > Decimal::ZERO => Some(l / c),
+            _ => None,
+        };
+
+        let rule_of_40 = annualized_growth + p.profit_margin;
+        let magic_number = if i > 0 {
+            let prev = &input.periods[i - 1];
+            ratio(
+                (p.revenue - prev.revenue) * ppy,
+                prev.sales_marketing_expense,
+            )
+        } else {
+            None
+        };
+
+        periods.push(SaasPeriodMetrics {
+            label: p.label.clone(),
+            arr_bridge: ArrBridge {
+                beginning_arr: p.beginning_arr,
+                new_arr: p.new_arr,
+                expansion_arr: p.expansion_arr,
+                contraction_arr: p.contraction_arr,
+                churned_arr: p.churned_arr,
+                net_new_arr,
+                ending_arr,
+            },
+            arr_growth,
+            annualized_growth,
+            net_revenue_retention,
+            gross_revenue_retention,
+            quick_ratio,
+            cac,
+            cac_payback_months,
+            arr_per_customer,
+            ltv,
+            ltv_to_cac,
+            rule_of_40,
+            passes_rule_of_40: rule_of_40 >= dec!(0.40),
+            magic_number,
+        });
+    }
+
+    if periods.iter().any(|p| p.cac_payback_months.is_none()) {
+        warnings.push(
+            "CAC payback not computable where net new ARR or gross margin is non-positive".into(),
+        );
+    }
+
+    // --- Cohorts ---
+    let cohorts: Vec<CohortRetention> = input
+        .cohorts
+        .iter()
+        .map(|c| CohortRetention {
+            label: c.label.clone(),
+            net_revenue_retention: c.arr_after_12m / c.starting_arr,
+            gross_revenue_retention: c.gross_retained_arr_after_12m / c.starting_arr,
+        })
+        .collect();
+    let cohort_base: Decimal = input.cohorts.iter().map(|c| c.starting_arr).sum();
+    let weighted_cohort_nrr = ratio(
+        input.cohorts.iter().map(|c| c.arr_after_12m).sum(),
+        cohort_base,
+    );
+    let weighted_cohort_grr = ratio(
+        input
+            .cohorts
+            .iter()
+            .map(|c| c.gross_retained_arr_after_12m)
+            .sum(),
+        cohort_base,
+    );
+
+    // --- Trends ---
+    let trends = vec![
+        build_trend(
+            "net_revenue_retention",
+            &periods,
+            |p| p.net_revenue_retention,
+            true,
+        ),
+        build_trend(
+            "cac_payback_months",
+            &periods,
+            |p| p.cac_payback_months,
+            false,
+        ),
+        build_trend("ltv_to_cac", &periods, |p| p.ltv_to_cac, true),
+        build_trend("rule_of_40", &periods, |p| Some(p.rule_of_40), true),
+        build_trend("magic_number", &periods, |p| p.magic_number, true),
+    ];
+
+    let output = SaasMetricsOutput {
+        company_name: input.company_name.clone(),
+        periods,
+        cohorts,
+        weighted_cohort_nrr,
+        weighted_cohort_grr,
+        trends,
+    };
+
+    let elapsed = start.elapsed().as_micros() as u64;
+    Ok(with_metadata(
+        "SaaS Metrics (ARR bridge, NRR/GRR, CAC payback, LTV/CAC, Rule of 40, magic number)",
+        &serde_json::json!({
+            "company_name": input.company_name,
+            "periods": input.periods.len(),
+            "periods_per_year": input.periods_per_year,
+            "cohorts": input.cohorts.len(),
+        }),
+        warnings,
+        elapsed,
+        output,
+    ))
+}
+
+fn build_trend<F>(
+    metric: &str,
+    periods: &[SaasPeriodMetrics],
+    f: F,
+    higher_is_better: bool,
+) -> MetricTrend
+where
+    F: Fn(&SaasPeriodMetrics) -> Option<Decimal>,
+{
+    let values: Vec<Option<Decimal>> = periods.iter().map(f).collect();
+    let first = values.iter().flatten().next().copied();
+    let last = values.iter().rev().flatten().next().copied();
+    let change = match (first, last) {
+        (Some(a), Some(b)) => Some(b - a),
+        _ => None,
+    };
+    let improving = change.map(|c| {
+        if higher_is_better {
+            c > Decimal::ZERO
+        } else {
+            c < Decimal::ZERO
+        }
+    });
+    MetricTrend {
+        metric: metric.to_string(),
+        values,
+        change,
+        improving,
+    }
+}
+
+fn validate_input(input: &SaasMetricsInput) -> CorpFinanceResult<()> {
+    if input.periods.is_empty() {
+        return Err(CorpFinanceError::InsufficientData(
+            "At least one period is required".into(),
+        ));
+    }
+    if !matches!(input.periods_per_year, 1 | 2 | 4 | 12) {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "periods_per_year".into(),
+            reason: "Must be 1 (annual), 2, 4 (quarterly) or 12 (monthly)".into(),
+        });
+    }
+    for p in &input.periods {
+        if p.beginning_arr < Decimal::ZERO
+            || p.new_arr < Decimal::ZERO
+            || p.expansion_arr < Decimal::ZERO
+            || p.contraction_arr < Decimal::ZERO
+            || p.churned_arr < Decimal::ZERO
+        {
+            return Err(CorpFinanceError::InvalidInput {
+                field: format!("periods[{}]", p.label),
+                reason: "ARR bridge components must be non-negative".into(),
+            });
+        }
+        if p.contraction_arr + p.churned_arr > p.beginning_arr {
+            return Err(CorpFinanceError::InvalidInput {
+                field: format!("periods[{}].churned_arr", p.label),
+                reason: "Contraction plus churn cannot exceed beginning ARR".into(),
+            });
+        }
+        if p.beginning_customers < Decimal::ZERO
+            || p.new_customers < Decimal::ZERO
+            || p.churned_customers < Decimal::ZERO
+            || p.churned_customers > p.beginning_customers
+        {
+            return Err(CorpFinanceError::InvalidInput {
+                field: format!("periods[{}].churned_customers", p.label),
+                reason: "Customer counts must be non-negative and churn cannot exceed the \
+                         beginning count"
+                    .into(),
+            });
+        }
+    }
+    for c in &input.cohorts {
+        if c.starting_arr <= Decimal::ZERO {
+            return Err(CorpFinanceError::InvalidInput {
+                field: format!("cohorts[{}].starting_arr", c.label),
+                reason: "Cohort starting ARR must be positive".into(),
+            });
+        }
+    }
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn quarter(label: &str, beginning_arr: Decimal, revenue: Decimal, sm: Decimal) -> SaasPeriod {
+        SaasPeriod {
+            label: label.into(),
+            beginning_arr,
+            new_arr: dec!(12),
+            expansion_arr: dec!(6),
+            contraction_arr: dec!(2),
+            churned_arr: dec!(4),
+            revenue,
+            gross_margin: dec!(0.80),
+            sales_marketing_expense: sm,
+            profit_margin: dec!(0.05),
+            beginning_customers: dec!(1000),
+            new_customers: dec!(60),
+            churned_customers: dec!(20),
+        }
+    }
+
+    fn sample_input() -> SaasMetricsInput {
+        SaasMetricsInput {
+            company_name: "CloudCo".into(),
+            periods: vec![
+                quarter("Q1", dec!(400), dec!(100), dec!(30)),
+                quarter("Q2", dec!(412), dec!(104), dec!(30)),
+                quarter("Q3", dec!(424), dec!(107), dec!(32)),
+            ],
+            periods_per_year: 4,
+            cohorts: vec![],
+        }
+    }
+
+    #[test]
+    fn test_arr_bridge() {
+        let out = analyze_saas_metrics(&sample_input()).unwrap();
+        let b = &out.result.periods[0].arr_bridge;
+        // 12 + 6 - 2 - 4 = 12
+        assert_eq!(b.net_new_arr, dec!(12));
+        assert_eq!(b.ending_arr, dec!(412));
+        assert!(out.warnings.iter().all(|w| !w.contains("does not match")));
+    }
+
+    #[test]
+    fn test_bridge_discontinuity_warns() {
+        let mut input = sample_input();
+        input.periods[1].beginning_arr = dec!(415);
+        let out = analyze_saas_metrics(&input).unwrap();
+        assert!(out.warnings.iter().any(|w| w.contains("does not match")));
+    }
+
+    #[test]
+    fn test_retention() {
+        let out = analyze_saas_metrics(&sample_input()).unwrap();
+        let p = &out.result.periods[0];
+        // (400 - 2 - 4) / 400 = 0.985 ; + 6 -> 1.0
+        assert_eq!(p.gross_revenue_retention, Some(dec!(0.985)));
+        assert_eq!(p.net_revenue_retention, Some(dec!(1)));
+        // (12 + 6) / (2 + 4) = 3
+        assert_eq!(p.quick_ratio, Some(dec!(3)));
+    }
+
+    #[test]
+    fn test_cac_and_payback() {
+        let out = analyze_saas_metrics(&sample_input()).unwrap();
+        let p = &out.result.periods[0];
+        // 30 / 60
+        assert_eq!(p.cac, Some(dec!(0.5)));
+        // 30 / (12 * 0.8) * 12 = 37.5 months
+        assert_eq!(p.cac_payback_months, Some(dec!(37.5)));
+    }
+
+    #[test]
+    fn test_ltv_and_ratio() {
+        let out = analyze_saas_metrics(&sample_input()).unwrap();
+        let p = &out.result.periods[0];
+        // Ending customers 1040, ARR/customer = 412 / 1040
+        let arpc = dec!(412) / dec!(1040);
+        assert_eq!(p.arr_per_customer, Some(arpc));
+        // Annual churn = 20/1000 * 4 = 0.08; LTV = arpc * 0.8 / 0.08 = 10 * arpc
+        let ltv = p.ltv.unwrap();
+        assert!((ltv - arpc * dec!(10)).abs() < dec!(0.000001));
+        assert!((p.ltv_to_cac.unwrap() - ltv / dec!(0.5)).abs() < dec!(0.000001));
+    }
+
+    #[test]
+    fn test_rule_of_40_compounds_period_growth() {
+        let out = analyze_saas_metrics(&sample_input()).unwrap();
+        let p = &out.result.periods[0];
+        // 1.03^4 - 1 = 0.12550881
+        assert!((p.annualized_growth - dec!(0.12550881)).abs() < dec!(0.0000001));
+        assert_eq!(p.rule_of_40, p.annualized_growth + dec!(0.05));
+        assert!(!p.passes_rule_of_40);
+    }
+
+    #[test]
+    fn test_rule_of_40_uses_yoy_revenue_with_history() {
+        let mut input = sample_input();
+        input
+            .periods
+            .push(quarter("Q4", dec!(436), dec!(110), dec!(33)));
+        input
+            .periods
+            .push(quarter("Q1+1", dec!(448), dec!(140), dec!(34)));
+        let out = analyze_saas_metrics(&input).unwrap();
+        let p = &out.result.periods[4];
+        // 140 / 100 - 1 = 0.40
+        assert_eq!(p.annualized_growth, dec!(0.4));
+        assert!(p.passes_rule_of_40);
+    }
+
+    #[test]
+    fn test_magic_number() {
+        let out = analyze_saas_metrics(&sample_input()).unwrap();
+        assert!(out.result.periods[0].magic_number.is_none());
+        // (104 - 100) * 4 / 30
+        let m = out.result.periods[1].magic_number.unwrap();
+        assert!((m - dec!(0.533333)).abs() < dec!(0.0001));
+    }
+
+    #[test]
+    fn test_negative_net_new_arr_no_payback() {
+        let mut input = sample_input();
+        input.periods[0].new_arr = Decimal::ZERO;
+        input.periods[0].expansion_arr = Decimal::ZERO;
+        let out = analyze_saas_metrics(&input).unwrap();
+        assert!(out.result.periods[0].cac_payback_months.is_none());
+        assert_eq!(out.result.periods[0].quick_ratio, Some(Decimal::ZERO));
+        assert!(out.warnings.iter().any(|w| w.contains("CAC payback")));
+    }
+
+    #[test]
+    fn test_no_churn_gives_no_ltv() {
+        let mut input = sample_input();
+        input.periods[0].churned_customers = Decimal::ZERO;
+        let out = analyze_saas_metrics(&input).unwrap();
+        assert!(out.result.periods[0].ltv.is_none());
+        assert!(out.result.periods[0].ltv_to_cac.is_none());
+    }
+
+    #[test]
+    fn test_cohort_retention() {
+        let mut input = sample_input();
+        input.cohorts = vec![
+            ArrCohort {
+                label: "2024Q1".into(),
+                starting_arr: dec!(100),
+                arr_after_12m: dec!(115),
+                gross_retained_arr_after_12m: dec!(92),
+            },
+            ArrCohort {
+                label: "2024Q2".into(),
+                starting_arr: dec!(300),
+                arr_after_12m: dec!(321),
+                gross_retained_arr_after_12m: dec!(276),
+            },
+        ];
+        let out = analyze_saas_metrics(&input).unwrap();
+        assert_eq!(out.result.cohorts[0].net_revenue_retention, dec!(1.15));
+        assert_eq!(out.result.cohorts[1].gross_revenue_retention, dec!(0.92));
+        // (115 + 321) / 400 = 1.09
+        assert_eq!(out.result.weighted_cohort_nrr, Some(dec!(1.09)));
+        assert_eq!(out.result.weighted_cohort_grr, Some(dec!(0.92)));
+    }
+
+    #[test]
+    fn test_no_cohorts() {
+        let out = analyze_saas_metrics(&sample_input()).unwrap();
+        assert!(out.result.cohorts.is_empty());
+        assert!(out.result.weighted_cohort_nrr.is_none());
+    }
+
+    #[test]
+    fn test_trends() {
+        let out = analyze_saas_metrics(&sample_input()).unwrap();
+        let payback = out
+            .result
+            .trends
+            .iter()
+            .find(|t| t.metric == "cac_payback_months")
+            .unwrap();
+        assert_eq!(payback.values.len(), 3);
+        // Q3: 32 / (12 * 0.8) * 12 = 40 vs 37.5 -> worse
+        assert_eq!(payback.change, Some(dec!(2.5)));
+        assert_eq!(payback.improving, Some(false));
+
+        let magic = out
+            .result
+            .trends
+            .iter()
+            .find(|t| t.metric == "magic_number")
+            .unwrap();
+        assert!(magic.values[0].is_none());
+        assert!(magic.change.is_some());
+    }
+
+    #[test]
+    fn test_annual_periods() {
+        let mut input = sample_input();
+        input.periods_per_year = 1;
+        let out = analyze_saas_metrics(&input).unwrap();
+        let p = &out.result.periods[0];
+        assert_eq!(p.annualized_growth, dec!(0.03));
+        // Magic number uses x1 for annual periods: (104 - 100) / 30
+        let m = out.result.periods[1].magic_number.unwrap();
+        assert!((m - dec!(0.133333)).abs() < dec!(0.0001));
+    }
+
+    #[test]
+    fn test_invalid_inputs() {
+        let mut input = sample_input();
+        input.periods.clear();
+        assert!(analyze_saas_metrics(&input).is_err());
+
+        let mut input = sample_input();
+        input.periods_per_year = 3;
+        assert!(analyze_saas_metrics(&input).is_err());
+
+        let mut input = sample_input();
+        input.periods[0].churned_arr = dec!(500);
+        assert!(analyze_saas_metrics(&input).is_err());
+
+        let mut input = sample_input();
+        input.periods[0].churned_customers = dec!(2000);
+        assert!(analyze_saas_metrics(&input).is_err());
+    }
+}
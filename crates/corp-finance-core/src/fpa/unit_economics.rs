@@ -0,0 +1,798 @@
+use rust_decimal::Decimal;
+use rust_decimal::MathematicalOps;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+use std::time::Instant;
+
+use crate::error::CorpFinanceError;
+use crate::sampling::{percentile, Lcg};
+use crate::types::{with_metadata, ComputationOutput, Money, Rate};
+use crate::CorpFinanceResult;
+
+/// Number of trailing observed hazards averaged to extrapolate an empirical curve.
+const EMPIRICAL_TAIL_MONTHS: usize = 3;
+const DEFAULT_SIMULATIONS: u32 = 1_000;
+const MAX_SIMULATIONS: u32 = 20_000;
+const DEFAULT_SEED: u64 = 42;
+
+// ---------------------------------------------------------------------------
+// Types — Unit Economics
+// ---------------------------------------------------------------------------
+
+/// Monthly retention curve of a cohort.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum RetentionCurve {
+    /// Observed share of the cohort still active at the end of months 1..n.
+    /// Beyond month n the curve is extrapolated with the average churn of the
+    /// last three observed months.
+    Empirical { survival: Vec<Rate> },
+    /// Constant monthly churn: S(t) = (1 - churn)^t.
+    Exponential { monthly_churn: Rate },
+    /// Churn decaying from an initial to a long-run level:
+    /// churn(t) = long_run + (initial - long_run) * decay^(t - 1).
+    DecayingChurn {
+        initial_churn: Rate,
+        long_run_churn: Rate,
+        decay: Decimal,
+    },
+}
+
+/// An acquisition cohort.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CohortInput {
+    /// Cohort label, e.g. "Jan-2025"
+    pub label: String,
+    /// Users (or customers) acquired
+    pub initial_users: Decimal,
+    /// Blended acquisition cost per user
+    pub cac_per_user: Money,
+    /// Retention curve
+    pub retention: RetentionCurve,
+}
+
+/// Parameters for the Monte Carlo uncertainty bands.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UncertaintySpec {
+    /// Relative standard deviation applied to churn (e.g. 0.20 = +/-20%)
+    pub churn_volatility: Rate,
+    /// Relative standard deviation applied to ARPU
+    pub arpu_volatility: Rate,
+    /// Number of simulations (default 1,000)
+    pub num_simulations: Option<u32>,
+    /// Seed for the deterministic random number generator (default 42)
+    pub seed: Option<u64>,
+}
+
+/// Input for cohort unit economics.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UnitEconomicsInput {
+    pub cohorts: Vec<CohortInput>,
+    /// Monthly revenue per active user in the first month
+    pub arpu_monthly: Money,
+    /// Annual ARPU growth (price increases / expansion)
+    #[serde(default)]
+    pub arpu_growth_annual: Rate,
+    /// Gross margin on revenue
+    pub gross_margin: Rate,
+    /// Other variable cost per active user per month (support, payments, ...)
+    #[serde(default)]
+    pub variable_cost_per_user_monthly: Money,
+    /// Annual discount rate for LTV
+    pub annual_discount_rate: Rate,
+    /// Lifetime horizon in months
+    pub horizon_months: u32,
+    /// Optional Monte Carlo uncertainty bands
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uncertainty: Option<UncertaintySpec>,
+}
+
+// ---------------------------------------------------------------------------
+// Output types — Unit Economics
+// ---------------------------------------------------------------------------
+
+/// Percentile band of a simulated value.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ValueBand {
+    pub mean: Decimal,
+    pub p5: Decimal,
+    pub p50: Decimal,
+    pub p95: Decimal,
+}
+
+/// Distribution of the CAC payback month across simulations.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PaybackDistribution {
+    /// Share of simulations that pay back within the horizon
+    pub probability_of_payback: Rate,
+    /// Percentile payback months (None = not paid back within the horizon)
+    pub p5_month: Option<u32>,
+    pub p50_month: Option<u32>,
+    pub p95_month: Option<u32>,
+}
+
+/// Unit economics of one cohort.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CohortEconomics {
+    pub label: String,
+    pub initial_users: Decimal,
+    pub cac_per_user: Money,
+    /// Share of the cohort active at the end of each month (index 0 = month 1)
+    pub survival_curve: Vec<Rate>,
+    /// Expected active months per user within the horizon
+    pub expected_lifetime_months: Decimal,
+    /// Month-1 contribution per active user
+    pub contribution_per_user_month1: Money,
+    /// Discounted lifetime contribution per acquired user
+    pub ltv_per_user: Money,
+    pub ltv_to_cac: Option<Decimal>,
+    /// First month in which cumulative discounted contribution covers CAC
+    pub payback_month: Option<u32>,
+    /// initial_users x (LTV - CAC)
+    pub cohort_net_value: Money,
+    pub ltv_band: Option<ValueBand>,
+    pub payback_distribution: Option<PaybackDistribution>,
+}
+
+/// Output of cohort unit economics.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UnitEconomicsOutput {
+    pub cohorts: Vec<CohortEconomics>,
+    pub total_users: Decimal,
+    /// User-weighted LTV per user
+    pub blended_ltv_per_user: Money,
+    /// User-weighted CAC per user
+    pub blended_cac_per_user: Money,
+    pub blended_ltv_to_cac: Option<Decimal>,
+    /// Sum of cohort net values
+    pub total_net_value: Money,
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Monthly churn hazards for months 1..=horizon.
+fn hazards(curve: &RetentionCurve, horizon: usize) -> Vec<Rate> {
+    match curve {
+        RetentionCurve::Exponential { monthly_churn } => vec![*monthly_churn; horizon],
+        RetentionCurve::DecayingChurn {
+            initial_churn,
+            long_run_churn,
+            decay,
+        } => {
+            let mut out = Vec::with_capacity(horizon);
+            let mut factor = Decimal::ONE;
+            for _ in 0..horizon {
+                out.push(*long_run_churn + (*initial_churn - *long_run_churn) * factor);
+                factor *= *decay;
+            }
+            out
+        }
+        RetentionCurve::Empirical { survival } => {
+            let mut observed = Vec::with_capacity(survival.len());
+            let mut prev = Decimal::ONE;
+            for s in survival {
+                let h = if prev.is_zero() {
+                    Decimal::ONE
+                } else {
+                    Decimal::ONE - *s / prev
+                };
+                observed.push(h);
+                prev = *s;
+            }
+            let tail_n = observed.len().min(EMPIRICAL_TAIL_MONTHS);
+            let tail = observed[observed.len() - tail_n..]
+                .iter()
+                .copied()
+                .sum::<Decimal>()
+                / Decimal::from(tail_n as u32);
+            (0..horizon)
+                .map(|t| observed.get(t).copied().unwrap_or(tail))
+                .collect()
+        }
+    }
+}
+
+/// Survival at the end of each month from hazards scaled by `churn_factor`.
+fn survival_from_hazards(hazards: &[Rate], churn_factor: Decimal) -> Vec<Rate> {
+    let mut s = Decimal::ONE;
+    hazards
+        .iter()
+        .map(|h| {
+            let h = (*h * churn_factor).max(Decimal::ZERO).min(Decimal::ONE);
+            s *= Decimal::ONE - h;
+            s
+        })
+        .collect()
+}
+
+/// Per-month economics shared by all cohorts.
+struct MonthlyTerms {
+    /// Contribution per active user in each month, before ARPU shocks
+    revenue: Vec<Money>,
+    /// Discount factor at the end of each month
+    discount: Vec<Decimal>,
+}
+
+fn monthly_terms(input: &UnitEconomicsInput) -> MonthlyTerms {
+    let horizon = input.horizon_months as usize;
+    let twelfth = Decimal::ONE / dec!(12);
+    let arpu_step = (Decimal::ONE + input.arpu_growth_annual).powd(twelfth);
+    let disc_step = (Decimal::ONE + input.annual_discount_rate).powd(twelfth);
+    let mut revenue = Vec::with_capacity(horizon);
+    let mut discount = Vec::with_capacity(horizon);
+    let mut arpu = input.arpu_monthly;
+    let mut df = Decimal::ONE;
+    for _ in 0..horizon {
+        revenue.push(arpu);
+        df /= disc_step;
+        discount.push(df);
+        arpu *= arpu_step;
+    }
+    MonthlyTerms { revenue, discount }
+}
+
+/// Discounted LTV per user and payback month for one path.
+fn value_path(
+    input: &UnitEconomicsInput,
+    terms: &MonthlyTerms,
+    survival: &[Rate],
+    arpu_factor: Decimal,
+    cac: Money,
+) -> (Money, Option<u32>) {
+    let mut ltv = Decimal::ZERO;
+    let mut payback = None;
+    let mut active = Decimal::ONE;
+    for (t, s) in survival.iter().enumerate() {
+        // Users active at the start of month t+1 generate that month's contribution.
+        let contribution = terms.revenue[t] * arpu_factor * input.gross_margin
+            - input.variable_cost_per_user_monthly;
+        ltv += active * contribution * terms.discount[t];
+        if payback.is_none() && ltv >= cac {
+            payback = Some((t + 1) as u32);
+        }
+        active = *s;
+    }
+    (ltv, payback)
+}
+
+// ---------------------------------------------------------------------------
+// Function: analyze_unit_economics
+// ---------------------------------------------------------------------------
+
+/// Cohort unit economics: retention curves, discounted LTV, LTV/CAC and CAC
+/// payback, with optional Monte Carlo bands on churn and ARPU.
+pub fn analyze_unit_economics(
+    input: &UnitEconomicsInput,
+) -> CorpFinanceResult<ComputationOutput<UnitEconomicsOutput>> {
+    let start = Instant::now();
+    let mut warnings: Vec<String> = Vec::new();
+
+    validate_input(input)?;
+
+    let horizon = input.horizon_months as usize;
+    let terms = monthly_terms(input);
+    let contribution_month1 =
+        input.arpu_monthly * input.gross_margin - input.variable_cost_per_user_monthly;
+    if contribution_month1 <= Decimal::ZERO {
+        warnings.push("Contribution per user is non-positive; CAC is never recovered".into());
+    }
+
+    let mut rng = Lcg::new(
+        input
+            .uncertainty
+            .as_ref()
+            .and_then(|u| u.seed)
+            .unwrap_or(DEFAULT_SEED),
+    );
+
+    let mut cohorts = Vec::with_capacity(input.cohorts.len());
+    for cohort in &input.cohorts {
+        let h = hazards(&cohort.retention, horizon);
+        let survival = survival_from_hazards(&h, Decimal::ONE);
+        let expected_lifetime_months =
+            Decimal::ONE + survival[..horizon - 1].iter().copied().sum::<Decimal>();
+        let (ltv_per_user, payback_month) =
+            value_path(input, &terms, &survival, Decimal::ONE, cohort.cac_per_user);
+
+        if payback_month.is_none() {
+            warnings.push(format!(
+                "{}: CAC not recovered within {} months",
+                cohort.label, input.horizon_months
+            ));
+        }
+        if matches!(cohort.retention, RetentionCurve::Empirical { .. })
+            && survival.last().is_some_and(|s| *s > dec!(0.5))
+        {
+            warnings.push(format!(
+                "{}: over half the cohort remains at the horizon; LTV is truncated",
+                cohort.label
+            ));
+        }
+
+        let (ltv_band, payback_distribution) = match &input.uncertainty {
+            Some(u) => {
+                let n = u.num_simulations.unwrap_or(DEFAULT_SIMULATIONS);
+                let mut ltvs = Vec::with_capacity(n as usize);
+                let mut paybacks = Vec::with_capacity(n as usize);
+                for _ in 0..n {
+                    let churn_factor =
+                        (Decimal::ONE + u.churn_volatility * rng.next_normal()).max(Decimal::ZERO);
+                    let arpu_factor =
+                        (Decimal::ONE + u.arpu_volatility * rng.next_normal()).max(Decimal::ZERO);
+                    let path = survival_from_hazards(&h, churn_factor);
+                    let (ltv, payback) =
+                        value_path(input, &terms, &path, arpu_factor, cohort.cac_per_user);
+                    ltvs.push(ltv);
+                    // Unpaid paths sort after every paid month.
+                    paybacks.push(payback.unwrap_or(u32::MAX));
+                }
+                ltvs.sort();
+                paybacks.sort();
+                let paid = paybacks.iter().filter(|m| **m != u32::MAX).count();
+                let month = |q: Decimal| {
+                    let m = percentile(&paybacks, q);
+                    (m != u32::MAX).then_some(m)
+                };
+                (
+                    Some(ValueBand {
+                        mean: ltvs.iter().copied().sum::<Decimal>() / Decimal::from(n),
+                        p5: percentile(&ltvs, dec!(0.05)),
+                        p50: percentile(&ltvs, dec!(0.50)),
+                        p95: percentile(&ltvs, dec!(0.95)),
+                    }),
+                    Some(PaybackDistribution {
+                        probability_of_payback: Decimal::from(paid as u32) / Decimal::from(n),
+                        p5_month: month(dec!(0.05)),
+                        p50_month: month(dec!(0.50)),
+                        p95_month: month(dec!(0.95)),
+                    }),
+                )
+            }
+            None => (None, None),
+        };
+
+        cohorts.push(CohortEconomics {
+            label: cohort.label.clone(),
+            initial_users: cohort.initial_users,
+            cac_per_user: cohort.cac_per_user,
+            survival_curve: survival,
+            expected_lifetime_months,
+            contribution_per_user_month1: contribution_month1,
+            ltv_per_user,
+            ltv_to_cac: if cohort.cac_per_user > Decimal::ZERO {
+                Some(ltv_per_user / cohort.cac_per_user)
+            } else {
+                None
+            },
+            payback_month,
+            cohort_net_value: cohort.initial_users * (ltv_per_user - cohort.cac_per_user),
+            ltv_band,
+            payback_distribution,
+        });
+    }
+
+    let total_users: Decimal = cohorts.iter().map(|c| c.initial_users).sum();
+    let weighted = |f: fn(&CohortEconomics) -> Decimal| {
+        if total_users.is_zero() {
+            Decimal::ZERO
+        } else {
+            cohorts
+                .iter()
+                .map(|c| c.initial_users * f(c))
+                .sum::<Decimal>()
+                / total_users
+        }
+    };
+    let blended_ltv_per_user = weighted(|c| c.ltv_per_user);
+    let blended_cac_per_user = weighted(|c| c.cac_per_user);
+    let blended_ltv_to_cac = if blended_cac_per_user > Decimal::ZERO {
+        Some(blended_ltv_per_user / blended_cac_per_user)
+    } else {
+        None
+    };
+    let total_net_value = cohorts.iter().map(|c| c.cohort_net_value).sum();
+
+    let output = UnitEconomicsOutput {
+        cohorts,
+        total_users,
+        blended_ltv_per_user,
+        blended_cac_per_user,
+        blended_ltv_to_cac,
+        total_net_value,
+    };
+
+    let elapsed = start.elapsed().as_micros() as u64;
+    Ok(with_metadata(
+        "Cohort Unit Economics (retention-weighted discounted contribution LTV)",
+        &serde_json::json!({
+            "cohorts": input.cohorts.len(),
+            "horizon_months": input.horizon_months,
+            "annual_discount_rate": input.annual_discount_rate.to_string(),
+            "simulations": input.uncertainty.as_ref().map(|u| u.num_simulations.unwrap_or(DEFAULT_SIMULATIONS)),
+        }),
+        warnings,
+        elapsed,
+        output,
+    ))
+}
+
+fn validate_rate(value: Rate, field: String) -> CorpFinanceResult<()> {
+    if value < Decimal::ZERO || value > Decimal::ONE {
+        return Err(CorpFinanceError::InvalidInput {
+            field,
+            reason: "Must be between 0 and 1".into(),
+        });
+    }
+    Ok(())
+}
+
+fn validate_input(input: &UnitEconomicsInput) -> CorpFinanceResult<()> {
+    if input.cohorts.is_empty() {
+        return Err(CorpFinanceError::InsufficientData(
+            "At least one cohort is required".into(),
+        ));
+    }
+    if input.horizon_months == 0 {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "horizon_months".into(),
+            reason: "Horizon must be at least one month".into(),
+        });
+    }
+    if input.arpu_monthly < Decimal::ZERO {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "arpu_monthly".into(),
+            reason: "ARPU must be non-negative".into(),
+        });
+    }
+    validate_rate(input.gross_margin, "gross_margin".into())?;
+    if input.annual_discount_rate <= dec!(-1) || input.arpu_growth_annual <= dec!(-1) {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "annual_discount_rate".into(),
+            reason: "Discount and ARPU growth rates must be greater than -100%".into(),
+        });
+    }
+    for c in &input.cohorts {
+        if c.initial_users < Decimal::ZERO || c.cac_per_user < Decimal::ZERO {
+            return Err(CorpFinanceError::InvalidInput {
+                field: format!("cohorts[{}]", c.label),
+                reason: "Users and CAC must be non-negative".into(),
+            });
+        }
+        match &c.retention {
+            RetentionCurve::Exponential { monthly_churn } => validate_rate(
+                *monthly_churn,
+                format!("cohorts[{}].monthly_churn", c.label),
+            )?,
+            RetentionCurve::DecayingChurn {
+                initial_churn,
+                long_run_churn,
+                decay,
+            } => {
+                validate_rate(
+                    *initial_churn,
+                    format!("cohorts[{}].initial_churn", c.label),
+                )?;
+                validate_rate(
+                    *long_run_churn,
+                    format!("cohorts[{}].long_run_churn", c.label),
+                )?;
+                validate_rate(*decay, format!("cohorts[{}].decay", c.label))?;
+            }
+            RetentionCurve::Empirical { survival } => {
+                if survival.is_empty() {
+                    return Err(CorpFinanceError::InsufficientData(format!(
+                        "{}: empirical survival curve is empty",
+                        c.label
+                    )));
+                }
+                let mut prev = Decimal::ONE;
+                for s in survival {
+                    if *s < Decimal::ZERO || *s > prev {
+                        return Err(CorpFinanceError::InvalidInput {
+                            field: format!("cohorts[{}].survival", c.label),
+                            reason: "Survival must be within [0, 1] and non-increasing".into(),
+                        });
+                    }
+                    prev = *s;
+                }
+            }
+        }
+    }
+    if let Some(u) = &input.uncertainty {
+        if u.churn_volatility < Decimal::ZERO || u.arpu_volatility < Decimal::ZERO {
+            return Err(CorpFinanceError::InvalidInput {
+                field: "uncertainty".into(),
+                reason: "Volatilities must be non-negative".into(),
+            });
+        }
+        if let Some(n) = u.num_simulations {
+            if n == 0 || n > MAX_SIMULATIONS {
+                return Err(CorpFinanceError::InvalidInput {
+                    field: "uncertainty.num_simulations".into(),
+                    reason: format!("Must be between 1 and {MAX_SIMULATIONS}"),
+                });
+            }
+        }
+        crate::limits::check_simulation_paths(
+            "uncertainty.num_simulations",
+            u.num_simulations.unwrap_or(DEFAULT_SIMULATIONS).into(),
+        )?;
+    }
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn close(a: Decimal, b: Decimal, eps: Decimal) -> bool {
+        (a - b).abs() < eps
+    }
+
+    fn cohort(label: &str, retention: RetentionCurve) -> CohortInput {
+        CohortInput {
+            label: label.into(),
+            initial_users: dec!(1000),
+            cac_per_user: dec!(100),
+            retention,
+        }
+    }
+
+    fn base_input() -> UnitEconomicsInput {
+        UnitEconomicsInput {
+            cohorts: vec![cohort(
+                "Jan",
+                RetentionCurve::Exponential {
+                    monthly_churn: dec!(0.05),
+                },
+            )],
+            arpu_monthly: dec!(20),
+            arpu_growth_annual: Decimal::ZERO,
+            gross_margin: dec!(0.75),
+            variable_cost_per_user_monthly: dec!(2),
+            annual_discount_rate: Decimal::ZERO,
+            horizon_months: 36,
+            uncertainty: None,
+        }
+    }
+
+    #[test]
+    fn test_exponential_survival() {
+        let out = analyze_unit_economics(&base_input()).unwrap();
+        let c = &out.result.cohorts[0];
+        assert_eq!(c.survival_curve.len(), 36);
+        assert_eq!(c.survival_curve[0], dec!(0.95));
+        assert!(close(
+            c.survival_curve[11],
+            dec!(0.95).powu(12),
+            dec!(0.0000001)
+        ));
+    }
+
+    #[test]
+    fn test_contribution_per_user() {
+        let out = analyze_unit_economics(&base_input()).unwrap();
+        // 20 * 0.75 - 2 = 13
+        assert_eq!(out.result.cohorts[0].contribution_per_user_month1, dec!(13));
+    }
+
+    #[test]
+    fn test_undiscounted_ltv_matches_geometric_sum() {
+        let out = analyze_unit_economics(&base_input()).unwrap();
+        let c = &out.result.cohorts[0];
+        // 13 * sum_{t=0}^{35} 0.95^t = 13 * (1 - 0.95^36) / 0.05
+        let expected = dec!(13) * (Decimal::ONE - dec!(0.95).powu(36)) / dec!(0.05);
+        assert!(close(c.ltv_per_user, expected, dec!(0.0001)));
+        assert!(close(
+            c.expected_lifetime_months,
+            (Decimal::ONE - dec!(0.95).powu(36)) / dec!(0.05),
+            dec!(0.0001)
+        ));
+    }
+
+    #[test]
+    fn test_payback_month() {
+        let out = analyze_unit_economics(&base_input()).unwrap();
+        let c = &out.result.cohorts[0];
+        // Cumulative 13 * (1 - 0.95^t) / 0.05 >= 100 first at t = 10
+        assert_eq!(c.payback_month, Some(10));
+    }
+
+    #[test]
+    fn test_discounting_lowers_ltv() {
+        let base = analyze_unit_economics(&base_input()).unwrap();
+        let mut input = base_input();
+        input.annual_discount_rate = dec!(0.12);
+        let out = analyze_unit_economics(&input).unwrap();
+        assert!(out.result.cohorts[0].ltv_per_user < base.result.cohorts[0].ltv_per_user);
+    }
+
+    #[test]
+    fn test_arpu_growth_raises_ltv() {
+        let base = analyze_unit_economics(&base_input()).unwrap();
+        let mut input = base_input();
+        input.arpu_growth_annual = dec!(0.10);
+        let out = analyze_unit_economics(&input).unwrap();
+        assert!(out.result.cohorts[0].ltv_per_user > base.result.cohorts[0].ltv_per_user);
+    }
+
+    #[test]
+    fn test_empirical_curve_and_extrapolation() {
+        let mut input = base_input();
+        input.cohorts = vec![cohort(
+            "Emp",
+            RetentionCurve::Empirical {
+                survival: vec![dec!(0.80), dec!(0.72), dec!(0.684), dec!(0.6498)],
+            },
+        )];
+        input.horizon_months = 6;
+        let out = analyze_unit_economics(&input).unwrap();
+        let s = &out.result.cohorts[0].survival_curve;
+        assert_eq!(s[0], dec!(0.80));
+        assert!(close(s[3], dec!(0.6498), dec!(0.0000001)));
+        // Tail hazard = avg(0.10, 0.05, 0.05) = 0.0666...
+        let tail = (dec!(0.10) + dec!(0.05) + dec!(0.05)) / dec!(3);
+        assert!(close(
+            s[4],
+            dec!(0.6498) * (Decimal::ONE - tail),
+            dec!(0.000001)
+        ));
+    }
+
+    #[test]
+    fn test_decaying_churn() {
+        let mut input = base_input();
+        input.cohorts = vec![cohort(
+            "Decay",
+            RetentionCurve::DecayingChurn {
+                initial_churn: dec!(0.20),
+                long_run_churn: dec!(0.02),
+                decay: dec!(0.5),
+            },
+        )];
+        let out = analyze_unit_economics(&input).unwrap();
+        let s = &out.result.cohorts[0].survival_curve;
+        // Month 1 churn 0.20, month 2 churn 0.02 + 0.18 * 0.5 = 0.11
+        assert_eq!(s[0], dec!(0.80));
+        assert!(close(s[1], dec!(0.80) * dec!(0.89), dec!(0.0000001)));
+    }
+
+    #[test]
+    fn test_no_payback_warns() {
+        let mut input = base_input();
+        input.cohorts[0].cac_per_user = dec!(10000);
+        let out = analyze_unit_economics(&input).unwrap();
+        assert!(out.result.cohorts[0].payback_month.is_none());
+        assert!(out.warnings.iter().any(|w| w.contains("not recovered")));
+        assert!(out.result.cohorts[0].cohort_net_value < Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_blended_metrics() {
+        let mut input = base_input();
+        let mut second = cohort(
+            "Feb",
+            RetentionCurve::Exponential {
+                monthly_churn: dec!(0.10),
+            },
+        );
+        second.initial_users = dec!(3000);
+        second.cac_per_user = dec!(60);
+        input.cohorts.push(second);
+        let out = analyze_unit_economics(&input).unwrap();
+        let r = &out.result;
+        assert_eq!(r.total_users, dec!(4000));
+        // (1000 * 100 + 3000 * 60) / 4000 = 70
+        assert_eq!(r.blended_cac_per_user, dec!(70));
+        let expected_ltv = (dec!(1000) * r.cohorts[0].ltv_per_user
+            + dec!(3000) * r.cohorts[1].ltv_per_user)
+            / dec!(4000);
+        assert!(close(r.blended_ltv_per_user, expected_ltv, dec!(0.000001)));
+        assert_eq!(
+            r.total_net_value,
+            r.cohorts[0].cohort_net_value + r.cohorts[1].cohort_net_value
+        );
+    }
+
+    #[test]
+    fn test_uncertainty_bands_ordered() {
+        let mut input = base_input();
+        input.uncertainty = Some(UncertaintySpec {
+            churn_volatility: dec!(0.25),
+            arpu_volatility: dec!(0.10),
+            num_simulations: Some(300),
+            seed: None,
+        });
+        let out = analyze_unit_economics(&input).unwrap();
+        let c = &out.result.cohorts[0];
+        let band = c.ltv_band.as_ref().unwrap();
+        assert!(band.p5 < band.p50 && band.p50 < band.p95);
+        assert!(band.p5 < c.ltv_per_user && c.ltv_per_user < band.p95);
+        let pb = c.payback_distribution.as_ref().unwrap();
+        assert!(pb.p5_month.unwrap() <= pb.p50_month.unwrap());
+        assert!(pb.probability_of_payback > dec!(0.9));
+    }
+
+    #[test]
+    fn test_uncertainty_zero_vol_collapses() {
+        let mut input = base_input();
+        input.uncertainty = Some(UncertaintySpec {
+            churn_volatility: Decimal::ZERO,
+            arpu_volatility: Decimal::ZERO,
+            num_simulations: Some(50),
+            seed: Some(7),
+        });
+        let out = analyze_unit_economics(&input).unwrap();
+        let c = &out.result.cohorts[0];
+        let band = c.ltv_band.as_ref().unwrap();
+        assert_eq!(band.p5, c.ltv_per_user);
+        assert_eq!(band.p95, c.ltv_per_user);
+        let pb = c.payback_distribution.as_ref().unwrap();
+        assert_eq!(pb.p50_month, Some(10));
+        assert_eq!(pb.probability_of_payback, Decimal::ONE);
+    }
+
+    #[test]
+    fn test_uncertainty_deterministic_seed() {
+        let mut input = base_input();
+        input.uncertainty = Some(UncertaintySpec {
+            churn_volatility: dec!(0.3),
+            arpu_volatility: dec!(0.1),
+            num_simulations: Some(100),
+            seed: Some(11),
+        });
+        let a = analyze_unit_economics(&input).unwrap();
+        let b = analyze_unit_economics(&input).unwrap();
+        assert_eq!(
+            a.result.cohorts[0].ltv_band.as_ref().unwrap().mean,
+            b.result.cohorts[0].ltv_band.as_ref().unwrap().mean
+        );
+    }
+
+    #[test]
+    fn test_non_positive_contribution_warns() {
+        let mut input = base_input();
+        input.variable_cost_per_user_monthly = dec!(20);
+        let out = analyze_unit_economics(&input).unwrap();
+        assert!(out.warnings.iter().any(|w| w.contains("non-positive")));
+        assert!(out.result.cohorts[0].ltv_per_user < Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_invalid_inputs() {
+        let mut input = base_input();
+        input.cohorts.clear();
+        assert!(analyze_unit_economics(&input).is_err());
+
+        let mut input = base_input();
+        input.horizon_months = 0;
+        assert!(analyze_unit_economics(&input).is_err());
+
+        let mut input = base_input();
+        input.cohorts[0].retention = RetentionCurve::Empirical {
+            survival: vec![dec!(0.8), dec!(0.9)],
+        };
+        assert!(analyze_unit_economics(&input).is_err());
+
+        let mut input = base_input();
+        input.cohorts[0].retention = RetentionCurve::Exponential {
+            monthly_churn: dec!(1.5),
+        };
+        assert!(analyze_unit_economics(&input).is_err());
+
+        let mut input = base_input();
+        input.uncertainty = Some(UncertaintySpec {
+            churn_volatility: dec!(-0.1),
+            arpu_volatility: Decimal::ZERO,
+            num_simulations: None,
+            seed: None,
+        });
+        assert!(analyze_unit_economics(&input).is_err());
+    }
+}
@@ -1,9 +1,11 @@
 use rust_decimal::Decimal;
+use rust_decimal::MathematicalOps;
 use rust_decimal_macros::dec;
 use serde::{Deserialize, Serialize};
 use std::time::Instant;
 
 use crate::error::CorpFinanceError;
+use crate::stats::{norm_cdf, norm_pdf};
 use crate::types::{with_metadata, ComputationOutput, Money, Rate};
 use crate::CorpFinanceResult;
 
@@ -914,6 +916,680 @@ fn build_historical_row(p: &ForecastPeriod, tax_rate: Rate) -> ForecastRow {
     }
 }
 
+// ---------------------------------------------------------------------------
+// Inventory Economics — Input / Output types
+// ---------------------------------------------------------------------------
+
+/// Input for driver-based inventory optimisation: order quantities, safety
+/// stock and the revolver capacity needed to carry the resulting inventory.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InventoryOptimizationInput {
+    /// Company identifier
+    pub company_name: String,
+    /// Stock-keeping units (or product families) to optimise
+    pub items: Vec<InventoryItem>,
+    /// Non-financing carrying cost per year as a fraction of unit cost
+    /// (storage, insurance, shrinkage, obsolescence)
+    pub holding_cost_rate: Rate,
+    /// Cost of capital on inventory not funded by the revolver
+    pub cost_of_capital: Rate,
+    /// Cycle service level for items without a stockout cost (default 0.95)
+    #[serde(default = "default_service_level")]
+    pub target_service_level: Rate,
+    /// Operating days per year used to convert annual demand (default 365)
+    #[serde(default = "default_days_per_year")]
+    pub days_per_year: u32,
+    /// Revolving facility terms; when present the borrowing base funds
+    /// inventory at the advance rate and the facility is sized from it
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub financing: Option<InventoryFinancing>,
+}
+
+fn default_service_level() -> Rate {
+    dec!(0.95)
+}
+
+fn default_days_per_year() -> u32 {
+    365
+}
+
+/// Demand, lead-time and cost drivers for a single inventory item.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InventoryItem {
+    /// Item or product family name
+    pub name: String,
+    /// Annual demand in units
+    pub annual_demand: Decimal,
+    /// Standard deviation of daily demand in units
+    #[serde(default)]
+    pub daily_demand_std_dev: Decimal,
+    /// Average replenishment lead time in days
+    pub lead_time_days: Decimal,
+    /// Standard deviation of the replenishment lead time in days
+    #[serde(default)]
+    pub lead_time_std_dev_days: Decimal,
+    /// Landed cost per unit
+    pub unit_cost: Money,
+    /// Fixed cost per purchase order or production set-up
+    pub order_cost: Money,
+    /// Cost per unit short (expediting, lost margin); sets the service level
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stockout_cost_per_unit: Option<Money>,
+    /// Current order quantity, for comparison with the optimised policy
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub current_order_quantity: Option<Decimal>,
+    /// Current safety stock in units
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub current_safety_stock: Option<Decimal>,
+}
+
+/// Asset-based revolver terms used to finance inventory.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InventoryFinancing {
+    /// All-in rate on drawn revolver balances
+    pub revolver_rate: Rate,
+    /// Fee on the undrawn commitment
+    #[serde(default)]
+    pub commitment_fee: Rate,
+    /// Borrowing-base advance rate against eligible inventory
+    pub advance_rate: Rate,
+    /// Cushion over the peak draw when sizing the commitment (default 0.15)
+    #[serde(default = "default_revolver_headroom")]
+    pub headroom: Rate,
+    /// Existing commitment, to test whether the facility is adequate
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub existing_commitment: Option<Money>,
+}
+
+fn default_revolver_headroom() -> Rate {
+    dec!(0.15)
+}
+
+/// Full output of inventory optimisation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InventoryOptimizationOutput {
+    /// Optimised policy per item
+    pub items: Vec<InventoryItemResult>,
+    /// Rate at which inventory is financed (revolver / cost of capital blend)
+    pub financing_rate: Rate,
+    /// Holding cost rate plus financing rate
+    pub carrying_cost_rate: Rate,
+    /// Annual COGS implied by demand x unit cost
+    pub annual_cogs: Money,
+    /// Average inventory = sum of (Q/2 + safety stock) x unit cost
+    pub average_inventory: Money,
+    /// Inventory if every item's order lands at once = sum of (Q + SS) x unit cost
+    pub peak_inventory: Money,
+    /// Driver-based DIO = average inventory / (COGS / days)
+    pub target_dio: Decimal,
+    /// Ordering + carrying + expected stockout cost across items
+    pub total_annual_cost: Money,
+    /// Portfolio cost at standard service levels, order quantities held at EOQ
+    pub service_level_tradeoff: Vec<ServiceLevelPoint>,
+    /// Comparison with current order quantities / safety stocks (if given)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub current_policy: Option<CurrentPolicyComparison>,
+    /// Revolver sizing (if financing terms given)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub revolver: Option<RevolverSizing>,
+    /// Actionable recommendations
+    pub recommendations: Vec<String>,
+}
+
+/// Optimised ordering policy and cost for a single item.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InventoryItemResult {
+    pub name: String,
+    /// EOQ = sqrt(2 x D x S / H)
+    pub economic_order_quantity: Decimal,
+    pub orders_per_year: Decimal,
+    /// Days between replenishments
+    pub cycle_days: Decimal,
+    /// Std dev of lead-time demand = sqrt(L x sd_d^2 + d^2 x sd_L^2)
+    pub lead_time_demand_std_dev: Decimal,
+    /// Cycle service level (probability of no stockout per cycle)
+    pub service_level: Rate,
+    pub z_score: Decimal,
+    pub safety_stock: Decimal,
+    /// Reorder point = d x L + safety stock
+    pub reorder_point: Decimal,
+    pub average_inventory_value: Money,
+    pub annual_ordering_cost: Money,
+    /// Carrying cost including financing
+    pub annual_holding_cost: Money,
+    /// Financing component of the carrying cost
+    pub annual_financing_cost: Money,
+    pub expected_annual_stockout_units: Decimal,
+    pub expected_annual_stockout_cost: Money,
+    pub total_annual_cost: Money,
+}
+
+/// Safety-stock carrying cost against expected stockout cost at one service level.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ServiceLevelPoint {
+    pub service_level: Rate,
+    pub safety_stock_value: Money,
+    pub safety_stock_carrying_cost: Money,
+    pub expected_stockout_cost: Money,
+    pub total_cost: Money,
+}
+
+/// Current policy versus the optimised policy.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CurrentPolicyComparison {
+    pub current_average_inventory: Money,
+    pub current_dio: Decimal,
+    pub current_total_annual_cost: Money,
+    pub optimized_total_annual_cost: Money,
+    /// Current cost minus optimised cost
+    pub annual_cost_savings: Money,
+    /// Reduction in average inventory (negative = inventory build)
+    pub cash_released: Money,
+}
+
+/// Revolver capacity and cost needed to carry the optimised inventory.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RevolverSizing {
+    /// Advance rate x average inventory
+    pub average_draw: Money,
+    /// Advance rate x peak inventory
+    pub peak_draw: Money,
+    /// Peak draw x (1 + headroom)
+    pub recommended_commitment: Money,
+    pub annual_interest: Money,
+    /// Commitment fee on the average undrawn amount
+    pub annual_commitment_fee: Money,
+    pub total_financing_cost: Money,
+    /// Peak draw / existing commitment (if an existing facility is given)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub existing_peak_utilization: Option<Rate>,
+}
+
+// ---------------------------------------------------------------------------
+// Public API — Inventory Economics
+// ---------------------------------------------------------------------------
+
+/// Optimise inventory from demand and lead-time drivers: EOQ order sizes,
+/// safety stock at the cost-optimal (or target) service level, and the
+/// revolver commitment needed to finance the resulting inventory. Financing
+/// cost feeds back into the carrying cost, so order sizes reflect what the
+/// inventory actually costs to fund.
+pub fn optimize_inventory(
+    input: &InventoryOptimizationInput,
+) -> CorpFinanceResult<ComputationOutput<InventoryOptimizationOutput>> {
+    let start = Instant::now();
+    let mut warnings: Vec<String> = Vec::new();
+
+    validate_inventory_input(input)?;
+
+    // -- Carrying cost rate --------------------------------------------------
+    let financing_rate = match &input.financing {
+        Some(f) => {
+            f.advance_rate * f.revolver_rate
+                + (Decimal::ONE - f.advance_rate) * input.cost_of_capital
+        }
+        None => input.cost_of_capital,
+    };
+    let carrying_cost_rate = input.holding_cost_rate + financing_rate;
+    if carrying_cost_rate <= Decimal::ZERO {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "holding_cost_rate".into(),
+            reason: "Holding cost rate plus financing rate must be positive.".into(),
+        });
+    }
+    let days = Decimal::from(input.days_per_year);
+
+    // -- Per-item policy -----------------------------------------------------
+    let mut policies: Vec<ItemPolicy> = Vec::with_capacity(input.items.len());
+    let mut items: Vec<InventoryItemResult> = Vec::with_capacity(input.items.len());
+    for item in &input.items {
+        let policy = compute_item_policy(
+            item,
+            carrying_cost_rate,
+            input.target_service_level,
+            days,
+            &mut warnings,
+        );
+        let cost = policy_cost(
+            item,
+            &policy,
+            policy.eoq,
+            policy.safety_stock,
+            carrying_cost_rate,
+        );
+        let average_inventory_value = (policy.eoq / dec!(2) + policy.safety_stock) * item.unit_cost;
+        let orders_per_year = item.annual_demand / policy.eoq;
+        items.push(InventoryItemResult {
+            name: item.name.clone(),
+            economic_order_quantity: policy.eoq,
+            orders_per_year,
+            cycle_days: days / orders_per_year,
+            lead_time_demand_std_dev: policy.sigma_lead_time,
+            service_level: policy.service_level,
+            z_score: policy.z,
+            safety_stock: policy.safety_stock,
+            reorder_point: policy.daily_demand * item.lead_time_days + policy.safety_stock,
+            average_inventory_value,
+            annual_ordering_cost: cost.ordering,
+            annual_holding_cost: cost.holding,
+            annual_financing_cost: average_inventory_value * financing_rate,
+            expected_annual_stockout_units: cost.stockout_units,
+            expected_annual_stockout_cost: cost.stockout,
+            total_annual_cost: cost.total(),
+        });
+        policies.push(policy);
+    }
+
+    // -- Aggregates ----------------------------------------------------------
+    let annual_cogs: Money = input
+        .items
+        .iter()
+        .map(|i| i.annual_demand * i.unit_cost)
+        .sum();
+    let average_inventory: Money = items.iter().map(|r| r.average_inventory_value).sum();
+    let peak_inventory: Money = input
+        .items
+        .iter()
+        .zip(&policies)
+        .map(|(i, p)| (p.eoq + p.safety_stock) * i.unit_cost)
+        .sum();
+    let target_dio = average_inventory / (annual_cogs / days);
+    let total_annual_cost: Money = items.iter().map(|r| r.total_annual_cost).sum();
+
+    // -- Service level trade-off ---------------------------------------------
+    let service_level_tradeoff = [dec!(0.90), dec!(0.95), dec!(0.975), dec!(0.99), dec!(0.995)]
+        .iter()
+        .map(|&sl| {
+            let z = norm_inv(sl);
+            let mut point = ServiceLevelPoint {
+                service_level: sl,
+                safety_stock_value: Decimal::ZERO,
+                safety_stock_carrying_cost: Decimal::ZERO,
+                expected_stockout_cost: Decimal::ZERO,
+                total_cost: Decimal::ZERO,
+            };
+            for (item, policy) in input.items.iter().zip(&policies) {
+                let ss = z * policy.sigma_lead_time;
+                let cost = policy_cost(item, policy, policy.eoq, ss, carrying_cost_rate);
+                point.safety_stock_value += ss * item.unit_cost;
+                point.expected_stockout_cost += cost.stockout;
+            }
+            point.safety_stock_carrying_cost = point.safety_stock_value * carrying_cost_rate;
+            point.total_cost = point.safety_stock_carrying_cost + point.expected_stockout_cost;
+            point
+        })
+        .collect();
+
+    // -- Current policy comparison -------------------------------------------
+    let has_current = input
+        .items
+        .iter()
+        .any(|i| i.current_order_quantity.is_some() || i.current_safety_stock.is_some());
+    let current_policy = if has_current {
+        let mut current_average_inventory = Decimal::ZERO;
+        let mut current_total_annual_cost = Decimal::ZERO;
+        for (item, policy) in input.items.iter().zip(&policies) {
+            let q = item.current_order_quantity.unwrap_or(policy.eoq);
+            let ss = item.current_safety_stock.unwrap_or(policy.safety_stock);
+            current_average_inventory += (q / dec!(2) + ss) * item.unit_cost;
+            current_total_annual_cost +=
+                policy_cost(item, policy, q, ss, carrying_cost_rate).total();
+        }
+        Some(CurrentPolicyComparison {
+            current_average_inventory,
+            current_dio: current_average_inventory / (annual_cogs / days),
+            current_total_annual_cost,
+            optimized_total_annual_cost: total_annual_cost,
+            annual_cost_savings: current_total_annual_cost - total_annual_cost,
+            cash_released: current_average_inventory - average_inventory,
+        })
+    } else {
+        None
+    };
+
+    // -- Revolver sizing -----------------------------------------------------
+    let revolver = input.financing.as_ref().map(|f| {
+        let average_draw = f.advance_rate * average_inventory;
+        let peak_draw = f.advance_rate * peak_inventory;
+        let recommended_commitment = peak_draw * (Decimal::ONE + f.headroom);
+        let annual_interest = average_draw * f.revolver_rate;
+        let annual_commitment_fee = (recommended_commitment - average_draw) * f.commitment_fee;
+        let existing_peak_utilization = f
+            .existing_commitment
+            .filter(|c| *c > Decimal::ZERO)
+            .map(|c| peak_draw / c);
+        if let Some(existing) = f.existing_commitment {
+            if peak_draw > existing {
+                warnings.push(format!(
+                    "Peak inventory draw of {} exceeds the existing revolver commitment of {}.",
+                    peak_draw.round_dp(0),
+                    existing.round_dp(0)
+                ));
+            }
+        }
+        RevolverSizing {
+            average_draw,
+            peak_draw,
+            recommended_commitment,
+            annual_interest,
+            annual_commitment_fee,
+            total_financing_cost: annual_interest + annual_commitment_fee,
+            existing_peak_utilization,
+        }
+    });
+
+    let recommendations = build_inventory_recommendations(
+        input,
+        &items,
+        target_dio,
+        current_policy.as_ref(),
+        revolver.as_ref(),
+    );
+
+    let output = InventoryOptimizationOutput {
+        items,
+        financing_rate,
+        carrying_cost_rate,
+        annual_cogs,
+        average_inventory,
+        peak_inventory,
+        target_dio,
+        total_annual_cost,
+        service_level_tradeoff,
+        current_policy,
+        revolver,
+        recommendations,
+    };
+
+    let elapsed = start.elapsed().as_micros() as u64;
+
+    Ok(with_metadata(
+        "Inventory Economics (EOQ, Safety Stock, Revolver Sizing)",
+        input,
+        warnings,
+        elapsed,
+        output,
+    ))
+}
+
+// ---------------------------------------------------------------------------
+// Internal helpers — Inventory Economics
+// ---------------------------------------------------------------------------
+
+/// Optimised policy parameters for one item.
+struct ItemPolicy {
+    eoq: Decimal,
+    daily_demand: Decimal,
+    sigma_lead_time: Decimal,
+    service_level: Rate,
+    z: Decimal,
+    safety_stock: Decimal,
+}
+
+/// Annual cost components of an ordering policy.
+struct PolicyCost {
+    ordering: Money,
+    holding: Money,
+    stockout_units: Decimal,
+    stockout: Money,
+}
+
+impl PolicyCost {
+    fn total(&self) -> Money {
+        self.ordering + self.holding + self.stockout
+    }
+}
+
+fn validate_inventory_input(input: &InventoryOptimizationInput) -> CorpFinanceResult<()> {
+    if input.items.is_empty() {
+        return Err(CorpFinanceError::InsufficientData(
+            "At least one inventory item is required.".into(),
+        ));
+    }
+    if input.holding_cost_rate < Decimal::ZERO {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "holding_cost_rate".into(),
+            reason: "Holding cost rate cannot be negative.".into(),
+        });
+    }
+    if input.cost_of_capital < Decimal::ZERO {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "cost_of_capital".into(),
+            reason: "Cost of capital cannot be negative.".into(),
+        });
+    }
+    if input.target_service_level <= Decimal::ZERO || input.target_service_level >= Decimal::ONE {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "target_service_level".into(),
+            reason: "Target service level must be between 0 and 1 (exclusive).".into(),
+        });
+    }
+    if input.days_per_year == 0 {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "days_per_year".into(),
+            reason: "Days per year must be positive.".into(),
+        });
+    }
+    for item in &input.items {
+        let positive = [
+            ("annual_demand", item.annual_demand),
+            ("unit_cost", item.unit_cost),
+            ("order_cost", item.order_cost),
+        ];
+        for (field, value) in positive {
+            if value <= Decimal::ZERO {
+                return Err(CorpFinanceError::InvalidInput {
+                    field: field.into(),
+                    reason: format!("Must be positive for item '{}'.", item.name),
+                });
+            }
+        }
+        let non_negative = [
+            ("daily_demand_std_dev", Some(item.daily_demand_std_dev)),
+            ("lead_time_days", Some(item.lead_time_days)),
+            ("lead_time_std_dev_days", Some(item.lead_time_std_dev_days)),
+            ("stockout_cost_per_unit", item.stockout_cost_per_unit),
+            ("current_safety_stock", item.current_safety_stock),
+        ];
+        for (field, value) in non_negative {
+            if value.is_some_and(|v| v < Decimal::ZERO) {
+                return Err(CorpFinanceError::InvalidInput {
+                    field: field.into(),
+                    reason: format!("Cannot be negative for item '{}'.", item.name),
+                });
+            }
+        }
+        if item
+            .current_order_quantity
+            .is_some_and(|q| q <= Decimal::ZERO)
+        {
+            return Err(CorpFinanceError::InvalidInput {
+                field: "current_order_quantity".into(),
+                reason: format!("Must be positive for item '{}'.", item.name),
+            });
+        }
+    }
+    if let Some(f) = &input.financing {
+        if f.revolver_rate < Decimal::ZERO
+            || f.commitment_fee < Decimal::ZERO
+            || f.headroom < Decimal::ZERO
+        {
+            return Err(CorpFinanceError::InvalidInput {
+                field: "financing".into(),
+                reason: "Revolver rate, commitment fee and headroom cannot be negative.".into(),
+            });
+        }
+        if f.advance_rate < Decimal::ZERO || f.advance_rate > Decimal::ONE {
+            return Err(CorpFinanceError::InvalidInput {
+                field: "advance_rate".into(),
+                reason: "Advance rate must be between 0 and 1.".into(),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// EOQ, lead-time demand variability and safety stock for one item. With a
+/// stockout cost B the cost-optimal cycle service level is 1 - H*Q / (B*D).
+fn compute_item_policy(
+    item: &InventoryItem,
+    carrying_cost_rate: Rate,
+    target_service_level: Rate,
+    days: Decimal,
+    warnings: &mut Vec<String>,
+) -> ItemPolicy {
+    let holding_per_unit = item.unit_cost * carrying_cost_rate;
+    let eoq = (dec!(2) * item.annual_demand * item.order_cost / holding_per_unit)
+        .sqrt()
+        .unwrap_or(Decimal::ZERO);
+    let daily_demand = item.annual_demand / days;
+    let sigma_lead_time = (item.lead_time_days
+        * item.daily_demand_std_dev
+        * item.daily_demand_std_dev
+        + daily_demand * daily_demand * item.lead_time_std_dev_days * item.lead_time_std_dev_days)
+        .sqrt()
+        .unwrap_or(Decimal::ZERO);
+
+    let service_level = match item.stockout_cost_per_unit {
+        Some(b) if b > Decimal::ZERO => {
+            let optimal = Decimal::ONE - holding_per_unit * eoq / (b * item.annual_demand);
+            if optimal < dec!(0.5) {
+                warnings.push(format!(
+                    "Stockout cost for '{}' is low relative to carrying cost; service level floored at 50%.",
+                    item.name
+                ));
+            }
+            optimal.clamp(dec!(0.5), dec!(0.9999))
+        }
+        _ => target_service_level,
+    };
+    let z = norm_inv(service_level);
+    let safety_stock = (z * sigma_lead_time).max(Decimal::ZERO);
+
+    ItemPolicy {
+        eoq,
+        daily_demand,
+        sigma_lead_time,
+        service_level,
+        z,
+        safety_stock,
+    }
+}
+
+/// Annual ordering, carrying and expected stockout cost of ordering `q`
+/// units with `ss` safety stock. Expected shortage per cycle is
+/// sigma_L x G(z), where G is the standard normal loss function.
+fn policy_cost(
+    item: &InventoryItem,
+    policy: &ItemPolicy,
+    q: Decimal,
+    ss: Decimal,
+    carrying_cost_rate: Rate,
+) -> PolicyCost {
+    let orders_per_year = item.annual_demand / q;
+    let ordering = orders_per_year * item.order_cost;
+    let holding = (q / dec!(2) + ss) * item.unit_cost * carrying_cost_rate;
+    let stockout_units = if policy.sigma_lead_time > Decimal::ZERO {
+        let z = ss / policy.sigma_lead_time;
+        let loss = (norm_pdf(z) - z * (Decimal::ONE - norm_cdf(z))).max(Decimal::ZERO);
+        orders_per_year * policy.sigma_lead_time * loss
+    } else {
+        Decimal::ZERO
+    };
+    let stockout = stockout_units * item.stockout_cost_per_unit.unwrap_or(Decimal::ZERO);
+    PolicyCost {
+        ordering,
+        holding,
+        stockout_units,
+        stockout,
+    }
+}
+
+fn build_inventory_recommendations(
+    input: &InventoryOptimizationInput,
+    items: &[InventoryItemResult],
+    target_dio: Decimal,
+    current: Option<&CurrentPolicyComparison>,
+    revolver: Option<&RevolverSizing>,
+) -> Vec<String> {
+    let mut recs = Vec::new();
+
+    match current {
+        Some(c) => {
+            recs.push(format!(
+                "Set the DIO target at {:.1} days from demand and lead-time drivers (current policy implies {:.1} days).",
+                target_dio, c.current_dio
+            ));
+            if c.cash_released > Decimal::ZERO {
+                recs.push(format!(
+                    "Moving to the optimised policy releases {:.0} of inventory and saves {:.0} a year.",
+                    c.cash_released, c.annual_cost_savings
+                ));
+            } else if c.cash_released < Decimal::ZERO {
+                recs.push(format!(
+                    "The optimised policy needs {:.0} more inventory but saves {:.0} a year in ordering and stockout cost.",
+                    -c.cash_released, c.annual_cost_savings
+                ));
+            }
+        }
+        None => recs.push(format!(
+            "Set the DIO target at {:.1} days from demand and lead-time drivers.",
+            target_dio
+        )),
+    }
+
+    for r in items {
+        if r.service_level < input.target_service_level {
+            recs.push(format!(
+                "'{}': stockouts are cheaper than safety stock; a {:.1}% service level is cost-optimal.",
+                r.name,
+                r.service_level * dec!(100)
+            ));
+        }
+    }
+
+    if let (Some(r), Some(f)) = (revolver, input.financing.as_ref()) {
+        match f.existing_commitment {
+            Some(existing) if r.peak_draw > existing => recs.push(format!(
+                "Upsize the revolver to {:.0}: peak inventory draw of {:.0} exceeds the {:.0} commitment.",
+                r.recommended_commitment, r.peak_draw, existing
+            )),
+            Some(existing) if existing > r.recommended_commitment * dec!(1.5) => {
+                recs.push(format!(
+                    "The {:.0} commitment is well above the {:.0} inventory needs; downsizing cuts commitment fees.",
+                    existing, r.recommended_commitment
+                ))
+            }
+            _ => recs.push(format!(
+                "Size the revolver at {:.0} to cover a peak inventory draw of {:.0}.",
+                r.recommended_commitment, r.peak_draw
+            )),
+        }
+    }
+
+    recs
+}
+
+/// Inverse of the standard normal CDF (Abramowitz & Stegun 26.2.23).
+fn norm_inv(p: Decimal) -> Decimal {
+    if p <= Decimal::ZERO {
+        return dec!(-10);
+    }
+    if p >= Decimal::ONE {
+        return dec!(10);
+    }
+    let (sign, pp) = if p < dec!(0.5) {
+        (Decimal::NEGATIVE_ONE, p)
+    } else {
+        (Decimal::ONE, Decimal::ONE - p)
+    };
+    let t = (dec!(-2) * pp.ln()).sqrt().unwrap_or(Decimal::ZERO);
+    let numerator = dec!(2.515517) + dec!(0.802853) * t + dec!(0.010328) * t * t;
+    let denominator =
+        Decimal::ONE + dec!(1.432788) * t + dec!(0.189269) * t * t + dec!(0.001308) * t * t * t;
+    sign * (t - numerator / denominator)
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -1401,4 +2077,182 @@ mod tests {
         let result = build_rolling_forecast(&input).unwrap();
         assert_eq!(result.methodology, "Rolling Financial Forecast");
     }
+
+    // -- Inventory economics -------------------------------------------------
+
+    fn sample_item() -> InventoryItem {
+        InventoryItem {
+            name: "Widget".to_string(),
+            annual_demand: dec!(12_000),
+            daily_demand_std_dev: dec!(10),
+            lead_time_days: dec!(16),
+            lead_time_std_dev_days: Decimal::ZERO,
+            unit_cost: dec!(10),
+            order_cost: dec!(100),
+            stockout_cost_per_unit: None,
+            current_order_quantity: None,
+            current_safety_stock: None,
+        }
+    }
+
+    fn sample_inventory_input() -> InventoryOptimizationInput {
+        InventoryOptimizationInput {
+            company_name: "StockCo".to_string(),
+            items: vec![sample_item()],
+            holding_cost_rate: dec!(0.14),
+            cost_of_capital: dec!(0.10),
+            target_service_level: dec!(0.95),
+            days_per_year: 360,
+            financing: None,
+        }
+    }
+
+    #[test]
+    fn test_eoq_textbook() {
+        // H = 10 x (0.14 + 0.10) = 2.4; EOQ = sqrt(2 x 12000 x 100 / 2.4) = 1000
+        let result = optimize_inventory(&sample_inventory_input()).unwrap();
+        let item = &result.result.items[0];
+        assert!((item.economic_order_quantity - dec!(1000)).abs() < dec!(0.001));
+        assert!((item.orders_per_year - dec!(12)).abs() < dec!(0.001));
+        assert!((item.cycle_days - dec!(30)).abs() < dec!(0.001));
+        // At EOQ ordering cost equals cycle-stock carrying cost
+        assert!((item.annual_ordering_cost - dec!(1200)).abs() < dec!(0.01));
+    }
+
+    #[test]
+    fn test_safety_stock_and_reorder_point() {
+        // sigma_L = sqrt(16) x 10 = 40; z(95%) ~ 1.645
+        let result = optimize_inventory(&sample_inventory_input()).unwrap();
+        let item = &result.result.items[0];
+        assert!((item.lead_time_demand_std_dev - dec!(40)).abs() < dec!(0.0001));
+        assert!((item.z_score - dec!(1.645)).abs() < dec!(0.01));
+        assert!((item.safety_stock - dec!(65.8)).abs() < dec!(0.5));
+        // d = 12000 / 360 = 33.33/day; d x L = 533.3
+        let expected_rop = dec!(12_000) / dec!(360) * dec!(16) + item.safety_stock;
+        assert!((item.reorder_point - expected_rop).abs() < dec!(0.0001));
+    }
+
+    #[test]
+    fn test_lead_time_variability_increases_safety_stock() {
+        let base = optimize_inventory(&sample_inventory_input()).unwrap();
+        let mut input = sample_inventory_input();
+        input.items[0].lead_time_std_dev_days = dec!(3);
+        let variable = optimize_inventory(&input).unwrap();
+        assert!(
+            variable.result.items[0].safety_stock > base.result.items[0].safety_stock * dec!(2)
+        );
+        assert!(variable.result.target_dio > base.result.target_dio);
+    }
+
+    #[test]
+    fn test_stockout_cost_sets_service_level() {
+        // Optimal SL = 1 - H x Q / (B x D) = 1 - 2.4 x 1000 / (20 x 12000) = 0.99
+        let mut input = sample_inventory_input();
+        input.items[0].stockout_cost_per_unit = Some(dec!(20));
+        let result = optimize_inventory(&input).unwrap();
+        let item = &result.result.items[0];
+        assert!((item.service_level - dec!(0.99)).abs() < dec!(0.0001));
+        assert!(item.expected_annual_stockout_cost > Decimal::ZERO);
+
+        // Stockout cost falls as the service level rises
+        let curve = &result.result.service_level_tradeoff;
+        assert_eq!(curve.len(), 5);
+        for pair in curve.windows(2) {
+            assert!(pair[1].expected_stockout_cost < pair[0].expected_stockout_cost);
+            assert!(pair[1].safety_stock_carrying_cost > pair[0].safety_stock_carrying_cost);
+        }
+    }
+
+    #[test]
+    fn test_revolver_sizing_and_financing_rate() {
+        let mut input = sample_inventory_input();
+        input.cost_of_capital = dec!(0.12);
+        input.financing = Some(InventoryFinancing {
+            revolver_rate: dec!(0.07),
+            commitment_fee: dec!(0.005),
+            advance_rate: dec!(0.8),
+            headroom: dec!(0.15),
+            existing_commitment: Some(dec!(5_000)),
+        });
+        let result = optimize_inventory(&input).unwrap();
+        let out = &result.result;
+        // 0.8 x 7% + 0.2 x 12% = 8%
+        assert_eq!(out.financing_rate, dec!(0.080));
+        assert_eq!(out.carrying_cost_rate, dec!(0.220));
+
+        let revolver = out.revolver.as_ref().unwrap();
+        assert_eq!(revolver.peak_draw, dec!(0.8) * out.peak_inventory);
+        assert_eq!(revolver.average_draw, dec!(0.8) * out.average_inventory);
+        assert_eq!(
+            revolver.recommended_commitment,
+            revolver.peak_draw * dec!(1.15)
+        );
+        assert!(revolver.existing_peak_utilization.unwrap() > Decimal::ONE);
+        assert!(result
+            .warnings
+            .iter()
+            .any(|w| w.contains("exceeds the existing revolver commitment")));
+    }
+
+    #[test]
+    fn test_cheaper_financing_raises_order_quantity() {
+        let unfunded = optimize_inventory(&sample_inventory_input()).unwrap();
+        let mut input = sample_inventory_input();
+        input.financing = Some(InventoryFinancing {
+            revolver_rate: dec!(0.05),
+            commitment_fee: Decimal::ZERO,
+            advance_rate: dec!(0.85),
+            headroom: dec!(0.15),
+            existing_commitment: None,
+        });
+        let funded = optimize_inventory(&input).unwrap();
+        assert!(
+            funded.result.items[0].economic_order_quantity
+                > unfunded.result.items[0].economic_order_quantity
+        );
+    }
+
+    #[test]
+    fn test_current_policy_comparison() {
+        let mut input = sample_inventory_input();
+        input.items[0].current_order_quantity = Some(dec!(3_000));
+        input.items[0].current_safety_stock = Some(dec!(200));
+        let result = optimize_inventory(&input).unwrap();
+        let current = result.result.current_policy.as_ref().unwrap();
+        assert!(current.annual_cost_savings > Decimal::ZERO);
+        assert!(current.cash_released > Decimal::ZERO);
+        assert!(current.current_dio > result.result.target_dio);
+    }
+
+    #[test]
+    fn test_large_current_safety_stock_has_no_stockouts() {
+        // sigma_L = sqrt(4) x 1 = 2, so 100 units of safety stock is z = 50
+        let mut input = sample_inventory_input();
+        input.items[0].daily_demand_std_dev = Decimal::ONE;
+        input.items[0].lead_time_days = dec!(4);
+        input.items[0].stockout_cost_per_unit = Some(dec!(5));
+        input.items[0].current_safety_stock = Some(dec!(100));
+        let result = optimize_inventory(&input).unwrap();
+        let current = result.result.current_policy.as_ref().unwrap();
+        assert!(current.current_total_annual_cost > current.optimized_total_annual_cost);
+        assert!(current.cash_released > Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_inventory_validation() {
+        let mut input = sample_inventory_input();
+        input.target_service_level = Decimal::ONE;
+        assert!(optimize_inventory(&input).is_err());
+
+        let mut input = sample_inventory_input();
+        input.items.clear();
+        assert!(matches!(
+            optimize_inventory(&input),
+            Err(CorpFinanceError::InsufficientData(_))
+        ));
+
+        let mut input = sample_inventory_input();
+        input.items[0].order_cost = Decimal::ZERO;
+        assert!(optimize_inventory(&input).is_err());
+    }
 }
@@ -0,0 +1,907 @@
+//! Franchise and license agreement economics.
+//!
+//! Models both sides of a franchise agreement. The franchisee view is a
+//! single unit over one agreement term: build-out and initial fee up front,
+//! ramping sales, four-wall margin, royalty, marketing fund and technology
+//! fees, and periodic refresh capex, giving unit IRR, NPV, payback and the
+//! royalty rate at which the franchisee only just earns its hurdle. The
+//! franchisor view rolls a development schedule out across a territory
+//! (cohorts, ramp, closures, renewals) and nets fee income against opening
+//! support, ongoing field support and overhead. Marketing fund contributions
+//! are treated as a pass-through: collected and spent on advertising, with no
+//! effect on franchisor profit. A royalty sensitivity re-runs both sides
+//! across a range of rates. All cash flows are annual and pre-tax.
+
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+use std::time::Instant;
+
+use crate::error::CorpFinanceError;
+use crate::time_value::{irr, npv};
+use crate::types::{with_metadata, ComputationOutput, Money, Multiple, Rate};
+use crate::CorpFinanceResult;
+
+// ---------------------------------------------------------------------------
+// Types
+// ---------------------------------------------------------------------------
+
+/// Operating economics of a single franchised unit.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UnitEconomics {
+    /// Franchisee build-out, equipment and pre-opening costs, excluding the
+    /// initial franchise fee.
+    pub initial_investment: Money,
+    /// Annual sales of a mature unit in year-1 dollars.
+    pub mature_revenue: Money,
+    /// Fraction of mature sales reached in each year after opening (e.g.
+    /// `[0.7, 0.9]`); later years run at maturity.
+    #[serde(default)]
+    pub ramp: Vec<Rate>,
+    /// Annual same-store sales growth.
+    #[serde(default)]
+    pub revenue_growth: Rate,
+    /// Four-wall EBITDA margin before franchise fees.
+    pub operating_margin: Rate,
+    /// Remodel / refresh capex required each cycle.
+    #[serde(default)]
+    pub refresh_capex: Money,
+    /// Years between required refreshes; none falls in the final year of
+    /// the term.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub refresh_cycle_years: Option<u32>,
+}
+
+/// Fees payable under the franchise agreement.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FeeStructure {
+    /// Initial franchise fee per unit, paid at opening.
+    pub initial_fee: Money,
+    /// Royalty as a fraction of unit sales.
+    pub royalty_rate: Rate,
+    /// Marketing / advertising fund contribution as a fraction of sales.
+    #[serde(default)]
+    pub marketing_fund_rate: Rate,
+    /// Flat annual technology or software fee per unit.
+    #[serde(default)]
+    pub technology_fee: Money,
+    /// Agreement term in years.
+    pub term_years: u32,
+    /// Fee per unit at the start of each renewal term.
+    #[serde(default)]
+    pub renewal_fee: Money,
+}
+
+/// Franchisor's cost of building and supporting the system.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FranchisorCosts {
+    /// Year-0 investment in the concept, systems and development team.
+    #[serde(default)]
+    pub upfront_investment: Money,
+    /// Training, site selection and opening support per new unit.
+    #[serde(default)]
+    pub opening_support_cost: Money,
+    /// Annual field support cost per operating unit.
+    #[serde(default)]
+    pub support_cost_per_unit: Money,
+    /// Fixed annual franchisor overhead.
+    #[serde(default)]
+    pub annual_overhead: Money,
+}
+
+/// Input for franchise economics.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FranchiseInput {
+    pub system_name: String,
+    pub unit: UnitEconomics,
+    pub fees: FeeStructure,
+    #[serde(default)]
+    pub franchisor_costs: FranchisorCosts,
+    /// New units opened in each projection year, year 1 first.
+    pub development_schedule: Vec<u32>,
+    /// Units already operating at mature volume. They pay royalties and
+    /// support costs but no initial or renewal fees within the horizon.
+    #[serde(default)]
+    pub existing_units: u32,
+    /// Maximum units the territory supports, existing units included.
+    /// Openings beyond it are dropped.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub territory_capacity: Option<u32>,
+    /// Fraction of operating units closing each year after opening.
+    #[serde(default)]
+    pub annual_closure_rate: Rate,
+    /// Years in the franchisor projection.
+    pub projection_years: u32,
+    /// Franchisee hurdle rate for unit NPV.
+    pub franchisee_discount_rate: Rate,
+    /// Franchisor discount rate for system NPV.
+    pub franchisor_discount_rate: Rate,
+    /// Multiple of final-year unit EBITDA after fees the franchisee realises
+    /// on resale at the end of the term (default none).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub franchisee_exit_multiple: Option<Multiple>,
+    /// Multiple of final-year franchisor cash flow taken as terminal value
+    /// (default none).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub franchisor_exit_multiple: Option<Multiple>,
+    /// Royalty rates for the sensitivity table (default the base rate and
+    /// one and two points either side).
+    #[serde(default)]
+    pub royalty_rates: Vec<Rate>,
+}
+
+/// One year of a single unit's franchisee cash flow.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UnitYear {
+    pub year: u32,
+    pub revenue: Money,
+    pub four_wall_ebitda: Money,
+    pub royalty: Money,
+    pub marketing_fund: Money,
+    pub technology_fee: Money,
+    pub refresh_capex: Money,
+    pub terminal_value: Money,
+    pub cash_flow: Money,
+    pub cumulative_cash_flow: Money,
+}
+
+/// Franchisee returns on a single unit over one term.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FranchiseeReturns {
+    /// Build-out plus initial fee.
+    pub total_investment: Money,
+    pub years: Vec<UnitYear>,
+    pub irr: Option<Rate>,
+    pub npv: Money,
+    /// First year in which cumulative cash flow turns non-negative.
+    pub payback_year: Option<u32>,
+    /// First mature-year cash flow over total investment.
+    pub cash_on_cash: Rate,
+    /// Royalty, marketing fund and technology fee over sales in the first
+    /// mature year.
+    pub fee_load: Rate,
+    /// Royalty rate at which unit NPV is zero at the franchisee hurdle.
+    pub breakeven_royalty_rate: Option<Rate>,
+}
+
+/// One year of the franchisor's system projection.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SystemYear {
+    pub year: u32,
+    pub new_units: u32,
+    pub units_operating: Decimal,
+    pub system_sales: Money,
+    pub initial_fees: Money,
+    pub royalties: Money,
+    pub technology_fees: Money,
+    pub renewal_fees: Money,
+    /// Collected and spent on advertising; excluded from franchisor profit.
+    pub marketing_fund: Money,
+    pub opening_costs: Money,
+    pub support_costs: Money,
+    pub overhead: Money,
+    pub franchisor_cash_flow: Money,
+}
+
+/// Franchisor returns across the system.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FranchisorReturns {
+    pub years: Vec<SystemYear>,
+    pub terminal_value: Money,
+    pub irr: Option<Rate>,
+    pub npv: Money,
+    /// Units opened over the horizon after any territory cap.
+    pub units_opened: u32,
+    /// Royalties over total franchisor fee income.
+    pub royalty_share_of_fees: Rate,
+    /// NPV to the franchisor of one unit over one term: fees less opening
+    /// and ongoing support, before overhead.
+    pub unit_npv: Money,
+}
+
+/// Outcome at one royalty rate.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RoyaltySensitivity {
+    pub royalty_rate: Rate,
+    pub franchisee_irr: Option<Rate>,
+    pub franchisee_npv: Money,
+    pub franchisor_irr: Option<Rate>,
+    pub franchisor_npv: Money,
+}
+
+/// Output of franchise economics.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FranchiseOutput {
+    pub franchisee: FranchiseeReturns,
+    pub franchisor: FranchisorReturns,
+    /// Franchisor unit NPV over the combined unit NPV of both parties, when
+    /// the combined value is positive.
+    pub franchisor_value_share: Option<Rate>,
+    pub royalty_sensitivity: Vec<RoyaltySensitivity>,
+}
+
+// ---------------------------------------------------------------------------
+// Public API
+// ---------------------------------------------------------------------------
+
+/// Model franchisee and franchisor returns under a franchise agreement.
+pub fn analyze_franchise(
+    input: &FranchiseInput,
+) -> CorpFinanceResult<ComputationOutput<FranchiseOutput>> {
+    let start = Instant::now();
+    let mut warnings: Vec<String> = Vec::new();
+
+    validate_input(input)?;
+
+    let franchisee = franchisee_returns(input)?;
+    let (years, terminal_value, flows, units_opened) = system_projection(input);
+    let franchisor_npv = npv(input.franchisor_discount_rate, &flows)?;
+    let franchisor_irr = irr_if_defined(&flows);
+
+    let royalties: Money = years.iter().map(|y| y.royalties).sum();
+    let total_fees: Money = years
+        .iter()
+        .map(|y| y.initial_fees + y.royalties + y.technology_fees + y.renewal_fees)
+        .sum();
+    let royalty_share_of_fees = if total_fees > Decimal::ZERO {
+        royalties / total_fees
+    } else {
+        Decimal::ZERO
+    };
+
+    let unit_npv = npv(
+        input.franchisor_discount_rate,
+        &franchisor_unit_flows(input),
+    )?;
+    let combined = franchisee.npv + unit_npv;
+    let franchisor_value_share = if combined > Decimal::ZERO {
+        Some(unit_npv / combined)
+    } else {
+        None
+    };
+
+    let mut rates = if input.royalty_rates.is_empty() {
+        [
+            dec!(-0.02),
+            dec!(-0.01),
+            Decimal::ZERO,
+            dec!(0.01),
+            dec!(0.02),
+        ]
+        .iter()
+        .map(|d| input.fees.royalty_rate + d)
+        .filter(|r| *r >= Decimal::ZERO)
+        .collect()
+    } else {
+        input.royalty_rates.clone()
+    };
+    rates.sort();
+    rates.dedup();
+    let mut royalty_sensitivity = Vec::with_capacity(rates.len());
+    for rate in rates {
+        let mut bumped = input.clone();
+        bumped.fees.royalty_rate = rate;
+        let unit = franchisee_returns(&bumped)?;
+        let (_, _, system_flows, _) = system_projection(&bumped);
+        royalty_sensitivity.push(RoyaltySensitivity {
+            royalty_rate: rate,
+            franchisee_irr: unit.irr,
+            franchisee_npv: unit.npv,
+            franchisor_irr: irr_if_defined(&system_flows),
+            franchisor_npv: npv(input.franchisor_discount_rate, &system_flows)?,
+        });
+    }
+
+    if franchisee.npv < Decimal::ZERO {
+        warnings.push(format!(
+            "Unit NPV is negative at the {} franchisee hurdle",
+            input.franchisee_discount_rate
+        ));
+    }
+    if franchisee.payback_year.is_none() {
+        warnings.push("Unit does not pay back within the agreement term".into());
+    }
+    if franchisee.breakeven_royalty_rate.is_none() {
+        warnings.push("Unit does not earn its hurdle even with no royalty".into());
+    }
+    let scheduled: u32 = input
+        .development_schedule
+        .iter()
+        .take(input.projection_years as usize)
+        .sum();
+    if units_opened < scheduled {
+        warnings.push(format!(
+            "Territory capacity limits openings to {units_opened} of {scheduled} scheduled units"
+        ));
+    }
+    if franchisor_irr.is_none() {
+        warnings.push(
+            "Franchisor IRR undefined: cash flows do not change sign (set upfront_investment)"
+                .into(),
+        );
+    }
+
+    let output = FranchiseOutput {
+        franchisee,
+        franchisor: FranchisorReturns {
+            years,
+            terminal_value,
+            irr: franchisor_irr,
+            npv: franchisor_npv,
+            units_opened,
+            royalty_share_of_fees,
+            unit_npv,
+        },
+        franchisor_value_share,
+        royalty_sensitivity,
+    };
+
+    let elapsed = start.elapsed().as_micros() as u64;
+    let assumptions = serde_json::json!({
+        "cash_flows": "annual, pre-tax",
+        "franchisee_horizon_years": input.fees.term_years,
+        "franchisor_horizon_years": input.projection_years,
+        "marketing_fund": "pass-through, excluded from franchisor profit",
+        "existing_units": "mature from year 1, no initial or renewal fees",
+        "closures": "applied from the year after opening",
+        "franchisee_discount_rate": input.franchisee_discount_rate.to_string(),
+        "franchisor_discount_rate": input.franchisor_discount_rate.to_string(),
+    });
+
+    Ok(with_metadata(
+        "Franchise economics: unit-level franchisee returns and system-level franchisor returns",
+        &assumptions,
+        warnings,
+        elapsed,
+        output,
+    ))
+}
+
+// ---------------------------------------------------------------------------
+// Franchisee
+// ---------------------------------------------------------------------------
+
+/// Unit sales in year of operation `age` and calendar year `year`.
+fn unit_revenue(unit: &UnitEconomics, age: u32, year: u32) -> Money {
+    let ramp = unit
+        .ramp
+        .get(age as usize - 1)
+        .copied()
+        .unwrap_or(Decimal::ONE);
+    let mut growth = Decimal::ONE;
+    for _ in 1..year {
+        growth *= Decimal::ONE + unit.revenue_growth;
+    }
+    unit.mature_revenue * ramp * growth
+}
+
+fn franchisee_returns(input: &FranchiseInput) -> CorpFinanceResult<FranchiseeReturns> {
+    let unit = &input.unit;
+    let fees = &input.fees;
+    let term = fees.term_years;
+    let total_investment = unit.initial_investment + fees.initial_fee;
+
+    let mut flows = vec![-total_investment];
+    let mut years = Vec::with_capacity(term as usize);
+    let mut cumulative = -total_investment;
+    let mut payback_year = None;
+    // PV of one point of royalty, for the breakeven rate.
+    let mut royalty_base_pv = Decimal::ZERO;
+    let v = Decimal::ONE / (Decimal::ONE + input.franchisee_discount_rate);
+    let mut disc = Decimal::ONE;
+
+    for t in 1..=term {
+        disc *= v;
+        let revenue = unit_revenue(unit, t, t);
+        let four_wall_ebitda = revenue * unit.operating_margin;
+        let royalty = revenue * fees.royalty_rate;
+        let marketing_fund = revenue * fees.marketing_fund_rate;
+        let ebitda_after_fees = four_wall_ebitda - royalty - marketing_fund - fees.technology_fee;
+        let refresh_capex = match unit.refresh_cycle_years {
+            Some(c) if c > 0 && t.is_multiple_of(c) && t < term => unit.refresh_capex,
+            _ => Decimal::ZERO,
+        };
+        let exit_multiple = if t == term {
+            input.franchisee_exit_multiple.unwrap_or(Decimal::ZERO)
+        } else {
+            Decimal::ZERO
+        };
+        let terminal_value = exit_multiple * ebitda_after_fees;
+        let cash_flow = ebitda_after_fees - refresh_capex + terminal_value;
+
+        royalty_base_pv += revenue * (Decimal::ONE + exit_multiple) * disc;
+        cumulative += cash_flow;
+        if payback_year.is_none() && cumulative >= Decimal::ZERO {
+            payback_year = Some(t);
+        }
+        flows.push(cash_flow);
+        years.push(UnitYear {
+            year: t,
+            revenue,
+            four_wall_ebitda,
+            royalty,
+            marketing_fund,
+            technology_fee: fees.technology_fee,
+            refresh_capex,
+            terminal_value,
+            cash_flow,
+            cumulative_cash_flow: cumulative,
+        });
+    }
+
+    let unit_npv = npv(input.franchisee_discount_rate, &flows)?;
+    let breakeven = if royalty_base_pv > Decimal::ZERO {
+        Some(fees.royalty_rate + unit_npv / royalty_base_pv).filter(|r| *r >= Decimal::ZERO)
+    } else {
+        None
+    };
+
+    // First year at full volume, before any refresh or exit.
+    let mature = years
+        .get(unit.ramp.len().min(years.len() - 1))
+        .expect("term is at least one year");
+    let mature_cash =
+        mature.four_wall_ebitda - mature.royalty - mature.marketing_fund - mature.technology_fee;
+    let cash_on_cash = if total_investment > Decimal::ZERO {
+        mature_cash / total_investment
+    } else {
+        Decimal::ZERO
+    };
+    let fee_load = if mature.revenue > Decimal::ZERO {
+        (mature.royalty + mature.marketing_fund + mature.technology_fee) / mature.revenue
+    } else {
+        Decimal::ZERO
+    };
+
+    Ok(FranchiseeReturns {
+        total_investment,
+        irr: irr_if_defined(&flows),
+        npv: unit_npv,
+        years,
+        payback_year,
+        cash_on_cash,
+        fee_load,
+        breakeven_royalty_rate: breakeven,
+    })
+}
+
+// ---------------------------------------------------------------------------
+// Franchisor
+// ---------------------------------------------------------------------------
+
+/// Franchisor fee income less direct support from one unit over one term.
+fn franchisor_unit_flows(input: &FranchiseInput) -> Vec<Money> {
+    let fees = &input.fees;
+    let costs = &input.franchisor_costs;
+    let mut flows = vec![fees.initial_fee - costs.opening_support_cost];
+    for t in 1..=fees.term_years {
+        let revenue = unit_revenue(&input.unit, t, t);
+        flows.push(revenue * fees.royalty_rate + fees.technology_fee - costs.support_cost_per_unit);
+    }
+    flows
+}
+
+/// Roll the development schedule forward. Returns the yearly projection,
+/// terminal value, franchisor cash flows from year 0 and units opened.
+fn system_projection(input: &FranchiseInput) -> (Vec<SystemYear>, Money, Vec<Money>, u32) {
+    let fees = &input.fees;
+    let costs = &input.franchisor_costs;
+    let survival = Decimal::ONE - input.annual_closure_rate;
+    let mut remaining_capacity = input
+        .territory_capacity
+        .map(|c| c.saturating_sub(input.existing_units));
+
+    let mut cohorts: Vec<(u32, u32)> = Vec::new();
+    let mut flows = vec![-costs.upfront_investment];
+    let mut years = Vec::with_capacity(input.projection_years as usize);
+    let mut units_opened = 0u32;
+    let mut existing = Decimal::from(input.existing_units);
+
+    for year in 1..=input.projection_years {
+        let scheduled = input
+            .development_schedule
+            .get(year as usize - 1)
+            .copied()
+            .unwrap_or(0);
+        let new_units = match remaining_capacity.as_mut() {
+            Some(cap) => {
+                let n = scheduled.min(*cap);
+                *cap -= n;
+                n
+            }
+            None => scheduled,
+        };
+        if new_units > 0 {
+            cohorts.push((year, new_units));
+        }
+        units_opened += new_units;
+
+        if year > 1 {
+            existing *= survival;
+        }
+        let mut units_operating = existing;
+        let mut system_sales = existing * unit_revenue(&input.unit, u32::MAX, year);
+        let mut renewing = Decimal::ZERO;
+        for &(opened, count) in &cohorts {
+            let age = year - opened + 1;
+            let mut alive = Decimal::from(count);
+            for _ in 1..age {
+                alive *= survival;
+            }
+            units_operating += alive;
+            system_sales += alive * unit_revenue(&input.unit, age, year);
+            if age > 1 && (age - 1).is_multiple_of(fees.term_years) {
+                renewing += alive;
+            }
+        }
+
+        let new = Decimal::from(new_units);
+        let initial_fees = new * fees.initial_fee;
+        let royalties = system_sales * fees.royalty_rate;
+        let technology_fees = units_operating * fees.technology_fee;
+        let renewal_fees = renewing * fees.renewal_fee;
+        let marketing_fund = system_sales * fees.marketing_fund_rate;
+        let opening_costs = new * costs.opening_support_cost;
+        let support_costs = units_operating * costs.support_cost_per_unit;
+        let overhead = costs.annual_overhead;
+        let franchisor_cash_flow = initial_fees + royalties + technology_fees + renewal_fees
+            - opening_costs
+            - support_costs
+            - overhead;
+
+        flows.push(franchisor_cash_flow);
+        years.push(SystemYear {
+            year,
+            new_units,
+            units_operating,
+            system_sales,
+            initial_fees,
+            royalties,
+            technology_fees,
+            renewal_fees,
+            marketing_fund,
+            opening_costs,
+            support_costs,
+            overhead,
+            franchisor_cash_flow,
+        });
+    }
+
+    let terminal_value = match (input.franchisor_exit_multiple, years.last()) {
+        (Some(m), Some(last)) => m * last.franchisor_cash_flow,
+        _ => Decimal::ZERO,
+    };
+    if let Some(last) = flows.last_mut() {
+        *last += terminal_value;
+    }
+    (years, terminal_value, flows, units_opened)
+}
+
+/// IRR when the flows change sign and the solver converges.
+fn irr_if_defined(flows: &[Money]) -> Option<Rate> {
+    let has_negative = flows.iter().any(|f| *f < Decimal::ZERO);
+    let has_positive = flows.iter().any(|f| *f > Decimal::ZERO);
+    if !(has_negative && has_positive) {
+        return None;
+    }
+    irr(flows, dec!(0.10)).ok()
+}
+
+// ---------------------------------------------------------------------------
+// Validation
+// ---------------------------------------------------------------------------
+
+fn validate_input(input: &FranchiseInput) -> CorpFinanceResult<()> {
+    let unit = &input.unit;
+    let fees = &input.fees;
+    let costs = &input.franchisor_costs;
+    if unit.mature_revenue <= Decimal::ZERO {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "unit.mature_revenue".into(),
+            reason: "Mature unit sales must be positive".into(),
+        });
+    }
+    if unit.initial_investment < Decimal::ZERO || unit.refresh_capex < Decimal::ZERO {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "unit.initial_investment".into(),
+            reason: "Unit investment and refresh capex cannot be negative".into(),
+        });
+    }
+    if unit.operating_margin <= dec!(-1) || unit.operating_margin > Decimal::ONE {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "unit.operating_margin".into(),
+            reason: "Operating margin must be between -100% and 100%".into(),
+        });
+    }
+    if unit.revenue_growth <= dec!(-1) {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "unit.revenue_growth".into(),
+            reason: "Growth must be greater than -100%".into(),
+        });
+    }
+    if unit.ramp.iter().any(|r| *r < Decimal::ZERO) {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "unit.ramp".into(),
+            reason: "Ramp factors cannot be negative".into(),
+        });
+    }
+    if fees.term_years == 0 {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "fees.term_years".into(),
+            reason: "Agreement term must be at least one year".into(),
+        });
+    }
+    if input.projection_years == 0 {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "projection_years".into(),
+            reason: "Projection must cover at least one year".into(),
+        });
+    }
+    crate::limits::check_schedule_periods(
+        "projection_years",
+        u64::from(input.projection_years.max(fees.term_years)),
+    )?;
+    for (field, rate) in [
+        ("fees.royalty_rate", fees.royalty_rate),
+        ("fees.marketing_fund_rate", fees.marketing_fund_rate),
+        ("annual_closure_rate", input.annual_closure_rate),
+    ] {
+        if rate < Decimal::ZERO || rate >= Decimal::ONE {
+            return Err(CorpFinanceError::InvalidInput {
+                field: field.into(),
+                reason: "Rate must be between 0 and 1".into(),
+            });
+        }
+    }
+    if input
+        .royalty_rates
+        .iter()
+        .any(|r| *r < Decimal::ZERO || *r >= Decimal::ONE)
+    {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "royalty_rates".into(),
+            reason: "Royalty rates must be between 0 and 1".into(),
+        });
+    }
+    if [
+        fees.initial_fee,
+        fees.technology_fee,
+        fees.renewal_fee,
+        costs.upfront_investment,
+        costs.opening_support_cost,
+        costs.support_cost_per_unit,
+        costs.annual_overhead,
+    ]
+    .iter()
+    .any(|m| *m < Decimal::ZERO)
+    {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "fees".into(),
+            reason: "Fees and franchisor costs cannot be negative".into(),
+        });
+    }
+    if input.franchisee_discount_rate <= dec!(-1) || input.franchisor_discount_rate <= dec!(-1) {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "franchisee_discount_rate".into(),
+            reason: "Discount rates must be greater than -100%".into(),
+        });
+    }
+    if input
+        .franchisee_exit_multiple
+        .is_some_and(|m| m < Decimal::ZERO)
+        || input
+            .franchisor_exit_multiple
+            .is_some_and(|m| m < Decimal::ZERO)
+    {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "franchisee_exit_multiple".into(),
+            reason: "Exit multiples cannot be negative".into(),
+        });
+    }
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal::MathematicalOps;
+
+    fn base_input() -> FranchiseInput {
+        FranchiseInput {
+            system_name: "Test QSR".into(),
+            unit: UnitEconomics {
+                initial_investment: dec!(800000),
+                mature_revenue: dec!(1500000),
+                ramp: vec![dec!(0.8), dec!(0.9)],
+                revenue_growth: dec!(0.02),
+                operating_margin: dec!(0.22),
+                refresh_capex: dec!(150000),
+                refresh_cycle_years: Some(5),
+            },
+            fees: FeeStructure {
+                initial_fee: dec!(40000),
+                royalty_rate: dec!(0.06),
+                marketing_fund_rate: dec!(0.02),
+                technology_fee: dec!(5000),
+                term_years: 10,
+                renewal_fee: dec!(10000),
+            },
+            franchisor_costs: FranchisorCosts {
+                upfront_investment: dec!(2000000),
+                opening_support_cost: dec!(25000),
+                support_cost_per_unit: dec!(15000),
+                annual_overhead: dec!(1000000),
+            },
+            development_schedule: vec![5, 10, 15, 20, 20],
+            existing_units: 0,
+            territory_capacity: None,
+            annual_closure_rate: Decimal::ZERO,
+            projection_years: 10,
+            franchisee_discount_rate: dec!(0.15),
+            franchisor_discount_rate: dec!(0.12),
+            franchisee_exit_multiple: None,
+            franchisor_exit_multiple: None,
+            royalty_rates: vec![],
+        }
+    }
+
+    #[test]
+    fn test_unit_cash_flows() {
+        let out = analyze_franchise(&base_input()).unwrap().result;
+        let f = &out.franchisee;
+        assert_eq!(f.total_investment, dec!(840000));
+        assert_eq!(f.years.len(), 10);
+        // Year 1: 1.2m sales, 264k four-wall, 72k royalty, 24k marketing, 5k tech
+        let y1 = &f.years[0];
+        assert_eq!(y1.revenue, dec!(1200000));
+        assert_eq!(y1.cash_flow, dec!(163000));
+        // Refresh in year 5 only (year 10 is the end of the term)
+        assert_eq!(f.years[4].refresh_capex, dec!(150000));
+        assert_eq!(f.years[9].refresh_capex, Decimal::ZERO);
+        let total: Money = f.years.iter().map(|y| y.cash_flow).sum();
+        assert_eq!(f.years[9].cumulative_cash_flow, total - dec!(840000));
+        assert!(f.irr.is_some());
+        // Mature year 3: 1.5m * 1.02^2 sales, fee load 8% + 5k/sales
+        let sales3 = dec!(1500000) * dec!(1.02) * dec!(1.02);
+        assert_eq!(f.fee_load, dec!(0.08) + dec!(5000) / sales3);
+    }
+
+    #[test]
+    fn test_breakeven_royalty_zeroes_npv() {
+        let input = base_input();
+        let out = analyze_franchise(&input).unwrap().result;
+        let breakeven = out.franchisee.breakeven_royalty_rate.unwrap();
+        assert!(breakeven > input.fees.royalty_rate);
+
+        let mut at_breakeven = input.clone();
+        at_breakeven.fees.royalty_rate = breakeven;
+        let check = analyze_franchise(&at_breakeven).unwrap().result;
+        assert!(check.franchisee.npv.abs() < dec!(0.01));
+        let irr = check.franchisee.irr.unwrap();
+        assert!((irr - dec!(0.15)).abs() < dec!(0.0001));
+    }
+
+    #[test]
+    fn test_system_rollout_and_fees() {
+        let input = base_input();
+        let out = analyze_franchise(&input).unwrap().result;
+        let sys = &out.franchisor;
+        assert_eq!(sys.units_opened, 70);
+        assert_eq!(sys.years[4].units_operating, dec!(70));
+        assert_eq!(sys.years[0].initial_fees, dec!(200000));
+        // Year 2: 5 units in ramp year 2, 10 new units in ramp year 1
+        let y2 = &sys.years[1];
+        let expected_sales =
+            dec!(1500000) * dec!(1.02) * (dec!(5) * dec!(0.9) + dec!(10) * dec!(0.8));
+        assert_eq!(y2.system_sales, expected_sales);
+        assert_eq!(y2.royalties, expected_sales * dec!(0.06));
+        assert_eq!(y2.marketing_fund, expected_sales * dec!(0.02));
+        // Marketing fund is not franchisor profit
+        assert_eq!(
+            y2.franchisor_cash_flow,
+            y2.initial_fees + y2.royalties + y2.technology_fees
+                - y2.opening_costs
+                - y2.support_costs
+                - y2.overhead
+        );
+        assert!(sys.irr.is_some());
+        assert!(sys.royalty_share_of_fees > dec!(0.5));
+    }
+
+    #[test]
+    fn test_renewals_and_closures() {
+        let mut input = base_input();
+        input.fees.term_years = 3;
+        input.development_schedule = vec![10];
+        input.projection_years = 7;
+        input.annual_closure_rate = dec!(0.10);
+        let out = analyze_franchise(&input).unwrap().result;
+        let sys = &out.franchisor.years;
+        assert_eq!(sys[0].units_operating, dec!(10));
+        assert_eq!(sys[1].units_operating, dec!(9));
+        // Renewal at the start of years 4 and 7
+        assert_eq!(sys[3].renewal_fees, dec!(7.29) * dec!(10000));
+        assert_eq!(
+            sys[6].renewal_fees,
+            dec!(10) * dec!(0.9).powi(6) * dec!(10000)
+        );
+        assert_eq!(sys[4].renewal_fees, Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_territory_capacity_caps_openings() {
+        let mut input = base_input();
+        input.existing_units = 10;
+        input.territory_capacity = Some(40);
+        let out = analyze_franchise(&input).unwrap();
+        let sys = &out.result.franchisor;
+        assert_eq!(sys.units_opened, 30);
+        assert_eq!(sys.years[2].new_units, 15);
+        assert_eq!(sys.years[3].new_units, 0);
+        assert_eq!(sys.years[9].units_operating, dec!(40));
+        assert!(out.warnings.iter().any(|w| w.contains("30 of 70")));
+    }
+
+    #[test]
+    fn test_royalty_sensitivity_moves_value_between_parties() {
+        let out = analyze_franchise(&base_input()).unwrap().result;
+        let rows = &out.royalty_sensitivity;
+        assert_eq!(rows.len(), 5);
+        assert_eq!(rows[0].royalty_rate, dec!(0.04));
+        assert_eq!(rows[2].royalty_rate, dec!(0.06));
+        assert_eq!(rows[2].franchisee_npv, out.franchisee.npv);
+        assert_eq!(rows[2].franchisor_npv, out.franchisor.npv);
+        for pair in rows.windows(2) {
+            assert!(pair[1].franchisee_npv < pair[0].franchisee_npv);
+            assert!(pair[1].franchisor_npv > pair[0].franchisor_npv);
+        }
+        let share = out.franchisor_value_share.unwrap();
+        assert!(share > Decimal::ZERO && share < Decimal::ONE);
+    }
+
+    #[test]
+    fn test_no_upfront_investment_leaves_franchisor_irr_undefined() {
+        let mut input = base_input();
+        input.franchisor_costs = FranchisorCosts::default();
+        let out = analyze_franchise(&input).unwrap();
+        assert!(out.result.franchisor.irr.is_none());
+        assert!(out.warnings.iter().any(|w| w.contains("IRR undefined")));
+    }
+
+    #[test]
+    fn test_franchisee_exit_multiple_adds_terminal_value() {
+        let mut input = base_input();
+        input.franchisee_exit_multiple = Some(dec!(4));
+        let with_exit = analyze_franchise(&input).unwrap().result;
+        let base = analyze_franchise(&base_input()).unwrap().result;
+        let last = &with_exit.franchisee.years[9];
+        let ebitda_after_fees =
+            last.four_wall_ebitda - last.royalty - last.marketing_fund - last.technology_fee;
+        assert_eq!(last.terminal_value, dec!(4) * ebitda_after_fees);
+        assert!(with_exit.franchisee.npv > base.franchisee.npv);
+        // Breakeven still zeroes NPV with the exit included
+        let mut check = input.clone();
+        check.fees.royalty_rate = with_exit.franchisee.breakeven_royalty_rate.unwrap();
+        let out = analyze_franchise(&check).unwrap().result;
+        assert!(out.franchisee.npv.abs() < dec!(0.01));
+    }
+
+    #[test]
+    fn test_validation() {
+        let mut input = base_input();
+        input.fees.term_years = 0;
+        assert!(analyze_franchise(&input).is_err());
+
+        let mut input = base_input();
+        input.fees.royalty_rate = dec!(1.2);
+        assert!(analyze_franchise(&input).is_err());
+
+        let mut input = base_input();
+        input.unit.mature_revenue = Decimal::ZERO;
+        assert!(analyze_franchise(&input).is_err());
+    }
+}
@@ -0,0 +1,836 @@
+//! Private Markets Cash Flow Forecasting (Takahashi-Alexander, 2002).
+//!
+//! Projects capital calls, distributions, and NAV for existing commitments
+//! from each fund's strategy and age, so pacing plans no longer need hand-built
+//! cash flow profiles:
+//!
+//! - **Contributions**: C_t = RC_age x (commitment - paid-in), with rates of
+//!   contribution by fund age
+//! - **Distributions**: D_t = RD_t x NAV_{t-1} x (1 + G), where
+//!   RD_t = max(Y, (age / L)^B) for yield Y, bow B, and fund life L
+//! - **NAV roll-forward**: NAV_t = NAV_{t-1} x (1 + G) + C_t - D_t
+//! - **Stochastic extension**: annual growth G + sigma x e_t with a market
+//!   shock e_t shared by all funds, giving percentile bands for net cash flow,
+//!   NAV, and cumulative liquidity need
+//!
+//! Built-in parameters are provided for common strategies and can be
+//! overridden per strategy. All arithmetic uses `rust_decimal::Decimal`.
+//! No `f64`.
+
+use rust_decimal::Decimal;
+use rust_decimal::MathematicalOps;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+
+use crate::error::CorpFinanceError;
+use crate::sampling::{percentile, Lcg};
+use crate::CorpFinanceResult;
+
+// ---------------------------------------------------------------------------
+// Input / Output
+// ---------------------------------------------------------------------------
+
+/// Takahashi-Alexander model parameters for one strategy.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TakahashiAlexanderParams {
+    /// Rates of contribution on remaining unfunded commitment by fund age
+    /// (year 1, 2, ...); the last rate applies to all later years.
+    pub contribution_rates: Vec<Decimal>,
+    /// Annual NAV growth rate G.
+    pub growth_rate: Decimal,
+    /// Bow factor B: higher values push distributions later in the life.
+    pub bow: Decimal,
+    /// Minimum annual distribution yield Y on NAV.
+    pub yield_rate: Decimal,
+    /// Fund life L in years; all NAV is distributed at this age.
+    pub fund_life: u32,
+}
+
+/// Override of the model parameters for a strategy.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StrategyAssumption {
+    /// Strategy name, matched case-insensitively against commitments.
+    pub strategy: String,
+    /// Model parameters for the strategy.
+    pub params: TakahashiAlexanderParams,
+}
+
+/// An existing commitment to forecast.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ForecastCommitment {
+    /// Fund name.
+    pub name: String,
+    /// Strategy (e.g. "Buyout", "Venture", "Real Estate").
+    pub strategy: String,
+    /// Vintage year (first capital call).
+    pub vintage: u32,
+    /// Total commitment.
+    pub commitment: Decimal,
+    /// Capital called to date.
+    pub paid_in: Decimal,
+    /// Current net asset value.
+    pub nav: Decimal,
+}
+
+/// Settings for the stochastic extension.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StochasticForecast {
+    /// Number of simulated paths (default 1,000).
+    pub num_paths: Option<u32>,
+    /// Annual volatility of NAV growth around each strategy's growth rate.
+    pub growth_volatility: Decimal,
+    /// Confidence level for the reported bands (default 0.95).
+    pub confidence: Option<Decimal>,
+    /// RNG seed (default 42).
+    pub seed: Option<u64>,
+}
+
+/// Input for the cash flow forecast.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CashFlowForecastInput {
+    /// Calendar year of the current paid-in and NAV figures.
+    pub as_of_year: u32,
+    /// Number of years to project.
+    pub horizon_years: u32,
+    /// Existing commitments.
+    pub commitments: Vec<ForecastCommitment>,
+    /// Strategy parameter overrides; built-in defaults are used otherwise.
+    #[serde(default)]
+    pub strategy_assumptions: Vec<StrategyAssumption>,
+    /// Optional stochastic extension.
+    pub stochastic: Option<StochasticForecast>,
+}
+
+/// Projected cash flows for one calendar year.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ForecastYear {
+    /// Calendar year.
+    pub year: u32,
+    /// Capital calls.
+    pub contributions: Decimal,
+    /// Distributions.
+    pub distributions: Decimal,
+    /// Distributions - contributions.
+    pub net_cash_flow: Decimal,
+    /// Year-end NAV.
+    pub nav: Decimal,
+    /// Year-end unfunded commitment.
+    pub unfunded: Decimal,
+}
+
+/// Projection for a single commitment.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FundForecast {
+    /// Fund name.
+    pub name: String,
+    /// Strategy.
+    pub strategy: String,
+    /// Fund age at `as_of_year`.
+    pub current_age: u32,
+    /// Year-by-year projection.
+    pub years: Vec<ForecastYear>,
+}
+
+/// Aggregate projection for one strategy.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StrategyForecast {
+    /// Strategy name as given on the first matching commitment.
+    pub strategy: String,
+    /// Parameters used.
+    pub params: TakahashiAlexanderParams,
+    /// Year-by-year projection summed over the strategy's commitments.
+    pub years: Vec<ForecastYear>,
+}
+
+/// Percentile bands for one year of the stochastic forecast.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StochasticYear {
+    /// Calendar year.
+    pub year: u32,
+    /// Lower / median / upper net cash flow.
+    pub net_cash_flow_low: Decimal,
+    pub net_cash_flow_median: Decimal,
+    pub net_cash_flow_high: Decimal,
+    /// Lower / median / upper year-end NAV.
+    pub nav_low: Decimal,
+    pub nav_median: Decimal,
+    pub nav_high: Decimal,
+}
+
+/// Output of the stochastic extension.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StochasticForecastOutput {
+    /// Number of paths simulated.
+    pub num_paths: u32,
+    /// Confidence level of the bands.
+    pub confidence: Decimal,
+    /// Year-by-year bands for the whole portfolio.
+    pub years: Vec<StochasticYear>,
+    /// Cumulative net outflow not exceeded at the confidence level
+    /// (positive = cash needed).
+    pub max_cumulative_outflow_at_confidence: Decimal,
+    /// Average cumulative net outflow trough across paths.
+    pub expected_max_cumulative_outflow: Decimal,
+}
+
+/// Output of the cash flow forecast.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CashFlowForecastOutput {
+    /// Portfolio-level projection.
+    pub portfolio: Vec<ForecastYear>,
+    /// Projection by strategy.
+    pub by_strategy: Vec<StrategyForecast>,
+    /// Projection by commitment.
+    pub funds: Vec<FundForecast>,
+    /// Total projected capital calls.
+    pub total_contributions: Decimal,
+    /// Total projected distributions.
+    pub total_distributions: Decimal,
+    /// Deepest cumulative net cash flow over the horizon (positive = cash
+    /// needed).
+    pub max_cumulative_outflow: Decimal,
+    /// First year cumulative net cash flow turns non-negative after an
+    /// outflow, if within the horizon.
+    pub breakeven_year: Option<u32>,
+    /// Percentile bands from the stochastic extension.
+    pub stochastic: Option<StochasticForecastOutput>,
+}
+
+// ---------------------------------------------------------------------------
+// Default parameters
+// ---------------------------------------------------------------------------
+
+/// Built-in parameters for common strategies (indicative annual values in
+/// the spirit of the original Yale calibration).
+pub fn default_params(strategy: &str) -> Option<TakahashiAlexanderParams> {
+    let params = |rc: &[Decimal], g, b, y, l| TakahashiAlexanderParams {
+        contribution_rates: rc.to_vec(),
+        growth_rate: g,
+        bow: b,
+        yield_rate: y,
+        fund_life: l,
+    };
+    let key = strategy.to_ascii_lowercase().replace(['_', '-'], " ");
+    match key.trim() {
+        "buyout" | "private equity" => Some(params(
+            &[dec!(0.25), dec!(0.333), dec!(0.5)],
+            dec!(0.13),
+            dec!(2.5),
+            dec!(0),
+            12,
+        )),
+        "venture" | "venture capital" | "growth" => Some(params(
+            &[dec!(0.25), dec!(0.333), dec!(0.5)],
+            dec!(0.15),
+            dec!(3.0),
+            dec!(0),
+            12,
+        )),
+        "real estate" => Some(params(
+            &[dec!(0.4), dec!(0.5), dec!(0.6)],
+            dec!(0.08),
+            dec!(1.5),
+            dec!(0.04),
+            10,
+        )),
+        "infrastructure" => Some(params(
+            &[dec!(0.3), dec!(0.4), dec!(0.5)],
+            dec!(0.09),
+            dec!(2.0),
+            dec!(0.05),
+            15,
+        )),
+        "private credit" | "private debt" => Some(params(
+            &[dec!(0.35), dec!(0.5), dec!(0.6)],
+            dec!(0.07),
+            dec!(1.2),
+            dec!(0.08),
+            8,
+        )),
+        _ => None,
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Core computation
+// ---------------------------------------------------------------------------
+
+/// Forecast capital calls, distributions, and NAV for existing commitments.
+pub fn forecast_cash_flows(
+    input: &CashFlowForecastInput,
+) -> CorpFinanceResult<CashFlowForecastOutput> {
+    validate_forecast_input(input)?;
+
+    let horizon = input.horizon_years as usize;
+    let params = input
+        .commitments
+        .iter()
+        .map(|c| resolve_params(&input.strategy_assumptions, &c.strategy))
+        .collect::<CorpFinanceResult<Vec<_>>>()?;
+
+    // 1. Deterministic projection per commitment
+    let growth: Vec<Vec<Decimal>> = params
+        .iter()
+        .map(|p| vec![p.growth_rate; horizon])
+        .collect();
+    let funds: Vec<FundForecast> = input
+        .commitments
+        .iter()
+        .zip(&params)
+        .zip(&growth)
+        .map(|((c, p), g)| FundForecast {
+            name: c.name.clone(),
+            strategy: c.strategy.clone(),
+            current_age: current_age(input.as_of_year, c.vintage),
+            years: project_commitment(c, p, input.as_of_year, g),
+        })
+        .collect();
+
+    // 2. Aggregate by strategy and portfolio
+    let mut by_strategy: Vec<StrategyForecast> = Vec::new();
+    for (fund, p) in funds.iter().zip(&params) {
+        match by_strategy
+            .iter_mut()
+            .find(|s| s.strategy.eq_ignore_ascii_case(&fund.strategy))
+        {
+            Some(s) => add_years(&mut s.years, &fund.years),
+            None => by_strategy.push(StrategyForecast {
+                strategy: fund.strategy.clone(),
+                params: p.clone(),
+                years: fund.years.clone(),
+            }),
+        }
+    }
+    let mut portfolio = empty_years(input.as_of_year, horizon);
+    for fund in &funds {
+        add_years(&mut portfolio, &fund.years);
+    }
+
+    let total_contributions = portfolio.iter().map(|y| y.contributions).sum();
+    let total_distributions = portfolio.iter().map(|y| y.distributions).sum();
+    let (max_cumulative_outflow, breakeven_year) = liquidity_profile(&portfolio);
+
+    // 3. Stochastic extension
+    let stochastic = input
+        .stochastic
+        .as_ref()
+        .map(|s| simulate(input, &params, s));
+
+    Ok(CashFlowForecastOutput {
+        portfolio,
+        by_strategy,
+        funds,
+        total_contributions,
+        total_distributions,
+        max_cumulative_outflow,
+        breakeven_year,
+        stochastic,
+    })
+}
+
+/// Project one commitment forward from `as_of_year` using the given annual
+/// growth rates (one per projection year).
+pub fn project_commitment(
+    commitment: &ForecastCommitment,
+    params: &TakahashiAlexanderParams,
+    as_of_year: u32,
+    growth: &[Decimal],
+) -> Vec<ForecastYear> {
+    let life = Decimal::from(params.fund_life);
+    let mut age = current_age(as_of_year, commitment.vintage);
+    let mut unfunded = (commitment.commitment - commitment.paid_in).max(Decimal::ZERO);
+    let mut nav = commitment.nav;
+
+    growth
+        .iter()
+        .enumerate()
+        .map(|(k, g)| {
+            age += 1;
+            let (contribution, distribution) = if age > params.fund_life {
+                (Decimal::ZERO, Decimal::ZERO)
+            } else {
+                let rc = contribution_rate(params, age);
+                let contribution = (unfunded * rc).min(unfunded);
+                let grown = nav * (Decimal::ONE + *g).max(Decimal::ZERO);
+                let rd = if age == params.fund_life {
+                    Decimal::ONE
+                } else {
+                    params
+                        .yield_rate
+                        .max((Decimal::from(age) / life).powd(params.bow))
+                        .min(Decimal::ONE)
+                };
+                let distribution = rd * grown;
+                nav = grown + contribution - distribution;
+                unfunded -= contribution;
+                (contribution, distribution)
+            };
+            ForecastYear {
+                year: as_of_year + k as u32 + 1,
+                contributions: contribution,
+                distributions: distribution,
+                net_cash_flow: distribution - contribution,
+                nav,
+                unfunded,
+            }
+        })
+        .collect()
+}
+
+/// Years completed since the vintage year at `as_of_year`.
+fn current_age(as_of_year: u32, vintage: u32) -> u32 {
+    as_of_year.saturating_sub(vintage)
+}
+
+/// Rate of contribution for a fund in its `age`-th year (1-based).
+fn contribution_rate(params: &TakahashiAlexanderParams, age: u32) -> Decimal {
+    let idx = (age as usize).saturating_sub(1);
+    params
+        .contribution_rates
+        .get(idx)
+        .or(params.contribution_rates.last())
+        .copied()
+        .unwrap_or(Decimal::ZERO)
+}
+
+/// Parameters for `strategy`: an override if given, else the built-in default.
+pub(crate) fn resolve_params(
+    assumptions: &[StrategyAssumption],
+    strategy: &str,
+) -> CorpFinanceResult<TakahashiAlexanderParams> {
+    assumptions
+        .iter()
+        .find(|s| s.strategy.eq_ignore_ascii_case(strategy))
+        .map(|s| s.params.clone())
+        .or_else(|| default_params(strategy))
+        .ok_or_else(|| CorpFinanceError::InvalidInput {
+            field: "strategy_assumptions".into(),
+            reason: format!("No parameters for strategy '{strategy}' and no built-in default"),
+        })
+}
+
+pub(crate) fn empty_years(as_of_year: u32, horizon: usize) -> Vec<ForecastYear> {
+    (0..horizon)
+        .map(|k| ForecastYear {
+            year: as_of_year + k as u32 + 1,
+            contributions: Decimal::ZERO,
+            distributions: Decimal::ZERO,
+            net_cash_flow: Decimal::ZERO,
+            nav: Decimal::ZERO,
+            unfunded: Decimal::ZERO,
+        })
+        .collect()
+}
+
+pub(crate) fn add_years(total: &mut [ForecastYear], years: &[ForecastYear]) {
+    for (t, y) in total.iter_mut().zip(years) {
+        t.contributions += y.contributions;
+        t.distributions += y.distributions;
+        t.net_cash_flow += y.net_cash_flow;
+        t.nav += y.nav;
+        t.unfunded += y.unfunded;
+    }
+}
+
+/// Deepest cumulative net outflow and the breakeven year that follows it.
+pub(crate) fn liquidity_profile(years: &[ForecastYear]) -> (Decimal, Option<u32>) {
+    let mut cumulative = Decimal::ZERO;
+    let mut trough = Decimal::ZERO;
+    let mut breakeven = None;
+    for y in years {
+        cumulative += y.net_cash_flow;
+        if cumulative < trough {
+            trough = cumulative;
+            breakeven = None;
+        } else if trough < Decimal::ZERO && cumulative >= Decimal::ZERO && breakeven.is_none() {
+            breakeven = Some(y.year);
+        }
+    }
+    (-trough, breakeven)
+}
+
+// ---------------------------------------------------------------------------
+// Stochastic extension
+// ---------------------------------------------------------------------------
+
+fn simulate(
+    input: &CashFlowForecastInput,
+    params: &[TakahashiAlexanderParams],
+    settings: &StochasticForecast,
+) -> StochasticForecastOutput {
+    let num_paths = settings.num_paths.unwrap_or(1_000);
+    let confidence = settings.confidence.unwrap_or(dec!(0.95));
+    let horizon = input.horizon_years as usize;
+    let mut rng = Lcg::new(settings.seed.unwrap_or(42));
+
+    let mut net_by_year: Vec<Vec<Decimal>> = vec![Vec::with_capacity(num_paths as usize); horizon];
+    let mut nav_by_year: Vec<Vec<Decimal>> = vec![Vec::with_capacity(num_paths as usize); horizon];
+    let mut troughs: Vec<Decimal> = Vec::with_capacity(num_paths as usize);
+
+    for _ in 0..num_paths {
+        // One market shock per year, shared by every commitment
+        let shocks: Vec<Decimal> = (0..horizon)
+            .map(|_| settings.growth_volatility * rng.next_normal())
+            .collect();
+        let mut path = empty_years(input.as_of_year, horizon);
+        for (c, p) in input.commitments.iter().zip(params) {
+            let growth: Vec<Decimal> = shocks.iter().map(|e| p.growth_rate + *e).collect();
+            add_years(
+                &mut path,
+                &project_commitment(c, p, input.as_of_year, &growth),
+            );
+        }
+        for (t, y) in path.iter().enumerate() {
+            net_by_year[t].push(y.net_cash_flow);
+            nav_by_year[t].push(y.nav);
+        }
+        troughs.push(liquidity_profile(&path).0);
+    }
+
+    let lower_q = (Decimal::ONE - confidence) / dec!(2);
+    let upper_q = Decimal::ONE - lower_q;
+    let years = net_by_year
+        .iter_mut()
+        .zip(nav_by_year.iter_mut())
+        .enumerate()
+        .map(|(t, (net, nav))| {
+            net.sort();
+            nav.sort();
+            StochasticYear {
+                year: input.as_of_year + t as u32 + 1,
+                net_cash_flow_low: percentile(net, lower_q),
+                net_cash_flow_median: percentile(net, dec!(0.5)),
+                net_cash_flow_high: percentile(net, upper_q),
+                nav_low: percentile(nav, lower_q),
+                nav_median: percentile(nav, dec!(0.5)),
+                nav_high: percentile(nav, upper_q),
+            }
+        })
+        .collect();
+
+    let expected_max_cumulative_outflow =
+        troughs.iter().copied().sum::<Decimal>() / Decimal::from(num_paths);
+    troughs.sort();
+
+    StochasticForecastOutput {
+        num_paths,
+        confidence,
+        years,
+        max_cumulative_outflow_at_confidence: percentile(&troughs, confidence),
+        expected_max_cumulative_outflow,
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Validation
+// ---------------------------------------------------------------------------
+
+fn validate_forecast_input(input: &CashFlowForecastInput) -> CorpFinanceResult<()> {
+    if input.commitments.is_empty() {
+        return Err(CorpFinanceError::InsufficientData(
+            "At least one commitment is required".into(),
+        ));
+    }
+    if input.horizon_years == 0 || input.horizon_years > 50 {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "horizon_years".into(),
+            reason: "Must be between 1 and 50".into(),
+        });
+    }
+    for c in &input.commitments {
+        if c.commitment <= Decimal::ZERO {
+            return Err(CorpFinanceError::InvalidInput {
+                field: format!("commitments[{}].commitment", c.name),
+                reason: "Commitment must be positive".into(),
+            });
+        }
+        if c.paid_in < Decimal::ZERO || c.nav < Decimal::ZERO {
+            return Err(CorpFinanceError::InvalidInput {
+                field: format!("commitments[{}]", c.name),
+                reason: "Paid-in and NAV cannot be negative".into(),
+            });
+        }
+        if c.vintage > input.as_of_year {
+            return Err(CorpFinanceError::InvalidInput {
+                field: format!("commitments[{}].vintage", c.name),
+                reason: "Vintage cannot be after as_of_year".into(),
+            });
+        }
+    }
+    validate_strategy_assumptions(&input.strategy_assumptions)?;
+    if let Some(s) = &input.stochastic {
+        if s.growth_volatility < Decimal::ZERO {
+            return Err(CorpFinanceError::InvalidInput {
+                field: "stochastic.growth_volatility".into(),
+                reason: "Volatility cannot be negative".into(),
+            });
+        }
+        if s.num_paths == Some(0) {
+            return Err(CorpFinanceError::InvalidInput {
+                field: "stochastic.num_paths".into(),
+                reason: "At least one path is required".into(),
+            });
+        }
+        crate::limits::check_simulation_paths(
+            "stochastic.num_paths",
+            s.num_paths.unwrap_or(1_000).into(),
+        )?;
+        if let Some(c) = s.confidence {
+            if c <= Decimal::ZERO || c >= Decimal::ONE {
+                return Err(CorpFinanceError::InvalidInput {
+                    field: "stochastic.confidence".into(),
+                    reason: "Confidence must be between 0 and 1".into(),
+                });
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Check strategy parameter overrides are usable by the model.
+pub(crate) fn validate_strategy_assumptions(
+    assumptions: &[StrategyAssumption],
+) -> CorpFinanceResult<()> {
+    for s in assumptions {
+        let p = &s.params;
+        if p.fund_life == 0 {
+            return Err(CorpFinanceError::InvalidInput {
+                field: format!("strategy_assumptions[{}].fund_life", s.strategy),
+                reason: "Fund life must be at least one year".into(),
+            });
+        }
+        if p.contribution_rates.is_empty()
+            || p.contribution_rates
+                .iter()
+                .any(|r| *r < Decimal::ZERO || *r > Decimal::ONE)
+        {
+            return Err(CorpFinanceError::InvalidInput {
+                field: format!("strategy_assumptions[{}].contribution_rates", s.strategy),
+                reason: "Rates must be provided and lie in [0, 1]".into(),
+            });
+        }
+        if p.bow <= Decimal::ZERO || p.yield_rate < Decimal::ZERO || p.yield_rate > Decimal::ONE {
+            return Err(CorpFinanceError::InvalidInput {
+                field: format!("strategy_assumptions[{}]", s.strategy),
+                reason: "Bow must be positive and yield in [0, 1]".into(),
+            });
+        }
+        if p.growth_rate <= dec!(-1) {
+            return Err(CorpFinanceError::InvalidInput {
+                field: format!("strategy_assumptions[{}].growth_rate", s.strategy),
+                reason: "Growth rate must be above -100%".into(),
+            });
+        }
+    }
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx_eq(a: Decimal, b: Decimal, eps: Decimal) -> bool {
+        (a - b).abs() < eps
+    }
+
+    fn new_buyout() -> ForecastCommitment {
+        ForecastCommitment {
+            name: "Buyout Fund VII".into(),
+            strategy: "Buyout".into(),
+            vintage: 2024,
+            commitment: dec!(100),
+            paid_in: dec!(0),
+            nav: dec!(0),
+        }
+    }
+
+    fn base_input() -> CashFlowForecastInput {
+        CashFlowForecastInput {
+            as_of_year: 2024,
+            horizon_years: 15,
+            commitments: vec![new_buyout()],
+            strategy_assumptions: vec![],
+            stochastic: None,
+        }
+    }
+
+    #[test]
+    fn test_first_year_matches_hand_calculation() {
+        let out = forecast_cash_flows(&base_input()).unwrap();
+        let y1 = &out.portfolio[0];
+        assert_eq!(y1.year, 2025);
+        // C1 = 0.25 x 100; no NAV yet so no distributions
+        assert_eq!(y1.contributions, dec!(25));
+        assert_eq!(y1.distributions, dec!(0));
+        assert_eq!(y1.nav, dec!(25));
+        assert_eq!(y1.unfunded, dec!(75));
+
+        // Year 2: C2 = 0.333 x 75; D2 = (2/12)^2.5 x 25 x 1.13
+        let y2 = &out.portfolio[1];
+        assert_eq!(y2.contributions, dec!(24.975));
+        let rd = (dec!(2) / dec!(12)).powd(dec!(2.5));
+        assert!(approx_eq(y2.distributions, rd * dec!(28.25), dec!(0.0001)));
+    }
+
+    #[test]
+    fn test_fund_fully_distributed_at_end_of_life() {
+        let out = forecast_cash_flows(&base_input()).unwrap();
+        let fund = &out.funds[0];
+        // Age 12 is 2036: NAV after the final distribution is only the
+        // final year's capital call, and nothing happens after that
+        let last_life_year = fund.years.iter().find(|y| y.year == 2036).unwrap();
+        let after: Vec<_> = fund.years.iter().filter(|y| y.year > 2036).collect();
+        assert!(last_life_year.nav <= last_life_year.contributions);
+        assert!(after
+            .iter()
+            .all(|y| y.contributions.is_zero() && y.distributions.is_zero()));
+    }
+
+    #[test]
+    fn test_j_curve_shape_and_breakeven() {
+        let out = forecast_cash_flows(&base_input()).unwrap();
+        assert!(out.portfolio[0].net_cash_flow < Decimal::ZERO);
+        assert!(out.max_cumulative_outflow > dec!(50));
+        let breakeven = out.breakeven_year.expect("breakeven within 15 years");
+        assert!((2028..=2036).contains(&breakeven), "breakeven {breakeven}");
+        // Growing at 13% the fund returns more than it called
+        assert!(out.total_distributions > out.total_contributions);
+    }
+
+    #[test]
+    fn test_existing_fund_starts_at_current_age() {
+        let mut input = base_input();
+        input.commitments = vec![ForecastCommitment {
+            name: "RE Fund III".into(),
+            strategy: "real_estate".into(),
+            vintage: 2019,
+            commitment: dec!(50),
+            paid_in: dec!(45),
+            nav: dec!(40),
+        }];
+        let out = forecast_cash_flows(&input).unwrap();
+        let fund = &out.funds[0];
+        assert_eq!(fund.current_age, 5);
+        // Age 6 contribution rate is the last real estate rate (0.6)
+        assert_eq!(fund.years[0].contributions, dec!(3.0));
+        // Yield floor applies: (6/10)^1.5 ~ 0.465 > 0.04
+        assert!(fund.years[0].distributions > dec!(40) * dec!(1.08) * dec!(0.46));
+        // Real estate life is 10 years: all distributed by 2029
+        assert!(fund.years[5].nav.is_zero() || fund.years[5].nav < dec!(1));
+    }
+
+    #[test]
+    fn test_strategy_aggregation_and_portfolio_totals() {
+        let mut input = base_input();
+        let mut second = new_buyout();
+        second.name = "Buyout Fund VIII".into();
+        second.strategy = "buyout".into();
+        input.commitments.push(second);
+        input.commitments.push(ForecastCommitment {
+            name: "VC Fund II".into(),
+            strategy: "Venture".into(),
+            vintage: 2022,
+            commitment: dec!(20),
+            paid_in: dec!(10),
+            nav: dec!(12),
+        });
+        let out = forecast_cash_flows(&input).unwrap();
+        assert_eq!(out.by_strategy.len(), 2);
+        assert_eq!(out.by_strategy[0].years[0].contributions, dec!(50));
+        for (t, p) in out.portfolio.iter().enumerate() {
+            let sum: Decimal = out.funds.iter().map(|f| f.years[t].nav).sum();
+            assert_eq!(p.nav, sum);
+        }
+    }
+
+    #[test]
+    fn test_strategy_override_used() {
+        let mut input = base_input();
+        input.strategy_assumptions = vec![StrategyAssumption {
+            strategy: "BUYOUT".into(),
+            params: TakahashiAlexanderParams {
+                contribution_rates: vec![dec!(0.5)],
+                growth_rate: dec!(0.10),
+                bow: dec!(2),
+                yield_rate: dec!(0),
+                fund_life: 8,
+            },
+        }];
+        let out = forecast_cash_flows(&input).unwrap();
+        assert_eq!(out.portfolio[0].contributions, dec!(50));
+        assert_eq!(out.by_strategy[0].params.fund_life, 8);
+    }
+
+    #[test]
+    fn test_unknown_strategy_rejected() {
+        let mut input = base_input();
+        input.commitments[0].strategy = "Timber".into();
+        let err = forecast_cash_flows(&input).unwrap_err();
+        match err {
+            CorpFinanceError::InvalidInput { field, .. } => {
+                assert_eq!(field, "strategy_assumptions")
+            }
+            other => panic!("Expected InvalidInput, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_stochastic_bands_bracket_median() {
+        let mut input = base_input();
+        input.stochastic = Some(StochasticForecast {
+            num_paths: Some(300),
+            growth_volatility: dec!(0.15),
+            confidence: Some(dec!(0.90)),
+            seed: Some(7),
+        });
+        let out = forecast_cash_flows(&input).unwrap();
+        let s = out.stochastic.unwrap();
+        assert_eq!(s.num_paths, 300);
+        for y in &s.years {
+            assert!(y.nav_low <= y.nav_median && y.nav_median <= y.nav_high);
+            assert!(y.net_cash_flow_low <= y.net_cash_flow_median);
+            assert!(y.net_cash_flow_median <= y.net_cash_flow_high);
+        }
+        // Contributions do not depend on growth, so year 1 has no dispersion
+        assert_eq!(s.years[0].net_cash_flow_low, s.years[0].net_cash_flow_high);
+        assert!(s.years[5].nav_high > s.years[5].nav_low);
+        assert!(s.max_cumulative_outflow_at_confidence >= s.expected_max_cumulative_outflow);
+    }
+
+    #[test]
+    fn test_stochastic_reproducible_with_seed() {
+        let mut input = base_input();
+        input.stochastic = Some(StochasticForecast {
+            num_paths: Some(100),
+            growth_volatility: dec!(0.2),
+            confidence: None,
+            seed: Some(11),
+        });
+        let a = forecast_cash_flows(&input).unwrap().stochastic.unwrap();
+        let b = forecast_cash_flows(&input).unwrap().stochastic.unwrap();
+        assert_eq!(a.years[6].nav_median, b.years[6].nav_median);
+        assert_eq!(
+            a.max_cumulative_outflow_at_confidence,
+            b.max_cumulative_outflow_at_confidence
+        );
+    }
+
+    #[test]
+    fn test_validation_errors() {
+        let mut input = base_input();
+        input.horizon_years = 0;
+        assert!(forecast_cash_flows(&input).is_err());
+
+        let mut input = base_input();
+        input.commitments[0].vintage = 2030;
+        assert!(forecast_cash_flows(&input).is_err());
+
+        let mut input = base_input();
+        input.commitments.clear();
+        assert!(forecast_cash_flows(&input).is_err());
+    }
+}
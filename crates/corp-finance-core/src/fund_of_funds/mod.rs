@@ -1,5 +1,7 @@
+pub mod cash_flow_forecast;
 pub mod commitment_pacing;
 pub mod j_curve;
 pub mod manager_selection;
 pub mod portfolio_construction;
 pub mod secondaries;
+pub mod style_analysis;
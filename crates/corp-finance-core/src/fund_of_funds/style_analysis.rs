@@ -0,0 +1,691 @@
+//! Returns-based Style Analysis (Sharpe, 1992).
+//!
+//! Explains a fund's returns as a long-only, fully invested mix of style
+//! indices, complementing the track-record scoring in `manager_selection`:
+//!
+//! - **Constrained regression**: style weights minimising tracking variance
+//!   subject to weights >= 0 and summing to 1 (active-set quadratic program)
+//! - **R² decomposition**: share of return variance explained by each style
+//!   versus manager selection
+//! - **Rolling windows**: style weights re-estimated over trailing windows
+//! - **Style drift**: dispersion of rolling weights and windows that deviate
+//!   from the full-period style mix
+//!
+//! All arithmetic uses `rust_decimal::Decimal`. No `f64`.
+
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+
+use crate::error::CorpFinanceError;
+use crate::CorpFinanceResult;
+
+// ---------------------------------------------------------------------------
+// Input / Output
+// ---------------------------------------------------------------------------
+
+/// A style index return series (e.g. "Large Value", "Small Growth").
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StyleIndex {
+    /// Index name.
+    pub name: String,
+    /// Periodic returns aligned with the fund returns.
+    pub returns: Vec<Decimal>,
+}
+
+/// Input for returns-based style analysis.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StyleAnalysisInput {
+    /// Fund name.
+    pub fund_name: String,
+    /// Periodic fund returns (as decimals).
+    pub fund_returns: Vec<Decimal>,
+    /// Style indices the fund is regressed on.
+    pub style_indices: Vec<StyleIndex>,
+    /// Rolling window length in periods (default 36).
+    pub rolling_window: Option<usize>,
+    /// Turnover versus the full-period weights (half the sum of absolute
+    /// weight differences) above which a window is flagged as drift
+    /// (default 0.20).
+    pub drift_threshold: Option<Decimal>,
+}
+
+/// Estimated weight and variance contribution of one style index.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StyleExposure {
+    /// Index name.
+    pub index: String,
+    /// Constrained regression weight (0 to 1).
+    pub weight: Decimal,
+    /// Share of fund return variance attributed to this style:
+    /// weight x Cov(index, fund + residual) / Var(fund). Sums to R².
+    pub variance_contribution: Decimal,
+}
+
+/// Style weights estimated over one rolling window.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RollingStyleWindow {
+    /// First period in the window (0-based).
+    pub start_period: usize,
+    /// Last period in the window (0-based, inclusive).
+    pub end_period: usize,
+    /// Weights in the order of `style_indices`.
+    pub weights: Vec<Decimal>,
+    /// Style R² within the window.
+    pub r_squared: Decimal,
+    /// Turnover versus the full-period weights.
+    pub deviation_from_full_period: Decimal,
+}
+
+/// Style drift diagnostics from the rolling windows.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StyleDrift {
+    /// Sum across indices of the standard deviation of rolling weights.
+    pub drift_score: Decimal,
+    /// Max minus min rolling weight, in the order of `style_indices`.
+    pub weight_ranges: Vec<Decimal>,
+    /// Largest turnover between consecutive windows.
+    pub max_window_turnover: Decimal,
+    /// End periods of windows whose deviation exceeds the drift threshold.
+    pub drift_periods: Vec<usize>,
+    /// True when any window exceeds the drift threshold.
+    pub drift_detected: bool,
+}
+
+/// Output of returns-based style analysis.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StyleAnalysisOutput {
+    /// Full-period style exposures.
+    pub exposures: Vec<StyleExposure>,
+    /// 1 - Var(residual) / Var(fund): share of variance explained by style.
+    pub r_squared: Decimal,
+    /// 1 - R²: share of variance attributed to manager selection.
+    pub selection_share: Decimal,
+    /// Mean periodic residual (fund return less style benchmark return).
+    pub selection_return: Decimal,
+    /// Standard deviation of the residual (periodic tracking error).
+    pub tracking_error: Decimal,
+    /// Periodic return of the style benchmark.
+    pub style_returns: Vec<Decimal>,
+    /// Rolling-window style weights (empty when history <= window).
+    pub rolling: Vec<RollingStyleWindow>,
+    /// Style drift diagnostics (None without at least two windows).
+    pub drift: Option<StyleDrift>,
+}
+
+// ---------------------------------------------------------------------------
+// Core computation
+// ---------------------------------------------------------------------------
+
+/// Perform returns-based style analysis.
+pub fn analyze_style(input: &StyleAnalysisInput) -> CorpFinanceResult<StyleAnalysisOutput> {
+    validate_style_input(input)?;
+
+    let window = input.rolling_window.unwrap_or(36);
+    let threshold = input.drift_threshold.unwrap_or(dec!(0.20));
+    let n = input.fund_returns.len();
+    let indices: Vec<&[Decimal]> = input
+        .style_indices
+        .iter()
+        .map(|s| s.returns.as_slice())
+        .collect();
+
+    // 1. Full-period constrained regression
+    let fit = fit_style(&input.fund_returns, &indices, 0, n);
+
+    // 2. Variance decomposition by style. Var(fund) - Var(residual) =
+    //    Cov(style, fund + residual), so the contributions add up to R².
+    let residuals: Vec<Decimal> = input
+        .fund_returns
+        .iter()
+        .zip(&fit.style_returns)
+        .map(|(r, s)| r - s)
+        .collect();
+    let fund_plus_residual: Vec<Decimal> = input
+        .fund_returns
+        .iter()
+        .zip(&residuals)
+        .map(|(r, e)| r + e)
+        .collect();
+    let var_fund = variance(&input.fund_returns);
+    let exposures = input
+        .style_indices
+        .iter()
+        .zip(&fit.weights)
+        .map(|(idx, w)| {
+            let contribution = if var_fund.is_zero() {
+                Decimal::ZERO
+            } else {
+                *w * covariance(&idx.returns, &fund_plus_residual) / var_fund
+            };
+            StyleExposure {
+                index: idx.name.clone(),
+                weight: *w,
+                variance_contribution: contribution,
+            }
+        })
+        .collect();
+
+    let selection_return = residuals.iter().copied().sum::<Decimal>() / Decimal::from(n as u32);
+    let tracking_error = decimal_sqrt(variance(&residuals));
+
+    // 3. Rolling windows
+    let rolling: Vec<RollingStyleWindow> = if n > window {
+        (0..=n - window)
+            .map(|start| {
+                let w = fit_style(&input.fund_returns, &indices, start, start + window);
+                RollingStyleWindow {
+                    start_period: start,
+                    end_period: start + window - 1,
+                    deviation_from_full_period: turnover(&w.weights, &fit.weights),
+                    weights: w.weights,
+                    r_squared: w.r_squared,
+                }
+            })
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    // 4. Style drift
+    let drift = (rolling.len() >= 2).then(|| compute_drift(&rolling, threshold));
+
+    Ok(StyleAnalysisOutput {
+        exposures,
+        r_squared: fit.r_squared,
+        selection_share: Decimal::ONE - fit.r_squared,
+        selection_return,
+        tracking_error,
+        style_returns: fit.style_returns,
+        rolling,
+        drift,
+    })
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+struct StyleFit {
+    weights: Vec<Decimal>,
+    style_returns: Vec<Decimal>,
+    r_squared: Decimal,
+}
+
+/// Constrained regression of fund returns on the indices over [start, end).
+fn fit_style(fund: &[Decimal], indices: &[&[Decimal]], start: usize, end: usize) -> StyleFit {
+    let k = indices.len();
+    let y = &fund[start..end];
+    let xs: Vec<&[Decimal]> = indices.iter().map(|x| &x[start..end]).collect();
+
+    // Normal equations of the tracking variance objective
+    let mut q = vec![vec![Decimal::ZERO; k]; k];
+    let mut c = vec![Decimal::ZERO; k];
+    for i in 0..k {
+        for j in i..k {
+            let v = covariance(xs[i], xs[j]);
+            q[i][j] = v;
+            q[j][i] = v;
+        }
+        c[i] = covariance(xs[i], y);
+    }
+
+    let weights = solve_simplex_qp(&q, &c);
+    let style_returns: Vec<Decimal> = (0..y.len())
+        .map(|t| xs.iter().zip(&weights).map(|(x, w)| x[t] * w).sum())
+        .collect();
+    let residuals: Vec<Decimal> = y.iter().zip(&style_returns).map(|(r, s)| r - s).collect();
+
+    let var_y = variance(y);
+    let r_squared = if var_y.is_zero() {
+        Decimal::ZERO
+    } else {
+        Decimal::ONE - variance(&residuals) / var_y
+    };
+
+    StyleFit {
+        weights,
+        style_returns,
+        r_squared,
+    }
+}
+
+/// Minimise ½wᵀQw − cᵀw subject to Σw = 1, w >= 0 with a primal
+/// active-set method. Small problems (a handful of indices) converge in a
+/// few iterations.
+fn solve_simplex_qp(q: &[Vec<Decimal>], c: &[Decimal]) -> Vec<Decimal> {
+    let k = c.len();
+    let tol = dec!(0.000000001);
+    // Light ridge keeps the system solvable when indices are collinear
+    let trace: Decimal = (0..k).map(|i| q[i][i]).sum();
+    let ridge = (trace * dec!(0.0000000001)).max(dec!(0.000000000001));
+
+    let mut w = vec![Decimal::ONE / Decimal::from(k as u32); k];
+    let mut at_bound = vec![false; k];
+
+    for _ in 0..(10 * k + 10) {
+        let free: Vec<usize> = (0..k).filter(|i| !at_bound[*i]).collect();
+        let Some((target, nu)) = solve_equality_qp(q, c, &free, ridge) else {
+            break;
+        };
+        let mut candidate = vec![Decimal::ZERO; k];
+        for (pos, &i) in free.iter().enumerate() {
+            candidate[i] = target[pos];
+        }
+        let step: Vec<Decimal> = candidate.iter().zip(&w).map(|(a, b)| a - b).collect();
+
+        if step.iter().all(|s| s.abs() < tol) {
+            // Release the bound with the most negative multiplier, if any
+            let release = (0..k)
+                .filter(|i| at_bound[*i])
+                .map(|i| {
+                    let grad: Decimal =
+                        (0..k).map(|j| q[i][j] * w[j]).sum::<Decimal>() + ridge * w[i] - c[i];
+                    (i, grad - nu)
+                })
+                .filter(|(_, lambda)| *lambda < -tol)
+                .min_by(|a, b| a.1.cmp(&b.1));
+            match release {
+                Some((i, _)) => at_bound[i] = false,
+                None => break,
+            }
+            continue;
+        }
+
+        // Longest feasible step towards the equality-constrained optimum
+        let mut alpha = Decimal::ONE;
+        let mut blocking = None;
+        for &i in &free {
+            if step[i] < Decimal::ZERO {
+                let ratio = -w[i] / step[i];
+                if ratio < alpha {
+                    alpha = ratio;
+                    blocking = Some(i);
+                }
+            }
+        }
+        for i in 0..k {
+            w[i] += alpha * step[i];
+        }
+        if let Some(i) = blocking {
+            w[i] = Decimal::ZERO;
+            at_bound[i] = true;
+        }
+    }
+
+    // Clean up rounding so weights are exactly non-negative and sum to 1
+    for v in w.iter_mut() {
+        if *v < Decimal::ZERO {
+            *v = Decimal::ZERO;
+        }
+    }
+    let total: Decimal = w.iter().copied().sum();
+    if !total.is_zero() {
+        for v in w.iter_mut() {
+            *v /= total;
+        }
+    }
+    w
+}
+
+/// Solve the KKT system on the free set: Q_FF w_F − c_F = ν·1, Σw_F = 1.
+/// Returns the free weights and the budget multiplier ν.
+fn solve_equality_qp(
+    q: &[Vec<Decimal>],
+    c: &[Decimal],
+    free: &[usize],
+    ridge: Decimal,
+) -> Option<(Vec<Decimal>, Decimal)> {
+    let m = free.len();
+    if m == 0 {
+        return None;
+    }
+    let size = m + 1;
+    let mut a = vec![vec![Decimal::ZERO; size + 1]; size];
+    for (r, &i) in free.iter().enumerate() {
+        for (col, &j) in free.iter().enumerate() {
+            a[r][col] = q[i][j];
+        }
+        a[r][r] += ridge;
+        a[r][m] = -Decimal::ONE;
+        a[r][size] = c[i];
+    }
+    for cell in a[m].iter_mut().take(m) {
+        *cell = Decimal::ONE;
+    }
+    a[m][size] = Decimal::ONE;
+
+    let x = gaussian_solve(a)?;
+    Some((x[..m].to_vec(), x[m]))
+}
+
+/// Gaussian elimination with partial pivoting on an augmented matrix.
+fn gaussian_solve(mut a: Vec<Vec<Decimal>>) -> Option<Vec<Decimal>> {
+    let n = a.len();
+    for col in 0..n {
+        let pivot = (col..n).max_by(|&i, &j| a[i][col].abs().cmp(&a[j][col].abs()))?;
+        if a[pivot][col].abs() < dec!(0.0000000000000001) {
+            return None;
+        }
+        a.swap(col, pivot);
+        let pivot_row = a[col].clone();
+        for row in a.iter_mut().skip(col + 1) {
+            let f = row[col] / pivot_row[col];
+            if f.is_zero() {
+                continue;
+            }
+            for (cell, p) in row.iter_mut().zip(&pivot_row).skip(col) {
+                *cell -= f * p;
+            }
+        }
+    }
+    let mut x = vec![Decimal::ZERO; n];
+    for row in (0..n).rev() {
+        let tail: Decimal = (row + 1..n).map(|j| a[row][j] * x[j]).sum();
+        x[row] = (a[row][n] - tail) / a[row][row];
+    }
+    Some(x)
+}
+
+fn compute_drift(rolling: &[RollingStyleWindow], threshold: Decimal) -> StyleDrift {
+    let k = rolling[0].weights.len();
+    let mut drift_score = Decimal::ZERO;
+    let mut weight_ranges = Vec::with_capacity(k);
+    for i in 0..k {
+        let series: Vec<Decimal> = rolling.iter().map(|w| w.weights[i]).collect();
+        drift_score += decimal_sqrt(variance(&series));
+        let max = series.iter().copied().max().unwrap_or(Decimal::ZERO);
+        let min = series.iter().copied().min().unwrap_or(Decimal::ZERO);
+        weight_ranges.push(max - min);
+    }
+
+    let max_window_turnover = rolling
+        .windows(2)
+        .map(|pair| turnover(&pair[0].weights, &pair[1].weights))
+        .max()
+        .unwrap_or(Decimal::ZERO);
+
+    let drift_periods: Vec<usize> = rolling
+        .iter()
+        .filter(|w| w.deviation_from_full_period > threshold)
+        .map(|w| w.end_period)
+        .collect();
+
+    StyleDrift {
+        drift_score,
+        weight_ranges,
+        max_window_turnover,
+        drift_detected: !drift_periods.is_empty(),
+        drift_periods,
+    }
+}
+
+/// Half the sum of absolute weight differences.
+fn turnover(a: &[Decimal], b: &[Decimal]) -> Decimal {
+    a.iter().zip(b).map(|(x, y)| (x - y).abs()).sum::<Decimal>() / dec!(2)
+}
+
+/// Population covariance.
+fn covariance(xs: &[Decimal], ys: &[Decimal]) -> Decimal {
+    let n = xs.len();
+    if n == 0 {
+        return Decimal::ZERO;
+    }
+    let n_dec = Decimal::from(n as u32);
+    let mean_x = xs.iter().copied().sum::<Decimal>() / n_dec;
+    let mean_y = ys.iter().copied().sum::<Decimal>() / n_dec;
+    xs.iter()
+        .zip(ys)
+        .map(|(x, y)| (x - mean_x) * (y - mean_y))
+        .sum::<Decimal>()
+        / n_dec
+}
+
+fn variance(xs: &[Decimal]) -> Decimal {
+    covariance(xs, xs)
+}
+
+/// Newton's method square root (20 iterations).
+fn decimal_sqrt(x: Decimal) -> Decimal {
+    if x <= Decimal::ZERO {
+        return Decimal::ZERO;
+    }
+    let two = dec!(2);
+    let mut guess = x / two;
+    if guess.is_zero() {
+        guess = Decimal::ONE;
+    }
+    for _ in 0..20 {
+        let next = (guess + x / guess) / two;
+        if (next - guess).abs() < dec!(0.0000000001) {
+            return next;
+        }
+        guess = next;
+    }
+    guess
+}
+
+// ---------------------------------------------------------------------------
+// Validation
+// ---------------------------------------------------------------------------
+
+fn validate_style_input(input: &StyleAnalysisInput) -> CorpFinanceResult<()> {
+    let k = input.style_indices.len();
+    if k < 2 {
+        return Err(CorpFinanceError::InsufficientData(
+            "At least two style indices are required.".into(),
+        ));
+    }
+    let n = input.fund_returns.len();
+    if n <= k {
+        return Err(CorpFinanceError::InsufficientData(format!(
+            "At least {} fund return observations are required for {k} style indices.",
+            k + 1
+        )));
+    }
+    for idx in &input.style_indices {
+        if idx.returns.len() != n {
+            return Err(CorpFinanceError::InvalidInput {
+                field: format!("style_indices.{}", idx.name),
+                reason: "Index returns must have the same length as fund returns.".into(),
+            });
+        }
+    }
+    if let Some(window) = input.rolling_window {
+        if window <= k {
+            return Err(CorpFinanceError::InvalidInput {
+                field: "rolling_window".into(),
+                reason: "Rolling window must exceed the number of style indices.".into(),
+            });
+        }
+    }
+    if let Some(threshold) = input.drift_threshold {
+        if threshold <= Decimal::ZERO || threshold > Decimal::ONE {
+            return Err(CorpFinanceError::InvalidInput {
+                field: "drift_threshold".into(),
+                reason: "Drift threshold must be in (0, 1].".into(),
+            });
+        }
+    }
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn approx_eq(a: Decimal, b: Decimal, eps: Decimal) -> bool {
+        (a - b).abs() < eps
+    }
+
+    /// Deterministic, weakly correlated index return series.
+    fn index_returns(n: usize) -> Vec<Vec<Decimal>> {
+        let series = |mult: i64, modulus: i64, scale: Decimal| -> Vec<Decimal> {
+            (0..n as i64)
+                .map(|t| Decimal::from((t * mult + 3) % modulus - modulus / 2) * scale)
+                .collect()
+        };
+        vec![
+            series(7, 11, dec!(0.01)),
+            series(5, 13, dec!(0.008)),
+            series(3, 7, dec!(0.012)),
+        ]
+    }
+
+    fn input_from(fund: Vec<Decimal>, indices: Vec<Vec<Decimal>>) -> StyleAnalysisInput {
+        let names = ["Large Value", "Small Growth", "Bonds"];
+        StyleAnalysisInput {
+            fund_name: "Test Fund".into(),
+            fund_returns: fund,
+            style_indices: indices
+                .into_iter()
+                .zip(names)
+                .map(|(returns, name)| StyleIndex {
+                    name: name.into(),
+                    returns,
+                })
+                .collect(),
+            rolling_window: None,
+            drift_threshold: None,
+        }
+    }
+
+    fn mix(indices: &[Vec<Decimal>], weights: &[Decimal], t: usize) -> Decimal {
+        indices.iter().zip(weights).map(|(x, w)| x[t] * w).sum()
+    }
+
+    #[test]
+    fn test_style_recovers_known_mix() {
+        let idx = index_returns(24);
+        let weights = [dec!(0.6), dec!(0.3), dec!(0.1)];
+        let fund = (0..24).map(|t| mix(&idx, &weights, t)).collect();
+        let out = analyze_style(&input_from(fund, idx)).unwrap();
+        for (e, w) in out.exposures.iter().zip(weights) {
+            assert!(
+                approx_eq(e.weight, w, dec!(0.0001)),
+                "{} weight {} != {}",
+                e.index,
+                e.weight,
+                w
+            );
+        }
+        assert!(approx_eq(out.r_squared, Decimal::ONE, dec!(0.0001)));
+        assert!(approx_eq(out.tracking_error, Decimal::ZERO, dec!(0.0001)));
+    }
+
+    #[test]
+    fn test_style_weights_long_only_and_fully_invested() {
+        // Unconstrained OLS would short the second index
+        let idx = index_returns(24);
+        let fund = (0..24)
+            .map(|t| dec!(1.5) * idx[0][t] - dec!(0.5) * idx[1][t])
+            .collect();
+        let out = analyze_style(&input_from(fund, idx)).unwrap();
+        let total: Decimal = out.exposures.iter().map(|e| e.weight).sum();
+        assert!(approx_eq(total, Decimal::ONE, dec!(0.0000001)));
+        assert!(out.exposures.iter().all(|e| e.weight >= Decimal::ZERO));
+        assert!(out.exposures[0].weight > dec!(0.9));
+        assert!(out.r_squared < Decimal::ONE);
+    }
+
+    #[test]
+    fn test_style_selection_return() {
+        // Constant outperformance is selection, not style
+        let idx = index_returns(24);
+        let weights = [dec!(0.5), dec!(0.5), dec!(0)];
+        let fund = (0..24)
+            .map(|t| mix(&idx, &weights, t) + dec!(0.002))
+            .collect();
+        let out = analyze_style(&input_from(fund, idx)).unwrap();
+        assert!(approx_eq(out.selection_return, dec!(0.002), dec!(0.00001)));
+        assert!(approx_eq(out.r_squared, Decimal::ONE, dec!(0.0001)));
+    }
+
+    #[test]
+    fn test_style_variance_decomposition() {
+        let idx = index_returns(30);
+        let fund: Vec<Decimal> = (0..30)
+            .map(|t| {
+                mix(&idx, &[dec!(0.4), dec!(0.4), dec!(0.2)], t)
+                    + Decimal::from((t as i64 * 17) % 5 - 2) * dec!(0.001)
+            })
+            .collect();
+        let out = analyze_style(&input_from(fund, idx)).unwrap();
+        let total: Decimal = out.exposures.iter().map(|e| e.variance_contribution).sum();
+        assert!(approx_eq(total, out.r_squared, dec!(0.0000001)));
+        assert!(approx_eq(
+            out.r_squared + out.selection_share,
+            Decimal::ONE,
+            dec!(0.0000001)
+        ));
+        assert!(out.r_squared > dec!(0.8) && out.r_squared < Decimal::ONE);
+    }
+
+    #[test]
+    fn test_style_drift_detected() {
+        // Pure large value for 24 periods, then pure small growth
+        let idx = index_returns(48);
+        let fund = (0..48)
+            .map(|t| if t < 24 { idx[0][t] } else { idx[1][t] })
+            .collect();
+        let mut input = input_from(fund, idx);
+        input.rolling_window = Some(12);
+        let out = analyze_style(&input).unwrap();
+        assert_eq!(out.rolling.len(), 37);
+        assert!(out.rolling[0].weights[0] > dec!(0.99));
+        assert!(out.rolling[36].weights[1] > dec!(0.99));
+        let drift = out.drift.unwrap();
+        assert!(drift.drift_detected);
+        assert!(drift.weight_ranges[0] > dec!(0.99));
+        assert!(drift.drift_periods.contains(&11));
+    }
+
+    #[test]
+    fn test_style_stable_mix_has_no_drift() {
+        let idx = index_returns(48);
+        let weights = [dec!(0.2), dec!(0.5), dec!(0.3)];
+        let fund = (0..48).map(|t| mix(&idx, &weights, t)).collect();
+        let mut input = input_from(fund, idx);
+        input.rolling_window = Some(12);
+        let drift = analyze_style(&input).unwrap().drift.unwrap();
+        assert!(!drift.drift_detected);
+        assert!(drift.drift_score < dec!(0.001));
+        assert!(drift.max_window_turnover < dec!(0.001));
+    }
+
+    #[test]
+    fn test_style_short_history_skips_rolling() {
+        let idx = index_returns(24);
+        let fund = idx[0].clone();
+        let out = analyze_style(&input_from(fund, idx)).unwrap();
+        assert!(out.rolling.is_empty());
+        assert!(out.drift.is_none());
+    }
+
+    #[test]
+    fn test_style_validation() {
+        let idx = index_returns(12);
+        let fund = idx[0].clone();
+
+        let mut mismatched = input_from(fund.clone(), idx.clone());
+        mismatched.style_indices[1].returns.pop();
+        match analyze_style(&mismatched).unwrap_err() {
+            CorpFinanceError::InvalidInput { field, .. } => {
+                assert_eq!(field, "style_indices.Small Growth")
+            }
+            other => panic!("Expected InvalidInput, got {other:?}"),
+        }
+
+        let one_index = input_from(fund.clone(), vec![idx[0].clone()]);
+        assert!(analyze_style(&one_index).is_err());
+
+        let mut bad_window = input_from(fund, idx);
+        bad_window.rolling_window = Some(3);
+        assert!(analyze_style(&bad_window).is_err());
+    }
+}
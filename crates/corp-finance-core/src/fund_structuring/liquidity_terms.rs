@@ -0,0 +1,711 @@
+use chrono::{Duration, Months, NaiveDate};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::time::Instant;
+
+use crate::error::CorpFinanceError;
+use crate::types::*;
+use crate::CorpFinanceResult;
+
+// ---------------------------------------------------------------------------
+// Types — Input
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DealingFrequency {
+    Monthly,
+    Quarterly,
+    SemiAnnual,
+    Annual,
+}
+
+impl DealingFrequency {
+    pub(crate) fn months(self) -> u32 {
+        match self {
+            DealingFrequency::Monthly => 1,
+            DealingFrequency::Quarterly => 3,
+            DealingFrequency::SemiAnnual => 6,
+            DealingFrequency::Annual => 12,
+        }
+    }
+}
+
+/// Hard lock-ups bar redemptions; soft lock-ups allow them for a fee.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LockupKind {
+    Hard,
+    Soft,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LockupTerms {
+    /// Lock-up length from each investor's subscription date
+    pub months: u32,
+    pub kind: LockupKind,
+    /// Fee on redemptions inside a soft lock-up, retained by the fund
+    #[serde(default)]
+    pub early_redemption_fee: Rate,
+}
+
+/// Illiquid assets segregated into a side pocket at the start of the stress.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SidePocket {
+    pub name: String,
+    /// Share of fund NAV moved into the side pocket
+    pub nav_pct: Rate,
+    /// Months after the stress date when the assets are realised
+    pub release_months: u32,
+    /// Realised value as a fraction of carrying value
+    pub recovery_rate: Rate,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InvestorPosition {
+    pub investor_id: String,
+    /// Holding value at the stress date
+    pub holding: Money,
+    pub subscription_date: NaiveDate,
+    /// Fraction of the holding the investor asks to redeem
+    pub redemption_request_pct: Rate,
+}
+
+/// Fund liquidity terms and the redemption stress to run against them.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LiquidityTermsInput {
+    pub fund_name: String,
+    /// Date redemption requests are submitted
+    pub stress_date: NaiveDate,
+    pub dealing_frequency: DealingFrequency,
+    /// Days of notice required before a dealing date
+    pub notice_period_days: u32,
+    /// Days from dealing date to cash settlement
+    #[serde(default)]
+    pub payment_delay_days: u32,
+    /// Fund-level gate: maximum share of liquid NAV paid per dealing date
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fund_gate_pct: Option<Rate>,
+    /// Investor-level gate: maximum share of an investor's original request
+    /// paid per dealing date
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub investor_gate_pct: Option<Rate>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lockup: Option<LockupTerms>,
+    #[serde(default)]
+    pub side_pockets: Vec<SidePocket>,
+    pub investors: Vec<InvestorPosition>,
+    /// NAV held by investors outside the scenario (counts towards the gate)
+    #[serde(default)]
+    pub other_investors_nav: Money,
+    /// Return on liquid assets per dealing period (missing = 0)
+    #[serde(default)]
+    pub liquid_returns: Vec<Rate>,
+    /// Dealing dates to simulate before stopping
+    pub max_dealing_dates: u32,
+}
+
+// ---------------------------------------------------------------------------
+// Types — Output
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum PayoutSource {
+    Liquid,
+    SidePocket(String),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RedemptionPayout {
+    pub dealing_date: NaiveDate,
+    pub payment_date: NaiveDate,
+    pub source: PayoutSource,
+    pub gross_amount: Money,
+    pub early_redemption_fee: Money,
+    pub net_amount: Money,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InvestorLiquidityResult {
+    pub investor_id: String,
+    pub requested_amount: Money,
+    pub lockup_end: Option<NaiveDate>,
+    pub payouts: Vec<RedemptionPayout>,
+    pub total_paid: Money,
+    pub total_fees: Money,
+    /// Liquid request still unpaid after the last simulated dealing date
+    pub unpaid_liquid_request: Money,
+    pub fully_paid: bool,
+    pub final_payment_date: Option<NaiveDate>,
+    pub days_to_final_payment: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DealingDateSummary {
+    pub dealing_date: NaiveDate,
+    pub liquid_nav: Money,
+    /// Eligible requests after investor gates
+    pub eligible_requests: Money,
+    pub fund_gate_limit: Option<Money>,
+    pub paid: Money,
+    pub gate_applied: bool,
+    /// Requests carried to later dealing dates
+    pub carried_forward: Money,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LiquidityTermsOutput {
+    pub fund_name: String,
+    pub total_requested: Money,
+    pub total_paid: Money,
+    pub total_early_redemption_fees: Money,
+    pub side_pocket_nav: Money,
+    pub gated_dealing_dates: u32,
+    pub final_payment_date: Option<NaiveDate>,
+    pub dealing_dates: Vec<DealingDateSummary>,
+    pub investors: Vec<InvestorLiquidityResult>,
+}
+
+// ---------------------------------------------------------------------------
+// Public API
+// ---------------------------------------------------------------------------
+
+/// Simulate a redemption stress against a fund's liquidity terms.
+///
+/// Requests submitted at the stress date are met on successive dealing dates
+/// once the notice period has run, subject to lock-ups, investor-level gates
+/// and a pro-rata fund-level gate. Gated amounts carry forward. Side-pocketed
+/// assets are paid out to redeeming investors when realised.
+pub fn simulate_redemption_stress(
+    input: &LiquidityTermsInput,
+) -> CorpFinanceResult<ComputationOutput<LiquidityTermsOutput>> {
+    let start = Instant::now();
+    let mut warnings: Vec<String> = Vec::new();
+
+    validate_input(input)?;
+
+    let side_pocket_share: Decimal = input.side_pockets.iter().map(|sp| sp.nav_pct).sum();
+    let liquid_share = Decimal::ONE - side_pocket_share;
+    let months = input.dealing_frequency.months();
+    let payment_delay = Duration::days(input.payment_delay_days as i64);
+
+    // Per-investor state, all in liquid-asset value
+    let n = input.investors.len();
+    let mut liquid: Vec<Money> = input
+        .investors
+        .iter()
+        .map(|i| i.holding * liquid_share)
+        .collect();
+    let mut pending: Vec<Money> = input
+        .investors
+        .iter()
+        .zip(&liquid)
+        .map(|(i, l)| *l * i.redemption_request_pct)
+        .collect();
+    // Original liquid request, grown with returns; the investor gate base
+    let mut gate_base = pending.clone();
+    let mut other_nav = input.other_investors_nav * liquid_share;
+
+    let lockup_ends: Vec<Option<NaiveDate>> = input
+        .investors
+        .iter()
+        .map(|i| {
+            input
+                .lockup
+                .as_ref()
+                .map(|l| add_months(i.subscription_date, l.months))
+        })
+        .collect();
+
+    let mut payouts: Vec<Vec<RedemptionPayout>> = vec![Vec::new(); n];
+    let mut dealing_dates = Vec::new();
+    let mut gated_dealing_dates = 0u32;
+    let mut total_fees = Decimal::ZERO;
+
+    for k in 1..=input.max_dealing_dates {
+        if pending.iter().all(|p| p.is_zero()) {
+            break;
+        }
+        let dealing_date = add_months(input.stress_date, k * months);
+
+        let r = input
+            .liquid_returns
+            .get(k as usize - 1)
+            .copied()
+            .unwrap_or(Decimal::ZERO);
+        let growth = Decimal::ONE + r;
+        for i in 0..n {
+            liquid[i] *= growth;
+            pending[i] *= growth;
+            gate_base[i] *= growth;
+        }
+        other_nav *= growth;
+        let liquid_nav: Money = liquid.iter().sum::<Money>() + other_nav;
+
+        // Requests eligible at this dealing date, after investor gates
+        let notice_met =
+            (dealing_date - input.stress_date).num_days() >= input.notice_period_days as i64;
+        let mut eligible = vec![Decimal::ZERO; n];
+        let mut in_soft_lockup = vec![false; n];
+        if notice_met {
+            for i in 0..n {
+                if let (Some(end), Some(terms)) = (lockup_ends[i], &input.lockup) {
+                    if dealing_date < end {
+                        match terms.kind {
+                            LockupKind::Hard => continue,
+                            LockupKind::Soft => in_soft_lockup[i] = true,
+                        }
+                    }
+                }
+                eligible[i] = match input.investor_gate_pct {
+                    Some(g) => pending[i].min(gate_base[i] * g),
+                    None => pending[i],
+                };
+            }
+        }
+        let eligible_total: Money = eligible.iter().sum();
+
+        // Fund-level gate scales all eligible requests pro rata
+        let fund_gate_limit = input.fund_gate_pct.map(|g| liquid_nav * g);
+        let scale = match fund_gate_limit {
+            Some(limit) if eligible_total > limit && eligible_total > Decimal::ZERO => {
+                limit / eligible_total
+            }
+            _ => Decimal::ONE,
+        };
+        let gate_applied = scale < Decimal::ONE;
+        if gate_applied {
+            gated_dealing_dates += 1;
+        }
+
+        let mut paid = Decimal::ZERO;
+        let mut fees_this_date = Decimal::ZERO;
+        for i in 0..n {
+            let gross = eligible[i] * scale;
+            if gross.is_zero() {
+                continue;
+            }
+            let fee = match (&input.lockup, in_soft_lockup[i]) {
+                (Some(terms), true) => gross * terms.early_redemption_fee,
+                _ => Decimal::ZERO,
+            };
+            liquid[i] -= gross;
+            pending[i] -= gross;
+            paid += gross;
+            fees_this_date += fee;
+            payouts[i].push(RedemptionPayout {
+                dealing_date,
+                payment_date: dealing_date + payment_delay,
+                source: PayoutSource::Liquid,
+                gross_amount: gross,
+                early_redemption_fee: fee,
+                net_amount: gross - fee,
+            });
+        }
+
+        // Early redemption fees stay in the fund for the remaining holders
+        if fees_this_date > Decimal::ZERO {
+            total_fees += fees_this_date;
+            let remaining: Money = liquid.iter().sum::<Money>() + other_nav;
+            if remaining > Decimal::ZERO {
+                let uplift = Decimal::ONE + fees_this_date / remaining;
+                liquid.iter_mut().for_each(|l| *l *= uplift);
+                other_nav *= uplift;
+            }
+        }
+
+        dealing_dates.push(DealingDateSummary {
+            dealing_date,
+            liquid_nav,
+            eligible_requests: eligible_total,
+            fund_gate_limit,
+            paid,
+            gate_applied,
+            carried_forward: pending.iter().sum(),
+        });
+    }
+
+    if pending.iter().any(|p| *p > Decimal::ZERO) {
+        warnings.push(format!(
+            "Redemption requests remain unpaid after {} dealing dates",
+            input.max_dealing_dates
+        ));
+    }
+
+    // Side pockets pay redeeming investors when realised
+    for sp in &input.side_pockets {
+        let release = add_months(input.stress_date, sp.release_months);
+        for (i, inv) in input.investors.iter().enumerate() {
+            let carrying = inv.holding * sp.nav_pct * inv.redemption_request_pct;
+            if carrying.is_zero() {
+                continue;
+            }
+            let proceeds = carrying * sp.recovery_rate;
+            payouts[i].push(RedemptionPayout {
+                dealing_date: release,
+                payment_date: release + payment_delay,
+                source: PayoutSource::SidePocket(sp.name.clone()),
+                gross_amount: proceeds,
+                early_redemption_fee: Decimal::ZERO,
+                net_amount: proceeds,
+            });
+        }
+        if sp.recovery_rate < Decimal::ONE {
+            warnings.push(format!(
+                "Side pocket '{}' realises {}% of carrying value",
+                sp.name,
+                (sp.recovery_rate * Decimal::ONE_HUNDRED).round_dp(1)
+            ));
+        }
+    }
+
+    let investors: Vec<InvestorLiquidityResult> = input
+        .investors
+        .iter()
+        .enumerate()
+        .map(|(i, inv)| {
+            let mut list = std::mem::take(&mut payouts[i]);
+            list.sort_by_key(|p| p.payment_date);
+            let total_paid: Money = list.iter().map(|p| p.net_amount).sum();
+            let fees: Money = list.iter().map(|p| p.early_redemption_fee).sum();
+            let final_payment_date = list.last().map(|p| p.payment_date);
+            let fully_paid = pending[i].is_zero();
+            InvestorLiquidityResult {
+                investor_id: inv.investor_id.clone(),
+                requested_amount: inv.holding * inv.redemption_request_pct,
+                lockup_end: lockup_ends[i],
+                total_paid,
+                total_fees: fees,
+                unpaid_liquid_request: pending[i],
+                fully_paid,
+                final_payment_date,
+                days_to_final_payment: final_payment_date
+                    .filter(|_| fully_paid)
+                    .map(|d| (d - input.stress_date).num_days()),
+                payouts: list,
+            }
+        })
+        .collect();
+
+    let output = LiquidityTermsOutput {
+        fund_name: input.fund_name.clone(),
+        total_requested: investors.iter().map(|i| i.requested_amount).sum(),
+        total_paid: investors.iter().map(|i| i.total_paid).sum(),
+        total_early_redemption_fees: total_fees,
+        side_pocket_nav: (input.investors.iter().map(|i| i.holding).sum::<Money>()
+            + input.other_investors_nav)
+            * side_pocket_share,
+        gated_dealing_dates,
+        final_payment_date: investors.iter().filter_map(|i| i.final_payment_date).max(),
+        dealing_dates,
+        investors,
+    };
+
+    let elapsed = start.elapsed().as_micros() as u64;
+    Ok(with_metadata(
+        "Fund Liquidity Terms: Redemption Stress with Gates, Lock-ups and Side Pockets",
+        &serde_json::json!({
+            "dealing_frequency": format!("{:?}", input.dealing_frequency),
+            "notice_period_days": input.notice_period_days,
+            "fund_gate_pct": input.fund_gate_pct.map(|g| g.to_string()),
+            "investor_gate_pct": input.investor_gate_pct.map(|g| g.to_string()),
+            "side_pockets": input.side_pockets.len(),
+            "investors": input.investors.len(),
+        }),
+        warnings,
+        elapsed,
+        output,
+    ))
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+fn add_months(date: NaiveDate, months: u32) -> NaiveDate {
+    date.checked_add_months(Months::new(months))
+        .unwrap_or(NaiveDate::MAX)
+}
+
+fn validate_rate(field: &str, value: Rate) -> CorpFinanceResult<()> {
+    if value < Decimal::ZERO || value > Decimal::ONE {
+        return Err(CorpFinanceError::InvalidInput {
+            field: field.into(),
+            reason: "Must be between 0 and 1".into(),
+        });
+    }
+    Ok(())
+}
+
+fn validate_input(input: &LiquidityTermsInput) -> CorpFinanceResult<()> {
+    if input.investors.is_empty() {
+        return Err(CorpFinanceError::InsufficientData(
+            "At least one investor position is required".into(),
+        ));
+    }
+    if input.max_dealing_dates == 0 {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "max_dealing_dates".into(),
+            reason: "At least one dealing date must be simulated".into(),
+        });
+    }
+    if input.other_investors_nav < Decimal::ZERO {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "other_investors_nav".into(),
+            reason: "Cannot be negative".into(),
+        });
+    }
+    if let Some(g) = input.fund_gate_pct {
+        validate_rate("fund_gate_pct", g)?;
+    }
+    if let Some(g) = input.investor_gate_pct {
+        validate_rate("investor_gate_pct", g)?;
+        if g.is_zero() {
+            return Err(CorpFinanceError::InvalidInput {
+                field: "investor_gate_pct".into(),
+                reason: "An investor gate of zero would never pay out".into(),
+            });
+        }
+    }
+    if let Some(l) = &input.lockup {
+        validate_rate("lockup.early_redemption_fee", l.early_redemption_fee)?;
+    }
+    for inv in &input.investors {
+        if inv.holding < Decimal::ZERO {
+            return Err(CorpFinanceError::InvalidInput {
+                field: format!("investors[{}].holding", inv.investor_id),
+                reason: "Holding cannot be negative".into(),
+            });
+        }
+        validate_rate(
+            &format!("investors[{}].redemption_request_pct", inv.investor_id),
+            inv.redemption_request_pct,
+        )?;
+    }
+    for sp in &input.side_pockets {
+        validate_rate(&format!("side_pockets[{}].nav_pct", sp.name), sp.nav_pct)?;
+        if sp.recovery_rate < Decimal::ZERO {
+            return Err(CorpFinanceError::InvalidInput {
+                field: format!("side_pockets[{}].recovery_rate", sp.name),
+                reason: "Recovery rate cannot be negative".into(),
+            });
+        }
+    }
+    let side_pocket_share: Decimal = input.side_pockets.iter().map(|sp| sp.nav_pct).sum();
+    if side_pocket_share >= Decimal::ONE {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "side_pockets".into(),
+            reason: "Side pockets must leave some liquid NAV".into(),
+        });
+    }
+    if input.liquid_returns.iter().any(|r| *r <= -Decimal::ONE) {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "liquid_returns".into(),
+            reason: "Period returns must be greater than -100%".into(),
+        });
+    }
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn d(y: i32, m: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, day).unwrap()
+    }
+
+    fn investor(id: &str, holding: Money, request: Rate) -> InvestorPosition {
+        InvestorPosition {
+            investor_id: id.into(),
+            holding,
+            subscription_date: d(2020, 1, 1),
+            redemption_request_pct: request,
+        }
+    }
+
+    fn base_input() -> LiquidityTermsInput {
+        LiquidityTermsInput {
+            fund_name: "Test Fund".into(),
+            stress_date: d(2024, 1, 31),
+            dealing_frequency: DealingFrequency::Quarterly,
+            notice_period_days: 60,
+            payment_delay_days: 30,
+            fund_gate_pct: None,
+            investor_gate_pct: None,
+            lockup: None,
+            side_pockets: vec![],
+            investors: vec![
+                investor("A", dec!(60), dec!(1)),
+                investor("B", dec!(40), dec!(0.5)),
+            ],
+            other_investors_nav: dec!(100),
+            liquid_returns: vec![],
+            max_dealing_dates: 12,
+        }
+    }
+
+    fn liquid_payouts(r: &InvestorLiquidityResult) -> Vec<Money> {
+        r.payouts
+            .iter()
+            .filter(|p| matches!(p.source, PayoutSource::Liquid))
+            .map(|p| p.net_amount)
+            .collect()
+    }
+
+    #[test]
+    fn test_ungated_redemption_paid_at_first_dealing_date() {
+        let out = simulate_redemption_stress(&base_input()).unwrap().result;
+        assert_eq!(out.total_requested, dec!(80));
+        assert_eq!(out.total_paid, dec!(80));
+        assert_eq!(out.gated_dealing_dates, 0);
+        let a = &out.investors[0];
+        assert!(a.fully_paid);
+        assert_eq!(a.payouts[0].dealing_date, d(2024, 4, 30));
+        assert_eq!(a.final_payment_date, Some(d(2024, 5, 30)));
+        assert_eq!(a.days_to_final_payment, Some(120));
+    }
+
+    #[test]
+    fn test_notice_period_defers_first_dealing_date() {
+        let mut input = base_input();
+        input.notice_period_days = 100;
+        let out = simulate_redemption_stress(&input).unwrap().result;
+        assert_eq!(out.dealing_dates[0].paid, Decimal::ZERO);
+        assert_eq!(out.investors[0].payouts[0].dealing_date, d(2024, 7, 31));
+    }
+
+    #[test]
+    fn test_fund_gate_prorates_and_carries_forward() {
+        let mut input = base_input();
+        input.fund_gate_pct = Some(dec!(0.20));
+        let out = simulate_redemption_stress(&input).unwrap().result;
+
+        // 200 NAV, 20% gate = 40 against 80 requested: each paid half
+        let first = &out.dealing_dates[0];
+        assert!(first.gate_applied);
+        assert_eq!(first.paid, dec!(40));
+        assert_eq!(first.carried_forward, dec!(40));
+        assert_eq!(liquid_payouts(&out.investors[0])[0], dec!(30));
+        assert_eq!(liquid_payouts(&out.investors[1])[0], dec!(10));
+
+        // 160 NAV, 32 limit, then the remaining 8
+        assert_eq!(out.dealing_dates[1].paid, dec!(32));
+        assert_eq!(out.dealing_dates[2].paid, dec!(8));
+        assert_eq!(out.gated_dealing_dates, 2);
+        assert!(out.investors.iter().all(|i| i.fully_paid));
+    }
+
+    #[test]
+    fn test_investor_gate_spreads_redemption() {
+        let mut input = base_input();
+        input.investor_gate_pct = Some(dec!(0.25));
+        let out = simulate_redemption_stress(&input).unwrap().result;
+        let a = liquid_payouts(&out.investors[0]);
+        assert_eq!(a, vec![dec!(15), dec!(15), dec!(15), dec!(15)]);
+        assert_eq!(out.investors[0].final_payment_date, Some(d(2025, 3, 2)));
+    }
+
+    #[test]
+    fn test_hard_lockup_blocks_until_expiry() {
+        let mut input = base_input();
+        input.lockup = Some(LockupTerms {
+            months: 12,
+            kind: LockupKind::Hard,
+            early_redemption_fee: Decimal::ZERO,
+        });
+        input.investors[0].subscription_date = d(2023, 9, 30);
+        let out = simulate_redemption_stress(&input).unwrap().result;
+        let a = &out.investors[0];
+        assert_eq!(a.lockup_end, Some(d(2024, 9, 30)));
+        assert_eq!(a.payouts[0].dealing_date, d(2024, 10, 31));
+        // B subscribed long ago and is paid at the first dealing date
+        assert_eq!(out.investors[1].payouts[0].dealing_date, d(2024, 4, 30));
+    }
+
+    #[test]
+    fn test_soft_lockup_charges_early_redemption_fee() {
+        let mut input = base_input();
+        input.lockup = Some(LockupTerms {
+            months: 12,
+            kind: LockupKind::Soft,
+            early_redemption_fee: dec!(0.05),
+        });
+        input.investors[0].subscription_date = d(2023, 9, 30);
+        let out = simulate_redemption_stress(&input).unwrap().result;
+        let a = &out.investors[0];
+        assert_eq!(a.payouts[0].dealing_date, d(2024, 4, 30));
+        assert_eq!(a.total_fees, dec!(3));
+        assert_eq!(a.total_paid, dec!(57));
+        assert_eq!(out.total_early_redemption_fees, dec!(3));
+        assert_eq!(out.investors[1].total_fees, Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_side_pocket_released_on_realisation() {
+        let mut input = base_input();
+        input.side_pockets = vec![SidePocket {
+            name: "Private loans".into(),
+            nav_pct: dec!(0.10),
+            release_months: 18,
+            recovery_rate: dec!(0.60),
+        }];
+        let result = simulate_redemption_stress(&input).unwrap();
+        let out = result.result;
+        let a = &out.investors[0];
+
+        // 90% liquid now, 10% side pocket realised at 60% after 18 months
+        assert_eq!(liquid_payouts(a), vec![dec!(54)]);
+        let sp = a.payouts.last().unwrap();
+        assert!(matches!(sp.source, PayoutSource::SidePocket(_)));
+        assert_eq!(sp.dealing_date, d(2025, 7, 31));
+        assert_eq!(sp.net_amount, dec!(3.6));
+        assert_eq!(out.side_pocket_nav, dec!(20));
+        assert_eq!(out.final_payment_date, Some(d(2025, 8, 30)));
+        assert!(result.warnings.iter().any(|w| w.contains("Private loans")));
+    }
+
+    #[test]
+    fn test_unpaid_requests_warn_at_horizon() {
+        let mut input = base_input();
+        input.investor_gate_pct = Some(dec!(0.10));
+        input.max_dealing_dates = 4;
+        let result = simulate_redemption_stress(&input).unwrap();
+        let a = &result.result.investors[0];
+        assert!(!a.fully_paid);
+        assert_eq!(a.unpaid_liquid_request, dec!(36));
+        assert!(a.days_to_final_payment.is_none());
+        assert!(!result.warnings.is_empty());
+    }
+
+    #[test]
+    fn test_liquid_returns_grow_pending_requests() {
+        let mut input = base_input();
+        input.liquid_returns = vec![dec!(-0.10)];
+        let out = simulate_redemption_stress(&input).unwrap().result;
+        assert_eq!(out.investors[0].total_paid, dec!(54));
+    }
+
+    #[test]
+    fn test_validation() {
+        let mut input = base_input();
+        input.side_pockets = vec![SidePocket {
+            name: "All".into(),
+            nav_pct: dec!(1),
+            release_months: 12,
+            recovery_rate: dec!(1),
+        }];
+        assert!(simulate_redemption_stress(&input).is_err());
+
+        let mut input = base_input();
+        input.investors.clear();
+        assert!(simulate_redemption_stress(&input).is_err());
+
+        let mut input = base_input();
+        input.fund_gate_pct = Some(dec!(1.5));
+        assert!(simulate_redemption_stress(&input).is_err());
+    }
+}
@@ -0,0 +1,2 @@
+pub mod liquidity_terms;
+pub mod semi_liquid_stress;
@@ -0,0 +1,637 @@
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::time::Instant;
+
+use crate::error::CorpFinanceError;
+use crate::types::*;
+use crate::CorpFinanceResult;
+
+use super::liquidity_terms::DealingFrequency;
+
+/// Horizons (months) reported in the asset liquidity profile.
+const PROFILE_HORIZONS: [u32; 4] = [1, 3, 6, 12];
+
+// ---------------------------------------------------------------------------
+// Types — Input
+// ---------------------------------------------------------------------------
+
+/// A pool of fund assets with its own sale timeline and price impact.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AssetBucket {
+    pub name: String,
+    /// Share of fund NAV held in the bucket
+    pub nav_pct: Rate,
+    /// Months needed to sell the whole bucket; caps monthly sales
+    pub months_to_sell: u32,
+    /// Discount to carrying value realised on stressed sales
+    pub fire_sale_discount: Rate,
+    /// Monthly return on carrying value
+    #[serde(default)]
+    pub monthly_return: Rate,
+}
+
+/// Redemption requests submitted in a given month.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RedemptionWave {
+    pub month: u32,
+    /// Requests as a share of NAV at submission
+    pub pct_of_nav: Rate,
+}
+
+/// A semi-liquid fund (open-end real estate, private credit) under a
+/// redemption stress.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SemiLiquidStressInput {
+    pub fund_name: String,
+    pub fund_nav: Money,
+    /// Share of NAV held in cash; the rest is split across `asset_buckets`
+    pub cash_pct: Rate,
+    /// Cash the fund keeps back and will not use to pay redemptions, as a
+    /// share of NAV
+    #[serde(default)]
+    pub minimum_cash_pct: Rate,
+    /// Undrawn committed credit facility available for redemptions
+    #[serde(default)]
+    pub credit_facility: Money,
+    pub asset_buckets: Vec<AssetBucket>,
+    pub dealing_frequency: DealingFrequency,
+    /// Months between request and the first dealing date it can be met
+    pub notice_months: u32,
+    /// Fund-level gate as a share of NAV per dealing date
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fund_gate_pct: Option<Rate>,
+    pub redemption_waves: Vec<RedemptionWave>,
+    pub horizon_months: u32,
+}
+
+// ---------------------------------------------------------------------------
+// Types — Output
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LiquidityProfilePoint {
+    pub horizon_months: u32,
+    /// Share of NAV that can be turned into cash within the horizon
+    pub liquid_pct: Rate,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StressMonth {
+    pub month: u32,
+    pub is_dealing_date: bool,
+    pub requests_received: Money,
+    /// Requests past notice and payable this dealing date, before the gate
+    pub requests_due: Money,
+    pub gate_limit: Option<Money>,
+    pub gate_triggered: bool,
+    pub redemptions_paid: Money,
+    /// Payable amount (after the gate) the fund could not fund
+    pub cash_shortfall: Money,
+    /// Carrying value of assets sold
+    pub assets_sold: Money,
+    pub sale_proceeds: Money,
+    pub fire_sale_loss: Money,
+    pub facility_drawn: Money,
+    pub cash: Money,
+    pub nav: Money,
+    pub nav_per_unit: Decimal,
+    /// Requests still waiting to be paid
+    pub queued_requests: Money,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SemiLiquidStressOutput {
+    pub fund_name: String,
+    pub liquidity_profile: Vec<LiquidityProfilePoint>,
+    pub months: Vec<StressMonth>,
+    pub gate_triggered: bool,
+    pub first_gate_month: Option<u32>,
+    pub months_with_shortfall: u32,
+    pub max_cash_shortfall: Money,
+    pub total_requested: Money,
+    pub total_paid: Money,
+    pub unpaid_at_horizon: Money,
+    pub total_fire_sale_loss: Money,
+    /// Fire-sale losses as a share of starting NAV
+    pub fire_sale_nav_impact: Rate,
+    /// Change in NAV per unit over the horizon
+    pub nav_per_unit_change: Rate,
+    pub facility_drawn: Money,
+}
+
+// ---------------------------------------------------------------------------
+// Public API
+// ---------------------------------------------------------------------------
+
+/// Simulate redemption waves against a semi-liquid fund's asset sale
+/// timelines.
+///
+/// Each month the fund sells assets, most liquid first and within each
+/// bucket's monthly capacity, to hold cash for queued requests. Stressed
+/// sales realise the bucket's fire-sale discount, which falls on NAV per unit
+/// for all holders. On dealing dates, requests past notice are paid up to the
+/// gate from cash above the minimum buffer and then the credit facility; any
+/// unfunded amount is a cash shortfall and stays queued.
+pub fn simulate_semi_liquid_stress(
+    input: &SemiLiquidStressInput,
+) -> CorpFinanceResult<ComputationOutput<SemiLiquidStressOutput>> {
+    let start = Instant::now();
+    let mut warnings: Vec<String> = Vec::new();
+
+    validate_input(input)?;
+
+    // Most liquid buckets are sold first
+    let mut buckets: Vec<&AssetBucket> = input.asset_buckets.iter().collect();
+    buckets.sort_by_key(|b| b.months_to_sell);
+    let mut carrying: Vec<Money> = buckets.iter().map(|b| input.fund_nav * b.nav_pct).collect();
+    let capacity: Vec<Money> = carrying
+        .iter()
+        .zip(&buckets)
+        .map(|(c, b)| *c / Decimal::from(b.months_to_sell))
+        .collect();
+
+    let liquidity_profile = PROFILE_HORIZONS
+        .iter()
+        .map(|&h| LiquidityProfilePoint {
+            horizon_months: h,
+            liquid_pct: input.cash_pct
+                + buckets
+                    .iter()
+                    .map(|b| {
+                        let share =
+                            (Decimal::from(h) / Decimal::from(b.months_to_sell)).min(Decimal::ONE);
+                        b.nav_pct * share * (Decimal::ONE - b.fire_sale_discount)
+                    })
+                    .sum::<Decimal>(),
+        })
+        .collect();
+
+    let dealing_months = input.dealing_frequency.months();
+    let mut cash = input.fund_nav * input.cash_pct;
+    let mut facility_drawn = Decimal::ZERO;
+    // Units start at one per unit of NAV
+    let mut units = input.fund_nav;
+    // Queued requests in units, with the month they become payable
+    let mut queue: Vec<(u32, Decimal)> = Vec::new();
+
+    let mut months = Vec::with_capacity(input.horizon_months as usize);
+    let mut total_requested = Decimal::ZERO;
+    let mut total_paid = Decimal::ZERO;
+    let mut total_loss = Decimal::ZERO;
+    let mut first_gate_month = None;
+    let mut months_with_shortfall = 0u32;
+    let mut max_cash_shortfall = Decimal::ZERO;
+
+    let nav_of = |cash: Money, carrying: &[Money], drawn: Money| {
+        cash + carrying.iter().sum::<Money>() - drawn
+    };
+
+    for month in 1..=input.horizon_months {
+        for (c, b) in carrying.iter_mut().zip(&buckets) {
+            *c *= Decimal::ONE + b.monthly_return;
+        }
+        let nav = nav_of(cash, &carrying, facility_drawn);
+        let nav_per_unit = if units > Decimal::ZERO {
+            nav / units
+        } else {
+            Decimal::ONE
+        };
+
+        // New requests become payable at the first dealing date after notice
+        let wave_pct: Rate = input
+            .redemption_waves
+            .iter()
+            .filter(|w| w.month == month)
+            .map(|w| w.pct_of_nav)
+            .sum();
+        let queued_units: Decimal = queue.iter().map(|(_, u)| *u).sum();
+        let new_units = ((units - queued_units) * wave_pct).max(Decimal::ZERO);
+        let requests_received = new_units * nav_per_unit;
+        if new_units > Decimal::ZERO {
+            let earliest = month + input.notice_months;
+            let payable_from = earliest.div_ceil(dealing_months) * dealing_months;
+            queue.push((payable_from, new_units));
+            total_requested += requests_received;
+        }
+
+        // Raise cash for everything queued, keeping the minimum buffer
+        let queued_value = queue.iter().map(|(_, u)| *u).sum::<Decimal>() * nav_per_unit;
+        let buffer = nav * input.minimum_cash_pct;
+        let mut need = (queued_value + buffer - cash).max(Decimal::ZERO);
+        let mut assets_sold = Decimal::ZERO;
+        let mut sale_proceeds = Decimal::ZERO;
+        let mut fire_sale_loss = Decimal::ZERO;
+        for (i, b) in buckets.iter().enumerate() {
+            if need <= Decimal::ZERO {
+                break;
+            }
+            let net_rate = Decimal::ONE - b.fire_sale_discount;
+            if net_rate <= Decimal::ZERO {
+                continue;
+            }
+            let sold = (need / net_rate).min(capacity[i]).min(carrying[i]);
+            carrying[i] -= sold;
+            let proceeds = sold * net_rate;
+            assets_sold += sold;
+            sale_proceeds += proceeds;
+            fire_sale_loss += sold - proceeds;
+            need -= proceeds;
+        }
+        cash += sale_proceeds;
+        total_loss += fire_sale_loss;
+
+        // Dealing date: pay what is due, up to the gate
+        let is_dealing_date = month.is_multiple_of(dealing_months);
+        let nav = nav_of(cash, &carrying, facility_drawn);
+        let nav_per_unit = if units > Decimal::ZERO {
+            nav / units
+        } else {
+            Decimal::ONE
+        };
+        let mut requests_due = Decimal::ZERO;
+        let mut gate_limit = None;
+        let mut gate_triggered = false;
+        let mut redemptions_paid = Decimal::ZERO;
+        let mut cash_shortfall = Decimal::ZERO;
+        let mut month_draw = Decimal::ZERO;
+        if is_dealing_date {
+            let due_units: Decimal = queue
+                .iter()
+                .filter(|(from, _)| *from <= month)
+                .map(|(_, u)| *u)
+                .sum();
+            requests_due = due_units * nav_per_unit;
+            let mut payable_units = due_units;
+            if let Some(g) = input.fund_gate_pct {
+                let limit_units = units * g;
+                gate_limit = Some(limit_units * nav_per_unit);
+                if due_units > limit_units {
+                    payable_units = limit_units;
+                    gate_triggered = true;
+                    first_gate_month.get_or_insert(month);
+                }
+            }
+            let payable = payable_units * nav_per_unit;
+
+            let from_cash = payable.min((cash - buffer).max(Decimal::ZERO));
+            let from_facility = (payable - from_cash).min(input.credit_facility - facility_drawn);
+            redemptions_paid = from_cash + from_facility;
+            cash_shortfall = payable - redemptions_paid;
+            cash -= from_cash;
+            facility_drawn += from_facility;
+            month_draw = from_facility;
+
+            if cash_shortfall > Decimal::ZERO {
+                months_with_shortfall += 1;
+                max_cash_shortfall = max_cash_shortfall.max(cash_shortfall);
+            }
+
+            // Settle the queue oldest first
+            let mut paid_units = if nav_per_unit > Decimal::ZERO {
+                redemptions_paid / nav_per_unit
+            } else {
+                Decimal::ZERO
+            };
+            units -= paid_units;
+            for (from, u) in queue.iter_mut() {
+                if *from > month || paid_units <= Decimal::ZERO {
+                    continue;
+                }
+                let settled = paid_units.min(*u);
+                *u -= settled;
+                paid_units -= settled;
+            }
+            queue.retain(|(_, u)| *u > Decimal::ZERO);
+            total_paid += redemptions_paid;
+        }
+
+        let nav = nav_of(cash, &carrying, facility_drawn);
+        let nav_per_unit = if units > Decimal::ZERO {
+            nav / units
+        } else {
+            Decimal::ONE
+        };
+        months.push(StressMonth {
+            month,
+            is_dealing_date,
+            requests_received,
+            requests_due,
+            gate_limit,
+            gate_triggered,
+            redemptions_paid,
+            cash_shortfall,
+            assets_sold,
+            sale_proceeds,
+            fire_sale_loss,
+            facility_drawn: month_draw,
+            cash,
+            nav,
+            nav_per_unit,
+            queued_requests: queue.iter().map(|(_, u)| *u).sum::<Decimal>() * nav_per_unit,
+        });
+    }
+
+    let unpaid_at_horizon = months
+        .last()
+        .map(|m| m.queued_requests)
+        .unwrap_or(Decimal::ZERO);
+    if unpaid_at_horizon > Decimal::ZERO {
+        warnings.push(format!(
+            "{} of redemption requests remain unpaid at the horizon",
+            unpaid_at_horizon.round_dp(2)
+        ));
+    }
+    if months_with_shortfall > 0 {
+        warnings.push(format!(
+            "Asset sales and facility could not fund redemptions in {} dealing month(s)",
+            months_with_shortfall
+        ));
+    }
+
+    let end_nav_per_unit = months
+        .last()
+        .map(|m| m.nav_per_unit)
+        .unwrap_or(Decimal::ONE);
+    let output = SemiLiquidStressOutput {
+        fund_name: input.fund_name.clone(),
+        liquidity_profile,
+        gate_triggered: first_gate_month.is_some(),
+        first_gate_month,
+        months_with_shortfall,
+        max_cash_shortfall,
+        total_requested,
+        total_paid,
+        unpaid_at_horizon,
+        total_fire_sale_loss: total_loss,
+        fire_sale_nav_impact: total_loss / input.fund_nav,
+        nav_per_unit_change: end_nav_per_unit - Decimal::ONE,
+        facility_drawn,
+        months,
+    };
+
+    let elapsed = start.elapsed().as_micros() as u64;
+    Ok(with_metadata(
+        "Semi-Liquid Fund Stress: Redemption Waves vs Asset Sale Timelines",
+        &serde_json::json!({
+            "dealing_frequency": format!("{:?}", input.dealing_frequency),
+            "notice_months": input.notice_months,
+            "fund_gate_pct": input.fund_gate_pct.map(|g| g.to_string()),
+            "cash_pct": input.cash_pct.to_string(),
+            "asset_buckets": input.asset_buckets.len(),
+            "sale_order": "shortest months_to_sell first",
+        }),
+        warnings,
+        elapsed,
+        output,
+    ))
+}
+
+// ---------------------------------------------------------------------------
+// Validation
+// ---------------------------------------------------------------------------
+
+fn validate_input(input: &SemiLiquidStressInput) -> CorpFinanceResult<()> {
+    if input.fund_nav <= Decimal::ZERO {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "fund_nav".into(),
+            reason: "Fund NAV must be positive".into(),
+        });
+    }
+    if input.horizon_months == 0 {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "horizon_months".into(),
+            reason: "Horizon must be at least one month".into(),
+        });
+    }
+    if input.credit_facility < Decimal::ZERO {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "credit_facility".into(),
+            reason: "Credit facility cannot be negative".into(),
+        });
+    }
+    for (field, value) in [
+        ("cash_pct", input.cash_pct),
+        ("minimum_cash_pct", input.minimum_cash_pct),
+    ] {
+        if value < Decimal::ZERO || value > Decimal::ONE {
+            return Err(CorpFinanceError::InvalidInput {
+                field: field.into(),
+                reason: "Must be between 0 and 1".into(),
+            });
+        }
+    }
+    if let Some(g) = input.fund_gate_pct {
+        if g <= Decimal::ZERO || g > Decimal::ONE {
+            return Err(CorpFinanceError::InvalidInput {
+                field: "fund_gate_pct".into(),
+                reason: "Gate must be greater than 0 and at most 1".into(),
+            });
+        }
+    }
+    for b in &input.asset_buckets {
+        if b.months_to_sell == 0 {
+            return Err(CorpFinanceError::InvalidInput {
+                field: format!("asset_buckets[{}].months_to_sell", b.name),
+                reason: "Sale timeline must be at least one month".into(),
+            });
+        }
+        if b.nav_pct < Decimal::ZERO
+            || b.fire_sale_discount < Decimal::ZERO
+            || b.fire_sale_discount > Decimal::ONE
+        {
+            return Err(CorpFinanceError::InvalidInput {
+                field: format!("asset_buckets[{}]", b.name),
+                reason: "NAV share must be non-negative and discount between 0 and 1".into(),
+            });
+        }
+    }
+    let total: Decimal = input.cash_pct
+        + input
+            .asset_buckets
+            .iter()
+            .map(|b| b.nav_pct)
+            .sum::<Decimal>();
+    if (total - Decimal::ONE).abs() > Decimal::new(1, 4) {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "asset_buckets".into(),
+            reason: format!("Cash and asset buckets must sum to 100% of NAV, got {total}"),
+        });
+    }
+    for w in &input.redemption_waves {
+        if w.pct_of_nav < Decimal::ZERO || w.pct_of_nav > Decimal::ONE {
+            return Err(CorpFinanceError::InvalidInput {
+                field: "redemption_waves".into(),
+                reason: "Wave size must be between 0 and 1".into(),
+            });
+        }
+    }
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn bucket(name: &str, nav_pct: Rate, months: u32, discount: Rate) -> AssetBucket {
+        AssetBucket {
+            name: name.into(),
+            nav_pct,
+            months_to_sell: months,
+            fire_sale_discount: discount,
+            monthly_return: Decimal::ZERO,
+        }
+    }
+
+    /// Open-end real estate fund: 10% cash, listed REITs, core property.
+    fn property_fund() -> SemiLiquidStressInput {
+        SemiLiquidStressInput {
+            fund_name: "Core Property Fund".into(),
+            fund_nav: dec!(1000),
+            cash_pct: dec!(0.10),
+            minimum_cash_pct: Decimal::ZERO,
+            credit_facility: Decimal::ZERO,
+            asset_buckets: vec![
+                bucket("Property", dec!(0.80), 12, dec!(0.15)),
+                bucket("Listed REITs", dec!(0.10), 1, dec!(0.02)),
+            ],
+            dealing_frequency: DealingFrequency::Quarterly,
+            notice_months: 1,
+            fund_gate_pct: None,
+            redemption_waves: vec![RedemptionWave {
+                month: 1,
+                pct_of_nav: dec!(0.05),
+            }],
+            horizon_months: 12,
+        }
+    }
+
+    #[test]
+    fn test_small_wave_met_from_cash() {
+        let out = simulate_semi_liquid_stress(&property_fund())
+            .unwrap()
+            .result;
+        assert!(!out.gate_triggered);
+        assert_eq!(out.total_requested, dec!(50));
+        assert_eq!(out.total_paid, dec!(50));
+        assert_eq!(out.total_fire_sale_loss, Decimal::ZERO);
+        // Paid on the first quarterly dealing date after one month's notice
+        assert_eq!(out.months[2].redemptions_paid, dec!(50));
+        assert_eq!(out.months[2].nav_per_unit, Decimal::ONE);
+    }
+
+    #[test]
+    fn test_liquidity_profile() {
+        let out = simulate_semi_liquid_stress(&property_fund())
+            .unwrap()
+            .result;
+        // 1 month: cash + REITs net of 2% + 1/12 of property net of 15%
+        let one = &out.liquidity_profile[0];
+        assert_eq!(one.horizon_months, 1);
+        let expected = dec!(0.10) + dec!(0.098) + dec!(0.80) / dec!(12) * dec!(0.85);
+        assert!((one.liquid_pct - expected).abs() < dec!(0.0000001));
+        let year = &out.liquidity_profile[3];
+        assert_eq!(year.liquid_pct, dec!(0.10) + dec!(0.098) + dec!(0.68));
+    }
+
+    #[test]
+    fn test_forced_sales_hit_nav_per_unit() {
+        let mut input = property_fund();
+        input.redemption_waves[0].pct_of_nav = dec!(0.30);
+        let out = simulate_semi_liquid_stress(&input).unwrap().result;
+
+        assert!(out.total_fire_sale_loss > Decimal::ZERO);
+        assert!(out.fire_sale_nav_impact > Decimal::ZERO);
+        assert!(out.nav_per_unit_change < Decimal::ZERO);
+        // Month 1 needs 200 beyond cash: all REITs (100 at 2%) then one
+        // month's capacity of property (800 / 12 at 15%)
+        let m1 = &out.months[0];
+        assert_eq!(m1.assets_sold, dec!(100) + dec!(800) / dec!(12));
+        assert_eq!(
+            m1.fire_sale_loss,
+            dec!(2) + dec!(800) / dec!(12) * dec!(0.15)
+        );
+    }
+
+    #[test]
+    fn test_gate_triggers_and_carries_forward() {
+        let mut input = property_fund();
+        input.redemption_waves[0].pct_of_nav = dec!(0.20);
+        input.fund_gate_pct = Some(dec!(0.05));
+        let out = simulate_semi_liquid_stress(&input).unwrap().result;
+
+        assert!(out.gate_triggered);
+        assert_eq!(out.first_gate_month, Some(3));
+        let q1 = &out.months[2];
+        assert!(q1.gate_triggered);
+        assert!(q1.redemptions_paid < q1.requests_due);
+        assert!(q1.queued_requests > Decimal::ZERO);
+        // 5% of a shrinking unit base each quarter: gated all year, queue falling
+        assert_eq!(out.months.iter().filter(|m| m.gate_triggered).count(), 4);
+        assert!(out.months[11].queued_requests < out.months[8].queued_requests);
+        assert!(out.unpaid_at_horizon > Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_cash_shortfall_when_assets_cannot_be_sold_in_time() {
+        let mut input = property_fund();
+        input.asset_buckets = vec![bucket("Property", dec!(0.90), 24, dec!(0.10))];
+        input.redemption_waves[0].pct_of_nav = dec!(0.50);
+        input.horizon_months = 3;
+        let result = simulate_semi_liquid_stress(&input).unwrap();
+        let out = result.result;
+
+        // 100 cash + 3 months of 37.5/month at 90% = 201.25 vs 500 due
+        assert_eq!(out.months_with_shortfall, 1);
+        assert!(out.max_cash_shortfall > dec!(290));
+        assert!(out.unpaid_at_horizon > Decimal::ZERO);
+        assert!(result.warnings.len() >= 2);
+    }
+
+    #[test]
+    fn test_credit_facility_bridges_shortfall() {
+        let mut input = property_fund();
+        input.asset_buckets = vec![bucket("Property", dec!(0.90), 24, dec!(0.10))];
+        input.redemption_waves[0].pct_of_nav = dec!(0.25);
+        input.horizon_months = 3;
+        let without = simulate_semi_liquid_stress(&input).unwrap().result;
+        assert!(without.max_cash_shortfall > Decimal::ZERO);
+
+        input.credit_facility = dec!(100);
+        let with = simulate_semi_liquid_stress(&input).unwrap().result;
+        assert_eq!(with.months_with_shortfall, 0);
+        assert!(with.facility_drawn > Decimal::ZERO);
+        assert!(with.facility_drawn <= dec!(100));
+    }
+
+    #[test]
+    fn test_minimum_cash_buffer_is_kept() {
+        let mut input = property_fund();
+        input.minimum_cash_pct = dec!(0.05);
+        let out = simulate_semi_liquid_stress(&input).unwrap().result;
+        assert!(out
+            .months
+            .iter()
+            .all(|m| m.cash >= m.nav * dec!(0.05) - dec!(0.01)));
+        assert_eq!(out.total_paid, out.total_requested);
+    }
+
+    #[test]
+    fn test_validation() {
+        let mut input = property_fund();
+        input.cash_pct = dec!(0.20);
+        assert!(simulate_semi_liquid_stress(&input).is_err());
+
+        let mut input = property_fund();
+        input.asset_buckets[0].months_to_sell = 0;
+        assert!(simulate_semi_liquid_stress(&input).is_err());
+
+        let mut input = property_fund();
+        input.fund_gate_pct = Some(Decimal::ZERO);
+        assert!(simulate_semi_liquid_stress(&input).is_err());
+    }
+}
@@ -4,7 +4,9 @@ use serde::{Deserialize, Serialize};
 use std::time::Instant;
 
 use crate::error::CorpFinanceError;
-use crate::types::{with_metadata, ComputationOutput, Money, Rate};
+use crate::types::{
+    fx_conversion_rate, with_metadata, ComputationOutput, Currency, FxRate, Money, Rate,
+};
 use crate::CorpFinanceResult;
 
 // ---------------------------------------------------------------------------
@@ -358,24 +360,10 @@ pub fn calculate_cross_rate(
     let (base2, quote2) = parse_pair(&input.rate2_pair)?;
     let (target_base, target_quote) = parse_pair(&input.target_pair)?;
 
-    // Build a map of implied rates: we know base1/quote1 = rate1, etc.
-    // We need to find target_base/target_quote.
-    //
-    // Strategy: express all currencies in terms of a common currency, then derive.
-    // rate1 = base1 per 1 quote1  (base1/quote1)
-    // rate2 = base2 per 1 quote2  (base2/quote2)
-    //
-    // We need: target_base per 1 target_quote
-
     let (cross_rate, derivation) = derive_cross_rate(
-        &base1,
-        &quote1,
-        input.rate1,
-        &base2,
-        &quote2,
-        input.rate2,
-        &target_base,
-        &target_quote,
+        (&base1, &quote1, input.rate1),
+        (&base2, &quote2, input.rate2),
+        (&target_base, &target_quote),
     )?;
 
     let output = CrossRateOutput {
@@ -402,107 +390,38 @@ pub fn calculate_cross_rate(
 
 /// Derive the cross rate given two known rates and the target pair.
 ///
-/// We consider all combinations of how the common currency links the pairs.
+/// A pair "X/Y" quoting `r` is `r` units of X per one Y, i.e. an [`FxRate`]
+/// with base Y and quote X, so the target TB/TQ converts one TQ into TB via
+/// the shared [`fx_conversion_rate`] (direct, inverse or crossed).
 fn derive_cross_rate(
-    b1: &str,
-    q1: &str,
-    r1: Decimal,
-    b2: &str,
-    q2: &str,
-    r2: Decimal,
-    tb: &str,
-    tq: &str,
+    (b1, q1, r1): (&str, &str, Decimal),
+    (b2, q2, r2): (&str, &str, Decimal),
+    (tb, tq): (&str, &str),
 ) -> CorpFinanceResult<(Decimal, String)> {
-    // Collect the four currencies involved in the two pairs.
-    // The common currency appears in both pairs.
-    //
-    // We can express any currency X in terms of any other currency Y by
-    // chaining the two known rates.
-    //
-    // For pair1 B1/Q1 = r1: 1 Q1 = r1 B1, or 1 B1 = (1/r1) Q1.
-    // For pair2 B2/Q2 = r2: 1 Q2 = r2 B2, or 1 B2 = (1/r2) Q2.
-    //
-    // We want TB/TQ = how many TB per 1 TQ.
-
-    // Helper: get the rate of currency X in terms of currency Y from a single pair.
-    // Returns Some(rate) where rate = X per 1 Y.
-    let rate_from_pair =
-        |x: &str, y: &str, base: &str, quote: &str, rate: Decimal| -> Option<Decimal> {
-            if x == base && y == quote {
-                // base/quote = rate => base per 1 quote = rate => X per 1 Y = rate
-                Some(rate)
-            } else if x == quote && y == base {
-                // We want quote per 1 base = 1/rate
-                Some(Decimal::ONE / rate)
-            } else {
-                None
-            }
-        };
-
-    // Direct: can we get TB/TQ from a single pair?
-    if let Some(r) = rate_from_pair(tb, tq, b1, q1, r1) {
-        return Ok((
-            r,
-            format!("{tb}/{tq} obtained directly from {b1}/{q1} = {r1}"),
-        ));
-    }
-    if let Some(r) = rate_from_pair(tb, tq, b2, q2, r2) {
-        return Ok((
-            r,
-            format!("{tb}/{tq} obtained directly from {b2}/{q2} = {r2}"),
-        ));
-    }
-
-    // Indirect: find common currency C such that we can chain:
-    //   TB per 1 C (from one pair) * C per 1 TQ (from the other pair)
-    // = TB per 1 TQ
-
-    // Try all four currencies as intermediary
-    let all_currencies = [b1, q1, b2, q2];
-
-    for &c in &all_currencies {
-        // We need: TB per 1 C from one pair, and C per 1 TQ from the other pair.
-
-        // Option A: TB/C from pair1, C/TQ from pair2
-        if let (Some(tb_per_c), Some(c_per_tq)) = (
-            rate_from_pair(tb, c, b1, q1, r1),
-            rate_from_pair(c, tq, b2, q2, r2),
-        ) {
-            let cross = tb_per_c * c_per_tq;
-            return Ok((
-                cross,
-                format!(
-                    "{tb}/{tq} = ({tb}/{c}) * ({c}/{tq}) \
-                     = ({b1}/{q1} derived {tb_per_c}) * ({b2}/{q2} derived {c_per_tq}) \
-                     = {cross}"
-                ),
-            ));
-        }
-
-        // Option B: TB/C from pair2, C/TQ from pair1
-        if let (Some(tb_per_c), Some(c_per_tq)) = (
-            rate_from_pair(tb, c, b2, q2, r2),
-            rate_from_pair(c, tq, b1, q1, r1),
-        ) {
-            let cross = tb_per_c * c_per_tq;
-            return Ok((
-                cross,
-                format!(
-                    "{tb}/{tq} = ({tb}/{c}) * ({c}/{tq}) \
-                     = ({b2}/{q2} derived {tb_per_c}) * ({b1}/{q1} derived {c_per_tq}) \
-                     = {cross}"
-                ),
-            ));
-        }
-    }
-
-    Err(CorpFinanceError::InvalidInput {
-        field: "target_pair".into(),
-        reason: format!(
-            "Cannot derive {tb}/{tq} from {b1}/{q1} and {b2}/{q2}: \
-             no common currency path found"
-        ),
-    })
+    let quote = |base: &str, quote: &str, rate: Decimal| FxRate {
+        base: Currency::from_code(quote),
+        quote: Currency::from_code(base),
+        rate,
+    };
+    let rates = [quote(b1, q1, r1), quote(b2, q2, r2)];
+    let cross = fx_conversion_rate(&Currency::from_code(tq), &Currency::from_code(tb), &rates)
+        .map_err(|_| CorpFinanceError::InvalidInput {
+            field: "target_pair".into(),
+            reason: format!(
+                "Cannot derive {tb}/{tq} from {b1}/{q1} and {b2}/{q2}: \
+                 no common currency path found"
+            ),
+        })?;
+
+    let is_pair = |b: &str, q: &str| (tb == b && tq == q) || (tb == q && tq == b);
+    let derivation = if is_pair(b1, q1) {
+        format!("{tb}/{tq} obtained directly from {b1}/{q1} = {r1}")
+    } else if is_pair(b2, q2) {
+        format!("{tb}/{tq} obtained directly from {b2}/{q2} = {r2}")
+    } else {
+        format!("{tb}/{tq} crossed from {b1}/{q1} = {r1} and {b2}/{q2} = {r2} = {cross}")
+    };
+    Ok((cross, derivation))
 }
 
 // ---------------------------------------------------------------------------
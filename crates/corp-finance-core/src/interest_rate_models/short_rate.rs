@@ -9,6 +9,7 @@ use rust_decimal_macros::dec;
 use serde::{Deserialize, Serialize};
 use std::time::Instant;
 
+use crate::curves::{Compounding, Curve, CurveConventions, CurveInterpolation, Extrapolation};
 use crate::error::CorpFinanceError;
 use crate::types::{with_metadata, ComputationOutput, Rate, Years};
 use crate::CorpFinanceResult;
@@ -160,14 +161,13 @@ fn decimal_sqrt(x: Decimal) -> Decimal {
 // Input / Output types
 // ---------------------------------------------------------------------------
 
-/// A point on the zero-rate curve.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ZeroRatePoint {
-    /// Time to maturity in years
-    pub maturity: Years,
-    /// Continuously compounded zero rate
-    pub rate: Rate,
-}
+/// Conventions for market curves that do not state their own: continuously
+/// compounded zero rates, linear between nodes, flat beyond them.
+const MARKET_CURVE: CurveConventions = CurveConventions {
+    compounding: Compounding::Continuous,
+    interpolation: CurveInterpolation::Linear,
+    extrapolation: Extrapolation::Flat,
+};
 
 // --- Vasicek ---
 
@@ -256,8 +256,9 @@ pub struct HullWhiteInput {
     pub time_horizon: Years,
     /// Number of time steps
     pub time_steps: u32,
-    /// Observed market zero rates for calibration
-    pub market_zero_rates: Vec<ZeroRatePoint>,
+    /// Observed market zero rates for calibration; a versioned curve document
+    /// or the legacy array of `{maturity, rate}` continuously compounded points
+    pub market_zero_rates: Curve,
 }
 
 /// Output of the Hull-White model.
@@ -692,47 +693,19 @@ fn validate_hull_white(input: &HullWhiteInput) -> CorpFinanceResult<()> {
         ));
     }
     // Verify rates are sorted by maturity
-    for w in input.market_zero_rates.windows(2) {
-        if w[1].maturity <= w[0].maturity {
-            return Err(CorpFinanceError::InvalidInput {
-                field: "market_zero_rates".into(),
-                reason: "Market zero rates must be sorted by ascending maturity".into(),
-            });
-        }
-    }
-    Ok(())
+    input.market_zero_rates.validate("market_zero_rates")
 }
 
-/// Interpolate a zero rate from market data at arbitrary maturity using linear interpolation.
-fn interpolate_zero_rate(market: &[ZeroRatePoint], t: Decimal) -> Decimal {
-    if market.is_empty() {
-        return Decimal::ZERO;
-    }
-    if t <= market[0].maturity {
-        return market[0].rate;
-    }
-    if t >= market[market.len() - 1].maturity {
-        return market[market.len() - 1].rate;
-    }
-
-    for w in market.windows(2) {
-        if t >= w[0].maturity && t <= w[1].maturity {
-            let dt = w[1].maturity - w[0].maturity;
-            if dt.is_zero() {
-                return w[0].rate;
-            }
-            let frac = (t - w[0].maturity) / dt;
-            return w[0].rate + frac * (w[1].rate - w[0].rate);
-        }
-    }
-
-    market[market.len() - 1].rate
+/// Continuously compounded zero rate from market data at arbitrary maturity,
+/// linear unless the curve states otherwise.
+fn interpolate_zero_rate(market: &Curve, t: Decimal) -> Decimal {
+    market.zero_rate_as(t, Compounding::Continuous)
 }
 
 /// Compute the market instantaneous forward rate at time t using:
 /// f(0,t) = d/dt [R(t)*t] = R(t) + t * R'(t)
 /// where R(t) is the zero rate and R'(t) is estimated by finite differences.
-fn market_forward_rate(market: &[ZeroRatePoint], t: Decimal) -> Decimal {
+fn market_forward_rate(market: &Curve, t: Decimal) -> Decimal {
     let eps = dec!(0.0001);
     let r_t = interpolate_zero_rate(market, t);
     let r_t_plus = interpolate_zero_rate(market, t + eps);
@@ -749,7 +722,7 @@ fn run_hull_white(input: &HullWhiteInput) -> CorpFinanceResult<HullWhiteOutput>
     let sigma = input.volatility;
     let r0 = input.current_rate;
     let t_total = input.time_horizon;
-    let market = &input.market_zero_rates;
+    let market = &input.market_zero_rates.with_conventions(MARKET_CURVE);
 
     // Handle zero time horizon
     if t_total == Decimal::ZERO {
@@ -802,9 +775,9 @@ fn run_hull_white(input: &HullWhiteInput) -> CorpFinanceResult<HullWhiteOutput>
     let mut model_prices = Vec::with_capacity(market.len());
     let mut sum_sq_error = Decimal::ZERO;
 
-    for point in market.iter() {
-        let t_mat = point.maturity;
-        let market_price = decimal_exp(Decimal::ZERO - point.rate * t_mat);
+    for t_mat in market.tenors() {
+        let rate = interpolate_zero_rate(market, t_mat);
+        let market_price = decimal_exp(Decimal::ZERO - rate * t_mat);
 
         // Model price: integrate the short rate path
         // Using the calibrated theta, the model should recover market prices.
@@ -819,7 +792,7 @@ fn run_hull_white(input: &HullWhiteInput) -> CorpFinanceResult<HullWhiteOutput>
         // Model ZCB from calibrated Hull-White:
         // ln P(0,T) = -R_market(T)*T  (by construction of theta calibration)
         // The correction from discretization is negligible by design.
-        let ln_market_price = Decimal::ZERO - point.rate * t_mat;
+        let ln_market_price = Decimal::ZERO - rate * t_mat;
 
         // Correction is zero by construction of theta calibration
         let correction = Decimal::ZERO;
@@ -1277,33 +1250,15 @@ mod tests {
     // Hull-White tests
     // -----------------------------------------------------------------------
 
-    fn standard_hw_market() -> Vec<ZeroRatePoint> {
-        vec![
-            ZeroRatePoint {
-                maturity: dec!(0.5),
-                rate: dec!(0.02),
-            },
-            ZeroRatePoint {
-                maturity: dec!(1),
-                rate: dec!(0.025),
-            },
-            ZeroRatePoint {
-                maturity: dec!(2),
-                rate: dec!(0.03),
-            },
-            ZeroRatePoint {
-                maturity: dec!(3),
-                rate: dec!(0.032),
-            },
-            ZeroRatePoint {
-                maturity: dec!(5),
-                rate: dec!(0.035),
-            },
-            ZeroRatePoint {
-                maturity: dec!(10),
-                rate: dec!(0.04),
-            },
-        ]
+    fn standard_hw_market() -> Curve {
+        Curve::from_zero_rates([
+            (dec!(0.5), dec!(0.02)),
+            (dec!(1), dec!(0.025)),
+            (dec!(2), dec!(0.03)),
+            (dec!(3), dec!(0.032)),
+            (dec!(5), dec!(0.035)),
+            (dec!(10), dec!(0.04)),
+        ])
     }
 
     fn standard_hw() -> HullWhiteInput {
@@ -1376,10 +1331,7 @@ mod tests {
     #[test]
     fn test_hw_insufficient_market_data() {
         let input = HullWhiteInput {
-            market_zero_rates: vec![ZeroRatePoint {
-                maturity: dec!(1),
-                rate: dec!(0.03),
-            }],
+            market_zero_rates: Curve::from_zero_rates([(dec!(1), dec!(0.03))]),
             ..standard_hw()
         };
         let err = run_hull_white(&input).unwrap_err();
@@ -1392,16 +1344,10 @@ mod tests {
     #[test]
     fn test_hw_unsorted_market_rates_rejected() {
         let input = HullWhiteInput {
-            market_zero_rates: vec![
-                ZeroRatePoint {
-                    maturity: dec!(5),
-                    rate: dec!(0.04),
-                },
-                ZeroRatePoint {
-                    maturity: dec!(1),
-                    rate: dec!(0.03),
-                },
-            ],
+            market_zero_rates: Curve::from_zero_rates([
+                (dec!(5), dec!(0.04)),
+                (dec!(1), dec!(0.03)),
+            ]),
             ..standard_hw()
         };
         let err = run_hull_white(&input).unwrap_err();
@@ -1543,24 +1489,8 @@ mod tests {
     #[test]
     fn test_hw_flat_curve_theta_near_constant() {
         // With a flat yield curve, theta should be approximately constant
-        let flat_market = vec![
-            ZeroRatePoint {
-                maturity: dec!(1),
-                rate: dec!(0.04),
-            },
-            ZeroRatePoint {
-                maturity: dec!(2),
-                rate: dec!(0.04),
-            },
-            ZeroRatePoint {
-                maturity: dec!(5),
-                rate: dec!(0.04),
-            },
-            ZeroRatePoint {
-                maturity: dec!(10),
-                rate: dec!(0.04),
-            },
-        ];
+        let flat_market =
+            Curve::from_zero_rates([dec!(1), dec!(2), dec!(5), dec!(10)].map(|t| (t, dec!(0.04))));
         let input = HullWhiteInput {
             current_rate: dec!(0.04),
             market_zero_rates: flat_market,
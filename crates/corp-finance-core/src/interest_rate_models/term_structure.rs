@@ -9,6 +9,7 @@ use rust_decimal_macros::dec;
 use serde::{Deserialize, Serialize};
 use std::time::Instant;
 
+use crate::curves::{Compounding, Curve, CurveInterpolation, Extrapolation};
 use crate::error::CorpFinanceError;
 use crate::types::{with_metadata, ComputationOutput, Rate, Years};
 use crate::CorpFinanceResult;
@@ -741,6 +742,10 @@ pub struct BootstrapOutput {
     pub discount_factors: Vec<DiscountFactor>,
     /// Forward rates between consecutive maturities
     pub forward_rates: Vec<ForwardRate>,
+    /// The zero curve in the versioned curve format, with the log-linear
+    /// discount interpolation the bootstrap itself uses, ready to pass to
+    /// swap, spread and short rate models
+    pub curve: Curve,
 }
 
 fn validate_bootstrap(input: &BootstrapInput) -> CorpFinanceResult<()> {
@@ -882,10 +887,18 @@ fn run_bootstrap(input: &BootstrapInput) -> CorpFinanceResult<BootstrapOutput> {
         });
     }
 
+    let curve = Curve {
+        compounding: Some(Compounding::Continuous),
+        interpolation: Some(CurveInterpolation::LogLinearDiscount),
+        extrapolation: Some(Extrapolation::Flat),
+        ..Curve::from_zero_rates(zero_curve.iter().map(|p| (p.maturity, p.rate)))
+    };
+
     Ok(BootstrapOutput {
         zero_curve,
         discount_factors,
         forward_rates,
+        curve,
     })
 }
 
@@ -1542,6 +1555,30 @@ mod tests {
         assert_eq!(result.forward_rates.len(), n - 1);
     }
 
+    #[test]
+    fn test_bootstrap_curve_matches_discount_factors() {
+        let input = BootstrapInput {
+            instruments: standard_bootstrap_instruments(),
+        };
+        let result = run_bootstrap(&input).unwrap();
+        assert_eq!(result.curve.len(), result.zero_curve.len());
+        for df in &result.discount_factors {
+            assert_close(
+                result.curve.discount_factor(df.maturity),
+                df.factor,
+                dec!(0.000001),
+                &format!("Curve DF at {}", df.maturity),
+            );
+        }
+        // Between nodes the curve interpolates ln DF as the bootstrap does
+        assert_close(
+            result.curve.discount_factor(dec!(4)),
+            interpolate_df(&result.discount_factors, dec!(4)),
+            dec!(0.000001),
+            "Curve DF at 4y",
+        );
+    }
+
     #[test]
     fn test_bootstrap_par_bond_at_par() {
         // A par bond priced at 100 should give a zero rate close to the coupon rate
@@ -92,6 +92,115 @@ pub struct FundYearProjection {
     pub tvpi: Multiple,
 }
 
+/// How the hurdle return for a performance fee is set.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum HurdleBasis {
+    /// No hurdle: fee on all gains
+    None,
+    /// Fixed annual hurdle rate, compounded per period
+    Fixed { annual_rate: Rate },
+    /// Benchmark index return plus an annual spread
+    Benchmark { spread: Rate },
+}
+
+/// Hard hurdles charge only on the excess over the hurdle; soft hurdles
+/// charge on the whole gain once the hurdle is cleared.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum HurdleStyle {
+    #[default]
+    Hard,
+    Soft,
+}
+
+/// Treatment of unrecouped losses between crystallisations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum HighWaterMarkPolicy {
+    /// Losses are forgotten at each crystallisation
+    None,
+    /// Losses carried forward in full, unaffected by redemptions
+    Standard,
+    /// Losses carried forward, reduced pro rata by redemptions
+    ProportionalLossCarryforward,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CrystallizationFrequency {
+    Quarterly,
+    Annual,
+    /// Only when capital is redeemed (including the final exit)
+    OnRedemption,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PerformanceFeeVariant {
+    pub name: String,
+    pub performance_fee_rate: Rate,
+    pub hurdle: HurdleBasis,
+    #[serde(default)]
+    pub hurdle_style: HurdleStyle,
+    pub high_water_mark: HighWaterMarkPolicy,
+    pub crystallization: CrystallizationFrequency,
+}
+
+/// Input for comparing performance fee structures on one return path.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PerformanceFeeInput {
+    pub initial_investment: Money,
+    /// Return periods per year: 1, 2, 4 or 12
+    pub periods_per_year: u8,
+    /// Gross return per period
+    pub gross_returns: Vec<Rate>,
+    /// Benchmark return per period (required for benchmark hurdles)
+    #[serde(default)]
+    pub benchmark_returns: Vec<Rate>,
+    /// Fraction of the holding redeemed at each period end (missing = 0).
+    /// The remainder is redeemed after the last period.
+    #[serde(default)]
+    pub redemptions: Vec<Rate>,
+    /// Annual management fee on NAV, charged each period
+    #[serde(default)]
+    pub management_fee_rate: Rate,
+    pub variants: Vec<PerformanceFeeVariant>,
+    pub currency: Option<Currency>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PerformanceFeePeriod {
+    pub period: u32,
+    pub gross_return: Rate,
+    pub hurdle_return: Rate,
+    pub performance_fee: Money,
+    pub crystallized: bool,
+    /// Unrecouped losses after this period
+    pub loss_carryforward: Money,
+    pub redemption_proceeds: Money,
+    /// NAV after redemptions, net of crystallised and accrued fees
+    pub nav: Money,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PerformanceFeeVariantResult {
+    pub name: String,
+    pub total_performance_fees: Money,
+    pub total_management_fees: Money,
+    pub crystallization_count: u32,
+    /// Total redemption proceeds received by the LP
+    pub lp_proceeds: Money,
+    /// Time-weighted LP net return over the horizon
+    pub lp_net_total_return: Rate,
+    pub lp_net_annualized_return: Rate,
+    /// Gross minus net annualised return
+    pub fee_drag: Rate,
+    pub periods: Vec<PerformanceFeePeriod>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PerformanceFeeOutput {
+    pub gross_total_return: Rate,
+    pub gross_annualized_return: Rate,
+    pub variants: Vec<PerformanceFeeVariantResult>,
+}
+
 // ---------------------------------------------------------------------------
 // Main calculation
 // ---------------------------------------------------------------------------
@@ -521,6 +630,308 @@ fn approximate_lp_net_irr(
     }
 }
 
+// ---------------------------------------------------------------------------
+// Performance fee variants
+// ---------------------------------------------------------------------------
+
+/// Compare performance fee structures on a single LP account.
+///
+/// Each variant is run over the same gross return path. Fees accrue over a
+/// crystallisation window against the window's opening NAV, net of any loss
+/// carryforward and hurdle. Redemptions crystallise the accrued fee on the
+/// redeemed fraction; the LP exits in full after the last period.
+pub fn compare_performance_fee_variants(
+    input: &PerformanceFeeInput,
+) -> CorpFinanceResult<ComputationOutput<PerformanceFeeOutput>> {
+    let start = Instant::now();
+    let mut warnings: Vec<String> = Vec::new();
+
+    validate_performance_fee_input(input)?;
+
+    let ppy = Decimal::from(input.periods_per_year);
+    let years = Decimal::from(input.gross_returns.len() as u32) / ppy;
+    let gross_growth: Decimal = input
+        .gross_returns
+        .iter()
+        .fold(Decimal::ONE, |acc, r| acc * (Decimal::ONE + r));
+
+    let variants = input
+        .variants
+        .iter()
+        .map(|v| run_fee_variant(input, v, years))
+        .collect::<Vec<_>>();
+
+    let gross_annualized_return = annualize(gross_growth, years);
+    let variants: Vec<PerformanceFeeVariantResult> = variants
+        .into_iter()
+        .map(|mut v| {
+            v.fee_drag = gross_annualized_return - v.lp_net_annualized_return;
+            v
+        })
+        .collect();
+
+    if input
+        .variants
+        .iter()
+        .any(|v| v.crystallization == CrystallizationFrequency::OnRedemption)
+        && input.redemptions.iter().all(|f| f.is_zero())
+    {
+        warnings
+            .push("No interim redemptions: on-redemption variants crystallise only at exit".into());
+    }
+
+    let output = PerformanceFeeOutput {
+        gross_total_return: gross_growth - Decimal::ONE,
+        gross_annualized_return,
+        variants,
+    };
+
+    let elapsed = start.elapsed().as_micros() as u64;
+    Ok(with_metadata(
+        "Performance Fee Variants: Hurdles, High-Water Mark and Crystallisation",
+        &serde_json::json!({
+            "periods_per_year": input.periods_per_year,
+            "periods": input.gross_returns.len(),
+            "management_fee_rate": input.management_fee_rate.to_string(),
+            "variants": input.variants.len(),
+        }),
+        warnings,
+        elapsed,
+        output,
+    ))
+}
+
+fn validate_performance_fee_input(input: &PerformanceFeeInput) -> CorpFinanceResult<()> {
+    if input.initial_investment <= Decimal::ZERO {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "initial_investment".into(),
+            reason: "Initial investment must be greater than zero".into(),
+        });
+    }
+    if !matches!(input.periods_per_year, 1 | 2 | 4 | 12) {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "periods_per_year".into(),
+            reason: "Periods per year must be 1, 2, 4, or 12".into(),
+        });
+    }
+    if input.gross_returns.is_empty() {
+        return Err(CorpFinanceError::InsufficientData(
+            "At least one gross return period is required".into(),
+        ));
+    }
+    if input.gross_returns.iter().any(|r| *r <= -Decimal::ONE) {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "gross_returns".into(),
+            reason: "Period returns must be greater than -100%".into(),
+        });
+    }
+    if input
+        .redemptions
+        .iter()
+        .any(|f| *f < Decimal::ZERO || *f > Decimal::ONE)
+    {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "redemptions".into(),
+            reason: "Redemption fractions must be between 0 and 1".into(),
+        });
+    }
+    if input.management_fee_rate < Decimal::ZERO || input.management_fee_rate > Decimal::ONE {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "management_fee_rate".into(),
+            reason: "Management fee rate must be between 0 and 1".into(),
+        });
+    }
+    if input.variants.is_empty() {
+        return Err(CorpFinanceError::InsufficientData(
+            "At least one performance fee variant is required".into(),
+        ));
+    }
+    for v in &input.variants {
+        if v.performance_fee_rate < Decimal::ZERO || v.performance_fee_rate > Decimal::ONE {
+            return Err(CorpFinanceError::InvalidInput {
+                field: format!("variants[{}].performance_fee_rate", v.name),
+                reason: "Performance fee rate must be between 0 and 1".into(),
+            });
+        }
+        if matches!(v.hurdle, HurdleBasis::Benchmark { .. })
+            && input.benchmark_returns.len() < input.gross_returns.len()
+        {
+            return Err(CorpFinanceError::InvalidInput {
+                field: "benchmark_returns".into(),
+                reason: format!(
+                    "Variant '{}' uses a benchmark hurdle; a benchmark return is needed for every period",
+                    v.name
+                ),
+            });
+        }
+        if v.crystallization == CrystallizationFrequency::Quarterly
+            && !matches!(input.periods_per_year, 4 | 12)
+        {
+            return Err(CorpFinanceError::InvalidInput {
+                field: format!("variants[{}].crystallization", v.name),
+                reason: "Quarterly crystallisation needs quarterly or monthly periods".into(),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Run one fee variant over the return path.
+fn run_fee_variant(
+    input: &PerformanceFeeInput,
+    variant: &PerformanceFeeVariant,
+    years: Decimal,
+) -> PerformanceFeeVariantResult {
+    let ppy = Decimal::from(input.periods_per_year);
+    let n = input.gross_returns.len();
+    let window = match variant.crystallization {
+        CrystallizationFrequency::Quarterly => Some(input.periods_per_year as usize / 4),
+        CrystallizationFrequency::Annual => Some(input.periods_per_year as usize),
+        CrystallizationFrequency::OnRedemption => None,
+    };
+
+    // Gross-of-accrual NAV; the reported NAV deducts the accrued fee
+    let mut nav = input.initial_investment;
+    // NAV the current window's gain is measured from
+    let mut base = nav;
+    let mut hurdle_growth = Decimal::ONE;
+    let mut loss_cf = Decimal::ZERO;
+    let mut net_nav = nav;
+    let mut net_growth = Decimal::ONE;
+    let mut total_perf = Decimal::ZERO;
+    let mut total_mgmt = Decimal::ZERO;
+    let mut proceeds = Decimal::ZERO;
+    let mut crystallization_count = 0u32;
+    let mut periods = Vec::with_capacity(n);
+
+    for (i, r) in input.gross_returns.iter().enumerate() {
+        nav *= Decimal::ONE + r;
+        let mgmt = nav * input.management_fee_rate / ppy;
+        nav -= mgmt;
+        total_mgmt += mgmt;
+
+        let hurdle_return = match &variant.hurdle {
+            HurdleBasis::None => Decimal::ZERO,
+            HurdleBasis::Fixed { annual_rate } => {
+                (Decimal::ONE + annual_rate).powd(Decimal::ONE / ppy) - Decimal::ONE
+            }
+            HurdleBasis::Benchmark { spread } => input.benchmark_returns[i] + spread / ppy,
+        };
+        hurdle_growth *= Decimal::ONE + hurdle_return;
+        let hurdle_amount = (base * (hurdle_growth - Decimal::ONE)).max(Decimal::ZERO);
+
+        let redeemed = if i == n - 1 {
+            Decimal::ONE
+        } else {
+            input.redemptions.get(i).copied().unwrap_or(Decimal::ZERO)
+        };
+        let scheduled = window.is_some_and(|w| (i + 1).is_multiple_of(w));
+
+        // Share of the window crystallised this period: all of it on a
+        // scheduled date, otherwise only the redeemed slice
+        let share = if scheduled { Decimal::ONE } else { redeemed };
+        let (fee, slice_loss) = assess_performance_fee(
+            variant,
+            share * (nav - base),
+            share * hurdle_amount,
+            share * loss_cf,
+        );
+        // Fee still accruing on the part of the window left open
+        let rest = Decimal::ONE - share;
+        let (accrued, _) = assess_performance_fee(
+            variant,
+            rest * (nav - base),
+            rest * hurdle_amount,
+            rest * loss_cf,
+        );
+        if share > Decimal::ZERO {
+            total_perf += fee;
+            crystallization_count += 1;
+        }
+
+        // Time-weighted net return before capital leaves
+        if net_nav > Decimal::ZERO {
+            net_growth *= (nav - fee - accrued) / net_nav;
+        }
+
+        let redemption_proceeds;
+        if share == Decimal::ONE {
+            nav -= fee;
+            loss_cf = match variant.high_water_mark {
+                HighWaterMarkPolicy::None => Decimal::ZERO,
+                _ => slice_loss,
+            };
+            base = nav;
+            hurdle_growth = Decimal::ONE;
+            redemption_proceeds = nav * redeemed;
+        } else {
+            // A fee crystallised on a redeemed slice is borne by that slice
+            redemption_proceeds = nav * redeemed - fee;
+        }
+
+        let keep = Decimal::ONE - redeemed;
+        if redeemed > Decimal::ZERO {
+            proceeds += redemption_proceeds;
+            nav *= keep;
+            base *= keep;
+            if variant.high_water_mark == HighWaterMarkPolicy::ProportionalLossCarryforward {
+                loss_cf *= keep;
+            }
+        }
+        // The open accrual belongs to the retained holding
+        net_nav = nav - accrued;
+
+        periods.push(PerformanceFeePeriod {
+            period: i as u32 + 1,
+            gross_return: *r,
+            hurdle_return,
+            performance_fee: fee,
+            crystallized: share > Decimal::ZERO,
+            loss_carryforward: loss_cf,
+            redemption_proceeds,
+            nav: net_nav,
+        });
+    }
+
+    PerformanceFeeVariantResult {
+        name: variant.name.clone(),
+        total_performance_fees: total_perf,
+        total_management_fees: total_mgmt,
+        crystallization_count,
+        lp_proceeds: proceeds,
+        lp_net_total_return: net_growth - Decimal::ONE,
+        lp_net_annualized_return: annualize(net_growth, years),
+        fee_drag: Decimal::ZERO,
+        periods,
+    }
+}
+
+/// Fee on a crystallised gain, and the loss carryforward left afterwards.
+fn assess_performance_fee(
+    variant: &PerformanceFeeVariant,
+    gain: Money,
+    hurdle_amount: Money,
+    loss_cf: Money,
+) -> (Money, Money) {
+    let chargeable = gain - loss_cf;
+    if chargeable <= Decimal::ZERO {
+        return (Decimal::ZERO, -chargeable);
+    }
+    let fee_base = match variant.hurdle_style {
+        HurdleStyle::Hard => (chargeable - hurdle_amount).max(Decimal::ZERO),
+        HurdleStyle::Soft if chargeable > hurdle_amount => chargeable,
+        HurdleStyle::Soft => Decimal::ZERO,
+    };
+    (fee_base * variant.performance_fee_rate, Decimal::ZERO)
+}
+
+fn annualize(growth: Decimal, years: Decimal) -> Rate {
+    if growth <= Decimal::ZERO || years.is_zero() {
+        return growth - Decimal::ONE;
+    }
+    growth.powd(Decimal::ONE / years) - Decimal::ONE
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -843,4 +1254,236 @@ mod tests {
             other => panic!("Expected InvalidInput for fund_life_years, got: {other}"),
         }
     }
+
+    // ------------------------------------------------------------------
+    // Performance fee variants
+    // ------------------------------------------------------------------
+
+    fn variant(
+        hurdle: HurdleBasis,
+        hurdle_style: HurdleStyle,
+        high_water_mark: HighWaterMarkPolicy,
+        crystallization: CrystallizationFrequency,
+    ) -> PerformanceFeeVariant {
+        PerformanceFeeVariant {
+            name: format!(
+                "{:?}/{:?}/{:?}",
+                hurdle_style, high_water_mark, crystallization
+            ),
+            performance_fee_rate: dec!(0.20),
+            hurdle,
+            hurdle_style,
+            high_water_mark,
+            crystallization,
+        }
+    }
+
+    fn fee_input(returns: Vec<Rate>, variants: Vec<PerformanceFeeVariant>) -> PerformanceFeeInput {
+        PerformanceFeeInput {
+            initial_investment: dec!(100),
+            periods_per_year: 1,
+            gross_returns: returns,
+            benchmark_returns: vec![],
+            redemptions: vec![],
+            management_fee_rate: Decimal::ZERO,
+            variants,
+            currency: Some(Currency::USD),
+        }
+    }
+
+    fn fees(out: &PerformanceFeeOutput) -> Vec<Money> {
+        out.variants
+            .iter()
+            .map(|v| v.total_performance_fees.round_dp(8))
+            .collect()
+    }
+
+    #[test]
+    fn test_hard_vs_soft_hurdle() {
+        let fixed = || HurdleBasis::Fixed {
+            annual_rate: dec!(0.08),
+        };
+        let input = fee_input(
+            vec![dec!(0.10)],
+            vec![
+                variant(
+                    fixed(),
+                    HurdleStyle::Hard,
+                    HighWaterMarkPolicy::Standard,
+                    CrystallizationFrequency::Annual,
+                ),
+                variant(
+                    fixed(),
+                    HurdleStyle::Soft,
+                    HighWaterMarkPolicy::Standard,
+                    CrystallizationFrequency::Annual,
+                ),
+            ],
+        );
+        let out = compare_performance_fee_variants(&input).unwrap().result;
+        // Hard: 20% of (10 - 8); soft: 20% of the full 10 once 8 is cleared
+        assert_eq!(fees(&out), vec![dec!(0.4), dec!(2.0)]);
+        assert!(out.variants[0].lp_net_total_return > out.variants[1].lp_net_total_return);
+
+        let below = fee_input(vec![dec!(0.05)], input.variants.clone());
+        let out = compare_performance_fee_variants(&below).unwrap().result;
+        assert_eq!(fees(&out), vec![Decimal::ZERO, Decimal::ZERO]);
+    }
+
+    #[test]
+    fn test_benchmark_plus_spread_hurdle() {
+        let mut input = fee_input(
+            vec![dec!(0.10)],
+            vec![variant(
+                HurdleBasis::Benchmark { spread: dec!(0.02) },
+                HurdleStyle::Hard,
+                HighWaterMarkPolicy::Standard,
+                CrystallizationFrequency::Annual,
+            )],
+        );
+        input.benchmark_returns = vec![dec!(0.06)];
+        let out = compare_performance_fee_variants(&input).unwrap().result;
+        assert_eq!(out.variants[0].periods[0].hurdle_return, dec!(0.08));
+        assert_eq!(fees(&out), vec![dec!(0.4)]);
+
+        input.benchmark_returns.clear();
+        assert!(compare_performance_fee_variants(&input).is_err());
+    }
+
+    #[test]
+    fn test_high_water_mark_recoups_losses_first() {
+        let input = fee_input(
+            vec![dec!(-0.10), dec!(0.20)],
+            vec![
+                variant(
+                    HurdleBasis::None,
+                    HurdleStyle::Hard,
+                    HighWaterMarkPolicy::Standard,
+                    CrystallizationFrequency::Annual,
+                ),
+                variant(
+                    HurdleBasis::None,
+                    HurdleStyle::Hard,
+                    HighWaterMarkPolicy::None,
+                    CrystallizationFrequency::Annual,
+                ),
+            ],
+        );
+        let out = compare_performance_fee_variants(&input).unwrap().result;
+        // 90 -> 108: HWM charges on 8 above 100, no HWM on the full 18
+        assert_eq!(out.variants[0].periods[0].loss_carryforward, dec!(10));
+        assert_eq!(fees(&out), vec![dec!(1.6), dec!(3.6)]);
+    }
+
+    #[test]
+    fn test_proportional_loss_carryforward_on_redemption() {
+        let mut input = fee_input(
+            vec![dec!(-0.20), Decimal::ZERO, dec!(0.50)],
+            vec![
+                variant(
+                    HurdleBasis::None,
+                    HurdleStyle::Hard,
+                    HighWaterMarkPolicy::Standard,
+                    CrystallizationFrequency::Annual,
+                ),
+                variant(
+                    HurdleBasis::None,
+                    HurdleStyle::Hard,
+                    HighWaterMarkPolicy::ProportionalLossCarryforward,
+                    CrystallizationFrequency::Annual,
+                ),
+            ],
+        );
+        input.redemptions = vec![Decimal::ZERO, dec!(0.5)];
+        let out = compare_performance_fee_variants(&input).unwrap().result;
+
+        assert_eq!(out.variants[0].periods[1].loss_carryforward, dec!(20));
+        assert_eq!(out.variants[1].periods[1].loss_carryforward, dec!(10));
+        // 40 -> 60: standard still owes 20 of losses, proportional only 10
+        assert_eq!(fees(&out), vec![Decimal::ZERO, dec!(2.0)]);
+    }
+
+    #[test]
+    fn test_crystallization_frequency_impact() {
+        let mut input = fee_input(
+            vec![dec!(0.10), dec!(-0.10), dec!(0.10), dec!(-0.10)],
+            vec![
+                variant(
+                    HurdleBasis::None,
+                    HurdleStyle::Hard,
+                    HighWaterMarkPolicy::Standard,
+                    CrystallizationFrequency::Quarterly,
+                ),
+                variant(
+                    HurdleBasis::None,
+                    HurdleStyle::Hard,
+                    HighWaterMarkPolicy::Standard,
+                    CrystallizationFrequency::Annual,
+                ),
+                variant(
+                    HurdleBasis::None,
+                    HurdleStyle::Hard,
+                    HighWaterMarkPolicy::Standard,
+                    CrystallizationFrequency::OnRedemption,
+                ),
+            ],
+        );
+        input.periods_per_year = 4;
+        let result = compare_performance_fee_variants(&input).unwrap();
+        let out = result.result;
+
+        // Quarterly locks in the Q1 fee; the year ends below the start
+        assert_eq!(fees(&out), vec![dec!(2.0), Decimal::ZERO, Decimal::ZERO]);
+        assert_eq!(out.variants[0].crystallization_count, 4);
+        assert_eq!(out.variants[2].crystallization_count, 1);
+        assert!(out.variants[0].fee_drag > out.variants[1].fee_drag);
+        assert!(result.warnings.iter().any(|w| w.contains("on-redemption")));
+
+        input.periods_per_year = 2;
+        assert!(compare_performance_fee_variants(&input).is_err());
+    }
+
+    #[test]
+    fn test_redemption_crystallizes_fee_on_redeemed_slice() {
+        let mut input = fee_input(
+            vec![dec!(0.20), Decimal::ZERO],
+            vec![variant(
+                HurdleBasis::None,
+                HurdleStyle::Hard,
+                HighWaterMarkPolicy::Standard,
+                CrystallizationFrequency::OnRedemption,
+            )],
+        );
+        input.redemptions = vec![dec!(0.5)];
+        let out = compare_performance_fee_variants(&input).unwrap().result;
+        let v = &out.variants[0];
+
+        // Each half pays 20% of its 10 gain; the slice bears its own fee
+        assert_eq!(v.periods[0].performance_fee, dec!(2.0));
+        assert_eq!(v.periods[0].redemption_proceeds, dec!(58));
+        assert_eq!(v.total_performance_fees, dec!(4.0));
+        assert_eq!(v.lp_proceeds, dec!(116));
+        assert_eq!(v.lp_net_total_return.round_dp(8), dec!(0.16));
+    }
+
+    #[test]
+    fn test_management_fee_reduces_net_return() {
+        let mut input = fee_input(
+            vec![dec!(0.10)],
+            vec![variant(
+                HurdleBasis::None,
+                HurdleStyle::Hard,
+                HighWaterMarkPolicy::Standard,
+                CrystallizationFrequency::Annual,
+            )],
+        );
+        input.management_fee_rate = dec!(0.02);
+        let out = compare_performance_fee_variants(&input).unwrap().result;
+        let v = &out.variants[0];
+        // 110 less 2.2 management fee, then 20% of 7.8
+        assert_eq!(v.total_management_fees, dec!(2.2));
+        assert_eq!(v.total_performance_fees, dec!(1.56));
+        assert_eq!(v.lp_net_total_return, dec!(0.0624));
+        assert_eq!(out.gross_total_return, dec!(0.10));
+    }
 }
@@ -91,6 +91,134 @@ pub struct InvestorNetReturnsOutput {
     pub net_moic: Multiple,
 }
 
+/// A partner in the fund and its commitment terms.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PartnerCommitment {
+    pub partner_id: String,
+    pub name: String,
+    pub commitment: Money,
+    /// Annual management fee rate charged on commitment (reflects any
+    /// side-letter discount; zero for the GP commitment)
+    pub management_fee_rate: Rate,
+    /// Whether the partner's profits bear carried interest
+    #[serde(default = "default_true")]
+    pub carry_bearing: bool,
+    /// General partner: receives the carried interest allocated from
+    /// carry-bearing partners
+    #[serde(default)]
+    pub general_partner: bool,
+    /// Capital account balance at the start of the first period
+    #[serde(default)]
+    pub opening_balance: Money,
+    /// Capital contributed before the first period
+    #[serde(default)]
+    pub contributed_to_date: Money,
+}
+
+/// Distribution amounts by tax character.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DistributionsByCharacter {
+    #[serde(default)]
+    pub return_of_capital: Money,
+    #[serde(default)]
+    pub dividend_income: Money,
+    #[serde(default)]
+    pub interest_income: Money,
+    #[serde(default)]
+    pub capital_gain: Money,
+    #[serde(default)]
+    pub other: Money,
+}
+
+/// Fund-level activity for one reporting period, allocated to partners
+/// pro rata to commitment.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CapitalAccountPeriod {
+    pub label: String,
+    /// Length of the period in years (0.25 for a quarter), for fee accrual
+    pub period_years: Decimal,
+    /// Capital called from partners (excluding management fees, which are
+    /// charged per partner)
+    pub capital_called: Money,
+    #[serde(default)]
+    pub distributions: DistributionsByCharacter,
+    pub realized_gain_loss: Money,
+    pub unrealized_gain_loss: Money,
+    /// Fund operating expenses and organisational costs
+    pub fund_expenses: Money,
+    /// Change in accrued carried interest at fund level (negative on reversal)
+    pub carried_interest: Money,
+}
+
+/// Input for per-partner capital account statements.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CapitalAccountInput {
+    pub fund_name: String,
+    pub partners: Vec<PartnerCommitment>,
+    pub periods: Vec<CapitalAccountPeriod>,
+    /// Management fees are called from partners alongside capital calls
+    #[serde(default = "default_true")]
+    pub fees_funded_by_calls: bool,
+    pub currency: Option<Currency>,
+}
+
+/// One period of a partner's capital account roll-forward.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CapitalAccountLine {
+    pub period: String,
+    pub beginning_balance: Money,
+    pub contributions: Money,
+    pub distributions: DistributionsByCharacter,
+    pub total_distributions: Money,
+    pub management_fees: Money,
+    pub fund_expenses: Money,
+    pub realized_gain_loss: Money,
+    pub unrealized_gain_loss: Money,
+    /// Carried interest charged (negative) or, for the GP, allocated (positive)
+    pub carried_interest: Money,
+    pub ending_balance: Money,
+    pub contributed_to_date: Money,
+    pub distributed_to_date: Money,
+    pub unfunded_commitment: Money,
+}
+
+/// Quarterly capital account statement data for one partner.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PartnerCapitalAccount {
+    pub partner_id: String,
+    pub name: String,
+    pub commitment: Money,
+    pub ownership_pct: Rate,
+    pub lines: Vec<CapitalAccountLine>,
+    pub ending_balance: Money,
+    /// Distributions / contributions
+    pub dpi: Multiple,
+    /// Ending balance / contributions
+    pub rvpi: Multiple,
+    pub tvpi: Multiple,
+}
+
+/// Fund-level totals per period, reconciling the partner accounts.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FundCapitalSummary {
+    pub period: String,
+    pub total_contributions: Money,
+    pub total_distributions: Money,
+    pub total_management_fees: Money,
+    pub ending_partners_capital: Money,
+}
+
+/// Output of capital account statements.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CapitalAccountOutput {
+    pub partners: Vec<PartnerCapitalAccount>,
+    pub fund_summary: Vec<FundCapitalSummary>,
+}
+
+fn default_true() -> bool {
+    true
+}
+
 // ---------------------------------------------------------------------------
 // Main calculation
 // ---------------------------------------------------------------------------
@@ -265,6 +393,169 @@ pub fn calculate_investor_net_returns(
     ))
 }
 
+/// Build per-partner capital account statements over a sequence of periods.
+///
+/// Fund-level calls, distributions (by character), realised and unrealised
+/// gains and fund expenses are allocated pro rata to commitment. Management
+/// fees are charged per partner at its own rate. Carried interest accrued at
+/// fund level is charged to carry-bearing partners pro rata and credited to
+/// the general partner(s), so the allocation nets to zero across partners.
+pub fn build_capital_account_statements(
+    input: &CapitalAccountInput,
+) -> CorpFinanceResult<ComputationOutput<CapitalAccountOutput>> {
+    let start = Instant::now();
+    let mut warnings: Vec<String> = Vec::new();
+
+    validate_capital_account_input(input)?;
+
+    let total_commitment: Money = input.partners.iter().map(|p| p.commitment).sum();
+    let carry_commitment: Money = input
+        .partners
+        .iter()
+        .filter(|p| p.carry_bearing && !p.general_partner)
+        .map(|p| p.commitment)
+        .sum();
+    let gp_commitment: Money = input
+        .partners
+        .iter()
+        .filter(|p| p.general_partner)
+        .map(|p| p.commitment)
+        .sum();
+    if gp_commitment.is_zero() && input.periods.iter().any(|p| !p.carried_interest.is_zero()) {
+        warnings.push(
+            "No general partner defined; carried interest is charged but not credited".into(),
+        );
+    }
+
+    let mut partners: Vec<PartnerCapitalAccount> = Vec::with_capacity(input.partners.len());
+    for partner in &input.partners {
+        let share = partner.commitment / total_commitment;
+        let mut balance = partner.opening_balance;
+        let mut contributed = partner.contributed_to_date;
+        let mut distributed = Decimal::ZERO;
+        let mut lines = Vec::with_capacity(input.periods.len());
+
+        for period in &input.periods {
+            let management_fees =
+                partner.commitment * partner.management_fee_rate * period.period_years;
+            let mut contributions = period.capital_called * share;
+            if input.fees_funded_by_calls {
+                contributions += management_fees;
+            }
+            let d = &period.distributions;
+            let distributions = DistributionsByCharacter {
+                return_of_capital: d.return_of_capital * share,
+                dividend_income: d.dividend_income * share,
+                interest_income: d.interest_income * share,
+                capital_gain: d.capital_gain * share,
+                other: d.other * share,
+            };
+            let total_distributions = distribution_total(&distributions);
+            let fund_expenses = period.fund_expenses * share;
+            let realized_gain_loss = period.realized_gain_loss * share;
+            let unrealized_gain_loss = period.unrealized_gain_loss * share;
+            let carried_interest = if partner.general_partner {
+                period.carried_interest * partner.commitment / gp_commitment
+            } else if partner.carry_bearing && !carry_commitment.is_zero() {
+                -period.carried_interest * partner.commitment / carry_commitment
+            } else {
+                Decimal::ZERO
+            };
+
+            let beginning_balance = balance;
+            balance = beginning_balance + contributions
+                - total_distributions
+                - management_fees
+                - fund_expenses
+                + realized_gain_loss
+                + unrealized_gain_loss
+                + carried_interest;
+            contributed += contributions;
+            distributed += total_distributions;
+
+            lines.push(CapitalAccountLine {
+                period: period.label.clone(),
+                beginning_balance,
+                contributions,
+                distributions,
+                total_distributions,
+                management_fees,
+                fund_expenses,
+                realized_gain_loss,
+                unrealized_gain_loss,
+                carried_interest,
+                ending_balance: balance,
+                contributed_to_date: contributed,
+                distributed_to_date: distributed,
+                unfunded_commitment: partner.commitment - contributed,
+            });
+        }
+
+        if contributed > partner.commitment {
+            warnings.push(format!(
+                "{}: contributions of {} exceed commitment of {}",
+                partner.name, contributed, partner.commitment
+            ));
+        }
+        if balance < Decimal::ZERO {
+            warnings.push(format!("{}: negative ending capital account", partner.name));
+        }
+
+        let (dpi, rvpi) = if contributed > Decimal::ZERO {
+            (distributed / contributed, balance / contributed)
+        } else {
+            (Decimal::ZERO, Decimal::ZERO)
+        };
+        partners.push(PartnerCapitalAccount {
+            partner_id: partner.partner_id.clone(),
+            name: partner.name.clone(),
+            commitment: partner.commitment,
+            ownership_pct: share,
+            lines,
+            ending_balance: balance,
+            dpi,
+            rvpi,
+            tvpi: dpi + rvpi,
+        });
+    }
+
+    let fund_summary = input
+        .periods
+        .iter()
+        .enumerate()
+        .map(|(i, period)| FundCapitalSummary {
+            period: period.label.clone(),
+            total_contributions: partners.iter().map(|p| p.lines[i].contributions).sum(),
+            total_distributions: partners
+                .iter()
+                .map(|p| p.lines[i].total_distributions)
+                .sum(),
+            total_management_fees: partners.iter().map(|p| p.lines[i].management_fees).sum(),
+            ending_partners_capital: partners.iter().map(|p| p.lines[i].ending_balance).sum(),
+        })
+        .collect();
+
+    let output = CapitalAccountOutput {
+        partners,
+        fund_summary,
+    };
+
+    let elapsed = start.elapsed().as_micros() as u64;
+    Ok(with_metadata(
+        "Partner Capital Account Statements: pro rata allocation with partner-level fees and carry",
+        &serde_json::json!({
+            "fund_name": input.fund_name,
+            "partners": input.partners.len(),
+            "periods": input.periods.len(),
+            "total_commitment": total_commitment.to_string(),
+            "fees_funded_by_calls": input.fees_funded_by_calls,
+        }),
+        warnings,
+        elapsed,
+        output,
+    ))
+}
+
 // ---------------------------------------------------------------------------
 // Helpers
 // ---------------------------------------------------------------------------
@@ -507,6 +798,61 @@ fn build_cost_breakdown(input: &InvestorNetReturnsInput, drags: &CostDrags) -> V
     layers
 }
 
+fn distribution_total(d: &DistributionsByCharacter) -> Money {
+    d.return_of_capital + d.dividend_income + d.interest_income + d.capital_gain + d.other
+}
+
+fn validate_capital_account_input(input: &CapitalAccountInput) -> CorpFinanceResult<()> {
+    if input.partners.is_empty() {
+        return Err(CorpFinanceError::InsufficientData(
+            "At least one partner is required".into(),
+        ));
+    }
+    if input.periods.is_empty() {
+        return Err(CorpFinanceError::InsufficientData(
+            "At least one period is required".into(),
+        ));
+    }
+    for (i, p) in input.partners.iter().enumerate() {
+        if p.commitment <= Decimal::ZERO {
+            return Err(CorpFinanceError::InvalidInput {
+                field: format!("partners[{i}].commitment"),
+                reason: "Commitment must be positive".into(),
+            });
+        }
+        if p.management_fee_rate < Decimal::ZERO {
+            return Err(CorpFinanceError::InvalidInput {
+                field: format!("partners[{i}].management_fee_rate"),
+                reason: "Fee rate cannot be negative".into(),
+            });
+        }
+        if input.partners[..i]
+            .iter()
+            .any(|q| q.partner_id == p.partner_id)
+        {
+            return Err(CorpFinanceError::InvalidInput {
+                field: format!("partners[{i}].partner_id"),
+                reason: format!("Duplicate partner id '{}'", p.partner_id),
+            });
+        }
+    }
+    for (i, p) in input.periods.iter().enumerate() {
+        if p.period_years <= Decimal::ZERO {
+            return Err(CorpFinanceError::InvalidInput {
+                field: format!("periods[{i}].period_years"),
+                reason: "Period length must be positive".into(),
+            });
+        }
+        if p.capital_called < Decimal::ZERO {
+            return Err(CorpFinanceError::InvalidInput {
+                field: format!("periods[{i}].capital_called"),
+                reason: "Capital called cannot be negative".into(),
+            });
+        }
+    }
+    Ok(())
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -888,4 +1234,225 @@ mod tests {
             expected_gross
         );
     }
+
+    // ------------------------------------------------------------------
+    // Capital account statements
+    // ------------------------------------------------------------------
+
+    fn partner(id: &str, commitment: Decimal, fee: Decimal) -> PartnerCommitment {
+        PartnerCommitment {
+            partner_id: id.into(),
+            name: id.into(),
+            commitment,
+            management_fee_rate: fee,
+            carry_bearing: true,
+            general_partner: false,
+            opening_balance: Decimal::ZERO,
+            contributed_to_date: Decimal::ZERO,
+        }
+    }
+
+    fn quarter(label: &str, called: Decimal, unrealized: Decimal) -> CapitalAccountPeriod {
+        CapitalAccountPeriod {
+            label: label.into(),
+            period_years: dec!(0.25),
+            capital_called: called,
+            distributions: DistributionsByCharacter::default(),
+            realized_gain_loss: Decimal::ZERO,
+            unrealized_gain_loss: unrealized,
+            fund_expenses: Decimal::ZERO,
+            carried_interest: Decimal::ZERO,
+        }
+    }
+
+    fn capital_input() -> CapitalAccountInput {
+        let mut gp = partner("GP", dec!(20), Decimal::ZERO);
+        gp.carry_bearing = false;
+        gp.general_partner = true;
+        CapitalAccountInput {
+            fund_name: "Fund I".into(),
+            partners: vec![
+                partner("LP1", dec!(600), dec!(0.02)),
+                partner("LP2", dec!(380), dec!(0.015)),
+                gp,
+            ],
+            periods: vec![
+                quarter("Q1", dec!(200), Decimal::ZERO),
+                quarter("Q2", dec!(100), dec!(50)),
+            ],
+            fees_funded_by_calls: true,
+            currency: Some(Currency::USD),
+        }
+    }
+
+    // ------------------------------------------------------------------
+    // Test 18: Calls allocated pro rata, fees per partner
+    // ------------------------------------------------------------------
+    #[test]
+    fn test_capital_account_contributions_and_fees() {
+        let result = build_capital_account_statements(&capital_input()).unwrap();
+        let lp1 = &result.result.partners[0];
+        let q1 = &lp1.lines[0];
+        // 60% of 200 called + 600 x 2% x 0.25 fee
+        assert_eq!(q1.management_fees, dec!(3));
+        assert_eq!(q1.contributions, dec!(123));
+        // Fee is funded by the call, so the balance reflects the call only
+        assert_eq!(q1.ending_balance, dec!(120));
+        assert_eq!(lp1.ownership_pct, dec!(0.6));
+
+        let lp2 = &result.result.partners[1];
+        assert_eq!(lp2.lines[0].management_fees, dec!(1.425));
+    }
+
+    // ------------------------------------------------------------------
+    // Test 19: Roll-forward ties period to period
+    // ------------------------------------------------------------------
+    #[test]
+    fn test_capital_account_roll_forward() {
+        let result = build_capital_account_statements(&capital_input()).unwrap();
+        for p in &result.result.partners {
+            for w in p.lines.windows(2) {
+                assert_eq!(w[1].beginning_balance, w[0].ending_balance);
+            }
+            let q2 = &p.lines[1];
+            assert_eq!(
+                q2.ending_balance,
+                q2.beginning_balance + q2.contributions
+                    - q2.total_distributions
+                    - q2.management_fees
+                    - q2.fund_expenses
+                    + q2.realized_gain_loss
+                    + q2.unrealized_gain_loss
+                    + q2.carried_interest
+            );
+        }
+        // LP1: 60% of the 50 unrealised gain
+        assert_eq!(
+            result.result.partners[0].lines[1].unrealized_gain_loss,
+            dec!(30)
+        );
+    }
+
+    // ------------------------------------------------------------------
+    // Test 20: Distributions by character
+    // ------------------------------------------------------------------
+    #[test]
+    fn test_capital_account_distribution_character() {
+        let mut input = capital_input();
+        input.periods[1].distributions = DistributionsByCharacter {
+            return_of_capital: dec!(50),
+            dividend_income: dec!(10),
+            interest_income: dec!(5),
+            capital_gain: dec!(35),
+            other: Decimal::ZERO,
+        };
+        let result = build_capital_account_statements(&input).unwrap();
+        let lp1 = &result.result.partners[0].lines[1];
+        assert_eq!(lp1.distributions.return_of_capital, dec!(30));
+        assert_eq!(lp1.distributions.capital_gain, dec!(21));
+        assert_eq!(lp1.total_distributions, dec!(60));
+        assert_eq!(lp1.distributed_to_date, dec!(60));
+        assert_eq!(result.result.fund_summary[1].total_distributions, dec!(100));
+    }
+
+    // ------------------------------------------------------------------
+    // Test 21: Carry charged to LPs and credited to the GP
+    // ------------------------------------------------------------------
+    #[test]
+    fn test_capital_account_carry_allocation() {
+        let mut input = capital_input();
+        input.periods[1].carried_interest = dec!(9.8);
+        let result = build_capital_account_statements(&input).unwrap();
+        let out = &result.result;
+        // Carry-bearing commitments: 600 + 380 = 980
+        assert_eq!(out.partners[0].lines[1].carried_interest, dec!(-6));
+        assert_eq!(out.partners[1].lines[1].carried_interest, dec!(-3.8));
+        assert_eq!(out.partners[2].lines[1].carried_interest, dec!(9.8));
+        let net: Decimal = out
+            .partners
+            .iter()
+            .map(|p| p.lines[1].carried_interest)
+            .sum();
+        assert_eq!(net, Decimal::ZERO);
+    }
+
+    // ------------------------------------------------------------------
+    // Test 22: Unfunded commitment and multiples
+    // ------------------------------------------------------------------
+    #[test]
+    fn test_capital_account_unfunded_and_multiples() {
+        let mut input = capital_input();
+        input.periods[1].distributions.capital_gain = dec!(100);
+        let result = build_capital_account_statements(&input).unwrap();
+        let gp = &result.result.partners[2];
+        // GP: 2% of 300 called, no fee
+        assert_eq!(gp.lines[1].contributed_to_date, dec!(6));
+        assert_eq!(gp.lines[1].unfunded_commitment, dec!(14));
+        assert_eq!(gp.dpi, dec!(2) / dec!(6));
+        assert_eq!(gp.tvpi, gp.dpi + gp.rvpi);
+    }
+
+    // ------------------------------------------------------------------
+    // Test 23: Fund summary reconciles partner accounts
+    // ------------------------------------------------------------------
+    #[test]
+    fn test_capital_account_fund_summary() {
+        let result = build_capital_account_statements(&capital_input()).unwrap();
+        let out = &result.result;
+        let q1 = &out.fund_summary[0];
+        let fees = dec!(3) + dec!(1.425);
+        assert_eq!(q1.total_management_fees, fees);
+        assert_eq!(q1.total_contributions, dec!(200) + fees);
+        assert_eq!(q1.ending_partners_capital, dec!(200));
+        assert_eq!(out.fund_summary[1].ending_partners_capital, dec!(350));
+    }
+
+    // ------------------------------------------------------------------
+    // Test 24: Fees not funded by calls reduce capital
+    // ------------------------------------------------------------------
+    #[test]
+    fn test_capital_account_fees_from_capital() {
+        let mut input = capital_input();
+        input.fees_funded_by_calls = false;
+        let result = build_capital_account_statements(&input).unwrap();
+        let q1 = &result.result.partners[0].lines[0];
+        assert_eq!(q1.contributions, dec!(120));
+        assert_eq!(q1.ending_balance, dec!(117));
+    }
+
+    // ------------------------------------------------------------------
+    // Test 25: Over-called commitment warns
+    // ------------------------------------------------------------------
+    #[test]
+    fn test_capital_account_overcall_warning() {
+        let mut input = capital_input();
+        input.periods[1].capital_called = dec!(900);
+        let result = build_capital_account_statements(&input).unwrap();
+        assert!(result
+            .warnings
+            .iter()
+            .any(|w| w.contains("exceed commitment")));
+    }
+
+    // ------------------------------------------------------------------
+    // Test 26: Invalid capital account inputs
+    // ------------------------------------------------------------------
+    #[test]
+    fn test_capital_account_invalid_inputs() {
+        let mut input = capital_input();
+        input.partners[1].partner_id = "LP1".into();
+        assert!(build_capital_account_statements(&input).is_err());
+
+        let mut input = capital_input();
+        input.partners[0].commitment = Decimal::ZERO;
+        assert!(build_capital_account_statements(&input).is_err());
+
+        let mut input = capital_input();
+        input.periods[0].period_years = Decimal::ZERO;
+        assert!(build_capital_account_statements(&input).is_err());
+
+        let mut input = capital_input();
+        input.periods.clear();
+        assert!(build_capital_account_statements(&input).is_err());
+    }
 }
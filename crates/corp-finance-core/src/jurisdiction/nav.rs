@@ -7,6 +7,16 @@ use crate::error::CorpFinanceError;
 use crate::types::*;
 use crate::CorpFinanceResult;
 
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+const BPS: Decimal = dec!(10000);
+
+/// Hedge ratio range regulators expect for currency-hedged share classes
+const HEDGE_RATIO_FLOOR: Decimal = dec!(0.95);
+const HEDGE_RATIO_CAP: Decimal = dec!(1.05);
+
 // ---------------------------------------------------------------------------
 // Types
 // ---------------------------------------------------------------------------
@@ -56,6 +66,39 @@ pub struct Redemption {
     pub nav_per_share_at_exit: Money,
 }
 
+/// How much of a hedged class's NAV is covered by the currency forward.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum HedgeRatioPolicy {
+    /// Hedge 100% of opening class NAV
+    Full,
+    /// Hedge a fixed proportion of opening class NAV
+    Fixed { ratio: Rate },
+    /// Leave the current ratio in place while inside the band; otherwise
+    /// rebalance back to target
+    Band {
+        current_ratio: Rate,
+        target_ratio: Rate,
+        lower: Rate,
+        upper: Rate,
+    },
+}
+
+/// Class-level currency hedge. FX rates are quoted as units of class
+/// currency per one unit of fund base currency.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShareClassHedge {
+    /// Spot rate at the start of the period (hedge inception)
+    pub spot_rate_start: Decimal,
+    /// Spot rate at the end of the period (hedge settlement)
+    pub spot_rate_end: Decimal,
+    /// Forward points over the period (forward = spot_start + forward_points)
+    pub forward_points: Decimal,
+    pub hedge_ratio_policy: HedgeRatioPolicy,
+    /// Dealing / roll cost in basis points of hedged notional
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub transaction_cost_bps: Option<Decimal>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ShareClassInput {
     pub class_name: String,
@@ -69,6 +112,9 @@ pub struct ShareClassInput {
     pub crystallisation_frequency: CrystallisationFrequency,
     pub fx_rate_to_base: Option<Decimal>,
     pub fx_hedging_cost: Option<Rate>,
+    /// Currency hedge overlay; hedging P&L and costs accrue to this class only
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hedging: Option<ShareClassHedge>,
     pub subscriptions: Vec<Subscription>,
     pub redemptions: Vec<Redemption>,
 }
@@ -80,6 +126,13 @@ pub struct NavInput {
     pub period_label: String,
     pub equalisation_method: EqualisationMethod,
     pub base_currency: Currency,
+    /// Spot FX rates used for classes without an explicit `fx_rate_to_base`
+    #[serde(default)]
+    pub fx_rates: Vec<FxRate>,
+    /// Reference class for divergence reporting (default: first unhedged
+    /// class in the base currency)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base_class: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -95,8 +148,51 @@ pub struct ShareClassNavOutput {
     pub hwm_distance: Rate,
     pub shares_outstanding: Decimal,
     pub class_total_nav: Money,
+    /// Class total NAV converted into the fund base currency
+    pub class_total_nav_base: Money,
     pub gross_return: Rate,
     pub net_return: Rate,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hedging: Option<ShareClassHedgeOutput>,
+}
+
+/// Per-share hedging result for a hedged class, in class currency.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShareClassHedgeOutput {
+    /// Hedge ratio applied this period
+    pub hedge_ratio: Rate,
+    /// True if a band policy breached and was reset to target
+    pub rebalanced: bool,
+    /// Hedged notional in base currency (class total)
+    pub hedge_notional_base: Money,
+    pub forward_rate: Decimal,
+    /// Gross return in class currency without the hedge
+    pub unhedged_gross_return: Rate,
+    /// Spot component of the forward P&L per share
+    pub spot_pnl_per_share: Money,
+    /// Forward-points carry per share (negative = cost)
+    pub carry_per_share: Money,
+    pub transaction_cost_per_share: Money,
+    /// Spot + carry - transaction costs, per share
+    pub net_hedge_pnl_per_share: Money,
+    /// Net hedge P&L for the whole class
+    pub class_hedge_pnl: Money,
+}
+
+/// Return divergence of a class versus the reference (base) class.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClassDivergence {
+    pub class_name: String,
+    pub net_return: Rate,
+    pub base_class_net_return: Rate,
+    /// net_return - base_class_net_return
+    pub divergence: Rate,
+    /// Portion explained by unhedged currency translation
+    pub currency_contribution: Rate,
+    /// Portion explained by hedge P&L net of costs
+    pub hedging_contribution: Rate,
+    /// Residual: fee, hurdle and HWM differences between classes
+    pub fee_and_other: Rate,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -107,6 +203,10 @@ pub struct NavOutput {
     pub base_currency: Currency,
     pub equalisation_method: EqualisationMethod,
     pub equalisation_adjustments: Vec<EqualisationAdjustment>,
+    /// Reference class used for divergence reporting, if one was found
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub base_class: Option<String>,
+    pub class_divergence: Vec<ClassDivergence>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -146,8 +246,30 @@ pub fn calculate_nav(input: &NavInput) -> CorpFinanceResult<ComputationOutput<Na
     for sc in &input.share_classes {
         let period_frac = sc.crystallisation_frequency.period_fraction();
 
-        // -- Step 1: Gross NAV per share --
-        let gross_nav_ps = sc.nav_per_share_opening * (Decimal::ONE + input.gross_portfolio_return);
+        // -- Step 1: Gross NAV per share (hedged classes carry their own overlay) --
+        let (gross_nav_ps, hedge_result) = match &sc.hedging {
+            Some(hedge) => {
+                let (nav_ps, mut out) = apply_class_hedge(
+                    sc.nav_per_share_opening,
+                    input.gross_portfolio_return,
+                    hedge,
+                );
+                if out.hedge_ratio < HEDGE_RATIO_FLOOR || out.hedge_ratio > HEDGE_RATIO_CAP {
+                    warnings.push(format!(
+                        "Share class '{}': hedge ratio {} outside the 95%-105% range expected for hedged classes",
+                        sc.class_name, out.hedge_ratio
+                    ));
+                }
+                // Notional scales with the class; the overlay above is per share
+                out.hedge_notional_base *= sc.shares_outstanding;
+                out.class_hedge_pnl = out.net_hedge_pnl_per_share * sc.shares_outstanding;
+                (nav_ps, Some(out))
+            }
+            Option::None => (
+                sc.nav_per_share_opening * (Decimal::ONE + input.gross_portfolio_return),
+                None,
+            ),
+        };
 
         // -- Step 2: Process subscriptions (increase shares outstanding) --
         let subscription_shares: Decimal = sc.subscriptions.iter().map(|s| s.shares_issued).sum();
@@ -230,19 +352,37 @@ pub fn calculate_nav(input: &NavInput) -> CorpFinanceResult<ComputationOutput<Na
             hwm_distance,
             shares_outstanding,
             class_total_nav,
+            class_total_nav_base: class_total_nav,
             gross_return,
             net_return,
+            hedging: hedge_result,
         });
     }
 
     // ------------------------------------------------------------------
     // 3. Total fund NAV in base currency
     // ------------------------------------------------------------------
-    let total_fund_nav =
-        calculate_total_fund_nav(&class_outputs, &input.share_classes, &mut warnings);
+    let total_fund_nav = calculate_total_fund_nav(
+        &mut class_outputs,
+        &input.share_classes,
+        &input.base_currency,
+        &input.fx_rates,
+        &mut warnings,
+    );
+
+    // ------------------------------------------------------------------
+    // 4. Class divergence versus the base class
+    // ------------------------------------------------------------------
+    let base_class = select_base_class(input, &mut warnings);
+    let class_divergence = match &base_class {
+        Some(name) => {
+            calculate_class_divergence(&class_outputs, name, input.gross_portfolio_return)
+        }
+        Option::None => Vec::new(),
+    };
 
     // ------------------------------------------------------------------
-    // 4. Assemble output
+    // 5. Assemble output
     // ------------------------------------------------------------------
     let output = NavOutput {
         period_label: input.period_label.clone(),
@@ -251,6 +391,8 @@ pub fn calculate_nav(input: &NavInput) -> CorpFinanceResult<ComputationOutput<Na
         base_currency: input.base_currency.clone(),
         equalisation_method: input.equalisation_method.clone(),
         equalisation_adjustments,
+        base_class,
+        class_divergence,
     };
 
     let elapsed = start.elapsed().as_micros() as u64;
@@ -327,11 +469,204 @@ fn validate_nav_input(input: &NavInput) -> CorpFinanceResult<()> {
                 ),
             });
         }
+        if let Some(hedge) = &sc.hedging {
+            validate_class_hedge(&sc.class_name, hedge)?;
+        }
     }
 
+    if let Some(name) = &input.base_class {
+        if !input.share_classes.iter().any(|sc| &sc.class_name == name) {
+            return Err(CorpFinanceError::InvalidInput {
+                field: "base_class".into(),
+                reason: format!("Base class '{}' is not one of the share classes", name),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+fn validate_class_hedge(class_name: &str, hedge: &ShareClassHedge) -> CorpFinanceResult<()> {
+    if hedge.spot_rate_start <= Decimal::ZERO || hedge.spot_rate_end <= Decimal::ZERO {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "hedging".into(),
+            reason: format!("Share class '{}': spot rates must be > 0", class_name),
+        });
+    }
+    if hedge.spot_rate_start + hedge.forward_points <= Decimal::ZERO {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "hedging.forward_points".into(),
+            reason: format!(
+                "Share class '{}': forward rate (spot + points) must be > 0",
+                class_name
+            ),
+        });
+    }
+    let ratios: Vec<Rate> = match &hedge.hedge_ratio_policy {
+        HedgeRatioPolicy::Full => vec![],
+        HedgeRatioPolicy::Fixed { ratio } => vec![*ratio],
+        HedgeRatioPolicy::Band {
+            current_ratio,
+            target_ratio,
+            lower,
+            upper,
+        } => {
+            if lower > target_ratio || target_ratio > upper {
+                return Err(CorpFinanceError::InvalidInput {
+                    field: "hedging.hedge_ratio_policy".into(),
+                    reason: format!(
+                        "Share class '{}': band must satisfy lower <= target <= upper",
+                        class_name
+                    ),
+                });
+            }
+            vec![*current_ratio, *target_ratio, *lower, *upper]
+        }
+    };
+    if ratios.iter().any(|r| *r < Decimal::ZERO || *r > dec!(2)) {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "hedging.hedge_ratio_policy".into(),
+            reason: format!(
+                "Share class '{}': hedge ratios must be in [0, 2]",
+                class_name
+            ),
+        });
+    }
+    if hedge
+        .transaction_cost_bps
+        .is_some_and(|c| c < Decimal::ZERO)
+    {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "hedging.transaction_cost_bps".into(),
+            reason: format!(
+                "Share class '{}': transaction cost must be >= 0",
+                class_name
+            ),
+        });
+    }
     Ok(())
 }
 
+/// Apply a currency hedge overlay to one share of a hedged class.
+///
+/// The class's base-currency exposure per share is `opening / spot_start`.
+/// A forward selling `ratio` of that exposure at `spot_start + points`
+/// settles at `spot_end`; its P&L and dealing costs are added to the
+/// unhedged class NAV. Notional and class P&L are per share here and scaled
+/// by the caller.
+fn apply_class_hedge(
+    opening_nav_ps: Money,
+    portfolio_return: Rate,
+    hedge: &ShareClassHedge,
+) -> (Money, ShareClassHedgeOutput) {
+    let (hedge_ratio, rebalanced) = match &hedge.hedge_ratio_policy {
+        HedgeRatioPolicy::Full => (Decimal::ONE, false),
+        HedgeRatioPolicy::Fixed { ratio } => (*ratio, false),
+        HedgeRatioPolicy::Band {
+            current_ratio,
+            target_ratio,
+            lower,
+            upper,
+        } => {
+            if current_ratio < lower || current_ratio > upper {
+                (*target_ratio, true)
+            } else {
+                (*current_ratio, false)
+            }
+        }
+    };
+
+    let s0 = hedge.spot_rate_start;
+    let s1 = hedge.spot_rate_end;
+    let forward_rate = s0 + hedge.forward_points;
+
+    let base_exposure_ps = opening_nav_ps / s0;
+    let notional_base_ps = base_exposure_ps * hedge_ratio;
+
+    let unhedged_nav_ps = base_exposure_ps * (Decimal::ONE + portfolio_return) * s1;
+    let unhedged_gross_return = unhedged_nav_ps / opening_nav_ps - Decimal::ONE;
+
+    let spot_pnl_ps = notional_base_ps * (s0 - s1);
+    let carry_ps = notional_base_ps * hedge.forward_points;
+    let tx_cost_ps =
+        notional_base_ps * s0 * hedge.transaction_cost_bps.unwrap_or(Decimal::ZERO) / BPS;
+    let net_hedge_pnl_ps = spot_pnl_ps + carry_ps - tx_cost_ps;
+
+    (
+        unhedged_nav_ps + net_hedge_pnl_ps,
+        ShareClassHedgeOutput {
+            hedge_ratio,
+            rebalanced,
+            hedge_notional_base: notional_base_ps,
+            forward_rate,
+            unhedged_gross_return,
+            spot_pnl_per_share: spot_pnl_ps,
+            carry_per_share: carry_ps,
+            transaction_cost_per_share: tx_cost_ps,
+            net_hedge_pnl_per_share: net_hedge_pnl_ps,
+            class_hedge_pnl: net_hedge_pnl_ps,
+        },
+    )
+}
+
+/// Pick the reference class: the explicit `base_class`, else the first
+/// unhedged class denominated in the base currency.
+fn select_base_class(input: &NavInput, warnings: &mut Vec<String>) -> Option<String> {
+    if let Some(name) = &input.base_class {
+        return Some(name.clone());
+    }
+    let found = input
+        .share_classes
+        .iter()
+        .find(|sc| sc.currency == input.base_currency && sc.hedging.is_none())
+        .map(|sc| sc.class_name.clone());
+    if found.is_none() && input.share_classes.iter().any(|sc| sc.hedging.is_some()) {
+        warnings
+            .push("No unhedged base-currency class found; class divergence not reported".into());
+    }
+    found
+}
+
+/// Decompose each class's net return divergence from the base class into
+/// currency translation, hedging and fee/other components.
+fn calculate_class_divergence(
+    class_outputs: &[ShareClassNavOutput],
+    base_class: &str,
+    portfolio_return: Rate,
+) -> Vec<ClassDivergence> {
+    let base = match class_outputs.iter().find(|c| c.class_name == base_class) {
+        Some(b) => b,
+        Option::None => return Vec::new(),
+    };
+
+    class_outputs
+        .iter()
+        .filter(|c| c.class_name != base_class)
+        .map(|c| {
+            let divergence = c.net_return - base.net_return;
+            let (currency_contribution, hedging_contribution) = match &c.hedging {
+                Some(h) => {
+                    let opening = c.gross_nav_per_share / (Decimal::ONE + c.gross_return);
+                    (
+                        h.unhedged_gross_return - portfolio_return,
+                        h.net_hedge_pnl_per_share / opening,
+                    )
+                }
+                Option::None => (Decimal::ZERO, Decimal::ZERO),
+            };
+            ClassDivergence {
+                class_name: c.class_name.clone(),
+                net_return: c.net_return,
+                base_class_net_return: base.net_return,
+                divergence,
+                currency_contribution,
+                hedging_contribution,
+                fee_and_other: divergence - currency_contribution - hedging_contribution,
+            }
+        })
+        .collect()
+}
+
 /// Calculate performance fee per share.
 ///
 /// Performance fee is only charged when gross NAV exceeds the high-water mark
@@ -437,17 +772,41 @@ fn calculate_equalisation_adjustment(
 
 /// Convert each share class total NAV to base currency and sum.
 fn calculate_total_fund_nav(
-    class_outputs: &[ShareClassNavOutput],
+    class_outputs: &mut [ShareClassNavOutput],
     class_inputs: &[ShareClassInput],
+    base_currency: &Currency,
+    fx_rates: &[FxRate],
     warnings: &mut Vec<String>,
 ) -> Money {
     let mut total = Decimal::ZERO;
 
-    for (co, ci) in class_outputs.iter().zip(class_inputs.iter()) {
+    for (co, ci) in class_outputs.iter_mut().zip(class_inputs.iter()) {
         let class_nav = co.class_total_nav;
 
-        let nav_in_base = match ci.fx_rate_to_base {
-            Some(fx_rate) if fx_rate > Decimal::ZERO => {
+        let fx_rate = match ci.fx_rate_to_base {
+            Some(fx_rate) if fx_rate > Decimal::ZERO => Some(fx_rate),
+            Some(fx_rate) => {
+                warnings.push(format!(
+                    "Share class '{}': invalid fx_rate_to_base ({}), using NAV as-is",
+                    co.class_name, fx_rate
+                ));
+                None
+            }
+            Option::None if ci.currency == *base_currency => None,
+            Option::None => match fx_conversion_rate(&ci.currency, base_currency, fx_rates) {
+                Ok(fx_rate) => Some(fx_rate),
+                Err(e) => {
+                    warnings.push(format!(
+                        "Share class '{}': {}, using NAV as-is",
+                        co.class_name, e
+                    ));
+                    None
+                }
+            },
+        };
+
+        let nav_in_base = match fx_rate {
+            Some(fx_rate) => {
                 let converted = class_nav * fx_rate;
 
                 // Apply FX hedging cost if present
@@ -456,16 +815,10 @@ fn calculate_total_fund_nav(
                     Option::None => converted,
                 }
             }
-            Some(fx_rate) => {
-                warnings.push(format!(
-                    "Share class '{}': invalid fx_rate_to_base ({}), using NAV as-is",
-                    co.class_name, fx_rate
-                ));
-                class_nav
-            }
             Option::None => class_nav,
         };
 
+        co.class_total_nav_base = nav_in_base;
         total += nav_in_base;
     }
 
@@ -496,6 +849,7 @@ mod tests {
                 crystallisation_frequency: CrystallisationFrequency::Annually,
                 fx_rate_to_base: None,
                 fx_hedging_cost: None,
+                hedging: None,
                 subscriptions: vec![],
                 redemptions: vec![],
             }],
@@ -503,6 +857,8 @@ mod tests {
             period_label: "Q4 2025".to_string(),
             equalisation_method: EqualisationMethod::None,
             base_currency: Currency::USD,
+            fx_rates: vec![],
+            base_class: None,
         }
     }
 
@@ -660,6 +1016,7 @@ mod tests {
             crystallisation_frequency: CrystallisationFrequency::Quarterly,
             fx_rate_to_base: None,
             fx_hedging_cost: None,
+            hedging: None,
             subscriptions: vec![],
             redemptions: vec![],
         });
@@ -806,6 +1163,8 @@ mod tests {
             period_label: "Q4 2025".to_string(),
             equalisation_method: EqualisationMethod::None,
             base_currency: Currency::USD,
+            fx_rates: vec![],
+            base_class: None,
         };
 
         let result = calculate_nav(&input);
@@ -858,4 +1217,225 @@ mod tests {
         // Quarterly management fee = 110 * 0.02 * 0.25 = 0.55
         assert_eq!(sc.management_fee_accrual, dec!(0.55));
     }
+
+    // ------------------------------------------------------------------
+    // Test 17: FX table used when no explicit class rate is given
+    // ------------------------------------------------------------------
+    #[test]
+    fn test_fx_rates_table_conversion() {
+        let mut input = single_class_input();
+        input.share_classes[0].currency = Currency::GBP;
+        input.share_classes[0].fx_rate_to_base = None;
+        input.fx_rates = vec![FxRate {
+            base: Currency::USD,
+            quote: Currency::GBP,
+            rate: dec!(0.80),
+        }];
+
+        let result = calculate_nav(&input).unwrap();
+        let sc = &result.result.share_classes[0];
+        assert_eq!(sc.class_total_nav_base, sc.class_total_nav * dec!(1.25));
+        assert_eq!(result.result.total_fund_nav, sc.class_total_nav_base);
+        assert!(result.warnings.is_empty());
+    }
+
+    // ------------------------------------------------------------------
+    // Test 18: Missing FX rate warns and falls back to unconverted NAV
+    // ------------------------------------------------------------------
+    #[test]
+    fn test_missing_fx_rate_warns() {
+        let mut input = single_class_input();
+        input.share_classes[0].currency = Currency::CHF;
+        input.share_classes[0].fx_rate_to_base = None;
+
+        let result = calculate_nav(&input).unwrap();
+        let sc = &result.result.share_classes[0];
+        assert_eq!(sc.class_total_nav_base, sc.class_total_nav);
+        assert!(result.warnings.iter().any(|w| w.contains("No FX rate")));
+    }
+
+    // ------------------------------------------------------------------
+    // Hedged share classes
+    // ------------------------------------------------------------------
+
+    /// USD base class A plus a EUR class B hedged back to USD.
+    /// FX quoted as EUR per USD.
+    fn hedged_input(policy: HedgeRatioPolicy, forward_points: Decimal) -> NavInput {
+        let mut input = single_class_input();
+        input.share_classes[0].performance_fee_rate = Decimal::ZERO;
+        let mut eur = input.share_classes[0].clone();
+        eur.class_name = "Class B EUR Hedged".to_string();
+        eur.currency = Currency::EUR;
+        eur.hedging = Some(ShareClassHedge {
+            spot_rate_start: dec!(0.90),
+            spot_rate_end: dec!(0.81),
+            forward_points,
+            hedge_ratio_policy: policy,
+            transaction_cost_bps: None,
+        });
+        input.share_classes.push(eur);
+        input
+    }
+
+    #[test]
+    fn test_full_hedge_removes_spot_move() {
+        let input = hedged_input(HedgeRatioPolicy::Full, Decimal::ZERO);
+        let out = calculate_nav(&input).unwrap().result;
+        let b = &out.share_classes[1];
+        let h = b.hedging.as_ref().unwrap();
+
+        // Unhedged: 100 / 0.90 * 1.10 * 0.81 = 99 (USD fell 10% vs EUR)
+        assert!((h.unhedged_gross_return - dec!(-0.01)).abs() < dec!(0.0000001));
+        // Hedged: 100 + 10 * 0.81 / 0.90 = 109
+        assert!((b.gross_nav_per_share - dec!(109)).abs() < dec!(0.0000001));
+        assert!((h.spot_pnl_per_share - dec!(10)).abs() < dec!(0.0000001));
+        assert_eq!(h.hedge_ratio, Decimal::ONE);
+    }
+
+    #[test]
+    fn test_forward_points_carry_charged_to_class() {
+        let flat = calculate_nav(&hedged_input(HedgeRatioPolicy::Full, Decimal::ZERO))
+            .unwrap()
+            .result;
+        let carry = calculate_nav(&hedged_input(HedgeRatioPolicy::Full, dec!(-0.0045)))
+            .unwrap()
+            .result;
+
+        let h = carry.share_classes[1].hedging.as_ref().unwrap();
+        // Carry = 100 / 0.90 * -0.0045 = -0.5 per share
+        assert!((h.carry_per_share - dec!(-0.5)).abs() < dec!(0.0000001));
+        let diff =
+            flat.share_classes[1].gross_nav_per_share - carry.share_classes[1].gross_nav_per_share;
+        assert!((diff - dec!(0.5)).abs() < dec!(0.0000001));
+        assert!((h.forward_rate - dec!(0.8955)).abs() < dec!(0.0000001));
+    }
+
+    #[test]
+    fn test_hedging_does_not_affect_unhedged_class() {
+        let single = calculate_nav(&single_class_input()).unwrap().result;
+        let mut input = hedged_input(HedgeRatioPolicy::Full, dec!(-0.0045));
+        input.share_classes[0].performance_fee_rate = dec!(0.20);
+        let out = calculate_nav(&input).unwrap().result;
+
+        let a = &out.share_classes[0];
+        assert!(a.hedging.is_none());
+        assert_eq!(
+            a.net_nav_per_share,
+            single.share_classes[0].net_nav_per_share
+        );
+    }
+
+    #[test]
+    fn test_transaction_costs_reduce_hedged_nav() {
+        let mut input = hedged_input(HedgeRatioPolicy::Full, Decimal::ZERO);
+        input.share_classes[1]
+            .hedging
+            .as_mut()
+            .unwrap()
+            .transaction_cost_bps = Some(dec!(5));
+        let out = calculate_nav(&input).unwrap().result;
+        let h = out.share_classes[1].hedging.as_ref().unwrap();
+
+        // 5bp of 111.11 USD notional at 0.90 = 0.05 EUR per share
+        assert!((h.transaction_cost_per_share - dec!(0.05)).abs() < dec!(0.0000001));
+        assert_eq!(
+            h.net_hedge_pnl_per_share,
+            h.spot_pnl_per_share + h.carry_per_share - h.transaction_cost_per_share
+        );
+    }
+
+    #[test]
+    fn test_band_policy_keeps_ratio_inside_band() {
+        let policy = HedgeRatioPolicy::Band {
+            current_ratio: dec!(0.98),
+            target_ratio: Decimal::ONE,
+            lower: dec!(0.95),
+            upper: dec!(1.05),
+        };
+        let out = calculate_nav(&hedged_input(policy, Decimal::ZERO))
+            .unwrap()
+            .result;
+        let h = out.share_classes[1].hedging.as_ref().unwrap();
+        assert_eq!(h.hedge_ratio, dec!(0.98));
+        assert!(!h.rebalanced);
+    }
+
+    #[test]
+    fn test_band_policy_rebalances_on_breach() {
+        let policy = HedgeRatioPolicy::Band {
+            current_ratio: dec!(1.08),
+            target_ratio: Decimal::ONE,
+            lower: dec!(0.95),
+            upper: dec!(1.05),
+        };
+        let out = calculate_nav(&hedged_input(policy, Decimal::ZERO))
+            .unwrap()
+            .result;
+        let h = out.share_classes[1].hedging.as_ref().unwrap();
+        assert_eq!(h.hedge_ratio, Decimal::ONE);
+        assert!(h.rebalanced);
+    }
+
+    #[test]
+    fn test_partial_hedge_ratio_warns() {
+        let result = calculate_nav(&hedged_input(
+            HedgeRatioPolicy::Fixed { ratio: dec!(0.5) },
+            Decimal::ZERO,
+        ))
+        .unwrap();
+        assert!(result.warnings.iter().any(|w| w.contains("hedge ratio")));
+        let h = result.result.share_classes[1].hedging.as_ref().unwrap();
+        assert!((h.spot_pnl_per_share - dec!(5)).abs() < dec!(0.0000001));
+    }
+
+    #[test]
+    fn test_class_hedge_pnl_scales_with_shares() {
+        let out = calculate_nav(&hedged_input(HedgeRatioPolicy::Full, dec!(-0.0045)))
+            .unwrap()
+            .result;
+        let b = &out.share_classes[1];
+        let h = b.hedging.as_ref().unwrap();
+        assert_eq!(
+            h.class_hedge_pnl,
+            h.net_hedge_pnl_per_share * b.shares_outstanding
+        );
+    }
+
+    #[test]
+    fn test_divergence_decomposition_sums() {
+        let out = calculate_nav(&hedged_input(HedgeRatioPolicy::Full, dec!(-0.0045)))
+            .unwrap()
+            .result;
+        assert_eq!(out.base_class.as_deref(), Some("Class A"));
+        assert_eq!(out.class_divergence.len(), 1);
+
+        let d = &out.class_divergence[0];
+        assert_eq!(d.class_name, "Class B EUR Hedged");
+        assert_eq!(
+            d.divergence,
+            d.currency_contribution + d.hedging_contribution + d.fee_and_other
+        );
+        // Currency translation -11%, hedge +9.5%
+        assert!((d.currency_contribution - dec!(-0.11)).abs() < dec!(0.0000001));
+        assert!((d.hedging_contribution - dec!(0.095)).abs() < dec!(0.0000001));
+        assert!(d.divergence < Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_unknown_base_class_rejected() {
+        let mut input = hedged_input(HedgeRatioPolicy::Full, Decimal::ZERO);
+        input.base_class = Some("Class Z".to_string());
+        assert!(calculate_nav(&input).is_err());
+    }
+
+    #[test]
+    fn test_invalid_hedge_spot_rejected() {
+        let mut input = hedged_input(HedgeRatioPolicy::Full, Decimal::ZERO);
+        input.share_classes[1]
+            .hedging
+            .as_mut()
+            .unwrap()
+            .spot_rate_end = Decimal::ZERO;
+        assert!(calculate_nav(&input).is_err());
+    }
 }
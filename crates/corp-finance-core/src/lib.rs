@@ -1,6 +1,17 @@
+pub mod amortization;
+pub mod curves;
+pub mod diff;
+pub mod dilution;
 pub mod error;
+pub mod limits;
+pub mod market_data;
+pub mod precision;
+pub mod ratings;
+pub mod sampling;
+pub mod stats;
 pub mod time_value;
 pub mod types;
+pub mod units;
 
 #[cfg(feature = "valuation")]
 pub mod valuation;
@@ -221,6 +232,24 @@ pub mod workflows;
 #[cfg(feature = "institutional_real_estate")]
 pub mod institutional_real_estate;
 
+#[cfg(feature = "ecm")]
+pub mod ecm;
+
+#[cfg(feature = "fund_structuring")]
+pub mod fund_structuring;
+
+#[cfg(feature = "deal_model")]
+pub mod deal_model;
+
+#[cfg(feature = "capital_budgeting")]
+pub mod capital_budgeting;
+#[cfg(feature = "cashflow_ladder")]
+pub mod cashflow_ladder;
+#[cfg(feature = "consumer_lending")]
+pub mod consumer_lending;
+#[cfg(feature = "franchise")]
+pub mod franchise;
+
 pub use error::CorpFinanceError;
 pub use types::*;
 
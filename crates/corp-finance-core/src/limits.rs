@@ -0,0 +1,252 @@
+use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
+use std::sync::{OnceLock, RwLock};
+use std::time::{Duration, Instant};
+
+use crate::{CorpFinanceError, CorpFinanceResult};
+
+// ---------------------------------------------------------------------------
+// Configuration
+// ---------------------------------------------------------------------------
+
+/// Guards against pathological requests in hosted use. `None` leaves a
+/// dimension unlimited, which is the default.
+///
+/// Limits apply process-wide, are read from the environment on first use
+/// (`CFA_MAX_SIMULATION_PATHS`, `CFA_MAX_SCHEDULE_PERIODS`,
+/// `CFA_MAX_GRID_CELLS`, `CFA_SOFT_TIMEOUT_MS`) and can be replaced with
+/// [`configure`] or overridden for one request with [`with_limits`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ComputeLimits {
+    /// Maximum Monte Carlo paths or simulations in one request.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_simulation_paths: Option<u64>,
+    /// Maximum periods in a projected schedule (years, quarters or months).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_schedule_periods: Option<u64>,
+    /// Maximum cells in a sensitivity grid.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_grid_cells: Option<u64>,
+    /// Wall-clock budget after which long-running calculators stop and
+    /// report how far they got.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub soft_timeout_ms: Option<u64>,
+}
+
+impl ComputeLimits {
+    /// Read limits from `CFA_MAX_SIMULATION_PATHS`, `CFA_MAX_SCHEDULE_PERIODS`,
+    /// `CFA_MAX_GRID_CELLS` and `CFA_SOFT_TIMEOUT_MS`. Unset or empty
+    /// variables leave the dimension unlimited.
+    pub fn from_env() -> CorpFinanceResult<Self> {
+        fn read(var: &str) -> CorpFinanceResult<Option<u64>> {
+            match std::env::var(var) {
+                Ok(v) if !v.trim().is_empty() => {
+                    v.trim()
+                        .parse()
+                        .map(Some)
+                        .map_err(|_| CorpFinanceError::InvalidInput {
+                            field: var.into(),
+                            reason: format!("'{v}' is not a non-negative integer"),
+                        })
+                }
+                _ => Ok(None),
+            }
+        }
+        Ok(ComputeLimits {
+            max_simulation_paths: read("CFA_MAX_SIMULATION_PATHS")?,
+            max_schedule_periods: read("CFA_MAX_SCHEDULE_PERIODS")?,
+            max_grid_cells: read("CFA_MAX_GRID_CELLS")?,
+            soft_timeout_ms: read("CFA_SOFT_TIMEOUT_MS")?,
+        })
+    }
+}
+
+fn global() -> &'static RwLock<ComputeLimits> {
+    static GLOBAL: OnceLock<RwLock<ComputeLimits>> = OnceLock::new();
+    // Invalid variables are ignored here; callers that want them reported
+    // call `ComputeLimits::from_env` and `configure` at start-up.
+    GLOBAL.get_or_init(|| RwLock::new(ComputeLimits::from_env().unwrap_or_default()))
+}
+
+thread_local! {
+    static SCOPED: RefCell<Option<ComputeLimits>> = const { RefCell::new(None) };
+}
+
+/// Replace the process-wide limits.
+pub fn configure(limits: ComputeLimits) {
+    *global().write().unwrap_or_else(|e| e.into_inner()) = limits;
+}
+
+/// Limits in force on the current thread.
+pub fn current() -> ComputeLimits {
+    SCOPED
+        .with(|s| *s.borrow())
+        .unwrap_or_else(|| *global().read().unwrap_or_else(|e| e.into_inner()))
+}
+
+/// Run `f` with `limits` in place of the process-wide limits on this thread,
+/// e.g. for one request of a multi-tenant server.
+pub fn with_limits<T>(limits: ComputeLimits, f: impl FnOnce() -> T) -> T {
+    struct Restore(Option<ComputeLimits>);
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            SCOPED.with(|s| *s.borrow_mut() = self.0);
+        }
+    }
+    let _restore = Restore(SCOPED.with(|s| s.borrow_mut().replace(limits)));
+    f()
+}
+
+// ---------------------------------------------------------------------------
+// Checks
+// ---------------------------------------------------------------------------
+
+fn check(limit: &str, field: &str, requested: u64, maximum: Option<u64>) -> CorpFinanceResult<()> {
+    match maximum {
+        Some(max) if requested > max => Err(CorpFinanceError::LimitExceeded {
+            limit: limit.into(),
+            detail: format!("{field} requests {requested}, above the maximum of {max}"),
+        }),
+        _ => Ok(()),
+    }
+}
+
+/// Reject a request for more simulation paths than allowed.
+pub fn check_simulation_paths(field: &str, requested: u64) -> CorpFinanceResult<()> {
+    check(
+        "max_simulation_paths",
+        field,
+        requested,
+        current().max_simulation_paths,
+    )
+}
+
+/// Reject a schedule longer than allowed.
+pub fn check_schedule_periods(field: &str, requested: u64) -> CorpFinanceResult<()> {
+    check(
+        "max_schedule_periods",
+        field,
+        requested,
+        current().max_schedule_periods,
+    )
+}
+
+/// Reject a sensitivity grid with more cells than allowed.
+pub fn check_grid_cells(field: &str, requested: u64) -> CorpFinanceResult<()> {
+    check("max_grid_cells", field, requested, current().max_grid_cells)
+}
+
+/// Soft timeout for one calculation, started when the calculation starts.
+///
+/// Capture it on the calling thread: it is `Copy` and can be moved into
+/// parallel workers, which do not see scoped limits themselves.
+#[derive(Debug, Clone, Copy)]
+pub struct Deadline {
+    started: Instant,
+    budget: Option<Duration>,
+}
+
+impl Deadline {
+    /// Start the clock under the current soft timeout.
+    pub fn start() -> Self {
+        Deadline {
+            started: Instant::now(),
+            budget: current().soft_timeout_ms.map(Duration::from_millis),
+        }
+    }
+
+    pub fn expired(&self) -> bool {
+        self.budget.is_some_and(|b| self.started.elapsed() >= b)
+    }
+
+    /// Fail with `LimitExceeded` once the budget is spent. `progress`
+    /// describes the partial work completed, e.g. `"4,096 of 100,000 paths"`.
+    pub fn check(&self, progress: impl FnOnce() -> String) -> CorpFinanceResult<()> {
+        match self.budget {
+            Some(budget) if self.started.elapsed() >= budget => {
+                Err(CorpFinanceError::LimitExceeded {
+                    limit: "soft_timeout_ms".into(),
+                    detail: format!(
+                        "stopped after {} ms with {} completed",
+                        budget.as_millis(),
+                        progress()
+                    ),
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unlimited_by_default() {
+        with_limits(ComputeLimits::default(), || {
+            assert!(check_simulation_paths("paths", u64::MAX).is_ok());
+            assert!(check_schedule_periods("years", u64::MAX).is_ok());
+            assert!(check_grid_cells("grid", u64::MAX).is_ok());
+            assert!(Deadline::start().check(String::new).is_ok());
+        });
+    }
+
+    #[test]
+    fn test_limits_enforced() {
+        let limits = ComputeLimits {
+            max_simulation_paths: Some(10_000),
+            max_schedule_periods: Some(40),
+            max_grid_cells: Some(400),
+            soft_timeout_ms: None,
+        };
+        with_limits(limits, || {
+            assert!(check_simulation_paths("num_simulations", 10_000).is_ok());
+            match check_simulation_paths("num_simulations", 10_001).unwrap_err() {
+                CorpFinanceError::LimitExceeded { limit, detail } => {
+                    assert_eq!(limit, "max_simulation_paths");
+                    assert!(detail.contains("num_simulations"));
+                }
+                other => panic!("Expected LimitExceeded, got {other:?}"),
+            }
+            assert!(check_schedule_periods("projection_years", 41).is_err());
+            assert!(check_grid_cells("grid", 401).is_err());
+        });
+    }
+
+    #[test]
+    fn test_scoped_limits_restored() {
+        let outer = current();
+        with_limits(
+            ComputeLimits {
+                max_grid_cells: Some(1),
+                ..Default::default()
+            },
+            || {
+                assert_eq!(current().max_grid_cells, Some(1));
+            },
+        );
+        assert_eq!(current(), outer);
+    }
+
+    #[test]
+    fn test_soft_timeout() {
+        let limits = ComputeLimits {
+            soft_timeout_ms: Some(0),
+            ..Default::default()
+        };
+        let deadline = with_limits(limits, Deadline::start);
+        assert!(deadline.expired());
+        match deadline.check(|| "3 of 10 paths".into()).unwrap_err() {
+            CorpFinanceError::LimitExceeded { limit, detail } => {
+                assert_eq!(limit, "soft_timeout_ms");
+                assert!(detail.contains("3 of 10 paths"));
+            }
+            other => panic!("Expected LimitExceeded, got {other:?}"),
+        }
+    }
+}
@@ -0,0 +1,813 @@
+use rust_decimal::Decimal;
+use rust_decimal::MathematicalOps;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+use std::time::Instant;
+
+use crate::error::CorpFinanceError;
+use crate::sampling::{percentile, Lcg};
+use crate::stats::norm_cdf;
+use crate::types::*;
+use crate::CorpFinanceResult;
+
+// ---------------------------------------------------------------------------
+// Types
+// ---------------------------------------------------------------------------
+
+/// Financial metric the earn-out is measured on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EarnoutMetric {
+    Revenue,
+    Ebitda,
+}
+
+/// Payoff for a single measurement period.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum EarnoutPayoff {
+    /// Fixed payment if the metric reaches the threshold.
+    Binary { payment: Money },
+    /// Share of the metric in excess of the threshold, optionally capped.
+    Linear {
+        participation_rate: Rate,
+        cap: Option<Money>,
+    },
+}
+
+/// One measurement period of the earn-out.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EarnoutPeriod {
+    /// Year (from closing) in which the metric is measured.
+    pub year: u32,
+    pub threshold: Money,
+    pub payoff: EarnoutPayoff,
+}
+
+/// A named set of metric outcomes for the negotiation payout table.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EarnoutScenario {
+    pub name: String,
+    /// Metric for each period, in period order.
+    pub metrics: Vec<Money>,
+}
+
+/// Inputs for earn-out (contingent consideration) valuation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EarnoutInput {
+    pub metric: EarnoutMetric,
+    /// Metric for the last full year before closing.
+    pub base_metric: Money,
+    /// Expected annual growth of the metric (management case).
+    pub expected_growth: Rate,
+    /// Annual volatility of the metric.
+    pub volatility: Rate,
+    pub risk_free_rate: Rate,
+    /// Metric risk premium deducted from growth to reach the risk-neutral drift.
+    pub metric_risk_premium: Rate,
+    /// Buyer's credit spread, added to the discount rate for payments.
+    #[serde(default)]
+    pub credit_spread: Rate,
+    /// Years between the end of a measurement period and payment.
+    #[serde(default)]
+    pub payment_lag_years: Decimal,
+    pub periods: Vec<EarnoutPeriod>,
+    /// Missed payments can be earned later if the cumulative metric catches up.
+    #[serde(default)]
+    pub catch_up: bool,
+    /// Cap on total payments across all periods.
+    pub aggregate_cap: Option<Money>,
+    #[serde(default)]
+    pub scenarios: Vec<EarnoutScenario>,
+    /// Number of Monte Carlo paths (default 10,000).
+    pub num_simulations: Option<u32>,
+    /// RNG seed (default 42).
+    pub seed: Option<u64>,
+}
+
+/// Valuation results for one measurement period.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EarnoutPeriodResult {
+    pub year: u32,
+    pub threshold: Money,
+    /// Risk-neutral expected metric.
+    pub expected_metric: Money,
+    pub probability_of_payment: Rate,
+    pub expected_payment: Money,
+    pub present_value: Money,
+}
+
+/// Distribution of total nominal payouts across simulated paths.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PayoutDistribution {
+    pub prob_zero: Rate,
+    /// Probability of paying the maximum (when payouts are bounded).
+    pub prob_max: Option<Rate>,
+    pub p10: Money,
+    pub p50: Money,
+    pub p90: Money,
+}
+
+/// Payouts under a named scenario.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScenarioPayout {
+    pub name: String,
+    pub payouts: Vec<Money>,
+    pub total: Money,
+    pub present_value: Money,
+}
+
+/// Earn-out valuation output.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EarnoutOutput {
+    /// Fair value of the contingent consideration for PPA purposes.
+    pub fair_value: Money,
+    pub monte_carlo_value: Money,
+    pub monte_carlo_std_error: Money,
+    /// Analytical value, available when periods are independent
+    /// (no catch-up and no aggregate cap).
+    pub closed_form_value: Option<Money>,
+    /// Maximum nominal payout; `None` when uncapped.
+    pub max_payout: Option<Money>,
+    pub fair_value_pct_of_max: Option<Rate>,
+    pub discount_rate: Rate,
+    pub periods: Vec<EarnoutPeriodResult>,
+    pub payout_distribution: PayoutDistribution,
+    pub scenario_payouts: Vec<ScenarioPayout>,
+}
+
+// ---------------------------------------------------------------------------
+// Core calculation
+// ---------------------------------------------------------------------------
+
+/// Value an earn-out on revenue or EBITDA milestones.
+///
+/// The metric follows a lognormal path with risk-neutral drift
+/// `growth - metric risk premium`; payments are discounted at the risk-free
+/// rate plus the buyer's credit spread. With catch-up, period `k` pays the
+/// greater of its own payoff and the cumulative entitlement not yet paid,
+/// where the cumulative entitlement evaluates every period to date at its
+/// threshold plus an equal share of the cumulative excess over target.
+pub fn value_earnout(input: &EarnoutInput) -> CorpFinanceResult<ComputationOutput<EarnoutOutput>> {
+    let start = Instant::now();
+    let mut warnings: Vec<String> = Vec::new();
+
+    validate_input(input)?;
+
+    let mut periods = input.periods.clone();
+    periods.sort_by_key(|p| p.year);
+    let discount_rate = input.risk_free_rate + input.credit_spread;
+    let drift = Decimal::ONE + input.expected_growth - input.metric_risk_premium;
+    let discount_factors: Vec<Decimal> = periods
+        .iter()
+        .map(|p| {
+            discount_factor(
+                discount_rate,
+                Decimal::from(p.year) + input.payment_lag_years,
+            )
+        })
+        .collect();
+
+    // --- Monte Carlo ---
+    let n = input.num_simulations.unwrap_or(10_000);
+    let last_year = periods.last().map(|p| p.year).unwrap_or(0);
+    let sigma = input.volatility;
+    let log_drift = drift.ln() - sigma * sigma / dec!(2);
+    let mut rng = Lcg::new(input.seed.unwrap_or(42));
+
+    let mut pv_paths: Vec<Decimal> = Vec::with_capacity(n as usize);
+    let mut totals: Vec<Decimal> = Vec::with_capacity(n as usize);
+    let mut period_hits = vec![0u32; periods.len()];
+    let mut period_sums = vec![Decimal::ZERO; periods.len()];
+
+    for _ in 0..n {
+        let mut path = Vec::with_capacity(last_year as usize);
+        let mut m = input.base_metric;
+        for _ in 0..last_year {
+            m *= (log_drift + sigma * rng.next_normal()).exp();
+            path.push(m);
+        }
+        let metrics: Vec<Decimal> = periods.iter().map(|p| path[p.year as usize - 1]).collect();
+        let payouts = payouts_for(&periods, &metrics, input.catch_up, input.aggregate_cap);
+
+        let mut pv = Decimal::ZERO;
+        for (i, (pay, df)) in payouts.iter().zip(&discount_factors).enumerate() {
+            if *pay > Decimal::ZERO {
+                period_hits[i] += 1;
+            }
+            period_sums[i] += *pay;
+            pv += *pay * *df;
+        }
+        pv_paths.push(pv);
+        totals.push(payouts.iter().copied().sum());
+    }
+
+    let nd = Decimal::from(n);
+    let monte_carlo_value = pv_paths.iter().copied().sum::<Decimal>() / nd;
+    let variance = pv_paths
+        .iter()
+        .map(|v| (*v - monte_carlo_value) * (*v - monte_carlo_value))
+        .sum::<Decimal>()
+        / nd;
+    let monte_carlo_std_error =
+        variance.sqrt().unwrap_or(Decimal::ZERO) / nd.sqrt().unwrap_or(Decimal::ONE);
+
+    // --- Closed form (independent periods only) ---
+    let closed_form_value = if !input.catch_up && input.aggregate_cap.is_none() {
+        Some(
+            periods
+                .iter()
+                .zip(&discount_factors)
+                .map(|(p, df)| {
+                    let forward = input.base_metric * drift.powu(p.year as u64);
+                    closed_form_payoff(p, forward, sigma, Decimal::from(p.year)) * *df
+                })
+                .sum(),
+        )
+    } else {
+        None
+    };
+
+    let fair_value = closed_form_value.unwrap_or(monte_carlo_value);
+
+    // --- Maximum payout ---
+    let period_max: Option<Decimal> = periods
+        .iter()
+        .map(|p| match &p.payoff {
+            EarnoutPayoff::Binary { payment } => Some(*payment),
+            EarnoutPayoff::Linear { cap, .. } => *cap,
+        })
+        .sum();
+    let max_payout = match (period_max, input.aggregate_cap) {
+        (Some(m), Some(c)) => Some(m.min(c)),
+        (Some(m), None) => Some(m),
+        (None, c) => c,
+    };
+    let fair_value_pct_of_max = max_payout
+        .filter(|m| *m > Decimal::ZERO)
+        .map(|m| fair_value / m);
+
+    // --- Period results ---
+    let period_results = periods
+        .iter()
+        .enumerate()
+        .map(|(i, p)| {
+            let expected_payment = period_sums[i] / nd;
+            EarnoutPeriodResult {
+                year: p.year,
+                threshold: p.threshold,
+                expected_metric: input.base_metric * drift.powu(p.year as u64),
+                probability_of_payment: Decimal::from(period_hits[i]) / nd,
+                expected_payment,
+                present_value: expected_payment * discount_factors[i],
+            }
+        })
+        .collect();
+
+    // --- Payout distribution ---
+    let prob_zero = Decimal::from(totals.iter().filter(|t| t.is_zero()).count() as u64) / nd;
+    let prob_max = max_payout.map(|m| {
+        Decimal::from(totals.iter().filter(|t| **t >= m - dec!(0.000001)).count() as u64) / nd
+    });
+    totals.sort();
+    let payout_distribution = PayoutDistribution {
+        prob_zero,
+        prob_max,
+        p10: percentile(&totals, dec!(0.10)),
+        p50: percentile(&totals, dec!(0.50)),
+        p90: percentile(&totals, dec!(0.90)),
+    };
+
+    // --- Scenario table ---
+    let mut scenario_payouts = Vec::with_capacity(input.scenarios.len());
+    for s in &input.scenarios {
+        if s.metrics.len() != periods.len() {
+            return Err(CorpFinanceError::InvalidInput {
+                field: format!("scenarios.{}.metrics", s.name),
+                reason: format!("Expected {} metrics, one per period", periods.len()),
+            });
+        }
+        let payouts = payouts_for(&periods, &s.metrics, input.catch_up, input.aggregate_cap);
+        scenario_payouts.push(ScenarioPayout {
+            name: s.name.clone(),
+            total: payouts.iter().copied().sum(),
+            present_value: payouts
+                .iter()
+                .zip(&discount_factors)
+                .map(|(p, df)| *p * *df)
+                .sum(),
+            payouts,
+        });
+    }
+
+    if let Some(cf) = closed_form_value {
+        if cf > Decimal::ZERO && (cf - monte_carlo_value).abs() / cf > dec!(0.05) {
+            warnings.push(format!(
+                "Monte Carlo value differs from closed form by more than 5%; consider more simulations (n = {n})"
+            ));
+        }
+    }
+    if input.metric == EarnoutMetric::Ebitda && input.volatility < dec!(0.15) {
+        warnings
+            .push("EBITDA volatility below 15% is low relative to typical earn-out targets".into());
+    }
+
+    let output = EarnoutOutput {
+        fair_value,
+        monte_carlo_value,
+        monte_carlo_std_error,
+        closed_form_value,
+        max_payout,
+        fair_value_pct_of_max,
+        discount_rate,
+        periods: period_results,
+        payout_distribution,
+        scenario_payouts,
+    };
+
+    let elapsed = start.elapsed().as_micros() as u64;
+    Ok(with_metadata(
+        "Earn-out Valuation (risk-neutral lognormal metric, Monte Carlo with closed-form check)",
+        &serde_json::json!({
+            "metric": input.metric,
+            "volatility": input.volatility.to_string(),
+            "metric_risk_premium": input.metric_risk_premium.to_string(),
+            "discount_rate": discount_rate.to_string(),
+            "catch_up": input.catch_up,
+            "num_simulations": n,
+            "seed": input.seed.unwrap_or(42),
+        }),
+        warnings,
+        elapsed,
+        output,
+    ))
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Payoff for one period at a given metric, before catch-up and aggregate cap.
+fn period_payoff(period: &EarnoutPeriod, metric: Decimal) -> Decimal {
+    match &period.payoff {
+        EarnoutPayoff::Binary { payment } => {
+            if metric >= period.threshold {
+                *payment
+            } else {
+                Decimal::ZERO
+            }
+        }
+        EarnoutPayoff::Linear {
+            participation_rate,
+            cap,
+        } => {
+            let raw = *participation_rate * (metric - period.threshold).max(Decimal::ZERO);
+            match cap {
+                Some(c) => raw.min(*c),
+                None => raw,
+            }
+        }
+    }
+}
+
+/// Payments per period for one path of metrics.
+fn payouts_for(
+    periods: &[EarnoutPeriod],
+    metrics: &[Decimal],
+    catch_up: bool,
+    aggregate_cap: Option<Decimal>,
+) -> Vec<Decimal> {
+    let mut paid = Decimal::ZERO;
+    let mut cum_excess = Decimal::ZERO;
+    let mut payouts = Vec::with_capacity(periods.len());
+    for (k, (period, metric)) in periods.iter().zip(metrics).enumerate() {
+        let mut pay = period_payoff(period, *metric);
+        if catch_up {
+            cum_excess += *metric - period.threshold;
+            let share = cum_excess / Decimal::from(k as u64 + 1);
+            let entitlement: Decimal = periods[..=k]
+                .iter()
+                .map(|p| period_payoff(p, p.threshold + share))
+                .sum();
+            pay = pay.max(entitlement - paid);
+        }
+        if let Some(cap) = aggregate_cap {
+            pay = pay.min((cap - paid).max(Decimal::ZERO));
+        }
+        paid += pay;
+        payouts.push(pay);
+    }
+    payouts
+}
+
+/// Risk-neutral expected payoff of one period for a lognormal metric with
+/// the given forward and total volatility `sigma * sqrt(t)`.
+fn closed_form_payoff(
+    period: &EarnoutPeriod,
+    forward: Decimal,
+    sigma: Decimal,
+    t: Decimal,
+) -> Decimal {
+    let sd = sigma * t.sqrt().unwrap_or(Decimal::ZERO);
+    match &period.payoff {
+        EarnoutPayoff::Binary { payment } => *payment * prob_above(forward, period.threshold, sd),
+        EarnoutPayoff::Linear {
+            participation_rate,
+            cap,
+        } => {
+            let call = expected_excess(forward, period.threshold, sd);
+            let capped = match cap {
+                Some(c) if *participation_rate > Decimal::ZERO => {
+                    call - expected_excess(forward, period.threshold + *c / *participation_rate, sd)
+                }
+                _ => call,
+            };
+            *participation_rate * capped
+        }
+    }
+}
+
+/// P(M >= k) for lognormal M with forward `f` and log standard deviation `sd`.
+fn prob_above(f: Decimal, k: Decimal, sd: Decimal) -> Decimal {
+    if k <= Decimal::ZERO {
+        return Decimal::ONE;
+    }
+    if sd.is_zero() {
+        return if f >= k { Decimal::ONE } else { Decimal::ZERO };
+    }
+    norm_cdf(((f / k).ln() - sd * sd / dec!(2)) / sd)
+}
+
+/// E[(M - k)+] for lognormal M with forward `f` and log standard deviation `sd`.
+fn expected_excess(f: Decimal, k: Decimal, sd: Decimal) -> Decimal {
+    if k <= Decimal::ZERO {
+        return f - k;
+    }
+    if sd.is_zero() {
+        return (f - k).max(Decimal::ZERO);
+    }
+    let d1 = ((f / k).ln() + sd * sd / dec!(2)) / sd;
+    let d2 = d1 - sd;
+    f * norm_cdf(d1) - k * norm_cdf(d2)
+}
+
+fn discount_factor(rate: Decimal, t: Decimal) -> Decimal {
+    Decimal::ONE / (Decimal::ONE + rate).powd(t)
+}
+
+fn validate_input(input: &EarnoutInput) -> CorpFinanceResult<()> {
+    if input.periods.is_empty() {
+        return Err(CorpFinanceError::InsufficientData(
+            "At least one earn-out period is required".into(),
+        ));
+    }
+    if input.base_metric <= Decimal::ZERO {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "base_metric".into(),
+            reason: "Base metric must be positive for a lognormal model".into(),
+        });
+    }
+    if input.volatility < Decimal::ZERO {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "volatility".into(),
+            reason: "Volatility cannot be negative".into(),
+        });
+    }
+    if Decimal::ONE + input.expected_growth - input.metric_risk_premium <= Decimal::ZERO {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "expected_growth".into(),
+            reason: "Risk-adjusted growth must exceed -100%".into(),
+        });
+    }
+    if input.payment_lag_years < Decimal::ZERO {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "payment_lag_years".into(),
+            reason: "Payment lag cannot be negative".into(),
+        });
+    }
+    if input.num_simulations == Some(0) {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "num_simulations".into(),
+            reason: "At least one simulation is required".into(),
+        });
+    }
+    crate::limits::check_simulation_paths(
+        "num_simulations",
+        input.num_simulations.unwrap_or(10_000).into(),
+    )?;
+    let mut years: Vec<u32> = input.periods.iter().map(|p| p.year).collect();
+    years.sort_unstable();
+    if years[0] == 0 || years.windows(2).any(|w| w[0] == w[1]) {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "periods.year".into(),
+            reason: "Period years must be distinct and start from 1".into(),
+        });
+    }
+    for p in &input.periods {
+        let invalid = match &p.payoff {
+            EarnoutPayoff::Binary { payment } => *payment < Decimal::ZERO,
+            EarnoutPayoff::Linear {
+                participation_rate,
+                cap,
+            } => *participation_rate < Decimal::ZERO || cap.is_some_and(|c| c < Decimal::ZERO),
+        };
+        if invalid {
+            return Err(CorpFinanceError::InvalidInput {
+                field: format!("periods[year {}].payoff", p.year),
+                reason: "Payments, rates and caps cannot be negative".into(),
+            });
+        }
+    }
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn approx_eq(a: Decimal, b: Decimal, eps: Decimal) -> bool {
+        (a - b).abs() < eps
+    }
+
+    fn binary(year: u32, threshold: Decimal, payment: Decimal) -> EarnoutPeriod {
+        EarnoutPeriod {
+            year,
+            threshold,
+            payoff: EarnoutPayoff::Binary { payment },
+        }
+    }
+
+    fn base_input() -> EarnoutInput {
+        EarnoutInput {
+            metric: EarnoutMetric::Ebitda,
+            base_metric: dec!(100),
+            expected_growth: dec!(0.10),
+            volatility: dec!(0.25),
+            risk_free_rate: dec!(0.04),
+            metric_risk_premium: dec!(0.05),
+            credit_spread: dec!(0.02),
+            payment_lag_years: Decimal::ZERO,
+            periods: vec![
+                binary(1, dec!(105), dec!(20)),
+                binary(2, dec!(115), dec!(20)),
+            ],
+            catch_up: false,
+            aggregate_cap: None,
+            scenarios: vec![],
+            num_simulations: Some(4_000),
+            seed: None,
+        }
+    }
+
+    #[test]
+    fn test_binary_closed_form() {
+        let out = value_earnout(&base_input()).unwrap();
+        let r = &out.result;
+        // Year 1: F = 105, K = 105, sd = 0.25 => N(-0.125) ~ 0.4503
+        let p1 = prob_above(dec!(105), dec!(105), dec!(0.25));
+        assert!(approx_eq(p1, dec!(0.4503), dec!(0.001)));
+        let cf = r.closed_form_value.unwrap();
+        assert_eq!(r.fair_value, cf);
+        assert!(cf > Decimal::ZERO && cf < dec!(40));
+    }
+
+    #[test]
+    fn test_threshold_far_below_forecast_pays_for_certain() {
+        let mut input = base_input();
+        input.base_metric = dec!(100_000_000);
+        input.volatility = dec!(0.05);
+        input.periods = vec![binary(1, dec!(10_000_000), dec!(20))];
+        let out = value_earnout(&input).unwrap();
+        let r = &out.result;
+        assert_eq!(r.periods[0].probability_of_payment, Decimal::ONE);
+        assert!(approx_eq(
+            r.periods[0].expected_payment,
+            dec!(20),
+            dec!(0.0001)
+        ));
+        assert!(r.fair_value > Decimal::ZERO && r.fair_value < dec!(20));
+    }
+
+    #[test]
+    fn test_monte_carlo_agrees_with_closed_form() {
+        let out = value_earnout(&base_input()).unwrap();
+        let r = &out.result;
+        let cf = r.closed_form_value.unwrap();
+        assert!(
+            (r.monte_carlo_value - cf).abs() < dec!(4) * r.monte_carlo_std_error + dec!(0.2),
+            "MC {} vs CF {}",
+            r.monte_carlo_value,
+            cf
+        );
+    }
+
+    #[test]
+    fn test_linear_capped_closed_form_matches_mc() {
+        let mut input = base_input();
+        input.periods = vec![EarnoutPeriod {
+            year: 2,
+            threshold: dec!(110),
+            payoff: EarnoutPayoff::Linear {
+                participation_rate: dec!(2),
+                cap: Some(dec!(30)),
+            },
+        }];
+        let out = value_earnout(&input).unwrap();
+        let r = &out.result;
+        let cf = r.closed_form_value.unwrap();
+        assert!((r.monte_carlo_value - cf).abs() < dec!(4) * r.monte_carlo_std_error + dec!(0.2));
+        assert_eq!(r.max_payout, Some(dec!(30)));
+        assert!(r.fair_value_pct_of_max.unwrap() < Decimal::ONE);
+    }
+
+    #[test]
+    fn test_uncapped_linear_has_no_max() {
+        let mut input = base_input();
+        input.periods = vec![EarnoutPeriod {
+            year: 1,
+            threshold: dec!(100),
+            payoff: EarnoutPayoff::Linear {
+                participation_rate: dec!(1),
+                cap: None,
+            },
+        }];
+        let out = value_earnout(&input).unwrap();
+        assert!(out.result.max_payout.is_none());
+        assert!(out.result.payout_distribution.prob_max.is_none());
+    }
+
+    #[test]
+    fn test_zero_volatility_is_deterministic() {
+        let mut input = base_input();
+        input.volatility = Decimal::ZERO;
+        let out = value_earnout(&input).unwrap();
+        let r = &out.result;
+        // Risk-adjusted metric: 105, 110.25 -> year 1 met, year 2 missed
+        assert_eq!(r.periods[0].probability_of_payment, Decimal::ONE);
+        assert_eq!(r.periods[1].probability_of_payment, Decimal::ZERO);
+        assert!(approx_eq(r.fair_value, dec!(20) / dec!(1.06), dec!(0.0001)));
+        assert!(approx_eq(r.monte_carlo_value, r.fair_value, dec!(0.0001)));
+    }
+
+    #[test]
+    fn test_catch_up_pays_missed_milestone() {
+        let periods = vec![
+            binary(1, dec!(105), dec!(20)),
+            binary(2, dec!(115), dec!(20)),
+        ];
+        // Miss year 1 by 3, beat year 2 by 5: cumulative target met
+        let no_catch = payouts_for(&periods, &[dec!(102), dec!(120)], false, None);
+        assert_eq!(no_catch, vec![Decimal::ZERO, dec!(20)]);
+        let catch = payouts_for(&periods, &[dec!(102), dec!(120)], true, None);
+        assert_eq!(catch, vec![Decimal::ZERO, dec!(40)]);
+        // Cumulative shortfall: no catch-up
+        let short = payouts_for(&periods, &[dec!(95), dec!(118)], true, None);
+        assert_eq!(short, vec![Decimal::ZERO, dec!(20)]);
+    }
+
+    #[test]
+    fn test_catch_up_linear_never_claws_back() {
+        let periods = vec![
+            EarnoutPeriod {
+                year: 1,
+                threshold: dec!(100),
+                payoff: EarnoutPayoff::Linear {
+                    participation_rate: dec!(1),
+                    cap: None,
+                },
+            },
+            EarnoutPeriod {
+                year: 2,
+                threshold: dec!(100),
+                payoff: EarnoutPayoff::Linear {
+                    participation_rate: dec!(1),
+                    cap: None,
+                },
+            },
+        ];
+        let p = payouts_for(&periods, &[dec!(120), dec!(90)], true, None);
+        assert_eq!(p, vec![dec!(20), Decimal::ZERO]);
+        let p = payouts_for(&periods, &[dec!(90), dec!(130)], true, None);
+        // Cumulative excess 20 -> entitlement 20, own payoff 30
+        assert_eq!(p, vec![Decimal::ZERO, dec!(30)]);
+    }
+
+    #[test]
+    fn test_catch_up_increases_value() {
+        let base = value_earnout(&base_input()).unwrap();
+        let mut input = base_input();
+        input.catch_up = true;
+        let out = value_earnout(&input).unwrap();
+        assert!(out.result.closed_form_value.is_none());
+        assert!(out.result.fair_value > base.result.monte_carlo_value);
+    }
+
+    #[test]
+    fn test_aggregate_cap() {
+        let periods = vec![
+            binary(1, dec!(100), dec!(20)),
+            binary(2, dec!(100), dec!(20)),
+        ];
+        let p = payouts_for(&periods, &[dec!(110), dec!(110)], false, Some(dec!(30)));
+        assert_eq!(p, vec![dec!(20), dec!(10)]);
+
+        let mut input = base_input();
+        input.aggregate_cap = Some(dec!(25));
+        let out = value_earnout(&input).unwrap();
+        assert_eq!(out.result.max_payout, Some(dec!(25)));
+        assert!(out.result.payout_distribution.p90 <= dec!(25));
+    }
+
+    #[test]
+    fn test_credit_spread_and_lag_reduce_value() {
+        let base = value_earnout(&base_input()).unwrap().result.fair_value;
+        let mut input = base_input();
+        input.credit_spread = dec!(0.06);
+        let wider = value_earnout(&input).unwrap().result.fair_value;
+        input.credit_spread = dec!(0.02);
+        input.payment_lag_years = dec!(0.5);
+        let lagged = value_earnout(&input).unwrap().result.fair_value;
+        assert!(wider < base);
+        assert!(lagged < base);
+    }
+
+    #[test]
+    fn test_higher_risk_premium_lowers_value() {
+        let base = value_earnout(&base_input()).unwrap().result.fair_value;
+        let mut input = base_input();
+        input.metric_risk_premium = dec!(0.10);
+        assert!(value_earnout(&input).unwrap().result.fair_value < base);
+    }
+
+    #[test]
+    fn test_scenario_table() {
+        let mut input = base_input();
+        input.scenarios = vec![
+            EarnoutScenario {
+                name: "Management".into(),
+                metrics: vec![dec!(110), dec!(121)],
+            },
+            EarnoutScenario {
+                name: "Downside".into(),
+                metrics: vec![dec!(100), dec!(105)],
+            },
+        ];
+        let out = value_earnout(&input).unwrap();
+        let s = &out.result.scenario_payouts;
+        assert_eq!(s[0].payouts, vec![dec!(20), dec!(20)]);
+        assert_eq!(s[0].total, dec!(40));
+        assert!(s[0].present_value < dec!(40));
+        assert_eq!(s[1].total, Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_scenario_length_mismatch() {
+        let mut input = base_input();
+        input.scenarios = vec![EarnoutScenario {
+            name: "Bad".into(),
+            metrics: vec![dec!(110)],
+        }];
+        assert!(value_earnout(&input).is_err());
+    }
+
+    #[test]
+    fn test_payout_distribution() {
+        let out = value_earnout(&base_input()).unwrap();
+        let d = &out.result.payout_distribution;
+        assert!(d.prob_zero > Decimal::ZERO && d.prob_zero < Decimal::ONE);
+        assert!(d.prob_max.unwrap() > Decimal::ZERO);
+        assert!(d.p10 <= d.p50 && d.p50 <= d.p90);
+        assert_eq!(out.result.max_payout, Some(dec!(40)));
+    }
+
+    #[test]
+    fn test_periods_sorted_by_year() {
+        let mut input = base_input();
+        input.periods.reverse();
+        let out = value_earnout(&input).unwrap();
+        assert_eq!(out.result.periods[0].year, 1);
+        assert_eq!(out.result.periods[1].year, 2);
+    }
+
+    #[test]
+    fn test_invalid_inputs() {
+        let mut input = base_input();
+        input.periods.clear();
+        assert!(value_earnout(&input).is_err());
+
+        let mut input = base_input();
+        input.base_metric = Decimal::ZERO;
+        assert!(value_earnout(&input).is_err());
+
+        let mut input = base_input();
+        input.periods[1].year = 1;
+        assert!(value_earnout(&input).is_err());
+
+        let mut input = base_input();
+        input.periods[0].payoff = EarnoutPayoff::Binary { payment: dec!(-1) };
+        assert!(value_earnout(&input).is_err());
+    }
+}
@@ -0,0 +1,1126 @@
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+use std::time::Instant;
+
+use crate::credit::covenants::{Covenant, CovenantDirection, CovenantMetric, CovenantResult};
+use crate::error::CorpFinanceError;
+use crate::pe::debt_schedule::{self, AmortisationType, DebtTrancheInput};
+use crate::types::*;
+use crate::CorpFinanceResult;
+
+/// Maximum coverage cap when cash interest is zero or near-zero.
+const COVERAGE_CAP: Decimal = dec!(999);
+
+// ---------------------------------------------------------------------------
+// Types
+// ---------------------------------------------------------------------------
+
+/// A debt facility raised to fund the acquisition.
+///
+/// Wraps the PE debt tranche definition with the underwriting economics
+/// that only matter at funding: arrangement fees, OID and the revolver
+/// commitment size.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FinancingTranche {
+    #[serde(flatten)]
+    pub tranche: DebtTrancheInput,
+    /// Upfront / arrangement fee as a fraction of the commitment (or amount).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub upfront_fee_pct: Option<Rate>,
+    /// Original issue discount as a fraction of face value.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub oid_pct: Option<Rate>,
+    /// Total facility size for revolvers; `amount` is the draw at closing.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub commitment: Option<Money>,
+}
+
+/// Combined company operating forecast for one year after closing.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProFormaYearInput {
+    pub ebitda: Money,
+    pub capex: Money,
+    pub cash_taxes: Money,
+    /// Increase in net working capital (positive = cash outflow).
+    pub nwc_change: Money,
+}
+
+/// Inputs for the acquisition financing bridge and pro-forma profile.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FinancingInput {
+    // --- Uses ---
+    /// Equity purchase price paid to target shareholders.
+    pub equity_purchase_price: Money,
+    /// Target debt repaid at closing.
+    pub target_debt_refinanced: Money,
+    /// Advisory, legal and other transaction fees.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transaction_fees: Option<Money>,
+
+    // --- Sources ---
+    /// Balance sheet cash contributed by the acquirer.
+    pub cash_on_hand_used: Money,
+    /// New debt facilities (RCF draw, term loans, bonds).
+    pub tranches: Vec<FinancingTranche>,
+    /// Gross proceeds of new equity issued to fund the deal.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub equity_issuance: Option<Money>,
+    /// Underwriting fee on the equity issuance as a fraction of proceeds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub equity_issuance_fee_pct: Option<Rate>,
+
+    // --- Combined balance sheet ---
+    /// Acquirer debt that remains outstanding after closing.
+    pub acquirer_existing_debt: Money,
+    /// Cash interest rate on the acquirer's existing debt.
+    pub acquirer_existing_debt_rate: Rate,
+    /// Combined cash balance immediately after closing.
+    pub closing_cash: Money,
+    /// Combined LTM EBITDA used for closing leverage.
+    pub ltm_combined_ebitda: Money,
+
+    // --- Forecast ---
+    pub projections: Vec<ProFormaYearInput>,
+    /// Share of excess free cash flow applied to prepayable bank debt.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cash_sweep_pct: Option<Rate>,
+    /// Minimum cash balance; shortfalls are funded on the revolver.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub minimum_cash: Option<Money>,
+    /// Maintenance covenants tested for each forecast year.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub covenants: Option<Vec<Covenant>>,
+}
+
+// ---------------------------------------------------------------------------
+// Output types
+// ---------------------------------------------------------------------------
+
+/// One line of the sources & uses bridge.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BridgeLine {
+    pub name: String,
+    pub amount: Money,
+    /// Share of total sources (or total uses).
+    pub pct_of_total: Rate,
+}
+
+/// Funding economics for a single debt facility.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrancheFundingSummary {
+    pub name: String,
+    pub face_amount: Money,
+    pub upfront_fee: Money,
+    pub oid: Money,
+    /// Cash received at closing (face less OID).
+    pub net_proceeds: Money,
+    /// Cash coupon including base rate for floating facilities.
+    pub cash_rate: Rate,
+    /// Cash rate plus fees and OID amortised straight-line over maturity.
+    pub all_in_cost: Rate,
+}
+
+/// Pro-forma combined credit profile for one forecast year.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProFormaYear {
+    pub year: u32,
+    pub ebitda: Money,
+    pub fcf_before_debt_service: Money,
+    pub cash_interest: Money,
+    pub pik_interest: Money,
+    pub commitment_fees: Money,
+    /// Non-cash amortisation of capitalised fees and OID.
+    pub fee_amortisation: Money,
+    pub mandatory_repayment: Money,
+    pub optional_repayment: Money,
+    pub revolver_draw: Money,
+    pub revolver_balance: Money,
+    pub total_debt: Money,
+    pub cash_balance: Money,
+    pub net_debt: Money,
+    pub total_leverage: Multiple,
+    pub net_leverage: Multiple,
+    /// EBITDA / cash interest (including commitment fees).
+    pub interest_coverage: Multiple,
+    /// (EBITDA - capex - cash taxes) / (cash interest + mandatory repayment).
+    pub dscr: Multiple,
+}
+
+/// Covenant compliance for one forecast year.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CovenantYear {
+    pub year: u32,
+    pub results: Vec<CovenantResult>,
+    pub all_passing: bool,
+    /// Smallest EBITDA decline (as a fraction) that would trip a leverage or
+    /// coverage covenant; negative when already in breach.
+    pub min_ebitda_cushion: Option<Rate>,
+}
+
+/// Results of the financing bridge and pro-forma leverage analysis.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FinancingOutput {
+    // --- Bridge ---
+    pub sources: Vec<BridgeLine>,
+    pub uses: Vec<BridgeLine>,
+    pub total_sources: Money,
+    pub total_uses: Money,
+    /// Uses less sources; positive means the package is under-funded.
+    pub funding_gap: Money,
+    pub tranches: Vec<TrancheFundingSummary>,
+    pub total_financing_fees: Money,
+    pub total_oid: Money,
+    pub new_debt_raised: Money,
+    /// Face-weighted cash cost of the new debt.
+    pub blended_cash_cost: Rate,
+    /// Face-weighted all-in cost of the new debt.
+    pub blended_all_in_cost: Rate,
+
+    // --- Closing credit profile ---
+    pub closing_total_debt: Money,
+    pub closing_net_debt: Money,
+    pub closing_leverage: Multiple,
+    pub closing_net_leverage: Multiple,
+
+    // --- Forecast ---
+    pub projections: Vec<ProFormaYear>,
+    pub peak_net_leverage: Multiple,
+    pub total_debt_repaid: Money,
+    pub covenant_tests: Vec<CovenantYear>,
+    /// First forecast year in which any covenant is breached.
+    pub first_breach_year: Option<u32>,
+}
+
+// ---------------------------------------------------------------------------
+// Core calculation
+// ---------------------------------------------------------------------------
+
+/// Build the acquisition financing bridge, then roll the combined capital
+/// structure forward to produce leverage, coverage and covenant headroom.
+pub fn analyze_financing(
+    input: &FinancingInput,
+) -> CorpFinanceResult<ComputationOutput<FinancingOutput>> {
+    let start = Instant::now();
+    let mut warnings: Vec<String> = Vec::new();
+
+    // ------------------------------------------------------------------
+    // 1. Validate inputs
+    // ------------------------------------------------------------------
+    if input.equity_purchase_price <= Decimal::ZERO {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "equity_purchase_price".into(),
+            reason: "Equity purchase price must be positive".into(),
+        });
+    }
+    if input.target_debt_refinanced < Decimal::ZERO || input.cash_on_hand_used < Decimal::ZERO {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "target_debt_refinanced".into(),
+            reason: "Refinanced debt and cash used cannot be negative".into(),
+        });
+    }
+    if input.ltm_combined_ebitda <= Decimal::ZERO {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "ltm_combined_ebitda".into(),
+            reason: "Combined LTM EBITDA must be positive".into(),
+        });
+    }
+    if input.projections.is_empty() {
+        return Err(CorpFinanceError::InsufficientData(
+            "At least one forecast year is required".into(),
+        ));
+    }
+    for ft in &input.tranches {
+        let t = &ft.tranche;
+        if t.amount < Decimal::ZERO {
+            return Err(CorpFinanceError::InvalidInput {
+                field: "tranches.amount".into(),
+                reason: format!("Tranche '{}' amount cannot be negative", t.name),
+            });
+        }
+        if t.maturity_years == 0 {
+            return Err(CorpFinanceError::InvalidInput {
+                field: "tranches.maturity_years".into(),
+                reason: format!("Tranche '{}' maturity must be at least 1 year", t.name),
+            });
+        }
+        if let Some(c) = ft.commitment {
+            if c < t.amount {
+                return Err(CorpFinanceError::InvalidInput {
+                    field: "tranches.commitment".into(),
+                    reason: format!("Tranche '{}' draw exceeds its commitment", t.name),
+                });
+            }
+        }
+    }
+    let sweep_pct = input.cash_sweep_pct.unwrap_or(Decimal::ZERO);
+    if sweep_pct < Decimal::ZERO || sweep_pct > Decimal::ONE {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "cash_sweep_pct".into(),
+            reason: "Cash sweep must be between 0 and 1".into(),
+        });
+    }
+
+    // ------------------------------------------------------------------
+    // 2. Tranche funding economics
+    // ------------------------------------------------------------------
+    let mut tranche_summaries: Vec<TrancheFundingSummary> = Vec::new();
+    let mut capitalised_costs: Vec<Money> = Vec::new();
+    for ft in &input.tranches {
+        let t = &ft.tranche;
+        let fee_base = ft.commitment.unwrap_or(t.amount);
+        let upfront_fee = fee_base * ft.upfront_fee_pct.unwrap_or(Decimal::ZERO);
+        let oid = t.amount * ft.oid_pct.unwrap_or(Decimal::ZERO);
+        let cash_rate = effective_rate(t);
+        let maturity = Decimal::from(t.maturity_years);
+        let all_in_cost = if t.amount > Decimal::ZERO {
+            cash_rate + (upfront_fee + oid) / t.amount / maturity
+        } else {
+            cash_rate
+        };
+        capitalised_costs.push(upfront_fee + oid);
+        tranche_summaries.push(TrancheFundingSummary {
+            name: t.name.clone(),
+            face_amount: t.amount,
+            upfront_fee,
+            oid,
+            net_proceeds: t.amount - oid,
+            cash_rate,
+            all_in_cost,
+        });
+    }
+
+    let new_debt_raised: Money = tranche_summaries.iter().map(|s| s.face_amount).sum();
+    let total_financing_fees: Money = tranche_summaries.iter().map(|s| s.upfront_fee).sum();
+    let total_oid: Money = tranche_summaries.iter().map(|s| s.oid).sum();
+    let (blended_cash_cost, blended_all_in_cost) = if new_debt_raised > Decimal::ZERO {
+        let cash: Decimal = tranche_summaries
+            .iter()
+            .map(|s| s.face_amount * s.cash_rate)
+            .sum();
+        let all_in: Decimal = tranche_summaries
+            .iter()
+            .map(|s| s.face_amount * s.all_in_cost)
+            .sum();
+        (cash / new_debt_raised, all_in / new_debt_raised)
+    } else {
+        (Decimal::ZERO, Decimal::ZERO)
+    };
+
+    // ------------------------------------------------------------------
+    // 3. Sources & uses bridge
+    // ------------------------------------------------------------------
+    let equity_issuance = input.equity_issuance.unwrap_or(Decimal::ZERO);
+    let equity_fees = equity_issuance * input.equity_issuance_fee_pct.unwrap_or(Decimal::ZERO);
+
+    let mut sources: Vec<(String, Money)> = Vec::new();
+    if input.cash_on_hand_used > Decimal::ZERO {
+        sources.push(("Cash on hand".into(), input.cash_on_hand_used));
+    }
+    for s in &tranche_summaries {
+        if s.face_amount > Decimal::ZERO {
+            sources.push((s.name.clone(), s.face_amount));
+        }
+    }
+    if equity_issuance > Decimal::ZERO {
+        sources.push(("Equity issuance".into(), equity_issuance));
+    }
+
+    let mut uses: Vec<(String, Money)> =
+        vec![("Equity purchase price".into(), input.equity_purchase_price)];
+    if input.target_debt_refinanced > Decimal::ZERO {
+        uses.push(("Refinance target debt".into(), input.target_debt_refinanced));
+    }
+    let transaction_fees = input.transaction_fees.unwrap_or(Decimal::ZERO);
+    if transaction_fees > Decimal::ZERO {
+        uses.push(("Transaction fees".into(), transaction_fees));
+    }
+    if total_financing_fees > Decimal::ZERO {
+        uses.push(("Financing fees".into(), total_financing_fees));
+    }
+    if total_oid > Decimal::ZERO {
+        uses.push(("Original issue discount".into(), total_oid));
+    }
+    if equity_fees > Decimal::ZERO {
+        uses.push(("Equity underwriting fees".into(), equity_fees));
+    }
+
+    let total_sources: Money = sources.iter().map(|(_, a)| *a).sum();
+    let total_uses: Money = uses.iter().map(|(_, a)| *a).sum();
+    let funding_gap = total_uses - total_sources;
+    if funding_gap > Decimal::ZERO {
+        warnings.push(format!(
+            "Financing package is short by {funding_gap}; sources do not cover uses"
+        ));
+    } else if funding_gap < Decimal::ZERO {
+        warnings.push(format!(
+            "Sources exceed uses by {}; excess assumed retained as cash",
+            -funding_gap
+        ));
+    }
+
+    // ------------------------------------------------------------------
+    // 4. Closing credit profile
+    // ------------------------------------------------------------------
+    let closing_total_debt = input.acquirer_existing_debt + new_debt_raised;
+    let closing_net_debt = closing_total_debt - input.closing_cash;
+    let closing_leverage = closing_total_debt / input.ltm_combined_ebitda;
+    let closing_net_leverage = closing_net_debt / input.ltm_combined_ebitda;
+
+    // ------------------------------------------------------------------
+    // 5. Pro-forma roll-forward
+    // ------------------------------------------------------------------
+    // Scheduled amortisation comes from the PE debt schedule builder; interest
+    // is charged on actual opening balances so sweeps and draws flow through.
+    let mut scheduled: Vec<Vec<Money>> = Vec::with_capacity(input.tranches.len());
+    for ft in &input.tranches {
+        if ft.tranche.amount > Decimal::ZERO {
+            let ds = debt_schedule::build_debt_schedule(&ft.tranche)?;
+            scheduled.push(
+                ds.result
+                    .periods
+                    .iter()
+                    .map(|p| p.scheduled_repayment)
+                    .collect(),
+            );
+        } else {
+            scheduled.push(Vec::new());
+        }
+    }
+
+    // Prepayment order: revolvers first, then amortising bank debt by seniority.
+    let mut prepay_order: Vec<usize> = (0..input.tranches.len())
+        .filter(|&i| {
+            let t = &input.tranches[i].tranche;
+            t.is_revolver || !matches!(t.amortisation, AmortisationType::Bullet)
+        })
+        .collect();
+    prepay_order.sort_by_key(|&i| {
+        let t = &input.tranches[i].tranche;
+        (!t.is_revolver, t.seniority)
+    });
+    let revolver_idx = input.tranches.iter().position(|ft| ft.tranche.is_revolver);
+
+    let minimum_cash = input.minimum_cash.unwrap_or(Decimal::ZERO);
+    let mut balances: Vec<Money> = input.tranches.iter().map(|ft| ft.tranche.amount).collect();
+    let mut cash_balance = input.closing_cash;
+    let mut projections: Vec<ProFormaYear> = Vec::with_capacity(input.projections.len());
+    let mut total_debt_repaid = Decimal::ZERO;
+
+    for (idx, py) in input.projections.iter().enumerate() {
+        let year = (idx + 1) as u32;
+
+        // Interest on opening balances
+        let mut cash_interest = input.acquirer_existing_debt * input.acquirer_existing_debt_rate;
+        let mut pik_interest = Decimal::ZERO;
+        let mut commitment_fees = Decimal::ZERO;
+        let mut fee_amortisation = Decimal::ZERO;
+        for (i, ft) in input.tranches.iter().enumerate() {
+            let t = &ft.tranche;
+            cash_interest += balances[i] * effective_rate(t);
+            let pik = balances[i] * t.pik_rate.unwrap_or(Decimal::ZERO);
+            pik_interest += pik;
+            balances[i] += pik;
+            if let (Some(commitment), Some(fee)) = (ft.commitment, t.commitment_fee) {
+                commitment_fees += (commitment - balances[i]).max(Decimal::ZERO) * fee;
+            }
+            if year <= t.maturity_years {
+                fee_amortisation += capitalised_costs[i] / Decimal::from(t.maturity_years);
+            }
+        }
+        let total_cash_interest = cash_interest + commitment_fees;
+
+        let fcf_before_debt_service = py.ebitda - py.capex - py.cash_taxes - py.nwc_change;
+
+        // Mandatory amortisation
+        let mut mandatory_repayment = Decimal::ZERO;
+        for i in 0..balances.len() {
+            if let Some(sched) = scheduled[i].get(idx) {
+                let repay = (*sched).min(balances[i]);
+                balances[i] -= repay;
+                mandatory_repayment += repay;
+            }
+        }
+
+        let fcf_after_mandatory =
+            fcf_before_debt_service - total_cash_interest - mandatory_repayment;
+        cash_balance += fcf_after_mandatory;
+
+        // Revolver draw to restore minimum cash
+        let mut revolver_draw = Decimal::ZERO;
+        if cash_balance < minimum_cash {
+            let shortfall = minimum_cash - cash_balance;
+            let capacity = revolver_idx
+                .map(|r| {
+                    let ft = &input.tranches[r];
+                    (ft.commitment.unwrap_or(ft.tranche.amount) - balances[r]).max(Decimal::ZERO)
+                })
+                .unwrap_or(Decimal::ZERO);
+            revolver_draw = shortfall.min(capacity);
+            if let Some(r) = revolver_idx {
+                balances[r] += revolver_draw;
+            }
+            cash_balance += revolver_draw;
+            if revolver_draw < shortfall {
+                warnings.push(format!(
+                    "Year {year}: liquidity shortfall of {} after revolver availability",
+                    shortfall - revolver_draw
+                ));
+            }
+        }
+
+        // Cash sweep of excess cash above the minimum
+        let mut optional_repayment = Decimal::ZERO;
+        if sweep_pct > Decimal::ZERO
+            && revolver_draw.is_zero()
+            && fcf_after_mandatory > Decimal::ZERO
+        {
+            let excess = (cash_balance - minimum_cash).max(Decimal::ZERO);
+            let mut remaining = (fcf_after_mandatory * sweep_pct).min(excess);
+            for &i in &prepay_order {
+                if remaining <= Decimal::ZERO {
+                    break;
+                }
+                let paydown = remaining.min(balances[i]);
+                balances[i] -= paydown;
+                remaining -= paydown;
+                optional_repayment += paydown;
+            }
+            cash_balance -= optional_repayment;
+        }
+        total_debt_repaid += mandatory_repayment + optional_repayment;
+
+        let total_debt: Money =
+            input.acquirer_existing_debt + balances.iter().copied().sum::<Money>();
+        let net_debt = total_debt - cash_balance;
+        let (total_leverage, net_leverage) = if py.ebitda > Decimal::ZERO {
+            (total_debt / py.ebitda, net_debt / py.ebitda)
+        } else {
+            warnings.push(format!(
+                "Year {year}: non-positive EBITDA; leverage not meaningful"
+            ));
+            (COVERAGE_CAP, COVERAGE_CAP)
+        };
+        let interest_coverage = if total_cash_interest > Decimal::ZERO {
+            (py.ebitda / total_cash_interest).min(COVERAGE_CAP)
+        } else {
+            COVERAGE_CAP
+        };
+        let debt_service = total_cash_interest + mandatory_repayment;
+        let dscr = if debt_service > Decimal::ZERO {
+            ((py.ebitda - py.capex - py.cash_taxes) / debt_service).min(COVERAGE_CAP)
+        } else {
+            COVERAGE_CAP
+        };
+
+        projections.push(ProFormaYear {
+            year,
+            ebitda: py.ebitda,
+            fcf_before_debt_service,
+            cash_interest: total_cash_interest,
+            pik_interest,
+            commitment_fees,
+            fee_amortisation,
+            mandatory_repayment,
+            optional_repayment,
+            revolver_draw,
+            revolver_balance: revolver_idx.map(|r| balances[r]).unwrap_or(Decimal::ZERO),
+            total_debt,
+            cash_balance,
+            net_debt,
+            total_leverage,
+            net_leverage,
+            interest_coverage,
+            dscr,
+        });
+    }
+
+    for (i, ft) in input.tranches.iter().enumerate() {
+        let t = &ft.tranche;
+        if (t.maturity_years as usize) <= input.projections.len() && balances[i] > Decimal::ZERO {
+            warnings.push(format!(
+                "Tranche '{}' matures in year {} within the forecast with {} outstanding",
+                t.name, t.maturity_years, balances[i]
+            ));
+        }
+    }
+
+    let peak_net_leverage = projections
+        .iter()
+        .map(|p| p.net_leverage)
+        .fold(closing_net_leverage, Decimal::max);
+
+    // ------------------------------------------------------------------
+    // 6. Covenant headroom
+    // ------------------------------------------------------------------
+    let covenant_tests = match &input.covenants {
+        Some(covs) if !covs.is_empty() => test_covenants_by_year(covs, &projections, &mut warnings),
+        _ => Vec::new(),
+    };
+    let first_breach_year = covenant_tests
+        .iter()
+        .find(|c| !c.all_passing)
+        .map(|c| c.year);
+    if let Some(y) = first_breach_year {
+        warnings.push(format!("Covenant breach projected in year {y}"));
+    }
+
+    let output = FinancingOutput {
+        sources: to_bridge_lines(&sources, total_sources),
+        uses: to_bridge_lines(&uses, total_uses),
+        total_sources,
+        total_uses,
+        funding_gap,
+        tranches: tranche_summaries,
+        total_financing_fees,
+        total_oid,
+        new_debt_raised,
+        blended_cash_cost,
+        blended_all_in_cost,
+        closing_total_debt,
+        closing_net_debt,
+        closing_leverage,
+        closing_net_leverage,
+        projections,
+        peak_net_leverage,
+        total_debt_repaid,
+        covenant_tests,
+        first_breach_year,
+    };
+
+    let elapsed = start.elapsed().as_micros() as u64;
+    Ok(with_metadata(
+        "Acquisition Financing Bridge & Pro-Forma Leverage Profile",
+        &serde_json::json!({
+            "tranche_count": input.tranches.len(),
+            "forecast_years": input.projections.len(),
+            "cash_sweep_pct": sweep_pct.to_string(),
+            "minimum_cash": minimum_cash.to_string(),
+            "interest_basis": "opening balances",
+        }),
+        warnings,
+        elapsed,
+        output,
+    ))
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+fn effective_rate(t: &DebtTrancheInput) -> Rate {
+    if t.is_floating {
+        t.base_rate.unwrap_or(Decimal::ZERO) + t.spread.unwrap_or(t.interest_rate)
+    } else {
+        t.interest_rate
+    }
+}
+
+fn to_bridge_lines(lines: &[(String, Money)], total: Money) -> Vec<BridgeLine> {
+    lines
+        .iter()
+        .map(|(name, amount)| BridgeLine {
+            name: name.clone(),
+            amount: *amount,
+            pct_of_total: if total > Decimal::ZERO {
+                *amount / total
+            } else {
+                Decimal::ZERO
+            },
+        })
+        .collect()
+}
+
+fn test_covenants_by_year(
+    covenants: &[Covenant],
+    projections: &[ProFormaYear],
+    warnings: &mut Vec<String>,
+) -> Vec<CovenantYear> {
+    for cov in covenants {
+        if metric_value(&cov.metric, &projections[0]).is_none() {
+            warnings.push(format!(
+                "Covenant '{}': metric {:?} not available in pro-forma profile; skipped.",
+                cov.name, cov.metric
+            ));
+        }
+    }
+
+    projections
+        .iter()
+        .map(|p| {
+            let mut results = Vec::new();
+            let mut min_cushion: Option<Rate> = None;
+            for cov in covenants {
+                let actual = match metric_value(&cov.metric, p) {
+                    Some(v) => v,
+                    None => continue,
+                };
+                let (passing, headroom) = match cov.direction {
+                    CovenantDirection::MaxOf => (actual <= cov.threshold, cov.threshold - actual),
+                    CovenantDirection::MinOf => (actual >= cov.threshold, actual - cov.threshold),
+                };
+                let headroom_pct = if cov.threshold.is_zero() {
+                    Decimal::ZERO
+                } else {
+                    headroom / cov.threshold
+                };
+                if let Some(c) = ebitda_cushion(cov, p) {
+                    min_cushion = Some(min_cushion.map_or(c, |m| m.min(c)));
+                }
+                results.push(CovenantResult {
+                    covenant: cov.name.clone(),
+                    threshold: cov.threshold,
+                    actual,
+                    passing,
+                    headroom,
+                    headroom_pct,
+                });
+            }
+            CovenantYear {
+                year: p.year,
+                all_passing: results.iter().all(|r| r.passing),
+                results,
+                min_ebitda_cushion: min_cushion,
+            }
+        })
+        .collect()
+}
+
+/// Map a covenant metric to the pro-forma value. `MinCash` tests the
+/// absolute cash balance; other metrics are not modelled here.
+fn metric_value(metric: &CovenantMetric, p: &ProFormaYear) -> Option<Decimal> {
+    match metric {
+        CovenantMetric::NetDebtToEbitda => Some(p.net_leverage),
+        CovenantMetric::InterestCoverage => Some(p.interest_coverage),
+        CovenantMetric::Dscr => Some(p.dscr),
+        CovenantMetric::MinCash => Some(p.cash_balance),
+        _ => None,
+    }
+}
+
+/// EBITDA decline (fraction) that would take a leverage or coverage
+/// covenant exactly to its threshold.
+fn ebitda_cushion(cov: &Covenant, p: &ProFormaYear) -> Option<Rate> {
+    if p.ebitda <= Decimal::ZERO || cov.threshold <= Decimal::ZERO {
+        return None;
+    }
+    match (&cov.metric, &cov.direction) {
+        (CovenantMetric::NetDebtToEbitda, CovenantDirection::MaxOf) => {
+            Some(Decimal::ONE - p.net_debt / (cov.threshold * p.ebitda))
+        }
+        (CovenantMetric::InterestCoverage, CovenantDirection::MinOf) => {
+            Some(Decimal::ONE - cov.threshold * p.cash_interest / p.ebitda)
+        }
+        _ => None,
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tranche(
+        name: &str,
+        amount: Decimal,
+        rate: Decimal,
+        amortisation: AmortisationType,
+        maturity: u32,
+        seniority: u32,
+    ) -> FinancingTranche {
+        FinancingTranche {
+            tranche: DebtTrancheInput {
+                name: name.into(),
+                amount,
+                interest_rate: rate,
+                is_floating: false,
+                base_rate: None,
+                spread: None,
+                amortisation,
+                maturity_years: maturity,
+                pik_rate: None,
+                seniority,
+                commitment_fee: None,
+                is_revolver: false,
+            },
+            upfront_fee_pct: None,
+            oid_pct: None,
+            commitment: None,
+        }
+    }
+
+    fn forecast_year(ebitda: Decimal) -> ProFormaYearInput {
+        ProFormaYearInput {
+            ebitda,
+            capex: dec!(100),
+            cash_taxes: dec!(80),
+            nwc_change: dec!(20),
+        }
+    }
+
+    fn sample_input() -> FinancingInput {
+        let mut rcf = tranche("RCF", dec!(100), dec!(0.06), AmortisationType::Bullet, 5, 1);
+        rcf.tranche.is_revolver = true;
+        rcf.tranche.commitment_fee = Some(dec!(0.005));
+        rcf.commitment = Some(dec!(500));
+        rcf.upfront_fee_pct = Some(dec!(0.01));
+
+        let mut tla = tranche(
+            "Term Loan A",
+            dec!(1000),
+            dec!(0.065),
+            AmortisationType::StraightLine(dec!(0.10)),
+            5,
+            1,
+        );
+        tla.upfront_fee_pct = Some(dec!(0.015));
+
+        let mut notes = tranche(
+            "Senior Notes",
+            dec!(800),
+            dec!(0.075),
+            AmortisationType::Bullet,
+            8,
+            2,
+        );
+        notes.upfront_fee_pct = Some(dec!(0.0125));
+        notes.oid_pct = Some(dec!(0.01));
+
+        FinancingInput {
+            equity_purchase_price: dec!(2500),
+            target_debt_refinanced: dec!(400),
+            transaction_fees: Some(dec!(40)),
+            cash_on_hand_used: dec!(200),
+            tranches: vec![rcf, tla, notes],
+            equity_issuance: Some(dec!(900)),
+            equity_issuance_fee_pct: Some(dec!(0.03)),
+            acquirer_existing_debt: dec!(600),
+            acquirer_existing_debt_rate: dec!(0.05),
+            closing_cash: dec!(150),
+            ltm_combined_ebitda: dec!(800),
+            projections: vec![
+                forecast_year(dec!(850)),
+                forecast_year(dec!(900)),
+                forecast_year(dec!(950)),
+            ],
+            cash_sweep_pct: None,
+            minimum_cash: Some(dec!(100)),
+            covenants: None,
+        }
+    }
+
+    fn approx(a: Decimal, b: Decimal, tol: Decimal) -> bool {
+        (a - b).abs() <= tol
+    }
+
+    #[test]
+    fn test_tranche_fees_and_oid() {
+        let out = analyze_financing(&sample_input()).unwrap().result;
+        // RCF fee on commitment: 500 * 1% = 5
+        assert_eq!(out.tranches[0].upfront_fee, dec!(5));
+        assert_eq!(out.tranches[1].upfront_fee, dec!(15));
+        assert_eq!(out.tranches[2].upfront_fee, dec!(10));
+        assert_eq!(out.tranches[2].oid, dec!(8));
+        assert_eq!(out.tranches[2].net_proceeds, dec!(792));
+        assert_eq!(out.total_financing_fees, dec!(30));
+        assert_eq!(out.total_oid, dec!(8));
+    }
+
+    #[test]
+    fn test_sources_and_uses_totals() {
+        let out = analyze_financing(&sample_input()).unwrap().result;
+        // Sources: 200 + 100 + 1000 + 800 + 900 = 3000
+        assert_eq!(out.total_sources, dec!(3000));
+        // Uses: 2500 + 400 + 40 + 30 + 8 + 27 = 3005
+        assert_eq!(out.total_uses, dec!(3005));
+        assert_eq!(out.funding_gap, dec!(5));
+        assert_eq!(out.sources.len(), 5);
+        assert_eq!(out.uses.len(), 6);
+    }
+
+    #[test]
+    fn test_funding_gap_warns() {
+        let result = analyze_financing(&sample_input()).unwrap();
+        assert!(result.warnings.iter().any(|w| w.contains("short by")));
+    }
+
+    #[test]
+    fn test_bridge_pct_sums_to_one() {
+        let out = analyze_financing(&sample_input()).unwrap().result;
+        let s: Decimal = out.sources.iter().map(|l| l.pct_of_total).sum();
+        let u: Decimal = out.uses.iter().map(|l| l.pct_of_total).sum();
+        assert!(approx(s, Decimal::ONE, dec!(0.000001)));
+        assert!(approx(u, Decimal::ONE, dec!(0.000001)));
+    }
+
+    #[test]
+    fn test_all_in_cost_exceeds_cash_cost() {
+        let out = analyze_financing(&sample_input()).unwrap().result;
+        // Notes: 7.5% + (10 + 8) / 800 / 8
+        assert!(approx(
+            out.tranches[2].all_in_cost,
+            dec!(0.075) + dec!(18) / dec!(800) / dec!(8),
+            dec!(0.0000001)
+        ));
+        assert!(out.blended_all_in_cost > out.blended_cash_cost);
+    }
+
+    #[test]
+    fn test_blended_cash_cost() {
+        let out = analyze_financing(&sample_input()).unwrap().result;
+        let expected =
+            (dec!(100) * dec!(0.06) + dec!(1000) * dec!(0.065) + dec!(800) * dec!(0.075))
+                / dec!(1900);
+        assert!(approx(out.blended_cash_cost, expected, dec!(0.0000001)));
+    }
+
+    #[test]
+    fn test_closing_leverage() {
+        let out = analyze_financing(&sample_input()).unwrap().result;
+        assert_eq!(out.closing_total_debt, dec!(2500));
+        assert_eq!(out.closing_net_debt, dec!(2350));
+        assert_eq!(out.closing_leverage, dec!(3.125));
+        assert!(approx(
+            out.closing_net_leverage,
+            dec!(2.9375),
+            dec!(0.0000001)
+        ));
+    }
+
+    #[test]
+    fn test_year_one_interest_and_amortisation() {
+        let out = analyze_financing(&sample_input()).unwrap().result;
+        let y1 = &out.projections[0];
+        // Existing 600*5% + RCF 100*6% + TLA 1000*6.5% + notes 800*7.5% = 30+6+65+60 = 161
+        // Commitment fee on undrawn RCF: 400 * 0.5% = 2
+        assert_eq!(y1.commitment_fees, dec!(2));
+        assert_eq!(y1.cash_interest, dec!(163));
+        assert_eq!(y1.mandatory_repayment, dec!(100));
+    }
+
+    #[test]
+    fn test_year_one_cash_and_leverage() {
+        let out = analyze_financing(&sample_input()).unwrap().result;
+        let y1 = &out.projections[0];
+        // FCF = 850 - 100 - 80 - 20 = 650 ; after service 650 - 163 - 100 = 387
+        assert_eq!(y1.fcf_before_debt_service, dec!(650));
+        assert_eq!(y1.cash_balance, dec!(537));
+        assert_eq!(y1.total_debt, dec!(2400));
+        assert_eq!(y1.net_debt, dec!(1863));
+        assert!(approx(
+            y1.net_leverage,
+            dec!(1863) / dec!(850),
+            dec!(0.0000001)
+        ));
+    }
+
+    #[test]
+    fn test_coverage_and_dscr() {
+        let out = analyze_financing(&sample_input()).unwrap().result;
+        let y1 = &out.projections[0];
+        assert!(approx(
+            y1.interest_coverage,
+            dec!(850) / dec!(163),
+            dec!(0.0000001)
+        ));
+        assert!(approx(y1.dscr, dec!(670) / dec!(263), dec!(0.0000001)));
+    }
+
+    #[test]
+    fn test_fee_amortisation_non_cash() {
+        let out = analyze_financing(&sample_input()).unwrap().result;
+        // RCF 5/5 + TLA 15/5 + notes 18/8 = 1 + 3 + 2.25
+        assert_eq!(out.projections[0].fee_amortisation, dec!(6.25));
+    }
+
+    #[test]
+    fn test_cash_sweep_prepays_revolver_first() {
+        let mut input = sample_input();
+        input.cash_sweep_pct = Some(dec!(0.5));
+        let out = analyze_financing(&input).unwrap().result;
+        let y1 = &out.projections[0];
+        // Sweep 50% of 387 = 193.5: RCF 100 repaid, then TLA 93.5
+        assert_eq!(y1.optional_repayment, dec!(193.5));
+        assert_eq!(y1.revolver_balance, Decimal::ZERO);
+        assert_eq!(y1.total_debt, dec!(2206.5));
+    }
+
+    #[test]
+    fn test_sweep_does_not_touch_bullet_bonds() {
+        let mut input = sample_input();
+        input.cash_sweep_pct = Some(Decimal::ONE);
+        let out = analyze_financing(&input).unwrap().result;
+        let last = out.projections.last().unwrap();
+        // Existing debt (600) and notes (800) remain after bank debt is repaid
+        assert!(last.total_debt >= dec!(1400));
+    }
+
+    #[test]
+    fn test_revolver_draw_funds_shortfall() {
+        let mut input = sample_input();
+        input.closing_cash = dec!(100);
+        input.projections = vec![ProFormaYearInput {
+            ebitda: dec!(200),
+            capex: dec!(100),
+            cash_taxes: dec!(0),
+            nwc_change: dec!(50),
+        }];
+        let out = analyze_financing(&input).unwrap().result;
+        let y1 = &out.projections[0];
+        // FCF 50 - interest 163 - amort 100 = -213 -> draw 213 to restore 100
+        assert_eq!(y1.revolver_draw, dec!(213));
+        assert_eq!(y1.revolver_balance, dec!(313));
+        assert_eq!(y1.cash_balance, dec!(100));
+    }
+
+    #[test]
+    fn test_liquidity_shortfall_beyond_revolver_warns() {
+        let mut input = sample_input();
+        input.closing_cash = dec!(100);
+        input.projections = vec![ProFormaYearInput {
+            ebitda: dec!(-300),
+            capex: dec!(100),
+            cash_taxes: dec!(0),
+            nwc_change: dec!(0),
+        }];
+        let result = analyze_financing(&input).unwrap();
+        assert_eq!(result.result.projections[0].revolver_balance, dec!(500));
+        assert!(result
+            .warnings
+            .iter()
+            .any(|w| w.contains("liquidity shortfall")));
+    }
+
+    #[test]
+    fn test_deleveraging_over_forecast() {
+        let out = analyze_financing(&sample_input()).unwrap().result;
+        let first = out.projections.first().unwrap().net_leverage;
+        let last = out.projections.last().unwrap().net_leverage;
+        assert!(last < first);
+        assert_eq!(out.total_debt_repaid, dec!(300));
+        assert_eq!(out.peak_net_leverage, out.closing_net_leverage);
+    }
+
+    #[test]
+    fn test_covenants_headroom_and_cushion() {
+        let mut input = sample_input();
+        input.covenants = Some(vec![
+            Covenant {
+                name: "Max Net Leverage".into(),
+                metric: CovenantMetric::NetDebtToEbitda,
+                threshold: dec!(3.5),
+                direction: CovenantDirection::MaxOf,
+            },
+            Covenant {
+                name: "Min Interest Cover".into(),
+                metric: CovenantMetric::InterestCoverage,
+                threshold: dec!(3.0),
+                direction: CovenantDirection::MinOf,
+            },
+        ]);
+        let out = analyze_financing(&input).unwrap().result;
+        assert_eq!(out.covenant_tests.len(), 3);
+        let y1 = &out.covenant_tests[0];
+        assert!(y1.all_passing);
+        assert_eq!(y1.results.len(), 2);
+        // Leverage cushion: 1 - 1863 / (3.5 * 850)
+        let lev_cushion = Decimal::ONE - dec!(1863) / (dec!(3.5) * dec!(850));
+        // Coverage cushion: 1 - 3 * 163 / 850
+        let cov_cushion = Decimal::ONE - dec!(3) * dec!(163) / dec!(850);
+        let expected = lev_cushion.min(cov_cushion);
+        assert!(approx(
+            y1.min_ebitda_cushion.unwrap(),
+            expected,
+            dec!(0.0000001)
+        ));
+        assert!(out.first_breach_year.is_none());
+    }
+
+    #[test]
+    fn test_covenant_breach_detected() {
+        let mut input = sample_input();
+        input.covenants = Some(vec![Covenant {
+            name: "Max Net Leverage".into(),
+            metric: CovenantMetric::NetDebtToEbitda,
+            threshold: dec!(2.0),
+            direction: CovenantDirection::MaxOf,
+        }]);
+        let result = analyze_financing(&input).unwrap();
+        assert_eq!(result.result.first_breach_year, Some(1));
+        assert!(result.result.covenant_tests[0].min_ebitda_cushion.unwrap() < Decimal::ZERO);
+        assert!(result.warnings.iter().any(|w| w.contains("breach")));
+    }
+
+    #[test]
+    fn test_unsupported_covenant_metric_skipped() {
+        let mut input = sample_input();
+        input.covenants = Some(vec![Covenant {
+            name: "Max D/E".into(),
+            metric: CovenantMetric::DebtToEquity,
+            threshold: dec!(2.0),
+            direction: CovenantDirection::MaxOf,
+        }]);
+        let result = analyze_financing(&input).unwrap();
+        assert!(result.result.covenant_tests[0].results.is_empty());
+        assert!(result.warnings.iter().any(|w| w.contains("skipped")));
+    }
+
+    #[test]
+    fn test_floating_rate_tranche() {
+        let mut input = sample_input();
+        input.tranches[1].tranche.is_floating = true;
+        input.tranches[1].tranche.base_rate = Some(dec!(0.04));
+        input.tranches[1].tranche.spread = Some(dec!(0.03));
+        let out = analyze_financing(&input).unwrap().result;
+        assert_eq!(out.tranches[1].cash_rate, dec!(0.07));
+    }
+
+    #[test]
+    fn test_maturity_inside_forecast_warns() {
+        let mut input = sample_input();
+        input.tranches[2].tranche.maturity_years = 2;
+        let result = analyze_financing(&input).unwrap();
+        // Notes are repaid by the schedule at maturity, so no outstanding warning
+        assert!(!result
+            .warnings
+            .iter()
+            .any(|w| w.contains("Senior Notes") && w.contains("matures")));
+        assert_eq!(
+            result.result.projections[1].total_debt,
+            result.result.projections[0].total_debt - dec!(100) - dec!(800)
+        );
+    }
+
+    #[test]
+    fn test_draw_above_commitment_rejected() {
+        let mut input = sample_input();
+        input.tranches[0].commitment = Some(dec!(50));
+        assert!(analyze_financing(&input).is_err());
+    }
+
+    #[test]
+    fn test_empty_projections_rejected() {
+        let mut input = sample_input();
+        input.projections.clear();
+        assert!(matches!(
+            analyze_financing(&input),
+            Err(CorpFinanceError::InsufficientData(_))
+        ));
+    }
+
+    #[test]
+    fn test_invalid_ltm_ebitda() {
+        let mut input = sample_input();
+        input.ltm_combined_ebitda = Decimal::ZERO;
+        assert!(analyze_financing(&input).is_err());
+    }
+
+    #[test]
+    fn test_methodology() {
+        let result = analyze_financing(&sample_input()).unwrap();
+        assert!(result.methodology.contains("Financing Bridge"));
+    }
+}
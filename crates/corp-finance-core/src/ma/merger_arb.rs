@@ -0,0 +1,606 @@
+use rust_decimal::Decimal;
+use rust_decimal::MathematicalOps;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+use std::time::Instant;
+
+use crate::error::CorpFinanceError;
+use crate::portfolio::sizing::{calculate_kelly, KellyInput, KellyOutput};
+use crate::types::*;
+use crate::CorpFinanceResult;
+
+// ---------------------------------------------------------------------------
+// Types
+// ---------------------------------------------------------------------------
+
+/// Inputs for a merger arbitrage spread analysis on a pending deal.
+///
+/// Consideration per target share is `cash_per_share` plus
+/// `exchange_ratio` acquirer shares; either component may be zero, which
+/// covers all-cash, all-stock and mixed deals.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MergerArbInput {
+    pub target_name: String,
+    /// Current market price of the target.
+    pub target_price: Money,
+    /// Cash paid per target share at closing.
+    pub cash_per_share: Money,
+    /// Acquirer shares delivered per target share (0 for all-cash deals).
+    pub exchange_ratio: Decimal,
+    /// Current acquirer share price (required when `exchange_ratio` > 0).
+    pub acquirer_price: Option<Money>,
+    /// Estimated target price if the deal breaks (undisturbed / fallback value).
+    pub downside_price: Money,
+    /// Calendar days until the expected closing date.
+    pub days_to_close: u32,
+    /// Annual risk-free rate used for carry and excess return.
+    pub risk_free_rate: Rate,
+    /// Target dividends expected to be received before closing.
+    pub target_dividends: Option<Money>,
+    /// Acquirer dividends per acquirer share owed on the short hedge before closing.
+    pub acquirer_dividends: Option<Money>,
+    /// Analyst's own estimate of the probability the deal closes. Used for
+    /// expected return and Kelly sizing; defaults to the market-implied probability.
+    pub completion_probability: Option<Rate>,
+    /// Fraction of full Kelly to apply (e.g. 0.5 for half Kelly). Defaults to 1.
+    pub kelly_fraction: Option<Rate>,
+    /// Portfolio value used to size the position in currency terms.
+    pub portfolio_value: Option<Money>,
+    /// Maximum position as a fraction of the portfolio.
+    pub max_position_pct: Option<Rate>,
+}
+
+/// Results of the merger arbitrage analysis.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MergerArbOutput {
+    // --- Spread ---
+    /// Value of the offer per target share at current acquirer price.
+    pub deal_value_per_share: Money,
+    /// Offer value less current target price.
+    pub gross_spread: Money,
+    /// Gross spread as a fraction of the target price.
+    pub gross_spread_pct: Rate,
+    /// Gross spread plus target dividends less dividends owed on the hedge.
+    pub net_spread: Money,
+    /// Net spread as a fraction of the target price.
+    pub net_spread_pct: Rate,
+
+    // --- Returns ---
+    /// Time to close in years (days / 365).
+    pub years_to_close: Years,
+    /// Net spread return annualised on a simple basis.
+    pub annualized_return_simple: Rate,
+    /// Net spread return annualised on a compound basis.
+    pub annualized_return_compound: Rate,
+    /// Compound annualised return less the risk-free rate.
+    pub excess_annualized_return: Rate,
+
+    // --- Probability ---
+    /// Upside per share if the deal closes (net spread).
+    pub upside_per_share: Money,
+    /// Loss per share if the deal breaks (target price less downside price).
+    pub downside_per_share: Money,
+    /// Ratio of upside to downside.
+    pub upside_downside_ratio: Decimal,
+    /// Completion probability implied by the spread, ignoring financing carry.
+    pub implied_probability_no_carry: Rate,
+    /// Completion probability implied by the spread after risk-free carry to closing.
+    pub implied_probability: Rate,
+    /// Probability used for expected return and sizing.
+    pub completion_probability_used: Rate,
+    /// Probability-weighted return to closing as a fraction of the target price.
+    pub expected_return: Rate,
+    /// Expected return annualised on a simple basis.
+    pub expected_annualized_return: Rate,
+
+    // --- Hedge & sizing ---
+    /// Acquirer shares to short per target share held.
+    pub hedge_ratio: Decimal,
+    /// Kelly sizing on the binary close / break outcome, net of risk-free carry.
+    pub kelly: KellyOutput,
+}
+
+// ---------------------------------------------------------------------------
+// Core calculation
+// ---------------------------------------------------------------------------
+
+pub fn analyze_merger_arb(
+    input: &MergerArbInput,
+) -> CorpFinanceResult<ComputationOutput<MergerArbOutput>> {
+    let start = Instant::now();
+    let mut warnings: Vec<String> = Vec::new();
+
+    // ------------------------------------------------------------------
+    // 1. Validate inputs
+    // ------------------------------------------------------------------
+    if input.target_price <= Decimal::ZERO {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "target_price".into(),
+            reason: "Target price must be positive".into(),
+        });
+    }
+    if input.cash_per_share < Decimal::ZERO {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "cash_per_share".into(),
+            reason: "Cash consideration cannot be negative".into(),
+        });
+    }
+    if input.exchange_ratio < Decimal::ZERO {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "exchange_ratio".into(),
+            reason: "Exchange ratio cannot be negative".into(),
+        });
+    }
+    if input.downside_price < Decimal::ZERO {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "downside_price".into(),
+            reason: "Downside price cannot be negative".into(),
+        });
+    }
+    if input.days_to_close == 0 {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "days_to_close".into(),
+            reason: "Days to close must be at least 1".into(),
+        });
+    }
+    if let Some(p) = input.completion_probability {
+        if p <= Decimal::ZERO || p >= Decimal::ONE {
+            return Err(CorpFinanceError::InvalidInput {
+                field: "completion_probability".into(),
+                reason: "Completion probability must be between 0 and 1 exclusive".into(),
+            });
+        }
+    }
+
+    let acquirer_price = if input.exchange_ratio > Decimal::ZERO {
+        match input.acquirer_price {
+            Some(p) if p > Decimal::ZERO => p,
+            _ => {
+                return Err(CorpFinanceError::InvalidInput {
+                    field: "acquirer_price".into(),
+                    reason: "A positive acquirer price is required for stock consideration".into(),
+                })
+            }
+        }
+    } else {
+        Decimal::ZERO
+    };
+
+    // ------------------------------------------------------------------
+    // 2. Spread
+    // ------------------------------------------------------------------
+    let stock_value = input.exchange_ratio * acquirer_price;
+    let deal_value_per_share = input.cash_per_share + stock_value;
+    if deal_value_per_share <= Decimal::ZERO {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "cash_per_share".into(),
+            reason: "Deal consideration must be positive".into(),
+        });
+    }
+
+    let gross_spread = deal_value_per_share - input.target_price;
+    let gross_spread_pct = gross_spread / input.target_price;
+
+    let target_divs = input.target_dividends.unwrap_or(Decimal::ZERO);
+    let hedge_divs = input.acquirer_dividends.unwrap_or(Decimal::ZERO) * input.exchange_ratio;
+    let net_spread = gross_spread + target_divs - hedge_divs;
+    let net_spread_pct = net_spread / input.target_price;
+
+    if gross_spread < Decimal::ZERO {
+        warnings.push(format!(
+            "Negative spread: target trades {} above offer value (market may expect a higher bid)",
+            -gross_spread
+        ));
+    }
+
+    // ------------------------------------------------------------------
+    // 3. Annualised returns
+    // ------------------------------------------------------------------
+    let years_to_close = Decimal::from(input.days_to_close) / dec!(365);
+    let annualized_return_simple = net_spread_pct / years_to_close;
+    let annualized_return_compound = if net_spread_pct > dec!(-1) {
+        (Decimal::ONE + net_spread_pct).powd(Decimal::ONE / years_to_close) - Decimal::ONE
+    } else {
+        dec!(-1)
+    };
+    let excess_annualized_return = annualized_return_compound - input.risk_free_rate;
+
+    // ------------------------------------------------------------------
+    // 4. Market-implied completion probability
+    // ------------------------------------------------------------------
+    // Price = p * (deal value + net dividends) + (1 - p) * downside, discounted
+    // at the risk-free rate to closing for the carry-adjusted version.
+    let upside_per_share = net_spread;
+    let downside_per_share = input.target_price - input.downside_price;
+    let close_payoff = input.target_price + net_spread;
+    let payoff_range = close_payoff - input.downside_price;
+    if payoff_range <= Decimal::ZERO {
+        return Err(CorpFinanceError::FinancialImpossibility(
+            "Deal payoff does not exceed the downside price; completion probability is undefined"
+                .into(),
+        ));
+    }
+
+    let carry_price = input.target_price * (Decimal::ONE + input.risk_free_rate * years_to_close);
+    let implied_probability_no_carry =
+        clamp_probability((input.target_price - input.downside_price) / payoff_range);
+    let implied_probability =
+        clamp_probability((carry_price - input.downside_price) / payoff_range);
+
+    if downside_per_share <= Decimal::ZERO {
+        warnings.push(
+            "Target trades at or below the downside price; spread implies no break risk".into(),
+        );
+    }
+
+    let upside_downside_ratio = if downside_per_share > Decimal::ZERO {
+        upside_per_share / downside_per_share
+    } else {
+        Decimal::ZERO
+    };
+
+    // ------------------------------------------------------------------
+    // 5. Expected return
+    // ------------------------------------------------------------------
+    let completion_probability_used = match input.completion_probability {
+        Some(p) => p,
+        None => {
+            warnings.push(
+                "No completion probability supplied; using market-implied probability (no edge)"
+                    .into(),
+            );
+            implied_probability
+        }
+    };
+    let p = completion_probability_used;
+    let expected_pnl = p * upside_per_share - (Decimal::ONE - p) * downside_per_share;
+    let expected_return = expected_pnl / input.target_price;
+    let expected_annualized_return = expected_return / years_to_close;
+
+    // ------------------------------------------------------------------
+    // 6. Kelly sizing on the binary outcome
+    // ------------------------------------------------------------------
+    // Payoffs are measured against the carry-adjusted price so that sizing
+    // at the market-implied probability yields zero edge over cash.
+    let kelly_win = close_payoff - carry_price;
+    let kelly_loss = carry_price - input.downside_price;
+    let kelly = if kelly_win > Decimal::ZERO
+        && kelly_loss > Decimal::ZERO
+        && p > Decimal::ZERO
+        && p < Decimal::ONE
+    {
+        let kelly_input = KellyInput {
+            win_probability: p,
+            win_loss_ratio: kelly_win / kelly_loss,
+            kelly_fraction: input.kelly_fraction.unwrap_or(Decimal::ONE),
+            portfolio_value: input.portfolio_value,
+            max_position_pct: input.max_position_pct,
+        };
+        let sized = calculate_kelly(&kelly_input)?;
+        warnings.extend(sized.warnings);
+        sized.result
+    } else {
+        warnings.push("Spread does not exceed risk-free carry; no position sized".into());
+        KellyOutput {
+            full_kelly_pct: Decimal::ZERO,
+            fractional_kelly_pct: Decimal::ZERO,
+            recommended_position: input.portfolio_value.map(|_| Decimal::ZERO),
+            edge: Decimal::ZERO,
+            growth_rate: Decimal::ZERO,
+        }
+    };
+
+    let output = MergerArbOutput {
+        deal_value_per_share,
+        gross_spread,
+        gross_spread_pct,
+        net_spread,
+        net_spread_pct,
+        years_to_close,
+        annualized_return_simple,
+        annualized_return_compound,
+        excess_annualized_return,
+        upside_per_share,
+        downside_per_share,
+        upside_downside_ratio,
+        implied_probability_no_carry,
+        implied_probability,
+        completion_probability_used,
+        expected_return,
+        expected_annualized_return,
+        hedge_ratio: input.exchange_ratio,
+        kelly,
+    };
+
+    let elapsed = start.elapsed().as_micros() as u64;
+    Ok(with_metadata(
+        "Merger Arbitrage Spread, Implied Probability and Kelly Sizing",
+        &serde_json::json!({
+            "target": input.target_name,
+            "days_to_close": input.days_to_close,
+            "risk_free_rate": input.risk_free_rate.to_string(),
+            "probability_source": if input.completion_probability.is_some() { "analyst" } else { "market_implied" },
+        }),
+        warnings,
+        elapsed,
+        output,
+    ))
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+fn clamp_probability(p: Decimal) -> Rate {
+    p.max(Decimal::ZERO).min(Decimal::ONE)
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cash_deal() -> MergerArbInput {
+        MergerArbInput {
+            target_name: "Target".into(),
+            target_price: dec!(48),
+            cash_per_share: dec!(50),
+            exchange_ratio: Decimal::ZERO,
+            acquirer_price: None,
+            downside_price: dec!(38),
+            days_to_close: 182,
+            risk_free_rate: dec!(0.04),
+            target_dividends: None,
+            acquirer_dividends: None,
+            completion_probability: Some(dec!(0.95)),
+            kelly_fraction: Some(dec!(0.5)),
+            portfolio_value: Some(dec!(10_000_000)),
+            max_position_pct: None,
+        }
+    }
+
+    fn approx(a: Decimal, b: Decimal, tol: Decimal) -> bool {
+        (a - b).abs() <= tol
+    }
+
+    #[test]
+    fn test_cash_deal_spread() {
+        let out = analyze_merger_arb(&cash_deal()).unwrap().result;
+        assert_eq!(out.deal_value_per_share, dec!(50));
+        assert_eq!(out.gross_spread, dec!(2));
+        assert_eq!(out.net_spread, dec!(2));
+        assert!(approx(out.gross_spread_pct, dec!(0.041667), dec!(0.00001)));
+        assert_eq!(out.hedge_ratio, Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_stock_deal_value_uses_acquirer_price() {
+        let mut input = cash_deal();
+        input.cash_per_share = Decimal::ZERO;
+        input.exchange_ratio = dec!(0.5);
+        input.acquirer_price = Some(dec!(100));
+        let out = analyze_merger_arb(&input).unwrap().result;
+        assert_eq!(out.deal_value_per_share, dec!(50));
+        assert_eq!(out.hedge_ratio, dec!(0.5));
+    }
+
+    #[test]
+    fn test_mixed_deal_value() {
+        let mut input = cash_deal();
+        input.cash_per_share = dec!(25);
+        input.exchange_ratio = dec!(0.25);
+        input.acquirer_price = Some(dec!(104));
+        let out = analyze_merger_arb(&input).unwrap().result;
+        assert_eq!(out.deal_value_per_share, dec!(51));
+        assert_eq!(out.gross_spread, dec!(3));
+    }
+
+    #[test]
+    fn test_dividends_adjust_net_spread() {
+        let mut input = cash_deal();
+        input.cash_per_share = dec!(20);
+        input.exchange_ratio = dec!(0.3);
+        input.acquirer_price = Some(dec!(100));
+        input.target_dividends = Some(dec!(0.50));
+        input.acquirer_dividends = Some(dec!(1.00));
+        let out = analyze_merger_arb(&input).unwrap().result;
+        // gross 2, +0.50 target div, -0.30 hedge div
+        assert_eq!(out.net_spread, dec!(2.20));
+    }
+
+    #[test]
+    fn test_annualized_simple_return() {
+        let out = analyze_merger_arb(&cash_deal()).unwrap().result;
+        let expected = (dec!(2) / dec!(48)) / (dec!(182) / dec!(365));
+        assert!(approx(
+            out.annualized_return_simple,
+            expected,
+            dec!(0.000001)
+        ));
+    }
+
+    #[test]
+    fn test_compound_exceeds_simple_for_sub_year() {
+        let out = analyze_merger_arb(&cash_deal()).unwrap().result;
+        assert!(out.annualized_return_compound > out.annualized_return_simple);
+        assert!(approx(
+            out.excess_annualized_return,
+            out.annualized_return_compound - dec!(0.04),
+            dec!(0.0000001)
+        ));
+    }
+
+    #[test]
+    fn test_implied_probability_no_carry() {
+        let out = analyze_merger_arb(&cash_deal()).unwrap().result;
+        // (48 - 38) / (50 - 38) = 0.8333
+        assert!(approx(
+            out.implied_probability_no_carry,
+            dec!(0.833333),
+            dec!(0.00001)
+        ));
+    }
+
+    #[test]
+    fn test_carry_raises_implied_probability() {
+        let out = analyze_merger_arb(&cash_deal()).unwrap().result;
+        assert!(out.implied_probability > out.implied_probability_no_carry);
+        let carry = dec!(48) * (Decimal::ONE + dec!(0.04) * dec!(182) / dec!(365));
+        let expected = (carry - dec!(38)) / dec!(12);
+        assert!(approx(out.implied_probability, expected, dec!(0.000001)));
+    }
+
+    #[test]
+    fn test_upside_downside() {
+        let out = analyze_merger_arb(&cash_deal()).unwrap().result;
+        assert_eq!(out.upside_per_share, dec!(2));
+        assert_eq!(out.downside_per_share, dec!(10));
+        assert_eq!(out.upside_downside_ratio, dec!(0.2));
+    }
+
+    #[test]
+    fn test_expected_return_with_analyst_probability() {
+        let out = analyze_merger_arb(&cash_deal()).unwrap().result;
+        // 0.95 * 2 - 0.05 * 10 = 1.4 per share
+        assert!(approx(
+            out.expected_return,
+            dec!(1.4) / dec!(48),
+            dec!(0.0000001)
+        ));
+        assert_eq!(out.completion_probability_used, dec!(0.95));
+    }
+
+    #[test]
+    fn test_kelly_sizing_net_of_carry() {
+        let out = analyze_merger_arb(&cash_deal()).unwrap().result;
+        let carry = dec!(48) * (Decimal::ONE + dec!(0.04) * dec!(182) / dec!(365));
+        let b = (dec!(50) - carry) / (carry - dec!(38));
+        let full = dec!(0.95) - dec!(0.05) / b;
+        assert!(approx(out.kelly.full_kelly_pct, full, dec!(0.000001)));
+        assert!(approx(
+            out.kelly.fractional_kelly_pct,
+            full / dec!(2),
+            dec!(0.000001)
+        ));
+        assert!(approx(
+            out.kelly.recommended_position.unwrap(),
+            dec!(10_000_000) * full / dec!(2),
+            dec!(0.01)
+        ));
+    }
+
+    #[test]
+    fn test_kelly_zero_rate_matches_unfunded_payoffs() {
+        let mut input = cash_deal();
+        input.risk_free_rate = Decimal::ZERO;
+        let out = analyze_merger_arb(&input).unwrap().result;
+        // f* = p - q / b = 0.95 - 0.05 / 0.2 = 0.70
+        assert!(approx(out.kelly.full_kelly_pct, dec!(0.70), dec!(0.000001)));
+    }
+
+    #[test]
+    fn test_insufficient_conviction_sizes_zero() {
+        let mut input = cash_deal();
+        input.completion_probability = Some(dec!(0.85));
+        let result = analyze_merger_arb(&input).unwrap();
+        assert_eq!(result.result.kelly.fractional_kelly_pct, Decimal::ZERO);
+        assert!(result.result.expected_return > Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_kelly_respects_max_position() {
+        let mut input = cash_deal();
+        input.max_position_pct = Some(dec!(0.05));
+        let out = analyze_merger_arb(&input).unwrap().result;
+        assert_eq!(out.kelly.fractional_kelly_pct, dec!(0.05));
+    }
+
+    #[test]
+    fn test_market_implied_probability_gives_no_edge() {
+        let mut input = cash_deal();
+        input.completion_probability = None;
+        let result = analyze_merger_arb(&input).unwrap();
+        let out = result.result;
+        assert_eq!(out.completion_probability_used, out.implied_probability);
+        assert!(approx(out.kelly.edge, Decimal::ZERO, dec!(0.000001)));
+        assert!(out.kelly.fractional_kelly_pct <= dec!(0.000001));
+        assert!(result.warnings.iter().any(|w| w.contains("market-implied")));
+    }
+
+    #[test]
+    fn test_negative_spread_warns_and_sizes_zero() {
+        let mut input = cash_deal();
+        input.target_price = dec!(51);
+        let result = analyze_merger_arb(&input).unwrap();
+        assert!(result.result.gross_spread < Decimal::ZERO);
+        assert_eq!(result.result.kelly.fractional_kelly_pct, Decimal::ZERO);
+        assert!(result
+            .warnings
+            .iter()
+            .any(|w| w.contains("Negative spread")));
+    }
+
+    #[test]
+    fn test_stock_deal_requires_acquirer_price() {
+        let mut input = cash_deal();
+        input.exchange_ratio = dec!(0.5);
+        input.acquirer_price = None;
+        let err = analyze_merger_arb(&input).unwrap_err();
+        match err {
+            CorpFinanceError::InvalidInput { field, .. } => assert_eq!(field, "acquirer_price"),
+            other => panic!("Expected InvalidInput, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_invalid_target_price() {
+        let mut input = cash_deal();
+        input.target_price = Decimal::ZERO;
+        assert!(analyze_merger_arb(&input).is_err());
+    }
+
+    #[test]
+    fn test_zero_days_rejected() {
+        let mut input = cash_deal();
+        input.days_to_close = 0;
+        assert!(analyze_merger_arb(&input).is_err());
+    }
+
+    #[test]
+    fn test_invalid_probability_rejected() {
+        let mut input = cash_deal();
+        input.completion_probability = Some(Decimal::ONE);
+        assert!(analyze_merger_arb(&input).is_err());
+    }
+
+    #[test]
+    fn test_downside_above_payoff_is_impossible() {
+        let mut input = cash_deal();
+        input.downside_price = dec!(55);
+        let err = analyze_merger_arb(&input).unwrap_err();
+        assert!(matches!(err, CorpFinanceError::FinancialImpossibility(_)));
+    }
+
+    #[test]
+    fn test_longer_close_lowers_annualized_return() {
+        let short = analyze_merger_arb(&cash_deal()).unwrap().result;
+        let mut input = cash_deal();
+        input.days_to_close = 365;
+        let long = analyze_merger_arb(&input).unwrap().result;
+        assert!(long.annualized_return_simple < short.annualized_return_simple);
+        assert!(approx(
+            long.annualized_return_compound,
+            long.net_spread_pct,
+            dec!(0.000001)
+        ));
+    }
+
+    #[test]
+    fn test_methodology() {
+        let result = analyze_merger_arb(&cash_deal()).unwrap();
+        assert!(result.methodology.contains("Merger Arbitrage"));
+    }
+}
@@ -3,6 +3,8 @@ use rust_decimal_macros::dec;
 use serde::{Deserialize, Serialize};
 use std::time::Instant;
 
+use super::ppa::{self, IdentifiableNetAssets, PpaInput, PpaOutput};
+use crate::credit::metrics::{coverage_leverage_rating, CreditRating};
 use crate::error::CorpFinanceError;
 use crate::types::*;
 use crate::CorpFinanceResult;
@@ -64,6 +66,100 @@ pub struct MergerInput {
     pub goodwill_amortisation: Option<Money>,
     /// One-time transaction / advisory fees.
     pub transaction_fees: Option<Money>,
+
+    // --- Phased synergies ---
+    /// Year-by-year synergy realisation curves and integration spend.
+    /// When supplied, the output includes a multi-year accretion / dilution
+    /// profile and net synergy NPV alongside the single-year analysis.
+    pub synergy_schedule: Option<SynergySchedule>,
+
+    // --- Purchase price allocation ---
+    /// Target net assets and fair-value step-ups. When supplied, goodwill is
+    /// derived from the deal value and the after-tax incremental D&A on the
+    /// step-ups is charged against pro-forma earnings.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub purchase_price_allocation: Option<IdentifiableNetAssets>,
+
+    // --- Pro-forma credit ---
+    /// EBITDA, debt and interest of both companies. When supplied, the output
+    /// includes standalone and pro-forma leverage, coverage and implied
+    /// rating, by year when a synergy schedule is given.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub credit_profile: Option<ProFormaCreditInput>,
+}
+
+/// Standalone EBITDA and capital structure of the two companies at closing.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProFormaCreditInput {
+    pub acquirer_ebitda: Money,
+    pub target_ebitda: Money,
+    pub acquirer_debt: Money,
+    pub target_debt: Money,
+    #[serde(default)]
+    pub acquirer_cash: Money,
+    #[serde(default)]
+    pub target_cash: Money,
+    pub acquirer_interest_expense: Money,
+    pub target_interest_expense: Money,
+    /// Acquisition debt raised at closing. Defaults to the cash consideration
+    /// when a debt financing rate is given, otherwise zero (cash on hand).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_acquisition_debt: Option<Money>,
+    /// Annual growth in combined standalone EBITDA over the synergy schedule.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ebitda_growth: Option<Rate>,
+    /// Net debt / EBITDA limit (covenant or rating threshold) to test against.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_net_leverage: Option<Multiple>,
+}
+
+/// Category of synergy, which determines how it flows through earnings.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum SynergyKind {
+    /// Operating cost savings: pre-tax earnings and cash flow.
+    Cost,
+    /// Incremental revenue: earnings at the category's flow-through margin.
+    Revenue,
+    /// Capital expenditure savings: cash flow only, no earnings impact.
+    Capex,
+}
+
+/// A single synergy category with its own realisation curve.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SynergyCategory {
+    pub name: String,
+    pub kind: SynergyKind,
+    /// Annual run-rate once fully realised (negative for dis-synergies).
+    pub run_rate: Money,
+    /// Fraction of run-rate achieved in each year (0..=1). Years beyond the
+    /// curve stay at the last value.
+    pub phasing: Vec<Rate>,
+    /// Share of revenue synergies that reaches pre-tax earnings.
+    /// Defaults to 1 (full flow-through); ignored for other kinds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flow_through_margin: Option<Rate>,
+}
+
+/// Multi-year synergy and integration assumptions.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SynergySchedule {
+    pub categories: Vec<SynergyCategory>,
+    /// One-time pre-tax integration costs by year (year 1 first).
+    pub integration_costs: Vec<Money>,
+    /// Number of forecast years for the accretion / dilution profile.
+    pub projection_years: u32,
+    /// Discount rate for the net synergy NPV.
+    pub discount_rate: Rate,
+    /// Growth of run-rate synergies beyond the forecast for the terminal value.
+    /// When omitted, no value is ascribed beyond the forecast.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub terminal_growth: Option<Rate>,
+    /// Annual growth in acquirer standalone net income.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub acquirer_net_income_growth: Option<Rate>,
+    /// Annual growth in target standalone net income.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_net_income_growth: Option<Rate>,
 }
 
 /// Results of the accretion / dilution analysis.
@@ -110,6 +206,318 @@ pub struct MergerOutput {
     // --- Breakeven ---
     /// Pre-tax synergies required for EPS-neutral deal.
     pub breakeven_synergies: Money,
+
+    // --- Phased synergies ---
+    /// Multi-year synergy, NPV and accretion / dilution profile (when a
+    /// synergy schedule is supplied).
+    pub synergy_phasing: Option<SynergyPhasingOutput>,
+
+    // --- Purchase price allocation ---
+    /// Goodwill, deferred tax and incremental D&A schedule (when net assets
+    /// are supplied).
+    pub purchase_price_allocation: Option<PpaOutput>,
+    /// After-tax incremental D&A charged against year 1 earnings.
+    pub incremental_da_after_tax: Money,
+
+    // --- Pro-forma credit ---
+    /// Acquirer credit metrics before the deal.
+    pub acquirer_credit_standalone: Option<CreditSnapshot>,
+    /// Combined credit metrics at closing, with year 1 synergies.
+    pub pro_forma_credit: Option<CreditSnapshot>,
+}
+
+/// Leverage, coverage and implied rating at one point in time.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreditSnapshot {
+    /// EBITDA including realised cost and revenue synergies; integration
+    /// costs are treated as one-off and excluded.
+    pub ebitda: Money,
+    /// Synergies included in EBITDA.
+    pub ebitda_synergies: Money,
+    pub total_debt: Money,
+    pub cash: Money,
+    pub net_debt: Money,
+    pub interest_expense: Money,
+    /// `None` when EBITDA is not positive.
+    pub total_debt_to_ebitda: Option<Multiple>,
+    /// `None` when EBITDA is not positive.
+    pub net_debt_to_ebitda: Option<Multiple>,
+    /// EBITDA / interest; `None` when there is no interest expense.
+    pub interest_coverage: Option<Multiple>,
+    /// Rating from the coverage x leverage grid used by the credit metrics.
+    pub implied_rating: CreditRating,
+    /// `true` when net leverage exceeds `max_net_leverage`.
+    pub exceeds_leverage_limit: bool,
+}
+
+/// Synergy realisation and EPS impact for one year after closing.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SynergyYear {
+    pub year: u32,
+    /// Pre-tax cost synergies realised.
+    pub cost_synergies: Money,
+    /// Pre-tax earnings contribution from revenue synergies.
+    pub revenue_synergies: Money,
+    /// Capex savings (cash only).
+    pub capex_synergies: Money,
+    /// One-time pre-tax integration costs.
+    pub integration_costs: Money,
+    /// After-tax earnings impact of synergies net of integration costs.
+    pub after_tax_earnings_impact: Money,
+    /// After-tax cash flow from synergies, capex savings and integration costs.
+    pub net_synergy_cash_flow: Money,
+    /// Present value of the net synergy cash flow.
+    pub present_value: Money,
+    pub acquirer_eps_standalone: Money,
+    pub pro_forma_net_income: Money,
+    pub pro_forma_eps: Money,
+    pub eps_accretion_dilution: Money,
+    pub eps_accretion_dilution_pct: Rate,
+    pub is_accretive: bool,
+    /// Pro-forma credit metrics (when a credit profile is supplied).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub credit: Option<CreditSnapshot>,
+}
+
+/// Multi-year synergy value and accretion / dilution profile.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SynergyPhasingOutput {
+    pub years: Vec<SynergyYear>,
+    /// Pre-tax run-rate earnings synergies (cost + revenue at flow-through).
+    pub run_rate_earnings_synergies: Money,
+    /// PV of net synergy cash flows over the forecast.
+    pub pv_forecast_synergies: Money,
+    /// PV of the terminal value of run-rate synergies.
+    pub pv_terminal_value: Money,
+    /// PV of forecast plus terminal net synergies.
+    pub net_synergy_npv: Money,
+    /// Control premium paid over the target's market value.
+    pub premium_paid: Money,
+    /// Net synergy NPV less premium paid and transaction fees; positive when
+    /// synergies more than pay for the premium.
+    pub value_created: Money,
+    /// Net synergy NPV divided by premium paid.
+    pub premium_coverage: Option<Multiple>,
+    /// First year in which the deal is EPS accretive.
+    pub first_accretive_year: Option<u32>,
+    /// First year in which pro-forma net leverage is within
+    /// `max_net_leverage` (when a credit profile with a limit is supplied).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_year_within_leverage_limit: Option<u32>,
+}
+
+/// How an exchange-ratio collar shares acquirer price risk between the
+/// parties.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum CollarType {
+    /// Exchange ratio fixed inside the collar; outside it the ratio adjusts to
+    /// hold the value per target share at the band edge.
+    FixedExchangeRatio,
+    /// Value per target share fixed inside the collar (floating ratio);
+    /// outside it the ratio freezes at the band edge.
+    FixedValue,
+}
+
+/// Collar on the exchange ratio of the stock component.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExchangeRatioCollar {
+    pub collar_type: CollarType,
+    /// Acquirer share price at signing, which sets the base exchange ratio.
+    pub reference_price: Money,
+    /// Lower bound of the acquirer price band.
+    pub lower_price: Money,
+    /// Upper bound of the acquirer price band.
+    pub upper_price: Money,
+    /// Acquirer price below which the target may terminate the deal.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub walk_away_price: Option<Money>,
+}
+
+/// Contingent value right paid in cash to selling target shareholders if a
+/// milestone is met.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContingentValueRight {
+    /// Payment per target share acquired if the milestone is met.
+    pub payment_per_share: Money,
+    /// Probability that the milestone is met (0..=1).
+    pub probability: Rate,
+    /// Years after the target's first closing when the CVR pays out.
+    pub payment_year: u32,
+    /// Discount rate for the CVR fair value.
+    pub discount_rate: Rate,
+}
+
+/// One purchase of a stake in a target.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AcquisitionStage {
+    /// Projection year in which the stake closes (at the start of the year).
+    pub year: u32,
+    /// Fraction of the target's shares bought in this stage (0..=1).
+    pub stake_pct: Rate,
+    /// Offer price for this stage; defaults to the target's offer price.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offer_price_per_share: Option<Money>,
+    /// Consideration for this stage; defaults to the target's consideration.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub consideration: Option<ConsiderationType>,
+    /// Acquirer share price at this closing; defaults to the current price.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub acquirer_share_price: Option<Money>,
+}
+
+/// A target in a multi-target acquisition programme.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TargetDeal {
+    pub name: String,
+    pub net_income: Money,
+    /// Annual growth in the target's standalone net income.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub net_income_growth: Option<Rate>,
+    pub shares_outstanding: Decimal,
+    pub share_price: Money,
+    pub offer_price_per_share: Money,
+    pub consideration: ConsiderationType,
+    /// Purchase stages. When empty the whole target is bought in year 1.
+    #[serde(default)]
+    pub stages: Vec<AcquisitionStage>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub collar: Option<ExchangeRatioCollar>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cvr: Option<ContingentValueRight>,
+    /// Synergy categories, phased from the year the acquirer gains control.
+    #[serde(default)]
+    pub synergies: Vec<SynergyCategory>,
+    /// Pre-tax integration costs by year from the year of control.
+    #[serde(default)]
+    pub integration_costs: Vec<Money>,
+    /// One-time transaction fees, charged in the first closing year.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transaction_fees: Option<Money>,
+}
+
+/// Inputs for a staged, multi-target accretion / dilution analysis.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MultiTargetMergerInput {
+    pub acquirer_name: String,
+    pub acquirer_net_income: Money,
+    pub acquirer_shares_outstanding: Decimal,
+    pub acquirer_share_price: Money,
+    pub acquirer_tax_rate: Rate,
+    /// Annual growth in acquirer standalone net income.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub acquirer_net_income_growth: Option<Rate>,
+    /// Interest rate on new debt raised to fund cash consideration.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub debt_financing_rate: Option<Rate>,
+    /// Rate earned on cash balances that are foregone when paying cash.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub foregone_interest_rate: Option<Rate>,
+    /// Number of years in the pro-forma EPS schedule.
+    pub projection_years: u32,
+    pub targets: Vec<TargetDeal>,
+}
+
+/// Where the acquirer price at closing sits relative to the collar band.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum CollarPosition {
+    BelowCollar,
+    WithinCollar,
+    AboveCollar,
+}
+
+/// Exchange ratio after applying a collar at the closing acquirer price.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CollarOutcome {
+    pub acquirer_price: Money,
+    /// Offer price / collar reference price.
+    pub base_exchange_ratio: Decimal,
+    pub effective_exchange_ratio: Decimal,
+    /// Value of the stock consideration per target share at closing.
+    pub value_per_target_share: Money,
+    pub position: CollarPosition,
+    pub walk_away_triggered: bool,
+}
+
+/// Consideration and share issuance for one acquisition stage.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StageOutcome {
+    pub year: u32,
+    pub stake_pct: Rate,
+    /// Stake held after this stage.
+    pub cumulative_stake: Rate,
+    pub cash_consideration: Money,
+    /// Stock consideration valued at the closing acquirer price.
+    pub stock_consideration: Money,
+    pub total_consideration: Money,
+    pub exchange_ratio: Option<Decimal>,
+    pub new_shares_issued: Decimal,
+    pub collar: Option<CollarOutcome>,
+}
+
+/// Expected payout and fair value of a contingent value right.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CvrOutcome {
+    /// Probability-weighted cash payment.
+    pub expected_payment: Money,
+    /// Present value of the expected payment at the first closing.
+    pub fair_value: Money,
+    /// Projection year in which the CVR pays out.
+    pub payment_year: u32,
+}
+
+/// Deal terms and consideration for one target.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TargetDealOutcome {
+    pub name: String,
+    pub stages: Vec<StageOutcome>,
+    pub final_stake: Rate,
+    /// First year in which the acquirer holds more than 50%.
+    pub control_year: Option<u32>,
+    /// Stage consideration plus the CVR fair value.
+    pub total_consideration: Money,
+    /// Premium over the target's share price on the shares acquired.
+    pub premium_paid: Money,
+    pub new_shares_issued: Decimal,
+    pub cvr: Option<CvrOutcome>,
+}
+
+/// Pro-forma earnings and EPS for one year of the programme.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProFormaYear {
+    pub year: u32,
+    pub acquirer_net_income: Money,
+    /// Target net income attributable to the stakes held.
+    pub acquired_net_income: Money,
+    pub after_tax_synergies: Money,
+    pub after_tax_integration_costs: Money,
+    pub transaction_fees: Money,
+    /// After-tax cost of funding cash consideration and CVR payments.
+    pub financing_cost: Money,
+    pub pro_forma_net_income: Money,
+    pub pro_forma_shares: Decimal,
+    pub acquirer_eps_standalone: Money,
+    pub pro_forma_eps: Money,
+    pub eps_accretion_dilution: Money,
+    pub eps_accretion_dilution_pct: Rate,
+    pub is_accretive: bool,
+}
+
+/// Results of a staged, multi-target accretion / dilution analysis.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MultiTargetMergerOutput {
+    pub targets: Vec<TargetDealOutcome>,
+    pub years: Vec<ProFormaYear>,
+    pub total_consideration: Money,
+    pub total_cash_consideration: Money,
+    pub total_stock_consideration: Money,
+    pub total_cvr_fair_value: Money,
+    pub total_new_shares_issued: Decimal,
+    /// Share of the enlarged share count held by existing acquirer holders.
+    pub acquirer_ownership_pct: Rate,
+    /// First year in which the programme is EPS accretive.
+    pub first_accretive_year: Option<u32>,
+    /// Sum of per-share accretion / dilution across the projection.
+    pub cumulative_eps_accretion_dilution: Money,
 }
 
 // ---------------------------------------------------------------------------
@@ -120,6 +528,12 @@ pub struct MergerOutput {
 ///
 /// Returns a `ComputationOutput<MergerOutput>` wrapped in the standard
 /// result envelope with methodology, assumptions, warnings and metadata.
+#[tracing::instrument(
+    level = "debug",
+    name = "merger",
+    skip_all,
+    fields(synergy_years = input.synergy_schedule.as_ref().map(|s| s.projection_years))
+)]
 pub fn analyze_merger(input: &MergerInput) -> CorpFinanceResult<ComputationOutput<MergerOutput>> {
     let start = Instant::now();
     let mut warnings: Vec<String> = Vec::new();
@@ -169,12 +583,50 @@ pub fn analyze_merger(input: &MergerInput) -> CorpFinanceResult<ComputationOutpu
     let synergy_impact = compute_synergy_impact(input, &mut warnings);
 
     // ------------------------------------------------------------------
-    // 8. Pro-forma net income
+    // 8. Purchase price allocation & incremental D&A
+    // ------------------------------------------------------------------
+    let purchase_price_allocation = match &input.purchase_price_allocation {
+        Some(net_assets) => {
+            let projection_years = input
+                .synergy_schedule
+                .as_ref()
+                .map(|s| s.projection_years)
+                .filter(|n| *n > 0)
+                .unwrap_or(5);
+            let ppa_input = PpaInput {
+                target_name: input.target_name.clone(),
+                purchase_consideration: deal_value,
+                non_controlling_interest: None,
+                previously_held_interest: None,
+                net_assets: net_assets.clone(),
+                tax_rate: input.acquirer_tax_rate,
+                projection_years,
+            };
+            let _stage = tracing::debug_span!("purchase_price_allocation").entered();
+            Some(ppa::compute_allocation(&ppa_input, &mut warnings)?)
+        }
+        None => None,
+    };
+    // After-tax charge per year (positive reduces earnings).
+    let incremental_da: Vec<Money> = purchase_price_allocation
+        .as_ref()
+        .map(|p| {
+            p.schedule
+                .iter()
+                .map(|y| -y.after_tax_earnings_impact)
+                .collect()
+        })
+        .unwrap_or_default();
+    let incremental_da_after_tax = incremental_da.first().copied().unwrap_or(zero);
+
+    // ------------------------------------------------------------------
+    // 9. Pro-forma net income
     // ------------------------------------------------------------------
-    let pro_forma_net_income = combined_net_income_pre_synergies - financing_cost + synergy_impact;
+    let pro_forma_net_income = combined_net_income_pre_synergies - financing_cost + synergy_impact
+        - incremental_da_after_tax;
 
     // ------------------------------------------------------------------
-    // 9. Pro-forma EPS & accretion / dilution
+    // 10. Pro-forma EPS & accretion / dilution
     // ------------------------------------------------------------------
     let pro_forma_eps = pro_forma_net_income / pro_forma_shares;
 
@@ -187,16 +639,94 @@ pub fn analyze_merger(input: &MergerInput) -> CorpFinanceResult<ComputationOutpu
     let is_accretive = eps_accretion_dilution >= zero;
 
     // ------------------------------------------------------------------
-    // 10. Breakeven synergies
+    // 11. Breakeven synergies
     // ------------------------------------------------------------------
     let breakeven_synergies = compute_breakeven_synergies(
         input,
         combined_net_income_pre_synergies,
-        financing_cost,
+        financing_cost + incremental_da_after_tax,
         acquirer_eps_standalone,
         pro_forma_shares,
     );
 
+    // ------------------------------------------------------------------
+    // 12. Standalone & pro-forma credit metrics
+    // ------------------------------------------------------------------
+    let closing_capital = match &input.credit_profile {
+        Some(credit) => {
+            validate_credit_profile(credit)?;
+            Some(compute_closing_capital(
+                input,
+                credit,
+                deal_value,
+                &mut warnings,
+            ))
+        }
+        None => None,
+    };
+    let (acquirer_credit_standalone, pro_forma_credit) =
+        match (&input.credit_profile, &closing_capital) {
+            (Some(credit), Some(capital)) => {
+                let standalone = credit_snapshot(
+                    credit.acquirer_ebitda,
+                    zero,
+                    credit.acquirer_debt,
+                    credit.acquirer_cash,
+                    credit.acquirer_interest_expense,
+                    credit.max_net_leverage,
+                );
+                let year_one_synergies = (input.cost_synergies.unwrap_or(zero)
+                    + input.revenue_synergies.unwrap_or(zero))
+                    * input.synergy_phase_in_pct.unwrap_or(dec!(1));
+                let pro_forma = credit_snapshot(
+                    credit.acquirer_ebitda + credit.target_ebitda,
+                    year_one_synergies,
+                    capital.total_debt,
+                    capital.cash,
+                    capital.interest_expense,
+                    credit.max_net_leverage,
+                );
+                if let (Some(limit), Some(leverage)) =
+                    (credit.max_net_leverage, pro_forma.net_debt_to_ebitda)
+                {
+                    if pro_forma.exceeds_leverage_limit {
+                        warnings.push(format!(
+                        "Pro-forma net leverage of {:.2}x at closing exceeds the {limit}x limit",
+                        leverage
+                    ));
+                    }
+                }
+                (Some(standalone), Some(pro_forma))
+            }
+            _ => (None, None),
+        };
+
+    // ------------------------------------------------------------------
+    // 13. Phased synergies & multi-year accretion / dilution
+    // ------------------------------------------------------------------
+    let synergy_phasing = match &input.synergy_schedule {
+        Some(schedule) => {
+            if input.cost_synergies.is_some() || input.revenue_synergies.is_some() {
+                warnings.push(
+                    "Both run-rate synergies and a synergy schedule supplied; the single-year \
+                     analysis uses the run-rate fields"
+                        .into(),
+                );
+            }
+            let _stage = tracing::debug_span!("synergy_phasing").entered();
+            Some(compute_synergy_phasing(
+                input,
+                schedule,
+                financing_cost,
+                &incremental_da,
+                pro_forma_shares,
+                premium_amount * input.target_shares_outstanding,
+                closing_capital.as_ref(),
+            )?)
+        }
+        None => None,
+    };
+
     // ------------------------------------------------------------------
     // Build output
     // ------------------------------------------------------------------
@@ -217,6 +747,11 @@ pub fn analyze_merger(input: &MergerInput) -> CorpFinanceResult<ComputationOutpu
         synergy_impact,
         financing_cost,
         breakeven_synergies,
+        synergy_phasing,
+        purchase_price_allocation,
+        incremental_da_after_tax,
+        acquirer_credit_standalone,
+        pro_forma_credit,
     };
 
     let elapsed = start.elapsed().as_micros() as u64;
@@ -234,6 +769,191 @@ pub fn analyze_merger(input: &MergerInput) -> CorpFinanceResult<ComputationOutpu
     ))
 }
 
+/// Accretion / dilution for an acquisition programme: several targets, each
+/// bought in one or more stages with its own consideration mix, optional
+/// exchange-ratio collar and contingent value right.
+///
+/// Each stage closes at the start of its year, so its new shares and
+/// financing cost count for the full year. Target earnings are attributed in
+/// proportion to the stake held; synergies and integration costs run from the
+/// year the acquirer first holds more than 50%.
+pub fn analyze_multi_target_merger(
+    input: &MultiTargetMergerInput,
+) -> CorpFinanceResult<ComputationOutput<MultiTargetMergerOutput>> {
+    let start = Instant::now();
+    let mut warnings: Vec<String> = Vec::new();
+
+    validate_multi_target_input(input)?;
+
+    let one = dec!(1);
+    let zero = Decimal::ZERO;
+    let tax_keep = one - input.acquirer_tax_rate;
+    let funding_rate =
+        input.debt_financing_rate.unwrap_or(zero) + input.foregone_interest_rate.unwrap_or(zero);
+
+    // ------------------------------------------------------------------
+    // 1. Stage consideration, collars and CVRs per target
+    // ------------------------------------------------------------------
+    let mut targets = Vec::with_capacity(input.targets.len());
+    let mut has_cash = false;
+    for target in &input.targets {
+        let outcome = compute_target_deal(input, target, &mut warnings);
+        has_cash |=
+            outcome.stages.iter().any(|s| s.cash_consideration > zero) || outcome.cvr.is_some();
+        targets.push(outcome);
+    }
+    if has_cash && input.debt_financing_rate.is_none() && input.foregone_interest_rate.is_none() {
+        warnings.push(
+            "Cash consideration with no financing rate specified; financing cost is zero".into(),
+        );
+    }
+
+    // ------------------------------------------------------------------
+    // 2. Pro-forma earnings and EPS by year
+    // ------------------------------------------------------------------
+    let acq_growth = input.acquirer_net_income_growth.unwrap_or(zero);
+    let mut years = Vec::with_capacity(input.projection_years as usize);
+    let mut growth_acq = one;
+
+    for year in 1..=input.projection_years {
+        let acquirer_net_income = input.acquirer_net_income * growth_acq;
+        growth_acq *= one + acq_growth;
+
+        let mut acquired_net_income = zero;
+        let mut pre_tax_synergies = zero;
+        let mut integration = zero;
+        let mut transaction_fees = zero;
+        let mut funded_cash = zero;
+        let mut pro_forma_shares = input.acquirer_shares_outstanding;
+
+        for (deal, outcome) in input.targets.iter().zip(&targets) {
+            let closed: Vec<&StageOutcome> =
+                outcome.stages.iter().filter(|s| s.year <= year).collect();
+            let Some(last) = closed.last() else {
+                continue;
+            };
+
+            let growth = deal.net_income_growth.unwrap_or(zero);
+            let target_ni = deal.net_income * pow_growth(growth, year - 1);
+            acquired_net_income += target_ni * last.cumulative_stake;
+
+            for stage in &closed {
+                funded_cash += stage.cash_consideration;
+                pro_forma_shares += stage.new_shares_issued;
+            }
+            if closed[0].year == year {
+                transaction_fees += deal.transaction_fees.unwrap_or(zero);
+            }
+            if let Some(cvr) = &outcome.cvr {
+                if cvr.payment_year <= year {
+                    funded_cash += cvr.expected_payment;
+                }
+            }
+
+            if let Some(control_year) = outcome.control_year.filter(|c| *c <= year) {
+                let idx = (year - control_year) as usize;
+                pre_tax_synergies += deal
+                    .synergies
+                    .iter()
+                    .map(|cat| {
+                        let realised = cat.run_rate * phase_at(&cat.phasing, idx);
+                        match cat.kind {
+                            SynergyKind::Cost => realised,
+                            SynergyKind::Revenue => {
+                                realised * cat.flow_through_margin.unwrap_or(one)
+                            }
+                            SynergyKind::Capex => zero,
+                        }
+                    })
+                    .sum::<Money>();
+                integration += deal.integration_costs.get(idx).copied().unwrap_or(zero);
+            }
+        }
+
+        let after_tax_synergies = pre_tax_synergies * tax_keep;
+        let after_tax_integration_costs = integration * tax_keep;
+        let financing_cost = funded_cash * funding_rate * tax_keep;
+        let pro_forma_net_income = acquirer_net_income + acquired_net_income + after_tax_synergies
+            - after_tax_integration_costs
+            - transaction_fees
+            - financing_cost;
+
+        let acquirer_eps_standalone = acquirer_net_income / input.acquirer_shares_outstanding;
+        let pro_forma_eps = pro_forma_net_income / pro_forma_shares;
+        let eps_accretion_dilution = pro_forma_eps - acquirer_eps_standalone;
+        let eps_accretion_dilution_pct = if acquirer_eps_standalone != zero {
+            eps_accretion_dilution / acquirer_eps_standalone
+        } else {
+            zero
+        };
+
+        years.push(ProFormaYear {
+            year,
+            acquirer_net_income,
+            acquired_net_income,
+            after_tax_synergies,
+            after_tax_integration_costs,
+            transaction_fees,
+            financing_cost,
+            pro_forma_net_income,
+            pro_forma_shares,
+            acquirer_eps_standalone,
+            pro_forma_eps,
+            eps_accretion_dilution,
+            eps_accretion_dilution_pct,
+            is_accretive: eps_accretion_dilution >= zero,
+        });
+    }
+
+    // ------------------------------------------------------------------
+    // 3. Programme totals
+    // ------------------------------------------------------------------
+    let all_stages = || targets.iter().flat_map(|t| t.stages.iter());
+    let total_cash_consideration: Money = all_stages().map(|s| s.cash_consideration).sum();
+    let total_stock_consideration: Money = all_stages().map(|s| s.stock_consideration).sum();
+    let total_new_shares_issued: Decimal = targets.iter().map(|t| t.new_shares_issued).sum();
+    let total_cvr_fair_value: Money = targets
+        .iter()
+        .filter_map(|t| t.cvr.as_ref())
+        .map(|c| c.fair_value)
+        .sum();
+    let total_consideration =
+        total_cash_consideration + total_stock_consideration + total_cvr_fair_value;
+    let acquirer_ownership_pct = input.acquirer_shares_outstanding
+        / (input.acquirer_shares_outstanding + total_new_shares_issued);
+    let first_accretive_year = years.iter().find(|y| y.is_accretive).map(|y| y.year);
+    let cumulative_eps_accretion_dilution = years.iter().map(|y| y.eps_accretion_dilution).sum();
+
+    let output = MultiTargetMergerOutput {
+        targets,
+        years,
+        total_consideration,
+        total_cash_consideration,
+        total_stock_consideration,
+        total_cvr_fair_value,
+        total_new_shares_issued,
+        acquirer_ownership_pct,
+        first_accretive_year,
+        cumulative_eps_accretion_dilution,
+    };
+
+    let elapsed = start.elapsed().as_micros() as u64;
+    Ok(with_metadata(
+        "Multi-Target Staged Accretion/Dilution Analysis",
+        &serde_json::json!({
+            "acquirer": input.acquirer_name,
+            "targets": input.targets.iter().map(|t| t.name.as_str()).collect::<Vec<_>>(),
+            "projection_years": input.projection_years,
+            "stage_closing": "start of year",
+            "earnings_attribution": "stake held",
+            "synergies_from": "year of control (>50%)",
+        }),
+        warnings,
+        elapsed,
+        output,
+    ))
+}
+
 // ---------------------------------------------------------------------------
 // Internal helpers
 // ---------------------------------------------------------------------------
@@ -425,6 +1145,676 @@ fn compute_breakeven_synergies(
     }
 }
 
+/// Build the year-by-year synergy profile, net synergy NPV and the
+/// resulting accretion / dilution path.
+///
+/// Integration costs are treated as tax deductible. Transaction fees are
+/// charged against year 1 earnings, goodwill amortisation against every year,
+/// incremental D&A from the purchase price allocation by year, and the
+/// financing cost is held flat at its closing level. Pro-forma credit metrics
+/// likewise hold debt, cash and interest at closing, so leverage moves with
+/// EBITDA growth and realised synergies only.
+fn compute_synergy_phasing(
+    input: &MergerInput,
+    schedule: &SynergySchedule,
+    financing_cost: Money,
+    incremental_da: &[Money],
+    pro_forma_shares: Decimal,
+    premium_paid: Money,
+    closing_capital: Option<&ClosingCapital>,
+) -> CorpFinanceResult<SynergyPhasingOutput> {
+    let one = dec!(1);
+    let zero = Decimal::ZERO;
+
+    if schedule.projection_years == 0 {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "synergy_schedule.projection_years".into(),
+            reason: "Projection must cover at least one year".into(),
+        });
+    }
+    crate::limits::check_schedule_periods(
+        "synergy_schedule.projection_years",
+        schedule.projection_years.into(),
+    )?;
+    if schedule.discount_rate <= dec!(-1) {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "synergy_schedule.discount_rate".into(),
+            reason: "Discount rate must be greater than -100%".into(),
+        });
+    }
+    for cat in &schedule.categories {
+        if cat.phasing.is_empty() {
+            return Err(CorpFinanceError::InvalidInput {
+                field: "synergy_schedule.categories.phasing".into(),
+                reason: format!("Synergy category '{}' has no phasing curve", cat.name),
+            });
+        }
+        if cat.phasing.iter().any(|p| *p < zero || *p > one) {
+            return Err(CorpFinanceError::InvalidInput {
+                field: "synergy_schedule.categories.phasing".into(),
+                reason: format!("Phasing for '{}' must be between 0 and 1", cat.name),
+            });
+        }
+    }
+
+    let tax_keep = one - input.acquirer_tax_rate;
+    let goodwill = input.goodwill_amortisation.unwrap_or(zero);
+    let fees = input.transaction_fees.unwrap_or(zero);
+    let acq_growth = schedule.acquirer_net_income_growth.unwrap_or(zero);
+    let tgt_growth = schedule.target_net_income_growth.unwrap_or(zero);
+
+    let mut years = Vec::with_capacity(schedule.projection_years as usize);
+    let mut pv_forecast_synergies = zero;
+    let mut discount_factor = one;
+    let mut growth_acq = one;
+    let mut growth_tgt = one;
+    let mut growth_ebitda = one;
+    let mut last_run_rate_cash_flow = zero;
+
+    for year in 1..=schedule.projection_years {
+        let idx = (year - 1) as usize;
+        discount_factor /= one + schedule.discount_rate;
+
+        let mut cost = zero;
+        let mut revenue = zero;
+        let mut capex = zero;
+        for cat in &schedule.categories {
+            let realised = cat.run_rate * phase_at(&cat.phasing, idx);
+            match cat.kind {
+                SynergyKind::Cost => cost += realised,
+                SynergyKind::Revenue => {
+                    revenue += realised * cat.flow_through_margin.unwrap_or(one)
+                }
+                SynergyKind::Capex => capex += realised,
+            }
+        }
+        let integration = schedule.integration_costs.get(idx).copied().unwrap_or(zero);
+
+        let after_tax_synergies = (cost + revenue) * tax_keep;
+        let after_tax_earnings_impact = after_tax_synergies - integration * tax_keep;
+        let net_synergy_cash_flow = after_tax_earnings_impact + capex;
+        let present_value = net_synergy_cash_flow * discount_factor;
+        pv_forecast_synergies += present_value;
+        last_run_rate_cash_flow = after_tax_synergies + capex;
+
+        // Standalone earnings grow from the closing-year base.
+        let acquirer_ni = input.acquirer_net_income * growth_acq;
+        let target_ni = input.target_net_income * growth_tgt;
+        growth_acq *= one + acq_growth;
+        growth_tgt *= one + tgt_growth;
+
+        let one_off_fees = if year == 1 { fees } else { zero };
+        let da_charge = incremental_da.get(idx).copied().unwrap_or(zero);
+        let pro_forma_net_income = acquirer_ni + target_ni - financing_cost
+            + after_tax_earnings_impact
+            - goodwill
+            - one_off_fees
+            - da_charge;
+
+        let acquirer_eps_standalone = acquirer_ni / input.acquirer_shares_outstanding;
+        let pro_forma_eps = pro_forma_net_income / pro_forma_shares;
+        let eps_accretion_dilution = pro_forma_eps - acquirer_eps_standalone;
+        let eps_accretion_dilution_pct = if acquirer_eps_standalone != zero {
+            eps_accretion_dilution / acquirer_eps_standalone
+        } else {
+            zero
+        };
+
+        let credit = match (&input.credit_profile, closing_capital) {
+            (Some(profile), Some(capital)) => {
+                let ebitda = (profile.acquirer_ebitda + profile.target_ebitda) * growth_ebitda;
+                growth_ebitda *= one + profile.ebitda_growth.unwrap_or(zero);
+                Some(credit_snapshot(
+                    ebitda,
+                    cost + revenue,
+                    capital.total_debt,
+                    capital.cash,
+                    capital.interest_expense,
+                    profile.max_net_leverage,
+                ))
+            }
+            _ => None,
+        };
+
+        years.push(SynergyYear {
+            year,
+            cost_synergies: cost,
+            revenue_synergies: revenue,
+            capex_synergies: capex,
+            integration_costs: integration,
+            after_tax_earnings_impact,
+            net_synergy_cash_flow,
+            present_value,
+            acquirer_eps_standalone,
+            pro_forma_net_income,
+            pro_forma_eps,
+            eps_accretion_dilution,
+            eps_accretion_dilution_pct,
+            is_accretive: eps_accretion_dilution >= zero,
+            credit,
+        });
+    }
+
+    // Terminal value on the final-year run-rate (excluding integration spend).
+    let pv_terminal_value = match schedule.terminal_growth {
+        Some(g) => {
+            if g >= schedule.discount_rate {
+                return Err(CorpFinanceError::InvalidInput {
+                    field: "synergy_schedule.terminal_growth".into(),
+                    reason: "Terminal growth must be below the discount rate".into(),
+                });
+            }
+            last_run_rate_cash_flow * (one + g) / (schedule.discount_rate - g) * discount_factor
+        }
+        None => zero,
+    };
+
+    let run_rate_earnings_synergies: Money = schedule
+        .categories
+        .iter()
+        .map(|c| match c.kind {
+            SynergyKind::Cost => c.run_rate,
+            SynergyKind::Revenue => c.run_rate * c.flow_through_margin.unwrap_or(one),
+            SynergyKind::Capex => zero,
+        })
+        .sum();
+
+    let net_synergy_npv = pv_forecast_synergies + pv_terminal_value;
+    let value_created = net_synergy_npv - premium_paid - fees;
+    let premium_coverage = if premium_paid > zero {
+        Some(net_synergy_npv / premium_paid)
+    } else {
+        None
+    };
+    let first_accretive_year = years.iter().find(|y| y.is_accretive).map(|y| y.year);
+    let first_year_within_leverage_limit = input
+        .credit_profile
+        .as_ref()
+        .and_then(|c| c.max_net_leverage)
+        .and_then(|_| {
+            years
+                .iter()
+                .find(|y| y.credit.as_ref().is_some_and(|c| !c.exceeds_leverage_limit))
+                .map(|y| y.year)
+        });
+
+    Ok(SynergyPhasingOutput {
+        years,
+        run_rate_earnings_synergies,
+        pv_forecast_synergies,
+        pv_terminal_value,
+        net_synergy_npv,
+        premium_paid,
+        value_created,
+        premium_coverage,
+        first_accretive_year,
+        first_year_within_leverage_limit,
+    })
+}
+
+/// Combined debt, cash and interest expense after funding the cash
+/// consideration.
+struct ClosingCapital {
+    total_debt: Money,
+    cash: Money,
+    interest_expense: Money,
+}
+
+/// Fund the cash consideration with new acquisition debt and, for the rest,
+/// combined cash on hand. A shortfall in cash is treated as additional debt.
+fn compute_closing_capital(
+    input: &MergerInput,
+    credit: &ProFormaCreditInput,
+    deal_value: Money,
+    warnings: &mut Vec<String>,
+) -> ClosingCapital {
+    let zero = Decimal::ZERO;
+    let cash_consideration = match &input.consideration {
+        ConsiderationType::AllCash => deal_value,
+        ConsiderationType::AllStock => zero,
+        ConsiderationType::Mixed { cash_pct } => deal_value * *cash_pct,
+    };
+    let new_debt = credit
+        .new_acquisition_debt
+        .unwrap_or(if input.debt_financing_rate.is_some() {
+            cash_consideration
+        } else {
+            zero
+        });
+    if new_debt > zero && input.debt_financing_rate.is_none() {
+        warnings.push(
+            "New acquisition debt with no debt financing rate; its interest is excluded from \
+             pro-forma coverage"
+                .into(),
+        );
+    }
+
+    let mut total_debt = credit.acquirer_debt + credit.target_debt + new_debt;
+    let mut cash = credit.acquirer_cash + credit.target_cash + new_debt - cash_consideration;
+    if cash < zero {
+        warnings.push(format!(
+            "Cash consideration exceeds combined cash and new debt by {:.2}; shortfall treated as \
+             additional debt",
+            -cash
+        ));
+        total_debt -= cash;
+        cash = zero;
+    }
+    let interest_expense = credit.acquirer_interest_expense
+        + credit.target_interest_expense
+        + (total_debt - credit.acquirer_debt - credit.target_debt)
+            * input.debt_financing_rate.unwrap_or(zero);
+
+    ClosingCapital {
+        total_debt,
+        cash,
+        interest_expense,
+    }
+}
+
+fn credit_snapshot(
+    base_ebitda: Money,
+    ebitda_synergies: Money,
+    total_debt: Money,
+    cash: Money,
+    interest_expense: Money,
+    max_net_leverage: Option<Multiple>,
+) -> CreditSnapshot {
+    let zero = Decimal::ZERO;
+    // Caps mirror the credit metrics module when a ratio is undefined.
+    let cap = dec!(999);
+    let ebitda = base_ebitda + ebitda_synergies;
+    let net_debt = total_debt - cash;
+    let (total_debt_to_ebitda, net_debt_to_ebitda) = if ebitda > zero {
+        (Some(total_debt / ebitda), Some(net_debt / ebitda))
+    } else {
+        (None, None)
+    };
+    let interest_coverage = if interest_expense > zero {
+        Some(ebitda / interest_expense)
+    } else {
+        None
+    };
+    let (implied_rating, _) = coverage_leverage_rating(
+        interest_coverage.unwrap_or(cap),
+        net_debt_to_ebitda.unwrap_or(cap),
+    );
+    let exceeds_leverage_limit = max_net_leverage
+        .is_some_and(|limit| net_debt_to_ebitda.is_none_or(|leverage| leverage > limit));
+
+    CreditSnapshot {
+        ebitda,
+        ebitda_synergies,
+        total_debt,
+        cash,
+        net_debt,
+        interest_expense,
+        total_debt_to_ebitda,
+        net_debt_to_ebitda,
+        interest_coverage,
+        implied_rating,
+        exceeds_leverage_limit,
+    }
+}
+
+fn validate_credit_profile(credit: &ProFormaCreditInput) -> CorpFinanceResult<()> {
+    let zero = Decimal::ZERO;
+    let balances = [
+        ("credit_profile.acquirer_debt", credit.acquirer_debt),
+        ("credit_profile.target_debt", credit.target_debt),
+        ("credit_profile.acquirer_cash", credit.acquirer_cash),
+        ("credit_profile.target_cash", credit.target_cash),
+        (
+            "credit_profile.acquirer_interest_expense",
+            credit.acquirer_interest_expense,
+        ),
+        (
+            "credit_profile.target_interest_expense",
+            credit.target_interest_expense,
+        ),
+        (
+            "credit_profile.new_acquisition_debt",
+            credit.new_acquisition_debt.unwrap_or(zero),
+        ),
+    ];
+    for (field, value) in balances {
+        if value < zero {
+            return Err(CorpFinanceError::InvalidInput {
+                field: field.into(),
+                reason: "Debt, cash and interest balances cannot be negative".into(),
+            });
+        }
+    }
+    if credit.max_net_leverage.is_some_and(|m| m <= zero) {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "credit_profile.max_net_leverage".into(),
+            reason: "Leverage limit must be positive".into(),
+        });
+    }
+    Ok(())
+}
+
+/// Realisation fraction for a given year index, holding the last value flat.
+fn phase_at(phasing: &[Rate], idx: usize) -> Rate {
+    phasing
+        .get(idx)
+        .or_else(|| phasing.last())
+        .copied()
+        .unwrap_or(Decimal::ZERO)
+}
+
+/// (1 + g)^n for a non-negative integer exponent.
+fn pow_growth(g: Rate, n: u32) -> Decimal {
+    (0..n).fold(dec!(1), |acc, _| acc * (dec!(1) + g))
+}
+
+/// Validate the acquirer, programme and per-target inputs.
+fn validate_multi_target_input(input: &MultiTargetMergerInput) -> CorpFinanceResult<()> {
+    let zero = Decimal::ZERO;
+    let one = dec!(1);
+
+    if input.acquirer_shares_outstanding <= zero {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "acquirer_shares_outstanding".into(),
+            reason: "Acquirer shares outstanding must be positive".into(),
+        });
+    }
+    if input.acquirer_share_price <= zero {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "acquirer_share_price".into(),
+            reason: "Acquirer share price must be positive".into(),
+        });
+    }
+    if input.acquirer_tax_rate < zero || input.acquirer_tax_rate > one {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "acquirer_tax_rate".into(),
+            reason: "Tax rate must be between 0 and 1".into(),
+        });
+    }
+    if input.projection_years == 0 {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "projection_years".into(),
+            reason: "Projection must cover at least one year".into(),
+        });
+    }
+    crate::limits::check_schedule_periods("projection_years", input.projection_years.into())?;
+    if input.targets.is_empty() {
+        return Err(CorpFinanceError::InsufficientData(
+            "At least one target is required".into(),
+        ));
+    }
+
+    for t in &input.targets {
+        if t.shares_outstanding <= zero || t.share_price <= zero || t.offer_price_per_share <= zero
+        {
+            return Err(CorpFinanceError::InvalidInput {
+                field: "targets".into(),
+                reason: format!(
+                    "Target '{}' shares, share price and offer price must be positive",
+                    t.name
+                ),
+            });
+        }
+        validate_consideration(&t.consideration, &t.name)?;
+
+        let mut stake = zero;
+        for s in &t.stages {
+            if s.year == 0 {
+                return Err(CorpFinanceError::InvalidInput {
+                    field: "targets.stages.year".into(),
+                    reason: format!("Stages for '{}' must close in year 1 or later", t.name),
+                });
+            }
+            if s.stake_pct <= zero || s.stake_pct > one {
+                return Err(CorpFinanceError::InvalidInput {
+                    field: "targets.stages.stake_pct".into(),
+                    reason: format!("Stage stakes for '{}' must be in (0, 1]", t.name),
+                });
+            }
+            if s.offer_price_per_share.is_some_and(|p| p <= zero)
+                || s.acquirer_share_price.is_some_and(|p| p <= zero)
+            {
+                return Err(CorpFinanceError::InvalidInput {
+                    field: "targets.stages".into(),
+                    reason: format!("Stage prices for '{}' must be positive", t.name),
+                });
+            }
+            if let Some(c) = &s.consideration {
+                validate_consideration(c, &t.name)?;
+            }
+            stake += s.stake_pct;
+        }
+        if stake > one {
+            return Err(CorpFinanceError::InvalidInput {
+                field: "targets.stages.stake_pct".into(),
+                reason: format!("Stakes bought in '{}' exceed 100%", t.name),
+            });
+        }
+
+        if let Some(c) = &t.collar {
+            if c.reference_price <= zero || c.lower_price <= zero || c.lower_price >= c.upper_price
+            {
+                return Err(CorpFinanceError::InvalidInput {
+                    field: "targets.collar".into(),
+                    reason: format!(
+                        "Collar for '{}' needs a positive reference price and \
+                         0 < lower_price < upper_price",
+                        t.name
+                    ),
+                });
+            }
+        }
+        if let Some(cvr) = &t.cvr {
+            if cvr.probability < zero || cvr.probability > one {
+                return Err(CorpFinanceError::InvalidInput {
+                    field: "targets.cvr.probability".into(),
+                    reason: "CVR probability must be between 0 and 1".into(),
+                });
+            }
+            if cvr.discount_rate <= dec!(-1) {
+                return Err(CorpFinanceError::InvalidInput {
+                    field: "targets.cvr.discount_rate".into(),
+                    reason: "Discount rate must be greater than -100%".into(),
+                });
+            }
+        }
+        for cat in &t.synergies {
+            if cat.phasing.is_empty() || cat.phasing.iter().any(|p| *p < zero || *p > one) {
+                return Err(CorpFinanceError::InvalidInput {
+                    field: "targets.synergies.phasing".into(),
+                    reason: format!(
+                        "Phasing for '{}' must be a non-empty curve between 0 and 1",
+                        cat.name
+                    ),
+                });
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn validate_consideration(
+    consideration: &ConsiderationType,
+    target: &str,
+) -> CorpFinanceResult<()> {
+    if let ConsiderationType::Mixed { cash_pct } = consideration {
+        if *cash_pct < Decimal::ZERO || *cash_pct > dec!(1) {
+            return Err(CorpFinanceError::InvalidInput {
+                field: "consideration.cash_pct".into(),
+                reason: format!("Cash percentage for '{target}' must be between 0 and 1"),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Exchange ratio for an offer price at a given acquirer closing price,
+/// after the collar.
+fn apply_collar(collar: &ExchangeRatioCollar, offer: Money, price: Money) -> CollarOutcome {
+    let base = offer / collar.reference_price;
+    let (ratio, position) = if price < collar.lower_price {
+        let ratio = match collar.collar_type {
+            CollarType::FixedExchangeRatio => base * collar.lower_price / price,
+            CollarType::FixedValue => offer / collar.lower_price,
+        };
+        (ratio, CollarPosition::BelowCollar)
+    } else if price > collar.upper_price {
+        let ratio = match collar.collar_type {
+            CollarType::FixedExchangeRatio => base * collar.upper_price / price,
+            CollarType::FixedValue => offer / collar.upper_price,
+        };
+        (ratio, CollarPosition::AboveCollar)
+    } else {
+        let ratio = match collar.collar_type {
+            CollarType::FixedExchangeRatio => base,
+            CollarType::FixedValue => offer / price,
+        };
+        (ratio, CollarPosition::WithinCollar)
+    };
+    CollarOutcome {
+        acquirer_price: price,
+        base_exchange_ratio: base,
+        effective_exchange_ratio: ratio,
+        value_per_target_share: ratio * price,
+        position,
+        walk_away_triggered: collar.walk_away_price.is_some_and(|w| price < w),
+    }
+}
+
+/// Consideration, share issuance and CVR value for one target's stages.
+fn compute_target_deal(
+    input: &MultiTargetMergerInput,
+    target: &TargetDeal,
+    warnings: &mut Vec<String>,
+) -> TargetDealOutcome {
+    let one = dec!(1);
+    let zero = Decimal::ZERO;
+    let control_threshold = dec!(0.5);
+
+    let default_stage = [AcquisitionStage {
+        year: 1,
+        stake_pct: one,
+        offer_price_per_share: None,
+        consideration: None,
+        acquirer_share_price: None,
+    }];
+    let mut ordered: Vec<&AcquisitionStage> = if target.stages.is_empty() {
+        default_stage.iter().collect()
+    } else {
+        target.stages.iter().collect()
+    };
+    ordered.sort_by_key(|s| s.year);
+
+    let mut stages = Vec::with_capacity(ordered.len());
+    let mut cumulative_stake = zero;
+    let mut control_year = None;
+    let mut premium_paid = zero;
+    let mut new_shares_total = zero;
+
+    for stage in ordered {
+        let offer = stage
+            .offer_price_per_share
+            .unwrap_or(target.offer_price_per_share);
+        let consideration = stage
+            .consideration
+            .as_ref()
+            .unwrap_or(&target.consideration);
+        let price = stage
+            .acquirer_share_price
+            .unwrap_or(input.acquirer_share_price);
+        let shares_bought = target.shares_outstanding * stage.stake_pct;
+
+        let cash_pct = match consideration {
+            ConsiderationType::AllCash => one,
+            ConsiderationType::AllStock => zero,
+            ConsiderationType::Mixed { cash_pct } => *cash_pct,
+        };
+        let stock_pct = one - cash_pct;
+
+        let (exchange_ratio, collar) = if stock_pct > zero {
+            match &target.collar {
+                Some(c) => {
+                    let outcome = apply_collar(c, offer, price);
+                    if outcome.walk_away_triggered {
+                        warnings.push(format!(
+                            "Acquirer price {} for the year {} stage of '{}' is below the \
+                             walk-away price; the target may terminate",
+                            price, stage.year, target.name
+                        ));
+                    }
+                    (Some(outcome.effective_exchange_ratio), Some(outcome))
+                }
+                None => (Some(offer / price), None),
+            }
+        } else {
+            (None, None)
+        };
+
+        let new_shares_issued = exchange_ratio
+            .map(|r| shares_bought * stock_pct * r)
+            .unwrap_or(zero);
+        let cash_consideration = shares_bought * offer * cash_pct;
+        let stock_consideration = new_shares_issued * price;
+
+        if stage.year > input.projection_years {
+            warnings.push(format!(
+                "Year {} stage of '{}' closes after the projection horizon",
+                stage.year, target.name
+            ));
+        }
+
+        cumulative_stake += stage.stake_pct;
+        if control_year.is_none() && cumulative_stake > control_threshold {
+            control_year = Some(stage.year);
+        }
+        premium_paid += shares_bought * (offer - target.share_price);
+        new_shares_total += new_shares_issued;
+
+        stages.push(StageOutcome {
+            year: stage.year,
+            stake_pct: stage.stake_pct,
+            cumulative_stake,
+            cash_consideration,
+            stock_consideration,
+            total_consideration: cash_consideration + stock_consideration,
+            exchange_ratio,
+            new_shares_issued,
+            collar,
+        });
+    }
+
+    if control_year.is_none() && !target.synergies.is_empty() {
+        warnings.push(format!(
+            "Acquirer never holds more than 50% of '{}'; its synergies are not realised",
+            target.name
+        ));
+    }
+
+    let first_year = stages.first().map(|s| s.year).unwrap_or(1);
+    let cvr = target.cvr.as_ref().map(|c| {
+        let expected_payment =
+            c.payment_per_share * target.shares_outstanding * cumulative_stake * c.probability;
+        CvrOutcome {
+            expected_payment,
+            fair_value: expected_payment / pow_growth(c.discount_rate, c.payment_year),
+            payment_year: first_year + c.payment_year,
+        }
+    });
+
+    let stage_total: Money = stages.iter().map(|s| s.total_consideration).sum();
+    TargetDealOutcome {
+        name: target.name.clone(),
+        final_stake: cumulative_stake,
+        control_year,
+        total_consideration: stage_total + cvr.as_ref().map(|c| c.fair_value).unwrap_or(zero),
+        premium_paid,
+        new_shares_issued: new_shares_total,
+        cvr,
+        stages,
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -461,6 +1851,10 @@ mod tests {
 
             goodwill_amortisation: None,
             transaction_fees: None,
+
+            synergy_schedule: None,
+            purchase_price_allocation: None,
+            credit_profile: None,
         }
     }
 
@@ -784,4 +2178,753 @@ mod tests {
         let result = analyze_merger(&input).unwrap();
         assert_eq!(result.methodology, "M&A Accretion/Dilution Analysis");
     }
+
+    // -----------------------------------------------------------------------
+    // 13. Phased synergies
+    // -----------------------------------------------------------------------
+    fn schedule() -> SynergySchedule {
+        SynergySchedule {
+            categories: vec![
+                SynergyCategory {
+                    name: "Procurement".into(),
+                    kind: SynergyKind::Cost,
+                    run_rate: dec!(40),
+                    phasing: vec![dec!(0.25), dec!(0.75), dec!(1)],
+                    flow_through_margin: None,
+                },
+                SynergyCategory {
+                    name: "Cross-sell".into(),
+                    kind: SynergyKind::Revenue,
+                    run_rate: dec!(100),
+                    phasing: vec![dec!(0), dec!(0.5), dec!(1)],
+                    flow_through_margin: Some(dec!(0.2)),
+                },
+                SynergyCategory {
+                    name: "Footprint".into(),
+                    kind: SynergyKind::Capex,
+                    run_rate: dec!(10),
+                    phasing: vec![dec!(0.5), dec!(1)],
+                    flow_through_margin: None,
+                },
+            ],
+            integration_costs: vec![dec!(60), dec!(20)],
+            projection_years: 4,
+            discount_rate: dec!(0.10),
+            terminal_growth: None,
+            acquirer_net_income_growth: None,
+            target_net_income_growth: None,
+        }
+    }
+
+    fn phased(input: &MergerInput) -> SynergyPhasingOutput {
+        analyze_merger(input)
+            .unwrap()
+            .result
+            .synergy_phasing
+            .expect("synergy phasing output")
+    }
+
+    #[test]
+    fn test_no_schedule_no_phasing_output() {
+        let result = analyze_merger(&base_input()).unwrap();
+        assert!(result.result.synergy_phasing.is_none());
+    }
+
+    #[test]
+    fn test_phasing_by_category() {
+        let mut input = base_input();
+        input.synergy_schedule = Some(schedule());
+        let out = phased(&input);
+        assert_eq!(out.years.len(), 4);
+
+        // Year 1: cost 10, revenue 0, capex 5, integration 60
+        let y1 = &out.years[0];
+        assert_eq!(y1.cost_synergies, dec!(10));
+        assert_eq!(y1.revenue_synergies, dec!(0));
+        assert_eq!(y1.capex_synergies, dec!(5));
+        assert_eq!(y1.integration_costs, dec!(60));
+
+        // Year 2: cost 30, revenue 100 * 0.5 * 0.2 = 10, capex 10
+        let y2 = &out.years[1];
+        assert_eq!(y2.cost_synergies, dec!(30));
+        assert_eq!(y2.revenue_synergies, dec!(10));
+        assert_eq!(y2.capex_synergies, dec!(10));
+
+        // Year 4 holds the last phasing value; no integration spend left
+        let y4 = &out.years[3];
+        assert_eq!(y4.cost_synergies, dec!(40));
+        assert_eq!(y4.revenue_synergies, dec!(20));
+        assert_eq!(y4.integration_costs, Decimal::ZERO);
+        assert_eq!(out.run_rate_earnings_synergies, dec!(60));
+    }
+
+    #[test]
+    fn test_phased_after_tax_impact_and_cash_flow() {
+        let mut input = base_input();
+        input.synergy_schedule = Some(schedule());
+        let out = phased(&input);
+        let y1 = &out.years[0];
+        // (10 + 0) * 0.75 - 60 * 0.75 = -37.5 ; cash flow adds capex 5
+        assert_eq!(y1.after_tax_earnings_impact, dec!(-37.5));
+        assert_eq!(y1.net_synergy_cash_flow, dec!(-32.5));
+    }
+
+    #[test]
+    fn test_phased_accretion_path() {
+        let mut input = base_input();
+        input.consideration = ConsiderationType::AllStock;
+        input.synergy_schedule = Some(schedule());
+        let out = phased(&input);
+        // Stock deal is dilutive at 4.80 without synergies; ramp-up makes it
+        // accretive once run-rate synergies arrive.
+        assert!(!out.years[0].is_accretive);
+        let y3 = &out.years[2];
+        // NI = 600 + 60 * 0.75 = 645 ; EPS = 645 / 125 = 5.16
+        assert_eq!(y3.pro_forma_net_income, dec!(645));
+        assert_eq!(y3.pro_forma_eps, dec!(5.16));
+        assert!(y3.is_accretive);
+        assert_eq!(out.first_accretive_year, Some(3));
+    }
+
+    #[test]
+    fn test_phased_year_one_includes_fees_and_goodwill() {
+        let mut input = base_input();
+        input.transaction_fees = Some(dec!(20));
+        input.goodwill_amortisation = Some(dec!(5));
+        input.synergy_schedule = Some(schedule());
+        let out = phased(&input);
+        // Year 1: 600 - 46.875 - 37.5 - 5 - 20 = 490.625
+        assert_eq!(out.years[0].pro_forma_net_income, dec!(490.625));
+        // Year 2 drops the one-off fees: 600 - 46.875 + (40 * 0.75 - 15) - 5
+        assert_eq!(out.years[1].pro_forma_net_income, dec!(563.125));
+    }
+
+    #[test]
+    fn test_synergy_npv_without_terminal() {
+        let mut input = base_input();
+        input.synergy_schedule = Some(schedule());
+        let out = phased(&input);
+        let expected: Decimal = out.years.iter().map(|y| y.present_value).sum();
+        assert_eq!(out.pv_forecast_synergies, expected);
+        assert_eq!(out.pv_terminal_value, Decimal::ZERO);
+        assert_eq!(out.net_synergy_npv, expected);
+        // Year 1 PV = -32.5 / 1.1
+        let pv1 = dec!(-32.5) / dec!(1.1);
+        assert!((out.years[0].present_value - pv1).abs() < dec!(0.0000001));
+    }
+
+    #[test]
+    fn test_synergy_npv_with_terminal() {
+        let mut input = base_input();
+        let mut sched = schedule();
+        sched.terminal_growth = Some(dec!(0.02));
+        input.synergy_schedule = Some(sched);
+        let out = phased(&input);
+        // Run-rate cash flow: 60 * 0.75 + 10 = 55
+        let df4 = Decimal::ONE / (dec!(1.1) * dec!(1.1) * dec!(1.1) * dec!(1.1));
+        let expected = dec!(55) * dec!(1.02) / dec!(0.08) * df4;
+        assert!((out.pv_terminal_value - expected).abs() < dec!(0.0001));
+        assert!(out.net_synergy_npv > out.pv_forecast_synergies);
+    }
+
+    #[test]
+    fn test_value_created_vs_premium() {
+        let mut input = base_input();
+        let mut sched = schedule();
+        sched.terminal_growth = Some(dec!(0.02));
+        input.synergy_schedule = Some(sched);
+        input.transaction_fees = Some(dec!(10));
+        let out = phased(&input);
+        // Premium = (25 - 20) * 50 = 250
+        assert_eq!(out.premium_paid, dec!(250));
+        assert_eq!(
+            out.value_created,
+            out.net_synergy_npv - dec!(250) - dec!(10)
+        );
+        assert_eq!(
+            out.premium_coverage.unwrap(),
+            out.net_synergy_npv / dec!(250)
+        );
+    }
+
+    #[test]
+    fn test_standalone_growth_applied() {
+        let mut input = base_input();
+        let mut sched = schedule();
+        sched.acquirer_net_income_growth = Some(dec!(0.10));
+        input.synergy_schedule = Some(sched);
+        let out = phased(&input);
+        assert_eq!(out.years[0].acquirer_eps_standalone, dec!(5));
+        assert_eq!(out.years[1].acquirer_eps_standalone, dec!(5.5));
+    }
+
+    #[test]
+    fn test_dis_synergies_reduce_value() {
+        let mut input = base_input();
+        let mut sched = schedule();
+        sched.categories.push(SynergyCategory {
+            name: "Customer attrition".into(),
+            kind: SynergyKind::Revenue,
+            run_rate: dec!(-200),
+            phasing: vec![dec!(1)],
+            flow_through_margin: Some(dec!(0.3)),
+        });
+        input.synergy_schedule = Some(sched);
+        let base = {
+            let mut i = base_input();
+            i.synergy_schedule = Some(schedule());
+            phased(&i)
+        };
+        let out = phased(&input);
+        assert_eq!(out.run_rate_earnings_synergies, dec!(0));
+        assert!(out.net_synergy_npv < base.net_synergy_npv);
+    }
+
+    #[test]
+    fn test_schedule_with_run_rate_fields_warns() {
+        let mut input = base_input();
+        input.cost_synergies = Some(dec!(50));
+        input.synergy_schedule = Some(schedule());
+        let result = analyze_merger(&input).unwrap();
+        assert!(result
+            .warnings
+            .iter()
+            .any(|w| w.contains("synergy schedule")));
+    }
+
+    #[test]
+    fn test_invalid_phasing_rejected() {
+        let mut input = base_input();
+        let mut sched = schedule();
+        sched.categories[0].phasing = vec![dec!(1.5)];
+        input.synergy_schedule = Some(sched);
+        assert!(analyze_merger(&input).is_err());
+
+        let mut input = base_input();
+        let mut sched = schedule();
+        sched.categories[0].phasing.clear();
+        input.synergy_schedule = Some(sched);
+        assert!(analyze_merger(&input).is_err());
+    }
+
+    #[test]
+    fn test_invalid_projection_years_and_terminal_growth() {
+        let mut input = base_input();
+        let mut sched = schedule();
+        sched.projection_years = 0;
+        input.synergy_schedule = Some(sched);
+        assert!(analyze_merger(&input).is_err());
+
+        let mut input = base_input();
+        let mut sched = schedule();
+        sched.terminal_growth = Some(dec!(0.10));
+        input.synergy_schedule = Some(sched);
+        assert!(analyze_merger(&input).is_err());
+    }
+
+    // -----------------------------------------------------------------------
+    // 14. Multi-target, staged acquisitions
+    // -----------------------------------------------------------------------
+    fn target_deal(consideration: ConsiderationType) -> TargetDeal {
+        TargetDeal {
+            name: "TargetCo".into(),
+            net_income: dec!(100),
+            net_income_growth: None,
+            shares_outstanding: dec!(50),
+            share_price: dec!(20),
+            offer_price_per_share: dec!(25),
+            consideration,
+            stages: vec![],
+            collar: None,
+            cvr: None,
+            synergies: vec![],
+            integration_costs: vec![],
+            transaction_fees: None,
+        }
+    }
+
+    fn programme(targets: Vec<TargetDeal>) -> MultiTargetMergerInput {
+        MultiTargetMergerInput {
+            acquirer_name: "AcquirerCo".into(),
+            acquirer_net_income: dec!(500),
+            acquirer_shares_outstanding: dec!(100),
+            acquirer_share_price: dec!(50),
+            acquirer_tax_rate: dec!(0.25),
+            acquirer_net_income_growth: None,
+            debt_financing_rate: Some(dec!(0.05)),
+            foregone_interest_rate: None,
+            projection_years: 3,
+            targets,
+        }
+    }
+
+    fn stage(year: u32, stake_pct: Decimal, consideration: ConsiderationType) -> AcquisitionStage {
+        AcquisitionStage {
+            year,
+            stake_pct,
+            offer_price_per_share: None,
+            consideration: Some(consideration),
+            acquirer_share_price: None,
+        }
+    }
+
+    fn collar(collar_type: CollarType) -> ExchangeRatioCollar {
+        ExchangeRatioCollar {
+            collar_type,
+            reference_price: dec!(50),
+            lower_price: dec!(45),
+            upper_price: dec!(55),
+            walk_away_price: Some(dec!(40)),
+        }
+    }
+
+    fn approx(a: Decimal, b: Decimal) -> bool {
+        (a - b).abs() < dec!(0.0001)
+    }
+
+    #[test]
+    fn test_single_target_matches_merger_model() {
+        for consideration in [
+            ConsiderationType::AllCash,
+            ConsiderationType::AllStock,
+            ConsiderationType::Mixed {
+                cash_pct: dec!(0.4),
+            },
+        ] {
+            let mut single = base_input();
+            single.consideration = consideration.clone();
+            let single = analyze_merger(&single).unwrap().result;
+
+            let multi = analyze_multi_target_merger(&programme(vec![target_deal(consideration)]))
+                .unwrap()
+                .result;
+            let y1 = &multi.years[0];
+            assert_eq!(y1.pro_forma_net_income, single.pro_forma_net_income);
+            assert_eq!(y1.pro_forma_shares, single.pro_forma_shares);
+            assert_eq!(y1.pro_forma_eps, single.pro_forma_eps);
+            assert_eq!(multi.total_consideration, single.deal_value);
+        }
+    }
+
+    #[test]
+    fn test_staged_stake_attribution_and_control() {
+        let mut t = target_deal(ConsiderationType::AllCash);
+        t.stages = vec![
+            stage(3, dec!(0.7), ConsiderationType::AllStock),
+            stage(1, dec!(0.3), ConsiderationType::AllCash),
+        ];
+        let out = analyze_multi_target_merger(&programme(vec![t]))
+            .unwrap()
+            .result;
+        let deal = &out.targets[0];
+
+        // Stages are ordered by year; control passes 50% in year 3
+        assert_eq!(deal.stages[0].year, 1);
+        assert_eq!(deal.stages[1].cumulative_stake, dec!(1.0));
+        assert_eq!(deal.control_year, Some(3));
+
+        // Years 1-2: 30% of earnings, cash 15 x 25 = 375 financed at 5% after tax
+        assert_eq!(out.years[0].acquired_net_income, dec!(30));
+        assert_eq!(out.years[1].financing_cost, dec!(14.0625));
+        assert_eq!(out.years[1].pro_forma_shares, dec!(100));
+
+        // Year 3: full earnings and 35 x 0.5 = 17.5 new shares
+        assert_eq!(out.years[2].acquired_net_income, dec!(100));
+        assert_eq!(out.years[2].pro_forma_shares, dec!(117.5));
+        assert_eq!(out.total_new_shares_issued, dec!(17.5));
+    }
+
+    #[test]
+    fn test_fixed_value_collar() {
+        let c = collar(CollarType::FixedValue);
+
+        // Within the band the target receives exactly the offer value
+        let within = apply_collar(&c, dec!(25), dec!(48));
+        assert_eq!(within.position, CollarPosition::WithinCollar);
+        assert!(approx(within.value_per_target_share, dec!(25)));
+
+        // Below the band the ratio freezes at 25 / 45 and value falls
+        let below = apply_collar(&c, dec!(25), dec!(42));
+        assert_eq!(below.position, CollarPosition::BelowCollar);
+        assert!(approx(below.effective_exchange_ratio, dec!(25) / dec!(45)));
+        assert!(below.value_per_target_share < dec!(25));
+        assert!(!below.walk_away_triggered);
+    }
+
+    #[test]
+    fn test_fixed_exchange_ratio_collar() {
+        let c = collar(CollarType::FixedExchangeRatio);
+
+        let within = apply_collar(&c, dec!(25), dec!(52));
+        assert_eq!(within.effective_exchange_ratio, dec!(0.5));
+
+        // Above the band value is capped at 0.5 x 55 = 27.5
+        let above = apply_collar(&c, dec!(25), dec!(60));
+        assert_eq!(above.position, CollarPosition::AboveCollar);
+        assert!(approx(above.value_per_target_share, dec!(27.5)));
+        assert!(above.effective_exchange_ratio < dec!(0.5));
+    }
+
+    #[test]
+    fn test_collar_drives_share_issuance_and_walk_away() {
+        let mut t = target_deal(ConsiderationType::AllStock);
+        t.collar = Some(collar(CollarType::FixedValue));
+        t.stages = vec![AcquisitionStage {
+            year: 1,
+            stake_pct: dec!(1),
+            offer_price_per_share: None,
+            consideration: None,
+            acquirer_share_price: Some(dec!(38)),
+        }];
+        let result = analyze_multi_target_merger(&programme(vec![t])).unwrap();
+        let s = &result.result.targets[0].stages[0];
+
+        // Ratio frozen at 25 / 45: 50 x 25 / 45 shares issued
+        assert!(approx(s.new_shares_issued, dec!(50) * dec!(25) / dec!(45)));
+        assert!(s.collar.as_ref().unwrap().walk_away_triggered);
+        assert!(result.warnings.iter().any(|w| w.contains("walk-away")));
+    }
+
+    #[test]
+    fn test_cvr_value_and_payment_financing() {
+        let mut t = target_deal(ConsiderationType::AllStock);
+        t.cvr = Some(ContingentValueRight {
+            payment_per_share: dec!(2),
+            probability: dec!(0.5),
+            payment_year: 2,
+            discount_rate: dec!(0.10),
+        });
+        let out = analyze_multi_target_merger(&programme(vec![t]))
+            .unwrap()
+            .result;
+        let cvr = out.targets[0].cvr.as_ref().unwrap();
+
+        // 50 shares x 2 x 50% = 50 expected, paid in year 1 + 2 = 3
+        assert_eq!(cvr.expected_payment, dec!(50));
+        assert_eq!(cvr.payment_year, 3);
+        assert!(approx(cvr.fair_value, dec!(50) / dec!(1.21)));
+        assert_eq!(out.total_cvr_fair_value, cvr.fair_value);
+        assert_eq!(out.total_consideration, dec!(1250) + cvr.fair_value);
+
+        // Payment is funded from year 3: 50 x 5% x 0.75
+        assert_eq!(out.years[1].financing_cost, Decimal::ZERO);
+        assert_eq!(out.years[2].financing_cost, dec!(1.875));
+    }
+
+    #[test]
+    fn test_multi_target_synergies_and_ownership() {
+        let mut first = target_deal(ConsiderationType::AllStock);
+        first.synergies = vec![SynergyCategory {
+            name: "Procurement".into(),
+            kind: SynergyKind::Cost,
+            run_rate: dec!(40),
+            phasing: vec![dec!(0.5), dec!(1)],
+            flow_through_margin: None,
+        }];
+        first.integration_costs = vec![dec!(20)];
+        first.transaction_fees = Some(dec!(5));
+
+        let mut second = target_deal(ConsiderationType::AllStock);
+        second.name = "BoltOnCo".into();
+        second.stages = vec![stage(2, dec!(1), ConsiderationType::AllStock)];
+
+        let out = analyze_multi_target_merger(&programme(vec![first, second]))
+            .unwrap()
+            .result;
+
+        // Year 1: 40 x 50% x 0.75 synergies, 20 x 0.75 integration, 5 fees
+        let y1 = &out.years[0];
+        assert_eq!(y1.after_tax_synergies, dec!(15));
+        assert_eq!(y1.after_tax_integration_costs, dec!(15));
+        assert_eq!(y1.transaction_fees, dec!(5));
+        assert_eq!(y1.pro_forma_net_income, dec!(595));
+        assert_eq!(y1.pro_forma_shares, dec!(125));
+
+        // Year 2: the bolt-on closes, full synergies, no one-offs
+        let y2 = &out.years[1];
+        assert_eq!(y2.pro_forma_shares, dec!(150));
+        assert_eq!(y2.pro_forma_net_income, dec!(730));
+        assert_eq!(out.total_new_shares_issued, dec!(50));
+        assert_eq!(out.acquirer_ownership_pct, dec!(100) / dec!(150));
+        assert_eq!(
+            out.cumulative_eps_accretion_dilution,
+            out.years
+                .iter()
+                .map(|y| y.eps_accretion_dilution)
+                .sum::<Decimal>()
+        );
+    }
+
+    #[test]
+    fn test_multi_target_validation() {
+        assert!(analyze_multi_target_merger(&programme(vec![])).is_err());
+
+        let mut t = target_deal(ConsiderationType::AllCash);
+        t.stages = vec![
+            stage(1, dec!(0.6), ConsiderationType::AllCash),
+            stage(2, dec!(0.6), ConsiderationType::AllCash),
+        ];
+        assert!(analyze_multi_target_merger(&programme(vec![t])).is_err());
+
+        let mut t = target_deal(ConsiderationType::AllStock);
+        let mut c = collar(CollarType::FixedValue);
+        c.lower_price = dec!(60);
+        t.collar = Some(c);
+        assert!(analyze_multi_target_merger(&programme(vec![t])).is_err());
+
+        let mut input = programme(vec![target_deal(ConsiderationType::AllCash)]);
+        input.projection_years = 0;
+        assert!(analyze_multi_target_merger(&input).is_err());
+    }
+
+    #[test]
+    fn test_stage_beyond_horizon_warns() {
+        let mut t = target_deal(ConsiderationType::AllCash);
+        t.stages = vec![stage(5, dec!(1), ConsiderationType::AllCash)];
+        let result = analyze_multi_target_merger(&programme(vec![t])).unwrap();
+        assert!(result
+            .warnings
+            .iter()
+            .any(|w| w.contains("after the projection horizon")));
+        assert!(result
+            .result
+            .years
+            .iter()
+            .all(|y| y.acquired_net_income == Decimal::ZERO));
+    }
+
+    // -----------------------------------------------------------------------
+    // 15. Purchase price allocation
+    // -----------------------------------------------------------------------
+    fn net_assets() -> IdentifiableNetAssets {
+        IdentifiableNetAssets {
+            book_equity: dec!(300),
+            existing_goodwill: Decimal::ZERO,
+            step_ups: vec![
+                ppa::FairValueStepUp {
+                    name: "Customer relationships".into(),
+                    category: ppa::StepUpCategory::Intangible,
+                    book_value: dec!(0),
+                    fair_value: dec!(200),
+                    useful_life_years: Some(10),
+                },
+                ppa::FairValueStepUp {
+                    name: "Plant".into(),
+                    category: ppa::StepUpCategory::PropertyPlantEquipment,
+                    book_value: dec!(100),
+                    fair_value: dec!(150),
+                    useful_life_years: Some(5),
+                },
+            ],
+            tax_basis_step_up: false,
+            tax_goodwill_life_years: None,
+        }
+    }
+
+    #[test]
+    fn test_ppa_goodwill_from_deal_value() {
+        let mut input = base_input();
+        input.purchase_price_allocation = Some(net_assets());
+        let out = analyze_merger(&input).unwrap().result;
+        let ppa = out.purchase_price_allocation.expect("ppa output");
+        // Deal value 1250 ; identifiable = 300 + 250 - 62.5 = 487.5
+        assert_eq!(ppa.purchase_consideration, dec!(1250));
+        assert_eq!(ppa.deferred_tax_liability, dec!(62.5));
+        assert_eq!(ppa.goodwill, dec!(762.5));
+        // No synergy schedule: five-year D&A schedule
+        assert_eq!(ppa.schedule.len(), 5);
+    }
+
+    #[test]
+    fn test_ppa_incremental_da_reduces_eps() {
+        let mut input = base_input();
+        input.purchase_price_allocation = Some(net_assets());
+        let out = analyze_merger(&input).unwrap().result;
+        // D&A = 200/10 + 50/5 = 30 pre-tax, 22.5 after tax
+        assert_eq!(out.incremental_da_after_tax, dec!(22.5));
+        // 553.125 - 22.5
+        assert_eq!(out.pro_forma_net_income, dec!(530.625));
+        assert_eq!(out.pro_forma_eps, dec!(5.30625));
+
+        let without = analyze_merger(&base_input()).unwrap().result;
+        assert!(without.purchase_price_allocation.is_none());
+        assert_eq!(without.incremental_da_after_tax, Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_ppa_raises_breakeven_synergies() {
+        let mut input = base_input();
+        input.consideration = ConsiderationType::AllStock;
+        let before = analyze_merger(&input).unwrap().result.breakeven_synergies;
+        input.purchase_price_allocation = Some(net_assets());
+        let after = analyze_merger(&input).unwrap().result.breakeven_synergies;
+        // Extra pre-tax synergies needed = 22.5 / 0.75
+        assert_eq!(after - before, dec!(30));
+    }
+
+    #[test]
+    fn test_ppa_flows_through_phased_years() {
+        let mut input = base_input();
+        input.synergy_schedule = Some(schedule());
+        let without = phased(&input);
+        input.purchase_price_allocation = Some(net_assets());
+        let result = analyze_merger(&input).unwrap().result;
+        let with = result.synergy_phasing.unwrap();
+        assert_eq!(result.purchase_price_allocation.unwrap().schedule.len(), 4);
+        for (a, b) in without.years.iter().zip(&with.years) {
+            assert_eq!(a.pro_forma_net_income - b.pro_forma_net_income, dec!(22.5));
+        }
+        // Synergy value is unaffected by the accounting charge
+        assert_eq!(without.net_synergy_npv, with.net_synergy_npv);
+    }
+
+    // -----------------------------------------------------------------------
+    // 16. Pro-forma credit metrics
+    // -----------------------------------------------------------------------
+    fn credit_profile() -> ProFormaCreditInput {
+        ProFormaCreditInput {
+            acquirer_ebitda: dec!(900),
+            target_ebitda: dec!(200),
+            acquirer_debt: dec!(1000),
+            target_debt: dec!(300),
+            acquirer_cash: dec!(200),
+            target_cash: dec!(50),
+            acquirer_interest_expense: dec!(50),
+            target_interest_expense: dec!(15),
+            new_acquisition_debt: None,
+            ebitda_growth: None,
+            max_net_leverage: None,
+        }
+    }
+
+    #[test]
+    fn test_credit_standalone_and_pro_forma() {
+        let mut input = base_input();
+        input.credit_profile = Some(credit_profile());
+        let out = analyze_merger(&input).unwrap().result;
+
+        let standalone = out.acquirer_credit_standalone.unwrap();
+        assert_eq!(standalone.net_debt, dec!(800));
+        assert_eq!(standalone.interest_coverage, Some(dec!(18)));
+        assert_eq!(standalone.implied_rating, CreditRating::AAA);
+
+        // Cash consideration of 1250 debt-funded at 5%
+        let pf = out.pro_forma_credit.unwrap();
+        assert_eq!(pf.total_debt, dec!(2550));
+        assert_eq!(pf.cash, dec!(250));
+        assert_eq!(pf.net_debt, dec!(2300));
+        assert_eq!(pf.interest_expense, dec!(127.5));
+        assert_eq!(pf.ebitda, dec!(1100));
+        assert!(pf.net_debt_to_ebitda.unwrap() > dec!(2.09));
+        assert_eq!(pf.implied_rating, CreditRating::A);
+        assert!(!pf.exceeds_leverage_limit);
+    }
+
+    #[test]
+    fn test_credit_year_one_uses_phased_synergies() {
+        let mut input = base_input();
+        input.cost_synergies = Some(dec!(100));
+        input.synergy_phase_in_pct = Some(dec!(0.5));
+        input.credit_profile = Some(credit_profile());
+        let pf = analyze_merger(&input)
+            .unwrap()
+            .result
+            .pro_forma_credit
+            .unwrap();
+        assert_eq!(pf.ebitda_synergies, dec!(50));
+        assert_eq!(pf.ebitda, dec!(1150));
+        assert_eq!(pf.net_debt_to_ebitda, Some(dec!(2)));
+    }
+
+    #[test]
+    fn test_credit_stock_deal_adds_no_debt() {
+        let mut input = base_input();
+        input.consideration = ConsiderationType::AllStock;
+        input.credit_profile = Some(credit_profile());
+        let pf = analyze_merger(&input)
+            .unwrap()
+            .result
+            .pro_forma_credit
+            .unwrap();
+        assert_eq!(pf.total_debt, dec!(1300));
+        assert_eq!(pf.net_debt, dec!(1050));
+        assert_eq!(pf.interest_expense, dec!(65));
+    }
+
+    #[test]
+    fn test_credit_cash_funded_shortfall_warns() {
+        let mut input = base_input();
+        input.debt_financing_rate = None;
+        input.foregone_interest_rate = Some(dec!(0.04));
+        input.credit_profile = Some(credit_profile());
+        let result = analyze_merger(&input).unwrap();
+        let pf = result.result.pro_forma_credit.unwrap();
+        // 250 of cash on hand; 1000 shortfall becomes debt
+        assert_eq!(pf.cash, Decimal::ZERO);
+        assert_eq!(pf.total_debt, dec!(2300));
+        assert!(result.warnings.iter().any(|w| w.contains("shortfall")));
+    }
+
+    #[test]
+    fn test_credit_leverage_limit_by_year() {
+        let mut input = base_input();
+        let mut credit = credit_profile();
+        credit.max_net_leverage = Some(dec!(2));
+        input.credit_profile = Some(credit);
+        input.synergy_schedule = Some(schedule());
+        let result = analyze_merger(&input).unwrap();
+        assert!(
+            result
+                .result
+                .pro_forma_credit
+                .unwrap()
+                .exceeds_leverage_limit
+        );
+        assert!(result
+            .warnings
+            .iter()
+            .any(|w| w.contains("exceeds the 2x limit")));
+
+        // Net debt 2300 held flat; EBITDA 1110, 1140, 1160 as synergies ramp
+        let phasing = result.result.synergy_phasing.unwrap();
+        let ebitda: Vec<Decimal> = phasing
+            .years
+            .iter()
+            .map(|y| y.credit.as_ref().unwrap().ebitda)
+            .collect();
+        assert_eq!(ebitda[..3], [dec!(1110), dec!(1140), dec!(1160)]);
+        assert_eq!(phasing.first_year_within_leverage_limit, Some(3));
+    }
+
+    #[test]
+    fn test_credit_ebitda_growth_applied_by_year() {
+        let mut input = base_input();
+        let mut credit = credit_profile();
+        credit.ebitda_growth = Some(dec!(0.10));
+        input.credit_profile = Some(credit);
+        input.synergy_schedule = Some(schedule());
+        let phasing = analyze_merger(&input)
+            .unwrap()
+            .result
+            .synergy_phasing
+            .unwrap();
+        // Year 2: 1100 * 1.1 + 40 of synergies
+        assert_eq!(phasing.years[1].credit.as_ref().unwrap().ebitda, dec!(1250));
+        assert_eq!(phasing.first_year_within_leverage_limit, None);
+    }
+
+    #[test]
+    fn test_credit_profile_validation() {
+        let mut input = base_input();
+        let mut credit = credit_profile();
+        credit.target_debt = dec!(-1);
+        input.credit_profile = Some(credit);
+        assert!(analyze_merger(&input).is_err());
+
+        let mut input = base_input();
+        let mut credit = credit_profile();
+        credit.max_net_leverage = Some(Decimal::ZERO);
+        input.credit_profile = Some(credit);
+        assert!(analyze_merger(&input).is_err());
+    }
 }
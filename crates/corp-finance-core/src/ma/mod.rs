@@ -1 +1,6 @@
+pub mod earnout;
+pub mod financing;
+pub mod merger_arb;
 pub mod merger_model;
+pub mod ppa;
+pub mod purchase_price;
@@ -0,0 +1,613 @@
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+use std::time::Instant;
+
+use crate::error::CorpFinanceError;
+use crate::types::*;
+use crate::CorpFinanceResult;
+
+// ---------------------------------------------------------------------------
+// Types
+// ---------------------------------------------------------------------------
+
+/// Balance sheet category of a fair-value step-up, which determines how the
+/// step-up runs through the income statement.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum StepUpCategory {
+    /// Customer relationships, technology, brands, backlog: amortisation.
+    Intangible,
+    /// Property, plant and equipment: depreciation.
+    PropertyPlantEquipment,
+    /// Inventory step-up: released through cost of sales as the stock is sold.
+    Inventory,
+    /// Other assets (or liabilities, with a negative step-up) amortised over
+    /// their remaining life.
+    Other,
+}
+
+/// Fair-value adjustment to one identifiable asset of the target.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FairValueStepUp {
+    pub name: String,
+    pub category: StepUpCategory,
+    /// Carrying value on the target's balance sheet (zero for internally
+    /// generated intangibles).
+    pub book_value: Money,
+    pub fair_value: Money,
+    /// Remaining useful life in years. `None` for indefinite-lived assets
+    /// (land, some brands), which are not amortised; inventory without a life
+    /// is released in year 1.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub useful_life_years: Option<u32>,
+}
+
+/// The target's net assets and the fair-value step-ups recognised on
+/// acquisition.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IdentifiableNetAssets {
+    /// Book value of the target's shareholders' equity.
+    pub book_equity: Money,
+    /// Goodwill already on the target's balance sheet, written off on
+    /// acquisition.
+    #[serde(default)]
+    pub existing_goodwill: Money,
+    pub step_ups: Vec<FairValueStepUp>,
+    /// `true` for asset deals and 338(h)(10)/336(e) elections: the step-up
+    /// and goodwill are tax deductible, so no deferred tax liability arises.
+    #[serde(default)]
+    pub tax_basis_step_up: bool,
+    /// Tax amortisation period for goodwill in a tax basis step-up
+    /// (default 15 years, per IRC §197).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_goodwill_life_years: Option<u32>,
+}
+
+/// Inputs for a purchase price allocation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PpaInput {
+    pub target_name: String,
+    /// Fair value of consideration transferred, including contingent
+    /// consideration.
+    pub purchase_consideration: Money,
+    /// Fair value of the non-controlling interest (full goodwill method).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub non_controlling_interest: Option<Money>,
+    /// Fair value of any previously held equity interest (step acquisition).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previously_held_interest: Option<Money>,
+    pub net_assets: IdentifiableNetAssets,
+    pub tax_rate: Rate,
+    /// Years in the incremental D&A schedule.
+    pub projection_years: u32,
+}
+
+/// Allocation of the step-up for one asset.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StepUpAllocation {
+    pub name: String,
+    pub category: StepUpCategory,
+    pub book_value: Money,
+    pub fair_value: Money,
+    pub step_up: Money,
+    pub useful_life_years: Option<u32>,
+    /// Straight-line annual D&A on the step-up (zero if indefinite-lived).
+    pub annual_charge: Money,
+    /// Deferred tax liability on the step-up.
+    pub deferred_tax: Money,
+}
+
+/// Incremental D&A and deferred tax for one year after closing.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PpaYear {
+    pub year: u32,
+    pub incremental_depreciation: Money,
+    pub incremental_amortisation: Money,
+    /// Inventory step-up released through cost of sales.
+    pub inventory_step_up_release: Money,
+    pub total_incremental_charge: Money,
+    /// Change in net income: the pre-tax charge less its tax effect.
+    pub after_tax_earnings_impact: Money,
+    /// Deferred tax liability unwound through the tax charge.
+    pub deferred_tax_release: Money,
+    pub closing_deferred_tax_liability: Money,
+    /// Cash tax saved from tax-deductible step-ups and goodwill.
+    pub cash_tax_savings: Money,
+}
+
+/// Results of a purchase price allocation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PpaOutput {
+    pub purchase_consideration: Money,
+    /// Consideration plus non-controlling and previously held interests.
+    pub total_value_to_allocate: Money,
+    /// Book equity less existing goodwill.
+    pub book_net_assets: Money,
+    pub total_step_up: Money,
+    pub deferred_tax_liability: Money,
+    /// Book net assets plus step-ups less the deferred tax liability.
+    pub identifiable_net_assets: Money,
+    pub goodwill: Money,
+    /// Gain recognised when identifiable net assets exceed the value to
+    /// allocate.
+    pub bargain_purchase_gain: Money,
+    /// Goodwill as a share of the total value to allocate.
+    pub goodwill_pct: Rate,
+    pub allocations: Vec<StepUpAllocation>,
+    pub schedule: Vec<PpaYear>,
+}
+
+// ---------------------------------------------------------------------------
+// Public API
+// ---------------------------------------------------------------------------
+
+/// Allocate the purchase price to identifiable net assets at fair value,
+/// derive goodwill and the deferred tax liability, and schedule the
+/// incremental D&A and deferred tax unwind.
+pub fn allocate_purchase_price(
+    input: &PpaInput,
+) -> CorpFinanceResult<ComputationOutput<PpaOutput>> {
+    let start = Instant::now();
+    let mut warnings: Vec<String> = Vec::new();
+
+    let output = compute_allocation(input, &mut warnings)?;
+
+    let elapsed = start.elapsed().as_micros() as u64;
+    Ok(with_metadata(
+        "Purchase Price Allocation (ASC 805 / IFRS 3 acquisition method)",
+        &serde_json::json!({
+            "target": input.target_name,
+            "tax_rate": input.tax_rate.to_string(),
+            "tax_basis_step_up": input.net_assets.tax_basis_step_up,
+            "goodwill_method": if input.non_controlling_interest.is_some() {
+                "full goodwill"
+            } else {
+                "acquirer share"
+            },
+            "depreciation": "straight-line over remaining useful life",
+        }),
+        warnings,
+        elapsed,
+        output,
+    ))
+}
+
+/// Core allocation, shared with the merger model.
+pub(crate) fn compute_allocation(
+    input: &PpaInput,
+    warnings: &mut Vec<String>,
+) -> CorpFinanceResult<PpaOutput> {
+    validate_input(input)?;
+
+    let zero = Decimal::ZERO;
+    let one = dec!(1);
+    let assets = &input.net_assets;
+    let t = input.tax_rate;
+    let deferred_tax_rate = if assets.tax_basis_step_up { zero } else { t };
+
+    // ------------------------------------------------------------------
+    // 1. Step-ups and deferred tax by asset
+    // ------------------------------------------------------------------
+    let allocations: Vec<StepUpAllocation> = assets
+        .step_ups
+        .iter()
+        .map(|s| {
+            let step_up = s.fair_value - s.book_value;
+            let life = charge_life(s);
+            let annual_charge = life.map(|n| step_up / Decimal::from(n)).unwrap_or(zero);
+            StepUpAllocation {
+                name: s.name.clone(),
+                category: s.category,
+                book_value: s.book_value,
+                fair_value: s.fair_value,
+                step_up,
+                useful_life_years: s.useful_life_years,
+                annual_charge,
+                deferred_tax: step_up * deferred_tax_rate,
+            }
+        })
+        .collect();
+
+    let total_step_up: Money = allocations.iter().map(|a| a.step_up).sum();
+    let deferred_tax_liability: Money = allocations.iter().map(|a| a.deferred_tax).sum();
+
+    // ------------------------------------------------------------------
+    // 2. Goodwill
+    // ------------------------------------------------------------------
+    let total_value_to_allocate = input.purchase_consideration
+        + input.non_controlling_interest.unwrap_or(zero)
+        + input.previously_held_interest.unwrap_or(zero);
+    let book_net_assets = assets.book_equity - assets.existing_goodwill;
+    let identifiable_net_assets = book_net_assets + total_step_up - deferred_tax_liability;
+    let residual = total_value_to_allocate - identifiable_net_assets;
+    let (goodwill, bargain_purchase_gain) = if residual >= zero {
+        (residual, zero)
+    } else {
+        warnings.push(
+            "Identifiable net assets exceed the value to allocate: bargain purchase gain \
+             recognised; reassess the fair values before booking it"
+                .into(),
+        );
+        (zero, -residual)
+    };
+    let goodwill_pct = if total_value_to_allocate > zero {
+        goodwill / total_value_to_allocate
+    } else {
+        zero
+    };
+
+    if goodwill > zero
+        && !assets
+            .step_ups
+            .iter()
+            .any(|s| s.category == StepUpCategory::Intangible)
+    {
+        warnings.push(
+            "No identifiable intangibles recognised; customer, technology and brand \
+             intangibles are normally separated from goodwill"
+                .into(),
+        );
+    }
+    if goodwill_pct > dec!(0.8) {
+        warnings.push(format!(
+            "Goodwill is {:.1}% of the value allocated; check for unidentified intangibles",
+            goodwill_pct * dec!(100)
+        ));
+    }
+
+    // ------------------------------------------------------------------
+    // 3. Incremental D&A, deferred tax unwind and cash tax savings
+    // ------------------------------------------------------------------
+    let tax_goodwill_life = assets.tax_goodwill_life_years.unwrap_or(15);
+    let tax_goodwill_charge = if assets.tax_basis_step_up {
+        goodwill / Decimal::from(tax_goodwill_life)
+    } else {
+        zero
+    };
+
+    let mut schedule = Vec::with_capacity(input.projection_years as usize);
+    let mut closing_dtl = deferred_tax_liability;
+    for year in 1..=input.projection_years {
+        let mut depreciation = zero;
+        let mut amortisation = zero;
+        let mut inventory = zero;
+        for (step, alloc) in assets.step_ups.iter().zip(&allocations) {
+            let in_life = charge_life(step).is_some_and(|n| year <= n);
+            if !in_life {
+                continue;
+            }
+            match step.category {
+                StepUpCategory::PropertyPlantEquipment => depreciation += alloc.annual_charge,
+                StepUpCategory::Inventory => inventory += alloc.annual_charge,
+                StepUpCategory::Intangible | StepUpCategory::Other => {
+                    amortisation += alloc.annual_charge
+                }
+            }
+        }
+        let total = depreciation + amortisation + inventory;
+        let deferred_tax_release = total * deferred_tax_rate;
+        closing_dtl -= deferred_tax_release;
+
+        let tax_goodwill = if year <= tax_goodwill_life {
+            tax_goodwill_charge
+        } else {
+            zero
+        };
+        let cash_tax_savings = if assets.tax_basis_step_up {
+            (total + tax_goodwill) * t
+        } else {
+            zero
+        };
+
+        schedule.push(PpaYear {
+            year,
+            incremental_depreciation: depreciation,
+            incremental_amortisation: amortisation,
+            inventory_step_up_release: inventory,
+            total_incremental_charge: total,
+            after_tax_earnings_impact: -total * (one - t),
+            deferred_tax_release,
+            closing_deferred_tax_liability: closing_dtl,
+            cash_tax_savings,
+        });
+    }
+
+    Ok(PpaOutput {
+        purchase_consideration: input.purchase_consideration,
+        total_value_to_allocate,
+        book_net_assets,
+        total_step_up,
+        deferred_tax_liability,
+        identifiable_net_assets,
+        goodwill,
+        bargain_purchase_gain,
+        goodwill_pct,
+        allocations,
+        schedule,
+    })
+}
+
+// ---------------------------------------------------------------------------
+// Internal helpers
+// ---------------------------------------------------------------------------
+
+/// Years over which a step-up is charged to earnings; inventory without a
+/// stated life turns over in year 1.
+fn charge_life(step: &FairValueStepUp) -> Option<u32> {
+    match (step.category, step.useful_life_years) {
+        (StepUpCategory::Inventory, None) => Some(1),
+        (_, life) => life,
+    }
+}
+
+fn validate_input(input: &PpaInput) -> CorpFinanceResult<()> {
+    let zero = Decimal::ZERO;
+    if input.purchase_consideration <= zero {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "purchase_consideration".into(),
+            reason: "Purchase consideration must be positive".into(),
+        });
+    }
+    if input.tax_rate < zero || input.tax_rate > dec!(1) {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "tax_rate".into(),
+            reason: "Tax rate must be between 0 and 1".into(),
+        });
+    }
+    if input.projection_years == 0 {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "projection_years".into(),
+            reason: "Projection must cover at least one year".into(),
+        });
+    }
+    crate::limits::check_schedule_periods("projection_years", input.projection_years.into())?;
+    if input.non_controlling_interest.is_some_and(|v| v < zero)
+        || input.previously_held_interest.is_some_and(|v| v < zero)
+    {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "non_controlling_interest".into(),
+            reason: "Non-controlling and previously held interests cannot be negative".into(),
+        });
+    }
+    validate_net_assets(&input.net_assets)
+}
+
+pub(crate) fn validate_net_assets(assets: &IdentifiableNetAssets) -> CorpFinanceResult<()> {
+    let zero = Decimal::ZERO;
+    if assets.existing_goodwill < zero {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "net_assets.existing_goodwill".into(),
+            reason: "Existing goodwill cannot be negative".into(),
+        });
+    }
+    if assets.tax_goodwill_life_years == Some(0) {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "net_assets.tax_goodwill_life_years".into(),
+            reason: "Tax goodwill life must be at least one year".into(),
+        });
+    }
+    for s in &assets.step_ups {
+        if s.fair_value < zero {
+            return Err(CorpFinanceError::InvalidInput {
+                field: "net_assets.step_ups.fair_value".into(),
+                reason: format!("Fair value of '{}' cannot be negative", s.name),
+            });
+        }
+        if s.useful_life_years == Some(0) {
+            return Err(CorpFinanceError::InvalidInput {
+                field: "net_assets.step_ups.useful_life_years".into(),
+                reason: format!("Useful life of '{}' must be at least one year", s.name),
+            });
+        }
+    }
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn step_up(
+        name: &str,
+        category: StepUpCategory,
+        book_value: Decimal,
+        fair_value: Decimal,
+        life: Option<u32>,
+    ) -> FairValueStepUp {
+        FairValueStepUp {
+            name: name.into(),
+            category,
+            book_value,
+            fair_value,
+            useful_life_years: life,
+        }
+    }
+
+    /// $1,000 paid for a target with $400 book equity (incl. $50 goodwill).
+    fn base_input() -> PpaInput {
+        PpaInput {
+            target_name: "TargetCo".into(),
+            purchase_consideration: dec!(1000),
+            non_controlling_interest: None,
+            previously_held_interest: None,
+            net_assets: IdentifiableNetAssets {
+                book_equity: dec!(400),
+                existing_goodwill: dec!(50),
+                step_ups: vec![
+                    step_up(
+                        "Customer relationships",
+                        StepUpCategory::Intangible,
+                        dec!(0),
+                        dec!(200),
+                        Some(10),
+                    ),
+                    step_up(
+                        "Plant",
+                        StepUpCategory::PropertyPlantEquipment,
+                        dec!(150),
+                        dec!(210),
+                        Some(5),
+                    ),
+                    step_up(
+                        "Inventory",
+                        StepUpCategory::Inventory,
+                        dec!(80),
+                        dec!(100),
+                        None,
+                    ),
+                    step_up(
+                        "Trade name",
+                        StepUpCategory::Intangible,
+                        dec!(0),
+                        dec!(40),
+                        None,
+                    ),
+                ],
+                tax_basis_step_up: false,
+                tax_goodwill_life_years: None,
+            },
+            tax_rate: dec!(0.25),
+            projection_years: 6,
+        }
+    }
+
+    fn run(input: &PpaInput) -> PpaOutput {
+        allocate_purchase_price(input).unwrap().result
+    }
+
+    #[test]
+    fn test_goodwill_and_deferred_tax() {
+        let out = run(&base_input());
+        // Step-up = 200 + 60 + 20 + 40 = 320, DTL = 80
+        assert_eq!(out.total_step_up, dec!(320));
+        assert_eq!(out.deferred_tax_liability, dec!(80));
+        // Identifiable = (400 - 50) + 320 - 80 = 590
+        assert_eq!(out.book_net_assets, dec!(350));
+        assert_eq!(out.identifiable_net_assets, dec!(590));
+        assert_eq!(out.goodwill, dec!(410));
+        assert_eq!(out.bargain_purchase_gain, Decimal::ZERO);
+        assert_eq!(out.goodwill_pct, dec!(0.41));
+    }
+
+    #[test]
+    fn test_incremental_da_schedule() {
+        let out = run(&base_input());
+        let y1 = &out.schedule[0];
+        // Amortisation 200/10 = 20, depreciation 60/5 = 12, inventory 20
+        assert_eq!(y1.incremental_amortisation, dec!(20));
+        assert_eq!(y1.incremental_depreciation, dec!(12));
+        assert_eq!(y1.inventory_step_up_release, dec!(20));
+        assert_eq!(y1.total_incremental_charge, dec!(52));
+        assert_eq!(y1.after_tax_earnings_impact, dec!(-39));
+
+        // Inventory gone from year 2, plant fully depreciated after year 5
+        assert_eq!(out.schedule[1].total_incremental_charge, dec!(32));
+        assert_eq!(out.schedule[5].incremental_depreciation, Decimal::ZERO);
+        assert_eq!(out.schedule[5].total_incremental_charge, dec!(20));
+    }
+
+    #[test]
+    fn test_deferred_tax_unwinds_with_charge() {
+        let out = run(&base_input());
+        let y1 = &out.schedule[0];
+        assert_eq!(y1.deferred_tax_release, dec!(13));
+        assert_eq!(y1.closing_deferred_tax_liability, dec!(67));
+        let released: Decimal = out.schedule.iter().map(|y| y.deferred_tax_release).sum();
+        assert_eq!(
+            out.schedule.last().unwrap().closing_deferred_tax_liability,
+            out.deferred_tax_liability - released
+        );
+        // Stock deal: no cash tax benefit
+        assert!(out.schedule.iter().all(|y| y.cash_tax_savings.is_zero()));
+    }
+
+    #[test]
+    fn test_indefinite_lived_intangible_not_amortised() {
+        let out = run(&base_input());
+        let trade_name = out
+            .allocations
+            .iter()
+            .find(|a| a.name == "Trade name")
+            .unwrap();
+        assert_eq!(trade_name.annual_charge, Decimal::ZERO);
+        assert_eq!(trade_name.deferred_tax, dec!(10));
+    }
+
+    #[test]
+    fn test_tax_basis_step_up() {
+        let mut input = base_input();
+        input.net_assets.tax_basis_step_up = true;
+        let out = run(&input);
+        // No DTL: identifiable = 350 + 320 = 670, goodwill = 330
+        assert_eq!(out.deferred_tax_liability, Decimal::ZERO);
+        assert_eq!(out.goodwill, dec!(330));
+        // Year 1 cash tax savings = (52 + 330/15) x 25% = 18.5
+        assert_eq!(out.schedule[0].cash_tax_savings, dec!(18.5));
+        assert_eq!(out.schedule[0].deferred_tax_release, Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_full_goodwill_with_nci_and_step_acquisition() {
+        let mut input = base_input();
+        input.non_controlling_interest = Some(dec!(150));
+        input.previously_held_interest = Some(dec!(50));
+        let result = allocate_purchase_price(&input).unwrap();
+        assert_eq!(result.result.total_value_to_allocate, dec!(1200));
+        assert_eq!(result.result.goodwill, dec!(610));
+        assert_eq!(
+            result.assumptions.get("goodwill_method").unwrap(),
+            "full goodwill"
+        );
+    }
+
+    #[test]
+    fn test_bargain_purchase() {
+        let mut input = base_input();
+        input.purchase_consideration = dec!(500);
+        let result = allocate_purchase_price(&input).unwrap();
+        assert_eq!(result.result.goodwill, Decimal::ZERO);
+        assert_eq!(result.result.bargain_purchase_gain, dec!(90));
+        assert!(result
+            .warnings
+            .iter()
+            .any(|w| w.contains("bargain purchase")));
+    }
+
+    #[test]
+    fn test_missing_intangibles_warns() {
+        let mut input = base_input();
+        input
+            .net_assets
+            .step_ups
+            .retain(|s| s.category != StepUpCategory::Intangible);
+        let result = allocate_purchase_price(&input).unwrap();
+        assert!(result
+            .warnings
+            .iter()
+            .any(|w| w.contains("No identifiable intangibles")));
+    }
+
+    #[test]
+    fn test_invalid_inputs() {
+        let mut input = base_input();
+        input.purchase_consideration = Decimal::ZERO;
+        assert!(allocate_purchase_price(&input).is_err());
+
+        let mut input = base_input();
+        input.tax_rate = dec!(1.2);
+        assert!(allocate_purchase_price(&input).is_err());
+
+        let mut input = base_input();
+        input.net_assets.step_ups[0].useful_life_years = Some(0);
+        assert!(allocate_purchase_price(&input).is_err());
+
+        let mut input = base_input();
+        input.projection_years = 0;
+        assert!(allocate_purchase_price(&input).is_err());
+    }
+}
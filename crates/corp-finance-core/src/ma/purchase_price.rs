@@ -0,0 +1,647 @@
+use rust_decimal::Decimal;
+use rust_decimal::MathematicalOps;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+use std::time::Instant;
+
+use crate::error::CorpFinanceError;
+use crate::sampling::{percentile, Lcg};
+use crate::types::*;
+use crate::CorpFinanceResult;
+
+// ---------------------------------------------------------------------------
+// Types
+// ---------------------------------------------------------------------------
+
+/// Locked-box ticker: compensation to the seller for cash generated between
+/// the locked-box date and completion.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub enum Ticker {
+    #[default]
+    None,
+    /// Annual rate applied to the locked-box equity price.
+    AnnualRate { rate: Rate },
+    /// Fixed amount per calendar day.
+    DailyAmount { amount: Money },
+}
+
+/// How a de minimis threshold works on the working capital true-up.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ThresholdType {
+    /// Once exceeded, the whole deviation is adjusted.
+    #[default]
+    Tipping,
+    /// Only the deviation in excess of the threshold is adjusted.
+    Deductible,
+}
+
+/// Locked-box terms.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LockedBoxTerms {
+    #[serde(default)]
+    pub ticker: Ticker,
+    /// Expected leakage to the seller not recovered under the indemnity.
+    #[serde(default)]
+    pub expected_unrecovered_leakage: Money,
+}
+
+/// Completion-accounts terms and working capital distribution.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompletionAccountsTerms {
+    /// Working capital peg agreed in the SPA.
+    pub nwc_target: Money,
+    /// Expected working capital at completion.
+    pub expected_nwc: Money,
+    /// Standard deviation of completion working capital, typically from the
+    /// historical monthly NWC series.
+    pub nwc_volatility: Money,
+    /// De minimis threshold below which no adjustment is made.
+    #[serde(default)]
+    pub de_minimis: Money,
+    #[serde(default)]
+    pub threshold_type: ThresholdType,
+    /// Maximum absolute true-up in either direction.
+    pub cap: Option<Money>,
+    /// Preparation, review and dispute costs borne by the buyer.
+    #[serde(default)]
+    pub buyer_process_cost: Money,
+    /// Preparation, review and dispute costs borne by the seller.
+    #[serde(default)]
+    pub seller_process_cost: Money,
+    /// Number of Monte Carlo draws (default 10,000).
+    pub num_simulations: Option<u32>,
+    /// RNG seed (default 42).
+    pub seed: Option<u64>,
+}
+
+/// Inputs for a locked-box vs completion-accounts comparison.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PurchasePriceInput {
+    pub target_name: String,
+    /// Cash-free, debt-free headline price on a normalised NWC basis.
+    pub enterprise_value: Money,
+    /// Net debt on the locked-box balance sheet.
+    pub net_debt_at_locked_box: Money,
+    /// Calendar days from the locked-box date to completion.
+    pub days_to_completion: u32,
+    /// Free cash flow generated by the target, annualised.
+    pub annual_free_cash_flow: Money,
+    pub locked_box: LockedBoxTerms,
+    pub completion_accounts: CompletionAccountsTerms,
+}
+
+/// Outcome under a locked-box mechanism.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LockedBoxOutcome {
+    /// Equity price fixed at the locked-box date.
+    pub equity_price: Money,
+    pub ticker_amount: Money,
+    /// Cash generated between locked box and completion (accrues to buyer).
+    pub accrued_cash_flow: Money,
+    /// Certain proceeds to the seller, including unrecovered leakage.
+    pub seller_proceeds: Money,
+    /// Expected value to the buyer net of price paid.
+    pub buyer_net_value: Money,
+    /// Buyer bears working capital swings at completion.
+    pub buyer_value_std_dev: Money,
+}
+
+/// Working capital true-up at a given percentile (positive = paid to seller).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrueUpPercentile {
+    pub percentile: Decimal,
+    pub true_up: Money,
+}
+
+/// Outcome under a completion-accounts mechanism.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompletionAccountsOutcome {
+    /// EV less expected net debt at completion, before the NWC true-up.
+    pub base_equity_price: Money,
+    pub expected_true_up: Money,
+    pub true_up_std_dev: Money,
+    pub true_up_percentiles: Vec<TrueUpPercentile>,
+    pub prob_payment_to_seller: Rate,
+    pub prob_payment_to_buyer: Rate,
+    pub prob_no_adjustment: Rate,
+    /// Expected proceeds to the seller, net of seller process costs.
+    pub expected_seller_proceeds: Money,
+    pub seller_proceeds_std_dev: Money,
+    /// Expected value to the buyer net of price paid and process costs.
+    pub buyer_net_value: Money,
+    /// Residual working capital risk left with the buyer by thresholds/caps.
+    pub buyer_value_std_dev: Money,
+}
+
+/// Side-by-side comparison of the two mechanisms.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PurchasePriceOutput {
+    pub locked_box: LockedBoxOutcome,
+    pub completion_accounts: CompletionAccountsOutcome,
+    /// Seller proceeds under locked box less completion accounts.
+    pub seller_advantage_locked_box: Money,
+    /// Buyer net value under locked box less completion accounts.
+    pub buyer_advantage_locked_box: Money,
+    /// Ticker rate at which the seller is indifferent between mechanisms.
+    pub breakeven_ticker_rate: Option<Rate>,
+    pub seller_preference: String,
+    pub buyer_preference: String,
+}
+
+// ---------------------------------------------------------------------------
+// Core calculation
+// ---------------------------------------------------------------------------
+
+/// Compare locked-box and completion-accounts pricing for a deal.
+///
+/// With net debt at completion `ND_c = ND_lb - FCF + leakage` and working
+/// capital deviation `D = NWC_c - peg`:
+///
+/// - Locked box: seller receives `EV - ND_lb + ticker` (plus any unrecovered
+///   leakage); the buyer keeps interim cash flow and all of `D`.
+/// - Completion accounts: seller receives `EV - ND_c + T(D)`, where `T` is
+///   the true-up after de minimis and cap; the buyer keeps `D - T(D)`.
+///
+/// `D` is simulated as normal; the comparison is zero-sum apart from
+/// process costs.
+pub fn analyze_purchase_price_mechanism(
+    input: &PurchasePriceInput,
+) -> CorpFinanceResult<ComputationOutput<PurchasePriceOutput>> {
+    let start = Instant::now();
+    let mut warnings: Vec<String> = Vec::new();
+
+    validate_input(input)?;
+
+    let days = Decimal::from(input.days_to_completion);
+    let year_frac = days / dec!(365);
+
+    // --- Locked box ---
+    let lb = &input.locked_box;
+    let equity_price = input.enterprise_value - input.net_debt_at_locked_box;
+    let ticker_amount = match &lb.ticker {
+        Ticker::None => Decimal::ZERO,
+        Ticker::AnnualRate { rate } => equity_price * *rate * year_frac,
+        Ticker::DailyAmount { amount } => *amount * days,
+    };
+    let accrued_cash_flow = input.annual_free_cash_flow * year_frac;
+    let leakage = lb.expected_unrecovered_leakage;
+
+    let ca = &input.completion_accounts;
+    let expected_deviation = ca.expected_nwc - ca.nwc_target;
+
+    let lb_seller = equity_price + ticker_amount + leakage;
+    let lb_buyer = accrued_cash_flow - leakage - ticker_amount + expected_deviation;
+
+    // --- Completion accounts: simulate the true-up ---
+    let n = ca.num_simulations.unwrap_or(10_000);
+    let mut rng = Lcg::new(ca.seed.unwrap_or(42));
+    let mut true_ups: Vec<Decimal> = Vec::with_capacity(n as usize);
+    let mut retained: Vec<Decimal> = Vec::with_capacity(n as usize);
+    let (mut to_seller, mut to_buyer, mut none) = (0u32, 0u32, 0u32);
+    for _ in 0..n {
+        let deviation = expected_deviation + ca.nwc_volatility * rng.next_normal();
+        let t = true_up(deviation, ca);
+        if t > Decimal::ZERO {
+            to_seller += 1;
+        } else if t < Decimal::ZERO {
+            to_buyer += 1;
+        } else {
+            none += 1;
+        }
+        true_ups.push(t);
+        retained.push(deviation - t);
+    }
+    let nd = Decimal::from(n);
+    let (expected_true_up, true_up_std_dev) = mean_std(&true_ups);
+    let (expected_retained, retained_std_dev) = mean_std(&retained);
+    true_ups.sort();
+    let true_up_percentiles = [dec!(0.05), dec!(0.25), dec!(0.50), dec!(0.75), dec!(0.95)]
+        .iter()
+        .map(|&q| TrueUpPercentile {
+            percentile: q,
+            true_up: percentile(&true_ups, q),
+        })
+        .collect();
+
+    let net_debt_at_completion = input.net_debt_at_locked_box - accrued_cash_flow;
+    let base_equity_price = input.enterprise_value - net_debt_at_completion;
+    let ca_seller = base_equity_price + expected_true_up - ca.seller_process_cost;
+    let ca_buyer = expected_retained - ca.buyer_process_cost;
+
+    let completion_accounts = CompletionAccountsOutcome {
+        base_equity_price,
+        expected_true_up,
+        true_up_std_dev,
+        true_up_percentiles,
+        prob_payment_to_seller: Decimal::from(to_seller) / nd,
+        prob_payment_to_buyer: Decimal::from(to_buyer) / nd,
+        prob_no_adjustment: Decimal::from(none) / nd,
+        expected_seller_proceeds: ca_seller,
+        seller_proceeds_std_dev: true_up_std_dev,
+        buyer_net_value: ca_buyer,
+        buyer_value_std_dev: retained_std_dev,
+    };
+
+    let locked_box = LockedBoxOutcome {
+        equity_price,
+        ticker_amount,
+        accrued_cash_flow,
+        seller_proceeds: lb_seller,
+        buyer_net_value: lb_buyer,
+        buyer_value_std_dev: ca.nwc_volatility,
+    };
+
+    // --- Comparison ---
+    let seller_advantage_locked_box = lb_seller - ca_seller;
+    let buyer_advantage_locked_box = lb_buyer - ca_buyer;
+
+    // Ticker rate r such that equity_price * r * t = ca_seller - equity_price - leakage
+    let breakeven_ticker_rate = if equity_price > Decimal::ZERO && year_frac > Decimal::ZERO {
+        Some((ca_seller - equity_price - leakage) / (equity_price * year_frac))
+    } else {
+        None
+    };
+
+    let preference = |advantage: Decimal| {
+        if advantage > Decimal::ZERO {
+            "Locked box".to_string()
+        } else if advantage < Decimal::ZERO {
+            "Completion accounts".to_string()
+        } else {
+            "Indifferent".to_string()
+        }
+    };
+    let seller_preference = preference(seller_advantage_locked_box);
+    let buyer_preference = preference(buyer_advantage_locked_box);
+
+    if input.days_to_completion > 180 {
+        warnings.push(format!(
+            "Locked box held for {} days; buyer exposure to leakage and trading risk is extended",
+            input.days_to_completion
+        ));
+    }
+    if equity_price > Decimal::ZERO && ca.nwc_volatility > equity_price * dec!(0.05) {
+        warnings.push(
+            "Working capital volatility exceeds 5% of equity value; locked box shifts material risk to the buyer"
+                .into(),
+        );
+    }
+    if matches!(lb.ticker, Ticker::None) && accrued_cash_flow > Decimal::ZERO {
+        warnings.push("No ticker: interim cash generation accrues entirely to the buyer".into());
+    }
+
+    let output = PurchasePriceOutput {
+        locked_box,
+        completion_accounts,
+        seller_advantage_locked_box,
+        buyer_advantage_locked_box,
+        breakeven_ticker_rate,
+        seller_preference,
+        buyer_preference,
+    };
+
+    let elapsed = start.elapsed().as_micros() as u64;
+    Ok(with_metadata(
+        "Purchase Price Mechanism: Locked Box vs Completion Accounts (Monte Carlo NWC true-up)",
+        &serde_json::json!({
+            "target": input.target_name,
+            "days_to_completion": input.days_to_completion,
+            "num_simulations": n,
+            "seed": ca.seed.unwrap_or(42),
+            "threshold_type": ca.threshold_type,
+        }),
+        warnings,
+        elapsed,
+        output,
+    ))
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Working capital true-up after de minimis and cap (positive = to seller).
+fn true_up(deviation: Decimal, terms: &CompletionAccountsTerms) -> Decimal {
+    let abs = deviation.abs();
+    let adjusted = if abs <= terms.de_minimis {
+        Decimal::ZERO
+    } else {
+        match terms.threshold_type {
+            ThresholdType::Tipping => abs,
+            ThresholdType::Deductible => abs - terms.de_minimis,
+        }
+    };
+    let capped = match terms.cap {
+        Some(cap) => adjusted.min(cap),
+        None => adjusted,
+    };
+    if deviation < Decimal::ZERO {
+        -capped
+    } else {
+        capped
+    }
+}
+
+fn mean_std(values: &[Decimal]) -> (Decimal, Decimal) {
+    let n = Decimal::from(values.len() as u64);
+    let mean = values.iter().copied().sum::<Decimal>() / n;
+    let var = values
+        .iter()
+        .map(|v| (*v - mean) * (*v - mean))
+        .sum::<Decimal>()
+        / n;
+    (mean, var.sqrt().unwrap_or(Decimal::ZERO))
+}
+
+fn validate_input(input: &PurchasePriceInput) -> CorpFinanceResult<()> {
+    if input.enterprise_value <= Decimal::ZERO {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "enterprise_value".into(),
+            reason: "Enterprise value must be positive".into(),
+        });
+    }
+    let ca = &input.completion_accounts;
+    if ca.nwc_volatility < Decimal::ZERO {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "completion_accounts.nwc_volatility".into(),
+            reason: "Volatility cannot be negative".into(),
+        });
+    }
+    if ca.de_minimis < Decimal::ZERO {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "completion_accounts.de_minimis".into(),
+            reason: "Threshold cannot be negative".into(),
+        });
+    }
+    if ca.cap.is_some_and(|c| c < Decimal::ZERO) {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "completion_accounts.cap".into(),
+            reason: "Cap cannot be negative".into(),
+        });
+    }
+    if ca.num_simulations == Some(0) {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "completion_accounts.num_simulations".into(),
+            reason: "At least one simulation is required".into(),
+        });
+    }
+    crate::limits::check_simulation_paths(
+        "completion_accounts.num_simulations",
+        ca.num_simulations.unwrap_or(10_000).into(),
+    )?;
+    if input.locked_box.expected_unrecovered_leakage < Decimal::ZERO {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "locked_box.expected_unrecovered_leakage".into(),
+            reason: "Leakage cannot be negative".into(),
+        });
+    }
+    match input.locked_box.ticker {
+        Ticker::AnnualRate { rate } if rate < Decimal::ZERO => {
+            Err(CorpFinanceError::InvalidInput {
+                field: "locked_box.ticker".into(),
+                reason: "Ticker rate cannot be negative".into(),
+            })
+        }
+        Ticker::DailyAmount { amount } if amount < Decimal::ZERO => {
+            Err(CorpFinanceError::InvalidInput {
+                field: "locked_box.ticker".into(),
+                reason: "Ticker amount cannot be negative".into(),
+            })
+        }
+        _ => Ok(()),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn approx_eq(a: Decimal, b: Decimal, eps: Decimal) -> bool {
+        (a - b).abs() < eps
+    }
+
+    fn base_input() -> PurchasePriceInput {
+        PurchasePriceInput {
+            target_name: "Target Ltd".into(),
+            enterprise_value: dec!(500),
+            net_debt_at_locked_box: dec!(100),
+            days_to_completion: 146,
+            annual_free_cash_flow: dec!(50),
+            locked_box: LockedBoxTerms {
+                ticker: Ticker::None,
+                expected_unrecovered_leakage: Decimal::ZERO,
+            },
+            completion_accounts: CompletionAccountsTerms {
+                nwc_target: dec!(60),
+                expected_nwc: dec!(60),
+                nwc_volatility: dec!(5),
+                de_minimis: Decimal::ZERO,
+                threshold_type: ThresholdType::Tipping,
+                cap: None,
+                buyer_process_cost: Decimal::ZERO,
+                seller_process_cost: Decimal::ZERO,
+                num_simulations: Some(2_000),
+                seed: None,
+            },
+        }
+    }
+
+    #[test]
+    fn test_locked_box_no_ticker() {
+        let out = analyze_purchase_price_mechanism(&base_input()).unwrap();
+        let lb = &out.result.locked_box;
+        assert_eq!(lb.equity_price, dec!(400));
+        // 50 x 146 / 365 = 20
+        assert_eq!(lb.accrued_cash_flow, dec!(20));
+        assert_eq!(lb.seller_proceeds, dec!(400));
+        assert_eq!(lb.buyer_net_value, dec!(20));
+    }
+
+    #[test]
+    fn test_ticker_annual_rate() {
+        let mut input = base_input();
+        input.locked_box.ticker = Ticker::AnnualRate { rate: dec!(0.05) };
+        let out = analyze_purchase_price_mechanism(&input).unwrap();
+        // 400 x 5% x 0.4 = 8
+        assert_eq!(out.result.locked_box.ticker_amount, dec!(8));
+        assert_eq!(out.result.locked_box.seller_proceeds, dec!(408));
+    }
+
+    #[test]
+    fn test_ticker_daily_amount() {
+        let mut input = base_input();
+        input.locked_box.ticker = Ticker::DailyAmount { amount: dec!(0.1) };
+        let out = analyze_purchase_price_mechanism(&input).unwrap();
+        assert_eq!(out.result.locked_box.ticker_amount, dec!(14.6));
+    }
+
+    #[test]
+    fn test_completion_accounts_base_price() {
+        let out = analyze_purchase_price_mechanism(&base_input()).unwrap();
+        let ca = &out.result.completion_accounts;
+        // Net debt falls by the 20 of interim cash flow
+        assert_eq!(ca.base_equity_price, dec!(420));
+    }
+
+    #[test]
+    fn test_unbiased_true_up_is_centred() {
+        let out = analyze_purchase_price_mechanism(&base_input()).unwrap();
+        let ca = &out.result.completion_accounts;
+        assert!(approx_eq(ca.expected_true_up, Decimal::ZERO, dec!(0.5)));
+        assert!(approx_eq(ca.true_up_std_dev, dec!(5), dec!(0.5)));
+        assert!(approx_eq(ca.prob_payment_to_seller, dec!(0.5), dec!(0.05)));
+        // Without thresholds the buyer retains no NWC risk
+        assert_eq!(ca.buyer_value_std_dev, Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_zero_sum_without_costs() {
+        let mut input = base_input();
+        input.locked_box.ticker = Ticker::AnnualRate { rate: dec!(0.06) };
+        input.completion_accounts.de_minimis = dec!(3);
+        input.completion_accounts.cap = Some(dec!(8));
+        let out = analyze_purchase_price_mechanism(&input).unwrap();
+        let r = &out.result;
+        assert!(approx_eq(
+            r.seller_advantage_locked_box + r.buyer_advantage_locked_box,
+            Decimal::ZERO,
+            dec!(0.5)
+        ));
+    }
+
+    #[test]
+    fn test_seller_prefers_completion_accounts_without_ticker() {
+        let out = analyze_purchase_price_mechanism(&base_input()).unwrap();
+        let r = &out.result;
+        assert!(r.seller_advantage_locked_box < Decimal::ZERO);
+        assert_eq!(r.seller_preference, "Completion accounts");
+        assert_eq!(r.buyer_preference, "Locked box");
+    }
+
+    #[test]
+    fn test_breakeven_ticker_makes_seller_indifferent() {
+        let mut input = base_input();
+        input.completion_accounts.nwc_volatility = Decimal::ZERO;
+        let out = analyze_purchase_price_mechanism(&input).unwrap();
+        let rate = out.result.breakeven_ticker_rate.unwrap();
+        // 20 / (400 x 0.4) = 12.5%
+        assert_eq!(rate, dec!(0.125));
+
+        input.locked_box.ticker = Ticker::AnnualRate { rate };
+        let out = analyze_purchase_price_mechanism(&input).unwrap();
+        assert_eq!(out.result.seller_advantage_locked_box, Decimal::ZERO);
+        assert_eq!(out.result.seller_preference, "Indifferent");
+    }
+
+    #[test]
+    fn test_true_up_tipping_vs_deductible() {
+        let mut terms = base_input().completion_accounts;
+        terms.de_minimis = dec!(2);
+        assert_eq!(true_up(dec!(1.5), &terms), Decimal::ZERO);
+        assert_eq!(true_up(dec!(3), &terms), dec!(3));
+        assert_eq!(true_up(dec!(-3), &terms), dec!(-3));
+        terms.threshold_type = ThresholdType::Deductible;
+        assert_eq!(true_up(dec!(3), &terms), dec!(1));
+        assert_eq!(true_up(dec!(-3), &terms), dec!(-1));
+    }
+
+    #[test]
+    fn test_true_up_cap() {
+        let mut terms = base_input().completion_accounts;
+        terms.cap = Some(dec!(4));
+        assert_eq!(true_up(dec!(10), &terms), dec!(4));
+        assert_eq!(true_up(dec!(-10), &terms), dec!(-4));
+        assert_eq!(true_up(dec!(2), &terms), dec!(2));
+    }
+
+    #[test]
+    fn test_de_minimis_leaves_risk_with_buyer() {
+        let mut input = base_input();
+        input.completion_accounts.de_minimis = dec!(5);
+        let out = analyze_purchase_price_mechanism(&input).unwrap();
+        let ca = &out.result.completion_accounts;
+        assert!(ca.prob_no_adjustment > dec!(0.5));
+        assert!(ca.buyer_value_std_dev > Decimal::ZERO);
+        assert!(ca.true_up_std_dev < dec!(5));
+    }
+
+    #[test]
+    fn test_peg_below_expected_nwc_favours_seller() {
+        let mut input = base_input();
+        input.completion_accounts.nwc_target = dec!(55);
+        let out = analyze_purchase_price_mechanism(&input).unwrap();
+        let ca = &out.result.completion_accounts;
+        assert!(approx_eq(ca.expected_true_up, dec!(5), dec!(0.5)));
+        assert!(ca.prob_payment_to_seller > dec!(0.8));
+        // Under locked box the buyer keeps the excess working capital
+        assert_eq!(out.result.locked_box.buyer_net_value, dec!(25));
+    }
+
+    #[test]
+    fn test_percentiles_ordered() {
+        let out = analyze_purchase_price_mechanism(&base_input()).unwrap();
+        let p = &out.result.completion_accounts.true_up_percentiles;
+        assert_eq!(p.len(), 5);
+        assert!(p.windows(2).all(|w| w[0].true_up <= w[1].true_up));
+    }
+
+    #[test]
+    fn test_leakage_and_process_costs() {
+        let mut input = base_input();
+        input.locked_box.expected_unrecovered_leakage = dec!(2);
+        input.completion_accounts.seller_process_cost = dec!(1);
+        input.completion_accounts.buyer_process_cost = dec!(1.5);
+        input.completion_accounts.nwc_volatility = Decimal::ZERO;
+        let out = analyze_purchase_price_mechanism(&input).unwrap();
+        let r = &out.result;
+        assert_eq!(r.locked_box.seller_proceeds, dec!(402));
+        assert_eq!(r.locked_box.buyer_net_value, dec!(18));
+        assert_eq!(r.completion_accounts.expected_seller_proceeds, dec!(419));
+        assert_eq!(r.completion_accounts.buyer_net_value, dec!(-1.5));
+    }
+
+    #[test]
+    fn test_deterministic_with_seed() {
+        let a = analyze_purchase_price_mechanism(&base_input()).unwrap();
+        let b = analyze_purchase_price_mechanism(&base_input()).unwrap();
+        assert_eq!(
+            a.result.completion_accounts.expected_true_up,
+            b.result.completion_accounts.expected_true_up
+        );
+    }
+
+    #[test]
+    fn test_warnings() {
+        let mut input = base_input();
+        input.days_to_completion = 200;
+        input.completion_accounts.nwc_volatility = dec!(30);
+        let out = analyze_purchase_price_mechanism(&input).unwrap();
+        assert!(out.warnings.iter().any(|w| w.contains("200 days")));
+        assert!(out.warnings.iter().any(|w| w.contains("volatility")));
+        assert!(out.warnings.iter().any(|w| w.contains("No ticker")));
+    }
+
+    #[test]
+    fn test_invalid_inputs() {
+        let mut input = base_input();
+        input.enterprise_value = Decimal::ZERO;
+        assert!(analyze_purchase_price_mechanism(&input).is_err());
+
+        let mut input = base_input();
+        input.completion_accounts.nwc_volatility = dec!(-1);
+        assert!(analyze_purchase_price_mechanism(&input).is_err());
+
+        let mut input = base_input();
+        input.locked_box.ticker = Ticker::AnnualRate { rate: dec!(-0.01) };
+        assert!(analyze_purchase_price_mechanism(&input).is_err());
+
+        let mut input = base_input();
+        input.completion_accounts.num_simulations = Some(0);
+        assert!(analyze_purchase_price_mechanism(&input).is_err());
+    }
+}
@@ -0,0 +1,951 @@
+use chrono::{Datelike, NaiveDate};
+use rust_decimal::prelude::MathematicalOps;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::{BTreeMap, BTreeSet};
+use std::str::FromStr;
+
+use crate::{CorpFinanceError, CorpFinanceResult};
+
+/// Input field holding the options for resolving market data references.
+pub const OPTIONS_FIELD: &str = "$market_data";
+/// Key marking an object as a reference to a market data series.
+pub const SERIES_REF: &str = "$series";
+/// Key marking an object as a reference to a date's position on the
+/// aligned axis.
+pub const DATE_INDEX_REF: &str = "$date_index";
+
+// ---------------------------------------------------------------------------
+// Types
+// ---------------------------------------------------------------------------
+
+/// One dated value of a series.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Observation {
+    pub date: NaiveDate,
+    pub value: Decimal,
+}
+
+/// Dated observations for one identifier (a price, index level, curve
+/// tenor such as `UST_10Y`, or factor return), in date order.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TimeSeries {
+    pub id: String,
+    pub observations: Vec<Observation>,
+}
+
+/// A set of time series keyed by identifier.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MarketData {
+    pub series: Vec<TimeSeries>,
+}
+
+/// Sampling frequency for [`TimeSeries::resample`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Frequency {
+    /// One observation per calendar day (no change for daily data)
+    #[default]
+    Daily,
+    /// ISO weeks
+    Weekly,
+    Monthly,
+    Quarterly,
+    Annual,
+}
+
+/// How the observations falling in one period are combined.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Aggregation {
+    /// Last observation of the period (prices, levels, yields)
+    #[default]
+    Last,
+    First,
+    Mean,
+    /// Sum, for log returns and flows
+    Sum,
+    /// Product of (1 + r) less one, for simple returns
+    Compound,
+}
+
+/// How series with different observation dates are put on one axis.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FillMethod {
+    /// Keep only dates on which every series has an observation
+    #[default]
+    Intersection,
+    /// Use every date from the first on which all series have started,
+    /// carrying the last observation forward over gaps
+    ForwardFill,
+}
+
+/// Values taken from a series.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Transform {
+    /// The observations themselves
+    #[default]
+    Levels,
+    /// v(t) / v(t-1) - 1
+    SimpleReturns,
+    /// ln(v(t) / v(t-1))
+    LogReturns,
+    /// v(t) - v(t-1), for yields and spreads
+    Changes,
+}
+
+/// Series on a common date axis; `values[j][i]` is series `ids[j]` on
+/// `dates[i]`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AlignedSeries {
+    pub dates: Vec<NaiveDate>,
+    pub ids: Vec<String>,
+    pub values: Vec<Vec<Decimal>>,
+}
+
+/// Options for [`resolve_references`], read from the `$market_data` field
+/// of an input document.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResolveOptions {
+    /// Resample every referenced series to this frequency first
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub frequency: Option<Frequency>,
+    #[serde(default)]
+    pub aggregation: Aggregation,
+    #[serde(default)]
+    pub fill: FillMethod,
+    /// First date to use (inclusive)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start: Option<NaiveDate>,
+    /// Last date to use (inclusive)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end: Option<NaiveDate>,
+    /// Transform for references that do not name one with `as`
+    #[serde(default)]
+    pub transform: Transform,
+}
+
+// ---------------------------------------------------------------------------
+// Series
+// ---------------------------------------------------------------------------
+
+impl TimeSeries {
+    /// Build a series, sorting the observations by date. Two observations on
+    /// the same date are an error.
+    pub fn new(
+        id: impl Into<String>,
+        mut observations: Vec<Observation>,
+    ) -> CorpFinanceResult<Self> {
+        let id = id.into();
+        observations.sort_by_key(|o| o.date);
+        if let Some(pair) = observations.windows(2).find(|w| w[0].date == w[1].date) {
+            return Err(CorpFinanceError::InvalidInput {
+                field: id,
+                reason: format!("two observations dated {}", pair[0].date),
+            });
+        }
+        Ok(TimeSeries { id, observations })
+    }
+
+    pub fn dates(&self) -> Vec<NaiveDate> {
+        self.observations.iter().map(|o| o.date).collect()
+    }
+
+    pub fn values(&self) -> Vec<Decimal> {
+        self.observations.iter().map(|o| o.value).collect()
+    }
+
+    /// Observations between `start` and `end`, inclusive.
+    pub fn window(&self, start: Option<NaiveDate>, end: Option<NaiveDate>) -> TimeSeries {
+        TimeSeries {
+            id: self.id.clone(),
+            observations: self
+                .observations
+                .iter()
+                .filter(|o| start.is_none_or(|s| o.date >= s) && end.is_none_or(|e| o.date <= e))
+                .copied()
+                .collect(),
+        }
+    }
+
+    /// Combine the observations of each period into one, dated at the last
+    /// observation of the period (the actual month-end trading day rather
+    /// than the calendar month-end).
+    pub fn resample(&self, frequency: Frequency, aggregation: Aggregation) -> TimeSeries {
+        let mut observations: Vec<Observation> = Vec::new();
+        let mut period: Vec<Decimal> = Vec::new();
+        for (i, obs) in self.observations.iter().enumerate() {
+            period.push(obs.value);
+            let closes = self.observations.get(i + 1).is_none_or(|next| {
+                period_key(next.date, frequency) != period_key(obs.date, frequency)
+            });
+            if closes {
+                observations.push(Observation {
+                    date: obs.date,
+                    value: aggregate(&period, aggregation),
+                });
+                period.clear();
+            }
+        }
+        TimeSeries {
+            id: self.id.clone(),
+            observations,
+        }
+    }
+
+    /// Period-on-period transform, dated at the end of each period. Levels
+    /// are returned unchanged.
+    pub fn transform(&self, transform: Transform) -> CorpFinanceResult<TimeSeries> {
+        let observations = match transform {
+            Transform::Levels => self.observations.clone(),
+            _ => self
+                .observations
+                .windows(2)
+                .map(|w| {
+                    Ok(Observation {
+                        date: w[1].date,
+                        value: period_change(&self.id, w[0], w[1], transform)?,
+                    })
+                })
+                .collect::<CorpFinanceResult<_>>()?,
+        };
+        Ok(TimeSeries {
+            id: self.id.clone(),
+            observations,
+        })
+    }
+}
+
+fn period_key(date: NaiveDate, frequency: Frequency) -> (i32, u32) {
+    match frequency {
+        Frequency::Daily => (date.year(), date.ordinal()),
+        Frequency::Weekly => (date.iso_week().year(), date.iso_week().week()),
+        Frequency::Monthly => (date.year(), date.month()),
+        Frequency::Quarterly => (date.year(), date.month0() / 3),
+        Frequency::Annual => (date.year(), 0),
+    }
+}
+
+fn aggregate(values: &[Decimal], aggregation: Aggregation) -> Decimal {
+    match aggregation {
+        Aggregation::Last => values[values.len() - 1],
+        Aggregation::First => values[0],
+        Aggregation::Mean => values.iter().sum::<Decimal>() / Decimal::from(values.len()),
+        Aggregation::Sum => values.iter().sum(),
+        Aggregation::Compound => {
+            values
+                .iter()
+                .fold(Decimal::ONE, |acc, r| acc * (Decimal::ONE + r))
+                - Decimal::ONE
+        }
+    }
+}
+
+fn period_change(
+    id: &str,
+    prev: Observation,
+    curr: Observation,
+    transform: Transform,
+) -> CorpFinanceResult<Decimal> {
+    if transform == Transform::Changes {
+        return Ok(curr.value - prev.value);
+    }
+    if prev.value.is_zero() {
+        return Err(CorpFinanceError::DivisionByZero {
+            context: format!("{id} return from zero value on {}", prev.date),
+        });
+    }
+    let ratio = curr.value / prev.value;
+    match transform {
+        Transform::LogReturns => {
+            if ratio <= Decimal::ZERO {
+                return Err(CorpFinanceError::InvalidInput {
+                    field: id.to_string(),
+                    reason: format!("log return needs positive values ({})", curr.date),
+                });
+            }
+            Ok(ratio.ln())
+        }
+        _ => Ok(ratio - Decimal::ONE),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Collection
+// ---------------------------------------------------------------------------
+
+impl MarketData {
+    pub fn get(&self, id: &str) -> Option<&TimeSeries> {
+        self.series.iter().find(|s| s.id == id)
+    }
+
+    /// Add a series, replacing any existing series with the same identifier.
+    pub fn insert(&mut self, series: TimeSeries) {
+        match self.series.iter_mut().find(|s| s.id == series.id) {
+            Some(existing) => *existing = series,
+            None => self.series.push(series),
+        }
+    }
+
+    /// Add every series of `other`; later sources win on identifier clashes.
+    pub fn merge(&mut self, other: MarketData) {
+        for series in other.series {
+            self.insert(series);
+        }
+    }
+
+    pub fn resample(&self, frequency: Frequency, aggregation: Aggregation) -> MarketData {
+        MarketData {
+            series: self
+                .series
+                .iter()
+                .map(|s| s.resample(frequency, aggregation))
+                .collect(),
+        }
+    }
+
+    /// Put the named series on one date axis.
+    pub fn align(&self, ids: &[&str], fill: FillMethod) -> CorpFinanceResult<AlignedSeries> {
+        let series = ids
+            .iter()
+            .map(|id| {
+                self.get(id).ok_or_else(|| CorpFinanceError::InvalidInput {
+                    field: SERIES_REF.into(),
+                    reason: format!("no market data series '{id}'"),
+                })
+            })
+            .collect::<CorpFinanceResult<Vec<_>>>()?;
+        if let Some(empty) = series.iter().find(|s| s.observations.is_empty()) {
+            return Err(CorpFinanceError::InsufficientData(format!(
+                "market data series '{}' has no observations in range",
+                empty.id
+            )));
+        }
+
+        let dates: Vec<NaiveDate> = match fill {
+            FillMethod::Intersection => {
+                let mut common: BTreeSet<NaiveDate> = series[0].dates().into_iter().collect();
+                for s in &series[1..] {
+                    let dates: BTreeSet<NaiveDate> = s.dates().into_iter().collect();
+                    common.retain(|d| dates.contains(d));
+                }
+                common.into_iter().collect()
+            }
+            FillMethod::ForwardFill => {
+                let first = series
+                    .iter()
+                    .map(|s| s.observations[0].date)
+                    .max()
+                    .unwrap_or_default();
+                series
+                    .iter()
+                    .flat_map(|s| s.dates())
+                    .filter(|d| *d >= first)
+                    .collect::<BTreeSet<_>>()
+                    .into_iter()
+                    .collect()
+            }
+        };
+
+        let values = series
+            .iter()
+            .map(|s| {
+                let mut column = Vec::with_capacity(dates.len());
+                let mut idx = 0;
+                let mut last = None;
+                for date in &dates {
+                    while idx < s.observations.len() && s.observations[idx].date <= *date {
+                        last = Some(s.observations[idx].value);
+                        idx += 1;
+                    }
+                    // Both methods guarantee an observation on or before each date
+                    column.push(last.unwrap_or_default());
+                }
+                column
+            })
+            .collect();
+
+        Ok(AlignedSeries {
+            dates,
+            ids: ids.iter().map(|id| id.to_string()).collect(),
+            values,
+        })
+    }
+
+    // -----------------------------------------------------------------------
+    // Adapters
+    // -----------------------------------------------------------------------
+
+    /// Read CSV text in wide form (`date,AAPL,MSFT` with one column per
+    /// series; blank cells are missing) or long form (`date,id,value` with
+    /// one row per observation). The delimiter may be `,`, `;` or a tab;
+    /// with `;` a decimal comma is accepted.
+    pub fn from_csv(text: &str) -> CorpFinanceResult<MarketData> {
+        let mut lines = text
+            .lines()
+            .enumerate()
+            .filter(|(_, l)| !l.trim().is_empty());
+        let Some((_, header_line)) = lines.next() else {
+            return Err(CorpFinanceError::InsufficientData(
+                "market data CSV is empty".into(),
+            ));
+        };
+        let delimiter = [',', ';', '\t']
+            .into_iter()
+            .max_by_key(|d| header_line.matches(*d).count())
+            .unwrap_or(',');
+        let header: Vec<String> = split_csv(header_line, delimiter)
+            .into_iter()
+            .map(|h| h.trim().to_string())
+            .collect();
+        let column = |names: &[&str]| {
+            header
+                .iter()
+                .position(|h| names.contains(&h.to_lowercase().as_str()))
+        };
+        let date_col = column(&["date", "as_of", "timestamp"]).unwrap_or(0);
+        let long = match (
+            column(&["id", "identifier", "symbol", "ticker", "series"]),
+            column(&["value", "price", "close", "level", "rate"]),
+        ) {
+            (Some(id), Some(value)) if header.len() == 3 => Some((id, value)),
+            _ => None,
+        };
+
+        let mut observations: BTreeMap<String, Vec<Observation>> = BTreeMap::new();
+        for (line_no, line) in lines {
+            let cells = split_csv(line, delimiter);
+            let cell = |i: usize| cells.get(i).map(|c| c.trim()).unwrap_or("");
+            let field = |col: usize| format!("line {} column '{}'", line_no + 1, header[col]);
+            let date =
+                parse_date(cell(date_col)).ok_or_else(|| CorpFinanceError::InvalidInput {
+                    field: field(date_col),
+                    reason: format!("'{}' is not a date", cell(date_col)),
+                })?;
+            let mut push = |id: &str, col: usize| -> CorpFinanceResult<()> {
+                let text = cell(col);
+                if text.is_empty() {
+                    return Ok(());
+                }
+                let value = parse_number(text, delimiter).ok_or_else(|| {
+                    CorpFinanceError::InvalidInput {
+                        field: field(col),
+                        reason: format!("'{text}' is not a number"),
+                    }
+                })?;
+                observations
+                    .entry(id.to_string())
+                    .or_default()
+                    .push(Observation { date, value });
+                Ok(())
+            };
+            match long {
+                Some((id_col, value_col)) => {
+                    let id = cell(id_col).to_string();
+                    push(&id, value_col)?;
+                }
+                None => {
+                    for (col, id) in header.iter().enumerate() {
+                        if col != date_col {
+                            push(id, col)?;
+                        }
+                    }
+                }
+            }
+        }
+        build(observations)
+    }
+
+    /// Read JSON in any of these shapes:
+    /// - `{"series": [{"id": .., "observations": [{"date": .., "value": ..}]}]}`
+    /// - `{"AAPL": {"2024-01-02": 185.6, ..}, ..}` or
+    ///   `{"AAPL": [{"date": .., "value": ..}], ..}`
+    /// - records `[{"date": .., "id": .., "value": ..}]`, or wide records
+    ///   `[{"date": .., "AAPL": 185.6, "MSFT": 370.9}]`
+    pub fn from_json(value: &Value) -> CorpFinanceResult<MarketData> {
+        let mut observations: BTreeMap<String, Vec<Observation>> = BTreeMap::new();
+        match value {
+            Value::Object(map) if map.contains_key("series") => {
+                let data: MarketData = serde_json::from_value(value.clone())
+                    .map_err(|e| CorpFinanceError::SerializationError(e.to_string()))?;
+                for s in data.series {
+                    observations.entry(s.id).or_default().extend(s.observations);
+                }
+            }
+            Value::Object(map) => {
+                for (id, points) in map {
+                    let entry = observations.entry(id.clone()).or_default();
+                    match points {
+                        Value::Object(by_date) => {
+                            for (date, v) in by_date {
+                                entry.push(json_observation(id, &Value::String(date.clone()), v)?);
+                            }
+                        }
+                        Value::Array(items) => {
+                            for item in items {
+                                let date = item.get("date").unwrap_or(&Value::Null);
+                                let v = item.get("value").unwrap_or(&Value::Null);
+                                entry.push(json_observation(id, date, v)?);
+                            }
+                        }
+                        _ => {
+                            return Err(CorpFinanceError::InvalidInput {
+                                field: id.clone(),
+                                reason: "expected a date -> value map or a list of observations"
+                                    .into(),
+                            })
+                        }
+                    }
+                }
+            }
+            Value::Array(records) => {
+                for (i, record) in records.iter().enumerate() {
+                    let Some(record) = record.as_object() else {
+                        return Err(CorpFinanceError::InvalidInput {
+                            field: format!("[{i}]"),
+                            reason: "expected an object".into(),
+                        });
+                    };
+                    let date = record.get("date").unwrap_or(&Value::Null);
+                    match record.get("id").and_then(Value::as_str) {
+                        Some(id) => {
+                            let v = record.get("value").unwrap_or(&Value::Null);
+                            let obs = json_observation(id, date, v)?;
+                            observations.entry(id.to_string()).or_default().push(obs);
+                        }
+                        None => {
+                            for (id, v) in record.iter().filter(|(k, _)| *k != "date") {
+                                if v.is_null() {
+                                    continue;
+                                }
+                                let obs = json_observation(id, date, v)?;
+                                observations.entry(id.clone()).or_default().push(obs);
+                            }
+                        }
+                    }
+                }
+            }
+            _ => {
+                return Err(CorpFinanceError::InvalidInput {
+                    field: "market_data".into(),
+                    reason: "expected a JSON object or array".into(),
+                })
+            }
+        }
+        build(observations)
+    }
+}
+
+fn build(observations: BTreeMap<String, Vec<Observation>>) -> CorpFinanceResult<MarketData> {
+    let series = observations
+        .into_iter()
+        .map(|(id, obs)| TimeSeries::new(id, obs))
+        .collect::<CorpFinanceResult<_>>()?;
+    Ok(MarketData { series })
+}
+
+fn json_observation(id: &str, date: &Value, value: &Value) -> CorpFinanceResult<Observation> {
+    let date =
+        date.as_str()
+            .and_then(parse_date)
+            .ok_or_else(|| CorpFinanceError::InvalidInput {
+                field: id.to_string(),
+                reason: format!("{date} is not a date"),
+            })?;
+    let value = match value {
+        Value::Number(n) => parse_number(&n.to_string(), ','),
+        Value::String(s) => parse_number(s, ','),
+        _ => None,
+    }
+    .ok_or_else(|| CorpFinanceError::InvalidInput {
+        field: format!("{id} {date}"),
+        reason: format!("{value} is not a number"),
+    })?;
+    Ok(Observation { date, value })
+}
+
+/// Dates as `2024-01-31`, `2024/01/31` or `20240131`; a time part after
+/// the date is ignored.
+fn parse_date(text: &str) -> Option<NaiveDate> {
+    let date = text.split(['T', ' ']).next().unwrap_or(text);
+    ["%Y-%m-%d", "%Y/%m/%d", "%Y%m%d"]
+        .iter()
+        .find_map(|format| NaiveDate::parse_from_str(date, format).ok())
+}
+
+fn parse_number(text: &str, delimiter: char) -> Option<Decimal> {
+    let text = if delimiter == ';' && !text.contains('.') {
+        text.replace(',', ".")
+    } else {
+        text.to_string()
+    };
+    Decimal::from_str(&text)
+        .or_else(|_| Decimal::from_scientific(&text))
+        .ok()
+}
+
+/// Split one CSV line, honouring double-quoted cells.
+fn split_csv(line: &str, delimiter: char) -> Vec<String> {
+    let mut cells = Vec::new();
+    let mut cell = String::new();
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                cell.push('"');
+                chars.next();
+            }
+            '"' => quoted = !quoted,
+            c if c == delimiter && !quoted => cells.push(std::mem::take(&mut cell)),
+            c => cell.push(c),
+        }
+    }
+    cells.push(cell);
+    cells
+}
+
+// ---------------------------------------------------------------------------
+// References
+// ---------------------------------------------------------------------------
+
+/// Replace market data references in an input document with values:
+/// - `{"$series": "AAPL"}` becomes the series' values, optionally
+///   transformed with `"as": "simple_returns" | "log_returns" | "changes"`
+/// - `{"$date_index": "2024-03-15"}` becomes the position of the first
+///   date on or after 2024-03-15 on the axis of the default transform,
+///   e.g. for an event study's `event_index`
+///
+/// Every referenced series is aligned on one axis first, so the arrays a
+/// pairs trade, factor regression or event study receives line up date by
+/// date. Options come from the document's `$market_data` field, which is
+/// removed. Returns the date axis of the default transform.
+pub fn resolve_references(doc: &mut Value, data: &MarketData) -> CorpFinanceResult<Vec<NaiveDate>> {
+    let options: ResolveOptions = match doc.as_object_mut().and_then(|m| m.remove(OPTIONS_FIELD)) {
+        Some(value) => {
+            serde_json::from_value(value).map_err(|e| CorpFinanceError::InvalidInput {
+                field: OPTIONS_FIELD.into(),
+                reason: e.to_string(),
+            })?
+        }
+        None => ResolveOptions::default(),
+    };
+
+    let mut ids = BTreeSet::new();
+    collect_ids(doc, &mut ids);
+    if ids.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let mut selected = MarketData::default();
+    for id in &ids {
+        let series = data.get(id).ok_or_else(|| CorpFinanceError::InvalidInput {
+            field: SERIES_REF.into(),
+            reason: format!("no market data series '{id}'"),
+        })?;
+        let series = series.window(options.start, options.end);
+        selected.insert(match options.frequency {
+            Some(frequency) => series.resample(frequency, options.aggregation),
+            None => series,
+        });
+    }
+    let ids: Vec<&str> = ids.iter().map(String::as_str).collect();
+    let aligned = selected.align(&ids, options.fill)?;
+    let axis = transformed_dates(&aligned.dates, options.transform);
+    replace(doc, "", &aligned, &options, &axis)?;
+    Ok(axis)
+}
+
+fn collect_ids(value: &Value, ids: &mut BTreeSet<String>) {
+    match value {
+        Value::Object(map) => match map.get(SERIES_REF).and_then(Value::as_str) {
+            Some(id) => {
+                ids.insert(id.to_string());
+            }
+            None => map.values().for_each(|v| collect_ids(v, ids)),
+        },
+        Value::Array(items) => items.iter().for_each(|v| collect_ids(v, ids)),
+        _ => {}
+    }
+}
+
+fn transformed_dates(dates: &[NaiveDate], transform: Transform) -> Vec<NaiveDate> {
+    match transform {
+        Transform::Levels => dates.to_vec(),
+        _ => dates.iter().skip(1).copied().collect(),
+    }
+}
+
+fn replace(
+    value: &mut Value,
+    path: &str,
+    aligned: &AlignedSeries,
+    options: &ResolveOptions,
+    axis: &[NaiveDate],
+) -> CorpFinanceResult<()> {
+    let invalid = |reason: String| CorpFinanceError::InvalidInput {
+        field: path.to_string(),
+        reason,
+    };
+    match value {
+        Value::Object(map) if map.contains_key(SERIES_REF) => {
+            let id = map[SERIES_REF].as_str().unwrap_or_default().to_string();
+            let transform = reference_transform(map, options.transform).map_err(invalid)?;
+            let column = aligned.ids.iter().position(|i| *i == id).unwrap_or(0);
+            let observations = aligned
+                .dates
+                .iter()
+                .zip(&aligned.values[column])
+                .map(|(&date, &value)| Observation { date, value })
+                .collect();
+            let series = TimeSeries { id, observations }.transform(transform)?;
+            *value = Value::Array(
+                series
+                    .values()
+                    .into_iter()
+                    .map(|v| Value::String(v.normalize().to_string()))
+                    .collect(),
+            );
+        }
+        Value::Object(map) if map.contains_key(DATE_INDEX_REF) => {
+            let text = map[DATE_INDEX_REF].as_str().unwrap_or_default();
+            let date =
+                parse_date(text).ok_or_else(|| invalid(format!("'{text}' is not a date")))?;
+            let index = axis
+                .iter()
+                .position(|d| *d >= date)
+                .ok_or_else(|| invalid(format!("{date} is after the last market data date")))?;
+            *value = Value::from(index);
+        }
+        Value::Object(map) => {
+            for (key, v) in map.iter_mut() {
+                let child = if path.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{path}.{key}")
+                };
+                replace(v, &child, aligned, options, axis)?;
+            }
+        }
+        Value::Array(items) => {
+            for (i, v) in items.iter_mut().enumerate() {
+                replace(v, &format!("{path}[{i}]"), aligned, options, axis)?;
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+fn reference_transform(map: &Map<String, Value>, default: Transform) -> Result<Transform, String> {
+    match map.get("as") {
+        None | Some(Value::Null) => Ok(default),
+        Some(value) => serde_json::from_value(value.clone())
+            .map_err(|_| format!("{value} is not levels, simple_returns, log_returns or changes")),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+    use serde_json::json;
+
+    fn date(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    fn series(id: &str, points: &[(&str, Decimal)]) -> TimeSeries {
+        let obs = points
+            .iter()
+            .map(|(d, v)| Observation {
+                date: date(d),
+                value: *v,
+            })
+            .collect();
+        TimeSeries::new(id, obs).unwrap()
+    }
+
+    #[test]
+    fn test_wide_and_long_csv_agree() {
+        let wide = "date,AAPL,MSFT\n2024-01-02,185.64,370.87\n2024-01-03,184.25,\n2024-01-04,181.91,367.94\n";
+        let long = "Date;Ticker;Close\n2024-01-04;AAPL;181,91\n2024-01-02;AAPL;185,64\n2024-01-03;AAPL;184,25\n2024-01-02;MSFT;370,87\n2024-01-04;MSFT;367,94\n";
+        let a = MarketData::from_csv(wide).unwrap();
+        let b = MarketData::from_csv(long).unwrap();
+        assert_eq!(a, b);
+        assert_eq!(
+            a.get("AAPL").unwrap().values(),
+            vec![dec!(185.64), dec!(184.25), dec!(181.91)]
+        );
+        assert_eq!(a.get("MSFT").unwrap().observations.len(), 2);
+    }
+
+    #[test]
+    fn test_csv_errors_name_line_and_column() {
+        let err = MarketData::from_csv("date,AAPL\n2024-01-02,abc\n").unwrap_err();
+        match err {
+            CorpFinanceError::InvalidInput { field, .. } => {
+                assert_eq!(field, "line 2 column 'AAPL'")
+            }
+            other => panic!("Expected InvalidInput, got {other:?}"),
+        }
+        assert!(MarketData::from_csv("date,AAPL\n2024-01-02,1\n2024-01-02,2\n").is_err());
+    }
+
+    #[test]
+    fn test_json_shapes() {
+        let expected = MarketData::from_csv("date,A,B\n2024-01-02,1,2\n2024-01-03,3,4\n").unwrap();
+        let by_date = json!({
+            "A": { "2024-01-02": 1, "2024-01-03": 3 },
+            "B": [{ "date": "2024-01-02", "value": "2" }, { "date": "2024-01-03", "value": 4 }],
+        });
+        let records = json!([
+            { "date": "2024-01-02", "A": 1, "B": 2 },
+            { "date": "2024-01-03", "id": "A", "value": 3 },
+            { "date": "2024-01-03", "id": "B", "value": 4 },
+        ]);
+        let canonical = serde_json::to_value(&expected).unwrap();
+        for doc in [by_date, records, canonical] {
+            assert_eq!(MarketData::from_json(&doc).unwrap(), expected, "{doc}");
+        }
+    }
+
+    #[test]
+    fn test_monthly_resample_uses_last_trading_day() {
+        let s = series(
+            "X",
+            &[
+                ("2024-01-30", dec!(100)),
+                ("2024-01-31", dec!(102)),
+                ("2024-02-01", dec!(103)),
+                ("2024-02-29", dec!(99)),
+                ("2024-03-28", dec!(105)),
+            ],
+        );
+        let monthly = s.resample(Frequency::Monthly, Aggregation::Last);
+        assert_eq!(
+            monthly.dates(),
+            vec![date("2024-01-31"), date("2024-02-29"), date("2024-03-28")]
+        );
+        assert_eq!(monthly.values(), vec![dec!(102), dec!(99), dec!(105)]);
+
+        let mean = s.resample(Frequency::Quarterly, Aggregation::Mean);
+        assert_eq!(mean.values(), vec![dec!(101.8)]);
+
+        let returns = series("R", &[("2024-01-02", dec!(0.1)), ("2024-01-03", dec!(0.1))]);
+        let compounded = returns.resample(Frequency::Annual, Aggregation::Compound);
+        assert_eq!(compounded.values(), vec![dec!(0.21)]);
+    }
+
+    #[test]
+    fn test_alignment_methods() {
+        let mut data = MarketData::default();
+        data.insert(series(
+            "A",
+            &[
+                ("2024-01-01", dec!(1)),
+                ("2024-01-02", dec!(2)),
+                ("2024-01-04", dec!(4)),
+            ],
+        ));
+        data.insert(series(
+            "B",
+            &[
+                ("2024-01-02", dec!(20)),
+                ("2024-01-03", dec!(30)),
+                ("2024-01-04", dec!(40)),
+            ],
+        ));
+
+        let inner = data.align(&["A", "B"], FillMethod::Intersection).unwrap();
+        assert_eq!(inner.dates, vec![date("2024-01-02"), date("2024-01-04")]);
+        assert_eq!(
+            inner.values,
+            vec![vec![dec!(2), dec!(4)], vec![dec!(20), dec!(40)]]
+        );
+
+        let filled = data.align(&["A", "B"], FillMethod::ForwardFill).unwrap();
+        assert_eq!(filled.dates.len(), 3, "starts once both series exist");
+        assert_eq!(filled.values[0], vec![dec!(2), dec!(2), dec!(4)]);
+
+        assert!(data.align(&["A", "C"], FillMethod::Intersection).is_err());
+    }
+
+    #[test]
+    fn test_transforms() {
+        let s = series(
+            "X",
+            &[
+                ("2024-01-01", dec!(100)),
+                ("2024-01-02", dec!(110)),
+                ("2024-01-03", dec!(99)),
+            ],
+        );
+        let simple = s.transform(Transform::SimpleReturns).unwrap();
+        assert_eq!(simple.dates(), vec![date("2024-01-02"), date("2024-01-03")]);
+        assert_eq!(simple.values(), vec![dec!(0.1), dec!(-0.1)]);
+        let changes = s.transform(Transform::Changes).unwrap();
+        assert_eq!(changes.values(), vec![dec!(10), dec!(-11)]);
+        let log = s.transform(Transform::LogReturns).unwrap();
+        assert!((log.values()[0] - dec!(0.0953102)).abs() < dec!(0.000001));
+
+        let zero = series("Z", &[("2024-01-01", dec!(0)), ("2024-01-02", dec!(1))]);
+        assert!(zero.transform(Transform::SimpleReturns).is_err());
+    }
+
+    #[test]
+    fn test_resolve_references_aligns_series() {
+        let data = MarketData::from_csv(
+            "date,AAA,BBB,MKT\n2024-01-01,100,50,1000\n2024-01-02,101,,1010\n2024-01-03,102,51,1020\n2024-01-04,103,52,1030\n",
+        )
+        .unwrap();
+        let mut doc = json!({
+            "$market_data": { "fill": "intersection" },
+            "asset_a_prices": { "$series": "AAA" },
+            "asset_b_prices": { "$series": "BBB" },
+            "lookback_period": 20,
+        });
+        let dates = resolve_references(&mut doc, &data).unwrap();
+        assert_eq!(dates.len(), 3);
+        assert_eq!(doc["asset_a_prices"], json!(["100", "102", "103"]));
+        assert_eq!(doc["asset_b_prices"], json!(["50", "51", "52"]));
+        assert!(doc.get("$market_data").is_none());
+
+        let mut doc = json!({
+            "$market_data": { "fill": "forward_fill", "transform": "simple_returns" },
+            "events": [{
+                "name": "AAA",
+                "event_index": { "$date_index": "2024-01-03" },
+                "security_returns": { "$series": "AAA" },
+                "market_returns": { "$series": "MKT" },
+                "levels": { "$series": "MKT", "as": "levels" },
+            }],
+        });
+        resolve_references(&mut doc, &data).unwrap();
+        let event = &doc["events"][0];
+        assert_eq!(event["event_index"], 1);
+        assert_eq!(event["market_returns"].as_array().unwrap().len(), 3);
+        assert_eq!(event["market_returns"][0], "0.01");
+        assert_eq!(event["levels"].as_array().unwrap().len(), 4);
+    }
+
+    #[test]
+    fn test_resolve_without_references_is_noop() {
+        let mut doc = json!({ "wacc": 0.09 });
+        let before = doc.clone();
+        assert!(resolve_references(&mut doc, &MarketData::default())
+            .unwrap()
+            .is_empty());
+        assert_eq!(doc, before);
+
+        let mut doc = json!({ "asset_returns": { "$series": "MISSING" } });
+        assert!(resolve_references(&mut doc, &MarketData::default()).is_err());
+    }
+}
@@ -1,11 +1,14 @@
 use rand::rngs::StdRng;
 use rand::Rng;
 use rand::SeedableRng;
+use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
-use statrs::distribution::{LogNormal, Normal, Triangular, Uniform};
+use statrs::distribution::{ContinuousCDF, LogNormal, Normal, Triangular, Uniform};
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::time::Instant;
 
 use crate::error::CorpFinanceError;
+use crate::limits;
 use crate::types::{ComputationMetadata, ComputationOutput};
 use crate::CorpFinanceResult;
 
@@ -29,6 +32,7 @@ fn with_metadata_f64<T: Serialize>(
             version: env!("CARGO_PKG_VERSION").to_string(),
             computation_time_us: elapsed_us,
             precision: "ieee754_f64".to_string(),
+            rounding: None,
         },
     }
 }
@@ -54,6 +58,29 @@ pub struct McVariable {
     pub distribution: McDistribution,
 }
 
+/// How the uniform draws behind each path are generated.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SamplingMethod {
+    /// Independent pseudo-random draws.
+    #[default]
+    PseudoRandom,
+    /// Pseudo-random draws paired with their mirror images (u, 1 - u).
+    Antithetic,
+    /// Sobol low-discrepancy sequence, randomised with digital shifts.
+    Sobol,
+}
+
+/// Variance reduction settings shared by the Monte Carlo engines.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct VarianceReduction {
+    #[serde(default)]
+    pub sampling: SamplingMethod,
+    /// Use the sampled inputs, whose means are known, as control variates
+    /// for the output mean. Applies to derived outputs such as McDcf.
+    #[serde(default)]
+    pub control_variates: bool,
+}
+
 /// Top-level input for a generic Monte Carlo simulation.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MonteCarloInput {
@@ -64,6 +91,13 @@ pub struct MonteCarloInput {
     pub seed: Option<u64>,
     /// Variables to simulate.
     pub variables: Vec<McVariable>,
+    /// Sampling scheme and control variates.
+    #[serde(default)]
+    pub variance_reduction: VarianceReduction,
+    /// Optional correlation matrix between variables, in `variables` order.
+    /// Applied through a Gaussian copula, so each marginal is preserved.
+    #[serde(default)]
+    pub correlation: Option<Vec<Vec<f64>>>,
 }
 
 fn default_num_simulations() -> u32 {
@@ -96,6 +130,8 @@ pub struct HistogramBin {
 pub struct McVariableResult {
     pub name: String,
     pub mean: f64,
+    /// Standard error of the mean under the chosen sampling scheme.
+    pub mean_std_error: f64,
     pub median: f64,
     pub std_dev: f64,
     pub min: f64,
@@ -110,6 +146,7 @@ pub struct McVariableResult {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MonteCarloOutput {
     pub num_simulations: u32,
+    pub sampling: SamplingMethod,
     pub variables: Vec<McVariableResult>,
 }
 
@@ -141,6 +178,14 @@ pub struct McDcfInput {
     pub num_simulations: u32,
     /// Optional seed for reproducibility.
     pub seed: Option<u64>,
+    /// Sampling scheme and control variates.
+    #[serde(default)]
+    pub variance_reduction: VarianceReduction,
+    /// Optional 4x4 correlation matrix between revenue growth, EBITDA
+    /// margin, WACC and terminal growth (in that order), applied through a
+    /// Gaussian copula.
+    #[serde(default)]
+    pub correlation: Option<Vec<Vec<f64>>>,
 }
 
 /// Probability that EV exceeds a given threshold.
@@ -150,13 +195,28 @@ pub struct ThresholdProbability {
     pub probability: f64,
 }
 
+/// Control variate adjustment of the mean enterprise value.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ControlVariateSummary {
+    /// Plain sample mean before adjustment.
+    pub raw_mean: f64,
+    /// Regression coefficients on revenue growth, EBITDA margin, WACC and
+    /// terminal growth.
+    pub coefficients: Vec<f64>,
+    /// Variance of raw EVs / variance of adjusted EVs.
+    pub variance_reduction_factor: f64,
+}
+
 /// Output of a Monte Carlo DCF simulation.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct McDcfOutput {
     /// Percentile summary of simulated enterprise values.
     pub enterprise_values: McPercentiles,
-    /// Mean enterprise value across all valid simulations.
+    /// Mean enterprise value across all valid simulations (control-variate
+    /// adjusted when enabled).
     pub ev_mean: f64,
+    /// Standard error of `ev_mean` under the chosen sampling scheme.
+    pub ev_mean_std_error: f64,
     /// Standard deviation of enterprise values.
     pub ev_std_dev: f64,
     /// Probability that EV exceeds selected thresholds.
@@ -165,6 +225,8 @@ pub struct McDcfOutput {
     pub implied_ev_range: (f64, f64),
     /// Number of valid simulations actually used.
     pub simulation_count: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub control_variate: Option<ControlVariateSummary>,
 }
 
 // ---------------------------------------------------------------------------
@@ -206,10 +268,428 @@ fn sample(rng: &mut StdRng, dist: &McDistribution) -> CorpFinanceResult<f64> {
     }
 }
 
+/// Map a uniform draw to the distribution by inverting its CDF.
+fn sample_from_uniform(dist: &McDistribution, u: f64) -> CorpFinanceResult<f64> {
+    let u = u.clamp(1e-12, 1.0 - 1e-12);
+    match dist {
+        McDistribution::Normal { mean, std_dev } => {
+            let n = Normal::new(*mean, *std_dev).map_err(|e| CorpFinanceError::InvalidInput {
+                field: "distribution".into(),
+                reason: format!("Invalid Normal parameters: {e}"),
+            })?;
+            Ok(n.inverse_cdf(u))
+        }
+        McDistribution::LogNormal { mu, sigma } => {
+            let n = Normal::new(*mu, *sigma).map_err(|e| CorpFinanceError::InvalidInput {
+                field: "distribution".into(),
+                reason: format!("Invalid LogNormal parameters: {e}"),
+            })?;
+            Ok(n.inverse_cdf(u).exp())
+        }
+        McDistribution::Triangular { min, mode, max } => {
+            Triangular::new(*min, *max, *mode).map_err(|e| CorpFinanceError::InvalidInput {
+                field: "distribution".into(),
+                reason: format!("Invalid Triangular parameters: {e}"),
+            })?;
+            let range = max - min;
+            if u < (mode - min) / range {
+                Ok(min + (u * range * (mode - min)).sqrt())
+            } else {
+                Ok(max - ((1.0 - u) * range * (max - mode)).sqrt())
+            }
+        }
+        McDistribution::Uniform { min, max } => {
+            Uniform::new(*min, *max).map_err(|e| CorpFinanceError::InvalidInput {
+                field: "distribution".into(),
+                reason: format!("Invalid Uniform parameters: {e}"),
+            })?;
+            Ok(min + u * (max - min))
+        }
+    }
+}
+
+/// Analytical mean of a distribution, used for control variates.
+fn distribution_mean(dist: &McDistribution) -> f64 {
+    match dist {
+        McDistribution::Normal { mean, .. } => *mean,
+        McDistribution::LogNormal { mu, sigma } => (mu + 0.5 * sigma * sigma).exp(),
+        McDistribution::Triangular { min, mode, max } => (min + mode + max) / 3.0,
+        McDistribution::Uniform { min, max } => (min + max) / 2.0,
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Gaussian copula
+// ---------------------------------------------------------------------------
+
+/// Validate a correlation matrix and return its lower Cholesky factor.
+#[allow(clippy::needless_range_loop)]
+fn correlation_cholesky(corr: &[Vec<f64>], dims: usize) -> CorpFinanceResult<Vec<Vec<f64>>> {
+    let invalid = |reason: String| CorpFinanceError::InvalidInput {
+        field: "correlation".into(),
+        reason,
+    };
+    if corr.len() != dims || corr.iter().any(|row| row.len() != dims) {
+        return Err(invalid(format!("Must be a {dims}x{dims} matrix")));
+    }
+    for i in 0..dims {
+        if (corr[i][i] - 1.0).abs() > 1e-9 {
+            return Err(invalid(format!("Diagonal entry {i} must be 1")));
+        }
+        for j in 0..i {
+            if (corr[i][j] - corr[j][i]).abs() > 1e-9 {
+                return Err(invalid(format!("Entries ({i},{j}) and ({j},{i}) differ")));
+            }
+            if !(-1.0..=1.0).contains(&corr[i][j]) {
+                return Err(invalid(format!("Entry ({i},{j}) must be in [-1, 1]")));
+            }
+        }
+    }
+
+    let mut l = vec![vec![0.0; dims]; dims];
+    for i in 0..dims {
+        for j in 0..=i {
+            let dot: f64 = (0..j).map(|k| l[i][k] * l[j][k]).sum();
+            if i == j {
+                let d = corr[i][i] - dot;
+                if d <= 1e-12 {
+                    return Err(invalid("Matrix is not positive definite".into()));
+                }
+                l[i][j] = d.sqrt();
+            } else {
+                l[i][j] = (corr[i][j] - dot) / l[j][j];
+            }
+        }
+    }
+    Ok(l)
+}
+
+/// Map independent uniforms to correlated uniforms: u -> z = N^-1(u),
+/// z' = L z, u' = N(z').
+fn correlate_uniforms(cholesky: &[Vec<f64>], uniforms: &[f64]) -> Vec<f64> {
+    let std_normal = Normal::new(0.0, 1.0).expect("standard normal");
+    let z: Vec<f64> = uniforms
+        .iter()
+        .map(|u| std_normal.inverse_cdf(u.clamp(1e-12, 1.0 - 1e-12)))
+        .collect();
+    cholesky
+        .iter()
+        .map(|row| std_normal.cdf(row.iter().zip(&z).map(|(l, z)| l * z).sum()))
+        .collect()
+}
+
+// ---------------------------------------------------------------------------
+// Sobol sequence
+// ---------------------------------------------------------------------------
+
+/// Joe-Kuo direction number parameters (degree s, coefficients a, initial
+/// m values) for dimensions 2 to 21; dimension 1 is van der Corput.
+const SOBOL_PARAMS: [(u32, u32, &[u32]); 20] = [
+    (1, 0, &[1]),
+    (2, 1, &[1, 3]),
+    (3, 1, &[1, 3, 1]),
+    (3, 2, &[1, 1, 1]),
+    (4, 1, &[1, 1, 3, 3]),
+    (4, 4, &[1, 3, 5, 13]),
+    (5, 2, &[1, 1, 5, 5, 17]),
+    (5, 4, &[1, 1, 5, 5, 5]),
+    (5, 7, &[1, 1, 7, 11, 19]),
+    (5, 11, &[1, 1, 5, 1, 1]),
+    (5, 13, &[1, 1, 1, 3, 11]),
+    (5, 14, &[1, 3, 5, 5, 31]),
+    (6, 1, &[1, 3, 3, 9, 7, 49]),
+    (6, 13, &[1, 1, 1, 15, 21, 21]),
+    (6, 16, &[1, 3, 1, 13, 27, 49]),
+    (6, 19, &[1, 1, 1, 15, 7, 5]),
+    (6, 22, &[1, 3, 1, 15, 13, 25]),
+    (6, 25, &[1, 1, 5, 5, 19, 61]),
+    (7, 1, &[1, 3, 7, 11, 23, 15, 103]),
+    (7, 4, &[1, 3, 7, 13, 13, 15, 69]),
+];
+
+/// Maximum number of dimensions supported by Sobol sampling.
+pub const MAX_SOBOL_DIMENSIONS: usize = SOBOL_PARAMS.len() + 1;
+
+/// Number of independently shifted Sobol replicates used to estimate the
+/// standard error.
+const QMC_REPLICATES: usize = 8;
+
+struct Sobol {
+    directions: Vec<[u32; 32]>,
+}
+
+impl Sobol {
+    fn new(dims: usize) -> Self {
+        let mut directions = Vec::with_capacity(dims);
+        let mut first = [0u32; 32];
+        for (k, v) in first.iter_mut().enumerate() {
+            *v = 1 << (31 - k);
+        }
+        directions.push(first);
+        for &(s, a, m) in SOBOL_PARAMS.iter().take(dims.saturating_sub(1)) {
+            let s = s as usize;
+            let mut v = [0u32; 32];
+            for k in 0..32 {
+                v[k] = if k < s {
+                    m[k] << (31 - k)
+                } else {
+                    let mut x = v[k - s] ^ (v[k - s] >> s);
+                    for j in 1..s {
+                        if (a >> (s - 1 - j)) & 1 == 1 {
+                            x ^= v[k - j];
+                        }
+                    }
+                    x
+                };
+            }
+            directions.push(v);
+        }
+        Sobol { directions }
+    }
+
+    /// Point `index` in Gray-code order, as 32-bit integers per dimension.
+    fn point(&self, index: u32) -> Vec<u32> {
+        let gray = index ^ (index >> 1);
+        self.directions
+            .iter()
+            .map(|v| {
+                (0..32)
+                    .filter(|k| (gray >> k) & 1 == 1)
+                    .fold(0u32, |acc, k| acc ^ v[k])
+            })
+            .collect()
+    }
+}
+
+/// Independent RNG for one path (or antithetic pair), derived from the base
+/// seed with a SplitMix64 finaliser so draws do not depend on which thread
+/// evaluates the path.
+fn path_rng(base_seed: u64, index: u64) -> StdRng {
+    let mut z = base_seed ^ index.wrapping_add(1).wrapping_mul(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    StdRng::seed_from_u64(z ^ (z >> 31))
+}
+
+/// Paths between soft-timeout checks, so the clock is not read on every path.
+const DEADLINE_CHECK_INTERVAL: usize = 1024;
+
+fn check_deadline(
+    deadline: &limits::Deadline,
+    index: usize,
+    completed: &AtomicUsize,
+    total: usize,
+) -> CorpFinanceResult<()> {
+    if !index.is_multiple_of(DEADLINE_CHECK_INTERVAL) {
+        return Ok(());
+    }
+    deadline.check(|| format!("{} of {total} paths", completed.load(Ordering::Relaxed)))
+}
+
+/// Generates the draws for each path under the chosen scheme. Every path is
+/// a pure function of the base seed and its index, so paths can be evaluated
+/// in any order and on any number of threads.
+struct PathSampler {
+    method: SamplingMethod,
+    base_seed: u64,
+    sobol: Option<Sobol>,
+    /// Digital shift per Sobol replicate and dimension.
+    shifts: Vec<Vec<u32>>,
+    replicate_len: usize,
+    /// Cholesky factor of the correlation matrix, if any.
+    cholesky: Option<Vec<Vec<f64>>>,
+}
+
+impl PathSampler {
+    fn new(
+        method: SamplingMethod,
+        seed: Option<u64>,
+        correlation: Option<&[Vec<f64>]>,
+        dims: usize,
+        num_paths: usize,
+    ) -> CorpFinanceResult<Self> {
+        let cholesky = correlation
+            .map(|c| correlation_cholesky(c, dims))
+            .transpose()?;
+        let base_seed = seed.unwrap_or_else(|| StdRng::from_entropy().gen());
+        let (sobol, shifts) = if method == SamplingMethod::Sobol {
+            if dims > MAX_SOBOL_DIMENSIONS {
+                return Err(CorpFinanceError::InvalidInput {
+                    field: "variance_reduction.sampling".into(),
+                    reason: format!(
+                        "Sobol sampling supports at most {MAX_SOBOL_DIMENSIONS} dimensions, got {dims}"
+                    ),
+                });
+            }
+            let mut rng = StdRng::seed_from_u64(base_seed);
+            let shifts = (0..QMC_REPLICATES)
+                .map(|_| (0..dims).map(|_| rng.gen::<u32>()).collect())
+                .collect();
+            (Some(Sobol::new(dims)), shifts)
+        } else {
+            (None, Vec::new())
+        };
+        Ok(PathSampler {
+            method,
+            base_seed,
+            sobol,
+            shifts,
+            replicate_len: num_paths.div_ceil(QMC_REPLICATES),
+            cholesky,
+        })
+    }
+
+    /// Sample every distribution for path `i`.
+    fn draw(&self, i: usize, dists: &[&McDistribution]) -> CorpFinanceResult<Vec<f64>> {
+        let uniforms: Vec<f64> = match self.method {
+            SamplingMethod::PseudoRandom => {
+                let mut rng = path_rng(self.base_seed, i as u64);
+                if self.cholesky.is_none() {
+                    return dists.iter().map(|d| sample(&mut rng, d)).collect();
+                }
+                (0..dists.len()).map(|_| rng.gen::<f64>()).collect()
+            }
+            SamplingMethod::Antithetic => {
+                // Both members of a pair share one stream; the odd path mirrors it
+                let mut rng = path_rng(self.base_seed, (i / 2) as u64);
+                let u = (0..dists.len()).map(|_| rng.gen::<f64>());
+                if i.is_multiple_of(2) {
+                    u.collect()
+                } else {
+                    u.map(|u| 1.0 - u).collect()
+                }
+            }
+            SamplingMethod::Sobol => {
+                let replicate = i / self.replicate_len;
+                // The digital shift moves the origin off the distribution
+                // minimum, so each replicate starts at point 0 to keep the net
+                let index = (i % self.replicate_len) as u32;
+                let point = self
+                    .sobol
+                    .as_ref()
+                    .map(|s| s.point(index))
+                    .unwrap_or_default();
+                point
+                    .iter()
+                    .zip(&self.shifts[replicate])
+                    .map(|(x, shift)| ((x ^ shift) as f64 + 0.5) / 4_294_967_296.0)
+                    .collect()
+            }
+        };
+        let uniforms = match &self.cholesky {
+            Some(l) => correlate_uniforms(l, &uniforms),
+            None => uniforms,
+        };
+        dists
+            .iter()
+            .zip(uniforms)
+            .map(|(d, u)| sample_from_uniform(d, u))
+            .collect()
+    }
+
+    /// Independent group a path belongs to for standard error estimation:
+    /// the path itself, its antithetic pair, or its Sobol replicate.
+    fn group(&self, i: usize) -> usize {
+        match self.method {
+            SamplingMethod::PseudoRandom => i,
+            SamplingMethod::Antithetic => i / 2,
+            SamplingMethod::Sobol => i / self.replicate_len,
+        }
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Statistics helpers
 // ---------------------------------------------------------------------------
 
+/// Standard error of the mean from independent groups of paths
+/// (`groups[i]` is the group of `values[i]`, non-decreasing).
+fn grouped_std_error(values: &[f64], groups: &[usize]) -> f64 {
+    let mut means: Vec<f64> = Vec::new();
+    let mut start = 0;
+    while start < values.len() {
+        let end = (start..values.len())
+            .find(|&j| groups[j] != groups[start])
+            .unwrap_or(values.len());
+        means.push(values[start..end].iter().sum::<f64>() / (end - start) as f64);
+        start = end;
+    }
+    let g = means.len();
+    if g < 2 {
+        return 0.0;
+    }
+    let mean = means.iter().sum::<f64>() / g as f64;
+    let var = means.iter().map(|m| (m - mean).powi(2)).sum::<f64>() / (g - 1) as f64;
+    (var / g as f64).sqrt()
+}
+
+/// Multiple control variate regression. Returns the coefficients and the
+/// adjusted values y_i - b'(x_i - mu).
+fn control_variate_adjust(y: &[f64], controls: &[Vec<f64>], means: &[f64]) -> (Vec<f64>, Vec<f64>) {
+    let n = y.len() as f64;
+    let k = means.len();
+    let y_bar = y.iter().sum::<f64>() / n;
+    let x_bar: Vec<f64> = (0..k)
+        .map(|j| controls.iter().map(|x| x[j]).sum::<f64>() / n)
+        .collect();
+
+    // Normal equations S_xx b = S_xy, with a tiny ridge for degenerate inputs
+    let mut a = vec![vec![0.0; k + 1]; k];
+    for x in controls.iter().zip(y) {
+        let (x, yi) = x;
+        for r in 0..k {
+            let dr = x[r] - x_bar[r];
+            for c in 0..k {
+                a[r][c] += dr * (x[c] - x_bar[c]);
+            }
+            a[r][k] += dr * (yi - y_bar);
+        }
+    }
+    for (r, row) in a.iter_mut().enumerate() {
+        row[r] += 1e-12 * (1.0 + row[r].abs());
+    }
+    let beta = solve_linear(a).unwrap_or_else(|| vec![0.0; k]);
+
+    let adjusted = controls
+        .iter()
+        .zip(y)
+        .map(|(x, yi)| {
+            yi - x
+                .iter()
+                .zip(means)
+                .zip(&beta)
+                .map(|((xj, mj), bj)| bj * (xj - mj))
+                .sum::<f64>()
+        })
+        .collect();
+    (beta, adjusted)
+}
+
+/// Gaussian elimination with partial pivoting on an augmented matrix.
+fn solve_linear(mut a: Vec<Vec<f64>>) -> Option<Vec<f64>> {
+    let n = a.len();
+    for col in 0..n {
+        let pivot = (col..n).max_by(|&i, &j| a[i][col].abs().total_cmp(&a[j][col].abs()))?;
+        if a[pivot][col].abs() < 1e-300 {
+            return None;
+        }
+        a.swap(col, pivot);
+        let pivot_row = a[col].clone();
+        for row in a.iter_mut().skip(col + 1) {
+            let f = row[col] / pivot_row[col];
+            for (cell, p) in row.iter_mut().zip(&pivot_row).skip(col) {
+                *cell -= f * p;
+            }
+        }
+    }
+    let mut x = vec![0.0; n];
+    for r in (0..n).rev() {
+        let tail: f64 = (r + 1..n).map(|c| a[r][c] * x[c]).sum();
+        x[r] = (a[r][n] - tail) / a[r][r];
+    }
+    Some(x)
+}
+
 /// Compute the percentile value from a **sorted** slice using linear interpolation.
 fn percentile_sorted(sorted: &[f64], p: f64) -> f64 {
     assert!(!sorted.is_empty());
@@ -336,6 +816,11 @@ fn compute_statistics(values: &mut [f64], name: &str) -> McVariableResult {
     McVariableResult {
         name: name.to_string(),
         mean,
+        mean_std_error: if values.len() > 1 {
+            (variance / (n - 1.0)).sqrt()
+        } else {
+            0.0
+        },
         median,
         std_dev,
         min,
@@ -356,12 +841,20 @@ fn compute_statistics(values: &mut [f64], name: &str) -> McVariableResult {
 /// Each variable is independently sampled from its distribution for
 /// `num_simulations` paths. Returns per-variable statistics including
 /// mean, median, standard deviation, percentiles, skewness, kurtosis,
-/// and a 20-bin histogram.
+/// and a 20-bin histogram. All variables of a path are drawn together, one
+/// dimension per variable. Paths are evaluated in parallel with per-path
+/// seeding, so a seeded run gives identical results on any thread count.
+#[tracing::instrument(
+    level = "debug",
+    name = "monte_carlo",
+    skip_all,
+    fields(simulations = input.num_simulations, variables = input.variables.len())
+)]
 pub fn run_monte_carlo_simulation(
     input: &MonteCarloInput,
 ) -> CorpFinanceResult<ComputationOutput<MonteCarloOutput>> {
     let start = Instant::now();
-    let warnings: Vec<String> = Vec::new();
+    let mut warnings: Vec<String> = Vec::new();
 
     // Validation
     if input.num_simulations < 100 {
@@ -370,30 +863,59 @@ pub fn run_monte_carlo_simulation(
             reason: "Must be at least 100".into(),
         });
     }
+    limits::check_simulation_paths("num_simulations", input.num_simulations.into())?;
     if input.variables.is_empty() {
         return Err(CorpFinanceError::InsufficientData(
             "At least one variable is required".into(),
         ));
     }
 
-    let mut rng = match input.seed {
-        Some(s) => StdRng::seed_from_u64(s),
-        None => StdRng::from_entropy(),
-    };
-
     let n = input.num_simulations as usize;
-    let mut variable_results = Vec::with_capacity(input.variables.len());
+    let method = input.variance_reduction.sampling;
+    if input.variance_reduction.control_variates {
+        warnings.push(
+            "Control variates need a derived output; ignored for independent variables".into(),
+        );
+    }
 
-    for var in &input.variables {
-        let mut samples = Vec::with_capacity(n);
-        for _ in 0..n {
-            samples.push(sample(&mut rng, &var.distribution)?);
-        }
-        variable_results.push(compute_statistics(&mut samples, &var.name));
+    // Draw all variables of a path together; paths run in parallel and are
+    // collected in index order
+    let dists: Vec<&McDistribution> = input.variables.iter().map(|v| &v.distribution).collect();
+    let sampler = PathSampler::new(
+        method,
+        input.seed,
+        input.correlation.as_deref(),
+        dists.len(),
+        n,
+    )?;
+    let deadline = limits::Deadline::start();
+    let completed = AtomicUsize::new(0);
+    let paths = tracing::debug_span!("sampling").in_scope(|| {
+        (0..n)
+            .into_par_iter()
+            .map(|i| {
+                check_deadline(&deadline, i, &completed, n)?;
+                let path = sampler.draw(i, &dists);
+                completed.fetch_add(1, Ordering::Relaxed);
+                path
+            })
+            .collect::<CorpFinanceResult<Vec<_>>>()
+    })?;
+    let groups: Vec<usize> = (0..n).map(|i| sampler.group(i)).collect();
+
+    let _stage = tracing::debug_span!("statistics").entered();
+    let mut variable_results = Vec::with_capacity(input.variables.len());
+    for (j, var) in input.variables.iter().enumerate() {
+        let mut column: Vec<f64> = paths.iter().map(|x| x[j]).collect();
+        let std_error = grouped_std_error(&column, &groups);
+        let mut result = compute_statistics(&mut column, &var.name);
+        result.mean_std_error = std_error;
+        variable_results.push(result);
     }
 
     let output = MonteCarloOutput {
         num_simulations: input.num_simulations,
+        sampling: method,
         variables: variable_results,
     };
 
@@ -403,7 +925,9 @@ pub fn run_monte_carlo_simulation(
         &serde_json::json!({
             "num_simulations": input.num_simulations,
             "seed": input.seed,
+            "sampling": format!("{method:?}"),
             "variables": input.variables.iter().map(|v| &v.name).collect::<Vec<_>>(),
+            "correlation": input.correlation,
         }),
         warnings,
         elapsed,
@@ -420,7 +944,10 @@ pub fn run_monte_carlo_simulation(
 /// For each simulation path, revenue growth, EBITDA margin, WACC, and
 /// terminal growth are sampled from their distributions. Free cash flows
 /// are projected and discounted. Paths where terminal growth >= WACC are
-/// skipped as financial impossibilities.
+/// skipped as financial impossibilities. With control variates enabled the
+/// mean EV is regressed on the four sampled inputs, whose means are known.
+/// Paths are valued in parallel and reduced in path order, so seeded results
+/// do not depend on the thread count.
 pub fn run_monte_carlo_dcf(
     input: &McDcfInput,
 ) -> CorpFinanceResult<ComputationOutput<McDcfOutput>> {
@@ -434,53 +961,82 @@ pub fn run_monte_carlo_dcf(
             reason: "Must be at least 100".into(),
         });
     }
+    limits::check_simulation_paths("num_simulations", input.num_simulations.into())?;
     if input.projection_years < 1 {
         return Err(CorpFinanceError::InvalidInput {
             field: "projection_years".into(),
             reason: "Must be at least 1".into(),
         });
     }
-
-    let mut rng = match input.seed {
-        Some(s) => StdRng::seed_from_u64(s),
-        None => StdRng::from_entropy(),
-    };
+    limits::check_schedule_periods("projection_years", input.projection_years.into())?;
 
     let n = input.num_simulations as usize;
-    let mut ev_values: Vec<f64> = Vec::with_capacity(n);
-    let mut skipped: u32 = 0;
-
-    for _ in 0..n {
-        let g = sample(&mut rng, &input.revenue_growth)?;
-        let margin = sample(&mut rng, &input.ebitda_margin)?;
-        let wacc = sample(&mut rng, &input.wacc)?;
-        let tg = sample(&mut rng, &input.terminal_growth)?;
+    let method = input.variance_reduction.sampling;
+    let dists = [
+        &input.revenue_growth,
+        &input.ebitda_margin,
+        &input.wacc,
+        &input.terminal_growth,
+    ];
+    let sampler = PathSampler::new(
+        method,
+        input.seed,
+        input.correlation.as_deref(),
+        dists.len(),
+        n,
+    )?;
+
+    // Value each path in parallel; `None` marks a skipped path
+    let deadline = limits::Deadline::start();
+    let completed = AtomicUsize::new(0);
+    let paths = (0..n)
+        .into_par_iter()
+        .map(|i| {
+            check_deadline(&deadline, i, &completed, n)?;
+            completed.fetch_add(1, Ordering::Relaxed);
+            let x = sampler.draw(i, &dists)?;
+            let (g, margin, wacc, tg) = (x[0], x[1], x[2], x[3]);
+
+            // Skip financially impossible paths
+            if tg >= wacc {
+                return Ok(None);
+            }
 
-        // Skip financially impossible paths
-        if tg >= wacc {
-            skipped += 1;
-            continue;
-        }
+            // Project FCFs
+            let mut npv = 0.0_f64;
+            let mut discount_factor = 1.0_f64;
+            let mut last_fcf = 0.0_f64;
+
+            for t in 1..=input.projection_years {
+                let revenue_multiplier = (1.0 + g).powi(t as i32);
+                let fcf = input.base_fcf * revenue_multiplier * margin;
+                discount_factor /= 1.0 + wacc;
+                npv += fcf * discount_factor;
+                last_fcf = fcf;
+            }
 
-        // Project FCFs
-        let mut npv = 0.0_f64;
-        let mut discount_factor = 1.0_f64;
-        let mut last_fcf = 0.0_f64;
-
-        for t in 1..=input.projection_years {
-            let revenue_multiplier = (1.0 + g).powi(t as i32);
-            let fcf = input.base_fcf * revenue_multiplier * margin;
-            discount_factor /= 1.0 + wacc;
-            npv += fcf * discount_factor;
-            last_fcf = fcf;
-        }
+            // Terminal value
+            let terminal_fcf = last_fcf * (1.0 + tg);
+            let terminal_value = terminal_fcf / (wacc - tg);
+            npv += terminal_value * discount_factor;
 
-        // Terminal value
-        let terminal_fcf = last_fcf * (1.0 + tg);
-        let terminal_value = terminal_fcf / (wacc - tg);
-        npv += terminal_value * discount_factor;
+            Ok(Some((npv, sampler.group(i), x)))
+        })
+        .collect::<CorpFinanceResult<Vec<_>>>()?;
 
-        ev_values.push(npv);
+    let mut ev_values: Vec<f64> = Vec::with_capacity(n);
+    let mut groups: Vec<usize> = Vec::with_capacity(n);
+    let mut drivers: Vec<Vec<f64>> = Vec::with_capacity(n);
+    let mut skipped: u32 = 0;
+    for path in paths {
+        match path {
+            Some((ev, group, x)) => {
+                ev_values.push(ev);
+                groups.push(group);
+                drivers.push(x);
+            }
+            None => skipped += 1,
+        }
     }
 
     if ev_values.is_empty() {
@@ -496,13 +1052,44 @@ pub fn run_monte_carlo_dcf(
         ));
     }
 
-    // Sort for percentile calculations
-    ev_values.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
-
     let valid_n = ev_values.len() as f64;
-    let ev_mean = ev_values.iter().sum::<f64>() / valid_n;
-    let ev_variance = ev_values.iter().map(|v| (v - ev_mean).powi(2)).sum::<f64>() / valid_n;
+    let raw_mean = ev_values.iter().sum::<f64>() / valid_n;
+    let ev_variance = ev_values
+        .iter()
+        .map(|v| (v - raw_mean).powi(2))
+        .sum::<f64>()
+        / valid_n;
     let ev_std_dev = ev_variance.sqrt();
+    let mut ev_mean = raw_mean;
+    let mut ev_mean_std_error = grouped_std_error(&ev_values, &groups);
+
+    // Skipped paths truncate the input distributions, so their analytical
+    // means no longer apply as control variate targets
+    let mut control_variate = None;
+    if input.variance_reduction.control_variates {
+        if skipped > 0 {
+            warnings.push("Control variates disabled because some paths were skipped".into());
+        } else {
+            let means: Vec<f64> = dists.iter().map(|d| distribution_mean(d)).collect();
+            let (coefficients, adjusted) = control_variate_adjust(&ev_values, &drivers, &means);
+            ev_mean = adjusted.iter().sum::<f64>() / valid_n;
+            let adjusted_variance =
+                adjusted.iter().map(|v| (v - ev_mean).powi(2)).sum::<f64>() / valid_n;
+            ev_mean_std_error = grouped_std_error(&adjusted, &groups);
+            control_variate = Some(ControlVariateSummary {
+                raw_mean,
+                coefficients,
+                variance_reduction_factor: if adjusted_variance > 0.0 {
+                    ev_variance / adjusted_variance
+                } else {
+                    f64::INFINITY
+                },
+            });
+        }
+    }
+
+    // Sort for percentile calculations
+    ev_values.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
 
     let enterprise_values = McPercentiles {
         p5: percentile_sorted(&ev_values, 5.0),
@@ -538,10 +1125,12 @@ pub fn run_monte_carlo_dcf(
     let output = McDcfOutput {
         enterprise_values,
         ev_mean,
+        ev_mean_std_error,
         ev_std_dev,
         probability_above,
         implied_ev_range,
         simulation_count: ev_values.len() as u32,
+        control_variate,
     };
 
     let elapsed = start.elapsed().as_micros() as u64;
@@ -556,6 +1145,9 @@ pub fn run_monte_carlo_dcf(
             "valid_simulations": ev_values.len(),
             "skipped_simulations": skipped,
             "seed": input.seed,
+            "sampling": format!("{method:?}"),
+            "control_variates": input.variance_reduction.control_variates,
+            "correlation": input.correlation,
         }),
         warnings,
         elapsed,
@@ -584,6 +1176,8 @@ mod tests {
         MonteCarloInput {
             num_simulations: 10_000,
             seed: Some(SEED),
+            variance_reduction: VarianceReduction::default(),
+            correlation: None,
             variables: vec![normal_var("revenue_growth", 0.05, 0.02)],
         }
     }
@@ -615,6 +1209,8 @@ mod tests {
         let input = MonteCarloInput {
             num_simulations: 50_000,
             seed: Some(SEED),
+            variance_reduction: VarianceReduction::default(),
+            correlation: None,
             variables: vec![normal_var("test", 100.0, 10.0)],
         };
         let result = run_monte_carlo_simulation(&input).unwrap();
@@ -635,6 +1231,8 @@ mod tests {
         let input = MonteCarloInput {
             num_simulations: 10_000,
             seed: Some(SEED),
+            variance_reduction: VarianceReduction::default(),
+            correlation: None,
             variables: vec![McVariable {
                 name: "asset_price".into(),
                 distribution: McDistribution::LogNormal {
@@ -665,6 +1263,8 @@ mod tests {
         let input = MonteCarloInput {
             num_simulations: 10_000,
             seed: Some(SEED),
+            variance_reduction: VarianceReduction::default(),
+            correlation: None,
             variables: vec![McVariable {
                 name: "growth".into(),
                 distribution: McDistribution::Triangular {
@@ -694,6 +1294,8 @@ mod tests {
         let input = MonteCarloInput {
             num_simulations: 10_000,
             seed: Some(SEED),
+            variance_reduction: VarianceReduction::default(),
+            correlation: None,
             variables: vec![McVariable {
                 name: "rate".into(),
                 distribution: McDistribution::Uniform {
@@ -722,6 +1324,8 @@ mod tests {
         let input = MonteCarloInput {
             num_simulations: 1_000,
             seed: Some(SEED),
+            variance_reduction: VarianceReduction::default(),
+            correlation: None,
             variables: vec![
                 normal_var("var_a", 10.0, 2.0),
                 normal_var("var_b", 50.0, 5.0),
@@ -782,6 +1386,8 @@ mod tests {
         let input = MonteCarloInput {
             num_simulations: 50,
             seed: Some(SEED),
+            variance_reduction: VarianceReduction::default(),
+            correlation: None,
             variables: vec![normal_var("x", 0.0, 1.0)],
         };
         assert!(run_monte_carlo_simulation(&input).is_err());
@@ -792,6 +1398,8 @@ mod tests {
         let input = MonteCarloInput {
             num_simulations: 100,
             seed: Some(SEED),
+            variance_reduction: VarianceReduction::default(),
+            correlation: None,
             variables: vec![],
         };
         assert!(run_monte_carlo_simulation(&input).is_err());
@@ -802,6 +1410,8 @@ mod tests {
         let input = MonteCarloInput {
             num_simulations: 100,
             seed: Some(SEED),
+            variance_reduction: VarianceReduction::default(),
+            correlation: None,
             variables: vec![normal_var("x", 0.0, 1.0)],
         };
         let result = run_monte_carlo_simulation(&input).unwrap();
@@ -813,6 +1423,8 @@ mod tests {
         let input = MonteCarloInput {
             num_simulations: 500,
             seed: Some(SEED),
+            variance_reduction: VarianceReduction::default(),
+            correlation: None,
             variables: vec![normal_var("only_one", 42.0, 1.0)],
         };
         let result = run_monte_carlo_simulation(&input).unwrap();
@@ -826,6 +1438,8 @@ mod tests {
         let input = MonteCarloInput {
             num_simulations: 100_000,
             seed: Some(SEED),
+            variance_reduction: VarianceReduction::default(),
+            correlation: None,
             variables: vec![normal_var("converge", 50.0, 5.0)],
         };
         let result = run_monte_carlo_simulation(&input).unwrap();
@@ -869,6 +1483,8 @@ mod tests {
             tax_rate: 0.25,
             num_simulations: 10_000,
             seed: Some(SEED),
+            variance_reduction: VarianceReduction::default(),
+            correlation: None,
         }
     }
 
@@ -967,6 +1583,8 @@ mod tests {
             tax_rate: 0.25,
             num_simulations: 10_000,
             seed: Some(SEED),
+            variance_reduction: VarianceReduction::default(),
+            correlation: None,
         };
         let result = run_monte_carlo_dcf(&input).unwrap();
         // Some simulations should have been skipped
@@ -981,4 +1599,302 @@ mod tests {
             "Expected a warning about skipped simulations"
         );
     }
+
+    fn with_sampling(
+        mut input: McDcfInput,
+        sampling: SamplingMethod,
+        control_variates: bool,
+    ) -> McDcfInput {
+        input.variance_reduction = VarianceReduction {
+            sampling,
+            control_variates,
+        };
+        input
+    }
+
+    fn on_threads<T: Send>(threads: usize, f: impl FnOnce() -> T + Send) -> T {
+        rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build()
+            .unwrap()
+            .install(f)
+    }
+
+    #[test]
+    fn test_results_independent_of_thread_count() {
+        for sampling in [
+            SamplingMethod::PseudoRandom,
+            SamplingMethod::Antithetic,
+            SamplingMethod::Sobol,
+        ] {
+            let input = with_sampling(basic_dcf_input(), sampling, true);
+            let single = on_threads(1, || run_monte_carlo_dcf(&input).unwrap().result);
+            let multi = on_threads(4, || run_monte_carlo_dcf(&input).unwrap().result);
+            assert_eq!(single.ev_mean, multi.ev_mean, "{sampling:?}");
+            assert_eq!(single.ev_std_dev, multi.ev_std_dev, "{sampling:?}");
+            assert_eq!(
+                single.enterprise_values.p5, multi.enterprise_values.p5,
+                "{sampling:?}"
+            );
+        }
+
+        let input = MonteCarloInput {
+            num_simulations: 5_000,
+            seed: Some(SEED),
+            variance_reduction: VarianceReduction::default(),
+            correlation: None,
+            variables: vec![
+                normal_var("a", 1.0, 0.5),
+                McVariable {
+                    name: "b".into(),
+                    distribution: McDistribution::LogNormal {
+                        mu: 0.0,
+                        sigma: 0.3,
+                    },
+                },
+            ],
+        };
+        let single = on_threads(1, || run_monte_carlo_simulation(&input).unwrap().result);
+        let multi = on_threads(3, || run_monte_carlo_simulation(&input).unwrap().result);
+        for (a, b) in single.variables.iter().zip(&multi.variables) {
+            assert_eq!(a.mean, b.mean);
+            assert_eq!(a.percentiles.p95, b.percentiles.p95);
+        }
+    }
+
+    #[test]
+    fn test_sobol_first_points() {
+        let sobol = Sobol::new(2);
+        let to_unit = |x: u32| x as f64 / 4_294_967_296.0;
+        let dim0: Vec<f64> = (1..4).map(|i| to_unit(sobol.point(i)[0])).collect();
+        assert_eq!(dim0, vec![0.5, 0.75, 0.25]);
+        let dim1: Vec<f64> = (1..4).map(|i| to_unit(sobol.point(i)[1])).collect();
+        assert_eq!(dim1, vec![0.5, 0.25, 0.75]);
+    }
+
+    #[test]
+    fn test_antithetic_reduces_dcf_standard_error() {
+        let plain = run_monte_carlo_dcf(&basic_dcf_input()).unwrap().result;
+        let anti = run_monte_carlo_dcf(&with_sampling(
+            basic_dcf_input(),
+            SamplingMethod::Antithetic,
+            false,
+        ))
+        .unwrap()
+        .result;
+        assert!(
+            anti.ev_mean_std_error < plain.ev_mean_std_error,
+            "antithetic SE {} should be below plain SE {}",
+            anti.ev_mean_std_error,
+            plain.ev_mean_std_error
+        );
+        assert!((anti.ev_mean - plain.ev_mean).abs() < 4.0 * plain.ev_mean_std_error);
+    }
+
+    #[test]
+    fn test_sobol_accurate_with_few_paths() {
+        let mut input = basic_input();
+        input.num_simulations = 4_096;
+        input.variables = vec![normal_var("x", 10.0, 2.0)];
+        input.variance_reduction.sampling = SamplingMethod::Sobol;
+        let out = run_monte_carlo_simulation(&input).unwrap().result;
+        let v = &out.variables[0];
+        assert!((v.mean - 10.0).abs() < 0.01, "Sobol mean {}", v.mean);
+        assert!((v.std_dev - 2.0).abs() < 0.05);
+        // Far below the pseudo-random 2 / sqrt(4096) ~ 0.031
+        assert!(v.mean_std_error < 0.005, "Sobol SE {}", v.mean_std_error);
+        assert_eq!(out.sampling, SamplingMethod::Sobol);
+    }
+
+    #[test]
+    fn test_sobol_seeded_reproducibility() {
+        let input = with_sampling(basic_dcf_input(), SamplingMethod::Sobol, false);
+        let a = run_monte_carlo_dcf(&input).unwrap().result;
+        let b = run_monte_carlo_dcf(&input).unwrap().result;
+        assert_eq!(a.ev_mean, b.ev_mean);
+        assert_eq!(a.ev_mean_std_error, b.ev_mean_std_error);
+    }
+
+    #[test]
+    fn test_sobol_dimension_limit() {
+        let mut input = basic_input();
+        input.variables = (0..=MAX_SOBOL_DIMENSIONS)
+            .map(|i| normal_var(&format!("v{i}"), 0.0, 1.0))
+            .collect();
+        input.variance_reduction.sampling = SamplingMethod::Sobol;
+        match run_monte_carlo_simulation(&input).unwrap_err() {
+            CorpFinanceError::InvalidInput { field, .. } => {
+                assert_eq!(field, "variance_reduction.sampling")
+            }
+            other => panic!("Expected InvalidInput, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_control_variates_reduce_variance() {
+        let plain = run_monte_carlo_dcf(&basic_dcf_input()).unwrap().result;
+        let cv = run_monte_carlo_dcf(&with_sampling(
+            basic_dcf_input(),
+            SamplingMethod::PseudoRandom,
+            true,
+        ))
+        .unwrap()
+        .result;
+        let summary = cv.control_variate.as_ref().unwrap();
+        assert!(summary.variance_reduction_factor > 1.0);
+        assert_eq!(summary.coefficients.len(), 4);
+        assert_eq!(summary.raw_mean, plain.ev_mean);
+        assert!(cv.ev_mean_std_error < plain.ev_mean_std_error);
+        // Percentiles are still taken from the raw paths
+        assert_eq!(cv.enterprise_values.p50, plain.enterprise_values.p50);
+    }
+
+    #[test]
+    fn test_qmc_with_tenth_of_paths_matches_pseudo_error() {
+        let pseudo = run_monte_carlo_dcf(&basic_dcf_input()).unwrap().result;
+        let mut input = with_sampling(basic_dcf_input(), SamplingMethod::Sobol, true);
+        input.num_simulations = pseudo.simulation_count / 10;
+        let qmc = run_monte_carlo_dcf(&input).unwrap().result;
+        assert!(
+            qmc.ev_mean_std_error <= pseudo.ev_mean_std_error,
+            "QMC SE {} with 1/10 paths vs pseudo SE {}",
+            qmc.ev_mean_std_error,
+            pseudo.ev_mean_std_error
+        );
+    }
+
+    fn sample_correlation(a: &[f64], b: &[f64]) -> f64 {
+        let n = a.len() as f64;
+        let (ma, mb) = (a.iter().sum::<f64>() / n, b.iter().sum::<f64>() / n);
+        let cov: f64 = a.iter().zip(b).map(|(x, y)| (x - ma) * (y - mb)).sum();
+        let va: f64 = a.iter().map(|x| (x - ma).powi(2)).sum();
+        let vb: f64 = b.iter().map(|y| (y - mb).powi(2)).sum();
+        cov / (va * vb).sqrt()
+    }
+
+    #[test]
+    fn test_correlated_draws_match_target() {
+        let corr = vec![vec![1.0, 0.7], vec![0.7, 1.0]];
+        let a = McDistribution::Normal {
+            mean: 0.05,
+            std_dev: 0.02,
+        };
+        let b = McDistribution::Uniform { min: 0.1, max: 0.3 };
+        for method in [
+            SamplingMethod::PseudoRandom,
+            SamplingMethod::Antithetic,
+            SamplingMethod::Sobol,
+        ] {
+            let sampler =
+                PathSampler::new(method, Some(SEED), Some(corr.as_slice()), 2, 20_000).unwrap();
+            let draws: Vec<Vec<f64>> = (0..20_000)
+                .map(|i| sampler.draw(i, &[&a, &b]).unwrap())
+                .collect();
+            let xs: Vec<f64> = draws.iter().map(|d| d[0]).collect();
+            let ys: Vec<f64> = draws.iter().map(|d| d[1]).collect();
+            let rho = sample_correlation(&xs, &ys);
+            // Pearson correlation of a normal with a uniform under a Gaussian
+            // copula sits slightly below the copula parameter
+            assert!((rho - 0.68).abs() < 0.03, "{method:?} rho={rho}");
+            // Marginals are untouched
+            assert!(ys.iter().all(|y| (0.1..=0.3).contains(y)));
+        }
+    }
+
+    #[test]
+    fn test_correlation_preserves_marginals() {
+        let mut input = basic_input();
+        input.variables = vec![normal_var("a", 10.0, 2.0), normal_var("b", 5.0, 1.0)];
+        input.correlation = Some(vec![vec![1.0, -0.6], vec![-0.6, 1.0]]);
+        let out = run_monte_carlo_simulation(&input).unwrap().result;
+        assert!((out.variables[0].mean - 10.0).abs() < 0.1);
+        assert!((out.variables[0].std_dev - 2.0).abs() < 0.1);
+        assert!((out.variables[1].mean - 5.0).abs() < 0.05);
+        assert!((out.variables[1].std_dev - 1.0).abs() < 0.05);
+    }
+
+    #[test]
+    fn test_dcf_positive_driver_correlation_widens_distribution() {
+        let independent = run_monte_carlo_dcf(&basic_dcf_input()).unwrap().result;
+        let mut input = basic_dcf_input();
+        input.correlation = Some(vec![
+            vec![1.0, 0.8, 0.0, 0.0],
+            vec![0.8, 1.0, 0.0, 0.0],
+            vec![0.0, 0.0, 1.0, 0.0],
+            vec![0.0, 0.0, 0.0, 1.0],
+        ]);
+        let correlated = run_monte_carlo_dcf(&input).unwrap().result;
+        assert!(
+            correlated.ev_std_dev > independent.ev_std_dev * 1.05,
+            "correlated sd {} vs independent sd {}",
+            correlated.ev_std_dev,
+            independent.ev_std_dev
+        );
+        assert!((correlated.ev_mean - independent.ev_mean).abs() < 0.05 * independent.ev_mean);
+    }
+
+    #[test]
+    fn test_invalid_correlation_rejected() {
+        let mut input = basic_dcf_input();
+        input.correlation = Some(vec![vec![1.0, 0.5], vec![0.5, 1.0]]);
+        assert!(run_monte_carlo_dcf(&input).is_err());
+
+        let mut input = basic_input();
+        input.variables = vec![
+            normal_var("a", 0.0, 1.0),
+            normal_var("b", 0.0, 1.0),
+            normal_var("c", 0.0, 1.0),
+        ];
+        // Pairwise valid but jointly impossible
+        input.correlation = Some(vec![
+            vec![1.0, 0.9, -0.9],
+            vec![0.9, 1.0, 0.9],
+            vec![-0.9, 0.9, 1.0],
+        ]);
+        match run_monte_carlo_simulation(&input).unwrap_err() {
+            CorpFinanceError::InvalidInput { field, reason } => {
+                assert_eq!(field, "correlation");
+                assert!(reason.contains("positive definite"), "{reason}");
+            }
+            other => panic!("Expected InvalidInput, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_compute_limits() {
+        let paths = limits::ComputeLimits {
+            max_simulation_paths: Some(1_000),
+            max_schedule_periods: Some(3),
+            ..Default::default()
+        };
+        limits::with_limits(paths, || {
+            let mut input = basic_input();
+            input.num_simulations = 1_000;
+            assert!(run_monte_carlo_simulation(&input).is_ok());
+            input.num_simulations = 1_001;
+            assert!(matches!(
+                run_monte_carlo_simulation(&input).unwrap_err(),
+                CorpFinanceError::LimitExceeded { .. }
+            ));
+            // basic DCF projects five years
+            let mut dcf = basic_dcf_input();
+            dcf.num_simulations = 1_000;
+            assert!(matches!(
+                run_monte_carlo_dcf(&dcf).unwrap_err(),
+                CorpFinanceError::LimitExceeded { ref limit, .. } if limit == "max_schedule_periods"
+            ));
+        });
+
+        let timeout = limits::ComputeLimits {
+            soft_timeout_ms: Some(0),
+            ..Default::default()
+        };
+        match limits::with_limits(timeout, || run_monte_carlo_simulation(&basic_input())) {
+            Err(CorpFinanceError::LimitExceeded { limit, detail }) => {
+                assert_eq!(limit, "soft_timeout_ms");
+                assert!(detail.contains("paths"), "{detail}");
+            }
+            other => panic!("Expected LimitExceeded, got {other:?}"),
+        }
+    }
 }
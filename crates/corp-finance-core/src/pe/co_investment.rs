@@ -0,0 +1,731 @@
+use rust_decimal::Decimal;
+use rust_decimal::MathematicalOps;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+use std::time::Instant;
+
+use crate::error::CorpFinanceError;
+use crate::portfolio::returns::ReturnFrequency;
+use crate::portfolio::risk::{self, RiskMetricsInput, RiskMetricsOutput};
+use crate::types::*;
+use crate::CorpFinanceResult;
+
+// ---------------------------------------------------------------------------
+// Types
+// ---------------------------------------------------------------------------
+
+/// The co-investment opportunity offered alongside a fund deal
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CoInvestmentDeal {
+    pub name: String,
+    pub sector: String,
+    pub geography: String,
+    /// Capital the LP would commit to the co-investment
+    pub investment_amount: Money,
+    /// Expected gross multiple on invested capital
+    pub expected_gross_moic: Multiple,
+    pub holding_period_years: Years,
+    /// Annual management fee on the co-investment (usually zero)
+    #[serde(default)]
+    pub management_fee: Rate,
+    /// Carried interest on the co-investment (usually zero)
+    #[serde(default)]
+    pub carried_interest: Rate,
+    /// Periodic deal returns (or a proxy) for portfolio risk impact
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub periodic_returns: Option<Vec<Decimal>>,
+}
+
+/// Terms the same exposure would bear through the fund
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FundTerms {
+    /// Annual management fee on invested capital
+    pub management_fee: Rate,
+    pub carried_interest: Rate,
+    /// Preferred return; carry is charged on all profit once it is cleared
+    pub hurdle_rate: Rate,
+}
+
+/// An existing LP portfolio exposure
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LpExposure {
+    pub name: String,
+    pub sector: String,
+    pub geography: String,
+    pub value: Money,
+}
+
+/// The LP portfolio the co-investment would be added to
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LpPortfolio {
+    pub exposures: Vec<LpExposure>,
+    /// Periodic portfolio returns, aligned with the deal return series
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub periodic_returns: Option<Vec<Decimal>>,
+    /// Frequency of both return series (default quarterly)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub return_frequency: Option<ReturnFrequency>,
+}
+
+/// LP co-investment policy constraints
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CoInvestmentPolicy {
+    /// Maximum exposure to any single asset, as a fraction of the portfolio
+    pub max_single_asset_pct: Rate,
+    pub max_sector_pct: Rate,
+    pub max_geography_pct: Rate,
+    /// Minimum acceptable net IRR on the co-investment
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_net_irr: Option<Rate>,
+    /// Net IRR at which the return component scores in full (default 15%)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_net_irr: Option<Rate>,
+}
+
+/// Input for co-investment evaluation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CoInvestmentInput {
+    pub deal: CoInvestmentDeal,
+    pub fund_terms: FundTerms,
+    pub portfolio: LpPortfolio,
+    pub policy: CoInvestmentPolicy,
+}
+
+/// Net economics of holding the exposure via one route
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RouteEconomics {
+    pub gross_proceeds: Money,
+    pub management_fees: Money,
+    pub carried_interest: Money,
+    pub net_proceeds: Money,
+    pub net_moic: Multiple,
+    pub net_irr: Rate,
+}
+
+/// Fee and return comparison of co-investing versus the fund route
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EconomicsComparison {
+    pub gross_irr: Rate,
+    pub via_fund: RouteEconomics,
+    pub co_investment: RouteEconomics,
+    /// Fees and carry saved by co-investing
+    pub fee_savings: Money,
+    pub net_irr_uplift: Rate,
+    pub net_moic_uplift: Multiple,
+}
+
+/// Exposure to one limit dimension after the deal
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LimitCheck {
+    /// "single_asset", "sector" or "geography"
+    pub dimension: String,
+    pub bucket: String,
+    pub pre_deal_pct: Rate,
+    pub post_deal_pct: Rate,
+    pub limit: Rate,
+    /// Post-deal exposure as a fraction of the limit
+    pub utilisation: Rate,
+    pub breached: bool,
+}
+
+/// Concentration of the LP portfolio before and after the deal
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConcentrationImpact {
+    pub pre_deal_hhi: Decimal,
+    pub post_deal_hhi: Decimal,
+    pub limit_checks: Vec<LimitCheck>,
+}
+
+/// Portfolio risk metrics before and after blending in the deal
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RiskImpact {
+    pub deal_weight: Rate,
+    pub pre_deal: RiskMetricsOutput,
+    pub post_deal: RiskMetricsOutput,
+    pub volatility_change: Rate,
+    pub cvar_change: Decimal,
+}
+
+/// Overall recommendation
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum CoInvestmentDecision {
+    Proceed,
+    Review,
+    Decline,
+}
+
+/// Breakdown of the decision score (0-100)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DecisionScore {
+    /// Net return against target (max 40)
+    pub return_score: Decimal,
+    /// Net IRR uplift from fee savings (max 20)
+    pub fee_score: Decimal,
+    /// Headroom under the tightest policy limit (max 40)
+    pub concentration_score: Decimal,
+    pub total: Decimal,
+}
+
+/// Output of co-investment evaluation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CoInvestmentOutput {
+    pub economics: EconomicsComparison,
+    pub concentration: ConcentrationImpact,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_impact: Option<RiskImpact>,
+    pub score: DecisionScore,
+    pub decision: CoInvestmentDecision,
+    pub policy_breaches: Vec<String>,
+}
+
+// ---------------------------------------------------------------------------
+// Public API
+// ---------------------------------------------------------------------------
+
+/// Evaluate a co-investment against the fund route and the LP's policy.
+///
+/// Fees are charged annually on invested capital; carry is charged on all
+/// profit once the hurdle is cleared (full catch-up). Any hard policy breach
+/// declines the deal; otherwise a score of 60 or more with the minimum net
+/// IRR met proceeds, and anything else goes to review.
+pub fn evaluate_co_investment(
+    input: &CoInvestmentInput,
+) -> CorpFinanceResult<ComputationOutput<CoInvestmentOutput>> {
+    let start = Instant::now();
+    let mut warnings: Vec<String> = Vec::new();
+
+    validate_input(input)?;
+    let deal = &input.deal;
+
+    // -- Economics --
+    let gross_irr = annualise(deal.expected_gross_moic, deal.holding_period_years);
+    let via_fund = route_economics(
+        deal,
+        input.fund_terms.management_fee,
+        input.fund_terms.carried_interest,
+        input.fund_terms.hurdle_rate,
+    );
+    let co_investment = route_economics(
+        deal,
+        deal.management_fee,
+        deal.carried_interest,
+        input.fund_terms.hurdle_rate,
+    );
+    let economics = EconomicsComparison {
+        gross_irr,
+        fee_savings: co_investment.net_proceeds - via_fund.net_proceeds,
+        net_irr_uplift: co_investment.net_irr - via_fund.net_irr,
+        net_moic_uplift: co_investment.net_moic - via_fund.net_moic,
+        via_fund,
+        co_investment,
+    };
+
+    // -- Concentration --
+    let concentration = concentration_impact(input);
+
+    // -- Portfolio risk --
+    let risk_impact = match (&deal.periodic_returns, &input.portfolio.periodic_returns) {
+        (Some(deal_returns), Some(port_returns)) => {
+            Some(risk_impact(input, deal_returns, port_returns)?)
+        }
+        (None, None) => None,
+        _ => {
+            warnings.push(
+                "Risk impact skipped: both deal and portfolio return series are required".into(),
+            );
+            None
+        }
+    };
+    if let Some(ref r) = risk_impact {
+        if r.volatility_change > Decimal::ZERO {
+            warnings.push(format!(
+                "Co-investment raises portfolio volatility by {}",
+                r.volatility_change.round_dp(4)
+            ));
+        }
+    }
+
+    // -- Scoring --
+    let target = input.policy.target_net_irr.unwrap_or(dec!(0.15));
+    let return_score = dec!(40) * clamp_unit(economics.co_investment.net_irr / target);
+    let fee_score = dec!(20) * clamp_unit(economics.net_irr_uplift / dec!(0.05));
+    let max_utilisation = concentration
+        .limit_checks
+        .iter()
+        .map(|c| c.utilisation)
+        .max()
+        .unwrap_or(Decimal::ZERO);
+    let concentration_score = dec!(40) * clamp_unit(Decimal::ONE - max_utilisation);
+    let score = DecisionScore {
+        return_score,
+        fee_score,
+        concentration_score,
+        total: return_score + fee_score + concentration_score,
+    };
+
+    let mut policy_breaches: Vec<String> = concentration
+        .limit_checks
+        .iter()
+        .filter(|c| c.breached)
+        .map(|c| {
+            format!(
+                "{} limit breached for {}: {} vs limit {}",
+                c.dimension,
+                c.bucket,
+                c.post_deal_pct.round_dp(4),
+                c.limit
+            )
+        })
+        .collect();
+    let min_irr_met = input
+        .policy
+        .min_net_irr
+        .is_none_or(|m| economics.co_investment.net_irr >= m);
+    if !min_irr_met {
+        policy_breaches.push(format!(
+            "Net IRR {} below policy minimum {}",
+            economics.co_investment.net_irr.round_dp(4),
+            input.policy.min_net_irr.unwrap_or_default()
+        ));
+    }
+
+    let decision = if !policy_breaches.is_empty() {
+        CoInvestmentDecision::Decline
+    } else if score.total >= dec!(60) {
+        CoInvestmentDecision::Proceed
+    } else {
+        CoInvestmentDecision::Review
+    };
+
+    if economics.net_irr_uplift <= Decimal::ZERO {
+        warnings.push("Co-investment terms offer no net return uplift over the fund".into());
+    }
+
+    let output = CoInvestmentOutput {
+        economics,
+        concentration,
+        risk_impact,
+        score,
+        decision,
+        policy_breaches,
+    };
+
+    let elapsed = start.elapsed().as_micros() as u64;
+    Ok(with_metadata(
+        "Co-Investment Evaluation (fee economics, concentration, policy scoring)",
+        &serde_json::json!({
+            "fee_basis": "annual management fee on invested capital",
+            "carry_basis": "full catch-up once hurdle cleared",
+            "target_net_irr": target.to_string(),
+            "score_weights": {"return": 40, "fees": 20, "concentration": 40},
+        }),
+        warnings,
+        elapsed,
+        output,
+    ))
+}
+
+// ---------------------------------------------------------------------------
+// Internal logic
+// ---------------------------------------------------------------------------
+
+fn annualise(moic: Decimal, years: Years) -> Rate {
+    if moic <= Decimal::ZERO {
+        return dec!(-1);
+    }
+    moic.powd(Decimal::ONE / years) - Decimal::ONE
+}
+
+fn route_economics(
+    deal: &CoInvestmentDeal,
+    fee_rate: Rate,
+    carry_rate: Rate,
+    hurdle: Rate,
+) -> RouteEconomics {
+    let amount = deal.investment_amount;
+    let gross_proceeds = amount * deal.expected_gross_moic;
+    let management_fees = amount * fee_rate * deal.holding_period_years;
+
+    let profit = gross_proceeds - management_fees - amount;
+    let preferred =
+        amount * ((Decimal::ONE + hurdle).powd(deal.holding_period_years) - Decimal::ONE);
+    let carried_interest = if profit > preferred && profit > Decimal::ZERO {
+        profit * carry_rate
+    } else {
+        Decimal::ZERO
+    };
+
+    let net_proceeds = gross_proceeds - management_fees - carried_interest;
+    let net_moic = net_proceeds / amount;
+    RouteEconomics {
+        gross_proceeds,
+        management_fees,
+        carried_interest,
+        net_proceeds,
+        net_moic,
+        net_irr: annualise(net_moic, deal.holding_period_years),
+    }
+}
+
+fn concentration_impact(input: &CoInvestmentInput) -> ConcentrationImpact {
+    let deal = &input.deal;
+    let exposures = &input.portfolio.exposures;
+    let pre_total: Money = exposures.iter().map(|e| e.value).sum();
+    let post_total = pre_total + deal.investment_amount;
+
+    let share = |value: Money, total: Money| {
+        if total.is_zero() {
+            Decimal::ZERO
+        } else {
+            value / total
+        }
+    };
+    let bucket_value = |pred: &dyn Fn(&LpExposure) -> bool| -> Money {
+        exposures.iter().filter(|e| pred(e)).map(|e| e.value).sum()
+    };
+
+    let policy = &input.policy;
+    let dimensions: [(&str, &String, Money, Rate); 3] = [
+        (
+            "single_asset",
+            &deal.name,
+            bucket_value(&|e| e.name == deal.name),
+            policy.max_single_asset_pct,
+        ),
+        (
+            "sector",
+            &deal.sector,
+            bucket_value(&|e| e.sector == deal.sector),
+            policy.max_sector_pct,
+        ),
+        (
+            "geography",
+            &deal.geography,
+            bucket_value(&|e| e.geography == deal.geography),
+            policy.max_geography_pct,
+        ),
+    ];
+
+    let limit_checks = dimensions
+        .iter()
+        .map(|(dimension, bucket, existing, limit)| {
+            let post_deal_pct = share(*existing + deal.investment_amount, post_total);
+            LimitCheck {
+                dimension: dimension.to_string(),
+                bucket: bucket.to_string(),
+                pre_deal_pct: share(*existing, pre_total),
+                post_deal_pct,
+                limit: *limit,
+                utilisation: post_deal_pct / *limit,
+                breached: post_deal_pct > *limit,
+            }
+        })
+        .collect();
+
+    // HHI by asset, with the deal merged into any existing position of the same name
+    let pre_deal_hhi = exposures
+        .iter()
+        .map(|e| share(e.value, pre_total) * share(e.value, pre_total))
+        .sum();
+    let mut post_values: Vec<Money> = exposures
+        .iter()
+        .map(|e| {
+            if e.name == deal.name {
+                e.value + deal.investment_amount
+            } else {
+                e.value
+            }
+        })
+        .collect();
+    if !exposures.iter().any(|e| e.name == deal.name) {
+        post_values.push(deal.investment_amount);
+    }
+    let post_deal_hhi = post_values
+        .iter()
+        .map(|v| share(*v, post_total) * share(*v, post_total))
+        .sum();
+
+    ConcentrationImpact {
+        pre_deal_hhi,
+        post_deal_hhi,
+        limit_checks,
+    }
+}
+
+fn risk_impact(
+    input: &CoInvestmentInput,
+    deal_returns: &[Decimal],
+    port_returns: &[Decimal],
+) -> CorpFinanceResult<RiskImpact> {
+    if deal_returns.len() != port_returns.len() {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "deal.periodic_returns".into(),
+            reason: "Deal and portfolio return series must have the same length".into(),
+        });
+    }
+    let pre_total: Money = input.portfolio.exposures.iter().map(|e| e.value).sum();
+    let deal_weight = input.deal.investment_amount / (pre_total + input.deal.investment_amount);
+    let blended: Vec<Decimal> = port_returns
+        .iter()
+        .zip(deal_returns)
+        .map(|(p, d)| (Decimal::ONE - deal_weight) * *p + deal_weight * *d)
+        .collect();
+
+    let frequency = input
+        .portfolio
+        .return_frequency
+        .unwrap_or(ReturnFrequency::Quarterly);
+    let metrics = |returns: Vec<Decimal>| {
+        risk::calculate_risk_metrics(&RiskMetricsInput {
+            returns,
+            frequency,
+            confidence_level: dec!(0.95),
+            portfolio_value: None,
+        })
+        .map(|o| o.result)
+    };
+    let pre_deal = metrics(port_returns.to_vec())?;
+    let post_deal = metrics(blended)?;
+
+    Ok(RiskImpact {
+        deal_weight,
+        volatility_change: post_deal.annualised_volatility - pre_deal.annualised_volatility,
+        cvar_change: post_deal.cvar - pre_deal.cvar,
+        pre_deal,
+        post_deal,
+    })
+}
+
+fn clamp_unit(x: Decimal) -> Decimal {
+    x.max(Decimal::ZERO).min(Decimal::ONE)
+}
+
+fn validate_input(input: &CoInvestmentInput) -> CorpFinanceResult<()> {
+    let deal = &input.deal;
+    if deal.investment_amount <= Decimal::ZERO {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "deal.investment_amount".into(),
+            reason: "Investment amount must be positive".into(),
+        });
+    }
+    if deal.holding_period_years <= Decimal::ZERO {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "deal.holding_period_years".into(),
+            reason: "Holding period must be positive".into(),
+        });
+    }
+    if deal.expected_gross_moic < Decimal::ZERO {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "deal.expected_gross_moic".into(),
+            reason: "Gross MOIC cannot be negative".into(),
+        });
+    }
+    for (field, v) in [
+        ("deal.management_fee", deal.management_fee),
+        ("deal.carried_interest", deal.carried_interest),
+        ("fund_terms.management_fee", input.fund_terms.management_fee),
+        (
+            "fund_terms.carried_interest",
+            input.fund_terms.carried_interest,
+        ),
+    ] {
+        if v < Decimal::ZERO || v >= Decimal::ONE {
+            return Err(CorpFinanceError::InvalidInput {
+                field: field.into(),
+                reason: "Must be between 0 and 1".into(),
+            });
+        }
+    }
+    for (field, v) in [
+        (
+            "policy.max_single_asset_pct",
+            input.policy.max_single_asset_pct,
+        ),
+        ("policy.max_sector_pct", input.policy.max_sector_pct),
+        ("policy.max_geography_pct", input.policy.max_geography_pct),
+    ] {
+        if v <= Decimal::ZERO || v > Decimal::ONE {
+            return Err(CorpFinanceError::InvalidInput {
+                field: field.into(),
+                reason: "Limit must be in (0, 1]".into(),
+            });
+        }
+    }
+    if input
+        .policy
+        .target_net_irr
+        .is_some_and(|t| t <= Decimal::ZERO)
+    {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "policy.target_net_irr".into(),
+            reason: "Target net IRR must be positive".into(),
+        });
+    }
+    if input
+        .portfolio
+        .exposures
+        .iter()
+        .any(|e| e.value < Decimal::ZERO)
+    {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "portfolio.exposures".into(),
+            reason: "Exposure values cannot be negative".into(),
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn exposure(name: &str, sector: &str, geo: &str, value: Decimal) -> LpExposure {
+        LpExposure {
+            name: name.into(),
+            sector: sector.into(),
+            geography: geo.into(),
+            value,
+        }
+    }
+
+    fn base_input() -> CoInvestmentInput {
+        CoInvestmentInput {
+            deal: CoInvestmentDeal {
+                name: "TargetCo".into(),
+                sector: "Healthcare".into(),
+                geography: "US".into(),
+                investment_amount: dec!(10),
+                expected_gross_moic: dec!(2.5),
+                holding_period_years: dec!(5),
+                management_fee: Decimal::ZERO,
+                carried_interest: Decimal::ZERO,
+                periodic_returns: None,
+            },
+            fund_terms: FundTerms {
+                management_fee: dec!(0.02),
+                carried_interest: dec!(0.20),
+                hurdle_rate: dec!(0.08),
+            },
+            portfolio: LpPortfolio {
+                exposures: vec![
+                    exposure("A", "Technology", "US", dec!(40)),
+                    exposure("B", "Healthcare", "Europe", dec!(30)),
+                    exposure("C", "Industrials", "Asia", dec!(20)),
+                ],
+                periodic_returns: None,
+                return_frequency: None,
+            },
+            policy: CoInvestmentPolicy {
+                max_single_asset_pct: dec!(0.15),
+                max_sector_pct: dec!(0.50),
+                max_geography_pct: dec!(0.60),
+                min_net_irr: Some(dec!(0.12)),
+                target_net_irr: None,
+            },
+        }
+    }
+
+    #[test]
+    fn test_fee_economics() {
+        let out = evaluate_co_investment(&base_input()).unwrap().result;
+        let fund = &out.economics.via_fund;
+        // Fees 10 * 2% * 5 = 1; profit 25 - 1 - 10 = 14; carry 2.8
+        assert_eq!(fund.management_fees, dec!(1.0));
+        assert_eq!(fund.carried_interest, dec!(2.8));
+        assert_eq!(fund.net_proceeds, dec!(21.2));
+        let co = &out.economics.co_investment;
+        assert_eq!(co.net_proceeds, dec!(25.0));
+        assert_eq!(out.economics.fee_savings, dec!(3.8));
+        assert!(out.economics.net_irr_uplift > dec!(0.03));
+    }
+
+    #[test]
+    fn test_no_carry_below_hurdle() {
+        let mut input = base_input();
+        input.deal.expected_gross_moic = dec!(1.3);
+        let out = evaluate_co_investment(&input).unwrap().result;
+        assert_eq!(out.economics.via_fund.carried_interest, Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_concentration_limits() {
+        let out = evaluate_co_investment(&base_input()).unwrap().result;
+        let sector = out
+            .concentration
+            .limit_checks
+            .iter()
+            .find(|c| c.dimension == "sector")
+            .unwrap();
+        // (30 + 10) / 100
+        assert_eq!(sector.post_deal_pct, dec!(0.4));
+        assert!(!sector.breached);
+        let geo = &out.concentration.limit_checks[2];
+        assert_eq!(geo.post_deal_pct, dec!(0.5));
+        assert!(out.concentration.post_deal_hhi < out.concentration.pre_deal_hhi);
+    }
+
+    #[test]
+    fn test_proceed_decision() {
+        let out = evaluate_co_investment(&base_input()).unwrap().result;
+        assert!(out.policy_breaches.is_empty());
+        assert_eq!(out.decision, CoInvestmentDecision::Proceed);
+        assert!(out.score.total >= dec!(60));
+    }
+
+    #[test]
+    fn test_single_asset_breach_declines() {
+        let mut input = base_input();
+        input.deal.investment_amount = dec!(30);
+        let out = evaluate_co_investment(&input).unwrap().result;
+        assert_eq!(out.decision, CoInvestmentDecision::Decline);
+        assert!(out.policy_breaches[0].contains("single_asset"));
+    }
+
+    #[test]
+    fn test_min_irr_breach_declines() {
+        let mut input = base_input();
+        input.deal.expected_gross_moic = dec!(1.2);
+        let out = evaluate_co_investment(&input).unwrap().result;
+        assert_eq!(out.decision, CoInvestmentDecision::Decline);
+        assert!(out.policy_breaches.iter().any(|b| b.contains("Net IRR")));
+    }
+
+    #[test]
+    fn test_risk_impact_reuses_risk_metrics() {
+        let mut input = base_input();
+        input.portfolio.periodic_returns = Some(vec![
+            dec!(0.02),
+            dec!(-0.01),
+            dec!(0.03),
+            dec!(0.01),
+            dec!(-0.02),
+        ]);
+        input.deal.periodic_returns = Some(vec![
+            dec!(0.08),
+            dec!(-0.06),
+            dec!(0.10),
+            dec!(0.02),
+            dec!(-0.07),
+        ]);
+        let out = evaluate_co_investment(&input).unwrap();
+        let risk = out.result.risk_impact.unwrap();
+        assert_eq!(risk.deal_weight, dec!(0.1));
+        assert!(risk.volatility_change > Decimal::ZERO);
+        assert!(out.warnings.iter().any(|w| w.contains("volatility")));
+    }
+
+    #[test]
+    fn test_mismatched_return_series_rejected() {
+        let mut input = base_input();
+        input.portfolio.periodic_returns = Some(vec![dec!(0.01); 5]);
+        input.deal.periodic_returns = Some(vec![dec!(0.01); 4]);
+        assert!(evaluate_co_investment(&input).is_err());
+    }
+
+    #[test]
+    fn test_invalid_amount() {
+        let mut input = base_input();
+        input.deal.investment_amount = Decimal::ZERO;
+        assert!(evaluate_co_investment(&input).is_err());
+    }
+}
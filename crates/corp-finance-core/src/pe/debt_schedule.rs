@@ -2,6 +2,7 @@ use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use std::time::Instant;
 
+use crate::amortization::{self, AmortizationInput, AmortizationMethod};
 use crate::error::CorpFinanceError;
 use crate::types::*;
 use crate::CorpFinanceResult;
@@ -79,6 +80,7 @@ pub fn build_debt_schedule(
             reason: "Maturity must be at least 1 year".into(),
         });
     }
+    crate::limits::check_schedule_periods("maturity_years", input.maturity_years.into())?;
 
     // Determine the effective interest rate
     let effective_rate = if input.is_floating {
@@ -95,91 +97,47 @@ pub fn build_debt_schedule(
         input.interest_rate
     };
 
-    let pik_rate = input.pik_rate.unwrap_or(Decimal::ZERO);
-    let original_amount = input.amount;
-
-    let mut periods = Vec::with_capacity(input.maturity_years as usize);
-    let mut balance = input.amount;
-    let mut total_interest_paid = Decimal::ZERO;
-    let mut total_principal_paid = Decimal::ZERO;
-
-    for year in 1..=input.maturity_years {
-        let opening = balance;
-
-        // PIK interest: capitalised onto the balance
-        let pik_interest = opening * pik_rate;
-
-        // Cash interest on opening balance (before PIK addition)
-        let interest = opening * effective_rate;
-        total_interest_paid += interest;
-
-        // Add PIK to balance before repayment calculation
-        balance += pik_interest;
-
-        // Calculate scheduled repayment
-        let repayment = match &input.amortisation {
-            AmortisationType::Bullet => {
-                if year == input.maturity_years {
-                    balance
-                } else {
-                    Decimal::ZERO
-                }
-            }
-            AmortisationType::StraightLine(pct) => {
-                let annual = original_amount * pct;
-                if year == input.maturity_years {
-                    // Final year: repay remaining balance
-                    balance
-                } else {
-                    annual.min(balance)
-                }
+    let method = match &input.amortisation {
+        AmortisationType::Bullet => AmortizationMethod::Bullet,
+        AmortisationType::StraightLine(pct) => AmortizationMethod::StraightLine(*pct),
+        AmortisationType::Custom(schedule) => AmortizationMethod::Custom(schedule.clone()),
+        AmortisationType::CashSweep(_pct) => {
+            // Cash sweep requires external cash flow data;
+            // for now treat as bullet with a warning
+            for year in 1..input.maturity_years {
+                warnings.push(format!(
+                    "Year {year}: CashSweep amortisation requires external cash flows; treated as bullet"
+                ));
             }
-            AmortisationType::Custom(schedule) => {
-                let idx = (year - 1) as usize;
-                if idx < schedule.len() {
-                    if year == input.maturity_years {
-                        balance
-                    } else {
-                        schedule[idx].min(balance)
-                    }
-                } else if year == input.maturity_years {
-                    balance
-                } else {
-                    Decimal::ZERO
-                }
-            }
-            AmortisationType::CashSweep(_pct) => {
-                // Cash sweep requires external cash flow data;
-                // for now treat as bullet with a warning
-                if year == input.maturity_years {
-                    balance
-                } else {
-                    warnings.push(format!(
-                        "Year {year}: CashSweep amortisation requires external cash flows; treated as bullet"
-                    ));
-                    Decimal::ZERO
-                }
-            }
-        };
-
-        balance -= repayment;
-        total_principal_paid += repayment;
-
-        periods.push(DebtPeriod {
-            year,
-            opening_balance: opening,
-            interest,
-            pik_interest,
-            scheduled_repayment: repayment,
-            closing_balance: balance,
-        });
-    }
+            AmortizationMethod::Bullet
+        }
+    };
+    let schedule = amortization::build_schedule(&AmortizationInput {
+        principal: input.amount,
+        rate: effective_rate,
+        pik_rate: input.pik_rate.unwrap_or(Decimal::ZERO),
+        periods: input.maturity_years,
+        method,
+    })?;
+
+    let periods: Vec<DebtPeriod> = schedule
+        .periods
+        .iter()
+        .map(|p| DebtPeriod {
+            year: p.period,
+            opening_balance: p.opening_balance,
+            interest: p.interest,
+            pik_interest: p.pik_interest,
+            scheduled_repayment: p.principal,
+            closing_balance: p.closing_balance,
+        })
+        .collect();
 
     let output = DebtScheduleOutput {
         tranche_name: input.name.clone(),
         periods,
-        total_interest_paid,
-        total_principal_paid,
+        total_interest_paid: schedule.total_interest,
+        total_principal_paid: schedule.total_principal,
     };
 
     let elapsed = start.elapsed().as_micros() as u64;
@@ -0,0 +1,592 @@
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+use std::time::Instant;
+
+use crate::error::CorpFinanceError;
+use crate::types::*;
+use crate::CorpFinanceResult;
+
+/// A listed comparable and its share price return since the last report
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PublicCompMovement {
+    pub name: String,
+    /// Total return from the last report date to the estimate date
+    pub return_since_report: Rate,
+    /// Relative weight in the comp basket (default: equal weight)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub weight: Option<Decimal>,
+}
+
+/// A known event between the report date and the estimate date
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum ValuationEvent {
+    /// Priced financing round: the holding is re-marked to the implied value
+    /// of the stake, rolled forward by comp returns after the round
+    NewRound {
+        date: NaiveDate,
+        /// Value of the fund's stake implied by the round price (local currency)
+        implied_value: Money,
+        /// Comp basket return from the round date to the estimate date
+        #[serde(default)]
+        market_return_since: Rate,
+    },
+    /// Full realisation: the stake leaves the portfolio and proceeds are
+    /// held as cash until distributed
+    Exit {
+        date: NaiveDate,
+        /// Net proceeds to the fund (local currency)
+        proceeds: Money,
+    },
+    /// Write-down of the stake by a fraction of its current value
+    Impairment {
+        date: NaiveDate,
+        write_down_pct: Rate,
+    },
+}
+
+impl ValuationEvent {
+    fn date(&self) -> NaiveDate {
+        match self {
+            ValuationEvent::NewRound { date, .. }
+            | ValuationEvent::Exit { date, .. }
+            | ValuationEvent::Impairment { date, .. } => *date,
+        }
+    }
+}
+
+/// A portfolio holding carried at the last reported mark
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InterimHolding {
+    pub name: String,
+    /// Currency the holding is valued in
+    #[serde(default)]
+    pub currency: Currency,
+    /// Fair value of the fund's stake at the last report (local currency)
+    pub reported_value: Money,
+    /// Listed comparables used to roll the mark forward
+    #[serde(default)]
+    pub public_comps: Vec<PublicCompMovement>,
+    /// Sensitivity of the holding's equity value to the comp basket
+    /// (default 1.0)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub beta: Option<Decimal>,
+    /// Known events since the last report
+    #[serde(default)]
+    pub events: Vec<ValuationEvent>,
+}
+
+/// Input for an interim NAV estimate between GP reporting dates
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InterimNavInput {
+    /// Date of the last GP-reported NAV
+    pub report_date: NaiveDate,
+    /// Date the NAV is estimated for (e.g. quarter end)
+    pub estimate_date: NaiveDate,
+    /// Currency the NAV is reported in
+    #[serde(default)]
+    pub reporting_currency: Currency,
+    pub holdings: Vec<InterimHolding>,
+    /// Cash and other net assets at the report date (reporting currency)
+    #[serde(default)]
+    pub other_net_assets: Money,
+    /// Capital called from LPs since the report date
+    #[serde(default)]
+    pub capital_calls: Money,
+    /// Distributions paid to LPs since the report date
+    #[serde(default)]
+    pub distributions: Money,
+    /// FX quotes at the report date
+    #[serde(default)]
+    pub fx_rates_at_report: Vec<FxRate>,
+    /// FX quotes at the estimate date
+    #[serde(default)]
+    pub fx_rates_at_estimate: Vec<FxRate>,
+}
+
+/// Roll-forward of a single holding (reporting currency)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HoldingBridge {
+    pub name: String,
+    pub currency: Currency,
+    /// Reported value converted at the report-date FX rate
+    pub reported_value: Money,
+    /// Weighted comp basket return used for the roll-forward
+    pub comp_return: Rate,
+    /// Change from public market movement
+    pub market_movement: Money,
+    /// Change from new rounds, exits and impairments
+    pub event_impact: Money,
+    /// Change from FX between the two dates
+    pub fx_impact: Money,
+    /// Estimated value of the stake still held
+    pub estimated_value: Money,
+    /// Exit proceeds now held as cash
+    pub realised_proceeds: Money,
+    /// Events applied, in date order
+    pub events_applied: Vec<String>,
+}
+
+/// Contribution of each driver to the NAV change
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NavContributions {
+    pub market_movement: Money,
+    pub events: Money,
+    pub fx: Money,
+    pub capital_calls: Money,
+    /// Negative: distributions reduce NAV
+    pub distributions: Money,
+}
+
+/// Output of the interim NAV estimate
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InterimNavOutput {
+    /// Last GP-reported NAV
+    pub reported_nav: Money,
+    /// Estimated NAV at the estimate date
+    pub interim_nav: Money,
+    pub nav_change: Money,
+    pub nav_change_pct: Rate,
+    pub contributions: NavContributions,
+    pub holdings: Vec<HoldingBridge>,
+    /// Days between the report date and the estimate date
+    pub days_rolled_forward: i64,
+}
+
+/// Roll a private portfolio NAV forward from the last GP report using public
+/// comp movements, FX and known events.
+///
+/// Each holding is moved by beta x its comp basket return, then events are
+/// applied in date order in local currency. The FX effect is the adjusted
+/// local value (plus any exit proceeds) times the change in the conversion
+/// rate, so the bridge sums exactly to the interim NAV.
+pub fn estimate_interim_nav(
+    input: &InterimNavInput,
+) -> CorpFinanceResult<ComputationOutput<InterimNavOutput>> {
+    let start = Instant::now();
+    let mut warnings: Vec<String> = Vec::new();
+
+    validate_input(input)?;
+
+    let days = (input.estimate_date - input.report_date).num_days();
+    if days > 180 {
+        warnings.push(format!(
+            "Marks rolled forward {days} days; comp-based estimates lose reliability beyond two quarters"
+        ));
+    }
+
+    let mut holdings = Vec::with_capacity(input.holdings.len());
+    for h in &input.holdings {
+        let fx_report = fx_conversion_rate(
+            &h.currency,
+            &input.reporting_currency,
+            &input.fx_rates_at_report,
+        )?;
+        let fx_estimate = fx_conversion_rate(
+            &h.currency,
+            &input.reporting_currency,
+            &input.fx_rates_at_estimate,
+        )?;
+
+        let beta = h.beta.unwrap_or(Decimal::ONE);
+        if beta > dec!(3) {
+            warnings.push(format!("{}: beta {beta} is unusually high", h.name));
+        }
+        let comp_return = basket_return(&h.public_comps);
+        if h.public_comps.is_empty() && h.events.is_empty() {
+            warnings.push(format!(
+                "{}: no comps or events, carried at the reported mark",
+                h.name
+            ));
+        }
+
+        // 1. Public market movement (local currency)
+        let mut value = h.reported_value * (Decimal::ONE + beta * comp_return);
+        let market_local = value - h.reported_value;
+
+        // 2. Known events in date order
+        let mut events: Vec<&ValuationEvent> = h.events.iter().collect();
+        events.sort_by_key(|e| e.date());
+        let mut event_local = Decimal::ZERO;
+        let mut proceeds = Decimal::ZERO;
+        let mut events_applied = Vec::with_capacity(events.len());
+        for e in events {
+            let before = value;
+            let mut realised = Decimal::ZERO;
+            match e {
+                ValuationEvent::NewRound {
+                    date,
+                    implied_value,
+                    market_return_since,
+                } => {
+                    value = *implied_value * (Decimal::ONE + beta * *market_return_since);
+                    events_applied.push(format!("{date}: new round"));
+                }
+                ValuationEvent::Exit { date, proceeds: p } => {
+                    realised = *p;
+                    proceeds += *p;
+                    value = Decimal::ZERO;
+                    events_applied.push(format!("{date}: exit"));
+                }
+                ValuationEvent::Impairment {
+                    date,
+                    write_down_pct,
+                } => {
+                    value *= Decimal::ONE - *write_down_pct;
+                    events_applied.push(format!("{date}: impairment"));
+                }
+            }
+            event_local += value + realised - before;
+        }
+
+        // 3. Translate into the reporting currency
+        let market_movement = market_local * fx_report;
+        let event_impact = event_local * fx_report;
+        let fx_impact = (value + proceeds) * (fx_estimate - fx_report);
+
+        holdings.push(HoldingBridge {
+            name: h.name.clone(),
+            currency: h.currency.clone(),
+            reported_value: h.reported_value * fx_report,
+            comp_return,
+            market_movement,
+            event_impact,
+            fx_impact,
+            estimated_value: value * fx_estimate,
+            realised_proceeds: proceeds * fx_estimate,
+            events_applied,
+        });
+    }
+
+    let reported_nav =
+        holdings.iter().map(|h| h.reported_value).sum::<Money>() + input.other_net_assets;
+    let contributions = NavContributions {
+        market_movement: holdings.iter().map(|h| h.market_movement).sum(),
+        events: holdings.iter().map(|h| h.event_impact).sum(),
+        fx: holdings.iter().map(|h| h.fx_impact).sum(),
+        capital_calls: input.capital_calls,
+        distributions: -input.distributions,
+    };
+    let nav_change = contributions.market_movement
+        + contributions.events
+        + contributions.fx
+        + contributions.capital_calls
+        + contributions.distributions;
+    let interim_nav = reported_nav + nav_change;
+    let nav_change_pct = if reported_nav.is_zero() {
+        Decimal::ZERO
+    } else {
+        nav_change / reported_nav
+    };
+
+    if interim_nav < Decimal::ZERO {
+        warnings.push("Interim NAV is negative; check distributions and events".into());
+    }
+
+    let output = InterimNavOutput {
+        reported_nav,
+        interim_nav,
+        nav_change,
+        nav_change_pct,
+        contributions,
+        holdings,
+        days_rolled_forward: days,
+    };
+
+    let elapsed = start.elapsed().as_micros() as u64;
+    Ok(with_metadata(
+        "Interim NAV Bridge (public comp roll-forward)",
+        &serde_json::json!({
+            "report_date": input.report_date.to_string(),
+            "estimate_date": input.estimate_date.to_string(),
+            "reporting_currency": input.reporting_currency,
+            "holdings": input.holdings.len(),
+            "event_ordering": "market movement, then events by date",
+        }),
+        warnings,
+        elapsed,
+        output,
+    ))
+}
+
+/// Weighted average comp return; equal weights when none are given.
+fn basket_return(comps: &[PublicCompMovement]) -> Rate {
+    if comps.is_empty() {
+        return Decimal::ZERO;
+    }
+    let weights: Vec<Decimal> = comps
+        .iter()
+        .map(|c| c.weight.unwrap_or(Decimal::ONE))
+        .collect();
+    let total: Decimal = weights.iter().copied().sum();
+    if total.is_zero() {
+        return Decimal::ZERO;
+    }
+    comps
+        .iter()
+        .zip(&weights)
+        .map(|(c, w)| c.return_since_report * *w)
+        .sum::<Decimal>()
+        / total
+}
+
+fn validate_input(input: &InterimNavInput) -> CorpFinanceResult<()> {
+    if input.estimate_date < input.report_date {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "estimate_date".into(),
+            reason: "Estimate date cannot precede the report date".into(),
+        });
+    }
+    if input.holdings.is_empty() {
+        return Err(CorpFinanceError::InsufficientData(
+            "At least one holding is required".into(),
+        ));
+    }
+    if input.capital_calls < Decimal::ZERO || input.distributions < Decimal::ZERO {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "capital_calls".into(),
+            reason: "Capital calls and distributions must be non-negative".into(),
+        });
+    }
+    for h in &input.holdings {
+        if h.reported_value < Decimal::ZERO {
+            return Err(CorpFinanceError::InvalidInput {
+                field: format!("holdings[{}].reported_value", h.name),
+                reason: "Reported value cannot be negative".into(),
+            });
+        }
+        if h.public_comps
+            .iter()
+            .any(|c| c.return_since_report <= dec!(-1))
+        {
+            return Err(CorpFinanceError::InvalidInput {
+                field: format!("holdings[{}].public_comps", h.name),
+                reason: "Comp returns must be above -100%".into(),
+            });
+        }
+        if h.public_comps
+            .iter()
+            .any(|c| c.weight.is_some_and(|w| w < Decimal::ZERO))
+        {
+            return Err(CorpFinanceError::InvalidInput {
+                field: format!("holdings[{}].public_comps", h.name),
+                reason: "Comp weights cannot be negative".into(),
+            });
+        }
+        if h.beta.is_some_and(|b| b < Decimal::ZERO) {
+            return Err(CorpFinanceError::InvalidInput {
+                field: format!("holdings[{}].beta", h.name),
+                reason: "Beta cannot be negative".into(),
+            });
+        }
+        for e in &h.events {
+            let date = e.date();
+            if date < input.report_date || date > input.estimate_date {
+                return Err(CorpFinanceError::InvalidInput {
+                    field: format!("holdings[{}].events", h.name),
+                    reason: format!("Event on {date} falls outside the roll-forward window"),
+                });
+            }
+            match e {
+                ValuationEvent::NewRound { implied_value, .. }
+                    if *implied_value < Decimal::ZERO =>
+                {
+                    return Err(CorpFinanceError::InvalidInput {
+                        field: format!("holdings[{}].events", h.name),
+                        reason: "Round implied value cannot be negative".into(),
+                    });
+                }
+                ValuationEvent::Exit { proceeds, .. } if *proceeds < Decimal::ZERO => {
+                    return Err(CorpFinanceError::InvalidInput {
+                        field: format!("holdings[{}].events", h.name),
+                        reason: "Exit proceeds cannot be negative".into(),
+                    });
+                }
+                ValuationEvent::Impairment { write_down_pct, .. }
+                    if *write_down_pct < Decimal::ZERO || *write_down_pct > Decimal::ONE =>
+                {
+                    return Err(CorpFinanceError::InvalidInput {
+                        field: format!("holdings[{}].events", h.name),
+                        reason: "Write-down must be between 0 and 1".into(),
+                    });
+                }
+                _ => {}
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn comp(name: &str, r: Decimal) -> PublicCompMovement {
+        PublicCompMovement {
+            name: name.into(),
+            return_since_report: r,
+            weight: None,
+        }
+    }
+
+    fn holding(name: &str, value: Decimal) -> InterimHolding {
+        InterimHolding {
+            name: name.into(),
+            currency: Currency::USD,
+            reported_value: value,
+            public_comps: vec![comp("A", dec!(0.10)), comp("B", dec!(0.02))],
+            beta: None,
+            events: vec![],
+        }
+    }
+
+    fn base_input() -> InterimNavInput {
+        InterimNavInput {
+            report_date: date(2025, 6, 30),
+            estimate_date: date(2025, 9, 30),
+            reporting_currency: Currency::USD,
+            holdings: vec![holding("Alpha", dec!(100))],
+            other_net_assets: dec!(10),
+            capital_calls: Decimal::ZERO,
+            distributions: Decimal::ZERO,
+            fx_rates_at_report: vec![],
+            fx_rates_at_estimate: vec![],
+        }
+    }
+
+    #[test]
+    fn test_market_roll_forward() {
+        let out = estimate_interim_nav(&base_input()).unwrap().result;
+        // Equal-weight basket return 6%
+        assert_eq!(out.holdings[0].comp_return, dec!(0.06));
+        assert_eq!(out.contributions.market_movement, dec!(6));
+        assert_eq!(out.reported_nav, dec!(110));
+        assert_eq!(out.interim_nav, dec!(116));
+        assert_eq!(out.days_rolled_forward, 92);
+    }
+
+    #[test]
+    fn test_beta_and_weights() {
+        let mut input = base_input();
+        input.holdings[0].beta = Some(dec!(1.5));
+        input.holdings[0].public_comps[0].weight = Some(dec!(3));
+        let out = estimate_interim_nav(&input).unwrap().result;
+        // Basket (0.30 + 0.02) / 4 = 0.08, x 1.5 = 12%
+        assert_eq!(out.holdings[0].comp_return, dec!(0.08));
+        assert_eq!(out.contributions.market_movement, dec!(12));
+    }
+
+    #[test]
+    fn test_new_round_remarks_holding() {
+        let mut input = base_input();
+        input.holdings[0].events = vec![ValuationEvent::NewRound {
+            date: date(2025, 8, 15),
+            implied_value: dec!(150),
+            market_return_since: dec!(0.02),
+        }];
+        let out = estimate_interim_nav(&input).unwrap().result;
+        let h = &out.holdings[0];
+        assert_eq!(h.estimated_value, dec!(153));
+        // Market moved it to 106, the round explains the rest
+        assert_eq!(h.market_movement, dec!(6));
+        assert_eq!(h.event_impact, dec!(47));
+        assert_eq!(out.interim_nav, dec!(163));
+    }
+
+    #[test]
+    fn test_exit_moves_value_to_cash() {
+        let mut input = base_input();
+        input.holdings.push(holding("Beta", dec!(50)));
+        input.holdings[1].events = vec![ValuationEvent::Exit {
+            date: date(2025, 7, 31),
+            proceeds: dec!(70),
+        }];
+        input.distributions = dec!(70);
+        let out = estimate_interim_nav(&input).unwrap().result;
+        let beta = &out.holdings[1];
+        assert_eq!(beta.estimated_value, Decimal::ZERO);
+        assert_eq!(beta.realised_proceeds, dec!(70));
+        // 50 -> 53 from market, then a 17 realised gain
+        assert_eq!(beta.event_impact, dec!(17));
+        assert_eq!(out.contributions.distributions, dec!(-70));
+        // 160 reported + 6 + 3 + 17 - 70
+        assert_eq!(out.interim_nav, dec!(116));
+    }
+
+    #[test]
+    fn test_events_applied_in_date_order() {
+        let mut input = base_input();
+        input.holdings[0].public_comps.clear();
+        input.holdings[0].events = vec![
+            ValuationEvent::Impairment {
+                date: date(2025, 9, 1),
+                write_down_pct: dec!(0.5),
+            },
+            ValuationEvent::NewRound {
+                date: date(2025, 7, 15),
+                implied_value: dec!(80),
+                market_return_since: Decimal::ZERO,
+            },
+        ];
+        let out = estimate_interim_nav(&input).unwrap().result;
+        assert_eq!(out.holdings[0].estimated_value, dec!(40));
+        assert!(out.holdings[0].events_applied[0].contains("new round"));
+    }
+
+    #[test]
+    fn test_fx_contribution() {
+        let mut input = base_input();
+        input.holdings[0].currency = Currency::EUR;
+        input.holdings[0].public_comps = vec![comp("A", dec!(0.10))];
+        input.fx_rates_at_report = vec![FxRate {
+            base: Currency::EUR,
+            quote: Currency::USD,
+            rate: dec!(1.10),
+        }];
+        input.fx_rates_at_estimate = vec![FxRate {
+            base: Currency::EUR,
+            quote: Currency::USD,
+            rate: dec!(1.20),
+        }];
+        let out = estimate_interim_nav(&input).unwrap().result;
+        let h = &out.holdings[0];
+        assert_eq!(h.reported_value, dec!(110));
+        assert_eq!(h.market_movement, dec!(11));
+        assert_eq!(h.fx_impact, dec!(11));
+        assert_eq!(h.estimated_value, dec!(132));
+        // Bridge reconciles
+        assert_eq!(out.interim_nav, out.reported_nav + out.nav_change);
+        assert_eq!(out.interim_nav, dec!(142));
+    }
+
+    #[test]
+    fn test_missing_fx_rate_errors() {
+        let mut input = base_input();
+        input.holdings[0].currency = Currency::GBP;
+        assert!(estimate_interim_nav(&input).is_err());
+    }
+
+    #[test]
+    fn test_stale_marks_warning() {
+        let mut input = base_input();
+        input.estimate_date = date(2026, 3, 31);
+        let out = estimate_interim_nav(&input).unwrap();
+        assert!(out.warnings.iter().any(|w| w.contains("rolled forward")));
+    }
+
+    #[test]
+    fn test_event_outside_window_rejected() {
+        let mut input = base_input();
+        input.holdings[0].events = vec![ValuationEvent::Exit {
+            date: date(2025, 10, 15),
+            proceeds: dec!(120),
+        }];
+        assert!(estimate_interim_nav(&input).is_err());
+    }
+}
@@ -67,6 +67,54 @@ pub struct LboInput {
     /// Minimum cash balance to maintain before optional repayments
     #[serde(skip_serializing_if = "Option::is_none")]
     pub minimum_cash: Option<Money>,
+    /// Revolving credit facility drawn when cash falls below `minimum_cash`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub revolver: Option<RevolverInput>,
+    /// Leverage-based excess cash flow sweep; replaces `cash_sweep_pct` when given
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sweep_tiers: Vec<CashSweepTier>,
+    /// Share of cash above `minimum_cash` voluntarily prepaid after the sweep
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub optional_prepayment_pct: Option<Rate>,
+    /// PIK toggle elections by tranche
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub pik_toggles: Vec<PikToggle>,
+}
+
+/// Revolving credit facility funding cash shortfalls.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RevolverInput {
+    /// Total commitment
+    pub commitment: Money,
+    /// All-in cash interest rate on the drawn balance
+    pub interest_rate: Rate,
+    /// Fee on the undrawn commitment
+    #[serde(default)]
+    pub commitment_fee: Rate,
+    /// Amount drawn at close (counted as a debt source)
+    #[serde(default)]
+    pub initial_draw: Money,
+}
+
+/// One step of a leverage-based excess cash flow sweep.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CashSweepTier {
+    /// Tier applies when opening total debt / EBITDA is at or above this level
+    pub min_leverage: Multiple,
+    /// Share of excess cash flow swept to prepay debt
+    pub sweep_pct: Rate,
+}
+
+/// PIK toggle election for a tranche.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PikToggle {
+    /// Tranche name (must match one of `tranches`)
+    pub tranche: String,
+    /// Years (1-based) in which interest is paid in kind instead of cash
+    pub pik_years: Vec<u32>,
+    /// Premium over the cash rate while paying in kind (e.g. 0.0075)
+    #[serde(default)]
+    pub pik_premium: Rate,
 }
 
 /// Full LBO model output
@@ -103,7 +151,13 @@ pub struct LboYearProjection {
     pub revenue: Money,
     pub ebitda: Money,
     pub ebit: Money,
+    /// Total interest expense (cash plus PIK)
     pub less_interest: Money,
+    /// Interest paid in cash, including revolver interest and commitment fees
+    pub cash_interest: Money,
+    /// Interest capitalised onto tranche balances
+    pub pik_interest: Money,
+    pub commitment_fee: Money,
     pub ebt: Money,
     pub tax: Money,
     pub net_income: Money,
@@ -112,7 +166,19 @@ pub struct LboYearProjection {
     pub less_nwc_change: Money,
     pub fcf_before_debt_service: Money,
     pub mandatory_repayment: Money,
+    /// Sweep plus voluntary prepayments
     pub optional_repayment: Money,
+    /// Excess cash flow swept under `cash_sweep_pct` or `sweep_tiers`
+    pub sweep_repayment: Money,
+    pub voluntary_prepayment: Money,
+    /// Sweep percentage applied this year
+    pub sweep_pct_applied: Rate,
+    pub revolver_draw: Money,
+    pub revolver_repayment: Money,
+    pub revolver_balance: Money,
+    /// Closing balance per tranche, in input order
+    pub tranche_balances: Vec<Money>,
+    /// Tranche and revolver balances
     pub total_debt_outstanding: Money,
     pub net_debt: Money,
     pub cash_balance: Money,
@@ -131,10 +197,40 @@ fn get_rate(rates: &[Rate], index: usize) -> Rate {
     }
 }
 
+/// Cash coupon for a tranche: base rate plus spread when floating.
+pub(crate) fn tranche_cash_rate(tranche: &DebtTrancheInput) -> Rate {
+    if tranche.is_floating {
+        tranche.base_rate.unwrap_or(Decimal::ZERO) + tranche.spread.unwrap_or(tranche.interest_rate)
+    } else {
+        tranche.interest_rate
+    }
+}
+
+/// Apply a prepayment across tranches in the given order, returning the
+/// amount actually applied.
+fn prepay(amount: Money, balances: &mut [Money], order: &[usize]) -> Money {
+    let mut remaining = amount;
+    for &idx in order {
+        if remaining <= Decimal::ZERO {
+            break;
+        }
+        let paydown = remaining.min(balances[idx]);
+        balances[idx] -= paydown;
+        remaining -= paydown;
+    }
+    amount - remaining
+}
+
 /// Build a complete LBO model from entry through exit.
 ///
 /// This is the top-level orchestrator that ties together sources & uses,
 /// debt schedules, operating projections, and exit/returns calculations.
+#[tracing::instrument(
+    level = "debug",
+    name = "lbo",
+    skip_all,
+    fields(tranches = input.tranches.len(), exit_year = input.exit_year)
+)]
 pub fn build_lbo(input: &LboInput) -> CorpFinanceResult<ComputationOutput<LboOutput>> {
     let start = Instant::now();
     let mut warnings: Vec<String> = Vec::new();
@@ -158,6 +254,7 @@ pub fn build_lbo(input: &LboInput) -> CorpFinanceResult<ComputationOutput<LboOut
             reason: "Exit year must be at least 1".into(),
         });
     }
+    crate::limits::check_schedule_periods("exit_year", input.exit_year.into())?;
     if input.tranches.is_empty() {
         return Err(CorpFinanceError::InvalidInput {
             field: "tranches".into(),
@@ -183,12 +280,63 @@ pub fn build_lbo(input: &LboInput) -> CorpFinanceResult<ComputationOutput<LboOut
         });
     }
 
+    if let Some(r) = &input.revolver {
+        if r.commitment <= Decimal::ZERO {
+            return Err(CorpFinanceError::InvalidInput {
+                field: "revolver.commitment".into(),
+                reason: "Revolver commitment must be positive".into(),
+            });
+        }
+        if r.initial_draw < Decimal::ZERO || r.initial_draw > r.commitment {
+            return Err(CorpFinanceError::InvalidInput {
+                field: "revolver.initial_draw".into(),
+                reason: "Initial draw must be between zero and the commitment".into(),
+            });
+        }
+    }
+    for tier in &input.sweep_tiers {
+        if tier.sweep_pct < Decimal::ZERO || tier.sweep_pct > Decimal::ONE {
+            return Err(CorpFinanceError::InvalidInput {
+                field: "sweep_tiers".into(),
+                reason: "Sweep percentages must be between 0 and 1".into(),
+            });
+        }
+    }
+    let toggles: Vec<Option<&PikToggle>> = input
+        .tranches
+        .iter()
+        .map(|t| input.pik_toggles.iter().find(|pt| pt.tranche == t.name))
+        .collect();
+    if let Some(unknown) = input
+        .pik_toggles
+        .iter()
+        .find(|pt| !input.tranches.iter().any(|t| t.name == pt.tranche))
+    {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "pik_toggles".into(),
+            reason: format!("No tranche named '{}'", unknown.tranche),
+        });
+    }
+    for t in input.tranches.iter().filter(|t| t.is_revolver) {
+        warnings.push(format!(
+            "Tranche '{}' is flagged as a revolver but modelled as term debt; use `revolver` to track draws and commitment fees",
+            t.name
+        ));
+    }
+
     // ─── Sources & Uses ──────────────────────────────────────────────
-    let debt_tranches_for_su: Vec<(String, Money)> = input
+    let mut debt_tranches_for_su: Vec<(String, Money)> = input
         .tranches
         .iter()
         .map(|t| (t.name.clone(), t.amount))
         .collect();
+    if let Some(r) = input
+        .revolver
+        .as_ref()
+        .filter(|r| r.initial_draw > Decimal::ZERO)
+    {
+        debt_tranches_for_su.push(("Revolver".into(), r.initial_draw));
+    }
 
     let su_input = SourcesUsesInput {
         enterprise_value: input.entry_ev,
@@ -206,6 +354,7 @@ pub fn build_lbo(input: &LboInput) -> CorpFinanceResult<ComputationOutput<LboOut
     // ─── Debt Schedules ──────────────────────────────────────────────
     // Build each tranche schedule up to at least exit_year periods.
     // The debt_schedule module uses each tranche's own maturity_years.
+    let stage = tracing::debug_span!("debt_schedules").entered();
     let mut debt_schedule_outputs: Vec<debt_schedule::DebtScheduleOutput> = Vec::new();
     for tranche in &input.tranches {
         let ds = debt_schedule::build_debt_schedule(tranche)?;
@@ -217,10 +366,16 @@ pub fn build_lbo(input: &LboInput) -> CorpFinanceResult<ComputationOutput<LboOut
         }
     }
 
+    drop(stage);
+
     // ─── Year-by-year projection ─────────────────────────────────────
-    let total_initial_debt: Money = input.tranches.iter().map(|t| t.amount).sum();
+    let stage = tracing::debug_span!("projection").entered();
+    let revolver = input.revolver.as_ref();
+    let total_initial_debt: Money = input.tranches.iter().map(|t| t.amount).sum::<Money>()
+        + revolver.map_or(Decimal::ZERO, |r| r.initial_draw);
     let minimum_cash = input.minimum_cash.unwrap_or(Decimal::ZERO);
     let cash_sweep_pct = input.cash_sweep_pct.unwrap_or(Decimal::ZERO);
+    let optional_prepayment_pct = input.optional_prepayment_pct.unwrap_or(Decimal::ZERO);
 
     let mut projections: Vec<LboYearProjection> = Vec::with_capacity(input.exit_year as usize);
     let mut prev_revenue = input.base_revenue;
@@ -228,6 +383,16 @@ pub fn build_lbo(input: &LboInput) -> CorpFinanceResult<ComputationOutput<LboOut
 
     // Track remaining balance per tranche (independent of the static debt schedules)
     let mut tranche_balances: Vec<Money> = input.tranches.iter().map(|t| t.amount).collect();
+    let mut revolver_balance = revolver.map_or(Decimal::ZERO, |r| r.initial_draw);
+    let cash_rates: Vec<Rate> = input.tranches.iter().map(tranche_cash_rate).collect();
+
+    // Prepayments go to the most junior tranche first (highest seniority number)
+    let mut prepayment_order: Vec<usize> = (0..input.tranches.len()).collect();
+    prepayment_order.sort_by(|a, b| {
+        input.tranches[*b]
+            .seniority
+            .cmp(&input.tranches[*a].seniority)
+    });
 
     // NWC tracking: we need the *change* in NWC
     let base_nwc = input.base_revenue * input.nwc_as_pct_revenue;
@@ -250,24 +415,31 @@ pub fn build_lbo(input: &LboInput) -> CorpFinanceResult<ComputationOutput<LboOut
         // EBIT
         let ebit = ebitda - da;
 
-        // Interest: sum across all tranches for this year
-        let mut total_interest = Decimal::ZERO;
+        // Interest on opening balances. Toggled years pay the cash coupon
+        // plus the toggle premium in kind.
+        let opening_debt: Money = tranche_balances.iter().sum::<Money>() + revolver_balance;
+        let mut cash_interest = Decimal::ZERO;
+        let mut pik_accruals: Vec<Money> = Vec::with_capacity(input.tranches.len());
         for (i, tranche) in input.tranches.iter().enumerate() {
-            if yr_idx < debt_schedule_outputs[i].periods.len() {
-                total_interest += debt_schedule_outputs[i].periods[yr_idx].interest;
-            } else {
-                // Tranche matured — if balance remains (shouldn't), charge interest
-                if tranche_balances[i] > Decimal::ZERO {
-                    let eff_rate = if tranche.is_floating {
-                        tranche.base_rate.unwrap_or(Decimal::ZERO)
-                            + tranche.spread.unwrap_or(tranche.interest_rate)
-                    } else {
-                        tranche.interest_rate
-                    };
-                    total_interest += tranche_balances[i] * eff_rate;
+            let balance = tranche_balances[i];
+            let pik_rate = tranche.pik_rate.unwrap_or(Decimal::ZERO);
+            match toggles[i].filter(|t| t.pik_years.contains(&year)) {
+                Some(toggle) => {
+                    pik_accruals.push(balance * (cash_rates[i] + toggle.pik_premium + pik_rate))
+                }
+                None => {
+                    cash_interest += balance * cash_rates[i];
+                    pik_accruals.push(balance * pik_rate);
                 }
             }
         }
+        let pik_interest: Money = pik_accruals.iter().sum();
+        let commitment_fee = revolver.map_or(Decimal::ZERO, |r| {
+            (r.commitment - revolver_balance).max(Decimal::ZERO) * r.commitment_fee
+        });
+        cash_interest +=
+            revolver.map_or(Decimal::ZERO, |r| revolver_balance * r.interest_rate) + commitment_fee;
+        let total_interest = cash_interest + pik_interest;
 
         // EBT, Tax, Net Income
         let ebt = ebit - total_interest;
@@ -278,72 +450,92 @@ pub fn build_lbo(input: &LboInput) -> CorpFinanceResult<ComputationOutput<LboOut
         };
         let net_income = ebt - tax;
 
-        // Free Cash Flow before debt service
+        // Free Cash Flow before debt service (PIK is non-cash)
         let capex = revenue * input.capex_as_pct_revenue;
         let current_nwc = revenue * input.nwc_as_pct_revenue;
         let nwc_change = current_nwc - prev_nwc;
 
-        let fcf_before_debt_service = net_income + da - capex - nwc_change;
+        let fcf_before_debt_service = net_income + da - capex - nwc_change + pik_interest;
 
-        // Mandatory repayment: sum of scheduled repayments from debt schedules
+        // Capitalise PIK, then scheduled repayments from the debt schedules;
+        // anything outstanding at maturity is repaid in full
         let mut mandatory_repayment = Decimal::ZERO;
-        for (i, _tranche) in input.tranches.iter().enumerate() {
-            if yr_idx < debt_schedule_outputs[i].periods.len() {
-                let sched_repay = debt_schedule_outputs[i].periods[yr_idx].scheduled_repayment;
-                // Cap at remaining tranche balance
-                let actual_repay = sched_repay.min(tranche_balances[i]);
-                mandatory_repayment += actual_repay;
-                tranche_balances[i] -= actual_repay;
-            }
+        for (i, tranche) in input.tranches.iter().enumerate() {
+            tranche_balances[i] += pik_accruals[i];
+            let due = if year >= tranche.maturity_years {
+                tranche_balances[i]
+            } else if yr_idx < debt_schedule_outputs[i].periods.len() {
+                debt_schedule_outputs[i].periods[yr_idx].scheduled_repayment
+            } else {
+                Decimal::ZERO
+            };
+            let actual_repay = due.min(tranche_balances[i]);
+            mandatory_repayment += actual_repay;
+            tranche_balances[i] -= actual_repay;
         }
 
         // Cash available after mandatory repayment
         let fcf_after_mandatory = fcf_before_debt_service - mandatory_repayment;
-
-        // Cash sweep (optional repayment)
-        let mut optional_repayment = Decimal::ZERO;
-        if cash_sweep_pct > Decimal::ZERO && fcf_after_mandatory > Decimal::ZERO {
-            let sweep_amount = fcf_after_mandatory * cash_sweep_pct;
-            // Apply cash sweep to tranches in reverse seniority (most junior first)
-            // but ensure we don't sweep below minimum cash
-            let available_for_sweep =
-                if cash_balance + fcf_after_mandatory - sweep_amount >= minimum_cash {
-                    sweep_amount
-                } else {
-                    // Only sweep what keeps us above minimum cash
-                    let max_sweep =
-                        (cash_balance + fcf_after_mandatory - minimum_cash).max(Decimal::ZERO);
-                    max_sweep.min(sweep_amount)
-                };
-
-            let total_remaining: Money = tranche_balances.iter().sum();
-            let actual_sweep = available_for_sweep.min(total_remaining);
-
-            if actual_sweep > Decimal::ZERO {
-                // Apply to the most junior tranche first (highest seniority number)
-                let mut remaining_sweep = actual_sweep;
-                // Sort tranche indices by seniority descending
-                let mut indices: Vec<usize> = (0..input.tranches.len()).collect();
-                indices.sort_by(|a, b| {
-                    input.tranches[*b]
-                        .seniority
-                        .cmp(&input.tranches[*a].seniority)
-                });
-
-                for idx in indices {
-                    if remaining_sweep <= Decimal::ZERO {
-                        break;
-                    }
-                    let paydown = remaining_sweep.min(tranche_balances[idx]);
-                    tranche_balances[idx] -= paydown;
-                    remaining_sweep -= paydown;
+        cash_balance += fcf_after_mandatory;
+
+        // Revolver: draw to restore minimum cash, otherwise repay from surplus
+        let mut revolver_draw = Decimal::ZERO;
+        let mut revolver_repayment = Decimal::ZERO;
+        if let Some(r) = revolver {
+            if cash_balance < minimum_cash {
+                let headroom = (r.commitment - revolver_balance).max(Decimal::ZERO);
+                revolver_draw = (minimum_cash - cash_balance).min(headroom);
+                if revolver_draw < minimum_cash - cash_balance {
+                    warnings.push(format!("Year {year}: revolver fully drawn"));
                 }
-                optional_repayment = actual_sweep - remaining_sweep;
+            } else if revolver_balance > Decimal::ZERO {
+                revolver_repayment = (cash_balance - minimum_cash).min(revolver_balance);
             }
+            revolver_balance += revolver_draw - revolver_repayment;
+            cash_balance += revolver_draw - revolver_repayment;
         }
 
-        // Update cash balance
-        cash_balance += fcf_after_mandatory - optional_repayment;
+        // Mandatory sweep of this year's excess cash flow, never below minimum cash
+        let sweep_pct_applied = if input.sweep_tiers.is_empty() {
+            cash_sweep_pct
+        } else {
+            let leverage = if ebitda > Decimal::ZERO {
+                opening_debt / ebitda
+            } else {
+                Decimal::MAX
+            };
+            input
+                .sweep_tiers
+                .iter()
+                .filter(|t| leverage >= t.min_leverage)
+                .max_by(|a, b| a.min_leverage.cmp(&b.min_leverage))
+                .map_or(Decimal::ZERO, |t| t.sweep_pct)
+        };
+        let excess_cash_flow = fcf_after_mandatory - revolver_repayment;
+        let mut sweep_repayment = Decimal::ZERO;
+        if sweep_pct_applied > Decimal::ZERO && excess_cash_flow > Decimal::ZERO {
+            let available = (cash_balance - minimum_cash).max(Decimal::ZERO);
+            sweep_repayment = prepay(
+                (excess_cash_flow * sweep_pct_applied).min(available),
+                &mut tranche_balances,
+                &prepayment_order,
+            );
+            cash_balance -= sweep_repayment;
+        }
+
+        // Voluntary prepayment from remaining cash above the minimum
+        let mut voluntary_prepayment = Decimal::ZERO;
+        if optional_prepayment_pct > Decimal::ZERO {
+            let surplus = (cash_balance - minimum_cash).max(Decimal::ZERO);
+            voluntary_prepayment = prepay(
+                surplus * optional_prepayment_pct,
+                &mut tranche_balances,
+                &prepayment_order,
+            );
+            cash_balance -= voluntary_prepayment;
+        }
+        let optional_repayment = sweep_repayment + voluntary_prepayment;
+
         if cash_balance < Decimal::ZERO {
             // Negative cash — flag as warning
             warnings.push(format!(
@@ -352,7 +544,7 @@ pub fn build_lbo(input: &LboInput) -> CorpFinanceResult<ComputationOutput<LboOut
         }
 
         // Total debt outstanding
-        let total_debt: Money = tranche_balances.iter().sum();
+        let total_debt: Money = tranche_balances.iter().sum::<Money>() + revolver_balance;
 
         // Net debt = total debt - cash
         let net_debt = total_debt - cash_balance.max(Decimal::ZERO);
@@ -368,6 +560,9 @@ pub fn build_lbo(input: &LboInput) -> CorpFinanceResult<ComputationOutput<LboOut
             ebitda,
             ebit,
             less_interest: total_interest,
+            cash_interest,
+            pik_interest,
+            commitment_fee,
             ebt,
             tax,
             net_income,
@@ -377,6 +572,13 @@ pub fn build_lbo(input: &LboInput) -> CorpFinanceResult<ComputationOutput<LboOut
             fcf_before_debt_service,
             mandatory_repayment,
             optional_repayment,
+            sweep_repayment,
+            voluntary_prepayment,
+            sweep_pct_applied,
+            revolver_draw,
+            revolver_repayment,
+            revolver_balance,
+            tranche_balances: tranche_balances.clone(),
             total_debt_outstanding: total_debt,
             net_debt,
             cash_balance,
@@ -387,6 +589,8 @@ pub fn build_lbo(input: &LboInput) -> CorpFinanceResult<ComputationOutput<LboOut
         prev_nwc = current_nwc;
     }
 
+    drop(stage);
+
     // ─── Exit ────────────────────────────────────────────────────────
     let last = projections
         .last()
@@ -513,6 +717,10 @@ mod tests {
             management_rollover: None,
             currency: None,
             minimum_cash: None,
+            revolver: None,
+            sweep_tiers: vec![],
+            optional_prepayment_pct: None,
+            pik_toggles: vec![],
         }
     }
 
@@ -786,4 +994,159 @@ mod tests {
         let year3_margin = projs[2].ebitda / projs[2].revenue;
         assert_eq!(year3_margin, dec!(0.22));
     }
+
+    #[test]
+    fn test_interest_follows_swept_balances() {
+        let base = build_lbo(&standard_lbo_input()).unwrap().result;
+        let mut input = standard_lbo_input();
+        input.cash_sweep_pct = Some(dec!(1.0));
+        let swept = build_lbo(&input).unwrap().result;
+
+        assert_eq!(
+            swept.projections[0].less_interest,
+            base.projections[0].less_interest
+        );
+        assert!(swept.projections[1].less_interest < base.projections[1].less_interest);
+    }
+
+    #[test]
+    fn test_revolver_funds_shortfall_and_charges_fees() {
+        let mut input = standard_lbo_input();
+        input.capex_as_pct_revenue = dec!(0.25);
+        input.minimum_cash = Some(dec!(20));
+        input.revolver = Some(RevolverInput {
+            commitment: dec!(500),
+            interest_rate: dec!(0.06),
+            commitment_fee: dec!(0.005),
+            initial_draw: Decimal::ZERO,
+        });
+
+        let out = build_lbo(&input).unwrap().result;
+        let y1 = &out.projections[0];
+        // Undrawn at open: fee on the full commitment
+        assert_eq!(y1.commitment_fee, dec!(2.5));
+        assert!(y1.revolver_draw > Decimal::ZERO);
+        assert_eq!(y1.cash_balance, dec!(20));
+        assert_eq!(y1.revolver_balance, y1.revolver_draw);
+
+        // Year 2 interest includes drawn margin and a smaller fee
+        let y2 = &out.projections[1];
+        let drawn = y1.revolver_balance;
+        assert_eq!(y2.commitment_fee, (dec!(500) - drawn) * dec!(0.005));
+        assert!(y2.total_debt_outstanding >= y2.revolver_balance);
+    }
+
+    #[test]
+    fn test_revolver_repaid_before_sweep() {
+        let mut input = standard_lbo_input();
+        input.cash_sweep_pct = Some(dec!(0.5));
+        input.revolver = Some(RevolverInput {
+            commitment: dec!(100),
+            interest_rate: dec!(0.06),
+            commitment_fee: Decimal::ZERO,
+            initial_draw: dec!(50),
+        });
+        input.equity_contribution = dec!(350);
+
+        let out = build_lbo(&input).unwrap().result;
+        let y1 = &out.projections[0];
+        assert_eq!(y1.revolver_repayment, dec!(50));
+        assert_eq!(y1.revolver_balance, Decimal::ZERO);
+        let fcf_after = y1.fcf_before_debt_service - y1.mandatory_repayment;
+        assert_eq!(y1.sweep_repayment, (fcf_after - dec!(50)) * dec!(0.5));
+        // Initial draw is a debt source at entry
+        assert_eq!(out.entry_leverage, dec!(650) / dec!(200));
+    }
+
+    #[test]
+    fn test_sweep_tiers_step_down_with_leverage() {
+        let mut input = standard_lbo_input();
+        input.sweep_tiers = vec![
+            CashSweepTier {
+                min_leverage: Decimal::ZERO,
+                sweep_pct: dec!(0.25),
+            },
+            CashSweepTier {
+                min_leverage: dec!(2.5),
+                sweep_pct: dec!(0.75),
+            },
+        ];
+
+        let out = build_lbo(&input).unwrap().result;
+        // Opening leverage 600 / 210 is above 2.5x
+        assert_eq!(out.projections[0].sweep_pct_applied, dec!(0.75));
+        let last = out.projections.last().unwrap();
+        assert_eq!(last.sweep_pct_applied, dec!(0.25));
+        assert!(out
+            .projections
+            .iter()
+            .all(|p| p.sweep_repayment > Decimal::ZERO));
+    }
+
+    #[test]
+    fn test_voluntary_prepayment_uses_cash_above_minimum() {
+        let mut input = standard_lbo_input();
+        input.minimum_cash = Some(dec!(10));
+        input.optional_prepayment_pct = Some(Decimal::ONE);
+
+        let out = build_lbo(&input).unwrap().result;
+        let y1 = &out.projections[0];
+        assert!(y1.voluntary_prepayment > Decimal::ZERO);
+        assert_eq!(y1.sweep_repayment, Decimal::ZERO);
+        assert_eq!(y1.optional_repayment, y1.voluntary_prepayment);
+        assert_eq!(y1.cash_balance, dec!(10));
+    }
+
+    #[test]
+    fn test_pik_toggle_capitalises_interest() {
+        let mut input = standard_lbo_input();
+        input.tranches.push(DebtTrancheInput {
+            name: "HoldCo PIK Notes".into(),
+            amount: dec!(100),
+            interest_rate: dec!(0.10),
+            is_floating: false,
+            base_rate: None,
+            spread: None,
+            amortisation: AmortisationType::Bullet,
+            maturity_years: 8,
+            pik_rate: None,
+            seniority: 3,
+            commitment_fee: None,
+            is_revolver: false,
+        });
+        input.equity_contribution = dec!(300);
+        input.pik_toggles = vec![PikToggle {
+            tranche: "HoldCo PIK Notes".into(),
+            pik_years: vec![1, 2],
+            pik_premium: dec!(0.0075),
+        }];
+
+        let out = build_lbo(&input).unwrap().result;
+        let y1 = &out.projections[0];
+        assert_eq!(y1.pik_interest, dec!(10.75));
+        // Senior cash coupon only
+        assert_eq!(y1.cash_interest, dec!(30));
+        assert_eq!(y1.less_interest, y1.cash_interest + y1.pik_interest);
+        assert_eq!(y1.tranche_balances[1], dec!(110.75));
+
+        // Year 3 reverts to cash pay on the accreted balance
+        let y3 = &out.projections[2];
+        assert_eq!(y3.pik_interest, Decimal::ZERO);
+        let y2_balances = &out.projections[1].tranche_balances;
+        assert_eq!(
+            y3.cash_interest,
+            y2_balances[0] * dec!(0.05) + y2_balances[1] * dec!(0.10)
+        );
+    }
+
+    #[test]
+    fn test_unknown_pik_toggle_tranche_rejected() {
+        let mut input = standard_lbo_input();
+        input.pik_toggles = vec![PikToggle {
+            tranche: "Missing".into(),
+            pik_years: vec![1],
+            pik_premium: Decimal::ZERO,
+        }];
+        assert!(build_lbo(&input).is_err());
+    }
 }
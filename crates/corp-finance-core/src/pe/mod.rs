@@ -1,5 +1,8 @@
+pub mod co_investment;
 pub mod debt_schedule;
+pub mod interim_nav;
 pub mod lbo;
+pub mod pacing;
 pub mod returns;
 pub mod sources_uses;
 pub mod waterfall;
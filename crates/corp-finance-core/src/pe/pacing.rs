@@ -0,0 +1,571 @@
+use rust_decimal::Decimal;
+use rust_decimal::MathematicalOps;
+use serde::{Deserialize, Serialize};
+use std::time::Instant;
+
+use crate::error::CorpFinanceError;
+use crate::fund_of_funds::cash_flow_forecast::{
+    add_years, empty_years, liquidity_profile, project_commitment, resolve_params,
+    validate_strategy_assumptions, ForecastCommitment, ForecastYear, StrategyAssumption,
+    TakahashiAlexanderParams,
+};
+use crate::fund_of_funds::commitment_pacing::PacingYearProjection;
+use crate::types::*;
+use crate::CorpFinanceResult;
+
+/// A commitment planned for a future vintage
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlannedCommitment {
+    /// Vintage year; capital calls start in this year
+    pub vintage: u32,
+    /// Strategy (e.g. "Buyout", "Venture", "Real Estate")
+    pub strategy: String,
+    pub commitment: Money,
+}
+
+/// Allocation target the pacing plan is measured against
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AllocationTarget {
+    /// Total portfolio value (all asset classes) at `as_of_year`
+    pub total_portfolio_value: Money,
+    /// Annual growth of the total portfolio (default 0)
+    #[serde(default)]
+    pub portfolio_growth_rate: Rate,
+    /// Target private markets allocation (e.g. 0.15 = 15%)
+    pub target_allocation_pct: Rate,
+    /// Strategy used to size the recommended annual commitment
+    pub strategy: String,
+}
+
+/// Input for a forward-looking commitment pacing plan.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PacingInput {
+    /// Calendar year of the current paid-in and NAV figures
+    pub as_of_year: u32,
+    /// Number of years to project
+    pub horizon_years: u32,
+    /// Existing commitments, projected from their current age
+    #[serde(default)]
+    pub existing_commitments: Vec<ForecastCommitment>,
+    /// Commitments planned for future vintages
+    #[serde(default)]
+    pub planned_commitments: Vec<PlannedCommitment>,
+    /// Strategy parameter overrides; built-in defaults are used otherwise
+    #[serde(default)]
+    pub strategy_assumptions: Vec<StrategyAssumption>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target: Option<AllocationTarget>,
+    /// Cash set aside to meet net capital calls
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub liquidity_reserve: Option<Money>,
+}
+
+/// Projection for all commitments of one vintage year
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VintageForecast {
+    pub vintage: u32,
+    pub commitment: Money,
+    /// Number of funds in the vintage
+    pub num_funds: u32,
+    pub years: Vec<ForecastYear>,
+    pub total_contributions: Money,
+    pub total_distributions: Money,
+    pub ending_nav: Money,
+}
+
+/// Forward-looking pacing plan.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PacingOutput {
+    /// Portfolio-level calls, distributions and NAV by calendar year
+    pub portfolio: Vec<ForecastYear>,
+    /// The same projection split by vintage year
+    pub by_vintage: Vec<VintageForecast>,
+    /// Cumulative net cash flow by calendar year (negative = cash funded)
+    pub cumulative_net_cash_flow: Vec<Money>,
+    /// Deepest cumulative net outflow (positive = cash needed)
+    pub peak_funding_need: Money,
+    /// First year cumulative net cash flow turns non-negative after an outflow
+    pub breakeven_year: Option<u32>,
+    /// First year the cumulative net outflow exceeds the liquidity reserve
+    pub liquidity_shortfall_year: Option<u32>,
+    /// Allocation and over-commitment path against the target
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub allocation: Vec<PacingYearProjection>,
+    /// Level annual commitment, on top of the planned commitments, that
+    /// brings NAV to the target allocation by the end of the horizon
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub recommended_annual_commitment: Option<Money>,
+}
+
+/// Project capital calls, distributions and NAV by vintage for existing and
+/// planned commitments using the Takahashi-Alexander (Yale) model.
+///
+/// Existing funds roll forward from their current age; planned funds start
+/// calling capital in their vintage year. Strategy parameters come from
+/// `fund_of_funds::cash_flow_forecast`. With a target, the plan is tracked
+/// on the same allocation and over-commitment basis as
+/// `fund_of_funds::commitment_pacing`, and the model is linear in
+/// commitment size so the level annual commitment that closes the gap is
+/// solved directly.
+pub fn project_pacing(input: &PacingInput) -> CorpFinanceResult<ComputationOutput<PacingOutput>> {
+    let start = Instant::now();
+    let mut warnings: Vec<String> = Vec::new();
+
+    validate_pacing_input(input)?;
+
+    let horizon = input.horizon_years as usize;
+    let last_year = input.as_of_year + input.horizon_years;
+
+    // 1. Project every commitment on the calendar grid
+    let mut funds: Vec<(u32, Money, Vec<ForecastYear>)> = Vec::new();
+    for c in &input.existing_commitments {
+        let params = resolve_params(&input.strategy_assumptions, &c.strategy)?;
+        let growth = vec![params.growth_rate; horizon];
+        funds.push((
+            c.vintage,
+            c.commitment,
+            project_commitment(c, &params, input.as_of_year, &growth),
+        ));
+    }
+    for (i, p) in input.planned_commitments.iter().enumerate() {
+        let params = resolve_params(&input.strategy_assumptions, &p.strategy)?;
+        let commitment = ForecastCommitment {
+            name: format!("Planned {} {}", p.vintage, p.strategy),
+            strategy: p.strategy.clone(),
+            vintage: p.vintage,
+            commitment: p.commitment,
+            paid_in: Decimal::ZERO,
+            nav: Decimal::ZERO,
+        };
+        if p.vintage > last_year {
+            warnings.push(format!(
+                "planned_commitments[{i}] vintage {} is beyond the horizon and has no effect",
+                p.vintage
+            ));
+        }
+        funds.push((
+            p.vintage,
+            p.commitment,
+            project_planned(&commitment, &params, input.as_of_year, horizon),
+        ));
+    }
+
+    // 2. Aggregate by vintage and portfolio
+    let mut by_vintage: Vec<VintageForecast> = Vec::new();
+    for (vintage, commitment, years) in &funds {
+        match by_vintage.iter_mut().find(|v| v.vintage == *vintage) {
+            Some(v) => {
+                v.commitment += *commitment;
+                v.num_funds += 1;
+                add_years(&mut v.years, years);
+            }
+            None => by_vintage.push(VintageForecast {
+                vintage: *vintage,
+                commitment: *commitment,
+                num_funds: 1,
+                years: years.clone(),
+                total_contributions: Decimal::ZERO,
+                total_distributions: Decimal::ZERO,
+                ending_nav: Decimal::ZERO,
+            }),
+        }
+    }
+    by_vintage.sort_by_key(|v| v.vintage);
+    for v in &mut by_vintage {
+        v.total_contributions = v.years.iter().map(|y| y.contributions).sum();
+        v.total_distributions = v.years.iter().map(|y| y.distributions).sum();
+        v.ending_nav = v.years.last().map_or(Decimal::ZERO, |y| y.nav);
+    }
+
+    let mut portfolio = empty_years(input.as_of_year, horizon);
+    for (_, _, years) in &funds {
+        add_years(&mut portfolio, years);
+    }
+
+    // 3. Liquidity
+    let cumulative_net_cash_flow: Vec<Money> = portfolio
+        .iter()
+        .scan(Decimal::ZERO, |cum, y| {
+            *cum += y.net_cash_flow;
+            Some(*cum)
+        })
+        .collect();
+    let (peak_funding_need, breakeven_year) = liquidity_profile(&portfolio);
+    let liquidity_shortfall_year = input.liquidity_reserve.and_then(|reserve| {
+        portfolio
+            .iter()
+            .zip(&cumulative_net_cash_flow)
+            .find(|(_, cum)| -**cum > reserve)
+            .map(|(y, _)| y.year)
+    });
+    if let (Some(year), Some(reserve)) = (liquidity_shortfall_year, input.liquidity_reserve) {
+        warnings.push(format!(
+            "Cumulative net capital calls exceed the liquidity reserve of {reserve} in {year}; peak funding need is {peak_funding_need}"
+        ));
+    }
+
+    // 4. Allocation path and recommended pace
+    let (allocation, recommended_annual_commitment) = match &input.target {
+        Some(target) => {
+            let params = resolve_params(&input.strategy_assumptions, &target.strategy)?;
+            let allocation = allocation_path(input, target, &portfolio);
+            let recommended = recommended_commitment(input, target, &params, &portfolio);
+            if recommended.is_zero() {
+                warnings.push(
+                    "Existing and planned commitments reach the target allocation without further commitments"
+                        .into(),
+                );
+            }
+            (allocation, Some(recommended))
+        }
+        None => (Vec::new(), None),
+    };
+
+    let output = PacingOutput {
+        portfolio,
+        by_vintage,
+        cumulative_net_cash_flow,
+        peak_funding_need,
+        breakeven_year,
+        liquidity_shortfall_year,
+        allocation,
+        recommended_annual_commitment,
+    };
+
+    let elapsed = start.elapsed().as_micros() as u64;
+    Ok(with_metadata(
+        "Commitment Pacing (Takahashi-Alexander by vintage)",
+        &serde_json::json!({
+            "as_of_year": input.as_of_year,
+            "horizon_years": input.horizon_years,
+            "existing_commitments": input.existing_commitments.len(),
+            "planned_commitments": input.planned_commitments.len(),
+            "planned_call_start": "vintage year",
+        }),
+        warnings,
+        elapsed,
+        output,
+    ))
+}
+
+/// Project a planned commitment onto the calendar years after `as_of_year`,
+/// with zero activity before its vintage.
+fn project_planned(
+    commitment: &ForecastCommitment,
+    params: &TakahashiAlexanderParams,
+    as_of_year: u32,
+    horizon: usize,
+) -> Vec<ForecastYear> {
+    let mut years = empty_years(as_of_year, horizon);
+    let offset = (commitment.vintage - as_of_year - 1) as usize;
+    if offset < horizon {
+        let growth = vec![params.growth_rate; horizon - offset];
+        let projected = project_commitment(commitment, params, commitment.vintage - 1, &growth);
+        for (slot, y) in years[offset..].iter_mut().zip(projected) {
+            *slot = y;
+        }
+    }
+    years
+}
+
+fn total_portfolio_value(target: &AllocationTarget, t: u32) -> Money {
+    target.total_portfolio_value * (Decimal::ONE + target.portfolio_growth_rate).powu(t as u64)
+}
+
+/// Allocation and over-commitment by year, on the commitment_pacing basis.
+fn allocation_path(
+    input: &PacingInput,
+    target: &AllocationTarget,
+    portfolio: &[ForecastYear],
+) -> Vec<PacingYearProjection> {
+    portfolio
+        .iter()
+        .enumerate()
+        .map(|(k, y)| {
+            let t = k as u32 + 1;
+            let total_value = total_portfolio_value(target, t);
+            let target_nav = total_value * target.target_allocation_pct;
+            let new_commitments = input
+                .planned_commitments
+                .iter()
+                .filter(|p| p.vintage == y.year)
+                .map(|p| p.commitment)
+                .sum();
+            PacingYearProjection {
+                year: t,
+                new_commitments,
+                projected_drawdowns: y.contributions,
+                projected_distributions: y.distributions,
+                projected_nav: y.nav,
+                allocation_pct: y.nav / total_value,
+                over_commitment_ratio: if target_nav.is_zero() {
+                    Decimal::ZERO
+                } else {
+                    (y.nav + y.unfunded) / target_nav
+                },
+                net_cash_flow: y.net_cash_flow,
+            }
+        })
+        .collect()
+}
+
+/// Level annual commitment from the first projected year that brings
+/// ending NAV to the target. Ending NAV is linear in commitment size, so
+/// one unit-commitment projection gives the answer.
+fn recommended_commitment(
+    input: &PacingInput,
+    target: &AllocationTarget,
+    params: &TakahashiAlexanderParams,
+    portfolio: &[ForecastYear],
+) -> Money {
+    let horizon = input.horizon_years as usize;
+    let target_nav =
+        total_portfolio_value(target, input.horizon_years) * target.target_allocation_pct;
+    let base_nav = portfolio.last().map_or(Decimal::ZERO, |y| y.nav);
+
+    let unit_nav: Money = (1..=input.horizon_years)
+        .map(|k| {
+            let unit = ForecastCommitment {
+                name: String::new(),
+                strategy: target.strategy.clone(),
+                vintage: input.as_of_year + k,
+                commitment: Decimal::ONE,
+                paid_in: Decimal::ZERO,
+                nav: Decimal::ZERO,
+            };
+            project_planned(&unit, params, input.as_of_year, horizon)
+                .last()
+                .map_or(Decimal::ZERO, |y| y.nav)
+        })
+        .sum();
+
+    if unit_nav.is_zero() {
+        Decimal::ZERO
+    } else {
+        ((target_nav - base_nav) / unit_nav).max(Decimal::ZERO)
+    }
+}
+
+fn validate_pacing_input(input: &PacingInput) -> CorpFinanceResult<()> {
+    if input.existing_commitments.is_empty() && input.planned_commitments.is_empty() {
+        return Err(CorpFinanceError::InsufficientData(
+            "At least one existing or planned commitment is required".into(),
+        ));
+    }
+    if input.horizon_years == 0 || input.horizon_years > 50 {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "horizon_years".into(),
+            reason: "Must be between 1 and 50".into(),
+        });
+    }
+    for c in &input.existing_commitments {
+        if c.commitment <= Decimal::ZERO || c.paid_in < Decimal::ZERO || c.nav < Decimal::ZERO {
+            return Err(CorpFinanceError::InvalidInput {
+                field: format!("existing_commitments[{}]", c.name),
+                reason: "Commitment must be positive and paid-in and NAV non-negative".into(),
+            });
+        }
+        if c.vintage > input.as_of_year {
+            return Err(CorpFinanceError::InvalidInput {
+                field: format!("existing_commitments[{}].vintage", c.name),
+                reason: "Vintage cannot be after as_of_year; use planned_commitments".into(),
+            });
+        }
+    }
+    for (i, p) in input.planned_commitments.iter().enumerate() {
+        if p.commitment <= Decimal::ZERO {
+            return Err(CorpFinanceError::InvalidInput {
+                field: format!("planned_commitments[{i}].commitment"),
+                reason: "Commitment must be positive".into(),
+            });
+        }
+        if p.vintage <= input.as_of_year {
+            return Err(CorpFinanceError::InvalidInput {
+                field: format!("planned_commitments[{i}].vintage"),
+                reason: "Planned vintages must be after as_of_year".into(),
+            });
+        }
+    }
+    if let Some(t) = &input.target {
+        if t.total_portfolio_value <= Decimal::ZERO {
+            return Err(CorpFinanceError::InvalidInput {
+                field: "target.total_portfolio_value".into(),
+                reason: "Total portfolio value must be positive".into(),
+            });
+        }
+        if t.target_allocation_pct <= Decimal::ZERO || t.target_allocation_pct > Decimal::ONE {
+            return Err(CorpFinanceError::InvalidInput {
+                field: "target.target_allocation_pct".into(),
+                reason: "Target allocation must be in (0, 1]".into(),
+            });
+        }
+        if t.portfolio_growth_rate <= -Decimal::ONE {
+            return Err(CorpFinanceError::InvalidInput {
+                field: "target.portfolio_growth_rate".into(),
+                reason: "Growth rate must be above -100%".into(),
+            });
+        }
+    }
+    if input.liquidity_reserve.is_some_and(|r| r < Decimal::ZERO) {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "liquidity_reserve".into(),
+            reason: "Liquidity reserve cannot be negative".into(),
+        });
+    }
+    validate_strategy_assumptions(&input.strategy_assumptions)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn existing_buyout() -> ForecastCommitment {
+        ForecastCommitment {
+            name: "Buyout Fund VI".into(),
+            strategy: "Buyout".into(),
+            vintage: 2021,
+            commitment: dec!(100),
+            paid_in: dec!(60),
+            nav: dec!(70),
+        }
+    }
+
+    fn sample_input() -> PacingInput {
+        PacingInput {
+            as_of_year: 2024,
+            horizon_years: 10,
+            existing_commitments: vec![existing_buyout()],
+            planned_commitments: vec![
+                PlannedCommitment {
+                    vintage: 2025,
+                    strategy: "Buyout".into(),
+                    commitment: dec!(50),
+                },
+                PlannedCommitment {
+                    vintage: 2026,
+                    strategy: "Venture".into(),
+                    commitment: dec!(30),
+                },
+            ],
+            strategy_assumptions: vec![],
+            target: None,
+            liquidity_reserve: None,
+        }
+    }
+
+    #[test]
+    fn test_existing_fund_matches_cash_flow_forecast() {
+        let input = PacingInput {
+            planned_commitments: vec![],
+            ..sample_input()
+        };
+        let out = project_pacing(&input).unwrap().result;
+        let params = crate::fund_of_funds::cash_flow_forecast::default_params("Buyout").unwrap();
+        let expected =
+            project_commitment(&existing_buyout(), &params, 2024, &[params.growth_rate; 10]);
+        for (a, b) in out.portfolio.iter().zip(&expected) {
+            assert_eq!(a.contributions, b.contributions);
+            assert_eq!(a.nav, b.nav);
+        }
+    }
+
+    #[test]
+    fn test_planned_commitment_starts_calling_in_vintage_year() {
+        let out = project_pacing(&sample_input()).unwrap().result;
+        let v2026 = out.by_vintage.iter().find(|v| v.vintage == 2026).unwrap();
+        assert_eq!(v2026.years[0].year, 2025);
+        assert_eq!(v2026.years[0].contributions, Decimal::ZERO);
+        // Venture year-1 contribution rate of 25%
+        assert_eq!(v2026.years[1].year, 2026);
+        assert_eq!(v2026.years[1].contributions, dec!(7.5));
+    }
+
+    #[test]
+    fn test_vintages_sum_to_portfolio() {
+        let out = project_pacing(&sample_input()).unwrap().result;
+        assert_eq!(
+            out.by_vintage.iter().map(|v| v.vintage).collect::<Vec<_>>(),
+            vec![2021, 2025, 2026]
+        );
+        for (k, y) in out.portfolio.iter().enumerate() {
+            let calls: Decimal = out
+                .by_vintage
+                .iter()
+                .map(|v| v.years[k].contributions)
+                .sum();
+            let nav: Decimal = out.by_vintage.iter().map(|v| v.years[k].nav).sum();
+            assert_eq!(calls, y.contributions);
+            assert_eq!(nav, y.nav);
+        }
+        assert_eq!(
+            *out.cumulative_net_cash_flow.last().unwrap(),
+            out.portfolio
+                .iter()
+                .map(|y| y.net_cash_flow)
+                .sum::<Decimal>()
+        );
+    }
+
+    #[test]
+    fn test_liquidity_shortfall_flagged() {
+        let mut input = sample_input();
+        input.liquidity_reserve = Some(dec!(1));
+        let result = project_pacing(&input).unwrap();
+        let out = &result.result;
+        assert!(out.peak_funding_need > dec!(1));
+        assert!(out.liquidity_shortfall_year.is_some());
+        assert!(result
+            .warnings
+            .iter()
+            .any(|w| w.contains("liquidity reserve")));
+
+        input.liquidity_reserve = Some(out.peak_funding_need);
+        let out = project_pacing(&input).unwrap().result;
+        assert!(out.liquidity_shortfall_year.is_none());
+    }
+
+    #[test]
+    fn test_recommended_commitment_hits_target() {
+        let mut input = sample_input();
+        input.target = Some(AllocationTarget {
+            total_portfolio_value: dec!(1000),
+            portfolio_growth_rate: dec!(0.05),
+            target_allocation_pct: dec!(0.20),
+            strategy: "Buyout".into(),
+        });
+        let out = project_pacing(&input).unwrap().result;
+        let pace = out.recommended_annual_commitment.unwrap();
+        assert!(pace > Decimal::ZERO);
+        assert_eq!(out.allocation.len(), 10);
+        assert_eq!(out.allocation[0].new_commitments, dec!(50));
+
+        // Adding the recommended pace each year lands on the target NAV
+        for year in 2025..=2034 {
+            input.planned_commitments.push(PlannedCommitment {
+                vintage: year,
+                strategy: "Buyout".into(),
+                commitment: pace,
+            });
+        }
+        let out = project_pacing(&input).unwrap().result;
+        let last = out.allocation.last().unwrap();
+        assert!((last.allocation_pct - dec!(0.20)).abs() < dec!(0.000001));
+    }
+
+    #[test]
+    fn test_validation_errors() {
+        let mut input = sample_input();
+        input.planned_commitments[0].vintage = 2024;
+        assert!(project_pacing(&input).is_err());
+
+        let mut input = sample_input();
+        input.existing_commitments.clear();
+        input.planned_commitments.clear();
+        assert!(project_pacing(&input).is_err());
+
+        let mut input = sample_input();
+        input.planned_commitments[0].strategy = "Unknown".into();
+        assert!(project_pacing(&input).is_err());
+    }
+}
@@ -1,5 +1,6 @@
 use chrono::NaiveDate;
 use rust_decimal::Decimal;
+use rust_decimal::MathematicalOps;
 use rust_decimal_macros::dec;
 use serde::{Deserialize, Serialize};
 use std::time::Instant;
@@ -26,6 +27,28 @@ pub struct ReturnsInput {
     /// Entry and exit dates (for XIRR and date-based holding period)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub dates: Option<(NaiveDate, NaiveDate)>,
+    /// Subscription line financing of capital calls; when set, returns are
+    /// also reported as if calls had been deferred by the line
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subscription_line: Option<SubscriptionLineTerms>,
+}
+
+/// Subscription line used to defer capital calls
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SubscriptionLineTerms {
+    /// Periods each capital call is deferred (periodic cash flows)
+    pub call_delay_periods: u32,
+    /// Days each capital call is deferred (dated cash flows)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub call_delay_days: Option<u32>,
+    /// Annual interest rate on line borrowings
+    pub interest_rate: Rate,
+    /// Upfront and commitment fees over the life of the line, called at inception
+    #[serde(default)]
+    pub fees: Money,
+    /// Facility size, for disclosure
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub facility_size: Option<Money>,
 }
 
 /// Output of PE returns calculation
@@ -45,6 +68,39 @@ pub struct ReturnsOutput {
     pub total_returned: Money,
     /// Holding period in years
     pub holding_period: Years,
+    /// Returns with and without the subscription line
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscription_line: Option<SubscriptionLineImpact>,
+}
+
+/// Effect of subscription line financing on reported performance
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SubscriptionLineImpact {
+    /// Periodic cash flows with calls deferred and grossed up for interest
+    pub cash_flows_with_line: Vec<Money>,
+    pub irr_uplift_bps: Option<Decimal>,
+    pub xirr_with_line: Option<Rate>,
+    pub xirr_uplift_bps: Option<Decimal>,
+    /// TVPI given up to interest and fees
+    pub tvpi_drag: Multiple,
+    pub interest_cost: Money,
+    pub fee_cost: Money,
+    pub disclosure: SubscriptionLineDisclosure,
+}
+
+/// Standardised subscription facility disclosure (ILPA reporting guidance):
+/// facility size and usage, days outstanding, cost, and net IRR and TVPI
+/// with and without the facility
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SubscriptionLineDisclosure {
+    pub facility_size: Option<Money>,
+    pub peak_balance_outstanding: Money,
+    pub average_days_outstanding: Decimal,
+    pub total_financing_cost: Money,
+    pub net_irr_with_facility: Option<Rate>,
+    pub net_irr_without_facility: Option<Rate>,
+    pub tvpi_with_facility: Multiple,
+    pub tvpi_without_facility: Multiple,
 }
 
 /// Calculate PE fund returns: IRR, XIRR, MOIC, Cash-on-Cash.
@@ -154,6 +210,20 @@ pub fn calculate_returns(
         None
     };
 
+    let subscription_line = match &input.subscription_line {
+        Some(terms) => Some(subscription_line_impact(
+            input,
+            terms,
+            irr_result,
+            xirr_result,
+            moic,
+            total_invested,
+            total_returned,
+            &mut warnings,
+        )?),
+        None => None,
+    };
+
     let output = ReturnsOutput {
         irr: irr_result,
         xirr: xirr_result,
@@ -162,6 +232,7 @@ pub fn calculate_returns(
         total_invested,
         total_returned,
         holding_period,
+        subscription_line,
     };
 
     let elapsed = start.elapsed().as_micros() as u64;
@@ -177,6 +248,149 @@ pub fn calculate_returns(
     ))
 }
 
+/// Defer each capital call by the line's delay, grossed up for interest, and
+/// compare returns with and without the facility. Fees are treated as an
+/// additional call at inception.
+#[allow(clippy::too_many_arguments)]
+fn subscription_line_impact(
+    input: &ReturnsInput,
+    terms: &SubscriptionLineTerms,
+    irr_without: Option<Rate>,
+    xirr_without: Option<Rate>,
+    tvpi_without: Multiple,
+    total_invested: Money,
+    total_returned: Money,
+    warnings: &mut Vec<String>,
+) -> CorpFinanceResult<SubscriptionLineImpact> {
+    if terms.interest_rate < Decimal::ZERO || terms.fees < Decimal::ZERO {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "subscription_line".into(),
+            reason: "Interest rate and fees cannot be negative".into(),
+        });
+    }
+
+    let growth = Decimal::ONE + terms.interest_rate;
+    let d = terms.call_delay_periods as usize;
+
+    // Periodic flows: calls move d periods later, distributions stay put
+    let mut cash_flows_with_line: Vec<Money> = input
+        .cash_flows
+        .iter()
+        .map(|cf| (*cf).max(Decimal::ZERO))
+        .collect();
+    let mut periodic_interest = Decimal::ZERO;
+    let mut peak_balance = Decimal::ZERO;
+    if !input.cash_flows.is_empty() {
+        cash_flows_with_line.resize(input.cash_flows.len() + d, Decimal::ZERO);
+        let gross_up = growth.powu(d as u64);
+        for (t, cf) in input.cash_flows.iter().enumerate() {
+            if cf.is_sign_negative() {
+                cash_flows_with_line[t + d] += *cf * gross_up;
+                periodic_interest += cf.abs() * (gross_up - Decimal::ONE);
+            }
+        }
+        // Balance at each period end: calls drawn on the line and not yet called
+        for t in 0..cash_flows_with_line.len() {
+            let balance: Decimal = input
+                .cash_flows
+                .iter()
+                .enumerate()
+                .filter(|(s, cf)| cf.is_sign_negative() && *s <= t && t < *s + d)
+                .map(|(s, cf)| cf.abs() * growth.powu((t - s) as u64))
+                .sum();
+            peak_balance = peak_balance.max(balance);
+        }
+        cash_flows_with_line[0] -= terms.fees;
+    }
+
+    let irr_with = if cash_flows_with_line.len() >= 2 {
+        match crate::time_value::irr(&cash_flows_with_line, dec!(0.10)) {
+            Ok(r) => Some(r),
+            Err(e) => {
+                warnings.push(format!("IRR with subscription line: {e}"));
+                None
+            }
+        }
+    } else {
+        None
+    };
+
+    // Dated flows: calls move by the delay in days
+    let mut dated_interest = Decimal::ZERO;
+    let xirr_with = match (&input.dated_cash_flows, terms.call_delay_days) {
+        (Some(dated), Some(days)) if dated.len() >= 2 => {
+            let years = Decimal::from(days) / dec!(365);
+            let gross_up = growth.powd(years);
+            let mut flows: Vec<(NaiveDate, Money)> = dated
+                .iter()
+                .map(|cf| {
+                    if cf.amount.is_sign_negative() {
+                        dated_interest += cf.amount.abs() * (gross_up - Decimal::ONE);
+                        (
+                            cf.date + chrono::Duration::days(days as i64),
+                            cf.amount * gross_up,
+                        )
+                    } else {
+                        (cf.date, cf.amount)
+                    }
+                })
+                .collect();
+            flows.sort_by_key(|(date, _)| *date);
+            if let Some(first) = flows.first_mut() {
+                first.1 -= terms.fees;
+            }
+            match crate::time_value::xirr(&flows, dec!(0.10)) {
+                Ok(r) => Some(r),
+                Err(e) => {
+                    warnings.push(format!("XIRR with subscription line: {e}"));
+                    None
+                }
+            }
+        }
+        _ => None,
+    };
+
+    let interest_cost = if input.cash_flows.is_empty() {
+        dated_interest
+    } else {
+        periodic_interest
+    };
+    let total_financing_cost = interest_cost + terms.fees;
+    let tvpi_with = total_returned / (total_invested + total_financing_cost);
+    let uplift = |with: Option<Rate>, without: Option<Rate>| match (with, without) {
+        (Some(w), Some(wo)) => Some((w - wo) * dec!(10000)),
+        _ => None,
+    };
+    let average_days_outstanding = match terms.call_delay_days {
+        Some(days) if input.cash_flows.is_empty() => Decimal::from(days),
+        _ => Decimal::from(terms.call_delay_periods) * dec!(365),
+    };
+
+    Ok(SubscriptionLineImpact {
+        cash_flows_with_line,
+        irr_uplift_bps: uplift(irr_with, irr_without),
+        xirr_with_line: xirr_with,
+        xirr_uplift_bps: uplift(xirr_with, xirr_without),
+        tvpi_drag: tvpi_without - tvpi_with,
+        interest_cost,
+        fee_cost: terms.fees,
+        disclosure: SubscriptionLineDisclosure {
+            facility_size: terms.facility_size,
+            peak_balance_outstanding: peak_balance,
+            average_days_outstanding,
+            total_financing_cost,
+            net_irr_with_facility: irr_with.or(xirr_with),
+            net_irr_without_facility: if irr_with.is_some() {
+                irr_without
+            } else {
+                xirr_without
+            },
+            tvpi_with_facility: tvpi_with,
+            tvpi_without_facility: tvpi_without,
+        },
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -192,6 +406,7 @@ mod tests {
             exit_equity: dec!(250),
             holding_period_years: Some(dec!(3)),
             dates: None,
+            subscription_line: None,
         };
         let result = calculate_returns(&input).unwrap();
         // MOIC = (50+50+150) / 100 = 2.5
@@ -207,6 +422,7 @@ mod tests {
             exit_equity: dec!(500),
             holding_period_years: Some(dec!(4)),
             dates: None,
+            subscription_line: None,
         };
         let result = calculate_returns(&input).unwrap();
         assert_eq!(result.result.cash_on_cash, dec!(2.5));
@@ -221,6 +437,7 @@ mod tests {
             exit_equity: dec!(400),
             holding_period_years: Some(dec!(3)),
             dates: None,
+            subscription_line: None,
         };
         let result = calculate_returns(&input).unwrap();
         let irr_val = result.result.irr.unwrap();
@@ -263,6 +480,7 @@ mod tests {
             exit_equity: dec!(400),
             holding_period_years: None,
             dates: Some((d0, d3)),
+            subscription_line: None,
         };
         let result = calculate_returns(&input).unwrap();
         assert!(result.result.xirr.is_some());
@@ -279,6 +497,7 @@ mod tests {
             exit_equity: dec!(200),
             holding_period_years: None,
             dates: Some((d0, d1)),
+            subscription_line: None,
         };
         let result = calculate_returns(&input).unwrap();
         // ~5 years
@@ -294,7 +513,120 @@ mod tests {
             exit_equity: dec!(100),
             holding_period_years: Some(dec!(1)),
             dates: None,
+            subscription_line: None,
         };
         assert!(calculate_returns(&input).is_err());
     }
+
+    fn sub_line(delay: u32) -> SubscriptionLineTerms {
+        SubscriptionLineTerms {
+            call_delay_periods: delay,
+            call_delay_days: None,
+            interest_rate: dec!(0.05),
+            fees: dec!(2),
+            facility_size: Some(dec!(100)),
+        }
+    }
+
+    fn fund_flows_input() -> ReturnsInput {
+        ReturnsInput {
+            cash_flows: vec![dec!(-100), dec!(-100), dec!(0), dec!(150), dec!(250)],
+            dated_cash_flows: None,
+            entry_equity: dec!(200),
+            exit_equity: dec!(400),
+            holding_period_years: None,
+            dates: None,
+            subscription_line: Some(sub_line(1)),
+        }
+    }
+
+    #[test]
+    fn test_subscription_line_defers_calls() {
+        let result = calculate_returns(&fund_flows_input()).unwrap();
+        let sl = result.result.subscription_line.unwrap();
+        assert_eq!(
+            sl.cash_flows_with_line,
+            vec![
+                dec!(-2),
+                dec!(-105),
+                dec!(-105),
+                dec!(150),
+                dec!(250),
+                dec!(0)
+            ]
+        );
+        assert_eq!(sl.interest_cost, dec!(10));
+        assert_eq!(sl.disclosure.total_financing_cost, dec!(12));
+        assert_eq!(sl.disclosure.peak_balance_outstanding, dec!(100));
+        assert_eq!(sl.disclosure.average_days_outstanding, dec!(365));
+    }
+
+    #[test]
+    fn test_subscription_line_irr_uplift_and_tvpi_drag() {
+        let result = calculate_returns(&fund_flows_input()).unwrap();
+        let out = result.result;
+        let sl = out.subscription_line.unwrap();
+        let d = &sl.disclosure;
+        assert_eq!(d.net_irr_without_facility, out.irr);
+        assert!(d.net_irr_with_facility.unwrap() > out.irr.unwrap());
+        assert!(sl.irr_uplift_bps.unwrap() > Decimal::ZERO);
+        // TVPI: 400 / 200 without, 400 / 212 with
+        assert_eq!(d.tvpi_without_facility, dec!(2));
+        assert_eq!(d.tvpi_with_facility, dec!(400) / dec!(212));
+        assert!(sl.tvpi_drag > Decimal::ZERO);
+        assert_eq!(d.facility_size, Some(dec!(100)));
+    }
+
+    #[test]
+    fn test_subscription_line_peak_balance_overlapping_calls() {
+        let mut input = fund_flows_input();
+        input.subscription_line = Some(sub_line(2));
+        let result = calculate_returns(&input).unwrap();
+        let sl = result.result.subscription_line.unwrap();
+        // Period 1: first call accrued one year plus second call
+        assert_eq!(sl.disclosure.peak_balance_outstanding, dec!(205));
+        assert_eq!(sl.cash_flows_with_line.len(), 7);
+    }
+
+    #[test]
+    fn test_subscription_line_dated_flows() {
+        let d0 = NaiveDate::from_ymd_opt(2020, 1, 1).unwrap();
+        let d1 = NaiveDate::from_ymd_opt(2021, 1, 1).unwrap();
+        let d3 = NaiveDate::from_ymd_opt(2023, 1, 1).unwrap();
+        let flow = |date, amount| CashFlow {
+            date,
+            amount,
+            label: None,
+        };
+        let mut terms = sub_line(0);
+        terms.call_delay_days = Some(180);
+        terms.fees = Decimal::ZERO;
+        let input = ReturnsInput {
+            cash_flows: vec![],
+            dated_cash_flows: Some(vec![
+                flow(d0, dec!(-100)),
+                flow(d1, dec!(-100)),
+                flow(d3, dec!(300)),
+            ]),
+            entry_equity: dec!(200),
+            exit_equity: dec!(300),
+            holding_period_years: None,
+            dates: Some((d0, d3)),
+            subscription_line: Some(terms),
+        };
+        let result = calculate_returns(&input).unwrap();
+        let out = result.result;
+        let sl = out.subscription_line.unwrap();
+        assert!(sl.xirr_with_line.unwrap() > out.xirr.unwrap());
+        assert!(sl.interest_cost > Decimal::ZERO);
+        assert_eq!(sl.disclosure.average_days_outstanding, dec!(180));
+        assert_eq!(sl.disclosure.net_irr_without_facility, out.xirr);
+    }
+
+    #[test]
+    fn test_subscription_line_negative_rate_error() {
+        let mut input = fund_flows_input();
+        input.subscription_line.as_mut().unwrap().interest_rate = dec!(-0.01);
+        assert!(calculate_returns(&input).is_err());
+    }
 }
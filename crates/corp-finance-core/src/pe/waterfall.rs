@@ -1,6 +1,8 @@
 use rust_decimal::Decimal;
+use rust_decimal::MathematicalOps;
 use rust_decimal_macros::dec;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::time::Instant;
 
 use crate::error::CorpFinanceError;
@@ -22,6 +24,58 @@ pub struct WaterfallInput {
     pub tiers: Vec<WaterfallTier>,
     /// GP commitment as a fraction of fund (typically 0.01 - 0.05)
     pub gp_commitment_pct: Rate,
+    /// Whole-fund (European) or deal-by-deal (American) carry
+    #[serde(default)]
+    pub style: WaterfallStyle,
+    /// Realised deals; required for deal-by-deal carry, where the tiers run
+    /// on each deal and totals are taken from the deals
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub deals: Vec<WaterfallDeal>,
+    /// Management fee offset from portfolio company fees
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fee_offset: Option<FeeOffset>,
+    /// GP tax rate; clawback is limited to carry net of tax (default 0)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gp_tax_rate: Option<Rate>,
+}
+
+/// Carry calculation basis.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WaterfallStyle {
+    /// Carry only after the whole fund has returned capital and preferred return
+    #[default]
+    European,
+    /// Carry on each realised deal, trued up by a whole-fund clawback
+    American,
+}
+
+/// A realised deal for deal-by-deal carry.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WaterfallDeal {
+    pub name: String,
+    pub invested: Money,
+    pub proceeds: Money,
+    /// Holding period; when given the preferred return compounds over it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub holding_years: Option<Decimal>,
+}
+
+/// Management fee offset and fee return before carry.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FeeOffset {
+    /// Management fees charged to LPs
+    pub management_fees: Money,
+    /// Transaction, monitoring and other fees the GP received from portfolio companies
+    pub portfolio_company_fees: Money,
+    /// Share of portfolio company fees credited against management fees
+    pub offset_pct: Rate,
+    /// Return net management fees to LPs alongside capital before carry
+    #[serde(default = "default_true")]
+    pub return_fees_before_carry: bool,
+}
+
+fn default_true() -> bool {
+    true
 }
 
 /// A single tier in the distribution waterfall.
@@ -40,7 +94,8 @@ pub enum WaterfallTierType {
     ReturnOfCapital,
     /// Preferred return (hurdle) on invested capital
     PreferredReturn { rate: Rate },
-    /// GP catch-up until GP carry equals target share of total profit
+    /// GP catch-up: the GP takes `gp_share` of proceeds in this tier until
+    /// its carry equals the carry rate of total profit
     CatchUp { gp_share: Rate },
     /// Carried interest split of remaining proceeds
     CarriedInterest { gp_share: Rate },
@@ -55,7 +110,7 @@ pub enum WaterfallTierType {
 /// Full waterfall distribution result.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WaterfallOutput {
-    /// Per-tier breakdown
+    /// Per-tier breakdown (summed across deals for deal-by-deal carry)
     pub tiers: Vec<WaterfallTierResult>,
     /// Total distributions to the GP
     pub total_to_gp: Money,
@@ -69,6 +124,14 @@ pub struct WaterfallOutput {
     pub gp_carry: Money,
     /// GP return attributable to its co-investment
     pub gp_co_invest_return: Money,
+    pub style: WaterfallStyle,
+    /// Per-deal results for deal-by-deal carry
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub deal_results: Vec<DealWaterfallResult>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub clawback: Option<ClawbackResult>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fee_offset: Option<FeeOffsetResult>,
 }
 
 /// Result for a single waterfall tier.
@@ -86,20 +149,57 @@ pub struct WaterfallTierResult {
     pub remaining: Money,
 }
 
+/// Distribution of a single deal under deal-by-deal carry.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DealWaterfallResult {
+    pub deal_name: String,
+    /// Invested capital plus allocated fees returned before carry
+    pub capital_base: Money,
+    pub proceeds: Money,
+    pub to_gp: Money,
+    pub to_lp: Money,
+    pub gp_carry: Money,
+}
+
+/// Whole-fund true-up of deal-by-deal carry.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClawbackResult {
+    /// Carry paid deal by deal
+    pub carry_received: Money,
+    /// Carry the GP would earn on a whole-fund basis
+    pub whole_fund_carry: Money,
+    /// Excess carry owed back to LPs
+    pub gross_clawback: Money,
+    /// Clawback after the GP tax limitation
+    pub clawback_payable: Money,
+}
+
+/// Fee offset applied before distribution.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FeeOffsetResult {
+    pub management_fees: Money,
+    pub offset: Money,
+    pub net_management_fees: Money,
+    /// Net fees added to the capital returned before carry
+    pub fees_returned_before_carry: Money,
+}
+
 // ---------------------------------------------------------------------------
 // Calculation
 // ---------------------------------------------------------------------------
 
-/// Calculate a PE fund cash-flow waterfall (European-style).
+/// Calculate a PE fund cash-flow waterfall.
 ///
-/// Distributes `total_proceeds` through an ordered set of tiers (return of
-/// capital, preferred return, GP catch-up, carried interest, residual) and
-/// tracks GP vs LP allocations throughout.
+/// Distributes proceeds through an ordered set of tiers (return of capital,
+/// preferred return, GP catch-up, carried interest, residual) and tracks GP
+/// vs LP allocations throughout. European style runs the tiers once on the
+/// whole fund; American style runs them on each deal and claws back any
+/// carry above the whole-fund entitlement.
 pub fn calculate_waterfall(
     input: &WaterfallInput,
 ) -> CorpFinanceResult<ComputationOutput<WaterfallOutput>> {
     let start = Instant::now();
-    let warnings: Vec<String> = Vec::new();
+    let mut warnings: Vec<String> = Vec::new();
 
     // --- Validation ---
     if input.total_proceeds < Decimal::ZERO {
@@ -126,19 +226,25 @@ pub fn calculate_waterfall(
             reason: "At least one waterfall tier is required".into(),
         });
     }
+    if input.style == WaterfallStyle::American && input.deals.is_empty() {
+        return Err(CorpFinanceError::InsufficientData(
+            "Deal-by-deal carry requires at least one deal".into(),
+        ));
+    }
+    for deal in &input.deals {
+        if deal.invested <= Decimal::ZERO || deal.proceeds < Decimal::ZERO {
+            return Err(CorpFinanceError::InvalidInput {
+                field: "deals".into(),
+                reason: format!(
+                    "Deal '{}' needs positive invested capital and non-negative proceeds",
+                    deal.name
+                ),
+            });
+        }
+    }
 
     let gp_pct = input.gp_commitment_pct;
 
-    let mut remaining = input.total_proceeds;
-    let mut tier_results: Vec<WaterfallTierResult> = Vec::new();
-
-    // Accumulators for GP co-invest amounts (return of capital + preferred)
-    let mut gp_co_invest_roc = Decimal::ZERO;
-    let mut gp_co_invest_pref = Decimal::ZERO;
-
-    // Track cumulative LP preferred (needed for catch-up target calculation)
-    let mut cumulative_lp_preferred = Decimal::ZERO;
-
     // Determine the carry rate from the first CarriedInterest or Residual tier.
     // This is needed to compute the catch-up target.
     let carry_rate = input
@@ -150,96 +256,179 @@ pub fn calculate_waterfall(
             _ => None,
         })
         .unwrap_or(dec!(0.20));
-
     for tier in &input.tiers {
-        let tier_result = match &tier.tier_type {
-            WaterfallTierType::ReturnOfCapital => {
-                let distributable = remaining.min(input.total_invested);
-                let to_gp = distributable * gp_pct;
-                let to_lp = distributable - to_gp;
-                remaining -= distributable;
-                gp_co_invest_roc = to_gp;
-                WaterfallTierResult {
-                    tier_name: tier.name.clone(),
-                    amount: distributable,
-                    to_gp,
-                    to_lp,
-                    remaining,
-                }
+        if let WaterfallTierType::CatchUp { gp_share } = tier.tier_type {
+            if gp_share <= carry_rate {
+                warnings.push(format!(
+                    "Catch-up share {gp_share} does not exceed the carry rate {carry_rate}; the catch-up never completes and absorbs all remaining proceeds"
+                ));
             }
-            WaterfallTierType::PreferredReturn { rate } => {
-                let preferred_total = input.total_invested * *rate;
-                let distributable = remaining.min(preferred_total);
-                let to_gp = distributable * gp_pct;
-                let to_lp = distributable - to_gp;
-                remaining -= distributable;
-                gp_co_invest_pref = to_gp;
-                cumulative_lp_preferred = to_lp;
-                WaterfallTierResult {
-                    tier_name: tier.name.clone(),
-                    amount: distributable,
-                    to_gp,
-                    to_lp,
-                    remaining,
-                }
+        }
+    }
+
+    // --- Fee offset ---
+    let fee_offset = input.fee_offset.as_ref().map(|f| {
+        let offset = (f.portfolio_company_fees * f.offset_pct).min(f.management_fees);
+        let net_management_fees = f.management_fees - offset;
+        FeeOffsetResult {
+            management_fees: f.management_fees,
+            offset,
+            net_management_fees,
+            fees_returned_before_carry: if f.return_fees_before_carry {
+                net_management_fees
+            } else {
+                Decimal::ZERO
+            },
+        }
+    });
+    let fees_returned = fee_offset
+        .as_ref()
+        .map_or(Decimal::ZERO, |f| f.fees_returned_before_carry);
+
+    let (total_proceeds, total_invested) = match input.style {
+        WaterfallStyle::European => (input.total_proceeds, input.total_invested),
+        WaterfallStyle::American => {
+            let proceeds: Money = input.deals.iter().map(|d| d.proceeds).sum();
+            let invested: Money = input.deals.iter().map(|d| d.invested).sum();
+            if proceeds != input.total_proceeds || invested != input.total_invested {
+                warnings.push(format!(
+                    "Deal-by-deal totals (proceeds {proceeds}, invested {invested}) replace total_proceeds and total_invested"
+                ));
             }
-            WaterfallTierType::CatchUp { gp_share } => {
-                // The GP catch-up target: GP should receive
-                // carry_rate / (1 - carry_rate) * LP_preferred
-                // so that total profit split reaches the target ratio.
-                let target_catchup = if carry_rate < Decimal::ONE {
-                    (carry_rate / (Decimal::ONE - carry_rate)) * cumulative_lp_preferred
-                } else {
-                    remaining // 100% carry => catch up takes everything
-                };
+            (proceeds, invested)
+        }
+    };
 
-                let distributable = remaining.min(target_catchup).max(Decimal::ZERO);
-                let to_gp = distributable * *gp_share;
-                let to_lp = distributable - to_gp;
-                remaining -= distributable;
-                WaterfallTierResult {
-                    tier_name: tier.name.clone(),
-                    amount: distributable,
-                    to_gp,
-                    to_lp,
-                    remaining,
+    let (tier_results, gp_co_invest_return, total_to_gp, deal_results, clawback) = match input.style
+    {
+        WaterfallStyle::European => {
+            let capital = total_invested + fees_returned;
+            let run = run_tiers(
+                total_proceeds,
+                capital,
+                &|rate| capital * rate,
+                &input.tiers,
+                gp_pct,
+                carry_rate,
+            );
+            let to_gp: Money = run.tiers.iter().map(|t| t.to_gp).sum();
+            (run.tiers, run.gp_co_invest_return, to_gp, Vec::new(), None)
+        }
+        WaterfallStyle::American => {
+            let mut deal_results = Vec::with_capacity(input.deals.len());
+            let mut aggregated: Vec<WaterfallTierResult> = Vec::new();
+            let mut tier_index: HashMap<String, usize> = HashMap::new();
+            let mut gp_co_invest_return = Decimal::ZERO;
+            let mut whole_fund_pref: HashMap<String, Money> = HashMap::new();
+
+            for deal in &input.deals {
+                let capital = deal.invested + fees_returned * deal.invested / total_invested;
+                let pref_for = |rate: Rate| match deal.holding_years {
+                    Some(years) => capital * ((Decimal::ONE + rate).powd(years) - Decimal::ONE),
+                    None => capital * rate,
+                };
+                for tier in &input.tiers {
+                    if let WaterfallTierType::PreferredReturn { rate } = tier.tier_type {
+                        *whole_fund_pref.entry(tier.name.clone()).or_default() += pref_for(rate);
+                    }
                 }
-            }
-            WaterfallTierType::CarriedInterest { gp_share }
-            | WaterfallTierType::Residual { gp_share } => {
-                let distributable = remaining;
-                let to_gp = distributable * *gp_share;
-                let to_lp = distributable - to_gp;
-                remaining = Decimal::ZERO;
-                WaterfallTierResult {
-                    tier_name: tier.name.clone(),
-                    amount: distributable,
+                let run = run_tiers(
+                    deal.proceeds,
+                    capital,
+                    &pref_for,
+                    &input.tiers,
+                    gp_pct,
+                    carry_rate,
+                );
+                let to_gp: Money = run.tiers.iter().map(|t| t.to_gp).sum();
+                let to_lp: Money = run.tiers.iter().map(|t| t.to_lp).sum();
+                gp_co_invest_return += run.gp_co_invest_return;
+                deal_results.push(DealWaterfallResult {
+                    deal_name: deal.name.clone(),
+                    capital_base: capital,
+                    proceeds: deal.proceeds,
                     to_gp,
                     to_lp,
-                    remaining,
+                    gp_carry: to_gp - run.gp_co_invest_return,
+                });
+                for t in run.tiers {
+                    match tier_index.get(&t.tier_name) {
+                        Some(&i) => {
+                            aggregated[i].amount += t.amount;
+                            aggregated[i].to_gp += t.to_gp;
+                            aggregated[i].to_lp += t.to_lp;
+                            aggregated[i].remaining += t.remaining;
+                        }
+                        None => {
+                            tier_index.insert(t.tier_name.clone(), aggregated.len());
+                            aggregated.push(t);
+                        }
+                    }
                 }
             }
-        };
 
-        tier_results.push(tier_result);
-    }
+            // Whole-fund entitlement on the same capital and preferred return
+            let capital = total_invested + fees_returned;
+            let whole_fund = run_tiers(
+                total_proceeds,
+                capital,
+                &|rate| {
+                    input
+                        .tiers
+                        .iter()
+                        .find_map(|t| match t.tier_type {
+                            WaterfallTierType::PreferredReturn { rate: r } if r == rate => {
+                                whole_fund_pref.get(&t.name).copied()
+                            }
+                            _ => None,
+                        })
+                        .unwrap_or(capital * rate)
+                },
+                &input.tiers,
+                gp_pct,
+                carry_rate,
+            );
+            let whole_fund_to_gp: Money = whole_fund.tiers.iter().map(|t| t.to_gp).sum();
+            let whole_fund_carry = whole_fund_to_gp - whole_fund.gp_co_invest_return;
+
+            let carry_received: Money = deal_results.iter().map(|d| d.gp_carry).sum();
+            let gross_clawback = (carry_received - whole_fund_carry).max(Decimal::ZERO);
+            let gp_tax_rate = input.gp_tax_rate.unwrap_or(Decimal::ZERO);
+            let clawback_payable = gross_clawback * (Decimal::ONE - gp_tax_rate);
+            if gross_clawback > Decimal::ZERO {
+                warnings.push(format!(
+                    "Deal-by-deal carry exceeds the whole-fund entitlement by {gross_clawback}; GP clawback of {clawback_payable} is due"
+                ));
+            }
+
+            let to_gp: Money =
+                deal_results.iter().map(|d| d.to_gp).sum::<Money>() - clawback_payable;
+            (
+                aggregated,
+                gp_co_invest_return,
+                to_gp,
+                deal_results,
+                Some(ClawbackResult {
+                    carry_received,
+                    whole_fund_carry,
+                    gross_clawback,
+                    clawback_payable,
+                }),
+            )
+        }
+    };
 
     // Aggregate totals
-    let total_to_gp: Money = tier_results.iter().map(|t| t.to_gp).sum();
-    let total_to_lp: Money = tier_results.iter().map(|t| t.to_lp).sum();
+    let total_to_lp = total_proceeds - total_to_gp;
 
-    let (gp_pct_of_total, lp_pct_of_total) = if input.total_proceeds.is_zero() {
+    let (gp_pct_of_total, lp_pct_of_total) = if total_proceeds.is_zero() {
         (Decimal::ZERO, Decimal::ZERO)
     } else {
-        (
-            total_to_gp / input.total_proceeds,
-            total_to_lp / input.total_proceeds,
-        )
+        (total_to_gp / total_proceeds, total_to_lp / total_proceeds)
     };
 
     // GP carry = total GP distributions minus the GP's pro-rata share of
     // return of capital and preferred return
-    let gp_co_invest_return = gp_co_invest_roc + gp_co_invest_pref;
     let gp_carry = total_to_gp - gp_co_invest_return;
 
     let output = WaterfallOutput {
@@ -250,16 +439,25 @@ pub fn calculate_waterfall(
         lp_pct_of_total,
         gp_carry,
         gp_co_invest_return,
+        style: input.style,
+        deal_results,
+        clawback,
+        fee_offset,
     };
 
+    let methodology = match input.style {
+        WaterfallStyle::European => "PE Cash-Flow Waterfall (European)",
+        WaterfallStyle::American => "PE Cash-Flow Waterfall (American, deal-by-deal with clawback)",
+    };
     let elapsed = start.elapsed().as_micros() as u64;
     Ok(with_metadata(
-        "PE Cash-Flow Waterfall (European)",
+        methodology,
         &serde_json::json!({
-            "total_proceeds": input.total_proceeds.to_string(),
-            "total_invested": input.total_invested.to_string(),
+            "total_proceeds": total_proceeds.to_string(),
+            "total_invested": total_invested.to_string(),
             "gp_commitment_pct": input.gp_commitment_pct.to_string(),
             "num_tiers": input.tiers.len(),
+            "num_deals": input.deals.len(),
         }),
         warnings,
         elapsed,
@@ -267,6 +465,79 @@ pub fn calculate_waterfall(
     ))
 }
 
+/// Tier results for one pass of the waterfall.
+struct TierRun {
+    tiers: Vec<WaterfallTierResult>,
+    /// GP pro-rata share of return of capital and preferred return
+    gp_co_invest_return: Money,
+}
+
+/// Run the tier stack over `proceeds` against `capital`. `pref_for` maps a
+/// preferred return rate to the hurdle amount.
+fn run_tiers(
+    proceeds: Money,
+    capital: Money,
+    pref_for: &dyn Fn(Rate) -> Money,
+    tiers: &[WaterfallTier],
+    gp_pct: Rate,
+    carry_rate: Rate,
+) -> TierRun {
+    let mut remaining = proceeds;
+    let mut tier_results: Vec<WaterfallTierResult> = Vec::with_capacity(tiers.len());
+
+    // Accumulators for GP co-invest amounts (return of capital + preferred)
+    let mut gp_co_invest_roc = Decimal::ZERO;
+    let mut gp_co_invest_pref = Decimal::ZERO;
+
+    // Track cumulative LP preferred (needed for catch-up target calculation)
+    let mut cumulative_lp_preferred = Decimal::ZERO;
+
+    for tier in tiers {
+        let (distributable, gp_share) = match &tier.tier_type {
+            WaterfallTierType::ReturnOfCapital => {
+                let distributable = remaining.min(capital);
+                gp_co_invest_roc = distributable * gp_pct;
+                (distributable, gp_pct)
+            }
+            WaterfallTierType::PreferredReturn { rate } => {
+                let distributable = remaining.min(pref_for(*rate));
+                gp_co_invest_pref = distributable * gp_pct;
+                cumulative_lp_preferred = distributable - gp_co_invest_pref;
+                (distributable, gp_pct)
+            }
+            WaterfallTierType::CatchUp { gp_share } => {
+                // The catch-up X is complete when the GP's share of it equals
+                // the carry rate of the preferred plus the catch-up:
+                // gp_share * X = carry_rate * (LP_preferred + X)
+                let target_catchup = if *gp_share > carry_rate {
+                    carry_rate * cumulative_lp_preferred / (*gp_share - carry_rate)
+                } else {
+                    remaining
+                };
+                (remaining.min(target_catchup).max(Decimal::ZERO), *gp_share)
+            }
+            WaterfallTierType::CarriedInterest { gp_share }
+            | WaterfallTierType::Residual { gp_share } => (remaining, *gp_share),
+        };
+
+        let to_gp = distributable * gp_share;
+        let to_lp = distributable - to_gp;
+        remaining -= distributable;
+        tier_results.push(WaterfallTierResult {
+            tier_name: tier.name.clone(),
+            amount: distributable,
+            to_gp,
+            to_lp,
+            remaining,
+        });
+    }
+
+    TierRun {
+        tiers: tier_results,
+        gp_co_invest_return: gp_co_invest_roc + gp_co_invest_pref,
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -308,6 +579,10 @@ mod tests {
                 },
             ],
             gp_commitment_pct,
+            style: WaterfallStyle::European,
+            deals: vec![],
+            fee_offset: None,
+            gp_tax_rate: None,
         }
     }
 
@@ -515,6 +790,10 @@ mod tests {
                 },
             ],
             gp_commitment_pct: dec!(0.02),
+            style: WaterfallStyle::European,
+            deals: vec![],
+            fee_offset: None,
+            gp_tax_rate: None,
         };
         let result = calculate_waterfall(&input).unwrap();
         let out = &result.result;
@@ -556,6 +835,10 @@ mod tests {
                 tier_type: WaterfallTierType::ReturnOfCapital,
             }],
             gp_commitment_pct: dec!(0.02),
+            style: WaterfallStyle::European,
+            deals: vec![],
+            fee_offset: None,
+            gp_tax_rate: None,
         };
         let result = calculate_waterfall(&input);
         assert!(result.is_err());
@@ -571,6 +854,10 @@ mod tests {
                 tier_type: WaterfallTierType::ReturnOfCapital,
             }],
             gp_commitment_pct: dec!(1.5), // > 1, invalid
+            style: WaterfallStyle::European,
+            deals: vec![],
+            fee_offset: None,
+            gp_tax_rate: None,
         };
         let result = calculate_waterfall(&input);
         assert!(result.is_err());
@@ -595,6 +882,10 @@ mod tests {
                 },
             ],
             gp_commitment_pct: dec!(0.01),
+            style: WaterfallStyle::European,
+            deals: vec![],
+            fee_offset: None,
+            gp_tax_rate: None,
         };
         let result = calculate_waterfall(&input).unwrap();
         let out = &result.result;
@@ -616,4 +907,134 @@ mod tests {
         let sum = out.gp_pct_of_total + out.lp_pct_of_total;
         assert_eq!(sum, Decimal::ONE);
     }
+
+    /// Helper: two-deal American waterfall with no GP commitment
+    fn american_waterfall(deals: Vec<(Money, Money)>) -> WaterfallInput {
+        let mut input = european_waterfall(Decimal::ZERO, Decimal::ONE, Decimal::ZERO);
+        input.style = WaterfallStyle::American;
+        input.deals = deals
+            .into_iter()
+            .enumerate()
+            .map(|(i, (invested, proceeds))| WaterfallDeal {
+                name: format!("Deal {}", i + 1),
+                invested,
+                proceeds,
+                holding_years: None,
+            })
+            .collect();
+        input.total_invested = input.deals.iter().map(|d| d.invested).sum();
+        input.total_proceeds = input.deals.iter().map(|d| d.proceeds).sum();
+        input
+    }
+
+    #[test]
+    fn test_partial_catch_up_reaches_full_carry() {
+        let mut input = european_waterfall(dec!(200), dec!(100), Decimal::ZERO);
+        input.tiers[2].tier_type = WaterfallTierType::CatchUp {
+            gp_share: dec!(0.80),
+        };
+        let out = calculate_waterfall(&input).unwrap().result;
+
+        // X = 0.20 * 8 / (0.80 - 0.20); GP share 0.8X = 20% of (8 + X)
+        let catch_up = &out.tiers[2];
+        assert!((catch_up.amount - dec!(8) / dec!(3)).abs() < dec!(0.0001));
+        assert!((catch_up.to_gp - dec!(0.20) * (dec!(8) + catch_up.amount)).abs() < dec!(0.0001));
+        // Full 20% of the $100 profit once the catch-up completes
+        assert!((out.gp_carry - dec!(20)).abs() < dec!(0.0001));
+    }
+
+    #[test]
+    fn test_catch_up_at_or_below_carry_warns() {
+        let mut input = european_waterfall(dec!(200), dec!(100), Decimal::ZERO);
+        input.tiers[2].tier_type = WaterfallTierType::CatchUp {
+            gp_share: dec!(0.20),
+        };
+        let result = calculate_waterfall(&input).unwrap();
+        assert!(result.warnings.iter().any(|w| w.contains("Catch-up share")));
+        assert_eq!(result.result.tiers[2].remaining, Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_american_clawback_after_losing_deal() {
+        let mut input = american_waterfall(vec![(dec!(50), dec!(100)), (dec!(50), dec!(20))]);
+        input.gp_tax_rate = Some(dec!(0.40));
+        let result = calculate_waterfall(&input).unwrap();
+        let out = &result.result;
+
+        // Deal 1 pays 20% of its $50 profit; deal 2 loses $30
+        assert_eq!(out.deal_results[0].gp_carry, dec!(10));
+        assert_eq!(out.deal_results[1].gp_carry, Decimal::ZERO);
+
+        // Whole fund: $20 profit -> $4 carry, so $6 is clawed back, net of 40% tax
+        let clawback = out.clawback.as_ref().unwrap();
+        assert_eq!(clawback.carry_received, dec!(10));
+        assert_eq!(clawback.whole_fund_carry, dec!(4));
+        assert_eq!(clawback.gross_clawback, dec!(6));
+        assert_eq!(clawback.clawback_payable, dec!(3.6));
+        assert_eq!(out.total_to_gp, dec!(6.4));
+        assert_eq!(out.total_to_gp + out.total_to_lp, dec!(120));
+        assert!(result.warnings.iter().any(|w| w.contains("clawback")));
+    }
+
+    #[test]
+    fn test_american_no_clawback_when_all_deals_profitable() {
+        let input = american_waterfall(vec![(dec!(50), dec!(100)), (dec!(50), dec!(100))]);
+        let out = calculate_waterfall(&input).unwrap().result;
+
+        let clawback = out.clawback.unwrap();
+        assert_eq!(clawback.gross_clawback, Decimal::ZERO);
+        assert_eq!(out.gp_carry, dec!(20));
+        // Tiers are summed across deals
+        assert_eq!(out.tiers[0].amount, dec!(100));
+        assert_eq!(out.tiers[1].amount, dec!(8));
+    }
+
+    #[test]
+    fn test_american_requires_deals() {
+        let mut input = european_waterfall(dec!(200), dec!(100), dec!(0.02));
+        input.style = WaterfallStyle::American;
+        assert!(calculate_waterfall(&input).is_err());
+    }
+
+    #[test]
+    fn test_compounded_pref_over_holding_period() {
+        let mut input = american_waterfall(vec![(dec!(100), dec!(150))]);
+        input.deals[0].holding_years = Some(dec!(2));
+        let out = calculate_waterfall(&input).unwrap().result;
+
+        // 100 x (1.08^2 - 1)
+        assert!((out.tiers[1].amount - dec!(16.64)).abs() < dec!(0.0001));
+    }
+
+    #[test]
+    fn test_fee_offset_returned_before_carry() {
+        let mut input = european_waterfall(dec!(200), dec!(100), Decimal::ZERO);
+        input.fee_offset = Some(FeeOffset {
+            management_fees: dec!(10),
+            portfolio_company_fees: dec!(8),
+            offset_pct: dec!(0.80),
+            return_fees_before_carry: true,
+        });
+        let out = calculate_waterfall(&input).unwrap().result;
+
+        let fees = out.fee_offset.as_ref().unwrap();
+        assert_eq!(fees.offset, dec!(6.4));
+        assert_eq!(fees.net_management_fees, dec!(3.6));
+        assert_eq!(out.tiers[0].amount, dec!(103.6));
+        // 20% of profit over capital plus net fees
+        assert!((out.gp_carry - dec!(19.28)).abs() < dec!(0.0001));
+    }
+
+    #[test]
+    fn test_style_defaults_to_european() {
+        let input: WaterfallInput = serde_json::from_value(serde_json::json!({
+            "total_proceeds": "150",
+            "total_invested": "100",
+            "tiers": [{ "name": "Return of Capital", "tier_type": "ReturnOfCapital" }],
+            "gp_commitment_pct": "0.02"
+        }))
+        .unwrap();
+        assert_eq!(input.style, WaterfallStyle::European);
+        assert!(input.deals.is_empty());
+    }
 }
@@ -0,0 +1,622 @@
+use rust_decimal::Decimal;
+use rust_decimal::MathematicalOps;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+use std::time::Instant;
+
+use crate::error::CorpFinanceError;
+use crate::portfolio::returns::ReturnFrequency;
+use crate::types::*;
+use crate::CorpFinanceResult;
+
+/// Periodic return series for one benchmark constituent index
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IndexReturnSeries {
+    pub index_name: String,
+    /// Periodic returns (as decimals), one per period
+    pub returns: Vec<Decimal>,
+}
+
+/// Target weight of an index within a blend
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BenchmarkComponent {
+    pub index_name: String,
+    pub weight: Decimal,
+}
+
+/// How the blend is brought back to its target weights
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub enum RebalancingRule {
+    /// Fixed weights every period (the usual "60/40" blend definition)
+    EveryPeriod,
+    /// Weights drift between rebalances every `every_n_periods` periods
+    Calendar { every_n_periods: u32 },
+    /// Rebalance when any weight drifts more than `tolerance` from target
+    Threshold { tolerance: Decimal },
+    /// Weights drift for the life of the segment
+    BuyAndHold,
+}
+
+/// A benchmark definition in force from `start_period` until the next segment
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BenchmarkSegment {
+    /// Zero-based period at which this definition takes effect
+    pub start_period: usize,
+    pub components: Vec<BenchmarkComponent>,
+    pub rebalancing: RebalancingRule,
+}
+
+/// Input for blended benchmark construction
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BlendedBenchmarkInput {
+    pub benchmark_name: String,
+    pub index_returns: Vec<IndexReturnSeries>,
+    /// Benchmark definitions in chronological order; the first starts at period 0
+    pub segments: Vec<BenchmarkSegment>,
+    /// Portfolio returns (same frequency) for excess return statistics
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub portfolio_returns: Option<Vec<Decimal>>,
+    pub frequency: ReturnFrequency,
+}
+
+/// Weight of one index at the start of a period
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ComponentWeight {
+    pub index_name: String,
+    pub weight: Decimal,
+}
+
+/// Blend return and weights for a single period
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BenchmarkPeriod {
+    pub period: usize,
+    /// Index into `segments` of the definition in force
+    pub segment: usize,
+    /// Weights at the start of the period, after any rebalance
+    pub weights: Vec<ComponentWeight>,
+    pub rebalanced: bool,
+    pub benchmark_return: Decimal,
+    pub portfolio_return: Option<Decimal>,
+    pub excess_return: Option<Decimal>,
+}
+
+/// Excess return statistics versus the blend
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExcessReturnStats {
+    pub cumulative_portfolio_return: Rate,
+    /// Cumulative portfolio return less cumulative benchmark return
+    pub arithmetic_excess_return: Rate,
+    /// (1 + Rp) / (1 + Rb) - 1 on cumulative returns
+    pub geometric_excess_return: Rate,
+    pub annualised_excess_return: Rate,
+    pub tracking_error: Rate,
+    pub information_ratio: Option<Decimal>,
+    /// Share of periods in which the portfolio beat the blend
+    pub hit_rate: Decimal,
+    /// Largest peak-to-trough fall in the portfolio / benchmark wealth ratio
+    pub max_relative_drawdown: Rate,
+}
+
+/// Output of blended benchmark construction
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BlendedBenchmarkOutput {
+    pub benchmark_name: String,
+    pub periods: Vec<BenchmarkPeriod>,
+    /// Chain-linked blend returns, one per period; pass directly as GIPS or
+    /// risk-adjusted `benchmark_returns`
+    pub benchmark_returns: Vec<Decimal>,
+    pub cumulative_return: Rate,
+    pub annualised_return: Rate,
+    pub rebalance_count: u32,
+    pub excess: Option<ExcessReturnStats>,
+}
+
+/// Build a blended benchmark from index returns, chaining across definition
+/// changes, and compare it with the portfolio if returns are supplied.
+pub fn build_blended_benchmark(
+    input: &BlendedBenchmarkInput,
+) -> CorpFinanceResult<ComputationOutput<BlendedBenchmarkOutput>> {
+    let start = Instant::now();
+    let mut warnings: Vec<String> = Vec::new();
+
+    validate_input(input)?;
+    let n = input.index_returns[0].returns.len();
+
+    let mut periods = Vec::with_capacity(n);
+    let mut benchmark_returns = Vec::with_capacity(n);
+    let mut rebalance_count: u32 = 0;
+    // Current weights aligned with the active segment's components
+    let mut weights: Vec<Decimal> = Vec::new();
+
+    for period in 0..n {
+        let seg_idx = input
+            .segments
+            .iter()
+            .rposition(|s| s.start_period <= period)
+            .unwrap_or(0);
+        let segment = &input.segments[seg_idx];
+        let targets: Vec<Decimal> = segment.components.iter().map(|c| c.weight).collect();
+        let elapsed_in_segment = period - segment.start_period;
+
+        // A new segment always starts at its own target weights
+        let rebalanced = if elapsed_in_segment == 0 {
+            true
+        } else {
+            match segment.rebalancing {
+                RebalancingRule::EveryPeriod => true,
+                RebalancingRule::Calendar { every_n_periods } => {
+                    (elapsed_in_segment as u32).is_multiple_of(every_n_periods)
+                }
+                RebalancingRule::Threshold { tolerance } => weights
+                    .iter()
+                    .zip(&targets)
+                    .any(|(w, t)| (*w - *t).abs() > tolerance),
+                RebalancingRule::BuyAndHold => false,
+            }
+        };
+        if rebalanced {
+            weights = targets;
+            if elapsed_in_segment > 0 {
+                rebalance_count += 1;
+            }
+        }
+
+        let component_returns: Vec<Decimal> = segment
+            .components
+            .iter()
+            .map(|c| index_return(input, &c.index_name, period))
+            .collect();
+        let benchmark_return: Decimal = weights
+            .iter()
+            .zip(&component_returns)
+            .map(|(w, r)| *w * *r)
+            .sum();
+
+        let portfolio_return = input.portfolio_returns.as_ref().map(|p| p[period]);
+        periods.push(BenchmarkPeriod {
+            period,
+            segment: seg_idx,
+            weights: segment
+                .components
+                .iter()
+                .zip(&weights)
+                .map(|(c, w)| ComponentWeight {
+                    index_name: c.index_name.clone(),
+                    weight: *w,
+                })
+                .collect(),
+            rebalanced,
+            benchmark_return,
+            portfolio_return,
+            excess_return: portfolio_return.map(|p| p - benchmark_return),
+        });
+        benchmark_returns.push(benchmark_return);
+
+        // Drift weights with component performance
+        let growth = Decimal::ONE + benchmark_return;
+        if growth > Decimal::ZERO {
+            weights = weights
+                .iter()
+                .zip(&component_returns)
+                .map(|(w, r)| *w * (Decimal::ONE + *r) / growth)
+                .collect();
+        } else {
+            warnings.push(format!(
+                "Benchmark lost 100% or more in period {period}; weights reset to target"
+            ));
+            weights = segment.components.iter().map(|c| c.weight).collect();
+        }
+    }
+
+    let periods_per_year = input.frequency.periods_per_year();
+    let cumulative_return = geometric_link(&benchmark_returns);
+    let annualised_return = annualise(cumulative_return, n, periods_per_year);
+
+    let excess = input.portfolio_returns.as_ref().map(|portfolio| {
+        excess_statistics(
+            portfolio,
+            &benchmark_returns,
+            cumulative_return,
+            periods_per_year,
+        )
+    });
+
+    let output = BlendedBenchmarkOutput {
+        benchmark_name: input.benchmark_name.clone(),
+        periods,
+        benchmark_returns,
+        cumulative_return,
+        annualised_return,
+        rebalance_count,
+        excess,
+    };
+
+    let elapsed = start.elapsed().as_micros() as u64;
+    Ok(with_metadata(
+        "Blended Benchmark (weighted index returns, chain-linked across definition changes)",
+        &serde_json::json!({
+            "observations": n,
+            "segments": input.segments.len(),
+            "frequency": format!("{:?}", input.frequency),
+            "linking": "geometric",
+        }),
+        warnings,
+        elapsed,
+        output,
+    ))
+}
+
+fn index_return(input: &BlendedBenchmarkInput, name: &str, period: usize) -> Decimal {
+    input
+        .index_returns
+        .iter()
+        .find(|s| s.index_name == name)
+        .map(|s| s.returns[period])
+        .unwrap_or(Decimal::ZERO)
+}
+
+fn excess_statistics(
+    portfolio: &[Decimal],
+    benchmark: &[Decimal],
+    cumulative_benchmark: Decimal,
+    periods_per_year: Decimal,
+) -> ExcessReturnStats {
+    let n = portfolio.len();
+    let n_dec = Decimal::from(n as i64);
+    let cumulative_portfolio = geometric_link(portfolio);
+
+    let excess: Vec<Decimal> = portfolio
+        .iter()
+        .zip(benchmark)
+        .map(|(p, b)| p - b)
+        .collect();
+    let excess_mean = excess.iter().sum::<Decimal>() / n_dec;
+    let tracking_error =
+        sqrt_decimal(sample_variance(&excess, excess_mean)) * sqrt_decimal(periods_per_year);
+
+    let annualised_portfolio = annualise(cumulative_portfolio, n, periods_per_year);
+    let annualised_benchmark = annualise(cumulative_benchmark, n, periods_per_year);
+    let annualised_excess_return = annualised_portfolio - annualised_benchmark;
+    let information_ratio = if tracking_error.is_zero() {
+        None
+    } else {
+        Some(annualised_excess_return / tracking_error)
+    };
+
+    let wins = excess.iter().filter(|e| **e > Decimal::ZERO).count();
+
+    // Relative wealth: growth of the portfolio per unit of benchmark growth
+    let mut relative = Decimal::ONE;
+    let mut peak = Decimal::ONE;
+    let mut max_relative_drawdown = Decimal::ZERO;
+    for (p, b) in portfolio.iter().zip(benchmark) {
+        let bench_growth = Decimal::ONE + b;
+        if bench_growth <= Decimal::ZERO {
+            continue;
+        }
+        relative = relative * (Decimal::ONE + p) / bench_growth;
+        peak = peak.max(relative);
+        if peak > Decimal::ZERO {
+            max_relative_drawdown = max_relative_drawdown.max((peak - relative) / peak);
+        }
+    }
+
+    let geometric_excess_return = if Decimal::ONE + cumulative_benchmark > Decimal::ZERO {
+        (Decimal::ONE + cumulative_portfolio) / (Decimal::ONE + cumulative_benchmark) - Decimal::ONE
+    } else {
+        Decimal::ZERO
+    };
+
+    ExcessReturnStats {
+        cumulative_portfolio_return: cumulative_portfolio,
+        arithmetic_excess_return: cumulative_portfolio - cumulative_benchmark,
+        geometric_excess_return,
+        annualised_excess_return,
+        tracking_error,
+        information_ratio,
+        hit_rate: Decimal::from(wins as i64) / n_dec,
+        max_relative_drawdown,
+    }
+}
+
+fn validate_input(input: &BlendedBenchmarkInput) -> CorpFinanceResult<()> {
+    if input.index_returns.is_empty() {
+        return Err(CorpFinanceError::InsufficientData(
+            "At least one index return series is required".into(),
+        ));
+    }
+    let n = input.index_returns[0].returns.len();
+    if n == 0 {
+        return Err(CorpFinanceError::InsufficientData(
+            "Index return series must not be empty".into(),
+        ));
+    }
+    if let Some(s) = input.index_returns.iter().find(|s| s.returns.len() != n) {
+        return Err(CorpFinanceError::InvalidInput {
+            field: format!("index_returns[{}]", s.index_name),
+            reason: "All index series must have the same length".into(),
+        });
+    }
+    if let Some(ref p) = input.portfolio_returns {
+        if p.len() != n {
+            return Err(CorpFinanceError::InvalidInput {
+                field: "portfolio_returns".into(),
+                reason: "Portfolio must have same length as index returns".into(),
+            });
+        }
+    }
+
+    if input.segments.first().map(|s| s.start_period) != Some(0) {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "segments".into(),
+            reason: "The first segment must start at period 0".into(),
+        });
+    }
+    if input
+        .segments
+        .windows(2)
+        .any(|w| w[1].start_period <= w[0].start_period)
+        || input.segments.iter().any(|s| s.start_period >= n)
+    {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "segments".into(),
+            reason: "Segment start periods must be increasing and within the return history".into(),
+        });
+    }
+
+    for (i, seg) in input.segments.iter().enumerate() {
+        let field = format!("segments[{i}]");
+        if seg.components.is_empty() {
+            return Err(CorpFinanceError::InvalidInput {
+                field,
+                reason: "A segment needs at least one component".into(),
+            });
+        }
+        if let Some(c) = seg.components.iter().find(|c| {
+            !input
+                .index_returns
+                .iter()
+                .any(|s| s.index_name == c.index_name)
+        }) {
+            return Err(CorpFinanceError::InvalidInput {
+                field,
+                reason: format!("No return series for index '{}'", c.index_name),
+            });
+        }
+        if seg.components.iter().any(|c| c.weight < Decimal::ZERO) {
+            return Err(CorpFinanceError::InvalidInput {
+                field,
+                reason: "Component weights must be non-negative".into(),
+            });
+        }
+        let total: Decimal = seg.components.iter().map(|c| c.weight).sum();
+        if (total - Decimal::ONE).abs() > dec!(0.0001) {
+            return Err(CorpFinanceError::InvalidInput {
+                field,
+                reason: format!("Component weights sum to {total}, expected 1"),
+            });
+        }
+        match seg.rebalancing {
+            RebalancingRule::Calendar { every_n_periods: 0 } => {
+                return Err(CorpFinanceError::InvalidInput {
+                    field: format!("{field}.rebalancing"),
+                    reason: "every_n_periods must be at least 1".into(),
+                });
+            }
+            RebalancingRule::Threshold { tolerance } if tolerance <= Decimal::ZERO => {
+                return Err(CorpFinanceError::InvalidInput {
+                    field: format!("{field}.rebalancing"),
+                    reason: "tolerance must be positive".into(),
+                });
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+/// Geometric linking of periodic returns
+fn geometric_link(returns: &[Decimal]) -> Decimal {
+    returns
+        .iter()
+        .fold(Decimal::ONE, |acc, r| acc * (Decimal::ONE + r))
+        - Decimal::ONE
+}
+
+/// Annualise a cumulative return observed over `n` periods
+fn annualise(cumulative: Decimal, n: usize, periods_per_year: Decimal) -> Decimal {
+    let growth = Decimal::ONE + cumulative;
+    if n == 0 || growth <= Decimal::ZERO {
+        return Decimal::ZERO;
+    }
+    let years = Decimal::from(n as i64) / periods_per_year;
+    if years == Decimal::ONE {
+        return cumulative;
+    }
+    growth.powd(Decimal::ONE / years) - Decimal::ONE
+}
+
+/// Sample variance (n-1 denominator)
+fn sample_variance(data: &[Decimal], mean: Decimal) -> Decimal {
+    let n = data.len();
+    if n < 2 {
+        return Decimal::ZERO;
+    }
+    let sum_sq: Decimal = data.iter().map(|x| (x - mean) * (x - mean)).sum();
+    sum_sq / Decimal::from((n - 1) as i64)
+}
+
+fn sqrt_decimal(val: Decimal) -> Decimal {
+    if val <= Decimal::ZERO {
+        return Decimal::ZERO;
+    }
+    val.sqrt().unwrap_or(Decimal::ZERO)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn series(name: &str, returns: &[Decimal]) -> IndexReturnSeries {
+        IndexReturnSeries {
+            index_name: name.into(),
+            returns: returns.to_vec(),
+        }
+    }
+
+    fn component(name: &str, weight: Decimal) -> BenchmarkComponent {
+        BenchmarkComponent {
+            index_name: name.into(),
+            weight,
+        }
+    }
+
+    fn sixty_forty(rebalancing: RebalancingRule) -> BlendedBenchmarkInput {
+        BlendedBenchmarkInput {
+            benchmark_name: "60/40".into(),
+            index_returns: vec![
+                series("Equity", &[dec!(0.10), dec!(0.10), dec!(-0.05), dec!(0.10)]),
+                series("Bonds", &[dec!(0.01), dec!(0.01), dec!(0.02), dec!(0.01)]),
+            ],
+            segments: vec![BenchmarkSegment {
+                start_period: 0,
+                components: vec![
+                    component("Equity", dec!(0.6)),
+                    component("Bonds", dec!(0.4)),
+                ],
+                rebalancing,
+            }],
+            portfolio_returns: None,
+            frequency: ReturnFrequency::Quarterly,
+        }
+    }
+
+    fn approx(a: Decimal, b: Decimal) -> bool {
+        (a - b).abs() < dec!(0.000001)
+    }
+
+    #[test]
+    fn test_fixed_weight_blend() {
+        let out = build_blended_benchmark(&sixty_forty(RebalancingRule::EveryPeriod))
+            .unwrap()
+            .result;
+        // 0.6 * 10% + 0.4 * 1% = 6.4%
+        assert_eq!(out.benchmark_returns[0], dec!(0.064));
+        // 0.6 * -5% + 0.4 * 2% = -2.2%
+        assert_eq!(out.benchmark_returns[2], dec!(-0.022));
+        assert_eq!(out.rebalance_count, 3);
+        let linked = dec!(1.064) * dec!(1.064) * dec!(0.978) * dec!(1.064) - Decimal::ONE;
+        assert!(approx(out.cumulative_return, linked));
+        // Four quarters annualise to the cumulative return
+        assert_eq!(out.annualised_return, out.cumulative_return);
+    }
+
+    #[test]
+    fn test_buy_and_hold_drifts_towards_winner() {
+        let out = build_blended_benchmark(&sixty_forty(RebalancingRule::BuyAndHold))
+            .unwrap()
+            .result;
+        assert_eq!(out.rebalance_count, 0);
+        let equity_weight = out.periods[1].weights[0].weight;
+        // 0.6 * 1.10 / 1.064
+        assert!(approx(equity_weight, dec!(0.66) / dec!(1.064)));
+        // Buy-and-hold return equals the weighted growth of each index
+        let equity_growth = dec!(1.10) * dec!(1.10) * dec!(0.95) * dec!(1.10);
+        let bond_growth = dec!(1.01) * dec!(1.01) * dec!(1.02) * dec!(1.01);
+        let expected = dec!(0.6) * equity_growth + dec!(0.4) * bond_growth - Decimal::ONE;
+        assert!(approx(out.cumulative_return, expected));
+    }
+
+    #[test]
+    fn test_calendar_and_threshold_rebalancing() {
+        let calendar = build_blended_benchmark(&sixty_forty(RebalancingRule::Calendar {
+            every_n_periods: 2,
+        }))
+        .unwrap()
+        .result;
+        let flags: Vec<bool> = calendar.periods.iter().map(|p| p.rebalanced).collect();
+        assert_eq!(flags, vec![true, false, true, false]);
+        assert_eq!(calendar.rebalance_count, 1);
+
+        // Equity drifts ~2 points after one quarter, ~4 after two
+        let threshold = build_blended_benchmark(&sixty_forty(RebalancingRule::Threshold {
+            tolerance: dec!(0.03),
+        }))
+        .unwrap()
+        .result;
+        let flags: Vec<bool> = threshold.periods.iter().map(|p| p.rebalanced).collect();
+        assert_eq!(flags, vec![true, false, true, false]);
+    }
+
+    #[test]
+    fn test_chain_linking_across_benchmark_change() {
+        let mut input = sixty_forty(RebalancingRule::EveryPeriod);
+        input.segments.push(BenchmarkSegment {
+            start_period: 2,
+            components: vec![component("Bonds", dec!(1))],
+            rebalancing: RebalancingRule::EveryPeriod,
+        });
+        let out = build_blended_benchmark(&input).unwrap().result;
+        assert_eq!(out.periods[1].segment, 0);
+        assert_eq!(out.periods[2].segment, 1);
+        assert_eq!(out.benchmark_returns[2], dec!(0.02));
+        assert_eq!(out.benchmark_returns[3], dec!(0.01));
+        let linked = dec!(1.064) * dec!(1.064) * dec!(1.02) * dec!(1.01) - Decimal::ONE;
+        assert!(approx(out.cumulative_return, linked));
+        // Switching definitions is not counted as an intra-segment rebalance
+        assert_eq!(out.rebalance_count, 2);
+    }
+
+    #[test]
+    fn test_excess_return_statistics() {
+        let mut input = sixty_forty(RebalancingRule::EveryPeriod);
+        input.portfolio_returns = Some(vec![dec!(0.07), dec!(0.06), dec!(-0.02), dec!(0.065)]);
+        let out = build_blended_benchmark(&input).unwrap().result;
+        let ex = out.excess.unwrap();
+        assert!(approx(out.periods[0].excess_return.unwrap(), dec!(0.006)));
+        assert_eq!(ex.hit_rate, dec!(0.75));
+        assert!(ex.tracking_error > Decimal::ZERO);
+        assert!(ex.information_ratio.unwrap() > Decimal::ZERO);
+        assert!(approx(
+            ex.arithmetic_excess_return,
+            ex.cumulative_portfolio_return - out.cumulative_return
+        ));
+        assert!(ex.geometric_excess_return < ex.arithmetic_excess_return);
+        // Only the second quarter lagged: 1.06 / 1.064 - 1
+        assert!(approx(
+            ex.max_relative_drawdown,
+            Decimal::ONE - dec!(1.06) / dec!(1.064)
+        ));
+    }
+
+    #[test]
+    fn test_weights_must_sum_to_one() {
+        let mut input = sixty_forty(RebalancingRule::EveryPeriod);
+        input.segments[0].components[1].weight = dec!(0.3);
+        match build_blended_benchmark(&input).unwrap_err() {
+            CorpFinanceError::InvalidInput { field, .. } => assert_eq!(field, "segments[0]"),
+            other => panic!("Expected InvalidInput, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_unknown_index_and_bad_segments_rejected() {
+        let mut unknown = sixty_forty(RebalancingRule::EveryPeriod);
+        unknown.segments[0].components[0].index_name = "Commodities".into();
+        assert!(build_blended_benchmark(&unknown).is_err());
+
+        let mut late_start = sixty_forty(RebalancingRule::EveryPeriod);
+        late_start.segments[0].start_period = 1;
+        assert!(build_blended_benchmark(&late_start).is_err());
+
+        let mut short_portfolio = sixty_forty(RebalancingRule::EveryPeriod);
+        short_portfolio.portfolio_returns = Some(vec![dec!(0.01)]);
+        match build_blended_benchmark(&short_portfolio).unwrap_err() {
+            CorpFinanceError::InvalidInput { field, .. } => {
+                assert_eq!(field, "portfolio_returns")
+            }
+            other => panic!("Expected InvalidInput, got {other:?}"),
+        }
+    }
+}
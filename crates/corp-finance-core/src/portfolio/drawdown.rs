@@ -0,0 +1,449 @@
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use rust_decimal::MathematicalOps;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+use std::time::Instant;
+
+use crate::error::CorpFinanceError;
+use crate::types::*;
+use crate::CorpFinanceResult;
+
+// Re-use ReturnFrequency from sibling module
+use super::returns::ReturnFrequency;
+
+/// Input for drawdown and recovery analytics
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DrawdownInput {
+    /// Periodic returns (as decimals)
+    pub returns: Vec<Decimal>,
+    /// Observation frequency
+    pub frequency: ReturnFrequency,
+    /// Confidence level for conditional drawdown-at-risk (default 0.95)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cdar_confidence: Option<Rate>,
+}
+
+/// One point on the underwater curve
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UnderwaterPoint {
+    pub period: usize,
+    /// Growth of 1 unit invested at inception
+    pub wealth_index: Decimal,
+    /// Running high-water mark of the wealth index (inception = 1)
+    pub peak: Decimal,
+    /// Fall below the running peak (positive number)
+    pub drawdown: Rate,
+}
+
+/// A peak-to-recovery drawdown episode
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DrawdownEpisode {
+    /// First period below the prior peak
+    pub start_period: usize,
+    pub trough_period: usize,
+    /// First period back at or above the prior peak; None if still underwater
+    pub recovery_period: Option<usize>,
+    /// Depth at the trough (positive number)
+    pub depth: Rate,
+    /// Periods from start to trough, inclusive
+    pub decline_periods: u32,
+    /// Periods from trough to recovery
+    pub recovery_periods: Option<u32>,
+    /// Periods spent underwater
+    pub duration_periods: u32,
+}
+
+/// Summary of a distribution of durations (in periods)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DurationDistribution {
+    pub count: u32,
+    pub mean: Decimal,
+    pub median: Decimal,
+    pub percentile_90: Decimal,
+    pub max: u32,
+}
+
+/// Output of drawdown and recovery analytics
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DrawdownOutput {
+    pub max_drawdown: Rate,
+    /// Episode containing the maximum drawdown
+    pub max_drawdown_episode: Option<DrawdownEpisode>,
+    pub current_drawdown: Rate,
+    /// Share of periods spent below a prior peak
+    pub time_underwater_pct: Rate,
+    pub episodes: Vec<DrawdownEpisode>,
+    /// Underwater durations of all episodes, including any unrecovered one
+    pub duration_distribution: DurationDistribution,
+    /// Trough-to-recovery times of recovered episodes
+    pub recovery_distribution: DurationDistribution,
+    /// Mean of the worst (1 - confidence) share of drawdown observations
+    pub conditional_drawdown_at_risk: Rate,
+    /// Mean drawdown across all periods
+    pub pain_index: Rate,
+    /// Root-mean-square drawdown across all periods
+    pub ulcer_index: Rate,
+    pub annualised_return: Rate,
+    /// Annualised return / pain index
+    pub pain_ratio: Option<Decimal>,
+    /// Annualised return / ulcer index
+    pub martin_ratio: Option<Decimal>,
+    pub underwater_curve: Vec<UnderwaterPoint>,
+}
+
+/// Calculate drawdown, recovery and underwater statistics from a return series.
+pub fn analyze_drawdowns(
+    input: &DrawdownInput,
+) -> CorpFinanceResult<ComputationOutput<DrawdownOutput>> {
+    let start = Instant::now();
+    let mut warnings: Vec<String> = Vec::new();
+
+    let n = input.returns.len();
+    if n < 2 {
+        return Err(CorpFinanceError::InsufficientData(
+            "At least 2 return observations required for drawdown analysis".into(),
+        ));
+    }
+    if input.returns.iter().any(|r| *r <= -Decimal::ONE) {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "returns".into(),
+            reason: "Returns must be greater than -100%".into(),
+        });
+    }
+    let confidence = input.cdar_confidence.unwrap_or(dec!(0.95));
+    if confidence <= Decimal::ZERO || confidence >= Decimal::ONE {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "cdar_confidence".into(),
+            reason: "Confidence level must be between 0 and 1 (exclusive)".into(),
+        });
+    }
+
+    let underwater_curve = underwater_curve(&input.returns);
+    let episodes = drawdown_episodes(&underwater_curve);
+
+    let drawdowns: Vec<Decimal> = underwater_curve.iter().map(|p| p.drawdown).collect();
+    let n_dec = Decimal::from(n as i64);
+    let max_drawdown = drawdowns.iter().copied().max().unwrap_or(Decimal::ZERO);
+    let current_drawdown = drawdowns[n - 1];
+    let underwater_periods = drawdowns.iter().filter(|d| **d > Decimal::ZERO).count();
+
+    let max_drawdown_episode = episodes
+        .iter()
+        .find(|e| e.depth == max_drawdown && max_drawdown > Decimal::ZERO)
+        .cloned();
+
+    let durations: Vec<u32> = episodes.iter().map(|e| e.duration_periods).collect();
+    let recoveries: Vec<u32> = episodes.iter().filter_map(|e| e.recovery_periods).collect();
+    if episodes.last().is_some_and(|e| e.recovery_period.is_none()) {
+        warnings.push(
+            "Series ends underwater; the open episode is excluded from recovery times".into(),
+        );
+    }
+
+    // CDaR: average of the worst ceil((1 - alpha) * n) drawdown observations
+    let mut sorted = drawdowns.clone();
+    sorted.sort_by(|a, b| b.cmp(a));
+    let tail = ((Decimal::ONE - confidence) * n_dec)
+        .ceil()
+        .to_usize()
+        .unwrap_or(1)
+        .clamp(1, n);
+    let conditional_drawdown_at_risk =
+        sorted[..tail].iter().sum::<Decimal>() / Decimal::from(tail as i64);
+
+    let pain_index = drawdowns.iter().sum::<Decimal>() / n_dec;
+    let ulcer_index = sqrt_decimal(drawdowns.iter().map(|d| d * d).sum::<Decimal>() / n_dec);
+
+    let final_wealth = underwater_curve[n - 1].wealth_index;
+    let years = n_dec / input.frequency.periods_per_year();
+    let annualised_return = if years == Decimal::ONE {
+        final_wealth - Decimal::ONE
+    } else {
+        final_wealth.powd(Decimal::ONE / years) - Decimal::ONE
+    };
+    let pain_ratio = (!pain_index.is_zero()).then(|| annualised_return / pain_index);
+    let martin_ratio = (!ulcer_index.is_zero()).then(|| annualised_return / ulcer_index);
+
+    let output = DrawdownOutput {
+        max_drawdown,
+        max_drawdown_episode,
+        current_drawdown,
+        time_underwater_pct: Decimal::from(underwater_periods as i64) / n_dec,
+        episodes,
+        duration_distribution: distribution(&durations),
+        recovery_distribution: distribution(&recoveries),
+        conditional_drawdown_at_risk,
+        pain_index,
+        ulcer_index,
+        annualised_return,
+        pain_ratio,
+        martin_ratio,
+        underwater_curve,
+    };
+
+    let elapsed = start.elapsed().as_micros() as u64;
+    Ok(with_metadata(
+        "Drawdown Analytics (underwater curve, episodes, CDaR, pain and ulcer indices)",
+        &serde_json::json!({
+            "observations": n,
+            "frequency": format!("{:?}", input.frequency),
+            "cdar_confidence": confidence.to_string(),
+            "wealth_index_base": "1 at inception",
+        }),
+        warnings,
+        elapsed,
+        output,
+    ))
+}
+
+/// Wealth index, running peak and drawdown after each period.
+fn underwater_curve(returns: &[Decimal]) -> Vec<UnderwaterPoint> {
+    let mut wealth = Decimal::ONE;
+    let mut peak = Decimal::ONE;
+    returns
+        .iter()
+        .enumerate()
+        .map(|(period, r)| {
+            wealth *= Decimal::ONE + r;
+            peak = peak.max(wealth);
+            UnderwaterPoint {
+                period,
+                wealth_index: wealth,
+                peak,
+                drawdown: (peak - wealth) / peak,
+            }
+        })
+        .collect()
+}
+
+/// Split the underwater curve into peak-to-recovery episodes.
+fn drawdown_episodes(curve: &[UnderwaterPoint]) -> Vec<DrawdownEpisode> {
+    let mut episodes = Vec::new();
+    // (start, trough, depth) of the episode in progress
+    let mut open: Option<(usize, usize, Decimal)> = None;
+
+    for p in curve {
+        match (open, p.drawdown > Decimal::ZERO) {
+            (None, true) => open = Some((p.period, p.period, p.drawdown)),
+            (Some((s, _, depth)), true) if p.drawdown > depth => {
+                open = Some((s, p.period, p.drawdown))
+            }
+            (Some((s, trough, depth)), false) => {
+                episodes.push(episode(s, trough, depth, Some(p.period)));
+                open = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some((s, trough, depth)) = open {
+        let end = curve.len();
+        let mut e = episode(s, trough, depth, None);
+        e.duration_periods = (end - s) as u32;
+        episodes.push(e);
+    }
+    episodes
+}
+
+fn episode(
+    start: usize,
+    trough: usize,
+    depth: Decimal,
+    recovery: Option<usize>,
+) -> DrawdownEpisode {
+    DrawdownEpisode {
+        start_period: start,
+        trough_period: trough,
+        recovery_period: recovery,
+        depth,
+        decline_periods: (trough - start + 1) as u32,
+        recovery_periods: recovery.map(|r| (r - trough) as u32),
+        duration_periods: recovery.map(|r| (r - start) as u32).unwrap_or(0),
+    }
+}
+
+fn distribution(values: &[u32]) -> DurationDistribution {
+    if values.is_empty() {
+        return DurationDistribution {
+            count: 0,
+            mean: Decimal::ZERO,
+            median: Decimal::ZERO,
+            percentile_90: Decimal::ZERO,
+            max: 0,
+        };
+    }
+    let mut sorted = values.to_vec();
+    sorted.sort_unstable();
+    let count = sorted.len();
+    let mean =
+        Decimal::from(sorted.iter().map(|v| *v as i64).sum::<i64>()) / Decimal::from(count as i64);
+    DurationDistribution {
+        count: count as u32,
+        mean,
+        median: percentile(&sorted, dec!(0.5)),
+        percentile_90: percentile(&sorted, dec!(0.9)),
+        max: sorted[count - 1],
+    }
+}
+
+/// Linearly interpolated percentile of sorted values.
+fn percentile(sorted: &[u32], p: Decimal) -> Decimal {
+    let rank = p * Decimal::from((sorted.len() - 1) as i64);
+    let lo = rank.floor().to_usize().unwrap_or(0).min(sorted.len() - 1);
+    let hi = (lo + 1).min(sorted.len() - 1);
+    let frac = rank - rank.floor();
+    Decimal::from(sorted[lo]) + frac * (Decimal::from(sorted[hi]) - Decimal::from(sorted[lo]))
+}
+
+fn sqrt_decimal(val: Decimal) -> Decimal {
+    if val <= Decimal::ZERO {
+        return Decimal::ZERO;
+    }
+    val.sqrt().unwrap_or(Decimal::ZERO)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn monthly(returns: Vec<Decimal>) -> DrawdownInput {
+        DrawdownInput {
+            returns,
+            frequency: ReturnFrequency::Monthly,
+            cdar_confidence: None,
+        }
+    }
+
+    /// Two episodes: -10% recovered after two periods, then -19% never recovered
+    fn two_episode_returns() -> Vec<Decimal> {
+        vec![
+            dec!(0.10),
+            dec!(-0.10),
+            dec!(0.05),
+            dec!(0.10),
+            dec!(0.02),
+            dec!(-0.10),
+            dec!(-0.10),
+            dec!(0.05),
+        ]
+    }
+
+    #[test]
+    fn test_underwater_curve() {
+        let out = analyze_drawdowns(&monthly(two_episode_returns()))
+            .unwrap()
+            .result;
+        let curve = &out.underwater_curve;
+        assert_eq!(curve.len(), 8);
+        assert_eq!(curve[0].drawdown, Decimal::ZERO);
+        assert_eq!(curve[1].wealth_index, dec!(0.99));
+        assert_eq!(curve[1].peak, dec!(1.10));
+        assert_eq!(curve[1].drawdown, dec!(0.1));
+        // 0.99 * 1.05 * 1.10 = 1.14345 sets a new peak
+        assert_eq!(curve[3].drawdown, Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_episodes_and_max_drawdown() {
+        let out = analyze_drawdowns(&monthly(two_episode_returns()))
+            .unwrap()
+            .result;
+        assert_eq!(out.episodes.len(), 2);
+
+        let first = &out.episodes[0];
+        assert_eq!(first.start_period, 1);
+        assert_eq!(first.trough_period, 1);
+        assert_eq!(first.recovery_period, Some(3));
+        assert_eq!(first.recovery_periods, Some(2));
+        assert_eq!(first.duration_periods, 2);
+
+        // Peak after period 4, then 0.9 * 0.9 = 0.81 of it
+        let second = &out.episodes[1];
+        assert_eq!(second.start_period, 5);
+        assert_eq!(second.trough_period, 6);
+        assert_eq!(second.recovery_period, None);
+        assert_eq!(second.decline_periods, 2);
+        assert_eq!(second.duration_periods, 3);
+        assert_eq!(second.depth, dec!(0.19));
+
+        assert_eq!(out.max_drawdown, dec!(0.19));
+        assert_eq!(out.max_drawdown_episode.unwrap().start_period, 5);
+        assert_eq!(out.time_underwater_pct, dec!(0.625));
+    }
+
+    #[test]
+    fn test_duration_and_recovery_distributions() {
+        let res = analyze_drawdowns(&monthly(two_episode_returns())).unwrap();
+        let out = &res.result;
+        assert_eq!(out.duration_distribution.count, 2);
+        assert_eq!(out.duration_distribution.mean, dec!(2.5));
+        assert_eq!(out.duration_distribution.max, 3);
+        // Only the first episode recovered
+        assert_eq!(out.recovery_distribution.count, 1);
+        assert_eq!(out.recovery_distribution.median, dec!(2));
+        assert!(res.warnings.iter().any(|w| w.contains("underwater")));
+    }
+
+    #[test]
+    fn test_pain_and_ulcer_indices() {
+        let out = analyze_drawdowns(&monthly(vec![
+            dec!(-0.10),
+            dec!(0.0),
+            dec!(0.20),
+            dec!(0.0),
+        ]))
+        .unwrap()
+        .result;
+        // Drawdowns: 0.1, 0.1, 0, 0
+        assert_eq!(out.pain_index, dec!(0.05));
+        let expected_ulcer = (dec!(0.02) / dec!(4)).sqrt().unwrap();
+        assert!((out.ulcer_index - expected_ulcer).abs() < dec!(0.0000001));
+        assert!(out.ulcer_index >= out.pain_index);
+        assert!(out.pain_ratio.unwrap() > Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_conditional_drawdown_at_risk() {
+        let returns = two_episode_returns();
+        let out = analyze_drawdowns(&DrawdownInput {
+            cdar_confidence: Some(dec!(0.75)),
+            ..monthly(returns)
+        })
+        .unwrap()
+        .result;
+        // Worst 2 of 8 drawdowns: 0.19 and 1 - 0.81 * 1.05 = 0.1495
+        assert_eq!(
+            out.conditional_drawdown_at_risk,
+            (dec!(0.19) + dec!(0.1495)) / dec!(2)
+        );
+        assert!(out.conditional_drawdown_at_risk <= out.max_drawdown);
+        assert!(out.conditional_drawdown_at_risk >= out.pain_index);
+    }
+
+    #[test]
+    fn test_no_drawdown() {
+        let out = analyze_drawdowns(&monthly(vec![dec!(0.01), dec!(0.02), dec!(0.01)]))
+            .unwrap()
+            .result;
+        assert!(out.episodes.is_empty());
+        assert_eq!(out.max_drawdown, Decimal::ZERO);
+        assert!(out.max_drawdown_episode.is_none());
+        assert!(out.martin_ratio.is_none());
+        assert_eq!(out.duration_distribution.count, 0);
+    }
+
+    #[test]
+    fn test_invalid_inputs() {
+        assert!(analyze_drawdowns(&monthly(vec![dec!(0.01)])).is_err());
+        assert!(analyze_drawdowns(&monthly(vec![dec!(0.01), dec!(-1.0)])).is_err());
+        let bad_conf = DrawdownInput {
+            cdar_confidence: Some(dec!(1)),
+            ..monthly(vec![dec!(0.01), dec!(-0.02)])
+        };
+        match analyze_drawdowns(&bad_conf).unwrap_err() {
+            CorpFinanceError::InvalidInput { field, .. } => assert_eq!(field, "cdar_confidence"),
+            other => panic!("Expected InvalidInput, got {other:?}"),
+        }
+    }
+}
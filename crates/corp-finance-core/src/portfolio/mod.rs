@@ -1,3 +1,10 @@
+pub mod benchmarks;
+pub mod drawdown;
+pub mod performance_ratios;
 pub mod returns;
+#[cfg(feature = "portfolio_valuation")]
+pub mod revaluation_var;
 pub mod risk;
 pub mod sizing;
+#[cfg(feature = "portfolio_valuation")]
+pub mod valuation;
@@ -0,0 +1,496 @@
+use rust_decimal::Decimal;
+use rust_decimal::MathematicalOps;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+use std::time::Instant;
+
+use crate::error::CorpFinanceError;
+use crate::types::*;
+use crate::CorpFinanceResult;
+
+use super::returns::{downside_deviation, max_drawdown, sample_variance, ReturnFrequency};
+
+/// Input for downside, tail-adjusted and capture performance ratios.
+/// Mirrors `RiskAdjustedInput` with additional Omega thresholds.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PerformanceRatiosInput {
+    /// Periodic returns (as decimals, e.g. 0.05 = 5%)
+    pub returns: Vec<Decimal>,
+    /// Risk-free rate (annualised)
+    pub risk_free_rate: Rate,
+    /// Benchmark returns (same frequency as `returns`), required for capture ratios
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub benchmark_returns: Option<Vec<Decimal>>,
+    /// Observation frequency
+    pub frequency: ReturnFrequency,
+    /// Minimum acceptable return for Sortino (annualised); defaults to risk_free_rate
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_return: Option<Rate>,
+    /// Omega ratio thresholds (annualised); defaults to 0 and the risk-free rate
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub omega_thresholds: Option<Vec<Rate>>,
+}
+
+/// Omega ratio at one threshold
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OmegaRatio {
+    /// Annualised threshold
+    pub threshold: Rate,
+    /// Sum of gains above the threshold / sum of losses below it;
+    /// None when no period falls below the threshold
+    pub omega: Option<Decimal>,
+}
+
+/// Up- and down-market capture versus the benchmark
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CaptureRatios {
+    /// Portfolio / benchmark geometric mean return in periods the benchmark rose
+    pub upside_capture: Option<Decimal>,
+    /// Portfolio / benchmark geometric mean return in periods the benchmark fell
+    pub downside_capture: Option<Decimal>,
+    /// Upside capture / downside capture
+    pub capture_ratio: Option<Decimal>,
+    pub up_periods: u32,
+    pub down_periods: u32,
+}
+
+/// Output of performance ratio calculations
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PerformanceRatiosOutput {
+    /// Arithmetic mean return x periods per year
+    pub annualised_return: Rate,
+    /// Compound annual growth rate
+    pub cagr: Rate,
+    pub annualised_volatility: Rate,
+    pub sharpe_ratio: Decimal,
+    /// Annualised downside deviation below the target return
+    pub downside_deviation: Rate,
+    /// (annualised return - target) / downside deviation
+    pub sortino_ratio: Option<Decimal>,
+    pub omega_ratios: Vec<OmegaRatio>,
+    pub max_drawdown: Rate,
+    /// CAGR / max drawdown over the trailing 36 months
+    pub calmar_ratio: Option<Decimal>,
+    /// CAGR / max drawdown over the full history
+    pub mar_ratio: Option<Decimal>,
+    /// Sample skewness of periodic returns
+    pub skewness: Decimal,
+    /// Sample excess kurtosis of periodic returns
+    pub excess_kurtosis: Decimal,
+    /// Pezier-White adjusted Sharpe ratio
+    pub adjusted_sharpe_ratio: Decimal,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub capture: Option<CaptureRatios>,
+}
+
+/// Calculate Sortino, Omega, Calmar/MAR, adjusted Sharpe and capture ratios.
+pub fn calculate_performance_ratios(
+    input: &PerformanceRatiosInput,
+) -> CorpFinanceResult<ComputationOutput<PerformanceRatiosOutput>> {
+    let start = Instant::now();
+    let mut warnings: Vec<String> = Vec::new();
+
+    let n = input.returns.len();
+    if n < 2 {
+        return Err(CorpFinanceError::InsufficientData(
+            "At least 2 return observations required".into(),
+        ));
+    }
+    if input.returns.iter().any(|r| *r <= -Decimal::ONE) {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "returns".into(),
+            reason: "Returns must be greater than -100%".into(),
+        });
+    }
+    if let Some(ref bench) = input.benchmark_returns {
+        if bench.len() != n {
+            return Err(CorpFinanceError::InvalidInput {
+                field: "benchmark_returns".into(),
+                reason: "Benchmark must have same length as returns".into(),
+            });
+        }
+    }
+
+    let n_dec = Decimal::from(n as i64);
+    let periods = input.frequency.periods_per_year();
+
+    let mean = input.returns.iter().sum::<Decimal>() / n_dec;
+    let annualised_return = mean * periods;
+    let variance = sample_variance(&input.returns, mean);
+    let std_dev = sqrt_decimal(variance);
+    let annualised_volatility = std_dev * sqrt_decimal(periods);
+    let sharpe_ratio = if annualised_volatility.is_zero() {
+        Decimal::ZERO
+    } else {
+        (annualised_return - input.risk_free_rate) / annualised_volatility
+    };
+    let cagr = compound_annual_growth(&input.returns, periods);
+
+    // Sortino against the minimum acceptable return
+    let target = input.target_return.unwrap_or(input.risk_free_rate);
+    let downside = downside_deviation(&input.returns, target / periods) * sqrt_decimal(periods);
+    let sortino_ratio = (!downside.is_zero()).then(|| (annualised_return - target) / downside);
+
+    let thresholds = input
+        .omega_thresholds
+        .clone()
+        .unwrap_or_else(|| vec![Decimal::ZERO, input.risk_free_rate]);
+    let omega_ratios = thresholds
+        .iter()
+        .map(|t| OmegaRatio {
+            threshold: *t,
+            omega: omega(&input.returns, *t / periods),
+        })
+        .collect();
+
+    // Calmar uses the trailing 36 months; MAR uses the whole record
+    let max_dd = max_drawdown(&input.returns);
+    let mar_ratio = (!max_dd.is_zero()).then(|| cagr / max_dd);
+    let window = (dec!(3) * periods)
+        .to_string()
+        .parse::<usize>()
+        .unwrap_or(n);
+    if n < window {
+        warnings.push("Fewer than 36 months of returns; Calmar ratio uses the full history".into());
+    }
+    let calmar_ratio = if window < n {
+        let trailing = &input.returns[n - window..];
+        let trailing_dd = max_drawdown(trailing);
+        (!trailing_dd.is_zero()).then(|| compound_annual_growth(trailing, periods) / trailing_dd)
+    } else {
+        mar_ratio
+    };
+
+    let (skewness, excess_kurtosis) = higher_moments(&input.returns, mean, variance);
+    if n < 4 {
+        warnings.push("Fewer than 4 observations; kurtosis set to zero".into());
+    }
+
+    // Pezier-White: penalise negative skew and fat tails
+    let adjusted_sharpe_ratio = sharpe_ratio
+        * (Decimal::ONE + skewness / dec!(6) * sharpe_ratio
+            - excess_kurtosis / dec!(24) * sharpe_ratio * sharpe_ratio);
+
+    let capture = input
+        .benchmark_returns
+        .as_ref()
+        .map(|bench| capture_ratios(&input.returns, bench));
+    if let Some(ref c) = capture {
+        if c.up_periods == 0 || c.down_periods == 0 {
+            warnings.push(
+                "Benchmark has no up or no down periods; the matching capture ratio is undefined"
+                    .into(),
+            );
+        }
+    }
+
+    let output = PerformanceRatiosOutput {
+        annualised_return,
+        cagr,
+        annualised_volatility,
+        sharpe_ratio,
+        downside_deviation: downside,
+        sortino_ratio,
+        omega_ratios,
+        max_drawdown: max_dd,
+        calmar_ratio,
+        mar_ratio,
+        skewness,
+        excess_kurtosis,
+        adjusted_sharpe_ratio,
+        capture,
+    };
+
+    let elapsed = start.elapsed().as_micros() as u64;
+    Ok(with_metadata(
+        "Performance Ratios (Sortino, Omega, Calmar/MAR, Adjusted Sharpe, Capture)",
+        &serde_json::json!({
+            "observations": n,
+            "frequency": format!("{:?}", input.frequency),
+            "risk_free_rate": input.risk_free_rate.to_string(),
+            "target_return": target.to_string(),
+            "calmar_window": "trailing 36 months",
+            "adjusted_sharpe": "SR * (1 + S/6 * SR - K/24 * SR^2), K = excess kurtosis",
+        }),
+        warnings,
+        elapsed,
+        output,
+    ))
+}
+
+/// Compound annual growth rate of a return series.
+fn compound_annual_growth(returns: &[Decimal], periods_per_year: Decimal) -> Rate {
+    let growth: Decimal = returns.iter().map(|r| Decimal::ONE + r).product();
+    let years = Decimal::from(returns.len() as i64) / periods_per_year;
+    if years == Decimal::ONE {
+        growth - Decimal::ONE
+    } else {
+        growth.powd(Decimal::ONE / years) - Decimal::ONE
+    }
+}
+
+/// Omega ratio at a per-period threshold.
+fn omega(returns: &[Decimal], threshold: Decimal) -> Option<Decimal> {
+    let gains: Decimal = returns
+        .iter()
+        .map(|r| (r - threshold).max(Decimal::ZERO))
+        .sum();
+    let losses: Decimal = returns
+        .iter()
+        .map(|r| (threshold - r).max(Decimal::ZERO))
+        .sum();
+    (!losses.is_zero()).then(|| gains / losses)
+}
+
+/// Bias-adjusted sample skewness and excess kurtosis.
+fn higher_moments(returns: &[Decimal], mean: Decimal, variance: Decimal) -> (Decimal, Decimal) {
+    let n = returns.len();
+    if n < 3 || variance.is_zero() {
+        return (Decimal::ZERO, Decimal::ZERO);
+    }
+    let n_dec = Decimal::from(n as i64);
+    let n1 = n_dec - Decimal::ONE;
+    let n2 = n_dec - dec!(2);
+    let std_dev = sqrt_decimal(variance);
+
+    let m3: Decimal = returns
+        .iter()
+        .map(|r| (r - mean) * (r - mean) * (r - mean))
+        .sum();
+    let skewness = n_dec / (n1 * n2) * m3 / (variance * std_dev);
+
+    let kurtosis = if n < 4 {
+        Decimal::ZERO
+    } else {
+        let n3 = n_dec - dec!(3);
+        let m4: Decimal = returns
+            .iter()
+            .map(|r| {
+                let d = (r - mean) * (r - mean);
+                d * d
+            })
+            .sum();
+        n_dec * (n_dec + Decimal::ONE) / (n1 * n2 * n3) * m4 / (variance * variance)
+            - dec!(3) * n1 * n1 / (n2 * n3)
+    };
+    (skewness, kurtosis)
+}
+
+fn capture_ratios(returns: &[Decimal], bench: &[Decimal]) -> CaptureRatios {
+    let up: Vec<(Decimal, Decimal)> = returns
+        .iter()
+        .zip(bench)
+        .filter(|(_, b)| **b > Decimal::ZERO)
+        .map(|(r, b)| (*r, *b))
+        .collect();
+    let down: Vec<(Decimal, Decimal)> = returns
+        .iter()
+        .zip(bench)
+        .filter(|(_, b)| **b < Decimal::ZERO)
+        .map(|(r, b)| (*r, *b))
+        .collect();
+
+    let capture = |pairs: &[(Decimal, Decimal)]| -> Option<Decimal> {
+        if pairs.is_empty() {
+            return None;
+        }
+        let port: Vec<Decimal> = pairs.iter().map(|(r, _)| *r).collect();
+        let bm: Vec<Decimal> = pairs.iter().map(|(_, b)| *b).collect();
+        let bm_mean = geometric_mean(&bm);
+        (!bm_mean.is_zero()).then(|| geometric_mean(&port) / bm_mean)
+    };
+
+    let upside_capture = capture(&up);
+    let downside_capture = capture(&down);
+    let capture_ratio = match (upside_capture, downside_capture) {
+        (Some(u), Some(d)) if !d.is_zero() => Some(u / d),
+        _ => None,
+    };
+
+    CaptureRatios {
+        upside_capture,
+        downside_capture,
+        capture_ratio,
+        up_periods: up.len() as u32,
+        down_periods: down.len() as u32,
+    }
+}
+
+/// Geometric mean periodic return.
+fn geometric_mean(returns: &[Decimal]) -> Decimal {
+    let growth: Decimal = returns.iter().map(|r| Decimal::ONE + r).product();
+    if returns.len() == 1 {
+        return growth - Decimal::ONE;
+    }
+    growth.powd(Decimal::ONE / Decimal::from(returns.len() as i64)) - Decimal::ONE
+}
+
+fn sqrt_decimal(val: Decimal) -> Decimal {
+    if val <= Decimal::ZERO {
+        return Decimal::ZERO;
+    }
+    val.sqrt().unwrap_or(Decimal::ZERO)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn sample_returns() -> Vec<Decimal> {
+        vec![
+            dec!(0.05),
+            dec!(-0.02),
+            dec!(0.03),
+            dec!(0.01),
+            dec!(-0.01),
+            dec!(0.04),
+            dec!(0.02),
+            dec!(-0.03),
+            dec!(0.06),
+            dec!(0.01),
+            dec!(-0.02),
+            dec!(0.03),
+        ]
+    }
+
+    fn monthly(returns: Vec<Decimal>) -> PerformanceRatiosInput {
+        PerformanceRatiosInput {
+            returns,
+            risk_free_rate: dec!(0.02),
+            benchmark_returns: None,
+            frequency: ReturnFrequency::Monthly,
+            target_return: None,
+            omega_thresholds: None,
+        }
+    }
+
+    fn approx_eq(a: Decimal, b: Decimal, tol: Decimal) -> bool {
+        (a - b).abs() < tol
+    }
+
+    #[test]
+    fn test_omega_ratio() {
+        let input = PerformanceRatiosInput {
+            omega_thresholds: Some(vec![dec!(0), dec!(0.12)]),
+            ..monthly(sample_returns())
+        };
+        let out = calculate_performance_ratios(&input).unwrap().result;
+        assert_eq!(out.omega_ratios.len(), 2);
+        // Gains above 0: 0.25; losses below 0: 0.08
+        assert_eq!(out.omega_ratios[0].omega, Some(dec!(0.25) / dec!(0.08)));
+        // A higher threshold lowers Omega
+        assert!(out.omega_ratios[1].omega.unwrap() < out.omega_ratios[0].omega.unwrap());
+    }
+
+    #[test]
+    fn test_omega_undefined_without_losses() {
+        let input = PerformanceRatiosInput {
+            omega_thresholds: Some(vec![dec!(0)]),
+            ..monthly(vec![dec!(0.01), dec!(0.02), dec!(0.03)])
+        };
+        let out = calculate_performance_ratios(&input).unwrap().result;
+        assert!(out.omega_ratios[0].omega.is_none());
+        assert!(out.mar_ratio.is_none());
+    }
+
+    #[test]
+    fn test_sortino_uses_target() {
+        let low = calculate_performance_ratios(&PerformanceRatiosInput {
+            target_return: Some(dec!(0)),
+            ..monthly(sample_returns())
+        })
+        .unwrap()
+        .result;
+        let high = calculate_performance_ratios(&PerformanceRatiosInput {
+            target_return: Some(dec!(0.10)),
+            ..monthly(sample_returns())
+        })
+        .unwrap()
+        .result;
+        assert!(high.downside_deviation > low.downside_deviation);
+        assert!(high.sortino_ratio.unwrap() < low.sortino_ratio.unwrap());
+    }
+
+    #[test]
+    fn test_calmar_uses_trailing_window() {
+        // Crash in year one, smooth growth for the next three years
+        let mut returns = vec![dec!(-0.30)];
+        returns.extend(vec![dec!(0.01); 11]);
+        let mut tail = vec![dec!(0.01); 36];
+        tail[20] = dec!(-0.05);
+        returns.extend(tail);
+        let res = calculate_performance_ratios(&monthly(returns)).unwrap();
+        let out = &res.result;
+        assert!(out.max_drawdown >= dec!(0.30));
+        // The trailing window only sees the 5% dip
+        assert!(out.calmar_ratio.unwrap() > out.mar_ratio.unwrap());
+        assert!(res.warnings.is_empty());
+    }
+
+    #[test]
+    fn test_adjusted_sharpe_penalises_negative_skew() {
+        // Same mean and volatility, mirrored distributions
+        let right = vec![
+            dec!(0.01),
+            dec!(0.01),
+            dec!(0.01),
+            dec!(0.01),
+            dec!(0.01),
+            dec!(0.08),
+            dec!(0.00),
+            dec!(0.00),
+        ];
+        let mean = right.iter().sum::<Decimal>() / dec!(8);
+        let left: Vec<Decimal> = right.iter().map(|r| dec!(2) * mean - r).collect();
+        let pos = calculate_performance_ratios(&monthly(right))
+            .unwrap()
+            .result;
+        let neg = calculate_performance_ratios(&monthly(left)).unwrap().result;
+        assert!(pos.skewness > Decimal::ZERO);
+        assert!(approx_eq(neg.skewness, -pos.skewness, dec!(0.0000001)));
+        assert!(approx_eq(
+            pos.sharpe_ratio,
+            neg.sharpe_ratio,
+            dec!(0.0000001)
+        ));
+        assert!(pos.adjusted_sharpe_ratio > neg.adjusted_sharpe_ratio);
+        assert!(pos.excess_kurtosis > Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_capture_ratios() {
+        let bench = vec![dec!(0.02), dec!(-0.02), dec!(0.04), dec!(-0.04)];
+        // Captures all of the upside and half of the downside
+        let input = PerformanceRatiosInput {
+            benchmark_returns: Some(bench),
+            ..monthly(vec![dec!(0.02), dec!(-0.01), dec!(0.04), dec!(-0.02)])
+        };
+        let out = calculate_performance_ratios(&input).unwrap().result;
+        let c = out.capture.unwrap();
+        assert_eq!(c.up_periods, 2);
+        assert_eq!(c.down_periods, 2);
+        assert!(approx_eq(
+            c.upside_capture.unwrap(),
+            Decimal::ONE,
+            dec!(0.000001)
+        ));
+        assert!(c.downside_capture.unwrap() < dec!(0.52));
+        assert!(c.capture_ratio.unwrap() > Decimal::ONE);
+    }
+
+    #[test]
+    fn test_invalid_inputs() {
+        assert!(calculate_performance_ratios(&monthly(vec![dec!(0.01)])).is_err());
+        let mismatch = PerformanceRatiosInput {
+            benchmark_returns: Some(vec![dec!(0.01)]),
+            ..monthly(sample_returns())
+        };
+        match calculate_performance_ratios(&mismatch).unwrap_err() {
+            CorpFinanceError::InvalidInput { field, .. } => {
+                assert_eq!(field, "benchmark_returns")
+            }
+            other => panic!("Expected InvalidInput, got {other:?}"),
+        }
+    }
+}
@@ -212,7 +212,7 @@ pub fn calculate_risk_adjusted_returns(
 }
 
 /// Sample variance (n-1 denominator)
-fn sample_variance(data: &[Decimal], mean: Decimal) -> Decimal {
+pub(super) fn sample_variance(data: &[Decimal], mean: Decimal) -> Decimal {
     let n = data.len();
     if n < 2 {
         return Decimal::ZERO;
@@ -222,7 +222,7 @@ fn sample_variance(data: &[Decimal], mean: Decimal) -> Decimal {
 }
 
 /// Downside deviation: std dev of returns below target
-fn downside_deviation(returns: &[Decimal], target: Decimal) -> Decimal {
+pub(super) fn downside_deviation(returns: &[Decimal], target: Decimal) -> Decimal {
     let n = returns.len();
     if n == 0 {
         return Decimal::ZERO;
@@ -242,7 +242,7 @@ fn downside_deviation(returns: &[Decimal], target: Decimal) -> Decimal {
 }
 
 /// Maximum drawdown from a return series
-fn max_drawdown(returns: &[Decimal]) -> Rate {
+pub(super) fn max_drawdown(returns: &[Decimal]) -> Rate {
     let mut cumulative = Decimal::ONE;
     let mut peak = Decimal::ONE;
     let mut max_dd = Decimal::ZERO;
@@ -263,7 +263,12 @@ fn max_drawdown(returns: &[Decimal]) -> Rate {
 }
 
 /// Covariance between two series (sample, n-1)
-fn covariance(x: &[Decimal], y: &[Decimal], x_mean: Decimal, y_mean: Decimal) -> Decimal {
+pub(super) fn covariance(
+    x: &[Decimal],
+    y: &[Decimal],
+    x_mean: Decimal,
+    y_mean: Decimal,
+) -> Decimal {
     let n = x.len();
     if n < 2 {
         return Decimal::ZERO;
@@ -0,0 +1,795 @@
+//! Value at Risk by full revaluation of a book of typed positions.
+//!
+//! `portfolio::risk` reads VaR off a return series, which cannot see option
+//! convexity or a bond's non-linear price-yield curve. Here every scenario
+//! shifts the shared market data set and each position is repriced by its
+//! own pricer (see `portfolio::valuation`), so the P&L distribution carries
+//! gamma, vega and FX translation. Scenarios are historical factor moves or
+//! correlated Monte Carlo draws. A first-order VaR built from the book's
+//! bump-and-revalue sensitivities on the same scenarios is reported
+//! alongside, so the non-linear part of the risk is visible.
+
+use std::time::Instant;
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use statrs::distribution::Normal;
+
+use super::valuation::{
+    book_values, fx_quotes, value_portfolio, PortfolioValuationInput, RiskSensitivities,
+    EQUITY_BUMP, ONE_BP, VOL_BUMP,
+};
+use crate::error::CorpFinanceError;
+use crate::limits;
+use crate::scenarios::market_shift::MarketScenario;
+use crate::types::{fx_conversion_rate, with_metadata, ComputationOutput, Currency, Money, Rate};
+use crate::CorpFinanceResult;
+
+/// Number of worst scenarios listed in the output
+const WORST_SCENARIOS: usize = 5;
+
+/// Factors simulated as log moves; the rest move additively
+const RELATIVE_FACTORS: [&str; 3] = ["equity_market", "commodities", "fx_usd"];
+
+// ---------------------------------------------------------------------------
+// Types
+// ---------------------------------------------------------------------------
+
+/// Where the market scenarios come from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum VarMethod {
+    /// Observed factor moves over the VaR horizon, one scenario each
+    Historical,
+    /// Correlated normal factor moves drawn at random
+    MonteCarlo,
+}
+
+/// One simulated market factor.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SimulatedFactor {
+    /// Factor name as in `MarketScenario::from_factor_shocks`
+    pub factor: String,
+    /// Standard deviation of the move over the VaR horizon: absolute for
+    /// rates, spreads and volatility, of the log move for equities,
+    /// commodities and the dollar
+    pub volatility: Rate,
+    /// Mean move over the horizon (default 0)
+    #[serde(default)]
+    pub drift: Decimal,
+}
+
+/// Monte Carlo scenario generator.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MonteCarloScenarios {
+    pub factors: Vec<SimulatedFactor>,
+    /// Correlation matrix in `factors` order (default independent)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub correlation_matrix: Option<Vec<Vec<Decimal>>>,
+    pub num_simulations: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub seed: Option<u64>,
+}
+
+/// Input for full-revaluation VaR.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RevaluationVarInput {
+    /// Book and base market, as for `value_portfolio`
+    pub portfolio: PortfolioValuationInput,
+    pub method: VarMethod,
+    /// Factor moves over the VaR horizon (historical method)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub historical_scenarios: Vec<MarketScenario>,
+    /// Scenario generator (Monte Carlo method)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub monte_carlo: Option<MonteCarloScenarios>,
+    /// Confidence level (e.g. 0.99)
+    pub confidence_level: Rate,
+}
+
+/// Book P&L in one scenario.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScenarioPnl {
+    pub scenario: String,
+    /// Full-revaluation P&L in the base currency
+    pub pnl: Money,
+    /// First-order P&L from the book's sensitivities
+    pub linear_pnl: Money,
+}
+
+/// One position's share of the book's tail risk.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PositionVarContribution {
+    pub id: String,
+    pub instrument_type: String,
+    pub market_value: Money,
+    /// Position P&L in the VaR scenario
+    pub pnl_at_var: Money,
+    /// Average position loss across the tail; sums to the book's ES
+    pub expected_shortfall_contribution: Money,
+    /// VaR of the position held alone
+    pub standalone_var: Money,
+}
+
+/// Output of full-revaluation VaR. Losses are positive numbers.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RevaluationVarOutput {
+    pub method: VarMethod,
+    pub base_currency: Currency,
+    /// Net market value of the book before any scenario
+    pub base_value: Money,
+    pub num_scenarios: usize,
+    pub confidence_level: Rate,
+    pub var: Money,
+    pub expected_shortfall: Money,
+    /// VaR from first-order P&L on the same scenarios
+    pub linear_var: Money,
+    pub linear_expected_shortfall: Money,
+    /// VaR less linear VaR: positive when convexity adds to the loss
+    pub nonlinearity: Money,
+    pub mean_pnl: Money,
+    pub pnl_std_dev: Money,
+    pub worst_loss: Money,
+    pub best_gain: Money,
+    /// Scenario at the VaR quantile
+    pub var_scenario: String,
+    pub positions: Vec<PositionVarContribution>,
+    pub worst_scenarios: Vec<ScenarioPnl>,
+}
+
+/// VaR and ES of one P&L vector.
+struct Tail {
+    var: Money,
+    expected_shortfall: Money,
+    /// Index of the scenario at the VaR quantile
+    var_scenario: usize,
+    /// Scenarios losing at least the VaR
+    scenarios: Vec<usize>,
+}
+
+// ---------------------------------------------------------------------------
+// Scenario generation
+// ---------------------------------------------------------------------------
+
+fn cholesky(corr: &[Vec<Decimal>], dims: usize) -> CorpFinanceResult<Vec<Vec<f64>>> {
+    let invalid = |reason: String| CorpFinanceError::InvalidInput {
+        field: "monte_carlo.correlation_matrix".into(),
+        reason,
+    };
+    if corr.len() != dims || corr.iter().any(|row| row.len() != dims) {
+        return Err(invalid(format!("Must be a {dims}x{dims} matrix")));
+    }
+    let c: Vec<Vec<f64>> = corr
+        .iter()
+        .map(|row| row.iter().map(|v| v.to_f64().unwrap_or(0.0)).collect())
+        .collect();
+    for (i, row) in c.iter().enumerate() {
+        if (row[i] - 1.0).abs() > 1e-9 {
+            return Err(invalid(format!("Diagonal entry {i} must be 1")));
+        }
+        for (j, other) in c.iter().enumerate().take(i) {
+            if (row[j] - other[i]).abs() > 1e-9 {
+                return Err(invalid(format!("Entries ({i},{j}) and ({j},{i}) differ")));
+            }
+        }
+    }
+
+    let mut l = vec![vec![0.0; dims]; dims];
+    for i in 0..dims {
+        for j in 0..=i {
+            let dot: f64 = (0..j).map(|k| l[i][k] * l[j][k]).sum();
+            if i == j {
+                let d = c[i][i] - dot;
+                if d <= 1e-12 {
+                    return Err(invalid("Matrix is not positive definite".into()));
+                }
+                l[i][j] = d.sqrt();
+            } else {
+                l[i][j] = (c[i][j] - dot) / l[j][j];
+            }
+        }
+    }
+    Ok(l)
+}
+
+/// Draw `num_simulations` market scenarios. Returns them with the seed used.
+fn simulate_scenarios(mc: &MonteCarloScenarios) -> CorpFinanceResult<(Vec<MarketScenario>, u64)> {
+    let dims = mc.factors.len();
+    let l = mc
+        .correlation_matrix
+        .as_ref()
+        .map(|c| cholesky(c, dims))
+        .transpose()?;
+    let seed = mc.seed.unwrap_or_else(|| StdRng::from_entropy().gen());
+    let mut rng = StdRng::seed_from_u64(seed);
+    let normal = Normal::new(0.0, 1.0).map_err(|e| CorpFinanceError::InvalidInput {
+        field: "monte_carlo".into(),
+        reason: format!("Invalid normal distribution: {e}"),
+    })?;
+    let params: Vec<(f64, f64, bool)> = mc
+        .factors
+        .iter()
+        .map(|f| {
+            (
+                f.drift.to_f64().unwrap_or(0.0),
+                f.volatility.to_f64().unwrap_or(0.0),
+                RELATIVE_FACTORS.contains(&f.factor.as_str()),
+            )
+        })
+        .collect();
+
+    let scenarios = (0..mc.num_simulations)
+        .map(|s| {
+            let z: Vec<f64> = (0..dims).map(|_| rng.sample(normal)).collect();
+            let shocks: Vec<(String, Decimal)> = mc
+                .factors
+                .iter()
+                .zip(&params)
+                .enumerate()
+                .map(|(i, (f, (drift, vol, relative)))| {
+                    let x = match &l {
+                        Some(l) => (0..=i).map(|k| l[i][k] * z[k]).sum(),
+                        None => z[i],
+                    };
+                    let mv = drift + vol * x;
+                    let shock = if *relative { mv.exp() - 1.0 } else { mv };
+                    (
+                        f.factor.clone(),
+                        Decimal::from_f64(shock).unwrap_or(Decimal::ZERO),
+                    )
+                })
+                .collect();
+            MarketScenario::from_factor_shocks(format!("simulation {}", s + 1), &shocks)
+        })
+        .collect::<CorpFinanceResult<Vec<_>>>()?;
+    Ok((scenarios, seed))
+}
+
+// ---------------------------------------------------------------------------
+// P&L statistics
+// ---------------------------------------------------------------------------
+
+/// First-order P&L of a position from its bump-and-revalue sensitivities.
+/// Only the class-wide parallel shocks enter; FX is held fixed.
+fn linear_pnl(risk: &RiskSensitivities, scenario: &MarketScenario) -> Money {
+    risk.dv01 * scenario.rate_shift / ONE_BP
+        + risk.cs01 * scenario.spread_shift / ONE_BP
+        + risk.equity_delta * scenario.equity_shock / EQUITY_BUMP
+        + risk.vega * scenario.vol_shift / VOL_BUMP
+}
+
+/// VaR at the same quantile as `portfolio::risk` (the floor((1-c) x n)-th
+/// worst outcome) and ES as the average of outcomes at or below it.
+fn tail(pnl: &[Money], confidence_level: Rate) -> Tail {
+    let n = pnl.len();
+    let mut order: Vec<usize> = (0..n).collect();
+    order.sort_by_key(|&i| pnl[i]);
+    let index = ((Decimal::ONE - confidence_level) * Decimal::from(n as u64))
+        .floor()
+        .to_usize()
+        .unwrap_or(0)
+        .min(n - 1);
+    let var_scenario = order[index];
+    let threshold = pnl[var_scenario];
+    let scenarios: Vec<usize> = order
+        .iter()
+        .copied()
+        .take_while(|&i| pnl[i] <= threshold)
+        .collect();
+    let tail_sum: Money = scenarios.iter().map(|&i| pnl[i]).sum();
+    Tail {
+        var: -threshold,
+        expected_shortfall: -(tail_sum / Decimal::from(scenarios.len() as u64)),
+        var_scenario,
+        scenarios,
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Validation
+// ---------------------------------------------------------------------------
+
+fn validate_input(input: &RevaluationVarInput) -> CorpFinanceResult<()> {
+    if input.confidence_level <= Decimal::ZERO || input.confidence_level >= Decimal::ONE {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "confidence_level".into(),
+            reason: "Confidence level must be between 0 and 1 (exclusive)".into(),
+        });
+    }
+    match input.method {
+        VarMethod::Historical => {
+            if input.historical_scenarios.is_empty() {
+                return Err(CorpFinanceError::InsufficientData(
+                    "Historical VaR needs at least one scenario".into(),
+                ));
+            }
+        }
+        VarMethod::MonteCarlo => {
+            let mc = input
+                .monte_carlo
+                .as_ref()
+                .ok_or_else(|| CorpFinanceError::InvalidInput {
+                    field: "monte_carlo".into(),
+                    reason: "Monte Carlo VaR needs a scenario generator".into(),
+                })?;
+            if mc.factors.is_empty() {
+                return Err(CorpFinanceError::InsufficientData(
+                    "Monte Carlo VaR needs at least one factor".into(),
+                ));
+            }
+            if mc.num_simulations == 0 {
+                return Err(CorpFinanceError::InvalidInput {
+                    field: "monte_carlo.num_simulations".into(),
+                    reason: "Must be positive".into(),
+                });
+            }
+            limits::check_simulation_paths(
+                "monte_carlo.num_simulations",
+                u64::from(mc.num_simulations),
+            )?;
+            for (i, f) in mc.factors.iter().enumerate() {
+                if f.volatility < Decimal::ZERO {
+                    return Err(CorpFinanceError::InvalidInput {
+                        field: format!("monte_carlo.factors[{i}].volatility"),
+                        reason: "Volatility cannot be negative".into(),
+                    });
+                }
+                if mc.factors[..i].iter().any(|g| g.factor == f.factor) {
+                    return Err(CorpFinanceError::InvalidInput {
+                        field: format!("monte_carlo.factors[{i}].factor"),
+                        reason: format!("Duplicate factor '{}'", f.factor),
+                    });
+                }
+            }
+            // Rejects unknown factor names before any draws
+            MarketScenario::from_factor_shocks(
+                "validation",
+                &mc.factors
+                    .iter()
+                    .map(|f| (f.factor.clone(), Decimal::ZERO))
+                    .collect::<Vec<_>>(),
+            )?;
+        }
+    }
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Public API
+// ---------------------------------------------------------------------------
+
+/// VaR and expected shortfall of a book of positions by full revaluation
+/// under historical or Monte Carlo market scenarios, with a first-order
+/// comparison, per-position tail contributions and the worst scenarios.
+pub fn calculate_revaluation_var(
+    input: &RevaluationVarInput,
+) -> CorpFinanceResult<ComputationOutput<RevaluationVarOutput>> {
+    let start = Instant::now();
+    let mut warnings: Vec<String> = Vec::new();
+
+    validate_input(input)?;
+    let deadline = limits::Deadline::start();
+
+    // --- Base valuation and sensitivities ---
+    let base = value_portfolio(&input.portfolio)?;
+    warnings.extend(base.warnings);
+    let base = base.result;
+
+    let (scenarios, seed) = match input.method {
+        VarMethod::Historical => (input.historical_scenarios.clone(), None),
+        VarMethod::MonteCarlo => {
+            // Validated above
+            let mc = input.monte_carlo.as_ref().unwrap();
+            let (scenarios, seed) = simulate_scenarios(mc)?;
+            (scenarios, Some(seed))
+        }
+    };
+    let n = scenarios.len();
+    let min_tail = Decimal::from(n as u64) * (Decimal::ONE - input.confidence_level);
+    if min_tail < Decimal::from(5) {
+        warnings.push(format!(
+            "Only {n} scenarios: fewer than 5 lie beyond the {} quantile, so VaR and ES rest on very few outcomes",
+            input.confidence_level
+        ));
+    }
+
+    // --- Full revaluation ---
+    let book = &input.portfolio;
+    let base_values: Vec<Money> = base.positions.iter().map(|p| p.market_value).collect();
+    let mut position_pnl: Vec<Vec<Money>> = Vec::with_capacity(n);
+    let mut linear: Vec<Money> = Vec::with_capacity(n);
+    for (s, scenario) in scenarios.iter().enumerate() {
+        deadline.check(|| format!("{s} of {n} scenarios"))?;
+        let market = book.market.shifted(scenario)?;
+        let quotes = fx_quotes(&market);
+        let values = book_values(book, &market)?;
+        let pnl = book
+            .positions
+            .iter()
+            .zip(&values)
+            .zip(&base_values)
+            .map(|((p, v), v0)| {
+                Ok(v * fx_conversion_rate(&p.currency, &book.base_currency, &quotes)? - v0)
+            })
+            .collect::<CorpFinanceResult<Vec<Money>>>()?;
+        position_pnl.push(pnl);
+        linear.push(
+            base.positions
+                .iter()
+                .map(|p| linear_pnl(&p.risk, scenario))
+                .sum(),
+        );
+    }
+    let total: Vec<Money> = position_pnl.iter().map(|row| row.iter().sum()).collect();
+
+    // --- Statistics ---
+    let full = tail(&total, input.confidence_level);
+    let first_order = tail(&linear, input.confidence_level);
+    let n_dec = Decimal::from(n as u64);
+    let mean_pnl = total.iter().sum::<Money>() / n_dec;
+    let pnl_std_dev = if n > 1 {
+        let ss: Decimal = total.iter().map(|v| (v - mean_pnl) * (v - mean_pnl)).sum();
+        (ss / Decimal::from((n - 1) as u64))
+            .to_f64()
+            .and_then(|v| Decimal::from_f64(v.sqrt()))
+            .unwrap_or(Decimal::ZERO)
+    } else {
+        Decimal::ZERO
+    };
+
+    let tail_len = Decimal::from(full.scenarios.len() as u64);
+    let positions: Vec<PositionVarContribution> = base
+        .positions
+        .iter()
+        .enumerate()
+        .map(|(i, p)| {
+            let column: Vec<Money> = position_pnl.iter().map(|row| row[i]).collect();
+            let tail_sum: Money = full.scenarios.iter().map(|&s| column[s]).sum();
+            PositionVarContribution {
+                id: p.id.clone(),
+                instrument_type: p.instrument_type.clone(),
+                market_value: p.market_value,
+                pnl_at_var: column[full.var_scenario],
+                expected_shortfall_contribution: -(tail_sum / tail_len),
+                standalone_var: tail(&column, input.confidence_level).var,
+            }
+        })
+        .collect();
+
+    let mut order: Vec<usize> = (0..n).collect();
+    order.sort_by_key(|&s| total[s]);
+    let worst_scenarios = order
+        .iter()
+        .take(WORST_SCENARIOS)
+        .map(|&s| ScenarioPnl {
+            scenario: scenarios[s].name.clone(),
+            pnl: total[s],
+            linear_pnl: linear[s],
+        })
+        .collect();
+
+    let output = RevaluationVarOutput {
+        method: input.method,
+        base_currency: base.base_currency.clone(),
+        base_value: base.total_market_value,
+        num_scenarios: n,
+        confidence_level: input.confidence_level,
+        var: full.var,
+        expected_shortfall: full.expected_shortfall,
+        linear_var: first_order.var,
+        linear_expected_shortfall: first_order.expected_shortfall,
+        nonlinearity: full.var - first_order.var,
+        mean_pnl,
+        pnl_std_dev,
+        worst_loss: -total[order[0]],
+        best_gain: total[order[n - 1]],
+        var_scenario: scenarios[full.var_scenario].name.clone(),
+        positions,
+        worst_scenarios,
+    };
+
+    let elapsed = start.elapsed().as_micros() as u64;
+    let assumptions = serde_json::json!({
+        "valuation_date": input.portfolio.valuation_date.to_string(),
+        "base_currency": input.portfolio.base_currency,
+        "num_positions": input.portfolio.positions.len(),
+        "method": input.method,
+        "num_scenarios": n,
+        "confidence_level": input.confidence_level.to_string(),
+        "seed": seed,
+        "quantile": "floor((1 - confidence) x n)-th worst outcome",
+        "linear_pnl": "dv01, cs01, equity delta and vega times parallel shocks; FX held fixed",
+    });
+
+    let methodology = match input.method {
+        VarMethod::Historical => "Historical VaR and expected shortfall by full revaluation",
+        VarMethod::MonteCarlo => "Monte Carlo VaR and expected shortfall by full revaluation",
+    };
+    Ok(with_metadata(
+        methodology,
+        &assumptions,
+        warnings,
+        elapsed,
+        output,
+    ))
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::curves::{Curve, VolSurface};
+    use crate::derivatives::options::OptionType;
+    use crate::portfolio::valuation::{
+        EquityOptionPosition, EquityPosition, Instrument, Position, SwapPosition,
+    };
+    use crate::scenarios::market_shift::MarketDataSet;
+    use chrono::NaiveDate;
+    use rust_decimal_macros::dec;
+
+    fn market() -> MarketDataSet {
+        let mut m = MarketDataSet::default();
+        m.rate_curves.insert(
+            "USD-OIS".into(),
+            Curve::from_zero_rates([dec!(1), dec!(5), dec!(10)].map(|t| (t, dec!(0.04)))),
+        );
+        m.equity_prices.insert("ACME".into(), dec!(50));
+        m.fx_rates.insert("EURUSD".into(), dec!(1.10));
+        let surface: VolSurface = serde_json::from_value(serde_json::json!({
+            "version": 1,
+            "underlying": "ACME",
+            "spot": 50,
+            "strike_axis": "moneyness",
+            "nodes": [
+                { "expiry": 0.25, "strike": 0.8, "vol": 0.30 },
+                { "expiry": 0.25, "strike": 1.2, "vol": 0.30 },
+                { "expiry": 2.0, "strike": 0.8, "vol": 0.30 },
+                { "expiry": 2.0, "strike": 1.2, "vol": 0.30 }
+            ]
+        }))
+        .unwrap();
+        m.vol_surfaces.insert("ACME-VOL".into(), surface);
+        m
+    }
+
+    fn position(id: &str, quantity: Decimal, instrument: Instrument) -> Position {
+        Position {
+            id: id.into(),
+            quantity,
+            currency: Currency::USD,
+            instrument,
+        }
+    }
+
+    fn shares() -> Instrument {
+        Instrument::Equity(EquityPosition {
+            underlying: "ACME".into(),
+        })
+    }
+
+    fn call() -> Instrument {
+        Instrument::EquityOption(EquityOptionPosition {
+            underlying: "ACME".into(),
+            strike_price: dec!(50),
+            time_to_expiry: dec!(0.25),
+            option_type: OptionType::Call,
+            exercise_style: None,
+            discount_curve: "USD-OIS".into(),
+            vol_surface: Some("ACME-VOL".into()),
+            volatility: None,
+            dividend_yield: Decimal::ZERO,
+        })
+    }
+
+    fn swap() -> Instrument {
+        Instrument::Swap(SwapPosition {
+            notional: dec!(1_000_000),
+            fixed_rate: dec!(0.04),
+            payment_frequency: 4,
+            remaining_years: dec!(5),
+            is_pay_fixed: true,
+            discount_curve: "USD-OIS".into(),
+            projection_curve: None,
+            last_floating_reset: None,
+        })
+    }
+
+    fn book(positions: Vec<Position>) -> PortfolioValuationInput {
+        PortfolioValuationInput {
+            valuation_date: NaiveDate::from_ymd_opt(2026, 6, 15).unwrap(),
+            base_currency: Currency::USD,
+            market: market(),
+            positions,
+        }
+    }
+
+    /// Equity moves from -20% to +20% in 1% steps
+    fn equity_history() -> Vec<MarketScenario> {
+        (-20..=20)
+            .map(|k| MarketScenario {
+                name: format!("equity {k}%"),
+                equity_shock: Decimal::from(k) / dec!(100),
+                ..MarketScenario::default()
+            })
+            .collect()
+    }
+
+    fn historical(positions: Vec<Position>) -> RevaluationVarInput {
+        RevaluationVarInput {
+            portfolio: book(positions),
+            method: VarMethod::Historical,
+            historical_scenarios: equity_history(),
+            monte_carlo: None,
+            confidence_level: dec!(0.95),
+        }
+    }
+
+    fn monte_carlo(seed: u64) -> RevaluationVarInput {
+        RevaluationVarInput {
+            portfolio: book(vec![
+                position("shares", dec!(1000), shares()),
+                position("calls", dec!(-500), call()),
+                position("swap", dec!(1), swap()),
+            ]),
+            method: VarMethod::MonteCarlo,
+            historical_scenarios: Vec::new(),
+            monte_carlo: Some(MonteCarloScenarios {
+                factors: vec![
+                    SimulatedFactor {
+                        factor: "equity_market".into(),
+                        volatility: dec!(0.05),
+                        drift: Decimal::ZERO,
+                    },
+                    SimulatedFactor {
+                        factor: "interest_rates".into(),
+                        volatility: dec!(0.002),
+                        drift: Decimal::ZERO,
+                    },
+                ],
+                correlation_matrix: Some(vec![vec![dec!(1), dec!(0.3)], vec![dec!(0.3), dec!(1)]]),
+                num_simulations: 200,
+                seed: Some(seed),
+            }),
+            confidence_level: dec!(0.99),
+        }
+    }
+
+    #[test]
+    fn test_linear_book_matches_first_order_var() {
+        // Shares are linear in the price, so full and linear VaR agree:
+        // the 95% quantile of 41 moves is the 3rd worst, a -18% move
+        let input = historical(vec![position("shares", dec!(1000), shares())]);
+        let out = calculate_revaluation_var(&input).unwrap().result;
+        assert_eq!(out.num_scenarios, 41);
+        assert_eq!(out.var_scenario, "equity -18%");
+        assert!((out.var - dec!(9000)).abs() < dec!(0.01));
+        assert!((out.var - out.linear_var).abs() < dec!(0.01));
+        assert!((out.worst_loss - dec!(10_000)).abs() < dec!(0.01));
+    }
+
+    #[test]
+    fn test_option_convexity_separates_full_and_linear_var() {
+        let long =
+            calculate_revaluation_var(&historical(vec![position("calls", dec!(1000), call())]))
+                .unwrap()
+                .result;
+        // A long call loses less than its delta implies
+        assert!(long.nonlinearity < Decimal::ZERO);
+        assert!(long.var < long.linear_var);
+
+        let short =
+            calculate_revaluation_var(&historical(vec![position("calls", dec!(-1000), call())]))
+                .unwrap()
+                .result;
+        // A short call loses more than its delta implies
+        assert!(short.nonlinearity > Decimal::ZERO);
+        assert!(short.var > short.linear_var);
+    }
+
+    #[test]
+    fn test_expected_shortfall_contributions_add_up() {
+        let input = historical(vec![
+            position("shares", dec!(1000), shares()),
+            position("calls", dec!(-800), call()),
+        ]);
+        let out = calculate_revaluation_var(&input).unwrap().result;
+        assert!(out.expected_shortfall >= out.var);
+        let es_sum: Money = out
+            .positions
+            .iter()
+            .map(|p| p.expected_shortfall_contribution)
+            .sum();
+        assert!((es_sum - out.expected_shortfall).abs() < dec!(0.0001));
+        let var_sum: Money = out.positions.iter().map(|p| p.pnl_at_var).sum();
+        assert!((var_sum + out.var).abs() < dec!(0.0001));
+        assert_eq!(out.worst_scenarios.len(), 5);
+        assert_eq!(out.worst_scenarios[0].pnl, -out.worst_loss);
+    }
+
+    #[test]
+    fn test_foreign_position_includes_fx_translation() {
+        let mut eur_shares = position("eur_shares", dec!(1000), shares());
+        eur_shares.currency = Currency::EUR;
+        let mut input = historical(vec![eur_shares]);
+        input.historical_scenarios = vec![MarketScenario {
+            name: "dollar +10%".into(),
+            fx_usd: dec!(0.10),
+            ..MarketScenario::default()
+        }];
+        let out = calculate_revaluation_var(&input).unwrap();
+        // 50,000 EUR at 1.10 then at 1.10 / 1.10 = 1.00
+        assert!((out.result.var - dec!(5000)).abs() < dec!(0.01));
+        assert_eq!(out.result.linear_var, Decimal::ZERO);
+        assert!(out.warnings.iter().any(|w| w.contains("Only 1 scenarios")));
+    }
+
+    #[test]
+    fn test_monte_carlo_is_reproducible_with_seed() {
+        let a = calculate_revaluation_var(&monte_carlo(7)).unwrap();
+        let b = calculate_revaluation_var(&monte_carlo(7)).unwrap();
+        let c = calculate_revaluation_var(&monte_carlo(8)).unwrap();
+        assert_eq!(a.result.num_scenarios, 200);
+        assert_eq!(a.result.var, b.result.var);
+        assert_ne!(a.result.var, c.result.var);
+        assert!(a.result.var > Decimal::ZERO);
+        assert!(a.result.expected_shortfall >= a.result.var);
+        assert_eq!(
+            a.methodology,
+            "Monte Carlo VaR and expected shortfall by full revaluation"
+        );
+    }
+
+    #[test]
+    fn test_invalid_inputs_rejected() {
+        let mut input = historical(vec![position("shares", dec!(1000), shares())]);
+        input.confidence_level = Decimal::ONE;
+        assert!(calculate_revaluation_var(&input).is_err());
+
+        input.confidence_level = dec!(0.95);
+        input.historical_scenarios.clear();
+        assert!(matches!(
+            calculate_revaluation_var(&input),
+            Err(CorpFinanceError::InsufficientData(_))
+        ));
+
+        let mut input = monte_carlo(1);
+        input.monte_carlo.as_mut().unwrap().factors[0].factor = "gold".into();
+        assert!(calculate_revaluation_var(&input).is_err());
+
+        let mut input = monte_carlo(1);
+        input.monte_carlo.as_mut().unwrap().correlation_matrix =
+            Some(vec![vec![dec!(1), dec!(1.5)], vec![dec!(1.5), dec!(1)]]);
+        assert!(calculate_revaluation_var(&input).is_err());
+
+        let mut input = monte_carlo(1);
+        input.monte_carlo = None;
+        assert!(calculate_revaluation_var(&input).is_err());
+    }
+
+    #[test]
+    fn test_input_deserialises_from_json() {
+        let input: RevaluationVarInput = serde_json::from_value(serde_json::json!({
+            "portfolio": {
+                "valuation_date": "2026-06-15",
+                "market": { "equity_prices": { "ACME": 50 } },
+                "positions": [
+                    { "id": "shares", "quantity": 100, "instrument": { "type": "equity", "underlying": "ACME" } }
+                ]
+            },
+            "method": "Historical",
+            "historical_scenarios": [
+                { "name": "crash", "equity_shock": -0.3 },
+                { "name": "rally", "equity_shock": 0.1 }
+            ],
+            "confidence_level": 0.9
+        }))
+        .unwrap();
+        let out = calculate_revaluation_var(&input).unwrap().result;
+        assert!((out.var - dec!(1500)).abs() < dec!(0.01));
+    }
+}
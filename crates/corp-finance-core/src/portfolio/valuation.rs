@@ -0,0 +1,1277 @@
+//! Position-level valuation of a mixed book against one market data set.
+//!
+//! Bonds, equities, options, interest-rate swaps, convertibles and
+//! structured notes name the curves, surfaces and prices they read in a
+//! shared `MarketDataSet`, and each is priced by its existing pricer. Risk
+//! is measured by revaluing the whole book under +1bp rate, +1bp spread,
+//! +1% equity and +1 vol point shifts of that set, so every position sees
+//! the same bumped market.
+
+use std::collections::BTreeMap;
+use std::time::Instant;
+
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+
+use crate::convertibles::pricing::{price_convertible, ConvertibleBondInput};
+use crate::curves::{Compounding, Curve};
+use crate::derivatives::options::{price_option, ExerciseStyle, OptionInput, OptionType};
+use crate::derivatives::swaps::{value_interest_rate_swap, IrsInput};
+use crate::error::CorpFinanceError;
+use crate::fixed_income::bonds::{price_bond, BondPricingInput};
+use crate::fixed_income::daycount::{year_fraction, DayCountConvention};
+use crate::scenarios::market_shift::{MarketDataSet, MarketScenario};
+use crate::structured_products::notes::{price_structured_note, NoteType, StructuredNoteInput};
+use crate::types::{
+    fx_conversion_rate, with_metadata, ComputationOutput, Currency, FxRate, Money, Rate, Years,
+};
+use crate::CorpFinanceResult;
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Rate and spread bump for DV01 and CS01
+pub(super) const ONE_BP: Rate = dec!(0.0001);
+/// Relative equity bump for the equity delta
+pub(super) const EQUITY_BUMP: Rate = dec!(0.01);
+/// Absolute volatility bump for vega
+pub(super) const VOL_BUMP: Rate = dec!(0.01);
+
+// ---------------------------------------------------------------------------
+// Types
+// ---------------------------------------------------------------------------
+
+/// A fixed-coupon bond, priced off the discount curve yield at maturity
+/// plus the spread curve.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BondPosition {
+    pub face_value: Money,
+    pub coupon_rate: Rate,
+    pub coupon_frequency: u8,
+    pub maturity_date: NaiveDate,
+    /// Day count convention (default 30/360)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub day_count: Option<DayCountConvention>,
+    /// Rate curve id in the market data set
+    pub discount_curve: String,
+    /// Spread curve id added to the discount curve yield
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub spread_curve: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub call_price: Option<Money>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub call_date: Option<NaiveDate>,
+}
+
+/// A holding of shares in an equity or index price.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EquityPosition {
+    /// Equity price id in the market data set
+    pub underlying: String,
+}
+
+/// A vanilla option on an equity price.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EquityOptionPosition {
+    /// Equity price id in the market data set
+    pub underlying: String,
+    pub strike_price: Money,
+    pub time_to_expiry: Years,
+    pub option_type: OptionType,
+    /// Exercise style (default European)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exercise_style: Option<ExerciseStyle>,
+    /// Rate curve id, read as a continuously compounded rate to expiry
+    pub discount_curve: String,
+    /// Volatility surface id, read at the strike and expiry
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vol_surface: Option<String>,
+    /// Flat volatility, used when no surface is given
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub volatility: Option<Rate>,
+    #[serde(default)]
+    pub dividend_yield: Rate,
+}
+
+/// A fixed-for-floating interest-rate swap.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SwapPosition {
+    pub notional: Money,
+    pub fixed_rate: Rate,
+    pub payment_frequency: u8,
+    pub remaining_years: Years,
+    pub is_pay_fixed: bool,
+    /// Rate curve id used for discounting
+    pub discount_curve: String,
+    /// Rate curve id used for floating forwards (default: the discount curve)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub projection_curve: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_floating_reset: Option<Rate>,
+}
+
+/// A convertible bond on an equity price.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConvertiblePosition {
+    pub face_value: Money,
+    pub coupon_rate: Rate,
+    pub coupon_frequency: u32,
+    pub maturity_years: Years,
+    pub conversion_ratio: Decimal,
+    /// Equity price id of the shares delivered on conversion
+    pub underlying: String,
+    /// Rate curve id, read as a continuously compounded rate to maturity
+    pub discount_curve: String,
+    /// Spread curve id for the issuer's credit spread
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub spread_curve: Option<String>,
+    /// Flat credit spread, used when no spread curve is given (default 0)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub credit_spread: Option<Rate>,
+    /// Volatility surface id, read at the conversion price and maturity
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vol_surface: Option<String>,
+    /// Flat volatility, used when no surface is given
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub volatility: Option<Rate>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dividend_yield: Option<Rate>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub call_price: Option<Money>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub call_protection_years: Option<Years>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub put_price: Option<Money>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub put_date_years: Option<Years>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tree_steps: Option<u32>,
+}
+
+/// A structured note on an equity price. Notes are priced as at issue,
+/// with strikes and barriers set against the current level, so they carry
+/// rate and volatility risk but no equity delta. Terms left out are
+/// solved at par by the note pricer, so give the traded participation or
+/// coupon to value a seasoned note.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StructuredNotePosition {
+    pub note_type: NoteType,
+    pub notional: Money,
+    pub maturity_years: Years,
+    /// Equity price id of the reference underlying
+    pub underlying: String,
+    /// Rate curve id, read as an annually compounded rate to maturity
+    pub discount_curve: String,
+    /// Volatility surface id, read at the note's strike and maturity
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vol_surface: Option<String>,
+    /// Flat volatility, used when no surface is given
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub volatility: Option<Rate>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub protection_level: Option<Rate>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub participation_rate: Option<Rate>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cap_level: Option<Rate>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub barrier_level: Option<Rate>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub coupon_rate: Option<Rate>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub strike_pct: Option<Rate>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub floor_level: Option<Rate>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reference_entity: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub credit_spread_bps: Option<Decimal>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub recovery_rate: Option<Rate>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_probability: Option<Rate>,
+}
+
+/// The instrument held in a position, tagged by `type`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Instrument {
+    Bond(BondPosition),
+    Equity(EquityPosition),
+    EquityOption(EquityOptionPosition),
+    Swap(SwapPosition),
+    Convertible(ConvertiblePosition),
+    StructuredNote(StructuredNotePosition),
+}
+
+impl Instrument {
+    /// Name used to group positions in the output.
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Instrument::Bond(_) => "bond",
+            Instrument::Equity(_) => "equity",
+            Instrument::EquityOption(_) => "equity_option",
+            Instrument::Swap(_) => "swap",
+            Instrument::Convertible(_) => "convertible",
+            Instrument::StructuredNote(_) => "structured_note",
+        }
+    }
+
+    /// Equity price the instrument is written on, if any.
+    fn underlying(&self) -> Option<&str> {
+        match self {
+            Instrument::Equity(e) => Some(&e.underlying),
+            Instrument::EquityOption(o) => Some(&o.underlying),
+            Instrument::Convertible(c) => Some(&c.underlying),
+            Instrument::StructuredNote(n) => Some(&n.underlying),
+            Instrument::Bond(_) | Instrument::Swap(_) => None,
+        }
+    }
+}
+
+/// One holding in the book.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Position {
+    pub id: String,
+    /// Units held, negative for shorts: bonds and convertibles in bonds,
+    /// equities and options in shares, swaps and notes in contracts
+    pub quantity: Decimal,
+    /// Currency the instrument is priced in
+    #[serde(default)]
+    pub currency: Currency,
+    pub instrument: Instrument,
+}
+
+/// Input for valuing a book of positions.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PortfolioValuationInput {
+    pub valuation_date: NaiveDate,
+    /// Currency values and risk are reported in
+    #[serde(default)]
+    pub base_currency: Currency,
+    /// Shared market data; FX rates in it translate positions into the
+    /// base currency
+    pub market: MarketDataSet,
+    pub positions: Vec<Position>,
+}
+
+/// Instrument sensitivities from the position's own pricer, scaled by
+/// quantity and in the position currency.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PositionGreeks {
+    /// Share-equivalent delta
+    pub delta: Decimal,
+    /// Change in delta per unit move in the underlying
+    pub gamma: Decimal,
+    /// Per 1 vol point
+    pub vega: Money,
+    /// Per calendar day
+    pub theta: Money,
+}
+
+/// Revaluation risk in the base currency: the change in value when the
+/// market data set is bumped.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RiskSensitivities {
+    /// Every rate curve up 1bp
+    pub dv01: Money,
+    /// Every spread curve up 1bp
+    pub cs01: Money,
+    /// Every equity price up 1%
+    pub equity_delta: Money,
+    /// Every volatility surface up 1 vol point
+    pub vega: Money,
+    /// Value held in currencies other than the base currency
+    pub fx_exposure: Money,
+}
+
+impl RiskSensitivities {
+    fn add(&mut self, other: &RiskSensitivities) {
+        self.dv01 += other.dv01;
+        self.cs01 += other.cs01;
+        self.equity_delta += other.equity_delta;
+        self.vega += other.vega;
+        self.fx_exposure += other.fx_exposure;
+    }
+}
+
+/// Value and risk of one position.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PositionValuation {
+    pub id: String,
+    pub instrument_type: String,
+    pub currency: Currency,
+    pub quantity: Decimal,
+    /// Value of one unit in the position currency (dirty price for bonds)
+    pub unit_price: Money,
+    /// quantity x unit price, in the position currency
+    pub local_value: Money,
+    /// Units of base currency per unit of position currency
+    pub fx_rate: Decimal,
+    /// Value in the base currency
+    pub market_value: Money,
+    /// Share of the book's gross market value
+    pub weight: Rate,
+    /// Pricer Greeks for equities, options and convertibles
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub greeks: Option<PositionGreeks>,
+    pub risk: RiskSensitivities,
+}
+
+/// Positions of one instrument type added together.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InstrumentTypeSummary {
+    pub instrument_type: String,
+    pub num_positions: usize,
+    pub market_value: Money,
+    pub risk: RiskSensitivities,
+}
+
+/// Greeks of every position on one underlying, in the base currency.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UnderlyingExposure {
+    pub underlying: String,
+    pub spot: Money,
+    /// Share-equivalent delta
+    pub delta: Decimal,
+    /// Delta x spot, in the base currency
+    pub delta_value: Money,
+    pub gamma: Decimal,
+    pub vega: Money,
+    pub theta: Money,
+}
+
+/// Output of portfolio valuation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PortfolioValuationOutput {
+    pub base_currency: Currency,
+    pub positions: Vec<PositionValuation>,
+    /// Net market value in the base currency
+    pub total_market_value: Money,
+    /// Sum of absolute position values
+    pub gross_market_value: Money,
+    pub total_risk: RiskSensitivities,
+    pub by_instrument_type: Vec<InstrumentTypeSummary>,
+    pub by_underlying: Vec<UnderlyingExposure>,
+}
+
+/// Unit price and Greeks of one position from its pricer.
+struct Priced {
+    unit_price: Money,
+    greeks: Option<PositionGreeks>,
+    warnings: Vec<String>,
+}
+
+// ---------------------------------------------------------------------------
+// Market data lookups
+// ---------------------------------------------------------------------------
+
+fn rate_curve<'a>(
+    market: &'a MarketDataSet,
+    id: &str,
+    field: &str,
+) -> CorpFinanceResult<&'a Curve> {
+    market
+        .rate_curves
+        .get(id)
+        .ok_or_else(|| CorpFinanceError::InvalidInput {
+            field: field.into(),
+            reason: format!("No rate curve '{id}' in the market data set"),
+        })
+}
+
+fn spread(
+    market: &MarketDataSet,
+    id: Option<&String>,
+    t: Years,
+    compounding: Compounding,
+    field: &str,
+) -> CorpFinanceResult<Rate> {
+    match id {
+        None => Ok(Decimal::ZERO),
+        Some(id) => market
+            .spread_curves
+            .get(id)
+            .map(|curve| curve.zero_rate_as(t, compounding))
+            .ok_or_else(|| CorpFinanceError::InvalidInput {
+                field: field.into(),
+                reason: format!("No spread curve '{id}' in the market data set"),
+            }),
+    }
+}
+
+fn equity_price(market: &MarketDataSet, id: &str, field: &str) -> CorpFinanceResult<Money> {
+    market
+        .equity_prices
+        .get(id)
+        .copied()
+        .ok_or_else(|| CorpFinanceError::InvalidInput {
+            field: field.into(),
+            reason: format!("No equity price '{id}' in the market data set"),
+        })
+}
+
+/// Volatility from the named surface, or the flat volatility if none.
+fn volatility(
+    market: &MarketDataSet,
+    surface: Option<&String>,
+    flat: Option<Rate>,
+    expiry: Years,
+    strike: Decimal,
+    field: &str,
+) -> CorpFinanceResult<Rate> {
+    match (surface, flat) {
+        (Some(id), _) => market
+            .vol_surfaces
+            .get(id)
+            .ok_or_else(|| CorpFinanceError::InvalidInput {
+                field: format!("{field}.vol_surface"),
+                reason: format!("No volatility surface '{id}' in the market data set"),
+            })?
+            .vol(expiry, strike),
+        (None, Some(vol)) => Ok(vol),
+        (None, None) => Err(CorpFinanceError::InvalidInput {
+            field: format!("{field}.vol_surface"),
+            reason: "Either a volatility surface or a flat volatility is required".into(),
+        }),
+    }
+}
+
+fn coupon_compounding(frequency: u8) -> Compounding {
+    match frequency {
+        2 => Compounding::SemiAnnual,
+        4 => Compounding::Quarterly,
+        12 => Compounding::Monthly,
+        _ => Compounding::Annual,
+    }
+}
+
+/// The market set's FX rates as quotes, skipping ids that are not pairs.
+pub(super) fn fx_quotes(market: &MarketDataSet) -> Vec<FxRate> {
+    market
+        .fx_rates
+        .iter()
+        .filter_map(|(id, rate)| {
+            let letters: String = id
+                .chars()
+                .filter(|c| c.is_ascii_alphabetic())
+                .collect::<String>()
+                .to_ascii_uppercase();
+            (letters.len() == 6).then(|| FxRate {
+                base: Currency::from_code(&letters[..3]),
+                quote: Currency::from_code(&letters[3..]),
+                rate: *rate,
+            })
+        })
+        .collect()
+}
+
+// ---------------------------------------------------------------------------
+// Pricing
+// ---------------------------------------------------------------------------
+
+/// Price one unit of a position against `market`.
+fn price_position(
+    position: &Position,
+    market: &MarketDataSet,
+    valuation_date: NaiveDate,
+    field: &str,
+) -> CorpFinanceResult<Priced> {
+    let field_of = |name: &str| format!("{field}.instrument.{name}");
+    match &position.instrument {
+        Instrument::Bond(b) => {
+            let day_count = b.day_count.unwrap_or(DayCountConvention::Thirty360);
+            let t = year_fraction(
+                day_count,
+                valuation_date,
+                b.maturity_date,
+                b.coupon_frequency,
+            );
+            let compounding = coupon_compounding(b.coupon_frequency);
+            let curve = rate_curve(market, &b.discount_curve, &field_of("discount_curve"))?;
+            let ytm = curve.zero_rate_as(t, compounding)
+                + spread(
+                    market,
+                    b.spread_curve.as_ref(),
+                    t,
+                    compounding,
+                    &field_of("spread_curve"),
+                )?;
+            let out = price_bond(&BondPricingInput {
+                face_value: b.face_value,
+                coupon_rate: b.coupon_rate,
+                coupon_frequency: b.coupon_frequency,
+                ytm,
+                settlement_date: valuation_date,
+                maturity_date: b.maturity_date,
+                day_count,
+                call_price: b.call_price,
+                call_date: b.call_date,
+                currency: position.currency.clone(),
+                presentation_currency: None,
+                fx_rates: Vec::new(),
+                payment_adjustment: None,
+            })?;
+            Ok(Priced {
+                unit_price: out.result.dirty_price,
+                greeks: None,
+                warnings: out.warnings,
+            })
+        }
+        Instrument::Equity(e) => {
+            let price = equity_price(market, &e.underlying, &field_of("underlying"))?;
+            Ok(Priced {
+                unit_price: price,
+                greeks: Some(PositionGreeks {
+                    delta: position.quantity,
+                    ..PositionGreeks::default()
+                }),
+                warnings: Vec::new(),
+            })
+        }
+        Instrument::EquityOption(o) => {
+            let spot = equity_price(market, &o.underlying, &field_of("underlying"))?;
+            let curve = rate_curve(market, &o.discount_curve, &field_of("discount_curve"))?;
+            let vol = volatility(
+                market,
+                o.vol_surface.as_ref(),
+                o.volatility,
+                o.time_to_expiry,
+                o.strike_price,
+                &format!("{field}.instrument"),
+            )?;
+            let out = price_option(&OptionInput {
+                spot_price: spot,
+                strike_price: o.strike_price,
+                time_to_expiry: o.time_to_expiry,
+                risk_free_rate: curve.zero_rate_as(o.time_to_expiry, Compounding::Continuous),
+                volatility: vol,
+                dividend_yield: o.dividend_yield,
+                option_type: o.option_type,
+                exercise_style: o.exercise_style.unwrap_or(ExerciseStyle::European),
+                binomial_steps: None,
+                early_exercise_model: None,
+                exercise_times: None,
+                simulation_paths: None,
+                seed: None,
+                greeks_method: None,
+                greek_bumps: None,
+            })?;
+            let g = &out.result.greeks;
+            let q = position.quantity;
+            Ok(Priced {
+                unit_price: out.result.price,
+                greeks: Some(PositionGreeks {
+                    delta: g.delta * q,
+                    gamma: g.gamma * q,
+                    vega: g.vega * q,
+                    theta: g.theta * q,
+                }),
+                warnings: out.warnings,
+            })
+        }
+        Instrument::Swap(s) => {
+            let discount_curve =
+                rate_curve(market, &s.discount_curve, &field_of("discount_curve"))?;
+            let projection_curve = s
+                .projection_curve
+                .as_ref()
+                .map(|id| rate_curve(market, id, &field_of("projection_curve")).cloned())
+                .transpose()?;
+            let out = value_interest_rate_swap(&IrsInput {
+                notional: s.notional,
+                fixed_rate: s.fixed_rate,
+                payment_frequency: s.payment_frequency,
+                remaining_years: s.remaining_years,
+                discount_curve: discount_curve.clone(),
+                projection_curve,
+                forward_rates: None,
+                is_pay_fixed: s.is_pay_fixed,
+                last_floating_reset: s.last_floating_reset,
+                effective_date: None,
+                fixed_day_count: None,
+                floating_day_count: None,
+                payment_adjustment: None,
+            })?;
+            Ok(Priced {
+                unit_price: out.result.net_value,
+                greeks: None,
+                warnings: out.warnings,
+            })
+        }
+        Instrument::Convertible(c) => {
+            let stock = equity_price(market, &c.underlying, &field_of("underlying"))?;
+            let curve = rate_curve(market, &c.discount_curve, &field_of("discount_curve"))?;
+            let credit_spread = match &c.spread_curve {
+                Some(_) => spread(
+                    market,
+                    c.spread_curve.as_ref(),
+                    c.maturity_years,
+                    Compounding::Continuous,
+                    &field_of("spread_curve"),
+                )?,
+                None => c.credit_spread.unwrap_or(Decimal::ZERO),
+            };
+            let conversion_price = if c.conversion_ratio > Decimal::ZERO {
+                c.face_value / c.conversion_ratio
+            } else {
+                stock
+            };
+            let vol = volatility(
+                market,
+                c.vol_surface.as_ref(),
+                c.volatility,
+                c.maturity_years,
+                conversion_price,
+                &format!("{field}.instrument"),
+            )?;
+            let out = price_convertible(&ConvertibleBondInput {
+                bond_name: position.id.clone(),
+                face_value: c.face_value,
+                coupon_rate: c.coupon_rate,
+                coupon_frequency: c.coupon_frequency,
+                maturity_years: c.maturity_years,
+                credit_spread,
+                risk_free_rate: curve.zero_rate_as(c.maturity_years, Compounding::Continuous),
+                stock_price: stock,
+                conversion_ratio: c.conversion_ratio,
+                stock_volatility: vol,
+                dividend_yield: c.dividend_yield,
+                call_price: c.call_price,
+                call_protection_years: c.call_protection_years,
+                put_price: c.put_price,
+                put_date_years: c.put_date_years,
+                tree_steps: c.tree_steps,
+            })?;
+            let r = &out.result;
+            let q = position.quantity;
+            Ok(Priced {
+                unit_price: r.model_price,
+                greeks: Some(PositionGreeks {
+                    delta: r.delta * q,
+                    gamma: r.gamma * q,
+                    vega: r.vega * q,
+                    // The convertible pricer reports theta per year
+                    theta: r.theta / dec!(365) * q,
+                }),
+                warnings: out.warnings,
+            })
+        }
+        Instrument::StructuredNote(n) => {
+            let level = equity_price(market, &n.underlying, &field_of("underlying"))?;
+            let curve = rate_curve(market, &n.discount_curve, &field_of("discount_curve"))?;
+            let vol = volatility(
+                market,
+                n.vol_surface.as_ref(),
+                n.volatility,
+                n.maturity_years,
+                level * n.strike_pct.unwrap_or(Decimal::ONE),
+                &format!("{field}.instrument"),
+            )?;
+            let out = price_structured_note(&StructuredNoteInput {
+                note_type: n.note_type,
+                notional: n.notional,
+                maturity_years: n.maturity_years,
+                risk_free_rate: curve.zero_rate_as(n.maturity_years, Compounding::Annual),
+                underlying_price: level,
+                underlying_volatility: vol,
+                protection_level: n.protection_level,
+                participation_rate: n.participation_rate,
+                cap_level: n.cap_level,
+                barrier_level: n.barrier_level,
+                coupon_rate: n.coupon_rate,
+                strike_pct: n.strike_pct,
+                floor_level: n.floor_level,
+                reference_entity: n.reference_entity.clone(),
+                credit_spread_bps: n.credit_spread_bps,
+                recovery_rate: n.recovery_rate,
+                default_probability: n.default_probability,
+            })?;
+            let mut warnings = out.warnings;
+            warnings.extend(out.result.warnings.iter().cloned());
+            Ok(Priced {
+                unit_price: out.result.issue_price,
+                greeks: None,
+                warnings,
+            })
+        }
+    }
+}
+
+/// Local value of every position against `market`.
+pub(super) fn book_values(
+    input: &PortfolioValuationInput,
+    market: &MarketDataSet,
+) -> CorpFinanceResult<Vec<Money>> {
+    input
+        .positions
+        .iter()
+        .enumerate()
+        .map(|(i, p)| {
+            let priced =
+                price_position(p, market, input.valuation_date, &format!("positions[{i}]"))?;
+            Ok(priced.unit_price * p.quantity)
+        })
+        .collect()
+}
+
+// ---------------------------------------------------------------------------
+// Validation
+// ---------------------------------------------------------------------------
+
+fn validate_input(input: &PortfolioValuationInput) -> CorpFinanceResult<()> {
+    if input.positions.is_empty() {
+        return Err(CorpFinanceError::InsufficientData(
+            "At least one position required".into(),
+        ));
+    }
+    input.market.validate()?;
+    for (i, p) in input.positions.iter().enumerate() {
+        if p.id.trim().is_empty() {
+            return Err(CorpFinanceError::InvalidInput {
+                field: format!("positions[{i}].id"),
+                reason: "Position id must not be empty".into(),
+            });
+        }
+        if input.positions[..i].iter().any(|q| q.id == p.id) {
+            return Err(CorpFinanceError::InvalidInput {
+                field: format!("positions[{i}].id"),
+                reason: format!("Duplicate position id '{}'", p.id),
+            });
+        }
+    }
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Public API
+// ---------------------------------------------------------------------------
+
+/// Value a book of positions against one market data set, returning
+/// per-position and aggregate values, pricer Greeks and bump-and-revalue
+/// risk in the base currency.
+pub fn value_portfolio(
+    input: &PortfolioValuationInput,
+) -> CorpFinanceResult<ComputationOutput<PortfolioValuationOutput>> {
+    let start = Instant::now();
+    let mut warnings: Vec<String> = Vec::new();
+
+    validate_input(input)?;
+
+    let quotes = fx_quotes(&input.market);
+    let base = &input.base_currency;
+
+    // --- Base valuation ---
+    let mut priced = Vec::with_capacity(input.positions.len());
+    let mut fx = Vec::with_capacity(input.positions.len());
+    for (i, p) in input.positions.iter().enumerate() {
+        let out = price_position(
+            p,
+            &input.market,
+            input.valuation_date,
+            &format!("positions[{i}]"),
+        )?;
+        warnings.extend(
+            out.warnings
+                .iter()
+                .map(|w| format!("Position '{}': {w}", p.id)),
+        );
+        if p.quantity.is_zero() {
+            warnings.push(format!("Position '{}' has zero quantity", p.id));
+        }
+        fx.push(fx_conversion_rate(&p.currency, base, &quotes).map_err(|_| {
+            CorpFinanceError::InvalidInput {
+                field: format!("positions[{i}].currency"),
+                reason: format!(
+                    "No FX rate in the market data set converts {:?} into {:?}",
+                    p.currency, base
+                ),
+            }
+        })?);
+        priced.push(out);
+    }
+    let base_values: Vec<Money> = input
+        .positions
+        .iter()
+        .zip(&priced)
+        .map(|(p, out)| out.unit_price * p.quantity)
+        .collect();
+
+    // --- Bump and revalue ---
+    let bumped = |scenario: MarketScenario| -> CorpFinanceResult<Vec<Money>> {
+        let market = input.market.shifted(&scenario)?;
+        let values = book_values(input, &market)?;
+        Ok(values
+            .iter()
+            .zip(&base_values)
+            .zip(&fx)
+            .map(|((v, v0), fx)| (v - v0) * fx)
+            .collect())
+    };
+    let dv01 = bumped(MarketScenario {
+        name: "rates +1bp".into(),
+        rate_shift: ONE_BP,
+        ..MarketScenario::default()
+    })?;
+    let cs01 = bumped(MarketScenario {
+        name: "spreads +1bp".into(),
+        spread_shift: ONE_BP,
+        ..MarketScenario::default()
+    })?;
+    let equity_delta = bumped(MarketScenario {
+        name: "equities +1%".into(),
+        equity_shock: EQUITY_BUMP,
+        ..MarketScenario::default()
+    })?;
+    let vega = bumped(MarketScenario {
+        name: "vol +1pt".into(),
+        vol_shift: VOL_BUMP,
+        ..MarketScenario::default()
+    })?;
+
+    // --- Per-position results ---
+    let market_values: Vec<Money> = base_values.iter().zip(&fx).map(|(v, f)| v * f).collect();
+    let gross_market_value: Money = market_values.iter().map(|v| v.abs()).sum();
+    let positions: Vec<PositionValuation> = input
+        .positions
+        .iter()
+        .enumerate()
+        .map(|(i, p)| {
+            let market_value = market_values[i];
+            PositionValuation {
+                id: p.id.clone(),
+                instrument_type: p.instrument.type_name().to_string(),
+                currency: p.currency.clone(),
+                quantity: p.quantity,
+                unit_price: priced[i].unit_price,
+                local_value: base_values[i],
+                fx_rate: fx[i],
+                market_value,
+                weight: if gross_market_value.is_zero() {
+                    Decimal::ZERO
+                } else {
+                    market_value / gross_market_value
+                },
+                greeks: priced[i].greeks.clone(),
+                risk: RiskSensitivities {
+                    dv01: dv01[i],
+                    cs01: cs01[i],
+                    equity_delta: equity_delta[i],
+                    vega: vega[i],
+                    fx_exposure: if p.currency == *base {
+                        Decimal::ZERO
+                    } else {
+                        market_value
+                    },
+                },
+            }
+        })
+        .collect();
+
+    // --- Aggregates ---
+    let mut total_risk = RiskSensitivities::default();
+    let mut by_type: BTreeMap<&str, InstrumentTypeSummary> = BTreeMap::new();
+    let mut by_underlying: BTreeMap<String, UnderlyingExposure> = BTreeMap::new();
+    for (p, v) in input.positions.iter().zip(&positions) {
+        total_risk.add(&v.risk);
+        let summary =
+            by_type
+                .entry(p.instrument.type_name())
+                .or_insert_with(|| InstrumentTypeSummary {
+                    instrument_type: v.instrument_type.clone(),
+                    num_positions: 0,
+                    market_value: Decimal::ZERO,
+                    risk: RiskSensitivities::default(),
+                });
+        summary.num_positions += 1;
+        summary.market_value += v.market_value;
+        summary.risk.add(&v.risk);
+
+        if let (Some(underlying), Some(g)) = (p.instrument.underlying(), &v.greeks) {
+            let spot = input.market.equity_prices[underlying];
+            let exposure = by_underlying
+                .entry(underlying.to_string())
+                .or_insert_with(|| UnderlyingExposure {
+                    underlying: underlying.to_string(),
+                    spot,
+                    delta: Decimal::ZERO,
+                    delta_value: Decimal::ZERO,
+                    gamma: Decimal::ZERO,
+                    vega: Decimal::ZERO,
+                    theta: Decimal::ZERO,
+                });
+            exposure.delta += g.delta;
+            exposure.delta_value += g.delta * spot * v.fx_rate;
+            exposure.gamma += g.gamma;
+            exposure.vega += g.vega * v.fx_rate;
+            exposure.theta += g.theta * v.fx_rate;
+        }
+    }
+
+    let output = PortfolioValuationOutput {
+        base_currency: base.clone(),
+        positions,
+        total_market_value: market_values.iter().sum(),
+        gross_market_value,
+        total_risk,
+        by_instrument_type: by_type.into_values().collect(),
+        by_underlying: by_underlying.into_values().collect(),
+    };
+
+    let elapsed = start.elapsed().as_micros() as u64;
+    let assumptions = serde_json::json!({
+        "valuation_date": input.valuation_date.to_string(),
+        "base_currency": input.base_currency,
+        "num_positions": input.positions.len(),
+        "rate_bump": ONE_BP.to_string(),
+        "spread_bump": ONE_BP.to_string(),
+        "equity_bump": EQUITY_BUMP.to_string(),
+        "vol_bump": VOL_BUMP.to_string(),
+        "curve_reads": "bonds at coupon frequency, options and convertibles continuous, notes annual",
+    });
+
+    Ok(with_metadata(
+        "Position-level portfolio valuation with bump-and-revalue risk",
+        &assumptions,
+        warnings,
+        elapsed,
+        output,
+    ))
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::curves::VolSurface;
+
+    fn flat_curve(rate: Rate) -> Curve {
+        Curve::from_zero_rates([dec!(1), dec!(5), dec!(10), dec!(30)].map(|t| (t, rate)))
+    }
+
+    fn market() -> MarketDataSet {
+        let mut m = MarketDataSet::default();
+        m.rate_curves
+            .insert("USD-OIS".into(), flat_curve(dec!(0.04)));
+        m.rate_curves
+            .insert("EUR-OIS".into(), flat_curve(dec!(0.03)));
+        m.spread_curves
+            .insert("ACME".into(), flat_curve(dec!(0.015)));
+        m.equity_prices.insert("ACME".into(), dec!(50));
+        m.equity_prices.insert("SX5E".into(), dec!(4800));
+        m.fx_rates.insert("EURUSD".into(), dec!(1.10));
+        let surface: VolSurface = serde_json::from_value(serde_json::json!({
+            "version": 1,
+            "underlying": "ACME",
+            "spot": 50,
+            "strike_axis": "moneyness",
+            "nodes": [
+                { "expiry": 0.5, "strike": 0.9, "vol": 0.32 },
+                { "expiry": 0.5, "strike": 1.0, "vol": 0.30 },
+                { "expiry": 0.5, "strike": 1.1, "vol": 0.29 },
+                { "expiry": 5.0, "strike": 0.9, "vol": 0.28 },
+                { "expiry": 5.0, "strike": 1.0, "vol": 0.27 },
+                { "expiry": 5.0, "strike": 1.5, "vol": 0.26 }
+            ]
+        }))
+        .unwrap();
+        m.vol_surfaces.insert("ACME-VOL".into(), surface);
+        m
+    }
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn position(id: &str, quantity: Decimal, instrument: Instrument) -> Position {
+        Position {
+            id: id.into(),
+            quantity,
+            currency: Currency::USD,
+            instrument,
+        }
+    }
+
+    fn bond() -> Instrument {
+        Instrument::Bond(BondPosition {
+            face_value: dec!(1000),
+            coupon_rate: dec!(0.05),
+            coupon_frequency: 2,
+            maturity_date: date(2031, 6, 15),
+            day_count: None,
+            discount_curve: "USD-OIS".into(),
+            spread_curve: Some("ACME".into()),
+            call_price: None,
+            call_date: None,
+        })
+    }
+
+    fn call_option() -> Instrument {
+        Instrument::EquityOption(EquityOptionPosition {
+            underlying: "ACME".into(),
+            strike_price: dec!(55),
+            time_to_expiry: dec!(0.5),
+            option_type: OptionType::Call,
+            exercise_style: None,
+            discount_curve: "USD-OIS".into(),
+            vol_surface: Some("ACME-VOL".into()),
+            volatility: None,
+            dividend_yield: Decimal::ZERO,
+        })
+    }
+
+    fn swap() -> Instrument {
+        Instrument::Swap(SwapPosition {
+            notional: dec!(10_000_000),
+            fixed_rate: dec!(0.035),
+            payment_frequency: 4,
+            remaining_years: dec!(5),
+            is_pay_fixed: true,
+            discount_curve: "USD-OIS".into(),
+            projection_curve: None,
+            last_floating_reset: None,
+        })
+    }
+
+    fn convertible() -> Instrument {
+        Instrument::Convertible(ConvertiblePosition {
+            face_value: dec!(1000),
+            coupon_rate: dec!(0.02),
+            coupon_frequency: 2,
+            maturity_years: dec!(5),
+            conversion_ratio: dec!(16),
+            underlying: "ACME".into(),
+            discount_curve: "USD-OIS".into(),
+            spread_curve: Some("ACME".into()),
+            credit_spread: None,
+            vol_surface: Some("ACME-VOL".into()),
+            volatility: None,
+            dividend_yield: None,
+            call_price: None,
+            call_protection_years: None,
+            put_price: None,
+            put_date_years: None,
+            tree_steps: Some(30),
+        })
+    }
+
+    fn note() -> Instrument {
+        Instrument::StructuredNote(StructuredNotePosition {
+            note_type: NoteType::CapitalProtected,
+            notional: dec!(100_000),
+            maturity_years: dec!(3),
+            underlying: "SX5E".into(),
+            discount_curve: "EUR-OIS".into(),
+            vol_surface: None,
+            volatility: Some(dec!(0.20)),
+            protection_level: Some(dec!(1)),
+            participation_rate: Some(dec!(0.6)),
+            cap_level: None,
+            barrier_level: None,
+            coupon_rate: None,
+            strike_pct: None,
+            floor_level: None,
+            reference_entity: None,
+            credit_spread_bps: None,
+            recovery_rate: None,
+            default_probability: None,
+        })
+    }
+
+    fn book() -> PortfolioValuationInput {
+        let mut eur_note = position("note", dec!(2), note());
+        eur_note.currency = Currency::EUR;
+        PortfolioValuationInput {
+            valuation_date: date(2026, 6, 15),
+            base_currency: Currency::USD,
+            market: market(),
+            positions: vec![
+                position("bond", dec!(100), bond()),
+                position(
+                    "shares",
+                    dec!(1000),
+                    Instrument::Equity(EquityPosition {
+                        underlying: "ACME".into(),
+                    }),
+                ),
+                position("calls", dec!(-500), call_option()),
+                position("swap", dec!(1), swap()),
+                position("cb", dec!(50), convertible()),
+                eur_note,
+            ],
+        }
+    }
+
+    fn find<'a>(out: &'a PortfolioValuationOutput, id: &str) -> &'a PositionValuation {
+        out.positions.iter().find(|p| p.id == id).unwrap()
+    }
+
+    #[test]
+    fn test_equity_position_is_price_times_quantity() {
+        let out = value_portfolio(&book()).unwrap().result;
+        let shares = find(&out, "shares");
+        assert_eq!(shares.market_value, dec!(50_000));
+        assert_eq!(shares.greeks.as_ref().unwrap().delta, dec!(1000));
+        // +1% on the price is 1% of the value
+        assert!((shares.risk.equity_delta - dec!(500)).abs() < dec!(0.0001));
+        assert_eq!(shares.risk.dv01, Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_bond_matches_standalone_pricer_at_curve_yield() {
+        let out = value_portfolio(&book()).unwrap().result;
+        let bond = find(&out, "bond");
+        let standalone = price_bond(&BondPricingInput {
+            face_value: dec!(1000),
+            coupon_rate: dec!(0.05),
+            coupon_frequency: 2,
+            ytm: flat_curve(dec!(0.04)).zero_rate_as(dec!(5), Compounding::SemiAnnual)
+                + flat_curve(dec!(0.015)).zero_rate_as(dec!(5), Compounding::SemiAnnual),
+            settlement_date: date(2026, 6, 15),
+            maturity_date: date(2031, 6, 15),
+            day_count: DayCountConvention::Thirty360,
+            call_price: None,
+            call_date: None,
+            currency: Currency::USD,
+            presentation_currency: None,
+            fx_rates: Vec::new(),
+            payment_adjustment: None,
+        })
+        .unwrap();
+        assert_eq!(bond.unit_price, standalone.result.dirty_price);
+        assert_eq!(bond.market_value, standalone.result.dirty_price * dec!(100));
+        // Long bond loses value when rates or spreads rise
+        assert!(bond.risk.dv01 < Decimal::ZERO);
+        assert!(bond.risk.cs01 < Decimal::ZERO);
+        assert!((bond.risk.dv01 - bond.risk.cs01).abs() < dec!(1));
+        assert!(bond.greeks.is_none());
+    }
+
+    #[test]
+    fn test_short_calls_have_negative_delta_and_vega() {
+        let out = value_portfolio(&book()).unwrap().result;
+        let calls = find(&out, "calls");
+        assert!(calls.market_value < Decimal::ZERO);
+        let g = calls.greeks.as_ref().unwrap();
+        assert!(g.delta < Decimal::ZERO);
+        assert!(g.vega < Decimal::ZERO);
+        assert!(calls.risk.equity_delta < Decimal::ZERO);
+        assert!(calls.risk.vega < Decimal::ZERO);
+        // Bumped revaluation agrees with the pricer delta to first order;
+        // the rest is gamma and the moneyness surface moving with spot
+        let approx = g.delta * dec!(0.5);
+        assert!((calls.risk.equity_delta - approx).abs() < approx.abs() * dec!(0.15));
+    }
+
+    #[test]
+    fn test_pay_fixed_swap_gains_when_rates_rise() {
+        let out = value_portfolio(&book()).unwrap().result;
+        let swap = find(&out, "swap");
+        assert!(swap.risk.dv01 > Decimal::ZERO);
+        assert_eq!(swap.risk.cs01, Decimal::ZERO);
+        assert_eq!(swap.risk.equity_delta, Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_convertible_has_equity_and_credit_risk() {
+        let out = value_portfolio(&book()).unwrap().result;
+        let cb = find(&out, "cb");
+        assert!(cb.risk.equity_delta > Decimal::ZERO);
+        assert!(cb.risk.cs01 < Decimal::ZERO);
+        assert!(cb.greeks.as_ref().unwrap().delta > Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_foreign_position_translated_to_base() {
+        let out = value_portfolio(&book()).unwrap().result;
+        let note = find(&out, "note");
+        assert_eq!(note.fx_rate, dec!(1.10));
+        assert_eq!(note.market_value, note.local_value * dec!(1.10));
+        assert_eq!(note.risk.fx_exposure, note.market_value);
+        assert!(note.risk.dv01 != Decimal::ZERO);
+        // Strikes reset to the bumped level, so no equity delta
+        assert!(note.risk.equity_delta.abs() < dec!(0.01));
+    }
+
+    #[test]
+    fn test_aggregates_add_up() {
+        let out = value_portfolio(&book()).unwrap().result;
+        let sum: Money = out.positions.iter().map(|p| p.market_value).sum();
+        assert!((out.total_market_value - sum).abs() < dec!(0.000001));
+        let dv01: Money = out.positions.iter().map(|p| p.risk.dv01).sum();
+        assert!((out.total_risk.dv01 - dv01).abs() < dec!(0.000001));
+        let by_type: Money = out.by_instrument_type.iter().map(|t| t.market_value).sum();
+        assert!((by_type - sum).abs() < dec!(0.000001));
+        assert_eq!(out.by_instrument_type.len(), 6);
+        let weights: Decimal = out.positions.iter().map(|p| p.weight.abs()).sum();
+        assert!((weights - Decimal::ONE).abs() < dec!(0.000001));
+    }
+
+    #[test]
+    fn test_underlying_exposure_nets_shares_and_options() {
+        let out = value_portfolio(&book()).unwrap().result;
+        let acme = out
+            .by_underlying
+            .iter()
+            .find(|u| u.underlying == "ACME")
+            .unwrap();
+        let expected: Decimal = ["shares", "calls", "cb"]
+            .iter()
+            .map(|id| find(&out, id).greeks.as_ref().unwrap().delta)
+            .sum();
+        assert!((acme.delta - expected).abs() < dec!(0.000001));
+        assert!((acme.delta_value - expected * dec!(50)).abs() < dec!(0.0001));
+    }
+
+    #[test]
+    fn test_missing_market_reference_rejected() {
+        let mut input = book();
+        input.market.rate_curves.remove("EUR-OIS");
+        match value_portfolio(&input) {
+            Err(CorpFinanceError::InvalidInput { field, .. }) => {
+                assert_eq!(field, "positions[5].instrument.discount_curve");
+            }
+            other => panic!("expected InvalidInput, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_missing_fx_rate_rejected() {
+        let mut input = book();
+        input.market.fx_rates.clear();
+        assert!(matches!(
+            value_portfolio(&input),
+            Err(CorpFinanceError::InvalidInput { field, .. }) if field == "positions[5].currency"
+        ));
+    }
+
+    #[test]
+    fn test_duplicate_ids_and_empty_book_rejected() {
+        let mut input = book();
+        input.positions[1].id = "bond".into();
+        assert!(value_portfolio(&input).is_err());
+        input.positions.clear();
+        assert!(matches!(
+            value_portfolio(&input),
+            Err(CorpFinanceError::InsufficientData(_))
+        ));
+    }
+
+    #[test]
+    fn test_positions_deserialise_from_tagged_json() {
+        let p: Position = serde_json::from_value(serde_json::json!({
+            "id": "spx",
+            "quantity": 10,
+            "instrument": { "type": "equity", "underlying": "SX5E" }
+        }))
+        .unwrap();
+        assert_eq!(p.instrument.type_name(), "equity");
+        assert_eq!(p.currency, Currency::USD);
+    }
+}
@@ -0,0 +1,483 @@
+use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
+use rust_decimal::{Decimal, RoundingStrategy};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::cell::RefCell;
+use std::str::FromStr;
+use std::sync::{OnceLock, RwLock};
+
+use crate::{CorpFinanceError, CorpFinanceResult};
+
+// ---------------------------------------------------------------------------
+// Policy
+// ---------------------------------------------------------------------------
+
+/// How values are rounded to the configured decimal places.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RoundingMode {
+    /// Half away from zero (2.345 -> 2.35), the usual accounting convention.
+    #[default]
+    HalfUp,
+    /// Half towards zero (2.345 -> 2.34).
+    HalfDown,
+    /// Banker's rounding: half to the nearest even digit.
+    HalfEven,
+    /// Truncate towards zero.
+    Down,
+    /// Away from zero.
+    Up,
+    /// Towards negative infinity.
+    Floor,
+    /// Towards positive infinity.
+    Ceiling,
+}
+
+impl RoundingMode {
+    fn strategy(self) -> RoundingStrategy {
+        match self {
+            RoundingMode::HalfUp => RoundingStrategy::MidpointAwayFromZero,
+            RoundingMode::HalfDown => RoundingStrategy::MidpointTowardZero,
+            RoundingMode::HalfEven => RoundingStrategy::MidpointNearestEven,
+            RoundingMode::Down => RoundingStrategy::ToZero,
+            RoundingMode::Up => RoundingStrategy::AwayFromZero,
+            RoundingMode::Floor => RoundingStrategy::ToNegativeInfinity,
+            RoundingMode::Ceiling => RoundingStrategy::ToPositiveInfinity,
+        }
+    }
+}
+
+impl FromStr for RoundingMode {
+    type Err = CorpFinanceError;
+
+    fn from_str(s: &str) -> CorpFinanceResult<Self> {
+        serde_json::from_value(Value::String(s.trim().replace('-', "_").to_lowercase())).map_err(
+            |_| CorpFinanceError::InvalidInput {
+                field: "rounding".into(),
+                reason: format!(
+                    "'{s}' is not one of half_up, half_down, half_even, down, up, floor, ceiling"
+                ),
+            },
+        )
+    }
+}
+
+/// Decimal places applied to calculator results when they are serialised.
+/// `None` leaves that class of value at full precision, which is the
+/// default, so outputs are unchanged unless a policy is configured.
+///
+/// Money, rates and ratios are told apart by field name (see
+/// [`classify`]); counts such as years, periods and seeds are never rounded.
+/// The active policy is echoed as `metadata.rounding`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PrecisionPolicy {
+    /// Decimal places for monetary amounts (e.g. 2 for pennies).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub money_dp: Option<u32>,
+    /// Decimal places for rates, yields and percentages held as decimals
+    /// (e.g. 4 for basis points).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rate_dp: Option<u32>,
+    /// Decimal places for ratios and multiples.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ratio_dp: Option<u32>,
+    #[serde(default)]
+    pub mode: RoundingMode,
+}
+
+/// Class of a numeric output field, inferred from its name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValueKind {
+    Money,
+    Rate,
+    Ratio,
+    /// Counts, periods and identifiers, which are left alone.
+    Count,
+}
+
+impl PrecisionPolicy {
+    /// Read the policy from `CFA_MONEY_DP`, `CFA_RATE_DP`, `CFA_RATIO_DP`
+    /// and `CFA_ROUNDING`. Unset or empty variables keep the default.
+    pub fn from_env() -> CorpFinanceResult<Self> {
+        fn var(name: &str) -> Option<String> {
+            std::env::var(name).ok().filter(|v| !v.trim().is_empty())
+        }
+        fn places(name: &str) -> CorpFinanceResult<Option<u32>> {
+            var(name)
+                .map(|v| {
+                    v.trim()
+                        .parse::<u32>()
+                        .ok()
+                        .filter(|dp| *dp <= MAX_DP)
+                        .ok_or_else(|| CorpFinanceError::InvalidInput {
+                            field: name.into(),
+                            reason: format!("'{v}' is not a number of decimal places (0-{MAX_DP})"),
+                        })
+                })
+                .transpose()
+        }
+        Ok(PrecisionPolicy {
+            money_dp: places("CFA_MONEY_DP")?,
+            rate_dp: places("CFA_RATE_DP")?,
+            ratio_dp: places("CFA_RATIO_DP")?,
+            mode: var("CFA_ROUNDING")
+                .map(|v| v.parse())
+                .transpose()?
+                .unwrap_or_default(),
+        })
+    }
+
+    /// Whether the policy rounds anything.
+    pub fn is_active(&self) -> bool {
+        self.money_dp.is_some() || self.rate_dp.is_some() || self.ratio_dp.is_some()
+    }
+
+    /// Fill in any unset places from `base`; `self` wins where both are set.
+    pub fn or(self, base: PrecisionPolicy) -> PrecisionPolicy {
+        PrecisionPolicy {
+            money_dp: self.money_dp.or(base.money_dp),
+            rate_dp: self.rate_dp.or(base.rate_dp),
+            ratio_dp: self.ratio_dp.or(base.ratio_dp),
+            mode: self.mode,
+        }
+    }
+
+    fn places(&self, kind: ValueKind) -> Option<u32> {
+        match kind {
+            ValueKind::Money => self.money_dp,
+            ValueKind::Rate => self.rate_dp,
+            ValueKind::Ratio => self.ratio_dp,
+            ValueKind::Count => None,
+        }
+    }
+
+    /// Round one value of the given kind.
+    pub fn round(&self, value: Decimal, kind: ValueKind) -> Decimal {
+        match self.places(kind) {
+            Some(dp) => value.round_dp_with_strategy(dp, self.mode.strategy()),
+            None => value,
+        }
+    }
+
+    /// Round every number in a serialised result in place. Decimals
+    /// serialise as strings and are rounded as strings; floats stay floats.
+    /// Array elements take the kind of the field holding the array.
+    pub fn apply(&self, value: &mut Value) {
+        if self.is_active() {
+            self.apply_as(value, ValueKind::Money);
+        }
+    }
+
+    fn apply_as(&self, value: &mut Value, kind: ValueKind) {
+        match value {
+            Value::Object(map) => {
+                for (key, v) in map.iter_mut() {
+                    self.apply_as(v, classify(key));
+                }
+            }
+            Value::Array(items) => {
+                for v in items {
+                    self.apply_as(v, kind);
+                }
+            }
+            Value::String(s) => {
+                if let Some(dp) = self.places(kind) {
+                    if let Ok(d) = Decimal::from_str(s) {
+                        *s = d
+                            .round_dp_with_strategy(dp, self.mode.strategy())
+                            .to_string();
+                    }
+                }
+            }
+            Value::Number(n) if n.is_f64() => {
+                let rounded = n
+                    .as_f64()
+                    .and_then(Decimal::from_f64)
+                    .map(|d| self.round(d, kind))
+                    .and_then(|d| d.to_f64())
+                    .and_then(serde_json::Number::from_f64);
+                if let Some(r) = rounded {
+                    *n = r;
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Largest scale a `Decimal` can hold.
+const MAX_DP: u32 = 28;
+
+const COUNT_WORDS: &[&str] = &[
+    "year",
+    "years",
+    "period",
+    "periods",
+    "month",
+    "months",
+    "quarter",
+    "day",
+    "days",
+    "count",
+    "num",
+    "number",
+    "index",
+    "seed",
+    "version",
+    "rank",
+    "step",
+    "steps",
+    "paths",
+    "simulations",
+    "us",
+];
+const RATE_WORDS: &[&str] = &[
+    "rate",
+    "rates",
+    "yield",
+    "yields",
+    "pct",
+    "percent",
+    "percentage",
+    "margin",
+    "margins",
+    "irr",
+    "growth",
+    "wacc",
+    "spread",
+    "spreads",
+    "return",
+    "returns",
+    "probability",
+    "pd",
+    "lgd",
+    "weight",
+    "weights",
+    "volatility",
+    "vol",
+    "cagr",
+    "utilization",
+    "utilisation",
+    "discount",
+    "correlation",
+    "confidence",
+    "bps",
+];
+const RATIO_WORDS: &[&str] = &[
+    "ratio",
+    "ratios",
+    "multiple",
+    "multiples",
+    "coverage",
+    "leverage",
+    "moic",
+    "tvpi",
+    "dpi",
+    "rvpi",
+    "beta",
+    "duration",
+    "convexity",
+    "times",
+    "x",
+    "factor",
+    "delta",
+    "gamma",
+    "vega",
+    "theta",
+    "rho",
+    "sharpe",
+    "sortino",
+];
+
+/// Classify a field by the words in its snake_case name, e.g.
+/// `interest_coverage` is a ratio, `exit_year` a count, `net_debt` money.
+/// Names with no recognised word are treated as money.
+pub fn classify(field: &str) -> ValueKind {
+    let lower = field.to_lowercase();
+    let words: Vec<&str> = lower.split(|c: char| !c.is_ascii_alphanumeric()).collect();
+    let has = |set: &[&str]| words.iter().any(|w| set.contains(w));
+    // The last word names the quantity: `growth_years` is a count,
+    // `years_to_breakeven_rate` a rate
+    match words.last() {
+        // Collateral balances, not profit margins
+        Some(&"margin") if has(&["initial", "variation"]) => ValueKind::Money,
+        Some(w) if COUNT_WORDS.contains(w) => ValueKind::Count,
+        Some(w) if RATIO_WORDS.contains(w) => ValueKind::Ratio,
+        Some(w) if RATE_WORDS.contains(w) => ValueKind::Rate,
+        // cost_of_equity, after_tax_cost_of_debt
+        _ if lower.contains("cost_of") => ValueKind::Rate,
+        _ if has(RATIO_WORDS) => ValueKind::Ratio,
+        _ if has(RATE_WORDS) => ValueKind::Rate,
+        // debt_to_ebitda, loan_to_value, cash_on_cash (but years_to_maturity)
+        Some(_) if lower.contains("_to_") || lower.contains("cash_on_cash") => {
+            if words.first().is_some_and(|w| COUNT_WORDS.contains(w)) {
+                ValueKind::Count
+            } else {
+                ValueKind::Ratio
+            }
+        }
+        _ => ValueKind::Money,
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Configuration
+// ---------------------------------------------------------------------------
+
+fn global() -> &'static RwLock<PrecisionPolicy> {
+    static GLOBAL: OnceLock<RwLock<PrecisionPolicy>> = OnceLock::new();
+    // Invalid variables are ignored here; callers that want them reported
+    // call `PrecisionPolicy::from_env` and `configure` at start-up.
+    GLOBAL.get_or_init(|| RwLock::new(PrecisionPolicy::from_env().unwrap_or_default()))
+}
+
+thread_local! {
+    static SCOPED: RefCell<Option<PrecisionPolicy>> = const { RefCell::new(None) };
+}
+
+/// Replace the process-wide policy.
+pub fn configure(policy: PrecisionPolicy) {
+    *global().write().unwrap_or_else(|e| e.into_inner()) = policy;
+}
+
+/// Policy in force on the current thread.
+pub fn current() -> PrecisionPolicy {
+    SCOPED
+        .with(|s| *s.borrow())
+        .unwrap_or_else(|| *global().read().unwrap_or_else(|e| e.into_inner()))
+}
+
+/// Run `f` with `policy` in place of the process-wide policy on this thread.
+/// Rounding happens when output is serialised, so serialise inside `f`.
+pub fn with_precision<T>(policy: PrecisionPolicy, f: impl FnOnce() -> T) -> T {
+    struct Restore(Option<PrecisionPolicy>);
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            SCOPED.with(|s| *s.borrow_mut() = self.0);
+        }
+    }
+    let _restore = Restore(SCOPED.with(|s| s.borrow_mut().replace(policy)));
+    f()
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+    use serde_json::json;
+
+    fn policy() -> PrecisionPolicy {
+        PrecisionPolicy {
+            money_dp: Some(2),
+            rate_dp: Some(4),
+            ratio_dp: Some(1),
+            mode: RoundingMode::HalfUp,
+        }
+    }
+
+    #[test]
+    fn test_classify() {
+        assert_eq!(classify("enterprise_value"), ValueKind::Money);
+        assert_eq!(classify("net_debt"), ValueKind::Money);
+        assert_eq!(classify("wacc"), ValueKind::Rate);
+        assert_eq!(classify("ebitda_margin"), ValueKind::Rate);
+        assert_eq!(classify("terminal_growth_rate"), ValueKind::Rate);
+        assert_eq!(classify("after_tax_cost_of_debt"), ValueKind::Rate);
+        assert_eq!(classify("total_cost"), ValueKind::Money);
+        assert_eq!(classify("interest_coverage"), ValueKind::Ratio);
+        assert_eq!(classify("net_debt_to_ebitda_x"), ValueKind::Ratio);
+        assert_eq!(classify("moic"), ValueKind::Ratio);
+        assert_eq!(classify("cash_on_cash"), ValueKind::Ratio);
+        assert_eq!(classify("loan_to_value"), ValueKind::Ratio);
+        assert_eq!(classify("yield_to_maturity"), ValueKind::Rate);
+        assert_eq!(classify("years_to_maturity"), ValueKind::Count);
+        assert_eq!(classify("exit_year"), ValueKind::Count);
+        assert_eq!(classify("computation_time_us"), ValueKind::Count);
+        assert_eq!(classify("growth_years"), ValueKind::Count);
+        assert_eq!(classify("initial_margin"), ValueKind::Money);
+    }
+
+    #[test]
+    fn test_rounding_modes() {
+        let v = dec!(2.345);
+        let round = |mode| PrecisionPolicy { mode, ..policy() }.round(v, ValueKind::Money);
+        assert_eq!(round(RoundingMode::HalfUp), dec!(2.35));
+        assert_eq!(round(RoundingMode::HalfDown), dec!(2.34));
+        assert_eq!(round(RoundingMode::HalfEven), dec!(2.34));
+        assert_eq!(round(RoundingMode::Down), dec!(2.34));
+        assert_eq!(round(RoundingMode::Up), dec!(2.35));
+        assert_eq!(
+            PrecisionPolicy {
+                mode: RoundingMode::Floor,
+                ..policy()
+            }
+            .round(dec!(-2.341), ValueKind::Money),
+            dec!(-2.35)
+        );
+        assert_eq!(
+            "half-even".parse::<RoundingMode>().unwrap(),
+            RoundingMode::HalfEven
+        );
+        assert!("nearest".parse::<RoundingMode>().is_err());
+    }
+
+    #[test]
+    fn test_apply_to_json() {
+        let mut v = json!({
+            "enterprise_value": "1234.5678",
+            "wacc": "0.0912345",
+            "interest_coverage": "3.456",
+            "exit_year": 5,
+            "cash_flows": ["10.005", "20.004"],
+            "paths": { "mean_value": 1.23456, "label": "base" },
+        });
+        policy().apply(&mut v);
+        assert_eq!(v["enterprise_value"], "1234.57");
+        assert_eq!(v["wacc"], "0.0912");
+        assert_eq!(v["interest_coverage"], "3.5");
+        assert_eq!(v["exit_year"], 5);
+        assert_eq!(v["cash_flows"], json!(["10.01", "20.00"]));
+        assert_eq!(v["paths"]["mean_value"], json!(1.23));
+        assert_eq!(v["paths"]["label"], "base");
+    }
+
+    #[test]
+    fn test_default_policy_is_inactive() {
+        let mut v = json!({ "enterprise_value": "1234.5678" });
+        PrecisionPolicy::default().apply(&mut v);
+        assert_eq!(v["enterprise_value"], "1234.5678");
+        assert!(!PrecisionPolicy::default().is_active());
+    }
+
+    #[test]
+    fn test_output_envelope_rounded_and_echoed() {
+        let output = crate::types::with_metadata(
+            "test",
+            &json!({ "input_value": "1.23456" }),
+            vec![],
+            0,
+            json!({ "equity_value": "1.23456" }),
+        );
+        let plain = serde_json::to_value(&output).unwrap();
+        assert_eq!(plain["result"]["equity_value"], "1.23456");
+        assert!(plain["metadata"].get("rounding").is_none());
+
+        let rounded = with_precision(policy(), || serde_json::to_value(&output).unwrap());
+        assert_eq!(rounded["result"]["equity_value"], "1.23");
+        // Inputs are echoed as given
+        assert_eq!(rounded["assumptions"]["input_value"], "1.23456");
+        assert_eq!(rounded["metadata"]["rounding"]["money_dp"], 2);
+        assert_eq!(rounded["metadata"]["rounding"]["mode"], "half_up");
+    }
+
+    #[test]
+    fn test_scoped_policy_restored() {
+        let outer = current();
+        with_precision(policy(), || assert_eq!(current(), policy()));
+        assert_eq!(current(), outer);
+    }
+}
@@ -0,0 +1,696 @@
+//! Bridge loan economics for acquisition financing.
+//!
+//! Models a committed bridge facility from signing through takeout:
+//! ticking and commitment fees while unfunded, funding and duration fees and
+//! the stepped-up coupon once funded, the negative carry of holding the loan
+//! against funding and capital costs, and the securities-demand loss when
+//! takeout bonds must be placed above the yield cap. Underwriter P&L is
+//! computed per takeout scenario and aggregated into a probability-weighted
+//! distribution.
+
+use rust_decimal::Decimal;
+use rust_decimal::MathematicalOps;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+use std::time::Instant;
+
+use crate::error::CorpFinanceError;
+use crate::types::{with_metadata, ComputationOutput, Money, Rate};
+use crate::CorpFinanceResult;
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Basis points divisor
+const BPS: Decimal = dec!(10000);
+
+/// Months per coupon step-up / duration fee period
+const STEP_MONTHS: u32 = 3;
+
+// ---------------------------------------------------------------------------
+// Input types
+// ---------------------------------------------------------------------------
+
+/// Fees payable on the bridge.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BridgeFees {
+    /// Paid at signing on the full commitment
+    pub commitment_fee_pct: Rate,
+    /// Ticking fee on the undrawn commitment from signing to closing (bps p.a.)
+    #[serde(default)]
+    pub ticking_fee_bps: Decimal,
+    /// Paid on the funded amount if the bridge is drawn
+    pub funding_fee_pct: Rate,
+    /// Duration fees on the outstanding amount at the end of each
+    /// three-month period the bridge remains funded (e.g. 90/180/270 days)
+    #[serde(default)]
+    pub duration_fees: Vec<Rate>,
+    /// Gross spread earned on the takeout securities
+    pub takeout_fee_pct: Rate,
+}
+
+/// Bridge coupon terms.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BridgeCoupon {
+    pub base_rate: Rate,
+    pub initial_spread_bps: Decimal,
+    /// Spread step-up every three months funded
+    pub step_up_bps: Decimal,
+    /// Total cap on the bridge coupon
+    pub cap_rate: Rate,
+}
+
+/// Lender cost of holding the commitment and the funded loan.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HoldingCosts {
+    /// Cost of funding the drawn amount (p.a.)
+    pub funding_cost_rate: Rate,
+    /// Cost of capital held against the undrawn commitment (p.a.)
+    pub unfunded_capital_charge: Rate,
+    /// Cost of capital held against the funded loan (p.a.)
+    pub funded_capital_charge: Rate,
+}
+
+/// Securities demand and takeout terms.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TakeoutTerms {
+    /// Yield at which the takeout bonds are launched; the underwriters may
+    /// flex pricing up to the securities demand cap at the borrower's cost
+    pub launch_yield: Rate,
+    /// Maximum yield at which the borrower must issue takeout securities
+    /// (securities demand cap); above it the underwriters absorb the difference
+    pub securities_demand_cap: Rate,
+    /// Modified duration of the takeout bonds, used to convert yield
+    /// concessions into price
+    pub bond_duration: Decimal,
+}
+
+/// A takeout outcome.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TakeoutScenario {
+    pub name: String,
+    pub probability: Rate,
+    /// Months the bridge is funded before takeout; 0 means the bonds are
+    /// placed before closing and the bridge is never drawn
+    pub months_funded: u32,
+    /// Clearing yield for the takeout bonds
+    pub market_yield: Rate,
+}
+
+/// Input for bridge financing economics.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BridgeFinancingInput {
+    pub deal_name: String,
+    pub commitment: Money,
+    /// Underwriter's share of the commitment (default 100%)
+    #[serde(default = "default_share")]
+    pub underwriter_share: Rate,
+    /// Days from signing to closing (commitment period)
+    pub days_to_closing: u32,
+    pub fees: BridgeFees,
+    pub coupon: BridgeCoupon,
+    pub holding_costs: HoldingCosts,
+    pub takeout: TakeoutTerms,
+    pub scenarios: Vec<TakeoutScenario>,
+}
+
+fn default_share() -> Rate {
+    Decimal::ONE
+}
+
+// ---------------------------------------------------------------------------
+// Output types
+// ---------------------------------------------------------------------------
+
+/// Underwriter P&L breakdown for one takeout scenario.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScenarioEconomics {
+    pub scenario: String,
+    pub probability: Rate,
+    pub funded: bool,
+    pub commitment_fee: Money,
+    pub ticking_fee: Money,
+    pub funding_fee: Money,
+    pub duration_fees: Money,
+    pub takeout_fee: Money,
+    /// Coupon earned while funded
+    pub interest_income: Money,
+    pub funding_cost: Money,
+    /// Capital charge on the commitment and the funded loan
+    pub capital_cost: Money,
+    /// Funding plus funded capital cost less interest income (positive = cost)
+    pub negative_carry: Money,
+    /// Pricing flex exercised above the launch yield, borne by the borrower
+    pub flex_used_bps: Decimal,
+    /// Loss from placing takeout bonds above the securities demand cap
+    pub securities_demand_loss: Money,
+    pub underwriter_pnl: Money,
+    /// Coupon in the final funded month
+    pub final_coupon: Rate,
+    /// Fees and interest paid by the borrower on the bridge (full commitment)
+    pub borrower_cost: Money,
+}
+
+/// Probability-weighted underwriter P&L.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PnlDistribution {
+    pub expected_pnl: Money,
+    pub pnl_std_dev: Money,
+    pub worst_case_pnl: Money,
+    pub best_case_pnl: Money,
+    pub probability_of_loss: Rate,
+    /// Expected P&L as a fraction of the underwriter's commitment
+    pub expected_return_on_commitment: Rate,
+}
+
+/// Output of bridge financing economics.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BridgeFinancingOutput {
+    pub underwritten_amount: Money,
+    /// Fees earned regardless of outcome (commitment + ticking)
+    pub guaranteed_fees: Money,
+    /// Monthly carry on the funded loan at the initial coupon (positive = cost)
+    pub initial_monthly_carry: Money,
+    /// Months until the coupon reaches its cap
+    pub months_to_cap: Option<u32>,
+    /// Market yield above the cap at which the underwriter P&L in a
+    /// refinancing at closing turns negative
+    pub breakeven_market_yield: Option<Rate>,
+    pub scenarios: Vec<ScenarioEconomics>,
+    pub distribution: PnlDistribution,
+}
+
+// ---------------------------------------------------------------------------
+// Public API
+// ---------------------------------------------------------------------------
+
+/// Underwriter and borrower economics of a committed bridge across takeout
+/// scenarios.
+pub fn analyze_bridge_financing(
+    input: &BridgeFinancingInput,
+) -> CorpFinanceResult<ComputationOutput<BridgeFinancingOutput>> {
+    let start = Instant::now();
+    let mut warnings: Vec<String> = Vec::new();
+
+    validate_input(input)?;
+
+    let amount = input.commitment * input.underwriter_share;
+    let commitment_years = Decimal::from(input.days_to_closing) / dec!(365);
+    let f = &input.fees;
+    let h = &input.holding_costs;
+
+    let commitment_fee = amount * f.commitment_fee_pct;
+    let ticking_fee =
+        amount * f.ticking_fee_bps / BPS * Decimal::from(input.days_to_closing) / dec!(360);
+    let unfunded_capital = amount * h.unfunded_capital_charge * commitment_years;
+
+    let scenarios: Vec<ScenarioEconomics> = input
+        .scenarios
+        .iter()
+        .map(|s| scenario_economics(input, s, commitment_fee, ticking_fee, unfunded_capital))
+        .collect();
+
+    // --- Distribution ---
+    let expected_pnl: Decimal = scenarios
+        .iter()
+        .map(|s| s.probability * s.underwriter_pnl)
+        .sum();
+    let variance: Decimal = scenarios
+        .iter()
+        .map(|s| {
+            s.probability * (s.underwriter_pnl - expected_pnl) * (s.underwriter_pnl - expected_pnl)
+        })
+        .sum();
+    let distribution = PnlDistribution {
+        expected_pnl,
+        pnl_std_dev: variance.sqrt().unwrap_or(Decimal::ZERO),
+        worst_case_pnl: scenarios
+            .iter()
+            .map(|s| s.underwriter_pnl)
+            .min()
+            .unwrap_or(Decimal::ZERO),
+        best_case_pnl: scenarios
+            .iter()
+            .map(|s| s.underwriter_pnl)
+            .max()
+            .unwrap_or(Decimal::ZERO),
+        probability_of_loss: scenarios
+            .iter()
+            .filter(|s| s.underwriter_pnl < Decimal::ZERO)
+            .map(|s| s.probability)
+            .sum(),
+        expected_return_on_commitment: expected_pnl / amount,
+    };
+
+    // --- Carry and breakeven ---
+    let c = &input.coupon;
+    let initial_coupon = coupon_for_month(c, 0);
+    let initial_monthly_carry =
+        amount * (h.funding_cost_rate + h.funded_capital_charge - initial_coupon) / dec!(12);
+    let months_to_cap = if initial_coupon >= c.cap_rate {
+        Some(0)
+    } else if c.step_up_bps > Decimal::ZERO {
+        (0..=120u32).find(|m| coupon_for_month(c, *m) >= c.cap_rate)
+    } else {
+        None
+    };
+
+    // Refinanced at closing: fees - unfunded capital - amount x D x (y - cap) = 0
+    let fees_at_closing = commitment_fee + ticking_fee + amount * f.takeout_fee_pct;
+    let breakeven_market_yield = if input.takeout.bond_duration > Decimal::ZERO {
+        Some(
+            input.takeout.securities_demand_cap
+                + (fees_at_closing - unfunded_capital) / (amount * input.takeout.bond_duration),
+        )
+    } else {
+        None
+    };
+
+    if initial_monthly_carry > Decimal::ZERO {
+        warnings.push(format!(
+            "Bridge carries negatively from funding: {:.0} per month at the initial coupon",
+            initial_monthly_carry
+        ));
+    }
+    for s in &scenarios {
+        if s.securities_demand_loss > Decimal::ZERO {
+            warnings.push(format!(
+                "{}: takeout clears above the securities demand cap",
+                s.scenario
+            ));
+        }
+    }
+    if months_to_cap.is_some_and(|m| m <= 6) {
+        warnings.push("Coupon reaches its cap within six months of funding".into());
+    }
+
+    let output = BridgeFinancingOutput {
+        underwritten_amount: amount,
+        guaranteed_fees: commitment_fee + ticking_fee,
+        initial_monthly_carry,
+        months_to_cap,
+        breakeven_market_yield,
+        scenarios,
+        distribution,
+    };
+
+    let elapsed = start.elapsed().as_micros() as u64;
+    Ok(with_metadata(
+        "Bridge Financing Economics (fees, negative carry, securities demand, takeout scenarios)",
+        &serde_json::json!({
+            "deal": input.deal_name,
+            "commitment": input.commitment.to_string(),
+            "underwriter_share": input.underwriter_share.to_string(),
+            "days_to_closing": input.days_to_closing,
+            "securities_demand_cap": input.takeout.securities_demand_cap.to_string(),
+            "scenarios": input.scenarios.len(),
+        }),
+        warnings,
+        elapsed,
+        output,
+    ))
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Bridge coupon in a funded month (0-based), stepping up each quarter.
+fn coupon_for_month(c: &BridgeCoupon, month: u32) -> Rate {
+    let steps = Decimal::from(month / STEP_MONTHS);
+    (c.base_rate + (c.initial_spread_bps + c.step_up_bps * steps) / BPS).min(c.cap_rate)
+}
+
+fn scenario_economics(
+    input: &BridgeFinancingInput,
+    s: &TakeoutScenario,
+    commitment_fee: Money,
+    ticking_fee: Money,
+    unfunded_capital: Money,
+) -> ScenarioEconomics {
+    let amount = input.commitment * input.underwriter_share;
+    let f = &input.fees;
+    let h = &input.holding_costs;
+    let funded = s.months_funded > 0;
+
+    let funding_fee = if funded {
+        amount * f.funding_fee_pct
+    } else {
+        Decimal::ZERO
+    };
+    let duration_fees: Decimal = f
+        .duration_fees
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| s.months_funded >= STEP_MONTHS * (*i as u32 + 1))
+        .map(|(_, fee)| amount * *fee)
+        .sum();
+
+    let mut interest_income = Decimal::ZERO;
+    let mut final_coupon = Decimal::ZERO;
+    for month in 0..s.months_funded {
+        final_coupon = coupon_for_month(&input.coupon, month);
+        interest_income += amount * final_coupon / dec!(12);
+    }
+    let months = Decimal::from(s.months_funded) / dec!(12);
+    let funding_cost = amount * h.funding_cost_rate * months;
+    let funded_capital = amount * h.funded_capital_charge * months;
+    let negative_carry = funding_cost + funded_capital - interest_income;
+
+    let takeout_fee = amount * f.takeout_fee_pct;
+    let cap = input.takeout.securities_demand_cap;
+    let flex_used_bps =
+        (s.market_yield.min(cap) - input.takeout.launch_yield).max(Decimal::ZERO) * BPS;
+    let securities_demand_loss =
+        amount * input.takeout.bond_duration * (s.market_yield - cap).max(Decimal::ZERO);
+
+    let fees = commitment_fee + ticking_fee + funding_fee + duration_fees + takeout_fee;
+    let underwriter_pnl = fees - negative_carry - unfunded_capital - securities_demand_loss;
+
+    // Borrower pays fees and coupon on the full commitment, not just this
+    // underwriter's share.
+    let gross_up = Decimal::ONE / input.underwriter_share;
+    let borrower_cost =
+        (commitment_fee + ticking_fee + funding_fee + duration_fees + interest_income) * gross_up;
+
+    ScenarioEconomics {
+        scenario: s.name.clone(),
+        probability: s.probability,
+        funded,
+        commitment_fee,
+        ticking_fee,
+        funding_fee,
+        duration_fees,
+        takeout_fee,
+        interest_income,
+        funding_cost,
+        capital_cost: unfunded_capital + funded_capital,
+        negative_carry,
+        flex_used_bps,
+        securities_demand_loss,
+        underwriter_pnl,
+        final_coupon,
+        borrower_cost,
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Validation
+// ---------------------------------------------------------------------------
+
+fn validate_input(input: &BridgeFinancingInput) -> CorpFinanceResult<()> {
+    if input.commitment <= Decimal::ZERO {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "commitment".into(),
+            reason: "Commitment must be positive".into(),
+        });
+    }
+    if input.underwriter_share <= Decimal::ZERO || input.underwriter_share > Decimal::ONE {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "underwriter_share".into(),
+            reason: "Share must be in (0, 1]".into(),
+        });
+    }
+    if input.coupon.cap_rate <= Decimal::ZERO {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "coupon.cap_rate".into(),
+            reason: "Cap rate must be positive".into(),
+        });
+    }
+    if input.takeout.bond_duration < Decimal::ZERO {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "takeout.bond_duration".into(),
+            reason: "Duration cannot be negative".into(),
+        });
+    }
+    if input.scenarios.is_empty() {
+        return Err(CorpFinanceError::InsufficientData(
+            "At least one takeout scenario is required".into(),
+        ));
+    }
+    if input
+        .scenarios
+        .iter()
+        .any(|s| s.probability < Decimal::ZERO)
+    {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "scenarios.probability".into(),
+            reason: "Probabilities cannot be negative".into(),
+        });
+    }
+    let total: Decimal = input.scenarios.iter().map(|s| s.probability).sum();
+    if (total - Decimal::ONE).abs() > dec!(0.0001) {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "scenarios.probability".into(),
+            reason: format!("Probabilities must sum to 1 (got {total})"),
+        });
+    }
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn scenario(name: &str, p: Decimal, months: u32, y: Decimal) -> TakeoutScenario {
+        TakeoutScenario {
+            name: name.into(),
+            probability: p,
+            months_funded: months,
+            market_yield: y,
+        }
+    }
+
+    fn base_input() -> BridgeFinancingInput {
+        BridgeFinancingInput {
+            deal_name: "Project Span".into(),
+            commitment: dec!(1000),
+            underwriter_share: Decimal::ONE,
+            days_to_closing: 180,
+            fees: BridgeFees {
+                commitment_fee_pct: dec!(0.005),
+                ticking_fee_bps: dec!(50),
+                funding_fee_pct: dec!(0.005),
+                duration_fees: vec![dec!(0.0025), dec!(0.005), dec!(0.0075)],
+                takeout_fee_pct: dec!(0.0125),
+            },
+            coupon: BridgeCoupon {
+                base_rate: dec!(0.05),
+                initial_spread_bps: dec!(400),
+                step_up_bps: dec!(50),
+                cap_rate: dec!(0.11),
+            },
+            holding_costs: HoldingCosts {
+                funding_cost_rate: dec!(0.055),
+                unfunded_capital_charge: dec!(0.004),
+                funded_capital_charge: dec!(0.012),
+            },
+            takeout: TakeoutTerms {
+                launch_yield: dec!(0.085),
+                securities_demand_cap: dec!(0.10),
+                bond_duration: dec!(5),
+            },
+            scenarios: vec![
+                scenario("Pre-close bond", dec!(0.5), 0, dec!(0.085)),
+                scenario("Funded 6m", dec!(0.3), 6, dec!(0.095)),
+                scenario("Hung bridge", dec!(0.2), 12, dec!(0.12)),
+            ],
+        }
+    }
+
+    #[test]
+    fn test_guaranteed_fees() {
+        let out = analyze_bridge_financing(&base_input()).unwrap();
+        let r = &out.result;
+        // 5.0 commitment + 50bps x 1000 x 180 / 360 = 2.5 ticking
+        assert_eq!(r.guaranteed_fees, dec!(7.5));
+        assert_eq!(r.underwritten_amount, dec!(1000));
+    }
+
+    #[test]
+    fn test_unfunded_takeout() {
+        let out = analyze_bridge_financing(&base_input()).unwrap();
+        let s = &out.result.scenarios[0];
+        assert!(!s.funded);
+        assert_eq!(s.funding_fee, Decimal::ZERO);
+        assert_eq!(s.interest_income, Decimal::ZERO);
+        assert_eq!(s.securities_demand_loss, Decimal::ZERO);
+        // 7.5 fees + 12.5 takeout - 1000 x 0.4% x 180/365 capital
+        let capital = dec!(1000) * dec!(0.004) * dec!(180) / dec!(365);
+        assert_eq!(s.underwriter_pnl, dec!(20) - capital);
+    }
+
+    #[test]
+    fn test_coupon_steps_and_cap() {
+        let c = base_input().coupon;
+        assert_eq!(coupon_for_month(&c, 0), dec!(0.09));
+        assert_eq!(coupon_for_month(&c, 2), dec!(0.09));
+        assert_eq!(coupon_for_month(&c, 3), dec!(0.095));
+        assert_eq!(coupon_for_month(&c, 6), dec!(0.10));
+        assert_eq!(coupon_for_month(&c, 12), dec!(0.11));
+        assert_eq!(coupon_for_month(&c, 24), dec!(0.11));
+    }
+
+    #[test]
+    fn test_months_to_cap() {
+        let out = analyze_bridge_financing(&base_input()).unwrap();
+        // 400bps + 50bps per quarter reaches 600bps after 4 steps
+        assert_eq!(out.result.months_to_cap, Some(12));
+
+        let mut input = base_input();
+        input.coupon.step_up_bps = Decimal::ZERO;
+        let out = analyze_bridge_financing(&input).unwrap();
+        assert_eq!(out.result.months_to_cap, None);
+    }
+
+    #[test]
+    fn test_duration_fees_by_months_funded() {
+        let out = analyze_bridge_financing(&base_input()).unwrap();
+        let r = &out.result;
+        // 6 months: first two duration fees
+        assert_eq!(r.scenarios[1].duration_fees, dec!(7.5));
+        // 12 months: all three
+        assert_eq!(r.scenarios[2].duration_fees, dec!(15));
+    }
+
+    #[test]
+    fn test_funded_carry() {
+        let out = analyze_bridge_financing(&base_input()).unwrap();
+        let s = &out.result.scenarios[1];
+        // 3 months at 9% + 3 months at 9.5% on 1000
+        let interest = dec!(1000) * (dec!(0.09) * dec!(3) + dec!(0.095) * dec!(3)) / dec!(12);
+        assert!((s.interest_income - interest).abs() < dec!(0.0000001));
+        let funding = dec!(1000) * dec!(0.055) * dec!(0.5);
+        let capital = dec!(1000) * dec!(0.012) * dec!(0.5);
+        assert_eq!(s.funding_cost, funding);
+        assert!((s.negative_carry - (funding + capital - interest)).abs() < dec!(0.0000001));
+        // Coupon exceeds funding + capital, so carry is positive income
+        assert!(s.negative_carry < Decimal::ZERO);
+        assert_eq!(s.final_coupon, dec!(0.095));
+    }
+
+    #[test]
+    fn test_negative_carry_when_funding_expensive() {
+        let mut input = base_input();
+        input.holding_costs.funding_cost_rate = dec!(0.09);
+        input.holding_costs.funded_capital_charge = dec!(0.03);
+        let out = analyze_bridge_financing(&input).unwrap();
+        assert!(out.result.initial_monthly_carry > Decimal::ZERO);
+        assert!(out.result.scenarios[1].negative_carry > Decimal::ZERO);
+        assert!(out.warnings.iter().any(|w| w.contains("negatively")));
+    }
+
+    #[test]
+    fn test_securities_demand_loss() {
+        let out = analyze_bridge_financing(&base_input()).unwrap();
+        let s = &out.result.scenarios[2];
+        // 1000 x 5 x (12% - 10%) = 100
+        assert_eq!(s.securities_demand_loss, dec!(100));
+        assert!(s.underwriter_pnl < Decimal::ZERO);
+        assert!(out.warnings.iter().any(|w| w.contains("Hung bridge")));
+    }
+
+    #[test]
+    fn test_flex_within_cap() {
+        let out = analyze_bridge_financing(&base_input()).unwrap();
+        let r = &out.result;
+        assert_eq!(r.scenarios[0].flex_used_bps, Decimal::ZERO);
+        // Cleared at 9.5%: 100bps of flex, no underwriter loss
+        assert_eq!(r.scenarios[1].flex_used_bps, dec!(100));
+        assert_eq!(r.scenarios[1].securities_demand_loss, Decimal::ZERO);
+        // Flex exhausted at the 10% cap; the rest is securities demand loss
+        assert_eq!(r.scenarios[2].flex_used_bps, dec!(150));
+    }
+
+    #[test]
+    fn test_distribution() {
+        let out = analyze_bridge_financing(&base_input()).unwrap();
+        let r = &out.result;
+        let expected: Decimal = r
+            .scenarios
+            .iter()
+            .map(|s| s.probability * s.underwriter_pnl)
+            .sum();
+        assert_eq!(r.distribution.expected_pnl, expected);
+        assert_eq!(r.distribution.probability_of_loss, dec!(0.2));
+        assert_eq!(
+            r.distribution.worst_case_pnl,
+            r.scenarios[2].underwriter_pnl
+        );
+        assert!(r.distribution.pnl_std_dev > Decimal::ZERO);
+        assert_eq!(
+            r.distribution.expected_return_on_commitment,
+            expected / dec!(1000)
+        );
+    }
+
+    #[test]
+    fn test_breakeven_market_yield() {
+        let input = base_input();
+        let out = analyze_bridge_financing(&input).unwrap();
+        let y = out.result.breakeven_market_yield.unwrap();
+        assert!(y > dec!(0.10));
+
+        let mut at_breakeven = base_input();
+        at_breakeven.scenarios = vec![scenario("Breakeven", Decimal::ONE, 0, y)];
+        let out = analyze_bridge_financing(&at_breakeven).unwrap();
+        assert!(out.result.scenarios[0].underwriter_pnl.abs() < dec!(0.000001));
+    }
+
+    #[test]
+    fn test_underwriter_share_scales_pnl() {
+        let full = analyze_bridge_financing(&base_input()).unwrap();
+        let mut input = base_input();
+        input.underwriter_share = dec!(0.25);
+        let out = analyze_bridge_financing(&input).unwrap();
+        assert_eq!(out.result.underwritten_amount, dec!(250));
+        let scaled = full.result.scenarios[1].underwriter_pnl * dec!(0.25);
+        assert!((out.result.scenarios[1].underwriter_pnl - scaled).abs() < dec!(0.0000001));
+        // Borrower cost is for the full facility
+        let diff = out.result.scenarios[1].borrower_cost - full.result.scenarios[1].borrower_cost;
+        assert!(diff.abs() < dec!(0.0000001));
+    }
+
+    #[test]
+    fn test_borrower_cost() {
+        let out = analyze_bridge_financing(&base_input()).unwrap();
+        let s = &out.result.scenarios[1];
+        assert_eq!(
+            s.borrower_cost,
+            s.commitment_fee + s.ticking_fee + s.funding_fee + s.duration_fees + s.interest_income
+        );
+    }
+
+    #[test]
+    fn test_probabilities_must_sum_to_one() {
+        let mut input = base_input();
+        input.scenarios[0].probability = dec!(0.4);
+        assert!(analyze_bridge_financing(&input).is_err());
+    }
+
+    #[test]
+    fn test_invalid_inputs() {
+        let mut input = base_input();
+        input.commitment = Decimal::ZERO;
+        assert!(analyze_bridge_financing(&input).is_err());
+
+        let mut input = base_input();
+        input.underwriter_share = dec!(1.5);
+        assert!(analyze_bridge_financing(&input).is_err());
+
+        let mut input = base_input();
+        input.scenarios.clear();
+        assert!(analyze_bridge_financing(&input).is_err());
+
+        let mut input = base_input();
+        input.scenarios[0].probability = dec!(-0.1);
+        input.scenarios[1].probability = dec!(0.9);
+        assert!(analyze_bridge_financing(&input).is_err());
+    }
+}
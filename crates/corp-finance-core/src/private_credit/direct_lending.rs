@@ -10,6 +10,7 @@ use rust_decimal_macros::dec;
 use serde::{Deserialize, Serialize};
 use std::time::Instant;
 
+use crate::amortization::{self, AmortizationInput, AmortizationMethod};
 use crate::error::CorpFinanceError;
 use crate::types::{with_metadata, ComputationOutput, Money, Rate};
 use crate::CorpFinanceResult;
@@ -270,49 +271,32 @@ pub fn model_direct_loan(
     let projection_years = input.projection_years.min(input.maturity_years);
 
     // Build cash flow schedule
-    let mut schedule: Vec<LoanPeriod> = Vec::with_capacity(projection_years as usize);
-    let mut balance = input.drawn_amount;
-
-    for yr in 1..=projection_years {
-        let beginning_balance = balance;
-
-        // Cash interest on current balance
-        let cash_interest = beginning_balance * cash_rate;
-
-        // PIK interest accrued to principal
-        let pik_interest = beginning_balance * effective_pik_rate;
-
-        // Principal payment based on amortization schedule
-        let principal_payment = compute_principal_payment(
-            &input.amortization_schedule,
-            input.drawn_amount,
-            beginning_balance,
-            yr,
-            projection_years,
-        );
-
-        // Ending balance = beginning + PIK - principal payment
-        let ending_balance = beginning_balance + pik_interest - principal_payment;
-
-        // Delayed draw fee on undrawn amount
-        let delayed_draw_fee = delayed_draw * dd_fee_rate;
-
-        // Total lender income = cash interest + delayed draw fees
-        let total_lender_income = cash_interest + delayed_draw_fee;
-
-        schedule.push(LoanPeriod {
-            year: yr,
-            beginning_balance,
-            cash_interest,
-            pik_interest,
-            principal_payment,
-            ending_balance,
+    let amortization = amortization::build_schedule(&AmortizationInput {
+        principal: input.drawn_amount,
+        rate: cash_rate,
+        pik_rate: effective_pik_rate,
+        periods: projection_years,
+        method: amortization_method(&input.amortization_schedule),
+    })?;
+
+    // Delayed draw fee on undrawn amount
+    let delayed_draw_fee = delayed_draw * dd_fee_rate;
+
+    let schedule: Vec<LoanPeriod> = amortization
+        .periods
+        .iter()
+        .map(|p| LoanPeriod {
+            year: p.period,
+            beginning_balance: p.opening_balance,
+            cash_interest: p.interest,
+            pik_interest: p.pik_interest,
+            principal_payment: p.principal,
+            ending_balance: p.closing_balance,
             delayed_draw_fee,
-            total_lender_income,
-        });
-
-        balance = ending_balance;
-    }
+            // Total lender income = cash interest + delayed draw fees
+            total_lender_income: p.interest + delayed_draw_fee,
+        })
+        .collect();
 
     // Yield metrics
     let cash_yield = cash_rate;
@@ -374,7 +358,7 @@ pub fn model_direct_loan(
         };
         let final_balance_with_pik = schedule
             .last()
-            .map(|p| p.ending_balance)
+            .map(|p| p.beginning_balance + p.pik_interest)
             .unwrap_or(input.drawn_amount);
         let cash_vs_total_yield = if total_yield.is_zero() {
             Decimal::ZERO
@@ -616,59 +600,19 @@ fn validate_syndication_input(input: &SyndicationInput) -> CorpFinanceResult<()>
 // Internal helpers
 // ---------------------------------------------------------------------------
 
-/// Compute principal payment for a given year based on amortization schedule.
-fn compute_principal_payment(
-    schedule: &AmortSchedule,
-    original_principal: Money,
-    beginning_balance: Money,
-    year: u32,
-    total_years: u32,
-) -> Money {
+/// Map the loan's amortization schedule onto the shared schedule generator.
+fn amortization_method(schedule: &AmortSchedule) -> AmortizationMethod {
     match schedule {
-        AmortSchedule::InterestOnly => {
-            if year == total_years {
-                beginning_balance
-            } else {
-                Decimal::ZERO
-            }
-        }
-        AmortSchedule::BulletMaturity => {
-            if year == total_years {
-                beginning_balance
-            } else {
-                Decimal::ZERO
-            }
-        }
-        AmortSchedule::LevelAmort(annual_pct) => {
-            let scheduled = original_principal * *annual_pct;
-            if year == total_years {
-                // Final year: pay remaining balance
-                beginning_balance
-            } else {
-                // Cap at current balance
-                scheduled.min(beginning_balance)
-            }
-        }
-        AmortSchedule::Custom(payments) => {
-            let idx = (year - 1) as usize;
-            if year == total_years {
-                // Final year: pay remaining balance
-                beginning_balance
-            } else if idx < payments.len() {
-                payments[idx].min(beginning_balance)
-            } else {
-                Decimal::ZERO
-            }
-        }
+        AmortSchedule::InterestOnly | AmortSchedule::BulletMaturity => AmortizationMethod::Bullet,
+        AmortSchedule::LevelAmort(annual_pct) => AmortizationMethod::StraightLine(*annual_pct),
+        AmortSchedule::Custom(payments) => AmortizationMethod::Custom(payments.clone()),
     }
 }
 
 /// Compute IRR of lender cash flows using Newton-Raphson.
 ///
 /// Cash flows: year 0 = -drawn_amount (initial outflow),
-/// years 1..n = cash_interest + delayed_draw_fee (income),
-/// year n also includes principal repayment (ending_balance at maturity
-/// is repaid to lender as principal).
+/// years 1..n = cash_interest + delayed_draw_fee + principal_payment.
 fn compute_lender_irr(
     input: &DirectLoanInput,
     schedule: &[LoanPeriod],
@@ -683,26 +627,13 @@ fn compute_lender_irr(
     // Year 0: lender deploys capital
     cash_flows.push(-input.drawn_amount);
 
-    // Years 1..n: cash income + principal returns
-    for (i, period) in schedule.iter().enumerate() {
-        let is_last = i == schedule.len() - 1;
-        let mut cf = period.total_lender_income;
-        cf += period.principal_payment;
-        // At maturity, any remaining PIK balance is repaid
-        // (principal_payment already captures the ending balance repayment
-        //  for the final year in compute_principal_payment)
-        if is_last && period.ending_balance > Decimal::ZERO {
-            // For non-bullet/IO, ending_balance should be 0 in final year.
-            // But with PIK, ending_balance might still be > 0 if we computed
-            // principal_payment = beginning_balance (not beginning_balance + PIK).
-            // The ending_balance = beginning + PIK - principal_payment.
-            // Since principal_payment = beginning_balance in final year,
-            // ending_balance = PIK interest for final year.
-            // This PIK amount is also returned to lender at maturity.
-            cf += period.ending_balance;
-        }
-        cash_flows.push(cf);
-    }
+    // Years 1..n: cash income + principal returns; the final principal
+    // payment includes any capitalised PIK
+    cash_flows.extend(
+        schedule
+            .iter()
+            .map(|period| period.total_lender_income + period.principal_payment),
+    );
 
     match newton_raphson_irr(&cash_flows, dec!(0.10)) {
         Ok(irr) => irr,
@@ -902,6 +833,11 @@ mod tests {
             Decimal::ZERO,
             "Cash vs total yield should be 0 for full PIK toggle"
         );
+
+        // Final-year PIK is capitalised and repaid with the bullet
+        let last = result.result.cash_flow_schedule.last().unwrap();
+        assert_eq!(pik.final_balance_with_pik, last.principal_payment);
+        assert_eq!(last.ending_balance, Decimal::ZERO);
     }
 
     // -----------------------------------------------------------------------
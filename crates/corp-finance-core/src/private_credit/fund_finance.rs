@@ -0,0 +1,896 @@
+//! Fund finance: NAV facilities and subscription lines.
+//!
+//! Computes the borrowing base of a fund-level facility — eligible NAV of
+//! portfolio investments for a NAV facility, or eligible undrawn investor
+//! commitments for a subscription line — after advance rates and
+//! concentration limits, the interest and fee economics of the facility, and
+//! its effect on the LPs' net IRR and multiple (the "IRR enhancement" and the
+//! cost drag that pays for it).
+
+use rust_decimal::Decimal;
+use rust_decimal::MathematicalOps;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::time::Instant;
+
+use crate::error::CorpFinanceError;
+use crate::time_value::irr;
+use crate::types::{with_metadata, ComputationOutput, Money, Multiple, Rate};
+use crate::CorpFinanceResult;
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Basis points divisor
+const BPS: Decimal = dec!(10000);
+
+// ---------------------------------------------------------------------------
+// Input types
+// ---------------------------------------------------------------------------
+
+/// A portfolio investment in a NAV facility borrowing base.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FundInvestment {
+    pub name: String,
+    pub nav: Money,
+    pub sector: String,
+    /// Excluded investments contribute nothing to the borrowing base
+    #[serde(default = "default_true")]
+    pub eligible: bool,
+    /// Overrides the facility advance rate for this investment
+    #[serde(default)]
+    pub advance_rate: Option<Rate>,
+}
+
+/// Investor classification in a subscription line borrowing base.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum InvestorTier {
+    /// Rated or high-quality institutional investors (typically 90%)
+    Included,
+    /// Other approved investors (typically 65%)
+    Designated,
+    Excluded,
+}
+
+/// An LP's undrawn commitment.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InvestorCommitment {
+    pub name: String,
+    pub undrawn_commitment: Money,
+    pub tier: InvestorTier,
+    /// Overrides the tier advance rate for this investor
+    #[serde(default)]
+    pub advance_rate: Option<Rate>,
+}
+
+/// Facility type and its borrowing base collateral.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum FundFacility {
+    NavFacility {
+        investments: Vec<FundInvestment>,
+        advance_rate: Rate,
+        /// Maximum share of eligible NAV from any one investment
+        single_asset_limit: Rate,
+        /// Maximum share of eligible NAV from any one sector
+        #[serde(default)]
+        sector_limit: Option<Rate>,
+        /// Loan-to-value covenant
+        max_ltv: Rate,
+        /// Period in which the drawn amount is distributed to LPs
+        draw_period: usize,
+        /// Period in which the facility is repaid out of distributions
+        repay_period: usize,
+    },
+    SubscriptionLine {
+        investors: Vec<InvestorCommitment>,
+        included_advance_rate: Rate,
+        designated_advance_rate: Rate,
+        /// Maximum share of eligible commitments from any one investor
+        #[serde(default)]
+        investor_limit: Option<Rate>,
+        /// Periods by which capital calls are deferred using the line
+        call_delay_periods: usize,
+    },
+}
+
+/// Facility pricing.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FacilityPricing {
+    pub base_rate: Rate,
+    pub margin_bps: Decimal,
+    /// Fee on the undrawn facility (bps p.a.)
+    pub commitment_fee_bps: Decimal,
+    /// One-off fee on the facility size (bps)
+    pub upfront_fee_bps: Decimal,
+}
+
+/// Input for fund finance analysis.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FundFinanceInput {
+    pub fund_name: String,
+    pub facility: FundFacility,
+    pub facility_size: Money,
+    pub drawn_amount: Money,
+    pub term_years: u32,
+    pub pricing: FacilityPricing,
+    /// LP net cash flows per annual period without the facility
+    /// (calls negative, distributions positive); empty skips the IRR analysis
+    #[serde(default)]
+    pub fund_cash_flows: Vec<Money>,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+// ---------------------------------------------------------------------------
+// Output types
+// ---------------------------------------------------------------------------
+
+/// Contribution of one investment or investor to the borrowing base.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BorrowingBaseLine {
+    pub name: String,
+    pub collateral_value: Money,
+    /// Value after eligibility and concentration limits
+    pub eligible_value: Money,
+    /// Value excluded by concentration limits
+    pub concentration_excess: Money,
+    pub advance_rate: Rate,
+    pub borrowing_base: Money,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BorrowingBase {
+    pub lines: Vec<BorrowingBaseLine>,
+    pub total_collateral: Money,
+    pub eligible_collateral: Money,
+    pub borrowing_base: Money,
+    /// Lower of facility size and borrowing base
+    pub availability: Money,
+    pub headroom: Money,
+    /// Drawn amount over eligible NAV (NAV facilities only)
+    pub ltv: Option<Rate>,
+    pub ltv_breach: bool,
+}
+
+/// Annual cost of the facility.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FacilityEconomics {
+    pub interest_rate: Rate,
+    pub annual_interest: Money,
+    pub annual_commitment_fee: Money,
+    pub upfront_fee: Money,
+    /// Interest, commitment fee and amortised upfront fee over the drawn amount
+    pub all_in_cost: Rate,
+    pub total_cost_over_term: Money,
+}
+
+/// Effect of the facility on LP returns.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IrrImpact {
+    pub cash_flows_with_facility: Vec<Money>,
+    pub irr_without: Option<Rate>,
+    pub irr_with: Option<Rate>,
+    pub irr_enhancement_bps: Option<Decimal>,
+    pub moic_without: Multiple,
+    pub moic_with: Multiple,
+    /// Reduction in multiple from financing costs
+    pub moic_drag: Multiple,
+    pub financing_cost: Money,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FundFinanceOutput {
+    pub borrowing_base: BorrowingBase,
+    pub economics: FacilityEconomics,
+    pub irr_impact: Option<IrrImpact>,
+}
+
+// ---------------------------------------------------------------------------
+// Public API
+// ---------------------------------------------------------------------------
+
+/// Borrowing base, facility economics and LP return impact of a NAV
+/// facility or subscription line.
+pub fn analyze_fund_finance(
+    input: &FundFinanceInput,
+) -> CorpFinanceResult<ComputationOutput<FundFinanceOutput>> {
+    let start = Instant::now();
+    let mut warnings: Vec<String> = Vec::new();
+
+    validate_input(input)?;
+
+    let borrowing_base = borrowing_base(input);
+    if input.drawn_amount > borrowing_base.availability {
+        warnings.push(format!(
+            "Drawn amount exceeds availability by {}",
+            input.drawn_amount - borrowing_base.availability
+        ));
+    }
+    if borrowing_base.ltv_breach {
+        warnings.push("NAV facility LTV exceeds the covenant maximum".into());
+    }
+
+    let economics = facility_economics(input);
+
+    let irr_impact = if input.fund_cash_flows.is_empty() {
+        None
+    } else {
+        let impact = irr_impact(input, &economics);
+        if impact.irr_without.is_none() || impact.irr_with.is_none() {
+            warnings.push("IRR did not converge for the supplied cash flows".into());
+        }
+        Some(impact)
+    };
+
+    let output = FundFinanceOutput {
+        borrowing_base,
+        economics,
+        irr_impact,
+    };
+
+    let elapsed = start.elapsed().as_micros() as u64;
+    let facility = match &input.facility {
+        FundFacility::NavFacility { .. } => "NAV facility",
+        FundFacility::SubscriptionLine { .. } => "Subscription line",
+    };
+    Ok(with_metadata(
+        "Fund Finance (borrowing base, facility economics, IRR enhancement)",
+        &serde_json::json!({
+            "fund": input.fund_name,
+            "facility": facility,
+            "facility_size": input.facility_size.to_string(),
+            "drawn_amount": input.drawn_amount.to_string(),
+            "term_years": input.term_years,
+        }),
+        warnings,
+        elapsed,
+        output,
+    ))
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+fn borrowing_base(input: &FundFinanceInput) -> BorrowingBase {
+    let (lines, ltv) = match &input.facility {
+        FundFacility::NavFacility {
+            investments,
+            advance_rate,
+            single_asset_limit,
+            sector_limit,
+            ..
+        } => {
+            let lines = nav_lines(
+                investments,
+                *advance_rate,
+                *single_asset_limit,
+                *sector_limit,
+            );
+            let eligible: Decimal = lines.iter().map(|l| l.eligible_value).sum();
+            let ltv = if eligible > Decimal::ZERO {
+                input.drawn_amount / eligible
+            } else {
+                Decimal::ZERO
+            };
+            (lines, Some(ltv))
+        }
+        FundFacility::SubscriptionLine {
+            investors,
+            included_advance_rate,
+            designated_advance_rate,
+            investor_limit,
+            ..
+        } => (
+            subscription_lines(
+                investors,
+                *included_advance_rate,
+                *designated_advance_rate,
+                *investor_limit,
+            ),
+            None,
+        ),
+    };
+
+    let borrowing_base: Decimal = lines.iter().map(|l| l.borrowing_base).sum();
+    let availability = borrowing_base.min(input.facility_size);
+    let ltv_breach = match (&input.facility, ltv) {
+        (FundFacility::NavFacility { max_ltv, .. }, Some(l)) => l > *max_ltv,
+        _ => false,
+    };
+
+    BorrowingBase {
+        total_collateral: lines.iter().map(|l| l.collateral_value).sum(),
+        eligible_collateral: lines.iter().map(|l| l.eligible_value).sum(),
+        borrowing_base,
+        availability,
+        headroom: availability - input.drawn_amount,
+        ltv,
+        ltv_breach,
+        lines,
+    }
+}
+
+/// NAV borrowing base: single-asset caps first, then sector caps applied
+/// pro rata across the sector's investments.
+fn nav_lines(
+    investments: &[FundInvestment],
+    advance_rate: Rate,
+    single_asset_limit: Rate,
+    sector_limit: Option<Rate>,
+) -> Vec<BorrowingBaseLine> {
+    let eligible_nav: Decimal = investments
+        .iter()
+        .filter(|i| i.eligible)
+        .map(|i| i.nav)
+        .sum();
+    let asset_cap = eligible_nav * single_asset_limit;
+
+    let mut capped: Vec<Decimal> = investments
+        .iter()
+        .map(|i| {
+            if i.eligible {
+                i.nav.min(asset_cap)
+            } else {
+                Decimal::ZERO
+            }
+        })
+        .collect();
+
+    if let Some(limit) = sector_limit {
+        let sector_cap = eligible_nav * limit;
+        let mut by_sector: BTreeMap<&str, Decimal> = BTreeMap::new();
+        for (i, inv) in investments.iter().enumerate() {
+            *by_sector.entry(inv.sector.as_str()).or_default() += capped[i];
+        }
+        for (i, inv) in investments.iter().enumerate() {
+            let total = by_sector[inv.sector.as_str()];
+            if total > sector_cap {
+                capped[i] = capped[i] * sector_cap / total;
+            }
+        }
+    }
+
+    investments
+        .iter()
+        .zip(capped)
+        .map(|(inv, eligible_value)| {
+            let rate = inv.advance_rate.unwrap_or(advance_rate);
+            let concentration_excess = if inv.eligible {
+                inv.nav - eligible_value
+            } else {
+                Decimal::ZERO
+            };
+            BorrowingBaseLine {
+                name: inv.name.clone(),
+                collateral_value: inv.nav,
+                eligible_value,
+                concentration_excess,
+                advance_rate: rate,
+                borrowing_base: eligible_value * rate,
+            }
+        })
+        .collect()
+}
+
+/// Subscription line borrowing base: advance rates by investor tier with an
+/// optional cap on any one investor's share of eligible commitments.
+fn subscription_lines(
+    investors: &[InvestorCommitment],
+    included_rate: Rate,
+    designated_rate: Rate,
+    investor_limit: Option<Rate>,
+) -> Vec<BorrowingBaseLine> {
+    let eligible_total: Decimal = investors
+        .iter()
+        .filter(|i| i.tier != InvestorTier::Excluded)
+        .map(|i| i.undrawn_commitment)
+        .sum();
+    let cap = investor_limit.map(|l| eligible_total * l);
+
+    investors
+        .iter()
+        .map(|inv| {
+            let (eligible_value, rate) = match inv.tier {
+                InvestorTier::Excluded => (Decimal::ZERO, Decimal::ZERO),
+                tier => {
+                    let value =
+                        cap.map_or(inv.undrawn_commitment, |c| inv.undrawn_commitment.min(c));
+                    let tier_rate = if tier == InvestorTier::Included {
+                        included_rate
+                    } else {
+                        designated_rate
+                    };
+                    (value, inv.advance_rate.unwrap_or(tier_rate))
+                }
+            };
+            let concentration_excess = if inv.tier == InvestorTier::Excluded {
+                Decimal::ZERO
+            } else {
+                inv.undrawn_commitment - eligible_value
+            };
+            BorrowingBaseLine {
+                name: inv.name.clone(),
+                collateral_value: inv.undrawn_commitment,
+                eligible_value,
+                concentration_excess,
+                advance_rate: rate,
+                borrowing_base: eligible_value * rate,
+            }
+        })
+        .collect()
+}
+
+fn facility_economics(input: &FundFinanceInput) -> FacilityEconomics {
+    let p = &input.pricing;
+    let interest_rate = p.base_rate + p.margin_bps / BPS;
+    let annual_interest = input.drawn_amount * interest_rate;
+    let undrawn = (input.facility_size - input.drawn_amount).max(Decimal::ZERO);
+    let annual_commitment_fee = undrawn * p.commitment_fee_bps / BPS;
+    let upfront_fee = input.facility_size * p.upfront_fee_bps / BPS;
+    let term = Decimal::from(input.term_years.max(1));
+
+    let all_in_cost = if input.drawn_amount > Decimal::ZERO {
+        (annual_interest + annual_commitment_fee + upfront_fee / term) / input.drawn_amount
+    } else {
+        Decimal::ZERO
+    };
+
+    FacilityEconomics {
+        interest_rate,
+        annual_interest,
+        annual_commitment_fee,
+        upfront_fee,
+        all_in_cost,
+        total_cost_over_term: (annual_interest + annual_commitment_fee) * term + upfront_fee,
+    }
+}
+
+/// LP cash flows with the facility in place. Subscription lines defer each
+/// capital call, which is then called with accrued interest; NAV facilities
+/// distribute the drawn amount early and repay it with interest out of later
+/// distributions. Upfront and commitment fees are borne by the LPs as they
+/// are paid.
+fn irr_impact(input: &FundFinanceInput, economics: &FacilityEconomics) -> IrrImpact {
+    let base = &input.fund_cash_flows;
+    let rate = economics.interest_rate;
+    let mut flows = base.clone();
+    let mut interest = Decimal::ZERO;
+
+    match &input.facility {
+        FundFacility::SubscriptionLine {
+            call_delay_periods, ..
+        } => {
+            let d = *call_delay_periods;
+            flows = base.iter().map(|f| (*f).max(Decimal::ZERO)).collect();
+            flows.resize(base.len() + d, Decimal::ZERO);
+            let growth = (Decimal::ONE + rate).powu(d as u64);
+            for (t, f) in base.iter().enumerate() {
+                if *f < Decimal::ZERO {
+                    flows[t + d] += *f * growth;
+                    interest += -*f * (growth - Decimal::ONE);
+                }
+            }
+        }
+        FundFacility::NavFacility {
+            draw_period,
+            repay_period,
+            ..
+        } => {
+            let periods = repay_period - draw_period;
+            let repayment = input.drawn_amount * (Decimal::ONE + rate).powu(periods as u64);
+            flows[*draw_period] += input.drawn_amount;
+            flows[*repay_period] -= repayment;
+            interest = repayment - input.drawn_amount;
+        }
+    }
+
+    let mut fees = economics.upfront_fee;
+    flows[0] -= economics.upfront_fee;
+    for t in 1..=(input.term_years as usize).min(flows.len() - 1) {
+        flows[t] -= economics.annual_commitment_fee;
+        fees += economics.annual_commitment_fee;
+    }
+
+    let irr_without = irr(base, dec!(0.10)).ok();
+    let irr_with = irr(&flows, dec!(0.10)).ok();
+    let irr_enhancement_bps = match (irr_without, irr_with) {
+        (Some(a), Some(b)) => Some((b - a) * BPS),
+        _ => None,
+    };
+    let moic_without = moic(base);
+    // Measured on gross contributions and distributions: subscription line
+    // costs are called from LPs, NAV facility costs come out of distributions.
+    let paid_in: Decimal = base
+        .iter()
+        .filter(|f| **f < Decimal::ZERO)
+        .map(|f| -*f)
+        .sum();
+    let distributed: Decimal = base.iter().filter(|f| **f > Decimal::ZERO).sum();
+    let financing_cost = interest + fees;
+    let moic_with = match &input.facility {
+        FundFacility::SubscriptionLine { .. } if paid_in + financing_cost > Decimal::ZERO => {
+            distributed / (paid_in + financing_cost)
+        }
+        FundFacility::NavFacility { .. } if paid_in > Decimal::ZERO => {
+            (distributed - financing_cost) / paid_in
+        }
+        _ => Decimal::ZERO,
+    };
+
+    IrrImpact {
+        cash_flows_with_facility: flows,
+        irr_without,
+        irr_with,
+        irr_enhancement_bps,
+        moic_without,
+        moic_with,
+        moic_drag: moic_without - moic_with,
+        financing_cost,
+    }
+}
+
+/// Total distributions over total contributions.
+fn moic(flows: &[Money]) -> Multiple {
+    let paid_in: Decimal = flows
+        .iter()
+        .filter(|f| **f < Decimal::ZERO)
+        .map(|f| -*f)
+        .sum();
+    let distributed: Decimal = flows.iter().filter(|f| **f > Decimal::ZERO).sum();
+    if paid_in.is_zero() {
+        Decimal::ZERO
+    } else {
+        distributed / paid_in
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Validation
+// ---------------------------------------------------------------------------
+
+fn validate_input(input: &FundFinanceInput) -> CorpFinanceResult<()> {
+    if input.facility_size <= Decimal::ZERO {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "facility_size".into(),
+            reason: "Facility size must be positive".into(),
+        });
+    }
+    if input.drawn_amount < Decimal::ZERO || input.drawn_amount > input.facility_size {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "drawn_amount".into(),
+            reason: "Drawn amount must be between zero and the facility size".into(),
+        });
+    }
+    if input.term_years == 0 {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "term_years".into(),
+            reason: "Term must be at least one year".into(),
+        });
+    }
+    let n = input.fund_cash_flows.len();
+    match &input.facility {
+        FundFacility::NavFacility {
+            investments,
+            single_asset_limit,
+            draw_period,
+            repay_period,
+            ..
+        } => {
+            if investments.is_empty() {
+                return Err(CorpFinanceError::InsufficientData(
+                    "NAV facility requires at least one investment".into(),
+                ));
+            }
+            if *single_asset_limit <= Decimal::ZERO || *single_asset_limit > Decimal::ONE {
+                return Err(CorpFinanceError::InvalidInput {
+                    field: "facility.single_asset_limit".into(),
+                    reason: "Limit must be in (0, 1]".into(),
+                });
+            }
+            if n > 0 && (repay_period <= draw_period || *repay_period >= n) {
+                return Err(CorpFinanceError::InvalidInput {
+                    field: "facility.repay_period".into(),
+                    reason: "Repayment must follow the draw and fall within the cash flows".into(),
+                });
+            }
+        }
+        FundFacility::SubscriptionLine { investors, .. } => {
+            if investors.is_empty() {
+                return Err(CorpFinanceError::InsufficientData(
+                    "Subscription line requires at least one investor".into(),
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn investment(name: &str, nav: Decimal, sector: &str) -> FundInvestment {
+        FundInvestment {
+            name: name.into(),
+            nav,
+            sector: sector.into(),
+            eligible: true,
+            advance_rate: None,
+        }
+    }
+
+    fn investor(name: &str, undrawn: Decimal, tier: InvestorTier) -> InvestorCommitment {
+        InvestorCommitment {
+            name: name.into(),
+            undrawn_commitment: undrawn,
+            tier,
+            advance_rate: None,
+        }
+    }
+
+    fn pricing() -> FacilityPricing {
+        FacilityPricing {
+            base_rate: dec!(0.04),
+            margin_bps: dec!(200),
+            commitment_fee_bps: dec!(50),
+            upfront_fee_bps: dec!(100),
+        }
+    }
+
+    fn nav_input() -> FundFinanceInput {
+        FundFinanceInput {
+            fund_name: "Fund IV".into(),
+            facility: FundFacility::NavFacility {
+                investments: vec![
+                    investment("A", dec!(400), "Tech"),
+                    investment("B", dec!(300), "Tech"),
+                    investment("C", dec!(200), "Health"),
+                    investment("D", dec!(100), "Industrials"),
+                ],
+                advance_rate: dec!(0.25),
+                single_asset_limit: dec!(0.30),
+                sector_limit: None,
+                max_ltv: dec!(0.20),
+                draw_period: 3,
+                repay_period: 5,
+            },
+            facility_size: dec!(250),
+            drawn_amount: dec!(150),
+            term_years: 3,
+            pricing: pricing(),
+            fund_cash_flows: vec![],
+        }
+    }
+
+    fn sub_line_input() -> FundFinanceInput {
+        FundFinanceInput {
+            fund_name: "Fund V".into(),
+            facility: FundFacility::SubscriptionLine {
+                investors: vec![
+                    investor("Pension", dec!(500), InvestorTier::Included),
+                    investor("Family office", dec!(200), InvestorTier::Designated),
+                    investor("Individual", dec!(100), InvestorTier::Excluded),
+                ],
+                included_advance_rate: dec!(0.90),
+                designated_advance_rate: dec!(0.65),
+                investor_limit: None,
+                call_delay_periods: 1,
+            },
+            facility_size: dec!(300),
+            drawn_amount: dec!(200),
+            term_years: 2,
+            pricing: pricing(),
+            fund_cash_flows: vec![
+                dec!(-300),
+                dec!(-300),
+                dec!(-200),
+                dec!(100),
+                dec!(500),
+                dec!(700),
+                dec!(600),
+            ],
+        }
+    }
+
+    #[test]
+    fn test_nav_single_asset_cap() {
+        let out = analyze_fund_finance(&nav_input()).unwrap();
+        let bb = &out.result.borrowing_base;
+        // Cap = 30% x 1000 = 300; A loses 100
+        assert_eq!(bb.lines[0].eligible_value, dec!(300));
+        assert_eq!(bb.lines[0].concentration_excess, dec!(100));
+        assert_eq!(bb.eligible_collateral, dec!(900));
+        assert_eq!(bb.borrowing_base, dec!(225));
+    }
+
+    #[test]
+    fn test_nav_sector_cap() {
+        let mut input = nav_input();
+        if let FundFacility::NavFacility { sector_limit, .. } = &mut input.facility {
+            *sector_limit = Some(dec!(0.45));
+        }
+        let out = analyze_fund_finance(&input).unwrap();
+        let bb = &out.result.borrowing_base;
+        // Tech after asset caps = 600, sector cap 450 scales both by 0.75
+        assert_eq!(bb.lines[0].eligible_value, dec!(225));
+        assert_eq!(bb.lines[1].eligible_value, dec!(225));
+        assert_eq!(bb.lines[2].eligible_value, dec!(200));
+        assert_eq!(bb.eligible_collateral, dec!(750));
+    }
+
+    #[test]
+    fn test_nav_ineligible_and_override() {
+        let mut input = nav_input();
+        if let FundFacility::NavFacility { investments, .. } = &mut input.facility {
+            investments[3].eligible = false;
+            investments[2].advance_rate = Some(dec!(0.10));
+        }
+        let out = analyze_fund_finance(&input).unwrap();
+        let bb = &out.result.borrowing_base;
+        assert_eq!(bb.lines[3].eligible_value, Decimal::ZERO);
+        assert_eq!(bb.lines[3].concentration_excess, Decimal::ZERO);
+        assert_eq!(bb.lines[2].borrowing_base, dec!(20));
+        assert_eq!(bb.total_collateral, dec!(1000));
+    }
+
+    #[test]
+    fn test_nav_ltv_and_availability() {
+        let out = analyze_fund_finance(&nav_input()).unwrap();
+        let bb = &out.result.borrowing_base;
+        // Availability = min(250 size, 225 base)
+        assert_eq!(bb.availability, dec!(225));
+        assert_eq!(bb.headroom, dec!(75));
+        assert_eq!(bb.ltv, Some(dec!(150) / dec!(900)));
+        assert!(!bb.ltv_breach);
+    }
+
+    #[test]
+    fn test_nav_ltv_breach_warning() {
+        let mut input = nav_input();
+        if let FundFacility::NavFacility { max_ltv, .. } = &mut input.facility {
+            *max_ltv = dec!(0.10);
+        }
+        let out = analyze_fund_finance(&input).unwrap();
+        assert!(out.result.borrowing_base.ltv_breach);
+        assert!(out.warnings.iter().any(|w| w.contains("LTV")));
+    }
+
+    #[test]
+    fn test_overadvance_warning() {
+        let mut input = nav_input();
+        input.drawn_amount = dec!(240);
+        let out = analyze_fund_finance(&input).unwrap();
+        assert_eq!(out.result.borrowing_base.headroom, dec!(-15));
+        assert!(out
+            .warnings
+            .iter()
+            .any(|w| w.contains("exceeds availability")));
+    }
+
+    #[test]
+    fn test_subscription_line_tiers() {
+        let out = analyze_fund_finance(&sub_line_input()).unwrap();
+        let bb = &out.result.borrowing_base;
+        assert_eq!(bb.lines[0].borrowing_base, dec!(450));
+        assert_eq!(bb.lines[1].borrowing_base, dec!(130));
+        assert_eq!(bb.lines[2].borrowing_base, Decimal::ZERO);
+        assert_eq!(bb.borrowing_base, dec!(580));
+        assert_eq!(bb.availability, dec!(300));
+        assert!(bb.ltv.is_none());
+    }
+
+    #[test]
+    fn test_subscription_line_investor_cap() {
+        let mut input = sub_line_input();
+        if let FundFacility::SubscriptionLine { investor_limit, .. } = &mut input.facility {
+            *investor_limit = Some(dec!(0.5));
+        }
+        let out = analyze_fund_finance(&input).unwrap();
+        let bb = &out.result.borrowing_base;
+        // Cap = 50% x 700 eligible = 350
+        assert_eq!(bb.lines[0].eligible_value, dec!(350));
+        assert_eq!(bb.lines[0].concentration_excess, dec!(150));
+        assert_eq!(bb.lines[1].eligible_value, dec!(200));
+    }
+
+    #[test]
+    fn test_facility_economics() {
+        let out = analyze_fund_finance(&nav_input()).unwrap();
+        let e = &out.result.economics;
+        assert_eq!(e.interest_rate, dec!(0.06));
+        assert_eq!(e.annual_interest, dec!(9));
+        // 50bps on 100 undrawn
+        assert_eq!(e.annual_commitment_fee, dec!(0.5));
+        assert_eq!(e.upfront_fee, dec!(2.5));
+        assert_eq!(e.total_cost_over_term, dec!(31));
+        let expected = (dec!(9) + dec!(0.5) + dec!(2.5) / dec!(3)) / dec!(150);
+        assert_eq!(e.all_in_cost, expected);
+    }
+
+    #[test]
+    fn test_subscription_line_defers_calls() {
+        let out = analyze_fund_finance(&sub_line_input()).unwrap();
+        let impact = out.result.irr_impact.unwrap();
+        let flows = &impact.cash_flows_with_facility;
+        assert_eq!(flows.len(), 8);
+        // Year 0: only the upfront fee; first call arrives a year later with interest
+        assert_eq!(flows[0], dec!(-3));
+        assert_eq!(flows[1], dec!(-300) * dec!(1.06) - dec!(0.5));
+    }
+
+    #[test]
+    fn test_subscription_line_irr_enhancement_and_drag() {
+        let out = analyze_fund_finance(&sub_line_input()).unwrap();
+        let impact = out.result.irr_impact.unwrap();
+        let uplift = impact.irr_enhancement_bps.unwrap();
+        assert!(uplift > Decimal::ZERO);
+        assert!(impact.moic_with < impact.moic_without);
+        assert!(impact.moic_drag > Decimal::ZERO);
+        // 6% interest on 800 of calls for one year + 3 upfront + 2 x 0.5
+        assert_eq!(impact.financing_cost, dec!(48) + dec!(3) + dec!(1));
+    }
+
+    #[test]
+    fn test_nav_facility_early_distribution() {
+        let mut input = nav_input();
+        input.fund_cash_flows = vec![
+            dec!(-500),
+            dec!(-300),
+            dec!(-200),
+            dec!(50),
+            dec!(100),
+            dec!(900),
+            dec!(800),
+        ];
+        let out = analyze_fund_finance(&input).unwrap();
+        let impact = out.result.irr_impact.unwrap();
+        let flows = &impact.cash_flows_with_facility;
+        assert_eq!(flows[3], dec!(50) + dec!(150) - dec!(0.5));
+        assert_eq!(flows[5], dec!(900) - dec!(150) * dec!(1.1236));
+        assert!(impact.irr_enhancement_bps.unwrap() > Decimal::ZERO);
+        assert!(impact.moic_drag > Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_no_cash_flows_skips_irr() {
+        let out = analyze_fund_finance(&nav_input()).unwrap();
+        assert!(out.result.irr_impact.is_none());
+    }
+
+    #[test]
+    fn test_moic() {
+        assert_eq!(moic(&[dec!(-100), dec!(50), dec!(150)]), dec!(2));
+        assert_eq!(moic(&[dec!(100)]), Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_invalid_inputs() {
+        let mut input = nav_input();
+        input.drawn_amount = dec!(300);
+        assert!(analyze_fund_finance(&input).is_err());
+
+        let mut input = nav_input();
+        input.term_years = 0;
+        assert!(analyze_fund_finance(&input).is_err());
+
+        let mut input = nav_input();
+        input.fund_cash_flows = vec![dec!(-100), dec!(200)];
+        assert!(analyze_fund_finance(&input).is_err());
+
+        let mut input = sub_line_input();
+        if let FundFacility::SubscriptionLine { investors, .. } = &mut input.facility {
+            investors.clear();
+        }
+        assert!(analyze_fund_finance(&input).is_err());
+    }
+}
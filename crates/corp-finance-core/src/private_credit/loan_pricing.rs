@@ -0,0 +1,790 @@
+//! New-issue leveraged loan pricing from a market clearing grid.
+//!
+//! Prices a term loan off a user-supplied grid of clearing spreads and OIDs
+//! by rating and leverage band, values the call protection (soft call /
+//! hard call premiums) at the expected refinancing date, and computes
+//! underwriter economics under market flex scenarios: how much of the move
+//! in clearing levels is absorbed by flex and what the arranger gives up
+//! when the market moves beyond the flex caps.
+
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+use std::time::Instant;
+
+use crate::error::CorpFinanceError;
+use crate::ratings::Rating;
+use crate::time_value::irr;
+use crate::types::{with_metadata, ComputationOutput, Money, Multiple, Rate};
+use crate::CorpFinanceResult;
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Basis points divisor
+const BPS: Decimal = dec!(10000);
+
+// ---------------------------------------------------------------------------
+// Input types
+// ---------------------------------------------------------------------------
+
+/// One cell of the market clearing grid: the spread and OID at which loans
+/// of a given rating clear up to a leverage level.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PricingGridEntry {
+    pub rating: Rating,
+    /// Upper bound of the leverage band (total debt / EBITDA)
+    pub max_leverage: Multiple,
+    /// Clearing spread over the base rate in basis points
+    pub spread_bps: Decimal,
+    /// Clearing original issue discount (e.g. 0.01 = 99.0 issue price)
+    pub oid_pct: Rate,
+}
+
+/// A market flex scenario: clearing levels observed at syndication.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FlexScenario {
+    pub name: String,
+    pub clearing_spread_bps: Decimal,
+    pub clearing_oid_pct: Rate,
+}
+
+/// Underwriting commitment terms.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UnderwritingTerms {
+    /// Underwriting / arrangement fee on the facility
+    pub underwriting_fee_pct: Rate,
+    /// Amount the underwriter intends to hold after syndication
+    pub final_hold: Money,
+    /// Maximum upward spread flex in basis points
+    pub max_spread_flex_bps: Decimal,
+    /// Maximum additional OID under flex
+    pub max_oid_flex_pct: Rate,
+    pub scenarios: Vec<FlexScenario>,
+}
+
+/// Input for leveraged loan pricing.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LoanPricingInput {
+    /// Deal identifier
+    pub deal_name: String,
+    /// Term loan amount
+    pub facility_amount: Money,
+    /// Expected facility / corporate rating
+    pub rating: Rating,
+    /// Pro forma total debt including the facility
+    pub total_debt: Money,
+    /// Borrower LTM EBITDA
+    pub borrower_ebitda: Money,
+    /// Market clearing grid
+    pub pricing_grid: Vec<PricingGridEntry>,
+    /// Base rate (SOFR or equivalent)
+    pub base_rate: Rate,
+    /// Base rate floor, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub base_rate_floor: Option<Rate>,
+    /// Maturity in years
+    pub maturity_years: u32,
+    /// Annual mandatory amortization as a fraction of original par
+    #[serde(default)]
+    pub amortization_pct: Rate,
+    /// Call premium by year (e.g. [0.01] = 101 soft call in year 1)
+    #[serde(default)]
+    pub call_protection: Vec<Rate>,
+    /// Year in which the loan is assumed to be refinanced (market convention
+    /// is 3 or 4 years for discount margin)
+    pub expected_repayment_year: u32,
+    /// Underwriting commitment and flex scenarios
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub underwriting: Option<UnderwritingTerms>,
+}
+
+// ---------------------------------------------------------------------------
+// Output types
+// ---------------------------------------------------------------------------
+
+/// Grid cell used to price the loan.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GridMatch {
+    /// Rating of the grid row used
+    pub rating: Rating,
+    /// Whether the grid had a row for the deal's own rating
+    pub exact_rating_match: bool,
+    /// Upper bound of the matched leverage band
+    pub band_max_leverage: Multiple,
+    pub spread_bps: Decimal,
+    pub oid_pct: Rate,
+}
+
+/// Pricing of the loan at the grid terms.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LoanPricing {
+    /// max(base rate, floor) + spread
+    pub all_in_coupon: Rate,
+    /// 1 - OID
+    pub issue_price: Decimal,
+    pub yield_to_maturity: Rate,
+    /// Yield assuming repayment at the expected year, including any call premium
+    pub yield_to_expected_repayment: Rate,
+    /// Yield to expected repayment less the effective base rate (bps)
+    pub discount_margin_bps: Decimal,
+    /// OID spread over the expected life (bps per annum)
+    pub oid_yield_pickup_bps: Decimal,
+}
+
+/// Value of call protection at the expected repayment date.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CallProtectionValue {
+    /// Premium payable on repayment in the expected year
+    pub premium_at_expected_repayment: Rate,
+    /// Present value of that premium per unit of par
+    pub pv_of_premium: Decimal,
+    /// Yield to expected repayment with minus without the premium (bps)
+    pub yield_pickup_bps: Decimal,
+    /// Last year with a call premium
+    pub protection_years: u32,
+}
+
+/// Outcome of a flex scenario.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FlexOutcome {
+    pub scenario: String,
+    pub final_spread_bps: Decimal,
+    pub final_oid_pct: Rate,
+    /// Spread change versus the grid (negative = reverse flex)
+    pub spread_flex_bps: Decimal,
+    pub oid_flex_pct: Rate,
+    /// Whether the clearing levels were reached within the flex caps
+    pub within_flex: bool,
+    /// Additional discount the underwriter must give beyond the caps (per unit of par)
+    pub price_concession_pct: Rate,
+    pub underwriting_fee: Money,
+    /// Concession on the amount sold down
+    pub sell_down_cost: Money,
+    /// Mark-to-market of the final hold at the clearing price
+    pub hold_mark_to_market: Money,
+    /// Fee less sell-down cost and hold mark-to-market
+    pub net_underwriter_economics: Money,
+    /// Change in the borrower's annual all-in cost (spread + amortized OID, bps)
+    pub borrower_cost_change_bps: Decimal,
+}
+
+/// Output of leveraged loan pricing.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LoanPricingOutput {
+    /// Total debt / EBITDA
+    pub leverage: Multiple,
+    pub grid_match: GridMatch,
+    pub pricing: LoanPricing,
+    pub call_protection: CallProtectionValue,
+    pub flex_outcomes: Vec<FlexOutcome>,
+}
+
+// ---------------------------------------------------------------------------
+// Public API
+// ---------------------------------------------------------------------------
+
+/// Price a new-issue leveraged term loan from the clearing grid, value its
+/// call protection, and run underwriter flex scenarios.
+pub fn price_leveraged_loan(
+    input: &LoanPricingInput,
+) -> CorpFinanceResult<ComputationOutput<LoanPricingOutput>> {
+    let start = Instant::now();
+    let mut warnings: Vec<String> = Vec::new();
+
+    validate_input(input)?;
+
+    let leverage = input.total_debt / input.borrower_ebitda;
+    let grid_match = match_grid(input, leverage, &mut warnings);
+    if !grid_match.exact_rating_match {
+        warnings.push(format!(
+            "No grid row for {}; priced off nearest row {}",
+            input.rating, grid_match.rating
+        ));
+    }
+
+    let effective_base = match input.base_rate_floor {
+        Some(floor) => input.base_rate.max(floor),
+        None => input.base_rate,
+    };
+    let all_in_coupon = effective_base + grid_match.spread_bps / BPS;
+    let issue_price = Decimal::ONE - grid_match.oid_pct;
+    let k = input.expected_repayment_year;
+
+    let ytm = loan_yield(
+        input,
+        all_in_coupon,
+        issue_price,
+        input.maturity_years,
+        false,
+    )?;
+    let ytr = loan_yield(input, all_in_coupon, issue_price, k, true)?;
+    let ytr_ex_premium = loan_yield(input, all_in_coupon, issue_price, k, false)?;
+
+    let premium = premium_in_year(input, k);
+    let balance_k = balance_before_repayment(input, k);
+    let mut discount = Decimal::ONE;
+    for _ in 0..k {
+        discount *= Decimal::ONE + ytr_ex_premium;
+    }
+
+    let pricing = LoanPricing {
+        all_in_coupon,
+        issue_price,
+        yield_to_maturity: ytm,
+        yield_to_expected_repayment: ytr,
+        discount_margin_bps: (ytr - effective_base) * BPS,
+        oid_yield_pickup_bps: grid_match.oid_pct / Decimal::from(k) * BPS,
+    };
+
+    let call_protection = CallProtectionValue {
+        premium_at_expected_repayment: premium,
+        pv_of_premium: balance_k * premium / discount,
+        yield_pickup_bps: (ytr - ytr_ex_premium) * BPS,
+        protection_years: input.call_protection.len() as u32,
+    };
+
+    let flex_outcomes = match &input.underwriting {
+        Some(terms) => terms
+            .scenarios
+            .iter()
+            .map(|s| flex_outcome(input, terms, &grid_match, effective_base, s))
+            .collect(),
+        None => Vec::new(),
+    };
+    for outcome in &flex_outcomes {
+        if outcome.net_underwriter_economics < Decimal::ZERO {
+            warnings.push(format!(
+                "{}: underwriter loses money beyond the flex caps",
+                outcome.scenario
+            ));
+        }
+    }
+
+    let output = LoanPricingOutput {
+        leverage,
+        grid_match,
+        pricing,
+        call_protection,
+        flex_outcomes,
+    };
+
+    let elapsed = start.elapsed().as_micros() as u64;
+    let assumptions = serde_json::json!({
+        "deal_name": input.deal_name,
+        "rating": input.rating.to_string(),
+        "base_rate": input.base_rate.to_string(),
+        "expected_repayment_year": input.expected_repayment_year,
+        "cash_flow_frequency": "annual",
+    });
+
+    Ok(with_metadata(
+        "Leveraged Loan Pricing (clearing grid, call protection, underwriting flex)",
+        &assumptions,
+        warnings,
+        elapsed,
+        output,
+    ))
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Pick the grid row: the deal's rating if present, otherwise the nearest
+/// rating (ties resolved towards the worse rating), then the tightest
+/// leverage band that covers the deal's leverage.
+fn match_grid(
+    input: &LoanPricingInput,
+    leverage: Multiple,
+    warnings: &mut Vec<String>,
+) -> GridMatch {
+    let row_rating = input
+        .pricing_grid
+        .iter()
+        .map(|e| e.rating)
+        .min_by_key(|r| {
+            let d = input.rating.notches_to(*r);
+            // Prefer worse ratings on equal distance.
+            (d.abs(), d < 0)
+        })
+        .unwrap_or(input.rating);
+
+    let mut bands: Vec<&PricingGridEntry> = input
+        .pricing_grid
+        .iter()
+        .filter(|e| e.rating == row_rating)
+        .collect();
+    bands.sort_by_key(|e| e.max_leverage);
+
+    let cell = match bands.iter().find(|e| e.max_leverage >= leverage) {
+        Some(e) => e,
+        None => {
+            warnings.push(format!(
+                "Leverage {:.2}x exceeds the widest {} band; using the highest band",
+                leverage, row_rating
+            ));
+            bands[bands.len() - 1]
+        }
+    };
+
+    GridMatch {
+        rating: row_rating,
+        exact_rating_match: row_rating == input.rating,
+        band_max_leverage: cell.max_leverage,
+        spread_bps: cell.spread_bps,
+        oid_pct: cell.oid_pct,
+    }
+}
+
+/// Call premium payable on repayment during `year` (1-based).
+fn premium_in_year(input: &LoanPricingInput, year: u32) -> Rate {
+    if year >= input.maturity_years {
+        return Decimal::ZERO;
+    }
+    input
+        .call_protection
+        .get(year as usize - 1)
+        .copied()
+        .unwrap_or(Decimal::ZERO)
+}
+
+/// Outstanding balance per unit of par at the end of `year`, before repayment.
+fn balance_before_repayment(input: &LoanPricingInput, year: u32) -> Decimal {
+    let amortized = input.amortization_pct * Decimal::from(year.saturating_sub(1));
+    (Decimal::ONE - amortized).max(Decimal::ZERO)
+}
+
+/// Annual yield per unit of par assuming full repayment at `repay_year`.
+fn loan_yield(
+    input: &LoanPricingInput,
+    coupon: Rate,
+    price: Decimal,
+    repay_year: u32,
+    include_premium: bool,
+) -> CorpFinanceResult<Rate> {
+    let mut flows = vec![-price];
+    let mut balance = Decimal::ONE;
+    for year in 1..=repay_year {
+        let interest = balance * coupon;
+        if year == repay_year {
+            let premium = if include_premium {
+                premium_in_year(input, year)
+            } else {
+                Decimal::ZERO
+            };
+            flows.push(interest + balance * (Decimal::ONE + premium));
+        } else {
+            let amort = input.amortization_pct.min(balance);
+            balance -= amort;
+            flows.push(interest + amort);
+        }
+    }
+    irr(&flows, coupon)
+}
+
+fn flex_outcome(
+    input: &LoanPricingInput,
+    terms: &UnderwritingTerms,
+    grid: &GridMatch,
+    effective_base: Rate,
+    scenario: &FlexScenario,
+) -> FlexOutcome {
+    // Reverse flex is uncapped; upward flex is capped at the commitment terms.
+    let spread_need = scenario.clearing_spread_bps - grid.spread_bps;
+    let spread_flex = spread_need.min(terms.max_spread_flex_bps);
+    let spread_gap = spread_need - spread_flex;
+
+    let oid_need = scenario.clearing_oid_pct - grid.oid_pct;
+    let oid_flex = oid_need.min(terms.max_oid_flex_pct);
+    let oid_gap = oid_need - oid_flex;
+
+    // Spread shortfall converted to price via the annuity factor over the
+    // expected life at the clearing yield.
+    let clearing_yield = effective_base + scenario.clearing_spread_bps / BPS;
+    let mut annuity = Decimal::ZERO;
+    let mut discount = Decimal::ONE;
+    for _ in 0..input.expected_repayment_year {
+        discount /= Decimal::ONE + clearing_yield;
+        annuity += discount;
+    }
+    let price_concession_pct = oid_gap + spread_gap / BPS * annuity;
+
+    let hold = terms.final_hold.min(input.facility_amount);
+    let underwriting_fee = input.facility_amount * terms.underwriting_fee_pct;
+    let sell_down_cost = (input.facility_amount - hold) * price_concession_pct;
+    let hold_mark_to_market = hold * price_concession_pct;
+
+    FlexOutcome {
+        scenario: scenario.name.clone(),
+        final_spread_bps: grid.spread_bps + spread_flex,
+        final_oid_pct: grid.oid_pct + oid_flex,
+        spread_flex_bps: spread_flex,
+        oid_flex_pct: oid_flex,
+        within_flex: spread_gap.is_zero() && oid_gap.is_zero(),
+        price_concession_pct,
+        underwriting_fee,
+        sell_down_cost,
+        hold_mark_to_market,
+        net_underwriter_economics: underwriting_fee - sell_down_cost - hold_mark_to_market,
+        borrower_cost_change_bps: spread_flex
+            + oid_flex / Decimal::from(input.expected_repayment_year) * BPS,
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Validation
+// ---------------------------------------------------------------------------
+
+fn validate_input(input: &LoanPricingInput) -> CorpFinanceResult<()> {
+    if input.facility_amount <= Decimal::ZERO {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "facility_amount".into(),
+            reason: "Facility amount must be positive".into(),
+        });
+    }
+    if input.borrower_ebitda <= Decimal::ZERO {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "borrower_ebitda".into(),
+            reason: "EBITDA must be positive to determine the leverage band".into(),
+        });
+    }
+    if input.total_debt < input.facility_amount {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "total_debt".into(),
+            reason: "Total debt must include the facility".into(),
+        });
+    }
+    if input.pricing_grid.is_empty() {
+        return Err(CorpFinanceError::InsufficientData(
+            "Pricing grid must contain at least one entry".into(),
+        ));
+    }
+    for entry in &input.pricing_grid {
+        if entry.spread_bps < Decimal::ZERO
+            || entry.oid_pct < Decimal::ZERO
+            || entry.oid_pct >= Decimal::ONE
+        {
+            return Err(CorpFinanceError::InvalidInput {
+                field: "pricing_grid".into(),
+                reason: format!(
+                    "{} / {}x: spread must be non-negative and OID within [0, 1)",
+                    entry.rating, entry.max_leverage
+                ),
+            });
+        }
+    }
+    if input.maturity_years == 0 {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "maturity_years".into(),
+            reason: "Maturity must be at least one year".into(),
+        });
+    }
+    if input.expected_repayment_year == 0 || input.expected_repayment_year > input.maturity_years {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "expected_repayment_year".into(),
+            reason: "Expected repayment must fall between year 1 and maturity".into(),
+        });
+    }
+    if input.amortization_pct < Decimal::ZERO || input.amortization_pct > Decimal::ONE {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "amortization_pct".into(),
+            reason: "Amortization must be between 0 and 1".into(),
+        });
+    }
+    if input.call_protection.iter().any(|p| *p < Decimal::ZERO) {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "call_protection".into(),
+            reason: "Call premiums cannot be negative".into(),
+        });
+    }
+    if let Some(terms) = &input.underwriting {
+        if terms.underwriting_fee_pct < Decimal::ZERO
+            || terms.max_spread_flex_bps < Decimal::ZERO
+            || terms.max_oid_flex_pct < Decimal::ZERO
+            || terms.final_hold < Decimal::ZERO
+        {
+            return Err(CorpFinanceError::InvalidInput {
+                field: "underwriting".into(),
+                reason: "Fees, flex caps and hold must be non-negative".into(),
+            });
+        }
+    }
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn approx_eq(a: Decimal, b: Decimal, tol: Decimal) -> bool {
+        (a - b).abs() <= tol
+    }
+
+    fn entry(
+        rating: Rating,
+        max_leverage: Decimal,
+        spread: Decimal,
+        oid: Decimal,
+    ) -> PricingGridEntry {
+        PricingGridEntry {
+            rating,
+            max_leverage,
+            spread_bps: spread,
+            oid_pct: oid,
+        }
+    }
+
+    fn grid() -> Vec<PricingGridEntry> {
+        vec![
+            entry(Rating::BBMinus, dec!(4.0), dec!(300), dec!(0.0025)),
+            entry(Rating::BBMinus, dec!(5.5), dec!(350), dec!(0.005)),
+            entry(Rating::B, dec!(5.0), dec!(375), dec!(0.005)),
+            entry(Rating::B, dec!(6.5), dec!(425), dec!(0.01)),
+            entry(Rating::BMinus, dec!(7.5), dec!(500), dec!(0.015)),
+        ]
+    }
+
+    fn base_input() -> LoanPricingInput {
+        LoanPricingInput {
+            deal_name: "Project Atlas TLB".into(),
+            facility_amount: dec!(500),
+            rating: Rating::B,
+            total_debt: dec!(600),
+            borrower_ebitda: dec!(100),
+            pricing_grid: grid(),
+            base_rate: dec!(0.05),
+            base_rate_floor: None,
+            maturity_years: 7,
+            amortization_pct: Decimal::ZERO,
+            call_protection: vec![],
+            expected_repayment_year: 3,
+            underwriting: None,
+        }
+    }
+
+    fn underwriting(scenarios: Vec<FlexScenario>) -> UnderwritingTerms {
+        UnderwritingTerms {
+            underwriting_fee_pct: dec!(0.02),
+            final_hold: dec!(50),
+            max_spread_flex_bps: dec!(50),
+            max_oid_flex_pct: dec!(0.01),
+            scenarios,
+        }
+    }
+
+    fn scenario(name: &str, spread: Decimal, oid: Decimal) -> FlexScenario {
+        FlexScenario {
+            name: name.into(),
+            clearing_spread_bps: spread,
+            clearing_oid_pct: oid,
+        }
+    }
+
+    #[test]
+    fn test_grid_band_selection() {
+        let out = price_leveraged_loan(&base_input()).unwrap();
+        let m = &out.result.grid_match;
+        assert_eq!(out.result.leverage, dec!(6));
+        assert_eq!(m.rating, Rating::B);
+        assert!(m.exact_rating_match);
+        assert_eq!(m.band_max_leverage, dec!(6.5));
+        assert_eq!(m.spread_bps, dec!(425));
+    }
+
+    #[test]
+    fn test_lower_leverage_uses_tighter_band() {
+        let mut input = base_input();
+        input.total_debt = dec!(500);
+        let out = price_leveraged_loan(&input).unwrap();
+        assert_eq!(out.result.grid_match.spread_bps, dec!(375));
+    }
+
+    #[test]
+    fn test_leverage_above_grid_warns() {
+        let mut input = base_input();
+        input.total_debt = dec!(800);
+        let out = price_leveraged_loan(&input).unwrap();
+        assert_eq!(out.result.grid_match.band_max_leverage, dec!(6.5));
+        assert!(out
+            .warnings
+            .iter()
+            .any(|w| w.contains("exceeds the widest")));
+    }
+
+    #[test]
+    fn test_missing_rating_uses_nearest_worse() {
+        let mut input = base_input();
+        input.rating = Rating::BPlus;
+        let out = price_leveraged_loan(&input).unwrap();
+        // B+ is one notch from both BB- and B; the worse row (B) wins.
+        assert_eq!(out.result.grid_match.rating, Rating::B);
+        assert!(!out.result.grid_match.exact_rating_match);
+        assert!(out.warnings.iter().any(|w| w.contains("nearest row")));
+    }
+
+    #[test]
+    fn test_coupon_and_price() {
+        let out = price_leveraged_loan(&base_input()).unwrap();
+        let p = &out.result.pricing;
+        assert_eq!(p.all_in_coupon, dec!(0.0925));
+        assert_eq!(p.issue_price, dec!(0.99));
+        // OID 1pt over 3 years = 33.3bp
+        assert!(approx_eq(p.oid_yield_pickup_bps, dec!(33.33), dec!(0.01)));
+    }
+
+    #[test]
+    fn test_floor_applies() {
+        let mut input = base_input();
+        input.base_rate = dec!(0.003);
+        input.base_rate_floor = Some(dec!(0.005));
+        let out = price_leveraged_loan(&input).unwrap();
+        assert_eq!(out.result.pricing.all_in_coupon, dec!(0.0475));
+    }
+
+    #[test]
+    fn test_par_loan_yield_equals_coupon() {
+        let mut input = base_input();
+        input.pricing_grid = vec![entry(Rating::B, dec!(7), dec!(400), Decimal::ZERO)];
+        let out = price_leveraged_loan(&input).unwrap();
+        let p = &out.result.pricing;
+        assert!(approx_eq(p.yield_to_maturity, dec!(0.09), dec!(0.000001)));
+        assert!(approx_eq(p.discount_margin_bps, dec!(400), dec!(0.01)));
+    }
+
+    #[test]
+    fn test_oid_shorter_life_higher_yield() {
+        let out = price_leveraged_loan(&base_input()).unwrap();
+        let p = &out.result.pricing;
+        assert!(p.yield_to_maturity > p.all_in_coupon);
+        assert!(p.yield_to_expected_repayment > p.yield_to_maturity);
+    }
+
+    #[test]
+    fn test_amortization_yield() {
+        let mut input = base_input();
+        input.amortization_pct = dec!(0.01);
+        let out = price_leveraged_loan(&input).unwrap();
+        let base = price_leveraged_loan(&base_input()).unwrap();
+        // Earlier return of discounted principal raises the yield slightly.
+        assert!(out.result.pricing.yield_to_maturity > base.result.pricing.yield_to_maturity);
+    }
+
+    #[test]
+    fn test_call_protection_value() {
+        let mut input = base_input();
+        input.call_protection = vec![dec!(0.02), dec!(0.01), dec!(0.01)];
+        let out = price_leveraged_loan(&input).unwrap();
+        let cp = &out.result.call_protection;
+        assert_eq!(cp.premium_at_expected_repayment, dec!(0.01));
+        assert_eq!(cp.protection_years, 3);
+        assert!(cp.pv_of_premium > Decimal::ZERO && cp.pv_of_premium < dec!(0.01));
+        assert!(cp.yield_pickup_bps > dec!(25) && cp.yield_pickup_bps < dec!(40));
+    }
+
+    #[test]
+    fn test_no_call_protection_after_period() {
+        let mut input = base_input();
+        input.call_protection = vec![dec!(0.01)];
+        let out = price_leveraged_loan(&input).unwrap();
+        let cp = &out.result.call_protection;
+        assert_eq!(cp.premium_at_expected_repayment, Decimal::ZERO);
+        assert_eq!(cp.pv_of_premium, Decimal::ZERO);
+        assert!(approx_eq(cp.yield_pickup_bps, Decimal::ZERO, dec!(0.0001)));
+    }
+
+    #[test]
+    fn test_flex_within_caps() {
+        let mut input = base_input();
+        input.underwriting = Some(underwriting(vec![scenario(
+            "Modest widening",
+            dec!(450),
+            dec!(0.015),
+        )]));
+        let out = price_leveraged_loan(&input).unwrap();
+        let f = &out.result.flex_outcomes[0];
+        assert!(f.within_flex);
+        assert_eq!(f.final_spread_bps, dec!(450));
+        assert_eq!(f.final_oid_pct, dec!(0.015));
+        assert_eq!(f.price_concession_pct, Decimal::ZERO);
+        assert_eq!(f.net_underwriter_economics, dec!(10));
+        // 25bp + 0.5pt / 3y = 41.67bp
+        assert!(approx_eq(
+            f.borrower_cost_change_bps,
+            dec!(41.667),
+            dec!(0.001)
+        ));
+    }
+
+    #[test]
+    fn test_flex_beyond_caps_costs_underwriter() {
+        let mut input = base_input();
+        input.underwriting = Some(underwriting(vec![scenario(
+            "Market dislocation",
+            dec!(575),
+            dec!(0.04),
+        )]));
+        let out = price_leveraged_loan(&input).unwrap();
+        let f = &out.result.flex_outcomes[0];
+        assert!(!f.within_flex);
+        assert_eq!(f.final_spread_bps, dec!(475));
+        assert_eq!(f.final_oid_pct, dec!(0.02));
+        // OID gap 2pts plus 100bp spread gap x annuity (~2.5)
+        assert!(f.price_concession_pct > dec!(0.04) && f.price_concession_pct < dec!(0.05));
+        assert_eq!(f.sell_down_cost, dec!(450) * f.price_concession_pct);
+        assert!(f.net_underwriter_economics < Decimal::ZERO);
+        assert!(out.warnings.iter().any(|w| w.contains("loses money")));
+    }
+
+    #[test]
+    fn test_reverse_flex() {
+        let mut input = base_input();
+        input.underwriting = Some(underwriting(vec![scenario(
+            "Hot market",
+            dec!(375),
+            dec!(0.005),
+        )]));
+        let out = price_leveraged_loan(&input).unwrap();
+        let f = &out.result.flex_outcomes[0];
+        assert!(f.within_flex);
+        assert_eq!(f.spread_flex_bps, dec!(-50));
+        assert!(f.borrower_cost_change_bps < Decimal::ZERO);
+        assert_eq!(f.net_underwriter_economics, dec!(10));
+    }
+
+    #[test]
+    fn test_serde_rating_notation() {
+        let json = r#"{"rating":"B2","max_leverage":"6","spread_bps":"400","oid_pct":"0.01"}"#;
+        let e: PricingGridEntry = serde_json::from_str(json).unwrap();
+        assert_eq!(e.rating, Rating::B);
+    }
+
+    #[test]
+    fn test_invalid_inputs() {
+        let mut input = base_input();
+        input.pricing_grid.clear();
+        assert!(price_leveraged_loan(&input).is_err());
+
+        let mut input = base_input();
+        input.borrower_ebitda = Decimal::ZERO;
+        assert!(price_leveraged_loan(&input).is_err());
+
+        let mut input = base_input();
+        input.expected_repayment_year = 8;
+        assert!(price_leveraged_loan(&input).is_err());
+
+        let mut input = base_input();
+        input.total_debt = dec!(400);
+        assert!(price_leveraged_loan(&input).is_err());
+
+        let mut input = base_input();
+        input.pricing_grid[0].oid_pct = dec!(1);
+        assert!(price_leveraged_loan(&input).is_err());
+    }
+}
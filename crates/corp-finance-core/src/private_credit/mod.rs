@@ -1,2 +1,5 @@
+pub mod bridge_financing;
 pub mod direct_lending;
+pub mod fund_finance;
+pub mod loan_pricing;
 pub mod unitranche;
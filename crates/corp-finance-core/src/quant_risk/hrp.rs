@@ -0,0 +1,626 @@
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+use std::time::Instant;
+
+use super::risk_parity::{
+    mat_vec_multiply, portfolio_variance, sqrt_decimal, validate_covariance_matrix,
+    AssetAllocation, AssetInfo, RiskContribution,
+};
+use crate::error::CorpFinanceError;
+use crate::types::{with_metadata, ComputationOutput};
+use crate::CorpFinanceResult;
+
+/// Correlation-matrix condition number above which ERC and minimum-variance
+/// weights become unreliable.
+const ILL_CONDITIONED: Decimal = dec!(1000);
+
+// ---------------------------------------------------------------------------
+// Types
+// ---------------------------------------------------------------------------
+
+/// How the distance between two clusters is measured.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub enum HrpLinkage {
+    /// Closest pair of members (Lopez de Prado's original choice)
+    #[default]
+    Single,
+    /// Furthest pair of members
+    Complete,
+    /// Mean over all member pairs
+    Average,
+}
+
+/// Input for hierarchical risk parity.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HrpInput {
+    /// Asset descriptions; weights use the covariance matrix, volatilities
+    /// only enter the diversification ratio
+    pub assets: Vec<AssetInfo>,
+    /// NxN covariance matrix (row-major)
+    pub covariance_matrix: Vec<Vec<Decimal>>,
+    /// Cluster linkage (defaults to single)
+    #[serde(default)]
+    pub linkage: HrpLinkage,
+    /// Optional target portfolio volatility -- weights are scaled post-hoc
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_volatility: Option<Decimal>,
+    /// Risk-free rate for Sharpe computation (defaults to 0)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_free_rate: Option<Decimal>,
+}
+
+/// One merge in the cluster tree. Leaves are 0..n-1 in input order; the
+/// cluster formed at step k is n + k.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClusterMerge {
+    pub cluster_id: usize,
+    pub left: usize,
+    pub right: usize,
+    pub distance: Decimal,
+    pub size: usize,
+}
+
+/// Output of hierarchical risk parity.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HrpOutput {
+    pub weights: Vec<AssetAllocation>,
+    pub risk_contributions: Vec<RiskContribution>,
+    /// Assets in quasi-diagonal (dendrogram leaf) order
+    pub cluster_order: Vec<String>,
+    pub linkage: Vec<ClusterMerge>,
+    pub portfolio_volatility: Decimal,
+    pub portfolio_expected_return: Decimal,
+    pub portfolio_sharpe: Decimal,
+    /// Weighted-average vol / portfolio vol
+    pub diversification_ratio: Decimal,
+    /// Herfindahl-based effective number of assets: 1 / sum(w_i^2)
+    pub effective_num_assets: Decimal,
+    /// Largest over smallest eigenvalue of the correlation matrix
+    pub condition_number: Option<Decimal>,
+}
+
+// ---------------------------------------------------------------------------
+// Public API
+// ---------------------------------------------------------------------------
+
+/// Compute hierarchical risk parity weights (Lopez de Prado, 2016): cluster
+/// assets on correlation distance, reorder the covariance matrix so similar
+/// assets sit together, then split the ordered list recursively, sharing
+/// weight between halves in inverse proportion to their variance. No matrix
+/// inversion, so it stays stable when the covariance matrix is near
+/// singular.
+pub fn calculate_hrp(input: &HrpInput) -> CorpFinanceResult<ComputationOutput<HrpOutput>> {
+    let start = Instant::now();
+    let mut warnings: Vec<String> = Vec::new();
+
+    // -- Validation --
+    let n = input.assets.len();
+    if n == 0 {
+        return Err(CorpFinanceError::InsufficientData(
+            "At least one asset required".into(),
+        ));
+    }
+    let cov = &input.covariance_matrix;
+    validate_covariance_matrix(cov, n)?;
+    for (i, row) in cov.iter().enumerate() {
+        if row[i] <= Decimal::ZERO {
+            return Err(CorpFinanceError::InvalidInput {
+                field: format!("covariance_matrix[{}][{}]", i, i),
+                reason: "Variances must be positive".into(),
+            });
+        }
+    }
+
+    // -- Tree clustering on correlation distance --
+    let vols: Vec<Decimal> = (0..n).map(|i| sqrt_decimal(cov[i][i])).collect();
+    let corr: Vec<Vec<Decimal>> = (0..n)
+        .map(|i| {
+            (0..n)
+                .map(|j| {
+                    if i == j {
+                        Decimal::ONE
+                    } else {
+                        (cov[i][j] / (vols[i] * vols[j])).clamp(dec!(-1), Decimal::ONE)
+                    }
+                })
+                .collect()
+        })
+        .collect();
+    let dist: Vec<Vec<Decimal>> = corr
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|rho| sqrt_decimal((Decimal::ONE - *rho) / dec!(2)))
+                .collect()
+        })
+        .collect();
+    // Distance between columns of the distance matrix, so assets are
+    // grouped by how they relate to the whole universe.
+    let col_dist: Vec<Vec<Decimal>> = (0..n)
+        .map(|i| {
+            (0..n)
+                .map(|j| {
+                    let ss: Decimal = (0..n)
+                        .map(|k| {
+                            let d = dist[k][i] - dist[k][j];
+                            d * d
+                        })
+                        .sum();
+                    sqrt_decimal(ss)
+                })
+                .collect()
+        })
+        .collect();
+
+    let linkage = cluster(&col_dist, input.linkage);
+    let order = quasi_diagonal_order(&linkage, n);
+
+    // -- Recursive bisection --
+    let raw_weights = recursive_bisection(cov, &order);
+
+    // -- Scale to target volatility if requested --
+    let weights: Vec<Decimal> = match input.target_volatility {
+        Some(target_vol) => {
+            let port_vol = sqrt_decimal(portfolio_variance(&raw_weights, cov));
+            if port_vol.is_zero() {
+                raw_weights
+            } else {
+                let scale = target_vol / port_vol;
+                raw_weights.iter().map(|w| *w * scale).collect()
+            }
+        }
+        None => raw_weights,
+    };
+
+    // -- Portfolio metrics --
+    let port_vol = sqrt_decimal(portfolio_variance(&weights, cov));
+    let port_ret: Decimal = weights
+        .iter()
+        .zip(input.assets.iter())
+        .map(|(w, a)| *w * a.expected_return)
+        .sum();
+    let rf = input.risk_free_rate.unwrap_or(Decimal::ZERO);
+    let port_sharpe = if port_vol.is_zero() {
+        Decimal::ZERO
+    } else {
+        (port_ret - rf) / port_vol
+    };
+    let weighted_avg_vol: Decimal = weights
+        .iter()
+        .zip(input.assets.iter())
+        .map(|(w, a)| *w * a.volatility)
+        .sum();
+    let diversification_ratio = if port_vol.is_zero() {
+        Decimal::ONE
+    } else {
+        weighted_avg_vol / port_vol
+    };
+    let hhi: Decimal = weights.iter().map(|w| *w * *w).sum();
+    let effective_num_assets = if hhi.is_zero() {
+        Decimal::ZERO
+    } else {
+        Decimal::ONE / hhi
+    };
+
+    // -- Risk contributions --
+    let sigma_w = mat_vec_multiply(cov, &weights);
+    let risk_contributions: Vec<RiskContribution> = weights
+        .iter()
+        .enumerate()
+        .map(|(i, w)| {
+            let marginal = if port_vol.is_zero() {
+                Decimal::ZERO
+            } else {
+                sigma_w[i] / port_vol
+            };
+            let rc = *w * marginal;
+            RiskContribution {
+                name: input.assets[i].name.clone(),
+                marginal_risk: marginal,
+                risk_contribution: rc,
+                risk_pct: if port_vol.is_zero() {
+                    Decimal::ZERO
+                } else {
+                    rc / port_vol
+                },
+            }
+        })
+        .collect();
+
+    let allocations: Vec<AssetAllocation> = weights
+        .iter()
+        .enumerate()
+        .map(|(i, w)| AssetAllocation {
+            name: input.assets[i].name.clone(),
+            weight: *w,
+        })
+        .collect();
+
+    // -- Conditioning --
+    let eigenvalues = symmetric_eigenvalues(&corr);
+    let max_eig = eigenvalues.iter().copied().max().unwrap_or(Decimal::ONE);
+    let min_eig = eigenvalues.iter().copied().min().unwrap_or(Decimal::ONE);
+    let condition_number = if min_eig > Decimal::ZERO {
+        Some(max_eig / min_eig)
+    } else {
+        None
+    };
+    match condition_number {
+        None => warnings.push(
+            "Correlation matrix is singular: ERC and minimum-variance weights are undefined".into(),
+        ),
+        Some(k) if k > ILL_CONDITIONED => warnings.push(format!(
+            "Correlation matrix is ill-conditioned (condition number {:.0}): \
+             ERC and minimum-variance weights would be unstable",
+            k
+        )),
+        _ => {}
+    }
+    for alloc in &allocations {
+        if alloc.weight > dec!(0.50) {
+            warnings.push(format!(
+                "Concentrated position: {} has weight {:.2}%",
+                alloc.name,
+                alloc.weight * dec!(100)
+            ));
+        }
+    }
+
+    let output = HrpOutput {
+        weights: allocations,
+        risk_contributions,
+        cluster_order: order
+            .iter()
+            .map(|&i| input.assets[i].name.clone())
+            .collect(),
+        linkage,
+        portfolio_volatility: port_vol,
+        portfolio_expected_return: port_ret,
+        portfolio_sharpe: port_sharpe,
+        diversification_ratio,
+        effective_num_assets,
+        condition_number,
+    };
+
+    let elapsed = start.elapsed().as_micros() as u64;
+    Ok(with_metadata(
+        "Hierarchical Risk Parity (Lopez de Prado)",
+        &serde_json::json!({
+            "num_assets": n,
+            "linkage": format!("{:?}", input.linkage),
+            "distance": "sqrt((1 - rho) / 2), clustered on Euclidean distance between columns",
+            "allocation": "recursive bisection, inverse-variance within clusters",
+            "target_volatility": input.target_volatility.map(|v| v.to_string()),
+        }),
+        warnings,
+        elapsed,
+        output,
+    ))
+}
+
+// ---------------------------------------------------------------------------
+// Clustering
+// ---------------------------------------------------------------------------
+
+/// Agglomerative clustering. Ties merge the lowest cluster ids first.
+fn cluster(dist: &[Vec<Decimal>], linkage: HrpLinkage) -> Vec<ClusterMerge> {
+    let n = dist.len();
+    let mut active: Vec<(usize, Vec<usize>)> = (0..n).map(|i| (i, vec![i])).collect();
+    let mut merges = Vec::with_capacity(n.saturating_sub(1));
+
+    let between = |a: &[usize], b: &[usize]| -> Decimal {
+        let pairs = a.iter().flat_map(|&i| b.iter().map(move |&j| dist[i][j]));
+        match linkage {
+            HrpLinkage::Single => pairs.min().unwrap_or(Decimal::ZERO),
+            HrpLinkage::Complete => pairs.max().unwrap_or(Decimal::ZERO),
+            HrpLinkage::Average => {
+                let total: Decimal = pairs.sum();
+                total / Decimal::from((a.len() * b.len()) as u64)
+            }
+        }
+    };
+
+    while active.len() > 1 {
+        let mut best: Option<(usize, usize, Decimal)> = None;
+        for x in 0..active.len() {
+            for y in (x + 1)..active.len() {
+                let d = between(&active[x].1, &active[y].1);
+                if best.is_none_or(|(_, _, b)| d < b) {
+                    best = Some((x, y, d));
+                }
+            }
+        }
+        let (x, y, distance) = best.expect("at least two active clusters");
+        let (right_id, right) = active.remove(y);
+        let (left_id, mut members) = active.remove(x);
+        members.extend(right);
+        let cluster_id = n + merges.len();
+        merges.push(ClusterMerge {
+            cluster_id,
+            left: left_id.min(right_id),
+            right: left_id.max(right_id),
+            distance,
+            size: members.len(),
+        });
+        active.push((cluster_id, members));
+    }
+    merges
+}
+
+/// Leaves of the dendrogram from the root, left subtree first.
+fn quasi_diagonal_order(merges: &[ClusterMerge], n: usize) -> Vec<usize> {
+    let Some(root) = merges.last() else {
+        return (0..n).collect();
+    };
+    let mut order = Vec::with_capacity(n);
+    let mut stack = vec![root.cluster_id];
+    while let Some(id) = stack.pop() {
+        if id < n {
+            order.push(id);
+        } else {
+            let m = &merges[id - n];
+            stack.push(m.right);
+            stack.push(m.left);
+        }
+    }
+    order
+}
+
+// ---------------------------------------------------------------------------
+// Allocation
+// ---------------------------------------------------------------------------
+
+/// Variance of a cluster held at inverse-variance weights.
+fn cluster_variance(cov: &[Vec<Decimal>], items: &[usize]) -> Decimal {
+    let inv: Vec<Decimal> = items.iter().map(|&i| Decimal::ONE / cov[i][i]).collect();
+    let total: Decimal = inv.iter().sum();
+    let w: Vec<Decimal> = inv.iter().map(|v| *v / total).collect();
+    let mut var = Decimal::ZERO;
+    for (a, &i) in items.iter().enumerate() {
+        for (b, &j) in items.iter().enumerate() {
+            var += w[a] * w[b] * cov[i][j];
+        }
+    }
+    var
+}
+
+/// Split the ordered assets in half repeatedly, giving each half weight in
+/// inverse proportion to its cluster variance.
+fn recursive_bisection(cov: &[Vec<Decimal>], order: &[usize]) -> Vec<Decimal> {
+    let mut weights = vec![Decimal::ONE; cov.len()];
+    let mut clusters: Vec<&[usize]> = vec![order];
+    while !clusters.is_empty() {
+        let mut next = Vec::new();
+        for c in clusters {
+            if c.len() < 2 {
+                continue;
+            }
+            let (left, right) = c.split_at(c.len() / 2);
+            let v_left = cluster_variance(cov, left);
+            let v_right = cluster_variance(cov, right);
+            let alpha = Decimal::ONE - v_left / (v_left + v_right);
+            for &i in left {
+                weights[i] *= alpha;
+            }
+            for &i in right {
+                weights[i] *= Decimal::ONE - alpha;
+            }
+            next.push(left);
+            next.push(right);
+        }
+        clusters = next;
+    }
+    weights
+}
+
+// ---------------------------------------------------------------------------
+// Conditioning
+// ---------------------------------------------------------------------------
+
+/// Eigenvalues of a symmetric matrix by cyclic Jacobi rotations.
+#[allow(clippy::needless_range_loop)]
+fn symmetric_eigenvalues(mat: &[Vec<Decimal>]) -> Vec<Decimal> {
+    let n = mat.len();
+    let mut a: Vec<Vec<Decimal>> = mat.to_vec();
+    let tolerance = dec!(0.000000000001);
+    for _ in 0..50 {
+        let off: Decimal = (0..n)
+            .flat_map(|i| ((i + 1)..n).map(move |j| (i, j)))
+            .map(|(i, j)| a[i][j].abs())
+            .sum();
+        if off < tolerance {
+            break;
+        }
+        for p in 0..n {
+            for q in (p + 1)..n {
+                let apq = a[p][q];
+                if apq.abs() < tolerance {
+                    continue;
+                }
+                let theta = (a[q][q] - a[p][p]) / (dec!(2) * apq);
+                let sign = if theta < Decimal::ZERO {
+                    dec!(-1)
+                } else {
+                    Decimal::ONE
+                };
+                let t = sign / (theta.abs() + sqrt_decimal(theta * theta + Decimal::ONE));
+                let c = Decimal::ONE / sqrt_decimal(t * t + Decimal::ONE);
+                let s = t * c;
+                a[p][p] -= t * apq;
+                a[q][q] += t * apq;
+                a[p][q] = Decimal::ZERO;
+                a[q][p] = Decimal::ZERO;
+                for r in 0..n {
+                    if r == p || r == q {
+                        continue;
+                    }
+                    let arp = a[r][p];
+                    let arq = a[r][q];
+                    a[r][p] = c * arp - s * arq;
+                    a[p][r] = a[r][p];
+                    a[r][q] = s * arp + c * arq;
+                    a[q][r] = a[r][q];
+                }
+            }
+        }
+    }
+    (0..n).map(|i| a[i][i]).collect()
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn asset(name: &str, vol: Decimal) -> AssetInfo {
+        AssetInfo {
+            name: name.into(),
+            expected_return: dec!(0.06),
+            volatility: vol,
+        }
+    }
+
+    /// Covariance from vols and a correlation matrix.
+    fn covariance(vols: &[Decimal], corr: &[Vec<Decimal>]) -> Vec<Vec<Decimal>> {
+        (0..vols.len())
+            .map(|i| {
+                (0..vols.len())
+                    .map(|j| corr[i][j] * vols[i] * vols[j])
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn input(vols: Vec<Decimal>, corr: Vec<Vec<Decimal>>) -> HrpInput {
+        HrpInput {
+            assets: vols
+                .iter()
+                .enumerate()
+                .map(|(i, v)| asset(&format!("A{i}"), *v))
+                .collect(),
+            covariance_matrix: covariance(&vols, &corr),
+            linkage: HrpLinkage::Single,
+            target_volatility: None,
+            risk_free_rate: None,
+        }
+    }
+
+    fn block_input() -> HrpInput {
+        // A0/A2 and A1/A3 are highly correlated pairs; pairs are unrelated.
+        let corr = vec![
+            vec![dec!(1), dec!(0.1), dec!(0.9), dec!(0.1)],
+            vec![dec!(0.1), dec!(1), dec!(0.1), dec!(0.8)],
+            vec![dec!(0.9), dec!(0.1), dec!(1), dec!(0.1)],
+            vec![dec!(0.1), dec!(0.8), dec!(0.1), dec!(1)],
+        ];
+        input(vec![dec!(0.10), dec!(0.20), dec!(0.15), dec!(0.25)], corr)
+    }
+
+    #[test]
+    fn test_two_uncorrelated_assets_get_inverse_variance() {
+        let inp = input(
+            vec![dec!(0.10), dec!(0.20)],
+            vec![vec![dec!(1), dec!(0)], vec![dec!(0), dec!(1)]],
+        );
+        let out = calculate_hrp(&inp).unwrap().result;
+        // 1/0.01 : 1/0.04 = 4 : 1
+        assert_eq!(out.weights[0].weight, dec!(0.8));
+        assert_eq!(out.weights[1].weight, dec!(0.2));
+        // Risk splits w_i^2 sigma_i^2: 0.0064 : 0.0016
+        let rc = &out.risk_contributions;
+        assert!((rc[0].risk_pct - dec!(0.8)).abs() < dec!(0.000001));
+        assert!((rc[1].risk_pct - dec!(0.2)).abs() < dec!(0.000001));
+    }
+
+    #[test]
+    fn test_clusters_correlated_pairs_together() {
+        let out = calculate_hrp(&block_input()).unwrap().result;
+        assert_eq!(out.linkage.len(), 3);
+        // Tightest pair merges first
+        assert_eq!((out.linkage[0].left, out.linkage[0].right), (0, 2));
+        assert_eq!((out.linkage[1].left, out.linkage[1].right), (1, 3));
+        assert_eq!(out.linkage[2].size, 4);
+        assert!(out.linkage[0].distance <= out.linkage[1].distance);
+        let order = &out.cluster_order;
+        let pos = |name: &str| order.iter().position(|n| n == name).unwrap();
+        assert_eq!(pos("A0").abs_diff(pos("A2")), 1);
+        assert_eq!(pos("A1").abs_diff(pos("A3")), 1);
+    }
+
+    #[test]
+    fn test_weights_positive_and_sum_to_one() {
+        let out = calculate_hrp(&block_input()).unwrap().result;
+        let total: Decimal = out.weights.iter().map(|w| w.weight).sum();
+        assert!((total - Decimal::ONE).abs() < dec!(0.0000001));
+        assert!(out.weights.iter().all(|w| w.weight > Decimal::ZERO));
+        // Lowest-vol asset carries the most weight
+        let max = out.weights.iter().max_by_key(|w| w.weight).unwrap();
+        assert_eq!(max.name, "A0");
+        let pct: Decimal = out.risk_contributions.iter().map(|r| r.risk_pct).sum();
+        assert!((pct - Decimal::ONE).abs() < dec!(0.000001));
+    }
+
+    #[test]
+    fn test_ill_conditioned_matrix_still_allocates() {
+        let corr = vec![
+            vec![dec!(1), dec!(0.9999), dec!(0.3)],
+            vec![dec!(0.9999), dec!(1), dec!(0.3)],
+            vec![dec!(0.3), dec!(0.3), dec!(1)],
+        ];
+        let out = calculate_hrp(&input(vec![dec!(0.2), dec!(0.2), dec!(0.1)], corr)).unwrap();
+        let k = out.result.condition_number.unwrap();
+        assert!(k > dec!(1000));
+        assert!(out.warnings.iter().any(|w| w.contains("ill-conditioned")));
+        let w: Vec<Decimal> = out.result.weights.iter().map(|a| a.weight).collect();
+        // The near-duplicate pair splits its cluster weight evenly
+        assert!((w[0] - w[1]).abs() < dec!(0.0000001));
+        assert!(w.iter().all(|x| *x > Decimal::ZERO));
+    }
+
+    #[test]
+    fn test_condition_number() {
+        let eig = symmetric_eigenvalues(&[vec![dec!(2), dec!(1)], vec![dec!(1), dec!(2)]]);
+        let mut eig = eig;
+        eig.sort();
+        assert!((eig[0] - dec!(1)).abs() < dec!(0.0000001));
+        assert!((eig[1] - dec!(3)).abs() < dec!(0.0000001));
+
+        let inp = input(
+            vec![dec!(0.1), dec!(0.1)],
+            vec![vec![dec!(1), dec!(0)], vec![dec!(0), dec!(1)]],
+        );
+        let out = calculate_hrp(&inp).unwrap().result;
+        assert_eq!(out.condition_number, Some(Decimal::ONE));
+    }
+
+    #[test]
+    fn test_linkage_choices_and_target_vol() {
+        for linkage in [HrpLinkage::Complete, HrpLinkage::Average] {
+            let mut inp = block_input();
+            inp.linkage = linkage;
+            inp.target_volatility = Some(dec!(0.10));
+            let out = calculate_hrp(&inp).unwrap().result;
+            assert!((out.portfolio_volatility - dec!(0.10)).abs() < dec!(0.000001));
+            assert_eq!((out.linkage[0].left, out.linkage[0].right), (0, 2));
+        }
+    }
+
+    #[test]
+    fn test_validation() {
+        let mut inp = block_input();
+        inp.covariance_matrix[1][1] = Decimal::ZERO;
+        assert!(calculate_hrp(&inp).is_err());
+
+        let mut inp = block_input();
+        inp.covariance_matrix.pop();
+        assert!(calculate_hrp(&inp).is_err());
+
+        let mut inp = block_input();
+        inp.assets.clear();
+        assert!(calculate_hrp(&inp).is_err());
+    }
+}
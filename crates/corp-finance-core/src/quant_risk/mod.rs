@@ -1,4 +1,5 @@
 pub mod black_litterman;
 pub mod factor_models;
+pub mod hrp;
 pub mod risk_parity;
 pub mod stress_testing;
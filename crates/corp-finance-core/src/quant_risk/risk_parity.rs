@@ -318,7 +318,7 @@ fn min_variance_weights(cov: &[Vec<Decimal>], n: usize) -> CorpFinanceResult<Vec
 // ---------------------------------------------------------------------------
 
 /// Matrix-vector multiplication: result_i = sum_j(mat[i][j] * vec[j]).
-fn mat_vec_multiply(mat: &[Vec<Decimal>], v: &[Decimal]) -> Vec<Decimal> {
+pub(super) fn mat_vec_multiply(mat: &[Vec<Decimal>], v: &[Decimal]) -> Vec<Decimal> {
     mat.iter().map(|row| vec_dot(row, v)).collect()
 }
 
@@ -388,13 +388,13 @@ fn mat_inverse(mat: &[Vec<Decimal>], n: usize) -> CorpFinanceResult<Vec<Vec<Deci
 }
 
 /// Portfolio variance: w' * Sigma * w.
-fn portfolio_variance(weights: &[Decimal], cov: &[Vec<Decimal>]) -> Decimal {
+pub(super) fn portfolio_variance(weights: &[Decimal], cov: &[Vec<Decimal>]) -> Decimal {
     let sigma_w = mat_vec_multiply(cov, weights);
     vec_dot(weights, &sigma_w)
 }
 
 /// Square root via Newton's method (20 iterations).
-fn sqrt_decimal(val: Decimal) -> Decimal {
+pub(super) fn sqrt_decimal(val: Decimal) -> Decimal {
     if val <= Decimal::ZERO {
         return Decimal::ZERO;
     }
@@ -420,7 +420,7 @@ fn sqrt_decimal(val: Decimal) -> Decimal {
 // ---------------------------------------------------------------------------
 
 #[allow(clippy::needless_range_loop)]
-fn validate_covariance_matrix(cov: &[Vec<Decimal>], n: usize) -> CorpFinanceResult<()> {
+pub(super) fn validate_covariance_matrix(cov: &[Vec<Decimal>], n: usize) -> CorpFinanceResult<()> {
     if cov.len() != n {
         return Err(CorpFinanceError::InvalidInput {
             field: "covariance_matrix".into(),
@@ -4,6 +4,7 @@ use serde::{Deserialize, Serialize};
 use std::time::Instant;
 
 use crate::error::CorpFinanceError;
+use crate::scenarios::scenario::{MacroScenario, MacroScenarioDefinition};
 use crate::types::{with_metadata, ComputationOutput};
 use crate::CorpFinanceResult;
 
@@ -74,7 +75,14 @@ pub struct StressTestInput {
     /// Current portfolio positions
     pub portfolio: Vec<PortfolioPosition>,
     /// Scenarios to evaluate
+    #[serde(default)]
     pub scenarios: Vec<StressScenario>,
+    /// Named scenarios from the macro library, evaluated after `scenarios`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub macro_scenarios: Vec<MacroScenario>,
+    /// Scale applied to library scenario shocks (default 1.0)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub macro_severity: Option<Decimal>,
     /// Multiply historical-scenario impacts by 1.2 to account for
     /// crisis correlation spikes (default true)
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -225,6 +233,24 @@ pub fn get_historical_scenarios() -> Vec<StressScenario> {
     ]
 }
 
+impl From<&MacroScenarioDefinition> for StressScenario {
+    fn from(def: &MacroScenarioDefinition) -> Self {
+        StressScenario {
+            name: def.name.clone(),
+            scenario_type: if def.historical {
+                ScenarioType::Historical
+            } else {
+                ScenarioType::Hypothetical
+            },
+            shocks: def
+                .market_shocks()
+                .into_iter()
+                .map(|(factor, shock_pct)| MarketShock { factor, shock_pct })
+                .collect(),
+        }
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Public API
 // ---------------------------------------------------------------------------
@@ -242,7 +268,21 @@ pub fn run_stress_test(
             "Portfolio must contain at least one position".into(),
         ));
     }
-    if input.scenarios.is_empty() {
+    let severity = input.macro_severity.unwrap_or(Decimal::ONE);
+    if severity <= Decimal::ZERO {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "macro_severity".into(),
+            reason: "Severity must be positive".into(),
+        });
+    }
+    let mut scenarios = input.scenarios.clone();
+    scenarios.extend(
+        input
+            .macro_scenarios
+            .iter()
+            .map(|m| StressScenario::from(&m.definition(severity))),
+    );
+    if scenarios.is_empty() {
         return Err(CorpFinanceError::InsufficientData(
             "At least one stress scenario required".into(),
         ));
@@ -259,9 +299,9 @@ pub fn run_stress_test(
     let use_corr_adj = input.correlation_adjustments.unwrap_or(true);
 
     // -- Evaluate each scenario --
-    let mut scenario_results: Vec<ScenarioResult> = Vec::with_capacity(input.scenarios.len());
+    let mut scenario_results: Vec<ScenarioResult> = Vec::with_capacity(scenarios.len());
 
-    for scenario in &input.scenarios {
+    for scenario in &scenarios {
         let result = evaluate_scenario(&input.portfolio, scenario, use_corr_adj);
         scenario_results.push(result);
     }
@@ -309,7 +349,9 @@ pub fn run_stress_test(
         "Portfolio Stress Testing",
         &serde_json::json!({
             "num_positions": input.portfolio.len(),
-            "num_scenarios": input.scenarios.len(),
+            "num_scenarios": scenarios.len(),
+            "macro_scenarios": input.macro_scenarios,
+            "macro_severity": severity.to_string(),
             "correlation_adjustments": use_corr_adj,
         }),
         warnings,
@@ -491,6 +533,8 @@ mod tests {
             portfolio: single_equity_portfolio(),
             scenarios: vec![simple_equity_crash()],
             correlation_adjustments: Some(false),
+            macro_scenarios: vec![],
+            macro_severity: None,
         };
         let result = run_stress_test(&input).unwrap();
         let sr = &result.result.scenario_results[0];
@@ -513,6 +557,8 @@ mod tests {
             portfolio,
             scenarios: vec![simple_equity_crash()],
             correlation_adjustments: Some(false),
+            macro_scenarios: vec![],
+            macro_severity: None,
         };
         let result = run_stress_test(&input).unwrap();
         // beta 1.5 * -30% = -45%
@@ -535,6 +581,8 @@ mod tests {
             portfolio: diversified_portfolio(),
             scenarios: vec![gfc],
             correlation_adjustments: Some(false),
+            macro_scenarios: vec![],
+            macro_severity: None,
         };
         let result = run_stress_test(&input).unwrap();
         let sr = &result.result.scenario_results[0];
@@ -549,6 +597,8 @@ mod tests {
             portfolio: diversified_portfolio(),
             scenarios: get_historical_scenarios(),
             correlation_adjustments: Some(true),
+            macro_scenarios: vec![],
+            macro_severity: None,
         };
         let result = run_stress_test(&input).unwrap();
         assert_eq!(result.result.scenario_results.len(), 5);
@@ -574,11 +624,15 @@ mod tests {
             portfolio: single_equity_portfolio(),
             scenarios: vec![hist_scenario.clone()],
             correlation_adjustments: Some(true),
+            macro_scenarios: vec![],
+            macro_severity: None,
         };
         let input_no_adj = StressTestInput {
             portfolio: single_equity_portfolio(),
             scenarios: vec![hist_scenario],
             correlation_adjustments: Some(false),
+            macro_scenarios: vec![],
+            macro_severity: None,
         };
         let adj = run_stress_test(&input_adj).unwrap();
         let no_adj = run_stress_test(&input_no_adj).unwrap();
@@ -599,6 +653,8 @@ mod tests {
             portfolio: single_equity_portfolio(),
             scenarios: vec![simple_equity_crash()],
             correlation_adjustments: Some(true),
+            macro_scenarios: vec![],
+            macro_severity: None,
         };
         let result = run_stress_test(&input).unwrap();
         // Hypothetical scenarios should not get the 1.2x multiplier
@@ -640,11 +696,15 @@ mod tests {
             portfolio: short_dur,
             scenarios: vec![rate_hike.clone()],
             correlation_adjustments: Some(false),
+            macro_scenarios: vec![],
+            macro_severity: None,
         };
         let long_input = StressTestInput {
             portfolio: long_dur,
             scenarios: vec![rate_hike],
             correlation_adjustments: Some(false),
+            macro_scenarios: vec![],
+            macro_severity: None,
         };
         let short_result = run_stress_test(&short_input).unwrap();
         let long_result = run_stress_test(&long_input).unwrap();
@@ -688,6 +748,8 @@ mod tests {
             portfolio,
             scenarios: vec![scenario],
             correlation_adjustments: Some(false),
+            macro_scenarios: vec![],
+            macro_severity: None,
         };
         let result = run_stress_test(&input).unwrap();
         // Impact = -0.03 * 5 * 0.5 = -0.075
@@ -721,6 +783,8 @@ mod tests {
             portfolio,
             scenarios: vec![scenario],
             correlation_adjustments: Some(false),
+            macro_scenarios: vec![],
+            macro_severity: None,
         };
         let result = run_stress_test(&input).unwrap();
         assert_eq!(
@@ -751,6 +815,8 @@ mod tests {
             portfolio,
             scenarios: vec![scenario],
             correlation_adjustments: Some(false),
+            macro_scenarios: vec![],
+            macro_severity: None,
         };
         let result = run_stress_test(&input).unwrap();
         assert_eq!(
@@ -789,6 +855,8 @@ mod tests {
             portfolio,
             scenarios: vec![scenario],
             correlation_adjustments: Some(false),
+            macro_scenarios: vec![],
+            macro_severity: None,
         };
         let result = run_stress_test(&input).unwrap();
         // RealEstate: equity*0.6 + rates*(-3) = -0.20*0.6 + 0.01*(-3) = -0.15
@@ -812,6 +880,8 @@ mod tests {
             portfolio,
             scenarios: vec![simple_equity_crash()],
             correlation_adjustments: Some(false),
+            macro_scenarios: vec![],
+            macro_severity: None,
         };
         let result = run_stress_test(&input).unwrap();
         // Alternative: equity * 0.4 = -0.30 * 0.4 = -0.12
@@ -847,6 +917,8 @@ mod tests {
             portfolio: diversified_portfolio(),
             scenarios: vec![scenario],
             correlation_adjustments: Some(false),
+            macro_scenarios: vec![],
+            macro_severity: None,
         };
         let result = run_stress_test(&input).unwrap();
         assert_eq!(result.result.scenario_results.len(), 1);
@@ -892,6 +964,8 @@ mod tests {
                 simple_equity_crash(), // -30%
             ],
             correlation_adjustments: Some(false),
+            macro_scenarios: vec![],
+            macro_severity: None,
         };
         let result = run_stress_test(&input).unwrap();
         assert!(!result.result.scenario_results[0].var_breach);
@@ -923,6 +997,8 @@ mod tests {
                 },
             ],
             correlation_adjustments: Some(false),
+            macro_scenarios: vec![],
+            macro_severity: None,
         };
         let result = run_stress_test(&input).unwrap();
         // Average = (-0.10 + -0.30) / 2 = -0.20
@@ -944,6 +1020,8 @@ mod tests {
             portfolio: vec![],
             scenarios: vec![simple_equity_crash()],
             correlation_adjustments: None,
+            macro_scenarios: vec![],
+            macro_severity: None,
         };
         assert!(run_stress_test(&input).is_err());
     }
@@ -954,6 +1032,8 @@ mod tests {
             portfolio: single_equity_portfolio(),
             scenarios: vec![],
             correlation_adjustments: None,
+            macro_scenarios: vec![],
+            macro_severity: None,
         };
         assert!(run_stress_test(&input).is_err());
     }
@@ -972,6 +1052,8 @@ mod tests {
             portfolio,
             scenarios: vec![simple_equity_crash()],
             correlation_adjustments: None,
+            macro_scenarios: vec![],
+            macro_severity: None,
         };
         assert!(run_stress_test(&input).is_err());
     }
@@ -1000,6 +1082,8 @@ mod tests {
             portfolio,
             scenarios: vec![scenario],
             correlation_adjustments: Some(false),
+            macro_scenarios: vec![],
+            macro_severity: None,
         };
         let result = run_stress_test(&input).unwrap();
         assert_eq!(
@@ -1024,6 +1108,8 @@ mod tests {
             portfolio,
             scenarios: vec![simple_equity_crash()],
             correlation_adjustments: Some(false),
+            macro_scenarios: vec![],
+            macro_severity: None,
         };
         let result = run_stress_test(&input).unwrap();
         // Default beta=1.0, so impact = -0.30
@@ -1055,6 +1141,8 @@ mod tests {
             portfolio,
             scenarios: vec![scenario],
             correlation_adjustments: Some(false),
+            macro_scenarios: vec![],
+            macro_severity: None,
         };
         let result = run_stress_test(&input).unwrap();
         // Default duration=5, impact = -5 * 0.01 = -0.05
@@ -1063,4 +1151,77 @@ mod tests {
             dec!(-0.05)
         );
     }
+
+    #[test]
+    fn test_macro_library_scenarios() {
+        let portfolio = vec![
+            PortfolioPosition {
+                name: "Equities".into(),
+                weight: dec!(0.6),
+                asset_class: AssetClass::Equity,
+                beta: None,
+                duration: None,
+                fx_exposure: None,
+            },
+            PortfolioPosition {
+                name: "Bonds".into(),
+                weight: dec!(0.4),
+                asset_class: AssetClass::FixedIncome,
+                beta: None,
+                duration: Some(dec!(6)),
+                fx_exposure: None,
+            },
+        ];
+        let input = StressTestInput {
+            portfolio,
+            scenarios: vec![],
+            correlation_adjustments: Some(false),
+            macro_scenarios: vec![MacroScenario::Gfc2008, MacroScenario::RateShock300bp],
+            macro_severity: None,
+        };
+        let out = run_stress_test(&input).unwrap().result;
+        assert_eq!(out.scenario_results.len(), 2);
+        // GFC: 0.6 * -0.38 + 0.4 * (-6 * -0.02) = -0.228 + 0.048
+        assert_eq!(out.scenario_results[0].portfolio_impact, dec!(-0.180));
+        // Rate shock: 0.6 * -0.15 + 0.4 * (-6 * 0.03) = -0.09 - 0.072
+        assert_eq!(out.scenario_results[1].portfolio_impact, dec!(-0.162));
+        assert_eq!(out.worst_case.scenario_name, "GFC 2008");
+    }
+
+    #[test]
+    fn test_macro_severity_and_custom_scenarios_combined() {
+        let portfolio = vec![PortfolioPosition {
+            name: "Equities".into(),
+            weight: Decimal::ONE,
+            asset_class: AssetClass::Equity,
+            beta: None,
+            duration: None,
+            fx_exposure: None,
+        }];
+        let input = StressTestInput {
+            portfolio,
+            scenarios: vec![StressScenario {
+                name: "Custom".into(),
+                scenario_type: ScenarioType::Hypothetical,
+                shocks: vec![MarketShock {
+                    factor: "equity_market".into(),
+                    shock_pct: dec!(-0.05),
+                }],
+            }],
+            correlation_adjustments: Some(false),
+            macro_scenarios: vec![MacroScenario::Covid2020],
+            macro_severity: Some(dec!(0.5)),
+        };
+        let out = run_stress_test(&input).unwrap().result;
+        assert_eq!(out.scenario_results[0].scenario_name, "Custom");
+        assert_eq!(out.scenario_results[1].portfolio_impact, dec!(-0.17));
+    }
+
+    #[test]
+    fn test_macro_scenario_type_mapping() {
+        let gfc = StressScenario::from(&MacroScenario::Gfc2008.definition(Decimal::ONE));
+        let shock = StressScenario::from(&MacroScenario::RateShock300bp.definition(Decimal::ONE));
+        assert_eq!(gfc.scenario_type, ScenarioType::Historical);
+        assert_eq!(shock.scenario_type, ScenarioType::Hypothetical);
+    }
 }
@@ -0,0 +1,635 @@
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+
+use crate::{CorpFinanceError, CorpFinanceResult};
+
+// ---------------------------------------------------------------------------
+// Decimal math helpers
+// ---------------------------------------------------------------------------
+
+/// Newton's method square root (20 iterations).
+fn sqrt_decimal(val: Decimal) -> Decimal {
+    if val <= Decimal::ZERO {
+        return Decimal::ZERO;
+    }
+    let mut guess = val / dec!(2);
+    if guess == Decimal::ZERO {
+        guess = Decimal::ONE;
+    }
+    for _ in 0..20 {
+        guess = (guess + val / guess) / dec!(2);
+    }
+    guess
+}
+
+// ---------------------------------------------------------------------------
+// Types
+// ---------------------------------------------------------------------------
+
+/// Model used to estimate normal (expected) returns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ExpectedReturnModel {
+    /// R = alpha + beta * Rm, fitted by OLS over the estimation window
+    MarketModel,
+    /// R = Rm (alpha = 0, beta = 1)
+    MarketAdjusted,
+    /// R = mean security return over the estimation window
+    MeanAdjusted,
+}
+
+/// One event in the sample: a security's returns aligned with the market.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventObservation {
+    /// Security or event identifier
+    pub name: String,
+    /// Index of the event date (day 0) within the return series
+    pub event_index: usize,
+    /// Daily security returns
+    pub security_returns: Vec<Decimal>,
+    /// Daily market (benchmark) returns, same length and dates
+    pub market_returns: Vec<Decimal>,
+}
+
+/// Input for an event study.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventStudyInput {
+    /// Events in the sample
+    pub events: Vec<EventObservation>,
+    /// First day of the estimation window relative to day 0 (e.g. -250)
+    pub estimation_start: i64,
+    /// Last day of the estimation window relative to day 0 (e.g. -11)
+    pub estimation_end: i64,
+    /// First day of the event window relative to day 0 (e.g. -1)
+    pub event_window_start: i64,
+    /// Last day of the event window relative to day 0 (e.g. +1)
+    pub event_window_end: i64,
+    /// Normal-return model
+    pub model: ExpectedReturnModel,
+}
+
+/// Estimation and abnormal-return results for one event.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventResult {
+    pub name: String,
+    pub alpha: Decimal,
+    pub beta: Decimal,
+    /// Standard deviation of estimation-window residuals
+    pub residual_std: Decimal,
+    pub estimation_days: usize,
+    /// Abnormal returns for each event-window day
+    pub abnormal_returns: Vec<Decimal>,
+    /// Cumulative abnormal return over the event window
+    pub car: Decimal,
+    /// CAR / (residual_std * sqrt(event days))
+    pub car_t_stat: Decimal,
+    /// Standardised CAR (sum of prediction-error adjusted SARs / sqrt(T))
+    pub scar: Decimal,
+}
+
+/// Cross-sectional statistics for one event-window day.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DailyAbnormalReturn {
+    /// Day relative to the event date
+    pub day: i64,
+    /// Average abnormal return across events
+    pub aar: Decimal,
+    /// Cumulative average abnormal return through this day
+    pub caar: Decimal,
+    /// AAR / (cross-sectional std / sqrt(N))
+    pub t_stat_cross_sectional: Decimal,
+    /// AAR / (sqrt(sum of estimation variances) / N)
+    pub t_stat_time_series: Decimal,
+}
+
+/// Output of an event study.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventStudyOutput {
+    pub event_results: Vec<EventResult>,
+    pub daily: Vec<DailyAbnormalReturn>,
+    /// Cumulative average abnormal return over the event window
+    pub caar: Decimal,
+    /// Cross-sectional t-statistic of CARs
+    pub caar_t_stat: Decimal,
+    /// Patell (1976) Z on standardised CARs
+    pub patell_z: Decimal,
+    /// Boehmer, Musumeci & Poulsen (1991) standardised cross-sectional t
+    pub bmp_t_stat: Decimal,
+    /// Share of events with positive CAR
+    pub pct_positive_car: Decimal,
+    /// True if |BMP t| exceeds 1.96
+    pub significant_at_5pct: bool,
+}
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+const MIN_ESTIMATION_DAYS: usize = 10;
+const CRITICAL_VALUE_5PCT: Decimal = dec!(1.96);
+
+// ---------------------------------------------------------------------------
+// Public API
+// ---------------------------------------------------------------------------
+
+/// Run a standard event study.
+///
+/// Fits the normal-return model over the estimation window for each event,
+/// computes abnormal and cumulative abnormal returns over the event window,
+/// and tests significance with cross-sectional, Patell and BMP statistics.
+pub fn run_event_study(input: &EventStudyInput) -> CorpFinanceResult<EventStudyOutput> {
+    validate_input(input)?;
+
+    let event_days = (input.event_window_end - input.event_window_start + 1) as usize;
+
+    let mut event_results: Vec<EventResult> = Vec::with_capacity(input.events.len());
+    let mut variances: Vec<Decimal> = Vec::with_capacity(input.events.len());
+    let mut patell_dof_sum = Decimal::ZERO;
+
+    for ev in &input.events {
+        let (est_sec, est_mkt) = window_slices(ev, input.estimation_start, input.estimation_end)?;
+        let (win_sec, win_mkt) =
+            window_slices(ev, input.event_window_start, input.event_window_end)?;
+
+        let fit = fit_model(input.model, est_sec, est_mkt);
+        let l = Decimal::from(est_sec.len() as i64);
+
+        let mut abnormal_returns = Vec::with_capacity(event_days);
+        let mut sar_sum = Decimal::ZERO;
+        for (r, rm) in win_sec.iter().zip(win_mkt.iter()) {
+            let expected = fit.alpha + fit.beta * *rm;
+            let ar = *r - expected;
+            abnormal_returns.push(ar);
+
+            // Prediction-error adjustment for the market model
+            let adj = if input.model == ExpectedReturnModel::MarketModel && fit.sxx > Decimal::ZERO
+            {
+                let d = *rm - fit.mean_market;
+                Decimal::ONE + Decimal::ONE / l + d * d / fit.sxx
+            } else {
+                Decimal::ONE + Decimal::ONE / l
+            };
+            let sd = fit.residual_std * sqrt_decimal(adj);
+            if sd > Decimal::ZERO {
+                sar_sum += ar / sd;
+            }
+        }
+
+        let car: Decimal = abnormal_returns.iter().copied().sum();
+        let t_sqrt = sqrt_decimal(Decimal::from(event_days as i64));
+        let car_t_stat = if fit.residual_std > Decimal::ZERO {
+            car / (fit.residual_std * t_sqrt)
+        } else {
+            Decimal::ZERO
+        };
+        let scar = sar_sum / t_sqrt;
+
+        // Variance of the standardised residual under a t distribution
+        if est_sec.len() > 4 {
+            patell_dof_sum += (l - dec!(2)) / (l - dec!(4));
+        }
+        variances.push(fit.residual_std * fit.residual_std);
+
+        event_results.push(EventResult {
+            name: ev.name.clone(),
+            alpha: fit.alpha,
+            beta: fit.beta,
+            residual_std: fit.residual_std,
+            estimation_days: est_sec.len(),
+            abnormal_returns,
+            car,
+            car_t_stat,
+            scar,
+        });
+    }
+
+    // ------------------------------------------------------------------
+    // Cross-sectional aggregation by event day
+    // ------------------------------------------------------------------
+    let n = event_results.len();
+    let n_dec = Decimal::from(n as i64);
+    let sum_var: Decimal = variances.iter().copied().sum();
+    let ts_denominator = sqrt_decimal(sum_var) / n_dec;
+
+    let mut daily: Vec<DailyAbnormalReturn> = Vec::with_capacity(event_days);
+    let mut caar = Decimal::ZERO;
+    for k in 0..event_days {
+        let ars: Vec<Decimal> = event_results
+            .iter()
+            .map(|e| e.abnormal_returns[k])
+            .collect();
+        let (aar, sd) = mean_and_std(&ars);
+        caar += aar;
+        let t_stat_cross_sectional = if sd > Decimal::ZERO {
+            aar / (sd / sqrt_decimal(n_dec))
+        } else {
+            Decimal::ZERO
+        };
+        let t_stat_time_series = if ts_denominator > Decimal::ZERO {
+            aar / ts_denominator
+        } else {
+            Decimal::ZERO
+        };
+        daily.push(DailyAbnormalReturn {
+            day: input.event_window_start + k as i64,
+            aar,
+            caar,
+            t_stat_cross_sectional,
+            t_stat_time_series,
+        });
+    }
+
+    // ------------------------------------------------------------------
+    // Test statistics on CARs
+    // ------------------------------------------------------------------
+    let cars: Vec<Decimal> = event_results.iter().map(|e| e.car).collect();
+    let (mean_car, sd_car) = mean_and_std(&cars);
+    let caar_t_stat = if sd_car > Decimal::ZERO {
+        mean_car / (sd_car / sqrt_decimal(n_dec))
+    } else {
+        Decimal::ZERO
+    };
+
+    let scars: Vec<Decimal> = event_results.iter().map(|e| e.scar).collect();
+    let sum_scar: Decimal = scars.iter().copied().sum();
+    let patell_z = if patell_dof_sum > Decimal::ZERO {
+        sum_scar / sqrt_decimal(patell_dof_sum)
+    } else {
+        Decimal::ZERO
+    };
+    let (mean_scar, sd_scar) = mean_and_std(&scars);
+    let bmp_t_stat = if sd_scar > Decimal::ZERO {
+        mean_scar / (sd_scar / sqrt_decimal(n_dec))
+    } else {
+        Decimal::ZERO
+    };
+
+    let positives = cars.iter().filter(|c| **c > Decimal::ZERO).count();
+    let pct_positive_car = Decimal::from(positives as i64) / n_dec;
+
+    Ok(EventStudyOutput {
+        event_results,
+        daily,
+        caar,
+        caar_t_stat,
+        patell_z,
+        bmp_t_stat,
+        pct_positive_car,
+        significant_at_5pct: bmp_t_stat.abs() > CRITICAL_VALUE_5PCT,
+    })
+}
+
+// ---------------------------------------------------------------------------
+// Internal helpers
+// ---------------------------------------------------------------------------
+
+struct ModelFit {
+    alpha: Decimal,
+    beta: Decimal,
+    residual_std: Decimal,
+    mean_market: Decimal,
+    sxx: Decimal,
+}
+
+fn validate_input(input: &EventStudyInput) -> CorpFinanceResult<()> {
+    if input.events.is_empty() {
+        return Err(CorpFinanceError::InsufficientData(
+            "At least one event is required".into(),
+        ));
+    }
+    if input.estimation_start > input.estimation_end {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "estimation_start".into(),
+            reason: "must not be after estimation_end".into(),
+        });
+    }
+    if input.event_window_start > input.event_window_end {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "event_window_start".into(),
+            reason: "must not be after event_window_end".into(),
+        });
+    }
+    if input.estimation_end >= input.event_window_start {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "estimation_end".into(),
+            reason: "estimation window must end before the event window starts".into(),
+        });
+    }
+    let est_days = (input.estimation_end - input.estimation_start + 1) as usize;
+    if est_days < MIN_ESTIMATION_DAYS {
+        return Err(CorpFinanceError::InsufficientData(format!(
+            "Estimation window has {} days; at least {} required",
+            est_days, MIN_ESTIMATION_DAYS
+        )));
+    }
+    for ev in &input.events {
+        if ev.security_returns.len() != ev.market_returns.len() {
+            return Err(CorpFinanceError::InvalidInput {
+                field: format!("events[{}].market_returns", ev.name),
+                reason: format!(
+                    "length {} differs from security_returns length {}",
+                    ev.market_returns.len(),
+                    ev.security_returns.len()
+                ),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Slice security and market returns for `[start, end]` relative to day 0.
+fn window_slices(
+    ev: &EventObservation,
+    start: i64,
+    end: i64,
+) -> CorpFinanceResult<(&[Decimal], &[Decimal])> {
+    let from = ev.event_index as i64 + start;
+    let to = ev.event_index as i64 + end;
+    if from < 0 || to >= ev.security_returns.len() as i64 {
+        return Err(CorpFinanceError::InsufficientData(format!(
+            "Event '{}': window [{}, {}] falls outside the {} available returns",
+            ev.name,
+            start,
+            end,
+            ev.security_returns.len()
+        )));
+    }
+    let (from, to) = (from as usize, to as usize + 1);
+    Ok((&ev.security_returns[from..to], &ev.market_returns[from..to]))
+}
+
+/// Fit the normal-return model and return its residual standard deviation.
+fn fit_model(model: ExpectedReturnModel, sec: &[Decimal], mkt: &[Decimal]) -> ModelFit {
+    let n = Decimal::from(sec.len() as i64);
+    let mean_sec: Decimal = sec.iter().copied().sum::<Decimal>() / n;
+    let mean_market: Decimal = mkt.iter().copied().sum::<Decimal>() / n;
+
+    let mut sxx = Decimal::ZERO;
+    let mut sxy = Decimal::ZERO;
+    for (r, rm) in sec.iter().zip(mkt.iter()) {
+        let dx = *rm - mean_market;
+        sxx += dx * dx;
+        sxy += dx * (*r - mean_sec);
+    }
+
+    let (alpha, beta, params) = match model {
+        ExpectedReturnModel::MarketModel => {
+            let beta = if sxx > Decimal::ZERO {
+                sxy / sxx
+            } else {
+                Decimal::ZERO
+            };
+            (mean_sec - beta * mean_market, beta, 2)
+        }
+        ExpectedReturnModel::MarketAdjusted => (Decimal::ZERO, Decimal::ONE, 0),
+        ExpectedReturnModel::MeanAdjusted => (mean_sec, Decimal::ZERO, 1),
+    };
+
+    let ssr: Decimal = sec
+        .iter()
+        .zip(mkt.iter())
+        .map(|(r, rm)| {
+            let e = *r - alpha - beta * *rm;
+            e * e
+        })
+        .sum();
+    let dof = Decimal::from((sec.len() - params) as i64);
+    let residual_std = if dof > Decimal::ZERO {
+        sqrt_decimal(ssr / dof)
+    } else {
+        Decimal::ZERO
+    };
+
+    ModelFit {
+        alpha,
+        beta,
+        residual_std,
+        mean_market,
+        sxx,
+    }
+}
+
+/// Sample mean and standard deviation (n - 1 denominator).
+fn mean_and_std(values: &[Decimal]) -> (Decimal, Decimal) {
+    let n = values.len();
+    if n == 0 {
+        return (Decimal::ZERO, Decimal::ZERO);
+    }
+    let n_dec = Decimal::from(n as i64);
+    let mean: Decimal = values.iter().copied().sum::<Decimal>() / n_dec;
+    if n < 2 {
+        return (mean, Decimal::ZERO);
+    }
+    let var: Decimal = values
+        .iter()
+        .map(|v| {
+            let d = *v - mean;
+            d * d
+        })
+        .sum::<Decimal>()
+        / (n_dec - Decimal::ONE);
+    (mean, sqrt_decimal(var))
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    /// Deterministic market returns with some dispersion.
+    fn market_series(n: usize) -> Vec<Decimal> {
+        (0..n)
+            .map(|i| match i % 4 {
+                0 => dec!(0.010),
+                1 => dec!(-0.005),
+                2 => dec!(0.003),
+                _ => dec!(-0.008),
+            })
+            .collect()
+    }
+
+    /// Security = 0.0005 + 1.2 * market + small noise, with a jump on day 0.
+    fn make_event(name: &str, jump: Decimal, noise_phase: usize) -> EventObservation {
+        let n = 60;
+        let event_index = 50;
+        let market = market_series(n);
+        let security = market
+            .iter()
+            .enumerate()
+            .map(|(i, rm)| {
+                let noise = match (i + noise_phase) % 3 {
+                    0 => dec!(0.001),
+                    1 => dec!(-0.001),
+                    _ => dec!(0),
+                };
+                let base = dec!(0.0005) + dec!(1.2) * *rm + noise;
+                if i == event_index {
+                    base + jump
+                } else {
+                    base
+                }
+            })
+            .collect();
+        EventObservation {
+            name: name.into(),
+            event_index,
+            security_returns: security,
+            market_returns: market,
+        }
+    }
+
+    fn default_input() -> EventStudyInput {
+        EventStudyInput {
+            events: vec![
+                make_event("Deal A", dec!(0.05), 0),
+                make_event("Deal B", dec!(0.04), 1),
+                make_event("Deal C", dec!(0.06), 2),
+            ],
+            estimation_start: -45,
+            estimation_end: -6,
+            event_window_start: -1,
+            event_window_end: 1,
+            model: ExpectedReturnModel::MarketModel,
+        }
+    }
+
+    #[test]
+    fn test_market_model_beta_recovered() {
+        let out = run_event_study(&default_input()).unwrap();
+        for e in &out.event_results {
+            assert!((e.beta - dec!(1.2)).abs() < dec!(0.05));
+            assert_eq!(e.estimation_days, 40);
+        }
+    }
+
+    #[test]
+    fn test_abnormal_return_on_event_day() {
+        let out = run_event_study(&default_input()).unwrap();
+        let a = &out.event_results[0];
+        assert_eq!(a.abnormal_returns.len(), 3);
+        // Day 0 abnormal return close to the 5% jump
+        assert!((a.abnormal_returns[1] - dec!(0.05)).abs() < dec!(0.003));
+    }
+
+    #[test]
+    fn test_car_is_sum_of_abnormal_returns() {
+        let out = run_event_study(&default_input()).unwrap();
+        for e in &out.event_results {
+            let sum: Decimal = e.abnormal_returns.iter().copied().sum();
+            assert_eq!(e.car, sum);
+        }
+    }
+
+    #[test]
+    fn test_caar_equals_mean_car() {
+        let out = run_event_study(&default_input()).unwrap();
+        let mean: Decimal = out.event_results.iter().map(|e| e.car).sum::<Decimal>() / dec!(3);
+        assert!((out.caar - mean).abs() < dec!(0.0000001));
+        assert_eq!(out.daily.last().unwrap().caar, out.caar);
+    }
+
+    #[test]
+    fn test_daily_days_relative_to_event() {
+        let out = run_event_study(&default_input()).unwrap();
+        let days: Vec<i64> = out.daily.iter().map(|d| d.day).collect();
+        assert_eq!(days, vec![-1, 0, 1]);
+    }
+
+    #[test]
+    fn test_significant_positive_event() {
+        let out = run_event_study(&default_input()).unwrap();
+        assert!(out.caar > dec!(0.03));
+        assert!(out.bmp_t_stat > dec!(1.96));
+        assert!(out.patell_z > dec!(1.96));
+        assert!(out.significant_at_5pct);
+        assert_eq!(out.pct_positive_car, dec!(1));
+        assert!(out.daily[1].t_stat_time_series > dec!(1.96));
+    }
+
+    #[test]
+    fn test_no_event_not_significant() {
+        let mut input = default_input();
+        input.events = vec![
+            make_event("A", dec!(0), 0),
+            make_event("B", dec!(0), 1),
+            make_event("C", dec!(0), 2),
+        ];
+        let out = run_event_study(&input).unwrap();
+        assert!(out.caar.abs() < dec!(0.005));
+        assert!(out.daily[1].t_stat_time_series.abs() < dec!(1.96));
+    }
+
+    #[test]
+    fn test_market_adjusted_model() {
+        let mut input = default_input();
+        input.model = ExpectedReturnModel::MarketAdjusted;
+        let out = run_event_study(&input).unwrap();
+        let e = &out.event_results[0];
+        assert_eq!(e.alpha, dec!(0));
+        assert_eq!(e.beta, dec!(1));
+        let ev = &input.events[0];
+        let expected = ev.security_returns[50] - ev.market_returns[50];
+        assert_eq!(e.abnormal_returns[1], expected);
+    }
+
+    #[test]
+    fn test_mean_adjusted_model() {
+        let mut input = default_input();
+        input.model = ExpectedReturnModel::MeanAdjusted;
+        let out = run_event_study(&input).unwrap();
+        let e = &out.event_results[0];
+        assert_eq!(e.beta, dec!(0));
+        let ev = &input.events[0];
+        let mean: Decimal = ev.security_returns[5..45].iter().copied().sum::<Decimal>() / dec!(40);
+        assert_eq!(e.alpha, mean);
+    }
+
+    #[test]
+    fn test_single_event_cross_sectional_stats_zero() {
+        let mut input = default_input();
+        input.events.truncate(1);
+        let out = run_event_study(&input).unwrap();
+        assert_eq!(out.caar_t_stat, dec!(0));
+        assert_eq!(out.bmp_t_stat, dec!(0));
+        assert!(out.patell_z > dec!(0));
+    }
+
+    #[test]
+    fn test_window_out_of_range() {
+        let mut input = default_input();
+        input.estimation_start = -100;
+        assert!(run_event_study(&input).is_err());
+    }
+
+    #[test]
+    fn test_overlapping_windows_error() {
+        let mut input = default_input();
+        input.estimation_end = 0;
+        assert!(run_event_study(&input).is_err());
+    }
+
+    #[test]
+    fn test_short_estimation_window_error() {
+        let mut input = default_input();
+        input.estimation_start = -10;
+        assert!(run_event_study(&input).is_err());
+    }
+
+    #[test]
+    fn test_mismatched_lengths_error() {
+        let mut input = default_input();
+        input.events[0].market_returns.pop();
+        assert!(run_event_study(&input).is_err());
+    }
+
+    #[test]
+    fn test_empty_events_error() {
+        let mut input = default_input();
+        input.events.clear();
+        assert!(run_event_study(&input).is_err());
+    }
+}
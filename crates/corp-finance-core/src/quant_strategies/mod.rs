@@ -1,2 +1,3 @@
+pub mod event_study;
 pub mod momentum;
 pub mod pairs_trading;
@@ -0,0 +1,515 @@
+//! Credit rating scale utilities.
+//!
+//! A canonical 22-notch rating scale shared across modules:
+//! - Parsing and formatting in S&P, Moody's and Fitch notation
+//! - Notching arithmetic (upgrade / downgrade / notch distance)
+//! - Moody's rating factors and WARF (Weighted Average Rating Factor)
+//! - Versioned rating-to-default-probability lookup tables
+//!
+//! Ratings serialize in S&P/Fitch notation ("BBB-") and deserialize from
+//! any supported agency notation ("BBB-", "Baa3").
+
+use rust_decimal::Decimal;
+use rust_decimal::MathematicalOps;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use crate::error::CorpFinanceError;
+use crate::CorpFinanceResult;
+
+// ---------------------------------------------------------------------------
+// Scale
+// ---------------------------------------------------------------------------
+
+/// Rating agency notation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RatingAgency {
+    SP,
+    Moodys,
+    Fitch,
+}
+
+/// Canonical long-term issuer rating, ordered from best (AAA) to worst (D).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum Rating {
+    AAA,
+    AAPlus,
+    AA,
+    AAMinus,
+    APlus,
+    A,
+    AMinus,
+    BBBPlus,
+    BBB,
+    BBBMinus,
+    BBPlus,
+    BB,
+    BBMinus,
+    BPlus,
+    B,
+    BMinus,
+    CCCPlus,
+    CCC,
+    CCCMinus,
+    CC,
+    C,
+    D,
+}
+
+/// All ratings in scale order.
+pub const RATING_SCALE: [Rating; 22] = [
+    Rating::AAA,
+    Rating::AAPlus,
+    Rating::AA,
+    Rating::AAMinus,
+    Rating::APlus,
+    Rating::A,
+    Rating::AMinus,
+    Rating::BBBPlus,
+    Rating::BBB,
+    Rating::BBBMinus,
+    Rating::BBPlus,
+    Rating::BB,
+    Rating::BBMinus,
+    Rating::BPlus,
+    Rating::B,
+    Rating::BMinus,
+    Rating::CCCPlus,
+    Rating::CCC,
+    Rating::CCCMinus,
+    Rating::CC,
+    Rating::C,
+    Rating::D,
+];
+
+/// S&P / Fitch notation, in scale order.
+const SP_NOTATION: [&str; 22] = [
+    "AAA", "AA+", "AA", "AA-", "A+", "A", "A-", "BBB+", "BBB", "BBB-", "BB+", "BB", "BB-", "B+",
+    "B", "B-", "CCC+", "CCC", "CCC-", "CC", "C", "D",
+];
+
+/// Moody's notation, in scale order. Moody's has no separate default grade;
+/// CC and C both map to Ca and D maps to C.
+const MOODYS_NOTATION: [&str; 22] = [
+    "Aaa", "Aa1", "Aa2", "Aa3", "A1", "A2", "A3", "Baa1", "Baa2", "Baa3", "Ba1", "Ba2", "Ba3",
+    "B1", "B2", "B3", "Caa1", "Caa2", "Caa3", "Ca", "Ca", "C",
+];
+
+/// Moody's idealized rating factors, in scale order.
+const MOODYS_RATING_FACTORS: [Decimal; 22] = [
+    dec!(1),
+    dec!(10),
+    dec!(20),
+    dec!(40),
+    dec!(70),
+    dec!(120),
+    dec!(180),
+    dec!(260),
+    dec!(360),
+    dec!(610),
+    dec!(940),
+    dec!(1350),
+    dec!(1766),
+    dec!(2220),
+    dec!(2720),
+    dec!(3490),
+    dec!(4770),
+    dec!(6500),
+    dec!(8070),
+    dec!(10000),
+    dec!(10000),
+    dec!(10000),
+];
+
+impl Rating {
+    /// Position on the scale: 0 = AAA, 21 = D.
+    pub fn notch(self) -> u8 {
+        self as u8
+    }
+
+    /// Rating at a scale position (0 = AAA).
+    pub fn from_notch(notch: u8) -> Option<Rating> {
+        RATING_SCALE.get(notch as usize).copied()
+    }
+
+    /// Parse a rating in S&P, Fitch or Moody's notation.
+    ///
+    /// Matching is case-insensitive for Moody's notation; a trailing
+    /// structured-finance suffix ("(sf)") is ignored. "SD" and "RD" parse as D.
+    /// A bare "C" is read as the S&P/Fitch grade.
+    pub fn parse(s: &str) -> CorpFinanceResult<Rating> {
+        let trimmed = s.trim();
+        let trimmed = trimmed
+            .strip_suffix("(sf)")
+            .or_else(|| trimmed.strip_suffix("(SF)"))
+            .unwrap_or(trimmed)
+            .trim();
+        if let Some(i) = SP_NOTATION.iter().position(|n| *n == trimmed) {
+            return Ok(RATING_SCALE[i]);
+        }
+        if matches!(trimmed, "SD" | "RD") {
+            return Ok(Rating::D);
+        }
+        // First match wins so "Ca" resolves to CC.
+        if let Some(i) = MOODYS_NOTATION
+            .iter()
+            .position(|n| n.eq_ignore_ascii_case(trimmed))
+        {
+            return Ok(RATING_SCALE[i]);
+        }
+        let upper = trimmed.to_uppercase();
+        if let Some(i) = SP_NOTATION.iter().position(|n| *n == upper) {
+            return Ok(RATING_SCALE[i]);
+        }
+        Err(CorpFinanceError::InvalidInput {
+            field: "rating".into(),
+            reason: format!("Unrecognised rating '{s}'"),
+        })
+    }
+
+    /// Rating in the given agency's notation.
+    pub fn to_agency(self, agency: RatingAgency) -> &'static str {
+        match agency {
+            RatingAgency::SP | RatingAgency::Fitch => SP_NOTATION[self as usize],
+            RatingAgency::Moodys => MOODYS_NOTATION[self as usize],
+        }
+    }
+
+    /// Letter grade without modifiers: "AAA", "AA", "A", "BBB", "BB", "B",
+    /// "CCC" (covering CCC+ through C) or "D".
+    pub fn letter_grade(self) -> &'static str {
+        match self {
+            Rating::AAA => "AAA",
+            Rating::AAPlus | Rating::AA | Rating::AAMinus => "AA",
+            Rating::APlus | Rating::A | Rating::AMinus => "A",
+            Rating::BBBPlus | Rating::BBB | Rating::BBBMinus => "BBB",
+            Rating::BBPlus | Rating::BB | Rating::BBMinus => "BB",
+            Rating::BPlus | Rating::B | Rating::BMinus => "B",
+            Rating::CCCPlus | Rating::CCC | Rating::CCCMinus | Rating::CC | Rating::C => "CCC",
+            Rating::D => "D",
+        }
+    }
+
+    /// BBB- or better.
+    pub fn is_investment_grade(self) -> bool {
+        self <= Rating::BBBMinus
+    }
+
+    pub fn is_default(self) -> bool {
+        self == Rating::D
+    }
+
+    /// Downgrade by `notches`, floored at C (notching never produces a default).
+    /// Defaulted ratings are returned unchanged.
+    pub fn downgrade(self, notches: u8) -> Rating {
+        if self.is_default() {
+            return self;
+        }
+        let target = (self.notch() as u16 + notches as u16).min(Rating::C.notch() as u16);
+        RATING_SCALE[target as usize]
+    }
+
+    /// Upgrade by `notches`, capped at AAA. Defaulted ratings are returned unchanged.
+    pub fn upgrade(self, notches: u8) -> Rating {
+        if self.is_default() {
+            return self;
+        }
+        RATING_SCALE[self.notch().saturating_sub(notches) as usize]
+    }
+
+    /// Signed notch distance to `other`; positive when `other` is worse.
+    pub fn notches_to(self, other: Rating) -> i32 {
+        other.notch() as i32 - self.notch() as i32
+    }
+
+    /// Moody's idealized rating factor (Aaa = 1 ... Ca/C = 10,000).
+    pub fn rating_factor(self) -> Decimal {
+        MOODYS_RATING_FACTORS[self as usize]
+    }
+
+    /// Rating implied by a rating factor: the lowest rating whose factor does
+    /// not exceed `factor` (the usual WARF-to-rating convention).
+    pub fn from_rating_factor(factor: Decimal) -> Rating {
+        RATING_SCALE[..=Rating::C.notch() as usize]
+            .iter()
+            .rev()
+            .find(|r| r.rating_factor() <= factor)
+            .copied()
+            .unwrap_or(Rating::AAA)
+    }
+
+    /// One-year probability of default from the given table.
+    pub fn default_probability(self, table: PdTable) -> Decimal {
+        match table {
+            PdTable::LongRunAverageV1 => LONG_RUN_PD_V1[self as usize],
+            PdTable::MoodysIdealizedV1 => {
+                if self.is_default() {
+                    return Decimal::ONE;
+                }
+                // Rating factors are 10-year idealized cumulative PDs x 10,000;
+                // annualize assuming a constant hazard.
+                let cumulative = (self.rating_factor() / dec!(10000)).min(dec!(0.9999));
+                Decimal::ONE - (Decimal::ONE - cumulative).powd(dec!(0.1))
+            }
+        }
+    }
+}
+
+impl fmt::Display for Rating {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.to_agency(RatingAgency::SP))
+    }
+}
+
+impl TryFrom<String> for Rating {
+    type Error = CorpFinanceError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        Rating::parse(&s)
+    }
+}
+
+impl From<Rating> for String {
+    fn from(r: Rating) -> Self {
+        r.to_string()
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Default probability tables
+// ---------------------------------------------------------------------------
+
+/// Versioned rating-to-PD lookup tables. Tables are never edited in place;
+/// revised calibrations are added as new versions so results stay reproducible.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PdTable {
+    /// Long-run average one-year corporate default rates by notch, rounded
+    /// from published agency default studies, with a 1bp floor at the top.
+    #[default]
+    LongRunAverageV1,
+    /// One-year PDs implied by Moody's idealized 10-year rating factors.
+    MoodysIdealizedV1,
+}
+
+const LONG_RUN_PD_V1: [Decimal; 22] = [
+    dec!(0.0001),
+    dec!(0.0002),
+    dec!(0.0002),
+    dec!(0.0003),
+    dec!(0.0005),
+    dec!(0.0006),
+    dec!(0.0007),
+    dec!(0.0010),
+    dec!(0.0015),
+    dec!(0.0024),
+    dec!(0.0030),
+    dec!(0.0050),
+    dec!(0.0090),
+    dec!(0.0180),
+    dec!(0.0290),
+    dec!(0.0550),
+    dec!(0.1500),
+    dec!(0.2500),
+    dec!(0.3500),
+    dec!(0.5000),
+    dec!(0.7000),
+    dec!(1),
+];
+
+/// The full table for a version, in scale order.
+pub fn pd_table(table: PdTable) -> Vec<(Rating, Decimal)> {
+    RATING_SCALE
+        .iter()
+        .map(|r| (*r, r.default_probability(table)))
+        .collect()
+}
+
+// ---------------------------------------------------------------------------
+// Portfolio measures
+// ---------------------------------------------------------------------------
+
+/// Weighted Average Rating Factor of `(rating, notional)` holdings.
+pub fn warf(holdings: &[(Rating, Decimal)]) -> CorpFinanceResult<Decimal> {
+    if holdings.iter().any(|(_, n)| *n < Decimal::ZERO) {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "holdings".into(),
+            reason: "Notionals cannot be negative".into(),
+        });
+    }
+    let total: Decimal = holdings.iter().map(|(_, n)| *n).sum();
+    if total.is_zero() {
+        return Err(CorpFinanceError::DivisionByZero {
+            context: "WARF total notional".into(),
+        });
+    }
+    Ok(holdings
+        .iter()
+        .map(|(r, n)| *n * r.rating_factor())
+        .sum::<Decimal>()
+        / total)
+}
+
+/// Notional-weighted one-year PD of `(rating, notional)` holdings.
+pub fn weighted_average_pd(
+    holdings: &[(Rating, Decimal)],
+    table: PdTable,
+) -> CorpFinanceResult<Decimal> {
+    let total: Decimal = holdings.iter().map(|(_, n)| *n).sum();
+    if total <= Decimal::ZERO {
+        return Err(CorpFinanceError::DivisionByZero {
+            context: "weighted average PD total notional".into(),
+        });
+    }
+    Ok(holdings
+        .iter()
+        .map(|(r, n)| *n * r.default_probability(table))
+        .sum::<Decimal>()
+        / total)
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scale_order() {
+        assert!(Rating::AAA < Rating::BBB);
+        assert!(Rating::CCC < Rating::D);
+        assert_eq!(Rating::AAA.notch(), 0);
+        assert_eq!(Rating::D.notch(), 21);
+        for (i, r) in RATING_SCALE.iter().enumerate() {
+            assert_eq!(Rating::from_notch(i as u8), Some(*r));
+        }
+        assert_eq!(Rating::from_notch(22), None);
+    }
+
+    #[test]
+    fn test_parse_sp_and_fitch() {
+        assert_eq!(Rating::parse("BBB-").unwrap(), Rating::BBBMinus);
+        assert_eq!(Rating::parse(" AA+ ").unwrap(), Rating::AAPlus);
+        assert_eq!(Rating::parse("bb").unwrap(), Rating::BB);
+        assert_eq!(Rating::parse("RD").unwrap(), Rating::D);
+        assert_eq!(Rating::parse("AAA(sf)").unwrap(), Rating::AAA);
+    }
+
+    #[test]
+    fn test_parse_moodys() {
+        assert_eq!(Rating::parse("Baa3").unwrap(), Rating::BBBMinus);
+        assert_eq!(Rating::parse("Aaa").unwrap(), Rating::AAA);
+        assert_eq!(Rating::parse("B2").unwrap(), Rating::B);
+        assert_eq!(Rating::parse("CAA1").unwrap(), Rating::CCCPlus);
+        assert_eq!(Rating::parse("Ca").unwrap(), Rating::CC);
+        assert_eq!(Rating::parse("C").unwrap(), Rating::C);
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert!(Rating::parse("ZZZ").is_err());
+        assert!(Rating::parse("").is_err());
+    }
+
+    #[test]
+    fn test_agency_round_trip() {
+        for r in RATING_SCALE {
+            assert_eq!(Rating::parse(r.to_agency(RatingAgency::SP)).unwrap(), r);
+            assert_eq!(Rating::parse(r.to_agency(RatingAgency::Fitch)).unwrap(), r);
+        }
+        assert_eq!(Rating::BBBMinus.to_agency(RatingAgency::Moodys), "Baa3");
+        assert_eq!(Rating::D.to_agency(RatingAgency::Moodys), "C");
+    }
+
+    #[test]
+    fn test_serde_accepts_any_notation() {
+        let r: Rating = serde_json::from_str("\"Ba2\"").unwrap();
+        assert_eq!(r, Rating::BB);
+        assert_eq!(serde_json::to_string(&r).unwrap(), "\"BB\"");
+        assert!(serde_json::from_str::<Rating>("\"XYZ\"").is_err());
+    }
+
+    #[test]
+    fn test_notching() {
+        assert_eq!(Rating::BBB.downgrade(2), Rating::BBPlus);
+        assert_eq!(Rating::BBB.upgrade(3), Rating::A);
+        assert_eq!(Rating::AA.upgrade(10), Rating::AAA);
+        assert_eq!(Rating::CCC.downgrade(10), Rating::C);
+        assert_eq!(Rating::D.upgrade(3), Rating::D);
+        assert_eq!(Rating::BBB.notches_to(Rating::BB), 3);
+        assert_eq!(Rating::BB.notches_to(Rating::BBB), -3);
+    }
+
+    #[test]
+    fn test_investment_grade_and_letter_grade() {
+        assert!(Rating::BBBMinus.is_investment_grade());
+        assert!(!Rating::BBPlus.is_investment_grade());
+        assert_eq!(Rating::AMinus.letter_grade(), "A");
+        assert_eq!(Rating::CC.letter_grade(), "CCC");
+        assert_eq!(Rating::D.letter_grade(), "D");
+    }
+
+    #[test]
+    fn test_rating_factors() {
+        assert_eq!(Rating::AAA.rating_factor(), dec!(1));
+        assert_eq!(Rating::BBB.rating_factor(), dec!(360));
+        assert_eq!(Rating::B.rating_factor(), dec!(2720));
+        assert_eq!(Rating::CCC.rating_factor(), dec!(6500));
+    }
+
+    #[test]
+    fn test_from_rating_factor() {
+        assert_eq!(Rating::from_rating_factor(dec!(2720)), Rating::B);
+        assert_eq!(Rating::from_rating_factor(dec!(2800)), Rating::B);
+        assert_eq!(Rating::from_rating_factor(dec!(2700)), Rating::BPlus);
+        assert_eq!(Rating::from_rating_factor(dec!(0.5)), Rating::AAA);
+        assert_eq!(Rating::from_rating_factor(dec!(20000)), Rating::C);
+    }
+
+    #[test]
+    fn test_warf() {
+        let holdings = [(Rating::BBB, dec!(100)), (Rating::B, dec!(100))];
+        assert_eq!(warf(&holdings).unwrap(), dec!(1540));
+        assert!(warf(&[]).is_err());
+        assert!(warf(&[(Rating::BBB, dec!(-1))]).is_err());
+    }
+
+    #[test]
+    fn test_long_run_pd_monotonic() {
+        let table = pd_table(PdTable::LongRunAverageV1);
+        assert_eq!(table.len(), 22);
+        for pair in table.windows(2) {
+            assert!(pair[0].1 <= pair[1].1);
+        }
+        assert_eq!(
+            Rating::D.default_probability(PdTable::default()),
+            Decimal::ONE
+        );
+        assert_eq!(
+            Rating::BBB.default_probability(PdTable::LongRunAverageV1),
+            dec!(0.0015)
+        );
+    }
+
+    #[test]
+    fn test_moodys_idealized_pd() {
+        let pd = Rating::B.default_probability(PdTable::MoodysIdealizedV1);
+        // 1 - (1 - 0.272)^(1/10) ~= 0.0313
+        assert!((pd - dec!(0.0313)).abs() < dec!(0.0005));
+        let table = pd_table(PdTable::MoodysIdealizedV1);
+        for pair in table.windows(2) {
+            assert!(pair[0].1 <= pair[1].1);
+        }
+    }
+
+    #[test]
+    fn test_weighted_average_pd() {
+        let holdings = [(Rating::BBB, dec!(300)), (Rating::BB, dec!(100))];
+        let pd = weighted_average_pd(&holdings, PdTable::LongRunAverageV1).unwrap();
+        // (300 * 0.0015 + 100 * 0.0050) / 400
+        assert_eq!(pd, dec!(0.002375));
+    }
+}
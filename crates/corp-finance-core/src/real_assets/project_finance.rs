@@ -3,6 +3,7 @@ use rust_decimal_macros::dec;
 use serde::{Deserialize, Serialize};
 use std::time::Instant;
 
+use crate::amortization::{self, AmortizationInput, AmortizationMethod};
 use crate::error::CorpFinanceError;
 use crate::types::{with_metadata, ComputationOutput, Money, Rate};
 use crate::CorpFinanceResult;
@@ -229,13 +230,6 @@ pub fn model_project_finance(
         Decimal::ZERO
     };
 
-    // Senior debt level repayment amount (used for LevelRepayment sculpting)
-    let level_principal = if debt.senior_tenor_years > 0 {
-        debt.senior_debt / Decimal::from(debt.senior_tenor_years)
-    } else {
-        Decimal::ZERO
-    };
-
     // Sub debt annual interest
     let sub_debt_amount = debt.subordinated_debt.unwrap_or(Decimal::ZERO);
     let sub_rate = debt.sub_rate.unwrap_or(Decimal::ZERO);
@@ -374,57 +368,43 @@ pub fn model_project_finance(
     let mut interest_schedule: Vec<Money> =
         vec![Decimal::ZERO; input.operating_period_years as usize];
 
-    // First pass: compute interest and principal for each operating year
-    match debt.sculpting {
-        DebtSculpting::LevelRepayment => {
-            let mut bal = initial_outstanding;
-            for i in 0..input.operating_period_years as usize {
-                let interest = bal * debt.senior_rate;
-                let principal = if i < debt.senior_tenor_years as usize {
-                    level_principal.min(bal)
-                } else {
-                    Decimal::ZERO
-                };
-                interest_schedule[i] = interest;
-                principal_schedule[i] = principal;
-                bal -= principal;
-            }
-        }
-        DebtSculpting::Sculpted => {
-            // Sculpted: principal_t = CFADS_t / target_dscr - interest_t
-            // We iterate: interest depends on outstanding, principal depends
-            // on CFADS and interest.
-            let mut bal = initial_outstanding;
-            let tenor = debt.senior_tenor_years as usize;
-            for i in 0..input.operating_period_years as usize {
-                let interest = bal * debt.senior_rate;
-                interest_schedule[i] = interest;
-
-                if i < tenor && bal > Decimal::ZERO && debt.target_dscr > Decimal::ZERO {
-                    // total_ds = CFADS / target_dscr
-                    // principal = total_ds - interest
-                    let total_ds = operating_cfads[i] / debt.target_dscr;
-                    let principal = (total_ds - interest).max(Decimal::ZERO).min(bal);
-                    principal_schedule[i] = principal;
-                    bal -= principal;
-                } else {
-                    principal_schedule[i] = Decimal::ZERO;
-                }
+    // First pass: compute interest and principal for each operating year.
+    // Repayment runs over the tenor; years beyond it carry interest on any
+    // sculpted tail but no scheduled principal.
+    let tenor = debt.senior_tenor_years;
+    if tenor > 0 {
+        let tenor_f = Decimal::from(tenor);
+        let method = match debt.sculpting {
+            DebtSculpting::LevelRepayment => {
+                AmortizationMethod::StraightLine(Decimal::ONE / tenor_f)
             }
+            DebtSculpting::Sculpted => AmortizationMethod::Sculpted {
+                cash_flows: operating_cfads.clone(),
+                target_dscr: debt.target_dscr,
+            },
+            DebtSculpting::BulletMaturity => AmortizationMethod::Bullet,
+        };
+        let senior = amortization::build_schedule(&AmortizationInput {
+            principal: initial_outstanding,
+            rate: debt.senior_rate,
+            pik_rate: Decimal::ZERO,
+            periods: tenor,
+            method,
+        })?;
+        for (i, p) in senior
+            .periods
+            .iter()
+            .take(input.operating_period_years as usize)
+            .enumerate()
+        {
+            interest_schedule[i] = p.interest;
+            principal_schedule[i] = p.principal;
         }
-        DebtSculpting::BulletMaturity => {
-            let mut bal = initial_outstanding;
-            let tenor = debt.senior_tenor_years as usize;
-            for i in 0..input.operating_period_years as usize {
-                let interest = bal * debt.senior_rate;
-                interest_schedule[i] = interest;
-                if i == tenor.saturating_sub(1) && tenor > 0 {
-                    // Full principal at maturity
-                    principal_schedule[i] = bal;
-                    bal = Decimal::ZERO;
-                }
-            }
+        for interest in interest_schedule.iter_mut().skip(tenor as usize) {
+            *interest = senior.residual_balance * debt.senior_rate;
         }
+    } else {
+        interest_schedule.fill(initial_outstanding * debt.senior_rate);
     }
 
     // ── Phase 3: Waterfall and final projections ─────────────────────
@@ -1001,6 +981,20 @@ mod tests {
                 prev_debt = proj.outstanding_debt;
             }
         }
+
+        // Level repayment retires the full balance, including capitalised
+        // construction interest, by the end of the tenor
+        let at_tenor = out
+            .projections
+            .iter()
+            .filter(|p| p.phase == "Operating")
+            .nth(14)
+            .unwrap();
+        assert!(
+            at_tenor.outstanding_debt.abs() < dec!(0.01),
+            "Debt should be fully repaid at tenor end, got {}",
+            at_tenor.outstanding_debt
+        );
     }
 
     #[test]
@@ -4,9 +4,12 @@ use serde::{Deserialize, Serialize};
 use std::time::Instant;
 
 use crate::error::CorpFinanceError;
+use crate::ratings::Rating;
 use crate::types::{with_metadata, ComputationOutput, Money};
 use crate::CorpFinanceResult;
 
+use super::saccr::{calculate_saccr, SaccrInput};
+
 // ---------------------------------------------------------------------------
 // Enums
 // ---------------------------------------------------------------------------
@@ -67,7 +70,8 @@ pub struct CreditExposure {
     /// Override risk weight (0 to 1.5); if None, derived from asset_class + rating
     #[serde(skip_serializing_if = "Option::is_none")]
     pub risk_weight: Option<Decimal>,
-    /// External credit rating: "AAA", "AA", "A", "BBB", "BB", "B", "CCC", "Unrated"
+    /// External credit rating in S&P/Fitch or Moody's notation (e.g. "BBB+",
+    /// "Baa1"), or "Unrated"
     #[serde(skip_serializing_if = "Option::is_none")]
     pub external_rating: Option<String>,
     /// Collateral value for credit risk mitigation
@@ -120,6 +124,10 @@ pub struct RegulatoryCapitalInput {
     pub operational_risk: OperationalRiskInput,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub buffers: Option<CapitalBuffers>,
+    /// Derivative netting sets whose SA-CCR EAD is added to the credit
+    /// exposures
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub counterparty_credit: Option<SaccrInput>,
 }
 
 // ---------------------------------------------------------------------------
@@ -192,6 +200,10 @@ pub struct SurplusDeficit {
 pub struct RegulatoryCapitalOutput {
     pub capital_summary: CapitalSummary,
     pub credit_rwa: Money,
+    /// SA-CCR exposure at default of derivative netting sets (included in
+    /// credit RWA)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub counterparty_ead: Option<Money>,
     pub market_rwa: Money,
     pub operational_rwa: Money,
     pub total_rwa: Money,
@@ -246,11 +258,23 @@ pub fn calculate_regulatory_capital(
         deductions: cap.deductions,
     };
 
+    // -- Counterparty credit risk (SA-CCR) -----------------------------------
+    let mut credit_exposures = input.credit_exposures.clone();
+    let counterparty_ead = match &input.counterparty_credit {
+        Some(ccr) => {
+            let saccr = calculate_saccr(ccr)?;
+            warnings.extend(saccr.warnings);
+            credit_exposures.extend(saccr.result.credit_exposures);
+            Some(saccr.result.total_ead)
+        }
+        None => None,
+    };
+
     // -- Credit RWA (Standardised Approach) ----------------------------------
     let mut exposure_details: Vec<ExposureDetail> = Vec::new();
     let mut credit_rwa = Decimal::ZERO;
 
-    for exp in &input.credit_exposures {
+    for exp in &credit_exposures {
         let detail = calculate_exposure_rwa(exp, &mut warnings)?;
         credit_rwa += detail.rwa;
         exposure_details.push(detail);
@@ -277,8 +301,7 @@ pub fn calculate_regulatory_capital(
     let total_capital_ratio = total_capital / total_rwa;
 
     // Leverage ratio: Tier 1 / total exposure (sum of EADs, not RWA)
-    let total_exposure: Decimal = input
-        .credit_exposures
+    let total_exposure: Decimal = credit_exposures
         .iter()
         .map(|e| e.exposure_amount)
         .sum::<Decimal>()
@@ -341,6 +364,7 @@ pub fn calculate_regulatory_capital(
     let output = RegulatoryCapitalOutput {
         capital_summary,
         credit_rwa,
+        counterparty_ead,
         market_rwa,
         operational_rwa,
         total_rwa,
@@ -404,7 +428,7 @@ fn validate_input(
         });
     }
 
-    if input.credit_exposures.is_empty() {
+    if input.credit_exposures.is_empty() && input.counterparty_credit.is_none() {
         warnings.push("No credit exposures provided; credit RWA will be zero.".into());
     }
 
@@ -478,9 +502,15 @@ fn derive_risk_weight(
     warnings: &mut Vec<String>,
 ) -> Decimal {
     let rating_str = rating.as_deref().unwrap_or("Unrated").trim().to_uppercase();
+    // Notched and Moody's ratings ("BBB+", "Baa1") collapse to their letter grade.
+    let grade = match rating.as_deref().map(Rating::parse) {
+        Some(Ok(r)) if r.is_default() => "CCC",
+        Some(Ok(r)) => r.letter_grade(),
+        _ => rating_str.as_str(),
+    };
 
     match asset_class {
-        AssetClass::Sovereign => match rating_str.as_str() {
+        AssetClass::Sovereign => match grade {
             "AAA" | "AA" => dec!(0),
             "A" => dec!(0.20),
             "BBB" => dec!(0.50),
@@ -498,7 +528,7 @@ fn derive_risk_weight(
                 dec!(1.50)
             }
         },
-        AssetClass::Bank => match rating_str.as_str() {
+        AssetClass::Bank => match grade {
             "AAA" | "AA" | "A" => dec!(0.20),
             "BBB" => dec!(0.50),
             "BB" | "B" => dec!(1.00),
@@ -515,7 +545,7 @@ fn derive_risk_weight(
                 dec!(1.50)
             }
         },
-        AssetClass::Corporate => match rating_str.as_str() {
+        AssetClass::Corporate => match grade {
             "AAA" | "AA" => dec!(0.20),
             "A" => dec!(0.50),
             "BBB" | "BB" => dec!(1.00),
@@ -721,6 +751,7 @@ mod tests {
             market_risk_charge: None,
             operational_risk: default_op_risk(),
             buffers: None,
+            counterparty_credit: None,
         }
     }
 
@@ -1316,6 +1347,29 @@ mod tests {
         assert_eq!(detail.risk_weight, dec!(1.50));
     }
 
+    // -- Test: Notched and Moody's ratings -----------------------------------
+
+    #[test]
+    fn test_notched_and_moodys_ratings() {
+        let mut warnings = Vec::new();
+
+        // BBB+ => BBB => 100% corporate
+        let exp = rated_exposure("Corp BBB+", dec!(10_000), AssetClass::Corporate, "BBB+");
+        let detail = calculate_exposure_rwa(&exp, &mut warnings).unwrap();
+        assert_eq!(detail.risk_weight, dec!(1.00));
+
+        // Baa1 => BBB => 50% bank
+        let exp = rated_exposure("Bank Baa1", dec!(10_000), AssetClass::Bank, "Baa1");
+        let detail = calculate_exposure_rwa(&exp, &mut warnings).unwrap();
+        assert_eq!(detail.risk_weight, dec!(0.50));
+
+        // Aa3 => AA => 0% sovereign
+        let exp = rated_exposure("Sov Aa3", dec!(10_000), AssetClass::Sovereign, "Aa3");
+        let detail = calculate_exposure_rwa(&exp, &mut warnings).unwrap();
+        assert_eq!(detail.risk_weight, dec!(0));
+        assert!(warnings.is_empty());
+    }
+
     // -- Test: Sovereign rating categories -----------------------------------
 
     #[test]
@@ -1468,4 +1522,54 @@ mod tests {
             other => panic!("Expected InsufficientData, got {other:?}"),
         }
     }
+
+    // -- Test: SA-CCR netting sets feed credit RWA ---------------------------
+
+    #[test]
+    fn test_counterparty_credit_adds_saccr_ead() {
+        use crate::regulatory::saccr::{SaccrAssetClass, SaccrInput, SaccrNettingSet, SaccrTrade};
+
+        let loan = simple_exposure("Loan", dec!(100_000), AssetClass::Other);
+        let mut input = make_input(vec![loan]);
+        input.counterparty_credit = Some(SaccrInput {
+            netting_sets: vec![SaccrNettingSet {
+                name: "Dealer ISDA".to_string(),
+                counterparty_asset_class: AssetClass::Corporate,
+                counterparty_rating: Some("BBB".to_string()),
+                trades: vec![SaccrTrade {
+                    id: "irs".to_string(),
+                    asset_class: SaccrAssetClass::InterestRate,
+                    hedging_set: Some("USD".to_string()),
+                    reference: None,
+                    notional: dec!(10_000_000),
+                    market_value: dec!(250_000),
+                    long: true,
+                    maturity_years: dec!(5),
+                    start_years: None,
+                    end_years: None,
+                    is_index: false,
+                    external_rating: None,
+                    option: None,
+                    supervisory_delta: None,
+                }],
+                margined: false,
+                net_collateral: Decimal::ZERO,
+                threshold: Decimal::ZERO,
+                minimum_transfer_amount: Decimal::ZERO,
+                nica: Decimal::ZERO,
+                margin_period_of_risk_days: None,
+            }],
+            alpha: None,
+        });
+
+        let result = calculate_regulatory_capital(&input).unwrap().result;
+        let ead = result.counterparty_ead.unwrap();
+        assert!(ead > dec!(250_000));
+        assert_eq!(result.exposure_details.len(), 2);
+        let ccr = &result.exposure_details[1];
+        assert_eq!(ccr.exposure, ead);
+        // BBB corporate => 100% RW
+        assert_eq!(ccr.rwa, ead);
+        assert_eq!(result.credit_rwa, dec!(100_000) + ead);
+    }
 }
@@ -1,3 +1,4 @@
 pub mod alm;
 pub mod capital;
 pub mod liquidity;
+pub mod saccr;
@@ -0,0 +1,985 @@
+use rust_decimal::Decimal;
+use rust_decimal::MathematicalOps;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::time::Instant;
+
+use crate::error::CorpFinanceError;
+use crate::ratings::Rating;
+use crate::stats::norm_cdf;
+use crate::types::{with_metadata, ComputationOutput, Money, Years};
+use crate::CorpFinanceResult;
+
+use super::capital::{AssetClass, CreditExposure};
+
+// ---------------------------------------------------------------------------
+// Enums
+// ---------------------------------------------------------------------------
+
+/// SA-CCR asset class of a derivative's primary risk driver.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum SaccrAssetClass {
+    InterestRate,
+    ForeignExchange,
+    Credit,
+    Equity,
+    Commodity,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OptionType {
+    Call,
+    Put,
+}
+
+// ---------------------------------------------------------------------------
+// Input types
+// ---------------------------------------------------------------------------
+
+/// Option terms for the supervisory delta. `long` on the trade means the
+/// option was bought.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SaccrOption {
+    pub option_type: OptionType,
+    /// Current price of the underlying (rate, FX rate, spread or price)
+    pub underlying_price: Decimal,
+    pub strike: Decimal,
+    /// Time to the latest exercise date in years
+    pub exercise_years: Years,
+}
+
+/// A single derivative trade in a netting set.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SaccrTrade {
+    pub id: String,
+    pub asset_class: SaccrAssetClass,
+    /// Interest rate: currency; FX: currency pair (e.g. "EUR/USD");
+    /// commodity: Energy, Metals, Agricultural or Other. Unused for credit
+    /// and equity, which form one hedging set each.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hedging_set: Option<String>,
+    /// Credit and equity: reference entity or index; commodity: commodity
+    /// type (e.g. "Brent", "Electricity"). Defaults to the trade id.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reference: Option<String>,
+    /// Notional in the reporting currency (market value of the underlying
+    /// for equity and commodity trades)
+    pub notional: Money,
+    /// Current mark-to-market value to the bank
+    pub market_value: Money,
+    /// Long in the primary risk factor (bought, for options)
+    pub long: bool,
+    /// Remaining maturity in years
+    pub maturity_years: Years,
+    /// Start of the underlying period for interest rate and credit trades
+    /// (defaults to 0)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_years: Option<Years>,
+    /// End of the underlying period for interest rate and credit trades
+    /// (defaults to maturity)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_years: Option<Years>,
+    /// Credit or equity index rather than a single name
+    #[serde(default)]
+    pub is_index: bool,
+    /// Rating of the credit reference entity, e.g. "BBB+"; for indices,
+    /// any investment grade rating marks an investment grade index
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_rating: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub option: Option<SaccrOption>,
+    /// Override the supervisory delta (e.g. for CDO tranches)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub supervisory_delta: Option<Decimal>,
+}
+
+/// Trades with one counterparty under a single netting agreement.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SaccrNettingSet {
+    pub name: String,
+    /// Capital asset class of the counterparty, for the RWA risk weight
+    pub counterparty_asset_class: AssetClass,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub counterparty_rating: Option<String>,
+    pub trades: Vec<SaccrTrade>,
+    /// Subject to a variation margin agreement
+    #[serde(default)]
+    pub margined: bool,
+    /// Haircut value of net collateral held (negative when posted)
+    #[serde(default)]
+    pub net_collateral: Money,
+    /// Margin threshold below which the counterparty posts no variation margin
+    #[serde(default)]
+    pub threshold: Money,
+    #[serde(default)]
+    pub minimum_transfer_amount: Money,
+    /// Net independent collateral amount (independent amounts and initial
+    /// margin held less posted)
+    #[serde(default)]
+    pub nica: Money,
+    /// Margin period of risk in business days (default and floor 10)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub margin_period_of_risk_days: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SaccrInput {
+    pub netting_sets: Vec<SaccrNettingSet>,
+    /// EAD multiplier (default 1.4)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alpha: Option<Decimal>,
+}
+
+// ---------------------------------------------------------------------------
+// Output types
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SaccrTradeDetail {
+    pub id: String,
+    pub asset_class: SaccrAssetClass,
+    pub hedging_set: String,
+    /// Notional times supervisory duration for interest rate and credit
+    pub adjusted_notional: Money,
+    pub supervisory_delta: Decimal,
+    pub maturity_factor: Decimal,
+    pub supervisory_factor: Decimal,
+    /// delta * adjusted notional * maturity factor
+    pub effective_notional: Money,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HedgingSetAddOn {
+    pub asset_class: SaccrAssetClass,
+    pub hedging_set: String,
+    pub add_on: Money,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NettingSetSaccr {
+    pub name: String,
+    /// Net mark-to-market of the trades
+    pub market_value: Money,
+    pub net_collateral: Money,
+    pub replacement_cost: Money,
+    pub aggregate_add_on: Money,
+    pub multiplier: Decimal,
+    /// multiplier * aggregate add-on
+    pub potential_future_exposure: Money,
+    /// alpha * (RC + PFE)
+    pub ead: Money,
+    /// The margined EAD was capped at the unmargined EAD
+    pub unmargined_cap_applied: bool,
+    pub add_ons: Vec<HedgingSetAddOn>,
+    pub trades: Vec<SaccrTradeDetail>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SaccrOutput {
+    pub netting_sets: Vec<NettingSetSaccr>,
+    pub total_ead: Money,
+    /// One exposure per netting set, ready for `calculate_regulatory_capital`
+    pub credit_exposures: Vec<CreditExposure>,
+}
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+const DEFAULT_ALPHA: Decimal = dec!(1.4);
+const MULTIPLIER_FLOOR: Decimal = dec!(0.05);
+/// Floor on the margin period of risk and on unmargined maturity (days)
+const MIN_MPOR_DAYS: u32 = 10;
+const BUSINESS_DAYS_PER_YEAR: Decimal = dec!(250);
+/// Rate in the supervisory duration formula
+const SD_RATE: Decimal = dec!(0.05);
+
+// ---------------------------------------------------------------------------
+// Public API
+// ---------------------------------------------------------------------------
+
+/// Calculate exposure at default under the Basel standardised approach for
+/// counterparty credit risk (SA-CCR, CRE52).
+///
+/// For each netting set, EAD = alpha * (RC + multiplier * AddOn), where RC
+/// is the replacement cost after collateral (and margin terms when
+/// margined), AddOn aggregates supervisory add-ons across hedging sets and
+/// asset classes, and the multiplier recognises over-collateralisation and
+/// negative mark-to-market. Margined EAD is capped at the unmargined EAD.
+pub fn calculate_saccr(input: &SaccrInput) -> CorpFinanceResult<ComputationOutput<SaccrOutput>> {
+    let start = Instant::now();
+    let mut warnings: Vec<String> = Vec::new();
+
+    validate_input(input)?;
+    let alpha = input.alpha.unwrap_or(DEFAULT_ALPHA);
+
+    let mut netting_sets = Vec::with_capacity(input.netting_sets.len());
+    let mut credit_exposures = Vec::with_capacity(input.netting_sets.len());
+    let mut total_ead = Decimal::ZERO;
+
+    for ns in &input.netting_sets {
+        let unmargined = netting_set_ead(ns, false, alpha, &mut warnings)?;
+        let result = if ns.margined {
+            let margined = netting_set_ead(ns, true, alpha, &mut Vec::new())?;
+            if margined.ead > unmargined.ead {
+                NettingSetSaccr {
+                    unmargined_cap_applied: true,
+                    ..unmargined
+                }
+            } else {
+                margined
+            }
+        } else {
+            unmargined
+        };
+
+        total_ead += result.ead;
+        credit_exposures.push(CreditExposure {
+            name: format!("{} (SA-CCR)", ns.name),
+            exposure_amount: result.ead,
+            asset_class: ns.counterparty_asset_class.clone(),
+            risk_weight: None,
+            external_rating: ns.counterparty_rating.clone(),
+            collateral_value: None,
+            collateral_type: None,
+        });
+        netting_sets.push(result);
+    }
+
+    let output = SaccrOutput {
+        netting_sets,
+        total_ead,
+        credit_exposures,
+    };
+
+    let elapsed = start.elapsed().as_micros() as u64;
+    let assumptions = serde_json::json!({
+        "framework": "Basel III SA-CCR (CRE52)",
+        "alpha": alpha.to_string(),
+        "multiplier_floor": "5%",
+        "margin_period_of_risk_floor_days": MIN_MPOR_DAYS,
+        "supervisory_duration_rate": "5%",
+    });
+
+    Ok(with_metadata(
+        "SA-CCR Exposure at Default",
+        &assumptions,
+        warnings,
+        elapsed,
+        output,
+    ))
+}
+
+// ---------------------------------------------------------------------------
+// Internal: netting set
+// ---------------------------------------------------------------------------
+
+fn netting_set_ead(
+    ns: &SaccrNettingSet,
+    margined: bool,
+    alpha: Decimal,
+    warnings: &mut Vec<String>,
+) -> CorpFinanceResult<NettingSetSaccr> {
+    let mpor_days = ns.margin_period_of_risk_days.unwrap_or(MIN_MPOR_DAYS);
+
+    let mut trades = Vec::with_capacity(ns.trades.len());
+    for trade in &ns.trades {
+        trades.push(trade_detail(trade, margined, mpor_days, warnings)?);
+    }
+
+    let add_ons = hedging_set_add_ons(ns, &trades);
+    let aggregate_add_on: Money = add_ons.iter().map(|a| a.add_on).sum();
+
+    let market_value: Money = ns.trades.iter().map(|t| t.market_value).sum();
+    let uncollateralised = market_value - ns.net_collateral;
+    let replacement_cost = if margined {
+        uncollateralised
+            .max(ns.threshold + ns.minimum_transfer_amount - ns.nica)
+            .max(Decimal::ZERO)
+    } else {
+        uncollateralised.max(Decimal::ZERO)
+    };
+
+    // multiplier = min(1, floor + (1 - floor) * exp((V - C) / (2 (1 - floor) AddOn)))
+    let multiplier = if aggregate_add_on.is_zero() || uncollateralised >= Decimal::ZERO {
+        Decimal::ONE
+    } else {
+        let scale = dec!(2) * (Decimal::ONE - MULTIPLIER_FLOOR) * aggregate_add_on;
+        // Deep out of the money the exponential underflows: the floor binds
+        (uncollateralised / scale)
+            .checked_exp()
+            .map_or(MULTIPLIER_FLOOR, |e| {
+                MULTIPLIER_FLOOR + (Decimal::ONE - MULTIPLIER_FLOOR) * e
+            })
+            .min(Decimal::ONE)
+    };
+
+    let potential_future_exposure = multiplier * aggregate_add_on;
+    let ead = alpha * (replacement_cost + potential_future_exposure);
+
+    Ok(NettingSetSaccr {
+        name: ns.name.clone(),
+        market_value,
+        net_collateral: ns.net_collateral,
+        replacement_cost,
+        aggregate_add_on,
+        multiplier,
+        potential_future_exposure,
+        ead,
+        unmargined_cap_applied: false,
+        add_ons,
+        trades,
+    })
+}
+
+// ---------------------------------------------------------------------------
+// Internal: trade-level parameters
+// ---------------------------------------------------------------------------
+
+fn trade_detail(
+    trade: &SaccrTrade,
+    margined: bool,
+    mpor_days: u32,
+    warnings: &mut Vec<String>,
+) -> CorpFinanceResult<SaccrTradeDetail> {
+    let hedging_set = match trade.asset_class {
+        // Interest rate and FX hedging sets are checked in validation
+        SaccrAssetClass::InterestRate | SaccrAssetClass::ForeignExchange => {
+            trade.hedging_set.clone().unwrap_or_default()
+        }
+        SaccrAssetClass::Commodity => trade
+            .hedging_set
+            .clone()
+            .unwrap_or_else(|| "Other".to_string()),
+        SaccrAssetClass::Credit => "Credit".to_string(),
+        SaccrAssetClass::Equity => "Equity".to_string(),
+    };
+
+    let adjusted_notional = match trade.asset_class {
+        SaccrAssetClass::InterestRate | SaccrAssetClass::Credit => {
+            let s = trade.start_years.unwrap_or(Decimal::ZERO);
+            let e = trade.end_years.unwrap_or(trade.maturity_years);
+            trade.notional * supervisory_duration(s, e)
+        }
+        _ => trade.notional,
+    };
+
+    let maturity_factor = if margined {
+        dec!(1.5)
+            * (Decimal::from(mpor_days) / BUSINESS_DAYS_PER_YEAR)
+                .sqrt()
+                .unwrap_or(Decimal::ZERO)
+    } else {
+        let floor = Decimal::from(MIN_MPOR_DAYS) / BUSINESS_DAYS_PER_YEAR;
+        trade
+            .maturity_years
+            .max(floor)
+            .min(Decimal::ONE)
+            .sqrt()
+            .unwrap_or(Decimal::ZERO)
+    };
+
+    let supervisory_factor = supervisory_factor(trade, warnings);
+    let supervisory_delta = match (trade.supervisory_delta, &trade.option) {
+        (Some(delta), _) => delta,
+        (None, Some(option)) => option_delta(trade, option),
+        (None, None) if trade.long => Decimal::ONE,
+        (None, None) => -Decimal::ONE,
+    };
+
+    Ok(SaccrTradeDetail {
+        id: trade.id.clone(),
+        asset_class: trade.asset_class,
+        hedging_set,
+        adjusted_notional,
+        supervisory_delta,
+        maturity_factor,
+        supervisory_factor,
+        effective_notional: supervisory_delta * adjusted_notional * maturity_factor,
+    })
+}
+
+/// SD = (exp(-0.05 S) - exp(-0.05 E)) / 0.05
+fn supervisory_duration(start: Years, end: Years) -> Decimal {
+    ((-SD_RATE * start).exp() - (-SD_RATE * end).exp()) / SD_RATE
+}
+
+fn is_electricity(trade: &SaccrTrade) -> bool {
+    trade.reference.as_deref().is_some_and(|r| {
+        let r = r.to_lowercase();
+        r.contains("electric") || r == "power"
+    })
+}
+
+/// Supervisory factors (CRE52.72).
+fn supervisory_factor(trade: &SaccrTrade, warnings: &mut Vec<String>) -> Decimal {
+    match trade.asset_class {
+        SaccrAssetClass::InterestRate => dec!(0.005),
+        SaccrAssetClass::ForeignExchange => dec!(0.04),
+        SaccrAssetClass::Credit => {
+            let rating = trade.external_rating.as_deref().map(Rating::parse);
+            match (trade.is_index, rating) {
+                (true, Some(Ok(r))) if r.is_investment_grade() => dec!(0.0038),
+                (true, _) => dec!(0.0106),
+                (false, Some(Ok(r))) => match r.letter_grade() {
+                    "AAA" | "AA" => dec!(0.0038),
+                    "A" => dec!(0.0042),
+                    "BBB" => dec!(0.0054),
+                    "BB" => dec!(0.0106),
+                    "B" => dec!(0.016),
+                    _ => dec!(0.06),
+                },
+                (false, _) => {
+                    warnings.push(format!(
+                        "Trade {}: unrated credit reference treated as BB (1.06% supervisory factor).",
+                        trade.id
+                    ));
+                    dec!(0.0106)
+                }
+            }
+        }
+        SaccrAssetClass::Equity if trade.is_index => dec!(0.20),
+        SaccrAssetClass::Equity => dec!(0.32),
+        SaccrAssetClass::Commodity if is_electricity(trade) => dec!(0.40),
+        SaccrAssetClass::Commodity => dec!(0.18),
+    }
+}
+
+/// Supervisory option volatilities (CRE52.72).
+fn supervisory_volatility(trade: &SaccrTrade) -> Decimal {
+    match trade.asset_class {
+        SaccrAssetClass::InterestRate => dec!(0.50),
+        SaccrAssetClass::ForeignExchange => dec!(0.15),
+        SaccrAssetClass::Credit if trade.is_index => dec!(0.80),
+        SaccrAssetClass::Credit => dec!(1.00),
+        SaccrAssetClass::Equity if trade.is_index => dec!(0.75),
+        SaccrAssetClass::Equity => dec!(1.20),
+        SaccrAssetClass::Commodity if is_electricity(trade) => dec!(1.50),
+        SaccrAssetClass::Commodity => dec!(0.70),
+    }
+}
+
+/// Supervisory correlation between entities or commodity types within a
+/// hedging set.
+fn supervisory_correlation(trade: &SaccrTrade) -> Decimal {
+    match trade.asset_class {
+        SaccrAssetClass::Credit | SaccrAssetClass::Equity if trade.is_index => dec!(0.80),
+        SaccrAssetClass::Commodity => dec!(0.40),
+        _ => dec!(0.50),
+    }
+}
+
+/// Delta = +/- N(+/- d1), d1 = (ln(P/K) + sigma^2 T / 2) / (sigma sqrt(T)):
+/// bought call +N(d1), sold call -N(d1), bought put -N(-d1), sold put +N(-d1).
+fn option_delta(trade: &SaccrTrade, option: &SaccrOption) -> Decimal {
+    let sigma = supervisory_volatility(trade);
+    let sigma_sqrt_t = sigma * option.exercise_years.sqrt().unwrap_or(Decimal::ZERO);
+    let d1 = if sigma_sqrt_t.is_zero() {
+        // At expiry the option is all or nothing
+        if option.underlying_price >= option.strike {
+            dec!(10)
+        } else {
+            dec!(-10)
+        }
+    } else {
+        ((option.underlying_price / option.strike).ln()
+            + sigma * sigma * option.exercise_years / dec!(2))
+            / sigma_sqrt_t
+    };
+    let sign = if trade.long {
+        Decimal::ONE
+    } else {
+        -Decimal::ONE
+    };
+    match option.option_type {
+        OptionType::Call => sign * norm_cdf(d1),
+        OptionType::Put => -sign * norm_cdf(-d1),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Internal: add-on aggregation
+// ---------------------------------------------------------------------------
+
+fn hedging_set_add_ons(ns: &SaccrNettingSet, trades: &[SaccrTradeDetail]) -> Vec<HedgingSetAddOn> {
+    // (asset class, hedging set) -> trade indices, in a stable order
+    let mut sets: BTreeMap<(SaccrAssetClass, String), Vec<usize>> = BTreeMap::new();
+    for (i, t) in trades.iter().enumerate() {
+        sets.entry((t.asset_class, t.hedging_set.clone()))
+            .or_default()
+            .push(i);
+    }
+
+    sets.into_iter()
+        .map(|((asset_class, hedging_set), idx)| {
+            let add_on = match asset_class {
+                SaccrAssetClass::InterestRate => interest_rate_add_on(ns, trades, &idx),
+                SaccrAssetClass::ForeignExchange => {
+                    let sf = trades[idx[0]].supervisory_factor;
+                    sf * idx
+                        .iter()
+                        .map(|&i| trades[i].effective_notional)
+                        .sum::<Decimal>()
+                        .abs()
+                }
+                _ => entity_add_on(ns, trades, &idx),
+            };
+            HedgingSetAddOn {
+                asset_class,
+                hedging_set,
+                add_on,
+            }
+        })
+        .collect()
+}
+
+/// Interest rate hedging set: effective notionals bucketed by end date
+/// (< 1y, 1-5y, > 5y) with 70% correlation between adjacent buckets and
+/// 30% between the outer two.
+fn interest_rate_add_on(ns: &SaccrNettingSet, trades: &[SaccrTradeDetail], idx: &[usize]) -> Money {
+    let mut buckets = [Decimal::ZERO; 3];
+    for &i in idx {
+        let src = &ns.trades[i];
+        let end = src.end_years.unwrap_or(src.maturity_years);
+        let bucket = if end < Decimal::ONE {
+            0
+        } else if end <= dec!(5) {
+            1
+        } else {
+            2
+        };
+        buckets[bucket] += trades[i].effective_notional;
+    }
+    let [d1, d2, d3] = buckets;
+    let variance = d1 * d1
+        + d2 * d2
+        + d3 * d3
+        + dec!(1.4) * d1 * d2
+        + dec!(1.4) * d2 * d3
+        + dec!(0.6) * d1 * d3;
+    let effective_notional = variance.max(Decimal::ZERO).sqrt().unwrap_or(Decimal::ZERO);
+    trades[idx[0]].supervisory_factor * effective_notional
+}
+
+/// Credit, equity and commodity hedging sets: add-ons per entity (or
+/// commodity type) combined through a single-factor model,
+/// sqrt((sum rho_k A_k)^2 + sum (1 - rho_k^2) A_k^2).
+fn entity_add_on(ns: &SaccrNettingSet, trades: &[SaccrTradeDetail], idx: &[usize]) -> Money {
+    let mut entities: BTreeMap<String, (Decimal, Decimal)> = BTreeMap::new();
+    for &i in idx {
+        let src = &ns.trades[i];
+        let entity = src.reference.clone().unwrap_or_else(|| src.id.clone());
+        let entry = entities
+            .entry(entity)
+            .or_insert((Decimal::ZERO, supervisory_correlation(src)));
+        entry.0 += trades[i].supervisory_factor * trades[i].effective_notional;
+    }
+
+    let systematic: Decimal = entities.values().map(|(a, rho)| rho * a).sum();
+    let idiosyncratic: Decimal = entities
+        .values()
+        .map(|(a, rho)| (Decimal::ONE - rho * rho) * a * a)
+        .sum();
+    (systematic * systematic + idiosyncratic)
+        .sqrt()
+        .unwrap_or(Decimal::ZERO)
+}
+
+// ---------------------------------------------------------------------------
+// Internal: input validation
+// ---------------------------------------------------------------------------
+
+fn validate_input(input: &SaccrInput) -> CorpFinanceResult<()> {
+    if input.netting_sets.is_empty() {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "netting_sets".into(),
+            reason: "At least one netting set is required.".into(),
+        });
+    }
+    if let Some(alpha) = input.alpha {
+        if alpha <= Decimal::ZERO {
+            return Err(CorpFinanceError::InvalidInput {
+                field: "alpha".into(),
+                reason: "Alpha must be positive.".into(),
+            });
+        }
+    }
+
+    for (n, ns) in input.netting_sets.iter().enumerate() {
+        if ns.trades.is_empty() {
+            return Err(CorpFinanceError::InvalidInput {
+                field: format!("netting_sets[{}].trades", n),
+                reason: "A netting set needs at least one trade.".into(),
+            });
+        }
+        if ns.threshold < Decimal::ZERO || ns.minimum_transfer_amount < Decimal::ZERO {
+            return Err(CorpFinanceError::InvalidInput {
+                field: format!("netting_sets[{}].threshold", n),
+                reason: "Threshold and minimum transfer amount cannot be negative.".into(),
+            });
+        }
+        if let Some(days) = ns.margin_period_of_risk_days {
+            if days < MIN_MPOR_DAYS {
+                return Err(CorpFinanceError::InvalidInput {
+                    field: format!("netting_sets[{}].margin_period_of_risk_days", n),
+                    reason: format!(
+                        "Margin period of risk is floored at {MIN_MPOR_DAYS} business days."
+                    ),
+                });
+            }
+        }
+
+        for (i, t) in ns.trades.iter().enumerate() {
+            let field = |name: &str| format!("netting_sets[{}].trades[{}].{}", n, i, name);
+            if t.notional < Decimal::ZERO {
+                return Err(CorpFinanceError::InvalidInput {
+                    field: field("notional"),
+                    reason: "Notional cannot be negative; use `long` for direction.".into(),
+                });
+            }
+            let needs_hedging_set = matches!(
+                t.asset_class,
+                SaccrAssetClass::InterestRate | SaccrAssetClass::ForeignExchange
+            );
+            if needs_hedging_set && t.hedging_set.is_none() {
+                return Err(CorpFinanceError::InvalidInput {
+                    field: field("hedging_set"),
+                    reason: "Interest rate and FX trades need a hedging set (currency or currency pair)."
+                        .into(),
+                });
+            }
+            if t.maturity_years < Decimal::ZERO {
+                return Err(CorpFinanceError::InvalidInput {
+                    field: field("maturity_years"),
+                    reason: "Maturity cannot be negative.".into(),
+                });
+            }
+            let start = t.start_years.unwrap_or(Decimal::ZERO);
+            let end = t.end_years.unwrap_or(t.maturity_years);
+            if start < Decimal::ZERO || end < start {
+                return Err(CorpFinanceError::InvalidInput {
+                    field: field("end_years"),
+                    reason: "Underlying period must satisfy 0 <= start <= end.".into(),
+                });
+            }
+            if let Some(option) = &t.option {
+                if option.underlying_price <= Decimal::ZERO || option.strike <= Decimal::ZERO {
+                    return Err(CorpFinanceError::InvalidInput {
+                        field: field("option"),
+                        reason: "Underlying price and strike must be positive.".into(),
+                    });
+                }
+                if option.exercise_years < Decimal::ZERO {
+                    return Err(CorpFinanceError::InvalidInput {
+                        field: field("option.exercise_years"),
+                        reason: "Exercise date cannot be in the past.".into(),
+                    });
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn swap(id: &str, notional: Decimal, mv: Decimal, long: bool, maturity: Decimal) -> SaccrTrade {
+        SaccrTrade {
+            id: id.to_string(),
+            asset_class: SaccrAssetClass::InterestRate,
+            hedging_set: Some("USD".to_string()),
+            reference: None,
+            notional,
+            market_value: mv,
+            long,
+            maturity_years: maturity,
+            start_years: None,
+            end_years: None,
+            is_index: false,
+            external_rating: None,
+            option: None,
+            supervisory_delta: None,
+        }
+    }
+
+    fn netting_set(trades: Vec<SaccrTrade>) -> SaccrNettingSet {
+        SaccrNettingSet {
+            name: "Bank A ISDA".to_string(),
+            counterparty_asset_class: AssetClass::Bank,
+            counterparty_rating: Some("A".to_string()),
+            trades,
+            margined: false,
+            net_collateral: Decimal::ZERO,
+            threshold: Decimal::ZERO,
+            minimum_transfer_amount: Decimal::ZERO,
+            nica: Decimal::ZERO,
+            margin_period_of_risk_days: None,
+        }
+    }
+
+    fn input(sets: Vec<SaccrNettingSet>) -> SaccrInput {
+        SaccrInput {
+            netting_sets: sets,
+            alpha: None,
+        }
+    }
+
+    fn assert_close(actual: Decimal, expected: Decimal, tol: Decimal) {
+        assert!(
+            (actual - expected).abs() <= tol,
+            "expected {expected}, got {actual}"
+        );
+    }
+
+    #[test]
+    fn test_single_swap_ead() {
+        // 10y receiver swap, 10m notional, MTM 0
+        // SD = (1 - e^-0.5) / 0.05 = 7.8694; AddOn = 0.5% * 78.694m = 393,469
+        let ns = netting_set(vec![swap("irs", dec!(10_000_000), dec!(0), true, dec!(10))]);
+        let out = calculate_saccr(&input(vec![ns])).unwrap().result;
+        let set = &out.netting_sets[0];
+        assert_close(set.aggregate_add_on, dec!(393_469), dec!(5));
+        assert_eq!(set.replacement_cost, Decimal::ZERO);
+        assert_eq!(set.multiplier, Decimal::ONE);
+        assert_close(set.ead, dec!(550_857), dec!(10));
+        assert_eq!(out.total_ead, set.ead);
+    }
+
+    #[test]
+    fn test_offsetting_swaps_net_within_bucket() {
+        let long = swap("a", dec!(10_000_000), dec!(0), true, dec!(10));
+        let short = swap("b", dec!(10_000_000), dec!(0), false, dec!(10));
+        let out = calculate_saccr(&input(vec![netting_set(vec![long, short])]))
+            .unwrap()
+            .result;
+        assert_eq!(out.netting_sets[0].aggregate_add_on, Decimal::ZERO);
+        assert_eq!(out.total_ead, Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_bucket_correlation_partial_offset() {
+        // Long 10y vs short 3y offsets only partially across buckets
+        let long = swap("a", dec!(10_000_000), dec!(0), true, dec!(10));
+        let short = swap("b", dec!(10_000_000), dec!(0), false, dec!(3));
+        let both = calculate_saccr(&input(vec![netting_set(vec![long.clone(), short])]))
+            .unwrap()
+            .result;
+        let alone = calculate_saccr(&input(vec![netting_set(vec![long])]))
+            .unwrap()
+            .result;
+        let add_on = both.netting_sets[0].aggregate_add_on;
+        assert!(add_on > Decimal::ZERO);
+        assert!(add_on < alone.netting_sets[0].aggregate_add_on);
+    }
+
+    #[test]
+    fn test_replacement_cost_and_multiplier() {
+        // In-the-money: RC = MTM - collateral
+        let mut ns = netting_set(vec![swap(
+            "a",
+            dec!(10_000_000),
+            dec!(500_000),
+            true,
+            dec!(5),
+        )]);
+        ns.net_collateral = dec!(200_000);
+        let out = calculate_saccr(&input(vec![ns])).unwrap().result;
+        assert_eq!(out.netting_sets[0].replacement_cost, dec!(300_000));
+        assert_eq!(out.netting_sets[0].multiplier, Decimal::ONE);
+
+        // Out-of-the-money: RC = 0 and the multiplier falls below one
+        let ns = netting_set(vec![swap(
+            "a",
+            dec!(10_000_000),
+            dec!(-300_000),
+            true,
+            dec!(5),
+        )]);
+        let out = calculate_saccr(&input(vec![ns])).unwrap().result;
+        let set = &out.netting_sets[0];
+        assert_eq!(set.replacement_cost, Decimal::ZERO);
+        assert!(set.multiplier < Decimal::ONE && set.multiplier >= MULTIPLIER_FLOOR);
+    }
+
+    #[test]
+    fn test_deep_out_of_the_money_multiplier_hits_floor() {
+        // MTM -8m against an add-on of ~49k: exp(-86) underflows Decimal
+        let ns = netting_set(vec![swap(
+            "a",
+            dec!(10_000_000),
+            dec!(-8_000_000),
+            true,
+            dec!(1),
+        )]);
+        let out = calculate_saccr(&input(vec![ns])).unwrap().result;
+        let set = &out.netting_sets[0];
+        assert_eq!(set.replacement_cost, Decimal::ZERO);
+        assert_close(set.multiplier, MULTIPLIER_FLOOR, dec!(0.000001));
+        assert_close(
+            set.ead,
+            dec!(1.4) * MULTIPLIER_FLOOR * set.aggregate_add_on,
+            dec!(1),
+        );
+    }
+
+    #[test]
+    fn test_margined_netting_set() {
+        let mut ns = netting_set(vec![swap(
+            "a",
+            dec!(10_000_000),
+            dec!(100_000),
+            true,
+            dec!(10),
+        )]);
+        ns.margined = true;
+        ns.net_collateral = dec!(100_000);
+        ns.threshold = dec!(100_000);
+        ns.minimum_transfer_amount = dec!(50_000);
+        let out = calculate_saccr(&input(vec![ns])).unwrap().result;
+        let set = &out.netting_sets[0];
+        // RC = max(V - C, TH + MTA - NICA, 0) = 150,000
+        assert_eq!(set.replacement_cost, dec!(150_000));
+        // MF = 1.5 * sqrt(10/250) = 0.3
+        assert_close(set.trades[0].maturity_factor, dec!(0.3), dec!(0.000001));
+        assert!(!set.unmargined_cap_applied);
+    }
+
+    #[test]
+    fn test_margined_ead_capped_at_unmargined() {
+        // A short-dated trade with a large threshold is cheaper unmargined
+        let mut ns = netting_set(vec![swap("a", dec!(1_000_000), dec!(0), true, dec!(0.1))]);
+        ns.margined = true;
+        ns.threshold = dec!(5_000_000);
+        let out = calculate_saccr(&input(vec![ns.clone()])).unwrap().result;
+        let set = &out.netting_sets[0];
+        assert!(set.unmargined_cap_applied);
+        ns.margined = false;
+        let unmargined = calculate_saccr(&input(vec![ns])).unwrap().result;
+        assert_eq!(set.ead, unmargined.total_ead);
+    }
+
+    #[test]
+    fn test_fx_and_commodity_add_ons() {
+        let mut fx = swap("fx", dec!(5_000_000), dec!(0), true, dec!(1));
+        fx.asset_class = SaccrAssetClass::ForeignExchange;
+        fx.hedging_set = Some("EUR/USD".to_string());
+        let mut oil = swap("oil", dec!(2_000_000), dec!(0), true, dec!(1));
+        oil.asset_class = SaccrAssetClass::Commodity;
+        oil.hedging_set = Some("Energy".to_string());
+        oil.reference = Some("Brent".to_string());
+        let out = calculate_saccr(&input(vec![netting_set(vec![fx, oil])]))
+            .unwrap()
+            .result;
+        let set = &out.netting_sets[0];
+        let fx_add_on = set
+            .add_ons
+            .iter()
+            .find(|a| a.asset_class == SaccrAssetClass::ForeignExchange)
+            .unwrap();
+        assert_eq!(fx_add_on.add_on, dec!(200_000));
+        // Single commodity type: sqrt((0.4 A)^2 + (1 - 0.16) A^2) = A = 18% * 2m
+        let energy = set
+            .add_ons
+            .iter()
+            .find(|a| a.hedging_set == "Energy")
+            .unwrap();
+        assert_close(energy.add_on, dec!(360_000), dec!(0.01));
+        assert_close(set.aggregate_add_on, dec!(560_000), dec!(0.01));
+    }
+
+    #[test]
+    fn test_credit_entities_diversify() {
+        let cds = |id: &str, entity: &str| {
+            let mut t = swap(id, dec!(10_000_000), dec!(0), true, dec!(5));
+            t.asset_class = SaccrAssetClass::Credit;
+            t.hedging_set = None;
+            t.reference = Some(entity.to_string());
+            t.external_rating = Some("BBB".to_string());
+            t
+        };
+        let two_names = calculate_saccr(&input(vec![netting_set(vec![
+            cds("a", "Acme"),
+            cds("b", "Globex"),
+        ])]))
+        .unwrap()
+        .result;
+        let one_name = calculate_saccr(&input(vec![netting_set(vec![
+            cds("a", "Acme"),
+            cds("b", "Acme"),
+        ])]))
+        .unwrap()
+        .result;
+        assert!(
+            two_names.netting_sets[0].aggregate_add_on < one_name.netting_sets[0].aggregate_add_on
+        );
+    }
+
+    #[test]
+    fn test_option_supervisory_delta() {
+        let mut call = swap("opt", dec!(1_000_000), dec!(50_000), true, dec!(1));
+        call.asset_class = SaccrAssetClass::Equity;
+        call.reference = Some("ACME".to_string());
+        call.option = Some(SaccrOption {
+            option_type: OptionType::Call,
+            underlying_price: dec!(100),
+            strike: dec!(100),
+            exercise_years: dec!(1),
+        });
+        // ATM: d1 = 0.6, N(0.6) = 0.7257
+        let out = calculate_saccr(&input(vec![netting_set(vec![call.clone()])]))
+            .unwrap()
+            .result;
+        assert_close(
+            out.netting_sets[0].trades[0].supervisory_delta,
+            dec!(0.7257),
+            dec!(0.0005),
+        );
+
+        call.long = false;
+        call.option.as_mut().unwrap().option_type = OptionType::Put;
+        let out = calculate_saccr(&input(vec![netting_set(vec![call])]))
+            .unwrap()
+            .result;
+        // Sold put: +N(-d1) = 0.2743
+        assert_close(
+            out.netting_sets[0].trades[0].supervisory_delta,
+            dec!(0.2743),
+            dec!(0.0005),
+        );
+    }
+
+    #[test]
+    fn test_credit_exposures_feed_capital() {
+        let ns = netting_set(vec![swap("irs", dec!(10_000_000), dec!(0), true, dec!(10))]);
+        let out = calculate_saccr(&input(vec![ns])).unwrap().result;
+        let exposure = &out.credit_exposures[0];
+        assert_eq!(exposure.exposure_amount, out.total_ead);
+        assert_eq!(exposure.asset_class, AssetClass::Bank);
+        assert_eq!(exposure.external_rating.as_deref(), Some("A"));
+    }
+
+    #[test]
+    fn test_missing_hedging_set_rejected() {
+        let mut t = swap("irs", dec!(1_000_000), dec!(0), true, dec!(5));
+        t.hedging_set = None;
+        let err = calculate_saccr(&input(vec![netting_set(vec![t])])).unwrap_err();
+        match err {
+            CorpFinanceError::InvalidInput { field, .. } => {
+                assert_eq!(field, "netting_sets[0].trades[0].hedging_set")
+            }
+            other => panic!("Expected InvalidInput, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_empty_netting_sets_rejected() {
+        assert!(calculate_saccr(&input(vec![])).is_err());
+    }
+}
@@ -0,0 +1,97 @@
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+/// Seeded linear congruential generator (Knuth's MMIX constants), so a
+/// simulation with the same seed reproduces the same paths.
+pub struct Lcg(u64);
+
+impl Lcg {
+    pub fn new(seed: u64) -> Self {
+        Lcg(seed)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self
+            .0
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        self.0
+    }
+
+    /// Uniform draw on [0, 1) from the top 31 bits.
+    pub fn next_uniform(&mut self) -> Decimal {
+        Decimal::from(self.next_u64() >> 33) / Decimal::from(1u64 << 31)
+    }
+
+    /// Approximate standard normal draw (Irwin-Hall: sum of 12 uniforms - 6).
+    pub fn next_normal(&mut self) -> Decimal {
+        (0..12).map(|_| self.next_uniform()).sum::<Decimal>() - dec!(6)
+    }
+
+    /// Uniform draw on (0, 1) from the top 53 bits, never exactly 0 so it is
+    /// safe to take the log of.
+    pub fn next_uniform_f64(&mut self) -> f64 {
+        ((self.next_u64() >> 11) as f64 + 0.5) / (1u64 << 53) as f64
+    }
+
+    /// Standard normal draw via Box-Muller, for f64 simulations where tail
+    /// accuracy matters.
+    pub fn next_normal_f64(&mut self) -> f64 {
+        let u1 = self.next_uniform_f64();
+        let u2 = self.next_uniform_f64();
+        (-2.0 * u1.ln()).sqrt() * (std::f64::consts::TAU * u2).cos()
+    }
+}
+
+/// Nearest-rank percentile of a sorted, non-empty slice; `q` is clamped to
+/// [0, 1].
+pub fn percentile<T: Copy>(sorted: &[T], q: Decimal) -> T {
+    let n = sorted.len();
+    let rank = (q.clamp(Decimal::ZERO, Decimal::ONE) * Decimal::from(n)).ceil();
+    let idx = rank.to_usize().unwrap_or(n).clamp(1, n);
+    sorted[idx - 1]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_same_seed_same_stream() {
+        let mut a = Lcg::new(7);
+        let mut b = Lcg::new(7);
+        for _ in 0..100 {
+            assert_eq!(a.next_uniform(), b.next_uniform());
+        }
+        let draws: Vec<Decimal> = (0..1000).map(|_| a.next_uniform()).collect();
+        assert!(draws
+            .iter()
+            .all(|u| *u >= Decimal::ZERO && *u < Decimal::ONE));
+    }
+
+    #[test]
+    fn test_normal_moments() {
+        let mut rng = Lcg::new(42);
+        let n = 20_000;
+        let draws: Vec<f64> = (0..n).map(|_| rng.next_normal_f64()).collect();
+        let mean = draws.iter().sum::<f64>() / n as f64;
+        let var = draws.iter().map(|z| (z - mean).powi(2)).sum::<f64>() / n as f64;
+        assert!(mean.abs() < 0.03, "mean {mean}");
+        assert!((var - 1.0).abs() < 0.05, "variance {var}");
+    }
+
+    #[test]
+    fn test_percentile_nearest_rank_and_bounds() {
+        let sorted: Vec<Decimal> = (1..=20).map(Decimal::from).collect();
+        assert_eq!(percentile(&sorted, dec!(0.05)), dec!(1));
+        assert_eq!(percentile(&sorted, dec!(0.50)), dec!(10));
+        assert_eq!(percentile(&sorted, dec!(0.95)), dec!(19));
+        assert_eq!(percentile(&sorted, Decimal::ZERO), dec!(1));
+        assert_eq!(percentile(&sorted, Decimal::ONE), dec!(20));
+        // Out-of-range levels clamp rather than index past the ends
+        assert_eq!(percentile(&sorted, dec!(-0.5)), dec!(1));
+        assert_eq!(percentile(&sorted, dec!(7)), dec!(20));
+        assert_eq!(percentile(&[3.5f64], dec!(0.99)), 3.5);
+    }
+}
@@ -0,0 +1,606 @@
+//! Apply one market scenario to a whole market data set at once.
+//!
+//! Rate and spread curves, volatility surfaces, equity and commodity prices
+//! and FX rates are shifted together from a single shock vector, so every
+//! pricer downstream sees the same stressed market. Shocks use the factor
+//! names and units of the macro scenario library and the stress-testing
+//! engine.
+
+use std::collections::BTreeMap;
+use std::time::Instant;
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::curves::{Curve, VolSurface};
+use crate::error::CorpFinanceError;
+use crate::scenarios::scenario::{MacroScenario, MacroScenarioDefinition};
+use crate::types::{with_metadata, ComputationOutput, Money, Rate, Years};
+use crate::CorpFinanceResult;
+
+// ---------------------------------------------------------------------------
+// Types
+// ---------------------------------------------------------------------------
+
+/// A market data set: everything a set of pricers reads, keyed by id.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MarketDataSet {
+    /// Discount and projection curves, moved by rate shocks
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub rate_curves: BTreeMap<String, Curve>,
+    /// Credit spread curves, moved by spread shocks
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub spread_curves: BTreeMap<String, Curve>,
+    /// Implied volatility surfaces; a surface whose `underlying` is an
+    /// equity price id has its spot moved with that price
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub vol_surfaces: BTreeMap<String, VolSurface>,
+    /// Equity and index prices
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub equity_prices: BTreeMap<String, Money>,
+    /// Commodity prices
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub commodity_prices: BTreeMap<String, Money>,
+    /// FX rates keyed by pair (e.g. "EURUSD" or "EUR/USD"), in units of the
+    /// quote currency per unit of the base
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub fx_rates: BTreeMap<String, Decimal>,
+}
+
+/// A rate shift at one tenor; shifts between tenors are interpolated
+/// linearly and held flat beyond the first and last.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct KeyRateShift {
+    pub tenor: Years,
+    pub shift: Rate,
+}
+
+/// One consistent set of market shocks.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MarketScenario {
+    pub name: String,
+    /// Parallel shift to every rate curve (0.01 = +100bp)
+    #[serde(default)]
+    pub rate_shift: Rate,
+    /// Key-rate shifts added to `rate_shift`, for steepeners and flatteners
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub rate_key_shifts: Vec<KeyRateShift>,
+    /// Parallel widening of every spread curve
+    #[serde(default)]
+    pub spread_shift: Rate,
+    /// Relative equity move (-0.30 = a 30% fall)
+    #[serde(default)]
+    pub equity_shock: Rate,
+    /// Relative commodity move
+    #[serde(default)]
+    pub commodity_shock: Rate,
+    /// Trade-weighted USD move (positive = USD strengthens); pairs without
+    /// USD are left unchanged
+    #[serde(default)]
+    pub fx_usd: Rate,
+    /// Change in implied volatility (0.05 = +5 vol points)
+    #[serde(default)]
+    pub vol_shift: Rate,
+    /// Shocks for individual ids, replacing the class-wide shock for that
+    /// item (a parallel shift for curves, vol points for surfaces, a
+    /// relative move for prices and FX rates)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub overrides: BTreeMap<String, Rate>,
+}
+
+/// Input for shifting a market data set under one or more scenarios.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MarketShiftInput {
+    pub market: MarketDataSet,
+    /// Scenarios to apply
+    #[serde(default)]
+    pub scenarios: Vec<MarketScenario>,
+    /// Named scenarios from the macro library, applied after `scenarios`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub macro_scenarios: Vec<MacroScenario>,
+    /// Scale applied to library scenario shocks (default 1.0)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub macro_severity: Option<Decimal>,
+}
+
+/// The market data set after one scenario.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShiftedMarket {
+    pub scenario: MarketScenario,
+    pub market: MarketDataSet,
+}
+
+/// Output of market data shifting.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MarketShiftOutput {
+    pub scenarios: Vec<ShiftedMarket>,
+}
+
+// ---------------------------------------------------------------------------
+// Scenario construction
+// ---------------------------------------------------------------------------
+
+impl MarketScenario {
+    /// Build a scenario from (factor, shock) pairs named as in
+    /// `quant_risk::stress_testing::MarketShock`: "equity_market",
+    /// "interest_rates", "credit_spreads", "fx_usd", "commodities" and
+    /// "volatility". Unknown factors are rejected.
+    pub fn from_factor_shocks(
+        name: impl Into<String>,
+        shocks: &[(String, Decimal)],
+    ) -> CorpFinanceResult<Self> {
+        let mut scenario = MarketScenario {
+            name: name.into(),
+            ..Default::default()
+        };
+        for (factor, shock) in shocks {
+            let slot = match factor.as_str() {
+                "equity_market" => &mut scenario.equity_shock,
+                "interest_rates" => &mut scenario.rate_shift,
+                "credit_spreads" => &mut scenario.spread_shift,
+                "fx_usd" => &mut scenario.fx_usd,
+                "commodities" => &mut scenario.commodity_shock,
+                "volatility" => &mut scenario.vol_shift,
+                other => {
+                    return Err(CorpFinanceError::InvalidInput {
+                        field: "factor".into(),
+                        reason: format!("Unknown market factor '{other}'"),
+                    })
+                }
+            };
+            *slot += *shock;
+        }
+        Ok(scenario)
+    }
+
+    /// Total rate shift at `tenor`: the parallel shift plus the key-rate
+    /// shifts interpolated to that tenor.
+    pub fn rate_shift_at(&self, tenor: Years) -> Rate {
+        let keys = &self.rate_key_shifts;
+        let key = match keys.len() {
+            0 => Decimal::ZERO,
+            _ if tenor <= keys[0].tenor => keys[0].shift,
+            n if tenor >= keys[n - 1].tenor => keys[n - 1].shift,
+            _ => {
+                let idx = keys.partition_point(|k| k.tenor < tenor);
+                let (k0, k1) = (&keys[idx - 1], &keys[idx]);
+                k0.shift + (tenor - k0.tenor) / (k1.tenor - k0.tenor) * (k1.shift - k0.shift)
+            }
+        };
+        self.rate_shift + key
+    }
+
+    fn validate(&self) -> CorpFinanceResult<()> {
+        let field = |name: &str| format!("scenarios.{}.{name}", self.name);
+        if self
+            .rate_key_shifts
+            .windows(2)
+            .any(|w| w[1].tenor <= w[0].tenor)
+        {
+            return Err(CorpFinanceError::InvalidInput {
+                field: field("rate_key_shifts"),
+                reason: "Key-rate shifts must be sorted by ascending tenor".into(),
+            });
+        }
+        for (name, shock) in [
+            ("equity_shock", self.equity_shock),
+            ("commodity_shock", self.commodity_shock),
+            ("fx_usd", self.fx_usd),
+        ] {
+            if shock <= Decimal::NEGATIVE_ONE {
+                return Err(CorpFinanceError::InvalidInput {
+                    field: field(name),
+                    reason: "A relative move must be greater than -100%".into(),
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+impl From<&MacroScenarioDefinition> for MarketScenario {
+    fn from(def: &MacroScenarioDefinition) -> Self {
+        let s = &def.shocks;
+        MarketScenario {
+            name: def.name.clone(),
+            rate_shift: s.interest_rates,
+            spread_shift: s.credit_spreads,
+            equity_shock: s.equity_market,
+            commodity_shock: s.commodities,
+            fx_usd: s.fx_usd,
+            vol_shift: s.volatility,
+            ..Default::default()
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Shifting
+// ---------------------------------------------------------------------------
+
+/// Split an FX pair id into (base, quote), accepting "EURUSD" and "EUR/USD".
+fn fx_pair(id: &str) -> Option<(String, String)> {
+    let letters: String = id.chars().filter(|c| c.is_ascii_alphabetic()).collect();
+    if letters.len() != 6 {
+        return None;
+    }
+    let upper = letters.to_ascii_uppercase();
+    Some((upper[..3].to_string(), upper[3..].to_string()))
+}
+
+impl MarketDataSet {
+    /// Check every curve, surface, price and FX rate in the set.
+    pub fn validate(&self) -> CorpFinanceResult<()> {
+        for (id, curve) in self.rate_curves.iter().chain(&self.spread_curves) {
+            curve.validate(id)?;
+        }
+        for (id, surface) in &self.vol_surfaces {
+            surface.validate(id)?;
+        }
+        for (id, price) in self
+            .equity_prices
+            .iter()
+            .chain(&self.commodity_prices)
+            .chain(&self.fx_rates)
+        {
+            if *price <= Decimal::ZERO {
+                return Err(CorpFinanceError::InvalidInput {
+                    field: id.clone(),
+                    reason: "Prices and FX rates must be positive".into(),
+                });
+            }
+        }
+        for id in self.fx_rates.keys() {
+            if fx_pair(id).is_none() {
+                return Err(CorpFinanceError::InvalidInput {
+                    field: id.clone(),
+                    reason: "FX rates must be keyed by a six-letter pair such as EURUSD".into(),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Every id in the set.
+    fn ids(&self) -> impl Iterator<Item = &String> {
+        self.rate_curves
+            .keys()
+            .chain(self.spread_curves.keys())
+            .chain(self.vol_surfaces.keys())
+            .chain(self.equity_prices.keys())
+            .chain(self.commodity_prices.keys())
+            .chain(self.fx_rates.keys())
+    }
+
+    /// The set as it stands after `scenario`. Curves keep their
+    /// conventions; surfaces are shifted in vol and, when their underlying
+    /// is one of the equity prices, have their spot moved with it.
+    pub fn shifted(&self, scenario: &MarketScenario) -> CorpFinanceResult<MarketDataSet> {
+        scenario.validate()?;
+        let shock = |id: &str, class: Rate| scenario.overrides.get(id).copied().unwrap_or(class);
+        let relative =
+            |id: &str, value: Decimal, class: Rate| value * (Decimal::ONE + shock(id, class));
+
+        let rate_curves = self
+            .rate_curves
+            .iter()
+            .map(|(id, curve)| {
+                let shifted = match scenario.overrides.get(id) {
+                    Some(parallel) => curve.shifted(*parallel),
+                    None => curve.shifted_by(|t| scenario.rate_shift_at(t)),
+                };
+                (id.clone(), shifted)
+            })
+            .collect();
+        let spread_curves = self
+            .spread_curves
+            .iter()
+            .map(|(id, curve)| (id.clone(), curve.shifted(shock(id, scenario.spread_shift))))
+            .collect();
+        let equity_prices: BTreeMap<String, Money> = self
+            .equity_prices
+            .iter()
+            .map(|(id, p)| (id.clone(), relative(id, *p, scenario.equity_shock)))
+            .collect();
+        let vol_surfaces = self
+            .vol_surfaces
+            .iter()
+            .map(|(id, surface)| {
+                let vol_shift = shock(id, scenario.vol_shift);
+                let mut shifted = surface.clone();
+                for node in &mut shifted.nodes {
+                    node.vol = (node.vol + vol_shift).max(Decimal::ZERO);
+                }
+                if let (Some(spot), Some(underlying)) = (surface.spot, &surface.underlying) {
+                    if self.equity_prices.contains_key(underlying) {
+                        shifted.spot = Some(relative(underlying, spot, scenario.equity_shock));
+                    }
+                }
+                (id.clone(), shifted)
+            })
+            .collect();
+        let commodity_prices = self
+            .commodity_prices
+            .iter()
+            .map(|(id, p)| (id.clone(), relative(id, *p, scenario.commodity_shock)))
+            .collect();
+        let fx_rates = self
+            .fx_rates
+            .iter()
+            .map(|(id, rate)| {
+                let shifted = match (scenario.overrides.get(id), fx_pair(id)) {
+                    (Some(mv), _) => *rate * (Decimal::ONE + mv),
+                    // USD buys more of the quote currency
+                    (None, Some((base, _))) if base == "USD" => {
+                        *rate * (Decimal::ONE + scenario.fx_usd)
+                    }
+                    // Fewer dollars per unit of the base currency
+                    (None, Some((_, quote))) if quote == "USD" => {
+                        *rate / (Decimal::ONE + scenario.fx_usd)
+                    }
+                    _ => *rate,
+                };
+                (id.clone(), shifted)
+            })
+            .collect();
+
+        Ok(MarketDataSet {
+            rate_curves,
+            spread_curves,
+            vol_surfaces,
+            equity_prices,
+            commodity_prices,
+            fx_rates,
+        })
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Public API
+// ---------------------------------------------------------------------------
+
+/// Shift a market data set under every scenario in the input.
+pub fn shift_market_data(
+    input: &MarketShiftInput,
+) -> CorpFinanceResult<ComputationOutput<MarketShiftOutput>> {
+    let start = Instant::now();
+    let mut warnings: Vec<String> = Vec::new();
+
+    input.market.validate()?;
+    let severity = input.macro_severity.unwrap_or(Decimal::ONE);
+    if severity <= Decimal::ZERO {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "macro_severity".into(),
+            reason: "Severity must be positive".into(),
+        });
+    }
+    let mut scenarios = input.scenarios.clone();
+    scenarios.extend(
+        input
+            .macro_scenarios
+            .iter()
+            .map(|m| MarketScenario::from(&m.definition(severity))),
+    );
+    if scenarios.is_empty() {
+        return Err(CorpFinanceError::InsufficientData(
+            "At least one market scenario required".into(),
+        ));
+    }
+
+    let ids: Vec<&String> = input.market.ids().collect();
+    for scenario in &scenarios {
+        for id in scenario.overrides.keys() {
+            if !ids.contains(&id) {
+                warnings.push(format!(
+                    "Scenario '{}': override '{id}' matches nothing in the market data set",
+                    scenario.name
+                ));
+            }
+        }
+    }
+
+    let shifted = scenarios
+        .into_iter()
+        .map(|scenario| {
+            let market = input.market.shifted(&scenario)?;
+            Ok(ShiftedMarket { scenario, market })
+        })
+        .collect::<CorpFinanceResult<Vec<_>>>()?;
+
+    let m = &input.market;
+    let assumptions = serde_json::json!({
+        "num_scenarios": shifted.len(),
+        "macro_scenarios": input.macro_scenarios,
+        "macro_severity": severity.to_string(),
+        "rate_curves": m.rate_curves.len(),
+        "spread_curves": m.spread_curves.len(),
+        "vol_surfaces": m.vol_surfaces.len(),
+        "prices": m.equity_prices.len() + m.commodity_prices.len(),
+        "fx_rates": m.fx_rates.len(),
+        "curve_shift": "zero rates at each node, conventions kept",
+        "vol_shift": "absolute vol points, floored at zero",
+    });
+
+    let elapsed = start.elapsed().as_micros() as u64;
+    Ok(with_metadata(
+        "Scenario-Consistent Market Data Shift",
+        &assumptions,
+        warnings,
+        elapsed,
+        MarketShiftOutput { scenarios: shifted },
+    ))
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::curves::{Compounding, VolNode};
+    use rust_decimal_macros::dec;
+
+    fn sample_market() -> MarketDataSet {
+        let sofr = Curve {
+            compounding: Some(Compounding::Continuous),
+            ..Curve::from_zero_rates([(dec!(1), dec!(0.04)), (dec!(10), dec!(0.045))])
+        };
+        let surface = VolSurface {
+            version: 1,
+            id: None,
+            underlying: Some("SPX".into()),
+            calibration_date: None,
+            day_count: None,
+            spot: Some(dec!(5000)),
+            strike_axis: Default::default(),
+            smile_interpolation: Default::default(),
+            time_interpolation: Default::default(),
+            extrapolation: Default::default(),
+            nodes: vec![VolNode {
+                expiry: dec!(1),
+                strike: dec!(5000),
+                vol: dec!(0.18),
+            }],
+        };
+        MarketDataSet {
+            rate_curves: BTreeMap::from([("USD-SOFR".into(), sofr)]),
+            spread_curves: BTreeMap::from([(
+                "BBB".into(),
+                Curve::from_zero_rates([(dec!(5), dec!(0.015))]),
+            )]),
+            vol_surfaces: BTreeMap::from([("SPX-VOL".into(), surface)]),
+            equity_prices: BTreeMap::from([("SPX".into(), dec!(5000))]),
+            commodity_prices: BTreeMap::from([("BRENT".into(), dec!(80))]),
+            fx_rates: BTreeMap::from([
+                ("EURUSD".into(), dec!(1.10)),
+                ("USD/JPY".into(), dec!(150)),
+                ("EURGBP".into(), dec!(0.85)),
+            ]),
+        }
+    }
+
+    fn input(scenarios: Vec<MarketScenario>) -> MarketShiftInput {
+        MarketShiftInput {
+            market: sample_market(),
+            scenarios,
+            macro_scenarios: vec![],
+            macro_severity: None,
+        }
+    }
+
+    #[test]
+    fn test_whole_set_shifted_consistently() {
+        let scenario = MarketScenario {
+            name: "Risk-off".into(),
+            rate_shift: dec!(-0.01),
+            spread_shift: dec!(0.02),
+            equity_shock: dec!(-0.20),
+            commodity_shock: dec!(-0.25),
+            fx_usd: dec!(0.10),
+            vol_shift: dec!(0.12),
+            ..Default::default()
+        };
+        let out = shift_market_data(&input(vec![scenario])).unwrap().result;
+        let m = &out.scenarios[0].market;
+
+        let sofr = &m.rate_curves["USD-SOFR"];
+        assert_eq!(sofr.nodes[0].value, dec!(0.03));
+        assert_eq!(sofr.compounding, Some(Compounding::Continuous));
+        assert_eq!(m.spread_curves["BBB"].nodes[0].value, dec!(0.035));
+        assert_eq!(m.equity_prices["SPX"], dec!(4000));
+        assert_eq!(m.commodity_prices["BRENT"], dec!(60));
+        assert_eq!(m.fx_rates["EURUSD"], dec!(1.10) / dec!(1.10));
+        assert_eq!(m.fx_rates["USD/JPY"], dec!(165));
+        assert_eq!(m.fx_rates["EURGBP"], dec!(0.85));
+
+        let surface = &m.vol_surfaces["SPX-VOL"];
+        assert_eq!(surface.nodes[0].vol, dec!(0.30));
+        // The surface's spot moves with its underlying
+        assert_eq!(surface.spot, Some(dec!(4000)));
+    }
+
+    #[test]
+    fn test_key_rate_shifts_twist_curve() {
+        let scenario = MarketScenario {
+            name: "Bear steepener".into(),
+            rate_key_shifts: vec![
+                KeyRateShift {
+                    tenor: dec!(2),
+                    shift: dec!(0.0025),
+                },
+                KeyRateShift {
+                    tenor: dec!(10),
+                    shift: dec!(0.01),
+                },
+            ],
+            ..Default::default()
+        };
+        assert_eq!(scenario.rate_shift_at(dec!(1)), dec!(0.0025));
+        assert_eq!(scenario.rate_shift_at(dec!(6)), dec!(0.00625));
+        let m = sample_market().shifted(&scenario).unwrap();
+        let sofr = &m.rate_curves["USD-SOFR"];
+        assert_eq!(sofr.nodes[0].value, dec!(0.0425));
+        assert_eq!(sofr.nodes[1].value, dec!(0.055));
+    }
+
+    #[test]
+    fn test_overrides_replace_class_shock() {
+        let scenario = MarketScenario {
+            name: "Idiosyncratic".into(),
+            equity_shock: dec!(-0.10),
+            overrides: BTreeMap::from([
+                ("SPX".into(), dec!(-0.50)),
+                ("EURGBP".into(), dec!(0.02)),
+                ("MISSING".into(), dec!(0.01)),
+            ]),
+            ..Default::default()
+        };
+        let out = shift_market_data(&input(vec![scenario])).unwrap();
+        let m = &out.result.scenarios[0].market;
+        assert_eq!(m.equity_prices["SPX"], dec!(2500));
+        assert_eq!(m.fx_rates["EURGBP"], dec!(0.867));
+        assert_eq!(out.warnings.len(), 1);
+        assert!(out.warnings[0].contains("MISSING"));
+    }
+
+    #[test]
+    fn test_macro_scenario_matches_library_shocks() {
+        let out = shift_market_data(&MarketShiftInput {
+            macro_scenarios: vec![MacroScenario::RateShock300bp],
+            macro_severity: Some(dec!(0.5)),
+            ..input(vec![])
+        })
+        .unwrap()
+        .result;
+        let shifted = &out.scenarios[0];
+        assert_eq!(shifted.scenario.rate_shift, dec!(0.015));
+        assert_eq!(
+            shifted.market.rate_curves["USD-SOFR"].nodes[0].value,
+            dec!(0.055)
+        );
+
+        let from_factors = MarketScenario::from_factor_shocks(
+            "Rate Shock +300bp",
+            &MacroScenario::RateShock300bp
+                .definition(dec!(0.5))
+                .market_shocks(),
+        )
+        .unwrap();
+        assert_eq!(from_factors, shifted.scenario);
+    }
+
+    #[test]
+    fn test_validation() {
+        assert!(shift_market_data(&input(vec![])).is_err());
+        let mut bad_fx = input(vec![MarketScenario::default()]);
+        bad_fx.market.fx_rates.insert("EURO".into(), dec!(1));
+        assert!(shift_market_data(&bad_fx).is_err());
+        let crash = MarketScenario {
+            equity_shock: dec!(-1),
+            ..Default::default()
+        };
+        assert!(shift_market_data(&input(vec![crash])).is_err());
+        assert!(MarketScenario::from_factor_shocks("x", &[("gold".into(), dec!(0.1))]).is_err());
+    }
+}
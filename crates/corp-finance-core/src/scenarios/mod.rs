@@ -1,2 +1,3 @@
+pub mod market_shift;
 pub mod scenario;
 pub mod sensitivity;
@@ -33,6 +33,190 @@ pub struct ScenarioOutput {
     pub probability_weighted_value: Decimal,
 }
 
+/// Predefined macro scenarios available by name
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MacroScenario {
+    /// Global financial crisis, Sep 2008 - Mar 2009
+    Gfc2008,
+    /// COVID-19 sell-off, Feb - Mar 2020
+    Covid2020,
+    /// 1973-74 oil shock and stagflation
+    Stagflation1970s,
+    /// Hypothetical parallel +300bp rate shock
+    RateShock300bp,
+}
+
+/// Market and macro shock vector for a named scenario.
+///
+/// Market factors use the same names and units as
+/// `quant_risk::stress_testing::MarketShock` (returns and rate / spread
+/// changes as decimals); macro variables are absolute levels or changes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MacroShockVector {
+    pub equity_market: Rate,
+    pub interest_rates: Rate,
+    pub credit_spreads: Rate,
+    /// Trade-weighted USD move (positive = USD strengthens)
+    pub fx_usd: Rate,
+    pub commodities: Rate,
+    /// Change in implied volatility (e.g. 0.40 = +40 vol points)
+    pub volatility: Rate,
+    /// Peak-to-trough real GDP change
+    pub gdp_growth: Rate,
+    /// Peak headline inflation over the episode
+    pub inflation: Rate,
+    /// Change in the unemployment rate
+    pub unemployment_change: Rate,
+}
+
+/// A library scenario: identifier, description and shocks
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MacroScenarioDefinition {
+    pub scenario: MacroScenario,
+    pub name: String,
+    pub description: String,
+    /// True when calibrated to an observed episode rather than hypothetical
+    pub historical: bool,
+    pub shocks: MacroShockVector,
+}
+
+impl MacroScenario {
+    pub const ALL: [MacroScenario; 4] = [
+        MacroScenario::Gfc2008,
+        MacroScenario::Covid2020,
+        MacroScenario::Stagflation1970s,
+        MacroScenario::RateShock300bp,
+    ];
+
+    /// Full definition of the scenario, with shocks scaled by `severity`
+    /// (1.0 = as calibrated).
+    pub fn definition(self, severity: Decimal) -> MacroScenarioDefinition {
+        let (name, description, historical, s) = match self {
+            MacroScenario::Gfc2008 => (
+                "GFC 2008",
+                "Lehman default, credit freeze and global recession",
+                true,
+                [
+                    dec!(-0.38),
+                    dec!(-0.02),
+                    dec!(0.04),
+                    dec!(0.10),
+                    dec!(-0.35),
+                    dec!(0.50),
+                    dec!(-0.04),
+                    dec!(0.05),
+                    dec!(0.05),
+                ],
+            ),
+            MacroScenario::Covid2020 => (
+                "COVID March 2020",
+                "Pandemic lockdowns, dash for cash and emergency easing",
+                true,
+                [
+                    dec!(-0.34),
+                    dec!(-0.01),
+                    dec!(0.03),
+                    dec!(0.05),
+                    dec!(-0.30),
+                    dec!(0.65),
+                    dec!(-0.09),
+                    dec!(0.02),
+                    dec!(0.10),
+                ],
+            ),
+            MacroScenario::Stagflation1970s => (
+                "1970s Stagflation",
+                "Oil embargo, double-digit inflation and policy tightening",
+                true,
+                [
+                    dec!(-0.45),
+                    dec!(0.04),
+                    dec!(0.03),
+                    dec!(-0.10),
+                    dec!(0.60),
+                    dec!(0.25),
+                    dec!(-0.03),
+                    dec!(0.12),
+                    dec!(0.04),
+                ],
+            ),
+            MacroScenario::RateShock300bp => (
+                "Rate Shock +300bp",
+                "Parallel 300bp rise in policy and long-end rates",
+                false,
+                [
+                    dec!(-0.15),
+                    dec!(0.03),
+                    dec!(0.01),
+                    dec!(0.05),
+                    dec!(-0.05),
+                    dec!(0.15),
+                    dec!(-0.01),
+                    dec!(0.04),
+                    dec!(0.01),
+                ],
+            ),
+        };
+        let [eq, ir, cs, fx, cmd, vol, gdp, infl, unemp] = s;
+        MacroScenarioDefinition {
+            scenario: self,
+            name: name.into(),
+            description: description.into(),
+            historical,
+            shocks: MacroShockVector {
+                equity_market: eq * severity,
+                interest_rates: ir * severity,
+                credit_spreads: cs * severity,
+                fx_usd: fx * severity,
+                commodities: cmd * severity,
+                volatility: vol * severity,
+                gdp_growth: gdp * severity,
+                // Inflation is a level, not a change; severity is not applied
+                inflation: infl,
+                unemployment_change: unemp * severity,
+            },
+        }
+    }
+}
+
+impl MacroScenarioDefinition {
+    /// Market factor shocks as (factor, shock) pairs, using the factor names
+    /// understood by the stress-testing engine. Zero shocks are omitted.
+    pub fn market_shocks(&self) -> Vec<(String, Decimal)> {
+        let s = &self.shocks;
+        [
+            ("equity_market", s.equity_market),
+            ("interest_rates", s.interest_rates),
+            ("credit_spreads", s.credit_spreads),
+            ("fx_usd", s.fx_usd),
+            ("commodities", s.commodities),
+            ("volatility", s.volatility),
+        ]
+        .into_iter()
+        .filter(|(_, v)| !v.is_zero())
+        .map(|(f, v)| (f.to_string(), v))
+        .collect()
+    }
+
+    /// Convert to a probability-weighted `Scenario` whose overrides carry
+    /// the full shock vector, for use with `analyze_scenarios`.
+    pub fn to_scenario(&self, probability: Rate) -> Scenario {
+        Scenario {
+            name: self.name.clone(),
+            probability,
+            overrides: serde_json::to_value(&self.shocks).unwrap_or_default(),
+        }
+    }
+}
+
+/// Return every scenario in the library at calibrated severity.
+pub fn macro_scenario_library() -> Vec<MacroScenarioDefinition> {
+    MacroScenario::ALL
+        .iter()
+        .map(|s| s.definition(Decimal::ONE))
+        .collect()
+}
+
 /// Run scenario analysis with pre-computed output values.
 ///
 /// Each scenario must include a probability and an output value.
@@ -277,4 +461,48 @@ mod tests {
         let values = vec![dec!(100), dec!(200)];
         assert!(analyze_scenarios(&input, &values, dec!(150)).is_err());
     }
+
+    #[test]
+    fn test_macro_library_complete() {
+        let lib = macro_scenario_library();
+        assert_eq!(lib.len(), 4);
+        let names: Vec<&str> = lib.iter().map(|d| d.name.as_str()).collect();
+        assert!(names.contains(&"GFC 2008"));
+        assert!(names.contains(&"Rate Shock +300bp"));
+        assert!(
+            !MacroScenario::RateShock300bp
+                .definition(Decimal::ONE)
+                .historical
+        );
+    }
+
+    #[test]
+    fn test_macro_severity_scaling() {
+        let full = MacroScenario::Gfc2008.definition(Decimal::ONE);
+        let half = MacroScenario::Gfc2008.definition(dec!(0.5));
+        assert_eq!(full.shocks.equity_market, dec!(-0.38));
+        assert_eq!(half.shocks.equity_market, dec!(-0.19));
+        assert_eq!(half.shocks.credit_spreads, dec!(0.02));
+        // Inflation is a level and does not scale
+        assert_eq!(half.shocks.inflation, full.shocks.inflation);
+    }
+
+    #[test]
+    fn test_macro_market_shocks_use_stress_factor_names() {
+        let def = MacroScenario::RateShock300bp.definition(Decimal::ONE);
+        let shocks = def.market_shocks();
+        assert!(shocks
+            .iter()
+            .any(|(f, v)| f == "interest_rates" && *v == dec!(0.03)));
+        assert_eq!(shocks.len(), 6);
+    }
+
+    #[test]
+    fn test_macro_to_scenario_overrides() {
+        let sc = MacroScenario::Stagflation1970s
+            .definition(Decimal::ONE)
+            .to_scenario(dec!(0.1));
+        assert_eq!(sc.probability, dec!(0.1));
+        assert_eq!(sc.overrides["commodities"], serde_json::json!("0.60"));
+    }
 }
@@ -1,9 +1,11 @@
+use rust_decimal::prelude::ToPrimitive;
 use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
 use serde::{Deserialize, Serialize};
 use std::time::Instant;
 
 use crate::error::CorpFinanceError;
+use crate::limits;
 use crate::types::*;
 use crate::CorpFinanceResult;
 
@@ -38,8 +40,9 @@ pub struct SensitivityOutput {
     pub base_case_position: (usize, usize),
 }
 
-/// Generate the sweep values for a sensitivity variable from min to max with step.
-fn generate_sweep_values(var: &SensitivityVariable) -> CorpFinanceResult<Vec<Decimal>> {
+/// Number of sweep values for a variable, computed without building them so
+/// oversized grids can be rejected up front.
+fn sweep_len(var: &SensitivityVariable) -> CorpFinanceResult<u64> {
     if var.step <= Decimal::ZERO {
         return Err(CorpFinanceError::InvalidInput {
             field: format!("variable:{}", var.name),
@@ -53,7 +56,27 @@ fn generate_sweep_values(var: &SensitivityVariable) -> CorpFinanceResult<Vec<Dec
         });
     }
 
-    let mut values = Vec::new();
+    let steps = (var.max - var.min)
+        .checked_div(var.step)
+        .and_then(|q| q.floor().to_u64());
+    Ok(match steps {
+        // Max is appended when the step doesn't land exactly on it
+        Some(k) => k + 1 + u64::from(var.min + Decimal::from(k) * var.step < var.max),
+        None => u64::MAX,
+    })
+}
+
+/// Reject a grid larger than the configured cell limit.
+fn check_grid_size(input: &SensitivityInput) -> CorpFinanceResult<u64> {
+    let cells = sweep_len(&input.variable_1)?.saturating_mul(sweep_len(&input.variable_2)?);
+    limits::check_grid_cells("variable_1 x variable_2", cells)?;
+    Ok(cells)
+}
+
+/// Generate the sweep values for a sensitivity variable from min to max with step.
+fn generate_sweep_values(var: &SensitivityVariable) -> CorpFinanceResult<Vec<Decimal>> {
+    let len = sweep_len(var)?;
+    let mut values = Vec::with_capacity(len.min(1 << 16) as usize);
     let mut current = var.min;
     while current <= var.max {
         values.push(current);
@@ -94,6 +117,7 @@ pub fn build_sensitivity_grid(
     let start = Instant::now();
     let warnings: Vec<String> = Vec::new();
 
+    check_grid_size(input)?;
     let v1_values = generate_sweep_values(&input.variable_1)?;
     let v2_values = generate_sweep_values(&input.variable_2)?;
 
@@ -146,14 +170,17 @@ where
     let start = Instant::now();
     let mut warnings: Vec<String> = Vec::new();
 
+    let cells = check_grid_size(input)?;
+    let deadline = limits::Deadline::start();
     let v1_values = generate_sweep_values(&input.variable_1)?;
     let v2_values = generate_sweep_values(&input.variable_2)?;
 
     let mut matrix = Vec::with_capacity(v1_values.len());
 
-    for v1 in &v1_values {
+    for (i, v1) in v1_values.iter().enumerate() {
         let mut row = Vec::with_capacity(v2_values.len());
-        for v2 in &v2_values {
+        for (j, v2) in v2_values.iter().enumerate() {
+            deadline.check(|| format!("{} of {cells} cells", i * v2_values.len() + j))?;
             match eval_fn(*v1, *v2) {
                 Ok(val) => row.push(val),
                 Err(e) => {
@@ -323,4 +350,59 @@ mod tests {
         // Midpoint of Growth 0.01-0.05 = 0.03 => index 2
         assert_eq!(out.base_case_position, (2, 2));
     }
+
+    #[test]
+    fn test_sweep_len_matches_values() {
+        for step in [dec!(1), dec!(0.3), dec!(0.25), dec!(7)] {
+            let var = SensitivityVariable {
+                name: "test".into(),
+                min: dec!(0),
+                max: dec!(5),
+                step,
+            };
+            let len = generate_sweep_values(&var).unwrap().len() as u64;
+            assert_eq!(sweep_len(&var).unwrap(), len, "step {step}");
+        }
+    }
+
+    #[test]
+    fn test_grid_cell_limit() {
+        let limits = limits::ComputeLimits {
+            max_grid_cells: Some(24),
+            ..Default::default()
+        };
+        let err = limits::with_limits(limits, || build_sensitivity_grid(&sample_input()));
+        assert!(matches!(
+            err.unwrap_err(),
+            CorpFinanceError::LimitExceeded { ref limit, .. } if limit == "max_grid_cells"
+        ));
+
+        // A step that would create billions of values is rejected before any
+        // are generated
+        let mut input = sample_input();
+        input.variable_1.step = dec!(0.0000000001);
+        let err = limits::with_limits(limits, || {
+            evaluate_sensitivity(&input, |_, _| Ok(Decimal::ONE))
+        });
+        assert!(err.is_err());
+    }
+
+    #[test]
+    fn test_soft_timeout_stops_evaluation() {
+        let limits = limits::ComputeLimits {
+            soft_timeout_ms: Some(0),
+            ..Default::default()
+        };
+        let err = limits::with_limits(limits, || {
+            evaluate_sensitivity(&sample_input(), |_, _| Ok(Decimal::ONE))
+        })
+        .unwrap_err();
+        match err {
+            CorpFinanceError::LimitExceeded { limit, detail } => {
+                assert_eq!(limit, "soft_timeout_ms");
+                assert!(detail.contains("0 of 25 cells"));
+            }
+            other => panic!("Expected LimitExceeded, got {other:?}"),
+        }
+    }
 }
@@ -4,12 +4,15 @@
 //! prepayment models, CDR/SDA default models, loss severity, recovery lag,
 //! servicing fees, and weighted average life (WAL) computation.
 
+use chrono::NaiveDate;
 use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
 use serde::{Deserialize, Serialize};
 use std::time::Instant;
 
 use crate::error::CorpFinanceError;
+use crate::fixed_income::calendars::DateAdjustment;
+use crate::fixed_income::daycount::add_months;
 use crate::types::{with_metadata, ComputationOutput, Money, Rate};
 use crate::CorpFinanceResult;
 
@@ -64,12 +67,22 @@ pub struct AbsMbsInput {
     pub servicing_fee_rate: Rate,
     /// Number of months to project.
     pub projection_months: u32,
+    /// Unadjusted distribution date of month 1; later months roll monthly
+    /// from it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub first_payment_date: Option<NaiveDate>,
+    /// Business-day adjustment of distribution dates.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub payment_adjustment: Option<DateAdjustment>,
 }
 
 /// A single period in the ABS/MBS cash flow projection.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AbsPeriod {
     pub month: u32,
+    /// Distribution date (when `first_payment_date` is given)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_date: Option<NaiveDate>,
     pub beginning_balance: Money,
     pub scheduled_principal: Money,
     pub scheduled_interest: Money,
@@ -283,6 +296,7 @@ pub fn model_abs_cashflows(
 
         periods.push(AbsPeriod {
             month,
+            payment_date: None,
             beginning_balance,
             scheduled_principal,
             scheduled_interest,
@@ -303,6 +317,23 @@ pub fn model_abs_cashflows(
         remaining_months = remaining_months.saturating_sub(1);
     }
 
+    // --- Distribution dates ---
+    match input.first_payment_date {
+        Some(first) => {
+            for period in periods.iter_mut() {
+                let date = add_months(first, period.month as i32 - 1);
+                period.payment_date = Some(match &input.payment_adjustment {
+                    Some(adj) => adj.adjust(date),
+                    None => date,
+                });
+            }
+        }
+        None if input.payment_adjustment.is_some() => {
+            warnings.push("Payment adjustment ignored without first_payment_date".into());
+        }
+        None => {}
+    }
+
     // --- WAL ---
     let weighted_average_life_years = if total_principal_collected > Decimal::ZERO {
         wal_numerator / total_principal_collected
@@ -612,6 +643,7 @@ fn nth_root(x: Decimal, n: u32) -> Decimal {
 fn zero_period(month: u32, balance: Money) -> AbsPeriod {
     AbsPeriod {
         month,
+        payment_date: None,
         beginning_balance: balance,
         scheduled_principal: Decimal::ZERO,
         scheduled_interest: Decimal::ZERO,
@@ -655,6 +687,8 @@ mod tests {
             recovery_lag_months: 6,
             servicing_fee_rate: dec!(0.0025),
             projection_months: 360,
+            first_payment_date: None,
+            payment_adjustment: None,
         }
     }
 
@@ -1535,4 +1569,35 @@ mod tests {
             );
         }
     }
+
+    // -----------------------------------------------------------------------
+    // 35. Distribution dates roll monthly and onto business days
+    // -----------------------------------------------------------------------
+    #[test]
+    fn test_payment_dates_business_day_adjusted() {
+        use crate::fixed_income::calendars::{BusinessDayConvention, HolidayCalendar};
+
+        let mut input = standard_input();
+        input.projection_months = 4;
+        assert!(model_abs_cashflows(&input).unwrap().result.periods[0]
+            .payment_date
+            .is_none());
+
+        input.first_payment_date = NaiveDate::from_ymd_opt(2024, 3, 25);
+        input.payment_adjustment = Some(DateAdjustment {
+            calendar: HolidayCalendar::UnitedStates,
+            convention: BusinessDayConvention::Following,
+        });
+        let out = model_abs_cashflows(&input).unwrap().result;
+        let dates: Vec<Option<NaiveDate>> = out.periods.iter().map(|p| p.payment_date).collect();
+        assert_eq!(
+            dates,
+            vec![
+                NaiveDate::from_ymd_opt(2024, 3, 25),
+                NaiveDate::from_ymd_opt(2024, 4, 25),
+                NaiveDate::from_ymd_opt(2024, 5, 28), // Sat, then Memorial Day
+                NaiveDate::from_ymd_opt(2024, 6, 25),
+            ]
+        );
+    }
 }
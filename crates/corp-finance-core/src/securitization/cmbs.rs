@@ -0,0 +1,977 @@
+//! CMBS loan-level cash flow modelling.
+//!
+//! Commercial pools are a few dozen large balloon loans, so defaults are
+//! driven by property cash flow rather than CPR/CDR curves. Each loan's NOI
+//! is projected; a loan defaults in term when its DSCR falls below a
+//! threshold and at maturity when the balloon cannot be refinanced at the
+//! market test (LTV and DSCR). Specially serviced loans carry an appraisal
+//! reduction amount (ARA) once reappraised, the ARA cuts interest advances
+//! through the appraisal subordinate entitlement reduction (ASER), and the
+//! master servicer advances P&I until the advances are deemed
+//! nonrecoverable. Liquidation proceeds reimburse advances with interest
+//! before any principal reaches the trust.
+
+use rust_decimal::Decimal;
+use rust_decimal::MathematicalOps;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+use std::time::Instant;
+
+use crate::error::CorpFinanceError;
+use crate::types::{with_metadata, ComputationOutput, Money, Rate};
+use crate::CorpFinanceResult;
+
+// ---------------------------------------------------------------------------
+// Types
+// ---------------------------------------------------------------------------
+
+/// A commercial mortgage loan in the pool.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CmbsLoan {
+    pub name: String,
+    /// Current principal balance.
+    pub balance: Money,
+    /// Annual note rate (30/360).
+    pub note_rate: Rate,
+    /// Interest-only months before amortisation starts.
+    #[serde(default)]
+    pub io_months: u32,
+    /// Amortisation term in months (e.g. 360). `None` for full-term IO.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub amortization_months: Option<u32>,
+    /// Month the balloon is due.
+    pub maturity_month: u32,
+    /// Current annual net operating income of the property.
+    pub noi: Money,
+    /// Annual NOI growth, applied each loan year (e.g. -0.05 for a decline).
+    #[serde(default)]
+    pub noi_growth: Rate,
+}
+
+/// Market test a balloon must pass to refinance.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RefinanceTest {
+    /// Maximum balloon / property value (e.g. 0.75).
+    pub max_ltv: Rate,
+    /// Minimum NOI / refinance debt service (e.g. 1.25).
+    pub min_dscr: Decimal,
+    /// Annual rate on the refinance loan.
+    pub rate: Rate,
+    /// Amortisation of the refinance loan in months (e.g. 360).
+    pub amortization_months: u32,
+    /// Cap rate used to value the property at maturity. Defaults to the
+    /// valuation cap rate; set higher to stress exit values.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exit_cap_rate: Option<Rate>,
+}
+
+/// Input parameters for CMBS cash flow modelling.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CmbsInput {
+    pub deal_name: String,
+    pub loans: Vec<CmbsLoan>,
+    /// Cap rate for appraisals and liquidation values (NOI / cap rate).
+    pub valuation_cap_rate: Rate,
+    pub refinance: RefinanceTest,
+    /// DSCR below which a performing loan defaults in term (e.g. 1.00).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub term_default_dscr: Option<Decimal>,
+    /// Months from default to the reappraisal that sets the ARA.
+    pub appraisal_lag_months: u32,
+    /// Months from default to liquidation.
+    pub workout_months: u32,
+    /// Liquidation costs as a share of property value.
+    pub liquidation_cost_pct: Rate,
+    /// Annual master servicing fee on outstanding balances.
+    pub servicing_fee_rate: Rate,
+    /// Annual special servicing fee on specially serviced balances.
+    pub special_servicing_fee_rate: Rate,
+    /// Annual interest the servicer earns on outstanding advances.
+    pub advance_rate: Rate,
+    /// Number of months to project.
+    pub projection_months: u32,
+}
+
+/// Why a loan transferred to special servicing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CmbsDefaultType {
+    /// DSCR fell below the term default threshold
+    Term,
+    /// Balloon failed the refinance test
+    Balloon,
+}
+
+/// Loan status at the end of the projection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CmbsLoanStatus {
+    Performing,
+    /// Balloon repaid through a refinancing
+    Refinanced,
+    /// Fully amortised before maturity
+    PaidOff,
+    /// Defaulted and awaiting liquidation
+    SpeciallyServiced,
+    Liquidated,
+}
+
+/// Life-of-deal outcome for a single loan.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CmbsLoanOutcome {
+    pub name: String,
+    pub status: CmbsLoanStatus,
+    pub default_type: Option<CmbsDefaultType>,
+    pub default_month: Option<u32>,
+    pub liquidation_month: Option<u32>,
+    /// Refinance DSCR at maturity
+    pub maturity_dscr: Option<Decimal>,
+    /// Balloon / exit value at maturity
+    pub maturity_ltv: Option<Rate>,
+    pub total_advances: Money,
+    pub peak_appraisal_reduction: Money,
+    pub total_aser: Money,
+    pub realized_loss: Money,
+    /// Realised loss / balance at liquidation
+    pub loss_severity: Rate,
+}
+
+/// Pool cash flows for a single month.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CmbsPeriod {
+    pub month: u32,
+    pub beginning_balance: Money,
+    /// Contractual interest on loans still in the trust
+    pub scheduled_interest: Money,
+    /// Interest paid by performing borrowers
+    pub interest_collected: Money,
+    pub scheduled_principal: Money,
+    /// Balloons repaid through refinancing
+    pub balloon_principal: Money,
+    pub interest_advanced: Money,
+    pub principal_advanced: Money,
+    /// Total appraisal reduction amount across the pool
+    pub appraisal_reduction: Money,
+    /// Interest advance withheld because of appraisal reductions
+    pub aser: Money,
+    pub liquidation_principal: Money,
+    pub realized_loss: Money,
+    pub servicing_fee: Money,
+    pub special_servicing_fee: Money,
+    /// Advance reimbursements that liquidation proceeds did not cover,
+    /// taken from pool collections
+    pub advance_reimbursement_shortfall: Money,
+    /// Net interest due to certificates but not available
+    pub interest_shortfall: Money,
+    /// Cash available to certificates
+    pub available_funds: Money,
+    pub ending_balance: Money,
+    pub specially_serviced_balance: Money,
+}
+
+/// Summary statistics for the CMBS projection.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CmbsSummary {
+    pub original_balance: Money,
+    pub total_interest_to_trust: Money,
+    pub total_principal_to_trust: Money,
+    pub term_defaults: u32,
+    pub balloon_defaults: u32,
+    pub total_advances: Money,
+    pub total_aser: Money,
+    pub total_interest_shortfall: Money,
+    pub total_realized_loss: Money,
+    pub cumulative_loss_rate: Rate,
+    pub weighted_average_life_years: Decimal,
+    pub ending_balance: Money,
+}
+
+/// Complete CMBS cash flow output.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CmbsOutput {
+    pub periods: Vec<CmbsPeriod>,
+    pub loans: Vec<CmbsLoanOutcome>,
+    pub summary: CmbsSummary,
+}
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Share of appraised value credited against the loan when sizing the ARA
+/// (standard CMBS pooling and servicing agreements use 90%).
+const APPRAISAL_CREDIT: Decimal = dec!(0.90);
+
+/// Balance below which a loan is treated as repaid.
+const BALANCE_EPSILON: Decimal = dec!(0.01);
+
+// ---------------------------------------------------------------------------
+// Internal state
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone)]
+struct LoanState {
+    balance: Money,
+    status: CmbsLoanStatus,
+    /// Level P&I payment, fixed when amortisation starts
+    payment: Option<Money>,
+    default_type: Option<CmbsDefaultType>,
+    default_month: Option<u32>,
+    liquidation_month: Option<u32>,
+    maturity_dscr: Option<Decimal>,
+    maturity_ltv: Option<Rate>,
+    /// Advances plus accrued advance interest owed to the servicer
+    advances_outstanding: Money,
+    advancing_stopped: bool,
+    appraisal_reduction: Money,
+    total_advances: Money,
+    peak_appraisal_reduction: Money,
+    total_aser: Money,
+    realized_loss: Money,
+    loss_severity: Rate,
+}
+
+/// Pool totals accumulated over the loans for one month.
+#[derive(Debug, Default)]
+struct MonthTotals {
+    scheduled_interest: Money,
+    interest_collected: Money,
+    scheduled_principal: Money,
+    balloon_principal: Money,
+    interest_advanced: Money,
+    principal_advanced: Money,
+    appraisal_reduction: Money,
+    aser: Money,
+    liquidation_principal: Money,
+    realized_loss: Money,
+    servicing_fee: Money,
+    special_servicing_fee: Money,
+    advance_reimbursement_shortfall: Money,
+}
+
+// ---------------------------------------------------------------------------
+// Public API
+// ---------------------------------------------------------------------------
+
+/// Model CMBS pool cash flows loan by loan.
+///
+/// Projects monthly collections, balloon refinancing, term and balloon
+/// defaults, appraisal reductions, ASER, servicer advancing and liquidation
+/// losses, and reports pool cash available to certificates.
+pub fn model_cmbs_cashflows(input: &CmbsInput) -> CorpFinanceResult<ComputationOutput<CmbsOutput>> {
+    let start = Instant::now();
+    let mut warnings: Vec<String> = Vec::new();
+
+    validate_input(input)?;
+    crate::limits::check_schedule_periods("projection_months", input.projection_months.into())?;
+
+    let original_balance: Money = input.loans.iter().map(|l| l.balance).sum();
+    let mut states: Vec<LoanState> = input
+        .loans
+        .iter()
+        .map(|l| LoanState {
+            balance: l.balance,
+            status: CmbsLoanStatus::Performing,
+            payment: None,
+            default_type: None,
+            default_month: None,
+            liquidation_month: None,
+            maturity_dscr: None,
+            maturity_ltv: None,
+            advances_outstanding: Decimal::ZERO,
+            advancing_stopped: false,
+            appraisal_reduction: Decimal::ZERO,
+            total_advances: Decimal::ZERO,
+            peak_appraisal_reduction: Decimal::ZERO,
+            total_aser: Decimal::ZERO,
+            realized_loss: Decimal::ZERO,
+            loss_severity: Decimal::ZERO,
+        })
+        .collect();
+
+    let mut periods: Vec<CmbsPeriod> = Vec::with_capacity(input.projection_months as usize);
+    let mut wal_numerator = Decimal::ZERO;
+    let mut total_principal = Decimal::ZERO;
+    let mut total_interest = Decimal::ZERO;
+    let mut total_shortfall = Decimal::ZERO;
+
+    for month in 1..=input.projection_months {
+        let beginning_balance: Money = states.iter().map(|s| s.balance).sum();
+        let mut totals = MonthTotals::default();
+
+        for (loan, state) in input.loans.iter().zip(states.iter_mut()) {
+            match state.status {
+                CmbsLoanStatus::Performing => {
+                    run_performing_month(input, loan, state, month, &mut totals)
+                }
+                CmbsLoanStatus::SpeciallyServiced => {
+                    run_special_servicing_month(input, loan, state, month, &mut totals)
+                }
+                _ => {}
+            }
+        }
+
+        let ending_balance: Money = states.iter().map(|s| s.balance).sum();
+        let specially_serviced_balance: Money = states
+            .iter()
+            .filter(|s| s.status == CmbsLoanStatus::SpeciallyServiced)
+            .map(|s| s.balance)
+            .sum();
+
+        let principal = totals.scheduled_principal
+            + totals.balloon_principal
+            + totals.principal_advanced
+            + totals.liquidation_principal;
+        let interest_available = totals.interest_collected + totals.interest_advanced
+            - totals.servicing_fee
+            - totals.special_servicing_fee
+            - totals.advance_reimbursement_shortfall;
+        let interest_shortfall =
+            (totals.scheduled_interest - totals.servicing_fee - interest_available)
+                .max(Decimal::ZERO);
+        let available_funds = interest_available + principal;
+
+        wal_numerator += Decimal::from(month) * principal;
+        total_principal += principal;
+        total_interest += interest_available;
+        total_shortfall += interest_shortfall;
+
+        periods.push(CmbsPeriod {
+            month,
+            beginning_balance,
+            scheduled_interest: totals.scheduled_interest,
+            interest_collected: totals.interest_collected,
+            scheduled_principal: totals.scheduled_principal,
+            balloon_principal: totals.balloon_principal,
+            interest_advanced: totals.interest_advanced,
+            principal_advanced: totals.principal_advanced,
+            appraisal_reduction: totals.appraisal_reduction,
+            aser: totals.aser,
+            liquidation_principal: totals.liquidation_principal,
+            realized_loss: totals.realized_loss,
+            servicing_fee: totals.servicing_fee,
+            special_servicing_fee: totals.special_servicing_fee,
+            advance_reimbursement_shortfall: totals.advance_reimbursement_shortfall,
+            interest_shortfall,
+            available_funds,
+            ending_balance,
+            specially_serviced_balance,
+        });
+    }
+
+    if available_negative(&periods) {
+        warnings.push(
+            "Advance reimbursements exceeded interest collections in at least one month; \
+             available funds went negative"
+                .into(),
+        );
+    }
+    let unresolved = states
+        .iter()
+        .filter(|s| s.status == CmbsLoanStatus::SpeciallyServiced)
+        .count();
+    if unresolved > 0 {
+        warnings.push(format!(
+            "{unresolved} specially serviced loan(s) not yet liquidated at the end of the projection"
+        ));
+    }
+
+    let loans: Vec<CmbsLoanOutcome> = input
+        .loans
+        .iter()
+        .zip(states.iter())
+        .map(|(loan, s)| CmbsLoanOutcome {
+            name: loan.name.clone(),
+            status: s.status,
+            default_type: s.default_type,
+            default_month: s.default_month,
+            liquidation_month: s.liquidation_month,
+            maturity_dscr: s.maturity_dscr,
+            maturity_ltv: s.maturity_ltv,
+            total_advances: s.total_advances,
+            peak_appraisal_reduction: s.peak_appraisal_reduction,
+            total_aser: s.total_aser,
+            realized_loss: s.realized_loss,
+            loss_severity: s.loss_severity,
+        })
+        .collect();
+
+    let total_realized_loss: Money = loans.iter().map(|l| l.realized_loss).sum();
+    let count_defaults = |kind: CmbsDefaultType| {
+        loans
+            .iter()
+            .filter(|l| l.default_type == Some(kind))
+            .count() as u32
+    };
+    let summary = CmbsSummary {
+        original_balance,
+        total_interest_to_trust: total_interest,
+        total_principal_to_trust: total_principal,
+        term_defaults: count_defaults(CmbsDefaultType::Term),
+        balloon_defaults: count_defaults(CmbsDefaultType::Balloon),
+        total_advances: loans.iter().map(|l| l.total_advances).sum(),
+        total_aser: loans.iter().map(|l| l.total_aser).sum(),
+        total_interest_shortfall: total_shortfall,
+        total_realized_loss,
+        cumulative_loss_rate: total_realized_loss / original_balance,
+        weighted_average_life_years: if total_principal.is_zero() {
+            Decimal::ZERO
+        } else {
+            wal_numerator / total_principal / dec!(12)
+        },
+        ending_balance: states.iter().map(|s| s.balance).sum(),
+    };
+
+    let output = CmbsOutput {
+        periods,
+        loans,
+        summary,
+    };
+
+    let elapsed = start.elapsed().as_micros() as u64;
+    let assumptions = serde_json::json!({
+        "deal_name": input.deal_name,
+        "num_loans": input.loans.len(),
+        "valuation_cap_rate": input.valuation_cap_rate.to_string(),
+        "refinance_max_ltv": input.refinance.max_ltv.to_string(),
+        "refinance_min_dscr": input.refinance.min_dscr.to_string(),
+        "term_default_dscr": input.term_default_dscr.map(|d| d.to_string()),
+        "appraisal_credit": APPRAISAL_CREDIT.to_string(),
+        "appraisal_lag_months": input.appraisal_lag_months,
+        "workout_months": input.workout_months,
+        "projection_months": input.projection_months,
+    });
+
+    Ok(with_metadata(
+        "CMBS loan-level cash flows: balloon refinance test, ARA/ASER, servicer advancing",
+        &assumptions,
+        warnings,
+        elapsed,
+        output,
+    ))
+}
+
+// ---------------------------------------------------------------------------
+// Monthly loan mechanics
+// ---------------------------------------------------------------------------
+
+fn run_performing_month(
+    input: &CmbsInput,
+    loan: &CmbsLoan,
+    state: &mut LoanState,
+    month: u32,
+    totals: &mut MonthTotals,
+) {
+    let interest = state.balance * loan.note_rate / dec!(12);
+    let amortizing = loan.amortization_months.is_some() && month > loan.io_months;
+    if amortizing && state.payment.is_none() {
+        let remaining = loan.amortization_months.unwrap_or(0);
+        state.payment = Some(level_payment(state.balance, loan.note_rate, remaining));
+    }
+    let payment = state.payment.filter(|_| amortizing).unwrap_or(interest);
+    let scheduled_principal = (payment - interest).max(Decimal::ZERO).min(state.balance);
+
+    totals.scheduled_interest += interest;
+    totals.servicing_fee += state.balance * input.servicing_fee_rate / dec!(12);
+
+    // Term default: the borrower stops paying and the servicer advances
+    if let Some(threshold) = input.term_default_dscr {
+        let debt_service = payment * dec!(12);
+        if debt_service > Decimal::ZERO && noi_at(loan, month) / debt_service < threshold {
+            state.status = CmbsLoanStatus::SpeciallyServiced;
+            state.default_type = Some(CmbsDefaultType::Term);
+            state.default_month = Some(month);
+            advance(
+                input,
+                loan,
+                state,
+                month,
+                interest,
+                scheduled_principal,
+                totals,
+            );
+            return;
+        }
+    }
+
+    totals.interest_collected += interest;
+    totals.scheduled_principal += scheduled_principal;
+    state.balance -= scheduled_principal;
+
+    if state.balance < BALANCE_EPSILON {
+        state.balance = Decimal::ZERO;
+        state.status = CmbsLoanStatus::PaidOff;
+        return;
+    }
+
+    if month == loan.maturity_month {
+        let balloon = state.balance;
+        let exit_cap = input
+            .refinance
+            .exit_cap_rate
+            .unwrap_or(input.valuation_cap_rate);
+        let value = noi_at(loan, month) / exit_cap;
+        let refi_debt_service = level_payment(
+            balloon,
+            input.refinance.rate,
+            input.refinance.amortization_months,
+        ) * dec!(12);
+        let dscr = if refi_debt_service.is_zero() {
+            Decimal::ZERO
+        } else {
+            noi_at(loan, month) / refi_debt_service
+        };
+        let ltv = if value > Decimal::ZERO {
+            balloon / value
+        } else {
+            Decimal::MAX
+        };
+        state.maturity_dscr = Some(dscr);
+        state.maturity_ltv = Some(ltv);
+
+        if dscr >= input.refinance.min_dscr && ltv <= input.refinance.max_ltv {
+            totals.balloon_principal += balloon;
+            state.balance = Decimal::ZERO;
+            state.status = CmbsLoanStatus::Refinanced;
+        } else {
+            state.status = CmbsLoanStatus::SpeciallyServiced;
+            state.default_type = Some(CmbsDefaultType::Balloon);
+            state.default_month = Some(month);
+        }
+    }
+}
+
+fn run_special_servicing_month(
+    input: &CmbsInput,
+    loan: &CmbsLoan,
+    state: &mut LoanState,
+    month: u32,
+    totals: &mut MonthTotals,
+) {
+    let months_in_workout = month - state.default_month.unwrap_or(month);
+    let value = noi_at(loan, month) / input.valuation_cap_rate;
+
+    // Servicer earns interest on what it has advanced
+    state.advances_outstanding += state.advances_outstanding * input.advance_rate / dec!(12);
+
+    if months_in_workout >= input.workout_months {
+        liquidate(input, state, month, value, totals);
+        return;
+    }
+
+    let interest = state.balance * loan.note_rate / dec!(12);
+    totals.scheduled_interest += interest;
+    totals.servicing_fee += state.balance * input.servicing_fee_rate / dec!(12);
+    totals.special_servicing_fee += state.balance * input.special_servicing_fee_rate / dec!(12);
+
+    if months_in_workout >= input.appraisal_lag_months {
+        state.appraisal_reduction = (state.balance + state.advances_outstanding
+            - APPRAISAL_CREDIT * value)
+            .max(Decimal::ZERO);
+        state.peak_appraisal_reduction = state
+            .peak_appraisal_reduction
+            .max(state.appraisal_reduction);
+    }
+    totals.appraisal_reduction += state.appraisal_reduction;
+
+    // Term defaults before maturity keep advancing scheduled amortisation;
+    // past maturity only interest is advanced
+    let scheduled_principal = match (state.default_type, state.payment) {
+        (Some(CmbsDefaultType::Term), Some(payment)) if month < loan.maturity_month => {
+            (payment - interest).max(Decimal::ZERO).min(state.balance)
+        }
+        _ => Decimal::ZERO,
+    };
+    advance(
+        input,
+        loan,
+        state,
+        month,
+        interest,
+        scheduled_principal,
+        totals,
+    );
+}
+
+/// Advance the scheduled payment net of ASER unless the servicer deems the
+/// advance nonrecoverable against the property's net liquidation value.
+fn advance(
+    input: &CmbsInput,
+    loan: &CmbsLoan,
+    state: &mut LoanState,
+    month: u32,
+    interest: Money,
+    scheduled_principal: Money,
+    totals: &mut MonthTotals,
+) {
+    let aser = (state.appraisal_reduction * loan.note_rate / dec!(12)).min(interest);
+    let interest_advance = interest - aser;
+    let proposed = interest_advance + scheduled_principal;
+
+    let recoverable = noi_at(loan, month) / input.valuation_cap_rate
+        * (Decimal::ONE - input.liquidation_cost_pct);
+    if state.advances_outstanding + proposed > recoverable {
+        state.advancing_stopped = true;
+    }
+    if state.advancing_stopped {
+        return;
+    }
+
+    state.total_aser += aser;
+    totals.aser += aser;
+    totals.interest_advanced += interest_advance;
+    totals.principal_advanced += scheduled_principal;
+    state.balance -= scheduled_principal;
+    state.advances_outstanding += proposed;
+    state.total_advances += proposed;
+}
+
+/// Sell the property, reimburse advances with interest, and pass what is
+/// left to the trust as principal.
+fn liquidate(
+    input: &CmbsInput,
+    state: &mut LoanState,
+    month: u32,
+    value: Money,
+    totals: &mut MonthTotals,
+) {
+    let net_proceeds = value * (Decimal::ONE - input.liquidation_cost_pct);
+    let reimbursement = state.advances_outstanding;
+    let principal_recovery = (net_proceeds - reimbursement)
+        .max(Decimal::ZERO)
+        .min(state.balance);
+    let shortfall = (reimbursement - net_proceeds).max(Decimal::ZERO);
+    let loss = state.balance - principal_recovery + shortfall;
+
+    totals.liquidation_principal += principal_recovery;
+    totals.advance_reimbursement_shortfall += shortfall;
+    totals.realized_loss += loss;
+
+    state.loss_severity = if state.balance.is_zero() {
+        Decimal::ZERO
+    } else {
+        loss / state.balance
+    };
+    state.realized_loss = loss;
+    state.balance = Decimal::ZERO;
+    state.advances_outstanding = Decimal::ZERO;
+    state.appraisal_reduction = Decimal::ZERO;
+    state.liquidation_month = Some(month);
+    state.status = CmbsLoanStatus::Liquidated;
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// NOI for the loan year containing `month`, grown annually.
+fn noi_at(loan: &CmbsLoan, month: u32) -> Money {
+    let years = (month.saturating_sub(1) / 12) as i64;
+    loan.noi * (Decimal::ONE + loan.noi_growth).powi(years)
+}
+
+/// Level monthly payment amortising `balance` over `months`.
+fn level_payment(balance: Money, annual_rate: Rate, months: u32) -> Money {
+    if months == 0 {
+        return balance;
+    }
+    let r = annual_rate / dec!(12);
+    if r.is_zero() {
+        return balance / Decimal::from(months);
+    }
+    let factor = (Decimal::ONE + r).powi(months as i64);
+    balance * r * factor / (factor - Decimal::ONE)
+}
+
+fn available_negative(periods: &[CmbsPeriod]) -> bool {
+    periods.iter().any(|p| p.available_funds < Decimal::ZERO)
+}
+
+// ---------------------------------------------------------------------------
+// Validation
+// ---------------------------------------------------------------------------
+
+fn validate_input(input: &CmbsInput) -> CorpFinanceResult<()> {
+    if input.loans.is_empty() {
+        return Err(CorpFinanceError::InsufficientData(
+            "At least one loan is required".into(),
+        ));
+    }
+    for loan in &input.loans {
+        if loan.balance <= Decimal::ZERO {
+            return Err(CorpFinanceError::InvalidInput {
+                field: format!("loans[{}].balance", loan.name),
+                reason: "Loan balance must be positive".into(),
+            });
+        }
+        if loan.note_rate < Decimal::ZERO {
+            return Err(CorpFinanceError::InvalidInput {
+                field: format!("loans[{}].note_rate", loan.name),
+                reason: "Note rate cannot be negative".into(),
+            });
+        }
+        if loan.maturity_month == 0 {
+            return Err(CorpFinanceError::InvalidInput {
+                field: format!("loans[{}].maturity_month", loan.name),
+                reason: "Maturity month must be greater than zero".into(),
+            });
+        }
+        if loan.amortization_months == Some(0) {
+            return Err(CorpFinanceError::InvalidInput {
+                field: format!("loans[{}].amortization_months", loan.name),
+                reason: "Amortisation term must be greater than zero".into(),
+            });
+        }
+        if loan.noi < Decimal::ZERO {
+            return Err(CorpFinanceError::InvalidInput {
+                field: format!("loans[{}].noi", loan.name),
+                reason: "NOI cannot be negative".into(),
+            });
+        }
+    }
+    if input.valuation_cap_rate <= Decimal::ZERO {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "valuation_cap_rate".into(),
+            reason: "Cap rate must be positive".into(),
+        });
+    }
+    if input
+        .refinance
+        .exit_cap_rate
+        .is_some_and(|c| c <= Decimal::ZERO)
+    {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "refinance.exit_cap_rate".into(),
+            reason: "Exit cap rate must be positive".into(),
+        });
+    }
+    if input.liquidation_cost_pct < Decimal::ZERO || input.liquidation_cost_pct > Decimal::ONE {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "liquidation_cost_pct".into(),
+            reason: "Liquidation costs must be between 0 and 1".into(),
+        });
+    }
+    if input.servicing_fee_rate < Decimal::ZERO || input.special_servicing_fee_rate < Decimal::ZERO
+    {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "servicing_fee_rate".into(),
+            reason: "Servicing fee rates cannot be negative".into(),
+        });
+    }
+    if input.projection_months == 0 {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "projection_months".into(),
+            reason: "Projection months must be greater than zero".into(),
+        });
+    }
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn loan(name: &str, noi: Money) -> CmbsLoan {
+        CmbsLoan {
+            name: name.into(),
+            balance: dec!(10_000_000),
+            note_rate: dec!(0.06),
+            io_months: 24,
+            amortization_months: Some(360),
+            maturity_month: 60,
+            noi,
+            noi_growth: Decimal::ZERO,
+        }
+    }
+
+    fn standard_input(loans: Vec<CmbsLoan>) -> CmbsInput {
+        CmbsInput {
+            deal_name: "TEST CMBS".into(),
+            loans,
+            valuation_cap_rate: dec!(0.07),
+            refinance: RefinanceTest {
+                max_ltv: dec!(0.75),
+                min_dscr: dec!(1.25),
+                rate: dec!(0.065),
+                amortization_months: 360,
+                exit_cap_rate: None,
+            },
+            term_default_dscr: Some(dec!(1.0)),
+            appraisal_lag_months: 3,
+            workout_months: 18,
+            liquidation_cost_pct: dec!(0.08),
+            servicing_fee_rate: dec!(0.0005),
+            special_servicing_fee_rate: dec!(0.0025),
+            advance_rate: dec!(0.05),
+            projection_months: 96,
+        }
+    }
+
+    /// Strong property: value 1.5M / 0.07 = 21.4M, balloon LTV ~45%
+    fn strong() -> CmbsLoan {
+        loan("STRONG", dec!(1_500_000))
+    }
+
+    /// Weak property: covers debt service (DSCR ~1.04) but value
+    /// 0.75M / 0.07 = 10.7M puts the balloon near 90% LTV
+    fn weak() -> CmbsLoan {
+        loan("WEAK", dec!(750_000))
+    }
+
+    #[test]
+    fn test_performing_loan_refinances_at_maturity() {
+        let result = model_cmbs_cashflows(&standard_input(vec![strong()])).unwrap();
+        let out = &result.result;
+        let outcome = &out.loans[0];
+        assert_eq!(outcome.status, CmbsLoanStatus::Refinanced);
+        assert!(outcome.maturity_ltv.unwrap() < dec!(0.75));
+
+        // IO for 24 months, then amortising; balloon repaid in month 60
+        assert_eq!(out.periods[0].scheduled_principal, Decimal::ZERO);
+        assert_eq!(out.periods[0].interest_collected, dec!(50_000));
+        assert!(out.periods[24].scheduled_principal > Decimal::ZERO);
+        assert!(out.periods[59].balloon_principal > dec!(9_000_000));
+        assert_eq!(out.summary.ending_balance, Decimal::ZERO);
+        assert_eq!(out.summary.total_realized_loss, Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_principal_conservation_without_losses() {
+        let result = model_cmbs_cashflows(&standard_input(vec![strong()])).unwrap();
+        assert_eq!(
+            result.result.summary.total_principal_to_trust.round_dp(6),
+            dec!(10_000_000)
+        );
+    }
+
+    #[test]
+    fn test_balloon_default_when_refinance_test_fails() {
+        let result = model_cmbs_cashflows(&standard_input(vec![weak()])).unwrap();
+        let outcome = &result.result.loans[0];
+        assert_eq!(outcome.default_type, Some(CmbsDefaultType::Balloon));
+        assert_eq!(outcome.default_month, Some(60));
+        assert!(outcome.maturity_ltv.unwrap() > dec!(0.75));
+        // Liquidated after the workout period
+        assert_eq!(outcome.liquidation_month, Some(78));
+        assert_eq!(outcome.status, CmbsLoanStatus::Liquidated);
+        assert!(outcome.realized_loss > Decimal::ZERO);
+        assert_eq!(result.result.summary.balloon_defaults, 1);
+    }
+
+    #[test]
+    fn test_exit_cap_rate_stress_causes_balloon_default() {
+        let mut input = standard_input(vec![strong()]);
+        input.refinance.exit_cap_rate = Some(dec!(0.15));
+        let result = model_cmbs_cashflows(&input).unwrap();
+        assert_eq!(
+            result.result.loans[0].default_type,
+            Some(CmbsDefaultType::Balloon)
+        );
+    }
+
+    #[test]
+    fn test_appraisal_reduction_and_aser() {
+        let result = model_cmbs_cashflows(&standard_input(vec![weak()])).unwrap();
+        let out = &result.result;
+
+        // Balloon default in month 60; reappraisal three months later
+        assert_eq!(out.periods[61].appraisal_reduction, Decimal::ZERO);
+        let ara = out.periods[62].appraisal_reduction;
+        assert!(ara > Decimal::ZERO, "ARA should be set after reappraisal");
+
+        // ASER = ARA x note rate / 12 and reduces the interest advance
+        let p = &out.periods[62];
+        assert_eq!(p.aser, ara * dec!(0.06) / dec!(12));
+        assert_eq!(p.interest_advanced, p.scheduled_interest - p.aser);
+        assert!(p.interest_shortfall > Decimal::ZERO);
+        assert!(out.loans[0].total_aser > Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_servicer_advances_then_reimbursed_on_liquidation() {
+        let result = model_cmbs_cashflows(&standard_input(vec![weak()])).unwrap();
+        let out = &result.result;
+        let outcome = &out.loans[0];
+        assert!(outcome.total_advances > Decimal::ZERO);
+
+        // Month 78 liquidation: net proceeds = 10.7M x 0.92, less advances
+        let liq = &out.periods[77];
+        let value = dec!(750_000) / dec!(0.07);
+        let net = value * dec!(0.92);
+        assert!(liq.liquidation_principal > Decimal::ZERO);
+        assert!(liq.liquidation_principal < net);
+        assert_eq!(
+            liq.liquidation_principal + liq.realized_loss,
+            liq.beginning_balance
+        );
+    }
+
+    #[test]
+    fn test_term_default_on_low_dscr() {
+        // NOI falls 30% a year; DSCR under 1.0 once amortisation starts
+        let mut l = loan("DECLINING", dec!(700_000));
+        l.noi_growth = dec!(-0.30);
+        let result = model_cmbs_cashflows(&standard_input(vec![l])).unwrap();
+        let outcome = &result.result.loans[0];
+        assert_eq!(outcome.default_type, Some(CmbsDefaultType::Term));
+        assert!(outcome.default_month.unwrap() < 60);
+        assert_eq!(result.result.summary.term_defaults, 1);
+    }
+
+    #[test]
+    fn test_nonrecoverable_advances_stop() {
+        // Collapsing NOI makes advances exceed liquidation value
+        let mut l = loan("COLLAPSE", dec!(700_000));
+        l.noi_growth = dec!(-0.80);
+        let mut input = standard_input(vec![l]);
+        input.workout_months = 36;
+        let result = model_cmbs_cashflows(&input).unwrap();
+        let out = &result.result;
+        let default_month = out.loans[0].default_month.unwrap() as usize;
+        let last_month = default_month + 35;
+        let stopped = out.periods[default_month..last_month]
+            .iter()
+            .any(|p| p.interest_advanced.is_zero() && p.scheduled_interest > Decimal::ZERO);
+        assert!(stopped, "advancing should stop once nonrecoverable");
+        assert!(out.summary.total_interest_shortfall > Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_pool_mixes_outcomes() {
+        let result = model_cmbs_cashflows(&standard_input(vec![strong(), weak()])).unwrap();
+        let out = &result.result;
+        assert_eq!(out.loans[0].status, CmbsLoanStatus::Refinanced);
+        assert_eq!(out.loans[1].status, CmbsLoanStatus::Liquidated);
+        let rate = out.summary.cumulative_loss_rate;
+        assert!(rate > Decimal::ZERO && rate < dec!(0.5));
+        assert!(out.periods[65].specially_serviced_balance > Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_unresolved_special_servicing_warns() {
+        let mut input = standard_input(vec![weak()]);
+        input.projection_months = 66;
+        let result = model_cmbs_cashflows(&input).unwrap();
+        assert_eq!(
+            result.result.loans[0].status,
+            CmbsLoanStatus::SpeciallyServiced
+        );
+        assert!(result
+            .warnings
+            .iter()
+            .any(|w| w.contains("not yet liquidated")));
+    }
+
+    #[test]
+    fn test_validation() {
+        assert!(model_cmbs_cashflows(&standard_input(vec![])).is_err());
+        let mut input = standard_input(vec![strong()]);
+        input.valuation_cap_rate = Decimal::ZERO;
+        assert!(model_cmbs_cashflows(&input).is_err());
+        let mut input = standard_input(vec![strong()]);
+        input.loans[0].maturity_month = 0;
+        assert!(model_cmbs_cashflows(&input).is_err());
+    }
+}
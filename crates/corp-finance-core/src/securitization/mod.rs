@@ -1,2 +1,3 @@
 pub mod abs_mbs;
+pub mod cmbs;
 pub mod tranching;
@@ -63,6 +63,47 @@ pub struct TranchingInput {
     pub ic_trigger: Option<Decimal>,
     /// Months during which principal can be reinvested (CLO feature)
     pub reinvestment_period_months: u32,
+    /// Pro-rata/sequential switching, loss triggers, turbo and excess-spread
+    /// trapping. Omitted, the deal pays sequentially and releases excess
+    /// interest once the reserve is topped up.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub waterfall_rules: Option<WaterfallRules>,
+}
+
+/// How collateral principal is split across tranches.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PrincipalAllocation {
+    /// Most senior outstanding tranche first
+    #[default]
+    Sequential,
+    /// In proportion to outstanding balances
+    ProRata,
+}
+
+/// Structural features layered on the base waterfall.
+///
+/// Any breached trigger (OC, IC or cumulative loss) switches a pro-rata deal
+/// to sequential pay for that period; with `sticky_triggers` the switch is
+/// permanent. OC/IC breaches also divert all excess interest to principal.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct WaterfallRules {
+    /// Principal allocation while every trigger passes
+    #[serde(default)]
+    pub principal_allocation: PrincipalAllocation,
+    /// Cumulative losses / original collateral above which the deal turns
+    /// sequential (e.g. 0.05 = 5%)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cumulative_loss_trigger: Option<Rate>,
+    /// Once breached, stay sequential for the rest of the deal
+    #[serde(default)]
+    pub sticky_triggers: bool,
+    /// Pay excess interest as principal, senior first, even when tests pass
+    #[serde(default)]
+    pub turbo: bool,
+    /// Annualised excess spread below which excess interest is trapped in
+    /// the reserve rather than released (e.g. 0.02 = 2%)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub excess_spread_trap: Option<Rate>,
 }
 
 // ---------------------------------------------------------------------------
@@ -116,6 +157,8 @@ pub struct TranchePayment {
     pub interest_paid: Money,
     pub principal_paid: Money,
     pub interest_shortfall: Money,
+    /// Balance after losses and this period's principal
+    pub ending_balance: Money,
 }
 
 /// Waterfall detail for a single period.
@@ -129,6 +172,33 @@ pub struct WaterfallPeriod {
     pub reserve_balance: Money,
     pub oc_test_result: Option<bool>,
     pub ic_test_result: Option<bool>,
+    /// Cumulative loss trigger passed (None when no trigger is set)
+    pub loss_test_result: Option<bool>,
+    /// Allocation actually applied this period
+    pub principal_allocation: PrincipalAllocation,
+    /// Excess interest redirected to principal on an OC/IC breach
+    pub interest_diverted: Money,
+    /// Excess interest paid as principal under the turbo feature
+    pub turbo_principal: Money,
+    /// Excess interest trapped in the reserve
+    pub excess_spread_trapped: Money,
+    /// Cash released to the residual holder
+    pub residual_released: Money,
+}
+
+/// When triggers first breached and what the structural features did.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TriggerSummary {
+    pub first_oc_breach_period: Option<u32>,
+    pub first_ic_breach_period: Option<u32>,
+    pub first_loss_trigger_period: Option<u32>,
+    /// First period a pro-rata deal paid sequentially
+    pub sequential_switch_period: Option<u32>,
+    pub periods_in_breach: u32,
+    pub total_interest_diverted: Money,
+    pub total_turbo_principal: Money,
+    pub total_excess_spread_trapped: Money,
+    pub total_residual_released: Money,
 }
 
 /// Summary metrics for the entire deal.
@@ -149,6 +219,7 @@ pub struct TranchingOutput {
     pub tranche_results: Vec<TrancheResult>,
     pub credit_enhancement: CreditEnhancement,
     pub waterfall_periods: Vec<WaterfallPeriod>,
+    pub trigger_summary: TriggerSummary,
     pub deal_summary: DealSummary,
 }
 
@@ -246,14 +317,18 @@ pub fn analyze_tranching(
     };
 
     // --- Waterfall execution ---
+    let rules = input.waterfall_rules.clone().unwrap_or_default();
     let mut waterfall_periods: Vec<WaterfallPeriod> = Vec::new();
+    let mut trigger_summary = TriggerSummary::default();
     let mut reserve_balance = input.reserve_account;
     let mut collateral_balance = input.collateral_balance;
     let mut cumulative_losses = Decimal::ZERO;
+    let mut sequential_locked = false;
 
     for cf in &input.collateral_cashflows {
         let period = cf.period;
         let period_losses = cf.losses;
+        let opening_collateral = collateral_balance;
 
         // 1. Allocate losses bottom-up
         cumulative_losses += period_losses;
@@ -280,7 +355,7 @@ pub fn analyze_tranching(
             reserve_balance -= draw;
         }
 
-        // 3. OC and IC tests
+        // 3. OC, IC and cumulative loss tests
         let oc_test = input.oc_trigger.map(|trigger| {
             let senior_balance = tranche_states
                 .first()
@@ -289,10 +364,7 @@ pub fn analyze_tranching(
             if senior_balance.is_zero() {
                 true
             } else {
-                let _oc_ratio = (collateral_balance - cumulative_losses
-                    + collateral_balance.min(Decimal::ZERO).abs())
-                .max(Decimal::ZERO);
-                // Simpler: use remaining collateral / senior outstanding
+                // Remaining collateral plus reserve cash / senior outstanding
                 let effective_collateral = collateral_balance.max(Decimal::ZERO) + reserve_balance;
                 let ratio = effective_collateral / senior_balance;
                 ratio >= trigger
@@ -317,24 +389,47 @@ pub fn analyze_tranching(
             }
         });
 
-        let oc_passed = oc_test.unwrap_or(true);
-        let ic_passed = ic_test.unwrap_or(true);
+        let loss_test = rules
+            .cumulative_loss_trigger
+            .map(|trigger| cumulative_losses / input.collateral_balance <= trigger);
+
+        let tests_passed = oc_test.unwrap_or(true) && ic_test.unwrap_or(true);
+        let triggers_passed = tests_passed && loss_test.unwrap_or(true);
+
+        if oc_test == Some(false) {
+            trigger_summary.first_oc_breach_period.get_or_insert(period);
+        }
+        if ic_test == Some(false) {
+            trigger_summary.first_ic_breach_period.get_or_insert(period);
+        }
+        if loss_test == Some(false) {
+            trigger_summary
+                .first_loss_trigger_period
+                .get_or_insert(period);
+        }
+        if !triggers_passed {
+            trigger_summary.periods_in_breach += 1;
+            if rules.sticky_triggers {
+                sequential_locked = true;
+            }
+        }
+
+        let allocation = if triggers_passed && !sequential_locked {
+            rules.principal_allocation
+        } else {
+            PrincipalAllocation::Sequential
+        };
+        if allocation != rules.principal_allocation {
+            trigger_summary
+                .sequential_switch_period
+                .get_or_insert(period);
+        }
 
         // 4. Interest waterfall (pay in seniority order)
         let mut tranche_payments: Vec<TranchePayment> = Vec::new();
         let mut remaining_interest = available_interest;
 
-        for (idx, state) in tranche_states.iter_mut().enumerate() {
-            if state.current_balance <= Decimal::ZERO {
-                tranche_payments.push(TranchePayment {
-                    tranche_name: state.name.clone(),
-                    interest_paid: Decimal::ZERO,
-                    principal_paid: Decimal::ZERO,
-                    interest_shortfall: Decimal::ZERO,
-                });
-                continue;
-            }
-
+        for state in tranche_states.iter_mut() {
             let interest_due = compute_period_coupon(state);
             let interest_paid = interest_due.min(remaining_interest);
             let interest_shortfall = interest_due - interest_paid;
@@ -347,99 +442,104 @@ pub fn analyze_tranching(
                 interest_paid,
                 principal_paid: Decimal::ZERO, // filled in principal waterfall
                 interest_shortfall,
+                ending_balance: Decimal::ZERO,
             });
-
-            // If OC or IC test failed, divert remaining junior interest
-            // to senior principal acceleration
-            if (!oc_passed || !ic_passed) && idx == 0 {
-                // After paying senior interest, any interest that would go
-                // to junior tranches gets redirected to senior principal
-                // We handle this after the interest loop
-            }
         }
 
-        // If OC/IC test failed, remaining interest (that would go to juniors)
-        // is redirected to senior principal paydown
-        let diverted_interest = if !oc_passed || !ic_passed {
-            remaining_interest
+        // Annualised excess spread on the opening collateral, used by the trap
+        let excess_spread_rate = if opening_collateral > Decimal::ZERO {
+            remaining_interest / opening_collateral * periods_per_year
         } else {
             Decimal::ZERO
         };
+        let trap_active = rules
+            .excess_spread_trap
+            .is_some_and(|floor| excess_spread_rate < floor);
+
+        // If OC/IC test failed, remaining interest (that would go to juniors)
+        // is redirected to principal paydown, senior first
+        let interest_diverted = if tests_passed {
+            Decimal::ZERO
+        } else {
+            remaining_interest
+        };
+        remaining_interest -= interest_diverted;
 
         // 5. Principal waterfall
         let in_reinvestment =
             period <= input.reinvestment_period_months && input.reinvestment_period_months > 0;
 
-        let mut remaining_principal = if in_reinvestment {
+        let collateral_principal = if in_reinvestment {
             // During reinvestment period, principal is reinvested (returned to pool).
             // Only diverted interest (from test failures) is used for principal paydown.
             collateral_balance += available_principal;
-            diverted_interest
+            Decimal::ZERO
         } else {
-            available_principal + diverted_interest
+            available_principal
         };
 
-        // Sequential pay: most senior first
-        for (idx, state) in tranche_states.iter_mut().enumerate() {
-            if state.current_balance <= Decimal::ZERO || remaining_principal <= Decimal::ZERO {
-                continue;
-            }
-
-            // If OC test failed and this is not the most senior tranche,
-            // skip principal (turbo amortisation directs all to senior)
-            if (!oc_passed || !ic_passed) && idx > 0 {
-                continue;
-            }
-
-            let principal_paid = state.current_balance.min(remaining_principal);
-            state.current_balance -= principal_paid;
-            state.total_principal_received += principal_paid;
-            remaining_principal -= principal_paid;
-
-            // WAL numerator: period * principal_paid
-            state.wal_numerator += Decimal::from(period) * principal_paid;
-
-            // Update the payment record
-            if let Some(payment) = tranche_payments.get_mut(idx) {
-                payment.principal_paid = principal_paid;
-            }
-        }
-
-        // If OC/IC passed and not in reinvestment, do sequential for remaining tranches
-        if oc_passed && ic_passed && !in_reinvestment && remaining_principal > Decimal::ZERO {
-            for (idx, state) in tranche_states.iter_mut().enumerate() {
-                if state.current_balance <= Decimal::ZERO || remaining_principal <= Decimal::ZERO {
-                    continue;
-                }
-                let principal_paid = state.current_balance.min(remaining_principal);
-                state.current_balance -= principal_paid;
-                state.total_principal_received += principal_paid;
-                remaining_principal -= principal_paid;
-                state.wal_numerator += Decimal::from(period) * principal_paid;
-
-                if let Some(payment) = tranche_payments.get_mut(idx) {
-                    payment.principal_paid += principal_paid;
-                }
-            }
-        }
+        let mut residual_released = pay_principal(
+            &mut tranche_states,
+            &mut tranche_payments,
+            period,
+            collateral_principal,
+            allocation,
+        );
+        residual_released += pay_principal(
+            &mut tranche_states,
+            &mut tranche_payments,
+            period,
+            interest_diverted,
+            PrincipalAllocation::Sequential,
+        );
 
-        // 6. Replenish reserve from any remaining interest
-        if oc_passed && ic_passed && remaining_interest > Decimal::ZERO {
+        // 6. Replenish reserve, then trap, turbo or release excess interest
+        let mut excess_spread_trapped = Decimal::ZERO;
+        let mut turbo_principal = Decimal::ZERO;
+        if tests_passed && remaining_interest > Decimal::ZERO {
             let max_reserve = input.reserve_account; // cap at initial level
             if reserve_balance < max_reserve {
                 let top_up = (max_reserve - reserve_balance).min(remaining_interest);
                 reserve_balance += top_up;
                 remaining_interest -= top_up;
             }
+
+            if trap_active {
+                excess_spread_trapped = remaining_interest;
+                reserve_balance += remaining_interest;
+                remaining_interest = Decimal::ZERO;
+            } else if rules.turbo {
+                let unused = pay_principal(
+                    &mut tranche_states,
+                    &mut tranche_payments,
+                    period,
+                    remaining_interest,
+                    PrincipalAllocation::Sequential,
+                );
+                turbo_principal = remaining_interest - unused;
+                remaining_interest = unused;
+            }
+        }
+
+        // Trapped cash above the reserve target is released once the trap lifts
+        if !trap_active && reserve_balance > input.reserve_account {
+            residual_released += reserve_balance - input.reserve_account;
+            reserve_balance = input.reserve_account;
         }
+        residual_released += remaining_interest;
 
         // 7. Record per-period cash flows for each tranche (for IRR calc)
-        for (idx, state) in tranche_states.iter_mut().enumerate() {
-            let payment = &tranche_payments[idx];
+        for (state, payment) in tranche_states.iter_mut().zip(tranche_payments.iter_mut()) {
+            payment.ending_balance = state.current_balance;
             let total_cf = payment.interest_paid + payment.principal_paid;
             state.cash_flows.push(total_cf);
         }
 
+        trigger_summary.total_interest_diverted += interest_diverted;
+        trigger_summary.total_turbo_principal += turbo_principal;
+        trigger_summary.total_excess_spread_trapped += excess_spread_trapped;
+        trigger_summary.total_residual_released += residual_released;
+
         waterfall_periods.push(WaterfallPeriod {
             period,
             available_interest: cf.interest,
@@ -449,6 +549,12 @@ pub fn analyze_tranching(
             reserve_balance,
             oc_test_result: oc_test,
             ic_test_result: ic_test,
+            loss_test_result: loss_test,
+            principal_allocation: allocation,
+            interest_diverted,
+            turbo_principal,
+            excess_spread_trapped,
+            residual_released,
         });
     }
 
@@ -568,6 +674,7 @@ pub fn analyze_tranching(
         tranche_results,
         credit_enhancement,
         waterfall_periods,
+        trigger_summary,
         deal_summary,
     };
 
@@ -580,6 +687,9 @@ pub fn analyze_tranching(
             "num_tranches": input.tranches.len(),
             "num_periods": input.collateral_cashflows.len(),
             "reinvestment_months": input.reinvestment_period_months,
+            "principal_allocation": rules.principal_allocation,
+            "turbo": rules.turbo,
+            "sticky_triggers": rules.sticky_triggers,
         }),
         warnings,
         elapsed,
@@ -654,6 +764,58 @@ fn compute_period_coupon(state: &TrancheState) -> Money {
     state.current_balance * state.coupon_rate / Decimal::from(state.payment_frequency)
 }
 
+/// Pay `amount` of principal across outstanding tranches. Pro-rata shares
+/// follow current balances; anything a retired tranche cannot absorb falls
+/// through senior first. Returns cash left once every tranche is retired.
+fn pay_principal(
+    tranche_states: &mut [TrancheState],
+    payments: &mut [TranchePayment],
+    period: u32,
+    mut amount: Money,
+    allocation: PrincipalAllocation,
+) -> Money {
+    let apply = |state: &mut TrancheState, payment: &mut TranchePayment, paid: Money| {
+        state.current_balance -= paid;
+        state.total_principal_received += paid;
+        // WAL numerator: period * principal_paid
+        state.wal_numerator += Decimal::from(period) * paid;
+        payment.principal_paid += paid;
+    };
+
+    if allocation == PrincipalAllocation::ProRata {
+        let outstanding: Money = tranche_states
+            .iter()
+            .map(|t| t.current_balance.max(Decimal::ZERO))
+            .sum();
+        if outstanding > Decimal::ZERO && amount > Decimal::ZERO {
+            let pool = amount.min(outstanding);
+            for (state, payment) in tranche_states.iter_mut().zip(payments.iter_mut()) {
+                if state.current_balance <= Decimal::ZERO {
+                    continue;
+                }
+                let share = (pool * state.current_balance / outstanding)
+                    .min(state.current_balance)
+                    .min(amount);
+                apply(state, payment, share);
+                amount -= share;
+            }
+        }
+    }
+
+    for (state, payment) in tranche_states.iter_mut().zip(payments.iter_mut()) {
+        if amount <= Decimal::ZERO {
+            break;
+        }
+        if state.current_balance <= Decimal::ZERO {
+            continue;
+        }
+        let paid = state.current_balance.min(amount);
+        apply(state, payment, paid);
+        amount -= paid;
+    }
+    amount
+}
+
 /// Allocate losses bottom-up (most junior tranche absorbs first).
 fn allocate_losses(tranche_states: &mut [TrancheState], mut losses: Money) {
     // Iterate from most junior (highest seniority number) to most senior
@@ -830,6 +992,7 @@ mod tests {
             oc_trigger: None,
             ic_trigger: None,
             reinvestment_period_months: 0,
+            waterfall_rules: None,
         }
     }
 
@@ -869,6 +1032,7 @@ mod tests {
             oc_trigger: None,
             ic_trigger: None,
             reinvestment_period_months: 0,
+            waterfall_rules: None,
         }
     }
 
@@ -1028,6 +1192,7 @@ mod tests {
             oc_trigger: Some(dec!(1.50)), // high trigger to force failure
             ic_trigger: None,
             reinvestment_period_months: 0,
+            waterfall_rules: None,
         };
 
         let result = analyze_tranching(&input).unwrap();
@@ -1080,6 +1245,7 @@ mod tests {
             oc_trigger: None,
             ic_trigger: Some(dec!(2.0)), // High IC trigger to force failure
             reinvestment_period_months: 0,
+            waterfall_rules: None,
         };
 
         let result = analyze_tranching(&input).unwrap();
@@ -1287,6 +1453,7 @@ mod tests {
             oc_trigger: None,
             ic_trigger: None,
             reinvestment_period_months: 0,
+            waterfall_rules: None,
         };
 
         let result = analyze_tranching(&input).unwrap();
@@ -1362,6 +1529,7 @@ mod tests {
             oc_trigger: None,
             ic_trigger: None,
             reinvestment_period_months: 0,
+            waterfall_rules: None,
         };
 
         let result = analyze_tranching(&input).unwrap();
@@ -1423,6 +1591,7 @@ mod tests {
             oc_trigger: None,
             ic_trigger: None,
             reinvestment_period_months: 0,
+            waterfall_rules: None,
         };
 
         let result = analyze_tranching(&input);
@@ -1450,6 +1619,7 @@ mod tests {
             oc_trigger: None,
             ic_trigger: None,
             reinvestment_period_months: 0,
+            waterfall_rules: None,
         };
 
         assert!(analyze_tranching(&input).is_err());
@@ -1476,6 +1646,7 @@ mod tests {
             oc_trigger: None,
             ic_trigger: None,
             reinvestment_period_months: 0,
+            waterfall_rules: None,
         };
 
         assert!(analyze_tranching(&input).is_err());
@@ -1523,6 +1694,7 @@ mod tests {
             oc_trigger: None,
             ic_trigger: None,
             reinvestment_period_months: 0,
+            waterfall_rules: None,
         };
 
         let result = analyze_tranching(&input).unwrap();
@@ -1636,6 +1808,7 @@ mod tests {
             oc_trigger: None,
             ic_trigger: None,
             reinvestment_period_months: 0,
+            waterfall_rules: None,
         };
 
         let result = analyze_tranching(&input).unwrap();
@@ -1667,8 +1840,175 @@ mod tests {
             oc_trigger: None,
             ic_trigger: None,
             reinvestment_period_months: 0,
+            waterfall_rules: None,
         };
 
         assert!(analyze_tranching(&input).is_err());
     }
+
+    // -----------------------------------------------------------------------
+    // Test 31: Pro-rata principal allocation
+    // -----------------------------------------------------------------------
+    #[test]
+    fn test_pro_rata_principal_allocation() {
+        let mut input = three_tranche_input();
+        input.waterfall_rules = Some(WaterfallRules {
+            principal_allocation: PrincipalAllocation::ProRata,
+            ..Default::default()
+        });
+
+        let result = analyze_tranching(&input).unwrap();
+        let first = &result.result.waterfall_periods[0];
+        assert_eq!(first.principal_allocation, PrincipalAllocation::ProRata);
+
+        // 100 of principal split 600 : 200 : 100
+        let paid: Vec<Money> = first
+            .tranche_payments
+            .iter()
+            .map(|p| p.principal_paid.round_dp(6))
+            .collect();
+        assert_eq!(paid[0], dec!(66.666667));
+        assert_eq!(paid[1], dec!(22.222222));
+        assert_eq!(paid[2], dec!(11.111111));
+        assert_eq!(
+            first.tranche_payments[0].ending_balance.round_dp(6),
+            dec!(533.333333)
+        );
+    }
+
+    // -----------------------------------------------------------------------
+    // Test 32: Cumulative loss trigger switches pro-rata to sequential
+    // -----------------------------------------------------------------------
+    #[test]
+    fn test_loss_trigger_sequential_switch() {
+        let mut input = three_tranche_input();
+        input.collateral_cashflows = make_cashflows(8, dec!(20), dec!(90), dec!(10));
+        input.waterfall_rules = Some(WaterfallRules {
+            principal_allocation: PrincipalAllocation::ProRata,
+            cumulative_loss_trigger: Some(dec!(0.025)),
+            sticky_triggers: true,
+            ..Default::default()
+        });
+
+        let result = analyze_tranching(&input).unwrap();
+        let out = &result.result;
+
+        // Cumulative losses: 1%, 2%, 3% of collateral -> breach in period 3
+        assert_eq!(out.trigger_summary.first_loss_trigger_period, Some(3));
+        assert_eq!(out.trigger_summary.sequential_switch_period, Some(3));
+        assert_eq!(out.waterfall_periods[1].loss_test_result, Some(true));
+        assert_eq!(out.waterfall_periods[2].loss_test_result, Some(false));
+
+        for wp in &out.waterfall_periods[2..] {
+            assert_eq!(wp.principal_allocation, PrincipalAllocation::Sequential);
+            // Only the senior tranche amortises while it is outstanding
+            if wp.tranche_payments[0].ending_balance > Decimal::ZERO {
+                assert_eq!(wp.tranche_payments[1].principal_paid, Decimal::ZERO);
+            }
+        }
+        assert_eq!(out.trigger_summary.periods_in_breach, 6);
+    }
+
+    // -----------------------------------------------------------------------
+    // Test 33: Turbo pays excess interest as senior principal
+    // -----------------------------------------------------------------------
+    #[test]
+    fn test_turbo_accelerates_senior() {
+        let base = analyze_tranching(&two_tranche_input()).unwrap().result;
+
+        let mut input = two_tranche_input();
+        input.waterfall_rules = Some(WaterfallRules {
+            turbo: true,
+            ..Default::default()
+        });
+        let turbo = analyze_tranching(&input).unwrap().result;
+
+        assert!(turbo.trigger_summary.total_turbo_principal > Decimal::ZERO);
+        assert!(
+            turbo.tranche_results[0].weighted_average_life
+                < base.tranche_results[0].weighted_average_life,
+            "turbo WAL {} should be shorter than base {}",
+            turbo.tranche_results[0].weighted_average_life,
+            base.tranche_results[0].weighted_average_life
+        );
+        assert!(
+            turbo.trigger_summary.total_residual_released
+                < base.trigger_summary.total_residual_released
+        );
+    }
+
+    // -----------------------------------------------------------------------
+    // Test 34: Excess spread trapped in the reserve, released once lifted
+    // -----------------------------------------------------------------------
+    #[test]
+    fn test_excess_spread_trap() {
+        let mut input = three_tranche_input();
+        // Periods 1-2 thin spread, then normal
+        input.collateral_cashflows[0].interest = dec!(8);
+        input.collateral_cashflows[1].interest = dec!(8);
+        input.waterfall_rules = Some(WaterfallRules {
+            excess_spread_trap: Some(dec!(0.01)),
+            ..Default::default()
+        });
+
+        let result = analyze_tranching(&input).unwrap();
+        let periods = &result.result.waterfall_periods;
+
+        // Period 1: tranche coupons are 4.5 + 3 + 3 = 10.5 > 8, so the
+        // reserve covers the gap and nothing is left to trap
+        assert_eq!(periods[0].excess_spread_trapped, Decimal::ZERO);
+        assert!(periods[0].reserve_balance < dec!(20));
+
+        // Period 3 spread recovers: no trap, reserve back at target
+        assert_eq!(periods[2].excess_spread_trapped, Decimal::ZERO);
+        assert_eq!(periods[2].reserve_balance, dec!(20));
+
+        // A floor above the deal's spread traps every period's excess
+        input.collateral_cashflows = make_cashflows(8, dec!(20), dec!(100), dec!(0));
+        input.waterfall_rules = Some(WaterfallRules {
+            excess_spread_trap: Some(dec!(0.50)),
+            ..Default::default()
+        });
+        let trapped = analyze_tranching(&input).unwrap().result;
+        let total_trapped = trapped.trigger_summary.total_excess_spread_trapped;
+        assert!(total_trapped > Decimal::ZERO);
+        let last = trapped.waterfall_periods.last().unwrap();
+        assert_eq!(last.reserve_balance, dec!(20) + total_trapped);
+    }
+
+    // -----------------------------------------------------------------------
+    // Test 35: Every dollar collected is paid, reserved or released
+    // -----------------------------------------------------------------------
+    #[test]
+    fn test_waterfall_conserves_cash() {
+        let mut input = three_tranche_input();
+        input.collateral_cashflows = make_cashflows(8, dec!(20), dec!(120), dec!(15));
+        input.oc_trigger = Some(dec!(1.30));
+        input.waterfall_rules = Some(WaterfallRules {
+            principal_allocation: PrincipalAllocation::ProRata,
+            turbo: true,
+            excess_spread_trap: Some(dec!(0.02)),
+            ..Default::default()
+        });
+
+        let result = analyze_tranching(&input).unwrap();
+        let mut prior_reserve = input.reserve_account;
+        for wp in &result.result.waterfall_periods {
+            let paid: Money = wp
+                .tranche_payments
+                .iter()
+                .map(|p| p.interest_paid + p.principal_paid)
+                .sum();
+            let sources = wp.available_interest + wp.available_principal + prior_reserve;
+            let uses = paid + wp.residual_released + wp.reserve_balance;
+            assert!(
+                (sources - uses).abs() < dec!(0.000001),
+                "period {}: sources {} vs uses {}",
+                wp.period,
+                sources,
+                uses
+            );
+            prior_reserve = wp.reserve_balance;
+        }
+    }
 }
@@ -0,0 +1,62 @@
+use rust_decimal::Decimal;
+use rust_decimal::MathematicalOps;
+use rust_decimal_macros::dec;
+
+/// Beyond this many standard deviations the density is taken as zero and the
+/// CDF as 0 or 1. `exp(-x^2/2)` underflows `Decimal` a little past 11.
+const TAIL_CUTOFF: Decimal = dec!(10);
+
+/// sqrt(2 * pi)
+const SQRT_2PI: Decimal = dec!(2.5066282746310002);
+
+/// Standard normal PDF, zero beyond `TAIL_CUTOFF`.
+pub fn norm_pdf(x: Decimal) -> Decimal {
+    if x.abs() > TAIL_CUTOFF {
+        return Decimal::ZERO;
+    }
+    (-(x * x) / dec!(2)).exp() / SQRT_2PI
+}
+
+/// Standard normal CDF (Abramowitz & Stegun 26.2.17), with `|x|` clamped to
+/// `TAIL_CUTOFF`.
+pub fn norm_cdf(x: Decimal) -> Decimal {
+    let b1 = dec!(0.319381530);
+    let b2 = dec!(-0.356563782);
+    let b3 = dec!(1.781477937);
+    let b4 = dec!(-1.821255978);
+    let b5 = dec!(1.330274429);
+    let p = dec!(0.2316419);
+
+    let abs_x = x.abs().min(TAIL_CUTOFF);
+    let t = Decimal::ONE / (Decimal::ONE + p * abs_x);
+    let poly = t * (b1 + t * (b2 + t * (b3 + t * (b4 + t * b5))));
+    let cdf_pos = Decimal::ONE - norm_pdf(abs_x) * poly;
+
+    if x < Decimal::ZERO {
+        Decimal::ONE - cdf_pos
+    } else {
+        cdf_pos
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_norm_cdf_known_values() {
+        assert!((norm_cdf(Decimal::ZERO) - dec!(0.5)).abs() < dec!(0.0000001));
+        assert!((norm_cdf(dec!(1.96)) - dec!(0.9750021)).abs() < dec!(0.000001));
+        assert!((norm_cdf(dec!(-1.96)) - dec!(0.0249979)).abs() < dec!(0.000001));
+    }
+
+    #[test]
+    fn test_far_tails_do_not_underflow() {
+        for x in [dec!(12), dec!(50), dec!(1_000_000)] {
+            assert_eq!(norm_pdf(x), Decimal::ZERO);
+            assert_eq!(norm_pdf(-x), Decimal::ZERO);
+            assert!(Decimal::ONE - norm_cdf(x) < dec!(0.000000000001));
+            assert!(norm_cdf(-x) < dec!(0.000000000001));
+        }
+    }
+}
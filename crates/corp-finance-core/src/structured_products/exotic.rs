@@ -3,6 +3,7 @@ use rust_decimal_macros::dec;
 use serde::{Deserialize, Serialize};
 use std::time::Instant;
 
+use crate::curves::VolSurface;
 use crate::error::CorpFinanceError;
 use crate::types::{with_metadata, ComputationOutput, Money, Rate};
 use crate::CorpFinanceResult;
@@ -101,6 +102,11 @@ pub struct ExoticProductInput {
     pub barrier_option: Option<BarrierOptionParams>,
     #[serde(default)]
     pub digital_option: Option<DigitalOptionParams>,
+    /// Implied volatility surface; when present the product's volatility is
+    /// read off it at the product's expiry and strike instead of taken from
+    /// the params
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub volatility_surface: Option<VolSurface>,
 }
 
 // -- Output types -----------------------------------------------------------
@@ -960,6 +966,20 @@ fn price_digital_option(
     Ok((fair_value, output, warnings))
 }
 
+/// Volatility at `expiry` and absolute `strike` from the input's surface, or
+/// `flat` when it has none.
+fn product_volatility(
+    input: &ExoticProductInput,
+    expiry: Decimal,
+    strike: Decimal,
+    flat: Rate,
+) -> CorpFinanceResult<Rate> {
+    match &input.volatility_surface {
+        Some(surface) => surface.vol(expiry, strike),
+        None => Ok(flat),
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Public API
 // ---------------------------------------------------------------------------
@@ -981,6 +1001,15 @@ pub fn price_exotic(
                             reason: "autocallable params required for Autocallable product type"
                                 .into(),
                         })?;
+                let params = &AutocallableParams {
+                    volatility: product_volatility(
+                        input,
+                        params.maturity_years,
+                        params.ki_strike * params.underlying_price,
+                        params.volatility,
+                    )?,
+                    ..params.clone()
+                };
                 validate_autocallable(params)?;
                 let (fv, out, w) = price_autocallable(params)?;
                 (
@@ -1000,6 +1029,15 @@ pub fn price_exotic(
                             .into(),
                     }
                 })?;
+                let params = &BarrierOptionParams {
+                    volatility: product_volatility(
+                        input,
+                        params.time_to_expiry,
+                        params.strike,
+                        params.volatility,
+                    )?,
+                    ..params.clone()
+                };
                 validate_barrier(params)?;
                 let (fv, out, w) = price_barrier_option(params)?;
                 (
@@ -1019,6 +1057,15 @@ pub fn price_exotic(
                             .into(),
                     }
                 })?;
+                let params = &DigitalOptionParams {
+                    volatility: product_volatility(
+                        input,
+                        params.time_to_expiry,
+                        params.strike,
+                        params.volatility,
+                    )?,
+                    ..params.clone()
+                };
                 validate_digital(params)?;
                 let (fv, out, w) = price_digital_option(params)?;
                 (
@@ -1046,6 +1093,7 @@ pub fn price_exotic(
     let assumptions = serde_json::json!({
         "product_type": product_type_str,
         "methodology": methodology,
+        "volatility_source": if input.volatility_surface.is_some() { "surface" } else { "params" },
         "precision": "rust_decimal_128bit",
         "math_helpers": "Taylor series exp/ln (40 iter), Newton sqrt (20 iter), A&S norm_cdf",
     });
@@ -1083,6 +1131,7 @@ mod tests {
         // Barrier very far below spot => behaves like vanilla
         let input = ExoticProductInput {
             product_type: ExoticType::BarrierOption,
+            volatility_surface: None,
             autocallable: None,
             barrier_option: Some(BarrierOptionParams {
                 spot: dec!(100),
@@ -1124,6 +1173,7 @@ mod tests {
         // Down-and-in call
         let di_input = ExoticProductInput {
             product_type: ExoticType::BarrierOption,
+            volatility_surface: None,
             autocallable: None,
             barrier_option: Some(BarrierOptionParams {
                 spot,
@@ -1145,6 +1195,7 @@ mod tests {
         // Down-and-out call
         let do_input = ExoticProductInput {
             product_type: ExoticType::BarrierOption,
+            volatility_surface: None,
             autocallable: None,
             barrier_option: Some(BarrierOptionParams {
                 spot,
@@ -1190,6 +1241,7 @@ mod tests {
 
         let input = ExoticProductInput {
             product_type: ExoticType::BarrierOption,
+            volatility_surface: None,
             autocallable: None,
             barrier_option: Some(BarrierOptionParams {
                 spot,
@@ -1258,6 +1310,7 @@ mod tests {
     fn test_digital_cash_or_nothing_call() {
         let input = ExoticProductInput {
             product_type: ExoticType::DigitalOption,
+            volatility_surface: None,
             autocallable: None,
             barrier_option: None,
             digital_option: Some(DigitalOptionParams {
@@ -1296,6 +1349,7 @@ mod tests {
     fn test_digital_asset_or_nothing_put() {
         let input = ExoticProductInput {
             product_type: ExoticType::DigitalOption,
+            volatility_surface: None,
             autocallable: None,
             barrier_option: None,
             digital_option: Some(DigitalOptionParams {
@@ -1334,6 +1388,7 @@ mod tests {
     fn test_autocallable_high_barrier_low_prob() {
         let input = ExoticProductInput {
             product_type: ExoticType::Autocallable,
+            volatility_surface: None,
             autocallable: Some(AutocallableParams {
                 notional: dec!(100000),
                 underlying_price: dec!(100),
@@ -1369,6 +1424,7 @@ mod tests {
     fn test_autocallable_observation_schedule() {
         let input = ExoticProductInput {
             product_type: ExoticType::Autocallable,
+            volatility_surface: None,
             autocallable: Some(AutocallableParams {
                 notional: dec!(100000),
                 underlying_price: dec!(100),
@@ -1427,6 +1483,7 @@ mod tests {
         // Down-and-out call: delta should be positive (value increases as S increases)
         let input = ExoticProductInput {
             product_type: ExoticType::BarrierOption,
+            volatility_surface: None,
             autocallable: None,
             barrier_option: Some(BarrierOptionParams {
                 spot: dec!(100),
@@ -1509,6 +1566,7 @@ mod tests {
 
         let call_input = ExoticProductInput {
             product_type: ExoticType::DigitalOption,
+            volatility_surface: None,
             autocallable: None,
             barrier_option: None,
             digital_option: Some(DigitalOptionParams {
@@ -1525,6 +1583,7 @@ mod tests {
         };
         let put_input = ExoticProductInput {
             product_type: ExoticType::DigitalOption,
+            volatility_surface: None,
             autocallable: None,
             barrier_option: None,
             digital_option: Some(DigitalOptionParams {
@@ -1557,6 +1616,7 @@ mod tests {
     fn test_missing_autocallable_params() {
         let input = ExoticProductInput {
             product_type: ExoticType::Autocallable,
+            volatility_surface: None,
             autocallable: None,
             barrier_option: None,
             digital_option: None,
@@ -1571,6 +1631,7 @@ mod tests {
     fn test_metadata_populated() {
         let input = ExoticProductInput {
             product_type: ExoticType::DigitalOption,
+            volatility_surface: None,
             autocallable: None,
             barrier_option: None,
             digital_option: Some(DigitalOptionParams {
@@ -1590,4 +1651,50 @@ mod tests {
         assert!(!result.metadata.version.is_empty());
         assert_eq!(result.metadata.precision, "rust_decimal_128bit");
     }
+
+    // -----------------------------------------------------------------------
+    // 13. Volatility read off a surface
+    // -----------------------------------------------------------------------
+    #[test]
+    fn test_volatility_surface_overrides_params() {
+        let digital = |vol: Rate, surface: Option<VolSurface>| ExoticProductInput {
+            product_type: ExoticType::DigitalOption,
+            volatility_surface: surface,
+            autocallable: None,
+            barrier_option: None,
+            digital_option: Some(DigitalOptionParams {
+                spot: dec!(100),
+                strike: dec!(105),
+                digital_type: DigitalType::CashOrNothing,
+                option_type: "Call".into(),
+                payout: dec!(100),
+                volatility: vol,
+                risk_free_rate: dec!(0.05),
+                dividend_yield: dec!(0),
+                time_to_expiry: dec!(1),
+            }),
+        };
+        let surface: VolSurface = serde_json::from_value(serde_json::json!({
+            "version": 1,
+            "spot": "100",
+            "strike_axis": "moneyness",
+            "nodes": [
+                { "expiry": "0.5", "strike": "1.0", "vol": "0.25" },
+                { "expiry": "0.5", "strike": "1.1", "vol": "0.35" },
+                { "expiry": "2", "strike": "1.0", "vol": "0.25" },
+                { "expiry": "2", "strike": "1.1", "vol": "0.35" }
+            ]
+        }))
+        .unwrap();
+
+        let from_surface = price_exotic(&digital(dec!(0.20), Some(surface))).unwrap();
+        let flat = price_exotic(&digital(dec!(0.30), None)).unwrap();
+        // Strike 105 sits halfway along the smile: 30% at every expiry
+        assert!(approx_eq(
+            from_surface.result.fair_value,
+            flat.result.fair_value,
+            dec!(0.0001)
+        ));
+        assert_eq!(from_surface.assumptions["volatility_source"], "surface");
+    }
 }
@@ -0,0 +1,300 @@
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::time::Instant;
+
+use crate::types::{with_metadata, ComputationOutput, Currency, Money, Multiple, Rate};
+use crate::valuation::dcf::{self, DcfInput, DcfOutput, ProjectedCashFlow, TerminalMethod};
+use crate::valuation::wacc::WaccInput;
+use crate::CorpFinanceResult;
+
+use super::model::{build_three_statement_model, ThreeStatementInput, ThreeStatementOutput};
+
+// ---------------------------------------------------------------------------
+// Input
+// ---------------------------------------------------------------------------
+
+/// Discounting, terminal value and equity bridge settings for valuing a
+/// three-statement projection.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DcfValuationParams {
+    /// Weighted average cost of capital (ignored when `wacc_input` is given)
+    #[serde(default)]
+    pub wacc: Rate,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wacc_input: Option<WaccInput>,
+    pub terminal_method: TerminalMethod,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub terminal_growth_rate: Option<Rate>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub terminal_exit_multiple: Option<Multiple>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub terminal_ronic: Option<Rate>,
+    #[serde(default)]
+    pub currency: Currency,
+    /// Use mid-year convention for discounting (default: true)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mid_year_convention: Option<bool>,
+    /// Net debt for the equity bridge (default: base debt less base cash)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub net_debt: Option<Money>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub minority_interest: Option<Money>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shares_outstanding: Option<Decimal>,
+}
+
+/// A three-statement model and the settings to value its cash flows.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModelToDcfInput {
+    pub model: ThreeStatementInput,
+    pub valuation: DcfValuationParams,
+}
+
+// ---------------------------------------------------------------------------
+// Output
+// ---------------------------------------------------------------------------
+
+/// Unlevered free cash flow derived from one projected year.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UnleveredCashFlowYear {
+    pub year: i32,
+    pub ebit: Money,
+    pub tax_rate: Rate,
+    pub nopat: Money,
+    pub depreciation: Money,
+    pub capex: Money,
+    /// Increase in receivables and inventory less increase in payables
+    pub nwc_change: Money,
+    pub fcff: Money,
+}
+
+/// Model, derived unlevered cash flows and the DCF built on them.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModelToDcfOutput {
+    pub model: ThreeStatementOutput,
+    pub unlevered_cash_flows: Vec<UnleveredCashFlowYear>,
+    pub dcf: DcfOutput,
+}
+
+// ---------------------------------------------------------------------------
+// Public API
+// ---------------------------------------------------------------------------
+
+/// Build the three-statement model and value its unlevered free cash flows
+/// with the DCF engine, so projections never need re-keying.
+///
+/// FCFF = EBIT x (1 - tax rate) + D&A - capex - increase in working capital,
+/// using the tax rate and statements of each projected year. Interest and
+/// debt flows in the model do not enter the valuation; net debt for the
+/// equity bridge defaults to the base-year balance sheet.
+pub fn model_to_dcf(
+    input: &ModelToDcfInput,
+) -> CorpFinanceResult<ComputationOutput<ModelToDcfOutput>> {
+    let start = Instant::now();
+    let mut warnings: Vec<String> = Vec::new();
+
+    let model_out = build_three_statement_model(&input.model)?;
+    warnings.extend(model_out.warnings.iter().map(|w| format!("[Model] {w}")));
+    let model = model_out.result;
+
+    let m = &input.model;
+    let unlevered_cash_flows: Vec<UnleveredCashFlowYear> = model
+        .income_statements
+        .iter()
+        .zip(&model.cash_flow_statements)
+        .enumerate()
+        .map(|(i, (is, cf))| {
+            let tax_rate = m
+                .drivers
+                .as_ref()
+                .and_then(|d| d.tax_rate.as_ref())
+                .and_then(|v| v.get(i).copied())
+                .unwrap_or(m.tax_rate);
+            let nopat = is.ebit * (Decimal::ONE - tax_rate);
+            let nwc_change =
+                cf.change_in_receivables + cf.change_in_inventory - cf.change_in_payables;
+            UnleveredCashFlowYear {
+                year: is.year,
+                ebit: is.ebit,
+                tax_rate,
+                nopat,
+                depreciation: cf.depreciation,
+                capex: cf.capex,
+                nwc_change,
+                fcff: nopat + cf.depreciation - cf.capex - nwc_change,
+            }
+        })
+        .collect();
+
+    let cash_flows: Vec<ProjectedCashFlow> = model
+        .income_statements
+        .iter()
+        .zip(&unlevered_cash_flows)
+        .map(|(is, u)| ProjectedCashFlow {
+            revenue: is.revenue,
+            ebitda: is.ebitda,
+            ebit: u.ebit,
+            nopat: u.nopat,
+            da: u.depreciation,
+            capex: u.capex,
+            nwc_change: u.nwc_change,
+        })
+        .collect();
+
+    let v = &input.valuation;
+    let dcf_input = DcfInput {
+        base_revenue: m.base_revenue,
+        revenue_growth_rates: m.revenue_growth_rates.clone(),
+        ebitda_margin: Decimal::ONE - m.cogs_pct - m.sga_pct - m.rnd_pct,
+        ebit_margin: None,
+        da_as_pct_revenue: None,
+        capex_as_pct_revenue: m.capex_pct,
+        nwc_as_pct_revenue: Decimal::ZERO,
+        tax_rate: m.tax_rate,
+        wacc: v.wacc,
+        wacc_input: v.wacc_input.clone(),
+        terminal_method: v.terminal_method.clone(),
+        terminal_growth_rate: v.terminal_growth_rate,
+        terminal_exit_multiple: v.terminal_exit_multiple,
+        terminal_ronic: v.terminal_ronic,
+        currency: v.currency.clone(),
+        forecast_years: Some(cash_flows.len() as u32),
+        mid_year_convention: v.mid_year_convention,
+        net_debt: Some(v.net_debt.unwrap_or(m.base_debt - m.base_cash)),
+        minority_interest: v.minority_interest,
+        shares_outstanding: v.shares_outstanding,
+        presentation_currency: None,
+        fx_rates: vec![],
+    };
+    let dcf_out = dcf::calculate_dcf_from_cash_flows(&dcf_input, &cash_flows)?;
+    warnings.extend(dcf_out.warnings.iter().map(|w| format!("[DCF] {w}")));
+
+    let output = ModelToDcfOutput {
+        model,
+        unlevered_cash_flows,
+        dcf: dcf_out.result,
+    };
+
+    let elapsed = start.elapsed().as_micros() as u64;
+    Ok(with_metadata(
+        "Three-Statement Model to FCFF DCF",
+        &serde_json::json!({
+            "fcff_definition": "EBIT x (1 - t) + D&A - capex - increase in NWC",
+            "net_debt_source": if v.net_debt.is_some() { "input" } else { "base balance sheet" },
+            "years": output.unlevered_cash_flows.len(),
+        }),
+        warnings,
+        elapsed,
+        output,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::three_statement::model::DriverSchedules;
+    use rust_decimal_macros::dec;
+
+    fn sample_input() -> ModelToDcfInput {
+        ModelToDcfInput {
+            model: ThreeStatementInput {
+                base_revenue: dec!(1000),
+                revenue_growth_rates: vec![dec!(0.10), dec!(0.08), dec!(0.06)],
+                cogs_pct: dec!(0.60),
+                sga_pct: dec!(0.10),
+                rnd_pct: dec!(0.05),
+                da_pct: dec!(0.10),
+                interest_rate: dec!(0.05),
+                tax_rate: dec!(0.25),
+                base_cash: dec!(100),
+                base_receivables: dec!(80),
+                base_inventory: dec!(60),
+                base_payables: dec!(50),
+                base_ppe: dec!(500),
+                base_debt: dec!(400),
+                base_equity: dec!(290),
+                dso_days: dec!(30),
+                dio_days: dec!(40),
+                dpo_days: dec!(35),
+                capex_pct: dec!(0.08),
+                debt_repayment_pct: dec!(0.05),
+                dividend_payout_ratio: dec!(0.30),
+                min_cash_balance: dec!(50),
+                drivers: None,
+            },
+            valuation: DcfValuationParams {
+                wacc: dec!(0.09),
+                wacc_input: None,
+                terminal_method: TerminalMethod::GordonGrowth,
+                terminal_growth_rate: Some(dec!(0.02)),
+                terminal_exit_multiple: None,
+                terminal_ronic: None,
+                currency: Currency::USD,
+                mid_year_convention: Some(false),
+                net_debt: None,
+                minority_interest: None,
+                shares_outstanding: Some(dec!(100)),
+            },
+        }
+    }
+
+    #[test]
+    fn test_fcff_derived_from_statements() {
+        let out = model_to_dcf(&sample_input()).unwrap().result;
+        let is = &out.model.income_statements[0];
+        let cf = &out.model.cash_flow_statements[0];
+        let u = &out.unlevered_cash_flows[0];
+        assert_eq!(u.nopat, is.ebit * dec!(0.75));
+        assert_eq!(
+            u.nwc_change,
+            cf.change_in_receivables + cf.change_in_inventory - cf.change_in_payables
+        );
+        assert_eq!(u.fcff, u.nopat + cf.depreciation - cf.capex - u.nwc_change);
+    }
+
+    #[test]
+    fn test_dcf_uses_model_cash_flows() {
+        let out = model_to_dcf(&sample_input()).unwrap().result;
+        assert_eq!(out.dcf.projections.len(), 3);
+        for (p, u) in out.dcf.projections.iter().zip(&out.unlevered_cash_flows) {
+            assert_eq!(p.fcff, u.fcff);
+            assert_eq!(p.nopat, u.nopat);
+        }
+        assert_eq!(
+            out.dcf.projections[0].revenue,
+            out.model.income_statements[0].revenue
+        );
+    }
+
+    #[test]
+    fn test_net_debt_defaults_to_base_balance_sheet() {
+        let out = model_to_dcf(&sample_input()).unwrap().result;
+        // 400 debt - 100 cash
+        assert_eq!(
+            out.dcf.equity_value.unwrap(),
+            out.dcf.enterprise_value - dec!(300)
+        );
+    }
+
+    #[test]
+    fn test_per_year_tax_rates_flow_through() {
+        let mut input = sample_input();
+        input.model.drivers = Some(DriverSchedules {
+            tax_rate: Some(vec![dec!(0.25), dec!(0.21), dec!(0.21)]),
+            ..Default::default()
+        });
+        let out = model_to_dcf(&input).unwrap().result;
+        assert_eq!(out.unlevered_cash_flows[1].tax_rate, dec!(0.21));
+        assert_eq!(
+            out.unlevered_cash_flows[1].nopat,
+            out.unlevered_cash_flows[1].ebit * dec!(0.79)
+        );
+    }
+
+    #[test]
+    fn test_invalid_valuation_params_rejected() {
+        let mut input = sample_input();
+        input.valuation.terminal_growth_rate = Some(dec!(0.10));
+        assert!(model_to_dcf(&input).is_err());
+    }
+}
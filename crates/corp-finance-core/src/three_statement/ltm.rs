@@ -0,0 +1,931 @@
+use chrono::{Datelike, NaiveDate};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::time::Instant;
+
+use crate::error::CorpFinanceError;
+use crate::types::{with_metadata, ComputationOutput, Money};
+use crate::CorpFinanceResult;
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+const MONTHS_IN_YEAR: u32 = 12;
+
+// ---------------------------------------------------------------------------
+// Input
+// ---------------------------------------------------------------------------
+
+/// A reported financial period (fiscal year, quarter or year-to-date).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FinancialPeriod {
+    /// Label, e.g. "FY2024" or "9M2025"
+    pub label: String,
+    /// Last day of the period
+    pub period_end: NaiveDate,
+    /// Number of months the period covers (1-12)
+    pub months: u32,
+    /// Reported values keyed by metric name (e.g. "revenue", "ebitda")
+    pub metrics: BTreeMap<String, Money>,
+}
+
+/// Input for building last-twelve-months financials.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LtmInput {
+    /// Most recent full fiscal year
+    pub latest_annual: FinancialPeriod,
+    /// Year-to-date period of the current fiscal year
+    pub current_interim: FinancialPeriod,
+    /// Comparable year-to-date period of the prior fiscal year
+    pub prior_interim: FinancialPeriod,
+    /// Balance-sheet style metrics taken from the latest interim rather than
+    /// summed (e.g. "net_debt", "total_assets")
+    #[serde(default)]
+    pub point_in_time_metrics: Vec<String>,
+}
+
+/// Fiscal periods reported by one company, for calendarization.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompanyPeriods {
+    pub name: String,
+    /// Non-overlapping periods (any order)
+    pub periods: Vec<FinancialPeriod>,
+}
+
+/// Input for calendarizing companies with different fiscal year ends.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CalendarizeInput {
+    pub companies: Vec<CompanyPeriods>,
+    /// End of the target twelve-month window (e.g. 2024-12-31)
+    pub target_period_end: NaiveDate,
+    /// Metrics taken from the latest period ending on or before the target
+    /// end rather than time-weighted
+    #[serde(default)]
+    pub point_in_time_metrics: Vec<String>,
+}
+
+/// Method used to annualize a partial period.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AnnualizationMethod {
+    /// Scale by 12 / months
+    RunRate,
+    /// Scale by the prior year's full-year / comparable-period ratio
+    Seasonal,
+}
+
+/// Input for annualizing a partial period.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnnualizeInput {
+    /// The partial period to annualize
+    pub period: FinancialPeriod,
+    pub method: AnnualizationMethod,
+    /// Same period one year earlier (required for Seasonal)
+    pub prior_comparable_period: Option<FinancialPeriod>,
+    /// Full prior fiscal year (required for Seasonal)
+    pub prior_fiscal_year: Option<FinancialPeriod>,
+    /// Metrics passed through unchanged
+    #[serde(default)]
+    pub point_in_time_metrics: Vec<String>,
+}
+
+// ---------------------------------------------------------------------------
+// Output structs
+// ---------------------------------------------------------------------------
+
+/// LTM build-up for one metric.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LtmLine {
+    pub metric: String,
+    pub latest_annual: Money,
+    /// Stub addition: current year-to-date
+    pub add_current_interim: Money,
+    /// Stub subtraction: prior year-to-date
+    pub less_prior_interim: Money,
+    pub ltm: Money,
+    pub point_in_time: bool,
+    /// Current interim vs prior interim growth
+    pub interim_growth: Option<Decimal>,
+}
+
+/// Last-twelve-months financials.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LtmOutput {
+    pub ltm_period_end: NaiveDate,
+    pub lines: Vec<LtmLine>,
+    pub metrics: BTreeMap<String, Money>,
+}
+
+/// Contribution of one fiscal period to a calendarized window.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PeriodWeight {
+    pub label: String,
+    pub period_end: NaiveDate,
+    pub overlap_months: u32,
+    /// overlap_months / period months
+    pub weight: Decimal,
+}
+
+/// Calendarized financials for one company.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CalendarizedCompany {
+    pub name: String,
+    /// Month (1-12) in which the company's latest fiscal period ends
+    pub fiscal_year_end_month: u32,
+    pub weights: Vec<PeriodWeight>,
+    pub metrics: BTreeMap<String, Money>,
+}
+
+/// Calendarization output.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CalendarizeOutput {
+    pub target_period_end: NaiveDate,
+    pub companies: Vec<CalendarizedCompany>,
+}
+
+/// Annualized financials.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnnualizeOutput {
+    pub method: AnnualizationMethod,
+    pub months: u32,
+    /// Scaling factor applied per metric
+    pub factors: BTreeMap<String, Decimal>,
+    pub metrics: BTreeMap<String, Money>,
+}
+
+// ---------------------------------------------------------------------------
+// Public API
+// ---------------------------------------------------------------------------
+
+/// Build LTM financials: latest fiscal year + current YTD - prior YTD.
+pub fn build_ltm(input: &LtmInput) -> CorpFinanceResult<ComputationOutput<LtmOutput>> {
+    let start = Instant::now();
+    let mut warnings: Vec<String> = Vec::new();
+
+    validate_ltm(input)?;
+
+    let annual = &input.latest_annual;
+    let current = &input.current_interim;
+    let prior = &input.prior_interim;
+
+    let names: BTreeSet<&String> = annual
+        .metrics
+        .keys()
+        .chain(current.metrics.keys())
+        .chain(prior.metrics.keys())
+        .collect();
+
+    let mut lines = Vec::new();
+    let mut metrics = BTreeMap::new();
+
+    for name in names {
+        let point_in_time = input.point_in_time_metrics.contains(name);
+        let cur = current.metrics.get(name).copied();
+        if point_in_time {
+            match cur {
+                Some(v) => {
+                    metrics.insert(name.clone(), v);
+                    lines.push(LtmLine {
+                        metric: name.clone(),
+                        latest_annual: annual.metrics.get(name).copied().unwrap_or_default(),
+                        add_current_interim: v,
+                        less_prior_interim: Decimal::ZERO,
+                        ltm: v,
+                        point_in_time: true,
+                        interim_growth: None,
+                    });
+                }
+                None => warnings.push(format!(
+                    "Point-in-time metric '{name}' missing from {}; skipped",
+                    current.label
+                )),
+            }
+            continue;
+        }
+
+        let (Some(fy), Some(cur), Some(pri)) = (
+            annual.metrics.get(name).copied(),
+            cur,
+            prior.metrics.get(name).copied(),
+        ) else {
+            warnings.push(format!(
+                "Metric '{name}' not reported in all three periods; excluded from LTM"
+            ));
+            continue;
+        };
+
+        let ltm = fy + cur - pri;
+        let interim_growth = if pri.is_zero() {
+            None
+        } else {
+            Some((cur - pri) / pri.abs())
+        };
+        metrics.insert(name.clone(), ltm);
+        lines.push(LtmLine {
+            metric: name.clone(),
+            latest_annual: fy,
+            add_current_interim: cur,
+            less_prior_interim: pri,
+            ltm,
+            point_in_time: false,
+            interim_growth,
+        });
+    }
+
+    if metrics.is_empty() {
+        return Err(CorpFinanceError::InsufficientData(
+            "No metric is reported consistently across the annual and interim periods".into(),
+        ));
+    }
+
+    let output = LtmOutput {
+        ltm_period_end: current.period_end,
+        lines,
+        metrics,
+    };
+
+    let elapsed = start.elapsed().as_micros() as u64;
+    Ok(with_metadata(
+        "LTM = latest fiscal year + current YTD - prior-year YTD",
+        &serde_json::json!({
+            "latest_annual": annual.label,
+            "current_interim": current.label,
+            "prior_interim": prior.label,
+            "stub_months": current.months,
+        }),
+        warnings,
+        elapsed,
+        output,
+    ))
+}
+
+/// Calendarize fiscal-period financials onto a common twelve-month window by
+/// time-weighting each fiscal period's overlap with the target window.
+pub fn calendarize(
+    input: &CalendarizeInput,
+) -> CorpFinanceResult<ComputationOutput<CalendarizeOutput>> {
+    let start = Instant::now();
+    let mut warnings: Vec<String> = Vec::new();
+
+    if input.companies.is_empty() {
+        return Err(CorpFinanceError::InsufficientData(
+            "At least one company is required for calendarization".into(),
+        ));
+    }
+
+    let target_end = month_index(input.target_period_end);
+    let target_start = target_end - MONTHS_IN_YEAR as i64 + 1;
+
+    let mut companies = Vec::with_capacity(input.companies.len());
+    for company in &input.companies {
+        let mut periods: Vec<&FinancialPeriod> = company.periods.iter().collect();
+        for p in &periods {
+            validate_period(p, &format!("{}.periods", company.name))?;
+        }
+        periods.sort_by_key(|p| p.period_end);
+        for pair in periods.windows(2) {
+            if period_start(pair[1]) <= month_index(pair[0].period_end) {
+                return Err(CorpFinanceError::InvalidInput {
+                    field: format!("{}.periods", company.name),
+                    reason: format!(
+                        "Periods '{}' and '{}' overlap; supply non-overlapping periods",
+                        pair[0].label, pair[1].label
+                    ),
+                });
+            }
+        }
+
+        let mut weights = Vec::new();
+        let mut covered = 0u32;
+        for p in &periods {
+            let overlap =
+                (month_index(p.period_end).min(target_end) - period_start(p).max(target_start) + 1)
+                    .max(0) as u32;
+            if overlap > 0 {
+                covered += overlap;
+                weights.push((
+                    *p,
+                    PeriodWeight {
+                        label: p.label.clone(),
+                        period_end: p.period_end,
+                        overlap_months: overlap,
+                        weight: Decimal::from(overlap) / Decimal::from(p.months),
+                    },
+                ));
+            }
+        }
+        if covered < MONTHS_IN_YEAR {
+            return Err(CorpFinanceError::InsufficientData(format!(
+                "{}: fiscal periods cover only {covered} of the 12 target months",
+                company.name
+            )));
+        }
+
+        let names: BTreeSet<&String> = weights.iter().flat_map(|(p, _)| p.metrics.keys()).collect();
+        let mut metrics = BTreeMap::new();
+        for name in names {
+            if input.point_in_time_metrics.contains(name) {
+                let latest = periods
+                    .iter()
+                    .rev()
+                    .filter(|p| p.period_end <= input.target_period_end)
+                    .find_map(|p| p.metrics.get(name).copied());
+                match latest {
+                    Some(v) => {
+                        metrics.insert(name.clone(), v);
+                    }
+                    None => warnings.push(format!(
+                        "{}: no period ending on or before the target reports '{name}'",
+                        company.name
+                    )),
+                }
+                continue;
+            }
+            let mut total = Decimal::ZERO;
+            let mut complete = true;
+            for (p, w) in &weights {
+                match p.metrics.get(name) {
+                    Some(v) => total += *v * w.weight,
+                    None => {
+                        complete = false;
+                        break;
+                    }
+                }
+            }
+            if complete {
+                metrics.insert(name.clone(), total);
+            } else {
+                warnings.push(format!(
+                    "{}: '{name}' missing from a contributing period; excluded",
+                    company.name
+                ));
+            }
+        }
+
+        let fiscal_year_end_month = periods
+            .last()
+            .map(|p| p.period_end.month())
+            .unwrap_or_default();
+        if fiscal_year_end_month != input.target_period_end.month() {
+            warnings.push(format!(
+                "{}: fiscal year ends in month {fiscal_year_end_month}; values are time-weighted",
+                company.name
+            ));
+        }
+
+        companies.push(CalendarizedCompany {
+            name: company.name.clone(),
+            fiscal_year_end_month,
+            weights: weights.into_iter().map(|(_, w)| w).collect(),
+            metrics,
+        });
+    }
+
+    let output = CalendarizeOutput {
+        target_period_end: input.target_period_end,
+        companies,
+    };
+
+    let elapsed = start.elapsed().as_micros() as u64;
+    Ok(with_metadata(
+        "Calendarization by month-weighted overlap of fiscal periods",
+        &serde_json::json!({
+            "target_period_end": input.target_period_end,
+            "companies": input.companies.len(),
+        }),
+        warnings,
+        elapsed,
+        output,
+    ))
+}
+
+/// Annualize a partial period by run-rate or prior-year seasonality.
+pub fn annualize_period(
+    input: &AnnualizeInput,
+) -> CorpFinanceResult<ComputationOutput<AnnualizeOutput>> {
+    let start = Instant::now();
+    let mut warnings: Vec<String> = Vec::new();
+
+    validate_period(&input.period, "period")?;
+    let run_rate = Decimal::from(MONTHS_IN_YEAR) / Decimal::from(input.period.months);
+
+    let seasonal = match input.method {
+        AnnualizationMethod::RunRate => None,
+        AnnualizationMethod::Seasonal => {
+            let (Some(comparable), Some(fiscal_year)) =
+                (&input.prior_comparable_period, &input.prior_fiscal_year)
+            else {
+                return Err(CorpFinanceError::InvalidInput {
+                    field: "prior_comparable_period".into(),
+                    reason: "Seasonal annualization requires the prior comparable period and \
+                             prior fiscal year"
+                        .into(),
+                });
+            };
+            if comparable.months != input.period.months {
+                return Err(CorpFinanceError::InvalidInput {
+                    field: "prior_comparable_period.months".into(),
+                    reason: "Prior comparable period must cover the same number of months".into(),
+                });
+            }
+            if fiscal_year.months != MONTHS_IN_YEAR {
+                return Err(CorpFinanceError::InvalidInput {
+                    field: "prior_fiscal_year.months".into(),
+                    reason: "Prior fiscal year must cover 12 months".into(),
+                });
+            }
+            Some((comparable, fiscal_year))
+        }
+    };
+
+    let mut factors = BTreeMap::new();
+    let mut metrics = BTreeMap::new();
+    for (name, value) in &input.period.metrics {
+        if input.point_in_time_metrics.contains(name) {
+            factors.insert(name.clone(), Decimal::ONE);
+            metrics.insert(name.clone(), *value);
+            continue;
+        }
+        let factor = match seasonal {
+            None => run_rate,
+            Some((comparable, fiscal_year)) => {
+                match (comparable.metrics.get(name), fiscal_year.metrics.get(name)) {
+                    (Some(c), Some(fy)) if !c.is_zero() => *fy / *c,
+                    _ => {
+                        warnings.push(format!(
+                            "'{name}': no usable prior-year seasonality; run-rate applied"
+                        ));
+                        run_rate
+                    }
+                }
+            }
+        };
+        factors.insert(name.clone(), factor);
+        metrics.insert(name.clone(), *value * factor);
+    }
+
+    let output = AnnualizeOutput {
+        method: input.method.clone(),
+        months: input.period.months,
+        factors,
+        metrics,
+    };
+
+    let elapsed = start.elapsed().as_micros() as u64;
+    Ok(with_metadata(
+        match input.method {
+            AnnualizationMethod::RunRate => "Run-rate annualization (x 12 / months)",
+            AnnualizationMethod::Seasonal => {
+                "Seasonal annualization (x prior full year / prior comparable period)"
+            }
+        },
+        &serde_json::json!({
+            "period": input.period.label,
+            "months": input.period.months,
+        }),
+        warnings,
+        elapsed,
+        output,
+    ))
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Months since year zero of the month containing `date`.
+fn month_index(date: NaiveDate) -> i64 {
+    date.year() as i64 * MONTHS_IN_YEAR as i64 + date.month0() as i64
+}
+
+/// Month index of the first month covered by a period.
+fn period_start(p: &FinancialPeriod) -> i64 {
+    month_index(p.period_end) - p.months as i64 + 1
+}
+
+// ---------------------------------------------------------------------------
+// Validation
+// ---------------------------------------------------------------------------
+
+fn validate_period(p: &FinancialPeriod, field: &str) -> CorpFinanceResult<()> {
+    if p.months == 0 || p.months > MONTHS_IN_YEAR {
+        return Err(CorpFinanceError::InvalidInput {
+            field: format!("{field}.months"),
+            reason: format!("Period '{}' must cover between 1 and 12 months", p.label),
+        });
+    }
+    Ok(())
+}
+
+fn validate_ltm(input: &LtmInput) -> CorpFinanceResult<()> {
+    let annual = &input.latest_annual;
+    let current = &input.current_interim;
+    let prior = &input.prior_interim;
+
+    validate_period(annual, "latest_annual")?;
+    validate_period(current, "current_interim")?;
+    validate_period(prior, "prior_interim")?;
+
+    if annual.months != MONTHS_IN_YEAR {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "latest_annual.months".into(),
+            reason: "Latest annual period must cover 12 months".into(),
+        });
+    }
+    if current.months != prior.months {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "prior_interim.months".into(),
+            reason: "Current and prior interim periods must cover the same number of months".into(),
+        });
+    }
+    if current.months == MONTHS_IN_YEAR {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "current_interim.months".into(),
+            reason: "A 12-month interim is already LTM; use it directly".into(),
+        });
+    }
+    let annual_end = month_index(annual.period_end);
+    if period_start(current) != annual_end + 1 {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "current_interim.period_end".into(),
+            reason: "Current interim must start the month after the latest fiscal year end".into(),
+        });
+    }
+    if period_start(prior) != annual_end - MONTHS_IN_YEAR as i64 + 1 {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "prior_interim.period_end".into(),
+            reason: "Prior interim must start at the beginning of the latest fiscal year".into(),
+        });
+    }
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn period(
+        label: &str,
+        end: NaiveDate,
+        months: u32,
+        items: &[(&str, Decimal)],
+    ) -> FinancialPeriod {
+        FinancialPeriod {
+            label: label.into(),
+            period_end: end,
+            months,
+            metrics: items.iter().map(|(k, v)| (k.to_string(), *v)).collect(),
+        }
+    }
+
+    fn ltm_input() -> LtmInput {
+        LtmInput {
+            latest_annual: period(
+                "FY2024",
+                date(2024, 12, 31),
+                12,
+                &[
+                    ("revenue", dec!(1000)),
+                    ("ebitda", dec!(200)),
+                    ("net_debt", dec!(400)),
+                ],
+            ),
+            current_interim: period(
+                "9M2025",
+                date(2025, 9, 30),
+                9,
+                &[
+                    ("revenue", dec!(800)),
+                    ("ebitda", dec!(170)),
+                    ("net_debt", dec!(350)),
+                ],
+            ),
+            prior_interim: period(
+                "9M2024",
+                date(2024, 9, 30),
+                9,
+                &[
+                    ("revenue", dec!(740)),
+                    ("ebitda", dec!(150)),
+                    ("net_debt", dec!(420)),
+                ],
+            ),
+            point_in_time_metrics: vec!["net_debt".into()],
+        }
+    }
+
+    #[test]
+    fn test_ltm_flow_metrics() {
+        let out = build_ltm(&ltm_input()).unwrap();
+        // 1000 + 800 - 740 = 1060
+        assert_eq!(out.result.metrics["revenue"], dec!(1060));
+        // 200 + 170 - 150 = 220
+        assert_eq!(out.result.metrics["ebitda"], dec!(220));
+        assert_eq!(out.result.ltm_period_end, date(2025, 9, 30));
+    }
+
+    #[test]
+    fn test_ltm_point_in_time_uses_latest_interim() {
+        let out = build_ltm(&ltm_input()).unwrap();
+        assert_eq!(out.result.metrics["net_debt"], dec!(350));
+        let line = out
+            .result
+            .lines
+            .iter()
+            .find(|l| l.metric == "net_debt")
+            .unwrap();
+        assert!(line.point_in_time);
+    }
+
+    #[test]
+    fn test_ltm_stub_lines() {
+        let out = build_ltm(&ltm_input()).unwrap();
+        let line = out
+            .result
+            .lines
+            .iter()
+            .find(|l| l.metric == "revenue")
+            .unwrap();
+        assert_eq!(line.latest_annual, dec!(1000));
+        assert_eq!(line.add_current_interim, dec!(800));
+        assert_eq!(line.less_prior_interim, dec!(740));
+        // (800 - 740) / 740
+        let g = line.interim_growth.unwrap();
+        assert!((g - dec!(0.081081)).abs() < dec!(0.0001));
+    }
+
+    #[test]
+    fn test_ltm_missing_metric_warns() {
+        let mut input = ltm_input();
+        input
+            .current_interim
+            .metrics
+            .insert("capex".into(), dec!(50));
+        let out = build_ltm(&input).unwrap();
+        assert!(!out.result.metrics.contains_key("capex"));
+        assert!(out.warnings.iter().any(|w| w.contains("capex")));
+    }
+
+    #[test]
+    fn test_ltm_rejects_mismatched_interims() {
+        let mut input = ltm_input();
+        input.prior_interim.months = 6;
+        assert!(build_ltm(&input).is_err());
+    }
+
+    #[test]
+    fn test_ltm_rejects_misaligned_current_interim() {
+        let mut input = ltm_input();
+        input.current_interim.period_end = date(2025, 10, 31);
+        assert!(build_ltm(&input).is_err());
+    }
+
+    #[test]
+    fn test_ltm_rejects_misaligned_prior_interim() {
+        let mut input = ltm_input();
+        input.prior_interim.period_end = date(2023, 9, 30);
+        assert!(build_ltm(&input).is_err());
+    }
+
+    #[test]
+    fn test_ltm_non_calendar_fiscal_year() {
+        let input = LtmInput {
+            latest_annual: period("FY2025", date(2025, 3, 31), 12, &[("revenue", dec!(480))]),
+            current_interim: period("H1FY26", date(2025, 9, 30), 6, &[("revenue", dec!(260))]),
+            prior_interim: period("H1FY25", date(2024, 9, 30), 6, &[("revenue", dec!(230))]),
+            point_in_time_metrics: vec![],
+        };
+        let out = build_ltm(&input).unwrap();
+        assert_eq!(out.result.metrics["revenue"], dec!(510));
+    }
+
+    #[test]
+    fn test_ltm_no_common_metrics_errors() {
+        let input = LtmInput {
+            latest_annual: period("FY", date(2024, 12, 31), 12, &[("revenue", dec!(1))]),
+            current_interim: period("Q1", date(2025, 3, 31), 3, &[("ebitda", dec!(1))]),
+            prior_interim: period("Q1", date(2024, 3, 31), 3, &[]),
+            point_in_time_metrics: vec![],
+        };
+        assert!(build_ltm(&input).is_err());
+    }
+
+    fn march_fy_company() -> CompanyPeriods {
+        CompanyPeriods {
+            name: "MarchCo".into(),
+            periods: vec![
+                period(
+                    "FY2025",
+                    date(2025, 3, 31),
+                    12,
+                    &[("revenue", dec!(1200)), ("net_debt", dec!(90))],
+                ),
+                period(
+                    "FY2024",
+                    date(2024, 3, 31),
+                    12,
+                    &[("revenue", dec!(1000)), ("net_debt", dec!(100))],
+                ),
+            ],
+        }
+    }
+
+    #[test]
+    fn test_calendarize_march_year_end() {
+        let input = CalendarizeInput {
+            companies: vec![march_fy_company()],
+            target_period_end: date(2024, 12, 31),
+            point_in_time_metrics: vec![],
+        };
+        let out = calendarize(&input).unwrap();
+        let co = &out.result.companies[0];
+        // CY2024 = 3/12 * 1000 + 9/12 * 1200 = 250 + 900
+        assert_eq!(co.metrics["revenue"], dec!(1150));
+        assert_eq!(co.fiscal_year_end_month, 3);
+        assert_eq!(co.weights.len(), 2);
+        assert_eq!(co.weights[0].overlap_months, 3);
+        assert_eq!(co.weights[1].overlap_months, 9);
+    }
+
+    #[test]
+    fn test_calendarize_aligned_company_unchanged() {
+        let input = CalendarizeInput {
+            companies: vec![CompanyPeriods {
+                name: "DecCo".into(),
+                periods: vec![period(
+                    "FY2024",
+                    date(2024, 12, 31),
+                    12,
+                    &[("revenue", dec!(500))],
+                )],
+            }],
+            target_period_end: date(2024, 12, 31),
+            point_in_time_metrics: vec![],
+        };
+        let out = calendarize(&input).unwrap();
+        assert_eq!(out.result.companies[0].metrics["revenue"], dec!(500));
+        assert!(out.warnings.is_empty());
+    }
+
+    #[test]
+    fn test_calendarize_point_in_time() {
+        let input = CalendarizeInput {
+            companies: vec![march_fy_company()],
+            target_period_end: date(2024, 12, 31),
+            point_in_time_metrics: vec!["net_debt".into()],
+        };
+        let out = calendarize(&input).unwrap();
+        // Latest period ending on or before Dec-2024 is FY2024 (Mar-2024)
+        assert_eq!(out.result.companies[0].metrics["net_debt"], dec!(100));
+    }
+
+    #[test]
+    fn test_calendarize_mixed_quarters() {
+        let input = CalendarizeInput {
+            companies: vec![CompanyPeriods {
+                name: "QuarterCo".into(),
+                periods: vec![
+                    period("FY2024", date(2024, 6, 30), 12, &[("revenue", dec!(1200))]),
+                    period("Q1FY25", date(2024, 9, 30), 3, &[("revenue", dec!(330))]),
+                    period("Q2FY25", date(2024, 12, 31), 3, &[("revenue", dec!(350))]),
+                ],
+            }],
+            target_period_end: date(2024, 12, 31),
+            point_in_time_metrics: vec![],
+        };
+        let out = calendarize(&input).unwrap();
+        // 6/12 * 1200 + 330 + 350
+        assert_eq!(out.result.companies[0].metrics["revenue"], dec!(1280));
+    }
+
+    #[test]
+    fn test_calendarize_insufficient_coverage() {
+        let mut company = march_fy_company();
+        company.periods.remove(0);
+        let input = CalendarizeInput {
+            companies: vec![company],
+            target_period_end: date(2024, 12, 31),
+            point_in_time_metrics: vec![],
+        };
+        assert!(calendarize(&input).is_err());
+    }
+
+    #[test]
+    fn test_calendarize_rejects_overlap() {
+        let mut company = march_fy_company();
+        company.periods.push(period(
+            "H1FY25",
+            date(2024, 9, 30),
+            6,
+            &[("revenue", dec!(550))],
+        ));
+        let input = CalendarizeInput {
+            companies: vec![company],
+            target_period_end: date(2024, 12, 31),
+            point_in_time_metrics: vec![],
+        };
+        assert!(calendarize(&input).is_err());
+    }
+
+    #[test]
+    fn test_calendarize_empty_errors() {
+        let input = CalendarizeInput {
+            companies: vec![],
+            target_period_end: date(2024, 12, 31),
+            point_in_time_metrics: vec![],
+        };
+        assert!(calendarize(&input).is_err());
+    }
+
+    #[test]
+    fn test_annualize_run_rate() {
+        let input = AnnualizeInput {
+            period: period(
+                "9M",
+                date(2025, 9, 30),
+                9,
+                &[("revenue", dec!(900)), ("cash", dec!(50))],
+            ),
+            method: AnnualizationMethod::RunRate,
+            prior_comparable_period: None,
+            prior_fiscal_year: None,
+            point_in_time_metrics: vec!["cash".into()],
+        };
+        let out = annualize_period(&input).unwrap();
+        assert_eq!(out.result.metrics["revenue"], dec!(1200));
+        assert_eq!(out.result.metrics["cash"], dec!(50));
+    }
+
+    #[test]
+    fn test_annualize_seasonal() {
+        let input = AnnualizeInput {
+            period: period("Q4", date(2025, 12, 31), 3, &[("revenue", dec!(440))]),
+            method: AnnualizationMethod::Seasonal,
+            prior_comparable_period: Some(period(
+                "Q4",
+                date(2024, 12, 31),
+                3,
+                &[("revenue", dec!(400))],
+            )),
+            prior_fiscal_year: Some(period(
+                "FY",
+                date(2024, 12, 31),
+                12,
+                &[("revenue", dec!(1000))],
+            )),
+            point_in_time_metrics: vec![],
+        };
+        let out = annualize_period(&input).unwrap();
+        // Q4 is 40% of the year -> 440 * 2.5
+        assert_eq!(out.result.metrics["revenue"], dec!(1100));
+        assert_eq!(out.result.factors["revenue"], dec!(2.5));
+    }
+
+    #[test]
+    fn test_annualize_seasonal_falls_back_to_run_rate() {
+        let input = AnnualizeInput {
+            period: period("Q4", date(2025, 12, 31), 3, &[("ebitda", dec!(100))]),
+            method: AnnualizationMethod::Seasonal,
+            prior_comparable_period: Some(period("Q4", date(2024, 12, 31), 3, &[])),
+            prior_fiscal_year: Some(period("FY", date(2024, 12, 31), 12, &[])),
+            point_in_time_metrics: vec![],
+        };
+        let out = annualize_period(&input).unwrap();
+        assert_eq!(out.result.metrics["ebitda"], dec!(400));
+        assert_eq!(out.warnings.len(), 1);
+    }
+
+    #[test]
+    fn test_annualize_seasonal_requires_prior_periods() {
+        let input = AnnualizeInput {
+            period: period("Q4", date(2025, 12, 31), 3, &[("revenue", dec!(440))]),
+            method: AnnualizationMethod::Seasonal,
+            prior_comparable_period: None,
+            prior_fiscal_year: None,
+            point_in_time_metrics: vec![],
+        };
+        assert!(annualize_period(&input).is_err());
+    }
+
+    #[test]
+    fn test_annualize_rejects_invalid_months() {
+        let input = AnnualizeInput {
+            period: period("Bad", date(2025, 12, 31), 0, &[("revenue", dec!(1))]),
+            method: AnnualizationMethod::RunRate,
+            prior_comparable_period: None,
+            prior_fiscal_year: None,
+            point_in_time_metrics: vec![],
+        };
+        assert!(annualize_period(&input).is_err());
+    }
+}
@@ -1 +1,3 @@
+pub mod dcf_bridge;
+pub mod ltm;
 pub mod model;
@@ -13,6 +13,7 @@ use crate::CorpFinanceResult;
 
 const DAYS_IN_YEAR: Decimal = dec!(365);
 const CIRCULAR_ITERATIONS: usize = 5;
+const BALANCE_TOLERANCE: Decimal = dec!(0.01);
 
 // ---------------------------------------------------------------------------
 // Input
@@ -65,6 +66,54 @@ pub struct ThreeStatementInput {
     pub dividend_payout_ratio: Rate,
     /// Minimum cash to maintain (excess goes to extra debt paydown)
     pub min_cash_balance: Money,
+    /// Per-year driver schedules overriding the constant assumptions above
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub drivers: Option<DriverSchedules>,
+}
+
+/// Year-by-year driver vectors. Each schedule that is provided must have one
+/// entry per projection year and replaces the matching constant assumption;
+/// omitted schedules fall back to the constant.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DriverSchedules {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cogs_pct: Option<Vec<Rate>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sga_pct: Option<Vec<Rate>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rnd_pct: Option<Vec<Rate>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub da_pct: Option<Vec<Rate>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub capex_pct: Option<Vec<Rate>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dso_days: Option<Vec<Decimal>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dio_days: Option<Vec<Decimal>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dpo_days: Option<Vec<Decimal>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub interest_rate: Option<Vec<Rate>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tax_rate: Option<Vec<Rate>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dividend_payout_ratio: Option<Vec<Rate>>,
+}
+
+/// Drivers resolved for a single projection year.
+#[derive(Debug, Clone, Copy)]
+struct YearDrivers {
+    cogs_pct: Rate,
+    sga_pct: Rate,
+    rnd_pct: Rate,
+    da_pct: Rate,
+    capex_pct: Rate,
+    dso_days: Decimal,
+    dio_days: Decimal,
+    dpo_days: Decimal,
+    interest_rate: Rate,
+    tax_rate: Rate,
+    dividend_payout_ratio: Rate,
 }
 
 // ---------------------------------------------------------------------------
@@ -77,9 +126,23 @@ pub struct ThreeStatementOutput {
     pub income_statements: Vec<IncomeStatement>,
     pub balance_sheets: Vec<BalanceSheet>,
     pub cash_flow_statements: Vec<CashFlowStatement>,
+    pub balance_checks: Vec<BalanceCheck>,
     pub summary: ProjectionSummary,
 }
 
+/// Integrity checks linking the three statements for one year.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BalanceCheck {
+    pub year: i32,
+    /// Total assets less total liabilities and equity
+    pub difference: Money,
+    /// Opening cash plus net change in cash equals balance sheet cash
+    pub cash_reconciles: bool,
+    /// Change in equity equals net income less dividends
+    pub equity_reconciles: bool,
+    pub balanced: bool,
+}
+
 /// Income statement for a single projected year.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct IncomeStatement {
@@ -156,6 +219,8 @@ pub struct ProjectionSummary {
     pub ending_debt: Money,
     pub ending_leverage: Decimal,
     pub cumulative_fcf: Money,
+    /// True when every projected year passes its balance checks
+    pub balance_sheet_balances: bool,
 }
 
 // ---------------------------------------------------------------------------
@@ -164,6 +229,12 @@ pub struct ProjectionSummary {
 
 /// Build a linked three-statement financial model (IS, BS, CF) with circular
 /// reference resolution for interest expense.
+#[tracing::instrument(
+    level = "debug",
+    name = "three_statement",
+    skip_all,
+    fields(years = input.revenue_growth_rates.len())
+)]
 pub fn build_three_statement_model(
     input: &ThreeStatementInput,
 ) -> CorpFinanceResult<ComputationOutput<ThreeStatementOutput>> {
@@ -173,6 +244,18 @@ pub fn build_three_statement_model(
     validate_input(input)?;
 
     let n_years = input.revenue_growth_rates.len();
+    let drivers = resolve_drivers(input);
+
+    let base_difference =
+        input.base_cash + input.base_receivables + input.base_inventory + input.base_ppe
+            - input.base_payables
+            - input.base_debt
+            - input.base_equity;
+    if base_difference.abs() > BALANCE_TOLERANCE {
+        warnings.push(format!(
+            "Base balance sheet does not balance (assets exceed liabilities and equity by {base_difference}); the gap carries into every projected year"
+        ));
+    }
 
     // Carry-forward state from the base year
     let mut prior_revenue = input.base_revenue;
@@ -188,8 +271,9 @@ pub fn build_three_statement_model(
     let mut income_statements = Vec::with_capacity(n_years);
     let mut balance_sheets = Vec::with_capacity(n_years);
     let mut cash_flow_statements = Vec::with_capacity(n_years);
+    let mut balance_checks = Vec::with_capacity(n_years);
 
-    for yr_idx in 0..n_years {
+    for (yr_idx, d) in drivers.iter().enumerate() {
         let year = (yr_idx + 1) as i32;
         let growth = input.revenue_growth_rates[yr_idx];
 
@@ -197,21 +281,21 @@ pub fn build_three_statement_model(
         // Income Statement (pre-interest)
         // ---------------------------------------------------------------
         let revenue = prior_revenue * (Decimal::ONE + growth);
-        let cogs = revenue * input.cogs_pct;
+        let cogs = revenue * d.cogs_pct;
         let gross_profit = revenue - cogs;
-        let sga = revenue * input.sga_pct;
-        let rnd = revenue * input.rnd_pct;
+        let sga = revenue * d.sga_pct;
+        let rnd = revenue * d.rnd_pct;
         let total_opex = sga + rnd;
-        let depreciation = prior_ppe * input.da_pct;
+        let depreciation = prior_ppe * d.da_pct;
         let ebitda = gross_profit - total_opex;
         let ebit = ebitda - depreciation;
 
         // ---------------------------------------------------------------
         // Working capital
         // ---------------------------------------------------------------
-        let receivables = revenue * input.dso_days / DAYS_IN_YEAR;
-        let inventory = cogs * input.dio_days / DAYS_IN_YEAR;
-        let payables = cogs * input.dpo_days / DAYS_IN_YEAR;
+        let receivables = revenue * d.dso_days / DAYS_IN_YEAR;
+        let inventory = cogs * d.dio_days / DAYS_IN_YEAR;
+        let payables = cogs * d.dpo_days / DAYS_IN_YEAR;
         let change_in_receivables = receivables - prior_receivables;
         let change_in_inventory = inventory - prior_inventory;
         let change_in_payables = payables - prior_payables;
@@ -219,7 +303,7 @@ pub fn build_three_statement_model(
         // ---------------------------------------------------------------
         // Capex and PP&E
         // ---------------------------------------------------------------
-        let capex = revenue * input.capex_pct;
+        let capex = revenue * d.capex_pct;
         let ppe_net = prior_ppe - depreciation + capex;
 
         // ---------------------------------------------------------------
@@ -230,7 +314,7 @@ pub fn build_three_statement_model(
         let scheduled_repayment = prior_debt * input.debt_repayment_pct;
 
         // Initial guess: interest on prior-year debt
-        let mut interest_expense = prior_debt * input.interest_rate;
+        let mut interest_expense = prior_debt * d.interest_rate;
 
         // Iterate to converge interest <-> debt <-> cash flow circular reference.
         // Only interest_expense is carried between iterations; everything else is
@@ -238,13 +322,13 @@ pub fn build_three_statement_model(
         for _iter in 0..CIRCULAR_ITERATIONS {
             let iter_ebt = ebit - interest_expense;
             let iter_taxes = if iter_ebt > Decimal::ZERO {
-                iter_ebt * input.tax_rate
+                iter_ebt * d.tax_rate
             } else {
                 Decimal::ZERO
             };
             let iter_ni = iter_ebt - iter_taxes;
             let iter_dividends = if iter_ni > Decimal::ZERO {
-                iter_ni * input.dividend_payout_ratio
+                iter_ni * d.dividend_payout_ratio
             } else {
                 Decimal::ZERO
             };
@@ -277,20 +361,20 @@ pub fn build_three_statement_model(
             }
 
             let avg_debt = (prior_debt + iter_debt) / dec!(2);
-            interest_expense = avg_debt * input.interest_rate;
+            interest_expense = avg_debt * d.interest_rate;
         }
 
         // Final computation with converged interest_expense
         let final_ebt = ebit - interest_expense;
         let final_taxes = if final_ebt > Decimal::ZERO {
-            final_ebt * input.tax_rate
+            final_ebt * d.tax_rate
         } else {
             Decimal::ZERO
         };
         let final_net_income = final_ebt - final_taxes;
 
         let final_dividends = if final_net_income > Decimal::ZERO {
-            final_net_income * input.dividend_payout_ratio
+            final_net_income * d.dividend_payout_ratio
         } else {
             Decimal::ZERO
         };
@@ -422,6 +506,29 @@ pub fn build_three_statement_model(
             fcfe,
         });
 
+        // Balance checks
+        let difference = total_assets - total_liabilities_and_equity;
+        let cash_reconciles =
+            (prior_cash + net_change_in_cash - final_ending_cash).abs() <= BALANCE_TOLERANCE;
+        let equity_reconciles =
+            (shareholders_equity - prior_equity - (final_net_income - final_dividends)).abs()
+                <= BALANCE_TOLERANCE;
+        let balanced = (difference - base_difference).abs() <= BALANCE_TOLERANCE
+            && cash_reconciles
+            && equity_reconciles;
+        if !balanced {
+            warnings.push(format!(
+                "Year {year}: balance sheet out of balance by {difference}"
+            ));
+        }
+        balance_checks.push(BalanceCheck {
+            year,
+            difference,
+            cash_reconciles,
+            equity_reconciles,
+            balanced,
+        });
+
         // Warnings
         if ebitda > Decimal::ZERO {
             let leverage = final_total_debt / ebitda;
@@ -462,12 +569,14 @@ pub fn build_three_statement_model(
         &income_statements,
         &cash_flow_statements,
         &balance_sheets,
+        &balance_checks,
     );
 
     let output = ThreeStatementOutput {
         income_statements,
         balance_sheets,
         cash_flow_statements,
+        balance_checks,
         summary,
     };
 
@@ -493,6 +602,10 @@ fn validate_input(input: &ThreeStatementInput) -> CorpFinanceResult<()> {
             reason: "Must contain at least one growth rate".into(),
         });
     }
+    crate::limits::check_schedule_periods(
+        "revenue_growth_rates",
+        input.revenue_growth_rates.len() as u64,
+    )?;
 
     validate_rate("cogs_pct", input.cogs_pct)?;
     validate_rate("sga_pct", input.sga_pct)?;
@@ -517,17 +630,100 @@ fn validate_input(input: &ThreeStatementInput) -> CorpFinanceResult<()> {
     validate_non_negative("dio_days", input.dio_days)?;
     validate_non_negative("dpo_days", input.dpo_days)?;
 
-    // Operating expenses should not exceed 100% of revenue
-    let total_cost_pct = input.cogs_pct + input.sga_pct + input.rnd_pct;
-    if total_cost_pct > Decimal::ONE {
-        return Err(CorpFinanceError::FinancialImpossibility(format!(
-            "Total operating cost percentage ({total_cost_pct}) exceeds 100% of revenue"
-        )));
+    if let Some(ref sched) = input.drivers {
+        validate_schedules(sched, input.revenue_growth_rates.len())?;
     }
 
+    // Operating expenses should not exceed 100% of revenue in any year
+    for (i, d) in resolve_drivers(input).iter().enumerate() {
+        let total_cost_pct = d.cogs_pct + d.sga_pct + d.rnd_pct;
+        if total_cost_pct > Decimal::ONE {
+            return Err(CorpFinanceError::FinancialImpossibility(format!(
+                "Year {}: total operating cost percentage ({total_cost_pct}) exceeds 100% of revenue",
+                i + 1
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+fn validate_schedules(sched: &DriverSchedules, n_years: usize) -> CorpFinanceResult<()> {
+    let rates = [
+        ("drivers.cogs_pct", &sched.cogs_pct),
+        ("drivers.sga_pct", &sched.sga_pct),
+        ("drivers.rnd_pct", &sched.rnd_pct),
+        ("drivers.da_pct", &sched.da_pct),
+        ("drivers.capex_pct", &sched.capex_pct),
+        ("drivers.interest_rate", &sched.interest_rate),
+        ("drivers.tax_rate", &sched.tax_rate),
+        (
+            "drivers.dividend_payout_ratio",
+            &sched.dividend_payout_ratio,
+        ),
+    ];
+    let days = [
+        ("drivers.dso_days", &sched.dso_days),
+        ("drivers.dio_days", &sched.dio_days),
+        ("drivers.dpo_days", &sched.dpo_days),
+    ];
+    for (field, values) in rates.iter().chain(days.iter()) {
+        if let Some(v) = values {
+            if v.len() != n_years {
+                return Err(CorpFinanceError::InvalidInput {
+                    field: (*field).into(),
+                    reason: format!(
+                        "Expected {n_years} values (one per projection year), got {}",
+                        v.len()
+                    ),
+                });
+            }
+        }
+    }
+    for (field, values) in rates {
+        for v in values.iter().flatten() {
+            validate_rate(field, *v)?;
+        }
+    }
+    for (field, values) in days {
+        for v in values.iter().flatten() {
+            validate_non_negative(field, *v)?;
+        }
+    }
     Ok(())
 }
 
+/// Resolve the driver set for each projection year, preferring schedule
+/// entries over the constant assumptions.
+fn resolve_drivers(input: &ThreeStatementInput) -> Vec<YearDrivers> {
+    let sched = input.drivers.clone().unwrap_or_default();
+    let pick = |schedule: &Option<Vec<Decimal>>, i: usize, constant: Decimal| {
+        schedule
+            .as_ref()
+            .and_then(|v| v.get(i).copied())
+            .unwrap_or(constant)
+    };
+    (0..input.revenue_growth_rates.len())
+        .map(|i| YearDrivers {
+            cogs_pct: pick(&sched.cogs_pct, i, input.cogs_pct),
+            sga_pct: pick(&sched.sga_pct, i, input.sga_pct),
+            rnd_pct: pick(&sched.rnd_pct, i, input.rnd_pct),
+            da_pct: pick(&sched.da_pct, i, input.da_pct),
+            capex_pct: pick(&sched.capex_pct, i, input.capex_pct),
+            dso_days: pick(&sched.dso_days, i, input.dso_days),
+            dio_days: pick(&sched.dio_days, i, input.dio_days),
+            dpo_days: pick(&sched.dpo_days, i, input.dpo_days),
+            interest_rate: pick(&sched.interest_rate, i, input.interest_rate),
+            tax_rate: pick(&sched.tax_rate, i, input.tax_rate),
+            dividend_payout_ratio: pick(
+                &sched.dividend_payout_ratio,
+                i,
+                input.dividend_payout_ratio,
+            ),
+        })
+        .collect()
+}
+
 fn validate_rate(field: &str, value: Rate) -> CorpFinanceResult<()> {
     if value < Decimal::ZERO || value > Decimal::ONE {
         return Err(CorpFinanceError::InvalidInput {
@@ -565,6 +761,7 @@ fn build_summary(
     income_statements: &[IncomeStatement],
     cash_flow_statements: &[CashFlowStatement],
     balance_sheets: &[BalanceSheet],
+    balance_checks: &[BalanceCheck],
 ) -> ProjectionSummary {
     let n = income_statements.len() as i32;
     let last_is = income_statements.last().unwrap();
@@ -604,6 +801,7 @@ fn build_summary(
         ending_debt: last_bs.total_debt,
         ending_leverage,
         cumulative_fcf,
+        balance_sheet_balances: balance_checks.iter().all(|c| c.balanced),
     }
 }
 
@@ -678,6 +876,7 @@ mod tests {
             debt_repayment_pct: dec!(0.05),
             dividend_payout_ratio: dec!(0.30),
             min_cash_balance: dec!(50),
+            drivers: None,
         }
     }
 
@@ -1279,4 +1478,91 @@ mod tests {
             );
         }
     }
+
+    // --------------------------------------------------
+    // Driver schedules and balance checks
+    // --------------------------------------------------
+
+    #[test]
+    fn test_balance_checks_pass() {
+        let result = build_three_statement_model(&sample_input()).unwrap();
+        let out = &result.result;
+        assert_eq!(out.balance_checks.len(), 3);
+        for check in &out.balance_checks {
+            assert!(
+                check.balanced,
+                "year {} difference {}",
+                check.year, check.difference
+            );
+            assert!(check.difference.abs() <= dec!(0.01));
+        }
+        assert!(out.summary.balance_sheet_balances);
+    }
+
+    #[test]
+    fn test_margin_schedule_overrides_constant() {
+        let mut input = sample_input();
+        input.drivers = Some(DriverSchedules {
+            cogs_pct: Some(vec![dec!(0.60), dec!(0.58), dec!(0.55)]),
+            ..Default::default()
+        });
+        let result = build_three_statement_model(&input).unwrap();
+        let is = &result.result.income_statements;
+        assert_eq!(is[0].gross_margin, dec!(0.40));
+        assert_eq!(is[2].gross_margin, dec!(0.45));
+        assert!(result.result.summary.balance_sheet_balances);
+    }
+
+    #[test]
+    fn test_capex_and_working_capital_schedules() {
+        let mut input = sample_input();
+        input.drivers = Some(DriverSchedules {
+            capex_pct: Some(vec![dec!(0.15), dec!(0.08), dec!(0.05)]),
+            dso_days: Some(vec![dec!(30), dec!(45), dec!(45)]),
+            ..Default::default()
+        });
+        let result = build_three_statement_model(&input).unwrap();
+        let out = &result.result;
+        let rev1 = out.income_statements[0].revenue;
+        let rev2 = out.income_statements[1].revenue;
+        assert_eq!(out.cash_flow_statements[0].capex, rev1 * dec!(0.15));
+        assert_eq!(
+            out.balance_sheets[1].accounts_receivable,
+            rev2 * dec!(45) / dec!(365)
+        );
+        assert!(out.summary.balance_sheet_balances);
+    }
+
+    #[test]
+    fn test_schedule_length_mismatch_rejected() {
+        let mut input = sample_input();
+        input.drivers = Some(DriverSchedules {
+            sga_pct: Some(vec![dec!(0.10), dec!(0.10)]),
+            ..Default::default()
+        });
+        assert!(build_three_statement_model(&input).is_err());
+    }
+
+    #[test]
+    fn test_schedule_cost_overrun_rejected() {
+        let mut input = sample_input();
+        input.drivers = Some(DriverSchedules {
+            cogs_pct: Some(vec![dec!(0.60), dec!(0.60), dec!(0.90)]),
+            ..Default::default()
+        });
+        assert!(build_three_statement_model(&input).is_err());
+    }
+
+    #[test]
+    fn test_unbalanced_base_year_warns() {
+        let mut input = sample_input();
+        input.base_equity = dec!(300);
+        let result = build_three_statement_model(&input).unwrap();
+        assert!(result
+            .warnings
+            .iter()
+            .any(|w| w.contains("Base balance sheet")));
+        // Projection is internally consistent with the opening gap
+        assert!(result.result.summary.balance_sheet_balances);
+    }
 }
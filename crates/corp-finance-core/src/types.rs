@@ -2,6 +2,8 @@ use chrono::NaiveDate;
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
+use crate::{CorpFinanceError, CorpFinanceResult};
+
 /// All monetary values. Wraps Decimal to prevent accidental f64 usage.
 pub type Money = Decimal;
 
@@ -30,6 +32,115 @@ pub enum Currency {
     Other(String),
 }
 
+impl Currency {
+    /// Currency for an ISO 4217 code such as "EUR"; unknown codes map to `Other`.
+    pub fn from_code(code: &str) -> Self {
+        match code {
+            "GBP" => Currency::GBP,
+            "USD" => Currency::USD,
+            "EUR" => Currency::EUR,
+            "CHF" => Currency::CHF,
+            "JPY" => Currency::JPY,
+            "CAD" => Currency::CAD,
+            "AUD" => Currency::AUD,
+            "HKD" => Currency::HKD,
+            "SGD" => Currency::SGD,
+            other => Currency::Other(other.to_string()),
+        }
+    }
+}
+
+/// Day count convention for computing accrued interest and period fractions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DayCountConvention {
+    /// 30/360 US corporate convention
+    Thirty360,
+    /// ACT/360 money market convention
+    Actual360,
+    /// ACT/365 fixed (UK gilts)
+    Actual365,
+    /// ACT/ACT ICMA (US Treasuries, EUR government and corporate bonds)
+    #[serde(alias = "ActualActualIcma")]
+    ActualActual,
+}
+
+impl DayCountConvention {
+    /// Market label for the convention (e.g. "ACT/360").
+    pub fn label(&self) -> &'static str {
+        match self {
+            DayCountConvention::Thirty360 => "30/360",
+            DayCountConvention::Actual360 => "ACT/360",
+            DayCountConvention::Actual365 => "ACT/365",
+            DayCountConvention::ActualActual => "ACT/ACT ICMA",
+        }
+    }
+}
+
+/// Spot FX quote: one unit of `base` buys `rate` units of `quote`
+/// (e.g. base GBP, quote USD, rate 1.25).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FxRate {
+    pub base: Currency,
+    pub quote: Currency,
+    pub rate: Decimal,
+}
+
+/// Rate converting one unit of `from` into `to`. Uses a direct quote, the
+/// inverse of the opposite quote, or a cross through a common currency.
+pub fn fx_conversion_rate(
+    from: &Currency,
+    to: &Currency,
+    rates: &[FxRate],
+) -> CorpFinanceResult<Decimal> {
+    if from == to {
+        return Ok(Decimal::ONE);
+    }
+    for r in rates {
+        if r.rate <= Decimal::ZERO {
+            return Err(CorpFinanceError::InvalidInput {
+                field: "fx_rates".into(),
+                reason: format!("FX rate {:?}/{:?} must be positive", r.base, r.quote),
+            });
+        }
+    }
+
+    if let Some(rate) = direct_fx_rate(from, to, rates) {
+        return Ok(rate);
+    }
+
+    // Cross through any currency quoted against both legs
+    for r in rates {
+        for via in [&r.base, &r.quote] {
+            if via == from || via == to {
+                continue;
+            }
+            if let (Some(a), Some(b)) = (
+                direct_fx_rate(from, via, rates),
+                direct_fx_rate(via, to, rates),
+            ) {
+                return Ok(a * b);
+            }
+        }
+    }
+
+    Err(CorpFinanceError::InsufficientData(format!(
+        "No FX rate available to convert {:?} to {:?}",
+        from, to
+    )))
+}
+
+fn direct_fx_rate(from: &Currency, to: &Currency, rates: &[FxRate]) -> Option<Decimal> {
+    rates.iter().find_map(|r| {
+        if r.base == *from && r.quote == *to {
+            Some(r.rate)
+        } else if r.base == *to && r.quote == *from {
+            Some(Decimal::ONE / r.rate)
+        } else {
+            None
+        }
+    })
+}
+
 /// A single cash flow at a point in time
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CashFlow {
@@ -71,8 +182,9 @@ pub struct Scenario {
     pub overrides: serde_json::Value,
 }
 
-/// Standard computation output envelope
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Standard computation output envelope. Serialising it applies the
+/// active [`PrecisionPolicy`](crate::precision::PrecisionPolicy) to `result`.
+#[derive(Debug, Clone, Deserialize)]
 pub struct ComputationOutput<T: Serialize> {
     pub result: T,
     pub methodology: String,
@@ -81,12 +193,44 @@ pub struct ComputationOutput<T: Serialize> {
     pub metadata: ComputationMetadata,
 }
 
+impl<T: Serialize> Serialize for ComputationOutput<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::{Error, SerializeStruct};
+
+        let policy = crate::precision::current();
+        let mut out = serializer.serialize_struct("ComputationOutput", 5)?;
+        if policy.is_active() {
+            let mut result = serde_json::to_value(&self.result).map_err(S::Error::custom)?;
+            policy.apply(&mut result);
+            out.serialize_field("result", &result)?;
+        } else {
+            out.serialize_field("result", &self.result)?;
+        }
+        out.serialize_field("methodology", &self.methodology)?;
+        out.serialize_field("assumptions", &self.assumptions)?;
+        out.serialize_field("warnings", &self.warnings)?;
+        if policy.is_active() {
+            let metadata = ComputationMetadata {
+                rounding: Some(policy),
+                ..self.metadata.clone()
+            };
+            out.serialize_field("metadata", &metadata)?;
+        } else {
+            out.serialize_field("metadata", &self.metadata)?;
+        }
+        out.end()
+    }
+}
+
 /// Metadata for every computation
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ComputationMetadata {
     pub version: String,
     pub computation_time_us: u64,
     pub precision: String,
+    /// Rounding applied to the result, when a precision policy is active.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rounding: Option<crate::precision::PrecisionPolicy>,
 }
 
 /// Helper to wrap computation results with metadata
@@ -97,6 +241,12 @@ pub fn with_metadata<T: Serialize>(
     elapsed_us: u64,
     result: T,
 ) -> ComputationOutput<T> {
+    tracing::debug!(
+        methodology,
+        elapsed_us,
+        warnings = warnings.len(),
+        "computation complete"
+    );
     ComputationOutput {
         result,
         methodology: methodology.to_string(),
@@ -106,6 +256,7 @@ pub fn with_metadata<T: Serialize>(
             version: env!("CARGO_PKG_VERSION").to_string(),
             computation_time_us: elapsed_us,
             precision: "rust_decimal_128bit".to_string(),
+            rounding: None,
         },
     }
 }
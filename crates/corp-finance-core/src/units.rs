@@ -0,0 +1,322 @@
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::str::FromStr;
+
+use crate::precision::{classify, ValueKind};
+use crate::{CorpFinanceError, CorpFinanceResult};
+
+/// Input field declaring the convention of the document's rates.
+pub const CONVENTION_FIELD: &str = "rate_convention";
+/// Input field turning ambiguous magnitudes into errors.
+pub const STRICT_FIELD: &str = "strict_units";
+
+// ---------------------------------------------------------------------------
+// Convention
+// ---------------------------------------------------------------------------
+
+/// How rate-type inputs are written. Calculators always work in decimals
+/// (0.05 = 5%); percent inputs are divided by 100 before use.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RateConvention {
+    /// 0.05 means 5%
+    #[default]
+    Decimal,
+    /// 5.0 means 5%
+    Percent,
+}
+
+impl FromStr for RateConvention {
+    type Err = CorpFinanceError;
+
+    fn from_str(s: &str) -> CorpFinanceResult<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "decimal" => Ok(RateConvention::Decimal),
+            "percent" | "percentage" => Ok(RateConvention::Percent),
+            _ => Err(CorpFinanceError::InvalidInput {
+                field: CONVENTION_FIELD.into(),
+                reason: format!("'{s}' is not 'decimal' or 'percent'"),
+            }),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UnitConvention {
+    #[serde(default)]
+    pub rates: RateConvention,
+    /// Reject rates whose magnitude contradicts the convention (a discount
+    /// rate of 7.5 in decimal mode, or 0.05 in percent mode) instead of
+    /// warning about them.
+    #[serde(default)]
+    pub strict: bool,
+}
+
+impl UnitConvention {
+    /// Take `rate_convention` and `strict_units` from the top level of an
+    /// input document, removing them so calculators never see them. Fields
+    /// that are absent keep the values from `default`.
+    pub fn take_from(doc: &mut Value, default: UnitConvention) -> CorpFinanceResult<Self> {
+        let Some(map) = doc.as_object_mut() else {
+            return Ok(default);
+        };
+        let rates = match map.remove(CONVENTION_FIELD) {
+            Some(Value::String(s)) => s.parse()?,
+            Some(Value::Null) | None => default.rates,
+            Some(other) => {
+                return Err(CorpFinanceError::InvalidInput {
+                    field: CONVENTION_FIELD.into(),
+                    reason: format!("expected \"decimal\" or \"percent\", got {other}"),
+                })
+            }
+        };
+        let strict = match map.remove(STRICT_FIELD) {
+            Some(Value::Bool(b)) => b,
+            Some(Value::Null) | None => default.strict,
+            Some(other) => {
+                return Err(CorpFinanceError::InvalidInput {
+                    field: STRICT_FIELD.into(),
+                    reason: format!("expected true or false, got {other}"),
+                })
+            }
+        };
+        Ok(UnitConvention { rates, strict })
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Normalisation
+// ---------------------------------------------------------------------------
+
+/// Premiums quoted as rates (`equity_risk_premium`, `size_premium`), as
+/// opposed to premium amounts such as `option_premium`.
+const RATE_PREMIUMS: &[&str] = &[
+    "risk",
+    "size",
+    "liquidity",
+    "illiquidity",
+    "call",
+    "pik",
+    "specialness",
+];
+
+/// Whether an input field holds a rate subject to the convention. Fields
+/// are classified by name as for output rounding; basis-point fields have
+/// their own unit and are left alone.
+pub fn is_rate_field(key: &str) -> bool {
+    let lower = key.to_lowercase();
+    let words: Vec<&str> = lower.split(|c: char| !c.is_ascii_alphanumeric()).collect();
+    if words.iter().any(|w| *w == "bps" || *w == "bp") {
+        return false;
+    }
+    if let [.., qualifier, "premium"] = words.as_slice() {
+        if RATE_PREMIUMS.contains(qualifier) {
+            return true;
+        }
+    }
+    classify(&lower) == ValueKind::Rate
+}
+
+/// Apply the document's convention (see [`UnitConvention::take_from`]) and
+/// convert every rate-type field to decimals. Returns warnings for
+/// magnitudes that look like the other convention; in strict mode those are
+/// errors instead.
+pub fn normalize_input(doc: &mut Value, default: UnitConvention) -> CorpFinanceResult<Vec<String>> {
+    let convention = UnitConvention::take_from(doc, default)?;
+    normalize_rates(doc, convention)
+}
+
+/// Convert every rate-type field of `doc` to decimals under `convention`.
+pub fn normalize_rates(
+    doc: &mut Value,
+    convention: UnitConvention,
+) -> CorpFinanceResult<Vec<String>> {
+    let mut warnings = Vec::new();
+    walk(doc, "", false, convention, &mut warnings)?;
+    Ok(warnings)
+}
+
+fn walk(
+    value: &mut Value,
+    path: &str,
+    is_rate: bool,
+    convention: UnitConvention,
+    warnings: &mut Vec<String>,
+) -> CorpFinanceResult<()> {
+    match value {
+        Value::Object(map) => {
+            for (key, v) in map.iter_mut() {
+                let child = if path.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{path}.{key}")
+                };
+                walk(v, &child, is_rate_field(key), convention, warnings)?;
+            }
+        }
+        Value::Array(items) => {
+            for (i, v) in items.iter_mut().enumerate() {
+                walk(v, &format!("{path}[{i}]"), is_rate, convention, warnings)?;
+            }
+        }
+        Value::Number(_) | Value::String(_) if is_rate => {
+            let text = match value {
+                Value::Number(n) => n.to_string(),
+                Value::String(s) => s.clone(),
+                _ => unreachable!(),
+            };
+            // Labels and other non-numeric strings are not rates
+            let Ok(rate) = Decimal::from_str(&text).or_else(|_| Decimal::from_scientific(&text))
+            else {
+                return Ok(());
+            };
+            if let Some(problem) = ambiguity(rate, convention.rates) {
+                let message = format!("{path} = {text}: {problem}");
+                if convention.strict {
+                    return Err(CorpFinanceError::InvalidInput {
+                        field: path.to_string(),
+                        reason: format!("{text} {problem}"),
+                    });
+                }
+                warnings.push(message);
+            }
+            if convention.rates == RateConvention::Percent {
+                let decimal = (rate / Decimal::ONE_HUNDRED).normalize();
+                *value = match value {
+                    Value::String(_) => Value::String(decimal.to_string()),
+                    _ => decimal
+                        .to_f64()
+                        .and_then(serde_json::Number::from_f64)
+                        .map(Value::Number)
+                        .unwrap_or(Value::String(decimal.to_string())),
+                };
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Describe why a rate's magnitude contradicts the declared convention.
+fn ambiguity(rate: Decimal, convention: RateConvention) -> Option<&'static str> {
+    let magnitude = rate.abs();
+    match convention {
+        RateConvention::Decimal if magnitude > Decimal::ONE => Some(
+            "looks like a percentage but rate_convention is decimal (write 0.05 for 5%, or declare rate_convention: percent)",
+        ),
+        RateConvention::Percent if !magnitude.is_zero() && magnitude < Decimal::ONE => Some(
+            "looks like a decimal but rate_convention is percent (write 5 for 5%, or declare rate_convention: decimal)",
+        ),
+        _ => None,
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn percent() -> UnitConvention {
+        UnitConvention {
+            rates: RateConvention::Percent,
+            strict: false,
+        }
+    }
+
+    #[test]
+    fn test_percent_inputs_normalised() {
+        let mut doc = json!({
+            "risk_free_rate": 4.25,
+            "terminal_growth_rate": "2.5",
+            "revenue_growth_rates": [5, 7.5, 10],
+            "spread_bps": 150,
+            "enterprise_value": 1000,
+            "tranches": [{ "name": "TLB", "interest_rate": 8, "amount": 500 }],
+        });
+        let warnings = normalize_rates(&mut doc, percent()).unwrap();
+        assert!(warnings.is_empty(), "{warnings:?}");
+        assert_eq!(doc["risk_free_rate"], json!(0.0425));
+        assert_eq!(doc["terminal_growth_rate"], "0.025");
+        assert_eq!(doc["revenue_growth_rates"], json!([0.05, 0.075, 0.1]));
+        assert_eq!(doc["spread_bps"], 150);
+        assert_eq!(doc["enterprise_value"], 1000);
+        assert_eq!(doc["tranches"][0]["interest_rate"], json!(0.08));
+        assert_eq!(doc["tranches"][0]["amount"], 500);
+    }
+
+    #[test]
+    fn test_decimal_inputs_unchanged() {
+        let mut doc = json!({ "wacc": 0.09, "tax_rate": "0.25" });
+        let before = doc.clone();
+        let warnings = normalize_rates(&mut doc, UnitConvention::default()).unwrap();
+        assert!(warnings.is_empty());
+        assert_eq!(doc, before);
+    }
+
+    #[test]
+    fn test_ambiguous_magnitude_warns_or_rejects() {
+        let mut doc = json!({ "discount_rate": 7.5 });
+        let warnings = normalize_rates(&mut doc, UnitConvention::default()).unwrap();
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].starts_with("discount_rate = 7.5"));
+        assert_eq!(
+            doc["discount_rate"],
+            json!(7.5),
+            "lenient mode leaves value"
+        );
+
+        let strict = UnitConvention {
+            rates: RateConvention::Decimal,
+            strict: true,
+        };
+        match normalize_rates(&mut doc, strict).unwrap_err() {
+            CorpFinanceError::InvalidInput { field, reason } => {
+                assert_eq!(field, "discount_rate");
+                assert!(reason.contains("looks like a percentage"));
+            }
+            other => panic!("Expected InvalidInput, got {other:?}"),
+        }
+
+        let mut doc = json!({ "tax_rate": 0.25 });
+        let strict_percent = UnitConvention {
+            strict: true,
+            ..percent()
+        };
+        assert!(normalize_rates(&mut doc, strict_percent).is_err());
+    }
+
+    #[test]
+    fn test_convention_declared_in_document() {
+        let mut doc = json!({
+            "rate_convention": "percent",
+            "strict_units": true,
+            "cost_of_debt": 6,
+        });
+        let warnings = normalize_input(&mut doc, UnitConvention::default()).unwrap();
+        assert!(warnings.is_empty());
+        assert_eq!(doc, json!({ "cost_of_debt": 0.06 }));
+
+        let mut doc = json!({ "rate_convention": "basis_points" });
+        assert!(normalize_input(&mut doc, UnitConvention::default()).is_err());
+    }
+
+    #[test]
+    fn test_rate_field_classification() {
+        assert!(is_rate_field("risk_free_rate"));
+        assert!(is_rate_field("ebitda_margin"));
+        assert!(is_rate_field("counterparty_default_probability"));
+        assert!(is_rate_field("equity_risk_premium"));
+        assert!(is_rate_field("size_premium"));
+        assert!(!is_rate_field("option_premium"));
+        assert!(!is_rate_field("spread_bps"));
+        assert!(!is_rate_field("initial_margin"));
+        assert!(!is_rate_field("notional"));
+        assert!(!is_rate_field("projection_years"));
+    }
+}
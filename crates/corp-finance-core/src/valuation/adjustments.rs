@@ -0,0 +1,663 @@
+use rust_decimal::Decimal;
+use rust_decimal::MathematicalOps;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+use std::time::Instant;
+
+use crate::error::CorpFinanceError;
+use crate::stats::norm_cdf;
+use crate::types::{with_metadata, ComputationOutput, Money, Rate, Years};
+use crate::CorpFinanceResult;
+
+// ---------------------------------------------------------------------------
+// Types
+// ---------------------------------------------------------------------------
+
+/// Level of value that the input enterprise value represents.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ValueBasis {
+    /// Marketable minority ("as if freely traded"), e.g. DCF or trading comps
+    MarketableMinority,
+    /// Control, e.g. precedent transactions
+    Control,
+}
+
+/// DLOM estimation model.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DlomMethod {
+    /// At-the-money European put over the holding period (Chaffe)
+    ProtectivePut,
+    /// Average-strike put (Finnerty 2012)
+    #[default]
+    Finnerty,
+    /// Simple average of the two models
+    Average,
+}
+
+/// Inputs for the option-based DLOM models.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DlomSpec {
+    /// Annualised equity volatility of the subject (or guideline companies)
+    pub volatility: Rate,
+    /// Expected holding period until a liquidity event
+    pub holding_period_years: Years,
+    pub risk_free_rate: Rate,
+    #[serde(default)]
+    pub dividend_yield: Rate,
+    #[serde(default)]
+    pub method: DlomMethod,
+    /// Apply this discount instead of the model estimate
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub override_discount: Option<Rate>,
+}
+
+/// Input for the chain of valuation adjustments.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ValuationAdjustmentsInput {
+    pub subject_name: String,
+    pub enterprise_value: Money,
+    /// Debt less cash
+    pub net_debt: Money,
+    /// Preferred equity, minority interests and other senior claims
+    #[serde(default)]
+    pub other_claims: Money,
+    /// Non-operating assets added back to equity
+    #[serde(default)]
+    pub non_operating_assets: Money,
+    /// Level of value of `enterprise_value`
+    pub value_basis: ValueBasis,
+    /// Fraction of equity being valued (1 = 100%)
+    pub interest_pct: Rate,
+    /// Whether the subject interest carries control
+    pub is_controlling_interest: bool,
+    /// Control premium applied when moving from minority to control
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub control_premium: Option<Rate>,
+    /// Minority (lack of control) discount; derived from the control premium
+    /// as 1 - 1 / (1 + premium) when not given
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub minority_discount: Option<Rate>,
+    /// Discount for lack of marketability; omitted for marketable interests
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dlom: Option<DlomSpec>,
+}
+
+/// One link in the chain from enterprise value to subject interest value.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AdjustmentStep {
+    pub step: String,
+    /// Change in value at this step
+    pub adjustment: Money,
+    /// Value after this step
+    pub value: Money,
+    pub rationale: String,
+}
+
+/// DLOM estimates from each model.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DlomEstimates {
+    pub protective_put: Rate,
+    pub finnerty: Rate,
+    /// Discount actually applied
+    pub applied: Rate,
+}
+
+/// Output of the valuation adjustments chain.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ValuationAdjustmentsOutput {
+    /// 100% equity value at the input basis
+    pub equity_value: Money,
+    /// Control premium applied (zero if none)
+    pub control_premium_applied: Rate,
+    /// Minority discount applied (zero if none)
+    pub minority_discount_applied: Rate,
+    /// Pro rata share of the adjusted equity value
+    pub pro_rata_value: Money,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dlom: Option<DlomEstimates>,
+    pub subject_interest_value: Money,
+    /// 1 - subject value / (interest_pct x equity value)
+    pub combined_discount: Rate,
+    pub steps: Vec<AdjustmentStep>,
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Chaffe protective put: value of an at-the-money European put as a
+/// fraction of the share value.
+fn protective_put_dlom(spec: &DlomSpec) -> CorpFinanceResult<Rate> {
+    let t = spec.holding_period_years;
+    let sigma_sqrt_t = spec.volatility * t.sqrt().unwrap_or(Decimal::ZERO);
+    let d1 = (spec.risk_free_rate - spec.dividend_yield
+        + spec.volatility * spec.volatility / dec!(2))
+        * t
+        / sigma_sqrt_t;
+    let d2 = d1 - sigma_sqrt_t;
+    let rate_df = (-spec.risk_free_rate * t)
+        .checked_exp()
+        .ok_or_else(dlom_out_of_range)?;
+    let dividend_df = (-spec.dividend_yield * t)
+        .checked_exp()
+        .ok_or_else(dlom_out_of_range)?;
+    Ok(rate_df * norm_cdf(-d2) - dividend_df * norm_cdf(-d1))
+}
+
+/// Finnerty (2012) average-strike put:
+/// D = e^(-qT) [N(v sqrt(T) / 2) - N(-v sqrt(T) / 2)], with
+/// v^2 T = s^2 T + ln[2 (e^(s^2 T) - s^2 T - 1)] - 2 ln(e^(s^2 T) - 1).
+fn finnerty_dlom(spec: &DlomSpec) -> CorpFinanceResult<Rate> {
+    let s2t = spec.volatility * spec.volatility * spec.holding_period_years;
+    let e = s2t.checked_exp().ok_or_else(dlom_out_of_range)?;
+    let ln_spread = dec!(2)
+        .checked_mul(e - s2t - Decimal::ONE)
+        .and_then(|x| x.checked_ln())
+        .ok_or_else(dlom_out_of_range)?;
+    let ln_growth = (e - Decimal::ONE)
+        .checked_ln()
+        .ok_or_else(dlom_out_of_range)?;
+    let v2t = s2t + ln_spread - dec!(2) * ln_growth;
+    let half = v2t.max(Decimal::ZERO).sqrt().unwrap_or(Decimal::ZERO) / dec!(2);
+    let dividend_df = (-spec.dividend_yield * spec.holding_period_years)
+        .checked_exp()
+        .ok_or_else(dlom_out_of_range)?;
+    Ok(dividend_df * (norm_cdf(half) - norm_cdf(-half)))
+}
+
+/// The exponentials in the DLOM models overflow `Decimal` once
+/// volatility^2 x holding period passes about 65.
+fn dlom_out_of_range() -> CorpFinanceError {
+    CorpFinanceError::InvalidInput {
+        field: "dlom".into(),
+        reason: "Volatility^2 x holding period is too large to price a DLOM (limit about 65)"
+            .into(),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Function: apply_valuation_adjustments
+// ---------------------------------------------------------------------------
+
+/// Walk from enterprise value to the value of the subject interest:
+///
+/// 1. Equity value = EV - net debt - other claims + non-operating assets
+/// 2. Control premium (minority basis, controlling interest) or minority
+///    discount (control basis, non-controlling interest)
+/// 3. Pro rata share for the interest being valued
+/// 4. DLOM (protective put / Finnerty) for non-marketable interests
+pub fn apply_valuation_adjustments(
+    input: &ValuationAdjustmentsInput,
+) -> CorpFinanceResult<ComputationOutput<ValuationAdjustmentsOutput>> {
+    let start = Instant::now();
+    let mut warnings: Vec<String> = Vec::new();
+
+    validate_input(input)?;
+
+    let mut steps = Vec::new();
+    steps.push(AdjustmentStep {
+        step: "Enterprise value".into(),
+        adjustment: Decimal::ZERO,
+        value: input.enterprise_value,
+        rationale: match input.value_basis {
+            ValueBasis::MarketableMinority => "Marketable minority basis".into(),
+            ValueBasis::Control => "Control basis".into(),
+        },
+    });
+
+    let equity_value =
+        input.enterprise_value - input.net_debt - input.other_claims + input.non_operating_assets;
+    steps.push(AdjustmentStep {
+        step: "Equity bridge".into(),
+        adjustment: equity_value - input.enterprise_value,
+        value: equity_value,
+        rationale: "Less net debt and senior claims, plus non-operating assets".into(),
+    });
+    if equity_value <= Decimal::ZERO {
+        warnings.push("Equity value is non-positive; discounts are not meaningful".into());
+    }
+
+    // --- Level of value: control vs minority ---
+    let mut value = equity_value;
+    let mut control_premium_applied = Decimal::ZERO;
+    let mut minority_discount_applied = Decimal::ZERO;
+    match (input.value_basis, input.is_controlling_interest) {
+        (ValueBasis::MarketableMinority, true) => match input.control_premium {
+            Some(cp) => {
+                control_premium_applied = cp;
+                let adjustment = value * cp;
+                value += adjustment;
+                steps.push(AdjustmentStep {
+                    step: "Control premium".into(),
+                    adjustment,
+                    value,
+                    rationale: "Controlling interest valued from a minority basis".into(),
+                });
+            }
+            None => warnings.push(
+                "Controlling interest on a minority basis with no control premium supplied".into(),
+            ),
+        },
+        (ValueBasis::Control, false) => {
+            let discount = match (input.minority_discount, input.control_premium) {
+                (Some(md), _) => Some(md),
+                (None, Some(cp)) => Some(Decimal::ONE - Decimal::ONE / (Decimal::ONE + cp)),
+                (None, None) => None,
+            };
+            match discount {
+                Some(md) => {
+                    minority_discount_applied = md;
+                    let adjustment = -value * md;
+                    value += adjustment;
+                    steps.push(AdjustmentStep {
+                        step: "Minority discount".into(),
+                        adjustment,
+                        value,
+                        rationale: "Non-controlling interest valued from a control basis".into(),
+                    });
+                }
+                None => warnings.push(
+                    "Minority interest on a control basis with no minority discount or control premium supplied"
+                        .into(),
+                ),
+            }
+        }
+        _ => {
+            if input.control_premium.is_some() || input.minority_discount.is_some() {
+                warnings.push(
+                    "Control premium / minority discount ignored: basis already matches the interest"
+                        .into(),
+                );
+            }
+        }
+    }
+
+    // --- Pro rata interest ---
+    let pro_rata_value = value * input.interest_pct;
+    if input.interest_pct != Decimal::ONE {
+        steps.push(AdjustmentStep {
+            step: "Pro rata interest".into(),
+            adjustment: pro_rata_value - value,
+            value: pro_rata_value,
+            rationale: format!("{:.2}% of equity", input.interest_pct * dec!(100)),
+        });
+    }
+    if input.is_controlling_interest && input.interest_pct <= dec!(0.5) {
+        warnings.push(
+            "Interest of 50% or less flagged as controlling; confirm governance rights".into(),
+        );
+    }
+
+    // --- Marketability ---
+    let dlom = input
+        .dlom
+        .as_ref()
+        .map(|spec| -> CorpFinanceResult<DlomEstimates> {
+            let protective_put = protective_put_dlom(spec)?;
+            let finnerty = finnerty_dlom(spec)?;
+            let applied = spec.override_discount.unwrap_or(match spec.method {
+                DlomMethod::ProtectivePut => protective_put,
+                DlomMethod::Finnerty => finnerty,
+                DlomMethod::Average => (protective_put + finnerty) / dec!(2),
+            });
+            Ok(DlomEstimates {
+                protective_put,
+                finnerty,
+                applied,
+            })
+        })
+        .transpose()?;
+
+    let mut subject_interest_value = pro_rata_value;
+    if let Some(d) = &dlom {
+        let adjustment = -subject_interest_value * d.applied;
+        subject_interest_value += adjustment;
+        steps.push(AdjustmentStep {
+            step: "DLOM".into(),
+            adjustment,
+            value: subject_interest_value,
+            rationale: format!(
+                "Discount for lack of marketability of {:.2}%",
+                d.applied * dec!(100)
+            ),
+        });
+        if d.applied > dec!(0.35) {
+            warnings.push("DLOM above 35% is at the high end of observed ranges".into());
+        }
+    }
+
+    let base = equity_value * input.interest_pct;
+    let combined_discount = if base > Decimal::ZERO {
+        Decimal::ONE - subject_interest_value / base
+    } else {
+        Decimal::ZERO
+    };
+
+    let output = ValuationAdjustmentsOutput {
+        equity_value,
+        control_premium_applied,
+        minority_discount_applied,
+        pro_rata_value,
+        dlom,
+        subject_interest_value,
+        combined_discount,
+        steps,
+    };
+
+    let elapsed = start.elapsed().as_micros() as u64;
+    Ok(with_metadata(
+        "Valuation Adjustments (control premium, minority discount, option-based DLOM)",
+        &serde_json::json!({
+            "subject": input.subject_name,
+            "value_basis": input.value_basis,
+            "interest_pct": input.interest_pct.to_string(),
+            "controlling": input.is_controlling_interest,
+            "dlom_method": input.dlom.as_ref().map(|d| d.method),
+        }),
+        warnings,
+        elapsed,
+        output,
+    ))
+}
+
+fn validate_input(input: &ValuationAdjustmentsInput) -> CorpFinanceResult<()> {
+    if input.interest_pct <= Decimal::ZERO || input.interest_pct > Decimal::ONE {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "interest_pct".into(),
+            reason: "Interest must be in (0, 1]".into(),
+        });
+    }
+    if input.control_premium.is_some_and(|cp| cp < Decimal::ZERO) {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "control_premium".into(),
+            reason: "Control premium cannot be negative".into(),
+        });
+    }
+    if input
+        .minority_discount
+        .is_some_and(|md| md < Decimal::ZERO || md >= Decimal::ONE)
+    {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "minority_discount".into(),
+            reason: "Minority discount must be in [0, 1)".into(),
+        });
+    }
+    if let Some(d) = &input.dlom {
+        if d.volatility <= Decimal::ZERO {
+            return Err(CorpFinanceError::InvalidInput {
+                field: "dlom.volatility".into(),
+                reason: "Volatility must be positive".into(),
+            });
+        }
+        if d.holding_period_years <= Decimal::ZERO {
+            return Err(CorpFinanceError::InvalidInput {
+                field: "dlom.holding_period_years".into(),
+                reason: "Holding period must be positive".into(),
+            });
+        }
+        if d.override_discount
+            .is_some_and(|v| v < Decimal::ZERO || v >= Decimal::ONE)
+        {
+            return Err(CorpFinanceError::InvalidInput {
+                field: "dlom.override_discount".into(),
+                reason: "Discount must be in [0, 1)".into(),
+            });
+        }
+    }
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn approx_eq(a: Decimal, b: Decimal, eps: Decimal) -> bool {
+        (a - b).abs() < eps
+    }
+
+    fn dlom_spec(method: DlomMethod) -> DlomSpec {
+        DlomSpec {
+            volatility: dec!(0.30),
+            holding_period_years: dec!(2),
+            risk_free_rate: dec!(0.04),
+            dividend_yield: Decimal::ZERO,
+            method,
+            override_discount: None,
+        }
+    }
+
+    fn base_input() -> ValuationAdjustmentsInput {
+        ValuationAdjustmentsInput {
+            subject_name: "Private Co".into(),
+            enterprise_value: dec!(1000),
+            net_debt: dec!(300),
+            other_claims: dec!(50),
+            non_operating_assets: dec!(50),
+            value_basis: ValueBasis::MarketableMinority,
+            interest_pct: Decimal::ONE,
+            is_controlling_interest: false,
+            control_premium: None,
+            minority_discount: None,
+            dlom: None,
+        }
+    }
+
+    #[test]
+    fn test_equity_bridge() {
+        let out = apply_valuation_adjustments(&base_input()).unwrap();
+        assert_eq!(out.result.equity_value, dec!(700));
+        assert_eq!(out.result.subject_interest_value, dec!(700));
+        assert_eq!(out.result.combined_discount, Decimal::ZERO);
+        assert_eq!(out.result.steps.len(), 2);
+    }
+
+    #[test]
+    fn test_control_premium_for_controlling_interest() {
+        let mut input = base_input();
+        input.is_controlling_interest = true;
+        input.control_premium = Some(dec!(0.30));
+        let out = apply_valuation_adjustments(&input).unwrap();
+        assert_eq!(out.result.control_premium_applied, dec!(0.30));
+        assert_eq!(out.result.subject_interest_value, dec!(910));
+    }
+
+    #[test]
+    fn test_minority_discount_derived_from_premium() {
+        let mut input = base_input();
+        input.value_basis = ValueBasis::Control;
+        input.control_premium = Some(dec!(0.25));
+        let out = apply_valuation_adjustments(&input).unwrap();
+        // 1 - 1/1.25 = 20%
+        assert_eq!(out.result.minority_discount_applied, dec!(0.2));
+        assert_eq!(out.result.subject_interest_value, dec!(560));
+    }
+
+    #[test]
+    fn test_explicit_minority_discount_wins() {
+        let mut input = base_input();
+        input.value_basis = ValueBasis::Control;
+        input.control_premium = Some(dec!(0.25));
+        input.minority_discount = Some(dec!(0.10));
+        let out = apply_valuation_adjustments(&input).unwrap();
+        assert_eq!(out.result.minority_discount_applied, dec!(0.10));
+        assert_eq!(out.result.subject_interest_value, dec!(630));
+    }
+
+    #[test]
+    fn test_basis_matches_interest_ignores_premium() {
+        let mut input = base_input();
+        input.control_premium = Some(dec!(0.30));
+        let out = apply_valuation_adjustments(&input).unwrap();
+        assert_eq!(out.result.control_premium_applied, Decimal::ZERO);
+        assert!(out.warnings.iter().any(|w| w.contains("ignored")));
+    }
+
+    #[test]
+    fn test_pro_rata_interest() {
+        let mut input = base_input();
+        input.interest_pct = dec!(0.25);
+        let out = apply_valuation_adjustments(&input).unwrap();
+        assert_eq!(out.result.pro_rata_value, dec!(175));
+        assert!(out
+            .result
+            .steps
+            .iter()
+            .any(|s| s.step == "Pro rata interest"));
+    }
+
+    #[test]
+    fn test_protective_put_dlom() {
+        let spec = dlom_spec(DlomMethod::ProtectivePut);
+        let d = protective_put_dlom(&spec).unwrap();
+        // BS ATM put, S=K=1, r=4%, sigma=30%, T=2: ~0.1259
+        assert!(approx_eq(d, dec!(0.1259), dec!(0.001)), "put DLOM {d}");
+    }
+
+    #[test]
+    fn test_protective_put_dlom_tiny_volatility() {
+        // sigma sqrt(T) ~ 0.0014 puts d1 ~ 57 standard deviations out;
+        // the put is worthless rather than a panic
+        let mut spec = dlom_spec(DlomMethod::ProtectivePut);
+        spec.volatility = dec!(0.001);
+        let d = protective_put_dlom(&spec).unwrap();
+        assert!(d.abs() < dec!(0.000001), "put DLOM {d}");
+    }
+
+    #[test]
+    fn test_finnerty_dlom() {
+        let spec = dlom_spec(DlomMethod::Finnerty);
+        let d = finnerty_dlom(&spec).unwrap();
+        // sigma=30%, T=2 => ~9.6%
+        assert!(approx_eq(d, dec!(0.096), dec!(0.003)), "Finnerty DLOM {d}");
+    }
+
+    #[test]
+    fn test_finnerty_increases_with_volatility_and_term() {
+        let base = finnerty_dlom(&dlom_spec(DlomMethod::Finnerty)).unwrap();
+        let mut high_vol = dlom_spec(DlomMethod::Finnerty);
+        high_vol.volatility = dec!(0.60);
+        let mut long = dlom_spec(DlomMethod::Finnerty);
+        long.holding_period_years = dec!(4);
+        assert!(finnerty_dlom(&high_vol).unwrap() > base);
+        assert!(finnerty_dlom(&long).unwrap() > base);
+        // Finnerty's discount is bounded at about 32%.
+        let mut extreme = dlom_spec(DlomMethod::Finnerty);
+        extreme.volatility = dec!(1.2);
+        extreme.holding_period_years = dec!(5);
+        assert!(finnerty_dlom(&extreme).unwrap() < dec!(0.33));
+    }
+
+    #[test]
+    fn test_extreme_volatility_and_horizon_rejected() {
+        // sigma^2 T = 90 overflows exp(); report it instead of panicking
+        for method in [DlomMethod::ProtectivePut, DlomMethod::Finnerty] {
+            let mut input = base_input();
+            let mut spec = dlom_spec(method);
+            spec.volatility = dec!(3);
+            spec.holding_period_years = dec!(10);
+            input.dlom = Some(spec);
+            match apply_valuation_adjustments(&input) {
+                Err(CorpFinanceError::InvalidInput { field, .. }) => assert_eq!(field, "dlom"),
+                other => panic!("expected InvalidInput for dlom, got {other:?}"),
+            }
+        }
+
+        // Still priced just inside the range, near Finnerty's ~32% ceiling
+        let mut spec = dlom_spec(DlomMethod::Finnerty);
+        spec.volatility = dec!(2);
+        spec.holding_period_years = dec!(10);
+        let d = finnerty_dlom(&spec).unwrap();
+        assert!(d > dec!(0.30) && d < dec!(0.33), "Finnerty DLOM {d}");
+    }
+
+    #[test]
+    fn test_dlom_applied_in_chain() {
+        let mut input = base_input();
+        input.interest_pct = dec!(0.20);
+        input.dlom = Some(dlom_spec(DlomMethod::Finnerty));
+        let out = apply_valuation_adjustments(&input).unwrap();
+        let r = &out.result;
+        let d = r.dlom.as_ref().unwrap();
+        assert_eq!(d.applied, d.finnerty);
+        assert_eq!(
+            r.subject_interest_value,
+            dec!(140) * (Decimal::ONE - d.applied)
+        );
+        assert!(approx_eq(r.combined_discount, d.applied, dec!(0.0000001)));
+    }
+
+    #[test]
+    fn test_dlom_average_and_override() {
+        let mut input = base_input();
+        input.dlom = Some(dlom_spec(DlomMethod::Average));
+        let out = apply_valuation_adjustments(&input).unwrap();
+        let d = out.result.dlom.unwrap();
+        assert_eq!(d.applied, (d.protective_put + d.finnerty) / dec!(2));
+
+        let mut spec = dlom_spec(DlomMethod::Finnerty);
+        spec.override_discount = Some(dec!(0.15));
+        input.dlom = Some(spec);
+        let out = apply_valuation_adjustments(&input).unwrap();
+        assert_eq!(out.result.dlom.unwrap().applied, dec!(0.15));
+        assert_eq!(out.result.subject_interest_value, dec!(595));
+    }
+
+    #[test]
+    fn test_full_chain_control_to_minority_nonmarketable() {
+        let mut input = base_input();
+        input.value_basis = ValueBasis::Control;
+        input.minority_discount = Some(dec!(0.20));
+        input.interest_pct = dec!(0.10);
+        let mut spec = dlom_spec(DlomMethod::Finnerty);
+        spec.override_discount = Some(dec!(0.25));
+        input.dlom = Some(spec);
+        let out = apply_valuation_adjustments(&input).unwrap();
+        let r = &out.result;
+        // 700 x 0.8 x 0.1 x 0.75 = 42
+        assert_eq!(r.subject_interest_value, dec!(42));
+        // 1 - 42 / 70 = 40%
+        assert_eq!(r.combined_discount, dec!(0.4));
+        let last = r.steps.last().unwrap();
+        assert_eq!(last.value, r.subject_interest_value);
+        let total: Decimal = r.steps.iter().map(|s| s.adjustment).sum();
+        assert_eq!(input.enterprise_value + total, dec!(42));
+    }
+
+    #[test]
+    fn test_missing_premium_warns() {
+        let mut input = base_input();
+        input.is_controlling_interest = true;
+        input.interest_pct = dec!(0.6);
+        let out = apply_valuation_adjustments(&input).unwrap();
+        assert!(out
+            .warnings
+            .iter()
+            .any(|w| w.contains("no control premium")));
+    }
+
+    #[test]
+    fn test_invalid_inputs() {
+        let mut input = base_input();
+        input.interest_pct = Decimal::ZERO;
+        assert!(apply_valuation_adjustments(&input).is_err());
+
+        let mut input = base_input();
+        input.minority_discount = Some(dec!(1));
+        assert!(apply_valuation_adjustments(&input).is_err());
+
+        let mut input = base_input();
+        let mut spec = dlom_spec(DlomMethod::Finnerty);
+        spec.volatility = Decimal::ZERO;
+        input.dlom = Some(spec);
+        assert!(apply_valuation_adjustments(&input).is_err());
+
+        let mut input = base_input();
+        input.control_premium = Some(dec!(-0.1));
+        assert!(apply_valuation_adjustments(&input).is_err());
+    }
+}
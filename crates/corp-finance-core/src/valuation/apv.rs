@@ -0,0 +1,566 @@
+use rust_decimal::Decimal;
+use rust_decimal::MathematicalOps;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+use std::time::Instant;
+
+use crate::error::CorpFinanceError;
+use crate::types::{with_metadata, ComputationOutput, Currency, Money, Rate};
+use crate::CorpFinanceResult;
+
+// ---------------------------------------------------------------------------
+// Types
+// ---------------------------------------------------------------------------
+
+/// Rate used to discount interest tax shields.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TaxShieldDiscounting {
+    /// Myers: shields are as risky as the debt (fixed debt schedule)
+    #[default]
+    CostOfDebt,
+    /// Harris-Pringle: shields are as risky as operations (debt rebalanced to value)
+    UnleveredCostOfEquity,
+}
+
+/// A financing side effect other than the interest tax shield, such as a
+/// subsidised loan, issuance fees or a financing guarantee.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FinancingSideEffect {
+    pub name: String,
+    /// Amount at the valuation date (negative for costs such as issuance fees)
+    #[serde(default)]
+    pub upfront: Money,
+    /// Annual amounts from year 1 (positive = benefit to the firm)
+    #[serde(default)]
+    pub annual: Vec<Money>,
+    /// Discount rate for the annual amounts (default: cost of debt)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub discount_rate: Option<Rate>,
+}
+
+/// Input for an adjusted present value valuation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApvInput {
+    /// Unlevered free cash flow per forecast year
+    pub unlevered_fcf: Vec<Money>,
+    /// Unlevered cost of equity (asset return)
+    pub unlevered_cost_of_equity: Rate,
+    /// Perpetual growth of unlevered FCF after the forecast
+    pub terminal_growth_rate: Rate,
+    pub tax_rate: Rate,
+    /// Pre-tax cost of debt
+    pub cost_of_debt: Rate,
+    /// Opening debt balance per forecast year
+    #[serde(default)]
+    pub debt_balances: Vec<Money>,
+    /// Interest expense per year; defaults to opening debt x cost of debt
+    #[serde(default)]
+    pub interest_expense: Vec<Money>,
+    /// EBIT per year; when given, shields are limited to taxable income
+    #[serde(default)]
+    pub ebit: Vec<Money>,
+    /// Debt carried into perpetuity, growing at the terminal growth rate
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub terminal_debt: Option<Money>,
+    #[serde(default)]
+    pub tax_shield_discounting: TaxShieldDiscounting,
+    /// Probability of financial distress over the life of the capital structure
+    #[serde(default)]
+    pub distress_probability: Rate,
+    /// Cost of distress as a share of unlevered firm value
+    #[serde(default)]
+    pub distress_cost_pct: Rate,
+    #[serde(default)]
+    pub side_effects: Vec<FinancingSideEffect>,
+    #[serde(default)]
+    pub currency: Currency,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub net_debt: Option<Money>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shares_outstanding: Option<Decimal>,
+}
+
+/// One forecast year of the APV build.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApvYear {
+    pub year: u32,
+    pub unlevered_fcf: Money,
+    pub discount_factor: Rate,
+    pub pv_fcf: Money,
+    pub opening_debt: Money,
+    pub interest_expense: Money,
+    /// Interest deductible after the taxable income limit
+    pub deductible_interest: Money,
+    pub tax_shield: Money,
+    pub pv_tax_shield: Money,
+}
+
+/// Present value of one financing side effect.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SideEffectValue {
+    pub name: String,
+    pub present_value: Money,
+}
+
+/// Output of the APV valuation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApvOutput {
+    pub currency: Currency,
+    pub years: Vec<ApvYear>,
+    pub pv_explicit_fcf: Money,
+    pub terminal_value: Money,
+    pub pv_terminal_value: Money,
+    /// Value of the all-equity firm at the unlevered cost of equity
+    pub unlevered_value: Money,
+    pub pv_tax_shields_explicit: Money,
+    pub pv_terminal_tax_shield: Money,
+    pub pv_tax_shields: Money,
+    /// Probability x cost x unlevered value
+    pub expected_distress_cost: Money,
+    pub side_effects: Vec<SideEffectValue>,
+    pub pv_side_effects: Money,
+    /// Unlevered value + tax shields - distress costs + side effects
+    pub adjusted_present_value: Money,
+    /// Value added by financing as a share of APV
+    pub financing_value_pct: Rate,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub equity_value: Option<Money>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub equity_value_per_share: Option<Money>,
+    /// Constant discount rate that reproduces the APV from the unlevered
+    /// FCF, for comparison with a WACC-based DCF
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub implied_wacc: Option<Rate>,
+}
+
+// ---------------------------------------------------------------------------
+// Public API
+// ---------------------------------------------------------------------------
+
+/// Value a firm by adjusted present value: unlevered value at the unlevered
+/// cost of equity plus the present value of interest tax shields from the
+/// debt schedule, less expected distress costs, plus other financing side
+/// effects. Suited to capital structures that change over the forecast,
+/// where a constant WACC is a poor approximation.
+pub fn calculate_apv(input: &ApvInput) -> CorpFinanceResult<ComputationOutput<ApvOutput>> {
+    let start = Instant::now();
+    let mut warnings: Vec<String> = Vec::new();
+
+    validate_apv_input(input)?;
+
+    let ku = input.unlevered_cost_of_equity;
+    let g = input.terminal_growth_rate;
+    let ts_rate = match input.tax_shield_discounting {
+        TaxShieldDiscounting::CostOfDebt => input.cost_of_debt,
+        TaxShieldDiscounting::UnleveredCostOfEquity => ku,
+    };
+
+    // --- Unlevered value and tax shields by year ---
+    let mut years = Vec::with_capacity(input.unlevered_fcf.len());
+    let mut pv_explicit_fcf = Decimal::ZERO;
+    let mut pv_tax_shields_explicit = Decimal::ZERO;
+    let mut capped_years: Vec<String> = Vec::new();
+
+    for (i, &fcf) in input.unlevered_fcf.iter().enumerate() {
+        let year = (i + 1) as u32;
+        let discount_factor = pv_factor(ku, year);
+        let pv_fcf = fcf * discount_factor;
+
+        let opening_debt = input.debt_balances.get(i).copied().unwrap_or(Decimal::ZERO);
+        let interest_expense = input
+            .interest_expense
+            .get(i)
+            .copied()
+            .unwrap_or(opening_debt * input.cost_of_debt);
+        let deductible_interest = match input.ebit.get(i) {
+            Some(&ebit) => {
+                let capped = interest_expense.min(ebit.max(Decimal::ZERO));
+                if capped < interest_expense {
+                    capped_years.push(year.to_string());
+                }
+                capped
+            }
+            None => interest_expense,
+        };
+        let tax_shield = deductible_interest * input.tax_rate;
+        let pv_tax_shield = tax_shield * pv_factor(ts_rate, year);
+
+        pv_explicit_fcf += pv_fcf;
+        pv_tax_shields_explicit += pv_tax_shield;
+        years.push(ApvYear {
+            year,
+            unlevered_fcf: fcf,
+            discount_factor,
+            pv_fcf,
+            opening_debt,
+            interest_expense,
+            deductible_interest,
+            tax_shield,
+            pv_tax_shield,
+        });
+    }
+
+    if !capped_years.is_empty() {
+        warnings.push(format!(
+            "Interest exceeds EBIT in year(s) {}; tax shields limited to taxable income",
+            capped_years.join(", ")
+        ));
+    }
+
+    let n = input.unlevered_fcf.len() as u32;
+    let last_fcf = *input.unlevered_fcf.last().unwrap_or(&Decimal::ZERO);
+    let terminal_value = last_fcf * (Decimal::ONE + g) / (ku - g);
+    let pv_terminal_value = terminal_value * pv_factor(ku, n);
+    let unlevered_value = pv_explicit_fcf + pv_terminal_value;
+
+    // --- Terminal tax shield on perpetual debt ---
+    let pv_terminal_tax_shield = match input.terminal_debt {
+        Some(debt) => {
+            if ts_rate <= g {
+                return Err(CorpFinanceError::FinancialImpossibility(
+                    "Tax shield discount rate must exceed terminal growth for a perpetual tax shield"
+                        .into(),
+                ));
+            }
+            let shield = debt * input.cost_of_debt * input.tax_rate;
+            shield / (ts_rate - g) * pv_factor(ts_rate, n)
+        }
+        None => {
+            if input.debt_balances.iter().any(|d| *d > Decimal::ZERO)
+                || input.interest_expense.iter().any(|i| *i > Decimal::ZERO)
+            {
+                warnings.push(
+                    "No terminal_debt given; tax shields beyond the forecast horizon are excluded"
+                        .into(),
+                );
+            }
+            Decimal::ZERO
+        }
+    };
+    let pv_tax_shields = pv_tax_shields_explicit + pv_terminal_tax_shield;
+
+    // --- Financial distress ---
+    let expected_distress_cost =
+        input.distress_probability * input.distress_cost_pct * unlevered_value;
+
+    // --- Other side effects ---
+    let side_effects: Vec<SideEffectValue> = input
+        .side_effects
+        .iter()
+        .map(|se| {
+            let rate = se.discount_rate.unwrap_or(input.cost_of_debt);
+            let pv_annual: Money = se
+                .annual
+                .iter()
+                .enumerate()
+                .map(|(i, amount)| *amount * pv_factor(rate, (i + 1) as u32))
+                .sum();
+            SideEffectValue {
+                name: se.name.clone(),
+                present_value: se.upfront + pv_annual,
+            }
+        })
+        .collect();
+    let pv_side_effects: Money = side_effects.iter().map(|s| s.present_value).sum();
+
+    let adjusted_present_value =
+        unlevered_value + pv_tax_shields - expected_distress_cost + pv_side_effects;
+    let financing_value_pct = if adjusted_present_value.is_zero() {
+        Decimal::ZERO
+    } else {
+        (adjusted_present_value - unlevered_value) / adjusted_present_value
+    };
+    if pv_terminal_value > dec!(0.75) * unlevered_value && unlevered_value > Decimal::ZERO {
+        warnings.push(
+            "Terminal value exceeds 75% of unlevered value; consider a longer forecast".into(),
+        );
+    }
+
+    // --- Equity bridge ---
+    let equity_value = input.net_debt.map(|nd| adjusted_present_value - nd);
+    let equity_value_per_share = match (equity_value, input.shares_outstanding) {
+        (Some(eq), Some(shares)) if shares > Decimal::ZERO => Some(eq / shares),
+        _ => None,
+    };
+
+    let implied_wacc = solve_implied_wacc(&input.unlevered_fcf, g, adjusted_present_value);
+    if implied_wacc.is_none() {
+        warnings.push("Could not solve for a WACC reproducing the APV".into());
+    }
+
+    let output = ApvOutput {
+        currency: input.currency.clone(),
+        years,
+        pv_explicit_fcf,
+        terminal_value,
+        pv_terminal_value,
+        unlevered_value,
+        pv_tax_shields_explicit,
+        pv_terminal_tax_shield,
+        pv_tax_shields,
+        expected_distress_cost,
+        side_effects,
+        pv_side_effects,
+        adjusted_present_value,
+        financing_value_pct,
+        equity_value,
+        equity_value_per_share,
+        implied_wacc,
+    };
+
+    let elapsed = start.elapsed().as_micros() as u64;
+    Ok(with_metadata(
+        "Adjusted Present Value (APV)",
+        &serde_json::json!({
+            "unlevered_cost_of_equity": ku.to_string(),
+            "terminal_growth_rate": g.to_string(),
+            "tax_shield_discounting": format!("{:?}", input.tax_shield_discounting),
+            "tax_shield_discount_rate": ts_rate.to_string(),
+            "distress_cost": "probability x cost % x unlevered value",
+        }),
+        warnings,
+        elapsed,
+        output,
+    ))
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+fn validate_apv_input(input: &ApvInput) -> CorpFinanceResult<()> {
+    if input.unlevered_fcf.is_empty() {
+        return Err(CorpFinanceError::InsufficientData(
+            "At least one year of unlevered FCF is required".into(),
+        ));
+    }
+    if input.unlevered_cost_of_equity <= Decimal::ZERO {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "unlevered_cost_of_equity".into(),
+            reason: "Unlevered cost of equity must be positive".into(),
+        });
+    }
+    if input.terminal_growth_rate >= input.unlevered_cost_of_equity {
+        return Err(CorpFinanceError::FinancialImpossibility(
+            "Terminal growth rate must be less than the unlevered cost of equity".into(),
+        ));
+    }
+    if input.tax_rate < Decimal::ZERO || input.tax_rate >= Decimal::ONE {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "tax_rate".into(),
+            reason: "Tax rate must be in [0, 1)".into(),
+        });
+    }
+    if input.cost_of_debt < Decimal::ZERO {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "cost_of_debt".into(),
+            reason: "Cost of debt cannot be negative".into(),
+        });
+    }
+    let n = input.unlevered_fcf.len();
+    for (field, len) in [
+        ("debt_balances", input.debt_balances.len()),
+        ("interest_expense", input.interest_expense.len()),
+        ("ebit", input.ebit.len()),
+    ] {
+        if len != 0 && len != n {
+            return Err(CorpFinanceError::InvalidInput {
+                field: field.into(),
+                reason: format!("Expected {n} values (one per forecast year), got {len}"),
+            });
+        }
+    }
+    for (field, value) in [
+        ("distress_probability", input.distress_probability),
+        ("distress_cost_pct", input.distress_cost_pct),
+    ] {
+        if value < Decimal::ZERO || value > Decimal::ONE {
+            return Err(CorpFinanceError::InvalidInput {
+                field: field.into(),
+                reason: "Must be between 0 and 1".into(),
+            });
+        }
+    }
+    Ok(())
+}
+
+fn pv_factor(rate: Rate, year: u32) -> Rate {
+    Decimal::ONE / (Decimal::ONE + rate).powd(Decimal::from(year))
+}
+
+/// Value of the unlevered FCF with Gordon terminal value at a single rate.
+fn value_at_rate(fcf: &[Money], g: Rate, rate: Rate) -> Money {
+    let n = fcf.len() as u32;
+    let pv: Money = fcf
+        .iter()
+        .enumerate()
+        .map(|(i, cf)| *cf * pv_factor(rate, (i + 1) as u32))
+        .sum();
+    let last = *fcf.last().unwrap_or(&Decimal::ZERO);
+    pv + last * (Decimal::ONE + g) / (rate - g) * pv_factor(rate, n)
+}
+
+/// Bisection for the constant rate above `g` that reproduces `target`.
+fn solve_implied_wacc(fcf: &[Money], g: Rate, target: Money) -> Option<Rate> {
+    let mut lo = g + dec!(0.0001);
+    let mut hi = dec!(1.0);
+    let f = |r: Rate| value_at_rate(fcf, g, r) - target;
+    let (f_lo, f_hi) = (f(lo), f(hi));
+    if f_lo.is_sign_negative() == f_hi.is_sign_negative() {
+        return None;
+    }
+    for _ in 0..100 {
+        let mid = (lo + hi) / dec!(2);
+        let f_mid = f(mid);
+        if f_mid.abs() < dec!(0.000001) {
+            return Some(mid);
+        }
+        if f_mid.is_sign_negative() == f_lo.is_sign_negative() {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    Some((lo + hi) / dec!(2))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_input() -> ApvInput {
+        ApvInput {
+            unlevered_fcf: vec![dec!(100), dec!(110), dec!(120)],
+            unlevered_cost_of_equity: dec!(0.10),
+            terminal_growth_rate: dec!(0.02),
+            tax_rate: dec!(0.25),
+            cost_of_debt: dec!(0.06),
+            debt_balances: vec![dec!(600), dec!(500), dec!(400)],
+            interest_expense: vec![],
+            ebit: vec![],
+            terminal_debt: None,
+            tax_shield_discounting: TaxShieldDiscounting::CostOfDebt,
+            distress_probability: Decimal::ZERO,
+            distress_cost_pct: Decimal::ZERO,
+            side_effects: vec![],
+            currency: Currency::USD,
+            net_debt: None,
+            shares_outstanding: None,
+        }
+    }
+
+    #[test]
+    fn test_unlevered_value() {
+        let out = calculate_apv(&sample_input()).unwrap().result;
+        let tv = dec!(120) * dec!(1.02) / dec!(0.08);
+        assert_eq!(out.terminal_value, tv);
+        let expected =
+            dec!(100) / dec!(1.1) + dec!(110) / dec!(1.21) + (dec!(120) + tv) / dec!(1.331);
+        assert!((out.unlevered_value - expected).abs() < dec!(0.0001));
+    }
+
+    #[test]
+    fn test_tax_shields_from_debt_schedule() {
+        let out = calculate_apv(&sample_input()).unwrap();
+        let y1 = &out.result.years[0];
+        // 600 x 6% x 25%
+        assert_eq!(y1.interest_expense, dec!(36));
+        assert_eq!(y1.tax_shield, dec!(9));
+        assert_eq!(y1.pv_tax_shield, dec!(9) / dec!(1.06));
+        assert_eq!(
+            out.result.adjusted_present_value,
+            out.result.unlevered_value + out.result.pv_tax_shields
+        );
+        assert!(out.warnings.iter().any(|w| w.contains("terminal_debt")));
+    }
+
+    #[test]
+    fn test_harris_pringle_discounts_shields_at_ku() {
+        let myers = calculate_apv(&sample_input()).unwrap().result;
+        let mut input = sample_input();
+        input.tax_shield_discounting = TaxShieldDiscounting::UnleveredCostOfEquity;
+        let hp = calculate_apv(&input).unwrap().result;
+        assert!(hp.pv_tax_shields < myers.pv_tax_shields);
+        assert_eq!(hp.years[0].pv_tax_shield, dec!(9) / dec!(1.1));
+    }
+
+    #[test]
+    fn test_terminal_tax_shield() {
+        let mut input = sample_input();
+        input.terminal_debt = Some(dec!(400));
+        let out = calculate_apv(&input).unwrap().result;
+        // 400 x 6% x 25% = 6, perpetuity at (6% - 2%), discounted 3 years at 6%
+        let expected = dec!(6) / dec!(0.04) / dec!(1.06).powd(dec!(3));
+        assert!((out.pv_terminal_tax_shield - expected).abs() < dec!(0.0001));
+    }
+
+    #[test]
+    fn test_shields_limited_to_taxable_income() {
+        let mut input = sample_input();
+        input.ebit = vec![dec!(20), dec!(200), dec!(200)];
+        let out = calculate_apv(&input).unwrap();
+        assert_eq!(out.result.years[0].deductible_interest, dec!(20));
+        assert_eq!(out.result.years[0].tax_shield, dec!(5));
+        assert!(out.warnings.iter().any(|w| w.contains("year(s) 1")));
+    }
+
+    #[test]
+    fn test_distress_and_side_effects() {
+        let mut input = sample_input();
+        input.distress_probability = dec!(0.10);
+        input.distress_cost_pct = dec!(0.20);
+        input.side_effects = vec![
+            FinancingSideEffect {
+                name: "Issuance fees".into(),
+                upfront: dec!(-12),
+                annual: vec![],
+                discount_rate: None,
+            },
+            FinancingSideEffect {
+                name: "Subsidised loan".into(),
+                upfront: Decimal::ZERO,
+                annual: vec![dec!(5.3), dec!(5.618)],
+                discount_rate: None,
+            },
+        ];
+        let out = calculate_apv(&input).unwrap().result;
+        assert_eq!(out.expected_distress_cost, dec!(0.02) * out.unlevered_value);
+        assert_eq!(out.side_effects[0].present_value, dec!(-12));
+        assert!((out.side_effects[1].present_value - dec!(10)).abs() < dec!(0.0001));
+        assert_eq!(
+            out.adjusted_present_value,
+            out.unlevered_value + out.pv_tax_shields - out.expected_distress_cost
+                + out.pv_side_effects
+        );
+    }
+
+    #[test]
+    fn test_implied_wacc_below_ku_with_shields() {
+        let mut input = sample_input();
+        input.net_debt = Some(dec!(600));
+        input.shares_outstanding = Some(dec!(10));
+        let out = calculate_apv(&input).unwrap().result;
+        let wacc = out.implied_wacc.unwrap();
+        assert!(wacc < dec!(0.10));
+        let check = value_at_rate(&input.unlevered_fcf, dec!(0.02), wacc);
+        assert!((check - out.adjusted_present_value).abs() < dec!(0.01));
+        assert_eq!(
+            out.equity_value_per_share.unwrap(),
+            (out.adjusted_present_value - dec!(600)) / dec!(10)
+        );
+    }
+
+    #[test]
+    fn test_growth_above_ku_rejected() {
+        let mut input = sample_input();
+        input.terminal_growth_rate = dec!(0.12);
+        assert!(calculate_apv(&input).is_err());
+    }
+
+    #[test]
+    fn test_schedule_length_mismatch_rejected() {
+        let mut input = sample_input();
+        input.debt_balances = vec![dec!(600)];
+        assert!(calculate_apv(&input).is_err());
+    }
+}
@@ -0,0 +1,727 @@
+use rust_decimal::Decimal;
+use rust_decimal::MathematicalOps;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+use std::time::Instant;
+
+use crate::error::CorpFinanceError;
+use crate::types::{with_metadata, ComputationOutput, Money, Rate};
+use crate::CorpFinanceResult;
+
+// ---------------------------------------------------------------------------
+// Types
+// ---------------------------------------------------------------------------
+
+/// Balance sheet asset categories with distinct default haircut schedules.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AssetCategory {
+    Cash,
+    Receivables,
+    Inventory,
+    PrepaidAndOther,
+    Investments,
+    PropertyPlantEquipment,
+    RealEstate,
+    Intangibles,
+    Goodwill,
+    Other,
+}
+
+/// Haircuts applied to an asset category. Fair value haircuts apply to book
+/// value when no explicit fair value is given; liquidation haircuts apply to
+/// fair value.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HaircutSchedule {
+    pub category: AssetCategory,
+    pub fair_value_haircut: Rate,
+    pub orderly_haircut: Rate,
+    pub forced_haircut: Rate,
+}
+
+/// An asset on the balance sheet.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AssetItem {
+    pub name: String,
+    pub category: AssetCategory,
+    pub book_value: Money,
+    /// Appraised or marked fair value (overrides the category haircut)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fair_value: Option<Money>,
+}
+
+/// A liability on (or off) the balance sheet.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LiabilityItem {
+    pub name: String,
+    pub book_value: Money,
+    /// Fair value for NAV (e.g. debt trading below par); defaults to book
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fair_value: Option<Money>,
+    /// Claim crystallising in liquidation (e.g. make-wholes, lease rejection,
+    /// severance); defaults to book
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub liquidation_claim: Option<Money>,
+}
+
+/// Timing and cost assumptions for a liquidation scenario.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LiquidationAssumptions {
+    /// Months to realise the assets
+    pub months_to_realise: u32,
+    /// Disposal costs (brokers, auctioneers, professional fees) as % of gross proceeds
+    pub disposal_cost_pct: Rate,
+    /// Fixed wind-down costs (payroll, rent, trustee) over the period
+    pub wind_down_costs: Money,
+}
+
+/// Input for asset-based valuation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AssetBasedInput {
+    pub company_name: String,
+    pub assets: Vec<AssetItem>,
+    pub liabilities: Vec<LiabilityItem>,
+    /// Category haircut overrides (categories not listed use the defaults)
+    #[serde(default)]
+    pub haircut_schedule: Vec<HaircutSchedule>,
+    pub orderly: LiquidationAssumptions,
+    pub forced: LiquidationAssumptions,
+    /// Annual rate used to discount liquidation proceeds to today
+    pub discount_rate: Rate,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shares_outstanding: Option<Decimal>,
+    /// Equity value from an income or market approach, for comparison
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub going_concern_equity_value: Option<Money>,
+}
+
+/// Fair value adjustment for one asset or liability.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ValueAdjustment {
+    pub name: String,
+    pub book_value: Money,
+    pub fair_value: Money,
+    /// Effect on NAV (positive = increases NAV)
+    pub nav_impact: Money,
+}
+
+/// Adjusted book value / NAV.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NetAssetValue {
+    pub book_assets: Money,
+    pub book_liabilities: Money,
+    pub book_equity: Money,
+    pub fair_value_assets: Money,
+    pub fair_value_liabilities: Money,
+    /// Fair value of assets less fair value of liabilities
+    pub adjusted_book_value: Money,
+    /// Adjusted book value excluding goodwill and other intangibles
+    pub tangible_nav: Money,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nav_per_share: Option<Money>,
+    pub adjustments: Vec<ValueAdjustment>,
+}
+
+/// Recovery by asset category in a liquidation scenario.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CategoryRecovery {
+    pub category: AssetCategory,
+    pub fair_value: Money,
+    pub gross_proceeds: Money,
+    /// Gross proceeds / fair value
+    pub recovery_rate: Rate,
+}
+
+/// Result of one liquidation scenario.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LiquidationValue {
+    pub scenario: String,
+    pub gross_proceeds: Money,
+    pub disposal_costs: Money,
+    pub wind_down_costs: Money,
+    /// Net proceeds discounted over the realisation period
+    pub net_proceeds_pv: Money,
+    pub liability_claims: Money,
+    /// Share of liability claims covered (capped at 100%)
+    pub creditor_recovery: Rate,
+    /// Residual to equity (floored at zero)
+    pub equity_value: Money,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub equity_value_per_share: Option<Money>,
+    pub by_category: Vec<CategoryRecovery>,
+}
+
+/// Output of asset-based valuation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AssetBasedOutput {
+    pub nav: NetAssetValue,
+    pub orderly_liquidation: LiquidationValue,
+    pub forced_liquidation: LiquidationValue,
+    /// Going-concern equity value / adjusted book value
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub going_concern_to_nav: Option<Decimal>,
+    /// Going-concern equity value less orderly liquidation equity value
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub going_concern_premium_over_liquidation: Option<Money>,
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Default haircuts per category: (fair value, orderly, forced).
+fn default_haircuts(category: AssetCategory) -> (Rate, Rate, Rate) {
+    match category {
+        AssetCategory::Cash => (dec!(0), dec!(0), dec!(0)),
+        AssetCategory::Receivables => (dec!(0.02), dec!(0.15), dec!(0.30)),
+        AssetCategory::Inventory => (dec!(0.05), dec!(0.40), dec!(0.60)),
+        AssetCategory::PrepaidAndOther => (dec!(0), dec!(0.90), dec!(1)),
+        AssetCategory::Investments => (dec!(0), dec!(0.10), dec!(0.25)),
+        AssetCategory::PropertyPlantEquipment => (dec!(0), dec!(0.50), dec!(0.70)),
+        AssetCategory::RealEstate => (dec!(0), dec!(0.20), dec!(0.40)),
+        AssetCategory::Intangibles => (dec!(0), dec!(0.80), dec!(0.95)),
+        AssetCategory::Goodwill => (dec!(1), dec!(1), dec!(1)),
+        AssetCategory::Other => (dec!(0), dec!(0.50), dec!(0.75)),
+    }
+}
+
+fn haircuts(input: &AssetBasedInput, category: AssetCategory) -> (Rate, Rate, Rate) {
+    input
+        .haircut_schedule
+        .iter()
+        .find(|h| h.category == category)
+        .map(|h| (h.fair_value_haircut, h.orderly_haircut, h.forced_haircut))
+        .unwrap_or_else(|| default_haircuts(category))
+}
+
+fn per_share(value: Money, shares: Option<Decimal>) -> Option<Money> {
+    shares.filter(|s| *s > Decimal::ZERO).map(|s| value / s)
+}
+
+fn liquidation_scenario(
+    input: &AssetBasedInput,
+    label: &str,
+    assumptions: &LiquidationAssumptions,
+    fair_values: &[Money],
+    forced: bool,
+) -> LiquidationValue {
+    let mut by_category: Vec<CategoryRecovery> = Vec::new();
+    for (asset, fv) in input.assets.iter().zip(fair_values) {
+        let (_, orderly_hc, forced_hc) = haircuts(input, asset.category);
+        let haircut = if forced { forced_hc } else { orderly_hc };
+        let proceeds = *fv * (Decimal::ONE - haircut);
+        match by_category
+            .iter_mut()
+            .find(|c| c.category == asset.category)
+        {
+            Some(c) => {
+                c.fair_value += *fv;
+                c.gross_proceeds += proceeds;
+            }
+            None => by_category.push(CategoryRecovery {
+                category: asset.category,
+                fair_value: *fv,
+                gross_proceeds: proceeds,
+                recovery_rate: Decimal::ZERO,
+            }),
+        }
+    }
+    for c in &mut by_category {
+        if !c.fair_value.is_zero() {
+            c.recovery_rate = c.gross_proceeds / c.fair_value;
+        }
+    }
+
+    let gross_proceeds: Money = by_category.iter().map(|c| c.gross_proceeds).sum();
+    let disposal_costs = gross_proceeds * assumptions.disposal_cost_pct;
+    let net = gross_proceeds - disposal_costs - assumptions.wind_down_costs;
+    // Proceeds are assumed to arrive, on average, at the end of the period.
+    let years = Decimal::from(assumptions.months_to_realise) / dec!(12);
+    let discount = (Decimal::ONE + input.discount_rate).powd(years);
+    let net_proceeds_pv = net / discount;
+
+    let liability_claims: Money = input
+        .liabilities
+        .iter()
+        .map(|l| l.liquidation_claim.unwrap_or(l.book_value))
+        .sum();
+    let creditor_recovery = if liability_claims.is_zero() {
+        Decimal::ONE
+    } else {
+        (net_proceeds_pv.max(Decimal::ZERO) / liability_claims).min(Decimal::ONE)
+    };
+    let equity_value = (net_proceeds_pv - liability_claims).max(Decimal::ZERO);
+
+    LiquidationValue {
+        scenario: label.into(),
+        gross_proceeds,
+        disposal_costs,
+        wind_down_costs: assumptions.wind_down_costs,
+        net_proceeds_pv,
+        liability_claims,
+        creditor_recovery,
+        equity_value,
+        equity_value_per_share: per_share(equity_value, input.shares_outstanding),
+        by_category,
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Function: asset_based_valuation
+// ---------------------------------------------------------------------------
+
+/// Asset approach to valuation: adjusted book value (NAV) with assets and
+/// liabilities marked to fair value, plus orderly and forced liquidation
+/// values net of disposal and wind-down costs.
+pub fn asset_based_valuation(
+    input: &AssetBasedInput,
+) -> CorpFinanceResult<ComputationOutput<AssetBasedOutput>> {
+    let start = Instant::now();
+    let mut warnings: Vec<String> = Vec::new();
+
+    validate_input(input)?;
+
+    // --- Adjusted book value ---
+    let mut adjustments = Vec::new();
+    let mut fair_values = Vec::with_capacity(input.assets.len());
+    for asset in &input.assets {
+        let (fv_haircut, _, _) = haircuts(input, asset.category);
+        let fv = asset
+            .fair_value
+            .unwrap_or(asset.book_value * (Decimal::ONE - fv_haircut));
+        fair_values.push(fv);
+        if fv != asset.book_value {
+            adjustments.push(ValueAdjustment {
+                name: asset.name.clone(),
+                book_value: asset.book_value,
+                fair_value: fv,
+                nav_impact: fv - asset.book_value,
+            });
+        }
+    }
+    for liability in &input.liabilities {
+        let fv = liability.fair_value.unwrap_or(liability.book_value);
+        if fv != liability.book_value {
+            adjustments.push(ValueAdjustment {
+                name: liability.name.clone(),
+                book_value: liability.book_value,
+                fair_value: fv,
+                nav_impact: liability.book_value - fv,
+            });
+        }
+    }
+
+    let book_assets: Money = input.assets.iter().map(|a| a.book_value).sum();
+    let book_liabilities: Money = input.liabilities.iter().map(|l| l.book_value).sum();
+    let fair_value_assets: Money = fair_values.iter().copied().sum();
+    let fair_value_liabilities: Money = input
+        .liabilities
+        .iter()
+        .map(|l| l.fair_value.unwrap_or(l.book_value))
+        .sum();
+    let adjusted_book_value = fair_value_assets - fair_value_liabilities;
+    let intangible_fv: Money = input
+        .assets
+        .iter()
+        .zip(&fair_values)
+        .filter(|(a, _)| {
+            matches!(
+                a.category,
+                AssetCategory::Goodwill | AssetCategory::Intangibles
+            )
+        })
+        .map(|(_, fv)| *fv)
+        .sum();
+
+    if adjusted_book_value < Decimal::ZERO {
+        warnings.push("Fair value of liabilities exceeds fair value of assets".into());
+    }
+
+    let nav = NetAssetValue {
+        book_assets,
+        book_liabilities,
+        book_equity: book_assets - book_liabilities,
+        fair_value_assets,
+        fair_value_liabilities,
+        adjusted_book_value,
+        tangible_nav: adjusted_book_value - intangible_fv,
+        nav_per_share: per_share(adjusted_book_value, input.shares_outstanding),
+        adjustments,
+    };
+
+    // --- Liquidation values ---
+    let orderly_liquidation =
+        liquidation_scenario(input, "Orderly", &input.orderly, &fair_values, false);
+    let forced_liquidation =
+        liquidation_scenario(input, "Forced", &input.forced, &fair_values, true);
+
+    if forced_liquidation.net_proceeds_pv > orderly_liquidation.net_proceeds_pv {
+        warnings.push(
+            "Forced liquidation exceeds orderly liquidation; check haircuts and timing".into(),
+        );
+    }
+    if orderly_liquidation.creditor_recovery < Decimal::ONE {
+        warnings.push(format!(
+            "Creditors recover {:.1}% in an orderly liquidation",
+            orderly_liquidation.creditor_recovery * dec!(100)
+        ));
+    }
+
+    // --- Valuation triangle ---
+    let (going_concern_to_nav, going_concern_premium_over_liquidation) =
+        match input.going_concern_equity_value {
+            Some(gc) => {
+                if gc < orderly_liquidation.equity_value {
+                    warnings.push(
+                        "Going-concern value is below orderly liquidation value; \
+                         liquidation may maximise value"
+                            .into(),
+                    );
+                }
+                (
+                    if adjusted_book_value > Decimal::ZERO {
+                        Some(gc / adjusted_book_value)
+                    } else {
+                        None
+                    },
+                    Some(gc - orderly_liquidation.equity_value),
+                )
+            }
+            None => (None, None),
+        };
+
+    let output = AssetBasedOutput {
+        nav,
+        orderly_liquidation,
+        forced_liquidation,
+        going_concern_to_nav,
+        going_concern_premium_over_liquidation,
+    };
+
+    let elapsed = start.elapsed().as_micros() as u64;
+    Ok(with_metadata(
+        "Asset-Based Valuation (adjusted book value, orderly and forced liquidation)",
+        &serde_json::json!({
+            "company": input.company_name,
+            "discount_rate": input.discount_rate.to_string(),
+            "orderly_months": input.orderly.months_to_realise,
+            "forced_months": input.forced.months_to_realise,
+            "haircut_overrides": input.haircut_schedule.len(),
+        }),
+        warnings,
+        elapsed,
+        output,
+    ))
+}
+
+fn validate_input(input: &AssetBasedInput) -> CorpFinanceResult<()> {
+    if input.assets.is_empty() {
+        return Err(CorpFinanceError::InsufficientData(
+            "At least one asset is required".into(),
+        ));
+    }
+    for a in &input.assets {
+        if a.book_value < Decimal::ZERO || a.fair_value.is_some_and(|v| v < Decimal::ZERO) {
+            return Err(CorpFinanceError::InvalidInput {
+                field: format!("assets[{}]", a.name),
+                reason: "Asset values must be non-negative".into(),
+            });
+        }
+    }
+    for l in &input.liabilities {
+        if l.book_value < Decimal::ZERO
+            || l.fair_value.is_some_and(|v| v < Decimal::ZERO)
+            || l.liquidation_claim.is_some_and(|v| v < Decimal::ZERO)
+        {
+            return Err(CorpFinanceError::InvalidInput {
+                field: format!("liabilities[{}]", l.name),
+                reason: "Liability values must be non-negative".into(),
+            });
+        }
+    }
+    for h in &input.haircut_schedule {
+        for (value, name) in [
+            (h.fair_value_haircut, "fair_value_haircut"),
+            (h.orderly_haircut, "orderly_haircut"),
+            (h.forced_haircut, "forced_haircut"),
+        ] {
+            if value < Decimal::ZERO || value > Decimal::ONE {
+                return Err(CorpFinanceError::InvalidInput {
+                    field: format!("haircut_schedule.{name}"),
+                    reason: "Haircuts must be between 0 and 1".into(),
+                });
+            }
+        }
+    }
+    for (a, name) in [(&input.orderly, "orderly"), (&input.forced, "forced")] {
+        if a.disposal_cost_pct < Decimal::ZERO
+            || a.disposal_cost_pct > Decimal::ONE
+            || a.wind_down_costs < Decimal::ZERO
+        {
+            return Err(CorpFinanceError::InvalidInput {
+                field: name.into(),
+                reason: "Disposal cost must be between 0 and 1 and wind-down costs non-negative"
+                    .into(),
+            });
+        }
+    }
+    if input.discount_rate <= dec!(-1) {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "discount_rate".into(),
+            reason: "Discount rate must be greater than -100%".into(),
+        });
+    }
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn asset(name: &str, category: AssetCategory, book: Decimal) -> AssetItem {
+        AssetItem {
+            name: name.into(),
+            category,
+            book_value: book,
+            fair_value: None,
+        }
+    }
+
+    fn liability(name: &str, book: Decimal) -> LiabilityItem {
+        LiabilityItem {
+            name: name.into(),
+            book_value: book,
+            fair_value: None,
+            liquidation_claim: None,
+        }
+    }
+
+    fn base_input() -> AssetBasedInput {
+        AssetBasedInput {
+            company_name: "Distressed Industrial Co".into(),
+            assets: vec![
+                asset("Cash", AssetCategory::Cash, dec!(50)),
+                asset("Receivables", AssetCategory::Receivables, dec!(200)),
+                asset("Inventory", AssetCategory::Inventory, dec!(300)),
+                asset("PP&E", AssetCategory::PropertyPlantEquipment, dec!(400)),
+                asset("Goodwill", AssetCategory::Goodwill, dec!(150)),
+            ],
+            liabilities: vec![
+                liability("Payables", dec!(250)),
+                liability("Term loan", dec!(450)),
+            ],
+            haircut_schedule: vec![],
+            orderly: LiquidationAssumptions {
+                months_to_realise: 0,
+                disposal_cost_pct: Decimal::ZERO,
+                wind_down_costs: Decimal::ZERO,
+            },
+            forced: LiquidationAssumptions {
+                months_to_realise: 0,
+                disposal_cost_pct: Decimal::ZERO,
+                wind_down_costs: Decimal::ZERO,
+            },
+            discount_rate: dec!(0.10),
+            shares_outstanding: Some(dec!(10)),
+            going_concern_equity_value: None,
+        }
+    }
+
+    #[test]
+    fn test_book_equity() {
+        let out = asset_based_valuation(&base_input()).unwrap();
+        let nav = &out.result.nav;
+        assert_eq!(nav.book_assets, dec!(1100));
+        assert_eq!(nav.book_liabilities, dec!(700));
+        assert_eq!(nav.book_equity, dec!(400));
+    }
+
+    #[test]
+    fn test_adjusted_book_value_default_haircuts() {
+        let out = asset_based_valuation(&base_input()).unwrap();
+        let nav = &out.result.nav;
+        // FV: 50 + 196 + 285 + 400 + 0 = 931; NAV = 931 - 700 = 231
+        assert_eq!(nav.fair_value_assets, dec!(931));
+        assert_eq!(nav.adjusted_book_value, dec!(231));
+        assert_eq!(nav.nav_per_share, Some(dec!(23.1)));
+        // Goodwill written off, receivables and inventory marked down
+        assert_eq!(nav.adjustments.len(), 3);
+    }
+
+    #[test]
+    fn test_explicit_fair_values() {
+        let mut input = base_input();
+        input.assets[3].fair_value = Some(dec!(550));
+        input.liabilities[1].fair_value = Some(dec!(400));
+        let out = asset_based_valuation(&input).unwrap();
+        let nav = &out.result.nav;
+        // FV assets 50 + 196 + 285 + 550 = 1081; liabilities 650
+        assert_eq!(nav.adjusted_book_value, dec!(431));
+        let loan = nav
+            .adjustments
+            .iter()
+            .find(|a| a.name == "Term loan")
+            .unwrap();
+        assert_eq!(loan.nav_impact, dec!(50));
+    }
+
+    #[test]
+    fn test_tangible_nav_excludes_intangibles() {
+        let mut input = base_input();
+        input
+            .assets
+            .push(asset("Brands", AssetCategory::Intangibles, dec!(80)));
+        let out = asset_based_valuation(&input).unwrap();
+        let nav = &out.result.nav;
+        assert_eq!(nav.tangible_nav, nav.adjusted_book_value - dec!(80));
+    }
+
+    #[test]
+    fn test_orderly_liquidation_proceeds() {
+        let out = asset_based_valuation(&base_input()).unwrap();
+        let o = &out.result.orderly_liquidation;
+        // 50 + 196*0.85 + 285*0.60 + 400*0.50 = 50 + 166.6 + 171 + 200 = 587.6
+        assert_eq!(o.gross_proceeds, dec!(587.6));
+        assert_eq!(o.net_proceeds_pv, dec!(587.6));
+        assert_eq!(o.liability_claims, dec!(700));
+        assert_eq!(o.equity_value, Decimal::ZERO);
+        assert_eq!(o.creditor_recovery, dec!(587.6) / dec!(700));
+    }
+
+    #[test]
+    fn test_forced_below_orderly() {
+        let out = asset_based_valuation(&base_input()).unwrap();
+        let r = &out.result;
+        // 50 + 196*0.70 + 285*0.40 + 400*0.30 = 50 + 137.2 + 114 + 120 = 421.2
+        assert_eq!(r.forced_liquidation.gross_proceeds, dec!(421.2));
+        assert!(r.forced_liquidation.net_proceeds_pv < r.orderly_liquidation.net_proceeds_pv);
+    }
+
+    #[test]
+    fn test_costs_and_discounting() {
+        let mut input = base_input();
+        input.orderly = LiquidationAssumptions {
+            months_to_realise: 12,
+            disposal_cost_pct: dec!(0.05),
+            wind_down_costs: dec!(20),
+        };
+        let out = asset_based_valuation(&input).unwrap();
+        let o = &out.result.orderly_liquidation;
+        assert_eq!(o.disposal_costs, dec!(29.38));
+        // (587.6 - 29.38 - 20) / 1.10
+        let expected = (dec!(587.6) - dec!(29.38) - dec!(20)) / dec!(1.10);
+        assert!((o.net_proceeds_pv - expected).abs() < dec!(0.0001));
+    }
+
+    #[test]
+    fn test_equity_residual_when_solvent() {
+        let mut input = base_input();
+        input.liabilities = vec![liability("Payables", dec!(200))];
+        let out = asset_based_valuation(&input).unwrap();
+        let o = &out.result.orderly_liquidation;
+        assert_eq!(o.equity_value, dec!(387.6));
+        assert_eq!(o.equity_value_per_share, Some(dec!(38.76)));
+        assert_eq!(o.creditor_recovery, Decimal::ONE);
+    }
+
+    #[test]
+    fn test_liquidation_claims_override() {
+        let mut input = base_input();
+        input.liabilities.push(LiabilityItem {
+            name: "Lease rejection".into(),
+            book_value: Decimal::ZERO,
+            fair_value: None,
+            liquidation_claim: Some(dec!(60)),
+        });
+        let out = asset_based_valuation(&input).unwrap();
+        assert_eq!(out.result.orderly_liquidation.liability_claims, dec!(760));
+        // Not in NAV
+        assert_eq!(out.result.nav.adjusted_book_value, dec!(231));
+    }
+
+    #[test]
+    fn test_haircut_override() {
+        let mut input = base_input();
+        input.haircut_schedule = vec![HaircutSchedule {
+            category: AssetCategory::Inventory,
+            fair_value_haircut: Decimal::ZERO,
+            orderly_haircut: dec!(0.20),
+            forced_haircut: dec!(0.50),
+        }];
+        let out = asset_based_valuation(&input).unwrap();
+        let inv = out
+            .result
+            .orderly_liquidation
+            .by_category
+            .iter()
+            .find(|c| c.category == AssetCategory::Inventory)
+            .unwrap();
+        assert_eq!(inv.fair_value, dec!(300));
+        assert_eq!(inv.gross_proceeds, dec!(240));
+        assert_eq!(inv.recovery_rate, dec!(0.8));
+    }
+
+    #[test]
+    fn test_category_aggregation() {
+        let mut input = base_input();
+        input
+            .assets
+            .push(asset("Cash (restricted)", AssetCategory::Cash, dec!(25)));
+        let out = asset_based_valuation(&input).unwrap();
+        let cash: Vec<_> = out
+            .result
+            .forced_liquidation
+            .by_category
+            .iter()
+            .filter(|c| c.category == AssetCategory::Cash)
+            .collect();
+        assert_eq!(cash.len(), 1);
+        assert_eq!(cash[0].gross_proceeds, dec!(75));
+    }
+
+    #[test]
+    fn test_going_concern_comparison() {
+        let mut input = base_input();
+        input.going_concern_equity_value = Some(dec!(462));
+        let out = asset_based_valuation(&input).unwrap();
+        assert_eq!(out.result.going_concern_to_nav, Some(dec!(2)));
+        assert_eq!(
+            out.result.going_concern_premium_over_liquidation,
+            Some(dec!(462))
+        );
+    }
+
+    #[test]
+    fn test_creditor_shortfall_warning() {
+        let out = asset_based_valuation(&base_input()).unwrap();
+        assert!(out.warnings.iter().any(|w| w.contains("Creditors recover")));
+    }
+
+    #[test]
+    fn test_invalid_inputs() {
+        let mut input = base_input();
+        input.assets.clear();
+        assert!(asset_based_valuation(&input).is_err());
+
+        let mut input = base_input();
+        input.assets[0].book_value = dec!(-1);
+        assert!(asset_based_valuation(&input).is_err());
+
+        let mut input = base_input();
+        input.haircut_schedule = vec![HaircutSchedule {
+            category: AssetCategory::Cash,
+            fair_value_haircut: Decimal::ZERO,
+            orderly_haircut: dec!(1.2),
+            forced_haircut: Decimal::ZERO,
+        }];
+        assert!(asset_based_valuation(&input).is_err());
+
+        let mut input = base_input();
+        input.forced.disposal_cost_pct = dec!(-0.1);
+        assert!(asset_based_valuation(&input).is_err());
+    }
+}
@@ -2,8 +2,12 @@ use rust_decimal::Decimal;
 use rust_decimal::MathematicalOps;
 use rust_decimal_macros::dec;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::time::Instant;
 
+use crate::dilution::{
+    diluted_shares_at_price, diluted_value_per_share, DilutionMethod, DilutiveSecurity,
+};
 use crate::error::CorpFinanceError;
 use crate::types::{with_metadata, ComputationOutput, Currency, Money, Multiple, Rate};
 use crate::CorpFinanceResult;
@@ -45,6 +49,17 @@ pub struct CompanyMetrics {
     /// Share price
     #[serde(skip_serializing_if = "Option::is_none")]
     pub share_price: Option<Money>,
+    /// Basic shares outstanding; with `share_price`, gives a diluted market
+    /// cap when `market_cap` is not supplied
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub basic_shares: Option<Decimal>,
+    /// Options, warrants, RSUs and convertibles diluting `basic_shares`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub dilutive_securities: Vec<DilutiveSecurity>,
+    /// Sector KPIs and operating drivers keyed by name (e.g. "arr",
+    /// "subscribers", "beds", "arr_growth", "net_churn")
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub kpis: BTreeMap<String, Decimal>,
 }
 
 /// A comparable company with its financial metrics.
@@ -82,6 +97,35 @@ impl std::fmt::Display for MultipleType {
     }
 }
 
+/// Value measure placed over a sector KPI.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum KpiNumerator {
+    EnterpriseValue,
+    MarketCap,
+}
+
+/// A sector-specific multiple over a KPI supplied in `CompanyMetrics::kpis`,
+/// e.g. EV/ARR, EV/subscriber, value per bed or per ton of capacity.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KpiMultipleDefinition {
+    /// Display name, e.g. "EV/ARR"
+    pub name: String,
+    /// Key of the KPI in `CompanyMetrics::kpis`
+    pub kpi: String,
+    /// Enterprise value or market cap
+    pub numerator: KpiNumerator,
+}
+
+/// Cross-sectional regression of a KPI multiple on operating drivers
+/// (e.g. EV/ARR against ARR growth and net churn for SaaS).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KpiRegressionSpec {
+    /// Name of a multiple in `kpi_multiples`
+    pub multiple: String,
+    /// KPI keys used as explanatory variables
+    pub drivers: Vec<String>,
+}
+
 /// Input for a trading comparables analysis.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CompsInput {
@@ -95,6 +139,12 @@ pub struct CompsInput {
     pub multiples: Vec<MultipleType>,
     /// Reporting currency
     pub currency: Currency,
+    /// Sector KPI multiples to compute
+    #[serde(default)]
+    pub kpi_multiples: Vec<KpiMultipleDefinition>,
+    /// Optional regression of a KPI multiple on operating drivers
+    #[serde(default)]
+    pub kpi_regression: Option<KpiRegressionSpec>,
 }
 
 /// Descriptive statistics for a single multiple across the comp set.
@@ -124,6 +174,54 @@ pub struct ImpliedValuation {
     pub implied_at_high: Money,
     /// The target metric used as the base
     pub target_metric_value: Money,
+    /// Diluted value per share at the median multiple, for equity multiples
+    /// when the target's `basic_shares` is supplied
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub implied_share_price_at_median: Option<Money>,
+}
+
+/// Descriptive statistics for a sector KPI multiple across the comp set.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KpiMultipleStatistics {
+    pub name: String,
+    pub kpi: String,
+    pub numerator: KpiNumerator,
+    pub values: Vec<(String, Multiple)>,
+    pub mean: Multiple,
+    pub median: Multiple,
+    pub high: Multiple,
+    pub low: Multiple,
+    pub std_dev: Multiple,
+    pub count: usize,
+}
+
+/// An implied valuation for the target from a sector KPI multiple.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KpiImpliedValuation {
+    pub name: String,
+    /// Whether the implied values are enterprise or equity values
+    pub numerator: KpiNumerator,
+    pub implied_at_median: Money,
+    pub implied_at_mean: Money,
+    pub implied_at_low: Money,
+    pub implied_at_high: Money,
+    /// The target KPI used as the base
+    pub target_kpi_value: Decimal,
+}
+
+/// Result of regressing a KPI multiple on operating drivers.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KpiRegressionOutput {
+    pub multiple: String,
+    pub intercept: Decimal,
+    /// Slope per driver
+    pub coefficients: Vec<(String, Decimal)>,
+    pub r_squared: Decimal,
+    pub observations: usize,
+    /// Multiple predicted from the target's drivers
+    pub target_predicted_multiple: Option<Multiple>,
+    /// Predicted multiple x target KPI
+    pub target_implied_value: Option<Money>,
 }
 
 /// Output of a trading comparables analysis.
@@ -137,6 +235,12 @@ pub struct CompsOutput {
     pub companies_included: usize,
     /// Number of comparable companies excluded
     pub companies_excluded: usize,
+    /// Statistics for each sector KPI multiple
+    pub kpi_multiple_statistics: Vec<KpiMultipleStatistics>,
+    /// Implied valuations from sector KPI multiples
+    pub kpi_implied_valuations: Vec<KpiImpliedValuation>,
+    /// KPI multiple regression, if requested
+    pub kpi_regression: Option<KpiRegressionOutput>,
 }
 
 // ---------------------------------------------------------------------------
@@ -158,7 +262,7 @@ pub fn calculate_comps(input: &CompsInput) -> CorpFinanceResult<ComputationOutpu
             "No comparable companies included in the analysis".into(),
         ));
     }
-    if input.multiples.is_empty() {
+    if input.multiples.is_empty() && input.kpi_multiples.is_empty() {
         return Err(CorpFinanceError::InvalidInput {
             field: "multiples".into(),
             reason: "At least one multiple type must be specified".into(),
@@ -197,7 +301,69 @@ pub fn calculate_comps(input: &CompsInput) -> CorpFinanceResult<ComputationOutpu
         multiple_statistics.push(stats);
     }
 
-    if multiple_statistics.is_empty() {
+    // --- Sector KPI multiples ---
+    let mut kpi_multiple_statistics: Vec<KpiMultipleStatistics> = Vec::new();
+    let mut kpi_implied_valuations: Vec<KpiImpliedValuation> = Vec::new();
+
+    for def in &input.kpi_multiples {
+        let values = compute_kpi_multiples(def, &included, &mut warnings);
+        if values.is_empty() {
+            warnings.push(format!(
+                "No comparable companies had sufficient data for {}",
+                def.name
+            ));
+            continue;
+        }
+
+        let summary = summarize(&values);
+        let stats = KpiMultipleStatistics {
+            name: def.name.clone(),
+            kpi: def.kpi.clone(),
+            numerator: def.numerator.clone(),
+            values,
+            mean: summary.mean,
+            median: summary.median,
+            high: summary.high,
+            low: summary.low,
+            std_dev: summary.std_dev,
+            count: summary.count,
+        };
+
+        match input.target_metrics.kpis.get(&def.kpi) {
+            Some(v) if *v > Decimal::ZERO => kpi_implied_valuations.push(KpiImpliedValuation {
+                name: def.name.clone(),
+                numerator: def.numerator.clone(),
+                implied_at_median: *v * stats.median,
+                implied_at_mean: *v * stats.mean,
+                implied_at_low: *v * stats.low,
+                implied_at_high: *v * stats.high,
+                target_kpi_value: *v,
+            }),
+            _ => warnings.push(format!(
+                "Target missing KPI '{}' for {} implied valuation",
+                def.kpi, def.name
+            )),
+        }
+
+        kpi_multiple_statistics.push(stats);
+    }
+
+    let kpi_regression = match &input.kpi_regression {
+        Some(spec) => {
+            let def = input
+                .kpi_multiples
+                .iter()
+                .find(|d| d.name == spec.multiple)
+                .ok_or_else(|| CorpFinanceError::InvalidInput {
+                    field: "kpi_regression.multiple".into(),
+                    reason: format!("'{}' is not defined in kpi_multiples", spec.multiple),
+                })?;
+            run_kpi_regression(def, spec, &included, &input.target_metrics, &mut warnings)
+        }
+        None => None,
+    };
+
+    if multiple_statistics.is_empty() && kpi_multiple_statistics.is_empty() {
         return Err(CorpFinanceError::InsufficientData(
             "Could not compute any multiples from the comparable set".into(),
         ));
@@ -208,6 +374,9 @@ pub fn calculate_comps(input: &CompsInput) -> CorpFinanceResult<ComputationOutpu
         implied_valuations,
         companies_included: included.len(),
         companies_excluded: excluded_count,
+        kpi_multiple_statistics,
+        kpi_implied_valuations,
+        kpi_regression,
     };
 
     let elapsed = start.elapsed().as_micros() as u64;
@@ -248,17 +417,17 @@ fn compute_multiples_for_type(
                 (Some(ev), Some(ebit)) if ebit > Decimal::ZERO => Some(ev / ebit),
                 _ => None,
             },
-            MultipleType::PriceEarnings => match (m.market_cap, m.net_income) {
+            MultipleType::PriceEarnings => match (equity_value(m), m.net_income) {
                 (Some(mc), Some(ni)) if ni > Decimal::ZERO => Some(mc / ni),
                 _ => None,
             },
-            MultipleType::PriceBook => match (m.market_cap, m.book_value) {
+            MultipleType::PriceBook => match (equity_value(m), m.book_value) {
                 (Some(mc), Some(bv)) if bv > Decimal::ZERO => Some(mc / bv),
                 _ => None,
             },
             MultipleType::Peg => {
                 // PEG = (P/E) / (EPS growth * 100)
-                match (m.market_cap, m.net_income, m.eps_growth_rate) {
+                match (equity_value(m), m.net_income, m.eps_growth_rate) {
                     (Some(mc), Some(ni), Some(g)) if ni > Decimal::ZERO && g > Decimal::ZERO => {
                         let pe = mc / ni;
                         let growth_pct = g * dec!(100);
@@ -288,6 +457,31 @@ fn compute_statistics(
     multiple_type: MultipleType,
     values: Vec<(String, Multiple)>,
 ) -> MultipleStatistics {
+    let summary = summarize(&values);
+
+    MultipleStatistics {
+        multiple_type,
+        values,
+        mean: summary.mean,
+        median: summary.median,
+        high: summary.high,
+        low: summary.low,
+        std_dev: summary.std_dev,
+        count: summary.count,
+    }
+}
+
+/// Mean, median, range and sample standard deviation of a set of multiples.
+struct Summary {
+    mean: Multiple,
+    median: Multiple,
+    high: Multiple,
+    low: Multiple,
+    std_dev: Multiple,
+    count: usize,
+}
+
+fn summarize(values: &[(String, Multiple)]) -> Summary {
     let count = values.len();
     let mut sorted_vals: Vec<Multiple> = values.iter().map(|(_, v)| *v).collect();
     sorted_vals.sort();
@@ -320,9 +514,7 @@ fn compute_statistics(
         Decimal::ZERO
     };
 
-    MultipleStatistics {
-        multiple_type,
-        values,
+    Summary {
         mean,
         median,
         high,
@@ -332,6 +524,180 @@ fn compute_statistics(
     }
 }
 
+/// Extract a sector KPI multiple for each comparable that reports the KPI.
+fn compute_kpi_multiples(
+    def: &KpiMultipleDefinition,
+    companies: &[&ComparableCompany],
+    warnings: &mut Vec<String>,
+) -> Vec<(String, Multiple)> {
+    let mut values = Vec::new();
+
+    for comp in companies {
+        match kpi_multiple(def, &comp.metrics) {
+            Some(v) => values.push((comp.name.clone(), v)),
+            None => warnings.push(format!("{}: insufficient data for {}", comp.name, def.name)),
+        }
+    }
+
+    values
+}
+
+fn kpi_multiple(def: &KpiMultipleDefinition, m: &CompanyMetrics) -> Option<Multiple> {
+    let numerator = match def.numerator {
+        KpiNumerator::EnterpriseValue => m.enterprise_value,
+        KpiNumerator::MarketCap => equity_value(m),
+    };
+    match (numerator, m.kpis.get(&def.kpi)) {
+        (Some(n), Some(k)) if *k > Decimal::ZERO => Some(n / *k),
+        _ => None,
+    }
+}
+
+/// OLS regression of a KPI multiple on the requested drivers across the comp set.
+fn run_kpi_regression(
+    def: &KpiMultipleDefinition,
+    spec: &KpiRegressionSpec,
+    companies: &[&ComparableCompany],
+    target: &CompanyMetrics,
+    warnings: &mut Vec<String>,
+) -> Option<KpiRegressionOutput> {
+    if spec.drivers.is_empty() {
+        warnings.push("KPI regression requested without drivers".into());
+        return None;
+    }
+
+    let mut rows: Vec<Vec<Decimal>> = Vec::new();
+    let mut ys: Vec<Decimal> = Vec::new();
+    for comp in companies {
+        let Some(y) = kpi_multiple(def, &comp.metrics) else {
+            continue;
+        };
+        let drivers: Option<Vec<Decimal>> = spec
+            .drivers
+            .iter()
+            .map(|d| comp.metrics.kpis.get(d).copied())
+            .collect();
+        match drivers {
+            Some(x) => {
+                let mut row = vec![Decimal::ONE];
+                row.extend(x);
+                rows.push(row);
+                ys.push(y);
+            }
+            None => warnings.push(format!(
+                "{}: missing regression driver; excluded from KPI regression",
+                comp.name
+            )),
+        }
+    }
+
+    let k = spec.drivers.len() + 1;
+    if rows.len() <= k {
+        warnings.push(format!(
+            "KPI regression needs more than {k} observations; only {} available",
+            rows.len()
+        ));
+        return None;
+    }
+
+    // Normal equations: (X'X) b = X'y
+    let mut xtx = vec![vec![Decimal::ZERO; k]; k];
+    let mut xty = vec![Decimal::ZERO; k];
+    for (row, y) in rows.iter().zip(&ys) {
+        for i in 0..k {
+            xty[i] += row[i] * *y;
+            for j in 0..k {
+                xtx[i][j] += row[i] * row[j];
+            }
+        }
+    }
+    let Some(beta) = solve_linear_system(xtx, xty) else {
+        warnings.push("KPI regression drivers are collinear; regression skipped".into());
+        return None;
+    };
+
+    let n = Decimal::from(ys.len() as i64);
+    let y_mean = ys.iter().copied().sum::<Decimal>() / n;
+    let mut ss_res = Decimal::ZERO;
+    let mut ss_tot = Decimal::ZERO;
+    for (row, y) in rows.iter().zip(&ys) {
+        let fitted: Decimal = row.iter().zip(&beta).map(|(x, b)| *x * *b).sum();
+        ss_res += (*y - fitted) * (*y - fitted);
+        ss_tot += (*y - y_mean) * (*y - y_mean);
+    }
+    let r_squared = if ss_tot.is_zero() {
+        Decimal::ZERO
+    } else {
+        Decimal::ONE - ss_res / ss_tot
+    };
+
+    let target_drivers: Option<Vec<Decimal>> = spec
+        .drivers
+        .iter()
+        .map(|d| target.kpis.get(d).copied())
+        .collect();
+    let target_predicted_multiple = match target_drivers {
+        Some(x) => Some(
+            beta[0]
+                + x.iter()
+                    .zip(&beta[1..])
+                    .map(|(xi, b)| *xi * *b)
+                    .sum::<Decimal>(),
+        ),
+        None => {
+            warnings.push("Target missing a regression driver; no predicted multiple".into());
+            None
+        }
+    };
+    let target_implied_value = match (target_predicted_multiple, target.kpis.get(&def.kpi)) {
+        (Some(m), Some(kpi)) if *kpi > Decimal::ZERO => Some(m * *kpi),
+        _ => None,
+    };
+
+    Some(KpiRegressionOutput {
+        multiple: def.name.clone(),
+        intercept: beta[0],
+        coefficients: spec
+            .drivers
+            .iter()
+            .cloned()
+            .zip(beta[1..].iter().copied())
+            .collect(),
+        r_squared,
+        observations: ys.len(),
+        target_predicted_multiple,
+        target_implied_value,
+    })
+}
+
+/// Gaussian elimination with partial pivoting. Returns `None` if singular.
+fn solve_linear_system(mut a: Vec<Vec<Decimal>>, mut b: Vec<Decimal>) -> Option<Vec<Decimal>> {
+    let n = b.len();
+    for col in 0..n {
+        let pivot = (col..n).max_by_key(|&r| a[r][col].abs())?;
+        if a[pivot][col].abs() < dec!(0.000000001) {
+            return None;
+        }
+        a.swap(col, pivot);
+        b.swap(col, pivot);
+        let pivot_row = a[col].clone();
+        let pivot_b = b[col];
+        for (a_row, b_row) in a.iter_mut().zip(b.iter_mut()).skip(col + 1) {
+            let factor = a_row[col] / pivot_row[col];
+            for (cell, p) in a_row.iter_mut().zip(&pivot_row).skip(col) {
+                *cell -= factor * *p;
+            }
+            *b_row -= factor * pivot_b;
+        }
+    }
+    let mut x = vec![Decimal::ZERO; n];
+    for row in (0..n).rev() {
+        let tail: Decimal = ((row + 1)..n).map(|c| a[row][c] * x[c]).sum();
+        x[row] = (b[row] - tail) / a[row][row];
+    }
+    Some(x)
+}
+
 /// Compute implied valuation for the target using the given statistics.
 fn compute_implied_valuation(
     mult_type: &MultipleType,
@@ -358,6 +724,10 @@ fn compute_implied_valuation(
                         implied_at_low: ni * stats.low * growth_pct,
                         implied_at_high: ni * stats.high * growth_pct,
                         target_metric_value: ni,
+                        implied_share_price_at_median: implied_share_price(
+                            target,
+                            ni * stats.median * growth_pct,
+                        ),
                     });
                 }
                 _ => {
@@ -379,6 +749,12 @@ fn compute_implied_valuation(
             implied_at_low: val * stats.low,
             implied_at_high: val * stats.high,
             target_metric_value: val,
+            implied_share_price_at_median: match mult_type {
+                MultipleType::PriceEarnings | MultipleType::PriceBook => {
+                    implied_share_price(target, val * stats.median)
+                }
+                _ => None,
+            },
         }),
         _ => {
             warnings.push(format!(
@@ -389,6 +765,33 @@ fn compute_implied_valuation(
     }
 }
 
+/// Equity value: market cap if supplied, otherwise share price times the
+/// diluted share count (TSM) when basic shares are known.
+fn equity_value(m: &CompanyMetrics) -> Option<Money> {
+    m.market_cap
+        .or_else(|| match (m.share_price, m.basic_shares) {
+            (Some(price), Some(basic)) => {
+                diluted_shares_at_price(basic, &m.dilutive_securities, price)
+                    .ok()
+                    .map(|d| d.diluted_market_cap)
+            }
+            _ => None,
+        })
+}
+
+/// Diluted value per share for an implied equity value.
+fn implied_share_price(target: &CompanyMetrics, equity: Money) -> Option<Money> {
+    let basic = target.basic_shares?;
+    diluted_value_per_share(
+        equity,
+        basic,
+        &target.dilutive_securities,
+        &DilutionMethod::TreasuryStock,
+    )
+    .ok()
+    .map(|v| v.value_per_share)
+}
+
 /// Integer square root approximation for Decimal via Newton's method.
 fn sqrt_decimal(value: Decimal) -> Decimal {
     if value <= Decimal::ZERO {
@@ -421,6 +824,9 @@ mod tests {
                 eps: Some(dec!(2.50)),
                 eps_growth_rate: Some(dec!(0.15)),
                 share_price: Some(dec!(40)),
+                basic_shares: None,
+                dilutive_securities: vec![],
+                kpis: BTreeMap::new(),
             },
             comparables: vec![
                 ComparableCompany {
@@ -436,6 +842,9 @@ mod tests {
                         eps: Some(dec!(3.00)),
                         eps_growth_rate: Some(dec!(0.12)),
                         share_price: Some(dec!(50)),
+                        basic_shares: None,
+                        dilutive_securities: vec![],
+                        kpis: BTreeMap::new(),
                     },
                     include: true,
                 },
@@ -452,6 +861,9 @@ mod tests {
                         eps: Some(dec!(4.00)),
                         eps_growth_rate: Some(dec!(0.20)),
                         share_price: Some(dec!(60)),
+                        basic_shares: None,
+                        dilutive_securities: vec![],
+                        kpis: BTreeMap::new(),
                     },
                     include: true,
                 },
@@ -468,6 +880,9 @@ mod tests {
                         eps: Some(dec!(2.00)),
                         eps_growth_rate: Some(dec!(0.10)),
                         share_price: Some(dec!(35)),
+                        basic_shares: None,
+                        dilutive_securities: vec![],
+                        kpis: BTreeMap::new(),
                     },
                     include: true,
                 },
@@ -484,6 +899,9 @@ mod tests {
                         eps: Some(dec!(5.00)),
                         eps_growth_rate: Some(dec!(0.08)),
                         share_price: Some(dec!(80)),
+                        basic_shares: None,
+                        dilutive_securities: vec![],
+                        kpis: BTreeMap::new(),
                     },
                     include: false,
                 },
@@ -495,6 +913,8 @@ mod tests {
                 MultipleType::PriceBook,
             ],
             currency: Currency::USD,
+            kpi_multiples: vec![],
+            kpi_regression: None,
         }
     }
 
@@ -706,6 +1126,67 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_market_cap_from_diluted_share_count() {
+        let mut input = sample_comps_input();
+        input.multiples = vec![MultipleType::PriceEarnings];
+        let comp_a = &mut input.comparables[0].metrics;
+        comp_a.market_cap = None;
+        comp_a.share_price = Some(dec!(50));
+        comp_a.basic_shares = Some(dec!(30));
+        // 4 options struck at 25: TSM adds 4 - 100 / 50 = 2 shares
+        comp_a.dilutive_securities = vec![DilutiveSecurity {
+            name: "ESOP".into(),
+            kind: crate::dilution::SecurityKind::Option,
+            count: dec!(4),
+            strike: dec!(25),
+            shares_per_instrument: Decimal::ONE,
+            time_to_expiry: None,
+        }];
+
+        let result = calculate_comps(&input).unwrap();
+        let pe = &result.result.multiple_statistics[0];
+        let (_, comp_a_pe) = pe.values.iter().find(|(n, _)| n == "CompA").unwrap();
+        // Diluted market cap 32 x 50 = 1600, as in the sample
+        assert_eq!(*comp_a_pe, dec!(1600) / dec!(120));
+    }
+
+    #[test]
+    fn test_implied_share_price_uses_dilution() {
+        let mut input = sample_comps_input();
+        input.multiples = vec![MultipleType::PriceEarnings, MultipleType::EvEbitda];
+        input.target_metrics.basic_shares = Some(dec!(30));
+        input.target_metrics.dilutive_securities = vec![DilutiveSecurity {
+            name: "Warrants".into(),
+            kind: crate::dilution::SecurityKind::Warrant,
+            count: dec!(5),
+            strike: dec!(10),
+            shares_per_instrument: Decimal::ONE,
+            time_to_expiry: None,
+        }];
+
+        let result = calculate_comps(&input).unwrap();
+        let pe = result
+            .result
+            .implied_valuations
+            .iter()
+            .find(|v| v.multiple_type == MultipleType::PriceEarnings)
+            .unwrap();
+        let equity = pe.implied_at_median;
+        let price = pe.implied_share_price_at_median.unwrap();
+        // Warrants in the money: P = (E + 50) / 35
+        assert!((price - (equity + dec!(50)) / dec!(35)).abs() < dec!(0.000001));
+        assert!(price < equity / dec!(30));
+
+        let ev = result
+            .result
+            .implied_valuations
+            .iter()
+            .find(|v| v.multiple_type == MultipleType::EvEbitda)
+            .unwrap();
+        assert!(ev.implied_share_price_at_median.is_none());
+    }
+
     #[test]
     fn test_methodology_string() {
         let input = sample_comps_input();
@@ -726,4 +1207,180 @@ mod tests {
             .iter()
             .any(|w| w.contains("comparables included")));
     }
+
+    fn saas_comp(
+        name: &str,
+        ev: Decimal,
+        arr: Decimal,
+        growth: Decimal,
+        churn: Decimal,
+    ) -> ComparableCompany {
+        ComparableCompany {
+            name: name.into(),
+            metrics: CompanyMetrics {
+                enterprise_value: Some(ev),
+                market_cap: None,
+                revenue: None,
+                ebitda: None,
+                ebit: None,
+                net_income: None,
+                book_value: None,
+                eps: None,
+                eps_growth_rate: None,
+                share_price: None,
+                basic_shares: None,
+                dilutive_securities: vec![],
+                kpis: BTreeMap::from([
+                    ("arr".to_string(), arr),
+                    ("arr_growth".to_string(), growth),
+                    ("net_churn".to_string(), churn),
+                ]),
+            },
+            include: true,
+        }
+    }
+
+    /// EV/ARR = 2 + 20 x growth - 10 x churn for every comp.
+    fn saas_input() -> CompsInput {
+        let mut input = sample_comps_input();
+        input.target_metrics.kpis = BTreeMap::from([
+            ("arr".to_string(), dec!(120)),
+            ("arr_growth".to_string(), dec!(0.30)),
+            ("net_churn".to_string(), dec!(0.10)),
+        ]);
+        input.comparables = vec![
+            saas_comp("S1", dec!(350), dec!(100), dec!(0.10), dec!(0.05)),
+            saas_comp("S2", dec!(1000), dec!(200), dec!(0.20), dec!(0.10)),
+            saas_comp("S3", dec!(750), dec!(100), dec!(0.30), dec!(0.05)),
+            saas_comp("S4", dec!(425), dec!(50), dec!(0.40), dec!(0.15)),
+            saas_comp("S5", dec!(1020), dec!(150), dec!(0.25), dec!(0.02)),
+        ];
+        input.multiples = vec![];
+        input.kpi_multiples = vec![KpiMultipleDefinition {
+            name: "EV/ARR".into(),
+            kpi: "arr".into(),
+            numerator: KpiNumerator::EnterpriseValue,
+        }];
+        input
+    }
+
+    #[test]
+    fn test_kpi_multiple_statistics() {
+        let result = calculate_comps(&saas_input()).unwrap();
+        let out = &result.result;
+        assert!(out.multiple_statistics.is_empty());
+        let stats = &out.kpi_multiple_statistics[0];
+        assert_eq!(stats.name, "EV/ARR");
+        assert_eq!(stats.count, 5);
+        // 3.5, 5.0, 7.5, 8.5, 6.8
+        assert_eq!(stats.median, dec!(6.8));
+        assert_eq!(stats.low, dec!(3.5));
+        assert_eq!(stats.high, dec!(8.5));
+    }
+
+    #[test]
+    fn test_kpi_implied_valuation() {
+        let result = calculate_comps(&saas_input()).unwrap();
+        let implied = &result.result.kpi_implied_valuations[0];
+        assert_eq!(implied.target_kpi_value, dec!(120));
+        assert_eq!(implied.implied_at_median, dec!(816));
+        assert_eq!(implied.numerator, KpiNumerator::EnterpriseValue);
+    }
+
+    #[test]
+    fn test_kpi_per_unit_market_cap() {
+        let mut input = saas_input();
+        input.kpi_multiples = vec![KpiMultipleDefinition {
+            name: "Value per bed".into(),
+            kpi: "beds".into(),
+            numerator: KpiNumerator::MarketCap,
+        }];
+        for (i, comp) in input.comparables.iter_mut().enumerate() {
+            comp.metrics.market_cap = Some(dec!(1000));
+            comp.metrics
+                .kpis
+                .insert("beds".into(), Decimal::from(100 * (i as i64 + 1)));
+        }
+        input.target_metrics.kpis.insert("beds".into(), dec!(250));
+        let result = calculate_comps(&input).unwrap();
+        let stats = &result.result.kpi_multiple_statistics[0];
+        // 10, 5, 3.33, 2.5, 2 -> median 3.33
+        assert!((stats.median - dec!(3.3333)).abs() < dec!(0.001));
+        let implied = &result.result.kpi_implied_valuations[0];
+        assert!((implied.implied_at_median - dec!(833.33)).abs() < dec!(0.01));
+    }
+
+    #[test]
+    fn test_kpi_missing_data_warns() {
+        let mut input = saas_input();
+        input.comparables[0].metrics.kpis.remove("arr");
+        input.target_metrics.kpis.remove("arr");
+        let result = calculate_comps(&input).unwrap();
+        assert_eq!(result.result.kpi_multiple_statistics[0].count, 4);
+        assert!(result.result.kpi_implied_valuations.is_empty());
+        assert!(result.warnings.iter().any(|w| w.contains("S1")));
+        assert!(result
+            .warnings
+            .iter()
+            .any(|w| w.contains("Target missing KPI")));
+    }
+
+    #[test]
+    fn test_kpi_regression_recovers_coefficients() {
+        let mut input = saas_input();
+        input.kpi_regression = Some(KpiRegressionSpec {
+            multiple: "EV/ARR".into(),
+            drivers: vec!["arr_growth".into(), "net_churn".into()],
+        });
+        let result = calculate_comps(&input).unwrap();
+        let reg = result.result.kpi_regression.unwrap();
+        assert_eq!(reg.observations, 5);
+        assert!((reg.intercept - dec!(2)).abs() < dec!(0.0001));
+        assert!((reg.coefficients[0].1 - dec!(20)).abs() < dec!(0.0001));
+        assert!((reg.coefficients[1].1 - dec!(-10)).abs() < dec!(0.0001));
+        assert!((reg.r_squared - Decimal::ONE).abs() < dec!(0.0001));
+        // Target: 2 + 6 - 1 = 7x ARR of 120
+        let predicted = reg.target_predicted_multiple.unwrap();
+        assert!((predicted - dec!(7)).abs() < dec!(0.0001));
+        assert!((reg.target_implied_value.unwrap() - dec!(840)).abs() < dec!(0.01));
+    }
+
+    #[test]
+    fn test_kpi_regression_insufficient_observations() {
+        let mut input = saas_input();
+        input.comparables.truncate(3);
+        input.kpi_regression = Some(KpiRegressionSpec {
+            multiple: "EV/ARR".into(),
+            drivers: vec!["arr_growth".into(), "net_churn".into()],
+        });
+        let result = calculate_comps(&input).unwrap();
+        assert!(result.result.kpi_regression.is_none());
+        assert!(result.warnings.iter().any(|w| w.contains("observations")));
+    }
+
+    #[test]
+    fn test_kpi_regression_unknown_multiple_errors() {
+        let mut input = saas_input();
+        input.kpi_regression = Some(KpiRegressionSpec {
+            multiple: "EV/Subscriber".into(),
+            drivers: vec!["arr_growth".into()],
+        });
+        assert!(calculate_comps(&input).is_err());
+    }
+
+    #[test]
+    fn test_kpi_regression_collinear_drivers() {
+        let mut input = saas_input();
+        for comp in &mut input.comparables {
+            let g = comp.metrics.kpis["arr_growth"];
+            comp.metrics.kpis.insert("growth_copy".into(), g * dec!(2));
+        }
+        input.kpi_regression = Some(KpiRegressionSpec {
+            multiple: "EV/ARR".into(),
+            drivers: vec!["arr_growth".into(), "growth_copy".into()],
+        });
+        let result = calculate_comps(&input).unwrap();
+        assert!(result.result.kpi_regression.is_none());
+        assert!(result.warnings.iter().any(|w| w.contains("collinear")));
+    }
 }
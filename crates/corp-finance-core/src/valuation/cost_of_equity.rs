@@ -0,0 +1,759 @@
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+use std::time::Instant;
+
+use crate::error::CorpFinanceError;
+use crate::types::{with_metadata, ComputationOutput, Money, Rate};
+use crate::valuation::wacc::{relever_beta, unlever_beta};
+use crate::CorpFinanceResult;
+
+// ---------------------------------------------------------------------------
+// Types
+// ---------------------------------------------------------------------------
+
+/// Formula used to unlever peer betas and relever at the target structure.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LeverageFormula {
+    /// Beta_L = Beta_U * (1 + (1 - t) * D/E)
+    #[default]
+    Hamada,
+    /// Beta_L = Beta_U * (1 + D/E) (no tax shield)
+    Practitioners,
+    /// Beta_L = Beta_U + (Beta_U - Beta_D) * D/E (Harris-Pringle with debt beta)
+    HarrisPringle,
+}
+
+/// How peer unlevered betas are aggregated.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BetaAggregation {
+    #[default]
+    Median,
+    Mean,
+}
+
+/// A peer used to estimate the industry (asset) beta.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BetaPeer {
+    pub name: String,
+    pub levered_beta: Decimal,
+    /// Market debt / market equity
+    pub debt_to_equity: Decimal,
+    pub tax_rate: Rate,
+}
+
+/// One step of a size premium schedule (e.g. a market-cap decile).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SizeBand {
+    /// Smallest market cap in the band
+    pub min_market_cap: Money,
+    pub premium: Rate,
+}
+
+/// Size premium: either supplied directly or looked up from a schedule.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum SizePremium {
+    Fixed {
+        premium: Rate,
+    },
+    Schedule {
+        market_cap: Money,
+        bands: Vec<SizeBand>,
+    },
+}
+
+/// Country risk premium estimation method.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum CountryRiskMethod {
+    /// CRP = sovereign default spread
+    SovereignSpread { spread_bps: Decimal },
+    /// CRP = sovereign spread x (local equity vol / local bond vol)
+    RelativeVolatility {
+        spread_bps: Decimal,
+        equity_volatility: Rate,
+        bond_volatility: Rate,
+    },
+    /// CRP = mature ERP x (local equity vol / mature equity vol - 1)
+    RelativeEquityVolatility {
+        local_equity_volatility: Rate,
+        mature_equity_volatility: Rate,
+    },
+}
+
+/// Country risk premium and the subject's exposure to it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CountryRiskSpec {
+    pub method: CountryRiskMethod,
+    /// Exposure to country risk (lambda); 1 = fully exposed
+    #[serde(default = "default_lambda")]
+    pub lambda: Decimal,
+}
+
+fn default_lambda() -> Decimal {
+    Decimal::ONE
+}
+
+/// Input for the cost of equity build-up.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CostOfEquityInput {
+    pub subject_name: String,
+    pub risk_free_rate: Rate,
+    /// Mature-market equity risk premium
+    pub equity_risk_premium: Rate,
+    pub peers: Vec<BetaPeer>,
+    #[serde(default)]
+    pub leverage_formula: LeverageFormula,
+    #[serde(default)]
+    pub beta_aggregation: BetaAggregation,
+    /// Debt beta for Harris-Pringle (default 0)
+    #[serde(default)]
+    pub debt_beta: Decimal,
+    /// Subject's target debt / equity for relevering
+    pub target_debt_to_equity: Decimal,
+    pub target_tax_rate: Rate,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size_premium: Option<SizePremium>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country_risk: Option<CountryRiskSpec>,
+    /// Company-specific risk premium
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub specific_risk_premium: Option<Rate>,
+}
+
+/// A peer's unlevered beta.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PeerBeta {
+    pub name: String,
+    pub levered_beta: Decimal,
+    pub debt_to_equity: Decimal,
+    pub unlevered_beta: Decimal,
+}
+
+/// A line of the build-up report.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BuildUpStep {
+    pub component: String,
+    pub value: Rate,
+    /// Cost of equity after adding this component
+    pub cumulative: Rate,
+}
+
+/// Output of the cost of equity build-up.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CostOfEquityOutput {
+    pub peer_betas: Vec<PeerBeta>,
+    pub unlevered_beta_median: Decimal,
+    pub unlevered_beta_mean: Decimal,
+    /// Aggregate unlevered beta used for relevering
+    pub selected_unlevered_beta: Decimal,
+    /// Beta relevered at the target capital structure
+    pub relevered_beta: Decimal,
+    /// Rf + beta x ERP
+    pub capm_cost_of_equity: Rate,
+    pub size_premium: Rate,
+    /// CRP before applying lambda
+    pub country_risk_premium: Rate,
+    pub specific_risk_premium: Rate,
+    pub cost_of_equity: Rate,
+    pub build_up: Vec<BuildUpStep>,
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+fn unlever(input: &CostOfEquityInput, peer: &BetaPeer) -> CorpFinanceResult<Decimal> {
+    let de = peer.debt_to_equity;
+    match input.leverage_formula {
+        LeverageFormula::Hamada => unlever_beta(peer.levered_beta, peer.tax_rate, de),
+        LeverageFormula::Practitioners => Ok(peer.levered_beta / (Decimal::ONE + de)),
+        LeverageFormula::HarrisPringle => {
+            Ok((peer.levered_beta + input.debt_beta * de) / (Decimal::ONE + de))
+        }
+    }
+}
+
+fn relever(input: &CostOfEquityInput, beta_u: Decimal) -> Decimal {
+    let de = input.target_debt_to_equity;
+    match input.leverage_formula {
+        LeverageFormula::Hamada => relever_beta(beta_u, input.target_tax_rate, de),
+        LeverageFormula::Practitioners => beta_u * (Decimal::ONE + de),
+        LeverageFormula::HarrisPringle => beta_u + (beta_u - input.debt_beta) * de,
+    }
+}
+
+fn median(values: &[Decimal]) -> Decimal {
+    let mut sorted = values.to_vec();
+    sorted.sort();
+    let n = sorted.len();
+    if n.is_multiple_of(2) {
+        (sorted[n / 2 - 1] + sorted[n / 2]) / dec!(2)
+    } else {
+        sorted[n / 2]
+    }
+}
+
+fn size_premium(spec: &SizePremium, warnings: &mut Vec<String>) -> Rate {
+    match spec {
+        SizePremium::Fixed { premium } => *premium,
+        SizePremium::Schedule { market_cap, bands } => {
+            match bands
+                .iter()
+                .filter(|b| b.min_market_cap <= *market_cap)
+                .max_by(|a, b| a.min_market_cap.cmp(&b.min_market_cap))
+            {
+                Some(band) => band.premium,
+                None => {
+                    // Below the smallest band: use the smallest-company premium.
+                    warnings.push(format!(
+                        "Market cap {market_cap} is below the size schedule; using the smallest band"
+                    ));
+                    bands
+                        .iter()
+                        .min_by(|a, b| a.min_market_cap.cmp(&b.min_market_cap))
+                        .map(|b| b.premium)
+                        .unwrap_or(Decimal::ZERO)
+                }
+            }
+        }
+    }
+}
+
+fn country_risk_premium(method: &CountryRiskMethod, erp: Rate) -> Rate {
+    match method {
+        CountryRiskMethod::SovereignSpread { spread_bps } => *spread_bps / dec!(10000),
+        CountryRiskMethod::RelativeVolatility {
+            spread_bps,
+            equity_volatility,
+            bond_volatility,
+        } => *spread_bps / dec!(10000) * *equity_volatility / *bond_volatility,
+        CountryRiskMethod::RelativeEquityVolatility {
+            local_equity_volatility,
+            mature_equity_volatility,
+        } => (erp * (*local_equity_volatility / *mature_equity_volatility - Decimal::ONE))
+            .max(Decimal::ZERO),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Function: build_cost_of_equity
+// ---------------------------------------------------------------------------
+
+/// Cost of equity build-up: unlever peer betas, relever at the subject's
+/// target structure, then add size, country and specific risk premia.
+///
+/// Ke = Rf + Beta_relevered * ERP + size + lambda * CRP + specific
+pub fn build_cost_of_equity(
+    input: &CostOfEquityInput,
+) -> CorpFinanceResult<ComputationOutput<CostOfEquityOutput>> {
+    let start = Instant::now();
+    let mut warnings: Vec<String> = Vec::new();
+
+    validate_input(input)?;
+
+    let mut peer_betas = Vec::with_capacity(input.peers.len());
+    for peer in &input.peers {
+        let unlevered_beta = unlever(input, peer)?;
+        peer_betas.push(PeerBeta {
+            name: peer.name.clone(),
+            levered_beta: peer.levered_beta,
+            debt_to_equity: peer.debt_to_equity,
+            unlevered_beta,
+        });
+    }
+    let unlevered: Vec<Decimal> = peer_betas.iter().map(|p| p.unlevered_beta).collect();
+    let unlevered_beta_median = median(&unlevered);
+    let unlevered_beta_mean =
+        unlevered.iter().copied().sum::<Decimal>() / Decimal::from(unlevered.len() as u32);
+    let selected_unlevered_beta = match input.beta_aggregation {
+        BetaAggregation::Median => unlevered_beta_median,
+        BetaAggregation::Mean => unlevered_beta_mean,
+    };
+    if input.peers.len() < 3 {
+        warnings.push(format!(
+            "Only {} peer(s); industry beta estimate is unreliable",
+            input.peers.len()
+        ));
+    }
+    for p in &peer_betas {
+        if p.unlevered_beta <= Decimal::ZERO {
+            warnings.push(format!("{}: non-positive unlevered beta", p.name));
+        }
+    }
+
+    let relevered_beta = relever(input, selected_unlevered_beta);
+    let capm_cost_of_equity = input.risk_free_rate + relevered_beta * input.equity_risk_premium;
+
+    let size = input
+        .size_premium
+        .as_ref()
+        .map(|s| size_premium(s, &mut warnings))
+        .unwrap_or(Decimal::ZERO);
+    let (crp, lambda) = match &input.country_risk {
+        Some(c) => (
+            country_risk_premium(&c.method, input.equity_risk_premium),
+            c.lambda,
+        ),
+        None => (Decimal::ZERO, Decimal::ZERO),
+    };
+    let specific = input.specific_risk_premium.unwrap_or(Decimal::ZERO);
+
+    let mut build_up = Vec::new();
+    let mut cumulative = Decimal::ZERO;
+    let mut step = |component: String, value: Rate| {
+        cumulative += value;
+        build_up.push(BuildUpStep {
+            component,
+            value,
+            cumulative,
+        });
+    };
+    step("Risk-free rate".into(), input.risk_free_rate);
+    step(
+        format!(
+            "Beta x ERP ({:.2} x {:.2}%)",
+            relevered_beta,
+            input.equity_risk_premium * dec!(100)
+        ),
+        relevered_beta * input.equity_risk_premium,
+    );
+    if input.size_premium.is_some() {
+        step("Size premium".into(), size);
+    }
+    if input.country_risk.is_some() {
+        step(
+            format!("Country risk premium (lambda {lambda})"),
+            lambda * crp,
+        );
+    }
+    if input.specific_risk_premium.is_some() {
+        step("Company-specific risk premium".into(), specific);
+    }
+    let cost_of_equity = cumulative;
+
+    if cost_of_equity > dec!(0.25) {
+        warnings.push(format!(
+            "Cost of equity of {cost_of_equity} exceeds 25%; review premia for double counting"
+        ));
+    }
+
+    let output = CostOfEquityOutput {
+        peer_betas,
+        unlevered_beta_median,
+        unlevered_beta_mean,
+        selected_unlevered_beta,
+        relevered_beta,
+        capm_cost_of_equity,
+        size_premium: size,
+        country_risk_premium: crp,
+        specific_risk_premium: specific,
+        cost_of_equity,
+        build_up,
+    };
+
+    let elapsed = start.elapsed().as_micros() as u64;
+    Ok(with_metadata(
+        "Cost of Equity Build-Up (peer beta unlever/relever, size, country and specific premia)",
+        &serde_json::json!({
+            "subject": input.subject_name,
+            "leverage_formula": input.leverage_formula,
+            "beta_aggregation": input.beta_aggregation,
+            "peers": input.peers.len(),
+            "target_debt_to_equity": input.target_debt_to_equity.to_string(),
+        }),
+        warnings,
+        elapsed,
+        output,
+    ))
+}
+
+fn validate_input(input: &CostOfEquityInput) -> CorpFinanceResult<()> {
+    if input.peers.is_empty() {
+        return Err(CorpFinanceError::InsufficientData(
+            "At least one peer is required to estimate beta".into(),
+        ));
+    }
+    for p in &input.peers {
+        if p.debt_to_equity < Decimal::ZERO {
+            return Err(CorpFinanceError::InvalidInput {
+                field: format!("peers[{}].debt_to_equity", p.name),
+                reason: "Debt to equity cannot be negative".into(),
+            });
+        }
+        if p.tax_rate < Decimal::ZERO || p.tax_rate > Decimal::ONE {
+            return Err(CorpFinanceError::InvalidInput {
+                field: format!("peers[{}].tax_rate", p.name),
+                reason: "Tax rate must be between 0 and 1".into(),
+            });
+        }
+    }
+    if input.target_debt_to_equity < Decimal::ZERO {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "target_debt_to_equity".into(),
+            reason: "Debt to equity cannot be negative".into(),
+        });
+    }
+    if input.target_tax_rate < Decimal::ZERO || input.target_tax_rate > Decimal::ONE {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "target_tax_rate".into(),
+            reason: "Tax rate must be between 0 and 1".into(),
+        });
+    }
+    if input.equity_risk_premium < Decimal::ZERO {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "equity_risk_premium".into(),
+            reason: "Equity risk premium cannot be negative".into(),
+        });
+    }
+    if let Some(SizePremium::Schedule { bands, .. }) = &input.size_premium {
+        if bands.is_empty() {
+            return Err(CorpFinanceError::InsufficientData(
+                "Size premium schedule has no bands".into(),
+            ));
+        }
+    }
+    if let Some(c) = &input.country_risk {
+        let positive = match &c.method {
+            CountryRiskMethod::SovereignSpread { .. } => true,
+            CountryRiskMethod::RelativeVolatility {
+                bond_volatility, ..
+            } => *bond_volatility > Decimal::ZERO,
+            CountryRiskMethod::RelativeEquityVolatility {
+                mature_equity_volatility,
+                ..
+            } => *mature_equity_volatility > Decimal::ZERO,
+        };
+        if !positive {
+            return Err(CorpFinanceError::DivisionByZero {
+                context: "country risk premium volatility ratio".into(),
+            });
+        }
+        if c.lambda < Decimal::ZERO {
+            return Err(CorpFinanceError::InvalidInput {
+                field: "country_risk.lambda".into(),
+                reason: "Lambda cannot be negative".into(),
+            });
+        }
+    }
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn approx_eq(a: Decimal, b: Decimal, eps: Decimal) -> bool {
+        (a - b).abs() < eps
+    }
+
+    fn peer(name: &str, beta: Decimal, de: Decimal, tax: Decimal) -> BetaPeer {
+        BetaPeer {
+            name: name.into(),
+            levered_beta: beta,
+            debt_to_equity: de,
+            tax_rate: tax,
+        }
+    }
+
+    fn base_input() -> CostOfEquityInput {
+        CostOfEquityInput {
+            subject_name: "Target Co".into(),
+            risk_free_rate: dec!(0.04),
+            equity_risk_premium: dec!(0.05),
+            peers: vec![
+                peer("Peer A", dec!(1.2), dec!(0.5), dec!(0.25)),
+                peer("Peer B", dec!(1.0), dec!(0.2), dec!(0.20)),
+                peer("Peer C", dec!(1.5), dec!(1.0), dec!(0.30)),
+            ],
+            leverage_formula: LeverageFormula::Hamada,
+            beta_aggregation: BetaAggregation::Median,
+            debt_beta: Decimal::ZERO,
+            target_debt_to_equity: dec!(0.4),
+            target_tax_rate: dec!(0.25),
+            size_premium: None,
+            country_risk: None,
+            specific_risk_premium: None,
+        }
+    }
+
+    #[test]
+    fn test_hamada_unlevering() {
+        let out = build_cost_of_equity(&base_input()).unwrap();
+        let p = &out.result.peer_betas;
+        // 1.2 / (1 + 0.75 * 0.5) = 0.872727...
+        assert!(approx_eq(
+            p[0].unlevered_beta,
+            dec!(0.872727),
+            dec!(0.00001)
+        ));
+        // 1.0 / 1.16 = 0.862069
+        assert!(approx_eq(
+            p[1].unlevered_beta,
+            dec!(0.862069),
+            dec!(0.00001)
+        ));
+        // 1.5 / 1.7 = 0.882353
+        assert!(approx_eq(
+            p[2].unlevered_beta,
+            dec!(0.882353),
+            dec!(0.00001)
+        ));
+    }
+
+    #[test]
+    fn test_median_and_relever() {
+        let out = build_cost_of_equity(&base_input()).unwrap();
+        let r = &out.result;
+        assert_eq!(r.selected_unlevered_beta, r.peer_betas[0].unlevered_beta);
+        // Relever: 0.872727 * (1 + 0.75 * 0.4) = 1.134545
+        assert!(approx_eq(r.relevered_beta, dec!(1.134545), dec!(0.00001)));
+        assert!(approx_eq(
+            r.capm_cost_of_equity,
+            dec!(0.04) + dec!(1.134545) * dec!(0.05),
+            dec!(0.000001)
+        ));
+    }
+
+    #[test]
+    fn test_mean_aggregation() {
+        let mut input = base_input();
+        input.beta_aggregation = BetaAggregation::Mean;
+        let out = build_cost_of_equity(&input).unwrap();
+        let r = &out.result;
+        assert_eq!(r.selected_unlevered_beta, r.unlevered_beta_mean);
+        let expected = r
+            .peer_betas
+            .iter()
+            .map(|p| p.unlevered_beta)
+            .sum::<Decimal>()
+            / dec!(3);
+        assert_eq!(r.unlevered_beta_mean, expected);
+    }
+
+    #[test]
+    fn test_practitioners_formula() {
+        let mut input = base_input();
+        input.leverage_formula = LeverageFormula::Practitioners;
+        let out = build_cost_of_equity(&input).unwrap();
+        // 1.2 / 1.5 = 0.8
+        assert_eq!(out.result.peer_betas[0].unlevered_beta, dec!(0.8));
+    }
+
+    #[test]
+    fn test_harris_pringle_with_debt_beta() {
+        let mut input = base_input();
+        input.leverage_formula = LeverageFormula::HarrisPringle;
+        input.debt_beta = dec!(0.2);
+        input.peers = vec![peer("Only", dec!(1.2), dec!(0.5), dec!(0.25))];
+        input.target_debt_to_equity = dec!(0.5);
+        let out = build_cost_of_equity(&input).unwrap();
+        // Unlever: (1.2 + 0.2 * 0.5) / 1.5 = 0.8666...
+        let bu = (dec!(1.2) + dec!(0.1)) / dec!(1.5);
+        assert_eq!(out.result.peer_betas[0].unlevered_beta, bu);
+        // Relevering at the same D/E recovers the peer beta.
+        assert!(approx_eq(
+            out.result.relevered_beta,
+            dec!(1.2),
+            dec!(0.000001)
+        ));
+    }
+
+    #[test]
+    fn test_round_trip_same_structure() {
+        let mut input = base_input();
+        input.peers = vec![peer("Only", dec!(1.3), dec!(0.6), dec!(0.25))];
+        input.target_debt_to_equity = dec!(0.6);
+        let out = build_cost_of_equity(&input).unwrap();
+        assert!(approx_eq(
+            out.result.relevered_beta,
+            dec!(1.3),
+            dec!(0.000001)
+        ));
+    }
+
+    #[test]
+    fn test_fixed_size_premium() {
+        let mut input = base_input();
+        input.size_premium = Some(SizePremium::Fixed {
+            premium: dec!(0.015),
+        });
+        let out = build_cost_of_equity(&input).unwrap();
+        let r = &out.result;
+        assert_eq!(r.size_premium, dec!(0.015));
+        assert_eq!(r.cost_of_equity, r.capm_cost_of_equity + dec!(0.015));
+    }
+
+    fn schedule(market_cap: Decimal) -> SizePremium {
+        SizePremium::Schedule {
+            market_cap,
+            bands: vec![
+                SizeBand {
+                    min_market_cap: dec!(10000),
+                    premium: Decimal::ZERO,
+                },
+                SizeBand {
+                    min_market_cap: dec!(2000),
+                    premium: dec!(0.01),
+                },
+                SizeBand {
+                    min_market_cap: dec!(500),
+                    premium: dec!(0.02),
+                },
+                SizeBand {
+                    min_market_cap: dec!(100),
+                    premium: dec!(0.035),
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn test_size_schedule_lookup() {
+        let mut input = base_input();
+        input.size_premium = Some(schedule(dec!(800)));
+        let out = build_cost_of_equity(&input).unwrap();
+        assert_eq!(out.result.size_premium, dec!(0.02));
+
+        input.size_premium = Some(schedule(dec!(50000)));
+        let out = build_cost_of_equity(&input).unwrap();
+        assert_eq!(out.result.size_premium, Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_size_below_schedule_warns() {
+        let mut input = base_input();
+        input.size_premium = Some(schedule(dec!(50)));
+        let out = build_cost_of_equity(&input).unwrap();
+        assert_eq!(out.result.size_premium, dec!(0.035));
+        assert!(out
+            .warnings
+            .iter()
+            .any(|w| w.contains("below the size schedule")));
+    }
+
+    #[test]
+    fn test_crp_sovereign_spread() {
+        let mut input = base_input();
+        input.country_risk = Some(CountryRiskSpec {
+            method: CountryRiskMethod::SovereignSpread {
+                spread_bps: dec!(250),
+            },
+            lambda: Decimal::ONE,
+        });
+        let out = build_cost_of_equity(&input).unwrap();
+        assert_eq!(out.result.country_risk_premium, dec!(0.025));
+        assert_eq!(
+            out.result.cost_of_equity,
+            out.result.capm_cost_of_equity + dec!(0.025)
+        );
+    }
+
+    #[test]
+    fn test_crp_relative_volatility_with_lambda() {
+        let mut input = base_input();
+        input.country_risk = Some(CountryRiskSpec {
+            method: CountryRiskMethod::RelativeVolatility {
+                spread_bps: dec!(200),
+                equity_volatility: dec!(0.30),
+                bond_volatility: dec!(0.20),
+            },
+            lambda: dec!(0.5),
+        });
+        let out = build_cost_of_equity(&input).unwrap();
+        // 2% x 1.5 = 3%, half exposure = 1.5%
+        assert_eq!(out.result.country_risk_premium, dec!(0.03));
+        assert_eq!(
+            out.result.cost_of_equity,
+            out.result.capm_cost_of_equity + dec!(0.015)
+        );
+    }
+
+    #[test]
+    fn test_crp_relative_equity_volatility() {
+        let mut input = base_input();
+        input.country_risk = Some(CountryRiskSpec {
+            method: CountryRiskMethod::RelativeEquityVolatility {
+                local_equity_volatility: dec!(0.24),
+                mature_equity_volatility: dec!(0.16),
+            },
+            lambda: Decimal::ONE,
+        });
+        let out = build_cost_of_equity(&input).unwrap();
+        // 5% x (1.5 - 1) = 2.5%
+        assert_eq!(out.result.country_risk_premium, dec!(0.025));
+    }
+
+    #[test]
+    fn test_build_up_report() {
+        let mut input = base_input();
+        input.size_premium = Some(SizePremium::Fixed {
+            premium: dec!(0.01),
+        });
+        input.country_risk = Some(CountryRiskSpec {
+            method: CountryRiskMethod::SovereignSpread {
+                spread_bps: dec!(100),
+            },
+            lambda: Decimal::ONE,
+        });
+        input.specific_risk_premium = Some(dec!(0.02));
+        let out = build_cost_of_equity(&input).unwrap();
+        let steps = &out.result.build_up;
+        assert_eq!(steps.len(), 5);
+        assert_eq!(steps[0].cumulative, dec!(0.04));
+        assert_eq!(steps[4].cumulative, out.result.cost_of_equity);
+        let sum: Decimal = steps.iter().map(|s| s.value).sum();
+        assert_eq!(sum, out.result.cost_of_equity);
+    }
+
+    #[test]
+    fn test_few_peers_warning() {
+        let mut input = base_input();
+        input.peers.truncate(2);
+        let out = build_cost_of_equity(&input).unwrap();
+        assert!(out.warnings.iter().any(|w| w.contains("unreliable")));
+        // Even count: median is the average of the two
+        let p = &out.result.peer_betas;
+        assert_eq!(
+            out.result.unlevered_beta_median,
+            (p[0].unlevered_beta + p[1].unlevered_beta) / dec!(2)
+        );
+    }
+
+    #[test]
+    fn test_invalid_inputs() {
+        let mut input = base_input();
+        input.peers.clear();
+        assert!(build_cost_of_equity(&input).is_err());
+
+        let mut input = base_input();
+        input.peers[0].debt_to_equity = dec!(-0.1);
+        assert!(build_cost_of_equity(&input).is_err());
+
+        let mut input = base_input();
+        input.target_tax_rate = dec!(1.2);
+        assert!(build_cost_of_equity(&input).is_err());
+
+        let mut input = base_input();
+        input.country_risk = Some(CountryRiskSpec {
+            method: CountryRiskMethod::RelativeVolatility {
+                spread_bps: dec!(200),
+                equity_volatility: dec!(0.3),
+                bond_volatility: Decimal::ZERO,
+            },
+            lambda: Decimal::ONE,
+        });
+        assert!(build_cost_of_equity(&input).is_err());
+
+        let mut input = base_input();
+        input.size_premium = Some(SizePremium::Schedule {
+            market_cap: dec!(100),
+            bands: vec![],
+        });
+        assert!(build_cost_of_equity(&input).is_err());
+    }
+}
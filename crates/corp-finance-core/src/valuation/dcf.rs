@@ -6,7 +6,8 @@ use std::time::Instant;
 
 use crate::error::CorpFinanceError;
 use crate::types::{
-    with_metadata, ComputationOutput, Currency, Money, Multiple, ProjectionPeriod, Rate,
+    fx_conversion_rate, with_metadata, ComputationOutput, Currency, FxRate, Money, Multiple,
+    ProjectionPeriod, Rate,
 };
 use crate::CorpFinanceResult;
 
@@ -25,6 +26,11 @@ pub enum TerminalMethod {
     ExitMultiple,
     /// Compute both and report; uses Gordon as primary
     Both,
+    /// Value-driver formula: TV = NOPAT_(n+1) * (1 - g / RONIC) / (WACC - g)
+    ValueDriver,
+    /// Convergence: returns on new capital fade to WACC, so growth adds no
+    /// value and TV = NOPAT_(n+1) / WACC
+    Convergence,
 }
 
 /// Input parameters for a Discounted Cash Flow valuation.
@@ -62,6 +68,10 @@ pub struct DcfInput {
     /// Exit EBITDA multiple (required for ExitMultiple / Both)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub terminal_exit_multiple: Option<Multiple>,
+    /// Return on new invested capital in the terminal period (required for
+    /// ValueDriver)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub terminal_ronic: Option<Rate>,
     /// Reporting currency
     pub currency: Currency,
     /// Number of explicit forecast years (default: length of growth_rates or 10)
@@ -79,6 +89,26 @@ pub struct DcfInput {
     /// Diluted shares outstanding for per-share value
     #[serde(skip_serializing_if = "Option::is_none")]
     pub shares_outstanding: Option<Decimal>,
+    /// Currency to translate headline values into (defaults to `currency`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub presentation_currency: Option<Currency>,
+    /// Spot FX rates used for the presentation translation
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub fx_rates: Vec<FxRate>,
+}
+
+/// One year of operating cash flow projected outside the DCF engine.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectedCashFlow {
+    pub revenue: Money,
+    pub ebitda: Money,
+    pub ebit: Money,
+    /// EBIT less unlevered taxes
+    pub nopat: Money,
+    pub da: Money,
+    pub capex: Money,
+    /// Increase in net working capital (positive = cash outflow)
+    pub nwc_change: Money,
 }
 
 /// Projection for a single year of the DCF model.
@@ -97,9 +127,48 @@ pub struct DcfYearProjection {
     pub pv_fcff: Money,
 }
 
+/// Headline DCF values translated into the presentation currency.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DcfPresentation {
+    pub currency: Currency,
+    /// Units of presentation currency per unit of reporting currency
+    pub fx_rate: Decimal,
+    pub enterprise_value: Money,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub equity_value: Option<Money>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub equity_value_per_share: Option<Money>,
+}
+
+/// Terminal value under one method, for comparison with the method used.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TerminalAlternative {
+    pub method: String,
+    pub terminal_value: Money,
+    /// Difference from the terminal value used, as a fraction of it
+    pub difference_pct: Rate,
+}
+
+/// What the chosen terminal value implies, plus every other method the
+/// inputs allow.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TerminalCrossCheck {
+    pub method: String,
+    pub terminal_value: Money,
+    /// TV / final-year EBITDA
+    pub implied_exit_multiple: Multiple,
+    /// Perpetual FCFF growth that reproduces the TV in a Gordon model
+    pub implied_perpetual_growth: Rate,
+    /// TV / next-year NOPAT
+    pub implied_nopat_multiple: Multiple,
+    pub alternatives: Vec<TerminalAlternative>,
+}
+
 /// Output of the DCF valuation.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DcfOutput {
+    /// Reporting currency of all values below
+    pub currency: Currency,
     /// Year-by-year projections
     pub projections: Vec<DcfYearProjection>,
     /// Terminal value via Gordon growth (if applicable)
@@ -128,6 +197,11 @@ pub struct DcfOutput {
     pub terminal_value_pct: Rate,
     /// WACC used in the calculation
     pub wacc_used: Rate,
+    /// Implied metrics for the terminal value used and alternative methods
+    pub terminal_cross_check: TerminalCrossCheck,
+    /// Headline values in the presentation currency (if it differs)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub presentation: Option<DcfPresentation>,
 }
 
 // ---------------------------------------------------------------------------
@@ -135,6 +209,7 @@ pub struct DcfOutput {
 // ---------------------------------------------------------------------------
 
 /// Run a 2-stage FCFF DCF valuation.
+#[tracing::instrument(level = "debug", name = "dcf", skip_all, fields(forecast_years))]
 pub fn calculate_dcf(input: &DcfInput) -> CorpFinanceResult<ComputationOutput<DcfOutput>> {
     let start = Instant::now();
     let mut warnings: Vec<String> = Vec::new();
@@ -147,17 +222,112 @@ pub fn calculate_dcf(input: &DcfInput) -> CorpFinanceResult<ComputationOutput<Dc
 
     let mid_year = input.mid_year_convention.unwrap_or(true);
     let n_years = resolve_forecast_years(input);
+    tracing::Span::current().record("forecast_years", n_years);
 
     // --- Project cash flows ---
-    let projections = build_projections(input, n_years, wacc, mid_year)?;
+    let projections = tracing::debug_span!("projection")
+        .in_scope(|| build_projections(input, n_years, wacc, mid_year))?;
+    let output = tracing::debug_span!("valuation")
+        .in_scope(|| value_projections(input, projections, wacc, &mut warnings))?;
+
+    let elapsed = start.elapsed().as_micros() as u64;
 
+    Ok(with_metadata(
+        "2-Stage FCFF DCF (WACC-based)",
+        input,
+        warnings,
+        elapsed,
+        output,
+    ))
+}
+
+/// Value FCFF projected outside the DCF engine, e.g. from a linked
+/// three-statement model.
+///
+/// The revenue growth, margin and reinvestment drivers on `input` are
+/// ignored; WACC, terminal value, equity bridge and presentation settings
+/// apply exactly as in [`calculate_dcf`].
+#[tracing::instrument(
+    level = "debug",
+    name = "dcf_from_cash_flows",
+    skip_all,
+    fields(years = cash_flows.len())
+)]
+pub fn calculate_dcf_from_cash_flows(
+    input: &DcfInput,
+    cash_flows: &[ProjectedCashFlow],
+) -> CorpFinanceResult<ComputationOutput<DcfOutput>> {
+    let start = Instant::now();
+    let mut warnings: Vec<String> = Vec::new();
+
+    let wacc = resolve_wacc(input, &mut warnings)?;
+    validate_valuation_params(input, wacc)?;
+    if cash_flows.is_empty() {
+        return Err(CorpFinanceError::InsufficientData(
+            "At least one projected cash flow is required".into(),
+        ));
+    }
+
+    let mid_year = input.mid_year_convention.unwrap_or(true);
+    let projections = cash_flows
+        .iter()
+        .enumerate()
+        .map(|(i, cf)| {
+            let year_num = (i + 1) as u32;
+            let fcff = cf.nopat + cf.da - cf.capex - cf.nwc_change;
+            let discount_factor = discount_factor(wacc, year_num, mid_year);
+            DcfYearProjection {
+                period: ProjectionPeriod {
+                    year: year_num as i32,
+                    label: format!("Year {year_num}"),
+                    is_terminal: false,
+                },
+                revenue: cf.revenue,
+                ebitda: cf.ebitda,
+                ebit: cf.ebit,
+                nopat: cf.nopat,
+                plus_da: cf.da,
+                less_capex: cf.capex,
+                less_nwc_change: cf.nwc_change,
+                fcff,
+                discount_factor,
+                pv_fcff: fcff * discount_factor,
+            }
+        })
+        .collect();
+    let output = value_projections(input, projections, wacc, &mut warnings)?;
+
+    let elapsed = start.elapsed().as_micros() as u64;
+
+    Ok(with_metadata(
+        "FCFF DCF on externally projected cash flows (WACC-based)",
+        input,
+        warnings,
+        elapsed,
+        output,
+    ))
+}
+
+// ---------------------------------------------------------------------------
+// Internal helpers
+// ---------------------------------------------------------------------------
+
+/// Terminal value, discounting and equity bridge over a set of projections.
+fn value_projections(
+    input: &DcfInput,
+    projections: Vec<DcfYearProjection>,
+    wacc: Rate,
+    warnings: &mut Vec<String>,
+) -> CorpFinanceResult<DcfOutput> {
+    let n_years = projections.len() as u32;
     let pv_of_fcff: Money = projections.iter().map(|p| p.pv_fcff).sum();
     let last = projections.last().ok_or_else(|| {
         CorpFinanceError::InsufficientData("No projection years generated".into())
     })?;
 
     // --- Terminal value ---
-    let (tv_gordon, tv_exit, tv_used) = compute_terminal_values(input, last, wacc, &mut warnings)?;
+    let (tv_gordon, tv_exit, tv_used) = compute_terminal_values(input, last, wacc, warnings)?;
+    let terminal_cross_check = build_cross_check(input, last, wacc, tv_used);
 
     // --- Discount TV to present ---
     let tv_discount_period = Decimal::from(n_years);
@@ -190,7 +360,23 @@ pub fn calculate_dcf(input: &DcfInput) -> CorpFinanceResult<ComputationOutput<Dc
     // --- Equity bridge ---
     let (equity_value, equity_value_per_share) = compute_equity_bridge(input, enterprise_value)?;
 
+    // --- Presentation currency translation ---
+    let presentation = match &input.presentation_currency {
+        Some(ccy) if *ccy != input.currency => {
+            let fx_rate = fx_conversion_rate(&input.currency, ccy, &input.fx_rates)?;
+            Some(DcfPresentation {
+                currency: ccy.clone(),
+                fx_rate,
+                enterprise_value: enterprise_value * fx_rate,
+                equity_value: equity_value.map(|v| v * fx_rate),
+                equity_value_per_share: equity_value_per_share.map(|v| v * fx_rate),
+            })
+        }
+        _ => None,
+    };
+
     let output = DcfOutput {
+        currency: input.currency.clone(),
         projections,
         terminal_value_gordon: tv_gordon,
         terminal_value_exit: tv_exit,
@@ -203,23 +389,13 @@ pub fn calculate_dcf(input: &DcfInput) -> CorpFinanceResult<ComputationOutput<Dc
         implied_exit_multiple,
         terminal_value_pct: tv_pct,
         wacc_used: wacc,
+        terminal_cross_check,
+        presentation,
     };
 
-    let elapsed = start.elapsed().as_micros() as u64;
-
-    Ok(with_metadata(
-        "2-Stage FCFF DCF (WACC-based)",
-        input,
-        warnings,
-        elapsed,
-        output,
-    ))
+    Ok(output)
 }
 
-// ---------------------------------------------------------------------------
-// Internal helpers
-// ---------------------------------------------------------------------------
-
 fn resolve_wacc(input: &DcfInput, warnings: &mut Vec<String>) -> CorpFinanceResult<Rate> {
     if let Some(ref wacc_input) = input.wacc_input {
         let wacc_out = calculate_wacc(wacc_input)?;
@@ -233,12 +409,6 @@ fn resolve_wacc(input: &DcfInput, warnings: &mut Vec<String>) -> CorpFinanceResu
 }
 
 fn validate_dcf_input(input: &DcfInput, wacc: Rate) -> CorpFinanceResult<()> {
-    if wacc <= Decimal::ZERO {
-        return Err(CorpFinanceError::InvalidInput {
-            field: "wacc".into(),
-            reason: "WACC must be positive".into(),
-        });
-    }
     if input.base_revenue <= Decimal::ZERO {
         return Err(CorpFinanceError::InvalidInput {
             field: "base_revenue".into(),
@@ -258,6 +428,18 @@ fn validate_dcf_input(input: &DcfInput, wacc: Rate) -> CorpFinanceResult<()> {
         });
     }
 
+    validate_valuation_params(input, wacc)
+}
+
+/// Checks on discounting and terminal value inputs shared by every entry point.
+fn validate_valuation_params(input: &DcfInput, wacc: Rate) -> CorpFinanceResult<()> {
+    if wacc <= Decimal::ZERO {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "wacc".into(),
+            reason: "WACC must be positive".into(),
+        });
+    }
+
     // Terminal growth must be less than WACC (Gordon growth model constraint)
     if let Some(g) = input.terminal_growth_rate {
         if g >= wacc {
@@ -299,6 +481,30 @@ fn validate_dcf_input(input: &DcfInput, wacc: Rate) -> CorpFinanceResult<()> {
                 });
             }
         }
+        TerminalMethod::ValueDriver => {
+            if input.terminal_growth_rate.is_none() {
+                return Err(CorpFinanceError::InvalidInput {
+                    field: "terminal_growth_rate".into(),
+                    reason: "Required for ValueDriver terminal method".into(),
+                });
+            }
+            if input.terminal_ronic.is_none() {
+                return Err(CorpFinanceError::InvalidInput {
+                    field: "terminal_ronic".into(),
+                    reason: "Required for ValueDriver terminal method".into(),
+                });
+            }
+        }
+        TerminalMethod::Convergence => {}
+    }
+
+    if let Some(ronic) = input.terminal_ronic {
+        if ronic <= Decimal::ZERO {
+            return Err(CorpFinanceError::InvalidInput {
+                field: "terminal_ronic".into(),
+                reason: "RONIC must be positive".into(),
+            });
+        }
     }
 
     Ok(())
@@ -348,13 +554,7 @@ fn build_projections(
         let plus_da = da;
         let fcff = nopat + plus_da - capex - nwc_change;
 
-        // Discount factor
-        let discount_period = if mid_year {
-            Decimal::from(year_num) - dec!(0.5)
-        } else {
-            Decimal::from(year_num)
-        };
-        let discount_factor = Decimal::ONE / (Decimal::ONE + wacc).powd(discount_period);
+        let discount_factor = discount_factor(wacc, year_num, mid_year);
         let pv_fcff = fcff * discount_factor;
 
         projections.push(DcfYearProjection {
@@ -382,6 +582,15 @@ fn build_projections(
     Ok(projections)
 }
 
+fn discount_factor(wacc: Rate, year_num: u32, mid_year: bool) -> Rate {
+    let discount_period = if mid_year {
+        Decimal::from(year_num) - dec!(0.5)
+    } else {
+        Decimal::from(year_num)
+    };
+    Decimal::ONE / (Decimal::ONE + wacc).powd(discount_period)
+}
+
 /// Get the growth rate for a given year index. If `revenue_growth_rates` is shorter
 /// than the forecast period, the last rate is carried forward.
 fn growth_rate_for_year(input: &DcfInput, year_idx: u32) -> Rate {
@@ -413,7 +622,7 @@ fn compute_terminal_values(
             let tv = last_year.fcff * (Decimal::ONE + g) / denom;
             Some(tv)
         }
-        TerminalMethod::ExitMultiple => None,
+        _ => None,
     };
 
     let tv_exit = match input.terminal_method {
@@ -422,7 +631,7 @@ fn compute_terminal_values(
             let tv = last_year.ebitda * multiple;
             Some(tv)
         }
-        TerminalMethod::GordonGrowth => None,
+        _ => None,
     };
 
     // Determine which TV to use
@@ -444,11 +653,108 @@ fn compute_terminal_values(
             // Use Gordon as primary when Both
             g
         }
+        TerminalMethod::ValueDriver => {
+            let g = input.terminal_growth_rate.unwrap(); // validated above
+            let ronic = input.terminal_ronic.unwrap(); // validated above
+            if g > Decimal::ZERO && ronic < wacc {
+                warnings.push(format!(
+                    "RONIC ({ronic}) below WACC ({wacc}): terminal growth destroys value"
+                ));
+            }
+            value_driver_tv(last_year.nopat, g, ronic, wacc)
+        }
+        TerminalMethod::Convergence => {
+            let g = input.terminal_growth_rate.unwrap_or(Decimal::ZERO);
+            last_year.nopat * (Decimal::ONE + g) / wacc
+        }
     };
 
     Ok((tv_gordon, tv_exit, tv_used))
 }
 
+/// Value-driver terminal value: growth funded by reinvesting g / RONIC of NOPAT.
+fn value_driver_tv(nopat: Money, g: Rate, ronic: Rate, wacc: Rate) -> Money {
+    let next_nopat = nopat * (Decimal::ONE + g);
+    next_nopat * (Decimal::ONE - g / ronic) / (wacc - g)
+}
+
+fn method_label(method: &TerminalMethod) -> &'static str {
+    match method {
+        TerminalMethod::GordonGrowth | TerminalMethod::Both => "GordonGrowth",
+        TerminalMethod::ExitMultiple => "ExitMultiple",
+        TerminalMethod::ValueDriver => "ValueDriver",
+        TerminalMethod::Convergence => "Convergence",
+    }
+}
+
+fn build_cross_check(
+    input: &DcfInput,
+    last_year: &DcfYearProjection,
+    wacc: Rate,
+    tv_used: Money,
+) -> TerminalCrossCheck {
+    let g = input.terminal_growth_rate;
+    let next_nopat = last_year.nopat * (Decimal::ONE + g.unwrap_or(Decimal::ZERO));
+
+    // Every method the inputs support
+    let mut candidates: Vec<(&str, Money)> = Vec::new();
+    if let Some(g) = g {
+        candidates.push((
+            "GordonGrowth",
+            last_year.fcff * (Decimal::ONE + g) / (wacc - g),
+        ));
+    }
+    if let Some(m) = input.terminal_exit_multiple {
+        candidates.push(("ExitMultiple", last_year.ebitda * m));
+    }
+    if let (Some(g), Some(ronic)) = (g, input.terminal_ronic) {
+        candidates.push((
+            "ValueDriver",
+            value_driver_tv(last_year.nopat, g, ronic, wacc),
+        ));
+    }
+    candidates.push(("Convergence", next_nopat / wacc));
+
+    let method = method_label(&input.terminal_method);
+    let alternatives = candidates
+        .into_iter()
+        .filter(|(m, _)| *m != method)
+        .map(|(m, tv)| TerminalAlternative {
+            method: m.into(),
+            terminal_value: tv,
+            difference_pct: if tv_used.is_zero() {
+                Decimal::ZERO
+            } else {
+                (tv - tv_used) / tv_used
+            },
+        })
+        .collect();
+
+    // Solve TV = FCFF * (1 + g) / (WACC - g) for g
+    let implied_perpetual_growth = if (tv_used + last_year.fcff).is_zero() {
+        Decimal::ZERO
+    } else {
+        (tv_used * wacc - last_year.fcff) / (tv_used + last_year.fcff)
+    };
+
+    TerminalCrossCheck {
+        method: method.into(),
+        terminal_value: tv_used,
+        implied_exit_multiple: if last_year.ebitda.is_zero() {
+            Decimal::ZERO
+        } else {
+            tv_used / last_year.ebitda
+        },
+        implied_perpetual_growth,
+        implied_nopat_multiple: if next_nopat.is_zero() {
+            Decimal::ZERO
+        } else {
+            tv_used / next_nopat
+        },
+        alternatives,
+    }
+}
+
 fn compute_equity_bridge(
     input: &DcfInput,
     enterprise_value: Money,
@@ -503,12 +809,15 @@ mod tests {
             terminal_method: TerminalMethod::GordonGrowth,
             terminal_growth_rate: Some(dec!(0.025)),
             terminal_exit_multiple: None,
+            terminal_ronic: None,
             currency: Currency::USD,
             forecast_years: None,
             mid_year_convention: Some(true),
             net_debt: Some(dec!(200)),
             minority_interest: None,
             shares_outstanding: Some(dec!(100)),
+            presentation_currency: None,
+            fx_rates: vec![],
         }
     }
 
@@ -721,4 +1030,189 @@ mod tests {
         assert!(out.terminal_value_pct >= Decimal::ZERO);
         assert!(out.terminal_value_pct <= Decimal::ONE);
     }
+
+    #[test]
+    fn test_dcf_presentation_currency_direct_rate() {
+        let mut input = sample_dcf_input();
+        input.currency = Currency::USD;
+        input.presentation_currency = Some(Currency::EUR);
+        input.fx_rates = vec![FxRate {
+            base: Currency::USD,
+            quote: Currency::EUR,
+            rate: dec!(0.90),
+        }];
+
+        let out = calculate_dcf(&input).unwrap().result;
+        let pres = out.presentation.expect("presentation block");
+        assert_eq!(out.currency, Currency::USD);
+        assert_eq!(pres.currency, Currency::EUR);
+        assert_eq!(pres.fx_rate, dec!(0.90));
+        assert_eq!(pres.enterprise_value, out.enterprise_value * dec!(0.90));
+        assert_eq!(
+            pres.equity_value_per_share,
+            out.equity_value_per_share.map(|v| v * dec!(0.90))
+        );
+    }
+
+    #[test]
+    fn test_dcf_presentation_currency_inverse_and_cross() {
+        let mut input = sample_dcf_input();
+        input.currency = Currency::GBP;
+        input.presentation_currency = Some(Currency::JPY);
+        // GBP -> USD via the inverse of a USD/GBP quote, then USD -> JPY
+        input.fx_rates = vec![
+            FxRate {
+                base: Currency::USD,
+                quote: Currency::GBP,
+                rate: dec!(0.80),
+            },
+            FxRate {
+                base: Currency::USD,
+                quote: Currency::JPY,
+                rate: dec!(150),
+            },
+        ];
+
+        let out = calculate_dcf(&input).unwrap().result;
+        let pres = out.presentation.unwrap();
+        assert_eq!(pres.fx_rate, dec!(187.5));
+        assert_eq!(pres.enterprise_value, out.enterprise_value * dec!(187.5));
+    }
+
+    #[test]
+    fn test_dcf_presentation_missing_rate_errors() {
+        let mut input = sample_dcf_input();
+        input.currency = Currency::USD;
+        input.presentation_currency = Some(Currency::CHF);
+
+        assert!(matches!(
+            calculate_dcf(&input),
+            Err(CorpFinanceError::InsufficientData(_))
+        ));
+    }
+
+    #[test]
+    fn test_dcf_presentation_same_currency_is_omitted() {
+        let mut input = sample_dcf_input();
+        input.presentation_currency = Some(input.currency.clone());
+
+        let out = calculate_dcf(&input).unwrap().result;
+        assert!(out.presentation.is_none());
+    }
+
+    #[test]
+    fn test_dcf_value_driver_terminal() {
+        let mut input = sample_dcf_input();
+        input.terminal_method = TerminalMethod::ValueDriver;
+        input.terminal_ronic = Some(dec!(0.15));
+
+        let out = calculate_dcf(&input).unwrap().result;
+        let last = out.projections.last().unwrap();
+        let g = dec!(0.025);
+        let expected =
+            last.nopat * (Decimal::ONE + g) * (Decimal::ONE - g / dec!(0.15)) / (dec!(0.10) - g);
+        assert_eq!(out.terminal_value_used, expected);
+        assert_eq!(out.terminal_cross_check.method, "ValueDriver");
+    }
+
+    #[test]
+    fn test_dcf_value_driver_requires_ronic() {
+        let mut input = sample_dcf_input();
+        input.terminal_method = TerminalMethod::ValueDriver;
+        assert!(calculate_dcf(&input).is_err());
+    }
+
+    #[test]
+    fn test_dcf_value_driver_ronic_equal_wacc_matches_convergence() {
+        let mut input = sample_dcf_input();
+        input.terminal_method = TerminalMethod::ValueDriver;
+        input.terminal_ronic = Some(dec!(0.10));
+        let vd = calculate_dcf(&input).unwrap().result;
+
+        input.terminal_method = TerminalMethod::Convergence;
+        let conv = calculate_dcf(&input).unwrap().result;
+
+        let diff = (vd.terminal_value_used - conv.terminal_value_used).abs();
+        assert!(diff < dec!(0.0001), "diff {diff}");
+    }
+
+    #[test]
+    fn test_dcf_convergence_terminal() {
+        let mut input = sample_dcf_input();
+        input.terminal_method = TerminalMethod::Convergence;
+        input.terminal_growth_rate = None;
+
+        let out = calculate_dcf(&input).unwrap().result;
+        let last = out.projections.last().unwrap();
+        assert_eq!(out.terminal_value_used, last.nopat / dec!(0.10));
+    }
+
+    #[test]
+    fn test_dcf_value_driver_low_ronic_warns() {
+        let mut input = sample_dcf_input();
+        input.terminal_method = TerminalMethod::ValueDriver;
+        input.terminal_ronic = Some(dec!(0.08));
+        let result = calculate_dcf(&input).unwrap();
+        assert!(result.warnings.iter().any(|w| w.contains("destroys value")));
+    }
+
+    #[test]
+    fn test_cross_check_implied_growth_round_trips_gordon() {
+        let input = sample_dcf_input();
+        let out = calculate_dcf(&input).unwrap().result;
+        let cc = &out.terminal_cross_check;
+        assert_eq!(cc.method, "GordonGrowth");
+        assert!((cc.implied_perpetual_growth - dec!(0.025)).abs() < dec!(0.000001));
+        assert_eq!(cc.implied_exit_multiple, out.implied_exit_multiple);
+        // Gordon used, so only Convergence is an alternative
+        assert_eq!(cc.alternatives.len(), 1);
+        assert_eq!(cc.alternatives[0].method, "Convergence");
+    }
+
+    #[test]
+    fn test_cross_check_exit_multiple_implies_growth() {
+        let mut input = sample_dcf_input();
+        input.terminal_method = TerminalMethod::ExitMultiple;
+        input.terminal_exit_multiple = Some(dec!(10));
+        input.terminal_ronic = Some(dec!(0.12));
+
+        let out = calculate_dcf(&input).unwrap().result;
+        let cc = &out.terminal_cross_check;
+        assert_eq!(cc.implied_exit_multiple, dec!(10));
+        let last = out.projections.last().unwrap();
+        // Implied g reproduces the exit TV in a Gordon model
+        let g = cc.implied_perpetual_growth;
+        let gordon = last.fcff * (Decimal::ONE + g) / (dec!(0.10) - g);
+        assert!((gordon - out.terminal_value_used).abs() < dec!(0.0001));
+        let methods: Vec<&str> = cc.alternatives.iter().map(|a| a.method.as_str()).collect();
+        assert_eq!(methods, vec!["GordonGrowth", "ValueDriver", "Convergence"]);
+    }
+
+    #[test]
+    fn test_dcf_from_cash_flows_matches_internal_projection() {
+        let input = sample_dcf_input();
+        let internal = calculate_dcf(&input).unwrap().result;
+        let flows: Vec<ProjectedCashFlow> = internal
+            .projections
+            .iter()
+            .map(|p| ProjectedCashFlow {
+                revenue: p.revenue,
+                ebitda: p.ebitda,
+                ebit: p.ebit,
+                nopat: p.nopat,
+                da: p.plus_da,
+                capex: p.less_capex,
+                nwc_change: p.less_nwc_change,
+            })
+            .collect();
+
+        let external = calculate_dcf_from_cash_flows(&input, &flows).unwrap();
+        assert_eq!(external.result.enterprise_value, internal.enterprise_value);
+        assert!(external.methodology.contains("externally projected"));
+    }
+
+    #[test]
+    fn test_dcf_from_cash_flows_empty_rejected() {
+        assert!(calculate_dcf_from_cash_flows(&sample_dcf_input(), &[]).is_err());
+    }
 }
@@ -0,0 +1,362 @@
+use rust_decimal::Decimal;
+use rust_decimal::MathematicalOps;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+use std::time::Instant;
+
+use crate::error::CorpFinanceError;
+use crate::types::{with_metadata, ComputationOutput, Currency, Money, Rate};
+use crate::CorpFinanceResult;
+
+use super::dcf::{calculate_dcf, DcfInput};
+
+// ---------------------------------------------------------------------------
+// Types
+// ---------------------------------------------------------------------------
+
+/// Economic profit (EVA) valuation built on the same forecast as the DCF.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EconomicProfitInput {
+    /// Operating forecast, discount rate and terminal assumptions
+    pub dcf: DcfInput,
+    /// Invested capital at the valuation date (operating working capital,
+    /// net PP&E and operating intangibles)
+    pub opening_invested_capital: Money,
+}
+
+/// Economic profit for one forecast year.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EconomicProfitYear {
+    pub year: u32,
+    pub opening_invested_capital: Money,
+    /// Capex less D&A plus increase in working capital
+    pub net_investment: Money,
+    pub closing_invested_capital: Money,
+    pub nopat: Money,
+    /// NOPAT / opening invested capital
+    pub roic: Rate,
+    /// ROIC - WACC
+    pub spread: Rate,
+    /// WACC x opening invested capital
+    pub capital_charge: Money,
+    /// NOPAT - capital charge
+    pub economic_profit: Money,
+    pub discount_factor: Rate,
+    pub pv_economic_profit: Money,
+}
+
+/// Tie-out of the economic profit value to the DCF enterprise value.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EconomicProfitReconciliation {
+    pub dcf_enterprise_value: Money,
+    pub economic_profit_enterprise_value: Money,
+    /// Should be zero up to rounding
+    pub difference: Money,
+}
+
+/// Output of the economic profit valuation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EconomicProfitOutput {
+    pub currency: Currency,
+    pub wacc_used: Rate,
+    pub years: Vec<EconomicProfitYear>,
+    pub opening_invested_capital: Money,
+    /// Sum of present values of explicit-period economic profits
+    pub pv_of_economic_profit: Money,
+    /// Invested capital at the end of the forecast
+    pub terminal_invested_capital: Money,
+    /// Continuing value of economic profit: DCF terminal value less
+    /// terminal invested capital
+    pub continuing_value: Money,
+    pub pv_of_continuing_value: Money,
+    /// Mid-year convention adjustment to the capital base. The capital
+    /// recovery terms only telescope to opening capital under end-of-year
+    /// discounting; this restores equality with the DCF when cash flows are
+    /// discounted at mid-year.
+    pub timing_adjustment: Money,
+    /// PV of explicit and continuing economic profit
+    pub market_value_added: Money,
+    /// Opening invested capital + market value added + timing adjustment
+    pub enterprise_value: Money,
+    /// Share of enterprise value from value created above invested capital
+    pub value_creation_pct: Rate,
+    pub average_roic: Rate,
+    pub average_spread: Rate,
+    pub reconciliation: EconomicProfitReconciliation,
+}
+
+// ---------------------------------------------------------------------------
+// Public API
+// ---------------------------------------------------------------------------
+
+/// Value the firm as invested capital plus the present value of economic
+/// profits, using the DCF forecast so the two methods reconcile.
+///
+/// Invested capital rolls forward by capex - D&A + increase in NWC, which
+/// makes FCFF = NOPAT - net investment. Economic profit is NOPAT less a
+/// WACC charge on opening capital, discounted with the DCF's own factors.
+/// The continuing value is the DCF terminal value less terminal capital.
+pub fn economic_profit_valuation(
+    input: &EconomicProfitInput,
+) -> CorpFinanceResult<ComputationOutput<EconomicProfitOutput>> {
+    let start = Instant::now();
+    let mut warnings: Vec<String> = Vec::new();
+
+    if input.opening_invested_capital <= Decimal::ZERO {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "opening_invested_capital".into(),
+            reason: "Opening invested capital must be positive".into(),
+        });
+    }
+    if input.dcf.presentation_currency.is_some() {
+        warnings.push(
+            "Economic profit is reported in the DCF reporting currency; presentation translation is ignored"
+                .into(),
+        );
+    }
+
+    let dcf_out = calculate_dcf(&input.dcf)?;
+    warnings.extend(dcf_out.warnings.iter().map(|w| format!("[DCF] {w}")));
+    let dcf = dcf_out.result;
+    let wacc = dcf.wacc_used;
+
+    let mut years = Vec::with_capacity(dcf.projections.len());
+    let mut invested_capital = input.opening_invested_capital;
+    let mut pv_of_economic_profit = Decimal::ZERO;
+    let mut roic_sum = Decimal::ZERO;
+    let mut negative_capital_year: Option<u32> = None;
+
+    for (i, p) in dcf.projections.iter().enumerate() {
+        let year = (i + 1) as u32;
+        let opening = invested_capital;
+        let net_investment = p.less_capex - p.plus_da + p.less_nwc_change;
+        let closing = opening + net_investment;
+
+        if opening <= Decimal::ZERO {
+            negative_capital_year.get_or_insert(year);
+        }
+        let roic = if opening > Decimal::ZERO {
+            p.nopat / opening
+        } else {
+            Decimal::ZERO
+        };
+        let capital_charge = wacc * opening;
+        let economic_profit = p.nopat - capital_charge;
+        let pv = economic_profit * p.discount_factor;
+
+        pv_of_economic_profit += pv;
+        roic_sum += roic;
+        years.push(EconomicProfitYear {
+            year,
+            opening_invested_capital: opening,
+            net_investment,
+            closing_invested_capital: closing,
+            nopat: p.nopat,
+            roic,
+            spread: roic - wacc,
+            capital_charge,
+            economic_profit,
+            discount_factor: p.discount_factor,
+            pv_economic_profit: pv,
+        });
+        invested_capital = closing;
+    }
+
+    if let Some(y) = negative_capital_year {
+        warnings.push(format!(
+            "Invested capital is not positive at the start of year {y}; ROIC is reported as zero"
+        ));
+    }
+    let destroying: Vec<String> = years
+        .iter()
+        .filter(|y| y.economic_profit < Decimal::ZERO)
+        .map(|y| y.year.to_string())
+        .collect();
+    if !destroying.is_empty() {
+        warnings.push(format!(
+            "ROIC is below WACC in year(s) {}: growth in these years destroys value",
+            destroying.join(", ")
+        ));
+    }
+
+    let n = Decimal::from(years.len() as u32);
+    let terminal_discount_factor = Decimal::ONE / (Decimal::ONE + wacc).powd(n);
+    let continuing_value = dcf.terminal_value_used - invested_capital;
+    let pv_of_continuing_value = continuing_value * terminal_discount_factor;
+
+    // With mid-year factors every explicit-period term carries an extra
+    // (1 + WACC)^0.5, including the capital recovery terms that telescope to
+    // IC_0 - IC_N / (1 + WACC)^N.
+    let mid_year = input.dcf.mid_year_convention.unwrap_or(true);
+    let timing_adjustment = if mid_year {
+        ((Decimal::ONE + wacc).sqrt().unwrap_or(Decimal::ONE) - Decimal::ONE)
+            * (input.opening_invested_capital - invested_capital * terminal_discount_factor)
+    } else {
+        Decimal::ZERO
+    };
+
+    let market_value_added = pv_of_economic_profit + pv_of_continuing_value;
+    let enterprise_value = input.opening_invested_capital + market_value_added + timing_adjustment;
+    let value_creation_pct = if enterprise_value.is_zero() {
+        Decimal::ZERO
+    } else {
+        market_value_added / enterprise_value
+    };
+    let average_roic = if n.is_zero() {
+        Decimal::ZERO
+    } else {
+        roic_sum / n
+    };
+
+    let difference = dcf.enterprise_value - enterprise_value;
+    if difference.abs() > dec!(0.01) {
+        warnings.push(format!(
+            "Economic profit value differs from DCF enterprise value by {}",
+            difference.round_dp(2)
+        ));
+    }
+
+    let output = EconomicProfitOutput {
+        currency: dcf.currency.clone(),
+        wacc_used: wacc,
+        years,
+        opening_invested_capital: input.opening_invested_capital,
+        pv_of_economic_profit,
+        terminal_invested_capital: invested_capital,
+        continuing_value,
+        pv_of_continuing_value,
+        timing_adjustment,
+        market_value_added,
+        enterprise_value,
+        value_creation_pct,
+        average_roic,
+        average_spread: average_roic - wacc,
+        reconciliation: EconomicProfitReconciliation {
+            dcf_enterprise_value: dcf.enterprise_value,
+            economic_profit_enterprise_value: enterprise_value,
+            difference,
+        },
+    };
+
+    let elapsed = start.elapsed().as_micros() as u64;
+    Ok(with_metadata(
+        "Economic Profit (EVA) Valuation",
+        &serde_json::json!({
+            "economic_profit": "NOPAT - WACC x opening invested capital",
+            "invested_capital_roll_forward": "capex - D&A + increase in NWC",
+            "continuing_value": "DCF terminal value - terminal invested capital",
+            "mid_year_convention": mid_year,
+        }),
+        warnings,
+        elapsed,
+        output,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::valuation::dcf::TerminalMethod;
+
+    fn sample_input() -> EconomicProfitInput {
+        EconomicProfitInput {
+            dcf: DcfInput {
+                base_revenue: dec!(1000),
+                revenue_growth_rates: vec![dec!(0.08), dec!(0.06), dec!(0.05)],
+                ebitda_margin: dec!(0.25),
+                ebit_margin: None,
+                da_as_pct_revenue: Some(dec!(0.04)),
+                capex_as_pct_revenue: dec!(0.06),
+                nwc_as_pct_revenue: dec!(0.10),
+                tax_rate: dec!(0.25),
+                wacc: dec!(0.09),
+                wacc_input: None,
+                terminal_method: TerminalMethod::GordonGrowth,
+                terminal_growth_rate: Some(dec!(0.025)),
+                terminal_exit_multiple: None,
+                terminal_ronic: None,
+                currency: Currency::USD,
+                forecast_years: Some(3),
+                mid_year_convention: Some(false),
+                net_debt: None,
+                minority_interest: None,
+                shares_outstanding: None,
+                presentation_currency: None,
+                fx_rates: vec![],
+            },
+            opening_invested_capital: dec!(800),
+        }
+    }
+
+    #[test]
+    fn test_reconciles_to_dcf_end_of_year() {
+        let out = economic_profit_valuation(&sample_input()).unwrap().result;
+        assert!(out.reconciliation.difference.abs() < dec!(0.0001));
+        assert_eq!(out.timing_adjustment, Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_reconciles_to_dcf_mid_year() {
+        let mut input = sample_input();
+        input.dcf.mid_year_convention = Some(true);
+        let out = economic_profit_valuation(&input).unwrap().result;
+        assert!(out.timing_adjustment > Decimal::ZERO);
+        assert!(out.reconciliation.difference.abs() < dec!(0.0001));
+    }
+
+    #[test]
+    fn test_year_one_economic_profit() {
+        let out = economic_profit_valuation(&sample_input()).unwrap().result;
+        let y1 = &out.years[0];
+        // Revenue 1080, EBIT = 1080 x (0.25 - 0.04) = 226.8, NOPAT = 170.1
+        assert_eq!(y1.nopat, dec!(170.1));
+        assert_eq!(y1.capital_charge, dec!(72));
+        assert_eq!(y1.economic_profit, dec!(98.1));
+        assert_eq!(y1.roic, dec!(170.1) / dec!(800));
+        assert_eq!(y1.spread, y1.roic - dec!(0.09));
+    }
+
+    #[test]
+    fn test_invested_capital_rolls_forward() {
+        let out = economic_profit_valuation(&sample_input()).unwrap().result;
+        for pair in out.years.windows(2) {
+            assert_eq!(
+                pair[1].opening_invested_capital,
+                pair[0].closing_invested_capital
+            );
+        }
+        let last = out.years.last().unwrap();
+        assert_eq!(out.terminal_invested_capital, last.closing_invested_capital);
+    }
+
+    #[test]
+    fn test_value_creation_split() {
+        let out = economic_profit_valuation(&sample_input()).unwrap().result;
+        assert!(out.market_value_added > Decimal::ZERO);
+        assert_eq!(
+            out.enterprise_value,
+            out.opening_invested_capital + out.market_value_added + out.timing_adjustment
+        );
+        assert!(out.average_spread > Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_value_destruction_warns() {
+        let mut input = sample_input();
+        input.opening_invested_capital = dec!(5000);
+        let out = economic_profit_valuation(&input).unwrap();
+        assert!(out.result.market_value_added < Decimal::ZERO);
+        assert!(out
+            .warnings
+            .iter()
+            .any(|w| w.contains("ROIC is below WACC")));
+        assert!(out.result.reconciliation.difference.abs() < dec!(0.0001));
+    }
+
+    #[test]
+    fn test_non_positive_capital_rejected() {
+        let mut input = sample_input();
+        input.opening_invested_capital = Decimal::ZERO;
+        assert!(economic_profit_valuation(&input).is_err());
+    }
+}
@@ -1,3 +1,11 @@
+pub mod adjustments;
+pub mod apv;
+pub mod asset_based;
 pub mod comps;
+pub mod cost_of_equity;
 pub mod dcf;
+pub mod economic_profit;
+pub mod peer_screen;
+pub mod rate_consistency;
+pub mod small_business;
 pub mod wacc;
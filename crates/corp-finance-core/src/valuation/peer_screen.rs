@@ -0,0 +1,675 @@
+use rust_decimal::Decimal;
+use rust_decimal::MathematicalOps;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+use std::time::Instant;
+
+use crate::error::CorpFinanceError;
+use crate::types::{with_metadata, ComputationOutput, Money, Rate};
+use crate::valuation::comps::{CompanyMetrics, ComparableCompany};
+use crate::CorpFinanceResult;
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Revenue ratio at which the size score falls to zero when no size band is set.
+const DEFAULT_SIZE_BAND: Decimal = dec!(10);
+/// Growth difference at which the growth score falls to zero.
+const DEFAULT_GROWTH_TOLERANCE: Rate = dec!(0.20);
+/// Margin difference at which the margin score falls to zero.
+const DEFAULT_MARGIN_TOLERANCE: Rate = dec!(0.20);
+/// Sector score when the sector matches but the sub-industry does not.
+const SECTOR_ONLY_SCORE: Decimal = dec!(0.7);
+
+// ---------------------------------------------------------------------------
+// Types
+// ---------------------------------------------------------------------------
+
+/// A company in the screening universe (or the target being screened for).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScreenCompany {
+    /// Company name or ticker
+    pub name: String,
+    /// Sector classification
+    pub sector: String,
+    /// Optional finer industry classification
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sub_industry: Option<String>,
+    /// Optional region / listing market
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub region: Option<String>,
+    /// LTM or forward revenue (size measure)
+    pub revenue: Money,
+    /// Revenue growth rate
+    pub revenue_growth: Rate,
+    /// EBITDA margin
+    pub ebitda_margin: Rate,
+    /// Valuation metrics passed through to the comps module
+    pub metrics: CompanyMetrics,
+}
+
+/// Hard screening filters. Empty lists and `None` bounds mean "no filter".
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ScreeningCriteria {
+    /// Allowed sectors (empty = any)
+    #[serde(default)]
+    pub sectors: Vec<String>,
+    /// Allowed regions (empty = any)
+    #[serde(default)]
+    pub regions: Vec<String>,
+    /// Require the candidate to share the target's sector
+    #[serde(default)]
+    pub require_same_sector: bool,
+    pub min_revenue: Option<Money>,
+    pub max_revenue: Option<Money>,
+    /// Maximum revenue ratio either side of the target (e.g. 4 = 0.25x-4x)
+    pub size_band: Option<Decimal>,
+    pub min_growth: Option<Rate>,
+    pub max_growth: Option<Rate>,
+    pub min_margin: Option<Rate>,
+    pub max_margin: Option<Rate>,
+    /// Minimum overall similarity score to be included
+    pub min_similarity: Option<Decimal>,
+    /// Maximum number of peers returned in the comp set
+    pub max_results: Option<usize>,
+}
+
+/// Relative weights of the similarity components.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SimilarityWeights {
+    pub size: Decimal,
+    pub growth: Decimal,
+    pub margin: Decimal,
+    pub sector: Decimal,
+}
+
+impl Default for SimilarityWeights {
+    fn default() -> Self {
+        Self {
+            size: dec!(0.30),
+            growth: dec!(0.25),
+            margin: dec!(0.25),
+            sector: dec!(0.20),
+        }
+    }
+}
+
+/// Input for peer screening.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PeerScreenInput {
+    /// The company peers are being screened for
+    pub target: ScreenCompany,
+    /// Candidate universe
+    pub universe: Vec<ScreenCompany>,
+    /// Hard filters
+    #[serde(default)]
+    pub criteria: ScreeningCriteria,
+    /// Similarity weights (defaults: size 30%, growth 25%, margin 25%, sector 20%)
+    #[serde(default)]
+    pub weights: Option<SimilarityWeights>,
+}
+
+/// A ranked peer candidate with its similarity breakdown.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PeerCandidate {
+    pub rank: usize,
+    pub name: String,
+    pub similarity_score: Decimal,
+    pub size_score: Decimal,
+    pub growth_score: Decimal,
+    pub margin_score: Decimal,
+    pub sector_score: Decimal,
+    /// Candidate revenue / target revenue
+    pub revenue_ratio: Decimal,
+}
+
+/// A universe company removed by the hard filters.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScreenExclusion {
+    pub name: String,
+    pub reason: String,
+}
+
+/// Output of peer screening.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PeerScreenOutput {
+    /// Ranked candidates, highest similarity first
+    pub candidates: Vec<PeerCandidate>,
+    /// Companies removed by filters
+    pub excluded: Vec<ScreenExclusion>,
+    /// Ready-to-use comparables for `calculate_comps`
+    pub comp_set: Vec<ComparableCompany>,
+}
+
+// ---------------------------------------------------------------------------
+// Public API
+// ---------------------------------------------------------------------------
+
+/// Screen a universe for peers of the target and rank them by similarity.
+pub fn peer_screen(
+    input: &PeerScreenInput,
+) -> CorpFinanceResult<ComputationOutput<PeerScreenOutput>> {
+    let start = Instant::now();
+    let mut warnings: Vec<String> = Vec::new();
+
+    validate_input(input)?;
+
+    let target = &input.target;
+    let criteria = &input.criteria;
+    let weights = input.weights.clone().unwrap_or_default();
+    let weight_sum = weights.size + weights.growth + weights.margin + weights.sector;
+
+    let size_band = criteria.size_band.unwrap_or(DEFAULT_SIZE_BAND);
+    let ln_band = size_band.ln();
+
+    let mut scored: Vec<(PeerCandidate, &ScreenCompany)> = Vec::new();
+    let mut excluded: Vec<ScreenExclusion> = Vec::new();
+
+    for company in &input.universe {
+        if company.name == target.name {
+            continue;
+        }
+        if let Some(reason) = exclusion_reason(company, target, criteria) {
+            excluded.push(ScreenExclusion {
+                name: company.name.clone(),
+                reason,
+            });
+            continue;
+        }
+
+        let revenue_ratio = company.revenue / target.revenue;
+        let size_score = if ln_band > Decimal::ZERO {
+            Decimal::ONE - (revenue_ratio.ln().abs() / ln_band).min(Decimal::ONE)
+        } else {
+            Decimal::ZERO
+        };
+        let growth_score = closeness(
+            company.revenue_growth,
+            target.revenue_growth,
+            DEFAULT_GROWTH_TOLERANCE,
+        );
+        let margin_score = closeness(
+            company.ebitda_margin,
+            target.ebitda_margin,
+            DEFAULT_MARGIN_TOLERANCE,
+        );
+        let sector_score = sector_similarity(company, target);
+
+        let similarity_score = (weights.size * size_score
+            + weights.growth * growth_score
+            + weights.margin * margin_score
+            + weights.sector * sector_score)
+            / weight_sum;
+
+        if let Some(min) = criteria.min_similarity {
+            if similarity_score < min {
+                excluded.push(ScreenExclusion {
+                    name: company.name.clone(),
+                    reason: format!(
+                        "Similarity {} below minimum {min}",
+                        similarity_score.round_dp(4)
+                    ),
+                });
+                continue;
+            }
+        }
+
+        scored.push((
+            PeerCandidate {
+                rank: 0,
+                name: company.name.clone(),
+                similarity_score,
+                size_score,
+                growth_score,
+                margin_score,
+                sector_score,
+                revenue_ratio,
+            },
+            company,
+        ));
+    }
+
+    scored.sort_by(|a, b| {
+        b.0.similarity_score
+            .cmp(&a.0.similarity_score)
+            .then_with(|| a.0.name.cmp(&b.0.name))
+    });
+    if let Some(max) = criteria.max_results {
+        scored.truncate(max);
+    }
+
+    let mut candidates = Vec::with_capacity(scored.len());
+    let mut comp_set = Vec::with_capacity(scored.len());
+    for (i, (mut candidate, company)) in scored.into_iter().enumerate() {
+        candidate.rank = i + 1;
+        comp_set.push(ComparableCompany {
+            name: company.name.clone(),
+            metrics: company.metrics.clone(),
+            include: true,
+        });
+        candidates.push(candidate);
+    }
+
+    if candidates.is_empty() {
+        warnings.push("No companies in the universe passed the screen".into());
+    } else if candidates.len() < 3 {
+        warnings.push(format!(
+            "Only {} peers passed the screen; consider relaxing the criteria",
+            candidates.len()
+        ));
+    }
+
+    let output = PeerScreenOutput {
+        candidates,
+        excluded,
+        comp_set,
+    };
+
+    let elapsed = start.elapsed().as_micros() as u64;
+
+    Ok(with_metadata(
+        "Peer Screening (filtered universe ranked by weighted size/growth/margin/sector similarity)",
+        &serde_json::json!({
+            "target": target.name,
+            "universe_size": input.universe.len(),
+            "size_band": size_band.to_string(),
+            "weights": weights,
+        }),
+        warnings,
+        elapsed,
+        output,
+    ))
+}
+
+// ---------------------------------------------------------------------------
+// Internal helpers
+// ---------------------------------------------------------------------------
+
+/// Returns the first hard filter the company fails, if any.
+fn exclusion_reason(
+    company: &ScreenCompany,
+    target: &ScreenCompany,
+    criteria: &ScreeningCriteria,
+) -> Option<String> {
+    if !criteria.sectors.is_empty() && !criteria.sectors.contains(&company.sector) {
+        return Some(format!("Sector '{}' not in screen", company.sector));
+    }
+    if criteria.require_same_sector && company.sector != target.sector {
+        return Some(format!("Sector '{}' differs from target", company.sector));
+    }
+    if !criteria.regions.is_empty()
+        && !company
+            .region
+            .as_ref()
+            .is_some_and(|r| criteria.regions.contains(r))
+    {
+        return Some("Region not in screen".into());
+    }
+    if company.revenue <= Decimal::ZERO {
+        return Some("Non-positive revenue".into());
+    }
+    if criteria
+        .min_revenue
+        .is_some_and(|min| company.revenue < min)
+    {
+        return Some("Revenue below minimum".into());
+    }
+    if criteria
+        .max_revenue
+        .is_some_and(|max| company.revenue > max)
+    {
+        return Some("Revenue above maximum".into());
+    }
+    if let Some(band) = criteria.size_band {
+        let ratio = company.revenue / target.revenue;
+        if ratio > band || ratio < Decimal::ONE / band {
+            return Some(format!(
+                "Revenue {}x target outside size band",
+                ratio.round_dp(2)
+            ));
+        }
+    }
+    if criteria
+        .min_growth
+        .is_some_and(|min| company.revenue_growth < min)
+    {
+        return Some("Growth below minimum".into());
+    }
+    if criteria
+        .max_growth
+        .is_some_and(|max| company.revenue_growth > max)
+    {
+        return Some("Growth above maximum".into());
+    }
+    if criteria
+        .min_margin
+        .is_some_and(|min| company.ebitda_margin < min)
+    {
+        return Some("EBITDA margin below minimum".into());
+    }
+    if criteria
+        .max_margin
+        .is_some_and(|max| company.ebitda_margin > max)
+    {
+        return Some("EBITDA margin above maximum".into());
+    }
+    None
+}
+
+/// Linear closeness score: 1 when equal, 0 at or beyond the tolerance.
+fn closeness(value: Decimal, target: Decimal, tolerance: Decimal) -> Decimal {
+    Decimal::ONE - ((value - target).abs() / tolerance).min(Decimal::ONE)
+}
+
+fn sector_similarity(company: &ScreenCompany, target: &ScreenCompany) -> Decimal {
+    if company.sector != target.sector {
+        return Decimal::ZERO;
+    }
+    match (&company.sub_industry, &target.sub_industry) {
+        (Some(a), Some(b)) if a == b => Decimal::ONE,
+        (None, None) => Decimal::ONE,
+        _ => SECTOR_ONLY_SCORE,
+    }
+}
+
+fn validate_input(input: &PeerScreenInput) -> CorpFinanceResult<()> {
+    if input.universe.is_empty() {
+        return Err(CorpFinanceError::InsufficientData(
+            "Screening universe is empty".into(),
+        ));
+    }
+    if input.target.revenue <= Decimal::ZERO {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "target.revenue".into(),
+            reason: "Target revenue must be positive to measure relative size".into(),
+        });
+    }
+    if let Some(band) = input.criteria.size_band {
+        if band <= Decimal::ONE {
+            return Err(CorpFinanceError::InvalidInput {
+                field: "criteria.size_band".into(),
+                reason: "Size band must be greater than 1".into(),
+            });
+        }
+    }
+    if let Some(w) = &input.weights {
+        let parts = [w.size, w.growth, w.margin, w.sector];
+        if parts.iter().any(|x| *x < Decimal::ZERO) {
+            return Err(CorpFinanceError::InvalidInput {
+                field: "weights".into(),
+                reason: "Similarity weights must be non-negative".into(),
+            });
+        }
+        if parts.iter().copied().sum::<Decimal>().is_zero() {
+            return Err(CorpFinanceError::InvalidInput {
+                field: "weights".into(),
+                reason: "At least one similarity weight must be positive".into(),
+            });
+        }
+    }
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn metrics() -> CompanyMetrics {
+        CompanyMetrics {
+            enterprise_value: Some(dec!(1000)),
+            market_cap: Some(dec!(800)),
+            revenue: Some(dec!(500)),
+            ebitda: Some(dec!(100)),
+            ebit: None,
+            net_income: None,
+            book_value: None,
+            eps: None,
+            eps_growth_rate: None,
+            share_price: None,
+            basic_shares: None,
+            dilutive_securities: vec![],
+            kpis: Default::default(),
+        }
+    }
+
+    fn company(
+        name: &str,
+        sector: &str,
+        revenue: Decimal,
+        growth: Decimal,
+        margin: Decimal,
+    ) -> ScreenCompany {
+        ScreenCompany {
+            name: name.into(),
+            sector: sector.into(),
+            sub_industry: None,
+            region: Some("US".into()),
+            revenue,
+            revenue_growth: growth,
+            ebitda_margin: margin,
+            metrics: metrics(),
+        }
+    }
+
+    fn sample_input() -> PeerScreenInput {
+        PeerScreenInput {
+            target: company("Target", "Software", dec!(500), dec!(0.15), dec!(0.25)),
+            universe: vec![
+                company("Twin", "Software", dec!(500), dec!(0.15), dec!(0.25)),
+                company("Bigger", "Software", dec!(2000), dec!(0.12), dec!(0.22)),
+                company("SlowCo", "Software", dec!(450), dec!(0.02), dec!(0.10)),
+                company("Bank", "Financials", dec!(600), dec!(0.05), dec!(0.40)),
+                company("Tiny", "Software", dec!(20), dec!(0.40), dec!(-0.10)),
+            ],
+            criteria: ScreeningCriteria::default(),
+            weights: None,
+        }
+    }
+
+    fn find<'a>(out: &'a PeerScreenOutput, name: &str) -> &'a PeerCandidate {
+        out.candidates.iter().find(|c| c.name == name).unwrap()
+    }
+
+    #[test]
+    fn test_identical_company_scores_one() {
+        let out = peer_screen(&sample_input()).unwrap();
+        let twin = find(&out.result, "Twin");
+        assert_eq!(twin.similarity_score, Decimal::ONE);
+        assert_eq!(twin.rank, 1);
+    }
+
+    #[test]
+    fn test_ranking_descending() {
+        let out = peer_screen(&sample_input()).unwrap();
+        let scores: Vec<Decimal> = out
+            .result
+            .candidates
+            .iter()
+            .map(|c| c.similarity_score)
+            .collect();
+        assert!(scores.windows(2).all(|w| w[0] >= w[1]));
+        let ranks: Vec<usize> = out.result.candidates.iter().map(|c| c.rank).collect();
+        assert_eq!(ranks, vec![1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_target_excluded_from_universe() {
+        let mut input = sample_input();
+        input.universe.push(input.target.clone());
+        let out = peer_screen(&input).unwrap();
+        assert!(out.result.candidates.iter().all(|c| c.name != "Target"));
+    }
+
+    #[test]
+    fn test_size_score_log_scale() {
+        let out = peer_screen(&sample_input()).unwrap();
+        let bigger = find(&out.result, "Bigger");
+        // ln(4) / ln(10) = 0.60206 -> 0.39794
+        assert!((bigger.size_score - dec!(0.39794)).abs() < dec!(0.0001));
+        assert_eq!(bigger.revenue_ratio, dec!(4));
+    }
+
+    #[test]
+    fn test_growth_and_margin_scores() {
+        let out = peer_screen(&sample_input()).unwrap();
+        let slow = find(&out.result, "SlowCo");
+        // |0.02 - 0.15| / 0.20 = 0.65 -> 0.35
+        assert_eq!(slow.growth_score, dec!(0.35));
+        // |0.10 - 0.25| / 0.20 = 0.75 -> 0.25
+        assert_eq!(slow.margin_score, dec!(0.25));
+    }
+
+    #[test]
+    fn test_sector_mismatch_scores_zero() {
+        let out = peer_screen(&sample_input()).unwrap();
+        assert_eq!(find(&out.result, "Bank").sector_score, Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_sub_industry_partial_match() {
+        let mut input = sample_input();
+        input.target.sub_industry = Some("Vertical SaaS".into());
+        input.universe[0].sub_industry = Some("Horizontal SaaS".into());
+        input.universe[1].sub_industry = Some("Vertical SaaS".into());
+        let out = peer_screen(&input).unwrap();
+        assert_eq!(find(&out.result, "Twin").sector_score, dec!(0.7));
+        assert_eq!(find(&out.result, "Bigger").sector_score, Decimal::ONE);
+    }
+
+    #[test]
+    fn test_require_same_sector_filter() {
+        let mut input = sample_input();
+        input.criteria.require_same_sector = true;
+        let out = peer_screen(&input).unwrap();
+        assert!(out.result.candidates.iter().all(|c| c.name != "Bank"));
+        assert!(out.result.excluded.iter().any(|e| e.name == "Bank"));
+    }
+
+    #[test]
+    fn test_size_band_filter() {
+        let mut input = sample_input();
+        input.criteria.size_band = Some(dec!(3));
+        let out = peer_screen(&input).unwrap();
+        let names: Vec<&str> = out
+            .result
+            .excluded
+            .iter()
+            .map(|e| e.name.as_str())
+            .collect();
+        assert!(names.contains(&"Bigger"));
+        assert!(names.contains(&"Tiny"));
+    }
+
+    #[test]
+    fn test_margin_and_growth_bounds() {
+        let mut input = sample_input();
+        input.criteria.min_margin = Some(dec!(0.0));
+        input.criteria.max_growth = Some(dec!(0.30));
+        let out = peer_screen(&input).unwrap();
+        assert!(out.result.excluded.iter().any(|e| e.name == "Tiny"));
+    }
+
+    #[test]
+    fn test_region_filter() {
+        let mut input = sample_input();
+        input.universe[1].region = Some("EU".into());
+        input.criteria.regions = vec!["US".into()];
+        let out = peer_screen(&input).unwrap();
+        assert!(out.result.excluded.iter().any(|e| e.name == "Bigger"));
+    }
+
+    #[test]
+    fn test_min_similarity_and_max_results() {
+        let mut input = sample_input();
+        input.criteria.min_similarity = Some(dec!(0.5));
+        input.criteria.max_results = Some(2);
+        let out = peer_screen(&input).unwrap();
+        assert!(out.result.candidates.len() <= 2);
+        assert!(out
+            .result
+            .candidates
+            .iter()
+            .all(|c| c.similarity_score >= dec!(0.5)));
+    }
+
+    #[test]
+    fn test_comp_set_matches_candidates() {
+        let out = peer_screen(&sample_input()).unwrap();
+        assert_eq!(out.result.comp_set.len(), out.result.candidates.len());
+        for (comp, cand) in out.result.comp_set.iter().zip(&out.result.candidates) {
+            assert_eq!(comp.name, cand.name);
+            assert!(comp.include);
+        }
+    }
+
+    #[test]
+    fn test_comp_set_feeds_calculate_comps() {
+        use crate::types::Currency;
+        use crate::valuation::comps::{calculate_comps, CompsInput, MultipleType};
+        let out = peer_screen(&sample_input()).unwrap();
+        let comps = CompsInput {
+            target_name: "Target".into(),
+            target_metrics: metrics(),
+            comparables: out.result.comp_set,
+            multiples: vec![MultipleType::EvEbitda],
+            currency: Currency::USD,
+            kpi_multiples: vec![],
+            kpi_regression: None,
+        };
+        let result = calculate_comps(&comps).unwrap();
+        assert_eq!(result.result.multiple_statistics[0].median, dec!(10));
+    }
+
+    #[test]
+    fn test_custom_weights_size_only() {
+        let mut input = sample_input();
+        input.weights = Some(SimilarityWeights {
+            size: Decimal::ONE,
+            growth: Decimal::ZERO,
+            margin: Decimal::ZERO,
+            sector: Decimal::ZERO,
+        });
+        let out = peer_screen(&input).unwrap();
+        let slow = find(&out.result, "SlowCo");
+        assert_eq!(slow.similarity_score, slow.size_score);
+    }
+
+    #[test]
+    fn test_empty_screen_warns() {
+        let mut input = sample_input();
+        input.criteria.sectors = vec!["Utilities".into()];
+        let out = peer_screen(&input).unwrap();
+        assert!(out.result.candidates.is_empty());
+        assert!(!out.warnings.is_empty());
+    }
+
+    #[test]
+    fn test_invalid_inputs() {
+        let mut input = sample_input();
+        input.universe.clear();
+        assert!(peer_screen(&input).is_err());
+
+        let mut input = sample_input();
+        input.target.revenue = Decimal::ZERO;
+        assert!(peer_screen(&input).is_err());
+
+        let mut input = sample_input();
+        input.criteria.size_band = Some(dec!(0.5));
+        assert!(peer_screen(&input).is_err());
+
+        let mut input = sample_input();
+        input.weights = Some(SimilarityWeights {
+            size: Decimal::ZERO,
+            growth: Decimal::ZERO,
+            margin: Decimal::ZERO,
+            sector: Decimal::ZERO,
+        });
+        assert!(peer_screen(&input).is_err());
+    }
+}
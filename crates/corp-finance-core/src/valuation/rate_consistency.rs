@@ -0,0 +1,466 @@
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+use std::time::Instant;
+
+use crate::error::CorpFinanceError;
+use crate::types::{with_metadata, ComputationOutput, Currency, Rate};
+use crate::CorpFinanceResult;
+
+// ---------------------------------------------------------------------------
+// Types
+// ---------------------------------------------------------------------------
+
+/// Whether a rate or cash flow stream includes expected inflation.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RateBasis {
+    #[default]
+    Nominal,
+    Real,
+}
+
+/// Expected inflation for a currency.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CurrencyInflation {
+    pub currency: Currency,
+    pub expected_inflation: Rate,
+}
+
+/// Description of the cash flows being discounted.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CashFlowBasis {
+    pub currency: Currency,
+    #[serde(default)]
+    pub basis: RateBasis,
+    /// Inflation built into a nominal forecast (price escalators, wage growth)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub embedded_inflation: Option<Rate>,
+    /// Perpetual growth rate, on the same basis as the cash flows
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub terminal_growth_rate: Option<Rate>,
+}
+
+/// Description of the discount rate as estimated.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DiscountRateBasis {
+    pub rate: Rate,
+    pub currency: Currency,
+    #[serde(default)]
+    pub basis: RateBasis,
+}
+
+/// Input for a cash flow / discount rate consistency check.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RateConsistencyInput {
+    pub cash_flows: CashFlowBasis,
+    pub discount_rate: DiscountRateBasis,
+    /// Expected inflation for each currency involved
+    pub inflation: Vec<CurrencyInflation>,
+    /// Gap between embedded and assumed inflation that is flagged (default 0.5%)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tolerance: Option<Rate>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum IssueSeverity {
+    /// Valuation is wrong as specified
+    Error,
+    /// Corrected by the restated rate, or worth reviewing
+    Warning,
+}
+
+/// A detected inconsistency.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConsistencyIssue {
+    pub code: String,
+    pub severity: IssueSeverity,
+    pub message: String,
+}
+
+/// One step of the rate restatement.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConversionStep {
+    pub description: String,
+    pub from_rate: Rate,
+    pub to_rate: Rate,
+}
+
+/// Output of the consistency check.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RateConsistencyOutput {
+    /// Discount rate restated to the currency and basis of the cash flows
+    pub consistent_discount_rate: Rate,
+    /// Real rate implied by the discount rate as given
+    pub real_discount_rate: Rate,
+    pub conversion_steps: Vec<ConversionStep>,
+    pub issues: Vec<ConsistencyIssue>,
+    /// True when the inputs were already consistent (no issues at all)
+    pub consistent: bool,
+}
+
+// ---------------------------------------------------------------------------
+// Conversions
+// ---------------------------------------------------------------------------
+
+/// Fisher relation: (1 + nominal) = (1 + real)(1 + inflation).
+pub fn nominal_to_real(nominal: Rate, inflation: Rate) -> Rate {
+    (Decimal::ONE + nominal) / (Decimal::ONE + inflation) - Decimal::ONE
+}
+
+/// Inverse Fisher relation.
+pub fn real_to_nominal(real: Rate, inflation: Rate) -> Rate {
+    (Decimal::ONE + real) * (Decimal::ONE + inflation) - Decimal::ONE
+}
+
+/// Restate a nominal rate from one currency to another using relative
+/// inflation (international Fisher effect):
+/// (1 + k_to) = (1 + k_from) x (1 + inflation_to) / (1 + inflation_from).
+pub fn convert_rate_across_currencies(
+    rate: Rate,
+    from_inflation: Rate,
+    to_inflation: Rate,
+) -> Rate {
+    (Decimal::ONE + rate) * (Decimal::ONE + to_inflation) / (Decimal::ONE + from_inflation)
+        - Decimal::ONE
+}
+
+// ---------------------------------------------------------------------------
+// Public API
+// ---------------------------------------------------------------------------
+
+/// Check that cash flows and the discount rate share a currency and an
+/// inflation basis, restating the rate where they do not and flagging
+/// assumptions that cannot both be right.
+pub fn check_rate_consistency(
+    input: &RateConsistencyInput,
+) -> CorpFinanceResult<ComputationOutput<RateConsistencyOutput>> {
+    let start = Instant::now();
+    let warnings: Vec<String> = Vec::new();
+    let tolerance = input.tolerance.unwrap_or(dec!(0.005));
+
+    let cf = &input.cash_flows;
+    let dr = &input.discount_rate;
+    if dr.rate <= dec!(-1) {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "discount_rate.rate".into(),
+            reason: "Discount rate must exceed -100%".into(),
+        });
+    }
+    for ci in &input.inflation {
+        if ci.expected_inflation <= dec!(-1) {
+            return Err(CorpFinanceError::InvalidInput {
+                field: "inflation".into(),
+                reason: format!("Inflation for {:?} must exceed -100%", ci.currency),
+            });
+        }
+    }
+
+    let inflation_for = |currency: &Currency| -> CorpFinanceResult<Rate> {
+        input
+            .inflation
+            .iter()
+            .find(|ci| &ci.currency == currency)
+            .map(|ci| ci.expected_inflation)
+            .ok_or_else(|| {
+                CorpFinanceError::InsufficientData(format!(
+                    "Expected inflation for {currency:?} is required"
+                ))
+            })
+    };
+
+    let mut issues: Vec<ConsistencyIssue> = Vec::new();
+    let mut steps: Vec<ConversionStep> = Vec::new();
+
+    // --- Real rate implied by the discount rate as given ---
+    let rate_inflation = inflation_for(&dr.currency)?;
+    let real_discount_rate = match dr.basis {
+        RateBasis::Real => dr.rate,
+        RateBasis::Nominal => nominal_to_real(dr.rate, rate_inflation),
+    };
+
+    // --- Restate to the cash flow currency (nominal) ---
+    let mut nominal = match dr.basis {
+        RateBasis::Nominal => dr.rate,
+        RateBasis::Real => {
+            let n = real_to_nominal(dr.rate, rate_inflation);
+            if cf.currency == dr.currency && cf.basis == RateBasis::Real {
+                // Already on the cash flow basis; no restatement to report
+                n
+            } else {
+                steps.push(ConversionStep {
+                    description: format!(
+                        "Real to nominal {:?} at {rate_inflation} inflation",
+                        dr.currency
+                    ),
+                    from_rate: dr.rate,
+                    to_rate: n,
+                });
+                n
+            }
+        }
+    };
+    let cf_inflation = if cf.currency != dr.currency {
+        let cf_inflation = inflation_for(&cf.currency)?;
+        let converted = convert_rate_across_currencies(nominal, rate_inflation, cf_inflation);
+        steps.push(ConversionStep {
+            description: format!(
+                "{:?} to {:?} via relative inflation ({rate_inflation} vs {cf_inflation})",
+                dr.currency, cf.currency
+            ),
+            from_rate: nominal,
+            to_rate: converted,
+        });
+        issues.push(ConsistencyIssue {
+            code: "CURRENCY_MISMATCH".into(),
+            severity: IssueSeverity::Warning,
+            message: format!(
+                "Discount rate is a {:?} rate but cash flows are in {:?}; use the restated rate",
+                dr.currency, cf.currency
+            ),
+        });
+        nominal = converted;
+        cf_inflation
+    } else {
+        rate_inflation
+    };
+
+    // --- Match the cash flow basis ---
+    let consistent_discount_rate = match cf.basis {
+        RateBasis::Nominal => nominal,
+        RateBasis::Real if dr.basis == RateBasis::Real && cf.currency == dr.currency => dr.rate,
+        RateBasis::Real => {
+            let real = nominal_to_real(nominal, cf_inflation);
+            steps.push(ConversionStep {
+                description: format!(
+                    "Nominal to real {:?} at {cf_inflation} inflation",
+                    cf.currency
+                ),
+                from_rate: nominal,
+                to_rate: real,
+            });
+            real
+        }
+    };
+    if cf.basis != dr.basis {
+        issues.push(ConsistencyIssue {
+            code: "BASIS_MISMATCH".into(),
+            severity: IssueSeverity::Warning,
+            message: format!(
+                "{:?} cash flows with a {:?} discount rate; use the restated rate",
+                cf.basis, dr.basis
+            ),
+        });
+    }
+
+    // --- Inflation embedded in the forecast ---
+    if let Some(embedded) = cf.embedded_inflation {
+        match cf.basis {
+            RateBasis::Real => {
+                if embedded.abs() > tolerance {
+                    issues.push(ConsistencyIssue {
+                        code: "REAL_FORECAST_WITH_INFLATION".into(),
+                        severity: IssueSeverity::Error,
+                        message: format!(
+                            "Cash flows are labelled real but embed {embedded} inflation"
+                        ),
+                    });
+                }
+            }
+            RateBasis::Nominal => {
+                if (embedded - cf_inflation).abs() > tolerance {
+                    issues.push(ConsistencyIssue {
+                        code: "INFLATION_MISMATCH".into(),
+                        severity: IssueSeverity::Error,
+                        message: format!(
+                            "Forecast embeds {embedded} inflation but the discount rate implies {cf_inflation} for {:?}",
+                            cf.currency
+                        ),
+                    });
+                }
+            }
+        }
+    }
+
+    // --- Terminal growth ---
+    if let Some(g) = cf.terminal_growth_rate {
+        if g >= consistent_discount_rate {
+            issues.push(ConsistencyIssue {
+                code: "GROWTH_EXCEEDS_RATE".into(),
+                severity: IssueSeverity::Error,
+                message: format!(
+                    "Terminal growth {g} is not below the consistent discount rate {consistent_discount_rate}"
+                ),
+            });
+        }
+        let real_growth = match cf.basis {
+            RateBasis::Real => g,
+            RateBasis::Nominal => nominal_to_real(g, cf_inflation),
+        };
+        if cf.basis == RateBasis::Nominal && real_growth < Decimal::ZERO {
+            issues.push(ConsistencyIssue {
+                code: "NEGATIVE_REAL_GROWTH".into(),
+                severity: IssueSeverity::Warning,
+                message: format!(
+                    "Nominal terminal growth {g} is below {cf_inflation} inflation, implying a real decline in perpetuity"
+                ),
+            });
+        }
+    }
+
+    if real_discount_rate < Decimal::ZERO {
+        issues.push(ConsistencyIssue {
+            code: "NEGATIVE_REAL_RATE".into(),
+            severity: IssueSeverity::Warning,
+            message: format!("Discount rate implies a negative real rate of {real_discount_rate}"),
+        });
+    }
+
+    let output = RateConsistencyOutput {
+        consistent_discount_rate,
+        real_discount_rate,
+        conversion_steps: steps,
+        consistent: issues.is_empty(),
+        issues,
+    };
+
+    let elapsed = start.elapsed().as_micros() as u64;
+    Ok(with_metadata(
+        "Discount Rate Consistency (Fisher relation)",
+        &serde_json::json!({
+            "fisher": "(1 + nominal) = (1 + real)(1 + inflation)",
+            "cross_currency": "(1 + k_to) = (1 + k_from)(1 + inflation_to) / (1 + inflation_from)",
+            "tolerance": tolerance.to_string(),
+        }),
+        warnings,
+        elapsed,
+        output,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn inflation() -> Vec<CurrencyInflation> {
+        vec![
+            CurrencyInflation {
+                currency: Currency::USD,
+                expected_inflation: dec!(0.02),
+            },
+            CurrencyInflation {
+                currency: Currency::Other("BRL".into()),
+                expected_inflation: dec!(0.05),
+            },
+        ]
+    }
+
+    fn usd_input() -> RateConsistencyInput {
+        RateConsistencyInput {
+            cash_flows: CashFlowBasis {
+                currency: Currency::USD,
+                basis: RateBasis::Nominal,
+                embedded_inflation: Some(dec!(0.02)),
+                terminal_growth_rate: Some(dec!(0.025)),
+            },
+            discount_rate: DiscountRateBasis {
+                rate: dec!(0.09),
+                currency: Currency::USD,
+                basis: RateBasis::Nominal,
+            },
+            inflation: inflation(),
+            tolerance: None,
+        }
+    }
+
+    #[test]
+    fn test_fisher_round_trip() {
+        let real = nominal_to_real(dec!(0.0914), dec!(0.02));
+        assert_eq!(real, dec!(0.07));
+        assert_eq!(real_to_nominal(real, dec!(0.02)), dec!(0.0914));
+    }
+
+    #[test]
+    fn test_consistent_inputs_pass() {
+        let out = check_rate_consistency(&usd_input()).unwrap().result;
+        assert!(out.consistent);
+        assert_eq!(out.consistent_discount_rate, dec!(0.09));
+        assert!(out.conversion_steps.is_empty());
+    }
+
+    #[test]
+    fn test_cross_currency_restatement() {
+        let mut input = usd_input();
+        input.cash_flows.currency = Currency::Other("BRL".into());
+        input.cash_flows.embedded_inflation = Some(dec!(0.05));
+        let out = check_rate_consistency(&input).unwrap().result;
+        // 1.09 x 1.05 / 1.02 - 1
+        let expected = dec!(1.09) * dec!(1.05) / dec!(1.02) - Decimal::ONE;
+        assert_eq!(out.consistent_discount_rate, expected);
+        assert!(out.issues.iter().any(|i| i.code == "CURRENCY_MISMATCH"));
+        assert!(!out
+            .issues
+            .iter()
+            .any(|i| i.severity == IssueSeverity::Error));
+    }
+
+    #[test]
+    fn test_real_cash_flows_with_nominal_rate() {
+        let mut input = usd_input();
+        input.cash_flows.basis = RateBasis::Real;
+        input.cash_flows.embedded_inflation = None;
+        input.cash_flows.terminal_growth_rate = Some(dec!(0.01));
+        let out = check_rate_consistency(&input).unwrap().result;
+        assert_eq!(
+            out.consistent_discount_rate,
+            nominal_to_real(dec!(0.09), dec!(0.02))
+        );
+        assert!(out.issues.iter().any(|i| i.code == "BASIS_MISMATCH"));
+    }
+
+    #[test]
+    fn test_inflation_mismatch_flagged() {
+        let mut input = usd_input();
+        input.cash_flows.embedded_inflation = Some(dec!(0.05));
+        let out = check_rate_consistency(&input).unwrap().result;
+        let issue = out
+            .issues
+            .iter()
+            .find(|i| i.code == "INFLATION_MISMATCH")
+            .unwrap();
+        assert_eq!(issue.severity, IssueSeverity::Error);
+        assert!(!out.consistent);
+    }
+
+    #[test]
+    fn test_terminal_growth_checks() {
+        let mut input = usd_input();
+        input.cash_flows.terminal_growth_rate = Some(dec!(0.01));
+        let out = check_rate_consistency(&input).unwrap().result;
+        assert!(out.issues.iter().any(|i| i.code == "NEGATIVE_REAL_GROWTH"));
+
+        input.cash_flows.terminal_growth_rate = Some(dec!(0.10));
+        let out = check_rate_consistency(&input).unwrap().result;
+        assert!(out.issues.iter().any(|i| i.code == "GROWTH_EXCEEDS_RATE"));
+    }
+
+    #[test]
+    fn test_missing_inflation_errors() {
+        let mut input = usd_input();
+        input.cash_flows.currency = Currency::EUR;
+        assert!(check_rate_consistency(&input).is_err());
+    }
+
+    #[test]
+    fn test_real_rate_and_real_flows_unchanged() {
+        let mut input = usd_input();
+        input.cash_flows.basis = RateBasis::Real;
+        input.cash_flows.embedded_inflation = None;
+        input.cash_flows.terminal_growth_rate = None;
+        input.discount_rate.basis = RateBasis::Real;
+        input.discount_rate.rate = dec!(0.065);
+        let out = check_rate_consistency(&input).unwrap().result;
+        assert!(out.consistent);
+        assert_eq!(out.consistent_discount_rate, dec!(0.065));
+        assert_eq!(out.real_discount_rate, dec!(0.065));
+    }
+}
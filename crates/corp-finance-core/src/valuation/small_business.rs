@@ -0,0 +1,778 @@
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+use std::time::Instant;
+
+use crate::error::CorpFinanceError;
+use crate::types::{with_metadata, ComputationOutput, Money, Multiple, Rate};
+use crate::valuation::cost_of_equity::BuildUpStep;
+use crate::CorpFinanceResult;
+
+/// Weighted SDE below which the SDE bands apply by default; larger
+/// businesses are priced on normalized EBITDA.
+const SDE_BASIS_CEILING: Money = dec!(1_000_000);
+
+// ---------------------------------------------------------------------------
+// Types
+// ---------------------------------------------------------------------------
+
+/// A normalization adjustment: positive amounts are added back to pre-tax
+/// income, negative amounts deducted.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NormalizationAdjustment {
+    pub description: String,
+    pub amount: Money,
+}
+
+/// One historical fiscal year of the subject business.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EarningsPeriod {
+    pub label: String,
+    pub revenue: Money,
+    pub pre_tax_income: Money,
+    /// Salary, bonus and payroll taxes of one working owner
+    #[serde(default)]
+    pub owner_compensation: Money,
+    /// Owner perquisites run through the business (vehicle, insurance,
+    /// retirement contributions)
+    #[serde(default)]
+    pub owner_benefits: Money,
+    #[serde(default)]
+    pub interest_expense: Money,
+    #[serde(default)]
+    pub depreciation_amortization: Money,
+    /// Non-recurring, non-operating or discretionary items
+    #[serde(default)]
+    pub adjustments: Vec<NormalizationAdjustment>,
+    /// Weight in the normalized average (default: 1 for the oldest year
+    /// rising by 1 to the latest)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub weight: Option<Decimal>,
+}
+
+/// Earnings measure a multiple is applied to.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EarningsBasis {
+    /// Seller's discretionary earnings: one owner's compensation included
+    #[default]
+    Sde,
+    /// SDE less a market wage for a replacement manager
+    Ebitda,
+}
+
+/// A market multiple range for businesses of a given size.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MultipleBand {
+    /// Industry the range applies to; omitted for all industries
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub industry: Option<String>,
+    pub basis: EarningsBasis,
+    /// Smallest earnings (on `basis`) in the band
+    pub min_earnings: Money,
+    /// Earnings ceiling (exclusive); omitted for the top band
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_earnings: Option<Money>,
+    pub low: Multiple,
+    pub mid: Multiple,
+    pub high: Multiple,
+}
+
+/// Simplified build-up of the equity discount rate.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BuildUpRate {
+    pub risk_free_rate: Rate,
+    pub equity_risk_premium: Rate,
+    pub size_premium: Rate,
+    #[serde(default)]
+    pub industry_risk_premium: Rate,
+    #[serde(default)]
+    pub company_specific_risk_premium: Rate,
+    /// Long-term growth of normalized cash flow
+    pub long_term_growth: Rate,
+}
+
+/// Input for a small business valuation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SmallBusinessInput {
+    pub business_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub industry: Option<String>,
+    /// Historical years, oldest first
+    pub periods: Vec<EarningsPeriod>,
+    /// Market wage and payroll taxes for a manager replacing the owner
+    pub replacement_compensation: Money,
+    pub build_up: BuildUpRate,
+    /// Entity-level tax on normalized earnings (0 for pass-through entities
+    /// valued pre-tax)
+    #[serde(default)]
+    pub tax_rate: Rate,
+    /// Annual capital expenditure to sustain the business (default the
+    /// latest year's depreciation)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maintenance_capex: Option<Money>,
+    /// Market multiple ranges; generic size bands are used when empty
+    #[serde(default)]
+    pub multiple_bands: Vec<MultipleBand>,
+    /// Weight on the market approach in the conclusion (default 0.5)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub market_weight: Option<Rate>,
+    /// Debt assumed by the buyer or repaid from proceeds
+    #[serde(default)]
+    pub interest_bearing_debt: Money,
+    /// Cash above normal working capital
+    #[serde(default)]
+    pub excess_cash: Money,
+}
+
+/// A year after normalization.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NormalizedPeriod {
+    pub label: String,
+    pub revenue: Money,
+    pub pre_tax_income: Money,
+    pub total_addbacks: Money,
+    pub sde: Money,
+    pub ebitda: Money,
+    pub sde_margin: Rate,
+    pub weight: Decimal,
+}
+
+/// Capitalized cash flow (single-period income) method.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IncomeApproach {
+    /// Normalized EBITDA less maintenance capex and entity tax
+    pub normalized_cash_flow: Money,
+    pub next_year_cash_flow: Money,
+    pub discount_rate: Rate,
+    /// Discount rate less long-term growth
+    pub capitalization_rate: Rate,
+    pub value: Money,
+}
+
+/// Market multiple method.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MarketApproach {
+    pub basis: EarningsBasis,
+    pub earnings: Money,
+    /// Band used, as supplied or from the generic table
+    pub band: MultipleBand,
+    pub generic_band: bool,
+    pub value_low: Money,
+    pub value_mid: Money,
+    pub value_high: Money,
+}
+
+/// Output of the small business valuation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SmallBusinessOutput {
+    pub periods: Vec<NormalizedPeriod>,
+    pub weighted_sde: Money,
+    pub weighted_ebitda: Money,
+    pub build_up: Vec<BuildUpStep>,
+    pub income_approach: IncomeApproach,
+    pub market_approach: MarketApproach,
+    pub market_weight: Rate,
+    /// Cash-free, debt-free value
+    pub concluded_enterprise_value: Money,
+    pub equity_value: Money,
+    pub implied_sde_multiple: Multiple,
+    pub implied_ebitda_multiple: Option<Multiple>,
+    /// Low and high of the market range alongside the income value
+    pub value_range: (Money, Money),
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+fn band(
+    basis: EarningsBasis,
+    min: Money,
+    max: Option<Money>,
+    (low, mid, high): (Multiple, Multiple, Multiple),
+) -> MultipleBand {
+    MultipleBand {
+        industry: None,
+        basis,
+        min_earnings: min,
+        max_earnings: max,
+        low,
+        mid,
+        high,
+    }
+}
+
+/// Generic main-street and lower middle market ranges by size, used when
+/// no bands are supplied.
+fn generic_bands() -> Vec<MultipleBand> {
+    use EarningsBasis::{Ebitda, Sde};
+    vec![
+        band(
+            Sde,
+            dec!(0),
+            Some(dec!(100_000)),
+            (dec!(1.0), dec!(1.75), dec!(2.5)),
+        ),
+        band(
+            Sde,
+            dec!(100_000),
+            Some(dec!(250_000)),
+            (dec!(1.75), dec!(2.25), dec!(2.75)),
+        ),
+        band(
+            Sde,
+            dec!(250_000),
+            Some(dec!(500_000)),
+            (dec!(2.0), dec!(2.6), dec!(3.25)),
+        ),
+        band(
+            Sde,
+            dec!(500_000),
+            Some(dec!(1_000_000)),
+            (dec!(2.5), dec!(3.0), dec!(3.75)),
+        ),
+        band(
+            Ebitda,
+            dec!(0),
+            Some(dec!(3_000_000)),
+            (dec!(3.5), dec!(4.5), dec!(5.5)),
+        ),
+        band(
+            Ebitda,
+            dec!(3_000_000),
+            Some(dec!(5_000_000)),
+            (dec!(4.5), dec!(5.5), dec!(6.5)),
+        ),
+        band(
+            Ebitda,
+            dec!(5_000_000),
+            None,
+            (dec!(5.5), dec!(6.5), dec!(8.0)),
+        ),
+    ]
+}
+
+fn in_band(b: &MultipleBand, earnings: Money) -> bool {
+    earnings >= b.min_earnings && b.max_earnings.is_none_or(|max| earnings < max)
+}
+
+/// Pick the band for the subject: an industry match on the preferred basis,
+/// then an industry match on the other basis, then the same for
+/// all-industry bands.
+fn select_band<'a>(
+    bands: &'a [MultipleBand],
+    industry: Option<&str>,
+    preferred: EarningsBasis,
+    sde: Money,
+    ebitda: Money,
+) -> Option<&'a MultipleBand> {
+    let other = match preferred {
+        EarningsBasis::Sde => EarningsBasis::Ebitda,
+        EarningsBasis::Ebitda => EarningsBasis::Sde,
+    };
+    let earnings = |basis| match basis {
+        EarningsBasis::Sde => sde,
+        EarningsBasis::Ebitda => ebitda,
+    };
+    let industry_match = |b: &MultipleBand, specific: bool| match (&b.industry, industry) {
+        (Some(bi), Some(i)) => specific && bi.eq_ignore_ascii_case(i),
+        (None, _) => !specific,
+        (Some(_), None) => false,
+    };
+    for specific in [true, false] {
+        for basis in [preferred, other] {
+            if let Some(b) = bands.iter().find(|b| {
+                b.basis == basis && industry_match(b, specific) && in_band(b, earnings(basis))
+            }) {
+                return Some(b);
+            }
+        }
+    }
+    None
+}
+
+// ---------------------------------------------------------------------------
+// Function: value_small_business
+// ---------------------------------------------------------------------------
+
+/// Small business valuation: normalize reported earnings to seller's
+/// discretionary earnings and EBITDA, capitalize normalized cash flow at a
+/// build-up rate, price earnings off size-banded market multiples and
+/// reconcile the two.
+pub fn value_small_business(
+    input: &SmallBusinessInput,
+) -> CorpFinanceResult<ComputationOutput<SmallBusinessOutput>> {
+    let start = Instant::now();
+    let mut warnings: Vec<String> = Vec::new();
+
+    validate_input(input)?;
+
+    // --- Normalization ---
+    let n = input.periods.len();
+    let mut periods = Vec::with_capacity(n);
+    for (i, p) in input.periods.iter().enumerate() {
+        let other: Money = p.adjustments.iter().map(|a| a.amount).sum();
+        let total_addbacks = p.owner_compensation
+            + p.owner_benefits
+            + p.interest_expense
+            + p.depreciation_amortization
+            + other;
+        let sde = p.pre_tax_income + total_addbacks;
+        periods.push(NormalizedPeriod {
+            label: p.label.clone(),
+            revenue: p.revenue,
+            pre_tax_income: p.pre_tax_income,
+            total_addbacks,
+            sde,
+            ebitda: sde - input.replacement_compensation,
+            sde_margin: if p.revenue.is_zero() {
+                Decimal::ZERO
+            } else {
+                sde / p.revenue
+            },
+            weight: p.weight.unwrap_or(Decimal::from(i as u32 + 1)),
+        });
+    }
+    let total_weight: Decimal = periods.iter().map(|p| p.weight).sum();
+    let weighted_sde = periods.iter().map(|p| p.sde * p.weight).sum::<Money>() / total_weight;
+    let weighted_ebitda = weighted_sde - input.replacement_compensation;
+
+    if weighted_sde <= Decimal::ZERO {
+        return Err(CorpFinanceError::FinancialImpossibility(
+            "Normalized seller's discretionary earnings are not positive".into(),
+        ));
+    }
+    if n >= 2 && periods[n - 1].sde < periods[0].sde {
+        warnings.push(format!(
+            "SDE declined from {} to {}; a weighted average may overstate sustainable earnings",
+            periods[0].sde.round_dp(0),
+            periods[n - 1].sde.round_dp(0)
+        ));
+    }
+    for p in &periods {
+        let owner_items = p.sde - p.pre_tax_income;
+        if p.sde > Decimal::ZERO && owner_items > p.sde * dec!(0.5) {
+            warnings.push(format!(
+                "{}: add-backs are {}% of SDE; support each with records",
+                p.label,
+                (owner_items / p.sde * dec!(100)).round_dp(0)
+            ));
+        }
+    }
+
+    // --- Income approach ---
+    let b = &input.build_up;
+    let mut build_up = Vec::new();
+    let mut cumulative = Decimal::ZERO;
+    let mut step = |component: &str, value: Rate| {
+        cumulative += value;
+        build_up.push(BuildUpStep {
+            component: component.into(),
+            value,
+            cumulative,
+        });
+    };
+    step("Risk-free rate", b.risk_free_rate);
+    step("Equity risk premium", b.equity_risk_premium);
+    step("Size premium", b.size_premium);
+    if !b.industry_risk_premium.is_zero() {
+        step("Industry risk premium", b.industry_risk_premium);
+    }
+    if !b.company_specific_risk_premium.is_zero() {
+        step(
+            "Company-specific risk premium",
+            b.company_specific_risk_premium,
+        );
+    }
+    let discount_rate = cumulative;
+    let capitalization_rate = discount_rate - b.long_term_growth;
+    if capitalization_rate <= Decimal::ZERO {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "build_up.long_term_growth".into(),
+            reason: "Long-term growth must be below the discount rate".into(),
+        });
+    }
+    if capitalization_rate < dec!(0.10) {
+        warnings.push(format!(
+            "Capitalization rate of {}% is low for a small private business",
+            (capitalization_rate * dec!(100)).round_dp(2)
+        ));
+    }
+
+    let latest = &input.periods[n - 1];
+    let capex = input
+        .maintenance_capex
+        .unwrap_or(latest.depreciation_amortization);
+    let taxable = (weighted_ebitda - latest.depreciation_amortization).max(Decimal::ZERO);
+    let normalized_cash_flow = weighted_ebitda - capex - input.tax_rate * taxable;
+    let next_year_cash_flow = normalized_cash_flow * (Decimal::ONE + b.long_term_growth);
+    let income_value = (next_year_cash_flow / capitalization_rate).max(Decimal::ZERO);
+    if normalized_cash_flow <= Decimal::ZERO {
+        warnings.push(
+            "Normalized cash flow after replacement compensation is not positive; income value is nil"
+                .into(),
+        );
+    }
+
+    // --- Market approach ---
+    let preferred = if weighted_sde < SDE_BASIS_CEILING {
+        EarningsBasis::Sde
+    } else {
+        EarningsBasis::Ebitda
+    };
+    let supplied = select_band(
+        &input.multiple_bands,
+        input.industry.as_deref(),
+        preferred,
+        weighted_sde,
+        weighted_ebitda,
+    );
+    let generic = generic_bands();
+    let (selected, generic_band) = match supplied {
+        Some(b) => (b.clone(), false),
+        None => {
+            if !input.multiple_bands.is_empty() {
+                warnings.push(
+                    "No supplied multiple band fits the subject's industry and size; using generic bands"
+                        .into(),
+                );
+            } else {
+                warnings.push(
+                    "Using generic size-band multiples; supply industry ranges from a transaction database for a defensible conclusion"
+                        .into(),
+                );
+            }
+            let b = select_band(&generic, None, preferred, weighted_sde, weighted_ebitda)
+                .ok_or_else(|| {
+                    CorpFinanceError::InsufficientData(
+                        "No multiple band applies to the subject's earnings".into(),
+                    )
+                })?;
+            (b.clone(), true)
+        }
+    };
+    let earnings = match selected.basis {
+        EarningsBasis::Sde => weighted_sde,
+        EarningsBasis::Ebitda => weighted_ebitda,
+    };
+    let market_approach = MarketApproach {
+        basis: selected.basis,
+        earnings,
+        value_low: earnings * selected.low,
+        value_mid: earnings * selected.mid,
+        value_high: earnings * selected.high,
+        band: selected,
+        generic_band,
+    };
+
+    // --- Reconciliation ---
+    let market_weight = input.market_weight.unwrap_or(dec!(0.5));
+    let concluded_enterprise_value =
+        market_weight * market_approach.value_mid + (Decimal::ONE - market_weight) * income_value;
+    let equity_value = concluded_enterprise_value - input.interest_bearing_debt + input.excess_cash;
+    let implied_ebitda_multiple = if weighted_ebitda > Decimal::ZERO {
+        Some(concluded_enterprise_value / weighted_ebitda)
+    } else {
+        None
+    };
+    let spread = if market_approach.value_mid.is_zero() {
+        Decimal::ZERO
+    } else {
+        (income_value - market_approach.value_mid).abs() / market_approach.value_mid
+    };
+    if spread > dec!(0.3) {
+        warnings.push(format!(
+            "Income and market values differ by {}%; revisit the build-up premia or the multiple band",
+            (spread * dec!(100)).round_dp(0)
+        ));
+    }
+    let value_range = (
+        market_approach.value_low.min(income_value),
+        market_approach.value_high.max(income_value),
+    );
+
+    let output = SmallBusinessOutput {
+        periods,
+        weighted_sde,
+        weighted_ebitda,
+        build_up,
+        income_approach: IncomeApproach {
+            normalized_cash_flow,
+            next_year_cash_flow,
+            discount_rate,
+            capitalization_rate,
+            value: income_value,
+        },
+        implied_sde_multiple: concluded_enterprise_value / weighted_sde,
+        implied_ebitda_multiple,
+        market_approach,
+        market_weight,
+        concluded_enterprise_value,
+        equity_value,
+        value_range,
+    };
+
+    let elapsed = start.elapsed().as_micros() as u64;
+    Ok(with_metadata(
+        "Small Business Valuation (SDE normalization, capitalized cash flow at a build-up rate, size-banded market multiples)",
+        &serde_json::json!({
+            "business": input.business_name,
+            "industry": input.industry,
+            "periods": n,
+            "replacement_compensation": input.replacement_compensation.to_string(),
+            "value_basis": "cash-free, debt-free enterprise value with normal working capital",
+            "market_weight": market_weight.to_string(),
+        }),
+        warnings,
+        elapsed,
+        output,
+    ))
+}
+
+fn validate_input(input: &SmallBusinessInput) -> CorpFinanceResult<()> {
+    if input.periods.is_empty() {
+        return Err(CorpFinanceError::InsufficientData(
+            "At least one year of earnings is required".into(),
+        ));
+    }
+    for p in &input.periods {
+        if p.revenue < Decimal::ZERO {
+            return Err(CorpFinanceError::InvalidInput {
+                field: format!("periods[{}].revenue", p.label),
+                reason: "Revenue cannot be negative".into(),
+            });
+        }
+        if p.weight.is_some_and(|w| w < Decimal::ZERO) {
+            return Err(CorpFinanceError::InvalidInput {
+                field: format!("periods[{}].weight", p.label),
+                reason: "Weights cannot be negative".into(),
+            });
+        }
+    }
+    let total_weight: Decimal = input
+        .periods
+        .iter()
+        .enumerate()
+        .map(|(i, p)| p.weight.unwrap_or(Decimal::from(i as u32 + 1)))
+        .sum();
+    if total_weight.is_zero() {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "periods.weight".into(),
+            reason: "Weights sum to zero".into(),
+        });
+    }
+    if input.replacement_compensation < Decimal::ZERO {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "replacement_compensation".into(),
+            reason: "Replacement compensation cannot be negative".into(),
+        });
+    }
+    if input.tax_rate < Decimal::ZERO || input.tax_rate > Decimal::ONE {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "tax_rate".into(),
+            reason: "Tax rate must be between 0 and 1".into(),
+        });
+    }
+    if input
+        .market_weight
+        .is_some_and(|w| w < Decimal::ZERO || w > Decimal::ONE)
+    {
+        return Err(CorpFinanceError::InvalidInput {
+            field: "market_weight".into(),
+            reason: "Market weight must be between 0 and 1".into(),
+        });
+    }
+    for b in &input.multiple_bands {
+        if b.low > b.mid || b.mid > b.high || b.low < Decimal::ZERO {
+            return Err(CorpFinanceError::InvalidInput {
+                field: "multiple_bands".into(),
+                reason: "Multiples must satisfy 0 <= low <= mid <= high".into(),
+            });
+        }
+        if b.max_earnings.is_some_and(|max| max <= b.min_earnings) {
+            return Err(CorpFinanceError::InvalidInput {
+                field: "multiple_bands".into(),
+                reason: "Band ceiling must exceed its floor".into(),
+            });
+        }
+    }
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn period(label: &str, pre_tax_income: Money) -> EarningsPeriod {
+        EarningsPeriod {
+            label: label.into(),
+            revenue: dec!(1_500_000),
+            pre_tax_income,
+            owner_compensation: dec!(120_000),
+            owner_benefits: dec!(15_000),
+            interest_expense: dec!(10_000),
+            depreciation_amortization: dec!(25_000),
+            adjustments: vec![NormalizationAdjustment {
+                description: "One-off legal settlement".into(),
+                amount: dec!(30_000),
+            }],
+            weight: None,
+        }
+    }
+
+    fn sample_input() -> SmallBusinessInput {
+        SmallBusinessInput {
+            business_name: "Main Street HVAC".into(),
+            industry: Some("HVAC Services".into()),
+            periods: vec![
+                period("FY2022", dec!(100_000)),
+                period("FY2023", dec!(130_000)),
+                period("FY2024", dec!(160_000)),
+            ],
+            replacement_compensation: dec!(90_000),
+            build_up: BuildUpRate {
+                risk_free_rate: dec!(0.045),
+                equity_risk_premium: dec!(0.055),
+                size_premium: dec!(0.06),
+                industry_risk_premium: dec!(0.01),
+                company_specific_risk_premium: dec!(0.05),
+                long_term_growth: dec!(0.03),
+            },
+            tax_rate: Decimal::ZERO,
+            maintenance_capex: None,
+            multiple_bands: vec![],
+            market_weight: None,
+            interest_bearing_debt: dec!(50_000),
+            excess_cash: dec!(20_000),
+        }
+    }
+
+    #[test]
+    fn test_sde_normalization_and_weighting() {
+        let out = value_small_business(&sample_input()).unwrap().result;
+        // Add-backs: 120k + 15k + 10k + 25k + 30k = 200k
+        assert_eq!(out.periods[0].total_addbacks, dec!(200_000));
+        assert_eq!(out.periods[2].sde, dec!(360_000));
+        assert_eq!(out.periods[2].ebitda, dec!(270_000));
+        assert_eq!(out.periods[2].sde_margin, dec!(0.24));
+        // 1-2-3 weighting: (300 + 2*330 + 3*360) / 6 = 340k
+        assert_eq!(out.weighted_sde, dec!(340_000));
+        assert_eq!(out.weighted_ebitda, dec!(250_000));
+    }
+
+    #[test]
+    fn test_build_up_and_capitalized_cash_flow() {
+        let out = value_small_business(&sample_input()).unwrap().result;
+        let inc = &out.income_approach;
+        assert_eq!(inc.discount_rate, dec!(0.22));
+        assert_eq!(inc.capitalization_rate, dec!(0.19));
+        assert_eq!(out.build_up.len(), 5);
+        assert_eq!(out.build_up.last().unwrap().cumulative, dec!(0.22));
+        // EBITDA 250k less 25k capex, no entity tax
+        assert_eq!(inc.normalized_cash_flow, dec!(225_000));
+        assert_eq!(inc.value, dec!(231_750) / dec!(0.19));
+    }
+
+    #[test]
+    fn test_generic_sde_band_and_conclusion() {
+        let out = value_small_business(&sample_input()).unwrap();
+        let r = &out.result;
+        let m = &r.market_approach;
+        assert!(m.generic_band);
+        assert_eq!(m.basis, EarningsBasis::Sde);
+        assert_eq!(m.band.min_earnings, dec!(250_000));
+        assert_eq!(m.value_mid, dec!(340_000) * dec!(2.6));
+        let expected = dec!(0.5) * m.value_mid + dec!(0.5) * r.income_approach.value;
+        assert_eq!(r.concluded_enterprise_value, expected);
+        assert_eq!(r.equity_value, expected - dec!(30_000));
+        assert_eq!(r.implied_sde_multiple, expected / dec!(340_000));
+        assert!(out.warnings.iter().any(|w| w.contains("generic size-band")));
+    }
+
+    #[test]
+    fn test_industry_band_preferred() {
+        let mut input = sample_input();
+        input.multiple_bands = vec![
+            MultipleBand {
+                industry: None,
+                basis: EarningsBasis::Sde,
+                min_earnings: dec!(0),
+                max_earnings: None,
+                low: dec!(1),
+                mid: dec!(2),
+                high: dec!(3),
+            },
+            MultipleBand {
+                industry: Some("hvac services".into()),
+                basis: EarningsBasis::Sde,
+                min_earnings: dec!(250_000),
+                max_earnings: Some(dec!(750_000)),
+                low: dec!(2.4),
+                mid: dec!(2.9),
+                high: dec!(3.4),
+            },
+        ];
+        let out = value_small_business(&input).unwrap();
+        let m = &out.result.market_approach;
+        assert!(!m.generic_band);
+        assert_eq!(m.band.mid, dec!(2.9));
+        assert!(!out.warnings.iter().any(|w| w.contains("generic")));
+    }
+
+    #[test]
+    fn test_larger_business_priced_on_ebitda() {
+        let mut input = sample_input();
+        for p in &mut input.periods {
+            p.revenue = dec!(12_000_000);
+            p.pre_tax_income += dec!(2_000_000);
+        }
+        input.replacement_compensation = dec!(200_000);
+        input.tax_rate = dec!(0.21);
+        let out = value_small_business(&input).unwrap().result;
+        let m = &out.market_approach;
+        assert_eq!(m.basis, EarningsBasis::Ebitda);
+        assert_eq!(m.earnings, out.weighted_ebitda);
+        assert_eq!(m.band.mid, dec!(4.5));
+        // Entity tax on EBITDA less depreciation
+        let taxable = out.weighted_ebitda - dec!(25_000);
+        assert_eq!(
+            out.income_approach.normalized_cash_flow,
+            out.weighted_ebitda - dec!(25_000) - dec!(0.21) * taxable
+        );
+    }
+
+    #[test]
+    fn test_declining_earnings_and_heavy_addbacks_warn() {
+        let mut input = sample_input();
+        input.periods.reverse();
+        input.periods[2].pre_tax_income = dec!(-50_000);
+        let out = value_small_business(&input).unwrap();
+        assert!(out.warnings.iter().any(|w| w.contains("SDE declined")));
+        assert!(out.warnings.iter().any(|w| w.contains("add-backs")));
+    }
+
+    #[test]
+    fn test_invalid_inputs_rejected() {
+        let mut input = sample_input();
+        input.periods.clear();
+        assert!(value_small_business(&input).is_err());
+
+        let mut input = sample_input();
+        input.build_up.long_term_growth = dec!(0.25);
+        assert!(value_small_business(&input).is_err());
+
+        let mut input = sample_input();
+        for p in &mut input.periods {
+            p.pre_tax_income = dec!(-500_000);
+        }
+        assert!(value_small_business(&input).is_err());
+
+        let mut input = sample_input();
+        input.market_weight = Some(dec!(1.5));
+        assert!(value_small_business(&input).is_err());
+    }
+}
@@ -3,6 +3,10 @@ use rust_decimal_macros::dec;
 use serde::{Deserialize, Serialize};
 use std::time::Instant;
 
+use crate::curves::{
+    Extrapolation, SmileInterpolation, StrikeAxis, TimeInterpolation, VolNode, VolSurface,
+    VOL_SURFACE_FORMAT_VERSION,
+};
 use crate::error::CorpFinanceError;
 use crate::types::*;
 use crate::CorpFinanceResult;
@@ -107,6 +111,9 @@ pub struct ImpliedVolSurfaceOutput {
     pub term_structure: Vec<TermPoint>,
     pub arbitrage_flags: Vec<ArbitrageFlag>,
     pub svi_params: Option<SviParams>,
+    /// The surface points in the versioned surface format, for pricing
+    /// models that take a `volatility_surface`
+    pub surface: VolSurface,
 }
 
 // ---------------------------------------------------------------------------
@@ -1231,8 +1238,31 @@ pub fn build_implied_vol_surface(
         input.extrapolation,
     );
 
+    // The grid was filled bilinearly, so it is read back the same way
+    let surface = VolSurface {
+        version: VOL_SURFACE_FORMAT_VERSION,
+        id: None,
+        underlying: None,
+        calibration_date: None,
+        day_count: None,
+        spot: Some(s),
+        strike_axis: StrikeAxis::Absolute,
+        smile_interpolation: SmileInterpolation::Linear,
+        time_interpolation: TimeInterpolation::Linear,
+        extrapolation: Extrapolation::Flat,
+        nodes: surface_points
+            .iter()
+            .map(|p| VolNode {
+                expiry: p.expiry,
+                strike: p.strike,
+                vol: p.implied_vol,
+            })
+            .collect(),
+    };
+
     let output = ImpliedVolSurfaceOutput {
         surface_points,
+        surface,
         smile_metrics,
         term_structure,
         arbitrage_flags,
@@ -1433,6 +1463,18 @@ mod tests {
         assert!(!result.result.surface_points.is_empty());
     }
 
+    #[test]
+    fn test_surface_format_reproduces_grid() {
+        let input = standard_input();
+        let out = build_implied_vol_surface(&input).unwrap().result;
+        assert_eq!(out.surface.nodes.len(), out.surface_points.len());
+        for p in &out.surface_points {
+            assert_eq!(out.surface.vol(p.expiry, p.strike).unwrap(), p.implied_vol);
+        }
+        let mid = out.surface.vol(dec!(0.25), dec!(95)).unwrap();
+        assert!(approx_eq(mid, dec!(0.225), dec!(0.0000001)));
+    }
+
     #[test]
     fn test_linear_surface_point_count() {
         let input = standard_input();
@@ -18,6 +18,7 @@ fn test_moic_known_answer() {
         exit_equity: dec!(200),
         holding_period_years: Some(dec!(3)),
         dates: None,
+        subscription_line: None,
     };
     let result = returns::calculate_returns(&input).unwrap();
     assert_eq!(result.result.moic, dec!(3));
@@ -33,6 +34,7 @@ fn test_moic_2x_typical_pe() {
         exit_equity: dec!(1000),
         holding_period_years: Some(dec!(5)),
         dates: None,
+        subscription_line: None,
     };
     let result = returns::calculate_returns(&input).unwrap();
     assert_eq!(result.result.moic, dec!(2));
@@ -49,6 +51,7 @@ fn test_irr_known_answer_even_cashflows() {
         exit_equity: dec!(400),
         holding_period_years: Some(dec!(3)),
         dates: None,
+        subscription_line: None,
     };
     let result = returns::calculate_returns(&input).unwrap();
     let irr = result.result.irr.unwrap();
@@ -69,6 +72,7 @@ fn test_irr_high_return_lbo() {
         exit_equity: dec!(300),
         holding_period_years: Some(dec!(3)),
         dates: None,
+        subscription_line: None,
     };
     let result = returns::calculate_returns(&input).unwrap();
     let irr = result.result.irr.unwrap();
@@ -109,6 +113,7 @@ fn test_xirr_with_dated_cashflows() {
         exit_equity: dec!(1200),
         holding_period_years: None,
         dates: Some((d0, d2)),
+        subscription_line: None,
     };
     let result = returns::calculate_returns(&input).unwrap();
     assert!(result.result.xirr.is_some());
@@ -131,6 +136,7 @@ fn test_holding_period_from_dates() {
         exit_equity: dec!(200),
         holding_period_years: None,
         dates: Some((d0, d1)),
+        subscription_line: None,
     };
     let result = returns::calculate_returns(&input).unwrap();
     assert!(
@@ -149,6 +155,7 @@ fn test_zero_entry_equity_error() {
         exit_equity: dec!(100),
         holding_period_years: Some(dec!(1)),
         dates: None,
+        subscription_line: None,
     };
     assert!(returns::calculate_returns(&input).is_err());
 }
@@ -162,6 +169,7 @@ fn test_negative_entry_equity_error() {
         exit_equity: dec!(200),
         holding_period_years: Some(dec!(3)),
         dates: None,
+        subscription_line: None,
     };
     assert!(returns::calculate_returns(&input).is_err());
 }
@@ -230,12 +230,15 @@ fn sample_dcf_input() -> dcf::DcfInput {
         terminal_method: dcf::TerminalMethod::GordonGrowth,
         terminal_growth_rate: Some(dec!(0.025)),
         terminal_exit_multiple: None,
+        terminal_ronic: None,
         currency: Currency::USD,
         forecast_years: None,
         mid_year_convention: Some(true),
         net_debt: Some(dec!(500_000)),
         minority_interest: None,
         shares_outstanding: Some(dec!(1000)),
+        presentation_currency: None,
+        fx_rates: vec![],
     }
 }
 
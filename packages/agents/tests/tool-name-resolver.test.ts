@@ -2,34 +2,35 @@ import { describe, it, expect } from 'vitest';
 import { resolveToolName, reverseResolve } from '../config/tool-name-resolver.js';
 
 describe('Tool name resolver', () => {
-  // All 215 MCP-registered tool names
+  // All 282 MCP-registered tool names
   const mcpTools = new Set([
-    'wacc_calculator', 'dcf_model', 'comps_analysis', 'sotp_valuation', 'target_price',
-    'credit_metrics', 'debt_capacity', 'covenant_compliance', 'credit_scorecard',
+    'wacc_calculator', 'dcf_model', 'comps_analysis', 'small_business_valuation', 'peer_screen', 'asset_based_valuation', 'cost_of_equity_buildup', 'valuation_adjustments', 'franchise_economics', 'capital_project_selection', 'sotp_valuation', 'target_price', 'reit_analysis', 'bank_valuation', 'reverse_dcf', 'economic_profit_valuation', 'apv_valuation', 'rate_consistency_check',
+    'credit_metrics', 'debt_capacity', 'debt_maturity_schedule', 'amortization_schedule', 'covenant_compliance', 'covenant_projection', 'counterparty_exposure', 'credit_scorecard',
     'merton_pd', 'intensity_model', 'pd_calibration', 'scoring_validation',
     'bond_pricer', 'bond_yield', 'bootstrap_spot_curve', 'nelson_siegel_fit',
     'bond_duration', 'credit_spreads',
     'option_pricer', 'implied_volatility', 'forward_pricer', 'forward_position_value',
     'futures_basis_analysis', 'interest_rate_swap', 'currency_swap', 'option_strategy',
-    'three_statement_model', 'monte_carlo_simulation', 'monte_carlo_dcf',
-    'factor_model', 'black_litterman', 'risk_parity', 'stress_test',
-    'sensitivity_matrix', 'scenario_analysis',
+    'three_statement_model', 'model_to_dcf', 'ltm_build', 'calendarize', 'annualize_period', 'deal_model', 'monte_carlo_simulation', 'monte_carlo_dcf',
+    'factor_model', 'black_litterman', 'risk_parity', 'hierarchical_risk_parity', 'stress_test',
+    'sensitivity_matrix', 'scenario_analysis', 'shift_market_data',
     'recovery_analysis', 'distressed_debt_analysis',
     'property_valuation', 'project_finance_model',
     'fx_forward', 'cross_rate', 'commodity_forward', 'commodity_curve',
-    'abs_cashflow_model', 'tranching_analysis',
+    'abs_cashflow_model', 'cmbs_cashflow_model', 'tranching_analysis',
     'funding_round', 'dilution_analysis', 'convertible_note', 'safe_conversion',
     'venture_fund_model',
     'esg_score', 'carbon_footprint', 'green_bond', 'sll_covenants',
-    'regulatory_capital', 'lcr', 'nsfr', 'alm_analysis',
-    'unitranche_pricing', 'direct_loan', 'syndication_analysis',
+    'regulatory_capital', 'lcr', 'nsfr', 'alm_analysis', 'cashflow_ladder', 'saccr_exposure',
+    'unitranche_pricing', 'direct_loan', 'syndication_analysis', 'leveraged_loan_pricing', 'bridge_financing', 'fund_finance',
     'retirement_planning', 'tax_loss_harvesting', 'estate_planning',
+    'retail_loan_schedule', 'loan_apr', 'refinance_analysis',
     'token_valuation', 'defi_analysis',
     'muni_bond_pricing', 'municipal_analysis',
     'structured_note_pricing', 'exotic_product_pricing',
     'letter_of_credit', 'supply_chain_finance',
-    'cds_pricing', 'cva_calculation',
-    'convertible_bond_pricing', 'convertible_bond_analysis',
+    'cds_pricing', 'cva_calculation', 'xva_calculation',
+    'convertible_bond_pricing', 'convertible_bond_analysis', 'convertible_book_pricing',
     'lease_classification', 'sale_leaseback_analysis',
     'pension_funding', 'ldi_strategy',
     'sovereign_bond_analysis', 'country_risk_assessment',
@@ -38,9 +39,10 @@ describe('Tool name resolver', () => {
     'earnings_quality_composite',
     'commodity_spread', 'storage_economics',
     'returns_calculator', 'debt_schedule', 'sources_uses', 'lbo_model',
-    'waterfall_calculator', 'altman_zscore',
+    'waterfall_calculator', 'altman_zscore', 'interim_nav',
+    'co_investment', 'pacing_projection',
     'ppp_model', 'concession_valuation',
-    'merger_model',
+    'merger_model', 'multi_target_merger', 'purchase_price_allocation', 'merger_arbitrage', 'acquisition_financing', 'purchase_price_mechanism', 'earnout_valuation', 'ipo_analysis', 'rights_issue',
     'mean_variance_optimization', 'black_litterman_portfolio',
     'factor_risk_budget', 'tail_risk_analysis',
     'brinson_attribution', 'factor_attribution',
@@ -50,13 +52,13 @@ describe('Tool name resolver', () => {
     'tips_analytics', 'inflation_derivatives',
     'repo_analytics', 'collateral_analytics',
     'h_model_ddm', 'multistage_ddm', 'buyback_analysis', 'payout_sustainability',
-    'total_shareholder_return',
+    'total_shareholder_return', 'asr_buyback', 'open_market_buyback', 'payout_optimizer',
     'portfolio_credit_risk', 'credit_migration',
     'benfords_law', 'dupont_analysis', 'zscore_models', 'peer_benchmarking',
     'red_flag_scoring',
     'nim_analysis', 'camels_rating', 'cecl_provisioning', 'deposit_beta',
     'loan_book_analysis',
-    'pairs_trading', 'momentum_analysis',
+    'pairs_trading', 'momentum_analysis', 'event_study',
     'index_weighting', 'index_rebalancing', 'tracking_error', 'smart_beta',
     'index_reconstitution',
     'spread_analysis', 'optimal_execution',
@@ -64,18 +66,19 @@ describe('Tool name resolver', () => {
     'monetary_policy', 'international_economics',
     'country_risk_premium', 'political_risk', 'capital_controls',
     'em_bond_analysis', 'em_equity_premium',
-    'risk_adjusted_returns', 'risk_metrics', 'kelly_sizing',
-    'variance_analysis', 'breakeven_analysis', 'working_capital', 'rolling_forecast',
+    'risk_adjusted_returns', 'risk_metrics', 'kelly_sizing', 'portfolio_valuation', 'revaluation_var', 'blended_benchmark', 'drawdown_analysis',
+    'performance_ratios',
+    'variance_analysis', 'breakeven_analysis', 'working_capital', 'inventory_optimization', 'rolling_forecast', 'backlog_revenue', 'saas_metrics', 'unit_economics',
     'cash_management', 'hedge_effectiveness',
     'loss_reserving', 'premium_pricing', 'combined_ratio', 'solvency_scr',
-    'fund_fee_calculator', 'gaap_ifrs_reconcile', 'withholding_tax', 'nav_calculator',
-    'gp_economics', 'investor_net_returns', 'ubti_screening',
+    'fund_fee_calculator', 'performance_fee_variants', 'gaap_ifrs_reconcile', 'withholding_tax', 'nav_calculator',
+    'gp_economics', 'investor_net_returns', 'capital_account_statements', 'ubti_screening',
     'economic_capital', 'raroc_calculation', 'euler_allocation', 'shapley_allocation',
     'limit_management',
     'carbon_credit_pricing', 'ets_compliance', 'cbam_analysis', 'offset_valuation',
     'shadow_carbon_price',
     'j_curve_model', 'commitment_pacing', 'manager_selection', 'secondaries_pricing',
-    'fof_portfolio',
+    'fof_portfolio', 'style_analysis', 'cash_flow_forecast',
     'concentrated_stock', 'philanthropic_vehicles', 'wealth_transfer',
     'direct_indexing', 'family_governance',
     'best_execution', 'gips_report',
@@ -86,8 +89,8 @@ describe('Tool name resolver', () => {
     'economic_substance', 'jurisdiction_substance_test',
     'aifmd_reporting', 'sec_cftc_reporting',
     'us_fund_structure', 'uk_eu_fund_structure',
-    'cayman_fund_structure', 'lux_ireland_fund_structure',
-    'clo_waterfall', 'clo_coverage_tests', 'clo_reinvestment',
+    'cayman_fund_structure', 'lux_ireland_fund_structure', 'redemption_stress', 'semi_liquid_stress',
+    'clo_waterfall', 'clo_coverage_tests', 'clo_portfolio_metrics', 'clo_reinvestment', 'clo_reinvestment_optimizer',
     'clo_tranche_analytics', 'clo_scenario',
   ]);
 
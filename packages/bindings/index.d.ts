@@ -3,236 +3,363 @@
 
 /* auto-generated by NAPI-RS */
 
-export declare function calculateWacc(inputJson: string): NapiResult
-export declare function buildDcf(inputJson: string): NapiResult
-export declare function compsAnalysis(inputJson: string): NapiResult
-export declare function creditMetrics(inputJson: string): NapiResult
-export declare function debtCapacity(inputJson: string): NapiResult
-export declare function covenantCompliance(inputJson: string): NapiResult
-export declare function calculateReturns(inputJson: string): NapiResult
-export declare function buildDebtSchedule(inputJson: string): NapiResult
-export declare function sourcesAndUses(inputJson: string): NapiResult
-export declare function buildLbo(inputJson: string): NapiResult
-export declare function calculateWaterfall(inputJson: string): NapiResult
-export declare function analyzeMerger(inputJson: string): NapiResult
-export declare function altmanZscore(inputJson: string): NapiResult
-export declare function calculateFundFees(inputJson: string): NapiResult
-export declare function reconcileAccounting(inputJson: string): NapiResult
-export declare function calculateWht(inputJson: string): NapiResult
-export declare function calculatePortfolioWht(inputJson: string): NapiResult
-export declare function calculateNav(inputJson: string): NapiResult
-export declare function calculateGpEconomics(inputJson: string): NapiResult
-export declare function calculateInvestorNetReturns(inputJson: string): NapiResult
-export declare function screenUbtiEci(inputJson: string): NapiResult
-export declare function priceBond(inputJson: string): NapiResult
-export declare function calculateBondYield(inputJson: string): NapiResult
-export declare function bootstrapSpotCurve(inputJson: string): NapiResult
-export declare function fitNelsonSiegel(inputJson: string): NapiResult
-export declare function calculateDuration(inputJson: string): NapiResult
-export declare function calculateCreditSpreads(inputJson: string): NapiResult
-export declare function priceOption(inputJson: string): NapiResult
-export declare function impliedVolatility(inputJson: string): NapiResult
-export declare function priceForward(inputJson: string): NapiResult
-export declare function valueForwardPosition(inputJson: string): NapiResult
-export declare function futuresBasisAnalysis(inputJson: string): NapiResult
-export declare function valueInterestRateSwap(inputJson: string): NapiResult
-export declare function valueCurrencySwap(inputJson: string): NapiResult
-export declare function analyzeStrategy(inputJson: string): NapiResult
-export declare function riskAdjustedReturns(inputJson: string): NapiResult
-export declare function riskMetrics(inputJson: string): NapiResult
-export declare function kellySizing(inputJson: string): NapiResult
-export declare function buildSensitivityGrid(inputJson: string): NapiResult
-export declare function buildThreeStatement(inputJson: string): NapiResult
-export declare function runMonteCarlo(inputJson: string): NapiResult
-export declare function runMcDcf(inputJson: string): NapiResult
-export declare function runFactorModel(inputJson: string): NapiResult
-export declare function runBlackLitterman(inputJson: string): NapiResult
-export declare function calculateRiskParity(inputJson: string): NapiResult
-export declare function runStressTest(inputJson: string): NapiResult
-export declare function analyzeRecovery(inputJson: string): NapiResult
-export declare function analyzeDistressedDebt(inputJson: string): NapiResult
-export declare function valueProperty(inputJson: string): NapiResult
-export declare function modelProjectFinance(inputJson: string): NapiResult
-export declare function tenantSchedule(inputJson: string): NapiResult
-export declare function leaseRollover(inputJson: string): NapiResult
-export declare function compAdjustmentGrid(inputJson: string): NapiResult
-export declare function compReconciliation(inputJson: string): NapiResult
-export declare function hbuAnalysis(inputJson: string): NapiResult
-export declare function financiallyFeasible(inputJson: string): NapiResult
-export declare function costApproach(inputJson: string): NapiResult
-export declare function marshallSwift(inputJson: string): NapiResult
-export declare function ncreifAttribution(inputJson: string): NapiResult
-export declare function odceComparison(inputJson: string): NapiResult
-export declare function acquisitionModel(inputJson: string): NapiResult
-export declare function developmentFeasibility(inputJson: string): NapiResult
-export declare function priceFxForward(inputJson: string): NapiResult
-export declare function calculateCrossRate(inputJson: string): NapiResult
-export declare function priceCommodityForward(inputJson: string): NapiResult
-export declare function analyzeCommodityCurve(inputJson: string): NapiResult
-export declare function scenarioAnalysis(inputJson: string): NapiResult
-export declare function modelAbsCashflows(inputJson: string): NapiResult
-export declare function analyzeTranching(inputJson: string): NapiResult
-export declare function modelFundingRound(inputJson: string): NapiResult
-export declare function analyzeDilution(inputJson: string): NapiResult
-export declare function convertNote(inputJson: string): NapiResult
-export declare function convertSafe(inputJson: string): NapiResult
-export declare function modelVentureFund(inputJson: string): NapiResult
-export declare function calculateEsgScore(inputJson: string): NapiResult
-export declare function analyzeCarbonFootprint(inputJson: string): NapiResult
-export declare function analyzeGreenBond(inputJson: string): NapiResult
-export declare function testSllCovenants(inputJson: string): NapiResult
-export declare function calculateRegulatoryCapital(inputJson: string): NapiResult
-export declare function calculateLcr(inputJson: string): NapiResult
-export declare function calculateNsfr(inputJson: string): NapiResult
-export declare function analyzeAlm(inputJson: string): NapiResult
-export declare function priceUnitranche(inputJson: string): NapiResult
-export declare function modelDirectLoan(inputJson: string): NapiResult
-export declare function analyzeSyndication(inputJson: string): NapiResult
-export declare function estimateReserves(inputJson: string): NapiResult
-export declare function pricePremium(inputJson: string): NapiResult
-export declare function analyzeCombinedRatio(inputJson: string): NapiResult
-export declare function calculateScr(inputJson: string): NapiResult
-export declare function analyzeVariance(inputJson: string): NapiResult
-export declare function analyzeBreakeven(inputJson: string): NapiResult
-export declare function analyzeWorkingCapital(inputJson: string): NapiResult
-export declare function buildRollingForecast(inputJson: string): NapiResult
-export declare function planRetirement(inputJson: string): NapiResult
-export declare function simulateTaxLossHarvesting(inputJson: string): NapiResult
-export declare function planEstate(inputJson: string): NapiResult
-export declare function valueToken(inputJson: string): NapiResult
-export declare function analyzeDefi(inputJson: string): NapiResult
-export declare function priceMuniBond(inputJson: string): NapiResult
-export declare function analyzeMunicipal(inputJson: string): NapiResult
-export declare function priceStructuredNote(inputJson: string): NapiResult
-export declare function priceExotic(inputJson: string): NapiResult
-export declare function priceLetterOfCredit(inputJson: string): NapiResult
-export declare function analyzeSupplyChainFinance(inputJson: string): NapiResult
-export declare function priceCds(inputJson: string): NapiResult
-export declare function calculateCva(inputJson: string): NapiResult
-export declare function priceConvertible(inputJson: string): NapiResult
-export declare function analyzeConvertible(inputJson: string): NapiResult
-export declare function classifyLease(inputJson: string): NapiResult
-export declare function analyzeSaleLeaseback(inputJson: string): NapiResult
-export declare function analyzePensionFunding(inputJson: string): NapiResult
-export declare function designLdiStrategy(inputJson: string): NapiResult
-export declare function analyzeSovereignBond(inputJson: string): NapiResult
-export declare function assessCountryRisk(inputJson: string): NapiResult
-export declare function valueRealOption(inputJson: string): NapiResult
-export declare function analyzeDecisionTree(inputJson: string): NapiResult
-export declare function calculateSotp(inputJson: string): NapiResult
-export declare function calculateTargetPrice(inputJson: string): NapiResult
-export declare function analyzeCommoditySpread(inputJson: string): NapiResult
-export declare function analyzeStorageEconomics(inputJson: string): NapiResult
-export declare function analyzePairsTrading(inputJson: string): NapiResult
-export declare function analyzeMomentum(inputJson: string): NapiResult
-export declare function analyzeCashManagement(inputJson: string): NapiResult
-export declare function analyzeHedging(inputJson: string): NapiResult
-export declare function modelPpp(inputJson: string): NapiResult
-export declare function valueConcession(inputJson: string): NapiResult
-export declare function analyzeProspectTheory(inputJson: string): NapiResult
-export declare function analyzeSentiment(inputJson: string): NapiResult
-export declare function brinsonAttribution(inputJson: string): NapiResult
-export declare function factorAttribution(inputJson: string): NapiResult
-export declare function calculatePortfolioCreditRisk(inputJson: string): NapiResult
-export declare function calculateMigration(inputJson: string): NapiResult
-export declare function analyzeMonetaryPolicy(inputJson: string): NapiResult
-export declare function analyzeInternational(inputJson: string): NapiResult
-export declare function analyzeBestExecution(inputJson: string): NapiResult
-export declare function generateGipsReport(inputJson: string): NapiResult
-export declare function analyzeUsFundStructure(inputJson: string): NapiResult
-export declare function analyzeUkEuFund(inputJson: string): NapiResult
-export declare function analyzeCaymanStructure(inputJson: string): NapiResult
-export declare function analyzeLuxStructure(inputJson: string): NapiResult
-export declare function analyzeBepsCompliance(inputJson: string): NapiResult
-export declare function analyzeIntercompany(inputJson: string): NapiResult
-export declare function analyzeTreatyNetwork(inputJson: string): NapiResult
-export declare function optimizeTreatyStructure(inputJson: string): NapiResult
-export declare function analyzeFatcaCrsReporting(inputJson: string): NapiResult
-export declare function classifyEntity(inputJson: string): NapiResult
-export declare function analyzeEconomicSubstance(inputJson: string): NapiResult
-export declare function runJurisdictionSubstanceTest(inputJson: string): NapiResult
-export declare function generateAifmdReport(inputJson: string): NapiResult
-export declare function generateSecCftcReport(inputJson: string): NapiResult
-export declare function assessKycRisk(inputJson: string): NapiResult
-export declare function screenSanctions(inputJson: string): NapiResult
-export declare function buildImpliedVolSurface(inputJson: string): NapiResult
-export declare function calibrateSabr(inputJson: string): NapiResult
-export declare function optimizeMeanVariance(inputJson: string): NapiResult
-export declare function optimizeBlackLittermanPortfolio(inputJson: string): NapiResult
-export declare function analyzeFactorRiskBudget(inputJson: string): NapiResult
-export declare function analyzeTailRisk(inputJson: string): NapiResult
-export declare function analyzeSpreads(inputJson: string): NapiResult
-export declare function optimizeExecution(inputJson: string): NapiResult
-export declare function analyzeShortRate(inputJson: string): NapiResult
-export declare function fitTermStructure(inputJson: string): NapiResult
-export declare function analyzePrepayment(inputJson: string): NapiResult
-export declare function analyzeMbs(inputJson: string): NapiResult
-export declare function analyzeTips(inputJson: string): NapiResult
-export declare function analyzeInflationDerivatives(inputJson: string): NapiResult
-export declare function analyzeRepo(inputJson: string): NapiResult
-export declare function analyzeCollateral(inputJson: string): NapiResult
-export declare function calculateScorecard(inputJson: string): NapiResult
-export declare function calculateMerton(inputJson: string): NapiResult
-export declare function calculateIntensityModel(inputJson: string): NapiResult
-export declare function calculateCalibration(inputJson: string): NapiResult
-export declare function calculateScoringValidation(inputJson: string): NapiResult
-export declare function calculateEconomicCapital(inputJson: string): NapiResult
-export declare function calculateRaroc(inputJson: string): NapiResult
-export declare function calculateEulerAllocation(inputJson: string): NapiResult
-export declare function calculateShapleyAllocation(inputJson: string): NapiResult
-export declare function evaluateLimits(inputJson: string): NapiResult
-export declare function calculateCloWaterfall(inputJson: string): NapiResult
-export declare function calculateCoverageTests(inputJson: string): NapiResult
-export declare function calculateReinvestment(inputJson: string): NapiResult
-export declare function calculateTrancheAnalytics(inputJson: string): NapiResult
-export declare function calculateCloScenario(inputJson: string): NapiResult
-export declare function calculateJCurve(inputJson: string): NapiResult
-export declare function calculateCommitmentPacing(inputJson: string): NapiResult
-export declare function analyzeManagerSelection(inputJson: string): NapiResult
-export declare function calculateSecondariesPricing(inputJson: string): NapiResult
-export declare function analyzeFofPortfolio(inputJson: string): NapiResult
-export declare function calculateBeneishMscore(inputJson: string): NapiResult
-export declare function calculatePiotroskiFscore(inputJson: string): NapiResult
-export declare function calculateAccrualQuality(inputJson: string): NapiResult
-export declare function calculateRevenueQuality(inputJson: string): NapiResult
-export declare function calculateEarningsQualityComposite(inputJson: string): NapiResult
-export declare function analyzeNim(inputJson: string): NapiResult
-export declare function calculateCamelsRating(inputJson: string): NapiResult
-export declare function calculateCeclProvision(inputJson: string): NapiResult
-export declare function analyzeDepositBeta(inputJson: string): NapiResult
-export declare function analyzeLoanBook(inputJson: string): NapiResult
-export declare function calculateHModelDdm(inputJson: string): NapiResult
-export declare function calculateMultistageDdm(inputJson: string): NapiResult
-export declare function analyzeBuyback(inputJson: string): NapiResult
-export declare function analyzePayoutSustainability(inputJson: string): NapiResult
-export declare function calculateTotalShareholderReturn(inputJson: string): NapiResult
-export declare function priceCarbonCredit(inputJson: string): NapiResult
-export declare function analyzeEtsCompliance(inputJson: string): NapiResult
-export declare function analyzeCbam(inputJson: string): NapiResult
-export declare function valueCarbonOffset(inputJson: string): NapiResult
-export declare function calculateShadowCarbonPrice(inputJson: string): NapiResult
-export declare function analyzeConcentratedStock(inputJson: string): NapiResult
-export declare function comparePhilanthropicVehicles(inputJson: string): NapiResult
-export declare function analyzeWealthTransfer(inputJson: string): NapiResult
-export declare function analyzeDirectIndexing(inputJson: string): NapiResult
-export declare function evaluateFamilyGovernance(inputJson: string): NapiResult
-export declare function calculateCountryRiskPremium(inputJson: string): NapiResult
-export declare function assessPoliticalRisk(inputJson: string): NapiResult
-export declare function analyseCapitalControls(inputJson: string): NapiResult
-export declare function analyseEmBonds(inputJson: string): NapiResult
-export declare function calculateEmEquityPremium(inputJson: string): NapiResult
-export declare function calculateWeighting(inputJson: string): NapiResult
-export declare function calculateRebalancing(inputJson: string): NapiResult
-export declare function calculateTrackingError(inputJson: string): NapiResult
-export declare function calculateSmartBeta(inputJson: string): NapiResult
-export declare function calculateReconstitution(inputJson: string): NapiResult
-export declare function analyzeBenfordsLaw(inputJson: string): NapiResult
-export declare function calculateDupont(inputJson: string): NapiResult
-export declare function calculateZscoreModels(inputJson: string): NapiResult
-export declare function calculatePeerBenchmarking(inputJson: string): NapiResult
-export declare function calculateRedFlagScoring(inputJson: string): NapiResult
-export declare function workflowList(inputJson: string): NapiResult
-export declare function workflowDescribe(inputJson: string): NapiResult
-export declare function workflowValidate(inputJson: string): NapiResult
-export declare function workflowQualityCheck(inputJson: string): NapiResult
-export declare function workflowAudit(inputJson: string): NapiResult
+export class ExternalObject<T> {
+  readonly '': {
+    readonly '': unique symbol
+    [K: symbol]: T
+  }
+}
+/**
+ * Envelope returned by the calculators, as serialised from
+ * `corp_finance_core::types::ComputationOutput`. Declared for the generated
+ * TypeScript definitions; values cross the boundary through serde.
+ */
+export interface ComputationOutput {
+  result: unknown
+  methodology: string
+  assumptions: unknown
+  warnings: Array<string>
+  metadata: ComputationMetadata
+}
+/** Run metadata attached to every `ComputationOutput`. */
+export interface ComputationMetadata {
+  version: string
+  computation_time_us: number
+  precision: string
+  rounding?: unknown
+}
+export declare function calculateWacc(input: unknown): ComputationOutput
+export declare function buildCostOfEquity(input: unknown): ComputationOutput
+export declare function buildDcf(input: unknown): ComputationOutput
+export declare function compsAnalysis(input: unknown): ComputationOutput
+export declare function peerScreen(input: unknown): ComputationOutput
+export declare function valueSmallBusiness(input: unknown): ComputationOutput
+export declare function assetBasedValuation(input: unknown): ComputationOutput
+export declare function economicProfitValuation(input: unknown): ComputationOutput
+export declare function calculateApv(input: unknown): ComputationOutput
+export declare function checkRateConsistency(input: unknown): ComputationOutput
+export declare function applyValuationAdjustments(input: unknown): ComputationOutput
+export declare function creditMetrics(input: unknown): ComputationOutput
+export declare function debtCapacity(input: unknown): ComputationOutput
+export declare function analyzeDebtMaturities(input: unknown): ComputationOutput
+export declare function amortizationSchedule(input: unknown): ComputationOutput
+export declare function covenantCompliance(input: unknown): ComputationOutput
+export declare function projectCovenants(input: unknown): ComputationOutput
+export declare function counterpartyExposure(input: unknown): ComputationOutput
+export declare function calculateReturns(input: unknown): ComputationOutput
+export declare function buildDebtSchedule(input: unknown): ComputationOutput
+export declare function sourcesAndUses(input: unknown): ComputationOutput
+export declare function buildLbo(input: unknown): ComputationOutput
+export declare function calculateWaterfall(input: unknown): ComputationOutput
+export declare function projectPacing(input: unknown): ComputationOutput
+export declare function evaluateCoInvestment(input: unknown): ComputationOutput
+export declare function estimateInterimNav(input: unknown): ComputationOutput
+export declare function analyzeMerger(input: unknown): ComputationOutput
+export declare function analyzeMultiTargetMerger(input: unknown): ComputationOutput
+export declare function allocatePurchasePrice(input: unknown): ComputationOutput
+export declare function analyzeMergerArb(input: unknown): ComputationOutput
+export declare function analyzeFinancing(input: unknown): ComputationOutput
+export declare function analyzePurchasePriceMechanism(input: unknown): ComputationOutput
+export declare function valueEarnout(input: unknown): ComputationOutput
+export declare function altmanZscore(input: unknown): ComputationOutput
+export declare function calculateFundFees(input: unknown): ComputationOutput
+export declare function comparePerformanceFeeVariants(input: unknown): ComputationOutput
+export declare function reconcileAccounting(input: unknown): ComputationOutput
+export declare function calculateWht(input: unknown): ComputationOutput
+export declare function calculatePortfolioWht(input: unknown): ComputationOutput
+export declare function calculateNav(input: unknown): ComputationOutput
+export declare function calculateGpEconomics(input: unknown): ComputationOutput
+export declare function calculateInvestorNetReturns(input: unknown): ComputationOutput
+export declare function buildCapitalAccountStatements(input: unknown): ComputationOutput
+export declare function screenUbtiEci(input: unknown): ComputationOutput
+export declare function priceBond(input: unknown): ComputationOutput
+export declare function calculateBondYield(input: unknown): ComputationOutput
+export declare function bootstrapSpotCurve(input: unknown): ComputationOutput
+export declare function fitNelsonSiegel(input: unknown): ComputationOutput
+export declare function calculateDuration(input: unknown): ComputationOutput
+export declare function calculateCreditSpreads(input: unknown): ComputationOutput
+export declare function priceOption(input: unknown): ComputationOutput
+export declare function impliedVolatility(input: unknown): ComputationOutput
+export declare function priceHeston(input: unknown): ComputationOutput
+export declare function calibrateHeston(input: unknown): ComputationOutput
+export declare function priceForward(input: unknown): ComputationOutput
+export declare function valueForwardPosition(input: unknown): ComputationOutput
+export declare function futuresBasisAnalysis(input: unknown): ComputationOutput
+export declare function valueInterestRateSwap(input: unknown): ComputationOutput
+export declare function valueCurrencySwap(input: unknown): ComputationOutput
+export declare function analyzeStrategy(input: unknown): ComputationOutput
+export declare function riskAdjustedReturns(input: unknown): ComputationOutput
+export declare function riskMetrics(input: unknown): ComputationOutput
+export declare function kellySizing(input: unknown): ComputationOutput
+export declare function valuePortfolio(input: unknown): ComputationOutput
+export declare function calculateRevaluationVar(input: unknown): ComputationOutput
+export declare function blendedBenchmark(input: unknown): ComputationOutput
+export declare function drawdownAnalysis(input: unknown): ComputationOutput
+export declare function performanceRatios(input: unknown): ComputationOutput
+export declare function buildSensitivityGrid(input: unknown): ComputationOutput
+export declare function buildThreeStatement(input: unknown): ComputationOutput
+export declare function modelToDcf(input: unknown): ComputationOutput
+export declare function runDealModel(input: unknown): ComputationOutput
+export declare function buildCashflowLadder(input: unknown): ComputationOutput
+export declare function annualizePeriod(input: unknown): ComputationOutput
+export declare function calendarize(input: unknown): ComputationOutput
+export declare function buildLtm(input: unknown): ComputationOutput
+export declare function runMonteCarlo(input: unknown): ComputationOutput
+export declare function runMcDcf(input: unknown): ComputationOutput
+export declare function runFactorModel(input: unknown): ComputationOutput
+export declare function runBlackLitterman(input: unknown): ComputationOutput
+export declare function calculateRiskParity(input: unknown): ComputationOutput
+export declare function calculateHrp(input: unknown): ComputationOutput
+export declare function runStressTest(input: unknown): ComputationOutput
+export declare function analyzeRecovery(input: unknown): ComputationOutput
+export declare function analyzeDistressedDebt(input: unknown): ComputationOutput
+export declare function valueProperty(input: unknown): ComputationOutput
+export declare function modelProjectFinance(input: unknown): ComputationOutput
+export declare function tenantSchedule(input: unknown): ComputationOutput
+export declare function leaseRollover(input: unknown): ComputationOutput
+export declare function compAdjustmentGrid(input: unknown): ComputationOutput
+export declare function compReconciliation(input: unknown): ComputationOutput
+export declare function hbuAnalysis(input: unknown): ComputationOutput
+export declare function financiallyFeasible(input: unknown): ComputationOutput
+export declare function costApproach(input: unknown): ComputationOutput
+export declare function marshallSwift(input: unknown): ComputationOutput
+export declare function ncreifAttribution(input: unknown): ComputationOutput
+export declare function odceComparison(input: unknown): ComputationOutput
+export declare function acquisitionModel(input: unknown): ComputationOutput
+export declare function developmentFeasibility(input: unknown): ComputationOutput
+export declare function priceFxForward(input: unknown): ComputationOutput
+export declare function calculateCrossRate(input: unknown): ComputationOutput
+export declare function priceCommodityForward(input: unknown): ComputationOutput
+export declare function analyzeCommodityCurve(input: unknown): ComputationOutput
+export declare function scenarioAnalysis(input: unknown): ComputationOutput
+export declare function shiftMarketData(input: unknown): ComputationOutput
+export declare function modelAbsCashflows(input: unknown): ComputationOutput
+export declare function modelCmbsCashflows(input: unknown): ComputationOutput
+export declare function analyzeTranching(input: unknown): ComputationOutput
+export declare function modelFundingRound(input: unknown): ComputationOutput
+export declare function analyzeDilution(input: unknown): ComputationOutput
+export declare function convertNote(input: unknown): ComputationOutput
+export declare function convertSafe(input: unknown): ComputationOutput
+export declare function modelVentureFund(input: unknown): ComputationOutput
+export declare function calculateEsgScore(input: unknown): ComputationOutput
+export declare function analyzeCarbonFootprint(input: unknown): ComputationOutput
+export declare function analyzeGreenBond(input: unknown): ComputationOutput
+export declare function testSllCovenants(input: unknown): ComputationOutput
+export declare function calculateRegulatoryCapital(input: unknown): ComputationOutput
+export declare function calculateLcr(input: unknown): ComputationOutput
+export declare function calculateNsfr(input: unknown): ComputationOutput
+export declare function analyzeAlm(input: unknown): ComputationOutput
+export declare function calculateSaccr(input: unknown): ComputationOutput
+export declare function priceUnitranche(input: unknown): ComputationOutput
+export declare function modelDirectLoan(input: unknown): ComputationOutput
+export declare function analyzeSyndication(input: unknown): ComputationOutput
+export declare function priceLeveragedLoan(input: unknown): ComputationOutput
+export declare function analyzeBridgeFinancing(input: unknown): ComputationOutput
+export declare function analyzeFundFinance(input: unknown): ComputationOutput
+export declare function estimateReserves(input: unknown): ComputationOutput
+export declare function pricePremium(input: unknown): ComputationOutput
+export declare function analyzeCombinedRatio(input: unknown): ComputationOutput
+export declare function calculateScr(input: unknown): ComputationOutput
+export declare function analyzeVariance(input: unknown): ComputationOutput
+export declare function analyzeBreakeven(input: unknown): ComputationOutput
+export declare function analyzeWorkingCapital(input: unknown): ComputationOutput
+export declare function optimizeInventory(input: unknown): ComputationOutput
+export declare function buildRollingForecast(input: unknown): ComputationOutput
+export declare function forecastBacklogRevenue(input: unknown): ComputationOutput
+export declare function analyzeSaasMetrics(input: unknown): ComputationOutput
+export declare function analyzeUnitEconomics(input: unknown): ComputationOutput
+export declare function planRetirement(input: unknown): ComputationOutput
+export declare function simulateTaxLossHarvesting(input: unknown): ComputationOutput
+export declare function planEstate(input: unknown): ComputationOutput
+export declare function buildRetailLoanSchedule(input: unknown): ComputationOutput
+export declare function calculateApr(input: unknown): ComputationOutput
+export declare function analyzeRefinance(input: unknown): ComputationOutput
+export declare function analyzeFranchise(input: unknown): ComputationOutput
+export declare function selectProjects(input: unknown): ComputationOutput
+export declare function valueToken(input: unknown): ComputationOutput
+export declare function analyzeDefi(input: unknown): ComputationOutput
+export declare function priceMuniBond(input: unknown): ComputationOutput
+export declare function analyzeMunicipal(input: unknown): ComputationOutput
+export declare function priceStructuredNote(input: unknown): ComputationOutput
+export declare function priceExotic(input: unknown): ComputationOutput
+export declare function priceLetterOfCredit(input: unknown): ComputationOutput
+export declare function analyzeSupplyChainFinance(input: unknown): ComputationOutput
+export declare function priceCds(input: unknown): unknown
+export declare function calculateCva(input: unknown): unknown
+export declare function calculateXva(input: unknown): unknown
+export declare function priceConvertible(input: unknown): ComputationOutput
+export declare function analyzeConvertible(input: unknown): ComputationOutput
+export declare function priceConvertibleBook(input: unknown): ComputationOutput
+/**
+ * Calibrate a CRR lattice once and return it as an opaque handle for
+ * `price_on_convertible_lattice`.
+ */
+export declare function calibrateConvertibleLattice(input: unknown): ExternalObject<CalibratedLattice>
+export declare function priceOnConvertibleLattice(lattice: ExternalObject<CalibratedLattice>, input: unknown): unknown
+export declare function classifyLease(input: unknown): unknown
+export declare function analyzeSaleLeaseback(input: unknown): unknown
+export declare function analyzePensionFunding(input: unknown): ComputationOutput
+export declare function designLdiStrategy(input: unknown): ComputationOutput
+export declare function analyzeSovereignBond(input: unknown): unknown
+export declare function assessCountryRisk(input: unknown): unknown
+export declare function valueRealOption(input: unknown): ComputationOutput
+export declare function analyzeDecisionTree(input: unknown): ComputationOutput
+export declare function calculateSotp(input: unknown): ComputationOutput
+export declare function calculateTargetPrice(input: unknown): ComputationOutput
+export declare function analyzeReit(input: unknown): ComputationOutput
+export declare function valueBank(input: unknown): ComputationOutput
+export declare function calculateReverseDcf(input: unknown): ComputationOutput
+export declare function analyzeCommoditySpread(input: unknown): unknown
+export declare function analyzeStorageEconomics(input: unknown): unknown
+export declare function analyzePairsTrading(input: unknown): unknown
+export declare function analyzeMomentum(input: unknown): unknown
+export declare function runEventStudy(input: unknown): unknown
+export declare function analyzeCashManagement(input: unknown): unknown
+export declare function analyzeHedging(input: unknown): unknown
+export declare function modelPpp(input: unknown): unknown
+export declare function valueConcession(input: unknown): unknown
+export declare function analyzeProspectTheory(input: unknown): unknown
+export declare function analyzeSentiment(input: unknown): unknown
+export declare function brinsonAttribution(input: unknown): unknown
+export declare function factorAttribution(input: unknown): unknown
+export declare function calculatePortfolioCreditRisk(input: unknown): ComputationOutput
+export declare function calculateMigration(input: unknown): ComputationOutput
+export declare function analyzeMonetaryPolicy(input: unknown): unknown
+export declare function analyzeInternational(input: unknown): unknown
+export declare function analyzeBestExecution(input: unknown): ComputationOutput
+export declare function generateGipsReport(input: unknown): ComputationOutput
+export declare function analyzeUsFundStructure(input: unknown): unknown
+export declare function analyzeUkEuFund(input: unknown): unknown
+export declare function analyzeCaymanStructure(input: unknown): unknown
+export declare function analyzeLuxStructure(input: unknown): unknown
+export declare function analyzeBepsCompliance(input: unknown): unknown
+export declare function analyzeIntercompany(input: unknown): unknown
+export declare function analyzeTreatyNetwork(input: unknown): unknown
+export declare function optimizeTreatyStructure(input: unknown): unknown
+export declare function analyzeFatcaCrsReporting(input: unknown): unknown
+export declare function classifyEntity(input: unknown): unknown
+export declare function analyzeEconomicSubstance(input: unknown): unknown
+export declare function runJurisdictionSubstanceTest(input: unknown): unknown
+export declare function generateAifmdReport(input: unknown): unknown
+export declare function generateSecCftcReport(input: unknown): unknown
+export declare function assessKycRisk(input: unknown): unknown
+export declare function screenSanctions(input: unknown): unknown
+export declare function buildImpliedVolSurface(input: unknown): ComputationOutput
+export declare function calibrateSabr(input: unknown): ComputationOutput
+export declare function optimizeMeanVariance(input: unknown): ComputationOutput
+export declare function optimizeBlackLittermanPortfolio(input: unknown): ComputationOutput
+export declare function analyzeFactorRiskBudget(input: unknown): ComputationOutput
+export declare function analyzeTailRisk(input: unknown): ComputationOutput
+export declare function analyzeSpreads(input: unknown): ComputationOutput
+export declare function optimizeExecution(input: unknown): ComputationOutput
+export declare function analyzeShortRate(input: unknown): ComputationOutput
+export declare function fitTermStructure(input: unknown): ComputationOutput
+export declare function analyzePrepayment(input: unknown): ComputationOutput
+export declare function analyzeMbs(input: unknown): ComputationOutput
+export declare function analyzeTips(input: unknown): ComputationOutput
+export declare function analyzeInflationDerivatives(input: unknown): ComputationOutput
+export declare function analyzeRepo(input: unknown): ComputationOutput
+export declare function analyzeCollateral(input: unknown): ComputationOutput
+export declare function calculateScorecard(input: unknown): unknown
+export declare function calculateMerton(input: unknown): unknown
+export declare function calculateIntensityModel(input: unknown): unknown
+export declare function calculateCalibration(input: unknown): unknown
+export declare function calculateScoringValidation(input: unknown): unknown
+export declare function calculateEconomicCapital(input: unknown): unknown
+export declare function calculateRaroc(input: unknown): unknown
+export declare function calculateEulerAllocation(input: unknown): unknown
+export declare function calculateShapleyAllocation(input: unknown): unknown
+export declare function evaluateLimits(input: unknown): unknown
+export declare function calculateCloWaterfall(input: unknown): unknown
+export declare function calculateCoverageTests(input: unknown): unknown
+export declare function calculatePortfolioMetrics(input: unknown): unknown
+export declare function calculateReinvestment(input: unknown): unknown
+export declare function optimizeReinvestment(input: unknown): unknown
+export declare function calculateTrancheAnalytics(input: unknown): unknown
+export declare function calculateCloScenario(input: unknown): unknown
+export declare function calculateJCurve(input: unknown): unknown
+export declare function calculateCommitmentPacing(input: unknown): unknown
+export declare function analyzeManagerSelection(input: unknown): unknown
+export declare function calculateSecondariesPricing(input: unknown): unknown
+export declare function analyzeFofPortfolio(input: unknown): unknown
+export declare function analyzeStyle(input: unknown): unknown
+export declare function forecastCashFlows(input: unknown): unknown
+export declare function calculateBeneishMscore(input: unknown): unknown
+export declare function calculatePiotroskiFscore(input: unknown): unknown
+export declare function calculateAccrualQuality(input: unknown): unknown
+export declare function calculateRevenueQuality(input: unknown): unknown
+export declare function calculateEarningsQualityComposite(input: unknown): unknown
+export declare function analyzeNim(input: unknown): unknown
+export declare function calculateCamelsRating(input: unknown): unknown
+export declare function calculateCeclProvision(input: unknown): unknown
+export declare function analyzeDepositBeta(input: unknown): unknown
+export declare function analyzeLoanBook(input: unknown): unknown
+export declare function calculateHModelDdm(input: unknown): unknown
+export declare function calculateMultistageDdm(input: unknown): unknown
+export declare function analyzeBuyback(input: unknown): unknown
+export declare function optimizePayoutPolicy(input: unknown): unknown
+export declare function modelAsr(input: unknown): unknown
+export declare function modelOpenMarketProgram(input: unknown): unknown
+export declare function analyzePayoutSustainability(input: unknown): unknown
+export declare function calculateTotalShareholderReturn(input: unknown): unknown
+export declare function priceCarbonCredit(input: unknown): unknown
+export declare function analyzeEtsCompliance(input: unknown): unknown
+export declare function analyzeCbam(input: unknown): unknown
+export declare function valueCarbonOffset(input: unknown): unknown
+export declare function calculateShadowCarbonPrice(input: unknown): unknown
+export declare function analyzeConcentratedStock(input: unknown): unknown
+export declare function comparePhilanthropicVehicles(input: unknown): unknown
+export declare function analyzeWealthTransfer(input: unknown): unknown
+export declare function analyzeDirectIndexing(input: unknown): unknown
+export declare function evaluateFamilyGovernance(input: unknown): unknown
+export declare function calculateCountryRiskPremium(input: unknown): unknown
+export declare function assessPoliticalRisk(input: unknown): unknown
+export declare function analyseCapitalControls(input: unknown): unknown
+export declare function analyseEmBonds(input: unknown): unknown
+export declare function calculateEmEquityPremium(input: unknown): unknown
+export declare function calculateWeighting(input: unknown): unknown
+export declare function calculateRebalancing(input: unknown): unknown
+export declare function calculateTrackingError(input: unknown): unknown
+export declare function calculateSmartBeta(input: unknown): unknown
+export declare function calculateReconstitution(input: unknown): unknown
+export declare function analyzeBenfordsLaw(input: unknown): unknown
+export declare function calculateDupont(input: unknown): unknown
+export declare function calculateZscoreModels(input: unknown): unknown
+export declare function calculatePeerBenchmarking(input: unknown): unknown
+export declare function calculateRedFlagScoring(input: unknown): unknown
+export declare function workflowList(input: unknown): unknown
+export declare function workflowDescribe(input: unknown): unknown
+export declare function workflowValidate(input: unknown): unknown
+export declare function workflowQualityCheck(input: unknown): unknown
+export declare function workflowAudit(input: unknown): unknown
+export declare function analyzeIpo(input: unknown): ComputationOutput
+export declare function analyzeRightsIssue(input: unknown): ComputationOutput
+export declare function simulateRedemptionStress(input: unknown): ComputationOutput
+export declare function simulateSemiLiquidStress(input: unknown): ComputationOutput
+/**
+ * Replace the process-wide output precision policy (initially read from
+ * `CFA_MONEY_DP`, `CFA_RATE_DP`, `CFA_RATIO_DP` and `CFA_ROUNDING`).
+ */
+export declare function configurePrecision(policy: unknown): void
+/**
+ * Convert the rate-type fields of an input to decimals, honouring its
+ * `rate_convention` and `strict_units` fields. Returns the normalised
+ * input and any warnings about ambiguous magnitudes.
+ */
+export declare function normalizeRateInputs(input: unknown): unknown
+/**
+ * Resolve `$series` / `$date_index` references in `request.input` against
+ * `request.market_data`, given as JSON in any shape accepted by
+ * `MarketData::from_json` or as CSV text. Returns the resolved input and
+ * the dates of the aligned axis.
+ */
+export declare function resolveMarketData(request: unknown): unknown
+/**
+ * Execute an array of `{function, input}` calls in one native call. An
+ * optional `precision` policy on a call rounds that call's result only.
+ *
+ * Returns an array in the same order whose entries are either
+ * `{function, ok: true, result}` or `{function, ok: false, error}`;
+ * a failing call does not abort the rest of the batch.
+ */
+export declare function runBatch(calls: Array<{ function: string; input: unknown; precision?: { money_dp?: number; rate_dp?: number; ratio_dp?: number; mode?: string } }>): object
@@ -310,28 +310,51 @@ if (!nativeBinding) {
   throw new Error(`Failed to load native binding`)
 }
 
-const { calculateWacc, buildDcf, compsAnalysis, creditMetrics, debtCapacity, covenantCompliance, calculateReturns, buildDebtSchedule, sourcesAndUses, buildLbo, calculateWaterfall, analyzeMerger, altmanZscore, calculateFundFees, reconcileAccounting, calculateWht, calculatePortfolioWht, calculateNav, calculateGpEconomics, calculateInvestorNetReturns, screenUbtiEci, priceBond, calculateBondYield, bootstrapSpotCurve, fitNelsonSiegel, calculateDuration, calculateCreditSpreads, priceOption, impliedVolatility, priceForward, valueForwardPosition, futuresBasisAnalysis, valueInterestRateSwap, valueCurrencySwap, analyzeStrategy, riskAdjustedReturns, riskMetrics, kellySizing, buildSensitivityGrid, buildThreeStatement, runMonteCarlo, runMcDcf, runFactorModel, runBlackLitterman, calculateRiskParity, runStressTest, analyzeRecovery, analyzeDistressedDebt, valueProperty, modelProjectFinance, tenantSchedule, leaseRollover, compAdjustmentGrid, compReconciliation, hbuAnalysis, financiallyFeasible, costApproach, marshallSwift, ncreifAttribution, odceComparison, acquisitionModel, developmentFeasibility, priceFxForward, calculateCrossRate, priceCommodityForward, analyzeCommodityCurve, scenarioAnalysis, modelAbsCashflows, analyzeTranching, modelFundingRound, analyzeDilution, convertNote, convertSafe, modelVentureFund, calculateEsgScore, analyzeCarbonFootprint, analyzeGreenBond, testSllCovenants, calculateRegulatoryCapital, calculateLcr, calculateNsfr, analyzeAlm, priceUnitranche, modelDirectLoan, analyzeSyndication, estimateReserves, pricePremium, analyzeCombinedRatio, calculateScr, analyzeVariance, analyzeBreakeven, analyzeWorkingCapital, buildRollingForecast, planRetirement, simulateTaxLossHarvesting, planEstate, valueToken, analyzeDefi, priceMuniBond, analyzeMunicipal, priceStructuredNote, priceExotic, priceLetterOfCredit, analyzeSupplyChainFinance, priceCds, calculateCva, priceConvertible, analyzeConvertible, classifyLease, analyzeSaleLeaseback, analyzePensionFunding, designLdiStrategy, analyzeSovereignBond, assessCountryRisk, valueRealOption, analyzeDecisionTree, calculateSotp, calculateTargetPrice, analyzeCommoditySpread, analyzeStorageEconomics, analyzePairsTrading, analyzeMomentum, analyzeCashManagement, analyzeHedging, modelPpp, valueConcession, analyzeProspectTheory, analyzeSentiment, brinsonAttribution, factorAttribution, calculatePortfolioCreditRisk, calculateMigration, analyzeMonetaryPolicy, analyzeInternational, analyzeBestExecution, generateGipsReport, analyzeUsFundStructure, analyzeUkEuFund, analyzeCaymanStructure, analyzeLuxStructure, analyzeBepsCompliance, analyzeIntercompany, analyzeTreatyNetwork, optimizeTreatyStructure, analyzeFatcaCrsReporting, classifyEntity, analyzeEconomicSubstance, runJurisdictionSubstanceTest, generateAifmdReport, generateSecCftcReport, assessKycRisk, screenSanctions, buildImpliedVolSurface, calibrateSabr, optimizeMeanVariance, optimizeBlackLittermanPortfolio, analyzeFactorRiskBudget, analyzeTailRisk, analyzeSpreads, optimizeExecution, analyzeShortRate, fitTermStructure, analyzePrepayment, analyzeMbs, analyzeTips, analyzeInflationDerivatives, analyzeRepo, analyzeCollateral, calculateScorecard, calculateMerton, calculateIntensityModel, calculateCalibration, calculateScoringValidation, calculateEconomicCapital, calculateRaroc, calculateEulerAllocation, calculateShapleyAllocation, evaluateLimits, calculateCloWaterfall, calculateCoverageTests, calculateReinvestment, calculateTrancheAnalytics, calculateCloScenario, calculateJCurve, calculateCommitmentPacing, analyzeManagerSelection, calculateSecondariesPricing, analyzeFofPortfolio, calculateBeneishMscore, calculatePiotroskiFscore, calculateAccrualQuality, calculateRevenueQuality, calculateEarningsQualityComposite, analyzeNim, calculateCamelsRating, calculateCeclProvision, analyzeDepositBeta, analyzeLoanBook, calculateHModelDdm, calculateMultistageDdm, analyzeBuyback, analyzePayoutSustainability, calculateTotalShareholderReturn, priceCarbonCredit, analyzeEtsCompliance, analyzeCbam, valueCarbonOffset, calculateShadowCarbonPrice, analyzeConcentratedStock, comparePhilanthropicVehicles, analyzeWealthTransfer, analyzeDirectIndexing, evaluateFamilyGovernance, calculateCountryRiskPremium, assessPoliticalRisk, analyseCapitalControls, analyseEmBonds, calculateEmEquityPremium, calculateWeighting, calculateRebalancing, calculateTrackingError, calculateSmartBeta, calculateReconstitution, analyzeBenfordsLaw, calculateDupont, calculateZscoreModels, calculatePeerBenchmarking, calculateRedFlagScoring, workflowList, workflowDescribe, workflowValidate, workflowQualityCheck, workflowAudit } = nativeBinding
+const { calculateWacc, buildCostOfEquity, buildDcf, compsAnalysis, peerScreen, valueSmallBusiness, assetBasedValuation, economicProfitValuation, calculateApv, checkRateConsistency, applyValuationAdjustments, creditMetrics, debtCapacity, analyzeDebtMaturities, amortizationSchedule, covenantCompliance, projectCovenants, counterpartyExposure, calculateReturns, buildDebtSchedule, sourcesAndUses, buildLbo, calculateWaterfall, projectPacing, evaluateCoInvestment, estimateInterimNav, analyzeMerger, analyzeMultiTargetMerger, allocatePurchasePrice, analyzeMergerArb, analyzeFinancing, analyzePurchasePriceMechanism, valueEarnout, altmanZscore, calculateFundFees, comparePerformanceFeeVariants, reconcileAccounting, calculateWht, calculatePortfolioWht, calculateNav, calculateGpEconomics, calculateInvestorNetReturns, buildCapitalAccountStatements, screenUbtiEci, priceBond, calculateBondYield, bootstrapSpotCurve, fitNelsonSiegel, calculateDuration, calculateCreditSpreads, priceOption, impliedVolatility, priceHeston, calibrateHeston, priceForward, valueForwardPosition, futuresBasisAnalysis, valueInterestRateSwap, valueCurrencySwap, analyzeStrategy, riskAdjustedReturns, riskMetrics, kellySizing, valuePortfolio, calculateRevaluationVar, blendedBenchmark, drawdownAnalysis, performanceRatios, buildSensitivityGrid, buildThreeStatement, modelToDcf, runDealModel, buildCashflowLadder, annualizePeriod, calendarize, buildLtm, runMonteCarlo, runMcDcf, runFactorModel, runBlackLitterman, calculateRiskParity, calculateHrp, runStressTest, analyzeRecovery, analyzeDistressedDebt, valueProperty, modelProjectFinance, tenantSchedule, leaseRollover, compAdjustmentGrid, compReconciliation, hbuAnalysis, financiallyFeasible, costApproach, marshallSwift, ncreifAttribution, odceComparison, acquisitionModel, developmentFeasibility, priceFxForward, calculateCrossRate, priceCommodityForward, analyzeCommodityCurve, scenarioAnalysis, shiftMarketData, modelAbsCashflows, modelCmbsCashflows, analyzeTranching, modelFundingRound, analyzeDilution, convertNote, convertSafe, modelVentureFund, calculateEsgScore, analyzeCarbonFootprint, analyzeGreenBond, testSllCovenants, calculateRegulatoryCapital, calculateLcr, calculateNsfr, analyzeAlm, calculateSaccr, priceUnitranche, modelDirectLoan, analyzeSyndication, priceLeveragedLoan, analyzeBridgeFinancing, analyzeFundFinance, estimateReserves, pricePremium, analyzeCombinedRatio, calculateScr, analyzeVariance, analyzeBreakeven, analyzeWorkingCapital, optimizeInventory, buildRollingForecast, forecastBacklogRevenue, analyzeSaasMetrics, analyzeUnitEconomics, planRetirement, simulateTaxLossHarvesting, planEstate, buildRetailLoanSchedule, calculateApr, analyzeRefinance, analyzeFranchise, selectProjects, valueToken, analyzeDefi, priceMuniBond, analyzeMunicipal, priceStructuredNote, priceExotic, priceLetterOfCredit, analyzeSupplyChainFinance, priceCds, calculateCva, calculateXva, priceConvertible, analyzeConvertible, priceConvertibleBook, calibrateConvertibleLattice, priceOnConvertibleLattice, classifyLease, analyzeSaleLeaseback, analyzePensionFunding, designLdiStrategy, analyzeSovereignBond, assessCountryRisk, valueRealOption, analyzeDecisionTree, calculateSotp, calculateTargetPrice, analyzeReit, valueBank, calculateReverseDcf, analyzeCommoditySpread, analyzeStorageEconomics, analyzePairsTrading, analyzeMomentum, runEventStudy, analyzeCashManagement, analyzeHedging, modelPpp, valueConcession, analyzeProspectTheory, analyzeSentiment, brinsonAttribution, factorAttribution, calculatePortfolioCreditRisk, calculateMigration, analyzeMonetaryPolicy, analyzeInternational, analyzeBestExecution, generateGipsReport, analyzeUsFundStructure, analyzeUkEuFund, analyzeCaymanStructure, analyzeLuxStructure, analyzeBepsCompliance, analyzeIntercompany, analyzeTreatyNetwork, optimizeTreatyStructure, analyzeFatcaCrsReporting, classifyEntity, analyzeEconomicSubstance, runJurisdictionSubstanceTest, generateAifmdReport, generateSecCftcReport, assessKycRisk, screenSanctions, buildImpliedVolSurface, calibrateSabr, optimizeMeanVariance, optimizeBlackLittermanPortfolio, analyzeFactorRiskBudget, analyzeTailRisk, analyzeSpreads, optimizeExecution, analyzeShortRate, fitTermStructure, analyzePrepayment, analyzeMbs, analyzeTips, analyzeInflationDerivatives, analyzeRepo, analyzeCollateral, calculateScorecard, calculateMerton, calculateIntensityModel, calculateCalibration, calculateScoringValidation, calculateEconomicCapital, calculateRaroc, calculateEulerAllocation, calculateShapleyAllocation, evaluateLimits, calculateCloWaterfall, calculateCoverageTests, calculatePortfolioMetrics, calculateReinvestment, optimizeReinvestment, calculateTrancheAnalytics, calculateCloScenario, calculateJCurve, calculateCommitmentPacing, analyzeManagerSelection, calculateSecondariesPricing, analyzeFofPortfolio, analyzeStyle, forecastCashFlows, calculateBeneishMscore, calculatePiotroskiFscore, calculateAccrualQuality, calculateRevenueQuality, calculateEarningsQualityComposite, analyzeNim, calculateCamelsRating, calculateCeclProvision, analyzeDepositBeta, analyzeLoanBook, calculateHModelDdm, calculateMultistageDdm, analyzeBuyback, optimizePayoutPolicy, modelAsr, modelOpenMarketProgram, analyzePayoutSustainability, calculateTotalShareholderReturn, priceCarbonCredit, analyzeEtsCompliance, analyzeCbam, valueCarbonOffset, calculateShadowCarbonPrice, analyzeConcentratedStock, comparePhilanthropicVehicles, analyzeWealthTransfer, analyzeDirectIndexing, evaluateFamilyGovernance, calculateCountryRiskPremium, assessPoliticalRisk, analyseCapitalControls, analyseEmBonds, calculateEmEquityPremium, calculateWeighting, calculateRebalancing, calculateTrackingError, calculateSmartBeta, calculateReconstitution, analyzeBenfordsLaw, calculateDupont, calculateZscoreModels, calculatePeerBenchmarking, calculateRedFlagScoring, workflowList, workflowDescribe, workflowValidate, workflowQualityCheck, workflowAudit, analyzeIpo, analyzeRightsIssue, simulateRedemptionStress, simulateSemiLiquidStress, configurePrecision, normalizeRateInputs, resolveMarketData, runBatch } = nativeBinding
 
 module.exports.calculateWacc = calculateWacc
+module.exports.buildCostOfEquity = buildCostOfEquity
 module.exports.buildDcf = buildDcf
 module.exports.compsAnalysis = compsAnalysis
+module.exports.peerScreen = peerScreen
+module.exports.valueSmallBusiness = valueSmallBusiness
+module.exports.assetBasedValuation = assetBasedValuation
+module.exports.economicProfitValuation = economicProfitValuation
+module.exports.calculateApv = calculateApv
+module.exports.checkRateConsistency = checkRateConsistency
+module.exports.applyValuationAdjustments = applyValuationAdjustments
 module.exports.creditMetrics = creditMetrics
 module.exports.debtCapacity = debtCapacity
+module.exports.analyzeDebtMaturities = analyzeDebtMaturities
+module.exports.amortizationSchedule = amortizationSchedule
 module.exports.covenantCompliance = covenantCompliance
+module.exports.projectCovenants = projectCovenants
+module.exports.counterpartyExposure = counterpartyExposure
 module.exports.calculateReturns = calculateReturns
 module.exports.buildDebtSchedule = buildDebtSchedule
 module.exports.sourcesAndUses = sourcesAndUses
 module.exports.buildLbo = buildLbo
 module.exports.calculateWaterfall = calculateWaterfall
+module.exports.projectPacing = projectPacing
+module.exports.evaluateCoInvestment = evaluateCoInvestment
+module.exports.estimateInterimNav = estimateInterimNav
 module.exports.analyzeMerger = analyzeMerger
+module.exports.analyzeMultiTargetMerger = analyzeMultiTargetMerger
+module.exports.allocatePurchasePrice = allocatePurchasePrice
+module.exports.analyzeMergerArb = analyzeMergerArb
+module.exports.analyzeFinancing = analyzeFinancing
+module.exports.analyzePurchasePriceMechanism = analyzePurchasePriceMechanism
+module.exports.valueEarnout = valueEarnout
 module.exports.altmanZscore = altmanZscore
 module.exports.calculateFundFees = calculateFundFees
+module.exports.comparePerformanceFeeVariants = comparePerformanceFeeVariants
 module.exports.reconcileAccounting = reconcileAccounting
 module.exports.calculateWht = calculateWht
 module.exports.calculatePortfolioWht = calculatePortfolioWht
 module.exports.calculateNav = calculateNav
 module.exports.calculateGpEconomics = calculateGpEconomics
 module.exports.calculateInvestorNetReturns = calculateInvestorNetReturns
+module.exports.buildCapitalAccountStatements = buildCapitalAccountStatements
 module.exports.screenUbtiEci = screenUbtiEci
 module.exports.priceBond = priceBond
 module.exports.calculateBondYield = calculateBondYield
@@ -341,6 +364,8 @@ module.exports.calculateDuration = calculateDuration
 module.exports.calculateCreditSpreads = calculateCreditSpreads
 module.exports.priceOption = priceOption
 module.exports.impliedVolatility = impliedVolatility
+module.exports.priceHeston = priceHeston
+module.exports.calibrateHeston = calibrateHeston
 module.exports.priceForward = priceForward
 module.exports.valueForwardPosition = valueForwardPosition
 module.exports.futuresBasisAnalysis = futuresBasisAnalysis
@@ -350,13 +375,25 @@ module.exports.analyzeStrategy = analyzeStrategy
 module.exports.riskAdjustedReturns = riskAdjustedReturns
 module.exports.riskMetrics = riskMetrics
 module.exports.kellySizing = kellySizing
+module.exports.valuePortfolio = valuePortfolio
+module.exports.calculateRevaluationVar = calculateRevaluationVar
+module.exports.blendedBenchmark = blendedBenchmark
+module.exports.drawdownAnalysis = drawdownAnalysis
+module.exports.performanceRatios = performanceRatios
 module.exports.buildSensitivityGrid = buildSensitivityGrid
 module.exports.buildThreeStatement = buildThreeStatement
+module.exports.modelToDcf = modelToDcf
+module.exports.runDealModel = runDealModel
+module.exports.buildCashflowLadder = buildCashflowLadder
+module.exports.annualizePeriod = annualizePeriod
+module.exports.calendarize = calendarize
+module.exports.buildLtm = buildLtm
 module.exports.runMonteCarlo = runMonteCarlo
 module.exports.runMcDcf = runMcDcf
 module.exports.runFactorModel = runFactorModel
 module.exports.runBlackLitterman = runBlackLitterman
 module.exports.calculateRiskParity = calculateRiskParity
+module.exports.calculateHrp = calculateHrp
 module.exports.runStressTest = runStressTest
 module.exports.analyzeRecovery = analyzeRecovery
 module.exports.analyzeDistressedDebt = analyzeDistressedDebt
@@ -379,7 +416,9 @@ module.exports.calculateCrossRate = calculateCrossRate
 module.exports.priceCommodityForward = priceCommodityForward
 module.exports.analyzeCommodityCurve = analyzeCommodityCurve
 module.exports.scenarioAnalysis = scenarioAnalysis
+module.exports.shiftMarketData = shiftMarketData
 module.exports.modelAbsCashflows = modelAbsCashflows
+module.exports.modelCmbsCashflows = modelCmbsCashflows
 module.exports.analyzeTranching = analyzeTranching
 module.exports.modelFundingRound = modelFundingRound
 module.exports.analyzeDilution = analyzeDilution
@@ -394,9 +433,13 @@ module.exports.calculateRegulatoryCapital = calculateRegulatoryCapital
 module.exports.calculateLcr = calculateLcr
 module.exports.calculateNsfr = calculateNsfr
 module.exports.analyzeAlm = analyzeAlm
+module.exports.calculateSaccr = calculateSaccr
 module.exports.priceUnitranche = priceUnitranche
 module.exports.modelDirectLoan = modelDirectLoan
 module.exports.analyzeSyndication = analyzeSyndication
+module.exports.priceLeveragedLoan = priceLeveragedLoan
+module.exports.analyzeBridgeFinancing = analyzeBridgeFinancing
+module.exports.analyzeFundFinance = analyzeFundFinance
 module.exports.estimateReserves = estimateReserves
 module.exports.pricePremium = pricePremium
 module.exports.analyzeCombinedRatio = analyzeCombinedRatio
@@ -404,10 +447,19 @@ module.exports.calculateScr = calculateScr
 module.exports.analyzeVariance = analyzeVariance
 module.exports.analyzeBreakeven = analyzeBreakeven
 module.exports.analyzeWorkingCapital = analyzeWorkingCapital
+module.exports.optimizeInventory = optimizeInventory
 module.exports.buildRollingForecast = buildRollingForecast
+module.exports.forecastBacklogRevenue = forecastBacklogRevenue
+module.exports.analyzeSaasMetrics = analyzeSaasMetrics
+module.exports.analyzeUnitEconomics = analyzeUnitEconomics
 module.exports.planRetirement = planRetirement
 module.exports.simulateTaxLossHarvesting = simulateTaxLossHarvesting
 module.exports.planEstate = planEstate
+module.exports.buildRetailLoanSchedule = buildRetailLoanSchedule
+module.exports.calculateApr = calculateApr
+module.exports.analyzeRefinance = analyzeRefinance
+module.exports.analyzeFranchise = analyzeFranchise
+module.exports.selectProjects = selectProjects
 module.exports.valueToken = valueToken
 module.exports.analyzeDefi = analyzeDefi
 module.exports.priceMuniBond = priceMuniBond
@@ -418,8 +470,12 @@ module.exports.priceLetterOfCredit = priceLetterOfCredit
 module.exports.analyzeSupplyChainFinance = analyzeSupplyChainFinance
 module.exports.priceCds = priceCds
 module.exports.calculateCva = calculateCva
+module.exports.calculateXva = calculateXva
 module.exports.priceConvertible = priceConvertible
 module.exports.analyzeConvertible = analyzeConvertible
+module.exports.priceConvertibleBook = priceConvertibleBook
+module.exports.calibrateConvertibleLattice = calibrateConvertibleLattice
+module.exports.priceOnConvertibleLattice = priceOnConvertibleLattice
 module.exports.classifyLease = classifyLease
 module.exports.analyzeSaleLeaseback = analyzeSaleLeaseback
 module.exports.analyzePensionFunding = analyzePensionFunding
@@ -430,10 +486,14 @@ module.exports.valueRealOption = valueRealOption
 module.exports.analyzeDecisionTree = analyzeDecisionTree
 module.exports.calculateSotp = calculateSotp
 module.exports.calculateTargetPrice = calculateTargetPrice
+module.exports.analyzeReit = analyzeReit
+module.exports.valueBank = valueBank
+module.exports.calculateReverseDcf = calculateReverseDcf
 module.exports.analyzeCommoditySpread = analyzeCommoditySpread
 module.exports.analyzeStorageEconomics = analyzeStorageEconomics
 module.exports.analyzePairsTrading = analyzePairsTrading
 module.exports.analyzeMomentum = analyzeMomentum
+module.exports.runEventStudy = runEventStudy
 module.exports.analyzeCashManagement = analyzeCashManagement
 module.exports.analyzeHedging = analyzeHedging
 module.exports.modelPpp = modelPpp
@@ -492,7 +552,9 @@ module.exports.calculateShapleyAllocation = calculateShapleyAllocation
 module.exports.evaluateLimits = evaluateLimits
 module.exports.calculateCloWaterfall = calculateCloWaterfall
 module.exports.calculateCoverageTests = calculateCoverageTests
+module.exports.calculatePortfolioMetrics = calculatePortfolioMetrics
 module.exports.calculateReinvestment = calculateReinvestment
+module.exports.optimizeReinvestment = optimizeReinvestment
 module.exports.calculateTrancheAnalytics = calculateTrancheAnalytics
 module.exports.calculateCloScenario = calculateCloScenario
 module.exports.calculateJCurve = calculateJCurve
@@ -500,6 +562,8 @@ module.exports.calculateCommitmentPacing = calculateCommitmentPacing
 module.exports.analyzeManagerSelection = analyzeManagerSelection
 module.exports.calculateSecondariesPricing = calculateSecondariesPricing
 module.exports.analyzeFofPortfolio = analyzeFofPortfolio
+module.exports.analyzeStyle = analyzeStyle
+module.exports.forecastCashFlows = forecastCashFlows
 module.exports.calculateBeneishMscore = calculateBeneishMscore
 module.exports.calculatePiotroskiFscore = calculatePiotroskiFscore
 module.exports.calculateAccrualQuality = calculateAccrualQuality
@@ -513,6 +577,9 @@ module.exports.analyzeLoanBook = analyzeLoanBook
 module.exports.calculateHModelDdm = calculateHModelDdm
 module.exports.calculateMultistageDdm = calculateMultistageDdm
 module.exports.analyzeBuyback = analyzeBuyback
+module.exports.optimizePayoutPolicy = optimizePayoutPolicy
+module.exports.modelAsr = modelAsr
+module.exports.modelOpenMarketProgram = modelOpenMarketProgram
 module.exports.analyzePayoutSustainability = analyzePayoutSustainability
 module.exports.calculateTotalShareholderReturn = calculateTotalShareholderReturn
 module.exports.priceCarbonCredit = priceCarbonCredit
@@ -545,3 +612,11 @@ module.exports.workflowDescribe = workflowDescribe
 module.exports.workflowValidate = workflowValidate
 module.exports.workflowQualityCheck = workflowQualityCheck
 module.exports.workflowAudit = workflowAudit
+module.exports.analyzeIpo = analyzeIpo
+module.exports.analyzeRightsIssue = analyzeRightsIssue
+module.exports.simulateRedemptionStress = simulateRedemptionStress
+module.exports.simulateSemiLiquidStress = simulateSemiLiquidStress
+module.exports.configurePrecision = configurePrecision
+module.exports.normalizeRateInputs = normalizeRateInputs
+module.exports.resolveMarketData = resolveMarketData
+module.exports.runBatch = runBatch
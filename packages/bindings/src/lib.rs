@@ -1,4 +1,5 @@
-use napi::Result as NapiResult;
+use napi::bindgen_prelude::External;
+use napi::{Env, JsObject, JsUnknown, Result};
 use napi_derive::napi;
 
 /// Convert any Display error into a napi::Error.
@@ -6,684 +7,1010 @@ fn to_napi_error(e: impl std::fmt::Display) -> napi::Error {
     napi::Error::from_reason(e.to_string())
 }
 
+/// Envelope returned by the calculators, as serialised from
+/// `corp_finance_core::types::ComputationOutput`. Declared for the generated
+/// TypeScript definitions; values cross the boundary through serde.
+#[napi(object)]
+pub struct ComputationOutput {
+    #[napi(ts_type = "unknown")]
+    pub result: serde_json::Value,
+    pub methodology: String,
+    #[napi(ts_type = "unknown")]
+    pub assumptions: serde_json::Value,
+    pub warnings: Vec<String>,
+    pub metadata: ComputationMetadata,
+}
+
+/// Run metadata attached to every `ComputationOutput`.
+#[napi(object)]
+pub struct ComputationMetadata {
+    pub version: String,
+    #[napi(js_name = "computation_time_us")]
+    pub computation_time_us: i64,
+    pub precision: String,
+    #[napi(ts_type = "unknown")]
+    pub rounding: Option<serde_json::Value>,
+}
+
 // ---------------------------------------------------------------------------
 // Valuation
 // ---------------------------------------------------------------------------
 
-#[napi]
-pub fn calculate_wacc(input_json: String) -> NapiResult<String> {
-    let input: corp_finance_core::valuation::wacc::WaccInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn calculate_wacc(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::valuation::wacc::WaccInput = env.from_js_value(input)?;
     let output =
         corp_finance_core::valuation::wacc::calculate_wacc(&input).map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
-#[napi]
-pub fn build_dcf(input_json: String) -> NapiResult<String> {
-    let input: corp_finance_core::valuation::dcf::DcfInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn build_cost_of_equity(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::valuation::cost_of_equity::CostOfEquityInput =
+        env.from_js_value(input)?;
+    let output = corp_finance_core::valuation::cost_of_equity::build_cost_of_equity(&input)
+        .map_err(to_napi_error)?;
+    env.to_js_value(&output)
+}
+
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn build_dcf(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::valuation::dcf::DcfInput = env.from_js_value(input)?;
     let output = corp_finance_core::valuation::dcf::calculate_dcf(&input).map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
-#[napi]
-pub fn comps_analysis(input_json: String) -> NapiResult<String> {
-    let input: corp_finance_core::valuation::comps::CompsInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn comps_analysis(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::valuation::comps::CompsInput = env.from_js_value(input)?;
     let output =
         corp_finance_core::valuation::comps::calculate_comps(&input).map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
+}
+
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn peer_screen(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::valuation::peer_screen::PeerScreenInput =
+        env.from_js_value(input)?;
+    let output =
+        corp_finance_core::valuation::peer_screen::peer_screen(&input).map_err(to_napi_error)?;
+    env.to_js_value(&output)
+}
+
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn value_small_business(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::valuation::small_business::SmallBusinessInput =
+        env.from_js_value(input)?;
+    let output = corp_finance_core::valuation::small_business::value_small_business(&input)
+        .map_err(to_napi_error)?;
+    env.to_js_value(&output)
+}
+
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn asset_based_valuation(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::valuation::asset_based::AssetBasedInput =
+        env.from_js_value(input)?;
+    let output = corp_finance_core::valuation::asset_based::asset_based_valuation(&input)
+        .map_err(to_napi_error)?;
+    env.to_js_value(&output)
+}
+
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn economic_profit_valuation(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::valuation::economic_profit::EconomicProfitInput =
+        env.from_js_value(input)?;
+    let output = corp_finance_core::valuation::economic_profit::economic_profit_valuation(&input)
+        .map_err(to_napi_error)?;
+    env.to_js_value(&output)
+}
+
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn calculate_apv(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::valuation::apv::ApvInput = env.from_js_value(input)?;
+    let output = corp_finance_core::valuation::apv::calculate_apv(&input).map_err(to_napi_error)?;
+    env.to_js_value(&output)
+}
+
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn check_rate_consistency(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::valuation::rate_consistency::RateConsistencyInput =
+        env.from_js_value(input)?;
+    let output = corp_finance_core::valuation::rate_consistency::check_rate_consistency(&input)
+        .map_err(to_napi_error)?;
+    env.to_js_value(&output)
+}
+
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn apply_valuation_adjustments(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::valuation::adjustments::ValuationAdjustmentsInput =
+        env.from_js_value(input)?;
+    let output = corp_finance_core::valuation::adjustments::apply_valuation_adjustments(&input)
+        .map_err(to_napi_error)?;
+    env.to_js_value(&output)
 }
 
 // ---------------------------------------------------------------------------
 // Credit
 // ---------------------------------------------------------------------------
 
-#[napi]
-pub fn credit_metrics(input_json: String) -> NapiResult<String> {
-    let input: corp_finance_core::credit::metrics::CreditMetricsInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn credit_metrics(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::credit::metrics::CreditMetricsInput = env.from_js_value(input)?;
     let output = corp_finance_core::credit::metrics::calculate_credit_metrics(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
-#[napi]
-pub fn debt_capacity(input_json: String) -> NapiResult<String> {
-    let input: corp_finance_core::credit::capacity::DebtCapacityInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn debt_capacity(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::credit::capacity::DebtCapacityInput = env.from_js_value(input)?;
     let output = corp_finance_core::credit::capacity::calculate_debt_capacity(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
-#[napi]
-pub fn covenant_compliance(input_json: String) -> NapiResult<String> {
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn analyze_debt_maturities(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::credit::capacity::DebtMaturityInput = env.from_js_value(input)?;
+    let output = corp_finance_core::credit::capacity::analyze_debt_maturities(&input)
+        .map_err(to_napi_error)?;
+    env.to_js_value(&output)
+}
+
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn amortization_schedule(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::amortization::AmortizationInput = env.from_js_value(input)?;
+    let output =
+        corp_finance_core::amortization::amortization_schedule(&input).map_err(to_napi_error)?;
+    env.to_js_value(&output)
+}
+
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn covenant_compliance(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::credit::covenants::CovenantTestInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output =
         corp_finance_core::credit::covenants::test_covenants(&input).map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
+}
+
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn project_covenants(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::credit::covenants::CovenantProjectionInput =
+        env.from_js_value(input)?;
+    let output =
+        corp_finance_core::credit::covenants::project_covenants(&input).map_err(to_napi_error)?;
+    env.to_js_value(&output)
+}
+
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn counterparty_exposure(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::credit::counterparty_exposure::CounterpartyExposureInput =
+        env.from_js_value(input)?;
+    let output =
+        corp_finance_core::credit::counterparty_exposure::calculate_counterparty_exposure(&input)
+            .map_err(to_napi_error)?;
+    env.to_js_value(&output)
 }
 
 // ---------------------------------------------------------------------------
 // Private Equity
 // ---------------------------------------------------------------------------
 
-#[napi]
-pub fn calculate_returns(input_json: String) -> NapiResult<String> {
-    let input: corp_finance_core::pe::returns::ReturnsInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn calculate_returns(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::pe::returns::ReturnsInput = env.from_js_value(input)?;
     let output =
         corp_finance_core::pe::returns::calculate_returns(&input).map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
-#[napi]
-pub fn build_debt_schedule(input_json: String) -> NapiResult<String> {
-    let input: corp_finance_core::pe::debt_schedule::DebtTrancheInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn build_debt_schedule(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::pe::debt_schedule::DebtTrancheInput = env.from_js_value(input)?;
     let output =
         corp_finance_core::pe::debt_schedule::build_debt_schedule(&input).map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
-#[napi]
-pub fn sources_and_uses(input_json: String) -> NapiResult<String> {
-    let input: corp_finance_core::pe::sources_uses::SourcesUsesInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn sources_and_uses(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::pe::sources_uses::SourcesUsesInput = env.from_js_value(input)?;
     let output =
         corp_finance_core::pe::sources_uses::build_sources_uses(&input).map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 // ---------------------------------------------------------------------------
 // Private Equity — Phase 2
 // ---------------------------------------------------------------------------
 
-#[napi]
-pub fn build_lbo(input_json: String) -> NapiResult<String> {
-    let input: corp_finance_core::pe::lbo::LboInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn build_lbo(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::pe::lbo::LboInput = env.from_js_value(input)?;
     let output = corp_finance_core::pe::lbo::build_lbo(&input).map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
-#[napi]
-pub fn calculate_waterfall(input_json: String) -> NapiResult<String> {
-    let input: corp_finance_core::pe::waterfall::WaterfallInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn calculate_waterfall(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::pe::waterfall::WaterfallInput = env.from_js_value(input)?;
     let output =
         corp_finance_core::pe::waterfall::calculate_waterfall(&input).map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
+}
+
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn project_pacing(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::pe::pacing::PacingInput = env.from_js_value(input)?;
+    let output = corp_finance_core::pe::pacing::project_pacing(&input).map_err(to_napi_error)?;
+    env.to_js_value(&output)
+}
+
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn evaluate_co_investment(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::pe::co_investment::CoInvestmentInput =
+        env.from_js_value(input)?;
+    let output = corp_finance_core::pe::co_investment::evaluate_co_investment(&input)
+        .map_err(to_napi_error)?;
+    env.to_js_value(&output)
+}
+
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn estimate_interim_nav(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::pe::interim_nav::InterimNavInput = env.from_js_value(input)?;
+    let output =
+        corp_finance_core::pe::interim_nav::estimate_interim_nav(&input).map_err(to_napi_error)?;
+    env.to_js_value(&output)
 }
 
 // ---------------------------------------------------------------------------
 // M&A
 // ---------------------------------------------------------------------------
 
-#[napi]
-pub fn analyze_merger(input_json: String) -> NapiResult<String> {
-    let input: corp_finance_core::ma::merger_model::MergerInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn analyze_merger(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::ma::merger_model::MergerInput = env.from_js_value(input)?;
     let output =
         corp_finance_core::ma::merger_model::analyze_merger(&input).map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
+}
+
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn analyze_multi_target_merger(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::ma::merger_model::MultiTargetMergerInput =
+        env.from_js_value(input)?;
+    let output = corp_finance_core::ma::merger_model::analyze_multi_target_merger(&input)
+        .map_err(to_napi_error)?;
+    env.to_js_value(&output)
+}
+
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn allocate_purchase_price(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::ma::ppa::PpaInput = env.from_js_value(input)?;
+    let output =
+        corp_finance_core::ma::ppa::allocate_purchase_price(&input).map_err(to_napi_error)?;
+    env.to_js_value(&output)
+}
+
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn analyze_merger_arb(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::ma::merger_arb::MergerArbInput = env.from_js_value(input)?;
+    let output =
+        corp_finance_core::ma::merger_arb::analyze_merger_arb(&input).map_err(to_napi_error)?;
+    env.to_js_value(&output)
+}
+
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn analyze_financing(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::ma::financing::FinancingInput = env.from_js_value(input)?;
+    let output =
+        corp_finance_core::ma::financing::analyze_financing(&input).map_err(to_napi_error)?;
+    env.to_js_value(&output)
+}
+
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn analyze_purchase_price_mechanism(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::ma::purchase_price::PurchasePriceInput =
+        env.from_js_value(input)?;
+    let output = corp_finance_core::ma::purchase_price::analyze_purchase_price_mechanism(&input)
+        .map_err(to_napi_error)?;
+    env.to_js_value(&output)
+}
+
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn value_earnout(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::ma::earnout::EarnoutInput = env.from_js_value(input)?;
+    let output = corp_finance_core::ma::earnout::value_earnout(&input).map_err(to_napi_error)?;
+    env.to_js_value(&output)
 }
 
 // ---------------------------------------------------------------------------
 // Credit — Phase 2
 // ---------------------------------------------------------------------------
 
-#[napi]
-pub fn altman_zscore(input_json: String) -> NapiResult<String> {
-    let input: corp_finance_core::credit::altman::AltmanInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn altman_zscore(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::credit::altman::AltmanInput = env.from_js_value(input)?;
     let output = corp_finance_core::credit::altman::calculate_altman_zscore(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 // ---------------------------------------------------------------------------
 // Jurisdiction / Fund
 // ---------------------------------------------------------------------------
 
-#[napi]
-pub fn calculate_fund_fees(input_json: String) -> NapiResult<String> {
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn calculate_fund_fees(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::jurisdiction::fund_fees::FundFeeInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::jurisdiction::fund_fees::calculate_fund_fees(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
-#[napi]
-pub fn reconcile_accounting(input_json: String) -> NapiResult<String> {
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn compare_performance_fee_variants(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::jurisdiction::fund_fees::PerformanceFeeInput =
+        env.from_js_value(input)?;
+    let output =
+        corp_finance_core::jurisdiction::fund_fees::compare_performance_fee_variants(&input)
+            .map_err(to_napi_error)?;
+    env.to_js_value(&output)
+}
+
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn reconcile_accounting(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::jurisdiction::reconciliation::ReconciliationInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output =
         corp_finance_core::jurisdiction::reconciliation::reconcile_accounting_standards(&input)
             .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
-#[napi]
-pub fn calculate_wht(input_json: String) -> NapiResult<String> {
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn calculate_wht(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::jurisdiction::withholding_tax::WhtInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output =
         corp_finance_core::jurisdiction::withholding_tax::calculate_withholding_tax(&input)
             .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
-#[napi]
-pub fn calculate_portfolio_wht(input_json: String) -> NapiResult<String> {
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn calculate_portfolio_wht(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::jurisdiction::withholding_tax::PortfolioWhtInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::jurisdiction::withholding_tax::calculate_portfolio_wht(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
-#[napi]
-pub fn calculate_nav(input_json: String) -> NapiResult<String> {
-    let input: corp_finance_core::jurisdiction::nav::NavInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn calculate_nav(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::jurisdiction::nav::NavInput = env.from_js_value(input)?;
     let output =
         corp_finance_core::jurisdiction::nav::calculate_nav(&input).map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
-#[napi]
-pub fn calculate_gp_economics(input_json: String) -> NapiResult<String> {
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn calculate_gp_economics(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::jurisdiction::gp_economics::GpEconomicsInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::jurisdiction::gp_economics::calculate_gp_economics(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
-#[napi]
-pub fn calculate_investor_net_returns(input_json: String) -> NapiResult<String> {
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn calculate_investor_net_returns(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::jurisdiction::investor_returns::InvestorNetReturnsInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output =
         corp_finance_core::jurisdiction::investor_returns::calculate_investor_net_returns(&input)
             .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
-#[napi]
-pub fn screen_ubti_eci(input_json: String) -> NapiResult<String> {
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn build_capital_account_statements(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::jurisdiction::investor_returns::CapitalAccountInput =
+        env.from_js_value(input)?;
+    let output =
+        corp_finance_core::jurisdiction::investor_returns::build_capital_account_statements(&input)
+            .map_err(to_napi_error)?;
+    env.to_js_value(&output)
+}
+
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn screen_ubti_eci(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::jurisdiction::ubti::UbtiScreeningInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output =
         corp_finance_core::jurisdiction::ubti::screen_ubti_eci(&input).map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 // ---------------------------------------------------------------------------
 // Fixed Income
 // ---------------------------------------------------------------------------
 
-#[napi]
-pub fn price_bond(input_json: String) -> NapiResult<String> {
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn price_bond(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::fixed_income::bonds::BondPricingInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output =
         corp_finance_core::fixed_income::bonds::price_bond(&input).map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
-#[napi]
-pub fn calculate_bond_yield(input_json: String) -> NapiResult<String> {
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn calculate_bond_yield(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::fixed_income::yields::BondYieldInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::fixed_income::yields::calculate_bond_yield(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
-#[napi]
-pub fn bootstrap_spot_curve(input_json: String) -> NapiResult<String> {
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn bootstrap_spot_curve(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::fixed_income::yields::BootstrapInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::fixed_income::yields::bootstrap_spot_curve(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
-#[napi]
-pub fn fit_nelson_siegel(input_json: String) -> NapiResult<String> {
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn fit_nelson_siegel(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::fixed_income::yields::NelsonSiegelInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::fixed_income::yields::fit_nelson_siegel(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
-#[napi]
-pub fn calculate_duration(input_json: String) -> NapiResult<String> {
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn calculate_duration(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::fixed_income::duration::DurationInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::fixed_income::duration::calculate_duration(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
-#[napi]
-pub fn calculate_credit_spreads(input_json: String) -> NapiResult<String> {
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn calculate_credit_spreads(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::fixed_income::spreads::CreditSpreadInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::fixed_income::spreads::calculate_credit_spreads(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 // ---------------------------------------------------------------------------
 // Derivatives
 // ---------------------------------------------------------------------------
 
-#[napi]
-pub fn price_option(input_json: String) -> NapiResult<String> {
-    let input: corp_finance_core::derivatives::options::OptionInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn price_option(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::derivatives::options::OptionInput = env.from_js_value(input)?;
     let output =
         corp_finance_core::derivatives::options::price_option(&input).map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
-#[napi]
-pub fn implied_volatility(input_json: String) -> NapiResult<String> {
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn implied_volatility(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::derivatives::options::ImpliedVolInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::derivatives::options::implied_volatility(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
-#[napi]
-pub fn price_forward(input_json: String) -> NapiResult<String> {
-    let input: corp_finance_core::derivatives::forwards::ForwardInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn price_heston(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::derivatives::heston::HestonPricingInput =
+        env.from_js_value(input)?;
+    let output =
+        corp_finance_core::derivatives::heston::price_heston(&input).map_err(to_napi_error)?;
+    env.to_js_value(&output)
+}
+
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn calibrate_heston(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::derivatives::heston::HestonCalibrationInput =
+        env.from_js_value(input)?;
+    let output =
+        corp_finance_core::derivatives::heston::calibrate_heston(&input).map_err(to_napi_error)?;
+    env.to_js_value(&output)
+}
+
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn price_forward(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::derivatives::forwards::ForwardInput = env.from_js_value(input)?;
     let output =
         corp_finance_core::derivatives::forwards::price_forward(&input).map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
-#[napi]
-pub fn value_forward_position(input_json: String) -> NapiResult<String> {
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn value_forward_position(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::derivatives::forwards::ForwardPositionInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::derivatives::forwards::value_forward_position(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
-#[napi]
-pub fn futures_basis_analysis(input_json: String) -> NapiResult<String> {
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn futures_basis_analysis(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::derivatives::forwards::BasisAnalysisInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::derivatives::forwards::futures_basis_analysis(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
-#[napi]
-pub fn value_interest_rate_swap(input_json: String) -> NapiResult<String> {
-    let input: corp_finance_core::derivatives::swaps::IrsInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn value_interest_rate_swap(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::derivatives::swaps::IrsInput = env.from_js_value(input)?;
     let output = corp_finance_core::derivatives::swaps::value_interest_rate_swap(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
-#[napi]
-pub fn value_currency_swap(input_json: String) -> NapiResult<String> {
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn value_currency_swap(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::derivatives::swaps::CurrencySwapInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::derivatives::swaps::value_currency_swap(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
-#[napi]
-pub fn analyze_strategy(input_json: String) -> NapiResult<String> {
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn analyze_strategy(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::derivatives::strategies::StrategyInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::derivatives::strategies::analyze_strategy(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 // ---------------------------------------------------------------------------
 // Portfolio
 // ---------------------------------------------------------------------------
 
-#[napi]
-pub fn risk_adjusted_returns(input_json: String) -> NapiResult<String> {
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn risk_adjusted_returns(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::portfolio::returns::RiskAdjustedInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::portfolio::returns::calculate_risk_adjusted_returns(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
-#[napi]
-pub fn risk_metrics(input_json: String) -> NapiResult<String> {
-    let input: corp_finance_core::portfolio::risk::RiskMetricsInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn risk_metrics(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::portfolio::risk::RiskMetricsInput = env.from_js_value(input)?;
     let output = corp_finance_core::portfolio::risk::calculate_risk_metrics(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
-#[napi]
-pub fn kelly_sizing(input_json: String) -> NapiResult<String> {
-    let input: corp_finance_core::portfolio::sizing::KellyInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn kelly_sizing(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::portfolio::sizing::KellyInput = env.from_js_value(input)?;
     let output =
         corp_finance_core::portfolio::sizing::calculate_kelly(&input).map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
+}
+
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn value_portfolio(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::portfolio::valuation::PortfolioValuationInput =
+        env.from_js_value(input)?;
+    let output =
+        corp_finance_core::portfolio::valuation::value_portfolio(&input).map_err(to_napi_error)?;
+    env.to_js_value(&output)
+}
+
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn calculate_revaluation_var(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::portfolio::revaluation_var::RevaluationVarInput =
+        env.from_js_value(input)?;
+    let output = corp_finance_core::portfolio::revaluation_var::calculate_revaluation_var(&input)
+        .map_err(to_napi_error)?;
+    env.to_js_value(&output)
+}
+
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn blended_benchmark(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::portfolio::benchmarks::BlendedBenchmarkInput =
+        env.from_js_value(input)?;
+    let output = corp_finance_core::portfolio::benchmarks::build_blended_benchmark(&input)
+        .map_err(to_napi_error)?;
+    env.to_js_value(&output)
+}
+
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn drawdown_analysis(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::portfolio::drawdown::DrawdownInput = env.from_js_value(input)?;
+    let output =
+        corp_finance_core::portfolio::drawdown::analyze_drawdowns(&input).map_err(to_napi_error)?;
+    env.to_js_value(&output)
+}
+
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn performance_ratios(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::portfolio::performance_ratios::PerformanceRatiosInput =
+        env.from_js_value(input)?;
+    let output =
+        corp_finance_core::portfolio::performance_ratios::calculate_performance_ratios(&input)
+            .map_err(to_napi_error)?;
+    env.to_js_value(&output)
 }
 
 // ---------------------------------------------------------------------------
 // Scenarios
 // ---------------------------------------------------------------------------
 
-#[napi]
-pub fn build_sensitivity_grid(input_json: String) -> NapiResult<String> {
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn build_sensitivity_grid(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::scenarios::sensitivity::SensitivityInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::scenarios::sensitivity::build_sensitivity_grid(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 // ---------------------------------------------------------------------------
 // Three-Statement Model
 // ---------------------------------------------------------------------------
 
-#[napi]
-pub fn build_three_statement(input_json: String) -> NapiResult<String> {
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn build_three_statement(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::three_statement::model::ThreeStatementInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::three_statement::model::build_three_statement_model(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
+}
+
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn model_to_dcf(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::three_statement::dcf_bridge::ModelToDcfInput =
+        env.from_js_value(input)?;
+    let output = corp_finance_core::three_statement::dcf_bridge::model_to_dcf(&input)
+        .map_err(to_napi_error)?;
+    env.to_js_value(&output)
+}
+
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn run_deal_model(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::deal_model::orchestration::DealInput =
+        env.from_js_value(input)?;
+    let output = corp_finance_core::deal_model::orchestration::run_deal_model(&input)
+        .map_err(to_napi_error)?;
+    env.to_js_value(&output)
+}
+
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn build_cashflow_ladder(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::cashflow_ladder::ladder::CashFlowLadderInput =
+        env.from_js_value(input)?;
+    let output = corp_finance_core::cashflow_ladder::ladder::build_cashflow_ladder(&input)
+        .map_err(to_napi_error)?;
+    env.to_js_value(&output)
+}
+
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn annualize_period(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::three_statement::ltm::AnnualizeInput =
+        env.from_js_value(input)?;
+    let output =
+        corp_finance_core::three_statement::ltm::annualize_period(&input).map_err(to_napi_error)?;
+    env.to_js_value(&output)
+}
+
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn calendarize(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::three_statement::ltm::CalendarizeInput =
+        env.from_js_value(input)?;
+    let output =
+        corp_finance_core::three_statement::ltm::calendarize(&input).map_err(to_napi_error)?;
+    env.to_js_value(&output)
+}
+
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn build_ltm(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::three_statement::ltm::LtmInput = env.from_js_value(input)?;
+    let output =
+        corp_finance_core::three_statement::ltm::build_ltm(&input).map_err(to_napi_error)?;
+    env.to_js_value(&output)
 }
 
 // ---------------------------------------------------------------------------
 // Monte Carlo
 // ---------------------------------------------------------------------------
 
-#[napi]
-pub fn run_monte_carlo(input_json: String) -> NapiResult<String> {
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn run_monte_carlo(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::monte_carlo::simulation::MonteCarloInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::monte_carlo::simulation::run_monte_carlo_simulation(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
-#[napi]
-pub fn run_mc_dcf(input_json: String) -> NapiResult<String> {
-    let input: corp_finance_core::monte_carlo::simulation::McDcfInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn run_mc_dcf(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::monte_carlo::simulation::McDcfInput = env.from_js_value(input)?;
     let output = corp_finance_core::monte_carlo::simulation::run_monte_carlo_dcf(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 // ---------------------------------------------------------------------------
 // Quant Risk
 // ---------------------------------------------------------------------------
 
-#[napi]
-pub fn run_factor_model(input_json: String) -> NapiResult<String> {
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn run_factor_model(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::quant_risk::factor_models::FactorModelInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::quant_risk::factor_models::run_factor_model(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
-#[napi]
-pub fn run_black_litterman(input_json: String) -> NapiResult<String> {
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn run_black_litterman(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::quant_risk::black_litterman::BlackLittermanInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::quant_risk::black_litterman::run_black_litterman(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
-#[napi]
-pub fn calculate_risk_parity(input_json: String) -> NapiResult<String> {
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn calculate_risk_parity(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::quant_risk::risk_parity::RiskParityInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::quant_risk::risk_parity::calculate_risk_parity(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
-#[napi]
-pub fn run_stress_test(input_json: String) -> NapiResult<String> {
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn calculate_hrp(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::quant_risk::hrp::HrpInput = env.from_js_value(input)?;
+    let output =
+        corp_finance_core::quant_risk::hrp::calculate_hrp(&input).map_err(to_napi_error)?;
+    env.to_js_value(&output)
+}
+
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn run_stress_test(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::quant_risk::stress_testing::StressTestInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::quant_risk::stress_testing::run_stress_test(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 // ---------------------------------------------------------------------------
 // Restructuring
 // ---------------------------------------------------------------------------
 
-#[napi]
-pub fn analyze_recovery(input_json: String) -> NapiResult<String> {
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn analyze_recovery(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::restructuring::recovery::RecoveryAnalysisInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::restructuring::recovery::analyze_recovery(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
-#[napi]
-pub fn analyze_distressed_debt(input_json: String) -> NapiResult<String> {
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn analyze_distressed_debt(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::restructuring::distressed_debt::DistressedDebtInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::restructuring::distressed_debt::analyze_distressed_debt(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 // ---------------------------------------------------------------------------
 // Real Assets
 // ---------------------------------------------------------------------------
 
-#[napi]
-pub fn value_property(input_json: String) -> NapiResult<String> {
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn value_property(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::real_assets::real_estate::PropertyValuationInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::real_assets::real_estate::value_property(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
-#[napi]
-pub fn model_project_finance(input_json: String) -> NapiResult<String> {
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn model_project_finance(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::real_assets::project_finance::ProjectFinanceInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::real_assets::project_finance::model_project_finance(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 // ---------------------------------------------------------------------------
 // Institutional Real Estate
 // ---------------------------------------------------------------------------
 
-#[napi]
-pub fn tenant_schedule(input_json: String) -> NapiResult<String> {
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn tenant_schedule(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::institutional_real_estate::rent_roll::TenantScheduleInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::institutional_real_estate::rent_roll::tenant_schedule(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
-#[napi]
-pub fn lease_rollover(input_json: String) -> NapiResult<String> {
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn lease_rollover(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::institutional_real_estate::rent_roll::LeaseRolloverInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::institutional_real_estate::rent_roll::lease_rollover(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
-#[napi]
-pub fn comp_adjustment_grid(input_json: String) -> NapiResult<String> {
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn comp_adjustment_grid(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::institutional_real_estate::comparable_sales::CompAdjustmentInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output =
         corp_finance_core::institutional_real_estate::comparable_sales::comp_adjustment_grid(
             &input,
         )
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
-#[napi]
-pub fn comp_reconciliation(input_json: String) -> NapiResult<String> {
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn comp_reconciliation(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::institutional_real_estate::comparable_sales::ReconciliationInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output =
         corp_finance_core::institutional_real_estate::comparable_sales::reconciliation(&input)
             .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
-#[napi]
-pub fn hbu_analysis(input_json: String) -> NapiResult<String> {
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn hbu_analysis(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::institutional_real_estate::highest_best_use::HbuAnalysisInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output =
         corp_finance_core::institutional_real_estate::highest_best_use::hbu_analysis(&input)
             .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
-#[napi]
-pub fn financially_feasible(input_json: String) -> NapiResult<String> {
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn financially_feasible(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::institutional_real_estate::highest_best_use::FinanciallyFeasibleInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output =
         corp_finance_core::institutional_real_estate::highest_best_use::financially_feasible(
             &input,
         )
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
-#[napi]
-pub fn cost_approach(input_json: String) -> NapiResult<String> {
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn cost_approach(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::institutional_real_estate::replacement_cost::CostApproachInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output =
         corp_finance_core::institutional_real_estate::replacement_cost::cost_approach(&input)
             .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
-#[napi]
-pub fn marshall_swift(input_json: String) -> NapiResult<String> {
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn marshall_swift(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::institutional_real_estate::replacement_cost::MarshallSwiftInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output =
         corp_finance_core::institutional_real_estate::replacement_cost::marshall_swift(&input)
             .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
-#[napi]
-pub fn ncreif_attribution(input_json: String) -> NapiResult<String> {
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn ncreif_attribution(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::institutional_real_estate::benchmark::NcreifAttributionInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output =
         corp_finance_core::institutional_real_estate::benchmark::ncreif_attribution(&input)
             .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
-#[napi]
-pub fn odce_comparison(input_json: String) -> NapiResult<String> {
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn odce_comparison(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::institutional_real_estate::benchmark::OdceComparisonInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::institutional_real_estate::benchmark::odce_comparison(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
-#[napi]
-pub fn acquisition_model(input_json: String) -> NapiResult<String> {
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn acquisition_model(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::institutional_real_estate::acquisition::AcquisitionModelInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output =
         corp_finance_core::institutional_real_estate::acquisition::acquisition_model(&input)
             .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
-#[napi]
-pub fn development_feasibility(input_json: String) -> NapiResult<String> {
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn development_feasibility(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::institutional_real_estate::acquisition::DevelopmentFeasibilityInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output =
         corp_finance_core::institutional_real_estate::acquisition::development_feasibility(&input)
             .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 // ---------------------------------------------------------------------------
 // FX & Commodities
 // ---------------------------------------------------------------------------
 
-#[napi]
-pub fn price_fx_forward(input_json: String) -> NapiResult<String> {
-    let input: corp_finance_core::fx_commodities::fx::FxForwardInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn price_fx_forward(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::fx_commodities::fx::FxForwardInput = env.from_js_value(input)?;
     let output =
         corp_finance_core::fx_commodities::fx::price_fx_forward(&input).map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
-#[napi]
-pub fn calculate_cross_rate(input_json: String) -> NapiResult<String> {
-    let input: corp_finance_core::fx_commodities::fx::CrossRateInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn calculate_cross_rate(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::fx_commodities::fx::CrossRateInput = env.from_js_value(input)?;
     let output = corp_finance_core::fx_commodities::fx::calculate_cross_rate(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
-#[napi]
-pub fn price_commodity_forward(input_json: String) -> NapiResult<String> {
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn price_commodity_forward(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::fx_commodities::commodities::CommodityForwardInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::fx_commodities::commodities::price_commodity_forward(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
-#[napi]
-pub fn analyze_commodity_curve(input_json: String) -> NapiResult<String> {
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn analyze_commodity_curve(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::fx_commodities::commodities::CommodityCurveInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::fx_commodities::commodities::analyze_commodity_curve(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 // ---------------------------------------------------------------------------
@@ -698,397 +1025,521 @@ struct ScenarioBindingInput {
     base_case_value: rust_decimal::Decimal,
 }
 
-#[napi]
-pub fn scenario_analysis(input_json: String) -> NapiResult<String> {
-    let binding_input: ScenarioBindingInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn scenario_analysis(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let binding_input: ScenarioBindingInput = env.from_js_value(input)?;
     let output = corp_finance_core::scenarios::scenario::analyze_scenarios(
         &binding_input.input,
         &binding_input.output_values,
         binding_input.base_case_value,
     )
     .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
+}
+
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn shift_market_data(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::scenarios::market_shift::MarketShiftInput =
+        env.from_js_value(input)?;
+    let output = corp_finance_core::scenarios::market_shift::shift_market_data(&input)
+        .map_err(to_napi_error)?;
+    env.to_js_value(&output)
 }
 
 // ---------------------------------------------------------------------------
 // Securitization
 // ---------------------------------------------------------------------------
 
-#[napi]
-pub fn model_abs_cashflows(input_json: String) -> NapiResult<String> {
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn model_abs_cashflows(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::securitization::abs_mbs::AbsMbsInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::securitization::abs_mbs::model_abs_cashflows(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
-#[napi]
-pub fn analyze_tranching(input_json: String) -> NapiResult<String> {
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn model_cmbs_cashflows(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::securitization::cmbs::CmbsInput = env.from_js_value(input)?;
+    let output = corp_finance_core::securitization::cmbs::model_cmbs_cashflows(&input)
+        .map_err(to_napi_error)?;
+    env.to_js_value(&output)
+}
+
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn analyze_tranching(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::securitization::tranching::TranchingInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::securitization::tranching::analyze_tranching(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 // ---------------------------------------------------------------------------
 // Venture Capital
 // ---------------------------------------------------------------------------
 
-#[napi]
-pub fn model_funding_round(input_json: String) -> NapiResult<String> {
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn model_funding_round(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::venture::valuation::FundingRoundInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::venture::valuation::model_funding_round(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
-#[napi]
-pub fn analyze_dilution(input_json: String) -> NapiResult<String> {
-    let input: corp_finance_core::venture::valuation::DilutionInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn analyze_dilution(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::venture::valuation::DilutionInput = env.from_js_value(input)?;
     let output =
         corp_finance_core::venture::valuation::analyze_dilution(&input).map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
-#[napi]
-pub fn convert_note(input_json: String) -> NapiResult<String> {
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn convert_note(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::venture::instruments::ConvertibleNoteInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output =
         corp_finance_core::venture::instruments::convert_note(&input).map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
-#[napi]
-pub fn convert_safe(input_json: String) -> NapiResult<String> {
-    let input: corp_finance_core::venture::instruments::SafeInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn convert_safe(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::venture::instruments::SafeInput = env.from_js_value(input)?;
     let output =
         corp_finance_core::venture::instruments::convert_safe(&input).map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
-#[napi]
-pub fn model_venture_fund(input_json: String) -> NapiResult<String> {
-    let input: corp_finance_core::venture::returns::VentureFundInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn model_venture_fund(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::venture::returns::VentureFundInput = env.from_js_value(input)?;
     let output =
         corp_finance_core::venture::returns::model_venture_fund(&input).map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 // ---------------------------------------------------------------------------
 // ESG
 // ---------------------------------------------------------------------------
 
-#[napi]
-pub fn calculate_esg_score(input_json: String) -> NapiResult<String> {
-    let input: corp_finance_core::esg::scoring::EsgScoreInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn calculate_esg_score(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::esg::scoring::EsgScoreInput = env.from_js_value(input)?;
     let output =
         corp_finance_core::esg::scoring::calculate_esg_score(&input).map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
-#[napi]
-pub fn analyze_carbon_footprint(input_json: String) -> NapiResult<String> {
-    let input: corp_finance_core::esg::climate::CarbonFootprintInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn analyze_carbon_footprint(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::esg::climate::CarbonFootprintInput = env.from_js_value(input)?;
     let output =
         corp_finance_core::esg::climate::analyze_carbon_footprint(&input).map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
-#[napi]
-pub fn analyze_green_bond(input_json: String) -> NapiResult<String> {
-    let input: corp_finance_core::esg::climate::GreenBondInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn analyze_green_bond(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::esg::climate::GreenBondInput = env.from_js_value(input)?;
     let output =
         corp_finance_core::esg::climate::analyze_green_bond(&input).map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
-#[napi]
-pub fn test_sll_covenants(input_json: String) -> NapiResult<String> {
-    let input: corp_finance_core::esg::climate::SllInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn test_sll_covenants(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::esg::climate::SllInput = env.from_js_value(input)?;
     let output =
         corp_finance_core::esg::climate::test_sll_covenants(&input).map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 // ---------------------------------------------------------------------------
 // Regulatory
 // ---------------------------------------------------------------------------
 
-#[napi]
-pub fn calculate_regulatory_capital(input_json: String) -> NapiResult<String> {
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn calculate_regulatory_capital(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::regulatory::capital::RegulatoryCapitalInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::regulatory::capital::calculate_regulatory_capital(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
-#[napi]
-pub fn calculate_lcr(input_json: String) -> NapiResult<String> {
-    let input: corp_finance_core::regulatory::liquidity::LcrInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn calculate_lcr(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::regulatory::liquidity::LcrInput = env.from_js_value(input)?;
     let output =
         corp_finance_core::regulatory::liquidity::calculate_lcr(&input).map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
-#[napi]
-pub fn calculate_nsfr(input_json: String) -> NapiResult<String> {
-    let input: corp_finance_core::regulatory::liquidity::NsfrInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn calculate_nsfr(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::regulatory::liquidity::NsfrInput = env.from_js_value(input)?;
     let output =
         corp_finance_core::regulatory::liquidity::calculate_nsfr(&input).map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
-#[napi]
-pub fn analyze_alm(input_json: String) -> NapiResult<String> {
-    let input: corp_finance_core::regulatory::alm::AlmInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn analyze_alm(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::regulatory::alm::AlmInput = env.from_js_value(input)?;
     let output = corp_finance_core::regulatory::alm::analyze_alm(&input).map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
+}
+
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn calculate_saccr(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::regulatory::saccr::SaccrInput = env.from_js_value(input)?;
+    let output =
+        corp_finance_core::regulatory::saccr::calculate_saccr(&input).map_err(to_napi_error)?;
+    env.to_js_value(&output)
 }
 
 // ---------------------------------------------------------------------------
 // Private Credit
 // ---------------------------------------------------------------------------
 
-#[napi]
-pub fn price_unitranche(input_json: String) -> NapiResult<String> {
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn price_unitranche(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::private_credit::unitranche::UnitrancheInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::private_credit::unitranche::price_unitranche(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
-#[napi]
-pub fn model_direct_loan(input_json: String) -> NapiResult<String> {
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn model_direct_loan(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::private_credit::direct_lending::DirectLoanInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::private_credit::direct_lending::model_direct_loan(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
-#[napi]
-pub fn analyze_syndication(input_json: String) -> NapiResult<String> {
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn analyze_syndication(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::private_credit::direct_lending::SyndicationInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::private_credit::direct_lending::analyze_syndication(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
+}
+
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn price_leveraged_loan(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::private_credit::loan_pricing::LoanPricingInput =
+        env.from_js_value(input)?;
+    let output = corp_finance_core::private_credit::loan_pricing::price_leveraged_loan(&input)
+        .map_err(to_napi_error)?;
+    env.to_js_value(&output)
+}
+
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn analyze_bridge_financing(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::private_credit::bridge_financing::BridgeFinancingInput =
+        env.from_js_value(input)?;
+    let output =
+        corp_finance_core::private_credit::bridge_financing::analyze_bridge_financing(&input)
+            .map_err(to_napi_error)?;
+    env.to_js_value(&output)
+}
+
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn analyze_fund_finance(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::private_credit::fund_finance::FundFinanceInput =
+        env.from_js_value(input)?;
+    let output = corp_finance_core::private_credit::fund_finance::analyze_fund_finance(&input)
+        .map_err(to_napi_error)?;
+    env.to_js_value(&output)
 }
 
 // ---------------------------------------------------------------------------
 // Insurance
 // ---------------------------------------------------------------------------
 
-#[napi]
-pub fn estimate_reserves(input_json: String) -> NapiResult<String> {
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn estimate_reserves(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::insurance::reserving::ReservingInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::insurance::reserving::estimate_reserves(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
-#[napi]
-pub fn price_premium(input_json: String) -> NapiResult<String> {
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn price_premium(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::insurance::pricing::PremiumPricingInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output =
         corp_finance_core::insurance::pricing::price_premium(&input).map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
-#[napi]
-pub fn analyze_combined_ratio(input_json: String) -> NapiResult<String> {
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn analyze_combined_ratio(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::insurance::pricing::CombinedRatioInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::insurance::pricing::analyze_combined_ratio(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
-#[napi]
-pub fn calculate_scr(input_json: String) -> NapiResult<String> {
-    let input: corp_finance_core::insurance::pricing::ScrInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn calculate_scr(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::insurance::pricing::ScrInput = env.from_js_value(input)?;
     let output =
         corp_finance_core::insurance::pricing::calculate_scr(&input).map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 // ---------------------------------------------------------------------------
 // FP&A (Financial Planning & Analysis)
 // ---------------------------------------------------------------------------
 
-#[napi]
-pub fn analyze_variance(input_json: String) -> NapiResult<String> {
-    let input: corp_finance_core::fpa::variance::VarianceInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn analyze_variance(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::fpa::variance::VarianceInput = env.from_js_value(input)?;
     let output =
         corp_finance_core::fpa::variance::analyze_variance(&input).map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
-#[napi]
-pub fn analyze_breakeven(input_json: String) -> NapiResult<String> {
-    let input: corp_finance_core::fpa::variance::BreakevenInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn analyze_breakeven(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::fpa::variance::BreakevenInput = env.from_js_value(input)?;
     let output =
         corp_finance_core::fpa::variance::analyze_breakeven(&input).map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
-#[napi]
-pub fn analyze_working_capital(input_json: String) -> NapiResult<String> {
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn analyze_working_capital(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::fpa::working_capital::WorkingCapitalInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::fpa::working_capital::analyze_working_capital(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
-#[napi]
-pub fn build_rolling_forecast(input_json: String) -> NapiResult<String> {
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn optimize_inventory(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::fpa::working_capital::InventoryOptimizationInput =
+        env.from_js_value(input)?;
+    let output = corp_finance_core::fpa::working_capital::optimize_inventory(&input)
+        .map_err(to_napi_error)?;
+    env.to_js_value(&output)
+}
+
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn build_rolling_forecast(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::fpa::working_capital::RollingForecastInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::fpa::working_capital::build_rolling_forecast(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
+}
+
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn forecast_backlog_revenue(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::fpa::backlog::BacklogRevenueInput = env.from_js_value(input)?;
+    let output =
+        corp_finance_core::fpa::backlog::forecast_backlog_revenue(&input).map_err(to_napi_error)?;
+    env.to_js_value(&output)
+}
+
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn analyze_saas_metrics(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::fpa::saas_metrics::SaasMetricsInput = env.from_js_value(input)?;
+    let output = corp_finance_core::fpa::saas_metrics::analyze_saas_metrics(&input)
+        .map_err(to_napi_error)?;
+    env.to_js_value(&output)
+}
+
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn analyze_unit_economics(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::fpa::unit_economics::UnitEconomicsInput =
+        env.from_js_value(input)?;
+    let output = corp_finance_core::fpa::unit_economics::analyze_unit_economics(&input)
+        .map_err(to_napi_error)?;
+    env.to_js_value(&output)
 }
 
 // ---------------------------------------------------------------------------
 // Wealth Management
 // ---------------------------------------------------------------------------
 
-#[napi]
-pub fn plan_retirement(input_json: String) -> NapiResult<String> {
-    let input: corp_finance_core::wealth::retirement::RetirementInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn plan_retirement(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::wealth::retirement::RetirementInput = env.from_js_value(input)?;
     let output =
         corp_finance_core::wealth::retirement::plan_retirement(&input).map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
-#[napi]
-pub fn simulate_tax_loss_harvesting(input_json: String) -> NapiResult<String> {
-    let input: corp_finance_core::wealth::tax_estate::TlhInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn simulate_tax_loss_harvesting(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::wealth::tax_estate::TlhInput = env.from_js_value(input)?;
     let output = corp_finance_core::wealth::tax_estate::simulate_tax_loss_harvesting(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
-#[napi]
-pub fn plan_estate(input_json: String) -> NapiResult<String> {
-    let input: corp_finance_core::wealth::tax_estate::EstatePlanInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn plan_estate(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::wealth::tax_estate::EstatePlanInput = env.from_js_value(input)?;
     let output =
         corp_finance_core::wealth::tax_estate::plan_estate(&input).map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
+}
+
+// ---------------------------------------------------------------------------
+// Consumer Lending
+// ---------------------------------------------------------------------------
+
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn build_retail_loan_schedule(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::consumer_lending::loans::RetailLoanInput =
+        env.from_js_value(input)?;
+    let output = corp_finance_core::consumer_lending::loans::build_retail_loan_schedule(&input)
+        .map_err(to_napi_error)?;
+    env.to_js_value(&output)
+}
+
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn calculate_apr(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::consumer_lending::apr::AprInput = env.from_js_value(input)?;
+    let output =
+        corp_finance_core::consumer_lending::apr::calculate_apr(&input).map_err(to_napi_error)?;
+    env.to_js_value(&output)
+}
+
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn analyze_refinance(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::consumer_lending::refinance::RefinanceInput =
+        env.from_js_value(input)?;
+    let output = corp_finance_core::consumer_lending::refinance::analyze_refinance(&input)
+        .map_err(to_napi_error)?;
+    env.to_js_value(&output)
+}
+
+// ---------------------------------------------------------------------------
+// Franchise
+// ---------------------------------------------------------------------------
+
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn analyze_franchise(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::franchise::economics::FranchiseInput =
+        env.from_js_value(input)?;
+    let output = corp_finance_core::franchise::economics::analyze_franchise(&input)
+        .map_err(to_napi_error)?;
+    env.to_js_value(&output)
+}
+
+// ---------------------------------------------------------------------------
+// Capital Budgeting
+// ---------------------------------------------------------------------------
+
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn select_projects(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::capital_budgeting::project_selection::ProjectSelectionInput =
+        env.from_js_value(input)?;
+    let output = corp_finance_core::capital_budgeting::project_selection::select_projects(&input)
+        .map_err(to_napi_error)?;
+    env.to_js_value(&output)
 }
 
 // ---------------------------------------------------------------------------
 // Crypto / Digital Assets — Phase 8
 // ---------------------------------------------------------------------------
 
-#[napi]
-pub fn value_token(input_json: String) -> NapiResult<String> {
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn value_token(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::crypto::valuation::TokenValuationInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output =
         corp_finance_core::crypto::valuation::value_token(&input).map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
-#[napi]
-pub fn analyze_defi(input_json: String) -> NapiResult<String> {
-    let input: corp_finance_core::crypto::defi::DefiYieldInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn analyze_defi(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::crypto::defi::DefiYieldInput = env.from_js_value(input)?;
     let output = corp_finance_core::crypto::defi::analyze_defi(&input).map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 // ---------------------------------------------------------------------------
 // Municipal Bonds — Phase 8
 // ---------------------------------------------------------------------------
 
-#[napi]
-pub fn price_muni_bond(input_json: String) -> NapiResult<String> {
-    let input: corp_finance_core::municipal::bonds::MuniBondInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn price_muni_bond(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::municipal::bonds::MuniBondInput = env.from_js_value(input)?;
     let output =
         corp_finance_core::municipal::bonds::price_muni_bond(&input).map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
-#[napi]
-pub fn analyze_municipal(input_json: String) -> NapiResult<String> {
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn analyze_municipal(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::municipal::analysis::MuniAnalysisInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output =
         corp_finance_core::municipal::analysis::analyze_municipal(&input).map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 // ---------------------------------------------------------------------------
 // Structured Products — Phase 8
 // ---------------------------------------------------------------------------
 
-#[napi]
-pub fn price_structured_note(input_json: String) -> NapiResult<String> {
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn price_structured_note(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::structured_products::notes::StructuredNoteInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::structured_products::notes::price_structured_note(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
-#[napi]
-pub fn price_exotic(input_json: String) -> NapiResult<String> {
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn price_exotic(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::structured_products::exotic::ExoticProductInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::structured_products::exotic::price_exotic(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 // ---------------------------------------------------------------------------
 // Trade Finance — Phase 8
 // ---------------------------------------------------------------------------
 
-#[napi]
-pub fn price_letter_of_credit(input_json: String) -> NapiResult<String> {
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn price_letter_of_credit(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::trade_finance::letter_of_credit::LetterOfCreditInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::trade_finance::letter_of_credit::price_letter_of_credit(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
-#[napi]
-pub fn analyze_supply_chain_finance(input_json: String) -> NapiResult<String> {
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn analyze_supply_chain_finance(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::trade_finance::supply_chain::SupplyChainFinanceInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output =
         corp_finance_core::trade_finance::supply_chain::analyze_supply_chain_finance(&input)
             .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 // ---------------------------------------------------------------------------
@@ -1096,43 +1547,84 @@ pub fn analyze_supply_chain_finance(input_json: String) -> NapiResult<String> {
 // ---------------------------------------------------------------------------
 
 #[napi]
-pub fn price_cds(input_json: String) -> NapiResult<String> {
-    let input: corp_finance_core::credit_derivatives::cds::CdsInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+pub fn price_cds(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::credit_derivatives::cds::CdsInput = env.from_js_value(input)?;
     let output =
         corp_finance_core::credit_derivatives::cds::price_cds(&input).map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 #[napi]
-pub fn calculate_cva(input_json: String) -> NapiResult<String> {
-    let input: corp_finance_core::credit_derivatives::cva::CvaInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+pub fn calculate_cva(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::credit_derivatives::cva::CvaInput = env.from_js_value(input)?;
     let output =
         corp_finance_core::credit_derivatives::cva::calculate_cva(&input).map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
+}
+
+#[napi]
+pub fn calculate_xva(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::credit_derivatives::xva::XvaInput = env.from_js_value(input)?;
+    let output =
+        corp_finance_core::credit_derivatives::xva::calculate_xva(&input).map_err(to_napi_error)?;
+    env.to_js_value(&output)
 }
 
 // ---------------------------------------------------------------------------
 // Convertible Bonds — Phase 9
 // ---------------------------------------------------------------------------
 
-#[napi]
-pub fn price_convertible(input_json: String) -> NapiResult<String> {
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn price_convertible(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::convertibles::pricing::ConvertibleBondInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::convertibles::pricing::price_convertible(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
-#[napi]
-pub fn analyze_convertible(input_json: String) -> NapiResult<String> {
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn analyze_convertible(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::convertibles::analysis::ConvertibleAnalysisInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::convertibles::analysis::analyze_convertible(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
+}
+
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn price_convertible_book(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::convertibles::lattice::ConvertibleBookInput =
+        env.from_js_value(input)?;
+    let output = corp_finance_core::convertibles::lattice::price_convertible_book(&input)
+        .map_err(to_napi_error)?;
+    env.to_js_value(&output)
+}
+
+/// Calibrate a CRR lattice once and return it as an opaque handle for
+/// `price_on_convertible_lattice`.
+#[napi]
+pub fn calibrate_convertible_lattice(
+    env: Env,
+    input: JsUnknown,
+) -> Result<External<corp_finance_core::convertibles::lattice::CalibratedLattice>> {
+    let input: corp_finance_core::convertibles::lattice::LatticeMarketInput =
+        env.from_js_value(input)?;
+    let lattice = corp_finance_core::convertibles::lattice::CalibratedLattice::calibrate(&input)
+        .map_err(to_napi_error)?;
+    Ok(External::new(lattice))
+}
+
+#[napi]
+pub fn price_on_convertible_lattice(
+    env: Env,
+    lattice: External<corp_finance_core::convertibles::lattice::CalibratedLattice>,
+    input: JsUnknown,
+) -> Result<JsUnknown> {
+    let terms: corp_finance_core::convertibles::lattice::LatticeBondTerms =
+        env.from_js_value(input)?;
+    let output = lattice.price(&terms).map_err(to_napi_error)?;
+    env.to_js_value(&output)
 }
 
 // ---------------------------------------------------------------------------
@@ -1140,44 +1632,43 @@ pub fn analyze_convertible(input_json: String) -> NapiResult<String> {
 // ---------------------------------------------------------------------------
 
 #[napi]
-pub fn classify_lease(input_json: String) -> NapiResult<String> {
+pub fn classify_lease(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::lease_accounting::classification::LeaseInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::lease_accounting::classification::classify_lease(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 #[napi]
-pub fn analyze_sale_leaseback(input_json: String) -> NapiResult<String> {
+pub fn analyze_sale_leaseback(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::lease_accounting::sale_leaseback::SaleLeasebackInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output =
         corp_finance_core::lease_accounting::sale_leaseback::analyze_sale_leaseback(&input)
             .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 // ---------------------------------------------------------------------------
 // Pension & LDI — Phase 9
 // ---------------------------------------------------------------------------
 
-#[napi]
-pub fn analyze_pension_funding(input_json: String) -> NapiResult<String> {
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn analyze_pension_funding(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::pension::funding::PensionFundingInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::pension::funding::analyze_pension_funding(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
-#[napi]
-pub fn design_ldi_strategy(input_json: String) -> NapiResult<String> {
-    let input: corp_finance_core::pension::ldi::LdiInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn design_ldi_strategy(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::pension::ldi::LdiInput = env.from_js_value(input)?;
     let output =
         corp_finance_core::pension::ldi::design_ldi_strategy(&input).map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 // ---------------------------------------------------------------------------
@@ -1185,65 +1676,90 @@ pub fn design_ldi_strategy(input_json: String) -> NapiResult<String> {
 // ---------------------------------------------------------------------------
 
 #[napi]
-pub fn analyze_sovereign_bond(input_json: String) -> NapiResult<String> {
+pub fn analyze_sovereign_bond(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::sovereign::sovereign_bonds::SovereignBondInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::sovereign::sovereign_bonds::analyze_sovereign_bond(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 #[napi]
-pub fn assess_country_risk(input_json: String) -> NapiResult<String> {
+pub fn assess_country_risk(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::sovereign::country_risk::CountryRiskInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::sovereign::country_risk::assess_country_risk(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 // ---------------------------------------------------------------------------
 // Real Options — Phase 10
 // ---------------------------------------------------------------------------
 
-#[napi]
-pub fn value_real_option(input_json: String) -> NapiResult<String> {
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn value_real_option(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::real_options::valuation::RealOptionInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::real_options::valuation::value_real_option(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
-#[napi]
-pub fn analyze_decision_tree(input_json: String) -> NapiResult<String> {
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn analyze_decision_tree(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::real_options::decision_tree::DecisionTreeInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::real_options::decision_tree::analyze_decision_tree(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 // ---------------------------------------------------------------------------
 // Equity Research — Phase 10
 // ---------------------------------------------------------------------------
 
-#[napi]
-pub fn calculate_sotp(input_json: String) -> NapiResult<String> {
-    let input: corp_finance_core::equity_research::sotp::SotpInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn calculate_sotp(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::equity_research::sotp::SotpInput = env.from_js_value(input)?;
     let output =
         corp_finance_core::equity_research::sotp::calculate_sotp(&input).map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
-#[napi]
-pub fn calculate_target_price(input_json: String) -> NapiResult<String> {
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn calculate_target_price(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::equity_research::target_price::TargetPriceInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::equity_research::target_price::calculate_target_price(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
+}
+
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn analyze_reit(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::equity_research::reit::ReitInput = env.from_js_value(input)?;
+    let output =
+        corp_finance_core::equity_research::reit::analyze_reit(&input).map_err(to_napi_error)?;
+    env.to_js_value(&output)
+}
+
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn value_bank(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::equity_research::bank_valuation::BankValuationInput =
+        env.from_js_value(input)?;
+    let output = corp_finance_core::equity_research::bank_valuation::value_bank(&input)
+        .map_err(to_napi_error)?;
+    env.to_js_value(&output)
+}
+
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn calculate_reverse_dcf(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::equity_research::target_price::ReverseDcfInput =
+        env.from_js_value(input)?;
+    let output = corp_finance_core::equity_research::target_price::calculate_reverse_dcf(&input)
+        .map_err(to_napi_error)?;
+    env.to_js_value(&output)
 }
 
 // ---------------------------------------------------------------------------
@@ -1251,21 +1767,21 @@ pub fn calculate_target_price(input_json: String) -> NapiResult<String> {
 // ---------------------------------------------------------------------------
 
 #[napi]
-pub fn analyze_commodity_spread(input_json: String) -> NapiResult<String> {
+pub fn analyze_commodity_spread(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::commodity_trading::spreads::CommoditySpreadInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::commodity_trading::spreads::analyze_commodity_spread(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 #[napi]
-pub fn analyze_storage_economics(input_json: String) -> NapiResult<String> {
+pub fn analyze_storage_economics(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::commodity_trading::storage::StorageEconomicsInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::commodity_trading::storage::analyze_storage_economics(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 // ---------------------------------------------------------------------------
@@ -1273,21 +1789,30 @@ pub fn analyze_storage_economics(input_json: String) -> NapiResult<String> {
 // ---------------------------------------------------------------------------
 
 #[napi]
-pub fn analyze_pairs_trading(input_json: String) -> NapiResult<String> {
+pub fn analyze_pairs_trading(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::quant_strategies::pairs_trading::PairsTradingInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::quant_strategies::pairs_trading::analyze_pairs_trading(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 #[napi]
-pub fn analyze_momentum(input_json: String) -> NapiResult<String> {
+pub fn analyze_momentum(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::quant_strategies::momentum::MomentumInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::quant_strategies::momentum::analyze_momentum(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
+}
+
+#[napi]
+pub fn run_event_study(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::quant_strategies::event_study::EventStudyInput =
+        env.from_js_value(input)?;
+    let output = corp_finance_core::quant_strategies::event_study::run_event_study(&input)
+        .map_err(to_napi_error)?;
+    env.to_js_value(&output)
 }
 
 // ---------------------------------------------------------------------------
@@ -1295,21 +1820,20 @@ pub fn analyze_momentum(input_json: String) -> NapiResult<String> {
 // ---------------------------------------------------------------------------
 
 #[napi]
-pub fn analyze_cash_management(input_json: String) -> NapiResult<String> {
+pub fn analyze_cash_management(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::treasury::cash_management::CashManagementInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::treasury::cash_management::analyze_cash_management(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 #[napi]
-pub fn analyze_hedging(input_json: String) -> NapiResult<String> {
-    let input: corp_finance_core::treasury::hedging::HedgingInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+pub fn analyze_hedging(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::treasury::hedging::HedgingInput = env.from_js_value(input)?;
     let output =
         corp_finance_core::treasury::hedging::analyze_hedging(&input).map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 // ---------------------------------------------------------------------------
@@ -1317,21 +1841,21 @@ pub fn analyze_hedging(input_json: String) -> NapiResult<String> {
 // ---------------------------------------------------------------------------
 
 #[napi]
-pub fn model_ppp(input_json: String) -> NapiResult<String> {
+pub fn model_ppp(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::infrastructure::ppp_model::PppModelInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output =
         corp_finance_core::infrastructure::ppp_model::model_ppp(&input).map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 #[napi]
-pub fn value_concession(input_json: String) -> NapiResult<String> {
+pub fn value_concession(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::infrastructure::concession::ConcessionInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::infrastructure::concession::value_concession(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 // ---------------------------------------------------------------------------
@@ -1339,21 +1863,21 @@ pub fn value_concession(input_json: String) -> NapiResult<String> {
 // ---------------------------------------------------------------------------
 
 #[napi]
-pub fn analyze_prospect_theory(input_json: String) -> NapiResult<String> {
+pub fn analyze_prospect_theory(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::behavioral::prospect_theory::ProspectTheoryInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::behavioral::prospect_theory::analyze_prospect_theory(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 #[napi]
-pub fn analyze_sentiment(input_json: String) -> NapiResult<String> {
+pub fn analyze_sentiment(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::behavioral::sentiment::SentimentInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::behavioral::sentiment::analyze_sentiment(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 // ---------------------------------------------------------------------------
@@ -1361,45 +1885,45 @@ pub fn analyze_sentiment(input_json: String) -> NapiResult<String> {
 // ---------------------------------------------------------------------------
 
 #[napi]
-pub fn brinson_attribution(input_json: String) -> NapiResult<String> {
+pub fn brinson_attribution(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::performance_attribution::brinson::BrinsonInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::performance_attribution::brinson::brinson_attribution(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 #[napi]
-pub fn factor_attribution(input_json: String) -> NapiResult<String> {
+pub fn factor_attribution(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::performance_attribution::factor_attribution::FactorAttributionInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output =
         corp_finance_core::performance_attribution::factor_attribution::factor_attribution(&input)
             .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 // ---------------------------------------------------------------------------
 // Credit Portfolio — Phase 12
 // ---------------------------------------------------------------------------
 
-#[napi]
-pub fn calculate_portfolio_credit_risk(input_json: String) -> NapiResult<String> {
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn calculate_portfolio_credit_risk(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::credit_portfolio::portfolio_risk::PortfolioRiskInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output =
         corp_finance_core::credit_portfolio::portfolio_risk::calculate_portfolio_risk(&input)
             .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
-#[napi]
-pub fn calculate_migration(input_json: String) -> NapiResult<String> {
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn calculate_migration(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::credit_portfolio::migration::MigrationInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::credit_portfolio::migration::calculate_migration(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 // ---------------------------------------------------------------------------
@@ -1407,44 +1931,43 @@ pub fn calculate_migration(input_json: String) -> NapiResult<String> {
 // ---------------------------------------------------------------------------
 
 #[napi]
-pub fn analyze_monetary_policy(input_json: String) -> NapiResult<String> {
+pub fn analyze_monetary_policy(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::macro_economics::monetary_policy::MonetaryPolicyInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output =
         corp_finance_core::macro_economics::monetary_policy::analyze_monetary_policy(&input)
             .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 #[napi]
-pub fn analyze_international(input_json: String) -> NapiResult<String> {
+pub fn analyze_international(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::macro_economics::international::InternationalInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::macro_economics::international::analyze_international(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 // ---------------------------------------------------------------------------
 // Compliance — Phase 12
 // ---------------------------------------------------------------------------
 
-#[napi]
-pub fn analyze_best_execution(input_json: String) -> NapiResult<String> {
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn analyze_best_execution(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::compliance::best_execution::BestExecutionInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::compliance::best_execution::analyze_best_execution(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
-#[napi]
-pub fn generate_gips_report(input_json: String) -> NapiResult<String> {
-    let input: corp_finance_core::compliance::reporting::GipsInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn generate_gips_report(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::compliance::reporting::GipsInput = env.from_js_value(input)?;
     let output = corp_finance_core::compliance::reporting::generate_gips_report(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 // ---------------------------------------------------------------------------
@@ -1452,21 +1975,21 @@ pub fn generate_gips_report(input_json: String) -> NapiResult<String> {
 // ---------------------------------------------------------------------------
 
 #[napi]
-pub fn analyze_us_fund_structure(input_json: String) -> NapiResult<String> {
+pub fn analyze_us_fund_structure(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::onshore_structures::us_funds::UsFundInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::onshore_structures::us_funds::analyze_us_fund_structure(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 #[napi]
-pub fn analyze_uk_eu_fund(input_json: String) -> NapiResult<String> {
+pub fn analyze_uk_eu_fund(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::onshore_structures::uk_eu_funds::UkEuFundInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::onshore_structures::uk_eu_funds::analyze_uk_eu_fund(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 // ---------------------------------------------------------------------------
@@ -1474,21 +1997,21 @@ pub fn analyze_uk_eu_fund(input_json: String) -> NapiResult<String> {
 // ---------------------------------------------------------------------------
 
 #[napi]
-pub fn analyze_cayman_structure(input_json: String) -> NapiResult<String> {
+pub fn analyze_cayman_structure(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::offshore_structures::cayman::CaymanFundInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::offshore_structures::cayman::analyze_cayman_structure(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 #[napi]
-pub fn analyze_lux_structure(input_json: String) -> NapiResult<String> {
+pub fn analyze_lux_structure(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::offshore_structures::luxembourg::LuxFundInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::offshore_structures::luxembourg::analyze_lux_structure(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 // ---------------------------------------------------------------------------
@@ -1496,21 +2019,20 @@ pub fn analyze_lux_structure(input_json: String) -> NapiResult<String> {
 // ---------------------------------------------------------------------------
 
 #[napi]
-pub fn analyze_beps_compliance(input_json: String) -> NapiResult<String> {
-    let input: corp_finance_core::transfer_pricing::beps::BepsInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+pub fn analyze_beps_compliance(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::transfer_pricing::beps::BepsInput = env.from_js_value(input)?;
     let output = corp_finance_core::transfer_pricing::beps::analyze_beps_compliance(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 #[napi]
-pub fn analyze_intercompany(input_json: String) -> NapiResult<String> {
+pub fn analyze_intercompany(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::transfer_pricing::intercompany::IntercompanyInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::transfer_pricing::intercompany::analyze_intercompany(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 // ---------------------------------------------------------------------------
@@ -1518,21 +2040,21 @@ pub fn analyze_intercompany(input_json: String) -> NapiResult<String> {
 // ---------------------------------------------------------------------------
 
 #[napi]
-pub fn analyze_treaty_network(input_json: String) -> NapiResult<String> {
+pub fn analyze_treaty_network(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::tax_treaty::treaty_network::TreatyNetworkInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::tax_treaty::treaty_network::analyze_treaty_network(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 #[napi]
-pub fn optimize_treaty_structure(input_json: String) -> NapiResult<String> {
+pub fn optimize_treaty_structure(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::tax_treaty::optimization::TreatyOptInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::tax_treaty::optimization::optimize_treaty_structure(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 // ---------------------------------------------------------------------------
@@ -1540,21 +2062,21 @@ pub fn optimize_treaty_structure(input_json: String) -> NapiResult<String> {
 // ---------------------------------------------------------------------------
 
 #[napi]
-pub fn analyze_fatca_crs_reporting(input_json: String) -> NapiResult<String> {
+pub fn analyze_fatca_crs_reporting(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::fatca_crs::reporting::FatcaCrsReportingInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::fatca_crs::reporting::analyze_fatca_crs_reporting(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 #[napi]
-pub fn classify_entity(input_json: String) -> NapiResult<String> {
+pub fn classify_entity(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::fatca_crs::classification::EntityClassificationInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::fatca_crs::classification::classify_entity(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 // ---------------------------------------------------------------------------
@@ -1562,24 +2084,24 @@ pub fn classify_entity(input_json: String) -> NapiResult<String> {
 // ---------------------------------------------------------------------------
 
 #[napi]
-pub fn analyze_economic_substance(input_json: String) -> NapiResult<String> {
+pub fn analyze_economic_substance(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::substance_requirements::economic_substance::EconomicSubstanceInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output =
         corp_finance_core::substance_requirements::economic_substance::analyze_economic_substance(
             &input,
         )
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 #[napi]
-pub fn run_jurisdiction_substance_test(input_json: String) -> NapiResult<String> {
+pub fn run_jurisdiction_substance_test(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::substance_requirements::jurisdiction_tests::JurisdictionTestInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::substance_requirements::jurisdiction_tests::run_jurisdiction_substance_test(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 // ---------------------------------------------------------------------------
@@ -1587,25 +2109,25 @@ pub fn run_jurisdiction_substance_test(input_json: String) -> NapiResult<String>
 // ---------------------------------------------------------------------------
 
 #[napi]
-pub fn generate_aifmd_report(input_json: String) -> NapiResult<String> {
+pub fn generate_aifmd_report(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::regulatory_reporting::aifmd_reporting::AifmdReportingInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output =
         corp_finance_core::regulatory_reporting::aifmd_reporting::generate_aifmd_report(&input)
             .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 #[napi]
-pub fn generate_sec_cftc_report(input_json: String) -> NapiResult<String> {
+pub fn generate_sec_cftc_report(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::regulatory_reporting::sec_cftc_reporting::SecCftcReportingInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output =
         corp_finance_core::regulatory_reporting::sec_cftc_reporting::generate_sec_cftc_report(
             &input,
         )
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 // ---------------------------------------------------------------------------
@@ -1613,208 +2135,208 @@ pub fn generate_sec_cftc_report(input_json: String) -> NapiResult<String> {
 // ---------------------------------------------------------------------------
 
 #[napi]
-pub fn assess_kyc_risk(input_json: String) -> NapiResult<String> {
+pub fn assess_kyc_risk(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::aml_compliance::kyc_scoring::KycRiskInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::aml_compliance::kyc_scoring::assess_kyc_risk(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 #[napi]
-pub fn screen_sanctions(input_json: String) -> NapiResult<String> {
+pub fn screen_sanctions(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::aml_compliance::sanctions_screening::SanctionsScreeningInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::aml_compliance::sanctions_screening::screen_sanctions(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 // ---------------------------------------------------------------------------
 // Volatility Surface — Phase 15
 // ---------------------------------------------------------------------------
 
-#[napi]
-pub fn build_implied_vol_surface(input_json: String) -> NapiResult<String> {
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn build_implied_vol_surface(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::volatility_surface::implied_vol_surface::ImpliedVolSurfaceInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output =
         corp_finance_core::volatility_surface::implied_vol_surface::build_implied_vol_surface(
             &input,
         )
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
-#[napi]
-pub fn calibrate_sabr(input_json: String) -> NapiResult<String> {
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn calibrate_sabr(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::volatility_surface::sabr_model::SabrCalibrationInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::volatility_surface::sabr_model::calibrate_sabr(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 // ---------------------------------------------------------------------------
 // Portfolio Optimization — Phase 15
 // ---------------------------------------------------------------------------
 
-#[napi]
-pub fn optimize_mean_variance(input_json: String) -> NapiResult<String> {
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn optimize_mean_variance(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::portfolio_optimization::mean_variance::MeanVarianceInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output =
         corp_finance_core::portfolio_optimization::mean_variance::optimize_mean_variance(&input)
             .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
-#[napi]
-pub fn optimize_black_litterman_portfolio(input_json: String) -> NapiResult<String> {
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn optimize_black_litterman_portfolio(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::portfolio_optimization::black_litterman_portfolio::BlackLittermanInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::portfolio_optimization::black_litterman_portfolio::optimize_black_litterman(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 // ---------------------------------------------------------------------------
 // Risk Budgeting — Phase 15
 // ---------------------------------------------------------------------------
 
-#[napi]
-pub fn analyze_factor_risk_budget(input_json: String) -> NapiResult<String> {
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn analyze_factor_risk_budget(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::risk_budgeting::factor_risk_budget::FactorRiskBudgetInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output =
         corp_finance_core::risk_budgeting::factor_risk_budget::analyze_factor_risk_budget(&input)
             .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
-#[napi]
-pub fn analyze_tail_risk(input_json: String) -> NapiResult<String> {
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn analyze_tail_risk(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::risk_budgeting::tail_risk::TailRiskInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::risk_budgeting::tail_risk::analyze_tail_risk(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 // ---------------------------------------------------------------------------
 // Market Microstructure — Phase 15
 // ---------------------------------------------------------------------------
 
-#[napi]
-pub fn analyze_spreads(input_json: String) -> NapiResult<String> {
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn analyze_spreads(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::market_microstructure::spread_analysis::SpreadAnalysisInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::market_microstructure::spread_analysis::analyze_spreads(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
-#[napi]
-pub fn optimize_execution(input_json: String) -> NapiResult<String> {
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn optimize_execution(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::market_microstructure::optimal_execution::OptimalExecutionInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output =
         corp_finance_core::market_microstructure::optimal_execution::optimize_execution(&input)
             .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 // ---------------------------------------------------------------------------
 // Interest Rate Models — Phase 16
 // ---------------------------------------------------------------------------
 
-#[napi]
-pub fn analyze_short_rate(input_json: String) -> NapiResult<String> {
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn analyze_short_rate(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::interest_rate_models::short_rate::ShortRateInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::interest_rate_models::short_rate::analyze_short_rate(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
-#[napi]
-pub fn fit_term_structure(input_json: String) -> NapiResult<String> {
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn fit_term_structure(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::interest_rate_models::term_structure::TermStructureInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output =
         corp_finance_core::interest_rate_models::term_structure::fit_term_structure(&input)
             .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 // ---------------------------------------------------------------------------
 // Mortgage Analytics — Phase 16
 // ---------------------------------------------------------------------------
 
-#[napi]
-pub fn analyze_prepayment(input_json: String) -> NapiResult<String> {
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn analyze_prepayment(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::mortgage_analytics::prepayment::PrepaymentInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::mortgage_analytics::prepayment::analyze_prepayment(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
-#[napi]
-pub fn analyze_mbs(input_json: String) -> NapiResult<String> {
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn analyze_mbs(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::mortgage_analytics::mbs_analytics::MbsAnalyticsInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::mortgage_analytics::mbs_analytics::analyze_mbs(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 // ---------------------------------------------------------------------------
 // Inflation-Linked — Phase 16
 // ---------------------------------------------------------------------------
 
-#[napi]
-pub fn analyze_tips(input_json: String) -> NapiResult<String> {
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn analyze_tips(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::inflation_linked::tips_pricing::TipsAnalyticsInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::inflation_linked::tips_pricing::analyze_tips(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
-#[napi]
-pub fn analyze_inflation_derivatives(input_json: String) -> NapiResult<String> {
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn analyze_inflation_derivatives(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::inflation_linked::inflation_derivatives::InflationDerivativeInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output =
         corp_finance_core::inflation_linked::inflation_derivatives::analyze_inflation_derivatives(
             &input,
         )
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 // ---------------------------------------------------------------------------
 // Repo Financing — Phase 16
 // ---------------------------------------------------------------------------
 
-#[napi]
-pub fn analyze_repo(input_json: String) -> NapiResult<String> {
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn analyze_repo(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::repo_financing::repo_rates::RepoAnalyticsInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::repo_financing::repo_rates::analyze_repo(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
-#[napi]
-pub fn analyze_collateral(input_json: String) -> NapiResult<String> {
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn analyze_collateral(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::repo_financing::collateral_management::CollateralInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output =
         corp_finance_core::repo_financing::collateral_management::analyze_collateral(&input)
             .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 // ---------------------------------------------------------------------------
@@ -1822,49 +2344,49 @@ pub fn analyze_collateral(input_json: String) -> NapiResult<String> {
 // ---------------------------------------------------------------------------
 
 #[napi]
-pub fn calculate_scorecard(input_json: String) -> NapiResult<String> {
+pub fn calculate_scorecard(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::credit_scoring::scorecard::ScorecardInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::credit_scoring::scorecard::calculate_scorecard(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 #[napi]
-pub fn calculate_merton(input_json: String) -> NapiResult<String> {
+pub fn calculate_merton(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::credit_scoring::structural_model::MertonInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::credit_scoring::structural_model::calculate_merton(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 #[napi]
-pub fn calculate_intensity_model(input_json: String) -> NapiResult<String> {
+pub fn calculate_intensity_model(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::credit_scoring::intensity_model::IntensityModelInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output =
         corp_finance_core::credit_scoring::intensity_model::calculate_intensity_model(&input)
             .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 #[napi]
-pub fn calculate_calibration(input_json: String) -> NapiResult<String> {
+pub fn calculate_calibration(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::credit_scoring::calibration::CalibrationInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::credit_scoring::calibration::calculate_calibration(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 #[napi]
-pub fn calculate_scoring_validation(input_json: String) -> NapiResult<String> {
+pub fn calculate_scoring_validation(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::credit_scoring::validation::ValidationInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::credit_scoring::validation::calculate_validation(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 // ---------------------------------------------------------------------------
@@ -1872,53 +2394,53 @@ pub fn calculate_scoring_validation(input_json: String) -> NapiResult<String> {
 // ---------------------------------------------------------------------------
 
 #[napi]
-pub fn calculate_economic_capital(input_json: String) -> NapiResult<String> {
+pub fn calculate_economic_capital(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::capital_allocation::economic_capital::EconomicCapitalInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output =
         corp_finance_core::capital_allocation::economic_capital::calculate_economic_capital(&input)
             .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 #[napi]
-pub fn calculate_raroc(input_json: String) -> NapiResult<String> {
+pub fn calculate_raroc(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::capital_allocation::raroc::RarocInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::capital_allocation::raroc::calculate_raroc(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 #[napi]
-pub fn calculate_euler_allocation(input_json: String) -> NapiResult<String> {
+pub fn calculate_euler_allocation(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::capital_allocation::euler_allocation::EulerAllocationInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output =
         corp_finance_core::capital_allocation::euler_allocation::calculate_euler_allocation(&input)
             .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 #[napi]
-pub fn calculate_shapley_allocation(input_json: String) -> NapiResult<String> {
+pub fn calculate_shapley_allocation(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::capital_allocation::shapley_allocation::ShapleyAllocationInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output =
         corp_finance_core::capital_allocation::shapley_allocation::calculate_shapley_allocation(
             &input,
         )
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 #[napi]
-pub fn evaluate_limits(input_json: String) -> NapiResult<String> {
+pub fn evaluate_limits(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::capital_allocation::limit_management::LimitManagementInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::capital_allocation::limit_management::evaluate_limits(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 // ---------------------------------------------------------------------------
@@ -1926,49 +2448,68 @@ pub fn evaluate_limits(input_json: String) -> NapiResult<String> {
 // ---------------------------------------------------------------------------
 
 #[napi]
-pub fn calculate_clo_waterfall(input_json: String) -> NapiResult<String> {
+pub fn calculate_clo_waterfall(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::clo_analytics::waterfall::WaterfallInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::clo_analytics::waterfall::calculate_waterfall(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 #[napi]
-pub fn calculate_coverage_tests(input_json: String) -> NapiResult<String> {
+pub fn calculate_coverage_tests(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::clo_analytics::coverage_tests::CoverageTestInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::clo_analytics::coverage_tests::calculate_coverage_tests(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
+}
+
+#[napi]
+pub fn calculate_portfolio_metrics(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::clo_analytics::portfolio_metrics::PortfolioMetricsInput =
+        env.from_js_value(input)?;
+    let output =
+        corp_finance_core::clo_analytics::portfolio_metrics::calculate_portfolio_metrics(&input)
+            .map_err(to_napi_error)?;
+    env.to_js_value(&output)
 }
 
 #[napi]
-pub fn calculate_reinvestment(input_json: String) -> NapiResult<String> {
+pub fn calculate_reinvestment(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::clo_analytics::reinvestment::ReinvestmentInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::clo_analytics::reinvestment::calculate_reinvestment(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 #[napi]
-pub fn calculate_tranche_analytics(input_json: String) -> NapiResult<String> {
+pub fn optimize_reinvestment(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::clo_analytics::reinvestment::ReinvestmentOptimizationInput =
+        env.from_js_value(input)?;
+    let output = corp_finance_core::clo_analytics::reinvestment::optimize_reinvestment(&input)
+        .map_err(to_napi_error)?;
+    env.to_js_value(&output)
+}
+
+#[napi]
+pub fn calculate_tranche_analytics(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::clo_analytics::tranche_analytics::TrancheAnalyticsInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output =
         corp_finance_core::clo_analytics::tranche_analytics::calculate_tranche_analytics(&input)
             .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 #[napi]
-pub fn calculate_clo_scenario(input_json: String) -> NapiResult<String> {
+pub fn calculate_clo_scenario(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::clo_analytics::scenario::CloScenarioInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::clo_analytics::scenario::calculate_clo_scenario(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 // ---------------------------------------------------------------------------
@@ -1976,52 +2517,69 @@ pub fn calculate_clo_scenario(input_json: String) -> NapiResult<String> {
 // ---------------------------------------------------------------------------
 
 #[napi]
-pub fn calculate_j_curve(input_json: String) -> NapiResult<String> {
-    let input: corp_finance_core::fund_of_funds::j_curve::JCurveInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+pub fn calculate_j_curve(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::fund_of_funds::j_curve::JCurveInput = env.from_js_value(input)?;
     let output = corp_finance_core::fund_of_funds::j_curve::calculate_j_curve(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 #[napi]
-pub fn calculate_commitment_pacing(input_json: String) -> NapiResult<String> {
+pub fn calculate_commitment_pacing(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::fund_of_funds::commitment_pacing::CommitmentPacingInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output =
         corp_finance_core::fund_of_funds::commitment_pacing::calculate_commitment_pacing(&input)
             .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 #[napi]
-pub fn analyze_manager_selection(input_json: String) -> NapiResult<String> {
+pub fn analyze_manager_selection(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::fund_of_funds::manager_selection::ManagerSelectionInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output =
         corp_finance_core::fund_of_funds::manager_selection::analyze_manager_selection(&input)
             .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 #[napi]
-pub fn calculate_secondaries_pricing(input_json: String) -> NapiResult<String> {
+pub fn calculate_secondaries_pricing(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::fund_of_funds::secondaries::SecondariesPricingInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output =
         corp_finance_core::fund_of_funds::secondaries::calculate_secondaries_pricing(&input)
             .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 #[napi]
-pub fn analyze_fof_portfolio(input_json: String) -> NapiResult<String> {
+pub fn analyze_fof_portfolio(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::fund_of_funds::portfolio_construction::FofPortfolioInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output =
         corp_finance_core::fund_of_funds::portfolio_construction::analyze_fof_portfolio(&input)
             .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
+}
+
+#[napi]
+pub fn analyze_style(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::fund_of_funds::style_analysis::StyleAnalysisInput =
+        env.from_js_value(input)?;
+    let output = corp_finance_core::fund_of_funds::style_analysis::analyze_style(&input)
+        .map_err(to_napi_error)?;
+    env.to_js_value(&output)
+}
+
+#[napi]
+pub fn forecast_cash_flows(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::fund_of_funds::cash_flow_forecast::CashFlowForecastInput =
+        env.from_js_value(input)?;
+    let output = corp_finance_core::fund_of_funds::cash_flow_forecast::forecast_cash_flows(&input)
+        .map_err(to_napi_error)?;
+    env.to_js_value(&output)
 }
 
 // ---------------------------------------------------------------------------
@@ -2029,54 +2587,54 @@ pub fn analyze_fof_portfolio(input_json: String) -> NapiResult<String> {
 // ---------------------------------------------------------------------------
 
 #[napi]
-pub fn calculate_beneish_mscore(input_json: String) -> NapiResult<String> {
+pub fn calculate_beneish_mscore(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::earnings_quality::beneish::BeneishInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::earnings_quality::beneish::calculate_beneish_m_score(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 #[napi]
-pub fn calculate_piotroski_fscore(input_json: String) -> NapiResult<String> {
+pub fn calculate_piotroski_fscore(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::earnings_quality::piotroski::PiotroskiInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output =
         corp_finance_core::earnings_quality::piotroski::calculate_piotroski_f_score(&input)
             .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 #[napi]
-pub fn calculate_accrual_quality(input_json: String) -> NapiResult<String> {
+pub fn calculate_accrual_quality(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::earnings_quality::accrual_quality::AccrualQualityInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output =
         corp_finance_core::earnings_quality::accrual_quality::calculate_accrual_quality(&input)
             .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 #[napi]
-pub fn calculate_revenue_quality(input_json: String) -> NapiResult<String> {
+pub fn calculate_revenue_quality(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::earnings_quality::revenue_quality::RevenueQualityInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output =
         corp_finance_core::earnings_quality::revenue_quality::calculate_revenue_quality(&input)
             .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 #[napi]
-pub fn calculate_earnings_quality_composite(input_json: String) -> NapiResult<String> {
+pub fn calculate_earnings_quality_composite(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::earnings_quality::composite::EarningsQualityCompositeInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output =
         corp_finance_core::earnings_quality::composite::calculate_earnings_quality_composite(
             &input,
         )
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 // ---------------------------------------------------------------------------
@@ -2084,48 +2642,47 @@ pub fn calculate_earnings_quality_composite(input_json: String) -> NapiResult<St
 // ---------------------------------------------------------------------------
 
 #[napi]
-pub fn analyze_nim(input_json: String) -> NapiResult<String> {
+pub fn analyze_nim(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::bank_analytics::nim_analysis::NimAnalysisInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::bank_analytics::nim_analysis::analyze_nim(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 #[napi]
-pub fn calculate_camels_rating(input_json: String) -> NapiResult<String> {
-    let input: corp_finance_core::bank_analytics::camels::CamelsInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+pub fn calculate_camels_rating(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::bank_analytics::camels::CamelsInput = env.from_js_value(input)?;
     let output = corp_finance_core::bank_analytics::camels::calculate_camels(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 #[napi]
-pub fn calculate_cecl_provision(input_json: String) -> NapiResult<String> {
+pub fn calculate_cecl_provision(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::bank_analytics::cecl_provisioning::CeclProvisioningInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::bank_analytics::cecl_provisioning::calculate_cecl(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 #[napi]
-pub fn analyze_deposit_beta(input_json: String) -> NapiResult<String> {
+pub fn analyze_deposit_beta(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::bank_analytics::deposit_beta::DepositBetaInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::bank_analytics::deposit_beta::analyze_deposit_beta(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 #[napi]
-pub fn analyze_loan_book(input_json: String) -> NapiResult<String> {
+pub fn analyze_loan_book(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::bank_analytics::loan_book::LoanBookInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::bank_analytics::loan_book::analyze_loan_book(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 // ---------------------------------------------------------------------------
@@ -2133,55 +2690,84 @@ pub fn analyze_loan_book(input_json: String) -> NapiResult<String> {
 // ---------------------------------------------------------------------------
 
 #[napi]
-pub fn calculate_h_model_ddm(input_json: String) -> NapiResult<String> {
+pub fn calculate_h_model_ddm(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::dividend_policy::h_model::HModelInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::dividend_policy::h_model::calculate_h_model(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 #[napi]
-pub fn calculate_multistage_ddm(input_json: String) -> NapiResult<String> {
+pub fn calculate_multistage_ddm(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::dividend_policy::multistage_ddm::MultistageDdmInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output =
         corp_finance_core::dividend_policy::multistage_ddm::calculate_multistage_ddm(&input)
             .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 #[napi]
-pub fn analyze_buyback(input_json: String) -> NapiResult<String> {
+pub fn analyze_buyback(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::dividend_policy::buyback::BuybackInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::dividend_policy::buyback::calculate_buyback(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 #[napi]
-pub fn analyze_payout_sustainability(input_json: String) -> NapiResult<String> {
+pub fn optimize_payout_policy(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::dividend_policy::payout_optimizer::PayoutOptimizerInput =
+        env.from_js_value(input)?;
+    let output =
+        corp_finance_core::dividend_policy::payout_optimizer::optimize_payout_policy(&input)
+            .map_err(to_napi_error)?;
+    env.to_js_value(&output)
+}
+
+#[napi]
+pub fn model_asr(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::dividend_policy::buyback_execution::AsrInput =
+        env.from_js_value(input)?;
+    let output = corp_finance_core::dividend_policy::buyback_execution::model_asr(&input)
+        .map_err(to_napi_error)?;
+    env.to_js_value(&output)
+}
+
+#[napi]
+pub fn model_open_market_program(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::dividend_policy::buyback_execution::OpenMarketInput =
+        env.from_js_value(input)?;
+    let output =
+        corp_finance_core::dividend_policy::buyback_execution::model_open_market_program(&input)
+            .map_err(to_napi_error)?;
+    env.to_js_value(&output)
+}
+
+#[napi]
+pub fn analyze_payout_sustainability(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::dividend_policy::payout_sustainability::PayoutSustainabilityInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output =
         corp_finance_core::dividend_policy::payout_sustainability::calculate_payout_sustainability(
             &input,
         )
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 #[napi]
-pub fn calculate_total_shareholder_return(input_json: String) -> NapiResult<String> {
+pub fn calculate_total_shareholder_return(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::dividend_policy::total_shareholder_return::TotalShareholderReturnInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output =
         corp_finance_core::dividend_policy::total_shareholder_return::calculate_total_shareholder_return(
             &input,
         )
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 // ---------------------------------------------------------------------------
@@ -2189,51 +2775,50 @@ pub fn calculate_total_shareholder_return(input_json: String) -> NapiResult<Stri
 // ---------------------------------------------------------------------------
 
 #[napi]
-pub fn price_carbon_credit(input_json: String) -> NapiResult<String> {
+pub fn price_carbon_credit(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::carbon_markets::carbon_pricing::CarbonPricingInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output =
         corp_finance_core::carbon_markets::carbon_pricing::calculate_carbon_pricing(&input)
             .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 #[napi]
-pub fn analyze_ets_compliance(input_json: String) -> NapiResult<String> {
+pub fn analyze_ets_compliance(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::carbon_markets::ets_compliance::EtsComplianceInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output =
         corp_finance_core::carbon_markets::ets_compliance::calculate_ets_compliance(&input)
             .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 #[napi]
-pub fn analyze_cbam(input_json: String) -> NapiResult<String> {
-    let input: corp_finance_core::carbon_markets::cbam::CbamInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+pub fn analyze_cbam(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::carbon_markets::cbam::CbamInput = env.from_js_value(input)?;
     let output =
         corp_finance_core::carbon_markets::cbam::calculate_cbam(&input).map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 #[napi]
-pub fn value_carbon_offset(input_json: String) -> NapiResult<String> {
+pub fn value_carbon_offset(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::carbon_markets::offset_valuation::OffsetValuationInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output =
         corp_finance_core::carbon_markets::offset_valuation::calculate_offset_valuation(&input)
             .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 #[napi]
-pub fn calculate_shadow_carbon_price(input_json: String) -> NapiResult<String> {
+pub fn calculate_shadow_carbon_price(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::carbon_markets::shadow_carbon::ShadowCarbonInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::carbon_markets::shadow_carbon::calculate_shadow_carbon(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 // ---------------------------------------------------------------------------
@@ -2241,55 +2826,55 @@ pub fn calculate_shadow_carbon_price(input_json: String) -> NapiResult<String> {
 // ---------------------------------------------------------------------------
 
 #[napi]
-pub fn analyze_concentrated_stock(input_json: String) -> NapiResult<String> {
+pub fn analyze_concentrated_stock(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::private_wealth::concentrated_stock::ConcentratedStockInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output =
         corp_finance_core::private_wealth::concentrated_stock::analyze_concentrated_stock(&input)
             .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 #[napi]
-pub fn compare_philanthropic_vehicles(input_json: String) -> NapiResult<String> {
+pub fn compare_philanthropic_vehicles(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::private_wealth::philanthropic_vehicles::PhilanthropicInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output =
         corp_finance_core::private_wealth::philanthropic_vehicles::compare_philanthropic_vehicles(
             &input,
         )
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 #[napi]
-pub fn analyze_wealth_transfer(input_json: String) -> NapiResult<String> {
+pub fn analyze_wealth_transfer(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::private_wealth::wealth_transfer::WealthTransferInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output =
         corp_finance_core::private_wealth::wealth_transfer::analyze_wealth_transfer(&input)
             .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 #[napi]
-pub fn analyze_direct_indexing(input_json: String) -> NapiResult<String> {
+pub fn analyze_direct_indexing(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::private_wealth::direct_indexing::DirectIndexingInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output =
         corp_finance_core::private_wealth::direct_indexing::analyze_direct_indexing(&input)
             .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 #[napi]
-pub fn evaluate_family_governance(input_json: String) -> NapiResult<String> {
+pub fn evaluate_family_governance(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::private_wealth::family_governance::FamilyGovernanceInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output =
         corp_finance_core::private_wealth::family_governance::evaluate_family_governance(&input)
             .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 // ---------------------------------------------------------------------------
@@ -2297,53 +2882,53 @@ pub fn evaluate_family_governance(input_json: String) -> NapiResult<String> {
 // ---------------------------------------------------------------------------
 
 #[napi]
-pub fn calculate_country_risk_premium(input_json: String) -> NapiResult<String> {
+pub fn calculate_country_risk_premium(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::emerging_markets::country_risk_premium::CountryRiskPremiumInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output =
         corp_finance_core::emerging_markets::country_risk_premium::calculate_country_risk_premium(
             &input,
         )
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 #[napi]
-pub fn assess_political_risk(input_json: String) -> NapiResult<String> {
+pub fn assess_political_risk(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::emerging_markets::political_risk::PoliticalRiskInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::emerging_markets::political_risk::assess_political_risk(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 #[napi]
-pub fn analyse_capital_controls(input_json: String) -> NapiResult<String> {
+pub fn analyse_capital_controls(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::emerging_markets::capital_controls::CapitalControlsInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output =
         corp_finance_core::emerging_markets::capital_controls::analyse_capital_controls(&input)
             .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 #[napi]
-pub fn analyse_em_bonds(input_json: String) -> NapiResult<String> {
+pub fn analyse_em_bonds(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::emerging_markets::em_bond_analysis::EmBondAnalysisInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::emerging_markets::em_bond_analysis::analyse_em_bonds(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 #[napi]
-pub fn calculate_em_equity_premium(input_json: String) -> NapiResult<String> {
+pub fn calculate_em_equity_premium(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::emerging_markets::em_equity_premium::EmEquityPremiumInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output =
         corp_finance_core::emerging_markets::em_equity_premium::calculate_em_equity_premium(&input)
             .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 // ---------------------------------------------------------------------------
@@ -2351,50 +2936,50 @@ pub fn calculate_em_equity_premium(input_json: String) -> NapiResult<String> {
 // ---------------------------------------------------------------------------
 
 #[napi]
-pub fn calculate_weighting(input_json: String) -> NapiResult<String> {
+pub fn calculate_weighting(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::index_construction::weighting::WeightingInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::index_construction::weighting::calculate_weighting(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 #[napi]
-pub fn calculate_rebalancing(input_json: String) -> NapiResult<String> {
+pub fn calculate_rebalancing(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::index_construction::rebalancing::RebalancingInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::index_construction::rebalancing::calculate_rebalancing(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 #[napi]
-pub fn calculate_tracking_error(input_json: String) -> NapiResult<String> {
+pub fn calculate_tracking_error(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::index_construction::tracking_error::TrackingErrorInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output =
         corp_finance_core::index_construction::tracking_error::calculate_tracking_error(&input)
             .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 #[napi]
-pub fn calculate_smart_beta(input_json: String) -> NapiResult<String> {
+pub fn calculate_smart_beta(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::index_construction::smart_beta::SmartBetaInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::index_construction::smart_beta::calculate_smart_beta(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 #[napi]
-pub fn calculate_reconstitution(input_json: String) -> NapiResult<String> {
+pub fn calculate_reconstitution(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::index_construction::reconstitution::ReconstitutionInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output =
         corp_finance_core::index_construction::reconstitution::calculate_reconstitution(&input)
             .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 // ---------------------------------------------------------------------------
@@ -2402,55 +2987,55 @@ pub fn calculate_reconstitution(input_json: String) -> NapiResult<String> {
 // ---------------------------------------------------------------------------
 
 #[napi]
-pub fn analyze_benfords_law(input_json: String) -> NapiResult<String> {
+pub fn analyze_benfords_law(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::financial_forensics::benfords_law::BenfordsLawInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::financial_forensics::benfords_law::analyze_benfords_law(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 #[napi]
-pub fn calculate_dupont(input_json: String) -> NapiResult<String> {
+pub fn calculate_dupont(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::financial_forensics::dupont_analysis::DupontInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::financial_forensics::dupont_analysis::calculate_dupont(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 #[napi]
-pub fn calculate_zscore_models(input_json: String) -> NapiResult<String> {
+pub fn calculate_zscore_models(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::financial_forensics::zscore_models::ZScoreModelsInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output =
         corp_finance_core::financial_forensics::zscore_models::calculate_zscore_models(&input)
             .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 #[napi]
-pub fn calculate_peer_benchmarking(input_json: String) -> NapiResult<String> {
+pub fn calculate_peer_benchmarking(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::financial_forensics::peer_benchmarking::PeerBenchmarkingInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output =
         corp_finance_core::financial_forensics::peer_benchmarking::calculate_peer_benchmarking(
             &input,
         )
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 #[napi]
-pub fn calculate_red_flag_scoring(input_json: String) -> NapiResult<String> {
+pub fn calculate_red_flag_scoring(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::financial_forensics::red_flag_scoring::RedFlagScoringInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output =
         corp_finance_core::financial_forensics::red_flag_scoring::calculate_red_flag_scoring(
             &input,
         )
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 // ---------------------------------------------------------------------------
@@ -2458,46 +3043,656 @@ pub fn calculate_red_flag_scoring(input_json: String) -> NapiResult<String> {
 // ---------------------------------------------------------------------------
 
 #[napi]
-pub fn workflow_list(input_json: String) -> NapiResult<String> {
-    let input: corp_finance_core::workflows::types::WorkflowListInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+pub fn workflow_list(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::workflows::types::WorkflowListInput = env.from_js_value(input)?;
     let output =
         corp_finance_core::workflows::types::list_workflows(&input).map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 #[napi]
-pub fn workflow_describe(input_json: String) -> NapiResult<String> {
+pub fn workflow_describe(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::workflows::types::WorkflowDescribeInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output =
         corp_finance_core::workflows::types::describe_workflow(&input).map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 #[napi]
-pub fn workflow_validate(input_json: String) -> NapiResult<String> {
+pub fn workflow_validate(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::workflows::types::WorkflowValidateInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output =
         corp_finance_core::workflows::types::validate_workflow(&input).map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 #[napi]
-pub fn workflow_quality_check(input_json: String) -> NapiResult<String> {
+pub fn workflow_quality_check(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::workflows::types::WorkflowQualityCheckInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output = corp_finance_core::workflows::types::quality_check_workflow(&input)
         .map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
 }
 
 #[napi]
-pub fn workflow_audit(input_json: String) -> NapiResult<String> {
+pub fn workflow_audit(env: Env, input: JsUnknown) -> Result<JsUnknown> {
     let input: corp_finance_core::workflows::audit::WorkflowAuditInput =
-        serde_json::from_str(&input_json).map_err(to_napi_error)?;
+        env.from_js_value(input)?;
     let output =
         corp_finance_core::workflows::audit::generate_audit_trail(&input).map_err(to_napi_error)?;
-    serde_json::to_string(&output).map_err(to_napi_error)
+    env.to_js_value(&output)
+}
+
+// ---------------------------------------------------------------------------
+// Equity Capital Markets
+// ---------------------------------------------------------------------------
+
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn analyze_ipo(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::ecm::ipo::IpoInput = env.from_js_value(input)?;
+    let output = corp_finance_core::ecm::ipo::analyze_ipo(&input).map_err(to_napi_error)?;
+    env.to_js_value(&output)
+}
+
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn analyze_rights_issue(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::ecm::rights_issue::RightsIssueInput = env.from_js_value(input)?;
+    let output = corp_finance_core::ecm::rights_issue::analyze_rights_issue(&input)
+        .map_err(to_napi_error)?;
+    env.to_js_value(&output)
+}
+
+// ---------------------------------------------------------------------------
+// Fund Structuring
+// ---------------------------------------------------------------------------
+
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn simulate_redemption_stress(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::fund_structuring::liquidity_terms::LiquidityTermsInput =
+        env.from_js_value(input)?;
+    let output =
+        corp_finance_core::fund_structuring::liquidity_terms::simulate_redemption_stress(&input)
+            .map_err(to_napi_error)?;
+    env.to_js_value(&output)
+}
+
+#[napi(ts_return_type = "ComputationOutput")]
+pub fn simulate_semi_liquid_stress(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let input: corp_finance_core::fund_structuring::semi_liquid_stress::SemiLiquidStressInput =
+        env.from_js_value(input)?;
+    let output =
+        corp_finance_core::fund_structuring::semi_liquid_stress::simulate_semi_liquid_stress(
+            &input,
+        )
+        .map_err(to_napi_error)?;
+    env.to_js_value(&output)
+}
+
+// ---------------------------------------------------------------------------
+// Configuration
+// ---------------------------------------------------------------------------
+
+/// Replace the process-wide output precision policy (initially read from
+/// `CFA_MONEY_DP`, `CFA_RATE_DP`, `CFA_RATIO_DP` and `CFA_ROUNDING`).
+#[napi]
+pub fn configure_precision(env: Env, policy: JsUnknown) -> Result<()> {
+    let policy: corp_finance_core::precision::PrecisionPolicy = env.from_js_value(policy)?;
+    corp_finance_core::precision::configure(policy);
+    Ok(())
+}
+
+/// Convert the rate-type fields of an input to decimals, honouring its
+/// `rate_convention` and `strict_units` fields. Returns the normalised
+/// input and any warnings about ambiguous magnitudes.
+#[napi]
+pub fn normalize_rate_inputs(env: Env, input: JsUnknown) -> Result<JsUnknown> {
+    let mut input: serde_json::Value = env.from_js_value(input)?;
+    let warnings = corp_finance_core::units::normalize_input(&mut input, Default::default())
+        .map_err(to_napi_error)?;
+    env.to_js_value(&serde_json::json!({ "input": input, "warnings": warnings }))
+}
+
+/// Resolve `$series` / `$date_index` references in `request.input` against
+/// `request.market_data`, given as JSON in any shape accepted by
+/// `MarketData::from_json` or as CSV text. Returns the resolved input and
+/// the dates of the aligned axis.
+#[napi]
+pub fn resolve_market_data(env: Env, request: JsUnknown) -> Result<JsUnknown> {
+    use corp_finance_core::market_data::{self, MarketData};
+    let mut request: serde_json::Value = env.from_js_value(request)?;
+    let data = match request.get("market_data") {
+        Some(serde_json::Value::String(csv)) => MarketData::from_csv(csv),
+        Some(value) => MarketData::from_json(value),
+        None => Ok(MarketData::default()),
+    }
+    .map_err(to_napi_error)?;
+    let mut input = request
+        .get_mut("input")
+        .map(serde_json::Value::take)
+        .unwrap_or_default();
+    let dates = market_data::resolve_references(&mut input, &data).map_err(to_napi_error)?;
+    env.to_js_value(&serde_json::json!({ "input": input, "dates": dates }))
+}
+
+// ---------------------------------------------------------------------------
+// Batch
+// ---------------------------------------------------------------------------
+
+type BindingFn = fn(Env, JsUnknown) -> Result<JsUnknown>;
+
+/// Bindings callable through `run_batch`, keyed by Rust function name.
+const BATCH_FUNCTIONS: &[(&str, BindingFn)] = &[
+    ("calculate_wacc", calculate_wacc),
+    ("build_cost_of_equity", build_cost_of_equity),
+    ("build_dcf", build_dcf),
+    ("comps_analysis", comps_analysis),
+    ("peer_screen", peer_screen),
+    ("asset_based_valuation", asset_based_valuation),
+    ("value_small_business", value_small_business),
+    ("economic_profit_valuation", economic_profit_valuation),
+    ("calculate_apv", calculate_apv),
+    ("check_rate_consistency", check_rate_consistency),
+    ("apply_valuation_adjustments", apply_valuation_adjustments),
+    ("credit_metrics", credit_metrics),
+    ("debt_capacity", debt_capacity),
+    ("analyze_debt_maturities", analyze_debt_maturities),
+    ("amortization_schedule", amortization_schedule),
+    ("covenant_compliance", covenant_compliance),
+    ("project_covenants", project_covenants),
+    ("counterparty_exposure", counterparty_exposure),
+    ("calculate_returns", calculate_returns),
+    ("build_debt_schedule", build_debt_schedule),
+    ("sources_and_uses", sources_and_uses),
+    ("build_lbo", build_lbo),
+    ("calculate_waterfall", calculate_waterfall),
+    ("estimate_interim_nav", estimate_interim_nav),
+    ("evaluate_co_investment", evaluate_co_investment),
+    ("project_pacing", project_pacing),
+    ("analyze_merger", analyze_merger),
+    ("analyze_merger_arb", analyze_merger_arb),
+    ("analyze_multi_target_merger", analyze_multi_target_merger),
+    ("allocate_purchase_price", allocate_purchase_price),
+    ("analyze_financing", analyze_financing),
+    (
+        "analyze_purchase_price_mechanism",
+        analyze_purchase_price_mechanism,
+    ),
+    ("value_earnout", value_earnout),
+    ("altman_zscore", altman_zscore),
+    ("calculate_fund_fees", calculate_fund_fees),
+    (
+        "compare_performance_fee_variants",
+        compare_performance_fee_variants,
+    ),
+    ("reconcile_accounting", reconcile_accounting),
+    ("calculate_wht", calculate_wht),
+    ("calculate_portfolio_wht", calculate_portfolio_wht),
+    ("calculate_nav", calculate_nav),
+    ("calculate_gp_economics", calculate_gp_economics),
+    (
+        "calculate_investor_net_returns",
+        calculate_investor_net_returns,
+    ),
+    (
+        "build_capital_account_statements",
+        build_capital_account_statements,
+    ),
+    ("screen_ubti_eci", screen_ubti_eci),
+    ("price_bond", price_bond),
+    ("calculate_bond_yield", calculate_bond_yield),
+    ("bootstrap_spot_curve", bootstrap_spot_curve),
+    ("fit_nelson_siegel", fit_nelson_siegel),
+    ("calculate_duration", calculate_duration),
+    ("calculate_credit_spreads", calculate_credit_spreads),
+    ("price_option", price_option),
+    ("implied_volatility", implied_volatility),
+    ("price_heston", price_heston),
+    ("calibrate_heston", calibrate_heston),
+    ("price_forward", price_forward),
+    ("value_forward_position", value_forward_position),
+    ("futures_basis_analysis", futures_basis_analysis),
+    ("value_interest_rate_swap", value_interest_rate_swap),
+    ("value_currency_swap", value_currency_swap),
+    ("analyze_strategy", analyze_strategy),
+    ("risk_adjusted_returns", risk_adjusted_returns),
+    ("risk_metrics", risk_metrics),
+    ("kelly_sizing", kelly_sizing),
+    ("value_portfolio", value_portfolio),
+    ("calculate_revaluation_var", calculate_revaluation_var),
+    ("blended_benchmark", blended_benchmark),
+    ("drawdown_analysis", drawdown_analysis),
+    ("performance_ratios", performance_ratios),
+    ("build_sensitivity_grid", build_sensitivity_grid),
+    ("build_three_statement", build_three_statement),
+    ("model_to_dcf", model_to_dcf),
+    ("run_deal_model", run_deal_model),
+    ("build_cashflow_ladder", build_cashflow_ladder),
+    ("annualize_period", annualize_period),
+    ("calendarize", calendarize),
+    ("build_ltm", build_ltm),
+    ("run_monte_carlo", run_monte_carlo),
+    ("run_mc_dcf", run_mc_dcf),
+    ("run_factor_model", run_factor_model),
+    ("run_black_litterman", run_black_litterman),
+    ("calculate_risk_parity", calculate_risk_parity),
+    ("calculate_hrp", calculate_hrp),
+    ("run_stress_test", run_stress_test),
+    ("analyze_recovery", analyze_recovery),
+    ("analyze_distressed_debt", analyze_distressed_debt),
+    ("value_property", value_property),
+    ("model_project_finance", model_project_finance),
+    ("tenant_schedule", tenant_schedule),
+    ("lease_rollover", lease_rollover),
+    ("comp_adjustment_grid", comp_adjustment_grid),
+    ("comp_reconciliation", comp_reconciliation),
+    ("hbu_analysis", hbu_analysis),
+    ("financially_feasible", financially_feasible),
+    ("cost_approach", cost_approach),
+    ("marshall_swift", marshall_swift),
+    ("ncreif_attribution", ncreif_attribution),
+    ("odce_comparison", odce_comparison),
+    ("acquisition_model", acquisition_model),
+    ("development_feasibility", development_feasibility),
+    ("price_fx_forward", price_fx_forward),
+    ("calculate_cross_rate", calculate_cross_rate),
+    ("price_commodity_forward", price_commodity_forward),
+    ("analyze_commodity_curve", analyze_commodity_curve),
+    ("scenario_analysis", scenario_analysis),
+    ("shift_market_data", shift_market_data),
+    ("model_abs_cashflows", model_abs_cashflows),
+    ("model_cmbs_cashflows", model_cmbs_cashflows),
+    ("analyze_tranching", analyze_tranching),
+    ("model_funding_round", model_funding_round),
+    ("analyze_dilution", analyze_dilution),
+    ("convert_note", convert_note),
+    ("convert_safe", convert_safe),
+    ("model_venture_fund", model_venture_fund),
+    ("calculate_esg_score", calculate_esg_score),
+    ("analyze_carbon_footprint", analyze_carbon_footprint),
+    ("analyze_green_bond", analyze_green_bond),
+    ("test_sll_covenants", test_sll_covenants),
+    ("calculate_regulatory_capital", calculate_regulatory_capital),
+    ("calculate_lcr", calculate_lcr),
+    ("calculate_nsfr", calculate_nsfr),
+    ("analyze_alm", analyze_alm),
+    ("calculate_saccr", calculate_saccr),
+    ("price_unitranche", price_unitranche),
+    ("model_direct_loan", model_direct_loan),
+    ("analyze_syndication", analyze_syndication),
+    ("price_leveraged_loan", price_leveraged_loan),
+    ("analyze_bridge_financing", analyze_bridge_financing),
+    ("analyze_fund_finance", analyze_fund_finance),
+    ("estimate_reserves", estimate_reserves),
+    ("price_premium", price_premium),
+    ("analyze_combined_ratio", analyze_combined_ratio),
+    ("calculate_scr", calculate_scr),
+    ("analyze_variance", analyze_variance),
+    ("analyze_breakeven", analyze_breakeven),
+    ("analyze_working_capital", analyze_working_capital),
+    ("optimize_inventory", optimize_inventory),
+    ("build_rolling_forecast", build_rolling_forecast),
+    ("forecast_backlog_revenue", forecast_backlog_revenue),
+    ("analyze_saas_metrics", analyze_saas_metrics),
+    ("analyze_unit_economics", analyze_unit_economics),
+    ("plan_retirement", plan_retirement),
+    ("simulate_tax_loss_harvesting", simulate_tax_loss_harvesting),
+    ("plan_estate", plan_estate),
+    ("build_retail_loan_schedule", build_retail_loan_schedule),
+    ("calculate_apr", calculate_apr),
+    ("analyze_refinance", analyze_refinance),
+    ("analyze_franchise", analyze_franchise),
+    ("select_projects", select_projects),
+    ("value_token", value_token),
+    ("analyze_defi", analyze_defi),
+    ("price_muni_bond", price_muni_bond),
+    ("analyze_municipal", analyze_municipal),
+    ("price_structured_note", price_structured_note),
+    ("price_exotic", price_exotic),
+    ("price_letter_of_credit", price_letter_of_credit),
+    ("analyze_supply_chain_finance", analyze_supply_chain_finance),
+    ("price_cds", price_cds),
+    ("calculate_cva", calculate_cva),
+    ("calculate_xva", calculate_xva),
+    ("price_convertible", price_convertible),
+    ("analyze_convertible", analyze_convertible),
+    ("price_convertible_book", price_convertible_book),
+    ("classify_lease", classify_lease),
+    ("analyze_sale_leaseback", analyze_sale_leaseback),
+    ("analyze_pension_funding", analyze_pension_funding),
+    ("design_ldi_strategy", design_ldi_strategy),
+    ("analyze_sovereign_bond", analyze_sovereign_bond),
+    ("assess_country_risk", assess_country_risk),
+    ("value_real_option", value_real_option),
+    ("analyze_decision_tree", analyze_decision_tree),
+    ("calculate_sotp", calculate_sotp),
+    ("calculate_target_price", calculate_target_price),
+    ("analyze_reit", analyze_reit),
+    ("value_bank", value_bank),
+    ("calculate_reverse_dcf", calculate_reverse_dcf),
+    ("analyze_commodity_spread", analyze_commodity_spread),
+    ("analyze_storage_economics", analyze_storage_economics),
+    ("analyze_pairs_trading", analyze_pairs_trading),
+    ("analyze_momentum", analyze_momentum),
+    ("run_event_study", run_event_study),
+    ("analyze_cash_management", analyze_cash_management),
+    ("analyze_hedging", analyze_hedging),
+    ("model_ppp", model_ppp),
+    ("value_concession", value_concession),
+    ("analyze_prospect_theory", analyze_prospect_theory),
+    ("analyze_sentiment", analyze_sentiment),
+    ("brinson_attribution", brinson_attribution),
+    ("factor_attribution", factor_attribution),
+    (
+        "calculate_portfolio_credit_risk",
+        calculate_portfolio_credit_risk,
+    ),
+    ("calculate_migration", calculate_migration),
+    ("analyze_monetary_policy", analyze_monetary_policy),
+    ("analyze_international", analyze_international),
+    ("analyze_best_execution", analyze_best_execution),
+    ("generate_gips_report", generate_gips_report),
+    ("analyze_us_fund_structure", analyze_us_fund_structure),
+    ("analyze_uk_eu_fund", analyze_uk_eu_fund),
+    ("analyze_cayman_structure", analyze_cayman_structure),
+    ("analyze_lux_structure", analyze_lux_structure),
+    ("analyze_beps_compliance", analyze_beps_compliance),
+    ("analyze_intercompany", analyze_intercompany),
+    ("analyze_treaty_network", analyze_treaty_network),
+    ("optimize_treaty_structure", optimize_treaty_structure),
+    ("analyze_fatca_crs_reporting", analyze_fatca_crs_reporting),
+    ("classify_entity", classify_entity),
+    ("analyze_economic_substance", analyze_economic_substance),
+    (
+        "run_jurisdiction_substance_test",
+        run_jurisdiction_substance_test,
+    ),
+    ("generate_aifmd_report", generate_aifmd_report),
+    ("generate_sec_cftc_report", generate_sec_cftc_report),
+    ("assess_kyc_risk", assess_kyc_risk),
+    ("screen_sanctions", screen_sanctions),
+    ("build_implied_vol_surface", build_implied_vol_surface),
+    ("calibrate_sabr", calibrate_sabr),
+    ("optimize_mean_variance", optimize_mean_variance),
+    (
+        "optimize_black_litterman_portfolio",
+        optimize_black_litterman_portfolio,
+    ),
+    ("analyze_factor_risk_budget", analyze_factor_risk_budget),
+    ("analyze_tail_risk", analyze_tail_risk),
+    ("analyze_spreads", analyze_spreads),
+    ("optimize_execution", optimize_execution),
+    ("analyze_short_rate", analyze_short_rate),
+    ("fit_term_structure", fit_term_structure),
+    ("analyze_prepayment", analyze_prepayment),
+    ("analyze_mbs", analyze_mbs),
+    ("analyze_tips", analyze_tips),
+    (
+        "analyze_inflation_derivatives",
+        analyze_inflation_derivatives,
+    ),
+    ("analyze_repo", analyze_repo),
+    ("analyze_collateral", analyze_collateral),
+    ("calculate_scorecard", calculate_scorecard),
+    ("calculate_merton", calculate_merton),
+    ("calculate_intensity_model", calculate_intensity_model),
+    ("calculate_calibration", calculate_calibration),
+    ("calculate_scoring_validation", calculate_scoring_validation),
+    ("calculate_economic_capital", calculate_economic_capital),
+    ("calculate_raroc", calculate_raroc),
+    ("calculate_euler_allocation", calculate_euler_allocation),
+    ("calculate_shapley_allocation", calculate_shapley_allocation),
+    ("evaluate_limits", evaluate_limits),
+    ("calculate_clo_waterfall", calculate_clo_waterfall),
+    ("calculate_coverage_tests", calculate_coverage_tests),
+    ("calculate_portfolio_metrics", calculate_portfolio_metrics),
+    ("calculate_reinvestment", calculate_reinvestment),
+    ("optimize_reinvestment", optimize_reinvestment),
+    ("calculate_tranche_analytics", calculate_tranche_analytics),
+    ("calculate_clo_scenario", calculate_clo_scenario),
+    ("calculate_j_curve", calculate_j_curve),
+    ("calculate_commitment_pacing", calculate_commitment_pacing),
+    ("analyze_manager_selection", analyze_manager_selection),
+    (
+        "calculate_secondaries_pricing",
+        calculate_secondaries_pricing,
+    ),
+    ("analyze_fof_portfolio", analyze_fof_portfolio),
+    ("analyze_style", analyze_style),
+    ("forecast_cash_flows", forecast_cash_flows),
+    ("calculate_beneish_mscore", calculate_beneish_mscore),
+    ("calculate_piotroski_fscore", calculate_piotroski_fscore),
+    ("calculate_accrual_quality", calculate_accrual_quality),
+    ("calculate_revenue_quality", calculate_revenue_quality),
+    (
+        "calculate_earnings_quality_composite",
+        calculate_earnings_quality_composite,
+    ),
+    ("analyze_nim", analyze_nim),
+    ("calculate_camels_rating", calculate_camels_rating),
+    ("calculate_cecl_provision", calculate_cecl_provision),
+    ("analyze_deposit_beta", analyze_deposit_beta),
+    ("analyze_loan_book", analyze_loan_book),
+    ("calculate_h_model_ddm", calculate_h_model_ddm),
+    ("calculate_multistage_ddm", calculate_multistage_ddm),
+    ("analyze_buyback", analyze_buyback),
+    ("optimize_payout_policy", optimize_payout_policy),
+    ("model_asr", model_asr),
+    ("model_open_market_program", model_open_market_program),
+    (
+        "analyze_payout_sustainability",
+        analyze_payout_sustainability,
+    ),
+    (
+        "calculate_total_shareholder_return",
+        calculate_total_shareholder_return,
+    ),
+    ("price_carbon_credit", price_carbon_credit),
+    ("analyze_ets_compliance", analyze_ets_compliance),
+    ("analyze_cbam", analyze_cbam),
+    ("value_carbon_offset", value_carbon_offset),
+    (
+        "calculate_shadow_carbon_price",
+        calculate_shadow_carbon_price,
+    ),
+    ("analyze_concentrated_stock", analyze_concentrated_stock),
+    (
+        "compare_philanthropic_vehicles",
+        compare_philanthropic_vehicles,
+    ),
+    ("analyze_wealth_transfer", analyze_wealth_transfer),
+    ("analyze_direct_indexing", analyze_direct_indexing),
+    ("evaluate_family_governance", evaluate_family_governance),
+    (
+        "calculate_country_risk_premium",
+        calculate_country_risk_premium,
+    ),
+    ("assess_political_risk", assess_political_risk),
+    ("analyse_capital_controls", analyse_capital_controls),
+    ("analyse_em_bonds", analyse_em_bonds),
+    ("calculate_em_equity_premium", calculate_em_equity_premium),
+    ("calculate_weighting", calculate_weighting),
+    ("calculate_rebalancing", calculate_rebalancing),
+    ("calculate_tracking_error", calculate_tracking_error),
+    ("calculate_smart_beta", calculate_smart_beta),
+    ("calculate_reconstitution", calculate_reconstitution),
+    ("analyze_benfords_law", analyze_benfords_law),
+    ("calculate_dupont", calculate_dupont),
+    ("calculate_zscore_models", calculate_zscore_models),
+    ("calculate_peer_benchmarking", calculate_peer_benchmarking),
+    ("calculate_red_flag_scoring", calculate_red_flag_scoring),
+    ("workflow_list", workflow_list),
+    ("workflow_describe", workflow_describe),
+    ("workflow_validate", workflow_validate),
+    ("workflow_quality_check", workflow_quality_check),
+    ("workflow_audit", workflow_audit),
+    ("analyze_ipo", analyze_ipo),
+    ("analyze_rights_issue", analyze_rights_issue),
+    ("simulate_redemption_stress", simulate_redemption_stress),
+    ("simulate_semi_liquid_stress", simulate_semi_liquid_stress),
+    ("normalize_rate_inputs", normalize_rate_inputs),
+    ("resolve_market_data", resolve_market_data),
+];
+
+/// Resolve a binding by its snake_case or camelCase name.
+fn find_batch_function(name: &str) -> Option<BindingFn> {
+    let mut snake = String::with_capacity(name.len() + 4);
+    for c in name.chars() {
+        if c.is_ascii_uppercase() {
+            snake.push('_');
+            snake.push(c.to_ascii_lowercase());
+        } else {
+            snake.push(c);
+        }
+    }
+    BATCH_FUNCTIONS
+        .iter()
+        .find(|(n, _)| *n == snake)
+        .map(|(_, f)| *f)
+}
+
+fn run_batch_call(env: Env, call: &JsObject) -> Result<JsUnknown> {
+    let function: String = call.get_named_property("function")?;
+    let f = find_batch_function(&function)
+        .ok_or_else(|| napi::Error::from_reason(format!("Unknown function '{function}'")))?;
+    let input = call.get_named_property("input")?;
+    if call.has_named_property("precision")? {
+        let policy: corp_finance_core::precision::PrecisionPolicy =
+            env.from_js_value(call.get_named_property::<JsUnknown>("precision")?)?;
+        corp_finance_core::precision::with_precision(policy, || f(env, input))
+    } else {
+        f(env, input)
+    }
+}
+
+/// Execute an array of `{function, input}` calls in one native call. An
+/// optional `precision` policy on a call rounds that call's result only.
+///
+/// Returns an array in the same order whose entries are either
+/// `{function, ok: true, result}` or `{function, ok: false, error}`;
+/// a failing call does not abort the rest of the batch.
+#[napi(
+    ts_args_type = "calls: Array<{ function: string; input: unknown; precision?: { money_dp?: number; rate_dp?: number; ratio_dp?: number; mode?: string } }>"
+)]
+pub fn run_batch(env: Env, calls: Vec<JsObject>) -> Result<JsObject> {
+    let mut results = env.create_array_with_length(calls.len())?;
+    for (i, call) in calls.iter().enumerate() {
+        let mut entry = env.create_object()?;
+        let function: JsUnknown = call.get_named_property("function")?;
+        entry.set_named_property("function", function)?;
+        match run_batch_call(env, call) {
+            Ok(result) => {
+                entry.set_named_property("ok", env.get_boolean(true)?)?;
+                entry.set_named_property("result", result)?;
+            }
+            Err(e) => {
+                entry.set_named_property("ok", env.get_boolean(false)?)?;
+                entry.set_named_property("error", env.create_string(&e.reason)?)?;
+            }
+        }
+        results.set_element(i as u32, entry)?;
+    }
+    Ok(results)
+}
+
+#[cfg(test)]
+mod tests {
+    /// Names of every `#[napi] pub fn` in this file, in source order.
+    fn napi_functions() -> Vec<&'static str> {
+        include_str!("lib.rs")
+            .split("\n#[napi")
+            .skip(1)
+            .filter_map(|item| item.split_once("]\npub fn "))
+            .map(|(_, after)| after.split('(').next().unwrap())
+            .collect()
+    }
+
+    /// Names registered in `BATCH_FUNCTIONS`, read from the source: the
+    /// napi symbols only resolve inside Node, so the test binary cannot
+    /// reference the table itself.
+    fn batch_table_names() -> Vec<&'static str> {
+        let source = include_str!("lib.rs");
+        let start = source
+            .find("const BATCH_FUNCTIONS: &[(&str, BindingFn)] = &[")
+            .unwrap();
+        let table = &source[start..start + source[start..].find("\n];").unwrap()];
+        table.split('"').skip(1).step_by(2).collect()
+    }
+
+    /// Bindings that cannot run from a `{function, input}` call: the lattice
+    /// pair passes an opaque handle (`price_convertible_book` is the JSON
+    /// equivalent), `configure_precision` sets process-wide state (batch
+    /// calls take a `precision` policy instead) and `run_batch` itself.
+    const NOT_BATCHABLE: &[&str] = &[
+        "calibrate_convertible_lattice",
+        "price_on_convertible_lattice",
+        "configure_precision",
+        "run_batch",
+    ];
+
+    #[test]
+    fn test_batch_table_registers_every_binding() {
+        let functions = napi_functions();
+        let registered = batch_table_names();
+        for name in NOT_BATCHABLE {
+            assert!(functions.contains(name), "{name} is no longer a binding");
+        }
+        let missing: Vec<&str> = functions
+            .iter()
+            .copied()
+            .filter(|name| !NOT_BATCHABLE.contains(name) && !registered.contains(name))
+            .collect();
+        assert!(missing.is_empty(), "BATCH_FUNCTIONS is missing {missing:?}");
+
+        for name in &registered {
+            assert!(
+                functions.contains(name) && !NOT_BATCHABLE.contains(name),
+                "{name} in BATCH_FUNCTIONS is not a batchable binding"
+            );
+        }
+        let mut unique = registered.clone();
+        unique.sort_unstable();
+        unique.dedup();
+        assert_eq!(unique.len(), registered.len(), "duplicate batch entry");
+        assert_eq!(registered.len() + NOT_BATCHABLE.len(), functions.len());
+    }
+
+    /// `index.d.ts` and `index.js` are generated by `napi build`; a binding
+    /// added without regenerating them is unreachable from Node.
+    #[test]
+    fn test_generated_js_files_export_every_binding() {
+        let dts = include_str!("../index.d.ts");
+        let js = include_str!("../index.js");
+        let mut count = 0;
+        for item in include_str!("lib.rs").split("\n#[napi").skip(1) {
+            let Some((_, after)) = item.split_once("]\npub fn ") else {
+                continue;
+            };
+            let name = after.split('(').next().unwrap();
+            let mut js_name = String::new();
+            let mut upper = false;
+            for c in name.chars() {
+                if c == '_' {
+                    upper = true;
+                } else if upper {
+                    js_name.extend(c.to_uppercase());
+                    upper = false;
+                } else {
+                    js_name.push(c);
+                }
+            }
+            assert!(
+                dts.contains(&format!("export declare function {js_name}(")),
+                "index.d.ts is missing {js_name}; regenerate with `napi build`"
+            );
+            assert!(
+                js.contains(&format!("module.exports.{js_name} = {js_name}\n")),
+                "index.js is missing {js_name}; regenerate with `napi build`"
+            );
+            count += 1;
+        }
+        assert_eq!(count, dts.matches("export declare function ").count());
+    }
 }
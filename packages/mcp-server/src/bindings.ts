@@ -5,10 +5,12 @@
 import pkg from "@robotixai/corp-finance-bindings";
 
 // eslint-disable-next-line @typescript-eslint/no-explicit-any
-const b = pkg as Record<string, (json: string) => string>;
+const b = pkg as Record<string, (input: unknown) => unknown>;
 
 export const acquisitionModel = b.acquisitionModel;
+export const allocatePurchasePrice = b.allocatePurchasePrice;
 export const altmanZscore = b.altmanZscore;
+export const amortizationSchedule = b.amortizationSchedule;
 export const analyseCapitalControls = b.analyseCapitalControls;
 export const analyseEmBonds = b.analyseEmBonds;
 export const analyzeAlm = b.analyzeAlm;
@@ -16,6 +18,7 @@ export const analyzeBenfordsLaw = b.analyzeBenfordsLaw;
 export const analyzeBepsCompliance = b.analyzeBepsCompliance;
 export const analyzeBestExecution = b.analyzeBestExecution;
 export const analyzeBreakeven = b.analyzeBreakeven;
+export const analyzeBridgeFinancing = b.analyzeBridgeFinancing;
 export const analyzeBuyback = b.analyzeBuyback;
 export const analyzeCarbonFootprint = b.analyzeCarbonFootprint;
 export const analyzeCashManagement = b.analyzeCashManagement;
@@ -27,6 +30,7 @@ export const analyzeCommodityCurve = b.analyzeCommodityCurve;
 export const analyzeCommoditySpread = b.analyzeCommoditySpread;
 export const analyzeConcentratedStock = b.analyzeConcentratedStock;
 export const analyzeConvertible = b.analyzeConvertible;
+export const analyzeDebtMaturities = b.analyzeDebtMaturities;
 export const analyzeDecisionTree = b.analyzeDecisionTree;
 export const analyzeDefi = b.analyzeDefi;
 export const analyzeDepositBeta = b.analyzeDepositBeta;
@@ -37,19 +41,25 @@ export const analyzeEconomicSubstance = b.analyzeEconomicSubstance;
 export const analyzeEtsCompliance = b.analyzeEtsCompliance;
 export const analyzeFactorRiskBudget = b.analyzeFactorRiskBudget;
 export const analyzeFatcaCrsReporting = b.analyzeFatcaCrsReporting;
+export const analyzeFinancing = b.analyzeFinancing;
 export const analyzeFofPortfolio = b.analyzeFofPortfolio;
+export const analyzeFranchise = b.analyzeFranchise;
+export const analyzeFundFinance = b.analyzeFundFinance;
 export const analyzeGreenBond = b.analyzeGreenBond;
 export const analyzeHedging = b.analyzeHedging;
 export const analyzeInflationDerivatives = b.analyzeInflationDerivatives;
 export const analyzeIntercompany = b.analyzeIntercompany;
 export const analyzeInternational = b.analyzeInternational;
+export const analyzeIpo = b.analyzeIpo;
 export const analyzeLoanBook = b.analyzeLoanBook;
 export const analyzeLuxStructure = b.analyzeLuxStructure;
 export const analyzeManagerSelection = b.analyzeManagerSelection;
 export const analyzeMbs = b.analyzeMbs;
 export const analyzeMerger = b.analyzeMerger;
+export const analyzeMergerArb = b.analyzeMergerArb;
 export const analyzeMomentum = b.analyzeMomentum;
 export const analyzeMonetaryPolicy = b.analyzeMonetaryPolicy;
+export const analyzeMultiTargetMerger = b.analyzeMultiTargetMerger;
 export const analyzeMunicipal = b.analyzeMunicipal;
 export const analyzeNim = b.analyzeNim;
 export const analyzePairsTrading = b.analyzePairsTrading;
@@ -57,8 +67,13 @@ export const analyzePayoutSustainability = b.analyzePayoutSustainability;
 export const analyzePensionFunding = b.analyzePensionFunding;
 export const analyzePrepayment = b.analyzePrepayment;
 export const analyzeProspectTheory = b.analyzeProspectTheory;
+export const analyzePurchasePriceMechanism = b.analyzePurchasePriceMechanism;
 export const analyzeRecovery = b.analyzeRecovery;
+export const analyzeRefinance = b.analyzeRefinance;
+export const analyzeReit = b.analyzeReit;
 export const analyzeRepo = b.analyzeRepo;
+export const analyzeRightsIssue = b.analyzeRightsIssue;
+export const analyzeSaasMetrics = b.analyzeSaasMetrics;
 export const analyzeSaleLeaseback = b.analyzeSaleLeaseback;
 export const analyzeSentiment = b.analyzeSentiment;
 export const analyzeShortRate = b.analyzeShortRate;
@@ -66,6 +81,7 @@ export const analyzeSovereignBond = b.analyzeSovereignBond;
 export const analyzeSpreads = b.analyzeSpreads;
 export const analyzeStorageEconomics = b.analyzeStorageEconomics;
 export const analyzeStrategy = b.analyzeStrategy;
+export const analyzeStyle = b.analyzeStyle;
 export const analyzeSupplyChainFinance = b.analyzeSupplyChainFinance;
 export const analyzeSyndication = b.analyzeSyndication;
 export const analyzeTailRisk = b.analyzeTailRisk;
@@ -73,23 +89,35 @@ export const analyzeTips = b.analyzeTips;
 export const analyzeTranching = b.analyzeTranching;
 export const analyzeTreatyNetwork = b.analyzeTreatyNetwork;
 export const analyzeUkEuFund = b.analyzeUkEuFund;
+export const analyzeUnitEconomics = b.analyzeUnitEconomics;
 export const analyzeUsFundStructure = b.analyzeUsFundStructure;
 export const analyzeVariance = b.analyzeVariance;
 export const analyzeWealthTransfer = b.analyzeWealthTransfer;
 export const analyzeWorkingCapital = b.analyzeWorkingCapital;
+export const annualizePeriod = b.annualizePeriod;
+export const applyValuationAdjustments = b.applyValuationAdjustments;
 export const assessCountryRisk = b.assessCountryRisk;
 export const assessKycRisk = b.assessKycRisk;
 export const assessPoliticalRisk = b.assessPoliticalRisk;
+export const assetBasedValuation = b.assetBasedValuation;
+export const blendedBenchmark = b.blendedBenchmark;
 export const bootstrapSpotCurve = b.bootstrapSpotCurve;
 export const brinsonAttribution = b.brinsonAttribution;
+export const buildCapitalAccountStatements = b.buildCapitalAccountStatements;
+export const buildCashflowLadder = b.buildCashflowLadder;
+export const buildCostOfEquity = b.buildCostOfEquity;
 export const buildDcf = b.buildDcf;
 export const buildDebtSchedule = b.buildDebtSchedule;
 export const buildImpliedVolSurface = b.buildImpliedVolSurface;
 export const buildLbo = b.buildLbo;
+export const buildLtm = b.buildLtm;
+export const buildRetailLoanSchedule = b.buildRetailLoanSchedule;
 export const buildRollingForecast = b.buildRollingForecast;
 export const buildSensitivityGrid = b.buildSensitivityGrid;
 export const buildThreeStatement = b.buildThreeStatement;
 export const calculateAccrualQuality = b.calculateAccrualQuality;
+export const calculateApr = b.calculateApr;
+export const calculateApv = b.calculateApv;
 export const calculateBeneishMscore = b.calculateBeneishMscore;
 export const calculateBondYield = b.calculateBondYield;
 export const calculateCalibration = b.calculateCalibration;
@@ -113,6 +141,7 @@ export const calculateEulerAllocation = b.calculateEulerAllocation;
 export const calculateFundFees = b.calculateFundFees;
 export const calculateGpEconomics = b.calculateGpEconomics;
 export const calculateHModelDdm = b.calculateHModelDdm;
+export const calculateHrp = b.calculateHrp;
 export const calculateIntensityModel = b.calculateIntensityModel;
 export const calculateInvestorNetReturns = b.calculateInvestorNetReturns;
 export const calculateJCurve = b.calculateJCurve;
@@ -125,6 +154,7 @@ export const calculateNsfr = b.calculateNsfr;
 export const calculatePeerBenchmarking = b.calculatePeerBenchmarking;
 export const calculatePiotroskiFscore = b.calculatePiotroskiFscore;
 export const calculatePortfolioCreditRisk = b.calculatePortfolioCreditRisk;
+export const calculatePortfolioMetrics = b.calculatePortfolioMetrics;
 export const calculateRaroc = b.calculateRaroc;
 export const calculateRebalancing = b.calculateRebalancing;
 export const calculateReconstitution = b.calculateReconstitution;
@@ -132,8 +162,11 @@ export const calculateRedFlagScoring = b.calculateRedFlagScoring;
 export const calculateRegulatoryCapital = b.calculateRegulatoryCapital;
 export const calculateReinvestment = b.calculateReinvestment;
 export const calculateReturns = b.calculateReturns;
+export const calculateRevaluationVar = b.calculateRevaluationVar;
 export const calculateRevenueQuality = b.calculateRevenueQuality;
+export const calculateReverseDcf = b.calculateReverseDcf;
 export const calculateRiskParity = b.calculateRiskParity;
+export const calculateSaccr = b.calculateSaccr;
 export const calculateScorecard = b.calculateScorecard;
 export const calculateScoringValidation = b.calculateScoringValidation;
 export const calculateScr = b.calculateScr;
@@ -150,10 +183,14 @@ export const calculateWacc = b.calculateWacc;
 export const calculateWaterfall = b.calculateWaterfall;
 export const calculateWeighting = b.calculateWeighting;
 export const calculateWht = b.calculateWht;
+export const calculateXva = b.calculateXva;
 export const calculateZscoreModels = b.calculateZscoreModels;
+export const calendarize = b.calendarize;
 export const calibrateSabr = b.calibrateSabr;
+export const checkRateConsistency = b.checkRateConsistency;
 export const classifyEntity = b.classifyEntity;
 export const classifyLease = b.classifyLease;
+export const comparePerformanceFeeVariants = b.comparePerformanceFeeVariants;
 export const comparePhilanthropicVehicles = b.comparePhilanthropicVehicles;
 export const compAdjustmentGrid = b.compAdjustmentGrid;
 export const compReconciliation = b.compReconciliation;
@@ -161,18 +198,25 @@ export const compsAnalysis = b.compsAnalysis;
 export const convertNote = b.convertNote;
 export const convertSafe = b.convertSafe;
 export const costApproach = b.costApproach;
+export const counterpartyExposure = b.counterpartyExposure;
 export const covenantCompliance = b.covenantCompliance;
 export const creditMetrics = b.creditMetrics;
 export const debtCapacity = b.debtCapacity;
 export const designLdiStrategy = b.designLdiStrategy;
 export const developmentFeasibility = b.developmentFeasibility;
+export const drawdownAnalysis = b.drawdownAnalysis;
+export const economicProfitValuation = b.economicProfitValuation;
+export const estimateInterimNav = b.estimateInterimNav;
 export const estimateReserves = b.estimateReserves;
+export const evaluateCoInvestment = b.evaluateCoInvestment;
 export const evaluateFamilyGovernance = b.evaluateFamilyGovernance;
 export const evaluateLimits = b.evaluateLimits;
 export const factorAttribution = b.factorAttribution;
 export const financiallyFeasible = b.financiallyFeasible;
 export const fitNelsonSiegel = b.fitNelsonSiegel;
 export const fitTermStructure = b.fitTermStructure;
+export const forecastBacklogRevenue = b.forecastBacklogRevenue;
+export const forecastCashFlows = b.forecastCashFlows;
 export const futuresBasisAnalysis = b.futuresBasisAnalysis;
 export const generateAifmdReport = b.generateAifmdReport;
 export const generateGipsReport = b.generateGipsReport;
@@ -183,17 +227,26 @@ export const kellySizing = b.kellySizing;
 export const leaseRollover = b.leaseRollover;
 export const marshallSwift = b.marshallSwift;
 export const modelAbsCashflows = b.modelAbsCashflows;
+export const modelAsr = b.modelAsr;
+export const modelCmbsCashflows = b.modelCmbsCashflows;
 export const modelDirectLoan = b.modelDirectLoan;
 export const modelFundingRound = b.modelFundingRound;
+export const modelOpenMarketProgram = b.modelOpenMarketProgram;
 export const modelPpp = b.modelPpp;
 export const modelProjectFinance = b.modelProjectFinance;
+export const modelToDcf = b.modelToDcf;
 export const modelVentureFund = b.modelVentureFund;
 export const ncreifAttribution = b.ncreifAttribution;
 export const odceComparison = b.odceComparison;
 export const optimizeBlackLittermanPortfolio = b.optimizeBlackLittermanPortfolio;
 export const optimizeExecution = b.optimizeExecution;
+export const optimizeInventory = b.optimizeInventory;
 export const optimizeMeanVariance = b.optimizeMeanVariance;
+export const optimizePayoutPolicy = b.optimizePayoutPolicy;
+export const optimizeReinvestment = b.optimizeReinvestment;
 export const optimizeTreatyStructure = b.optimizeTreatyStructure;
+export const peerScreen = b.peerScreen;
+export const performanceRatios = b.performanceRatios;
 export const planEstate = b.planEstate;
 export const planRetirement = b.planRetirement;
 export const priceBond = b.priceBond;
@@ -201,19 +254,25 @@ export const priceCarbonCredit = b.priceCarbonCredit;
 export const priceCds = b.priceCds;
 export const priceCommodityForward = b.priceCommodityForward;
 export const priceConvertible = b.priceConvertible;
+export const priceConvertibleBook = b.priceConvertibleBook;
 export const priceExotic = b.priceExotic;
 export const priceForward = b.priceForward;
 export const priceFxForward = b.priceFxForward;
 export const priceLetterOfCredit = b.priceLetterOfCredit;
+export const priceLeveragedLoan = b.priceLeveragedLoan;
 export const priceMuniBond = b.priceMuniBond;
 export const priceOption = b.priceOption;
 export const pricePremium = b.pricePremium;
 export const priceStructuredNote = b.priceStructuredNote;
 export const priceUnitranche = b.priceUnitranche;
+export const projectCovenants = b.projectCovenants;
+export const projectPacing = b.projectPacing;
 export const reconcileAccounting = b.reconcileAccounting;
 export const riskAdjustedReturns = b.riskAdjustedReturns;
 export const riskMetrics = b.riskMetrics;
 export const runBlackLitterman = b.runBlackLitterman;
+export const runDealModel = b.runDealModel;
+export const runEventStudy = b.runEventStudy;
 export const runFactorModel = b.runFactorModel;
 export const runJurisdictionSubstanceTest = b.runJurisdictionSubstanceTest;
 export const runMcDcf = b.runMcDcf;
@@ -222,17 +281,25 @@ export const runStressTest = b.runStressTest;
 export const scenarioAnalysis = b.scenarioAnalysis;
 export const screenSanctions = b.screenSanctions;
 export const screenUbtiEci = b.screenUbtiEci;
+export const selectProjects = b.selectProjects;
+export const shiftMarketData = b.shiftMarketData;
+export const simulateRedemptionStress = b.simulateRedemptionStress;
+export const simulateSemiLiquidStress = b.simulateSemiLiquidStress;
 export const simulateTaxLossHarvesting = b.simulateTaxLossHarvesting;
 export const sourcesAndUses = b.sourcesAndUses;
 export const tenantSchedule = b.tenantSchedule;
 export const testSllCovenants = b.testSllCovenants;
+export const valueBank = b.valueBank;
 export const valueCarbonOffset = b.valueCarbonOffset;
 export const valueConcession = b.valueConcession;
 export const valueCurrencySwap = b.valueCurrencySwap;
+export const valueEarnout = b.valueEarnout;
 export const valueForwardPosition = b.valueForwardPosition;
 export const valueInterestRateSwap = b.valueInterestRateSwap;
+export const valuePortfolio = b.valuePortfolio;
 export const valueProperty = b.valueProperty;
 export const valueRealOption = b.valueRealOption;
+export const valueSmallBusiness = b.valueSmallBusiness;
 export const valueToken = b.valueToken;
 export const workflowList = b.workflowList;
 export const workflowDescribe = b.workflowDescribe;
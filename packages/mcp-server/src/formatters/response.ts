@@ -9,14 +9,15 @@ export function coerceNumbers(obj: unknown): unknown {
   return obj;
 }
 
-export function wrapResponse(resultJson: unknown) {
-  if (resultJson instanceof Error) {
+export function wrapResponse(result: unknown) {
+  if (result instanceof Error) {
     return {
-      content: [{ type: "text" as const, text: JSON.stringify({ error: resultJson.message }) }],
+      content: [{ type: "text" as const, text: JSON.stringify({ error: result.message }) }],
       isError: true,
     };
   }
+  const text = typeof result === "string" ? result : JSON.stringify(result);
   return {
-    content: [{ type: "text" as const, text: String(resultJson) }],
+    content: [{ type: "text" as const, text }],
   };
 }
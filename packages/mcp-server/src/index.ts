@@ -1,6 +1,7 @@
 #!/usr/bin/env node
 import { McpServer } from "@modelcontextprotocol/sdk/server/mcp.js";
 import { StdioServerTransport } from "@modelcontextprotocol/sdk/server/stdio.js";
+import { instrumentTools, startMetricsServer } from "./metrics.js";
 import { registerValuationTools } from "./tools/valuation.js";
 import { registerCreditTools } from "./tools/credit.js";
 import { registerPETools } from "./tools/pe.js";
@@ -11,6 +12,11 @@ import { registerJurisdictionTools } from "./tools/jurisdiction.js";
 import { registerFixedIncomeTools } from "./tools/fixed_income.js";
 import { registerDerivativesTools } from "./tools/derivatives.js";
 import { registerThreeStatementTools } from "./tools/three_statement.js";
+import { registerDealModelTools } from "./tools/deal_model.js";
+import { registerCashflowLadderTools } from "./tools/cashflow_ladder.js";
+import { registerConsumerLendingTools } from "./tools/consumer_lending.js";
+import { registerFranchiseTools } from "./tools/franchise.js";
+import { registerCapitalBudgetingTools } from "./tools/capital_budgeting.js";
 import { registerMonteCarloTools } from "./tools/monte_carlo.js";
 import { registerQuantRiskTools } from "./tools/quant_risk.js";
 import { registerRestructuringTools } from "./tools/restructuring.js";
@@ -64,6 +70,7 @@ import { registerCreditScoringTools } from "./tools/credit_scoring.js";
 import { registerCapitalAllocationTools } from "./tools/capital_allocation.js";
 import { registerCloAnalyticsTools } from "./tools/clo_analytics.js";
 import { registerFundOfFundsTools } from "./tools/fund_of_funds.js";
+import { registerFundStructuringTools } from "./tools/fund_structuring.js";
 import { registerEarningsQualityTools } from "./tools/earnings_quality.js";
 import { registerBankAnalyticsTools } from "./tools/bank_analytics.js";
 import { registerDividendPolicyTools } from "./tools/dividend_policy.js";
@@ -73,6 +80,7 @@ import { registerEmergingMarketsTools } from "./tools/emerging_markets.js";
 import { registerIndexConstructionTools } from "./tools/index_construction.js";
 import { registerInstitutionalRealEstateTools } from "./tools/institutional_real_estate.js";
 import { registerFinancialForensicsTools } from "./tools/financial_forensics.js";
+import { registerEcmTools } from "./tools/ecm.js";
 import { registerWorkflowTools } from "./tools/workflows.js";
 
 const server = new McpServer({
@@ -80,6 +88,9 @@ const server = new McpServer({
   version: "1.0.0",
 });
 
+// Count and time every tool call; registrations below go through the wrapper
+instrumentTools(server);
+
 registerValuationTools(server);
 registerCreditTools(server);
 registerPETools(server);
@@ -90,6 +101,8 @@ registerJurisdictionTools(server);
 registerFixedIncomeTools(server);
 registerDerivativesTools(server);
 registerThreeStatementTools(server);
+registerDealModelTools(server);
+registerCashflowLadderTools(server);
 registerMonteCarloTools(server);
 registerQuantRiskTools(server);
 registerRestructuringTools(server);
@@ -103,6 +116,9 @@ registerPrivateCreditTools(server);
 registerInsuranceTools(server);
 registerFpaTools(server);
 registerWealthTools(server);
+registerConsumerLendingTools(server);
+registerFranchiseTools(server);
+registerCapitalBudgetingTools(server);
 registerCryptoTools(server);
 registerMunicipalTools(server);
 registerStructuredProductsTools(server);
@@ -143,6 +159,7 @@ registerCreditScoringTools(server);
 registerCapitalAllocationTools(server);
 registerCloAnalyticsTools(server);
 registerFundOfFundsTools(server);
+registerFundStructuringTools(server);
 registerEarningsQualityTools(server);
 registerBankAnalyticsTools(server);
 registerDividendPolicyTools(server);
@@ -152,7 +169,14 @@ registerEmergingMarketsTools(server);
 registerIndexConstructionTools(server);
 registerInstitutionalRealEstateTools(server);
 registerFinancialForensicsTools(server);
+registerEcmTools(server);
 registerWorkflowTools(server);
 
+// Server mode: expose Prometheus metrics when a port is configured
+const metricsPort = Number(process.env.CFA_METRICS_PORT);
+if (Number.isInteger(metricsPort) && metricsPort > 0) {
+  startMetricsServer(metricsPort);
+}
+
 const transport = new StdioServerTransport();
 await server.connect(transport);
@@ -0,0 +1,127 @@
+import { createServer, type Server } from "node:http";
+import type { McpServer } from "@modelcontextprotocol/sdk/server/mcp.js";
+
+/**
+ * Per-tool request counts, error counts and latency histograms, exposed in
+ * the Prometheus text format. Kept in-process with no extra dependencies.
+ */
+
+// Seconds; calculators range from microseconds to multi-second simulations
+const DURATION_BUCKETS = [0.001, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1, 2.5, 5, 10];
+
+interface ToolStats {
+  requests: number;
+  errors: number;
+  durationSum: number;
+  bucketCounts: number[];
+}
+
+const stats = new Map<string, ToolStats>();
+
+function statsFor(tool: string): ToolStats {
+  let s = stats.get(tool);
+  if (!s) {
+    s = {
+      requests: 0,
+      errors: 0,
+      durationSum: 0,
+      bucketCounts: DURATION_BUCKETS.map(() => 0),
+    };
+    stats.set(tool, s);
+  }
+  return s;
+}
+
+/** Record one tool call. */
+export function recordCall(tool: string, seconds: number, failed: boolean): void {
+  const s = statsFor(tool);
+  s.requests += 1;
+  if (failed) s.errors += 1;
+  s.durationSum += seconds;
+  DURATION_BUCKETS.forEach((le, i) => {
+    if (seconds <= le) s.bucketCounts[i] += 1;
+  });
+}
+
+/**
+ * Wrap every tool registered on `server` after this call so each invocation
+ * is timed and counted. A handler that throws (e.g. schema validation or a
+ * calculator error) or returns `isError` counts as an error.
+ */
+export function instrumentTools(server: McpServer): void {
+  const register = server.tool.bind(server) as unknown as (...args: unknown[]) => unknown;
+  (server as unknown as { tool: (...args: unknown[]) => unknown }).tool = (
+    ...args: unknown[]
+  ) => {
+    const name = String(args[0]);
+    const handler = args[args.length - 1];
+    if (typeof handler === "function") {
+      args[args.length - 1] = async (...handlerArgs: unknown[]) => {
+        const start = process.hrtime.bigint();
+        let failed = true;
+        try {
+          const result = await handler(...handlerArgs);
+          failed = Boolean((result as { isError?: boolean } | undefined)?.isError);
+          return result;
+        } finally {
+          const seconds = Number(process.hrtime.bigint() - start) / 1e9;
+          recordCall(name, seconds, failed);
+        }
+      };
+    }
+    return register(...args);
+  };
+}
+
+function label(tool: string): string {
+  return tool.replace(/\\/g, "\\\\").replace(/"/g, '\\"');
+}
+
+/** Render all metrics in the Prometheus text exposition format. */
+export function renderMetrics(): string {
+  const tools = [...stats.keys()].sort();
+  const lines: string[] = [
+    "# HELP cfa_tool_requests_total Tool calls handled, by tool.",
+    "# TYPE cfa_tool_requests_total counter",
+    ...tools.map((t) => `cfa_tool_requests_total{tool="${label(t)}"} ${stats.get(t)!.requests}`),
+    "# HELP cfa_tool_errors_total Tool calls that failed validation or calculation, by tool.",
+    "# TYPE cfa_tool_errors_total counter",
+    ...tools.map((t) => `cfa_tool_errors_total{tool="${label(t)}"} ${stats.get(t)!.errors}`),
+    "# HELP cfa_tool_duration_seconds Tool call latency, by tool.",
+    "# TYPE cfa_tool_duration_seconds histogram",
+  ];
+  for (const t of tools) {
+    const s = stats.get(t)!;
+    DURATION_BUCKETS.forEach((le, i) => {
+      lines.push(
+        `cfa_tool_duration_seconds_bucket{tool="${label(t)}",le="${le}"} ${s.bucketCounts[i]}`
+      );
+    });
+    lines.push(`cfa_tool_duration_seconds_bucket{tool="${label(t)}",le="+Inf"} ${s.requests}`);
+    lines.push(`cfa_tool_duration_seconds_sum{tool="${label(t)}"} ${s.durationSum}`);
+    lines.push(`cfa_tool_duration_seconds_count{tool="${label(t)}"} ${s.requests}`);
+  }
+  return lines.join("\n") + "\n";
+}
+
+/**
+ * Serve `GET /metrics` on `port`. Logs go to stderr because stdout carries
+ * the MCP stdio transport.
+ */
+export function startMetricsServer(port: number): Server {
+  const server = createServer((req, res) => {
+    if (req.method === "GET" && req.url?.split("?")[0] === "/metrics") {
+      res.writeHead(200, { "Content-Type": "text/plain; version=0.0.4; charset=utf-8" });
+      res.end(renderMetrics());
+    } else {
+      res.writeHead(404).end();
+    }
+  });
+  server.on("error", (err) => {
+    console.error(`corp-finance-mcp: metrics server failed: ${err.message}`);
+  });
+  server.listen(port, () => {
+    console.error(`corp-finance-mcp: Prometheus metrics on :${port}/metrics`);
+  });
+  return server;
+}
@@ -0,0 +1,20 @@
+import { z } from "zod";
+
+// --- ProjectSelectionInput ---
+// Rust struct: ProjectSelectionInput in capital_budgeting/project_selection.rs
+export const ProjectSelectionSchema = z.object({
+  projects: z.array(z.object({
+    name: z.string().describe("Unique project name"),
+    npv: z.coerce.number().optional().describe("Project NPV; computed from cash_flows at discount_rate when omitted"),
+    irr: z.coerce.number().optional().describe("Project IRR for reporting; computed from cash_flows when omitted"),
+    cash_flows: z.array(z.coerce.number()).optional().describe("Net cash flows from year 0"),
+    capex: z.array(z.coerce.number().min(0)).describe("Capex drawn against each budget year, year 1 first"),
+    mandatory: z.boolean().optional().describe("Must be in the portfolio"),
+    requires: z.array(z.string()).optional().describe("Projects that must also be selected"),
+    exclusive_group: z.string().optional().describe("At most one project per group is selected"),
+  })).min(1).describe("Candidate projects"),
+  budgets: z.array(z.coerce.number().min(0)).min(1).describe("Capex budget per year, year 1 first"),
+  discount_rate: z.coerce.number().optional().describe("Discount rate for projects given as cash flows"),
+  method: z.enum(["Auto", "Exact", "Heuristic"]).optional().describe("Auto = branch and bound up to 20 projects, else greedy + local search"),
+  budget_relaxation: z.coerce.number().positive().optional().describe("Fractional budget increase for shadow values (default 0.10)"),
+});
@@ -0,0 +1,107 @@
+import { z } from "zod";
+import { AmortizationSchema } from "./credit.js";
+import { BondPricingSchema, CurveSchema } from "./fixed_income.js";
+import { LeaseClassificationSchema } from "./lease_accounting.js";
+import { ProjectFinanceSchema } from "./real_assets.js";
+import { AbsMbsSchema, TranchingSchema } from "./securitization.js";
+
+// --- CashFlowLadderInput ---
+// Rust struct: CashFlowLadderInput in cashflow_ladder/ladder.rs
+// Each position's source embeds the input of the model that projects it.
+const PeriodMonths = z.coerce.number().int().positive().describe("Months between payments");
+
+const CashFlowSourceSchema = z.discriminatedUnion("type", [
+  z.object({
+    type: z.literal("bond"),
+    bond: BondPricingSchema.describe("Bond terms; coupons are interest, redemption is principal"),
+  }),
+  z.object({
+    type: z.literal("loan"),
+    loan: AmortizationSchema.describe("Amortising loan; cash interest and principal per period"),
+    first_payment_date: z.string().describe("First payment date (YYYY-MM-DD)"),
+    period_months: PeriodMonths,
+  }),
+  z.object({
+    type: z.literal("lease"),
+    lease: LeaseClassificationSchema.describe("Lease terms; monthly payments in arrears"),
+    commencement_date: z.string().describe("Lease commencement date (YYYY-MM-DD)"),
+  }),
+  z.object({
+    type: z.literal("abs_pool"),
+    pool: AbsMbsSchema.describe("Collateral pool; all principal, net interest and recoveries"),
+    first_payment_date: z
+      .string()
+      .optional()
+      .describe("Date of month 1 when the pool has none (default one month after as_of)"),
+  }),
+  z.object({
+    type: z.literal("abs_tranche"),
+    deal: TranchingSchema.describe("Securitisation waterfall"),
+    tranche_name: z.string().describe("Tranche whose interest and principal are held"),
+    first_payment_date: z.string().describe("Date of waterfall period 1 (YYYY-MM-DD)"),
+    period_months: PeriodMonths,
+  }),
+  z.object({
+    type: z.literal("project_finance"),
+    project: ProjectFinanceSchema.describe("Project finance model input"),
+    start_date: z.string().describe("Project start; year k flows fall k years later"),
+    component: z
+      .enum(["senior_debt", "equity"])
+      .describe("Senior lender flows (draws negative) or equity distributions"),
+  }),
+  z.object({
+    type: z.literal("annuity"),
+    payment: z.coerce.number().describe("First payment"),
+    periods: z.coerce.number().int().positive().describe("Number of payments"),
+    period_months: PeriodMonths,
+    first_payment_date: z.string().describe("First payment date (YYYY-MM-DD)"),
+    growth_rate: z.coerce.number().optional().describe("Payment growth per period (default 0)"),
+  }),
+  z.object({
+    type: z.literal("scheduled"),
+    flows: z
+      .array(
+        z.object({
+          date: z.string().describe("Payment date (YYYY-MM-DD)"),
+          interest: z.coerce.number().optional().describe("Interest"),
+          principal: z.coerce.number().optional().describe("Principal"),
+          other: z.coerce.number().optional().describe("Other cash"),
+        })
+      )
+      .describe("Flows from any other model"),
+  }),
+]);
+
+export const CashFlowLadderSchema = z.object({
+  as_of: z.string().describe("Ladder date (YYYY-MM-DD); earlier flows are left out"),
+  positions: z
+    .array(
+      z.object({
+        id: z.string().describe("Unique position id"),
+        side: z.enum(["asset", "liability"]).optional().describe("Received or paid (default asset)"),
+        quantity: z.coerce
+          .number()
+          .optional()
+          .describe("Multiplier on the model's flows, e.g. bonds held or share of a tranche (default 1)"),
+        source: CashFlowSourceSchema.describe("Model the flows are projected from, tagged by type"),
+      })
+    )
+    .min(1)
+    .describe("Positions in the book, all in one currency"),
+  bucketing: z
+    .union([
+      z.object({
+        tenor: z
+          .array(z.coerce.number().int().positive())
+          .min(1)
+          .describe("Bucket end points in months after as_of, increasing"),
+      }),
+      z.object({
+        periodic: z.coerce.number().int().positive().describe("Bucket length in months"),
+      }),
+    ])
+    .optional()
+    .describe("Tenor or periodic buckets (default 1M, 3M, 6M, 1Y, 2Y, 3Y, 5Y, 10Y and over)"),
+  discount_curve: CurveSchema.optional().describe("Curve for present values and durations"),
+  include_flows: z.boolean().optional().describe("Return every dated flow (default true)"),
+});
@@ -16,10 +16,26 @@ export const CloWaterfallSchema = z.object({
   recovery_lag_months: z.coerce.number().int().describe("Recovery lag in months"),
   reference_rate: z.coerce.number().describe("Reference rate (e.g. SOFR) as decimal"),
   num_periods: z.coerce.number().int().describe("Number of periods to project"),
+  loan_tape: CloLoanTapeSchema.optional().describe("Loan tape; adds WARF, WAS, WAL and diversity score to the output"),
   period_days: z.coerce.number().int().describe("Days per period (e.g. 90 for quarterly)"),
   senior_fees_bps: z.coerce.number().describe("Senior fees in basis points"),
 });
 
+const CloLoanTapeSchema = z.array(z.object({
+  name: z.string().describe("Loan or facility identifier"),
+  obligor: z.string().optional().describe("Borrower; facilities to one obligor are aggregated for diversity (default the loan name)"),
+  par: z.coerce.number().min(0).describe("Outstanding par"),
+  rating: z.string().describe("Rating in any agency notation (e.g. 'B2', 'B')"),
+  spread: z.coerce.number().describe("Spread over the reference rate as decimal (0.035 = 350bp)"),
+  remaining_life: z.coerce.number().min(0).describe("Remaining life in years"),
+  industry: z.string().describe("Moody's industry classification"),
+  defaulted: z.boolean().optional().describe("Defaulted obligation, excluded from the quality measures"),
+}));
+
+export const CloPortfolioMetricsSchema = z.object({
+  loans: CloLoanTapeSchema.min(1).describe("Collateral loan tape"),
+});
+
 export const CloCoverageTestsSchema = z.object({
   tranches: z.array(z.object({
     name: z.string().describe("Tranche name"),
@@ -33,6 +49,7 @@ export const CloCoverageTestsSchema = z.object({
   interest_income: z.coerce.number().describe("Periodic interest income from pool"),
   senior_fees: z.coerce.number().describe("Senior fees amount"),
   reference_rate: z.coerce.number().describe("Reference rate as decimal"),
+  loan_tape: CloLoanTapeSchema.optional().describe("Loan tape; adds WARF, WAS, WAL and diversity score to the output"),
 });
 
 export const CloReinvestmentSchema = z.object({
@@ -40,17 +57,33 @@ export const CloReinvestmentSchema = z.object({
     name: z.string().describe("Asset identifier"),
     notional: z.coerce.number().describe("Asset notional"),
     rating: z.string().describe("Asset rating (e.g. B, BB)"),
-    spread: z.coerce.number().describe("Asset spread in bps"),
+    spread: z.coerce.number().describe("Asset spread as decimal (0.035 = 350bp)"),
     remaining_life: z.coerce.number().describe("Remaining life in years"),
     industry: z.string().describe("Industry classification"),
   })).describe("Current portfolio assets"),
   target_par: z.coerce.number().describe("Target par balance to maintain"),
   max_warf: z.coerce.number().describe("Maximum weighted average rating factor"),
-  min_wals: z.coerce.number().describe("Minimum weighted average life (short)"),
+  min_wals: z.coerce.number().describe("Minimum weighted average loan spread as decimal"),
   max_wal: z.coerce.number().describe("Maximum weighted average life"),
   min_diversity_score: z.coerce.number().describe("Minimum diversity score"),
 });
 
+export const CloReinvestmentOptimizerSchema = z.object({
+  pool: CloReinvestmentSchema.describe("Current pool, target par and collateral quality test limits"),
+  candidates: z.array(z.object({
+    name: z.string().describe("Asset identifier; a name already held is added to"),
+    max_notional: z.coerce.number().min(0).describe("Maximum par available to buy"),
+    price: z.coerce.number().positive().optional().describe("Price as fraction of par (default 1.0)"),
+    rating: z.string().describe("Asset rating (e.g. B, BB, B2)"),
+    spread: z.coerce.number().min(0).describe("Asset spread as decimal"),
+    remaining_life: z.coerce.number().min(0).describe("Remaining life in years"),
+    industry: z.string().describe("Industry classification"),
+  })).min(1).describe("Loans on offer"),
+  available_proceeds: z.coerce.number().min(0).describe("Principal proceeds to reinvest"),
+  lot_size: z.coerce.number().positive().optional().describe("Par bought per step (default 1% of proceeds)"),
+  objective: z.enum(["MaxSpread", "MinWarf", "MaxPar"]).optional().describe("Purchase ranking (default MaxSpread)"),
+});
+
 export const CloTrancheAnalyticsSchema = z.object({
   tranche_name: z.string().describe("Tranche identifier"),
   cash_flows: z.array(z.object({
@@ -67,6 +100,7 @@ export const CloTrancheAnalyticsSchema = z.object({
 export const CloScenarioSchema = z.object({
   tranches: z.array(z.object({
     name: z.string().describe("Tranche name"),
+    rating: z.string().describe("Tranche rating label"),
     notional: z.coerce.number().describe("Tranche notional"),
     spread: z.coerce.number().describe("Tranche spread in bps"),
     is_equity: z.boolean().describe("Whether this is equity tranche"),
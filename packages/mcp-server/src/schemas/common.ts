@@ -4,6 +4,12 @@ export const CurrencySchema = z
   .enum(["GBP", "USD", "EUR", "CHF", "JPY", "CAD", "AUD", "HKD", "SGD"])
   .describe("ISO 4217 currency code");
 
+export const FxRateSchema = z.object({
+  base: CurrencySchema.describe("Base currency of the quote"),
+  quote: CurrencySchema.describe("Quote currency"),
+  rate: z.coerce.number().positive().describe("Units of quote currency per one unit of base"),
+});
+
 export const OutputFormatSchema = z
   .enum(["json", "table", "csv", "minimal"])
   .optional()
@@ -32,3 +38,26 @@ export const SensitivityVariableSchema = z.object({
   max: z.coerce.number().describe("Maximum value for the sensitivity range"),
   step: z.coerce.number().positive().describe("Step size between values"),
 });
+
+export const DilutiveSecuritySchema = z.object({
+  name: z.string().describe("Tranche name"),
+  kind: z
+    .enum(["Option", "Warrant", "RestrictedStock", "Convertible", "NetShareConvertible"])
+    .describe("Security type"),
+  count: z.coerce.number().min(0).describe("Number of instruments"),
+  strike: z.coerce
+    .number()
+    .min(0)
+    .optional()
+    .describe("Exercise or conversion price per underlying share (0 for RSUs)"),
+  shares_per_instrument: z.coerce
+    .number()
+    .positive()
+    .optional()
+    .describe("Underlying shares per instrument (conversion ratio for convertibles)"),
+  time_to_expiry: z.coerce
+    .number()
+    .positive()
+    .optional()
+    .describe("Remaining life in years (option-value method)"),
+});
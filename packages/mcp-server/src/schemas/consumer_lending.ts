@@ -0,0 +1,76 @@
+import { z } from "zod";
+
+// --- ArmTerms ---
+// Rust struct: ArmTerms in consumer_lending/loans.rs
+const ArmTermsSchema = z.object({
+  initial_fixed_months: z.coerce.number().int().positive().describe("Months at the start rate before the first reset (60 for a 5/1 ARM)"),
+  reset_frequency_months: z.coerce.number().int().positive().describe("Months between later resets"),
+  margin: z.coerce.number().min(0).describe("Margin over the index (decimal)"),
+  index_rates: z.array(z.coerce.number()).min(1).describe("Index at each reset in order; the last value is held flat"),
+  initial_adjustment_cap: z.coerce.number().min(0).describe("Maximum change at the first reset"),
+  periodic_cap: z.coerce.number().min(0).describe("Maximum change at each later reset"),
+  lifetime_cap: z.coerce.number().min(0).describe("Maximum rise over the start rate for the life of the loan"),
+  floor: z.coerce.number().min(0).optional().describe("Minimum rate (default the margin)"),
+  rounding: z.coerce.number().positive().optional().describe("Round the fully indexed rate to this increment (default 0.00125)"),
+});
+
+// --- RetailLoanInput ---
+// Rust struct: RetailLoanInput in consumer_lending/loans.rs
+export const RetailLoanSchema = z.object({
+  loan_type: z.enum(["Mortgage", "Auto", "Student"]).describe("Loan product"),
+  principal: z.coerce.number().positive().describe("Amount borrowed"),
+  annual_rate: z.coerce.number().min(0).describe("Annual note rate (decimal); the start rate for an ARM"),
+  term_months: z.coerce.number().int().positive().describe("Amortisation term in months (after any deferment)"),
+  arm: ArmTermsSchema.optional().describe("Adjustable rate terms"),
+  extra_payments: z
+    .object({
+      monthly: z.coerce.number().min(0).default(0).describe("Extra principal paid every month"),
+      start_month: z.coerce.number().int().positive().optional().describe("First month of the recurring extra payment (default 1)"),
+      lump_sums: z
+        .array(
+          z.object({
+            month: z.coerce.number().int().positive().describe("Month paid"),
+            amount: z.coerce.number().positive().describe("Extra principal"),
+          })
+        )
+        .default([])
+        .describe("One-off prepayments"),
+    })
+    .optional()
+    .describe("Prepayments; the output then includes the no-prepayment baseline, interest saved and months saved"),
+  student: z
+    .object({
+      deferment_months: z.coerce.number().int().min(0).describe("In-school and grace months before repayment"),
+      subsidized: z.boolean().describe("Interest paid by the government during deferment"),
+    })
+    .optional()
+    .describe("Student loan deferment; unsubsidised interest is capitalised"),
+});
+
+// --- AprInput ---
+// Rust struct: AprInput in consumer_lending/apr.rs
+export const LoanAprSchema = z.object({
+  loan_amount: z.coerce.number().positive().describe("Note amount"),
+  note_rate: z.coerce.number().min(0).describe("Annual note rate (decimal)"),
+  term_months: z.coerce.number().int().positive().describe("Term in months"),
+  points: z.coerce.number().min(0).default(0).describe("Discount and origination points as a fraction of the loan (0.01 = 1 point)"),
+  prepaid_finance_charges: z.coerce.number().min(0).default(0).describe("Other prepaid finance charges: lender fees, prepaid interest, upfront mortgage insurance"),
+  arm: ArmTermsSchema.optional().describe("Adjustable rate terms; only the index at consummation is used"),
+  par_rate: z.coerce.number().min(0).optional().describe("Rate available with no discount points, for the points breakeven"),
+});
+
+// --- RefinanceInput ---
+// Rust struct: RefinanceInput in consumer_lending/refinance.rs
+export const RefinanceSchema = z.object({
+  current_balance: z.coerce.number().positive().describe("Outstanding balance on the current loan"),
+  current_rate: z.coerce.number().min(0).describe("Annual rate on the current loan (decimal)"),
+  remaining_months: z.coerce.number().int().positive().describe("Months remaining on the current loan"),
+  new_rate: z.coerce.number().min(0).describe("Annual rate on the new loan (decimal)"),
+  new_term_months: z.coerce.number().int().positive().describe("Term of the new loan in months"),
+  closing_costs: z.coerce.number().min(0).default(0).describe("Lender and third-party closing costs"),
+  points: z.coerce.number().min(0).max(1).default(0).describe("Points on the new loan as a fraction of its amount"),
+  cash_out: z.coerce.number().min(0).default(0).describe("Cash taken out on top of the payoff"),
+  finance_costs: z.boolean().default(false).describe("Roll closing costs and points into the new loan instead of paying cash"),
+  discount_rate: z.coerce.number().min(0).optional().describe("Annual discount rate for the NPV (default the current loan rate)"),
+  holding_period_months: z.coerce.number().int().positive().optional().describe("Months the borrower expects to keep the loan (default the remaining term)"),
+});
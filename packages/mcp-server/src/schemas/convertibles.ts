@@ -1,4 +1,5 @@
 import { z } from "zod";
+import { DilutiveSecuritySchema } from "./common.js";
 
 export const ConvertiblePricingSchema = z.object({
   bond_name: z.string().describe("Bond name / identifier"),
@@ -34,4 +35,43 @@ export const ConvertibleAnalysisSchema = z.object({
   stock_scenarios: z.array(z.coerce.number().positive()).min(1).describe("Stock price scenarios"),
   vol_scenarios: z.array(z.coerce.number().positive()).optional().describe("Volatility scenarios"),
   spread_scenarios: z.array(z.coerce.number()).optional().describe("Credit spread scenarios"),
+  dilution: z
+    .object({
+      basic_shares: z.coerce.number().positive().describe("Basic shares outstanding"),
+      bonds_outstanding: z.coerce.number().positive().describe("Number of bonds outstanding"),
+      other_securities: z
+        .array(DilutiveSecuritySchema)
+        .optional()
+        .describe("Options, warrants and RSUs"),
+    })
+    .optional()
+    .describe("Share count for if-converted and net share dilution"),
+});
+
+const LatticeBondTermsSchema = z.object({
+  bond_name: z.string().describe("Bond name / identifier"),
+  face_value: z.coerce.number().positive().describe("Face value of the bond"),
+  coupon_rate: z.coerce.number().min(0).describe("Annual coupon rate"),
+  coupon_frequency: z.coerce.number().int().positive().describe("Coupon payments per year"),
+  maturity_years: z.coerce.number().positive().describe("Years to maturity, at most the lattice horizon"),
+  conversion_ratio: z.coerce.number().min(0).describe("Shares per bond on conversion; 0 for a straight callable/puttable bond"),
+  call_price: z.coerce.number().positive().optional().describe("Issuer call price"),
+  call_protection_years: z.coerce.number().min(0).optional().describe("Call protection period in years"),
+  put_price: z.coerce.number().positive().optional().describe("Investor put price"),
+  put_date_years: z.coerce.number().min(0).optional().describe("Put exercise date in years"),
+});
+
+export const ConvertibleBookSchema = z.object({
+  market: z
+    .object({
+      risk_free_rate: z.coerce.number().describe("Risk-free rate"),
+      credit_spread: z.coerce.number().min(0).describe("Issuer credit spread over risk-free"),
+      stock_price: z.coerce.number().positive().describe("Current stock price"),
+      stock_volatility: z.coerce.number().positive().describe("Stock return volatility"),
+      dividend_yield: z.coerce.number().min(0).optional().describe("Stock dividend yield"),
+      horizon_years: z.coerce.number().positive().describe("Longest maturity the lattice prices"),
+      tree_steps: z.coerce.number().int().positive().optional().describe("Binomial tree steps to the horizon (default 100)"),
+    })
+    .describe("Shared curve, spread and vol assumptions the lattice is calibrated to"),
+  bonds: z.array(LatticeBondTermsSchema).min(1).describe("Bonds priced on the calibrated lattice"),
 });
@@ -82,6 +82,117 @@ export const DebtCapacitySchema = z.object({
   ffo: z.coerce.number().optional().describe("Funds from operations"),
 });
 
+// --- AmortizationInput ---
+// Rust struct: AmortizationInput in amortization.rs
+// Method is externally tagged: "LevelPayment", "Bullet", {"StraightLine": share},
+// {"InterestOnly": periods}, {"Custom": [principal]}, {"Sculpted": {...}}
+export const AmortizationSchema = z.object({
+  principal: z.coerce.number().min(0).describe("Opening loan balance"),
+  rate: z.coerce.number().describe("Cash interest rate per period"),
+  pik_rate: z.coerce
+    .number()
+    .min(0)
+    .optional()
+    .describe("Interest capitalised onto the balance each period"),
+  periods: z.coerce.number().int().min(1).describe("Number of periods to maturity"),
+  method: z
+    .union([
+      z.enum(["LevelPayment", "Bullet"]),
+      z.object({
+        StraightLine: z.coerce
+          .number()
+          .min(0)
+          .describe("Share of original principal repaid each period (1 / periods for equal instalments)"),
+      }),
+      z.object({
+        InterestOnly: z.coerce
+          .number()
+          .int()
+          .min(0)
+          .describe("Interest-only periods before level-payment amortisation"),
+      }),
+      z.object({
+        Custom: z.array(z.coerce.number().min(0)).describe("Principal per period"),
+      }),
+      z.object({
+        Sculpted: z.object({
+          cash_flows: z.array(z.coerce.number()).describe("Cash flow available for debt service per period"),
+          target_dscr: z.coerce.number().positive().describe("Target DSCR (e.g. 1.3)"),
+        }),
+      }),
+    ])
+    .describe("Repayment profile; the final period settles the remaining balance except when sculpted"),
+});
+
+// --- DebtMaturityInput ---
+// Rust struct: DebtMaturityInput in credit/capacity.rs
+export const DebtMaturitySchema = z.object({
+  facilities: z
+    .array(
+      z.object({
+        name: z.string().describe("Facility name (e.g. Term Loan B)"),
+        principal: z.coerce.number().min(0).describe("Outstanding principal"),
+        interest_rate: z.coerce.number().min(0).describe("All-in interest rate"),
+        maturity_year: z
+          .number()
+          .int()
+          .min(1)
+          .describe("Projection year in which the facility matures (1 = end of year 1)"),
+        annual_amortisation: z
+          .number()
+          .min(0)
+          .optional()
+          .describe("Scheduled annual amortisation before maturity"),
+      })
+    )
+    .min(1)
+    .describe("Debt facilities in the capital structure"),
+  ebitda: z.coerce.number().positive().describe("Year 1 EBITDA"),
+  ebitda_growth: z.coerce.number().optional().describe("Annual EBITDA growth rate"),
+  cash_flow_conversion: z
+    .number()
+    .describe("Share of EBITDA available for debt service after tax, capex and working capital"),
+  opening_cash: z.coerce.number().default(0).describe("Opening cash balance"),
+  minimum_cash: z
+    .number()
+    .min(0)
+    .optional()
+    .describe("Cash kept on balance sheet and not used to repay maturities"),
+  refinancing: z
+    .object({
+      interest_rate: z.coerce.number().min(0).describe("Coupon on refinancing debt"),
+      tenor_years: z.number().int().min(1).describe("Tenor of refinancing debt in years"),
+      refinance_pct: z
+        .number()
+        .min(0)
+        .max(1)
+        .optional()
+        .describe("Share of each maturity to refinance (default 1); the rest is repaid from cash"),
+      fee_pct: z
+        .number()
+        .min(0)
+        .optional()
+        .describe("Upfront fees as a share of the amount refinanced"),
+    })
+    .describe("Refinancing assumptions for maturing debt"),
+  max_leverage: z
+    .number()
+    .positive()
+    .optional()
+    .describe("Maximum Debt / EBITDA (e.g. 4.0x)"),
+  min_interest_coverage: z
+    .number()
+    .positive()
+    .optional()
+    .describe("Minimum EBITDA / Interest coverage (e.g. 3.0x)"),
+  min_dscr: z
+    .number()
+    .positive()
+    .optional()
+    .describe("Minimum debt service coverage ratio (e.g. 1.5x)"),
+  projection_years: z.number().int().min(1).describe("Number of years to project"),
+});
+
 // --- CreditMetricsOutput ---
 // Rust struct: CreditMetricsOutput in credit/metrics.rs
 // Used as the `actuals` field in CovenantTestInput.
@@ -147,7 +258,11 @@ const CovenantMetricSchema = z.union([
     "MinCash",
     "MaxCapex",
   ]),
-  z.object({ Custom: z.string() }),
+  z.object({
+    Custom: z
+      .string()
+      .describe("Expression over CreditMetricsOutput fields, e.g. total_debt_to_ebitda - net_debt_to_ebitda"),
+  }),
 ]);
 
 // --- CovenantTestInput ---
@@ -173,3 +288,89 @@ export const CovenantTestSchema = z.object({
     "Actual CreditMetricsOutput to test against covenants"
   ),
 });
+
+// --- CovenantProjectionInput ---
+// Rust struct: CovenantProjectionInput in credit/covenants.rs
+export const CovenantProjectionSchema = z.object({
+  covenants: z
+    .array(
+      z.object({
+        name: z.string().describe("Covenant name"),
+        formula: z
+          .string()
+          .describe(
+            "Covenant formula, e.g. 'net_debt / ebitda <= 4.5x stepping to 4.0x after Q8'. Supports + - * / ( ), min/max/abs, <= < >= >, x and % suffixes, and step-downs via 'stepping to <level> after|from <Qn|Yn|n>'"
+          ),
+      })
+    )
+    .min(1)
+    .describe("Covenants defined by formula"),
+  periods: z
+    .array(
+      z.object({
+        label: z.string().optional().describe("Test date label (e.g. Q1 2026)"),
+        values: z
+          .record(z.coerce.number())
+          .describe("Projected financials keyed by the variable names used in formulas"),
+      })
+    )
+    .min(1)
+    .describe("Test dates in order; period numbers in formulas count from 1"),
+  frequency: z
+    .enum(["Quarterly", "Annual"])
+    .default("Quarterly")
+    .describe("Test frequency, used to resolve Qn / Yn references"),
+  definitions: z
+    .record(z.string())
+    .default({})
+    .describe("Defined terms as expressions, e.g. { net_debt: 'total_debt - cash' }"),
+});
+
+const ProductTypeSchema = z.enum(["FxForward", "InterestRateSwap", "FxOption", "EquityOption"]);
+
+const ExposureTradeSchema = z.object({
+  trade_id: z.string().describe("Trade identifier"),
+  counterparty: z.string().describe("Counterparty name"),
+  netting_set: z.string().optional().describe("Trades sharing a netting set are netted; trades without one stand alone."),
+  product: ProductTypeSchema.describe("Product type, which sets the asset class and add-on factor"),
+  notional: z.coerce.number().describe("Signed notional: positive is long the risk factor (buy foreign currency, pay fixed, bought option), negative is short."),
+  mark_to_market: z.coerce.number().describe("Current mark-to-market from our side."),
+  maturity_years: z.coerce.number().describe("Residual maturity in years"),
+});
+
+// --- PfeMethod ---
+// Rust enum (externally tagged serde default):
+//   AddOn                  -> "AddOn"
+//   Simulation { ... }     -> { "Simulation": { "fx_volatility": v, ... } }
+const PfeMethodSchema = z.union([
+  z.literal("AddOn"),
+  z.object({
+    Simulation: z.object({
+      fx_volatility: z.coerce.number().describe("Annualised FX spot volatility"),
+      rate_volatility: z.coerce.number().describe("Annualised absolute volatility of swap rates (e.g. 0.01 = 100bp)"),
+      equity_volatility: z.coerce.number().describe("Annualised equity volatility"),
+      num_paths: z.coerce.number().int().optional().describe("Number of simulated paths (default 2000)"),
+      confidence: z.coerce.number().optional().describe("PFE quantile (default 0.95)"),
+      seed: z.coerce.number().int().optional().describe("RNG seed"),
+    }),
+  }),
+]);
+
+const TenorLimitSchema = z.object({
+  up_to_years: z.coerce.number().describe("Upper end of the bucket; the bucket starts where the previous one ends."),
+  limit: z.coerce.number().describe("PFE limit for the bucket"),
+});
+
+const CounterpartyLimitSchema = z.object({
+  counterparty: z.string().describe("Counterparty the limit applies to"),
+  pfe_limit: z.coerce.number().describe("Limit on peak PFE across all tenors."),
+  tenor_limits: z.array(TenorLimitSchema).optional().describe("PFE limits by tenor bucket"),
+});
+
+export const CounterpartyExposureSchema = z.object({
+  trades: z.array(ExposureTradeSchema).describe("OTC derivative trades"),
+  method: PfeMethodSchema.optional().describe("PFE estimation: supervisory AddOn (default) or Monte Carlo Simulation"),
+  time_grid: z.array(z.coerce.number()).optional().describe("Profile time points in years; defaults to a standard grid out to the longest maturity."),
+  limits: z.array(CounterpartyLimitSchema).optional().describe("Counterparty PFE limits to check utilisation against"),
+  warning_threshold: z.coerce.number().optional().describe("Utilisation at or above which a limit is flagged as a warning."),
+});
@@ -29,4 +29,46 @@ export const CvaCalculationSchema = z.object({
   risk_free_rate: z.coerce.number().describe("Risk-free discount rate"),
   netting_benefit: z.coerce.number().min(0).max(1).optional().describe("Netting benefit reduction ratio"),
   collateral_threshold: z.coerce.number().min(0).optional().describe("Collateral posting threshold"),
+  wrong_way_correlation: z.coerce.number().gt(-1).lt(1).optional().describe("Exposure-default correlation (Gaussian copula); positive = wrong-way risk, negative = right-way"),
+});
+
+const XvaExposurePointSchema = z.object({
+  time_years: z.coerce.number().min(0).describe("Time in years, strictly increasing"),
+  expected_positive_exposure: z.coerce.number().min(0).describe("Netted expected positive exposure before collateral"),
+  expected_negative_exposure: z.coerce.number().min(0).optional().describe("Netted expected negative exposure as a positive amount"),
+  initial_margin: z.coerce.number().min(0).optional().describe("Initial margin posted by us (drives MVA)"),
+});
+
+const CsaTermsSchema = z.object({
+  threshold: z.coerce.number().min(0).describe("Counterparty threshold below which exposure is uncollateralised"),
+  own_threshold: z.coerce.number().min(0).optional().describe("Our threshold for collateral we post (defaults to threshold)"),
+  minimum_transfer_amount: z.coerce.number().min(0).optional().describe("Minimum transfer amount"),
+  independent_amount: z.coerce.number().min(0).optional().describe("Independent amount received from the counterparty"),
+});
+
+const NettingSetSchema = z.object({
+  name: z.string().describe("Netting set name"),
+  exposure_profile: z.array(XvaExposurePointSchema).min(1).describe("Netted exposure profile"),
+  csa: CsaTermsSchema.optional().describe("CSA terms; uncollateralised if omitted"),
+});
+
+export const XvaCalculationSchema = z.object({
+  trade_description: z.string().describe("Trade or book description"),
+  netting_sets: z.array(NettingSetSchema).min(1).describe("Netting sets, valued independently and summed"),
+  counterparty_default_probability: z.coerce.number().min(0).max(1).describe("Annual PD of counterparty"),
+  counterparty_recovery_rate: z.coerce.number().min(0).max(1).describe("Counterparty recovery rate"),
+  own_default_probability: z.coerce.number().min(0).max(1).optional().describe("Own PD for DVA"),
+  own_recovery_rate: z.coerce.number().min(0).max(1).optional().describe("Own recovery rate (default 0.40)"),
+  risk_free_rate: z.coerce.number().describe("Risk-free discount rate"),
+  funding: z.object({
+    funding_spread: z.coerce.number().describe("Funding spread over risk-free for positive exposure"),
+    benefit_spread: z.coerce.number().optional().describe("Spread earned on negative exposure (defaults to funding_spread)"),
+    initial_margin_remuneration: z.coerce.number().optional().describe("Spread earned on posted initial margin"),
+  }).optional().describe("Funding assumptions for FVA and MVA"),
+  capital: z.object({
+    hurdle_rate: z.coerce.number().min(0).describe("Required return on capital"),
+    capital_ratio: z.coerce.number().positive().max(1).optional().describe("Capital ratio on RWA (default 0.08)"),
+    risk_weight: z.coerce.number().min(0).optional().describe("Counterparty risk weight (default 1.0)"),
+    alpha: z.coerce.number().min(0).optional().describe("EAD multiplier on expected exposure (default 1.4)"),
+  }).optional().describe("Capital assumptions for KVA"),
 });
@@ -0,0 +1,59 @@
+import { z } from "zod";
+import { SensitivityVariableSchema } from "./common.js";
+import { CovenantTestSchema } from "./credit.js";
+import { DebtScheduleSchema } from "./pe.js";
+import { ModelToDcfSchema, ThreeStatementSchema } from "./three_statement.js";
+
+// --- DealInput ---
+// Rust struct: DealInput in deal_model/orchestration.rs
+// Fields: target (ThreeStatementInput), financing (DealFinancing),
+//         valuation (DcfValuationParams), covenants?, sensitivity?
+export const DealModelSchema = z.object({
+  target: ThreeStatementSchema.describe(
+    "Target base-year financials and projection drivers (three_statement_model input)"
+  ),
+  financing: z
+    .object({
+      entry_ev: z.coerce.number().positive().describe("Purchase enterprise value"),
+      tranches: z
+        .array(DebtScheduleSchema)
+        .min(1)
+        .describe("Acquisition debt tranches in seniority order"),
+      equity_contribution: z.coerce.number().positive().describe("Sponsor equity contribution"),
+      management_rollover: z.coerce.number().min(0).optional().describe("Management equity rollover"),
+      transaction_fees: z.coerce.number().min(0).optional().describe("Transaction advisory fees"),
+      financing_fees: z.coerce.number().min(0).optional().describe("Debt financing fees"),
+      cash_sweep_pct: z.coerce
+        .number()
+        .min(0)
+        .max(1)
+        .optional()
+        .describe("Share of excess cash flow swept to repay acquisition debt"),
+      exit_multiple: z.coerce.number().positive().describe("Exit EV/EBITDA multiple"),
+      exit_year: z.coerce
+        .number()
+        .int()
+        .min(1)
+        .optional()
+        .describe("Exit year (default: last projected year)"),
+    })
+    .describe("Acquisition financing and exit assumptions"),
+  valuation: ModelToDcfSchema.shape.valuation,
+  covenants: CovenantTestSchema.shape.covenants
+    .optional()
+    .describe("Maintenance covenants tested against each pro forma year"),
+  sensitivity: z
+    .object({
+      variable_1: SensitivityVariableSchema.describe(
+        "Row driver: wacc, terminal_growth_rate, terminal_exit_multiple, exit_multiple or entry_ev"
+      ),
+      variable_2: SensitivityVariableSchema.describe(
+        "Column driver: wacc, terminal_growth_rate, terminal_exit_multiple, exit_multiple or entry_ev"
+      ),
+      output_metric: z
+        .enum(["DcfEnterpriseValue", "DcfEquityValue", "Irr", "Moic"])
+        .describe("Deal output measured in each cell"),
+    })
+    .optional()
+    .describe("Optional two-way sensitivity of one deal output"),
+});
@@ -1,4 +1,5 @@
 import { z } from 'zod';
+import { CurveSchema, DateAdjustmentSchema, DayCountSchema } from './fixed_income.js';
 
 // ---------------------------------------------------------------------------
 // Option Pricing — matches OptionInput in options.rs
@@ -11,8 +12,22 @@ export const OptionPriceSchema = z.object({
   volatility: z.coerce.number().positive().describe('Annualised volatility (decimal)'),
   dividend_yield: z.coerce.number().optional().default(0).describe('Continuous dividend yield'),
   option_type: z.enum(['Call', 'Put']).describe('Option type'),
-  exercise_style: z.enum(['European', 'American']).describe('Exercise style'),
-  binomial_steps: z.coerce.number().int().optional().describe('Number of binomial tree steps (default 100)'),
+  exercise_style: z.enum(['European', 'American', 'Bermudan']).describe('Exercise style'),
+  binomial_steps: z.coerce.number().int().positive().optional().describe('Number of lattice steps (default 100); also the LSM exercise grid for American options'),
+  early_exercise_model: z.enum(['Binomial', 'Trinomial', 'LongstaffSchwartz']).optional().describe('Pricing method for American/Bermudan exercise (default Binomial)'),
+  exercise_times: z.array(z.coerce.number().positive()).optional().describe('Bermudan exercise dates as year fractions; expiry is always exercisable'),
+  simulation_paths: z.coerce.number().int().min(2).optional().describe('Longstaff-Schwartz path count (default 10000)'),
+  seed: z.coerce.number().int().optional().describe('Random seed for Longstaff-Schwartz paths (default 42)'),
+  greeks_method: z.enum(['Analytic', 'BumpAndReprice']).optional().describe('Greeks calculation: closed-form Black-Scholes or finite differences repricing with the selected model (default Analytic)'),
+  greek_bumps: z
+    .object({
+      spot_bump_pct: z.coerce.number().positive().optional().describe('Relative spot bump (default 0.01)'),
+      vol_bump: z.coerce.number().positive().optional().describe('Absolute vol bump (default 0.01)'),
+      rate_bump: z.coerce.number().positive().optional().describe('Absolute rate bump (default 0.0001)'),
+      time_bump_days: z.coerce.number().positive().optional().describe('Theta step in calendar days (default 1)'),
+    })
+    .optional()
+    .describe('Bump sizes for BumpAndReprice Greeks'),
 });
 
 // ---------------------------------------------------------------------------
@@ -71,13 +86,8 @@ export const BasisAnalysisSchema = z.object({
 });
 
 // ---------------------------------------------------------------------------
-// Interest Rate Swap — matches IrsInput / DiscountPoint / ForwardRatePoint in swaps.rs
+// Interest Rate Swap — matches IrsInput / ForwardRatePoint in swaps.rs
 // ---------------------------------------------------------------------------
-export const DiscountPointSchema = z.object({
-  maturity: z.coerce.number().positive().describe('Maturity in year fractions'),
-  rate: z.coerce.number().describe('Spot rate as decimal'),
-});
-
 export const ForwardRatePointSchema = z.object({
   maturity: z.coerce.number().positive().describe('Maturity in year fractions'),
   rate: z.coerce.number().describe('Forward rate as decimal'),
@@ -88,10 +98,15 @@ export const IrsSchema = z.object({
   fixed_rate: z.coerce.number().describe('Fixed rate (annualised decimal)'),
   payment_frequency: z.coerce.number().int().describe('Payments per year: 1, 2, or 4'),
   remaining_years: z.coerce.number().positive().describe('Remaining swap tenor in years'),
-  discount_curve: z.array(DiscountPointSchema).min(1).describe('Discount / spot rate curve points'),
-  forward_rates: z.array(ForwardRatePointSchema).optional().describe('Forward rate curve points (derived from discount curve if omitted)'),
+  discount_curve: CurveSchema.describe('Discount / spot rate curve (OIS curve for collateralised swaps; annual compounding if unstated)'),
+  projection_curve: CurveSchema.optional().describe('Projection curve for floating forwards; omit for single-curve valuation'),
+  forward_rates: z.array(ForwardRatePointSchema).optional().describe('Forward rate curve points (derived from the projection or discount curve if omitted)'),
   is_pay_fixed: z.coerce.boolean().describe('True if valuing from pay-fixed perspective'),
   last_floating_reset: z.coerce.number().optional().describe('Last observed floating reset rate for the current period'),
+  effective_date: z.string().optional().describe('Effective date (YYYY-MM-DD) for a dated schedule with day count accruals'),
+  fixed_day_count: DayCountSchema.optional().describe('Fixed leg day count (default Thirty360)'),
+  floating_day_count: DayCountSchema.optional().describe('Floating leg day count (default Actual360)'),
+  payment_adjustment: DateAdjustmentSchema.optional().describe('Business day adjustment of payment dates (requires effective_date)'),
 });
 
 // ---------------------------------------------------------------------------
@@ -104,8 +119,8 @@ export const CurrencySwapSchema = z.object({
   foreign_fixed_rate: z.coerce.number().describe('Foreign fixed coupon rate'),
   payment_frequency: z.coerce.number().int().describe('Payments per year: 1, 2, or 4'),
   remaining_years: z.coerce.number().positive().describe('Remaining swap tenor in years'),
-  domestic_discount_curve: z.array(DiscountPointSchema).min(1).describe('Domestic discount curve points'),
-  foreign_discount_curve: z.array(DiscountPointSchema).min(1).describe('Foreign discount curve points'),
+  domestic_discount_curve: CurveSchema.describe('Domestic discount curve'),
+  foreign_discount_curve: CurveSchema.describe('Foreign discount curve'),
   spot_fx_rate: z.coerce.number().positive().describe('Spot FX rate (domestic per foreign)'),
   is_pay_domestic: z.coerce.boolean().describe('True if paying domestic leg'),
 });
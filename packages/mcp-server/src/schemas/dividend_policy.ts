@@ -52,3 +52,58 @@ export const TotalShareholderReturnSchema = z.object({
   shares_ending: z.coerce.number().describe("Shares outstanding at end"),
   holding_period_years: z.coerce.number().describe("Holding period in years"),
 });
+
+const PricePathAssumptionsSchema = z.object({
+  current_price: z.coerce.number().describe("Current share price."),
+  annual_volatility: z.coerce.number().describe("Annualised volatility of the share price (e.g. 0.30)."),
+  annual_drift: z.coerce.number().optional().describe("Annualised price drift (e.g. 0.05). Defaults to zero."),
+  num_simulations: z.coerce.number().int().optional().describe("Number of simulated price paths (default 500)."),
+  seed: z.coerce.number().int().optional().describe("Seed for the deterministic random number generator (default 42)."),
+});
+
+export const AsrSchema = z.object({
+  notional: z.coerce.number().describe("Notional paid to the dealer at inception."),
+  initial_delivery_pct: z.coerce.number().describe("Fraction of notional delivered upfront at spot (e.g. 0.80)."),
+  term_days: z.coerce.number().int().describe("Contract term in trading days over which VWAP is averaged."),
+  vwap_discount: z.coerce.number().describe("Discount to average VWAP granted by the dealer (e.g. 0.01)."),
+  shares_outstanding: z.coerce.number().describe("Shares outstanding before the ASR."),
+  market: PricePathAssumptionsSchema.describe("Price path assumptions."),
+});
+
+export const OpenMarketBuybackSchema = z.object({
+  program_amount: z.coerce.number().describe("Total dollar amount authorised for the program."),
+  shares_outstanding: z.coerce.number().describe("Shares outstanding before the program."),
+  average_daily_volume: z.coerce.number().describe("Average daily trading volume (shares)."),
+  max_pct_of_volume: z.coerce.number().describe("Maximum participation as a fraction of ADV (e.g. 0.25)."),
+  daily_budget: z.coerce.number().optional().describe("Optional cap on dollars spent per day."),
+  limit_price: z.coerce.number().optional().describe("Optional limit price; no purchases on days the price exceeds it."),
+  max_days: z.coerce.number().int().describe("Simulation horizon in trading days."),
+  market: PricePathAssumptionsSchema.describe("Price path assumptions."),
+});
+
+const PayoutYearInputSchema = z.object({
+  net_income: z.coerce.number().describe("Forecast net income."),
+  ebitda: z.coerce.number().describe("Forecast EBITDA (leverage denominator)."),
+  capital_requirement: z.coerce.number().describe("Earnings that must be retained (reinvestment or regulatory capital)."),
+});
+
+const PayoutAlternativeSchema = z.object({
+  name: z.string().describe("Label, e.g. \"Dividend-heavy\"."),
+  target_payout_ratio: z.coerce.number().describe("Total payout (dividends + buybacks) as a fraction of net income."),
+  dividend_mix: z.coerce.number().describe("Fraction of the total payout paid as dividends (rest is buybacks)."),
+});
+
+export const PayoutOptimizerSchema = z.object({
+  shares_outstanding: z.coerce.number().describe("Shares outstanding at the start of the horizon."),
+  share_price: z.coerce.number().describe("Current share price."),
+  current_dps: z.coerce.number().describe("Current (last paid) dividend per share."),
+  pe_multiple: z.coerce.number().describe("P/E multiple used to price buybacks and the terminal share."),
+  cost_of_equity: z.coerce.number().describe("Cost of equity used to discount shareholder cash flows."),
+  dividend_tax_rate: z.coerce.number().describe("Personal tax rate on dividends."),
+  capital_gains_tax_rate: z.coerce.number().describe("Personal tax rate on capital gains."),
+  opening_net_debt: z.coerce.number().describe("Net debt at the start of the horizon."),
+  max_net_debt_to_ebitda: z.coerce.number().describe("Maximum Net Debt / EBITDA."),
+  no_dividend_cuts: z.boolean().describe("Whether DPS may never fall below the prior year's level."),
+  years: z.array(PayoutYearInputSchema).describe("Forecast years."),
+  alternatives: z.array(PayoutAlternativeSchema).describe("Candidate payout policies."),
+});
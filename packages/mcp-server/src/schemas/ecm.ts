@@ -0,0 +1,58 @@
+import { z } from "zod";
+import { CompsSchema, DcfSchema } from "./valuation.js";
+
+const CapTableEntrySchema = z.object({
+  name: z.string(),
+  shares: z.coerce.number().int(),
+  ownership_pct: z.coerce.number(),
+  value_at_post_money: z.coerce.number(),
+});
+
+const SellingShareholderSchema = z.object({
+  name: z.string().describe("Must match a name in the pre-IPO cap table."),
+  shares_sold: z.coerce.number(),
+});
+
+const UseOfProceedsSchema = z.object({
+  name: z.string(),
+  amount: z.coerce.number(),
+});
+
+const LockupTermSchema = z.object({
+  name: z.string().describe("Must match a name in the pre-IPO cap table."),
+  lockup_days: z.coerce.number().int(),
+});
+
+export const IpoSchema = z.object({
+  company_name: z.string(),
+  comps: CompsSchema.optional().describe("Trading comparables for the issuer; median implied values are used."),
+  dcf: DcfSchema.optional().describe("DCF inputs for the issuer."),
+  comps_weight: z.coerce.number().optional().describe("Weight on the comps value (0..=1); the DCF receives the remainder. Defaults to an equal weighting of the methods supplied."),
+  net_debt: z.coerce.number().describe("Pre-IPO net debt, used to bridge EV-based values to equity."),
+  ipo_discount: z.coerce.number().describe("Discount to fair value offered to IPO investors (e.g. 0.15)."),
+  price_range_pct: z.coerce.number().optional().describe("Half-width of the marketing price range around the midpoint (e.g. 0.05)."),
+  pre_ipo_cap_table: z.array(CapTableEntrySchema).describe("Fully diluted pre-IPO cap table (e.g. from the venture module)."),
+  primary_proceeds: z.coerce.number().describe("Gross primary proceeds the company targets in the base deal."),
+  selling_shareholders: z.array(SellingShareholderSchema).optional(),
+  greenshoe_pct: z.coerce.number().describe("Greenshoe as a fraction of base deal shares, issued as primary on exercise."),
+  greenshoe_exercised: z.boolean().describe("Whether the greenshoe is assumed exercised in the post-IPO figures."),
+  gross_spread_pct: z.coerce.number().describe("Underwriting gross spread as a fraction of gross proceeds."),
+  other_expenses: z.coerce.number().optional().describe("Legal, listing and other offering expenses borne by the company."),
+  use_of_proceeds: z.array(UseOfProceedsSchema).optional(),
+  lockups: z.array(LockupTermSchema).optional(),
+  default_lockup_days: z.coerce.number().int().describe("Lock-up applied to holders without a specific term (e.g. 180)."),
+});
+
+export const RightsIssueSchema = z.object({
+  company_name: z.string(),
+  shares_outstanding: z.coerce.number(),
+  cum_rights_price: z.coerce.number().describe("Share price cum-rights (before the shares go ex-rights)."),
+  subscription_price: z.coerce.number(),
+  new_shares: z.coerce.number(),
+  existing_shares: z.coerce.number(),
+  underwriting_fee_pct: z.coerce.number().describe("Underwriting commission as a fraction of gross proceeds."),
+  other_expenses: z.coerce.number().optional().describe("Legal, listing and other expenses borne by the company."),
+  expected_take_up: z.coerce.number().optional().describe("Expected fraction of rights taken up by shareholders; the underwriters take up the rest. Defaults to 1."),
+  shareholder_shares: z.coerce.number().optional().describe("Shares held by an illustrative shareholder for the dilution analysis."),
+  alternative_prices: z.array(z.coerce.number()).optional().describe("Alternative subscription prices raising the same gross proceeds."),
+});
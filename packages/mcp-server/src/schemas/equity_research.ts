@@ -1,4 +1,5 @@
 import { z } from "zod";
+import { DilutiveSecuritySchema } from "./common.js";
 
 const SegmentInputSchema = z.object({
   name: z.string(),
@@ -14,6 +15,30 @@ const SegmentInputSchema = z.object({
   margin: z.coerce.number().optional(),
 });
 
+const CrossHoldingSchema = z.object({
+  name: z.string(),
+  shares_held: z.coerce.number(),
+  market_price: z.coerce.number(),
+  blockage_discount: z.coerce.number().optional(),
+  liquidity_discount: z.coerce.number().optional(),
+  average_daily_volume: z.coerce.number().optional(),
+  tax_basis: z.coerce.number().optional(),
+  capital_gains_tax_rate: z.coerce.number().optional(),
+  ownership_pct: z.coerce.number().optional(),
+  investee_net_debt: z.coerce.number().optional(),
+});
+
+const SegmentDebtAllocationSchema = z.object({
+  segment: z.string(),
+  net_debt: z.coerce.number(),
+});
+
+const HoldcoDiscountEstimationSchema = z.object({
+  annual_holdco_costs: z.coerce.number(),
+  cost_capitalisation_rate: z.coerce.number(),
+  peer_discounts: z.array(z.coerce.number()).optional(),
+});
+
 export const SotpSchema = z.object({
   company_name: z.string(),
   segments: z.array(SegmentInputSchema),
@@ -22,6 +47,9 @@ export const SotpSchema = z.object({
   holding_company_discount: z.coerce.number().optional(),
   minority_interests: z.coerce.number().optional(),
   unconsolidated_investments: z.coerce.number().optional(),
+  cross_holdings: z.array(CrossHoldingSchema).optional(),
+  segment_net_debt: z.array(SegmentDebtAllocationSchema).optional(),
+  holdco_discount_estimation: HoldcoDiscountEstimationSchema.optional(),
 });
 
 const PeerMultipleSchema = z.object({
@@ -47,3 +75,103 @@ export const TargetPriceSchema = z.object({
   terminal_growth: z.coerce.number(),
   projection_years: z.coerce.number(),
 });
+
+export const ReitSchema = z.object({
+  company_name: z.string().describe("REIT name"),
+  net_income: z.coerce.number().describe("GAAP net income attributable to common shareholders"),
+  real_estate_depreciation: z.coerce.number().describe("Depreciation and amortisation of real estate assets"),
+  gains_on_property_sales: z.coerce.number().describe("Gains (positive) or losses (negative) on sale of depreciable property"),
+  impairment_charges: z.coerce.number().describe("Impairment write-downs of depreciable real estate"),
+  unconsolidated_jv_adjustments: z.coerce.number().optional().describe("Share of FFO adjustments from unconsolidated joint ventures"),
+  noncontrolling_interest_adjustments: z.coerce.number().optional().describe("FFO adjustments attributable to non-controlling interests (subtracted)"),
+  recurring_capex: z.coerce.number().describe("Recurring (maintenance) capital expenditures"),
+  leasing_costs: z.coerce.number().describe("Tenant improvements and leasing commissions on second-generation space"),
+  straight_line_rent: z.coerce.number().describe("Straight-line rent adjustment (GAAP rent in excess of cash rent)"),
+  stock_based_compensation: z.coerce.number().describe("Non-cash stock-based compensation (added back)"),
+  above_below_market_lease_amortization: z.coerce.number().optional().describe("Net amortisation of above/below-market lease intangibles (subtracted)"),
+  non_cash_interest: z.coerce.number().optional().describe("Non-cash interest such as deferred financing cost amortisation (added back)"),
+  shares_outstanding: z.coerce.number().describe("Diluted shares and OP units outstanding"),
+  dividend_per_share: z.coerce.number().describe("Annual dividend per share"),
+  share_price: z.coerce.number().describe("Current share price"),
+  forward_noi: z.coerce.number().describe("Forward 12-month cash net operating income of the stabilised portfolio"),
+  cap_rate: z.coerce.number().describe("Market capitalisation rate applied to NOI (e.g. 0.055 = 5.5%)"),
+  cash: z.coerce.number().describe("Cash and equivalents"),
+  other_assets: z.coerce.number().describe("Non-income-producing assets at book (land, CIP, development pipeline)"),
+  total_debt: z.coerce.number().describe("Total debt at face value"),
+  preferred_equity: z.coerce.number().optional().describe("Preferred equity at liquidation preference"),
+  other_liabilities: z.coerce.number().describe("Other liabilities (accounts payable, accrued, security deposits)"),
+  cap_rate_shifts: z.array(z.coerce.number()).optional().describe("Cap-rate shifts for the NAV sensitivity (default: -50, -25, 0, +25, +50 bps)"),
+});
+
+const BankProjectionYearSchema = z.object({
+  rote: z.coerce.number().describe("Return on tangible equity earned on opening tangible equity"),
+  rwa_growth: z.coerce.number().describe("Growth in risk-weighted assets over the year"),
+});
+
+const BankPeerSchema = z.object({
+  name: z.string(),
+  price_to_tbv: z.coerce.number().describe("Price / tangible book value per share"),
+  rote: z.coerce.number().describe("Forward return on tangible equity"),
+});
+
+const SegmentEclResultSchema = z.object({
+  name: z.string().describe("Segment name."),
+  ecl_12month: z.coerce.number().describe("12-month ECL (PD x LGD x EAD)."),
+  ecl_lifetime: z.coerce.number().describe("Lifetime ECL (discounted sum)."),
+  applied_ecl: z.coerce.number().describe("Applied ECL (12-month for stage 1, lifetime for stage 2/3)."),
+  weighted_ecl: z.coerce.number().describe("Scenario-weighted ECL."),
+  stage: z.coerce.number().int().describe("Stage (1, 2, or 3)."),
+});
+
+const ScenarioBreakdownSchema = z.object({
+  base_ecl: z.coerce.number(),
+  adverse_ecl: z.coerce.number(),
+  severe_ecl: z.coerce.number(),
+});
+
+const CeclProvisioningOutputSchema = z.object({
+  total_ecl: z.coerce.number().describe("Total expected credit loss."),
+  total_exposure: z.coerce.number().describe("Total exposure across all segments."),
+  ecl_coverage_ratio: z.coerce.number().describe("ECL coverage ratio (total_ecl / total_exposure)."),
+  segment_results: z.array(SegmentEclResultSchema).describe("Per-segment results."),
+  scenario_breakdown: ScenarioBreakdownSchema.describe("Breakdown by scenario."),
+});
+
+export const BankValuationSchema = z.object({
+  company_name: z.string().describe("Bank name"),
+  share_price: z.coerce.number().describe("Current share price"),
+  shares_outstanding: z.coerce.number().describe("Diluted shares outstanding"),
+  tangible_book_value: z.coerce.number().describe("Tangible common equity (book equity less goodwill and intangibles)"),
+  cet1_capital: z.coerce.number().describe("Common equity tier 1 capital"),
+  risk_weighted_assets: z.coerce.number().describe("Risk-weighted assets"),
+  rote: z.coerce.number().describe("Sustainable forward return on tangible equity"),
+  cost_of_equity: z.coerce.number().describe("Cost of equity"),
+  long_term_growth: z.coerce.number().describe("Long-term growth rate of book value and dividends"),
+  target_cet1_ratio: z.coerce.number().describe("Management / regulatory CET1 target ratio (e.g. 0.12 = 12%)"),
+  projection: z.array(BankProjectionYearSchema).describe("Explicit projection years for the multi-stage DDM"),
+  max_payout_ratio: z.coerce.number().optional().describe("Cap on distributions as a fraction of net income (None = unconstrained)"),
+  terminal_rote: z.coerce.number().optional().describe("ROTE in the terminal steady state (defaults to the last projected year)"),
+  peers: z.array(BankPeerSchema).describe("Peer banks for the P/TBV regression"),
+  cecl: CeclProvisioningOutputSchema.optional().describe("CECL / IFRS 9 output from bank_analytics used to test reserve adequacy"),
+  existing_allowance: z.coerce.number().optional().describe("Allowance for credit losses currently on balance sheet"),
+  tax_rate: z.coerce.number().optional().describe("Tax rate applied to any reserve shortfall (default 21%)"),
+});
+
+const ExpectationEstimatesSchema = z.object({
+  revenue_growth: z.coerce.number().describe("Annual revenue growth over the explicit forecast (decimal)"),
+  operating_margin: z.coerce.number().describe("Operating (EBIT) margin over the explicit forecast (decimal)"),
+});
+
+export const ReverseDcfSchema = z.object({
+  current_price: z.coerce.number().describe("Current market price per share"),
+  shares_outstanding: z.coerce.number().describe("Diluted shares outstanding, or basic shares when `dilutive_securities` is supplied"),
+  net_debt: z.coerce.number().describe("Net debt (debt less cash) deducted from enterprise value"),
+  dilutive_securities: z.array(DilutiveSecuritySchema).optional().describe("Options, warrants, RSUs and convertibles; diluted by TSM at the current price and solved for circularly at DCF values"),
+  base_revenue: z.coerce.number().describe("Last twelve months revenue"),
+  tax_rate: z.coerce.number().describe("Cash tax rate on operating profit"),
+  sales_to_capital: z.coerce.number().describe("Incremental revenue generated per unit of reinvested capital"),
+  wacc: z.coerce.number().describe("Weighted average cost of capital"),
+  terminal_growth: z.coerce.number().describe("Perpetual growth after the explicit forecast"),
+  projection_years: z.coerce.number().int().describe("Length of the explicit forecast in years"),
+  estimates: ExpectationEstimatesSchema.describe("Analyst estimates to compare against market-implied values"),
+});
@@ -1,4 +1,105 @@
 import { z } from 'zod';
+import { CurrencySchema, FxRateSchema } from './common.js';
+
+export const DayCountSchema = z
+  .enum(['Thirty360', 'Actual360', 'Actual365', 'ActualActual'])
+  .describe('Day count convention (ActualActual = ACT/ACT ICMA)');
+
+// ---------------------------------------------------------------------------
+// Versioned curve and volatility surface formats — match Curve / VolSurface in curves.rs
+// ---------------------------------------------------------------------------
+const LegacyCurvePointSchema = z.object({
+  maturity: z.coerce.number().min(0).describe('Maturity in years'),
+  rate: z.coerce.number().describe('Zero rate as decimal'),
+});
+
+export const CurveSchema = z
+  .union([
+    z.object({
+      version: z.literal(1).describe('Curve format version'),
+      id: z.string().optional().describe('Curve identifier, e.g. USD-SOFR'),
+      currency: z.string().optional().describe('Curve currency'),
+      calibration_date: z.string().optional().describe('Calibration date (YYYY-MM-DD)'),
+      day_count: DayCountSchema.optional().describe('Convention that turned dates into node tenors'),
+      compounding: z
+        .enum(['continuous', 'annual', 'semi_annual', 'quarterly', 'monthly', 'simple'])
+        .optional()
+        .describe("Compounding of the node rates (the consuming model's own if omitted)"),
+      interpolation: z
+        .enum(['linear', 'log_linear_discount', 'cubic_spline'])
+        .optional()
+        .describe('Interpolation between nodes'),
+      extrapolation: z.enum(['flat', 'linear']).optional().describe('Extrapolation beyond the first and last nodes'),
+      value_type: z.enum(['zero_rate', 'discount_factor']).optional().describe('What node values are (default zero_rate)'),
+      nodes: z
+        .array(
+          z.object({
+            tenor: z.coerce.number().min(0).describe('Tenor in years'),
+            value: z.coerce.number().describe('Zero rate or discount factor'),
+            label: z.string().optional().describe('Pillar label, e.g. 3M'),
+          }),
+        )
+        .min(1)
+        .describe('Curve nodes sorted by ascending tenor'),
+    }),
+    z.array(LegacyCurvePointSchema).min(1),
+  ])
+  .describe('Versioned curve document, or a legacy array of {maturity, rate} points');
+
+export const VolSurfaceSchema = z.object({
+  version: z.literal(1).describe('Surface format version'),
+  id: z.string().optional().describe('Surface identifier'),
+  underlying: z.string().optional().describe('Underlying name'),
+  calibration_date: z.string().optional().describe('Calibration date (YYYY-MM-DD)'),
+  day_count: DayCountSchema.optional().describe('Convention that turned expiry dates into year fractions'),
+  spot: z.coerce.number().positive().optional().describe('Spot at calibration (required for moneyness strikes)'),
+  strike_axis: z.enum(['absolute', 'moneyness']).optional().describe('Strike measure (default absolute)'),
+  smile_interpolation: z.enum(['linear', 'cubic_spline']).optional().describe('Interpolation across strikes (default linear)'),
+  time_interpolation: z.enum(['total_variance', 'linear']).optional().describe('Interpolation across expiries (default total_variance)'),
+  extrapolation: z.enum(['flat', 'linear']).optional().describe('Extrapolation across strikes (default flat)'),
+  nodes: z
+    .array(
+      z.object({
+        expiry: z.coerce.number().positive().describe('Expiry in years'),
+        strike: z.coerce.number().positive().describe('Strike on the strike axis'),
+        vol: z.coerce.number().min(0).describe('Implied volatility as decimal'),
+      }),
+    )
+    .min(1)
+    .describe('Surface nodes'),
+});
+
+export const DatedTenorSchema = z.object({
+  settlement_date: z.string().describe('Settlement date in YYYY-MM-DD format'),
+  maturity_date: z.string().describe('Maturity date in YYYY-MM-DD format'),
+  day_count: DayCountSchema,
+});
+
+export type HolidayCalendar =
+  | 'WeekendsOnly'
+  | 'Target'
+  | 'UnitedStates'
+  | 'UnitedKingdom'
+  | { Custom: { holidays: string[] } }
+  | { Joint: HolidayCalendar[] };
+
+export const HolidayCalendarSchema: z.ZodType<HolidayCalendar> = z
+  .lazy(() =>
+    z.union([
+      z.enum(['WeekendsOnly', 'Target', 'UnitedStates', 'UnitedKingdom']),
+      z.object({ Custom: z.object({ holidays: z.array(z.string()).describe('Holiday dates in YYYY-MM-DD format') }) }),
+      z.object({ Joint: z.array(HolidayCalendarSchema) }),
+    ]),
+  )
+  .describe('Holiday calendar; Custom adds explicit holidays to weekends, Joint combines calendars');
+
+export const DateAdjustmentSchema = z.object({
+  calendar: HolidayCalendarSchema,
+  convention: z
+    .enum(['Unadjusted', 'Following', 'ModifiedFollowing', 'Preceding'])
+    .optional()
+    .describe('Business day roll convention (default ModifiedFollowing)'),
+});
 
 // ---------------------------------------------------------------------------
 // Bond Pricing — matches BondPricingInput in bonds.rs
@@ -10,9 +111,13 @@ export const BondPricingSchema = z.object({
   ytm: z.coerce.number().describe('Yield to maturity as decimal'),
   settlement_date: z.string().describe('Settlement date in YYYY-MM-DD format'),
   maturity_date: z.string().describe('Maturity date in YYYY-MM-DD format'),
-  day_count: z.enum(['Thirty360', 'Actual360', 'Actual365', 'ActualActual']).describe('Day count convention'),
+  day_count: DayCountSchema,
   call_price: z.coerce.number().optional().describe('Call price for callable bonds'),
   call_date: z.string().optional().describe('Call date for callable bonds in YYYY-MM-DD format'),
+  currency: CurrencySchema.optional().describe('Currency the bond is denominated in (default USD)'),
+  presentation_currency: CurrencySchema.optional().describe('Currency to translate prices into'),
+  fx_rates: z.array(FxRateSchema).optional().describe('Spot FX rates for the presentation translation'),
+  payment_adjustment: DateAdjustmentSchema.optional().describe('Business day adjustment of cashflow payment dates'),
 });
 
 // ---------------------------------------------------------------------------
@@ -23,7 +128,8 @@ export const BondYieldSchema = z.object({
   coupon_rate: z.coerce.number().min(0).describe('Annual coupon rate as decimal'),
   coupon_frequency: z.coerce.number().int().describe('Coupons per year: 1, 2, 4, or 12'),
   market_price: z.coerce.number().positive().describe('Market (dirty) price of the bond'),
-  years_to_maturity: z.coerce.number().positive().describe('Years remaining until maturity'),
+  years_to_maturity: z.coerce.number().min(0).optional().describe('Years remaining until maturity (derived from dates if given)'),
+  dates: DatedTenorSchema.optional().describe('Settlement/maturity dates; overrides years_to_maturity'),
   current_yield_only: z.coerce.boolean().optional().default(false).describe('Skip Newton-Raphson YTM solve'),
 });
 
@@ -61,26 +167,22 @@ export const DurationSchema = z.object({
   coupon_rate: z.coerce.number().min(0).describe('Annual coupon rate as decimal'),
   coupon_frequency: z.coerce.number().int().describe('Coupons per year: 1, 2, 4, or 12'),
   ytm: z.coerce.number().describe('Yield to maturity as decimal'),
-  years_to_maturity: z.coerce.number().positive().describe('Years remaining until maturity'),
+  years_to_maturity: z.coerce.number().min(0).optional().describe('Years remaining until maturity (derived from dates if given)'),
+  dates: DatedTenorSchema.optional().describe('Settlement/maturity dates; overrides years_to_maturity'),
   yield_shift_bps: z.coerce.number().optional().describe('Yield shift in basis points for effective duration (default 10)'),
   key_rate_tenors: z.array(z.coerce.number()).optional().describe('Tenors for key rate duration analysis (e.g. [1, 2, 5, 10, 30])'),
 });
 
 // ---------------------------------------------------------------------------
-// Credit Spreads — matches CreditSpreadInput / BenchmarkPoint in spreads.rs
+// Credit Spreads — matches CreditSpreadInput in spreads.rs
 // ---------------------------------------------------------------------------
-export const BenchmarkPointSchema = z.object({
-  maturity: z.coerce.number().positive().describe('Time to maturity in years'),
-  rate: z.coerce.number().describe('Spot rate as decimal'),
-});
-
 export const CreditSpreadSchema = z.object({
   face_value: z.coerce.number().positive().describe('Par / face value'),
   coupon_rate: z.coerce.number().min(0).describe('Annual coupon rate as decimal'),
   coupon_frequency: z.coerce.number().int().describe('Coupons per year: 1, 2, 4, or 12'),
   market_price: z.coerce.number().positive().describe('Dirty market price of the bond'),
   years_to_maturity: z.coerce.number().positive().describe('Years remaining until maturity'),
-  benchmark_curve: z.array(BenchmarkPointSchema).min(2).describe('Risk-free benchmark spot-rate curve sorted ascending by maturity'),
+  benchmark_curve: CurveSchema.describe('Risk-free benchmark spot-rate curve, at least 2 points sorted ascending (annual compounding, linear if unstated)'),
   recovery_rate: z.coerce.number().min(0).max(1).optional().describe('Recovery rate for CDS spread estimate (default 0.40)'),
   default_probability: z.coerce.number().min(0).max(1).optional().describe('Annual default probability for CDS spread estimate'),
 });
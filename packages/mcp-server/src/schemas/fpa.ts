@@ -62,6 +62,33 @@ export const WorkingCapitalSchema = z.object({
   cost_of_capital: z.coerce.number().min(0).max(1).describe("WACC for financing savings calculation"),
 });
 
+export const InventoryOptimizationSchema = z.object({
+  company_name: z.string().describe("Company identifier"),
+  items: z.array(z.object({
+    name: z.string().describe("Item or product family name"),
+    annual_demand: z.coerce.number().positive().describe("Annual demand in units"),
+    daily_demand_std_dev: z.coerce.number().min(0).optional().describe("Standard deviation of daily demand in units (default 0)"),
+    lead_time_days: z.coerce.number().min(0).describe("Average replenishment lead time in days"),
+    lead_time_std_dev_days: z.coerce.number().min(0).optional().describe("Standard deviation of lead time in days (default 0)"),
+    unit_cost: z.coerce.number().positive().describe("Landed cost per unit"),
+    order_cost: z.coerce.number().positive().describe("Fixed cost per purchase order or set-up"),
+    stockout_cost_per_unit: z.coerce.number().min(0).optional().describe("Cost per unit short; sets the cost-optimal service level"),
+    current_order_quantity: z.coerce.number().positive().optional().describe("Current order quantity for comparison"),
+    current_safety_stock: z.coerce.number().min(0).optional().describe("Current safety stock in units"),
+  })).min(1).describe("Inventory items to optimise"),
+  holding_cost_rate: z.coerce.number().min(0).describe("Non-financing carrying cost per year as a fraction of unit cost"),
+  cost_of_capital: z.coerce.number().min(0).describe("Cost of capital on inventory not funded by the revolver"),
+  target_service_level: z.coerce.number().gt(0).lt(1).optional().describe("Cycle service level for items without a stockout cost (default 0.95)"),
+  days_per_year: z.coerce.number().int().positive().optional().describe("Operating days per year (default 365)"),
+  financing: z.object({
+    revolver_rate: z.coerce.number().min(0).describe("All-in rate on drawn revolver balances"),
+    commitment_fee: z.coerce.number().min(0).optional().describe("Fee on undrawn commitment (default 0)"),
+    advance_rate: z.coerce.number().min(0).max(1).describe("Borrowing-base advance rate against inventory"),
+    headroom: z.coerce.number().min(0).optional().describe("Cushion over peak draw when sizing the commitment (default 0.15)"),
+    existing_commitment: z.coerce.number().min(0).optional().describe("Existing revolver commitment to test adequacy"),
+  }).optional().describe("Revolver terms financing the inventory"),
+});
+
 export const RollingForecastSchema = z.object({
   company_name: z.string().describe("Company name"),
   historical_periods: z.array(z.object({
@@ -82,3 +109,114 @@ export const RollingForecastSchema = z.object({
     tax_rate: z.coerce.number().min(0).max(1).describe("Corporate tax rate"),
   }).describe("Driver assumptions"),
 });
+
+// --- BacklogRevenueInput ---
+// Rust struct: BacklogRevenueInput in fpa/backlog.rs
+const RecognitionPolicySchema = z.enum(["Ratable", "PercentageOfCompletion", "PointInTime"]);
+
+export const BacklogRevenueSchema = z.object({
+  company_name: z.string().describe("Company name"),
+  periods: z.coerce.number().int().positive().describe("Number of forecast periods"),
+  period_labels: z.array(z.string()).optional().describe("Labels, one per period"),
+  contracts: z.array(z.object({
+    name: z.string(),
+    total_value: z.coerce.number().min(0).describe("Total contract value"),
+    recognized_to_date: z.coerce.number().min(0).optional().describe("Revenue already recognised"),
+    policy: RecognitionPolicySchema.optional().describe("Recognition policy (default Ratable)"),
+    start_period: z.coerce.number().int().min(0).optional().describe("Forecast period remaining delivery starts (0 = first)"),
+    duration_periods: z.coerce.number().int().positive().describe("Periods over which the remaining value is delivered"),
+    cost_profile: z.array(z.coerce.number().min(0)).optional().describe("Remaining cost weights per delivery period for percentage of completion"),
+  })).optional().describe("Signed contracts with unrecognised value"),
+  pipeline: z.array(z.object({
+    name: z.string(),
+    value: z.coerce.number().min(0).describe("Contract value if won"),
+    win_rate: z.coerce.number().min(0).max(1).describe("Probability of winning"),
+    expected_close_period: z.coerce.number().int().min(0).describe("Forecast period of signing"),
+    start_lag_periods: z.coerce.number().int().min(0).optional().describe("Periods from signing to start of delivery"),
+    policy: RecognitionPolicySchema.optional(),
+    duration_periods: z.coerce.number().int().positive(),
+    cost_profile: z.array(z.coerce.number().min(0)).optional(),
+  })).optional().describe("Unsigned opportunities, weighted by win rate"),
+  revenue_targets: z.array(z.coerce.number()).optional().describe("Revenue target per period for coverage"),
+  base_revenue: z.coerce.number().optional().describe("Revenue of the period before the forecast, for growth rates"),
+});
+
+const SaasPeriodSchema = z.object({
+  label: z.string().describe("Period label, e.g. \"Q1 2025\""),
+  beginning_arr: z.coerce.number().describe("ARR at the start of the period"),
+  new_arr: z.coerce.number().describe("ARR from new customers"),
+  expansion_arr: z.coerce.number().describe("Upsell / cross-sell ARR from existing customers"),
+  contraction_arr: z.coerce.number().describe("Downgrade ARR from existing customers (positive number)"),
+  churned_arr: z.coerce.number().describe("ARR lost from churned customers (positive number)"),
+  revenue: z.coerce.number().describe("Recognised subscription revenue for the period"),
+  gross_margin: z.coerce.number().describe("Subscription gross margin"),
+  sales_marketing_expense: z.coerce.number().describe("Sales & marketing expense for the period"),
+  profit_margin: z.coerce.number().describe("Profitability margin used for Rule of 40 (FCF or EBITDA margin)"),
+  beginning_customers: z.coerce.number().describe("Customers at the start of the period"),
+  new_customers: z.coerce.number().describe("Customers acquired in the period"),
+  churned_customers: z.coerce.number().describe("Customers lost in the period"),
+});
+
+const ArrCohortSchema = z.object({
+  label: z.string().describe("Cohort label, e.g. \"2024 Q1\""),
+  starting_arr: z.coerce.number().describe("Cohort ARR at the start"),
+  arr_after_12m: z.coerce.number().describe("Cohort ARR twelve months later, including expansion"),
+  gross_retained_arr_after_12m: z.coerce.number().describe("Cohort ARR twelve months later, excluding expansion"),
+});
+
+export const SaasMetricsSchema = z.object({
+  company_name: z.string().describe("Company name"),
+  periods: z.array(SaasPeriodSchema).describe("Periods in chronological order"),
+  periods_per_year: z.coerce.number().int().describe("Periods per year (4 = quarterly, 12 = monthly, 1 = annual)"),
+  cohorts: z.array(ArrCohortSchema).optional().describe("Optional cohort data for trailing-twelve-month NRR/GRR"),
+});
+
+// --- RetentionCurve ---
+// Rust enum (externally tagged serde default):
+//   Empirical { survival }                              -> { "Empirical": { "survival": [...] } }
+//   Exponential { monthly_churn }                       -> { "Exponential": { "monthly_churn": r } }
+//   DecayingChurn { initial_churn, long_run_churn, decay } -> { "DecayingChurn": { ... } }
+const RetentionCurveSchema = z.union([
+  z.object({
+    Empirical: z.object({
+      survival: z.array(z.coerce.number()).describe("Share of the cohort still active at the end of months 1..n"),
+    }),
+  }),
+  z.object({
+    Exponential: z.object({
+      monthly_churn: z.coerce.number().describe("Constant monthly churn"),
+    }),
+  }),
+  z.object({
+    DecayingChurn: z.object({
+      initial_churn: z.coerce.number().describe("Month-one churn"),
+      long_run_churn: z.coerce.number().describe("Long-run churn level"),
+      decay: z.coerce.number().describe("Decay factor applied to the excess churn each month"),
+    }),
+  }),
+]);
+
+const CohortInputSchema = z.object({
+  label: z.string().describe("Cohort label, e.g. \"Jan-2025\""),
+  initial_users: z.coerce.number().describe("Users (or customers) acquired"),
+  cac_per_user: z.coerce.number().describe("Blended acquisition cost per user"),
+  retention: RetentionCurveSchema.describe("Retention curve"),
+});
+
+const UncertaintySpecSchema = z.object({
+  churn_volatility: z.coerce.number().describe("Relative standard deviation applied to churn (e.g. 0.20 = +/-20%)"),
+  arpu_volatility: z.coerce.number().describe("Relative standard deviation applied to ARPU"),
+  num_simulations: z.coerce.number().int().optional().describe("Number of simulations (default 1,000)"),
+  seed: z.coerce.number().int().optional().describe("Seed for the deterministic random number generator (default 42)"),
+});
+
+export const UnitEconomicsSchema = z.object({
+  cohorts: z.array(CohortInputSchema).describe("Acquisition cohorts"),
+  arpu_monthly: z.coerce.number().describe("Monthly revenue per active user in the first month"),
+  arpu_growth_annual: z.coerce.number().optional().describe("Annual ARPU growth (price increases / expansion)"),
+  gross_margin: z.coerce.number().describe("Gross margin on revenue"),
+  variable_cost_per_user_monthly: z.coerce.number().optional().describe("Other variable cost per active user per month (support, payments, ...)"),
+  annual_discount_rate: z.coerce.number().describe("Annual discount rate for LTV"),
+  horizon_months: z.coerce.number().int().describe("Lifetime horizon in months"),
+  uncertainty: UncertaintySpecSchema.optional().describe("Optional Monte Carlo uncertainty bands"),
+});
@@ -0,0 +1,40 @@
+import { z } from "zod";
+
+// --- FranchiseInput ---
+// Rust struct: FranchiseInput in franchise/economics.rs
+export const FranchiseSchema = z.object({
+  system_name: z.string().describe("Franchise system or brand"),
+  unit: z.object({
+    initial_investment: z.coerce.number().min(0).describe("Franchisee build-out, equipment and pre-opening costs, excluding the initial fee"),
+    mature_revenue: z.coerce.number().positive().describe("Annual sales of a mature unit in year-1 dollars"),
+    ramp: z.array(z.coerce.number().min(0)).optional().describe("Fraction of mature sales in each year after opening, e.g. [0.7, 0.9]"),
+    revenue_growth: z.coerce.number().optional().describe("Annual same-store sales growth (decimal)"),
+    operating_margin: z.coerce.number().describe("Four-wall EBITDA margin before franchise fees (decimal)"),
+    refresh_capex: z.coerce.number().min(0).optional().describe("Remodel / refresh capex each cycle"),
+    refresh_cycle_years: z.coerce.number().int().positive().optional().describe("Years between required refreshes"),
+  }).describe("Single-unit operating economics"),
+  fees: z.object({
+    initial_fee: z.coerce.number().min(0).describe("Initial franchise fee per unit"),
+    royalty_rate: z.coerce.number().min(0).max(1).describe("Royalty as a fraction of sales"),
+    marketing_fund_rate: z.coerce.number().min(0).max(1).optional().describe("Marketing fund contribution as a fraction of sales (pass-through)"),
+    technology_fee: z.coerce.number().min(0).optional().describe("Flat annual technology fee per unit"),
+    term_years: z.coerce.number().int().positive().describe("Agreement term in years"),
+    renewal_fee: z.coerce.number().min(0).optional().describe("Fee per unit at each renewal"),
+  }).describe("Fees under the franchise agreement"),
+  franchisor_costs: z.object({
+    upfront_investment: z.coerce.number().min(0).optional().describe("Year-0 franchisor investment; needed for a franchisor IRR"),
+    opening_support_cost: z.coerce.number().min(0).optional().describe("Training and opening support per new unit"),
+    support_cost_per_unit: z.coerce.number().min(0).optional().describe("Annual field support per operating unit"),
+    annual_overhead: z.coerce.number().min(0).optional().describe("Fixed annual franchisor overhead"),
+  }).optional().describe("Franchisor costs of building and supporting the system"),
+  development_schedule: z.array(z.coerce.number().int().min(0)).describe("New units opened each year, year 1 first"),
+  existing_units: z.coerce.number().int().min(0).optional().describe("Units already operating at maturity"),
+  territory_capacity: z.coerce.number().int().min(0).optional().describe("Maximum units in the territory, existing included"),
+  annual_closure_rate: z.coerce.number().min(0).max(1).optional().describe("Fraction of units closing each year after opening"),
+  projection_years: z.coerce.number().int().positive().describe("Years in the franchisor projection"),
+  franchisee_discount_rate: z.coerce.number().describe("Franchisee hurdle rate (decimal)"),
+  franchisor_discount_rate: z.coerce.number().describe("Franchisor discount rate (decimal)"),
+  franchisee_exit_multiple: z.coerce.number().min(0).optional().describe("Multiple of final-year unit EBITDA after fees realised at the end of the term"),
+  franchisor_exit_multiple: z.coerce.number().min(0).optional().describe("Multiple of final-year franchisor cash flow taken as terminal value"),
+  royalty_rates: z.array(z.coerce.number().min(0).max(1)).optional().describe("Royalty rates for the sensitivity (default base +/- 1 and 2 points)"),
+});
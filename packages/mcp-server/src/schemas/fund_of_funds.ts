@@ -79,3 +79,45 @@ export const FofPortfolioSchema = z.object({
   max_vintage_pct: z.coerce.number().describe("Maximum allocation to any single vintage"),
   max_geography_pct: z.coerce.number().describe("Maximum allocation to any single geography"),
 });
+
+export const StyleAnalysisSchema = z.object({
+  fund_name: z.string().describe("Fund name"),
+  fund_returns: z.array(z.coerce.number()).describe("Periodic fund returns as decimals"),
+  style_indices: z.array(z.object({
+    name: z.string().describe("Style index name (e.g. Large Value, Small Growth)"),
+    returns: z.array(z.coerce.number()).describe("Index returns aligned with fund returns"),
+  })).min(2).describe("Style indices to regress the fund on"),
+  rolling_window: z.coerce.number().int().optional().describe("Rolling window in periods (default 36)"),
+  drift_threshold: z.coerce.number().optional().describe("Turnover vs full-period weights that flags style drift (default 0.20)"),
+});
+
+export const TakahashiAlexanderParamsSchema = z.object({
+  contribution_rates: z.array(z.coerce.number().min(0).max(1)).min(1).describe("Rates of contribution on unfunded commitment by fund age (year 1, 2, ...); last rate repeats"),
+  growth_rate: z.coerce.number().describe("Annual NAV growth rate G"),
+  bow: z.coerce.number().positive().describe("Bow factor B (higher = later distributions)"),
+  yield_rate: z.coerce.number().min(0).max(1).describe("Minimum annual distribution yield Y on NAV"),
+  fund_life: z.coerce.number().int().min(1).describe("Fund life L in years"),
+});
+
+export const CashFlowForecastSchema = z.object({
+  as_of_year: z.coerce.number().int().describe("Calendar year of the current paid-in and NAV figures"),
+  horizon_years: z.coerce.number().int().min(1).max(50).describe("Number of years to project"),
+  commitments: z.array(z.object({
+    name: z.string().describe("Fund name"),
+    strategy: z.string().describe("Strategy: Buyout, Venture, Growth, Real Estate, Infrastructure, Private Credit, or a custom name with strategy_assumptions"),
+    vintage: z.coerce.number().int().describe("Vintage year"),
+    commitment: z.coerce.number().positive().describe("Total commitment"),
+    paid_in: z.coerce.number().min(0).describe("Capital called to date"),
+    nav: z.coerce.number().min(0).describe("Current NAV"),
+  })).min(1).describe("Existing commitments"),
+  strategy_assumptions: z.array(z.object({
+    strategy: z.string().describe("Strategy name"),
+    params: TakahashiAlexanderParamsSchema,
+  })).optional().describe("Per-strategy Takahashi-Alexander parameter overrides"),
+  stochastic: z.object({
+    num_paths: z.coerce.number().int().min(1).optional().describe("Number of simulated paths (default 1,000)"),
+    growth_volatility: z.coerce.number().min(0).describe("Annual volatility of NAV growth"),
+    confidence: z.coerce.number().optional().describe("Confidence level for bands (default 0.95)"),
+    seed: z.coerce.number().int().optional().describe("RNG seed (default 42)"),
+  }).optional().describe("Stochastic extension with a shared annual growth shock"),
+});
@@ -0,0 +1,72 @@
+import { z } from "zod";
+
+const DealingFrequencySchema = z
+  .enum(["Monthly", "Quarterly", "SemiAnnual", "Annual"])
+  .describe("Dealing date frequency");
+
+// --- LiquidityTermsInput ---
+// Rust struct: LiquidityTermsInput in fund_structuring/liquidity_terms.rs
+const LockupTermsSchema = z.object({
+  months: z.coerce.number().int().min(0).describe("Lock-up length from each investor's subscription date"),
+  kind: z.enum(["Hard", "Soft"]).describe("Hard lock-ups bar redemptions; soft lock-ups allow them for a fee"),
+  early_redemption_fee: z.coerce.number().min(0).optional().describe("Fee on redemptions inside a soft lock-up, retained by the fund"),
+});
+
+const SidePocketSchema = z.object({
+  name: z.string().describe("Side pocket name"),
+  nav_pct: z.coerce.number().min(0).max(1).describe("Share of fund NAV moved into the side pocket"),
+  release_months: z.coerce.number().int().min(0).describe("Months after the stress date when the assets are realised"),
+  recovery_rate: z.coerce.number().min(0).describe("Realised value as a fraction of carrying value"),
+});
+
+const InvestorPositionSchema = z.object({
+  investor_id: z.string().describe("Investor identifier"),
+  holding: z.coerce.number().min(0).describe("Holding value at the stress date"),
+  subscription_date: z.string().describe("Subscription date (YYYY-MM-DD), for the lock-up"),
+  redemption_request_pct: z.coerce.number().min(0).max(1).describe("Fraction of the holding the investor asks to redeem"),
+});
+
+export const RedemptionStressSchema = z.object({
+  fund_name: z.string().describe("Fund name"),
+  stress_date: z.string().describe("Date redemption requests are submitted (YYYY-MM-DD)"),
+  dealing_frequency: DealingFrequencySchema,
+  notice_period_days: z.coerce.number().int().min(0).describe("Days of notice required before a dealing date"),
+  payment_delay_days: z.coerce.number().int().min(0).optional().describe("Days from dealing date to cash settlement"),
+  fund_gate_pct: z.coerce.number().min(0).max(1).optional().describe("Fund-level gate: maximum share of liquid NAV paid per dealing date"),
+  investor_gate_pct: z.coerce.number().min(0).max(1).optional().describe("Investor-level gate: maximum share of an investor's original request paid per dealing date"),
+  lockup: LockupTermsSchema.optional(),
+  side_pockets: z.array(SidePocketSchema).optional().describe("Illiquid assets segregated into side pockets at the start of the stress"),
+  investors: z.array(InvestorPositionSchema).min(1).describe("Investors in the redemption scenario"),
+  other_investors_nav: z.coerce.number().min(0).optional().describe("NAV held by investors outside the scenario (counts towards the gate)"),
+  liquid_returns: z.array(z.coerce.number()).optional().describe("Return on liquid assets per dealing period (missing = 0)"),
+  max_dealing_dates: z.coerce.number().int().positive().describe("Dealing dates to simulate before stopping"),
+});
+
+// --- SemiLiquidStressInput ---
+// Rust struct: SemiLiquidStressInput in fund_structuring/semi_liquid_stress.rs
+const AssetBucketSchema = z.object({
+  name: z.string().describe("Asset bucket name"),
+  nav_pct: z.coerce.number().min(0).max(1).describe("Share of fund NAV held in the bucket"),
+  months_to_sell: z.coerce.number().int().positive().describe("Months needed to sell the whole bucket; caps monthly sales"),
+  fire_sale_discount: z.coerce.number().min(0).max(1).describe("Discount to carrying value realised on stressed sales"),
+  monthly_return: z.coerce.number().optional().describe("Monthly return on carrying value"),
+});
+
+const RedemptionWaveSchema = z.object({
+  month: z.coerce.number().int().min(0).describe("Month the requests are submitted"),
+  pct_of_nav: z.coerce.number().min(0).max(1).describe("Requests as a share of NAV at submission"),
+});
+
+export const SemiLiquidStressSchema = z.object({
+  fund_name: z.string().describe("Fund name"),
+  fund_nav: z.coerce.number().positive().describe("Fund NAV at the start of the stress"),
+  cash_pct: z.coerce.number().min(0).max(1).describe("Share of NAV held in cash; the rest is split across asset_buckets"),
+  minimum_cash_pct: z.coerce.number().min(0).max(1).optional().describe("Cash kept back and not used to pay redemptions, as a share of NAV"),
+  credit_facility: z.coerce.number().min(0).optional().describe("Undrawn committed credit facility available for redemptions"),
+  asset_buckets: z.array(AssetBucketSchema).min(1).describe("Non-cash assets with their sale timelines and fire-sale discounts"),
+  dealing_frequency: DealingFrequencySchema,
+  notice_months: z.coerce.number().int().min(0).describe("Months between request and the first dealing date it can be met"),
+  fund_gate_pct: z.coerce.number().min(0).max(1).optional().describe("Fund-level gate as a share of NAV per dealing date"),
+  redemption_waves: z.array(RedemptionWaveSchema).min(1).describe("Redemption requests by month"),
+  horizon_months: z.coerce.number().int().positive().describe("Months to simulate"),
+});
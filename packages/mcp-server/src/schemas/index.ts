@@ -1,5 +1,6 @@
 export {
   CurrencySchema,
+  FxRateSchema,
   OutputFormatSchema,
   ProjectionPeriodSchema,
   CashFlowSchema,
@@ -7,12 +8,16 @@ export {
   SensitivityVariableSchema,
 } from "./common.js";
 
-export { WaccSchema, DcfSchema, CompsSchema } from "./valuation.js";
+export { WaccSchema, DcfSchema, CompsSchema, SmallBusinessSchema, PeerScreenSchema, AssetBasedValuationSchema, CostOfEquitySchema, ValuationAdjustmentsSchema, EconomicProfitSchema, ApvSchema, RateConsistencySchema } from "./valuation.js";
 
 export {
   CreditMetricsSchema,
   DebtCapacitySchema,
+  DebtMaturitySchema,
+  AmortizationSchema,
   CovenantTestSchema,
+  CovenantProjectionSchema,
+  CounterpartyExposureSchema,
 } from "./credit.js";
 
 export {
@@ -25,11 +30,18 @@ export {
   RiskAdjustedSchema,
   RiskMetricsSchema,
   KellySchema,
+  PortfolioValuationSchema,
+  RevaluationVarSchema,
 } from "./portfolio.js";
 
-export { SensitivitySchema, ScenarioSchema } from "./scenarios.js";
+export {
+  SensitivitySchema,
+  ScenarioSchema,
+  MarketDataSetSchema,
+  MarketShiftSchema,
+} from "./scenarios.js";
 
-export { MergerSchema } from "./ma.js";
+export { MergerSchema, MergerArbSchema, AcquisitionFinancingSchema, PurchasePriceMechanismSchema, EarnoutSchema } from "./ma.js";
 
 export {
   FundFeeSchema,
@@ -40,6 +52,7 @@ export {
   GpEconomicsSchema,
   InvestorNetReturnsSchema,
   UbtiScreeningSchema,
+  CapitalAccountSchema,
 } from "./jurisdiction.js";
 
 export {
@@ -55,6 +68,8 @@ export {
   NelsonSiegelSchema,
   DurationSchema,
   CreditSpreadSchema,
+  CurveSchema,
+  VolSurfaceSchema,
 } from "./fixed_income.js";
 
 export {
@@ -68,7 +83,12 @@ export {
   StrategySchema,
 } from "./derivatives.js";
 
-export { ThreeStatementSchema } from "./three_statement.js";
+export { ModelToDcfSchema, ThreeStatementSchema, LtmSchema, CalendarizeSchema, AnnualizeSchema } from "./three_statement.js";
+export { DealModelSchema } from "./deal_model.js";
+export { CashFlowLadderSchema } from "./cashflow_ladder.js";
+export { LoanAprSchema, RefinanceSchema, RetailLoanSchema } from "./consumer_lending.js";
+export { FranchiseSchema } from "./franchise.js";
+export { ProjectSelectionSchema } from "./capital_budgeting.js";
 
 export { MonteCarloSchema, McDcfSchema } from "./monte_carlo.js";
 
@@ -76,6 +96,7 @@ export {
   FactorModelSchema,
   BlackLittermanSchema,
   RiskParitySchema,
+  HrpSchema,
   StressTestSchema,
 } from "./quant_risk.js";
 
@@ -93,7 +114,7 @@ export {
   CommodityCurveSchema,
 } from "./fx_commodities.js";
 
-export { AbsMbsSchema, TranchingSchema } from "./securitization.js";
+export { AbsMbsSchema, CmbsSchema, TranchingSchema } from "./securitization.js";
 
 export {
   FundingRoundSchema,
@@ -115,12 +136,16 @@ export {
   LcrSchema,
   NsfrSchema,
   AlmSchema,
+  SaccrSchema,
 } from "./regulatory.js";
 
 export {
   UnitrancheSchema,
   DirectLoanSchema,
   SyndicationSchema,
+  LoanPricingSchema,
+  BridgeFinancingSchema,
+  FundFinanceSchema,
 } from "./private_credit.js";
 
 export {
@@ -134,7 +159,11 @@ export {
   VarianceSchema,
   BreakevenSchema,
   WorkingCapitalSchema,
+  InventoryOptimizationSchema,
   RollingForecastSchema,
+  BacklogRevenueSchema,
+  SaasMetricsSchema,
+  UnitEconomicsSchema,
 } from "./fpa.js";
 
 export {
@@ -166,11 +195,13 @@ export {
 export {
   CdsPricingSchema,
   CvaCalculationSchema,
+  XvaCalculationSchema,
 } from "./credit_derivatives.js";
 
 export {
   ConvertiblePricingSchema,
   ConvertibleAnalysisSchema,
+  ConvertibleBookSchema,
 } from "./convertibles.js";
 
 export {
@@ -196,6 +227,9 @@ export {
 export {
   SotpSchema,
   TargetPriceSchema,
+  ReitSchema,
+  BankValuationSchema,
+  ReverseDcfSchema,
 } from "./equity_research.js";
 
 export {
@@ -206,6 +240,7 @@ export {
 export {
   PairsTradingSchema,
   MomentumSchema,
+  EventStudySchema,
 } from "./quant_strategies.js";
 
 export {
@@ -281,4 +316,20 @@ export {
 export {
   KycRiskSchema,
   SanctionsScreeningSchema,
-} from "./aml_compliance.js";
\ No newline at end of file
+} from "./aml_compliance.js";
+
+export {
+  IpoSchema,
+  RightsIssueSchema,
+} from "./ecm.js";
+
+export {
+  AsrSchema,
+  OpenMarketBuybackSchema,
+  PayoutOptimizerSchema,
+} from "./dividend_policy.js";
+
+export {
+  RedemptionStressSchema,
+  SemiLiquidStressSchema,
+} from "./fund_structuring.js";
@@ -1,9 +1,5 @@
 import { z } from "zod";
-
-const ZeroRatePointSchema = z.object({
-  maturity: z.coerce.number().describe("Time to maturity in years"),
-  rate: z.coerce.number().describe("Zero rate as decimal (e.g. 0.05 = 5%)"),
-});
+import { CurveSchema } from "./fixed_income.js";
 
 const VasicekInputSchema = z.object({
   mean_reversion_speed: z.coerce.number().describe("Speed of mean reversion (a)"),
@@ -29,7 +25,7 @@ const HullWhiteInputSchema = z.object({
   current_rate: z.coerce.number().describe("Current short rate (r0)"),
   time_horizon: z.coerce.number().describe("Time horizon in years"),
   time_steps: z.coerce.number().int().describe("Number of time steps"),
-  market_zero_rates: z.array(ZeroRatePointSchema).describe("Market zero rate curve"),
+  market_zero_rates: CurveSchema.describe("Market zero rate curve (continuous compounding if unstated)"),
 });
 
 export const ShortRateSchema = z.object({
@@ -1,5 +1,5 @@
 import { z } from "zod";
-import { CurrencySchema } from "./common.js";
+import { CurrencySchema, FxRateSchema } from "./common.js";
 
 // ---------------------------------------------------------------------------
 // Jurisdiction enum (matches Rust Jurisdiction enum in withholding_tax.rs)
@@ -31,6 +31,33 @@ export const FundFeeSchema = z.object({
   currency: CurrencySchema.optional(),
 });
 
+// ---------------------------------------------------------------------------
+// PerformanceFeeInput (fund_fees.rs)
+// ---------------------------------------------------------------------------
+const PerformanceFeeVariantSchema = z.object({
+  name: z.string().describe("Label for this fee structure"),
+  performance_fee_rate: z.coerce.number().min(0).max(1).describe("Performance fee rate (e.g. 0.20 for 20%)"),
+  hurdle: z.union([
+    z.literal("None"),
+    z.object({ Fixed: z.object({ annual_rate: z.coerce.number().describe("Fixed annual hurdle rate") }) }),
+    z.object({ Benchmark: z.object({ spread: z.coerce.number().describe("Annual spread over the benchmark return") }) }),
+  ]).describe("Hurdle basis: None, Fixed annual rate, or Benchmark index plus spread"),
+  hurdle_style: z.enum(["Hard", "Soft"]).optional().describe("Hard charges only on the excess over the hurdle; Soft on the whole gain once cleared (default Hard)"),
+  high_water_mark: z.enum(["None", "Standard", "ProportionalLossCarryforward"]).describe("Loss carryforward treatment; Proportional reduces it pro rata on redemptions"),
+  crystallization: z.enum(["Quarterly", "Annual", "OnRedemption"]).describe("When accrued performance fees crystallise"),
+});
+
+export const PerformanceFeeSchema = z.object({
+  initial_investment: z.coerce.number().positive().describe("LP investment at the start of the horizon"),
+  periods_per_year: z.coerce.number().int().describe("Return periods per year: 1, 2, 4, or 12"),
+  gross_returns: z.array(z.coerce.number()).min(1).describe("Gross return per period"),
+  benchmark_returns: z.array(z.coerce.number()).optional().describe("Benchmark return per period (required for benchmark hurdles)"),
+  redemptions: z.array(z.coerce.number().min(0).max(1)).optional().describe("Fraction of the holding redeemed at each period end"),
+  management_fee_rate: z.coerce.number().min(0).max(1).optional().describe("Annual management fee on NAV"),
+  variants: z.array(PerformanceFeeVariantSchema).min(1).describe("Performance fee structures to compare"),
+  currency: CurrencySchema.optional(),
+});
+
 // ---------------------------------------------------------------------------
 // ReconciliationInput (reconciliation.rs)
 // ---------------------------------------------------------------------------
@@ -92,6 +119,27 @@ const RedemptionSchema = z.object({
   nav_per_share_at_exit: z.coerce.number().describe("NAV per share at time of redemption"),
 });
 
+const HedgeRatioPolicySchema = z.union([
+  z.literal("Full"),
+  z.object({ Fixed: z.object({ ratio: z.coerce.number().min(0).max(2) }) }),
+  z.object({
+    Band: z.object({
+      current_ratio: z.coerce.number().min(0).max(2),
+      target_ratio: z.coerce.number().min(0).max(2),
+      lower: z.coerce.number().min(0).max(2),
+      upper: z.coerce.number().min(0).max(2),
+    }),
+  }),
+]).describe("Hedge ratio policy: Full, Fixed ratio, or a rebalancing Band");
+
+const ShareClassHedgeSchema = z.object({
+  spot_rate_start: z.coerce.number().positive().describe("Spot rate at period start (class currency per unit of base)"),
+  spot_rate_end: z.coerce.number().positive().describe("Spot rate at period end (class currency per unit of base)"),
+  forward_points: z.coerce.number().describe("Forward points over the period (forward = spot + points)"),
+  hedge_ratio_policy: HedgeRatioPolicySchema,
+  transaction_cost_bps: z.coerce.number().min(0).optional().describe("Dealing cost in bps of hedged notional"),
+});
+
 const ShareClassInputSchema = z.object({
   class_name: z.string().describe("Share class name (e.g. Class A, Class B)"),
   currency: CurrencySchema.describe("Currency for this share class"),
@@ -104,6 +152,7 @@ const ShareClassInputSchema = z.object({
   crystallisation_frequency: z.enum(["Monthly", "Quarterly", "SemiAnnually", "Annually", "OnRedemption"]).describe("Performance fee crystallisation frequency"),
   fx_rate_to_base: z.coerce.number().optional().describe("FX rate to convert class currency to base currency"),
   fx_hedging_cost: z.coerce.number().optional().describe("Annual FX hedging cost as a rate"),
+  hedging: ShareClassHedgeSchema.optional().describe("Class-level currency hedge overlay"),
   subscriptions: z.array(SubscriptionSchema).describe("Subscriptions during the period"),
   redemptions: z.array(RedemptionSchema).describe("Redemptions during the period"),
 });
@@ -117,6 +166,8 @@ export const NavSchema = z.object({
   period_label: z.string().describe("Label for the calculation period (e.g. Q4 2025)"),
   equalisation_method: z.enum(["EqualisationShares", "SeriesAccounting", "DepreciationDeposit", "None"]).describe("NAV equalisation method"),
   base_currency: CurrencySchema.describe("Base currency for total fund NAV calculation"),
+  fx_rates: z.array(FxRateSchema).optional().describe("Spot FX rates for classes without an explicit fx_rate_to_base"),
+  base_class: z.string().optional().describe("Reference class for return divergence reporting"),
 });
 
 // ---------------------------------------------------------------------------
@@ -203,3 +254,44 @@ export const UbtiScreeningSchema = z.object({
   use_blocker: z.coerce.boolean().optional().describe("Whether a blocker entity is being considered"),
   currency: CurrencySchema.optional(),
 });
+
+// ---------------------------------------------------------------------------
+// CapitalAccountInput (investor_returns.rs)
+// ---------------------------------------------------------------------------
+const PartnerCommitmentSchema = z.object({
+  partner_id: z.string().describe("Partner identifier"),
+  name: z.string().describe("Partner name"),
+  commitment: z.coerce.number().positive().describe("Capital commitment; fund-level items are allocated pro rata to it"),
+  management_fee_rate: z.coerce.number().min(0).describe("Annual management fee rate charged on commitment (reflects any side-letter discount; zero for the GP commitment)"),
+  carry_bearing: z.boolean().optional().describe("Whether the partner's profits bear carried interest (default true)"),
+  general_partner: z.boolean().optional().describe("General partner: receives the carried interest allocated from carry-bearing partners"),
+  opening_balance: z.coerce.number().optional().describe("Capital account balance at the start of the first period"),
+  contributed_to_date: z.coerce.number().optional().describe("Capital contributed before the first period"),
+});
+
+const DistributionsByCharacterSchema = z.object({
+  return_of_capital: z.coerce.number().optional(),
+  dividend_income: z.coerce.number().optional(),
+  interest_income: z.coerce.number().optional(),
+  capital_gain: z.coerce.number().optional(),
+  other: z.coerce.number().optional(),
+});
+
+const CapitalAccountPeriodSchema = z.object({
+  label: z.string().describe("Period label, e.g. Q1 2024"),
+  period_years: z.coerce.number().positive().describe("Length of the period in years (0.25 for a quarter), for fee accrual"),
+  capital_called: z.coerce.number().min(0).describe("Capital called from partners (excluding management fees, which are charged per partner)"),
+  distributions: DistributionsByCharacterSchema.optional().describe("Fund-level distributions by tax character"),
+  realized_gain_loss: z.coerce.number().describe("Fund-level realised gain (negative for a loss)"),
+  unrealized_gain_loss: z.coerce.number().describe("Fund-level change in unrealised gain (negative for a loss)"),
+  fund_expenses: z.coerce.number().min(0).describe("Fund operating expenses and organisational costs"),
+  carried_interest: z.coerce.number().describe("Change in accrued carried interest at fund level (negative on reversal)"),
+});
+
+export const CapitalAccountSchema = z.object({
+  fund_name: z.string().describe("Fund name"),
+  partners: z.array(PartnerCommitmentSchema).min(1).describe("Limited and general partners with their commitments"),
+  periods: z.array(CapitalAccountPeriodSchema).min(1).describe("Reporting periods in order"),
+  fees_funded_by_calls: z.boolean().optional().describe("Management fees are called from partners alongside capital calls (default true)"),
+  currency: CurrencySchema.optional(),
+});
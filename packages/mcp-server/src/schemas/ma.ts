@@ -1,4 +1,6 @@
 import { z } from "zod";
+import { CovenantTestSchema } from "./credit.js";
+import { DebtScheduleSchema } from "./pe.js";
 
 // --- ConsiderationType ---
 // Rust enum (externally tagged serde default):
@@ -19,6 +21,133 @@ const ConsiderationTypeSchema = z.union([
   }),
 ]);
 
+// --- SynergySchedule ---
+// Rust struct: SynergySchedule in ma/merger_model.rs
+const SynergyCategorySchema = z.object({
+  name: z.string().describe("Synergy category name"),
+  kind: z
+    .enum(["Cost", "Revenue", "Capex"])
+    .describe("Cost and Revenue flow through earnings; Capex is cash only"),
+  run_rate: z.coerce
+    .number()
+    .describe("Annual run-rate once fully realised (negative for dis-synergies)"),
+  phasing: z
+    .array(z.number().min(0).max(1))
+    .min(1)
+    .describe("Fraction of run-rate achieved each year; last value held flat"),
+  flow_through_margin: z
+    .number()
+    .min(0)
+    .max(1)
+    .optional()
+    .describe("Share of revenue synergies reaching pre-tax earnings (default 1)"),
+});
+
+const SynergyScheduleSchema = z.object({
+  categories: z
+    .array(SynergyCategorySchema)
+    .describe("Synergy categories with their realisation curves"),
+  integration_costs: z
+    .array(z.number())
+    .describe("One-time pre-tax integration costs by year (year 1 first)"),
+  projection_years: z
+    .number()
+    .int()
+    .positive()
+    .describe("Years in the accretion / dilution profile"),
+  discount_rate: z.number().describe("Discount rate for net synergy NPV"),
+  terminal_growth: z
+    .number()
+    .optional()
+    .describe("Run-rate synergy growth for the terminal value (omit for none)"),
+  acquirer_net_income_growth: z
+    .number()
+    .optional()
+    .describe("Annual growth in acquirer standalone net income"),
+  target_net_income_growth: z
+    .number()
+    .optional()
+    .describe("Annual growth in target standalone net income"),
+});
+
+// --- IdentifiableNetAssets ---
+// Rust struct: IdentifiableNetAssets in ma/ppa.rs
+const FairValueStepUpSchema = z.object({
+  name: z.string().describe("Asset name"),
+  category: z
+    .enum(["Intangible", "PropertyPlantEquipment", "Inventory", "Other"])
+    .describe(
+      "Intangible and Other are amortised, PP&E depreciated, Inventory released through cost of sales"
+    ),
+  book_value: z
+    .number()
+    .describe("Carrying value on the target balance sheet (0 for internally generated intangibles)"),
+  fair_value: z.number().min(0).describe("Fair value at acquisition"),
+  useful_life_years: z
+    .number()
+    .int()
+    .positive()
+    .optional()
+    .describe("Remaining useful life; omit for indefinite-lived assets (inventory defaults to 1 year)"),
+});
+
+const IdentifiableNetAssetsSchema = z.object({
+  book_equity: z.coerce.number().describe("Target book shareholders' equity"),
+  existing_goodwill: z
+    .number()
+    .min(0)
+    .optional()
+    .describe("Goodwill already on the target balance sheet, written off on acquisition"),
+  step_ups: z
+    .array(FairValueStepUpSchema)
+    .describe("Fair-value step-ups of identifiable assets"),
+  tax_basis_step_up: z
+    .boolean()
+    .optional()
+    .describe("Asset deal or 338(h)(10) election: step-ups and goodwill are tax deductible, no DTL"),
+  tax_goodwill_life_years: z
+    .number()
+    .int()
+    .positive()
+    .optional()
+    .describe("Tax amortisation period for goodwill in a tax basis step-up (default 15)"),
+});
+
+// --- ProFormaCreditInput ---
+// Rust struct: ProFormaCreditInput in ma/merger_model.rs
+const ProFormaCreditSchema = z.object({
+  acquirer_ebitda: z.coerce.number().describe("Acquirer standalone EBITDA"),
+  target_ebitda: z.coerce.number().describe("Target standalone EBITDA"),
+  acquirer_debt: z.number().min(0).describe("Acquirer total debt at closing"),
+  target_debt: z.number().min(0).describe("Target total debt assumed"),
+  acquirer_cash: z.number().min(0).optional().describe("Acquirer cash (default 0)"),
+  target_cash: z.number().min(0).optional().describe("Target cash (default 0)"),
+  acquirer_interest_expense: z
+    .number()
+    .min(0)
+    .describe("Acquirer annual interest expense"),
+  target_interest_expense: z
+    .number()
+    .min(0)
+    .describe("Target annual interest expense"),
+  new_acquisition_debt: z
+    .number()
+    .min(0)
+    .optional()
+    .describe(
+      "Acquisition debt raised; defaults to the cash consideration when debt_financing_rate is set"
+    ),
+  ebitda_growth: z
+    .number()
+    .optional()
+    .describe("Annual growth in combined standalone EBITDA over the synergy schedule"),
+  max_net_leverage: z
+    .number()
+    .positive()
+    .optional()
+    .describe("Net debt / EBITDA limit to test each year against"),
+});
+
 // --- MergerInput ---
 // Rust struct: MergerInput in ma/merger_model.rs
 export const MergerSchema = z.object({
@@ -101,4 +230,342 @@ export const MergerSchema = z.object({
     .number()
     .optional()
     .describe("One-time transaction / advisory fees"),
+
+  // Phased synergies
+  synergy_schedule: SynergyScheduleSchema.optional().describe(
+    "Per-category synergy phasing, integration costs and NPV assumptions for a multi-year accretion / dilution profile"
+  ),
+
+  // Purchase price allocation
+  purchase_price_allocation: IdentifiableNetAssetsSchema.optional().describe(
+    "Target net assets and fair-value step-ups; derives goodwill and charges after-tax incremental D&A against pro-forma earnings"
+  ),
+
+  // Pro-forma credit
+  credit_profile: ProFormaCreditSchema.optional().describe(
+    "EBITDA, debt, cash and interest of both companies for standalone and pro-forma leverage, coverage and implied rating (by year with a synergy schedule)"
+  ),
+});
+
+// --- MultiTargetMergerInput ---
+// Rust struct: MultiTargetMergerInput in ma/merger_model.rs
+const ExchangeRatioCollarSchema = z.object({
+  collar_type: z
+    .enum(["FixedExchangeRatio", "FixedValue"])
+    .describe(
+      "FixedExchangeRatio: ratio fixed inside the band; FixedValue: value per target share fixed inside the band"
+    ),
+  reference_price: z
+    .number()
+    .positive()
+    .describe("Acquirer share price at signing (sets the base exchange ratio)"),
+  lower_price: z.number().positive().describe("Lower acquirer price bound"),
+  upper_price: z.number().positive().describe("Upper acquirer price bound"),
+  walk_away_price: z
+    .number()
+    .positive()
+    .optional()
+    .describe("Acquirer price below which the target may terminate"),
+});
+
+const ContingentValueRightSchema = z.object({
+  payment_per_share: z
+    .number()
+    .min(0)
+    .describe("Cash paid per target share acquired if the milestone is met"),
+  probability: z
+    .number()
+    .min(0)
+    .max(1)
+    .describe("Probability the milestone is met"),
+  payment_year: z
+    .number()
+    .int()
+    .min(0)
+    .describe("Years after the target's first closing when the CVR pays"),
+  discount_rate: z.number().describe("Discount rate for the CVR fair value"),
+});
+
+const AcquisitionStageSchema = z.object({
+  year: z
+    .number()
+    .int()
+    .positive()
+    .describe("Projection year the stake closes (start of year)"),
+  stake_pct: z
+    .number()
+    .gt(0)
+    .max(1)
+    .describe("Fraction of target shares bought in this stage"),
+  offer_price_per_share: z
+    .number()
+    .positive()
+    .optional()
+    .describe("Stage offer price (defaults to the target offer price)"),
+  consideration: ConsiderationTypeSchema.optional().describe(
+    "Stage consideration (defaults to the target consideration)"
+  ),
+  acquirer_share_price: z
+    .number()
+    .positive()
+    .optional()
+    .describe("Acquirer share price at this closing (defaults to current)"),
+});
+
+const TargetDealSchema = z.object({
+  name: z.string().describe("Target company name"),
+  net_income: z.coerce.number().describe("Target annual net income"),
+  net_income_growth: z
+    .number()
+    .optional()
+    .describe("Annual growth in target standalone net income"),
+  shares_outstanding: z.number().positive().describe("Target shares outstanding"),
+  share_price: z.number().positive().describe("Target current share price"),
+  offer_price_per_share: z
+    .number()
+    .positive()
+    .describe("Offer price per target share"),
+  consideration: ConsiderationTypeSchema.describe(
+    'Deal consideration: "AllCash", "AllStock", or {"Mixed": {"cash_pct": 0.5}}'
+  ),
+  stages: z
+    .array(AcquisitionStageSchema)
+    .optional()
+    .describe("Purchase stages (omit to buy 100% in year 1)"),
+  collar: ExchangeRatioCollarSchema.optional().describe(
+    "Collar on the stock exchange ratio"
+  ),
+  cvr: ContingentValueRightSchema.optional().describe(
+    "Contingent value right paid in cash to selling shareholders"
+  ),
+  synergies: z
+    .array(SynergyCategorySchema)
+    .optional()
+    .describe("Synergy categories phased from the year of control (>50%)"),
+  integration_costs: z
+    .array(z.number())
+    .optional()
+    .describe("Pre-tax integration costs by year from the year of control"),
+  transaction_fees: z
+    .number()
+    .optional()
+    .describe("One-time fees charged in the first closing year"),
+});
+
+export const MultiTargetMergerSchema = z.object({
+  acquirer_name: z.string().describe("Acquirer company name"),
+  acquirer_net_income: z.coerce.number().describe("Acquirer annual net income"),
+  acquirer_shares_outstanding: z
+    .number()
+    .positive()
+    .describe("Acquirer shares outstanding"),
+  acquirer_share_price: z
+    .number()
+    .positive()
+    .describe("Acquirer current share price"),
+  acquirer_tax_rate: z
+    .number()
+    .min(0)
+    .max(1)
+    .describe("Acquirer corporate tax rate"),
+  acquirer_net_income_growth: z
+    .number()
+    .optional()
+    .describe("Annual growth in acquirer standalone net income"),
+  debt_financing_rate: z
+    .number()
+    .min(0)
+    .optional()
+    .describe("Interest rate on debt funding cash consideration and CVR payments"),
+  foregone_interest_rate: z
+    .number()
+    .min(0)
+    .optional()
+    .describe("Rate earned on cash balances that are foregone when paying cash"),
+  projection_years: z
+    .number()
+    .int()
+    .positive()
+    .describe("Years in the pro-forma EPS schedule"),
+  targets: z
+    .array(TargetDealSchema)
+    .min(1)
+    .describe("Targets in the acquisition programme"),
+});
+
+// --- PpaInput ---
+// Rust struct: PpaInput in ma/ppa.rs
+export const PpaSchema = z.object({
+  target_name: z.string().describe("Target company name"),
+  purchase_consideration: z
+    .number()
+    .positive()
+    .describe("Fair value of consideration transferred, including contingent consideration"),
+  non_controlling_interest: z
+    .number()
+    .min(0)
+    .optional()
+    .describe("Fair value of the non-controlling interest (full goodwill method)"),
+  previously_held_interest: z
+    .number()
+    .min(0)
+    .optional()
+    .describe("Fair value of a previously held equity interest (step acquisition)"),
+  net_assets: IdentifiableNetAssetsSchema.describe(
+    "Target book equity and fair-value step-ups"
+  ),
+  tax_rate: z.number().min(0).max(1).describe("Tax rate for deferred tax"),
+  projection_years: z
+    .number()
+    .int()
+    .positive()
+    .describe("Years in the incremental D&A schedule"),
+});
+
+export const MergerArbSchema = z.object({
+  target_name: z.string(),
+  target_price: z.coerce.number().describe("Current market price of the target."),
+  cash_per_share: z.coerce.number().describe("Cash paid per target share at closing."),
+  exchange_ratio: z.coerce.number().describe("Acquirer shares delivered per target share (0 for all-cash deals)."),
+  acquirer_price: z.coerce.number().optional().describe("Current acquirer share price (required when `exchange_ratio` > 0)."),
+  downside_price: z.coerce.number().describe("Estimated target price if the deal breaks (undisturbed / fallback value)."),
+  days_to_close: z.coerce.number().int().describe("Calendar days until the expected closing date."),
+  risk_free_rate: z.coerce.number().describe("Annual risk-free rate used for carry and excess return."),
+  target_dividends: z.coerce.number().optional().describe("Target dividends expected to be received before closing."),
+  acquirer_dividends: z.coerce.number().optional().describe("Acquirer dividends per acquirer share owed on the short hedge before closing."),
+  completion_probability: z.coerce.number().optional().describe("Analyst's own estimate of the probability the deal closes. Used for expected return and Kelly sizing; defaults to the market-implied probability."),
+  kelly_fraction: z.coerce.number().optional().describe("Fraction of full Kelly to apply (e.g. 0.5 for half Kelly). Defaults to 1."),
+  portfolio_value: z.coerce.number().optional().describe("Portfolio value used to size the position in currency terms."),
+  max_position_pct: z.coerce.number().optional().describe("Maximum position as a fraction of the portfolio."),
+});
+
+const FinancingTrancheSchema = z.object({
+  tranche: DebtScheduleSchema.describe("Facility terms (term loan, bond or revolver draw)"),
+  upfront_fee_pct: z.coerce.number().optional().describe("Upfront / arrangement fee as a fraction of the commitment (or amount)"),
+  oid_pct: z.coerce.number().optional().describe("Original issue discount as a fraction of face value"),
+  commitment: z.coerce.number().optional().describe("Total facility size for revolvers; `amount` is the draw at closing"),
+});
+
+const ProFormaYearInputSchema = z.object({
+  ebitda: z.coerce.number().describe("Combined EBITDA"),
+  capex: z.coerce.number().describe("Capital expenditure"),
+  cash_taxes: z.coerce.number().describe("Cash taxes paid"),
+  nwc_change: z.coerce.number().describe("Increase in net working capital (positive = cash outflow)"),
+});
+
+export const AcquisitionFinancingSchema = z.object({
+  equity_purchase_price: z.coerce.number().describe("Equity purchase price paid to target shareholders"),
+  target_debt_refinanced: z.coerce.number().describe("Target debt repaid at closing"),
+  transaction_fees: z.coerce.number().optional().describe("Advisory, legal and other transaction fees"),
+  cash_on_hand_used: z.coerce.number().describe("Balance sheet cash contributed by the acquirer"),
+  tranches: z.array(FinancingTrancheSchema).describe("New debt facilities (RCF draw, term loans, bonds)"),
+  equity_issuance: z.coerce.number().optional().describe("Gross proceeds of new equity issued to fund the deal"),
+  equity_issuance_fee_pct: z.coerce.number().optional().describe("Underwriting fee on the equity issuance as a fraction of proceeds"),
+  acquirer_existing_debt: z.coerce.number().describe("Acquirer debt that remains outstanding after closing"),
+  acquirer_existing_debt_rate: z.coerce.number().describe("Cash interest rate on the acquirer's existing debt"),
+  closing_cash: z.coerce.number().describe("Combined cash balance immediately after closing"),
+  ltm_combined_ebitda: z.coerce.number().describe("Combined LTM EBITDA used for closing leverage"),
+  projections: z.array(ProFormaYearInputSchema).describe("Pro forma forecast years"),
+  cash_sweep_pct: z.coerce.number().optional().describe("Share of excess free cash flow applied to prepayable bank debt"),
+  minimum_cash: z.coerce.number().optional().describe("Minimum cash balance; shortfalls are funded on the revolver"),
+  covenants: CovenantTestSchema.shape.covenants
+    .optional()
+    .describe("Maintenance covenants tested for each forecast year"),
+});
+
+// --- Ticker ---
+// Rust enum (externally tagged serde default):
+//   None                  -> "None"
+//   AnnualRate { rate }   -> { "AnnualRate": { "rate": r } }
+//   DailyAmount { amount } -> { "DailyAmount": { "amount": m } }
+const TickerSchema = z.union([
+  z.literal("None"),
+  z.object({
+    AnnualRate: z.object({
+      rate: z.coerce.number().describe("Annual rate applied to the locked-box equity price"),
+    }),
+  }),
+  z.object({
+    DailyAmount: z.object({
+      amount: z.coerce.number().describe("Fixed amount per calendar day"),
+    }),
+  }),
+]);
+
+const LockedBoxTermsSchema = z.object({
+  ticker: TickerSchema.optional().describe("Locked-box ticker paid to the seller for the period to completion (default None)"),
+  expected_unrecovered_leakage: z.coerce.number().optional().describe("Expected leakage to the seller not recovered under the indemnity."),
+});
+
+const ThresholdTypeSchema = z.enum(["Tipping", "Deductible"]);
+
+const CompletionAccountsTermsSchema = z.object({
+  nwc_target: z.coerce.number().describe("Working capital peg agreed in the SPA."),
+  expected_nwc: z.coerce.number().describe("Expected working capital at completion."),
+  nwc_volatility: z.coerce.number().describe("Standard deviation of completion working capital, typically from the historical monthly NWC series."),
+  de_minimis: z.coerce.number().optional().describe("De minimis threshold below which no adjustment is made."),
+  threshold_type: ThresholdTypeSchema.optional().describe("Tipping (whole amount once exceeded) or Deductible (only the excess)"),
+  cap: z.coerce.number().optional().describe("Maximum absolute true-up in either direction."),
+  buyer_process_cost: z.coerce.number().optional().describe("Preparation, review and dispute costs borne by the buyer."),
+  seller_process_cost: z.coerce.number().optional().describe("Preparation, review and dispute costs borne by the seller."),
+  num_simulations: z.coerce.number().int().optional().describe("Number of Monte Carlo draws (default 10,000)."),
+  seed: z.coerce.number().int().optional().describe("RNG seed (default 42)."),
+});
+
+export const PurchasePriceMechanismSchema = z.object({
+  target_name: z.string().describe("Target company name"),
+  enterprise_value: z.coerce.number().describe("Cash-free, debt-free headline price on a normalised NWC basis."),
+  net_debt_at_locked_box: z.coerce.number().describe("Net debt on the locked-box balance sheet."),
+  days_to_completion: z.coerce.number().int().describe("Calendar days from the locked-box date to completion."),
+  annual_free_cash_flow: z.coerce.number().describe("Free cash flow generated by the target, annualised."),
+  locked_box: LockedBoxTermsSchema.describe("Locked-box terms"),
+  completion_accounts: CompletionAccountsTermsSchema.describe("Completion-accounts terms and working capital distribution"),
+});
+
+const EarnoutMetricSchema = z.enum(["Revenue", "Ebitda"]);
+
+// --- EarnoutPayoff ---
+// Rust enum (externally tagged serde default):
+//   Binary { payment }               -> { "Binary": { "payment": m } }
+//   Linear { participation_rate, cap } -> { "Linear": { "participation_rate": r, "cap": m } }
+const EarnoutPayoffSchema = z.union([
+  z.object({
+    Binary: z.object({
+      payment: z.coerce.number().describe("Fixed payment if the metric reaches the threshold"),
+    }),
+  }),
+  z.object({
+    Linear: z.object({
+      participation_rate: z.coerce.number().describe("Share of the metric in excess of the threshold"),
+      cap: z.coerce.number().optional().describe("Maximum payment for the period"),
+    }),
+  }),
+]);
+
+const EarnoutPeriodSchema = z.object({
+  year: z.coerce.number().int().describe("Year (from closing) in which the metric is measured."),
+  threshold: z.coerce.number().describe("Metric level the payoff is measured against"),
+  payoff: EarnoutPayoffSchema.describe("Binary payment or linear participation above the threshold"),
+});
+
+const EarnoutScenarioSchema = z.object({
+  name: z.string().describe("Scenario name"),
+  metrics: z.array(z.coerce.number()).describe("Metric for each period, in period order."),
+});
+
+export const EarnoutSchema = z.object({
+  metric: EarnoutMetricSchema.describe("Performance metric the earn-out is based on"),
+  base_metric: z.coerce.number().describe("Metric for the last full year before closing."),
+  expected_growth: z.coerce.number().describe("Expected annual growth of the metric (management case)."),
+  volatility: z.coerce.number().describe("Annual volatility of the metric."),
+  risk_free_rate: z.coerce.number().describe("Risk-free rate"),
+  metric_risk_premium: z.coerce.number().describe("Metric risk premium deducted from growth to reach the risk-neutral drift."),
+  credit_spread: z.coerce.number().optional().describe("Buyer's credit spread, added to the discount rate for payments."),
+  payment_lag_years: z.coerce.number().optional().describe("Years between the end of a measurement period and payment."),
+  periods: z.array(EarnoutPeriodSchema).describe("Measurement periods in year order"),
+  catch_up: z.boolean().optional().describe("Missed payments can be earned later if the cumulative metric catches up."),
+  aggregate_cap: z.coerce.number().optional().describe("Cap on total payments across all periods."),
+  scenarios: z.array(EarnoutScenarioSchema).optional().describe("Named metric outcomes for the negotiation payout table"),
+  num_simulations: z.coerce.number().int().optional().describe("Number of Monte Carlo paths (default 10,000)."),
+  seed: z.coerce.number().int().optional().describe("RNG seed (default 42)."),
 });
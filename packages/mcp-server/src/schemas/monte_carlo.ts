@@ -24,9 +24,16 @@ const McDistributionSchema = z.discriminatedUnion("type", [
   }),
 ]);
 
+const VarianceReductionSchema = z.object({
+  sampling: z.enum(["PseudoRandom", "Antithetic", "Sobol"]).optional().describe("Sampling scheme: independent draws, antithetic pairs, or randomised Sobol sequence (default PseudoRandom)"),
+  control_variates: z.boolean().optional().describe("Adjust the output mean using the sampled inputs as control variates (McDcf only)"),
+});
+
 export const MonteCarloSchema = z.object({
   num_simulations: z.coerce.number().int().min(100).max(1000000).optional().describe("Number of simulation paths (default 10,000)"),
   seed: z.coerce.number().int().optional().describe("Optional seed for reproducibility"),
+  variance_reduction: VarianceReductionSchema.optional().describe("Variance reduction and quasi-Monte Carlo settings"),
+  correlation: z.array(z.array(z.coerce.number().min(-1).max(1))).optional().describe("Correlation matrix between variables, in variables order (Gaussian copula)"),
   variables: z.array(z.object({
     name: z.string().describe("Variable name"),
     distribution: McDistributionSchema.describe("Probability distribution for this variable"),
@@ -44,4 +51,6 @@ export const McDcfSchema = z.object({
   tax_rate: z.coerce.number().min(0).max(0.5).describe("Corporate tax rate"),
   num_simulations: z.coerce.number().int().min(100).max(1000000).optional().describe("Number of simulation paths (default 10,000)"),
   seed: z.coerce.number().int().optional().describe("Optional seed for reproducibility"),
+  variance_reduction: VarianceReductionSchema.optional().describe("Variance reduction and quasi-Monte Carlo settings"),
+  correlation: z.array(z.array(z.coerce.number().min(-1).max(1))).optional().describe("4x4 correlation matrix between revenue growth, EBITDA margin, WACC and terminal growth (Gaussian copula)"),
 });
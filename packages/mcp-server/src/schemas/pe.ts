@@ -1,10 +1,11 @@
 import { z } from "zod";
-import { CashFlowSchema, CurrencySchema } from "./common.js";
+import { CashFlowSchema, CurrencySchema, FxRateSchema } from "./common.js";
+import { TakahashiAlexanderParamsSchema } from "./fund_of_funds.js";
 
 // --- ReturnsInput ---
 // Rust struct: ReturnsInput in pe/returns.rs
 // Fields: cash_flows, dated_cash_flows?, entry_equity, exit_equity,
-//         holding_period_years?, dates?
+//         holding_period_years?, dates?, subscription_line?
 export const ReturnsSchema = z.object({
   cash_flows: z
     .array(z.coerce.number())
@@ -27,6 +28,37 @@ export const ReturnsSchema = z.object({
     .describe(
       "Entry and exit dates as [entry, exit] ISO 8601 strings for XIRR and date-based holding period"
     ),
+  subscription_line: z
+    .object({
+      call_delay_periods: z
+        .number()
+        .int()
+        .min(0)
+        .describe("Periods each capital call is deferred (periodic cash flows)"),
+      call_delay_days: z
+        .number()
+        .int()
+        .min(0)
+        .optional()
+        .describe("Days each capital call is deferred (dated cash flows)"),
+      interest_rate: z.coerce
+        .number()
+        .min(0)
+        .describe("Annual interest rate on line borrowings"),
+      fees: z.coerce
+        .number()
+        .min(0)
+        .optional()
+        .describe("Upfront and commitment fees, called at inception"),
+      facility_size: z.coerce
+        .number()
+        .optional()
+        .describe("Facility size, for disclosure"),
+    })
+    .optional()
+    .describe(
+      "Subscription line financing; reports IRR/TVPI with and without the line and an ILPA-style disclosure"
+    ),
 });
 
 // --- AmortisationType ---
@@ -178,6 +210,40 @@ export const LboSchema = z.object({
     .number()
     .optional()
     .describe("Minimum cash balance to maintain before optional repayments"),
+  revolver: z
+    .object({
+      commitment: z.coerce.number().positive().describe("Total revolver commitment"),
+      interest_rate: z.coerce.number().min(0).describe("All-in cash rate on drawn balance"),
+      commitment_fee: z.coerce.number().min(0).optional().describe("Fee on undrawn commitment"),
+      initial_draw: z.coerce.number().min(0).optional().describe("Amount drawn at close"),
+    })
+    .optional()
+    .describe("Revolving credit facility drawn when cash falls below minimum_cash"),
+  sweep_tiers: z
+    .array(
+      z.object({
+        min_leverage: z.coerce.number().min(0).describe("Tier applies at or above this debt / EBITDA"),
+        sweep_pct: z.coerce.number().min(0).max(1).describe("Share of excess cash flow swept"),
+      })
+    )
+    .optional()
+    .describe("Leverage-based mandatory cash sweep; replaces cash_sweep_pct when given"),
+  optional_prepayment_pct: z
+    .number()
+    .min(0)
+    .max(1)
+    .optional()
+    .describe("Share of cash above minimum_cash voluntarily prepaid after the sweep"),
+  pik_toggles: z
+    .array(
+      z.object({
+        tranche: z.string().describe("Tranche name"),
+        pik_years: z.array(z.coerce.number().int().min(1)).describe("Years paying interest in kind"),
+        pik_premium: z.coerce.number().min(0).optional().describe("Premium over cash rate when PIK"),
+      })
+    )
+    .optional()
+    .describe("PIK toggle elections by tranche"),
 });
 
 // --- WaterfallTierType ---
@@ -197,7 +263,8 @@ const WaterfallTierTypeSchema = z.union([
 
 // --- WaterfallInput ---
 // Rust struct: WaterfallInput in pe/waterfall.rs
-// Fields: total_proceeds, total_invested, tiers (Vec<WaterfallTier>), gp_commitment_pct
+// Fields: total_proceeds, total_invested, tiers (Vec<WaterfallTier>), gp_commitment_pct,
+//         style, deals, fee_offset, gp_tax_rate
 export const WaterfallSchema = z.object({
   total_proceeds: z
     .number()
@@ -221,6 +288,173 @@ export const WaterfallSchema = z.object({
     .min(0)
     .max(1)
     .describe("GP commitment as a fraction of fund (typically 0.01 - 0.05)"),
+  style: z
+    .enum(["European", "American"])
+    .optional()
+    .describe("Whole-fund (European, default) or deal-by-deal (American) carry with clawback"),
+  deals: z
+    .array(
+      z.object({
+        name: z.string(),
+        invested: z.number().positive().describe("Capital invested in the deal"),
+        proceeds: z.number().min(0).describe("Realised proceeds from the deal"),
+        holding_years: z
+          .number()
+          .positive()
+          .optional()
+          .describe("Holding period; compounds the preferred return when given"),
+      })
+    )
+    .optional()
+    .describe("Realised deals (required for American style; totals are taken from the deals)"),
+  fee_offset: z
+    .object({
+      management_fees: z.number().min(0).describe("Management fees charged to LPs"),
+      portfolio_company_fees: z
+        .number()
+        .min(0)
+        .describe("Transaction and monitoring fees received from portfolio companies"),
+      offset_pct: z
+        .number()
+        .min(0)
+        .max(1)
+        .describe("Share of portfolio company fees credited against management fees"),
+      return_fees_before_carry: z
+        .boolean()
+        .optional()
+        .describe("Return net management fees to LPs before carry (default true)"),
+    })
+    .optional()
+    .describe("Management fee offset"),
+  gp_tax_rate: z
+    .number()
+    .min(0)
+    .max(1)
+    .optional()
+    .describe("GP tax rate; clawback is limited to after-tax carry"),
+});
+
+// --- InterimNavInput ---
+// Rust struct: InterimNavInput in pe/interim_nav.rs
+const ValuationEventSchema = z.discriminatedUnion("type", [
+  z.object({
+    type: z.literal("NewRound"),
+    date: z.string().describe("Round closing date (YYYY-MM-DD)"),
+    implied_value: z.coerce.number().min(0).describe("Value of the fund's stake at the round price (local currency)"),
+    market_return_since: z.coerce.number().optional().describe("Comp basket return from the round date to the estimate date"),
+  }),
+  z.object({
+    type: z.literal("Exit"),
+    date: z.string().describe("Exit date (YYYY-MM-DD)"),
+    proceeds: z.coerce.number().min(0).describe("Net proceeds to the fund (local currency)"),
+  }),
+  z.object({
+    type: z.literal("Impairment"),
+    date: z.string().describe("Impairment date (YYYY-MM-DD)"),
+    write_down_pct: z.coerce.number().min(0).max(1).describe("Write-down as a fraction of current value"),
+  }),
+]);
+
+export const InterimNavSchema = z.object({
+  report_date: z.string().describe("Date of the last GP-reported NAV (YYYY-MM-DD)"),
+  estimate_date: z.string().describe("Date to estimate the NAV for (YYYY-MM-DD)"),
+  reporting_currency: CurrencySchema.optional().describe("NAV reporting currency (default USD)"),
+  holdings: z
+    .array(
+      z.object({
+        name: z.string().describe("Portfolio company name"),
+        currency: CurrencySchema.optional().describe("Currency the holding is valued in"),
+        reported_value: z.coerce.number().min(0).describe("Last reported fair value of the stake (local currency)"),
+        public_comps: z
+          .array(
+            z.object({
+              name: z.string().describe("Comparable company or index"),
+              return_since_report: z.coerce.number().describe("Total return since the report date (decimal)"),
+              weight: z.coerce.number().min(0).optional().describe("Basket weight (default equal weight)"),
+            })
+          )
+          .optional()
+          .describe("Listed comparables used to roll the mark forward"),
+        beta: z.coerce.number().min(0).optional().describe("Sensitivity to the comp basket (default 1.0)"),
+        events: z.array(ValuationEventSchema).optional().describe("Known events since the report date"),
+      })
+    )
+    .min(1)
+    .describe("Holdings at their last reported marks"),
+  other_net_assets: z.coerce.number().optional().describe("Cash and other net assets at the report date"),
+  capital_calls: z.coerce.number().min(0).optional().describe("Capital called since the report date"),
+  distributions: z.coerce.number().min(0).optional().describe("Distributions paid since the report date"),
+  fx_rates_at_report: z.array(FxRateSchema).optional().describe("FX quotes at the report date"),
+  fx_rates_at_estimate: z.array(FxRateSchema).optional().describe("FX quotes at the estimate date"),
+});
+
+// --- CoInvestmentInput ---
+// Rust struct: CoInvestmentInput in pe/co_investment.rs
+export const CoInvestmentSchema = z.object({
+  deal: z.object({
+    name: z.string().describe("Portfolio company name"),
+    sector: z.string().describe("Sector of the deal"),
+    geography: z.string().describe("Geography of the deal"),
+    investment_amount: z.coerce.number().positive().describe("Capital committed to the co-investment"),
+    expected_gross_moic: z.coerce.number().min(0).describe("Expected gross multiple on invested capital"),
+    holding_period_years: z.coerce.number().positive().describe("Expected holding period in years"),
+    management_fee: z.coerce.number().min(0).max(1).optional().describe("Annual co-invest management fee (default 0)"),
+    carried_interest: z.coerce.number().min(0).max(1).optional().describe("Co-invest carried interest (default 0)"),
+    periodic_returns: z.array(z.coerce.number()).optional().describe("Deal (or proxy) periodic returns for risk impact"),
+  }).describe("Co-investment opportunity"),
+  fund_terms: z.object({
+    management_fee: z.coerce.number().min(0).max(1).describe("Fund annual management fee on invested capital"),
+    carried_interest: z.coerce.number().min(0).max(1).describe("Fund carried interest"),
+    hurdle_rate: z.coerce.number().min(0).describe("Fund preferred return"),
+  }).describe("Terms for the same exposure via the fund"),
+  portfolio: z.object({
+    exposures: z.array(z.object({
+      name: z.string().describe("Asset name"),
+      sector: z.string().describe("Sector"),
+      geography: z.string().describe("Geography"),
+      value: z.coerce.number().min(0).describe("Current value"),
+    })).describe("Existing LP portfolio exposures"),
+    periodic_returns: z.array(z.coerce.number()).optional().describe("Portfolio periodic returns aligned with the deal series"),
+    return_frequency: z.enum(["Daily", "Weekly", "Monthly", "Quarterly", "Annual"]).optional().describe("Return frequency (default Quarterly)"),
+  }).describe("LP portfolio"),
+  policy: z.object({
+    max_single_asset_pct: z.coerce.number().positive().max(1).describe("Maximum single-asset exposure"),
+    max_sector_pct: z.coerce.number().positive().max(1).describe("Maximum sector exposure"),
+    max_geography_pct: z.coerce.number().positive().max(1).describe("Maximum geography exposure"),
+    min_net_irr: z.coerce.number().optional().describe("Minimum acceptable net IRR"),
+    target_net_irr: z.coerce.number().positive().optional().describe("Net IRR scoring in full (default 0.15)"),
+  }).describe("Co-investment policy constraints"),
+});
+
+// --- PacingInput ---
+// Rust struct: PacingInput in pe/pacing.rs
+export const PacingSchema = z.object({
+  as_of_year: z.coerce.number().int().describe("Calendar year of the current paid-in and NAV figures"),
+  horizon_years: z.coerce.number().int().min(1).max(50).describe("Number of years to project"),
+  existing_commitments: z.array(z.object({
+    name: z.string().describe("Fund name"),
+    strategy: z.string().describe("Strategy: Buyout, Venture, Growth, Real Estate, Infrastructure, Private Credit, or a custom name with strategy_assumptions"),
+    vintage: z.coerce.number().int().describe("Vintage year"),
+    commitment: z.coerce.number().positive().describe("Total commitment"),
+    paid_in: z.coerce.number().min(0).describe("Capital called to date"),
+    nav: z.coerce.number().min(0).describe("Current NAV"),
+  })).optional().describe("Existing commitments, projected from their current age"),
+  planned_commitments: z.array(z.object({
+    vintage: z.coerce.number().int().describe("Vintage year (after as_of_year); calls start this year"),
+    strategy: z.string().describe("Strategy"),
+    commitment: z.coerce.number().positive().describe("Commitment amount"),
+  })).optional().describe("Commitments planned for future vintages"),
+  strategy_assumptions: z.array(z.object({
+    strategy: z.string().describe("Strategy name"),
+    params: TakahashiAlexanderParamsSchema,
+  })).optional().describe("Per-strategy Takahashi-Alexander parameter overrides"),
+  target: z.object({
+    total_portfolio_value: z.coerce.number().positive().describe("Total portfolio value at as_of_year"),
+    portfolio_growth_rate: z.coerce.number().optional().describe("Annual growth of the total portfolio (default 0)"),
+    target_allocation_pct: z.coerce.number().positive().max(1).describe("Target private markets allocation"),
+    strategy: z.string().describe("Strategy used to size the recommended annual commitment"),
+  }).optional().describe("Allocation target for over-commitment tracking and the recommended pace"),
+  liquidity_reserve: z.coerce.number().min(0).optional().describe("Cash set aside to meet net capital calls"),
 });
 
 // --- AltmanInput ---
@@ -1,4 +1,7 @@
 import { z } from "zod";
+import { CurrencySchema } from "./common.js";
+import { DayCountSchema } from "./fixed_income.js";
+import { MarketDataSetSchema, MarketScenarioSchema } from "./scenarios.js";
 
 export const RiskAdjustedSchema = z.object({
   returns: z
@@ -23,6 +26,93 @@ export const RiskAdjustedSchema = z.object({
     .describe("Target return for Sortino ratio calculation (annualised); defaults to risk_free_rate"),
 });
 
+export const PerformanceRatiosSchema = z.object({
+  returns: z
+    .array(z.coerce.number())
+    .min(2)
+    .describe("Periodic returns as decimals (e.g. 0.02 = 2%)"),
+  risk_free_rate: z
+    .number()
+    .min(0)
+    .max(0.2)
+    .describe("Annualised risk-free rate"),
+  benchmark_returns: z
+    .array(z.coerce.number())
+    .optional()
+    .describe("Benchmark returns for upside/downside capture (same frequency)"),
+  frequency: z
+    .enum(["Daily", "Weekly", "Monthly", "Quarterly", "Annual"])
+    .describe("Return observation frequency"),
+  target_return: z
+    .number()
+    .optional()
+    .describe("Minimum acceptable return for Sortino (annualised); defaults to risk_free_rate"),
+  omega_thresholds: z
+    .array(z.coerce.number())
+    .optional()
+    .describe("Annualised thresholds for Omega ratios (default: 0 and risk_free_rate)"),
+});
+
+export const DrawdownSchema = z.object({
+  returns: z
+    .array(z.coerce.number())
+    .min(2)
+    .describe("Periodic returns as decimals (e.g. 0.02 = 2%)"),
+  frequency: z
+    .enum(["Daily", "Weekly", "Monthly", "Quarterly", "Annual"])
+    .describe("Return observation frequency"),
+  cdar_confidence: z
+    .number()
+    .min(0.5)
+    .max(0.999)
+    .optional()
+    .describe("Confidence level for conditional drawdown-at-risk (default 0.95)"),
+});
+
+export const BlendedBenchmarkSchema = z.object({
+  benchmark_name: z.string().describe("Name of the blended benchmark"),
+  index_returns: z
+    .array(
+      z.object({
+        index_name: z.string().describe("Index identifier referenced by segment components"),
+        returns: z.array(z.coerce.number()).min(1).describe("Periodic index returns as decimals"),
+      })
+    )
+    .min(1)
+    .describe("Return series for every constituent index, all the same length"),
+  segments: z
+    .array(
+      z.object({
+        start_period: z.coerce.number().int().min(0).describe("Zero-based period the definition takes effect"),
+        components: z
+          .array(
+            z.object({
+              index_name: z.string().describe("Index name"),
+              weight: z.coerce.number().min(0).max(1).describe("Target weight; segment weights sum to 1"),
+            })
+          )
+          .min(1)
+          .describe("Indices and target weights in the blend"),
+        rebalancing: z
+          .union([
+            z.enum(["EveryPeriod", "BuyAndHold"]),
+            z.object({ Calendar: z.object({ every_n_periods: z.coerce.number().int().min(1) }) }),
+            z.object({ Threshold: z.object({ tolerance: z.coerce.number().positive() }) }),
+          ])
+          .describe("Rebalancing rule: fixed weights, calendar, drift threshold or buy-and-hold"),
+      })
+    )
+    .min(1)
+    .describe("Benchmark definitions in chronological order; the first starts at period 0"),
+  portfolio_returns: z
+    .array(z.coerce.number())
+    .optional()
+    .describe("Portfolio returns (same frequency) for excess return statistics"),
+  frequency: z
+    .enum(["Daily", "Weekly", "Monthly", "Quarterly", "Annual"])
+    .describe("Return observation frequency"),
+});
+
 export const RiskMetricsSchema = z.object({
   returns: z
     .array(z.coerce.number())
@@ -70,3 +160,122 @@ export const KellySchema = z.object({
     .optional()
     .describe("Maximum position as a percentage of portfolio"),
 });
+
+const VolInputs = {
+  vol_surface: z.string().optional().describe("Vol surface id in the market set"),
+  volatility: z.coerce.number().min(0).optional().describe("Flat volatility when no surface is given"),
+};
+
+const InstrumentSchema = z.discriminatedUnion("type", [
+  z.object({
+    type: z.literal("bond"),
+    face_value: z.coerce.number().positive().describe("Face value per bond"),
+    coupon_rate: z.coerce.number().min(0).describe("Annual coupon rate"),
+    coupon_frequency: z.coerce.number().int().positive().describe("Coupons per year"),
+    maturity_date: z.string().describe("Maturity date (YYYY-MM-DD)"),
+    day_count: DayCountSchema.optional().describe("Day count (default Thirty360)"),
+    discount_curve: z.string().describe("Rate curve id; yield read at maturity"),
+    spread_curve: z.string().optional().describe("Spread curve id added to the yield"),
+    call_price: z.coerce.number().positive().optional().describe("Call price"),
+    call_date: z.string().optional().describe("Call date (YYYY-MM-DD)"),
+  }),
+  z.object({
+    type: z.literal("equity"),
+    underlying: z.string().describe("Equity price id"),
+  }),
+  z.object({
+    type: z.literal("equity_option"),
+    underlying: z.string().describe("Equity price id"),
+    strike_price: z.coerce.number().positive().describe("Strike price"),
+    time_to_expiry: z.coerce.number().positive().describe("Years to expiry"),
+    option_type: z.enum(["Call", "Put"]).describe("Option type"),
+    exercise_style: z.enum(["European", "American", "Bermudan"]).optional().describe("Exercise style (default European)"),
+    discount_curve: z.string().describe("Rate curve id"),
+    ...VolInputs,
+    dividend_yield: z.coerce.number().min(0).optional().describe("Continuous dividend yield"),
+  }),
+  z.object({
+    type: z.literal("swap"),
+    notional: z.coerce.number().positive().describe("Swap notional"),
+    fixed_rate: z.coerce.number().describe("Fixed rate"),
+    payment_frequency: z.coerce.number().int().positive().describe("Payments per year"),
+    remaining_years: z.coerce.number().positive().describe("Years remaining"),
+    is_pay_fixed: z.boolean().describe("True to pay fixed and receive floating"),
+    discount_curve: z.string().describe("Rate curve id for discounting"),
+    projection_curve: z.string().optional().describe("Rate curve id for floating forwards"),
+    last_floating_reset: z.coerce.number().optional().describe("Fixed first floating rate"),
+  }),
+  z.object({
+    type: z.literal("convertible"),
+    face_value: z.coerce.number().positive().describe("Face value per bond"),
+    coupon_rate: z.coerce.number().min(0).describe("Annual coupon rate"),
+    coupon_frequency: z.coerce.number().int().positive().describe("Coupons per year"),
+    maturity_years: z.coerce.number().positive().describe("Years to maturity"),
+    conversion_ratio: z.coerce.number().min(0).describe("Shares per bond"),
+    underlying: z.string().describe("Equity price id of the shares"),
+    discount_curve: z.string().describe("Rate curve id"),
+    spread_curve: z.string().optional().describe("Issuer spread curve id"),
+    credit_spread: z.coerce.number().min(0).optional().describe("Flat credit spread when no spread curve is given"),
+    ...VolInputs,
+    dividend_yield: z.coerce.number().min(0).optional().describe("Dividend yield"),
+    call_price: z.coerce.number().positive().optional().describe("Issuer call price"),
+    call_protection_years: z.coerce.number().min(0).optional().describe("Hard call protection in years"),
+    put_price: z.coerce.number().positive().optional().describe("Holder put price"),
+    put_date_years: z.coerce.number().positive().optional().describe("Put date in years"),
+    tree_steps: z.coerce.number().int().positive().optional().describe("Lattice steps (default 100)"),
+  }),
+  z.object({
+    type: z.literal("structured_note"),
+    note_type: z.enum(["CapitalProtected", "YieldEnhancement", "Participation", "CreditLinked"]).describe("Note type"),
+    notional: z.coerce.number().positive().describe("Note notional"),
+    maturity_years: z.coerce.number().positive().describe("Years to maturity"),
+    underlying: z.string().describe("Equity price id of the reference"),
+    discount_curve: z.string().describe("Rate curve id"),
+    ...VolInputs,
+    protection_level: z.coerce.number().optional().describe("Capital protection level"),
+    participation_rate: z.coerce.number().optional().describe("Traded participation (solved at par if omitted)"),
+    cap_level: z.coerce.number().optional().describe("Cap level"),
+    barrier_level: z.coerce.number().optional().describe("Barrier level"),
+    coupon_rate: z.coerce.number().optional().describe("Note coupon"),
+    strike_pct: z.coerce.number().optional().describe("Strike as a share of the current level"),
+    floor_level: z.coerce.number().optional().describe("Floor level"),
+    reference_entity: z.string().optional().describe("Credit-linked reference entity"),
+    credit_spread_bps: z.coerce.number().optional().describe("Credit spread in bps"),
+    recovery_rate: z.coerce.number().optional().describe("Recovery rate"),
+    default_probability: z.coerce.number().optional().describe("Annual default probability"),
+  }),
+]);
+
+export const PortfolioValuationSchema = z.object({
+  valuation_date: z.string().describe("Valuation date (YYYY-MM-DD)"),
+  base_currency: CurrencySchema.optional().describe("Reporting currency (default USD)"),
+  market: MarketDataSetSchema.describe("Shared market data the positions reference by id"),
+  positions: z
+    .array(
+      z.object({
+        id: z.string().describe("Unique position id"),
+        quantity: z.coerce.number().describe("Units held, negative for shorts"),
+        currency: CurrencySchema.optional().describe("Currency the instrument is priced in (default USD)"),
+        instrument: InstrumentSchema.describe("Instrument terms, tagged by type"),
+      })
+    )
+    .min(1)
+    .describe("Positions in the book"),
+});
+
+export const RevaluationVarSchema = z.object({
+  portfolio: PortfolioValuationSchema.describe("Book and base market, as for portfolio_valuation"),
+  method: z.enum(["Historical", "MonteCarlo"]).describe("Historical factor moves or correlated Monte Carlo draws"),
+  historical_scenarios: z.array(MarketScenarioSchema).optional().describe("Factor moves over the VaR horizon, one per observation (Historical)"),
+  monte_carlo: z.object({
+    factors: z.array(z.object({
+      factor: z.enum(["equity_market", "interest_rates", "credit_spreads", "fx_usd", "commodities", "volatility"]).describe("Market factor"),
+      volatility: z.coerce.number().min(0).describe("Std dev of the move over the horizon (log move for equities, commodities, USD)"),
+      drift: z.coerce.number().optional().describe("Mean move over the horizon (default 0)"),
+    })).min(1).describe("Simulated factors"),
+    correlation_matrix: z.array(z.array(z.coerce.number())).optional().describe("Factor correlation matrix in factor order (default independent)"),
+    num_simulations: z.coerce.number().int().positive().describe("Number of simulated scenarios"),
+    seed: z.coerce.number().int().min(0).optional().describe("Random seed for reproducibility"),
+  }).optional().describe("Scenario generator (MonteCarlo)"),
+  confidence_level: z.coerce.number().gt(0).lt(1).describe("Confidence level (e.g. 0.99)"),
+});
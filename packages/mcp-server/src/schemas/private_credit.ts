@@ -60,3 +60,150 @@ export const SyndicationSchema = z.object({
   participation_fee_bps: z.coerce.number().min(0).describe("Participation fee in bps on allocation"),
   coupon_spread_bps: z.coerce.number().min(0).describe("Ongoing coupon spread in bps"),
 });
+
+// Rating accepts S&P/Fitch ("BB-") or Moody's ("Ba3") notation.
+const RatingSchema = z.string();
+
+const PricingGridEntrySchema = z.object({
+  rating: RatingSchema.describe("Rating the grid row applies to"),
+  max_leverage: z.coerce.number().describe("Upper bound of the leverage band (total debt / EBITDA)"),
+  spread_bps: z.coerce.number().describe("Clearing spread over the base rate in basis points"),
+  oid_pct: z.coerce.number().describe("Clearing original issue discount (e.g. 0.01 = 99.0 issue price)"),
+});
+
+const FlexScenarioSchema = z.object({
+  name: z.string().describe("Scenario name"),
+  clearing_spread_bps: z.coerce.number().describe("Spread at which the loan clears in this scenario (bps)"),
+  clearing_oid_pct: z.coerce.number().describe("OID at which the loan clears in this scenario"),
+});
+
+const UnderwritingTermsSchema = z.object({
+  underwriting_fee_pct: z.coerce.number().describe("Underwriting / arrangement fee on the facility"),
+  final_hold: z.coerce.number().describe("Amount the underwriter intends to hold after syndication"),
+  max_spread_flex_bps: z.coerce.number().describe("Maximum upward spread flex in basis points"),
+  max_oid_flex_pct: z.coerce.number().describe("Maximum additional OID under flex"),
+  scenarios: z.array(FlexScenarioSchema).describe("Syndication clearing scenarios"),
+});
+
+export const LoanPricingSchema = z.object({
+  deal_name: z.string().describe("Deal identifier"),
+  facility_amount: z.coerce.number().describe("Term loan amount"),
+  rating: RatingSchema.describe("Expected facility / corporate rating"),
+  total_debt: z.coerce.number().describe("Pro forma total debt including the facility"),
+  borrower_ebitda: z.coerce.number().describe("Borrower LTM EBITDA"),
+  pricing_grid: z.array(PricingGridEntrySchema).describe("Market clearing grid"),
+  base_rate: z.coerce.number().describe("Base rate (SOFR or equivalent)"),
+  base_rate_floor: z.coerce.number().optional().describe("Base rate floor, if any"),
+  maturity_years: z.coerce.number().int().describe("Maturity in years"),
+  amortization_pct: z.coerce.number().optional().describe("Annual mandatory amortization as a fraction of original par"),
+  call_protection: z.array(z.coerce.number()).optional().describe("Call premium by year (e.g. [0.01] = 101 soft call in year 1)"),
+  expected_repayment_year: z.coerce.number().int().describe("Year in which the loan is assumed to be refinanced (market convention is 3 or 4 years for discount margin)"),
+  underwriting: UnderwritingTermsSchema.optional().describe("Underwriting commitment and flex scenarios"),
+});
+
+const BridgeFeesSchema = z.object({
+  commitment_fee_pct: z.coerce.number().describe("Paid at signing on the full commitment"),
+  ticking_fee_bps: z.coerce.number().optional().describe("Ticking fee on the undrawn commitment from signing to closing (bps p.a.)"),
+  funding_fee_pct: z.coerce.number().describe("Paid on the funded amount if the bridge is drawn"),
+  duration_fees: z.array(z.coerce.number()).optional().describe("Duration fees on the outstanding amount at the end of each three-month period the bridge remains funded (e.g. 90/180/270 days)"),
+  takeout_fee_pct: z.coerce.number().describe("Gross spread earned on the takeout securities"),
+});
+
+const BridgeCouponSchema = z.object({
+  base_rate: z.coerce.number().describe("Reference rate (e.g. SOFR or EURIBOR)"),
+  initial_spread_bps: z.coerce.number().describe("Initial spread over the base rate (bps)"),
+  step_up_bps: z.coerce.number().describe("Spread step-up every three months funded"),
+  cap_rate: z.coerce.number().describe("Total cap on the bridge coupon"),
+});
+
+const HoldingCostsSchema = z.object({
+  funding_cost_rate: z.coerce.number().describe("Cost of funding the drawn amount (p.a.)"),
+  unfunded_capital_charge: z.coerce.number().describe("Cost of capital held against the undrawn commitment (p.a.)"),
+  funded_capital_charge: z.coerce.number().describe("Cost of capital held against the funded loan (p.a.)"),
+});
+
+const TakeoutTermsSchema = z.object({
+  launch_yield: z.coerce.number().describe("Yield at which the takeout bonds are launched; the underwriters may flex pricing up to the securities demand cap at the borrower's cost"),
+  securities_demand_cap: z.coerce.number().describe("Maximum yield at which the borrower must issue takeout securities (securities demand cap); above it the underwriters absorb the difference"),
+  bond_duration: z.coerce.number().describe("Modified duration of the takeout bonds, used to convert yield concessions into price"),
+});
+
+const TakeoutScenarioSchema = z.object({
+  name: z.string().describe("Scenario name"),
+  probability: z.coerce.number().describe("Scenario probability (probabilities sum to 1)"),
+  months_funded: z.coerce.number().int().describe("Months the bridge is funded before takeout; 0 means the bonds are placed before closing and the bridge is never drawn"),
+  market_yield: z.coerce.number().describe("Clearing yield for the takeout bonds"),
+});
+
+export const BridgeFinancingSchema = z.object({
+  deal_name: z.string().describe("Deal name"),
+  commitment: z.coerce.number().describe("Total bridge commitment"),
+  underwriter_share: z.coerce.number().optional().describe("Underwriter's share of the commitment (default 100%)"),
+  days_to_closing: z.coerce.number().int().describe("Days from signing to closing (commitment period)"),
+  fees: BridgeFeesSchema.describe("Commitment, ticking, funding, duration and takeout fees"),
+  coupon: BridgeCouponSchema.describe("Bridge coupon with quarterly step-ups and cap"),
+  holding_costs: HoldingCostsSchema.describe("Funding and capital costs of holding the commitment"),
+  takeout: TakeoutTermsSchema.describe("Takeout bond terms and securities demand cap"),
+  scenarios: z.array(TakeoutScenarioSchema).describe("Takeout scenarios weighted by probability"),
+});
+
+const FundInvestmentSchema = z.object({
+  name: z.string().describe("Investment name"),
+  nav: z.coerce.number().describe("Net asset value of the investment"),
+  sector: z.string().describe("Sector, used for the sector concentration limit"),
+  eligible: z.boolean().optional().describe("Excluded investments contribute nothing to the borrowing base (default true)"),
+  advance_rate: z.coerce.number().optional().describe("Overrides the facility advance rate for this investment"),
+});
+
+const InvestorTierSchema = z.enum(["Included", "Designated", "Excluded"]);
+
+const InvestorCommitmentSchema = z.object({
+  name: z.string().describe("Investor name"),
+  undrawn_commitment: z.coerce.number().describe("Undrawn commitment of the LP"),
+  tier: InvestorTierSchema.describe("Included (rated institutions), Designated (other approved investors) or Excluded"),
+  advance_rate: z.coerce.number().optional().describe("Overrides the tier advance rate for this investor"),
+});
+
+// --- FundFacility ---
+// Rust enum (externally tagged serde default):
+//   NavFacility { ... }      -> { "NavFacility": { "investments": [...], ... } }
+//   SubscriptionLine { ... } -> { "SubscriptionLine": { "investors": [...], ... } }
+const FundFacilitySchema = z.union([
+  z.object({
+    NavFacility: z.object({
+      investments: z.array(FundInvestmentSchema).describe("Portfolio investments in the borrowing base"),
+      advance_rate: z.coerce.number().describe("Advance rate on eligible NAV"),
+      single_asset_limit: z.coerce.number().describe("Maximum share of eligible NAV from any one investment"),
+      sector_limit: z.coerce.number().optional().describe("Maximum share of eligible NAV from any one sector"),
+      max_ltv: z.coerce.number().describe("Loan-to-value covenant"),
+      draw_period: z.coerce.number().int().describe("Period in which the drawn amount is distributed to LPs"),
+      repay_period: z.coerce.number().int().describe("Period in which the facility is repaid out of distributions"),
+    }),
+  }),
+  z.object({
+    SubscriptionLine: z.object({
+      investors: z.array(InvestorCommitmentSchema).describe("LP undrawn commitments in the borrowing base"),
+      included_advance_rate: z.coerce.number().describe("Advance rate on Included investors"),
+      designated_advance_rate: z.coerce.number().describe("Advance rate on Designated investors"),
+      investor_limit: z.coerce.number().optional().describe("Maximum share of eligible commitments from any one investor"),
+      call_delay_periods: z.coerce.number().int().describe("Periods by which capital calls are deferred using the line"),
+    }),
+  }),
+]);
+
+const FacilityPricingSchema = z.object({
+  base_rate: z.coerce.number().describe("Reference rate"),
+  margin_bps: z.coerce.number().describe("Margin over the base rate (bps)"),
+  commitment_fee_bps: z.coerce.number().describe("Fee on the undrawn facility (bps p.a.)"),
+  upfront_fee_bps: z.coerce.number().describe("One-off fee on the facility size (bps)"),
+});
+
+export const FundFinanceSchema = z.object({
+  fund_name: z.string().describe("Fund name"),
+  facility: FundFacilitySchema.describe("NAV facility or subscription line with its borrowing base collateral"),
+  facility_size: z.coerce.number().describe("Committed facility size"),
+  drawn_amount: z.coerce.number().describe("Amount drawn under the facility"),
+  term_years: z.coerce.number().int().describe("Facility term in years"),
+  pricing: FacilityPricingSchema.describe("Facility margin and fees"),
+  fund_cash_flows: z.array(z.coerce.number()).optional().describe("LP net cash flows per annual period without the facility (calls negative, distributions positive); empty skips the IRR analysis"),
+});
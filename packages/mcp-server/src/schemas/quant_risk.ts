@@ -41,6 +41,18 @@ export const RiskParitySchema = z.object({
   risk_free_rate: z.coerce.number().min(0).optional().describe("Risk-free rate for Sharpe computation (defaults to 0)"),
 });
 
+export const HrpSchema = z.object({
+  assets: z.array(z.object({
+    name: z.string().describe("Asset name"),
+    expected_return: z.coerce.number().describe("Expected annualised return"),
+    volatility: z.coerce.number().positive().describe("Annualised volatility"),
+  })).describe("Asset descriptions"),
+  covariance_matrix: z.array(z.array(z.coerce.number())).describe("NxN covariance matrix (row-major)"),
+  linkage: z.enum(["Single", "Complete", "Average"]).optional().describe("Agglomerative linkage used to cluster the correlation distance matrix (defaults to Single)"),
+  target_volatility: z.coerce.number().positive().optional().describe("Optional target portfolio volatility for weight scaling"),
+  risk_free_rate: z.coerce.number().min(0).optional().describe("Risk-free rate for Sharpe computation (defaults to 0)"),
+});
+
 export const StressTestSchema = z.object({
   portfolio: z.array(z.object({
     name: z.string().describe("Position name"),
@@ -57,6 +69,8 @@ export const StressTestSchema = z.object({
       factor: z.string().describe("Risk factor name (equity_market, interest_rates, credit_spreads, fx_usd, commodities, volatility)"),
       shock_pct: z.coerce.number().describe("Shock magnitude as decimal (e.g. -0.40 for 40% decline)"),
     })).describe("Market risk factor shocks"),
-  })).describe("Scenarios to evaluate"),
+  })).optional().describe("Custom scenarios to evaluate"),
+  macro_scenarios: z.array(z.enum(["Gfc2008", "Covid2020", "Stagflation1970s", "RateShock300bp"])).optional().describe("Named scenarios from the macro library (GFC 2008, COVID 2020, 1970s stagflation, +300bp rate shock)"),
+  macro_severity: z.coerce.number().positive().optional().describe("Scale applied to library scenario shocks (default 1.0)"),
   correlation_adjustments: z.coerce.boolean().optional().describe("Multiply historical impacts by 1.2 for crisis correlation spikes (default true)"),
 });
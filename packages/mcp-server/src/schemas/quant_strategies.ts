@@ -26,3 +26,21 @@ export const MomentumSchema = z.object({
   top_n: z.coerce.number().int().positive().describe("Number of top momentum assets to hold"),
   risk_free_rate: z.coerce.number().describe("Annualized risk-free rate"),
 });
+
+const EventObservationSchema = z.object({
+  name: z.string().describe("Security or event identifier"),
+  event_index: z.coerce.number().int().describe("Index of the event date (day 0) within the return series"),
+  security_returns: z.array(z.coerce.number()).describe("Daily security returns"),
+  market_returns: z.array(z.coerce.number()).describe("Daily market (benchmark) returns, same length and dates"),
+});
+
+const ExpectedReturnModelSchema = z.enum(["MarketModel", "MarketAdjusted", "MeanAdjusted"]);
+
+export const EventStudySchema = z.object({
+  events: z.array(EventObservationSchema).describe("Events in the sample"),
+  estimation_start: z.coerce.number().int().describe("First day of the estimation window relative to day 0 (e.g. -250)"),
+  estimation_end: z.coerce.number().int().describe("Last day of the estimation window relative to day 0 (e.g. -11)"),
+  event_window_start: z.coerce.number().int().describe("First day of the event window relative to day 0 (e.g. -1)"),
+  event_window_end: z.coerce.number().int().describe("Last day of the event window relative to day 0 (e.g. +1)"),
+  model: ExpectedReturnModelSchema.describe("Normal-return model"),
+});
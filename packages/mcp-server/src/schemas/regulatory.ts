@@ -1,5 +1,45 @@
 import { z } from "zod";
 
+const CapitalAssetClassEnum = z.enum(["Sovereign", "Bank", "Corporate", "Retail", "Mortgage", "Equity", "Other"]);
+
+const SaccrTradeSchema = z.object({
+  id: z.string().describe("Trade identifier"),
+  asset_class: z.enum(["InterestRate", "ForeignExchange", "Credit", "Equity", "Commodity"]).describe("SA-CCR asset class of the primary risk driver"),
+  hedging_set: z.string().optional().describe("IR: currency; FX: currency pair; commodity: Energy/Metals/Agricultural/Other"),
+  reference: z.string().optional().describe("Credit/equity reference entity or index; commodity type"),
+  notional: z.coerce.number().min(0).describe("Notional in reporting currency (underlying value for equity/commodity)"),
+  market_value: z.coerce.number().describe("Current mark-to-market to the bank"),
+  long: z.boolean().describe("Long the primary risk factor (bought, for options)"),
+  maturity_years: z.coerce.number().min(0).describe("Remaining maturity in years"),
+  start_years: z.coerce.number().min(0).optional().describe("Start of underlying period (IR/credit)"),
+  end_years: z.coerce.number().min(0).optional().describe("End of underlying period (IR/credit)"),
+  is_index: z.boolean().optional().default(false).describe("Credit or equity index rather than single name"),
+  external_rating: z.string().optional().describe("Credit reference entity rating"),
+  option: z.object({
+    option_type: z.enum(["Call", "Put"]).describe("Option type"),
+    underlying_price: z.coerce.number().positive().describe("Underlying price"),
+    strike: z.coerce.number().positive().describe("Strike"),
+    exercise_years: z.coerce.number().min(0).describe("Years to latest exercise date"),
+  }).optional().describe("Option terms for the supervisory delta"),
+  supervisory_delta: z.coerce.number().optional().describe("Override supervisory delta"),
+});
+
+export const SaccrSchema = z.object({
+  netting_sets: z.array(z.object({
+    name: z.string().describe("Netting set name"),
+    counterparty_asset_class: CapitalAssetClassEnum.describe("Counterparty asset class for the risk weight"),
+    counterparty_rating: z.string().optional().describe("Counterparty external rating"),
+    trades: z.array(SaccrTradeSchema).min(1).describe("Derivative trades"),
+    margined: z.boolean().optional().default(false).describe("Subject to variation margin"),
+    net_collateral: z.coerce.number().optional().default(0).describe("Haircut net collateral held (negative if posted)"),
+    threshold: z.coerce.number().min(0).optional().default(0).describe("Margin threshold"),
+    minimum_transfer_amount: z.coerce.number().min(0).optional().default(0).describe("Minimum transfer amount"),
+    nica: z.coerce.number().optional().default(0).describe("Net independent collateral amount"),
+    margin_period_of_risk_days: z.coerce.number().int().min(10).optional().describe("Margin period of risk in business days"),
+  })).min(1).describe("Derivative netting sets"),
+  alpha: z.coerce.number().positive().optional().describe("EAD multiplier (default 1.4)"),
+});
+
 export const RegulatoryCapitalSchema = z.object({
   institution_name: z.string().describe("Institution name"),
   capital: z.object({
@@ -11,7 +51,7 @@ export const RegulatoryCapitalSchema = z.object({
   credit_exposures: z.array(z.object({
     name: z.string().describe("Exposure name"),
     exposure_amount: z.coerce.number().min(0).describe("Exposure at default (EAD)"),
-    asset_class: z.enum(["Sovereign", "Bank", "Corporate", "Retail", "Mortgage", "Equity", "Other"]).describe("Basel III asset class"),
+    asset_class: CapitalAssetClassEnum.describe("Basel III asset class"),
     risk_weight: z.coerce.number().min(0).max(1.5).optional().describe("Override risk weight"),
     external_rating: z.string().optional().describe("External credit rating"),
     collateral_value: z.coerce.number().min(0).optional().describe("Collateral value for CRM"),
@@ -31,6 +71,7 @@ export const RegulatoryCapitalSchema = z.object({
     countercyclical_buffer: z.coerce.number().min(0).describe("Countercyclical buffer"),
     systemic_buffer: z.coerce.number().min(0).describe("G-SIB systemic buffer"),
   }).optional().describe("Capital buffer requirements"),
+  counterparty_credit: SaccrSchema.optional().describe("Derivative netting sets whose SA-CCR EAD is added to credit exposures"),
 });
 
 const HqlaAssetSchema = z.object({
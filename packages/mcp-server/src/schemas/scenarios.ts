@@ -1,5 +1,6 @@
 import { z } from "zod";
 import { SensitivityVariableSchema } from "./common.js";
+import { CurveSchema, VolSurfaceSchema } from "./fixed_income.js";
 
 export const SensitivitySchema = z.object({
   base_inputs: z
@@ -48,3 +49,45 @@ export const ScenarioSchema = z.object({
     .number()
     .describe("Base case output value for deviation calculations"),
 });
+
+export const MarketScenarioSchema = z.object({
+  name: z.string().describe("Scenario name"),
+  rate_shift: z.coerce.number().optional().describe("Parallel shift to every rate curve (0.01 = +100bp)"),
+  rate_key_shifts: z
+    .array(
+      z.object({
+        tenor: z.coerce.number().min(0).describe("Tenor in years"),
+        shift: z.coerce.number().describe("Rate shift at this tenor"),
+      })
+    )
+    .optional()
+    .describe("Key-rate shifts added to rate_shift, sorted by tenor (linear between, flat outside)"),
+  spread_shift: z.coerce.number().optional().describe("Parallel widening of every spread curve"),
+  equity_shock: z.coerce.number().gt(-1).optional().describe("Relative equity move (-0.30 = 30% fall)"),
+  commodity_shock: z.coerce.number().gt(-1).optional().describe("Relative commodity move"),
+  fx_usd: z.coerce.number().gt(-1).optional().describe("USD move (positive = USD strengthens); non-USD pairs unchanged"),
+  vol_shift: z.coerce.number().optional().describe("Implied vol change in absolute points (0.05 = +5 vols)"),
+  overrides: z
+    .record(z.coerce.number())
+    .optional()
+    .describe("Shocks by item id replacing the class-wide shock (parallel shift for curves, vol points for surfaces, relative move for prices and FX)"),
+});
+
+export const MarketDataSetSchema = z.object({
+  rate_curves: z.record(CurveSchema).optional().describe("Discount and projection curves by id"),
+  spread_curves: z.record(CurveSchema).optional().describe("Credit spread curves by id"),
+  vol_surfaces: z.record(VolSurfaceSchema).optional().describe("Vol surfaces by id; spot follows an equity price named as the surface underlying"),
+  equity_prices: z.record(z.coerce.number().positive()).optional().describe("Equity and index prices by id"),
+  commodity_prices: z.record(z.coerce.number().positive()).optional().describe("Commodity prices by id"),
+  fx_rates: z.record(z.coerce.number().positive()).optional().describe("FX rates by pair, e.g. EURUSD (quote per base)"),
+});
+
+export const MarketShiftSchema = z.object({
+  market: MarketDataSetSchema.describe("Market data set to shift"),
+  scenarios: z.array(MarketScenarioSchema).optional().describe("Custom market scenarios"),
+  macro_scenarios: z
+    .array(z.enum(["Gfc2008", "Covid2020", "Stagflation1970s", "RateShock300bp"]))
+    .optional()
+    .describe("Named scenarios from the macro library"),
+  macro_severity: z.coerce.number().positive().optional().describe("Scale applied to library scenario shocks (default 1.0)"),
+});
@@ -1,4 +1,5 @@
 import { z } from "zod";
+import { DateAdjustmentSchema } from "./fixed_income.js";
 
 export const AbsMbsSchema = z.object({
   pool_balance: z.coerce.number().positive().describe("Initial pool balance (unpaid principal balance)"),
@@ -20,6 +21,45 @@ export const AbsMbsSchema = z.object({
   recovery_lag_months: z.coerce.number().int().min(0).describe("Months to recover from defaulted loans"),
   servicing_fee_rate: z.coerce.number().min(0).max(0.05).describe("Annual servicing fee rate (e.g. 0.0025 = 25bps)"),
   projection_months: z.coerce.number().int().positive().describe("Number of months to project"),
+  first_payment_date: z.string().optional().describe("Unadjusted distribution date of month 1 (YYYY-MM-DD)"),
+  payment_adjustment: DateAdjustmentSchema.optional().describe("Business day adjustment of distribution dates"),
+});
+
+export const CmbsSchema = z.object({
+  deal_name: z.string().describe("Deal name / identifier"),
+  loans: z
+    .array(
+      z.object({
+        name: z.string().describe("Loan name"),
+        balance: z.coerce.number().positive().describe("Current principal balance"),
+        note_rate: z.coerce.number().min(0).describe("Annual note rate (30/360)"),
+        io_months: z.coerce.number().int().min(0).optional().describe("Interest-only months before amortisation"),
+        amortization_months: z.coerce.number().int().positive().optional().describe("Amortisation term in months; omit for full-term IO"),
+        maturity_month: z.coerce.number().int().positive().describe("Month the balloon is due"),
+        noi: z.coerce.number().min(0).describe("Current annual net operating income"),
+        noi_growth: z.coerce.number().optional().describe("Annual NOI growth (e.g. -0.05)"),
+      })
+    )
+    .min(1)
+    .describe("Commercial mortgage loans in the pool"),
+  valuation_cap_rate: z.coerce.number().positive().describe("Cap rate for appraisals and liquidation values"),
+  refinance: z
+    .object({
+      max_ltv: z.coerce.number().positive().describe("Maximum balloon / property value"),
+      min_dscr: z.coerce.number().positive().describe("Minimum NOI / refinance debt service"),
+      rate: z.coerce.number().min(0).describe("Refinance loan rate"),
+      amortization_months: z.coerce.number().int().positive().describe("Refinance loan amortisation in months"),
+      exit_cap_rate: z.coerce.number().positive().optional().describe("Cap rate for the maturity valuation (defaults to valuation cap rate)"),
+    })
+    .describe("Market test a balloon must pass to refinance"),
+  term_default_dscr: z.coerce.number().positive().optional().describe("DSCR below which a loan defaults in term"),
+  appraisal_lag_months: z.coerce.number().int().min(0).describe("Months from default to the reappraisal that sets the ARA"),
+  workout_months: z.coerce.number().int().min(0).describe("Months from default to liquidation"),
+  liquidation_cost_pct: z.coerce.number().min(0).max(1).describe("Liquidation costs as a share of property value"),
+  servicing_fee_rate: z.coerce.number().min(0).describe("Annual master servicing fee"),
+  special_servicing_fee_rate: z.coerce.number().min(0).describe("Annual special servicing fee on specially serviced balances"),
+  advance_rate: z.coerce.number().min(0).describe("Annual interest on servicer advances"),
+  projection_months: z.coerce.number().int().positive().describe("Number of months to project"),
 });
 
 export const TranchingSchema = z.object({
@@ -43,4 +83,14 @@ export const TranchingSchema = z.object({
   oc_trigger: z.coerce.number().positive().optional().describe("Overcollateralisation trigger ratio (e.g. 1.20)"),
   ic_trigger: z.coerce.number().positive().optional().describe("Interest coverage trigger ratio (e.g. 1.05)"),
   reinvestment_period_months: z.coerce.number().int().min(0).describe("Months during which principal can be reinvested"),
+  waterfall_rules: z
+    .object({
+      principal_allocation: z.enum(["Sequential", "ProRata"]).optional().describe("Principal allocation while every trigger passes (default Sequential)"),
+      cumulative_loss_trigger: z.coerce.number().min(0).optional().describe("Cumulative losses / original collateral that switches to sequential pay (e.g. 0.05)"),
+      sticky_triggers: z.boolean().optional().describe("Stay sequential for the rest of the deal once a trigger breaches"),
+      turbo: z.boolean().optional().describe("Pay excess interest as senior principal even when tests pass"),
+      excess_spread_trap: z.coerce.number().optional().describe("Annualised excess spread below which excess interest is trapped in the reserve (e.g. 0.02)"),
+    })
+    .optional()
+    .describe("Pro-rata/sequential switching, loss trigger, turbo and excess-spread trapping"),
 });
@@ -1,4 +1,5 @@
 import { z } from "zod";
+import { VolSurfaceSchema } from "./fixed_income.js";
 
 export const StructuredNoteSchema = z.object({
   note_type: z.enum(["CapitalProtected", "YieldEnhancement", "Participation", "CreditLinked"]).describe("Note type"),
@@ -65,4 +66,5 @@ export const ExoticProductSchema = z.object({
   autocallable: AutocallableParamsSchema.optional().describe("Autocallable parameters"),
   barrier_option: BarrierOptionParamsSchema.optional().describe("Barrier option parameters"),
   digital_option: DigitalOptionParamsSchema.optional().describe("Digital option parameters"),
+  volatility_surface: VolSurfaceSchema.optional().describe("Implied vol surface; overrides the params volatility at the product's expiry and strike"),
 });
@@ -1,4 +1,5 @@
 import { z } from "zod";
+import { DcfSchema } from "./valuation.js";
 
 export const ThreeStatementSchema = z.object({
   base_revenue: z.coerce.number().positive().describe("Base year revenue"),
@@ -23,4 +24,75 @@ export const ThreeStatementSchema = z.object({
   debt_repayment_pct: z.coerce.number().min(0).max(1).describe("Annual debt repayment as % of beginning debt"),
   dividend_payout_ratio: z.coerce.number().min(0).max(1).describe("Dividends as % of net income"),
   min_cash_balance: z.coerce.number().min(0).describe("Minimum cash to maintain"),
+  drivers: z.object({
+    cogs_pct: z.array(z.coerce.number().min(0).max(1)).optional().describe("COGS % of revenue per year"),
+    sga_pct: z.array(z.coerce.number().min(0).max(1)).optional().describe("SG&A % of revenue per year"),
+    rnd_pct: z.array(z.coerce.number().min(0).max(1)).optional().describe("R&D % of revenue per year"),
+    da_pct: z.array(z.coerce.number().min(0).max(1)).optional().describe("D&A % of prior PP&E per year"),
+    capex_pct: z.array(z.coerce.number().min(0).max(1)).optional().describe("Capex % of revenue per year"),
+    dso_days: z.array(z.coerce.number().min(0)).optional().describe("Days sales outstanding per year"),
+    dio_days: z.array(z.coerce.number().min(0)).optional().describe("Days inventory outstanding per year"),
+    dpo_days: z.array(z.coerce.number().min(0)).optional().describe("Days payable outstanding per year"),
+    interest_rate: z.array(z.coerce.number().min(0).max(1)).optional().describe("Interest rate on average debt per year"),
+    tax_rate: z.array(z.coerce.number().min(0).max(1)).optional().describe("Tax rate per year"),
+    dividend_payout_ratio: z.array(z.coerce.number().min(0).max(1)).optional().describe("Dividend payout ratio per year"),
+  }).optional().describe("Per-year driver schedules (one entry per projection year) overriding the constant assumptions"),
+});
+
+export const ModelToDcfSchema = z.object({
+  model: ThreeStatementSchema.describe("Three-statement model whose unlevered FCF is valued"),
+  valuation: DcfSchema.pick({
+    wacc: true,
+    wacc_input: true,
+    terminal_method: true,
+    terminal_growth_rate: true,
+    terminal_exit_multiple: true,
+    terminal_ronic: true,
+    currency: true,
+    mid_year_convention: true,
+    minority_interest: true,
+    shares_outstanding: true,
+  })
+    .extend({
+      net_debt: z
+        .number()
+        .optional()
+        .describe("Net debt for the equity bridge (default: base debt less base cash)"),
+    })
+    .describe("Discounting, terminal value and equity bridge settings"),
+});
+
+const FinancialPeriodSchema = z.object({
+  label: z.string().describe("Label, e.g. \"FY2024\" or \"9M2025\""),
+  period_end: z.string().describe("Last day of the period (YYYY-MM-DD)"),
+  months: z.coerce.number().int().describe("Number of months the period covers (1-12)"),
+  metrics: z.record(z.coerce.number()).describe("Reported values keyed by metric name (e.g. \"revenue\", \"ebitda\")"),
+});
+
+export const LtmSchema = z.object({
+  latest_annual: FinancialPeriodSchema.describe("Most recent full fiscal year"),
+  current_interim: FinancialPeriodSchema.describe("Year-to-date period of the current fiscal year"),
+  prior_interim: FinancialPeriodSchema.describe("Comparable year-to-date period of the prior fiscal year"),
+  point_in_time_metrics: z.array(z.string()).optional().describe("Balance-sheet style metrics taken from the latest interim rather than summed (e.g. \"net_debt\", \"total_assets\")"),
+});
+
+const CompanyPeriodsSchema = z.object({
+  name: z.string().describe("Company name"),
+  periods: z.array(FinancialPeriodSchema).describe("Non-overlapping periods (any order)"),
+});
+
+export const CalendarizeSchema = z.object({
+  companies: z.array(CompanyPeriodsSchema).describe("Companies to calendarize"),
+  target_period_end: z.string().describe("End of the target twelve-month window (e.g. 2024-12-31)"),
+  point_in_time_metrics: z.array(z.string()).optional().describe("Metrics taken from the latest period ending on or before the target end rather than time-weighted"),
+});
+
+const AnnualizationMethodSchema = z.enum(["RunRate", "Seasonal"]);
+
+export const AnnualizeSchema = z.object({
+  period: FinancialPeriodSchema.describe("The partial period to annualize"),
+  method: AnnualizationMethodSchema.describe("RunRate scales by 12 / months; Seasonal scales by the prior full-year / comparable-period ratio"),
+  prior_comparable_period: FinancialPeriodSchema.optional().describe("Same period one year earlier (required for Seasonal)"),
+  prior_fiscal_year: FinancialPeriodSchema.optional().describe("Full prior fiscal year (required for Seasonal)"),
+  point_in_time_metrics: z.array(z.string()).optional().describe("Metrics passed through unchanged"),
 });
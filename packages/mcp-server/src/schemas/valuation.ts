@@ -1,5 +1,5 @@
 import { z } from "zod";
-import { CurrencySchema } from "./common.js";
+import { CurrencySchema, DilutiveSecuritySchema, FxRateSchema } from "./common.js";
 
 export const WaccSchema = z.object({
   risk_free_rate: z
@@ -69,6 +69,21 @@ const CompanyMetricsSchema = z.object({
     .optional()
     .describe("Expected EPS growth rate (for PEG ratio)"),
   share_price: z.coerce.number().optional().describe("Share price"),
+  basic_shares: z.coerce
+    .number()
+    .positive()
+    .optional()
+    .describe("Basic shares outstanding; with share_price gives a diluted market cap"),
+  dilutive_securities: z
+    .array(DilutiveSecuritySchema)
+    .optional()
+    .describe("Options, warrants, RSUs and convertibles (treasury stock method)"),
+  kpis: z
+    .record(z.coerce.number())
+    .optional()
+    .describe(
+      "Sector KPIs and operating drivers keyed by name (e.g. arr, subscribers, beds, arr_growth, net_churn)"
+    ),
 });
 
 export const DcfSchema = z.object({
@@ -117,7 +132,7 @@ export const DcfSchema = z.object({
     "If provided, WACC is computed from these inputs (overrides wacc field)"
   ),
   terminal_method: z
-    .enum(["GordonGrowth", "ExitMultiple", "Both"])
+    .enum(["GordonGrowth", "ExitMultiple", "Both", "ValueDriver", "Convergence"])
     .describe("Terminal value calculation methodology"),
   terminal_growth_rate: z
     .number()
@@ -131,6 +146,11 @@ export const DcfSchema = z.object({
     .max(50)
     .optional()
     .describe("Exit EV/EBITDA multiple for terminal value"),
+  terminal_ronic: z
+    .number()
+    .positive()
+    .optional()
+    .describe("Return on new invested capital in the terminal period (required for ValueDriver)"),
   currency: CurrencySchema.describe("Reporting currency"),
   forecast_years: z
     .number()
@@ -156,6 +176,13 @@ export const DcfSchema = z.object({
     .positive()
     .optional()
     .describe("Diluted shares outstanding for per-share value"),
+  presentation_currency: CurrencySchema.optional().describe(
+    "Currency to translate headline values into"
+  ),
+  fx_rates: z
+    .array(FxRateSchema)
+    .optional()
+    .describe("Spot FX rates for the presentation translation"),
 });
 
 export const CompsSchema = z.object({
@@ -188,7 +215,319 @@ export const CompsSchema = z.object({
         "Peg",
       ])
     )
-    .min(1)
-    .describe("Valuation multiples to compute"),
+    .describe(
+      "Valuation multiples to compute (may be empty if kpi_multiples are given)"
+    ),
   currency: CurrencySchema.describe("Reporting currency"),
+  kpi_multiples: z
+    .array(
+      z.object({
+        name: z.string().describe("Display name, e.g. EV/ARR"),
+        kpi: z.string().describe("Key of the KPI in metrics.kpis"),
+        numerator: z
+          .enum(["EnterpriseValue", "MarketCap"])
+          .describe("Value measure placed over the KPI"),
+      })
+    )
+    .optional()
+    .describe(
+      "Sector KPI multiples (EV/ARR, EV/subscriber, value per bed, per ton of capacity)"
+    ),
+  kpi_regression: z
+    .object({
+      multiple: z.string().describe("Name of a multiple in kpi_multiples"),
+      drivers: z
+        .array(z.string())
+        .min(1)
+        .describe(
+          "KPI keys used as explanatory variables (e.g. arr_growth, net_churn)"
+        ),
+    })
+    .optional()
+    .describe("Regress a KPI multiple on operating drivers across the comp set"),
+});
+
+export const SmallBusinessSchema = z.object({
+  business_name: z.string().describe("Subject business"),
+  industry: z.string().optional().describe("Industry label, matched case-insensitively against multiple_bands"),
+  periods: z.array(z.object({
+    label: z.string().describe("Fiscal year label"),
+    revenue: z.coerce.number().min(0).describe("Revenue"),
+    pre_tax_income: z.coerce.number().describe("Reported pre-tax income"),
+    owner_compensation: z.coerce.number().optional().describe("Salary, bonus and payroll taxes of one working owner"),
+    owner_benefits: z.coerce.number().optional().describe("Owner perquisites run through the business"),
+    interest_expense: z.coerce.number().optional().describe("Interest expense"),
+    depreciation_amortization: z.coerce.number().optional().describe("Depreciation and amortization"),
+    adjustments: z.array(z.object({
+      description: z.string(),
+      amount: z.coerce.number().describe("Positive to add back, negative to deduct"),
+    })).optional().describe("Non-recurring, non-operating or discretionary items"),
+    weight: z.coerce.number().min(0).optional().describe("Weight in the normalized average (default 1, 2, 3... oldest to latest)"),
+  })).min(1).describe("Historical years, oldest first"),
+  replacement_compensation: z.coerce.number().min(0).describe("Market wage and payroll taxes for a manager replacing the owner; SDE less this is normalized EBITDA"),
+  build_up: z.object({
+    risk_free_rate: z.coerce.number().describe("Risk-free rate as decimal"),
+    equity_risk_premium: z.coerce.number().describe("Equity risk premium as decimal"),
+    size_premium: z.coerce.number().describe("Small company size premium as decimal"),
+    industry_risk_premium: z.coerce.number().optional().describe("Industry risk premium as decimal"),
+    company_specific_risk_premium: z.coerce.number().optional().describe("Company-specific risk premium as decimal"),
+    long_term_growth: z.coerce.number().describe("Long-term growth of normalized cash flow as decimal"),
+  }).describe("Build-up discount rate; capitalization rate = discount rate - growth"),
+  tax_rate: z.coerce.number().min(0).max(1).optional().describe("Entity-level tax rate (0 for pass-through entities valued pre-tax)"),
+  maintenance_capex: z.coerce.number().min(0).optional().describe("Sustaining capex (default latest year's depreciation)"),
+  multiple_bands: z.array(z.object({
+    industry: z.string().optional().describe("Industry; omit for all industries"),
+    basis: z.enum(["Sde", "Ebitda"]).describe("Earnings measure the multiple applies to"),
+    min_earnings: z.coerce.number().min(0).describe("Band floor on that earnings measure"),
+    max_earnings: z.coerce.number().optional().describe("Band ceiling (exclusive); omit for the top band"),
+    low: z.coerce.number().min(0),
+    mid: z.coerce.number().min(0),
+    high: z.coerce.number().min(0),
+  })).optional().describe("Market multiple ranges by industry and size; generic size bands are used when omitted"),
+  market_weight: z.coerce.number().min(0).max(1).optional().describe("Weight on the market approach in the conclusion (default 0.5)"),
+  interest_bearing_debt: z.coerce.number().min(0).optional().describe("Debt deducted to reach equity value"),
+  excess_cash: z.coerce.number().min(0).optional().describe("Cash above normal working capital, added to equity value"),
+});
+
+const ScreenCompanySchema = z.object({
+  name: z.string().describe("Company name or ticker"),
+  sector: z.string().describe("Sector classification"),
+  sub_industry: z.string().optional().describe("Optional finer industry classification"),
+  region: z.string().optional().describe("Optional region / listing market"),
+  revenue: z.coerce.number().describe("LTM or forward revenue (size measure)"),
+  revenue_growth: z.coerce.number().describe("Revenue growth rate"),
+  ebitda_margin: z.coerce.number().describe("EBITDA margin"),
+  metrics: CompanyMetricsSchema.describe("Valuation metrics passed through to the comps module"),
+});
+
+const ScreeningCriteriaSchema = z.object({
+  sectors: z.array(z.string()).optional().describe("Allowed sectors (empty = any)"),
+  regions: z.array(z.string()).optional().describe("Allowed regions (empty = any)"),
+  require_same_sector: z.boolean().optional().describe("Require the candidate to share the target's sector"),
+  min_revenue: z.coerce.number().optional(),
+  max_revenue: z.coerce.number().optional(),
+  size_band: z.coerce.number().optional().describe("Maximum revenue ratio either side of the target (e.g. 4 = 0.25x-4x)"),
+  min_growth: z.coerce.number().optional(),
+  max_growth: z.coerce.number().optional(),
+  min_margin: z.coerce.number().optional(),
+  max_margin: z.coerce.number().optional(),
+  min_similarity: z.coerce.number().optional().describe("Minimum overall similarity score to be included"),
+  max_results: z.coerce.number().int().optional().describe("Maximum number of peers returned in the comp set"),
+});
+
+const SimilarityWeightsSchema = z.object({
+  size: z.coerce.number().describe("Weight on revenue size similarity"),
+  growth: z.coerce.number().describe("Weight on growth similarity"),
+  margin: z.coerce.number().describe("Weight on margin similarity"),
+  sector: z.coerce.number().describe("Weight on sector match"),
+});
+
+export const PeerScreenSchema = z.object({
+  target: ScreenCompanySchema.describe("The company peers are being screened for"),
+  universe: z.array(ScreenCompanySchema).describe("Candidate universe"),
+  criteria: ScreeningCriteriaSchema.optional().describe("Hard filters"),
+  weights: SimilarityWeightsSchema.optional().describe("Similarity weights (defaults: size 30%, growth 25%, margin 25%, sector 20%)"),
+});
+
+const AssetCategorySchema = z.enum(["Cash", "Receivables", "Inventory", "PrepaidAndOther", "Investments", "PropertyPlantEquipment", "RealEstate", "Intangibles", "Goodwill", "Other"]);
+
+const AssetItemSchema = z.object({
+  name: z.string().describe("Asset description"),
+  category: AssetCategorySchema.describe("Asset category driving the default haircuts"),
+  book_value: z.coerce.number().describe("Carrying value"),
+  fair_value: z.coerce.number().optional().describe("Appraised or marked fair value (overrides the category haircut)"),
+});
+
+const LiabilityItemSchema = z.object({
+  name: z.string().describe("Liability description"),
+  book_value: z.coerce.number().describe("Carrying value"),
+  fair_value: z.coerce.number().optional().describe("Fair value for NAV (e.g. debt trading below par); defaults to book"),
+  liquidation_claim: z.coerce.number().optional().describe("Claim crystallising in liquidation (e.g. make-wholes, lease rejection, severance); defaults to book"),
+});
+
+const HaircutScheduleSchema = z.object({
+  category: AssetCategorySchema.describe("Asset category the haircuts apply to"),
+  fair_value_haircut: z.coerce.number().describe("Haircut to book for adjusted NAV"),
+  orderly_haircut: z.coerce.number().describe("Haircut to book in an orderly liquidation"),
+  forced_haircut: z.coerce.number().describe("Haircut to book in a forced liquidation"),
+});
+
+const LiquidationAssumptionsSchema = z.object({
+  months_to_realise: z.coerce.number().int().describe("Months to realise the assets"),
+  disposal_cost_pct: z.coerce.number().describe("Disposal costs (brokers, auctioneers, professional fees) as % of gross proceeds"),
+  wind_down_costs: z.coerce.number().describe("Fixed wind-down costs (payroll, rent, trustee) over the period"),
+});
+
+export const AssetBasedValuationSchema = z.object({
+  company_name: z.string().describe("Company name"),
+  assets: z.array(AssetItemSchema).describe("Balance sheet assets"),
+  liabilities: z.array(LiabilityItemSchema).describe("Balance sheet liabilities"),
+  haircut_schedule: z.array(HaircutScheduleSchema).optional().describe("Category haircut overrides (categories not listed use the defaults)"),
+  orderly: LiquidationAssumptionsSchema.describe("Orderly liquidation assumptions"),
+  forced: LiquidationAssumptionsSchema.describe("Forced liquidation assumptions"),
+  discount_rate: z.coerce.number().describe("Annual rate used to discount liquidation proceeds to today"),
+  shares_outstanding: z.coerce.number().optional().describe("Shares outstanding for per-share values"),
+  going_concern_equity_value: z.coerce.number().optional().describe("Equity value from an income or market approach, for comparison"),
+});
+
+const BetaPeerSchema = z.object({
+  name: z.string().describe("Peer name"),
+  levered_beta: z.coerce.number().describe("Observed levered equity beta"),
+  debt_to_equity: z.coerce.number().describe("Market debt / market equity"),
+  tax_rate: z.coerce.number().describe("Peer marginal tax rate"),
+});
+
+const LeverageFormulaSchema = z.enum(["Hamada", "Practitioners", "HarrisPringle"]);
+
+const BetaAggregationSchema = z.enum(["Median", "Mean"]);
+
+// --- SizePremium ---
+// Rust enum (externally tagged serde default):
+//   Fixed { premium }              -> { "Fixed": { "premium": r } }
+//   Schedule { market_cap, bands } -> { "Schedule": { "market_cap": m, "bands": [...] } }
+const SizePremiumSchema = z.union([
+  z.object({
+    Fixed: z.object({
+      premium: z.coerce.number().describe("Size premium"),
+    }),
+  }),
+  z.object({
+    Schedule: z.object({
+      market_cap: z.coerce.number().describe("Subject market capitalisation"),
+      bands: z.array(z.object({
+        min_market_cap: z.coerce.number().describe("Smallest market cap in the band"),
+        premium: z.coerce.number().describe("Size premium for the band"),
+      })).describe("Size premium schedule"),
+    }),
+  }),
+]);
+
+// --- CountryRiskMethod ---
+// Rust enum (externally tagged serde default):
+//   SovereignSpread { spread_bps }                                        -> CRP = default spread
+//   RelativeVolatility { spread_bps, equity_volatility, bond_volatility } -> CRP = spread x equity vol / bond vol
+//   RelativeEquityVolatility { local_equity_volatility, mature_equity_volatility }
+const CountryRiskMethodSchema = z.union([
+  z.object({
+    SovereignSpread: z.object({
+      spread_bps: z.coerce.number().describe("Sovereign default spread in basis points"),
+    }),
+  }),
+  z.object({
+    RelativeVolatility: z.object({
+      spread_bps: z.coerce.number().describe("Sovereign default spread in basis points"),
+      equity_volatility: z.coerce.number().describe("Local equity market volatility"),
+      bond_volatility: z.coerce.number().describe("Local sovereign bond volatility"),
+    }),
+  }),
+  z.object({
+    RelativeEquityVolatility: z.object({
+      local_equity_volatility: z.coerce.number().describe("Local equity market volatility"),
+      mature_equity_volatility: z.coerce.number().describe("Mature equity market volatility"),
+    }),
+  }),
+]);
+
+const CountryRiskSpecSchema = z.object({
+  method: CountryRiskMethodSchema.describe("Country risk premium estimation method"),
+  lambda: z.coerce.number().optional().describe("Exposure to country risk (lambda); 1 = fully exposed"),
+});
+
+export const CostOfEquitySchema = z.object({
+  subject_name: z.string().describe("Subject company name"),
+  risk_free_rate: z.coerce.number().describe("Risk-free rate"),
+  equity_risk_premium: z.coerce.number().describe("Mature-market equity risk premium"),
+  peers: z.array(BetaPeerSchema).describe("Peers used to estimate the unlevered industry beta"),
+  leverage_formula: LeverageFormulaSchema.optional().describe("Unlever/relever formula (default Hamada)"),
+  beta_aggregation: BetaAggregationSchema.optional().describe("Peer beta aggregation (default Median)"),
+  debt_beta: z.coerce.number().optional().describe("Debt beta for Harris-Pringle (default 0)"),
+  target_debt_to_equity: z.coerce.number().describe("Subject's target debt / equity for relevering"),
+  target_tax_rate: z.coerce.number().describe("Subject marginal tax rate"),
+  size_premium: SizePremiumSchema.optional().describe("Size premium, fixed or from a market-cap schedule"),
+  country_risk: CountryRiskSpecSchema.optional().describe("Country risk premium and exposure"),
+  specific_risk_premium: z.coerce.number().optional().describe("Company-specific risk premium"),
+});
+
+const ValueBasisSchema = z.enum(["MarketableMinority", "Control"]);
+
+const DlomMethodSchema = z.enum(["ProtectivePut", "Finnerty", "Average"]);
+
+const DlomSpecSchema = z.object({
+  volatility: z.coerce.number().describe("Annualised equity volatility of the subject (or guideline companies)"),
+  holding_period_years: z.coerce.number().describe("Expected holding period until a liquidity event"),
+  risk_free_rate: z.coerce.number().describe("Risk-free rate over the holding period"),
+  dividend_yield: z.coerce.number().optional().describe("Dividend yield of the subject (default 0)"),
+  method: DlomMethodSchema.optional().describe("DLOM model: ProtectivePut (Chaffe), Finnerty average-strike put, or the Average of both"),
+  override_discount: z.coerce.number().optional().describe("Apply this discount instead of the model estimate"),
+});
+
+export const ValuationAdjustmentsSchema = z.object({
+  subject_name: z.string().describe("Subject company name"),
+  enterprise_value: z.coerce.number().describe("Enterprise value at the stated level of value"),
+  net_debt: z.coerce.number().describe("Debt less cash"),
+  other_claims: z.coerce.number().optional().describe("Preferred equity, minority interests and other senior claims"),
+  non_operating_assets: z.coerce.number().optional().describe("Non-operating assets added back to equity"),
+  value_basis: ValueBasisSchema.describe("Level of value of `enterprise_value`"),
+  interest_pct: z.coerce.number().describe("Fraction of equity being valued (1 = 100%)"),
+  is_controlling_interest: z.boolean().describe("Whether the subject interest carries control"),
+  control_premium: z.coerce.number().optional().describe("Control premium applied when moving from minority to control"),
+  minority_discount: z.coerce.number().optional().describe("Minority (lack of control) discount; derived from the control premium as 1 - 1 / (1 + premium) when not given"),
+  dlom: DlomSpecSchema.optional().describe("Discount for lack of marketability; omitted for marketable interests"),
+});
+
+export const EconomicProfitSchema = z.object({
+  dcf: DcfSchema.describe("Operating forecast, discount rate and terminal assumptions (same inputs as dcf_model)"),
+  opening_invested_capital: z.coerce.number().positive().describe("Invested capital at the valuation date (operating working capital, net PP&E and operating intangibles)"),
+});
+
+const FinancingSideEffectSchema = z.object({
+  name: z.string().describe("Side effect name, e.g. subsidised loan or issuance fees"),
+  upfront: z.coerce.number().optional().describe("Amount at the valuation date (negative for costs such as issuance fees)"),
+  annual: z.array(z.coerce.number()).optional().describe("Annual amounts from year 1 (positive = benefit to the firm)"),
+  discount_rate: z.coerce.number().optional().describe("Discount rate for the annual amounts (default: cost of debt)"),
+});
+
+export const ApvSchema = z.object({
+  unlevered_fcf: z.array(z.coerce.number()).min(1).describe("Unlevered free cash flow per forecast year"),
+  unlevered_cost_of_equity: z.coerce.number().describe("Unlevered cost of equity (asset return)"),
+  terminal_growth_rate: z.coerce.number().describe("Perpetual growth of unlevered FCF after the forecast"),
+  tax_rate: z.coerce.number().min(0).max(1).describe("Marginal tax rate for interest tax shields"),
+  cost_of_debt: z.coerce.number().describe("Pre-tax cost of debt"),
+  debt_balances: z.array(z.coerce.number()).optional().describe("Opening debt balance per forecast year"),
+  interest_expense: z.array(z.coerce.number()).optional().describe("Interest expense per year; defaults to opening debt x cost of debt"),
+  ebit: z.array(z.coerce.number()).optional().describe("EBIT per year; when given, shields are limited to taxable income"),
+  terminal_debt: z.coerce.number().optional().describe("Debt carried into perpetuity, growing at the terminal growth rate"),
+  tax_shield_discounting: z
+    .enum(["CostOfDebt", "UnleveredCostOfEquity"])
+    .optional()
+    .describe("CostOfDebt (Myers, fixed debt schedule) or UnleveredCostOfEquity (Harris-Pringle, debt rebalanced to value)"),
+  distress_probability: z.coerce.number().min(0).max(1).optional().describe("Probability of financial distress over the life of the capital structure"),
+  distress_cost_pct: z.coerce.number().min(0).optional().describe("Cost of distress as a share of unlevered firm value"),
+  side_effects: z.array(FinancingSideEffectSchema).optional().describe("Other financing side effects valued separately"),
+  currency: CurrencySchema.optional(),
+  net_debt: z.coerce.number().optional().describe("Net debt deducted to reach equity value"),
+  shares_outstanding: z.coerce.number().positive().optional().describe("Diluted shares for per-share value"),
+});
+
+const RateBasisSchema = z
+  .enum(["Nominal", "Real"])
+  .describe("Whether the rate or cash flows include expected inflation");
+
+export const RateConsistencySchema = z.object({
+  cash_flows: z.object({
+    currency: CurrencySchema.describe("Currency of the forecast cash flows"),
+    basis: RateBasisSchema.optional(),
+    embedded_inflation: z.coerce.number().optional().describe("Inflation built into a nominal forecast (price escalators, wage growth)"),
+    terminal_growth_rate: z.coerce.number().optional().describe("Perpetual growth rate, on the same basis as the cash flows"),
+  }).describe("Description of the cash flows being discounted"),
+  discount_rate: z.object({
+    rate: z.coerce.number().describe("Discount rate as estimated"),
+    currency: CurrencySchema.describe("Currency the discount rate was estimated in"),
+    basis: RateBasisSchema.optional(),
+  }).describe("Description of the discount rate as estimated"),
+  inflation: z.array(z.object({
+    currency: CurrencySchema,
+    expected_inflation: z.coerce.number().describe("Expected annual inflation"),
+  })).min(1).describe("Expected inflation for each currency involved"),
+  tolerance: z.coerce.number().min(0).optional().describe("Gap between embedded and assumed inflation that is flagged (default 0.005)"),
 });
@@ -16,7 +16,7 @@ export function registerAmlComplianceTools(server: McpServer) {
     KycRiskSchema.shape,
     async (params) => {
       const validated = KycRiskSchema.parse(coerceNumbers(params));
-      const result = assessKycRisk(JSON.stringify(validated));
+      const result = assessKycRisk(validated);
       return wrapResponse(result);
     }
   );
@@ -27,7 +27,7 @@ export function registerAmlComplianceTools(server: McpServer) {
     SanctionsScreeningSchema.shape,
     async (params) => {
       const validated = SanctionsScreeningSchema.parse(coerceNumbers(params));
-      const result = screenSanctions(JSON.stringify(validated));
+      const result = screenSanctions(validated);
       return wrapResponse(result);
     }
   );
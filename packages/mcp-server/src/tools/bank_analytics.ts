@@ -22,7 +22,7 @@ export function registerBankAnalyticsTools(server: McpServer) {
     NimAnalysisSchema.shape,
     async (params) => {
       const validated = NimAnalysisSchema.parse(coerceNumbers(params));
-      const result = analyzeNim(JSON.stringify(validated));
+      const result = analyzeNim(validated);
       return wrapResponse(result);
     }
   );
@@ -33,7 +33,7 @@ export function registerBankAnalyticsTools(server: McpServer) {
     CamelsRatingSchema.shape,
     async (params) => {
       const validated = CamelsRatingSchema.parse(coerceNumbers(params));
-      const result = calculateCamelsRating(JSON.stringify(validated));
+      const result = calculateCamelsRating(validated);
       return wrapResponse(result);
     }
   );
@@ -44,7 +44,7 @@ export function registerBankAnalyticsTools(server: McpServer) {
     CeclProvisioningSchema.shape,
     async (params) => {
       const validated = CeclProvisioningSchema.parse(coerceNumbers(params));
-      const result = calculateCeclProvision(JSON.stringify(validated));
+      const result = calculateCeclProvision(validated);
       return wrapResponse(result);
     }
   );
@@ -55,7 +55,7 @@ export function registerBankAnalyticsTools(server: McpServer) {
     DepositBetaSchema.shape,
     async (params) => {
       const validated = DepositBetaSchema.parse(coerceNumbers(params));
-      const result = analyzeDepositBeta(JSON.stringify(validated));
+      const result = analyzeDepositBeta(validated);
       return wrapResponse(result);
     }
   );
@@ -66,7 +66,7 @@ export function registerBankAnalyticsTools(server: McpServer) {
     LoanBookAnalysisSchema.shape,
     async (params) => {
       const validated = LoanBookAnalysisSchema.parse(coerceNumbers(params));
-      const result = analyzeLoanBook(JSON.stringify(validated));
+      const result = analyzeLoanBook(validated);
       return wrapResponse(result);
     }
   );
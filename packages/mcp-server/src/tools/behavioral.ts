@@ -16,7 +16,7 @@ export function registerBehavioralTools(server: McpServer) {
     ProspectTheorySchema.shape,
     async (params) => {
       const validated = ProspectTheorySchema.parse(coerceNumbers(params));
-      const result = analyzeProspectTheory(JSON.stringify(validated));
+      const result = analyzeProspectTheory(validated);
       return wrapResponse(result);
     }
   );
@@ -27,7 +27,7 @@ export function registerBehavioralTools(server: McpServer) {
     SentimentSchema.shape,
     async (params) => {
       const validated = SentimentSchema.parse(coerceNumbers(params));
-      const result = analyzeSentiment(JSON.stringify(validated));
+      const result = analyzeSentiment(validated);
       return wrapResponse(result);
     }
   );
@@ -22,7 +22,7 @@ export function registerCapitalAllocationTools(server: McpServer) {
     EconomicCapitalSchema.shape,
     async (params) => {
       const validated = EconomicCapitalSchema.parse(coerceNumbers(params));
-      const result = calculateEconomicCapital(JSON.stringify(validated));
+      const result = calculateEconomicCapital(validated);
       return wrapResponse(result);
     }
   );
@@ -33,7 +33,7 @@ export function registerCapitalAllocationTools(server: McpServer) {
     RarocSchema.shape,
     async (params) => {
       const validated = RarocSchema.parse(coerceNumbers(params));
-      const result = calculateRaroc(JSON.stringify(validated));
+      const result = calculateRaroc(validated);
       return wrapResponse(result);
     }
   );
@@ -44,7 +44,7 @@ export function registerCapitalAllocationTools(server: McpServer) {
     EulerAllocationSchema.shape,
     async (params) => {
       const validated = EulerAllocationSchema.parse(coerceNumbers(params));
-      const result = calculateEulerAllocation(JSON.stringify(validated));
+      const result = calculateEulerAllocation(validated);
       return wrapResponse(result);
     }
   );
@@ -55,7 +55,7 @@ export function registerCapitalAllocationTools(server: McpServer) {
     ShapleyAllocationSchema.shape,
     async (params) => {
       const validated = ShapleyAllocationSchema.parse(coerceNumbers(params));
-      const result = calculateShapleyAllocation(JSON.stringify(validated));
+      const result = calculateShapleyAllocation(validated);
       return wrapResponse(result);
     }
   );
@@ -66,7 +66,7 @@ export function registerCapitalAllocationTools(server: McpServer) {
     LimitManagementSchema.shape,
     async (params) => {
       const validated = LimitManagementSchema.parse(coerceNumbers(params));
-      const result = evaluateLimits(JSON.stringify(validated));
+      const result = evaluateLimits(validated);
       return wrapResponse(result);
     }
   );
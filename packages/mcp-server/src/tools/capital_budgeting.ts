@@ -0,0 +1,17 @@
+import { McpServer } from "@modelcontextprotocol/sdk/server/mcp.js";
+import { selectProjects } from "../bindings.js";
+import { ProjectSelectionSchema } from "../schemas/capital_budgeting.js";
+import { wrapResponse, coerceNumbers } from "../formatters/response.js";
+
+export function registerCapitalBudgetingTools(server: McpServer) {
+  server.tool(
+    "capital_project_selection",
+    "Select the capital project portfolio with the highest total NPV within multi-year capex budgets. Handles capex phasing by year, mandatory projects, dependencies (a project requires others) and mutually exclusive groups. Solves exactly by branch and bound for up to 20 candidates, otherwise by greedy ranking on NPV per unit of scarce budget with add and swap local search. Returns selected and rejected projects with reasons, budget use and slack by year, and the shadow value of relaxing each year's budget (and all years together) from re-solving with extra budget.",
+    ProjectSelectionSchema.shape,
+    async (params) => {
+      const validated = ProjectSelectionSchema.parse(coerceNumbers(params));
+      const result = selectProjects(validated);
+      return wrapResponse(result);
+    }
+  );
+}
@@ -22,7 +22,7 @@ export function registerCarbonMarketsTools(server: McpServer) {
     CarbonCreditPricingSchema.shape,
     async (params) => {
       const validated = CarbonCreditPricingSchema.parse(coerceNumbers(params));
-      const result = priceCarbonCredit(JSON.stringify(validated));
+      const result = priceCarbonCredit(validated);
       return wrapResponse(result);
     }
   );
@@ -33,7 +33,7 @@ export function registerCarbonMarketsTools(server: McpServer) {
     EtsComplianceSchema.shape,
     async (params) => {
       const validated = EtsComplianceSchema.parse(coerceNumbers(params));
-      const result = analyzeEtsCompliance(JSON.stringify(validated));
+      const result = analyzeEtsCompliance(validated);
       return wrapResponse(result);
     }
   );
@@ -44,7 +44,7 @@ export function registerCarbonMarketsTools(server: McpServer) {
     CbamAnalysisSchema.shape,
     async (params) => {
       const validated = CbamAnalysisSchema.parse(coerceNumbers(params));
-      const result = analyzeCbam(JSON.stringify(validated));
+      const result = analyzeCbam(validated);
       return wrapResponse(result);
     }
   );
@@ -55,7 +55,7 @@ export function registerCarbonMarketsTools(server: McpServer) {
     OffsetValuationSchema.shape,
     async (params) => {
       const validated = OffsetValuationSchema.parse(coerceNumbers(params));
-      const result = valueCarbonOffset(JSON.stringify(validated));
+      const result = valueCarbonOffset(validated);
       return wrapResponse(result);
     }
   );
@@ -66,7 +66,7 @@ export function registerCarbonMarketsTools(server: McpServer) {
     ShadowCarbonPriceSchema.shape,
     async (params) => {
       const validated = ShadowCarbonPriceSchema.parse(coerceNumbers(params));
-      const result = calculateShadowCarbonPrice(JSON.stringify(validated));
+      const result = calculateShadowCarbonPrice(validated);
       return wrapResponse(result);
     }
   );
@@ -0,0 +1,17 @@
+import { McpServer } from "@modelcontextprotocol/sdk/server/mcp.js";
+import { buildCashflowLadder } from "../bindings.js";
+import { CashFlowLadderSchema } from "../schemas/cashflow_ladder.js";
+import { wrapResponse, coerceNumbers } from "../formatters/response.js";
+
+export function registerCashflowLadderTools(server: McpServer) {
+  server.tool(
+    "cashflow_ladder",
+    "Aggregate projected cash flows across asset classes into one ladder. Each position is run through its own model (bond, amortising loan, lease, ABS pool or tranche, project finance senior debt or equity, annuity, or a hand-entered schedule), its dated flows are split into interest, principal and other, scaled by quantity and summed into tenor or periodic buckets for assets and liabilities. Returns per-bucket asset, liability, net and cumulative net flows, per-position totals, bucket profile and weighted average life, the largest cumulative funding shortfall, and with a discount curve the present values and durations of each side. Use for ALM, LDI cash flow matching and treasury liquidity profiles.",
+    CashFlowLadderSchema.shape,
+    async (params) => {
+      const validated = CashFlowLadderSchema.parse(coerceNumbers(params));
+      const result = buildCashflowLadder(validated);
+      return wrapResponse(result);
+    }
+  );
+}
@@ -2,14 +2,18 @@ import { McpServer } from "@modelcontextprotocol/sdk/server/mcp.js";
 import {
   calculateCloWaterfall,
   calculateCoverageTests,
+  calculatePortfolioMetrics,
   calculateReinvestment,
+  optimizeReinvestment,
   calculateTrancheAnalytics,
   calculateCloScenario,
 } from "../bindings.js";
 import {
   CloWaterfallSchema,
   CloCoverageTestsSchema,
+  CloPortfolioMetricsSchema,
   CloReinvestmentSchema,
+  CloReinvestmentOptimizerSchema,
   CloTrancheAnalyticsSchema,
   CloScenarioSchema,
 } from "../schemas/clo_analytics.js";
@@ -22,18 +26,29 @@ export function registerCloAnalyticsTools(server: McpServer) {
     CloWaterfallSchema.shape,
     async (params) => {
       const validated = CloWaterfallSchema.parse(coerceNumbers(params));
-      const result = calculateCloWaterfall(JSON.stringify(validated));
+      const result = calculateCloWaterfall(validated);
       return wrapResponse(result);
     }
   );
 
   server.tool(
     "clo_coverage_tests",
-    "CLO coverage tests: OC/IC ratios, trigger breach detection, cure mechanics, diversion amounts",
+    "CLO coverage tests: OC/IC ratios, trigger breach detection, cure mechanics, diversion amounts; with a loan tape also WARF, WAS, WAL and diversity score",
     CloCoverageTestsSchema.shape,
     async (params) => {
       const validated = CloCoverageTestsSchema.parse(coerceNumbers(params));
-      const result = calculateCoverageTests(JSON.stringify(validated));
+      const result = calculateCoverageTests(validated);
+      return wrapResponse(result);
+    }
+  );
+
+  server.tool(
+    "clo_portfolio_metrics",
+    "CLO collateral quality metrics from a loan tape: WARF (Moody's rating factors) and its implied rating, weighted average spread, weighted average life and Moody's diversity score (obligor par aggregation, equivalent units capped at the average obligor par, industry diversity table), with a per-industry breakdown. Defaulted obligations are excluded.",
+    CloPortfolioMetricsSchema.shape,
+    async (params) => {
+      const validated = CloPortfolioMetricsSchema.parse(coerceNumbers(params));
+      const result = calculatePortfolioMetrics(validated);
       return wrapResponse(result);
     }
   );
@@ -44,7 +59,18 @@ export function registerCloAnalyticsTools(server: McpServer) {
     CloReinvestmentSchema.shape,
     async (params) => {
       const validated = CloReinvestmentSchema.parse(coerceNumbers(params));
-      const result = calculateReinvestment(JSON.stringify(validated));
+      const result = calculateReinvestment(validated);
+      return wrapResponse(result);
+    }
+  );
+
+  server.tool(
+    "clo_reinvestment_optimizer",
+    "CLO reinvestment optimiser: choose which candidate loans to buy with principal proceeds. Buys lot by lot in objective order (max spread, min WARF or max par) while keeping WARF, WALS, WAL and diversity tests passing, or not worsening a test already failing (maintain or improve). Returns purchases, proceeds used, pre- and post-trade pool analytics, slack on each test with binding flags, and why each unfilled candidate stopped",
+    CloReinvestmentOptimizerSchema.shape,
+    async (params) => {
+      const validated = CloReinvestmentOptimizerSchema.parse(coerceNumbers(params));
+      const result = optimizeReinvestment(validated);
       return wrapResponse(result);
     }
   );
@@ -55,7 +81,7 @@ export function registerCloAnalyticsTools(server: McpServer) {
     CloTrancheAnalyticsSchema.shape,
     async (params) => {
       const validated = CloTrancheAnalyticsSchema.parse(coerceNumbers(params));
-      const result = calculateTrancheAnalytics(JSON.stringify(validated));
+      const result = calculateTrancheAnalytics(validated);
       return wrapResponse(result);
     }
   );
@@ -66,7 +92,7 @@ export function registerCloAnalyticsTools(server: McpServer) {
     CloScenarioSchema.shape,
     async (params) => {
       const validated = CloScenarioSchema.parse(coerceNumbers(params));
-      const result = calculateCloScenario(JSON.stringify(validated));
+      const result = calculateCloScenario(validated);
       return wrapResponse(result);
     }
   );
@@ -16,7 +16,7 @@ export function registerCommodityTradingTools(server: McpServer) {
     CommoditySpreadSchema.shape,
     async (params) => {
       const validated = CommoditySpreadSchema.parse(coerceNumbers(params));
-      const result = analyzeCommoditySpread(JSON.stringify(validated));
+      const result = analyzeCommoditySpread(validated);
       return wrapResponse(result);
     }
   );
@@ -27,7 +27,7 @@ export function registerCommodityTradingTools(server: McpServer) {
     StorageEconomicsSchema.shape,
     async (params) => {
       const validated = StorageEconomicsSchema.parse(coerceNumbers(params));
-      const result = analyzeStorageEconomics(JSON.stringify(validated));
+      const result = analyzeStorageEconomics(validated);
       return wrapResponse(result);
     }
   );
@@ -16,7 +16,7 @@ export function registerComplianceTools(server: McpServer) {
     BestExecutionSchema.shape,
     async (params) => {
       const validated = BestExecutionSchema.parse(coerceNumbers(params));
-      const result = analyzeBestExecution(JSON.stringify(validated));
+      const result = analyzeBestExecution(validated);
       return wrapResponse(result);
     }
   );
@@ -27,7 +27,7 @@ export function registerComplianceTools(server: McpServer) {
     GipsReportSchema.shape,
     async (params) => {
       const validated = GipsReportSchema.parse(coerceNumbers(params));
-      const result = generateGipsReport(JSON.stringify(validated));
+      const result = generateGipsReport(validated);
       return wrapResponse(result);
     }
   );
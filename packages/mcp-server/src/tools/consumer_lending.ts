@@ -0,0 +1,39 @@
+import { McpServer } from "@modelcontextprotocol/sdk/server/mcp.js";
+import { analyzeRefinance, buildRetailLoanSchedule, calculateApr } from "../bindings.js";
+import { LoanAprSchema, RefinanceSchema, RetailLoanSchema } from "../schemas/consumer_lending.js";
+import { wrapResponse, coerceNumbers } from "../formatters/response.js";
+
+export function registerConsumerLendingTools(server: McpServer) {
+  server.tool(
+    "retail_loan_schedule",
+    "Monthly amortisation schedule for a mortgage, auto or student loan. Supports adjustable rate resets with initial, periodic and lifetime caps, a floor and rate rounding (payment recast over the remaining term at each reset), recurring and lump-sum extra payments compared against the no-prepayment baseline (interest and months saved), and student loan deferment with capitalised interest for unsubsidised loans. Returns the schedule, rate resets, payment shock and summary totals.",
+    RetailLoanSchema.shape,
+    async (params) => {
+      const validated = RetailLoanSchema.parse(coerceNumbers(params));
+      const result = buildRetailLoanSchedule(validated);
+      return wrapResponse(result);
+    }
+  );
+
+  server.tool(
+    "loan_apr",
+    "Regulation Z annual percentage rate by the Appendix J actuarial method. Deducts points and prepaid finance charges from the loan to get the amount financed, then solves for the rate that discounts the payment stream to it; adjustable rate loans use a composite stream with the index at consummation held flat. Returns amount financed, finance charge, total of payments, APR and its spread over the note rate, the disclosure tolerance (1/8 point regular, 1/4 point irregular) and, with a par rate, the monthly saving and breakeven months for discount points.",
+    LoanAprSchema.shape,
+    async (params) => {
+      const validated = LoanAprSchema.parse(coerceNumbers(params));
+      const result = calculateApr(validated);
+      return wrapResponse(result);
+    }
+  );
+
+  server.tool(
+    "refinance_analysis",
+    "Refinance decision for a consumer loan. Compares the current loan with a new one, with closing costs and points paid in cash or rolled into the loan and optional cash out. Returns both payments, the monthly saving, simple breakeven (costs over saving), a breakeven that also credits the difference in balances outstanding so term extensions are not flattered, the NPV of refinancing over the holding period, and lifetime interest on both loans.",
+    RefinanceSchema.shape,
+    async (params) => {
+      const validated = RefinanceSchema.parse(coerceNumbers(params));
+      const result = analyzeRefinance(validated);
+      return wrapResponse(result);
+    }
+  );
+}
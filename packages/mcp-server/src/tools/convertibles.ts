@@ -2,10 +2,12 @@ import { McpServer } from "@modelcontextprotocol/sdk/server/mcp.js";
 import {
   priceConvertible,
   analyzeConvertible,
+  priceConvertibleBook,
 } from "../bindings.js";
 import {
   ConvertiblePricingSchema,
   ConvertibleAnalysisSchema,
+  ConvertibleBookSchema,
 } from "../schemas/convertibles.js";
 import { wrapResponse, coerceNumbers } from "../formatters/response.js";
 
@@ -16,7 +18,7 @@ export function registerConvertibleTools(server: McpServer) {
     ConvertiblePricingSchema.shape,
     async (params) => {
       const validated = ConvertiblePricingSchema.parse(coerceNumbers(params));
-      const result = priceConvertible(JSON.stringify(validated));
+      const result = priceConvertible(validated);
       return wrapResponse(result);
     }
   );
@@ -27,7 +29,18 @@ export function registerConvertibleTools(server: McpServer) {
     ConvertibleAnalysisSchema.shape,
     async (params) => {
       const validated = ConvertibleAnalysisSchema.parse(coerceNumbers(params));
-      const result = analyzeConvertible(JSON.stringify(validated));
+      const result = analyzeConvertible(validated);
+      return wrapResponse(result);
+    }
+  );
+
+  server.tool(
+    "convertible_book_pricing",
+    "Price a book of convertible and callable/puttable bonds off one calibrated CRR lattice. The tree is built once from the shared rate, credit spread, stock price and volatility, then each bond rolls back on it, with shorter maturities snapped to the nearest step. A zero conversion ratio prices a straight callable bond. Returns model price, bond floor, conversion value and premium, embedded option value, and tree delta and gamma per bond.",
+    ConvertibleBookSchema.shape,
+    async (params) => {
+      const validated = ConvertibleBookSchema.parse(coerceNumbers(params));
+      const result = priceConvertibleBook(validated);
       return wrapResponse(result);
     }
   );
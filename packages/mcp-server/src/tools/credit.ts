@@ -2,12 +2,20 @@ import { McpServer } from "@modelcontextprotocol/sdk/server/mcp.js";
 import {
   creditMetrics,
   debtCapacity,
+  analyzeDebtMaturities,
+  amortizationSchedule,
   covenantCompliance,
+  projectCovenants,
+  counterpartyExposure,
 } from "../bindings.js";
 import {
   CreditMetricsSchema,
   DebtCapacitySchema,
+  DebtMaturitySchema,
+  AmortizationSchema,
   CovenantTestSchema,
+  CovenantProjectionSchema,
+  CounterpartyExposureSchema,
 } from "../schemas/credit.js";
 import { wrapResponse, coerceNumbers } from "../formatters/response.js";
 
@@ -18,7 +26,7 @@ export function registerCreditTools(server: McpServer) {
     CreditMetricsSchema.shape,
     async (params) => {
       const validated = CreditMetricsSchema.parse(coerceNumbers(params));
-      const result = creditMetrics(JSON.stringify(validated));
+      const result = creditMetrics(validated);
       return wrapResponse(result);
     }
   );
@@ -29,7 +37,29 @@ export function registerCreditTools(server: McpServer) {
     DebtCapacitySchema.shape,
     async (params) => {
       const validated = DebtCapacitySchema.parse(coerceNumbers(params));
-      const result = debtCapacity(JSON.stringify(validated));
+      const result = debtCapacity(validated);
+      return wrapResponse(result);
+    }
+  );
+
+  server.tool(
+    "debt_maturity_schedule",
+    "Project a debt stack year by year with scheduled amortisation, maturities and refinancing. Maturing debt is refinanced up to the marginal capacity allowed by leverage, interest coverage and DSCR limits; the rest is repaid from cash above a minimum balance, leaving a funding gap otherwise. Returns the maturity wall, refinancing events, weighted average maturity and rate, and the years in which capacity binds.",
+    DebtMaturitySchema.shape,
+    async (params) => {
+      const validated = DebtMaturitySchema.parse(coerceNumbers(params));
+      const result = analyzeDebtMaturities(validated);
+      return wrapResponse(result);
+    }
+  );
+
+  server.tool(
+    "amortization_schedule",
+    "Generate a period-by-period loan amortisation schedule: level payment (annuity), straight-line, interest-only then amortising, bullet, custom principal, or DSCR-sculpted against a cash flow profile. Interest accrues on the opening balance, PIK is capitalised before principal is paid, and the final period settles the remaining balance (sculpted schedules report any tail instead). Returns each period's opening balance, interest, PIK, principal, debt service and closing balance, with totals and weighted average life. The same engine drives the LBO debt schedule, direct lending and project finance models.",
+    AmortizationSchema.shape,
+    async (params) => {
+      const validated = AmortizationSchema.parse(coerceNumbers(params));
+      const result = amortizationSchedule(validated);
       return wrapResponse(result);
     }
   );
@@ -40,7 +70,29 @@ export function registerCreditTools(server: McpServer) {
     CovenantTestSchema.shape,
     async (params) => {
       const validated = CovenantTestSchema.parse(coerceNumbers(params));
-      const result = covenantCompliance(JSON.stringify(validated));
+      const result = covenantCompliance(validated);
+      return wrapResponse(result);
+    }
+  );
+
+  server.tool(
+    "covenant_projection",
+    "Test bespoke covenants written as formulas (e.g. 'net_debt / ebitda <= 4.5x stepping to 4.0x after Q8') over a projection of test dates. Supports defined terms, step-downs by quarter or year, and maximum or minimum covenants. Returns the threshold, actual, pass/fail and headroom per period, a headroom waterfall attributing each period's change to threshold steps and individual inputs, the tightest period and the first breach.",
+    CovenantProjectionSchema.shape,
+    async (params) => {
+      const validated = CovenantProjectionSchema.parse(coerceNumbers(params));
+      const result = projectCovenants(validated);
+      return wrapResponse(result);
+    }
+  );
+
+  server.tool(
+    "counterparty_exposure",
+    "Counterparty credit exposure for OTC derivatives: current exposure and potential future exposure profiles by netting set (supervisory add-on with net-to-gross ratio, or Monte Carlo simulation), expected exposure, peak PFE, and utilisation against counterparty and tenor limits",
+    CounterpartyExposureSchema.shape,
+    async (params) => {
+      const validated = CounterpartyExposureSchema.parse(coerceNumbers(params));
+      const result = counterpartyExposure(validated);
       return wrapResponse(result);
     }
   );
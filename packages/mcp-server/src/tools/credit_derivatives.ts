@@ -2,10 +2,12 @@ import { McpServer } from "@modelcontextprotocol/sdk/server/mcp.js";
 import {
   priceCds,
   calculateCva,
+  calculateXva,
 } from "../bindings.js";
 import {
   CdsPricingSchema,
   CvaCalculationSchema,
+  XvaCalculationSchema,
 } from "../schemas/credit_derivatives.js";
 import { wrapResponse, coerceNumbers } from "../formatters/response.js";
 
@@ -16,7 +18,7 @@ export function registerCreditDerivativesTools(server: McpServer) {
     CdsPricingSchema.shape,
     async (params) => {
       const validated = CdsPricingSchema.parse(coerceNumbers(params));
-      const result = priceCds(JSON.stringify(validated));
+      const result = priceCds(validated);
       return wrapResponse(result);
     }
   );
@@ -27,7 +29,18 @@ export function registerCreditDerivativesTools(server: McpServer) {
     CvaCalculationSchema.shape,
     async (params) => {
       const validated = CvaCalculationSchema.parse(coerceNumbers(params));
-      const result = calculateCva(JSON.stringify(validated));
+      const result = calculateCva(validated);
+      return wrapResponse(result);
+    }
+  );
+
+  server.tool(
+    "xva_calculation",
+    "Full XVA stack for desk-level pricing: CVA, DVA, FVA (funding cost and benefit), KVA (cost of regulatory capital) and MVA (cost of funding initial margin). Takes netted exposure profiles per netting set with CSA terms (threshold, minimum transfer amount, independent amount) and returns each adjustment per netting set and in total, with the collateralised exposure, capital and survival profile.",
+    XvaCalculationSchema.shape,
+    async (params) => {
+      const validated = XvaCalculationSchema.parse(coerceNumbers(params));
+      const result = calculateXva(validated);
       return wrapResponse(result);
     }
   );
@@ -16,7 +16,7 @@ export function registerCreditPortfolioTools(server: McpServer) {
     PortfolioRiskSchema.shape,
     async (params) => {
       const validated = PortfolioRiskSchema.parse(coerceNumbers(params));
-      const result = calculatePortfolioCreditRisk(JSON.stringify(validated));
+      const result = calculatePortfolioCreditRisk(validated);
       return wrapResponse(result);
     }
   );
@@ -27,7 +27,7 @@ export function registerCreditPortfolioTools(server: McpServer) {
     MigrationSchema.shape,
     async (params) => {
       const validated = MigrationSchema.parse(coerceNumbers(params));
-      const result = calculateMigration(JSON.stringify(validated));
+      const result = calculateMigration(validated);
       return wrapResponse(result);
     }
   );
@@ -22,7 +22,7 @@ export function registerCreditScoringTools(server: McpServer) {
     CreditScorecardSchema.shape,
     async (params) => {
       const validated = CreditScorecardSchema.parse(coerceNumbers(params));
-      const result = calculateScorecard(JSON.stringify(validated));
+      const result = calculateScorecard(validated);
       return wrapResponse(result);
     }
   );
@@ -33,7 +33,7 @@ export function registerCreditScoringTools(server: McpServer) {
     MertonPdSchema.shape,
     async (params) => {
       const validated = MertonPdSchema.parse(coerceNumbers(params));
-      const result = calculateMerton(JSON.stringify(validated));
+      const result = calculateMerton(validated);
       return wrapResponse(result);
     }
   );
@@ -44,7 +44,7 @@ export function registerCreditScoringTools(server: McpServer) {
     IntensityModelSchema.shape,
     async (params) => {
       const validated = IntensityModelSchema.parse(coerceNumbers(params));
-      const result = calculateIntensityModel(JSON.stringify(validated));
+      const result = calculateIntensityModel(validated);
       return wrapResponse(result);
     }
   );
@@ -55,7 +55,7 @@ export function registerCreditScoringTools(server: McpServer) {
     PdCalibrationSchema.shape,
     async (params) => {
       const validated = PdCalibrationSchema.parse(coerceNumbers(params));
-      const result = calculateCalibration(JSON.stringify(validated));
+      const result = calculateCalibration(validated);
       return wrapResponse(result);
     }
   );
@@ -66,7 +66,7 @@ export function registerCreditScoringTools(server: McpServer) {
     ScoringValidationSchema.shape,
     async (params) => {
       const validated = ScoringValidationSchema.parse(coerceNumbers(params));
-      const result = calculateScoringValidation(JSON.stringify(validated));
+      const result = calculateScoringValidation(validated);
       return wrapResponse(result);
     }
   );
@@ -21,7 +21,7 @@ export function registerCryptoTools(server: McpServer) {
     TokenValuationSchema.shape,
     async (params) => {
       const validated = TokenValuationSchema.parse(coerceNumbers(params));
-      const result = _valueToken(JSON.stringify(validated));
+      const result = _valueToken(validated);
       return wrapResponse(result);
     }
   );
@@ -32,7 +32,7 @@ export function registerCryptoTools(server: McpServer) {
     DefiYieldSchema.shape,
     async (params) => {
       const validated = DefiYieldSchema.parse(coerceNumbers(params));
-      const result = _analyzeDefi(JSON.stringify(validated));
+      const result = _analyzeDefi(validated);
       return wrapResponse(result);
     }
   );
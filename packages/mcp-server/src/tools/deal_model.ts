@@ -0,0 +1,17 @@
+import { McpServer } from "@modelcontextprotocol/sdk/server/mcp.js";
+import { runDealModel } from "../bindings.js";
+import { DealModelSchema } from "../schemas/deal_model.js";
+import { wrapResponse, coerceNumbers } from "../formatters/response.js";
+
+export function registerDealModelTools(server: McpServer) {
+  server.tool(
+    "deal_model",
+    "Run an acquisition end to end from one input: target financials, financing structure and valuation assumptions. Builds the target three-statement model and values it by FCFF DCF, runs the LBO on the same revenue path and margins, builds a pro forma model carrying the acquisition debt and sponsor equity (purchase premium held as goodwill/step-up), computes credit metrics and tests covenants for every pro forma year, and optionally sweeps a two-way sensitivity of DCF value, IRR or MOIC. Returns one consolidated output with a headline summary (entry multiple, premium to DCF, IRR, MOIC, opening and peak leverage, minimum coverage, first covenant breach).",
+    DealModelSchema.shape,
+    async (params) => {
+      const validated = DealModelSchema.parse(coerceNumbers(params));
+      const result = runDealModel(validated);
+      return wrapResponse(result);
+    }
+  );
+}
@@ -6,11 +6,11 @@ import { wrapResponse, coerceNumbers } from "../formatters/response.js";
 export function registerDerivativesTools(server: McpServer) {
   server.tool(
     "option_pricer",
-    "Price an option using Black-Scholes or binomial model — price, Greeks (delta, gamma, theta, vega, rho), intrinsic/time value",
+    "Price an option using Black-Scholes, binomial/trinomial trees or Longstaff-Schwartz Monte Carlo (European, American, Bermudan) — price, Greeks (delta, gamma, theta, vega, rho, vanna, volga; analytic or bump-and-reprice), intrinsic/time value",
     OptionPriceSchema.shape,
     async (params) => {
       const validated = OptionPriceSchema.parse(coerceNumbers(params));
-      const result = priceOption(JSON.stringify(validated));
+      const result = priceOption(validated);
       return wrapResponse(result);
     }
   );
@@ -21,7 +21,7 @@ export function registerDerivativesTools(server: McpServer) {
     ImpliedVolSchema.shape,
     async (params) => {
       const validated = ImpliedVolSchema.parse(coerceNumbers(params));
-      const result = impliedVolatility(JSON.stringify(validated));
+      const result = impliedVolatility(validated);
       return wrapResponse(result);
     }
   );
@@ -32,7 +32,7 @@ export function registerDerivativesTools(server: McpServer) {
     ForwardPriceSchema.shape,
     async (params) => {
       const validated = ForwardPriceSchema.parse(coerceNumbers(params));
-      const result = priceForward(JSON.stringify(validated));
+      const result = priceForward(validated);
       return wrapResponse(result);
     }
   );
@@ -43,7 +43,7 @@ export function registerDerivativesTools(server: McpServer) {
     ForwardPositionSchema.shape,
     async (params) => {
       const validated = ForwardPositionSchema.parse(coerceNumbers(params));
-      const result = valueForwardPosition(JSON.stringify(validated));
+      const result = valueForwardPosition(validated);
       return wrapResponse(result);
     }
   );
@@ -54,7 +54,7 @@ export function registerDerivativesTools(server: McpServer) {
     BasisAnalysisSchema.shape,
     async (params) => {
       const validated = BasisAnalysisSchema.parse(coerceNumbers(params));
-      const result = futuresBasisAnalysis(JSON.stringify(validated));
+      const result = futuresBasisAnalysis(validated);
       return wrapResponse(result);
     }
   );
@@ -65,7 +65,7 @@ export function registerDerivativesTools(server: McpServer) {
     IrsSchema.shape,
     async (params) => {
       const validated = IrsSchema.parse(coerceNumbers(params));
-      const result = valueInterestRateSwap(JSON.stringify(validated));
+      const result = valueInterestRateSwap(validated);
       return wrapResponse(result);
     }
   );
@@ -76,7 +76,7 @@ export function registerDerivativesTools(server: McpServer) {
     CurrencySwapSchema.shape,
     async (params) => {
       const validated = CurrencySwapSchema.parse(coerceNumbers(params));
-      const result = valueCurrencySwap(JSON.stringify(validated));
+      const result = valueCurrencySwap(validated);
       return wrapResponse(result);
     }
   );
@@ -87,7 +87,7 @@ export function registerDerivativesTools(server: McpServer) {
     StrategySchema.shape,
     async (params) => {
       const validated = StrategySchema.parse(coerceNumbers(params));
-      const result = analyzeStrategy(JSON.stringify(validated));
+      const result = analyzeStrategy(validated);
       return wrapResponse(result);
     }
   );
@@ -5,6 +5,9 @@ import {
   analyzeBuyback,
   analyzePayoutSustainability,
   calculateTotalShareholderReturn,
+  modelAsr,
+  modelOpenMarketProgram,
+  optimizePayoutPolicy,
 } from "../bindings.js";
 import {
   HModelDdmSchema,
@@ -12,6 +15,9 @@ import {
   BuybackAnalysisSchema,
   PayoutSustainabilitySchema,
   TotalShareholderReturnSchema,
+  AsrSchema,
+  OpenMarketBuybackSchema,
+  PayoutOptimizerSchema,
 } from "../schemas/dividend_policy.js";
 import { wrapResponse, coerceNumbers } from "../formatters/response.js";
 
@@ -22,7 +28,7 @@ export function registerDividendPolicyTools(server: McpServer) {
     HModelDdmSchema.shape,
     async (params) => {
       const validated = HModelDdmSchema.parse(coerceNumbers(params));
-      const result = calculateHModelDdm(JSON.stringify(validated));
+      const result = calculateHModelDdm(validated);
       return wrapResponse(result);
     }
   );
@@ -33,7 +39,7 @@ export function registerDividendPolicyTools(server: McpServer) {
     MultistageDdmSchema.shape,
     async (params) => {
       const validated = MultistageDdmSchema.parse(coerceNumbers(params));
-      const result = calculateMultistageDdm(JSON.stringify(validated));
+      const result = calculateMultistageDdm(validated);
       return wrapResponse(result);
     }
   );
@@ -44,7 +50,7 @@ export function registerDividendPolicyTools(server: McpServer) {
     BuybackAnalysisSchema.shape,
     async (params) => {
       const validated = BuybackAnalysisSchema.parse(coerceNumbers(params));
-      const result = analyzeBuyback(JSON.stringify(validated));
+      const result = analyzeBuyback(validated);
       return wrapResponse(result);
     }
   );
@@ -55,7 +61,7 @@ export function registerDividendPolicyTools(server: McpServer) {
     PayoutSustainabilitySchema.shape,
     async (params) => {
       const validated = PayoutSustainabilitySchema.parse(coerceNumbers(params));
-      const result = analyzePayoutSustainability(JSON.stringify(validated));
+      const result = analyzePayoutSustainability(validated);
       return wrapResponse(result);
     }
   );
@@ -66,7 +72,40 @@ export function registerDividendPolicyTools(server: McpServer) {
     TotalShareholderReturnSchema.shape,
     async (params) => {
       const validated = TotalShareholderReturnSchema.parse(coerceNumbers(params));
-      const result = calculateTotalShareholderReturn(JSON.stringify(validated));
+      const result = calculateTotalShareholderReturn(validated);
+      return wrapResponse(result);
+    }
+  );
+
+  server.tool(
+    "asr_buyback",
+    "Accelerated share repurchase: upfront delivery at spot, simulated VWAP settlement with dealer discount, final share delivery or cash true-up, average price paid and share count reduction distribution",
+    AsrSchema.shape,
+    async (params) => {
+      const validated = AsrSchema.parse(coerceNumbers(params));
+      const result = modelAsr(validated);
+      return wrapResponse(result);
+    }
+  );
+
+  server.tool(
+    "open_market_buyback",
+    "Open-market (10b5-1) buyback execution: simulated daily purchases under volume participation, daily budget and limit price constraints, days to complete, average price and shares retired distribution",
+    OpenMarketBuybackSchema.shape,
+    async (params) => {
+      const validated = OpenMarketBuybackSchema.parse(coerceNumbers(params));
+      const result = modelOpenMarketProgram(validated);
+      return wrapResponse(result);
+    }
+  );
+
+  server.tool(
+    "payout_optimizer",
+    "Payout policy optimizer: project dividend/buyback mixes under capital requirements, a Net Debt/EBITDA cap and a no-dividend-cut constraint, then rank feasible policies by after-tax shareholder value",
+    PayoutOptimizerSchema.shape,
+    async (params) => {
+      const validated = PayoutOptimizerSchema.parse(coerceNumbers(params));
+      const result = optimizePayoutPolicy(validated);
       return wrapResponse(result);
     }
   );
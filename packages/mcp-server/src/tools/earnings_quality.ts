@@ -22,7 +22,7 @@ export function registerEarningsQualityTools(server: McpServer) {
     BeneishMscoreSchema.shape,
     async (params) => {
       const validated = BeneishMscoreSchema.parse(coerceNumbers(params));
-      const result = calculateBeneishMscore(JSON.stringify(validated));
+      const result = calculateBeneishMscore(validated);
       return wrapResponse(result);
     }
   );
@@ -33,7 +33,7 @@ export function registerEarningsQualityTools(server: McpServer) {
     PiotroskiFscoreSchema.shape,
     async (params) => {
       const validated = PiotroskiFscoreSchema.parse(coerceNumbers(params));
-      const result = calculatePiotroskiFscore(JSON.stringify(validated));
+      const result = calculatePiotroskiFscore(validated);
       return wrapResponse(result);
     }
   );
@@ -44,7 +44,7 @@ export function registerEarningsQualityTools(server: McpServer) {
     AccrualQualitySchema.shape,
     async (params) => {
       const validated = AccrualQualitySchema.parse(coerceNumbers(params));
-      const result = calculateAccrualQuality(JSON.stringify(validated));
+      const result = calculateAccrualQuality(validated);
       return wrapResponse(result);
     }
   );
@@ -55,7 +55,7 @@ export function registerEarningsQualityTools(server: McpServer) {
     RevenueQualitySchema.shape,
     async (params) => {
       const validated = RevenueQualitySchema.parse(coerceNumbers(params));
-      const result = calculateRevenueQuality(JSON.stringify(validated));
+      const result = calculateRevenueQuality(validated);
       return wrapResponse(result);
     }
   );
@@ -66,7 +66,7 @@ export function registerEarningsQualityTools(server: McpServer) {
     EarningsQualityCompositeSchema.shape,
     async (params) => {
       const validated = EarningsQualityCompositeSchema.parse(coerceNumbers(params));
-      const result = calculateEarningsQualityComposite(JSON.stringify(validated));
+      const result = calculateEarningsQualityComposite(validated);
       return wrapResponse(result);
     }
   );
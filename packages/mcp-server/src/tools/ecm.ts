@@ -0,0 +1,34 @@
+import { McpServer } from "@modelcontextprotocol/sdk/server/mcp.js";
+import {
+  analyzeIpo,
+  analyzeRightsIssue,
+} from "../bindings.js";
+import {
+  IpoSchema,
+  RightsIssueSchema,
+} from "../schemas/ecm.js";
+import { wrapResponse, coerceNumbers } from "../formatters/response.js";
+
+export function registerEcmTools(server: McpServer) {
+  server.tool(
+    "ipo_analysis",
+    "IPO analysis: comps/DCF blended pre-money value, IPO discount and price range, primary/secondary offering structure with greenshoe, gross spread and net proceeds, post-IPO cap table and lock-up expiry table",
+    IpoSchema.shape,
+    async (params) => {
+      const validated = IpoSchema.parse(coerceNumbers(params));
+      const result = analyzeIpo(validated);
+      return wrapResponse(result);
+    }
+  );
+
+  server.tool(
+    "rights_issue",
+    "Rights issue: theoretical ex-rights price (TERP), value of a right, discount to TERP, underwriting fees and underwriter take-up, shareholder dilution if rights are not taken up, alternative subscription prices",
+    RightsIssueSchema.shape,
+    async (params) => {
+      const validated = RightsIssueSchema.parse(coerceNumbers(params));
+      const result = analyzeRightsIssue(validated);
+      return wrapResponse(result);
+    }
+  );
+}
@@ -22,7 +22,7 @@ export function registerEmergingMarketsTools(server: McpServer) {
     CountryRiskPremiumSchema.shape,
     async (params) => {
       const validated = CountryRiskPremiumSchema.parse(coerceNumbers(params));
-      const result = calculateCountryRiskPremium(JSON.stringify(validated));
+      const result = calculateCountryRiskPremium(validated);
       return wrapResponse(result);
     }
   );
@@ -33,7 +33,7 @@ export function registerEmergingMarketsTools(server: McpServer) {
     PoliticalRiskSchema.shape,
     async (params) => {
       const validated = PoliticalRiskSchema.parse(coerceNumbers(params));
-      const result = assessPoliticalRisk(JSON.stringify(validated));
+      const result = assessPoliticalRisk(validated);
       return wrapResponse(result);
     }
   );
@@ -44,7 +44,7 @@ export function registerEmergingMarketsTools(server: McpServer) {
     CapitalControlsSchema.shape,
     async (params) => {
       const validated = CapitalControlsSchema.parse(coerceNumbers(params));
-      const result = analyseCapitalControls(JSON.stringify(validated));
+      const result = analyseCapitalControls(validated);
       return wrapResponse(result);
     }
   );
@@ -55,7 +55,7 @@ export function registerEmergingMarketsTools(server: McpServer) {
     EmBondAnalysisSchema.shape,
     async (params) => {
       const validated = EmBondAnalysisSchema.parse(coerceNumbers(params));
-      const result = analyseEmBonds(JSON.stringify(validated));
+      const result = analyseEmBonds(validated);
       return wrapResponse(result);
     }
   );
@@ -66,7 +66,7 @@ export function registerEmergingMarketsTools(server: McpServer) {
     EmEquityPremiumSchema.shape,
     async (params) => {
       const validated = EmEquityPremiumSchema.parse(coerceNumbers(params));
-      const result = calculateEmEquityPremium(JSON.stringify(validated));
+      const result = calculateEmEquityPremium(validated);
       return wrapResponse(result);
     }
   );
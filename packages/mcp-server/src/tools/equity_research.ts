@@ -2,21 +2,27 @@ import { McpServer } from "@modelcontextprotocol/sdk/server/mcp.js";
 import {
   calculateSotp,
   calculateTargetPrice,
+  analyzeReit,
+  valueBank,
+  calculateReverseDcf,
 } from "../bindings.js";
 import {
   SotpSchema,
   TargetPriceSchema,
+  ReitSchema,
+  BankValuationSchema,
+  ReverseDcfSchema,
 } from "../schemas/equity_research.js";
 import { wrapResponse, coerceNumbers } from "../formatters/response.js";
 
 export function registerEquityResearchTools(server: McpServer) {
   server.tool(
     "sotp_valuation",
-    "Sum-of-the-parts valuation: segment-level multiples, conglomerate discount, football field analysis",
+    "Sum-of-the-parts valuation: segment-level multiples, listed cross-holdings, holdco discount estimation, look-through leverage, football field analysis",
     SotpSchema.shape,
     async (params) => {
       const validated = SotpSchema.parse(coerceNumbers(params));
-      const result = calculateSotp(JSON.stringify(validated));
+      const result = calculateSotp(validated);
       return wrapResponse(result);
     }
   );
@@ -27,7 +33,40 @@ export function registerEquityResearchTools(server: McpServer) {
     TargetPriceSchema.shape,
     async (params) => {
       const validated = TargetPriceSchema.parse(coerceNumbers(params));
-      const result = calculateTargetPrice(JSON.stringify(validated));
+      const result = calculateTargetPrice(validated);
+      return wrapResponse(result);
+    }
+  );
+
+  server.tool(
+    "reit_analysis",
+    "REIT analysis: NAREIT FFO and AFFO reconciliation, per-share metrics, payout sustainability, cap-rate NAV with sensitivity, implied cap rate and P/FFO",
+    ReitSchema.shape,
+    async (params) => {
+      const validated = ReitSchema.parse(coerceNumbers(params));
+      const result = analyzeReit(validated);
+      return wrapResponse(result);
+    }
+  );
+
+  server.tool(
+    "bank_valuation",
+    "Bank valuation: warranted P/TBV from ROTE and cost of equity, excess-capital multi-stage DDM constrained by a CET1 target, peer P/TBV vs ROTE regression, CECL reserve adequacy",
+    BankValuationSchema.shape,
+    async (params) => {
+      const validated = BankValuationSchema.parse(coerceNumbers(params));
+      const result = valueBank(validated);
+      return wrapResponse(result);
+    }
+  );
+
+  server.tool(
+    "reverse_dcf",
+    "Reverse DCF: solve for the revenue growth and operating margin implied by the current share price, with TSM dilution of options, warrants, RSUs and convertibles, compared against analyst estimates",
+    ReverseDcfSchema.shape,
+    async (params) => {
+      const validated = ReverseDcfSchema.parse(coerceNumbers(params));
+      const result = calculateReverseDcf(validated);
       return wrapResponse(result);
     }
   );
@@ -20,7 +20,7 @@ export function registerEsgTools(server: McpServer) {
     EsgScoreSchema.shape,
     async (params) => {
       const validated = EsgScoreSchema.parse(coerceNumbers(params));
-      const result = calculateEsgScore(JSON.stringify(validated));
+      const result = calculateEsgScore(validated);
       return wrapResponse(result);
     }
   );
@@ -31,7 +31,7 @@ export function registerEsgTools(server: McpServer) {
     CarbonFootprintSchema.shape,
     async (params) => {
       const validated = CarbonFootprintSchema.parse(coerceNumbers(params));
-      const result = analyzeCarbonFootprint(JSON.stringify(validated));
+      const result = analyzeCarbonFootprint(validated);
       return wrapResponse(result);
     }
   );
@@ -42,7 +42,7 @@ export function registerEsgTools(server: McpServer) {
     GreenBondSchema.shape,
     async (params) => {
       const validated = GreenBondSchema.parse(coerceNumbers(params));
-      const result = analyzeGreenBond(JSON.stringify(validated));
+      const result = analyzeGreenBond(validated);
       return wrapResponse(result);
     }
   );
@@ -53,7 +53,7 @@ export function registerEsgTools(server: McpServer) {
     SllSchema.shape,
     async (params) => {
       const validated = SllSchema.parse(coerceNumbers(params));
-      const result = testSllCovenants(JSON.stringify(validated));
+      const result = testSllCovenants(validated);
       return wrapResponse(result);
     }
   );
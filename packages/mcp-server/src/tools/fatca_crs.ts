@@ -16,7 +16,7 @@ export function registerFatcaCrsTools(server: McpServer) {
     FatcaCrsReportingSchema.shape,
     async (params) => {
       const validated = FatcaCrsReportingSchema.parse(coerceNumbers(params));
-      const result = analyzeFatcaCrsReporting(JSON.stringify(validated));
+      const result = analyzeFatcaCrsReporting(validated);
       return wrapResponse(result);
     }
   );
@@ -27,7 +27,7 @@ export function registerFatcaCrsTools(server: McpServer) {
     EntityClassificationSchema.shape,
     async (params) => {
       const validated = EntityClassificationSchema.parse(coerceNumbers(params));
-      const result = classifyEntity(JSON.stringify(validated));
+      const result = classifyEntity(validated);
       return wrapResponse(result);
     }
   );
@@ -22,7 +22,7 @@ export function registerFinancialForensicsTools(server: McpServer) {
     BenfordsLawSchema.shape,
     async (params) => {
       const validated = BenfordsLawSchema.parse(coerceNumbers(params));
-      const result = analyzeBenfordsLaw(JSON.stringify(validated));
+      const result = analyzeBenfordsLaw(validated);
       return wrapResponse(result);
     }
   );
@@ -33,7 +33,7 @@ export function registerFinancialForensicsTools(server: McpServer) {
     DupontSchema.shape,
     async (params) => {
       const validated = DupontSchema.parse(coerceNumbers(params));
-      const result = calculateDupont(JSON.stringify(validated));
+      const result = calculateDupont(validated);
       return wrapResponse(result);
     }
   );
@@ -44,7 +44,7 @@ export function registerFinancialForensicsTools(server: McpServer) {
     ZScoreModelsSchema.shape,
     async (params) => {
       const validated = ZScoreModelsSchema.parse(coerceNumbers(params));
-      const result = calculateZscoreModels(JSON.stringify(validated));
+      const result = calculateZscoreModels(validated);
       return wrapResponse(result);
     }
   );
@@ -55,7 +55,7 @@ export function registerFinancialForensicsTools(server: McpServer) {
     PeerBenchmarkingSchema.shape,
     async (params) => {
       const validated = PeerBenchmarkingSchema.parse(coerceNumbers(params));
-      const result = calculatePeerBenchmarking(JSON.stringify(validated));
+      const result = calculatePeerBenchmarking(validated);
       return wrapResponse(result);
     }
   );
@@ -66,7 +66,7 @@ export function registerFinancialForensicsTools(server: McpServer) {
     RedFlagScoringSchema.shape,
     async (params) => {
       const validated = RedFlagScoringSchema.parse(coerceNumbers(params));
-      const result = calculateRedFlagScoring(JSON.stringify(validated));
+      const result = calculateRedFlagScoring(validated);
       return wrapResponse(result);
     }
   );
@@ -10,7 +10,7 @@ export function registerFixedIncomeTools(server: McpServer) {
     BondPricingSchema.shape,
     async (params) => {
       const validated = BondPricingSchema.parse(coerceNumbers(params));
-      const result = priceBond(JSON.stringify(validated));
+      const result = priceBond(validated);
       return wrapResponse(result);
     }
   );
@@ -21,7 +21,7 @@ export function registerFixedIncomeTools(server: McpServer) {
     BondYieldSchema.shape,
     async (params) => {
       const validated = BondYieldSchema.parse(coerceNumbers(params));
-      const result = calculateBondYield(JSON.stringify(validated));
+      const result = calculateBondYield(validated);
       return wrapResponse(result);
     }
   );
@@ -32,7 +32,7 @@ export function registerFixedIncomeTools(server: McpServer) {
     BootstrapSchema.shape,
     async (params) => {
       const validated = BootstrapSchema.parse(coerceNumbers(params));
-      const result = bootstrapSpotCurve(JSON.stringify(validated));
+      const result = bootstrapSpotCurve(validated);
       return wrapResponse(result);
     }
   );
@@ -43,7 +43,7 @@ export function registerFixedIncomeTools(server: McpServer) {
     NelsonSiegelSchema.shape,
     async (params) => {
       const validated = NelsonSiegelSchema.parse(coerceNumbers(params));
-      const result = fitNelsonSiegel(JSON.stringify(validated));
+      const result = fitNelsonSiegel(validated);
       return wrapResponse(result);
     }
   );
@@ -54,7 +54,7 @@ export function registerFixedIncomeTools(server: McpServer) {
     DurationSchema.shape,
     async (params) => {
       const validated = DurationSchema.parse(coerceNumbers(params));
-      const result = calculateDuration(JSON.stringify(validated));
+      const result = calculateDuration(validated);
       return wrapResponse(result);
     }
   );
@@ -65,7 +65,7 @@ export function registerFixedIncomeTools(server: McpServer) {
     CreditSpreadSchema.shape,
     async (params) => {
       const validated = CreditSpreadSchema.parse(coerceNumbers(params));
-      const result = calculateCreditSpreads(JSON.stringify(validated));
+      const result = calculateCreditSpreads(validated);
       return wrapResponse(result);
     }
   );
@@ -3,13 +3,21 @@ import {
   analyzeVariance,
   analyzeBreakeven,
   analyzeWorkingCapital,
+  optimizeInventory,
   buildRollingForecast,
+  forecastBacklogRevenue,
+  analyzeSaasMetrics,
+  analyzeUnitEconomics,
 } from "../bindings.js";
 import {
   VarianceSchema,
   BreakevenSchema,
   WorkingCapitalSchema,
+  InventoryOptimizationSchema,
   RollingForecastSchema,
+  BacklogRevenueSchema,
+  SaasMetricsSchema,
+  UnitEconomicsSchema,
 } from "../schemas/fpa.js";
 import { wrapResponse, coerceNumbers } from "../formatters/response.js";
 
@@ -20,7 +28,7 @@ export function registerFpaTools(server: McpServer) {
     VarianceSchema.shape,
     async (params) => {
       const validated = VarianceSchema.parse(coerceNumbers(params));
-      const result = analyzeVariance(JSON.stringify(validated));
+      const result = analyzeVariance(validated);
       return wrapResponse(result);
     }
   );
@@ -31,7 +39,7 @@ export function registerFpaTools(server: McpServer) {
     BreakevenSchema.shape,
     async (params) => {
       const validated = BreakevenSchema.parse(coerceNumbers(params));
-      const result = analyzeBreakeven(JSON.stringify(validated));
+      const result = analyzeBreakeven(validated);
       return wrapResponse(result);
     }
   );
@@ -42,7 +50,18 @@ export function registerFpaTools(server: McpServer) {
     WorkingCapitalSchema.shape,
     async (params) => {
       const validated = WorkingCapitalSchema.parse(coerceNumbers(params));
-      const result = analyzeWorkingCapital(JSON.stringify(validated));
+      const result = analyzeWorkingCapital(validated);
+      return wrapResponse(result);
+    }
+  );
+
+  server.tool(
+    "inventory_optimization",
+    "Optimise inventory from demand and lead-time drivers instead of ratio targets. Computes EOQ order quantities, lead-time demand variability from demand and lead-time standard deviations, safety stock and reorder points at the cost-optimal service level (from stockout cost) or a target service level, and the carrying vs stockout cost trade-off across service levels. Inventory financing links to a borrowing-base revolver: the blended revolver / cost-of-capital rate feeds the carrying cost, and the facility is sized from peak inventory with headroom. Returns per-item policies, driver-based DIO target, revolver draw, commitment and cost, and savings and cash released versus the current policy.",
+    InventoryOptimizationSchema.shape,
+    async (params) => {
+      const validated = InventoryOptimizationSchema.parse(coerceNumbers(params));
+      const result = optimizeInventory(validated);
       return wrapResponse(result);
     }
   );
@@ -53,7 +72,40 @@ export function registerFpaTools(server: McpServer) {
     RollingForecastSchema.shape,
     async (params) => {
       const validated = RollingForecastSchema.parse(coerceNumbers(params));
-      const result = buildRollingForecast(JSON.stringify(validated));
+      const result = buildRollingForecast(validated);
+      return wrapResponse(result);
+    }
+  );
+
+  server.tool(
+    "backlog_revenue",
+    "Convert contracted backlog and sales pipeline into a recognised revenue forecast. Signed contracts recognise their unrecognised value ratably, by percentage of completion against a remaining cost profile, or at a point in time on delivery; pipeline opportunities are weighted by win rate and start delivery after their close period plus a start lag. Returns per-contract schedules, revenue split contracted vs pipeline, opening and closing backlog, backlog coverage (contracted revenue over target or forecast) and target attainment by period, revenue beyond the horizon, and period growth rates from a base revenue that can be fed to three_statement_model.",
+    BacklogRevenueSchema.shape,
+    async (params) => {
+      const validated = BacklogRevenueSchema.parse(coerceNumbers(params));
+      const result = forecastBacklogRevenue(validated);
+      return wrapResponse(result);
+    }
+  );
+
+  server.tool(
+    "saas_metrics",
+    "SaaS metrics: ARR bridge (new, expansion, contraction, churn), net and gross revenue retention, logo churn, CAC payback, LTV/CAC, magic number and Rule of 40 by period and trailing twelve months",
+    SaasMetricsSchema.shape,
+    async (params) => {
+      const validated = SaasMetricsSchema.parse(coerceNumbers(params));
+      const result = analyzeSaasMetrics(validated);
+      return wrapResponse(result);
+    }
+  );
+
+  server.tool(
+    "unit_economics",
+    "Cohort unit economics: empirical, exponential or decaying-churn retention curves, discounted contribution LTV, LTV/CAC, CAC payback month, and Monte Carlo bands on LTV and payback under churn and ARPU uncertainty",
+    UnitEconomicsSchema.shape,
+    async (params) => {
+      const validated = UnitEconomicsSchema.parse(coerceNumbers(params));
+      const result = analyzeUnitEconomics(validated);
       return wrapResponse(result);
     }
   );
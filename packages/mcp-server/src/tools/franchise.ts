@@ -0,0 +1,17 @@
+import { McpServer } from "@modelcontextprotocol/sdk/server/mcp.js";
+import { analyzeFranchise } from "../bindings.js";
+import { FranchiseSchema } from "../schemas/franchise.js";
+import { wrapResponse, coerceNumbers } from "../formatters/response.js";
+
+export function registerFranchiseTools(server: McpServer) {
+  server.tool(
+    "franchise_economics",
+    "Franchise and license agreement economics for both parties. Franchisee: one unit over one term with build-out and initial fee, ramping sales, four-wall margin, royalty, marketing fund and technology fees, and refresh capex cycles, giving unit IRR, NPV, payback, cash-on-cash, fee load and the breakeven royalty rate at the franchisee hurdle. Franchisor: a development schedule rolled across the territory (cohort ramp, closures, renewals, capacity cap) netting initial, royalty, technology and renewal fees against opening support, field support and overhead, giving system IRR and NPV and the franchisor's share of unit value. Marketing fund is a pass-through. Includes a royalty rate sensitivity of both parties' IRR and NPV.",
+    FranchiseSchema.shape,
+    async (params) => {
+      const validated = FranchiseSchema.parse(coerceNumbers(params));
+      const result = analyzeFranchise(validated);
+      return wrapResponse(result);
+    }
+  );
+}
@@ -5,6 +5,8 @@ import {
   analyzeManagerSelection,
   calculateSecondariesPricing,
   analyzeFofPortfolio,
+  analyzeStyle,
+  forecastCashFlows,
 } from "../bindings.js";
 import {
   JCurveSchema,
@@ -12,6 +14,8 @@ import {
   ManagerSelectionSchema,
   SecondariesPricingSchema,
   FofPortfolioSchema,
+  StyleAnalysisSchema,
+  CashFlowForecastSchema,
 } from "../schemas/fund_of_funds.js";
 import { wrapResponse, coerceNumbers } from "../formatters/response.js";
 
@@ -22,7 +26,7 @@ export function registerFundOfFundsTools(server: McpServer) {
     JCurveSchema.shape,
     async (params) => {
       const validated = JCurveSchema.parse(coerceNumbers(params));
-      const result = calculateJCurve(JSON.stringify(validated));
+      const result = calculateJCurve(validated);
       return wrapResponse(result);
     }
   );
@@ -33,7 +37,7 @@ export function registerFundOfFundsTools(server: McpServer) {
     CommitmentPacingSchema.shape,
     async (params) => {
       const validated = CommitmentPacingSchema.parse(coerceNumbers(params));
-      const result = calculateCommitmentPacing(JSON.stringify(validated));
+      const result = calculateCommitmentPacing(validated);
       return wrapResponse(result);
     }
   );
@@ -44,7 +48,7 @@ export function registerFundOfFundsTools(server: McpServer) {
     ManagerSelectionSchema.shape,
     async (params) => {
       const validated = ManagerSelectionSchema.parse(coerceNumbers(params));
-      const result = analyzeManagerSelection(JSON.stringify(validated));
+      const result = analyzeManagerSelection(validated);
       return wrapResponse(result);
     }
   );
@@ -55,7 +59,7 @@ export function registerFundOfFundsTools(server: McpServer) {
     SecondariesPricingSchema.shape,
     async (params) => {
       const validated = SecondariesPricingSchema.parse(coerceNumbers(params));
-      const result = calculateSecondariesPricing(JSON.stringify(validated));
+      const result = calculateSecondariesPricing(validated);
       return wrapResponse(result);
     }
   );
@@ -66,7 +70,29 @@ export function registerFundOfFundsTools(server: McpServer) {
     FofPortfolioSchema.shape,
     async (params) => {
       const validated = FofPortfolioSchema.parse(coerceNumbers(params));
-      const result = analyzeFofPortfolio(JSON.stringify(validated));
+      const result = analyzeFofPortfolio(validated);
+      return wrapResponse(result);
+    }
+  );
+
+  server.tool(
+    "style_analysis",
+    "Returns-based style analysis (Sharpe): long-only constrained regression on style indices, R² decomposition into style vs selection, rolling-window weights, style drift detection",
+    StyleAnalysisSchema.shape,
+    async (params) => {
+      const validated = StyleAnalysisSchema.parse(coerceNumbers(params));
+      const result = analyzeStyle(validated);
+      return wrapResponse(result);
+    }
+  );
+
+  server.tool(
+    "cash_flow_forecast",
+    "Private markets cash flow forecast (Takahashi-Alexander): capital calls, distributions and NAV by commitment, strategy and year from fund age, with optional stochastic growth bands",
+    CashFlowForecastSchema.shape,
+    async (params) => {
+      const validated = CashFlowForecastSchema.parse(coerceNumbers(params));
+      const result = forecastCashFlows(validated);
       return wrapResponse(result);
     }
   );
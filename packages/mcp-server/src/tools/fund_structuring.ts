@@ -0,0 +1,34 @@
+import { McpServer } from "@modelcontextprotocol/sdk/server/mcp.js";
+import {
+  simulateRedemptionStress,
+  simulateSemiLiquidStress,
+} from "../bindings.js";
+import {
+  RedemptionStressSchema,
+  SemiLiquidStressSchema,
+} from "../schemas/fund_structuring.js";
+import { wrapResponse, coerceNumbers } from "../formatters/response.js";
+
+export function registerFundStructuringTools(server: McpServer) {
+  server.tool(
+    "redemption_stress",
+    "Redemption stress test against a fund's liquidity terms: rolls investor redemption requests through dealing dates after the notice period, applying fund-level and investor-level gates, hard or soft lock-ups with early redemption fees, side pockets released on realisation and payment delays. Returns per-investor payout schedules with time to full liquidity, per-dealing-date requested vs paid amounts and gate utilisation, and warnings for requests still unpaid at the horizon",
+    RedemptionStressSchema.shape,
+    async (params) => {
+      const validated = RedemptionStressSchema.parse(coerceNumbers(params));
+      const result = simulateRedemptionStress(validated);
+      return wrapResponse(result);
+    }
+  );
+
+  server.tool(
+    "semi_liquid_stress",
+    "Month-by-month redemption stress for a semi-liquid fund (open-end real estate, private credit, evergreen): sells assets each month, most liquid first and within each bucket's sale capacity, at fire-sale discounts that fall on NAV per unit, and pays queued redemption waves on dealing dates after the notice period from cash above the minimum buffer and then the credit facility, up to an optional fund gate. Returns the asset liquidity profile at 1/3/6/12 months, monthly cash, facility draws, sales and losses, NAV per unit, whether and when the gate triggered, and total fire-sale loss borne by remaining investors",
+    SemiLiquidStressSchema.shape,
+    async (params) => {
+      const validated = SemiLiquidStressSchema.parse(coerceNumbers(params));
+      const result = simulateSemiLiquidStress(validated);
+      return wrapResponse(result);
+    }
+  );
+}
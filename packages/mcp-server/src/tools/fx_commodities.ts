@@ -20,7 +20,7 @@ export function registerFxCommoditiesTools(server: McpServer) {
     FxForwardSchema.shape,
     async (params) => {
       const validated = FxForwardSchema.parse(coerceNumbers(params));
-      const result = priceFxForward(JSON.stringify(validated));
+      const result = priceFxForward(validated);
       return wrapResponse(result);
     }
   );
@@ -31,7 +31,7 @@ export function registerFxCommoditiesTools(server: McpServer) {
     CrossRateSchema.shape,
     async (params) => {
       const validated = CrossRateSchema.parse(coerceNumbers(params));
-      const result = calculateCrossRate(JSON.stringify(validated));
+      const result = calculateCrossRate(validated);
       return wrapResponse(result);
     }
   );
@@ -42,7 +42,7 @@ export function registerFxCommoditiesTools(server: McpServer) {
     CommodityForwardSchema.shape,
     async (params) => {
       const validated = CommodityForwardSchema.parse(coerceNumbers(params));
-      const result = priceCommodityForward(JSON.stringify(validated));
+      const result = priceCommodityForward(validated);
       return wrapResponse(result);
     }
   );
@@ -53,7 +53,7 @@ export function registerFxCommoditiesTools(server: McpServer) {
     CommodityCurveSchema.shape,
     async (params) => {
       const validated = CommodityCurveSchema.parse(coerceNumbers(params));
-      const result = analyzeCommodityCurve(JSON.stringify(validated));
+      const result = analyzeCommodityCurve(validated);
       return wrapResponse(result);
     }
   );
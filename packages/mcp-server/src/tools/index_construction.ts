@@ -22,7 +22,7 @@ export function registerIndexConstructionTools(server: McpServer) {
     WeightingSchema.shape,
     async (params) => {
       const validated = WeightingSchema.parse(coerceNumbers(params));
-      const result = calculateWeighting(JSON.stringify(validated));
+      const result = calculateWeighting(validated);
       return wrapResponse(result);
     }
   );
@@ -33,7 +33,7 @@ export function registerIndexConstructionTools(server: McpServer) {
     RebalancingSchema.shape,
     async (params) => {
       const validated = RebalancingSchema.parse(coerceNumbers(params));
-      const result = calculateRebalancing(JSON.stringify(validated));
+      const result = calculateRebalancing(validated);
       return wrapResponse(result);
     }
   );
@@ -44,7 +44,7 @@ export function registerIndexConstructionTools(server: McpServer) {
     TrackingErrorSchema.shape,
     async (params) => {
       const validated = TrackingErrorSchema.parse(coerceNumbers(params));
-      const result = calculateTrackingError(JSON.stringify(validated));
+      const result = calculateTrackingError(validated);
       return wrapResponse(result);
     }
   );
@@ -55,7 +55,7 @@ export function registerIndexConstructionTools(server: McpServer) {
     SmartBetaSchema.shape,
     async (params) => {
       const validated = SmartBetaSchema.parse(coerceNumbers(params));
-      const result = calculateSmartBeta(JSON.stringify(validated));
+      const result = calculateSmartBeta(validated);
       return wrapResponse(result);
     }
   );
@@ -66,7 +66,7 @@ export function registerIndexConstructionTools(server: McpServer) {
     ReconstitutionSchema.shape,
     async (params) => {
       const validated = ReconstitutionSchema.parse(coerceNumbers(params));
-      const result = calculateReconstitution(JSON.stringify(validated));
+      const result = calculateReconstitution(validated);
       return wrapResponse(result);
     }
   );
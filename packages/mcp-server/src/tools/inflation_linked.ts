@@ -16,7 +16,7 @@ export function registerInflationLinkedTools(server: McpServer) {
     TipsAnalyticsSchema.shape,
     async (params) => {
       const validated = TipsAnalyticsSchema.parse(coerceNumbers(params));
-      const result = analyzeTips(JSON.stringify(validated));
+      const result = analyzeTips(validated);
       return wrapResponse(result);
     }
   );
@@ -27,7 +27,7 @@ export function registerInflationLinkedTools(server: McpServer) {
     InflationDerivativeSchema.shape,
     async (params) => {
       const validated = InflationDerivativeSchema.parse(coerceNumbers(params));
-      const result = analyzeInflationDerivatives(JSON.stringify(validated));
+      const result = analyzeInflationDerivatives(validated);
       return wrapResponse(result);
     }
   );
@@ -16,7 +16,7 @@ export function registerInfrastructureTools(server: McpServer) {
     PppModelSchema.shape,
     async (params) => {
       const validated = PppModelSchema.parse(coerceNumbers(params));
-      const result = modelPpp(JSON.stringify(validated));
+      const result = modelPpp(validated);
       return wrapResponse(result);
     }
   );
@@ -27,7 +27,7 @@ export function registerInfrastructureTools(server: McpServer) {
     ConcessionSchema.shape,
     async (params) => {
       const validated = ConcessionSchema.parse(coerceNumbers(params));
-      const result = valueConcession(JSON.stringify(validated));
+      const result = valueConcession(validated);
       return wrapResponse(result);
     }
   );
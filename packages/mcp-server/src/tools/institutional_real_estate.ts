@@ -24,7 +24,7 @@ export function registerInstitutionalRealEstateTools(server: McpServer) {
     RentRollSchema.shape,
     async (params) => {
       const validated = RentRollSchema.parse(coerceNumbers(params));
-      const result = tenantSchedule(JSON.stringify(validated));
+      const result = tenantSchedule(validated);
       return wrapResponse(result);
     }
   );
@@ -35,7 +35,7 @@ export function registerInstitutionalRealEstateTools(server: McpServer) {
     ComparableSalesSchema.shape,
     async (params) => {
       const validated = ComparableSalesSchema.parse(coerceNumbers(params));
-      const result = compAdjustmentGrid(JSON.stringify(validated));
+      const result = compAdjustmentGrid(validated);
       return wrapResponse(result);
     }
   );
@@ -46,7 +46,7 @@ export function registerInstitutionalRealEstateTools(server: McpServer) {
     HbuAnalysisSchema.shape,
     async (params) => {
       const validated = HbuAnalysisSchema.parse(coerceNumbers(params));
-      const result = hbuAnalysis(JSON.stringify(validated));
+      const result = hbuAnalysis(validated);
       return wrapResponse(result);
     }
   );
@@ -57,7 +57,7 @@ export function registerInstitutionalRealEstateTools(server: McpServer) {
     ReplacementCostSchema.shape,
     async (params) => {
       const validated = ReplacementCostSchema.parse(coerceNumbers(params));
-      const result = costApproach(JSON.stringify(validated));
+      const result = costApproach(validated);
       return wrapResponse(result);
     }
   );
@@ -68,7 +68,7 @@ export function registerInstitutionalRealEstateTools(server: McpServer) {
     BenchmarkSchema.shape,
     async (params) => {
       const validated = BenchmarkSchema.parse(coerceNumbers(params));
-      const result = ncreifAttribution(JSON.stringify(validated));
+      const result = ncreifAttribution(validated);
       return wrapResponse(result);
     }
   );
@@ -79,7 +79,7 @@ export function registerInstitutionalRealEstateTools(server: McpServer) {
     AcquisitionSchema.shape,
     async (params) => {
       const validated = AcquisitionSchema.parse(coerceNumbers(params));
-      const result = acquisitionModel(JSON.stringify(validated));
+      const result = acquisitionModel(validated);
       return wrapResponse(result);
     }
   );
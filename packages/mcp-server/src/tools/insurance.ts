@@ -20,7 +20,7 @@ export function registerInsuranceTools(server: McpServer) {
     ReservingSchema.shape,
     async (params) => {
       const validated = ReservingSchema.parse(coerceNumbers(params));
-      const result = estimateReserves(JSON.stringify(validated));
+      const result = estimateReserves(validated);
       return wrapResponse(result);
     }
   );
@@ -31,7 +31,7 @@ export function registerInsuranceTools(server: McpServer) {
     PremiumPricingSchema.shape,
     async (params) => {
       const validated = PremiumPricingSchema.parse(coerceNumbers(params));
-      const result = pricePremium(JSON.stringify(validated));
+      const result = pricePremium(validated);
       return wrapResponse(result);
     }
   );
@@ -42,7 +42,7 @@ export function registerInsuranceTools(server: McpServer) {
     CombinedRatioSchema.shape,
     async (params) => {
       const validated = CombinedRatioSchema.parse(coerceNumbers(params));
-      const result = analyzeCombinedRatio(JSON.stringify(validated));
+      const result = analyzeCombinedRatio(validated);
       return wrapResponse(result);
     }
   );
@@ -53,7 +53,7 @@ export function registerInsuranceTools(server: McpServer) {
     ScrSchema.shape,
     async (params) => {
       const validated = ScrSchema.parse(coerceNumbers(params));
-      const result = calculateScr(JSON.stringify(validated));
+      const result = calculateScr(validated);
       return wrapResponse(result);
     }
   );
@@ -16,7 +16,7 @@ export function registerInterestRateModelsTools(server: McpServer) {
     ShortRateSchema.shape,
     async (params) => {
       const validated = ShortRateSchema.parse(coerceNumbers(params));
-      const result = analyzeShortRate(JSON.stringify(validated));
+      const result = analyzeShortRate(validated);
       return wrapResponse(result);
     }
   );
@@ -27,7 +27,7 @@ export function registerInterestRateModelsTools(server: McpServer) {
     TermStructureSchema.shape,
     async (params) => {
       const validated = TermStructureSchema.parse(coerceNumbers(params));
-      const result = fitTermStructure(JSON.stringify(validated));
+      const result = fitTermStructure(validated);
       return wrapResponse(result);
     }
   );
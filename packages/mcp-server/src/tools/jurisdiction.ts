@@ -1,21 +1,25 @@
 import { McpServer } from "@modelcontextprotocol/sdk/server/mcp.js";
 import {
   calculateFundFees,
+  comparePerformanceFeeVariants,
   reconcileAccounting,
   calculateWht,
   calculateNav,
   calculateGpEconomics,
   calculateInvestorNetReturns,
   screenUbtiEci,
+  buildCapitalAccountStatements,
 } from "../bindings.js";
 import {
   FundFeeSchema,
+  PerformanceFeeSchema,
   ReconciliationSchema,
   WhtSchema,
   NavSchema,
   GpEconomicsSchema,
   InvestorNetReturnsSchema,
   UbtiScreeningSchema,
+  CapitalAccountSchema,
 } from "../schemas/jurisdiction.js";
 import { wrapResponse, coerceNumbers } from "../formatters/response.js";
 
@@ -26,7 +30,18 @@ export function registerJurisdictionTools(server: McpServer) {
     FundFeeSchema.shape,
     async (params) => {
       const validated = FundFeeSchema.parse(coerceNumbers(params));
-      const result = calculateFundFees(JSON.stringify(validated));
+      const result = calculateFundFees(validated);
+      return wrapResponse(result);
+    }
+  );
+
+  server.tool(
+    "performance_fee_variants",
+    "Compare performance fee structures on the same gross return path: hard vs soft hurdles, fixed or benchmark-plus-spread hurdles, high-water mark with standard or proportional loss carryforward, and quarterly, annual or on-redemption crystallisation. Reports fees, crystallisations, LP net returns and fee drag per variant.",
+    PerformanceFeeSchema.shape,
+    async (params) => {
+      const validated = PerformanceFeeSchema.parse(coerceNumbers(params));
+      const result = comparePerformanceFeeVariants(validated);
       return wrapResponse(result);
     }
   );
@@ -37,7 +52,7 @@ export function registerJurisdictionTools(server: McpServer) {
     ReconciliationSchema.shape,
     async (params) => {
       const validated = ReconciliationSchema.parse(coerceNumbers(params));
-      const result = reconcileAccounting(JSON.stringify(validated));
+      const result = reconcileAccounting(validated);
       return wrapResponse(result);
     }
   );
@@ -48,7 +63,7 @@ export function registerJurisdictionTools(server: McpServer) {
     WhtSchema.shape,
     async (params) => {
       const validated = WhtSchema.parse(coerceNumbers(params));
-      const result = calculateWht(JSON.stringify(validated));
+      const result = calculateWht(validated);
       return wrapResponse(result);
     }
   );
@@ -59,7 +74,7 @@ export function registerJurisdictionTools(server: McpServer) {
     NavSchema.shape,
     async (params) => {
       const validated = NavSchema.parse(coerceNumbers(params));
-      const result = calculateNav(JSON.stringify(validated));
+      const result = calculateNav(validated);
       return wrapResponse(result);
     }
   );
@@ -70,7 +85,7 @@ export function registerJurisdictionTools(server: McpServer) {
     GpEconomicsSchema.shape,
     async (params) => {
       const validated = GpEconomicsSchema.parse(coerceNumbers(params));
-      const result = calculateGpEconomics(JSON.stringify(validated));
+      const result = calculateGpEconomics(validated);
       return wrapResponse(result);
     }
   );
@@ -81,7 +96,7 @@ export function registerJurisdictionTools(server: McpServer) {
     InvestorNetReturnsSchema.shape,
     async (params) => {
       const validated = InvestorNetReturnsSchema.parse(coerceNumbers(params));
-      const result = calculateInvestorNetReturns(JSON.stringify(validated));
+      const result = calculateInvestorNetReturns(validated);
       return wrapResponse(result);
     }
   );
@@ -92,7 +107,18 @@ export function registerJurisdictionTools(server: McpServer) {
     UbtiScreeningSchema.shape,
     async (params) => {
       const validated = UbtiScreeningSchema.parse(coerceNumbers(params));
-      const result = screenUbtiEci(JSON.stringify(validated));
+      const result = screenUbtiEci(validated);
+      return wrapResponse(result);
+    }
+  );
+
+  server.tool(
+    "capital_account_statements",
+    "Per-partner capital account statements (ILPA-style) for a fund: allocates capital calls, management fees, distributions by tax character, realised and unrealised gains, fund expenses and carried interest pro rata to commitments each period, rolling opening to closing balances, with unfunded commitment, DPI/RVPI/TVPI per partner and a fund-level summary",
+    CapitalAccountSchema.shape,
+    async (params) => {
+      const validated = CapitalAccountSchema.parse(coerceNumbers(params));
+      const result = buildCapitalAccountStatements(validated);
       return wrapResponse(result);
     }
   );
@@ -16,7 +16,7 @@ export function registerLeaseAccountingTools(server: McpServer) {
     LeaseClassificationSchema.shape,
     async (params) => {
       const validated = LeaseClassificationSchema.parse(coerceNumbers(params));
-      const result = classifyLease(JSON.stringify(validated));
+      const result = classifyLease(validated);
       return wrapResponse(result);
     }
   );
@@ -27,7 +27,7 @@ export function registerLeaseAccountingTools(server: McpServer) {
     SaleLeasebackSchema.shape,
     async (params) => {
       const validated = SaleLeasebackSchema.parse(coerceNumbers(params));
-      const result = analyzeSaleLeaseback(JSON.stringify(validated));
+      const result = analyzeSaleLeaseback(validated);
       return wrapResponse(result);
     }
   );
@@ -1,16 +1,98 @@
 import { McpServer } from "@modelcontextprotocol/sdk/server/mcp.js";
-import { analyzeMerger } from "../bindings.js";
-import { MergerSchema } from "../schemas/ma.js";
+import {
+  allocatePurchasePrice,
+  analyzeMerger,
+  analyzeMultiTargetMerger,
+  analyzeMergerArb,
+  analyzeFinancing,
+  analyzePurchasePriceMechanism,
+  valueEarnout,
+} from "../bindings.js";
+import {
+  MergerSchema,
+  MultiTargetMergerSchema,
+  PpaSchema,
+  MergerArbSchema,
+  AcquisitionFinancingSchema,
+  PurchasePriceMechanismSchema,
+  EarnoutSchema,
+} from "../schemas/ma.js";
 import { wrapResponse, coerceNumbers } from "../formatters/response.js";
 
 export function registerMATools(server: McpServer) {
   server.tool(
     "merger_model",
-    "Analyze a merger for EPS accretion/dilution. Supports all-cash, all-stock, and mixed consideration. Calculates pro-forma EPS, premium analysis, exchange ratios, synergy impact, and breakeven synergies needed for EPS neutrality.",
+    "Analyze a merger for EPS accretion/dilution. Supports all-cash, all-stock, and mixed consideration. Calculates pro-forma EPS, premium analysis, exchange ratios, synergy impact, and breakeven synergies needed for EPS neutrality. Optional purchase_price_allocation derives goodwill from fair-value step-ups and charges the after-tax incremental D&A against earnings. Optional synergy_schedule adds per-category synergy phasing, integration costs, net synergy NPV versus premium paid, and accretion/dilution by year. Optional credit_profile adds standalone vs pro-forma net leverage, interest coverage and implied rating, tracked by year as synergies ramp up against a leverage limit.",
     MergerSchema.shape,
     async (params) => {
       const validated = MergerSchema.parse(coerceNumbers(params));
-      const result = analyzeMerger(JSON.stringify(validated));
+      const result = analyzeMerger(validated);
+      return wrapResponse(result);
+    }
+  );
+
+  server.tool(
+    "multi_target_merger",
+    "Staged, multi-target acquisition programme accretion/dilution. Each target can be bought in several stages with its own cash/stock/mixed consideration, an exchange-ratio collar (fixed ratio or fixed value, with walk-away price) and a contingent value right. Returns per-stage consideration and share issuance, CVR fair value, and a multi-year pro-forma EPS schedule with stake-weighted earnings, synergies from the year of control, and financing cost.",
+    MultiTargetMergerSchema.shape,
+    async (params) => {
+      const validated = MultiTargetMergerSchema.parse(coerceNumbers(params));
+      const result = analyzeMultiTargetMerger(validated);
+      return wrapResponse(result);
+    }
+  );
+
+  server.tool(
+    "purchase_price_allocation",
+    "Purchase price allocation under the acquisition method (ASC 805 / IFRS 3). Takes fair-value step-ups of identifiable intangibles, PP&E and inventory and computes the deferred tax liability, identifiable net assets, goodwill (or bargain purchase gain, with full goodwill for NCI and step acquisitions), and a year-by-year schedule of incremental depreciation, amortisation, inventory step-up release, after-tax earnings impact, DTL unwind and cash tax savings for asset deals.",
+    PpaSchema.shape,
+    async (params) => {
+      const validated = PpaSchema.parse(coerceNumbers(params));
+      const result = allocatePurchasePrice(validated);
+      return wrapResponse(result);
+    }
+  );
+
+  server.tool(
+    "merger_arbitrage",
+    "Merger arbitrage: gross and annualised deal spread for cash, stock and mixed consideration, market-implied completion probability, expected return and fractional Kelly position sizing",
+    MergerArbSchema.shape,
+    async (params) => {
+      const validated = MergerArbSchema.parse(coerceNumbers(params));
+      const result = analyzeMergerArb(validated);
+      return wrapResponse(result);
+    }
+  );
+
+  server.tool(
+    "acquisition_financing",
+    "Acquisition financing: sources and uses with fees and OID, funding mix, closing leverage, pro-forma debt paydown with cash sweep and revolver, leverage and coverage profile, covenant headroom by year",
+    AcquisitionFinancingSchema.shape,
+    async (params) => {
+      const validated = AcquisitionFinancingSchema.parse(coerceNumbers(params));
+      const result = analyzeFinancing(validated);
+      return wrapResponse(result);
+    }
+  );
+
+  server.tool(
+    "purchase_price_mechanism",
+    "Locked box vs completion accounts: expected price to the seller under each mechanism, locked-box ticker and leakage, simulated working capital true-up with de minimis, tipping or deductible threshold and cap, process costs and price certainty",
+    PurchasePriceMechanismSchema.shape,
+    async (params) => {
+      const validated = PurchasePriceMechanismSchema.parse(coerceNumbers(params));
+      const result = analyzePurchasePriceMechanism(validated);
+      return wrapResponse(result);
+    }
+  );
+
+  server.tool(
+    "earnout_valuation",
+    "Earn-out (contingent consideration) valuation: Monte Carlo of a Revenue or EBITDA metric under the risk-neutral drift, binary or linear payoffs per period with catch-up and aggregate cap, discounting at the buyer's credit-adjusted rate, and a scenario payout table",
+    EarnoutSchema.shape,
+    async (params) => {
+      const validated = EarnoutSchema.parse(coerceNumbers(params));
+      const result = valueEarnout(validated);
       return wrapResponse(result);
     }
   );
@@ -16,7 +16,7 @@ export function registerMacroEconomicsTools(server: McpServer) {
     MonetaryPolicySchema.shape,
     async (params) => {
       const validated = MonetaryPolicySchema.parse(coerceNumbers(params));
-      const result = analyzeMonetaryPolicy(JSON.stringify(validated));
+      const result = analyzeMonetaryPolicy(validated);
       return wrapResponse(result);
     }
   );
@@ -27,7 +27,7 @@ export function registerMacroEconomicsTools(server: McpServer) {
     InternationalSchema.shape,
     async (params) => {
       const validated = InternationalSchema.parse(coerceNumbers(params));
-      const result = analyzeInternational(JSON.stringify(validated));
+      const result = analyzeInternational(validated);
       return wrapResponse(result);
     }
   );
@@ -16,7 +16,7 @@ export function registerMarketMicrostructureTools(server: McpServer) {
     SpreadAnalysisSchema.shape,
     async (params) => {
       const validated = SpreadAnalysisSchema.parse(coerceNumbers(params));
-      const result = analyzeSpreads(JSON.stringify(validated));
+      const result = analyzeSpreads(validated);
       return wrapResponse(result);
     }
   );
@@ -27,7 +27,7 @@ export function registerMarketMicrostructureTools(server: McpServer) {
     OptimalExecutionSchema.shape,
     async (params) => {
       const validated = OptimalExecutionSchema.parse(coerceNumbers(params));
-      const result = optimizeExecution(JSON.stringify(validated));
+      const result = optimizeExecution(validated);
       return wrapResponse(result);
     }
   );
@@ -10,7 +10,7 @@ export function registerMonteCarloTools(server: McpServer) {
     MonteCarloSchema.shape,
     async (params) => {
       const validated = MonteCarloSchema.parse(coerceNumbers(params));
-      const result = runMonteCarlo(JSON.stringify(validated));
+      const result = runMonteCarlo(validated);
       return wrapResponse(result);
     }
   );
@@ -21,7 +21,7 @@ export function registerMonteCarloTools(server: McpServer) {
     McDcfSchema.shape,
     async (params) => {
       const validated = McDcfSchema.parse(coerceNumbers(params));
-      const result = runMcDcf(JSON.stringify(validated));
+      const result = runMcDcf(validated);
       return wrapResponse(result);
     }
   );
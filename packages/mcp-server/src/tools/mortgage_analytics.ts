@@ -16,7 +16,7 @@ export function registerMortgageAnalyticsTools(server: McpServer) {
     PrepaymentSchema.shape,
     async (params) => {
       const validated = PrepaymentSchema.parse(coerceNumbers(params));
-      const result = analyzePrepayment(JSON.stringify(validated));
+      const result = analyzePrepayment(validated);
       return wrapResponse(result);
     }
   );
@@ -27,7 +27,7 @@ export function registerMortgageAnalyticsTools(server: McpServer) {
     MbsAnalyticsSchema.shape,
     async (params) => {
       const validated = MbsAnalyticsSchema.parse(coerceNumbers(params));
-      const result = analyzeMbs(JSON.stringify(validated));
+      const result = analyzeMbs(validated);
       return wrapResponse(result);
     }
   );
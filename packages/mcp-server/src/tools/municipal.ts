@@ -16,7 +16,7 @@ export function registerMunicipalTools(server: McpServer) {
     MuniBondSchema.shape,
     async (params) => {
       const validated = MuniBondSchema.parse(coerceNumbers(params));
-      const result = priceMuniBond(JSON.stringify(validated));
+      const result = priceMuniBond(validated);
       return wrapResponse(result);
     }
   );
@@ -27,7 +27,7 @@ export function registerMunicipalTools(server: McpServer) {
     MuniAnalysisSchema.shape,
     async (params) => {
       const validated = MuniAnalysisSchema.parse(coerceNumbers(params));
-      const result = analyzeMunicipal(JSON.stringify(validated));
+      const result = analyzeMunicipal(validated);
       return wrapResponse(result);
     }
   );
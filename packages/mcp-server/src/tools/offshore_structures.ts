@@ -16,7 +16,7 @@ export function registerOffshoreStructuresTools(server: McpServer) {
     CaymanFundSchema.shape,
     async (params) => {
       const validated = CaymanFundSchema.parse(coerceNumbers(params));
-      const result = analyzeCaymanStructure(JSON.stringify(validated));
+      const result = analyzeCaymanStructure(validated);
       return wrapResponse(result);
     }
   );
@@ -27,7 +27,7 @@ export function registerOffshoreStructuresTools(server: McpServer) {
     LuxFundSchema.shape,
     async (params) => {
       const validated = LuxFundSchema.parse(coerceNumbers(params));
-      const result = analyzeLuxStructure(JSON.stringify(validated));
+      const result = analyzeLuxStructure(validated);
       return wrapResponse(result);
     }
   );
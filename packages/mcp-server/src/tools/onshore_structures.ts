@@ -16,7 +16,7 @@ export function registerOnshoreStructuresTools(server: McpServer) {
     UsFundSchema.shape,
     async (params) => {
       const validated = UsFundSchema.parse(coerceNumbers(params));
-      const result = analyzeUsFundStructure(JSON.stringify(validated));
+      const result = analyzeUsFundStructure(validated);
       return wrapResponse(result);
     }
   );
@@ -27,7 +27,7 @@ export function registerOnshoreStructuresTools(server: McpServer) {
     UkEuFundSchema.shape,
     async (params) => {
       const validated = UkEuFundSchema.parse(coerceNumbers(params));
-      const result = analyzeUkEuFund(JSON.stringify(validated));
+      const result = analyzeUkEuFund(validated);
       return wrapResponse(result);
     }
   );
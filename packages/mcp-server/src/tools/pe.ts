@@ -5,6 +5,9 @@ import {
   sourcesAndUses,
   buildLbo,
   calculateWaterfall,
+  estimateInterimNav,
+  evaluateCoInvestment,
+  projectPacing,
   altmanZscore,
 } from "../bindings.js";
 import {
@@ -13,6 +16,9 @@ import {
   SourcesUsesSchema,
   LboSchema,
   WaterfallSchema,
+  InterimNavSchema,
+  CoInvestmentSchema,
+  PacingSchema,
   AltmanSchema,
 } from "../schemas/pe.js";
 import { wrapResponse, coerceNumbers } from "../formatters/response.js";
@@ -24,7 +30,7 @@ export function registerPETools(server: McpServer) {
     ReturnsSchema.shape,
     async (params) => {
       const validated = ReturnsSchema.parse(coerceNumbers(params));
-      const result = calculateReturns(JSON.stringify(validated));
+      const result = calculateReturns(validated);
       return wrapResponse(result);
     }
   );
@@ -35,7 +41,7 @@ export function registerPETools(server: McpServer) {
     DebtScheduleSchema.shape,
     async (params) => {
       const validated = DebtScheduleSchema.parse(coerceNumbers(params));
-      const result = buildDebtSchedule(JSON.stringify(validated));
+      const result = buildDebtSchedule(validated);
       return wrapResponse(result);
     }
   );
@@ -46,29 +52,62 @@ export function registerPETools(server: McpServer) {
     SourcesUsesSchema.shape,
     async (params) => {
       const validated = SourcesUsesSchema.parse(coerceNumbers(params));
-      const result = sourcesAndUses(JSON.stringify(validated));
+      const result = sourcesAndUses(validated);
       return wrapResponse(result);
     }
   );
 
   server.tool(
     "lbo_model",
-    "Build a full leveraged buyout model with multi-tranche debt, cash sweep, year-by-year projections, and exit returns (IRR, MOIC). Includes sources & uses, debt schedules, and credit metrics at entry/exit.",
+    "Build a full leveraged buyout model with multi-tranche debt, a revolving credit facility with commitment fees, leverage-tiered cash sweeps, voluntary prepayments, PIK toggle tranches, year-by-year projections, and exit returns (IRR, MOIC). Includes sources & uses, debt schedules, and credit metrics at entry/exit.",
     LboSchema.shape,
     async (params) => {
       const validated = LboSchema.parse(coerceNumbers(params));
-      const result = buildLbo(JSON.stringify(validated));
+      const result = buildLbo(validated);
       return wrapResponse(result);
     }
   );
 
   server.tool(
     "waterfall_calculator",
-    "Calculate GP/LP distribution waterfall with return of capital, preferred return (hurdle), GP catch-up, and carried interest tiers. Supports European and American waterfall structures.",
+    "Calculate GP/LP distribution waterfall with return of capital, preferred return (hurdle), GP catch-up, and carried interest tiers. Catch-up shares below 100% are supported. European (whole-fund) or American (deal-by-deal) carry with after-tax clawback, and management fee offsets from portfolio company fees.",
     WaterfallSchema.shape,
     async (params) => {
       const validated = WaterfallSchema.parse(coerceNumbers(params));
-      const result = calculateWaterfall(JSON.stringify(validated));
+      const result = calculateWaterfall(validated);
+      return wrapResponse(result);
+    }
+  );
+
+  server.tool(
+    "interim_nav",
+    "Estimate an interim fund NAV between GP reporting dates. Rolls each private mark forward by beta-adjusted public comp returns, applies known events (new rounds, exits, impairments) in date order, and translates at report and estimate FX rates. Returns the adjusted NAV with a market / events / FX / flows contribution bridge per holding.",
+    InterimNavSchema.shape,
+    async (params) => {
+      const validated = InterimNavSchema.parse(coerceNumbers(params));
+      const result = estimateInterimNav(validated);
+      return wrapResponse(result);
+    }
+  );
+
+  server.tool(
+    "co_investment",
+    "Evaluate a co-investment opportunity for an LP. Compares no-fee/no-carry co-invest economics with the same exposure through the fund (fee savings, net IRR and MOIC uplift), measures single-asset, sector and geography concentration and HHI before and after the deal, optionally blends return series to show portfolio volatility and CVaR impact, and scores the deal against policy limits with a Proceed / Review / Decline decision.",
+    CoInvestmentSchema.shape,
+    async (params) => {
+      const validated = CoInvestmentSchema.parse(coerceNumbers(params));
+      const result = evaluateCoInvestment(validated);
+      return wrapResponse(result);
+    }
+  );
+
+  server.tool(
+    "pacing_projection",
+    "Project capital calls, distributions and NAV by vintage for existing and planned private markets commitments with the Takahashi-Alexander (Yale) model. Reports cumulative net cash flow, peak funding need, breakeven year and the first year net calls exceed a liquidity reserve. With an allocation target, tracks allocation and over-commitment by year and solves the level annual commitment that reaches the target by the end of the horizon.",
+    PacingSchema.shape,
+    async (params) => {
+      const validated = PacingSchema.parse(coerceNumbers(params));
+      const result = projectPacing(validated);
       return wrapResponse(result);
     }
   );
@@ -79,7 +118,7 @@ export function registerPETools(server: McpServer) {
     AltmanSchema.shape,
     async (params) => {
       const validated = AltmanSchema.parse(coerceNumbers(params));
-      const result = altmanZscore(JSON.stringify(validated));
+      const result = altmanZscore(validated);
       return wrapResponse(result);
     }
   );
@@ -16,7 +16,7 @@ export function registerPensionTools(server: McpServer) {
     PensionFundingSchema.shape,
     async (params) => {
       const validated = PensionFundingSchema.parse(coerceNumbers(params));
-      const result = analyzePensionFunding(JSON.stringify(validated));
+      const result = analyzePensionFunding(validated);
       return wrapResponse(result);
     }
   );
@@ -27,7 +27,7 @@ export function registerPensionTools(server: McpServer) {
     LdiStrategySchema.shape,
     async (params) => {
       const validated = LdiStrategySchema.parse(coerceNumbers(params));
-      const result = designLdiStrategy(JSON.stringify(validated));
+      const result = designLdiStrategy(validated);
       return wrapResponse(result);
     }
   );
@@ -16,7 +16,7 @@ export function registerPerformanceAttributionTools(server: McpServer) {
     BrinsonSchema.shape,
     async (params) => {
       const validated = BrinsonSchema.parse(coerceNumbers(params));
-      const result = brinsonAttribution(JSON.stringify(validated));
+      const result = brinsonAttribution(validated);
       return wrapResponse(result);
     }
   );
@@ -27,7 +27,7 @@ export function registerPerformanceAttributionTools(server: McpServer) {
     FactorAttributionSchema.shape,
     async (params) => {
       const validated = FactorAttributionSchema.parse(coerceNumbers(params));
-      const result = factorAttribution(JSON.stringify(validated));
+      const result = factorAttribution(validated);
       return wrapResponse(result);
     }
   );
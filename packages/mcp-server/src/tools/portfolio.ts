@@ -3,11 +3,21 @@ import {
   riskAdjustedReturns,
   riskMetrics,
   kellySizing,
+  blendedBenchmark,
+  drawdownAnalysis,
+  performanceRatios,
+  valuePortfolio,
+  calculateRevaluationVar,
 } from "../bindings.js";
 import {
   RiskAdjustedSchema,
   RiskMetricsSchema,
   KellySchema,
+  BlendedBenchmarkSchema,
+  DrawdownSchema,
+  PerformanceRatiosSchema,
+  PortfolioValuationSchema,
+  RevaluationVarSchema,
 } from "../schemas/portfolio.js";
 import { wrapResponse, coerceNumbers } from "../formatters/response.js";
 
@@ -18,7 +28,7 @@ export function registerPortfolioTools(server: McpServer) {
     RiskAdjustedSchema.shape,
     async (params) => {
       const validated = RiskAdjustedSchema.parse(coerceNumbers(params));
-      const result = riskAdjustedReturns(JSON.stringify(validated));
+      const result = riskAdjustedReturns(validated);
       return wrapResponse(result);
     }
   );
@@ -29,7 +39,7 @@ export function registerPortfolioTools(server: McpServer) {
     RiskMetricsSchema.shape,
     async (params) => {
       const validated = RiskMetricsSchema.parse(coerceNumbers(params));
-      const result = riskMetrics(JSON.stringify(validated));
+      const result = riskMetrics(validated);
       return wrapResponse(result);
     }
   );
@@ -40,7 +50,62 @@ export function registerPortfolioTools(server: McpServer) {
     KellySchema.shape,
     async (params) => {
       const validated = KellySchema.parse(coerceNumbers(params));
-      const result = kellySizing(JSON.stringify(validated));
+      const result = kellySizing(validated);
+      return wrapResponse(result);
+    }
+  );
+
+  server.tool(
+    "blended_benchmark",
+    "Construct a custom blended benchmark from index returns with target weights and rebalancing rules (every period, calendar, drift threshold, buy-and-hold). Chain-links returns across benchmark definition changes and, given portfolio returns, reports excess return, tracking error, information ratio, hit rate and relative drawdown. The benchmark_returns series feeds GIPS reports and risk-adjusted metrics.",
+    BlendedBenchmarkSchema.shape,
+    async (params) => {
+      const validated = BlendedBenchmarkSchema.parse(coerceNumbers(params));
+      const result = blendedBenchmark(validated);
+      return wrapResponse(result);
+    }
+  );
+
+  server.tool(
+    "drawdown_analysis",
+    "Analyse drawdowns and recoveries from a return series: underwater curve, drawdown episodes with decline and recovery times, duration and recovery-time distributions, max and current drawdown, conditional drawdown-at-risk (CDaR), pain index, ulcer index, and pain/Martin ratios.",
+    DrawdownSchema.shape,
+    async (params) => {
+      const validated = DrawdownSchema.parse(coerceNumbers(params));
+      const result = drawdownAnalysis(validated);
+      return wrapResponse(result);
+    }
+  );
+
+  server.tool(
+    "performance_ratios",
+    "Downside and higher-moment performance ratios from a return series: Sortino on downside deviation below a minimum acceptable return, Omega ratio at configurable thresholds, Calmar (trailing 36 months) and MAR ratios, skewness/kurtosis adjusted Sharpe ratio, and upside/downside capture versus a benchmark.",
+    PerformanceRatiosSchema.shape,
+    async (params) => {
+      const validated = PerformanceRatiosSchema.parse(coerceNumbers(params));
+      const result = performanceRatios(validated);
+      return wrapResponse(result);
+    }
+  );
+
+  server.tool(
+    "portfolio_valuation",
+    "Value a mixed book of bonds, equities, equity options, interest-rate swaps, convertibles and structured notes against one shared market data set (rate and spread curves, vol surfaces, equity prices, FX rates) that positions reference by id. Each position is priced by its existing pricer and translated into the base currency. Returns per-position unit price, market value, weight, pricer Greeks and bump-and-revalue risk (DV01, CS01, 1% equity delta, 1 vol point vega, FX exposure), plus totals by instrument type and delta/gamma/vega/theta netted by underlying.",
+    PortfolioValuationSchema.shape,
+    async (params) => {
+      const validated = PortfolioValuationSchema.parse(coerceNumbers(params));
+      const result = valuePortfolio(validated);
+      return wrapResponse(result);
+    }
+  );
+
+  server.tool(
+    "revaluation_var",
+    "Value at Risk and expected shortfall of a book of typed positions (bonds, equities, options, swaps, convertibles, structured notes) by full revaluation: every historical or Monte Carlo scenario shifts the shared market data set and each position is repriced by its own pricer, so option convexity, vega and FX translation enter the P&L. Monte Carlo draws correlated normal factor moves (log moves for equities, commodities and USD). Returns VaR, ES, a first-order VaR from DV01/CS01/delta/vega on the same scenarios and the nonlinearity gap, P&L mean and dispersion, per-position P&L at VaR, ES contributions and standalone VaR, and the worst scenarios.",
+    RevaluationVarSchema.shape,
+    async (params) => {
+      const validated = RevaluationVarSchema.parse(coerceNumbers(params));
+      const result = calculateRevaluationVar(validated);
       return wrapResponse(result);
     }
   );
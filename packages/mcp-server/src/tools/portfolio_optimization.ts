@@ -16,7 +16,7 @@ export function registerPortfolioOptimizationTools(server: McpServer) {
     MeanVarianceSchema.shape,
     async (params) => {
       const validated = MeanVarianceSchema.parse(coerceNumbers(params));
-      const result = optimizeMeanVariance(JSON.stringify(validated));
+      const result = optimizeMeanVariance(validated);
       return wrapResponse(result);
     }
   );
@@ -27,7 +27,7 @@ export function registerPortfolioOptimizationTools(server: McpServer) {
     BlackLittermanPortfolioSchema.shape,
     async (params) => {
       const validated = BlackLittermanPortfolioSchema.parse(coerceNumbers(params));
-      const result = optimizeBlackLittermanPortfolio(JSON.stringify(validated));
+      const result = optimizeBlackLittermanPortfolio(validated);
       return wrapResponse(result);
     }
   );
@@ -3,11 +3,17 @@ import {
   priceUnitranche,
   modelDirectLoan,
   analyzeSyndication,
+  priceLeveragedLoan,
+  analyzeBridgeFinancing,
+  analyzeFundFinance,
 } from "../bindings.js";
 import {
   UnitrancheSchema,
   DirectLoanSchema,
   SyndicationSchema,
+  LoanPricingSchema,
+  BridgeFinancingSchema,
+  FundFinanceSchema,
 } from "../schemas/private_credit.js";
 import { wrapResponse, coerceNumbers } from "../formatters/response.js";
 
@@ -18,7 +24,7 @@ export function registerPrivateCreditTools(server: McpServer) {
     UnitrancheSchema.shape,
     async (params) => {
       const validated = UnitrancheSchema.parse(coerceNumbers(params));
-      const result = priceUnitranche(JSON.stringify(validated));
+      const result = priceUnitranche(validated);
       return wrapResponse(result);
     }
   );
@@ -29,7 +35,7 @@ export function registerPrivateCreditTools(server: McpServer) {
     DirectLoanSchema.shape,
     async (params) => {
       const validated = DirectLoanSchema.parse(coerceNumbers(params));
-      const result = modelDirectLoan(JSON.stringify(validated));
+      const result = modelDirectLoan(validated);
       return wrapResponse(result);
     }
   );
@@ -40,7 +46,40 @@ export function registerPrivateCreditTools(server: McpServer) {
     SyndicationSchema.shape,
     async (params) => {
       const validated = SyndicationSchema.parse(coerceNumbers(params));
-      const result = analyzeSyndication(JSON.stringify(validated));
+      const result = analyzeSyndication(validated);
+      return wrapResponse(result);
+    }
+  );
+
+  server.tool(
+    "leveraged_loan_pricing",
+    "Leveraged loan pricing: clearing spread and OID from a rating/leverage market grid, yield and discount margin to an assumed refinancing date, value of soft-call protection, and underwriter economics under spread and OID flex scenarios",
+    LoanPricingSchema.shape,
+    async (params) => {
+      const validated = LoanPricingSchema.parse(coerceNumbers(params));
+      const result = priceLeveragedLoan(validated);
+      return wrapResponse(result);
+    }
+  );
+
+  server.tool(
+    "bridge_financing",
+    "Acquisition bridge commitment economics for the underwriter: commitment, ticking, funding and duration fees, stepped-up coupon, funding and capital costs, takeout flex and securities demand cap losses, probability-weighted across takeout scenarios",
+    BridgeFinancingSchema.shape,
+    async (params) => {
+      const validated = BridgeFinancingSchema.parse(coerceNumbers(params));
+      const result = analyzeBridgeFinancing(validated);
+      return wrapResponse(result);
+    }
+  );
+
+  server.tool(
+    "fund_finance",
+    "Fund finance facilities: borrowing base for a NAV facility (eligible NAV after advance rates, single-asset and sector limits, LTV covenant) or a subscription line (included and designated investor commitments, investor limit), interest and fee cost, and the effect on LP net IRR and multiple",
+    FundFinanceSchema.shape,
+    async (params) => {
+      const validated = FundFinanceSchema.parse(coerceNumbers(params));
+      const result = analyzeFundFinance(validated);
       return wrapResponse(result);
     }
   );
@@ -22,7 +22,7 @@ export function registerPrivateWealthTools(server: McpServer) {
     ConcentratedStockSchema.shape,
     async (params) => {
       const validated = ConcentratedStockSchema.parse(coerceNumbers(params));
-      const result = analyzeConcentratedStock(JSON.stringify(validated));
+      const result = analyzeConcentratedStock(validated);
       return wrapResponse(result);
     }
   );
@@ -33,7 +33,7 @@ export function registerPrivateWealthTools(server: McpServer) {
     PhilanthropicVehiclesSchema.shape,
     async (params) => {
       const validated = PhilanthropicVehiclesSchema.parse(coerceNumbers(params));
-      const result = comparePhilanthropicVehicles(JSON.stringify(validated));
+      const result = comparePhilanthropicVehicles(validated);
       return wrapResponse(result);
     }
   );
@@ -44,7 +44,7 @@ export function registerPrivateWealthTools(server: McpServer) {
     WealthTransferSchema.shape,
     async (params) => {
       const validated = WealthTransferSchema.parse(coerceNumbers(params));
-      const result = analyzeWealthTransfer(JSON.stringify(validated));
+      const result = analyzeWealthTransfer(validated);
       return wrapResponse(result);
     }
   );
@@ -55,7 +55,7 @@ export function registerPrivateWealthTools(server: McpServer) {
     DirectIndexingSchema.shape,
     async (params) => {
       const validated = DirectIndexingSchema.parse(coerceNumbers(params));
-      const result = analyzeDirectIndexing(JSON.stringify(validated));
+      const result = analyzeDirectIndexing(validated);
       return wrapResponse(result);
     }
   );
@@ -66,7 +66,7 @@ export function registerPrivateWealthTools(server: McpServer) {
     FamilyGovernanceSchema.shape,
     async (params) => {
       const validated = FamilyGovernanceSchema.parse(coerceNumbers(params));
-      const result = evaluateFamilyGovernance(JSON.stringify(validated));
+      const result = evaluateFamilyGovernance(validated);
       return wrapResponse(result);
     }
   );
@@ -3,12 +3,14 @@ import {
   runFactorModel,
   runBlackLitterman,
   calculateRiskParity,
+  calculateHrp,
   runStressTest,
 } from "../bindings.js";
 import {
   FactorModelSchema,
   BlackLittermanSchema,
   RiskParitySchema,
+  HrpSchema,
   StressTestSchema,
 } from "../schemas/quant_risk.js";
 import { wrapResponse, coerceNumbers } from "../formatters/response.js";
@@ -20,7 +22,7 @@ export function registerQuantRiskTools(server: McpServer) {
     FactorModelSchema.shape,
     async (params) => {
       const validated = FactorModelSchema.parse(coerceNumbers(params));
-      const result = runFactorModel(JSON.stringify(validated));
+      const result = runFactorModel(validated);
       return wrapResponse(result);
     }
   );
@@ -31,7 +33,7 @@ export function registerQuantRiskTools(server: McpServer) {
     BlackLittermanSchema.shape,
     async (params) => {
       const validated = BlackLittermanSchema.parse(coerceNumbers(params));
-      const result = runBlackLitterman(JSON.stringify(validated));
+      const result = runBlackLitterman(validated);
       return wrapResponse(result);
     }
   );
@@ -42,18 +44,29 @@ export function registerQuantRiskTools(server: McpServer) {
     RiskParitySchema.shape,
     async (params) => {
       const validated = RiskParitySchema.parse(coerceNumbers(params));
-      const result = calculateRiskParity(JSON.stringify(validated));
+      const result = calculateRiskParity(validated);
+      return wrapResponse(result);
+    }
+  );
+
+  server.tool(
+    "hierarchical_risk_parity",
+    "Construct a Hierarchical Risk Parity (Lopez de Prado) portfolio. Clusters assets on correlation distance (single, complete or average linkage), quasi-diagonalises the covariance matrix and allocates by recursive bisection on inverse cluster variance, so no covariance inversion is needed. Returns weights, risk contributions, cluster order and merge tree, portfolio volatility, expected return, Sharpe ratio, diversification ratio, effective number of assets, and the covariance condition number.",
+    HrpSchema.shape,
+    async (params) => {
+      const validated = HrpSchema.parse(coerceNumbers(params));
+      const result = calculateHrp(validated);
       return wrapResponse(result);
     }
   );
 
   server.tool(
     "stress_test",
-    "Run portfolio stress tests across multiple historical or hypothetical scenarios. Maps market shocks (equity, rates, credit spreads, FX, commodities, volatility) to portfolio positions based on asset class, beta, and duration. Returns per-scenario P&L impact, per-position breakdown, worst case scenario, average loss, and VaR breach detection.",
+    "Run portfolio stress tests across multiple historical or hypothetical scenarios. Custom scenarios can be combined with named macro library scenarios (GFC 2008, COVID 2020, 1970s stagflation, +300bp rate shock) scaled by a severity factor. Maps market shocks (equity, rates, credit spreads, FX, commodities, volatility) to portfolio positions based on asset class, beta, and duration. Returns per-scenario P&L impact, per-position breakdown, worst case scenario, average loss, and VaR breach detection.",
     StressTestSchema.shape,
     async (params) => {
       const validated = StressTestSchema.parse(coerceNumbers(params));
-      const result = runStressTest(JSON.stringify(validated));
+      const result = runStressTest(validated);
       return wrapResponse(result);
     }
   );
@@ -2,10 +2,12 @@ import { McpServer } from "@modelcontextprotocol/sdk/server/mcp.js";
 import {
   analyzePairsTrading,
   analyzeMomentum,
+  runEventStudy,
 } from "../bindings.js";
 import {
   PairsTradingSchema,
   MomentumSchema,
+  EventStudySchema,
 } from "../schemas/quant_strategies.js";
 import { wrapResponse, coerceNumbers } from "../formatters/response.js";
 
@@ -16,7 +18,7 @@ export function registerQuantStrategiesTools(server: McpServer) {
     PairsTradingSchema.shape,
     async (params) => {
       const validated = PairsTradingSchema.parse(coerceNumbers(params));
-      const result = analyzePairsTrading(JSON.stringify(validated));
+      const result = analyzePairsTrading(validated);
       return wrapResponse(result);
     }
   );
@@ -27,7 +29,18 @@ export function registerQuantStrategiesTools(server: McpServer) {
     MomentumSchema.shape,
     async (params) => {
       const validated = MomentumSchema.parse(coerceNumbers(params));
-      const result = analyzeMomentum(JSON.stringify(validated));
+      const result = analyzeMomentum(validated);
+      return wrapResponse(result);
+    }
+  );
+
+  server.tool(
+    "event_study",
+    "Event study: market-model, market-adjusted or mean-adjusted abnormal returns, CAR and CAAR over the event window with cross-sectional t, Patell and BMP test statistics",
+    EventStudySchema.shape,
+    async (params) => {
+      const validated = EventStudySchema.parse(coerceNumbers(params));
+      const result = runEventStudy(validated);
       return wrapResponse(result);
     }
   );
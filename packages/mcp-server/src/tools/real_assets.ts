@@ -10,7 +10,7 @@ export function registerRealAssetsTools(server: McpServer) {
     PropertyValuationSchema.shape,
     async (params) => {
       const validated = PropertyValuationSchema.parse(coerceNumbers(params));
-      const result = valueProperty(JSON.stringify(validated));
+      const result = valueProperty(validated);
       return wrapResponse(result);
     }
   );
@@ -21,7 +21,7 @@ export function registerRealAssetsTools(server: McpServer) {
     ProjectFinanceSchema.shape,
     async (params) => {
       const validated = ProjectFinanceSchema.parse(coerceNumbers(params));
-      const result = modelProjectFinance(JSON.stringify(validated));
+      const result = modelProjectFinance(validated);
       return wrapResponse(result);
     }
   );
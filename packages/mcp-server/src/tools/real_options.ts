@@ -16,7 +16,7 @@ export function registerRealOptionsTools(server: McpServer) {
     RealOptionSchema.shape,
     async (params) => {
       const validated = RealOptionSchema.parse(coerceNumbers(params));
-      const result = valueRealOption(JSON.stringify(validated));
+      const result = valueRealOption(validated);
       return wrapResponse(result);
     }
   );
@@ -27,7 +27,7 @@ export function registerRealOptionsTools(server: McpServer) {
     DecisionTreeSchema.shape,
     async (params) => {
       const validated = DecisionTreeSchema.parse(coerceNumbers(params));
-      const result = analyzeDecisionTree(JSON.stringify(validated));
+      const result = analyzeDecisionTree(validated);
       return wrapResponse(result);
     }
   );
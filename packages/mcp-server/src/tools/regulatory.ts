@@ -4,12 +4,14 @@ import {
   calculateLcr,
   calculateNsfr,
   analyzeAlm,
+  calculateSaccr,
 } from "../bindings.js";
 import {
   RegulatoryCapitalSchema,
   LcrSchema,
   NsfrSchema,
   AlmSchema,
+  SaccrSchema,
 } from "../schemas/regulatory.js";
 import { wrapResponse, coerceNumbers } from "../formatters/response.js";
 
@@ -20,7 +22,7 @@ export function registerRegulatoryTools(server: McpServer) {
     RegulatoryCapitalSchema.shape,
     async (params) => {
       const validated = RegulatoryCapitalSchema.parse(coerceNumbers(params));
-      const result = calculateRegulatoryCapital(JSON.stringify(validated));
+      const result = calculateRegulatoryCapital(validated);
       return wrapResponse(result);
     }
   );
@@ -31,7 +33,7 @@ export function registerRegulatoryTools(server: McpServer) {
     LcrSchema.shape,
     async (params) => {
       const validated = LcrSchema.parse(coerceNumbers(params));
-      const result = calculateLcr(JSON.stringify(validated));
+      const result = calculateLcr(validated);
       return wrapResponse(result);
     }
   );
@@ -42,7 +44,7 @@ export function registerRegulatoryTools(server: McpServer) {
     NsfrSchema.shape,
     async (params) => {
       const validated = NsfrSchema.parse(coerceNumbers(params));
-      const result = calculateNsfr(JSON.stringify(validated));
+      const result = calculateNsfr(validated);
       return wrapResponse(result);
     }
   );
@@ -53,7 +55,18 @@ export function registerRegulatoryTools(server: McpServer) {
     AlmSchema.shape,
     async (params) => {
       const validated = AlmSchema.parse(coerceNumbers(params));
-      const result = analyzeAlm(JSON.stringify(validated));
+      const result = analyzeAlm(validated);
+      return wrapResponse(result);
+    }
+  );
+
+  server.tool(
+    "saccr_exposure",
+    "Calculate counterparty credit exposure at default under Basel SA-CCR (CRE52). Per netting set: replacement cost after collateral and margin terms, PFE add-ons by asset class and hedging set (IR maturity buckets, FX pairs, credit/equity entities, commodity types) with supervisory deltas, maturity factors and the PFE multiplier; EAD = 1.4 x (RC + PFE), margined sets capped at unmargined EAD. Returns credit exposures ready for regulatory_capital.",
+    SaccrSchema.shape,
+    async (params) => {
+      const validated = SaccrSchema.parse(coerceNumbers(params));
+      const result = calculateSaccr(validated);
       return wrapResponse(result);
     }
   );
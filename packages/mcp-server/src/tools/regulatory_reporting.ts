@@ -16,7 +16,7 @@ export function registerRegulatoryReportingTools(server: McpServer) {
     AifmdReportingSchema.shape,
     async (params) => {
       const validated = AifmdReportingSchema.parse(coerceNumbers(params));
-      const result = generateAifmdReport(JSON.stringify(validated));
+      const result = generateAifmdReport(validated);
       return wrapResponse(result);
     }
   );
@@ -27,7 +27,7 @@ export function registerRegulatoryReportingTools(server: McpServer) {
     SecCftcReportingSchema.shape,
     async (params) => {
       const validated = SecCftcReportingSchema.parse(coerceNumbers(params));
-      const result = generateSecCftcReport(JSON.stringify(validated));
+      const result = generateSecCftcReport(validated);
       return wrapResponse(result);
     }
   );
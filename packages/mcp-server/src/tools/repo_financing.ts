@@ -16,7 +16,7 @@ export function registerRepoFinancingTools(server: McpServer) {
     RepoAnalyticsSchema.shape,
     async (params) => {
       const validated = RepoAnalyticsSchema.parse(coerceNumbers(params));
-      const result = analyzeRepo(JSON.stringify(validated));
+      const result = analyzeRepo(validated);
       return wrapResponse(result);
     }
   );
@@ -27,7 +27,7 @@ export function registerRepoFinancingTools(server: McpServer) {
     CollateralSchema.shape,
     async (params) => {
       const validated = CollateralSchema.parse(coerceNumbers(params));
-      const result = analyzeCollateral(JSON.stringify(validated));
+      const result = analyzeCollateral(validated);
       return wrapResponse(result);
     }
   );
@@ -10,7 +10,7 @@ export function registerRestructuringTools(server: McpServer) {
     RecoverySchema.shape,
     async (params) => {
       const validated = RecoverySchema.parse(coerceNumbers(params));
-      const result = analyzeRecovery(JSON.stringify(validated));
+      const result = analyzeRecovery(validated);
       return wrapResponse(result);
     }
   );
@@ -21,7 +21,7 @@ export function registerRestructuringTools(server: McpServer) {
     DistressedDebtSchema.shape,
     async (params) => {
       const validated = DistressedDebtSchema.parse(coerceNumbers(params));
-      const result = analyzeDistressedDebt(JSON.stringify(validated));
+      const result = analyzeDistressedDebt(validated);
       return wrapResponse(result);
     }
   );
@@ -16,7 +16,7 @@ export function registerRiskBudgetingTools(server: McpServer) {
     FactorRiskBudgetSchema.shape,
     async (params) => {
       const validated = FactorRiskBudgetSchema.parse(coerceNumbers(params));
-      const result = analyzeFactorRiskBudget(JSON.stringify(validated));
+      const result = analyzeFactorRiskBudget(validated);
       return wrapResponse(result);
     }
   );
@@ -27,7 +27,7 @@ export function registerRiskBudgetingTools(server: McpServer) {
     TailRiskSchema.shape,
     async (params) => {
       const validated = TailRiskSchema.parse(coerceNumbers(params));
-      const result = analyzeTailRisk(JSON.stringify(validated));
+      const result = analyzeTailRisk(validated);
       return wrapResponse(result);
     }
   );
@@ -2,10 +2,12 @@ import { McpServer } from "@modelcontextprotocol/sdk/server/mcp.js";
 import {
   buildSensitivityGrid,
   scenarioAnalysis,
+  shiftMarketData,
 } from "../bindings.js";
 import {
   SensitivitySchema,
   ScenarioSchema,
+  MarketShiftSchema,
 } from "../schemas/scenarios.js";
 import { wrapResponse, coerceNumbers } from "../formatters/response.js";
 
@@ -16,7 +18,7 @@ export function registerScenarioTools(server: McpServer) {
     SensitivitySchema.shape,
     async (params) => {
       const validated = SensitivitySchema.parse(coerceNumbers(params));
-      const result = buildSensitivityGrid(JSON.stringify(validated));
+      const result = buildSensitivityGrid(validated);
       return wrapResponse(result);
     }
   );
@@ -27,7 +29,18 @@ export function registerScenarioTools(server: McpServer) {
     ScenarioSchema.shape,
     async (params) => {
       const validated = ScenarioSchema.parse(coerceNumbers(params));
-      const result = scenarioAnalysis(JSON.stringify(validated));
+      const result = scenarioAnalysis(validated);
+      return wrapResponse(result);
+    }
+  );
+
+  server.tool(
+    "shift_market_data",
+    "Apply a macro scenario consistently to a whole market data set in one operation. Shifts rate curves (parallel plus key-rate twists), widens spread curves, moves vol surfaces in vol points with their spot following the underlying, and shocks equity prices, commodity prices and USD FX pairs. Accepts custom scenarios or named macro library scenarios (GFC 2008, COVID 2020, 1970s stagflation, +300bp) with per-id overrides. Returns the shifted set per scenario, with curves and surfaces in the versioned format ready for swap, spread and exotic pricers.",
+    MarketShiftSchema.shape,
+    async (params) => {
+      const validated = MarketShiftSchema.parse(coerceNumbers(params));
+      const result = shiftMarketData(validated);
       return wrapResponse(result);
     }
   );
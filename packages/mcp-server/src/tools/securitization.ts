@@ -1,6 +1,6 @@
 import { McpServer } from "@modelcontextprotocol/sdk/server/mcp.js";
-import { modelAbsCashflows, analyzeTranching } from "../bindings.js";
-import { AbsMbsSchema, TranchingSchema } from "../schemas/securitization.js";
+import { modelAbsCashflows, modelCmbsCashflows, analyzeTranching } from "../bindings.js";
+import { AbsMbsSchema, CmbsSchema, TranchingSchema } from "../schemas/securitization.js";
 import { wrapResponse, coerceNumbers } from "../formatters/response.js";
 
 export function registerSecuritizationTools(server: McpServer) {
@@ -10,18 +10,29 @@ export function registerSecuritizationTools(server: McpServer) {
     AbsMbsSchema.shape,
     async (params) => {
       const validated = AbsMbsSchema.parse(coerceNumbers(params));
-      const result = modelAbsCashflows(JSON.stringify(validated));
+      const result = modelAbsCashflows(validated);
+      return wrapResponse(result);
+    }
+  );
+
+  server.tool(
+    "cmbs_cashflow_model",
+    "Model CMBS pool cash flows loan by loan from property NOI rather than residential CPR/CDR curves. Loans default in term when DSCR falls below a threshold and at maturity when the balloon fails the refinance LTV/DSCR test (optionally at a stressed exit cap rate). Specially serviced loans carry appraisal reduction amounts that cut interest advances through ASER; the servicer advances P&I until nonrecoverable and is reimbursed with interest from liquidation proceeds. Returns monthly pool cash flows, loan outcomes (default type and timing, loss severity) and summary statistics.",
+    CmbsSchema.shape,
+    async (params) => {
+      const validated = CmbsSchema.parse(coerceNumbers(params));
+      const result = modelCmbsCashflows(validated);
       return wrapResponse(result);
     }
   );
 
   server.tool(
     "tranching_analysis",
-    "Analyse a CDO/CLO tranching structure with waterfall distribution. Runs the full sequential/turbo waterfall for each period of collateral cash flows, applying OC/IC tests, loss allocation bottom-up, and reinvestment logic. Returns per-tranche results (IRR, WAL, credit enhancement), waterfall period detail, and deal summary metrics.",
+    "Analyse a CDO/CLO tranching structure with waterfall distribution. Runs the full sequential/turbo waterfall for each period of collateral cash flows, applying OC/IC tests, loss allocation bottom-up, and reinvestment logic. Optional waterfall rules add pro-rata pay with a switch to sequential on OC/IC or cumulative loss trigger breaches, turbo amortisation and excess-spread trapping. Returns per-tranche results (IRR, WAL, credit enhancement), tranche-level cash flows and balances by period, trigger timing, and deal summary metrics.",
     TranchingSchema.shape,
     async (params) => {
       const validated = TranchingSchema.parse(coerceNumbers(params));
-      const result = analyzeTranching(JSON.stringify(validated));
+      const result = analyzeTranching(validated);
       return wrapResponse(result);
     }
   );
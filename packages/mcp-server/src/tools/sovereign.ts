@@ -16,7 +16,7 @@ export function registerSovereignTools(server: McpServer) {
     SovereignBondSchema.shape,
     async (params) => {
       const validated = SovereignBondSchema.parse(coerceNumbers(params));
-      const result = analyzeSovereignBond(JSON.stringify(validated));
+      const result = analyzeSovereignBond(validated);
       return wrapResponse(result);
     }
   );
@@ -27,7 +27,7 @@ export function registerSovereignTools(server: McpServer) {
     CountryRiskSchema.shape,
     async (params) => {
       const validated = CountryRiskSchema.parse(coerceNumbers(params));
-      const result = assessCountryRisk(JSON.stringify(validated));
+      const result = assessCountryRisk(validated);
       return wrapResponse(result);
     }
   );
@@ -16,7 +16,7 @@ export function registerStructuredProductsTools(server: McpServer) {
     StructuredNoteSchema.shape,
     async (params) => {
       const validated = StructuredNoteSchema.parse(coerceNumbers(params));
-      const result = priceStructuredNote(JSON.stringify(validated));
+      const result = priceStructuredNote(validated);
       return wrapResponse(result);
     }
   );
@@ -27,7 +27,7 @@ export function registerStructuredProductsTools(server: McpServer) {
     ExoticProductSchema.shape,
     async (params) => {
       const validated = ExoticProductSchema.parse(coerceNumbers(params));
-      const result = priceExotic(JSON.stringify(validated));
+      const result = priceExotic(validated);
       return wrapResponse(result);
     }
   );
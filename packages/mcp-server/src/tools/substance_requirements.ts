@@ -16,7 +16,7 @@ export function registerSubstanceRequirementsTools(server: McpServer) {
     EconomicSubstanceSchema.shape,
     async (params) => {
       const validated = EconomicSubstanceSchema.parse(coerceNumbers(params));
-      const result = analyzeEconomicSubstance(JSON.stringify(validated));
+      const result = analyzeEconomicSubstance(validated);
       return wrapResponse(result);
     }
   );
@@ -27,7 +27,7 @@ export function registerSubstanceRequirementsTools(server: McpServer) {
     JurisdictionSubstanceTestSchema.shape,
     async (params) => {
       const validated = JurisdictionSubstanceTestSchema.parse(coerceNumbers(params));
-      const result = runJurisdictionSubstanceTest(JSON.stringify(validated));
+      const result = runJurisdictionSubstanceTest(validated);
       return wrapResponse(result);
     }
   );
@@ -16,7 +16,7 @@ export function registerTaxTreatyTools(server: McpServer) {
     TreatyNetworkSchema.shape,
     async (params) => {
       const validated = TreatyNetworkSchema.parse(coerceNumbers(params));
-      const result = analyzeTreatyNetwork(JSON.stringify(validated));
+      const result = analyzeTreatyNetwork(validated);
       return wrapResponse(result);
     }
   );
@@ -27,7 +27,7 @@ export function registerTaxTreatyTools(server: McpServer) {
     TreatyOptSchema.shape,
     async (params) => {
       const validated = TreatyOptSchema.parse(coerceNumbers(params));
-      const result = optimizeTreatyStructure(JSON.stringify(validated));
+      const result = optimizeTreatyStructure(validated);
       return wrapResponse(result);
     }
   );
@@ -1,6 +1,12 @@
 import { McpServer } from "@modelcontextprotocol/sdk/server/mcp.js";
-import { buildThreeStatement } from "../bindings.js";
-import { ThreeStatementSchema } from "../schemas/three_statement.js";
+import { buildThreeStatement, modelToDcf   buildLtm,
+  calendarize,
+  annualizePeriod,
+} from "../bindings.js";
+import { ModelToDcfSchema, ThreeStatementSchema   LtmSchema,
+  CalendarizeSchema,
+  AnnualizeSchema,
+} from "../schemas/three_statement.js";
 import { wrapResponse, coerceNumbers } from "../formatters/response.js";
 
 export function registerThreeStatementTools(server: McpServer) {
@@ -10,7 +16,51 @@ export function registerThreeStatementTools(server: McpServer) {
     ThreeStatementSchema.shape,
     async (params) => {
       const validated = ThreeStatementSchema.parse(coerceNumbers(params));
-      const result = buildThreeStatement(JSON.stringify(validated));
+      const result = buildThreeStatement(validated);
+      return wrapResponse(result);
+    }
+  );
+
+  server.tool(
+    "model_to_dcf",
+    "Build a three-statement model and value it with an FCFF DCF in one step. Unlevered free cash flow per year is derived from the projected statements (EBIT x (1 - tax rate) + D&A - capex - increase in working capital) and discounted at WACC with the chosen terminal method. Net debt defaults to the base-year balance sheet. Returns the model, the per-year FCFF bridge and the full DCF output.",
+    ModelToDcfSchema.shape,
+    async (params) => {
+      const validated = ModelToDcfSchema.parse(coerceNumbers(params));
+      const result = modelToDcf(validated);
+      return wrapResponse(result);
+    }
+  );
+
+  server.tool(
+    "ltm_build",
+    "LTM build-up: last fiscal year plus current year-to-date less prior year-to-date for each metric, with point-in-time balance sheet metrics taken from the latest interim",
+    LtmSchema.shape,
+    async (params) => {
+      const validated = LtmSchema.parse(coerceNumbers(params));
+      const result = buildLtm(validated);
+      return wrapResponse(result);
+    }
+  );
+
+  server.tool(
+    "calendarize",
+    "Calendarization: time-weight fiscal periods of companies with different year ends onto a common twelve-month window ending on a target date, with point-in-time metrics from the latest period",
+    CalendarizeSchema.shape,
+    async (params) => {
+      const validated = CalendarizeSchema.parse(coerceNumbers(params));
+      const result = calendarize(validated);
+      return wrapResponse(result);
+    }
+  );
+
+  server.tool(
+    "annualize_period",
+    "Partial-period annualization: run-rate (12 / months) or seasonal scaling using the prior year's comparable-period share of the full fiscal year",
+    AnnualizeSchema.shape,
+    async (params) => {
+      const validated = AnnualizeSchema.parse(coerceNumbers(params));
+      const result = annualizePeriod(validated);
       return wrapResponse(result);
     }
   );
@@ -16,7 +16,7 @@ export function registerTradeFinanceTools(server: McpServer) {
     LetterOfCreditSchema.shape,
     async (params) => {
       const validated = LetterOfCreditSchema.parse(coerceNumbers(params));
-      const result = priceLetterOfCredit(JSON.stringify(validated));
+      const result = priceLetterOfCredit(validated);
       return wrapResponse(result);
     }
   );
@@ -27,7 +27,7 @@ export function registerTradeFinanceTools(server: McpServer) {
     SupplyChainFinanceSchema.shape,
     async (params) => {
       const validated = SupplyChainFinanceSchema.parse(coerceNumbers(params));
-      const result = analyzeSupplyChainFinance(JSON.stringify(validated));
+      const result = analyzeSupplyChainFinance(validated);
       return wrapResponse(result);
     }
   );
@@ -16,7 +16,7 @@ export function registerTransferPricingTools(server: McpServer) {
     BepsSchema.shape,
     async (params) => {
       const validated = BepsSchema.parse(coerceNumbers(params));
-      const result = analyzeBepsCompliance(JSON.stringify(validated));
+      const result = analyzeBepsCompliance(validated);
       return wrapResponse(result);
     }
   );
@@ -27,7 +27,7 @@ export function registerTransferPricingTools(server: McpServer) {
     IntercompanySchema.shape,
     async (params) => {
       const validated = IntercompanySchema.parse(coerceNumbers(params));
-      const result = analyzeIntercompany(JSON.stringify(validated));
+      const result = analyzeIntercompany(validated);
       return wrapResponse(result);
     }
   );
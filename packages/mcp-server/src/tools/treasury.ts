@@ -16,7 +16,7 @@ export function registerTreasuryTools(server: McpServer) {
     CashManagementSchema.shape,
     async (params) => {
       const validated = CashManagementSchema.parse(coerceNumbers(params));
-      const result = analyzeCashManagement(JSON.stringify(validated));
+      const result = analyzeCashManagement(validated);
       return wrapResponse(result);
     }
   );
@@ -27,7 +27,7 @@ export function registerTreasuryTools(server: McpServer) {
     HedgingSchema.shape,
     async (params) => {
       const validated = HedgingSchema.parse(coerceNumbers(params));
-      const result = analyzeHedging(JSON.stringify(validated));
+      const result = analyzeHedging(validated);
       return wrapResponse(result);
     }
   );
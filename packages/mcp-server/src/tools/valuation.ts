@@ -1,6 +1,20 @@
 import { McpServer } from "@modelcontextprotocol/sdk/server/mcp.js";
-import { calculateWacc, buildDcf, compsAnalysis } from "../bindings.js";
-import { WaccSchema, DcfSchema, CompsSchema } from "../schemas/valuation.js";
+import { calculateWacc, buildDcf, compsAnalysis, valueSmallBusiness   peerScreen,
+  assetBasedValuation,
+  buildCostOfEquity,
+  applyValuationAdjustments,
+  economicProfitValuation,
+  calculateApv,
+  checkRateConsistency,
+} from "../bindings.js";
+import { WaccSchema, DcfSchema, CompsSchema, SmallBusinessSchema   PeerScreenSchema,
+  AssetBasedValuationSchema,
+  CostOfEquitySchema,
+  ValuationAdjustmentsSchema,
+  EconomicProfitSchema,
+  ApvSchema,
+  RateConsistencySchema,
+} from "../schemas/valuation.js";
 import { wrapResponse, coerceNumbers } from "../formatters/response.js";
 
 export function registerValuationTools(server: McpServer) {
@@ -10,29 +24,117 @@ export function registerValuationTools(server: McpServer) {
     WaccSchema.shape,
     async (params) => {
       const validated = WaccSchema.parse(coerceNumbers(params));
-      const result = calculateWacc(JSON.stringify(validated));
+      const result = calculateWacc(validated);
       return wrapResponse(result);
     }
   );
 
   server.tool(
     "dcf_model",
-    "Build a discounted cash flow (DCF) model using FCFF methodology. Projects revenue, EBITDA, and free cash flow, then discounts to present value. Supports Gordon Growth and/or exit multiple terminal value. Returns enterprise value, equity value, and per-share value with full year-by-year projections.",
+    "Build a discounted cash flow (DCF) model using FCFF methodology. Projects revenue, EBITDA, and free cash flow, then discounts to present value. Supports Gordon Growth, exit multiple, value-driver (NOPAT, RONIC, growth) and convergence terminal values, with a cross-check panel of implied exit multiple, implied perpetual growth and alternative-method TVs. Returns enterprise value, equity value, and per-share value with full year-by-year projections.",
     DcfSchema.shape,
     async (params) => {
       const validated = DcfSchema.parse(coerceNumbers(params));
-      const result = buildDcf(JSON.stringify(validated));
+      const result = buildDcf(validated);
       return wrapResponse(result);
     }
   );
 
   server.tool(
     "comps_analysis",
-    "Perform trading comparables (comps) analysis. Calculates valuation multiples (EV/EBITDA, EV/Revenue, P/E, P/B, PEG) across a peer set, computes mean/median/high/low statistics, and derives implied valuations for the target company.",
+    "Perform trading comparables (comps) analysis. Calculates valuation multiples (EV/EBITDA, EV/Revenue, P/E, P/B, PEG) across a peer set, computes mean/median/high/low statistics, and derives implied valuations for the target company. Also supports sector KPI multiples (EV/ARR, EV/subscriber, per bed, per ton) and regression of a KPI multiple against growth/churn drivers.",
     CompsSchema.shape,
     async (params) => {
       const validated = CompsSchema.parse(coerceNumbers(params));
-      const result = compsAnalysis(JSON.stringify(validated));
+      const result = compsAnalysis(validated);
+      return wrapResponse(result);
+    }
+  );
+
+  server.tool(
+    "small_business_valuation",
+    "Value a small or owner-managed business without full DCF inputs. Normalizes each year's pre-tax income to seller's discretionary earnings (adding back owner compensation and perks, interest, D&A and one-off items) and to EBITDA after a market wage for a replacement manager, weights the years (latest heaviest by default), capitalizes normalized cash flow at a build-up rate (risk-free + ERP + size + industry + specific - growth), and prices SDE or EBITDA off market multiple ranges by industry and size band (generic bands if none supplied). Returns normalized years, the build-up, income and market values, the weighted conclusion, equity value and implied multiples.",
+    SmallBusinessSchema.shape,
+    async (params) => {
+      const validated = SmallBusinessSchema.parse(coerceNumbers(params));
+      const result = valueSmallBusiness(validated);
+      return wrapResponse(result);
+    }
+  );
+
+  server.tool(
+    "peer_screen",
+    "Peer screening: filter a candidate universe by sector, region, size band, growth and margin, score similarity to the target on weighted size, growth, margin and sector, and suggest a ranked comp set",
+    PeerScreenSchema.shape,
+    async (params) => {
+      const validated = PeerScreenSchema.parse(coerceNumbers(params));
+      const result = peerScreen(validated);
+      return wrapResponse(result);
+    }
+  );
+
+  server.tool(
+    "asset_based_valuation",
+    "Asset-based valuation: adjusted book value / NAV from category haircuts or appraisals, orderly and forced liquidation values net of disposal and wind-down costs discounted to today, and comparison with going-concern equity value",
+    AssetBasedValuationSchema.shape,
+    async (params) => {
+      const validated = AssetBasedValuationSchema.parse(coerceNumbers(params));
+      const result = assetBasedValuation(validated);
+      return wrapResponse(result);
+    }
+  );
+
+  server.tool(
+    "cost_of_equity_buildup",
+    "Cost of equity build-up: unlever peer betas (Hamada, practitioners or Harris-Pringle), relever at the target structure, then add size premium (fixed or market-cap schedule), country risk premium with lambda exposure and company-specific premium",
+    CostOfEquitySchema.shape,
+    async (params) => {
+      const validated = CostOfEquitySchema.parse(coerceNumbers(params));
+      const result = buildCostOfEquity(validated);
+      return wrapResponse(result);
+    }
+  );
+
+  server.tool(
+    "valuation_adjustments",
+    "Valuation adjustments bridge: move an enterprise value to the equity value of a specific interest via net debt, control premium or minority discount, and DLOM (Chaffe protective put, Finnerty average-strike put, or their average)",
+    ValuationAdjustmentsSchema.shape,
+    async (params) => {
+      const validated = ValuationAdjustmentsSchema.parse(coerceNumbers(params));
+      const result = applyValuationAdjustments(validated);
+      return wrapResponse(result);
+    }
+  );
+
+  server.tool(
+    "economic_profit_valuation",
+    "Economic profit (EVA) valuation on the same forecast as the DCF: rolls invested capital forward by capex less D&A plus working capital investment, charges WACC on opening capital to get economic profit each year, and values the firm as opening invested capital plus the present value of explicit and continuing economic profit. Returns per-year ROIC, spread, capital charge and economic profit, market value added, value creation share, and a reconciliation to the DCF enterprise value (including the mid-year timing adjustment).",
+    EconomicProfitSchema.shape,
+    async (params) => {
+      const validated = EconomicProfitSchema.parse(coerceNumbers(params));
+      const result = economicProfitValuation(validated);
+      return wrapResponse(result);
+    }
+  );
+
+  server.tool(
+    "apv_valuation",
+    "Adjusted present value (APV): values the unlevered firm by discounting unlevered FCF and a Gordon growth terminal value at the unlevered cost of equity, then adds the present value of interest tax shields (discounted at the cost of debt per Myers or at the unlevered cost of equity per Harris-Pringle, optionally capped by EBIT and with a terminal shield on perpetual debt), subtracts expected distress costs, and adds other financing side effects such as subsidised loans or issuance fees. Returns the per-year shield build, each value component, APV, and equity value and value per share when net debt and shares are given.",
+    ApvSchema.shape,
+    async (params) => {
+      const validated = ApvSchema.parse(coerceNumbers(params));
+      const result = calculateApv(validated);
+      return wrapResponse(result);
+    }
+  );
+
+  server.tool(
+    "rate_consistency_check",
+    "Check that a discount rate matches the cash flows it is applied to: same currency (converting via relative inflation, the Fisher relation across currencies) and same nominal or real basis (nominal = (1 + real)(1 + inflation) - 1). Flags forecasts whose embedded inflation differs from the currency's expected inflation and terminal growth rates at or above the discount rate. Returns the consistent discount rate, each conversion step, and issues by severity.",
+    RateConsistencySchema.shape,
+    async (params) => {
+      const validated = RateConsistencySchema.parse(coerceNumbers(params));
+      const result = checkRateConsistency(validated);
       return wrapResponse(result);
     }
   );
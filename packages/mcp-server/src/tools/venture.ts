@@ -22,7 +22,7 @@ export function registerVentureTools(server: McpServer) {
     FundingRoundSchema.shape,
     async (params) => {
       const validated = FundingRoundSchema.parse(coerceNumbers(params));
-      const result = modelFundingRound(JSON.stringify(validated));
+      const result = modelFundingRound(validated);
       return wrapResponse(result);
     }
   );
@@ -33,7 +33,7 @@ export function registerVentureTools(server: McpServer) {
     DilutionSchema.shape,
     async (params) => {
       const validated = DilutionSchema.parse(coerceNumbers(params));
-      const result = analyzeDilution(JSON.stringify(validated));
+      const result = analyzeDilution(validated);
       return wrapResponse(result);
     }
   );
@@ -44,7 +44,7 @@ export function registerVentureTools(server: McpServer) {
     ConvertibleNoteSchema.shape,
     async (params) => {
       const validated = ConvertibleNoteSchema.parse(coerceNumbers(params));
-      const result = convertNote(JSON.stringify(validated));
+      const result = convertNote(validated);
       return wrapResponse(result);
     }
   );
@@ -55,7 +55,7 @@ export function registerVentureTools(server: McpServer) {
     SafeSchema.shape,
     async (params) => {
       const validated = SafeSchema.parse(coerceNumbers(params));
-      const result = convertSafe(JSON.stringify(validated));
+      const result = convertSafe(validated);
       return wrapResponse(result);
     }
   );
@@ -66,7 +66,7 @@ export function registerVentureTools(server: McpServer) {
     VentureFundSchema.shape,
     async (params) => {
       const validated = VentureFundSchema.parse(coerceNumbers(params));
-      const result = modelVentureFund(JSON.stringify(validated));
+      const result = modelVentureFund(validated);
       return wrapResponse(result);
     }
   );
@@ -16,7 +16,7 @@ export function registerVolatilitySurfaceTools(server: McpServer) {
     ImpliedVolSurfaceSchema.shape,
     async (params) => {
       const validated = ImpliedVolSurfaceSchema.parse(coerceNumbers(params));
-      const result = buildImpliedVolSurface(JSON.stringify(validated));
+      const result = buildImpliedVolSurface(validated);
       return wrapResponse(result);
     }
   );
@@ -27,7 +27,7 @@ export function registerVolatilitySurfaceTools(server: McpServer) {
     SabrCalibrationSchema.shape,
     async (params) => {
       const validated = SabrCalibrationSchema.parse(coerceNumbers(params));
-      const result = calibrateSabr(JSON.stringify(validated));
+      const result = calibrateSabr(validated);
       return wrapResponse(result);
     }
   );
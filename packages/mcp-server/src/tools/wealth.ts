@@ -18,7 +18,7 @@ export function registerWealthTools(server: McpServer) {
     RetirementSchema.shape,
     async (params) => {
       const validated = RetirementSchema.parse(coerceNumbers(params));
-      const result = planRetirement(JSON.stringify(validated));
+      const result = planRetirement(validated);
       return wrapResponse(result);
     }
   );
@@ -29,7 +29,7 @@ export function registerWealthTools(server: McpServer) {
     TlhSchema.shape,
     async (params) => {
       const validated = TlhSchema.parse(coerceNumbers(params));
-      const result = simulateTaxLossHarvesting(JSON.stringify(validated));
+      const result = simulateTaxLossHarvesting(validated);
       return wrapResponse(result);
     }
   );
@@ -40,7 +40,7 @@ export function registerWealthTools(server: McpServer) {
     EstatePlanSchema.shape,
     async (params) => {
       const validated = EstatePlanSchema.parse(coerceNumbers(params));
-      const result = planEstate(JSON.stringify(validated));
+      const result = planEstate(validated);
       return wrapResponse(result);
     }
   );
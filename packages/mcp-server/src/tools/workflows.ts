@@ -22,7 +22,7 @@ export function registerWorkflowTools(server: McpServer) {
     WorkflowListSchema.shape,
     async (params) => {
       const validated = WorkflowListSchema.parse(coerceNumbers(params));
-      const result = workflowList(JSON.stringify(validated));
+      const result = workflowList(validated);
       return wrapResponse(result);
     }
   );
@@ -33,7 +33,7 @@ export function registerWorkflowTools(server: McpServer) {
     WorkflowDescribeSchema.shape,
     async (params) => {
       const validated = WorkflowDescribeSchema.parse(coerceNumbers(params));
-      const result = workflowDescribe(JSON.stringify(validated));
+      const result = workflowDescribe(validated);
       return wrapResponse(result);
     }
   );
@@ -44,7 +44,7 @@ export function registerWorkflowTools(server: McpServer) {
     WorkflowValidateSchema.shape,
     async (params) => {
       const validated = WorkflowValidateSchema.parse(coerceNumbers(params));
-      const result = workflowValidate(JSON.stringify(validated));
+      const result = workflowValidate(validated);
       return wrapResponse(result);
     }
   );
@@ -55,7 +55,7 @@ export function registerWorkflowTools(server: McpServer) {
     WorkflowQualityCheckSchema.shape,
     async (params) => {
       const validated = WorkflowQualityCheckSchema.parse(coerceNumbers(params));
-      const result = workflowQualityCheck(JSON.stringify(validated));
+      const result = workflowQualityCheck(validated);
       return wrapResponse(result);
     }
   );
@@ -66,7 +66,7 @@ export function registerWorkflowTools(server: McpServer) {
     WorkflowAuditSchema.shape,
     async (params) => {
       const validated = WorkflowAuditSchema.parse(coerceNumbers(params));
-      const result = workflowAudit(JSON.stringify(validated));
+      const result = workflowAudit(validated);
       return wrapResponse(result);
     }
   );